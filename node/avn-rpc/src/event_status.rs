@@ -0,0 +1,123 @@
+use jsonrpsee::{
+    core::{Error as JsonRpseeError, RpcResult as Result},
+    proc_macros::rpc,
+    types::error::{CallError, ErrorCode, ErrorObject},
+};
+use node_primitives::BlockNumber;
+use pallet_ethereum_events_runtime_api::EthEventStatusApi;
+use serde::{Deserialize, Serialize};
+use sp_api::ProvideRuntimeApi;
+use sp_avn_common::event_types::{CheckResult, EthEventStatus, ValidEvents};
+use sp_blockchain::HeaderBackend;
+use sp_core::H256;
+use sp_runtime::traits::Block as BlockT;
+use std::sync::Arc;
+
+/// The status of an Ethereum event on the AvN
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct EventStatus {
+    pub event_type: String,
+    pub event_signature: H256,
+    pub transaction_hash: H256,
+    /// Short name of the stage the event is in
+    pub status: String,
+    /// What the status means for the event
+    pub description: String,
+}
+
+impl EventStatus {
+    fn new(
+        event_signature: H256,
+        transaction_hash: H256,
+        status: EthEventStatus<BlockNumber>,
+    ) -> Self {
+        let event_type = ValidEvents::try_from(&event_signature)
+            .map(|event_type| format!("{:?}", event_type))
+            .unwrap_or_else(|| "Unknown".to_string());
+
+        let (status, description) = match status {
+            EthEventStatus::Discovered { votes } => (
+                "Discovered",
+                format!("Found by {} author(s), waiting for the authors to agree on it", votes),
+            ),
+            EthEventStatus::Unchecked =>
+                ("Unchecked", "Waiting to be checked by a validator".to_string()),
+            EthEventStatus::PendingChallenge { check_result, ready_for_processing_after_block } =>
+                (
+                    "PendingChallenge",
+                    format!(
+                        "Checked as {}, it can be challenged until block {}",
+                        check_result_name(&check_result),
+                        ready_for_processing_after_block
+                    ),
+                ),
+            EthEventStatus::Processed { accepted: true } =>
+                ("Accepted", "Processed and accepted by the AvN".to_string()),
+            EthEventStatus::Processed { accepted: false } =>
+                ("Rejected", "Processed but rejected by the AvN".to_string()),
+        };
+
+        EventStatus {
+            event_type,
+            event_signature,
+            transaction_hash,
+            status: status.to_string(),
+            description,
+        }
+    }
+}
+
+fn check_result_name(check_result: &CheckResult) -> &'static str {
+    match check_result {
+        CheckResult::Ok => "valid",
+        CheckResult::Invalid => "invalid",
+        CheckResult::HttpErrorCheckingEvent => "unverifiable",
+        CheckResult::InsufficientConfirmations => "not confirmed",
+        CheckResult::Unknown => "unknown",
+    }
+}
+
+#[rpc(server, namespace = "avn")]
+pub trait EventStatusApi {
+    /// Returns the status of each event of the Ethereum transaction `tx_hash` at the best block.
+    /// An empty list means the AvN does not know about any event of the transaction.
+    #[method(name = "eventStatus")]
+    fn event_status(&self, tx_hash: H256) -> Result<Vec<EventStatus>>;
+}
+
+pub struct EventStatusProvider<C, Block> {
+    client: Arc<C>,
+    _marker: std::marker::PhantomData<Block>,
+}
+
+impl<C, Block> EventStatusProvider<C, Block> {
+    pub fn new(client: Arc<C>) -> Self {
+        Self { client, _marker: Default::default() }
+    }
+}
+
+impl<C, Block> EventStatusApiServer for EventStatusProvider<C, Block>
+where
+    Block: BlockT,
+    C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+    C::Api: EthEventStatusApi<Block, BlockNumber>,
+{
+    fn event_status(&self, tx_hash: H256) -> Result<Vec<EventStatus>> {
+        let best_hash = self.client.info().best_hash;
+        let statuses = self.client.runtime_api().event_status(best_hash, tx_hash).map_err(|e| {
+            JsonRpseeError::Call(CallError::Custom(ErrorObject::owned(
+                ErrorCode::InternalError.code(),
+                "Error querying the status of the events",
+                Some(format!("{:?}", e)),
+            )))
+        })?;
+
+        Ok(statuses
+            .into_iter()
+            .map(|(event_id, status)| {
+                EventStatus::new(event_id.signature, event_id.transaction_hash, status)
+            })
+            .collect())
+    }
+}
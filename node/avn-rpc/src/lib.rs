@@ -0,0 +1,9 @@
+//! AvN specific RPC methods served by the node.
+
+mod event_status;
+mod processed_events;
+mod root_proof;
+
+pub use event_status::{EventStatus, EventStatusApiServer, EventStatusProvider};
+pub use processed_events::{ProcessedEvent, ProcessedEvents, ProcessedEventsApiServer};
+pub use root_proof::{RootProof, RootProofApiServer, RootProofProvider, RootProofTarget};
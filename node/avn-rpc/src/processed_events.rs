@@ -0,0 +1,84 @@
+use codec::Decode;
+use jsonrpsee::{
+    core::{Error as JsonRpseeError, RpcResult as Result},
+    proc_macros::rpc,
+    types::error::{CallError, ErrorCode, ErrorObject},
+};
+use node_primitives::BlockNumber;
+use serde::{Deserialize, Serialize};
+use sp_avn_common::event_types::{
+    processed_event_index_key, EthEventId, ProcessedEventRecord, ValidEvents,
+};
+use sp_core::{
+    offchain::{OffchainStorage, STORAGE_PREFIX},
+    H256,
+};
+
+/// A processed Ethereum event, as recorded in the offchain index
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ProcessedEvent {
+    pub event_type: String,
+    pub event_signature: H256,
+    pub transaction_hash: H256,
+    /// The AvN block the event was processed in
+    pub block_number: BlockNumber,
+    pub accepted: bool,
+}
+
+#[rpc(server, namespace = "avn")]
+pub trait ProcessedEventsApi {
+    /// Returns the events of the Ethereum transaction `tx_hash` that have been processed by the
+    /// AvN. The node must run with offchain indexing enabled, and only events processed while it
+    /// was enabled are returned.
+    #[method(name = "processedEvents")]
+    fn processed_events(&self, tx_hash: H256) -> Result<Vec<ProcessedEvent>>;
+}
+
+pub struct ProcessedEvents<S> {
+    storage: S,
+}
+
+impl<S> ProcessedEvents<S> {
+    pub fn new(storage: S) -> Self {
+        Self { storage }
+    }
+}
+
+impl<S> ProcessedEventsApiServer for ProcessedEvents<S>
+where
+    S: OffchainStorage + 'static,
+{
+    fn processed_events(&self, tx_hash: H256) -> Result<Vec<ProcessedEvent>> {
+        let mut processed_events = Vec::new();
+
+        for event_type in ValidEvents::values() {
+            let event_id =
+                EthEventId { signature: event_type.signature(), transaction_hash: tx_hash };
+            let encoded_record =
+                match self.storage.get(STORAGE_PREFIX, &processed_event_index_key(&event_id)) {
+                    Some(encoded_record) => encoded_record,
+                    None => continue,
+                };
+
+            let record = ProcessedEventRecord::<BlockNumber>::decode(&mut &encoded_record[..])
+                .map_err(|e| {
+                    JsonRpseeError::Call(CallError::Custom(ErrorObject::owned(
+                        ErrorCode::InternalError.code(),
+                        "Error decoding processed event record",
+                        Some(format!("{:?}", e)),
+                    )))
+                })?;
+
+            processed_events.push(ProcessedEvent {
+                event_type: format!("{:?}", event_type),
+                event_signature: record.event_id.signature,
+                transaction_hash: record.event_id.transaction_hash,
+                block_number: record.block_number,
+                accepted: record.accepted,
+            });
+        }
+
+        Ok(processed_events)
+    }
+}
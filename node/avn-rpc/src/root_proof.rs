@@ -0,0 +1,187 @@
+use avn_service::{
+    extrinsic_utils::{
+        get_extrinsics_and_check_if_filter_target_exists,
+        process_extrinsics_in_block_and_check_if_filter_target_exists, EncodedLeafData,
+        LowerLeafFilter,
+    },
+    merkle_tree_utils::{generate_merkle_path, generate_tree_root},
+};
+use jsonrpsee::{
+    core::{Error as JsonRpseeError, RpcResult as Result},
+    proc_macros::rpc,
+    types::error::{CallError, ErrorCode, ErrorObject},
+};
+use node_primitives::BlockNumber;
+use pallet_summary_runtime_api::SummaryApi;
+use sc_client_api::{client::BlockBackend, UsageProvider};
+use serde::{Deserialize, Serialize};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_core::{Bytes, H256};
+use sp_runtime::traits::Block as BlockT;
+use std::sync::Arc;
+
+/// The leaf to prove, identified either by its position or by its content
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(untagged)]
+pub enum RootProofTarget {
+    /// The extrinsic at `extrinsic_index` in the AvN block `block_number`
+    #[serde(rename_all = "camelCase")]
+    Extrinsic { block_number: BlockNumber, extrinsic_index: u32 },
+    /// An encoded extrinsic included in the AvN block `block_number`
+    #[serde(rename_all = "camelCase")]
+    Leaf { block_number: BlockNumber, leaf: Bytes },
+}
+
+impl RootProofTarget {
+    fn block_number(&self) -> BlockNumber {
+        match self {
+            RootProofTarget::Extrinsic { block_number, .. } => *block_number,
+            RootProofTarget::Leaf { block_number, .. } => *block_number,
+        }
+    }
+}
+
+/// Proof that a leaf is part of a root published on Ethereum
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct RootProof {
+    pub root_hash: H256,
+    /// First AvN block summarised by the root
+    pub from_block: BlockNumber,
+    /// Last AvN block summarised by the root
+    pub to_block: BlockNumber,
+    /// Hash of the AvN header at `to_block`, published to Ethereum with the root
+    pub header_hash: Option<H256>,
+    /// The id of the Ethereum transaction publishing the root, if one has been assigned
+    pub tx_id: Option<u32>,
+    pub encoded_leaf: Bytes,
+    pub merkle_path: Vec<H256>,
+}
+
+#[rpc(server, namespace = "avn")]
+pub trait RootProofApi {
+    /// Returns the merkle path of `target` in the validated summary root covering its block. The
+    /// node must still have the bodies of all the blocks in the root's range.
+    #[method(name = "rootProof", blocking)]
+    fn root_proof(&self, target: RootProofTarget) -> Result<RootProof>;
+}
+
+pub struct RootProofProvider<C, Block> {
+    client: Arc<C>,
+    _marker: std::marker::PhantomData<Block>,
+}
+
+impl<C, Block> RootProofProvider<C, Block> {
+    pub fn new(client: Arc<C>) -> Self {
+        Self { client, _marker: Default::default() }
+    }
+}
+
+impl<C, Block> RootProofProvider<C, Block>
+where
+    Block: BlockT,
+    C: BlockBackend<Block> + UsageProvider<Block> + Send + Sync + 'static,
+{
+    fn range_leaves(
+        &self,
+        from_block: BlockNumber,
+        to_block: BlockNumber,
+    ) -> Result<Vec<EncodedLeafData>> {
+        let mut leaves = vec![];
+        for block_number in from_block..=to_block {
+            let (_, mut block_leaves) =
+                process_extrinsics_in_block_and_check_if_filter_target_exists(
+                    &self.client,
+                    block_number,
+                    None,
+                )?;
+            leaves.append(&mut block_leaves);
+        }
+
+        Ok(leaves)
+    }
+}
+
+fn call_error(code: ErrorCode, message: String, data: Option<String>) -> JsonRpseeError {
+    JsonRpseeError::Call(CallError::Custom(ErrorObject::owned(code.code(), message, data)))
+}
+
+impl<C, Block> RootProofApiServer for RootProofProvider<C, Block>
+where
+    Block: BlockT,
+    C: ProvideRuntimeApi<Block>
+        + HeaderBackend<Block>
+        + BlockBackend<Block>
+        + UsageProvider<Block>
+        + Send
+        + Sync
+        + 'static,
+    C::Api: SummaryApi<Block, BlockNumber>,
+{
+    fn root_proof(&self, target: RootProofTarget) -> Result<RootProof> {
+        let best_hash = self.client.info().best_hash;
+        let root = self
+            .client
+            .runtime_api()
+            .validated_root(best_hash, target.block_number())
+            .map_err(|e| {
+                call_error(
+                    ErrorCode::InternalError,
+                    "Error querying the summary root".to_string(),
+                    Some(format!("{:?}", e)),
+                )
+            })?
+            .ok_or_else(|| {
+                call_error(
+                    ErrorCode::InvalidParams,
+                    format!("No validated root covers block #{}", target.block_number()),
+                    None,
+                )
+            })?;
+
+        let (encoded_leaf, leaves) = match target {
+            RootProofTarget::Extrinsic { block_number, extrinsic_index } =>
+                get_extrinsics_and_check_if_filter_target_exists(
+                    &self.client,
+                    root.from_block,
+                    root.to_block,
+                    LowerLeafFilter { block_number, extrinsic_index },
+                )?,
+            RootProofTarget::Leaf { leaf, .. } => {
+                let leaves = self.range_leaves(root.from_block, root.to_block)?;
+                (Some(leaf.0).filter(|leaf| leaves.contains(leaf)), leaves)
+            },
+        };
+
+        let encoded_leaf = encoded_leaf.ok_or_else(|| {
+            call_error(
+                ErrorCode::InvalidParams,
+                "The leaf is not part of the root".to_string(),
+                None,
+            )
+        })?;
+
+        // The leaves are read from this node's blocks, so make sure they rebuild the root the
+        // validators agreed on before handing out a path to it.
+        if generate_tree_root(leaves.clone())? != root.root_hash {
+            return Err(call_error(
+                ErrorCode::InternalError,
+                "The blocks of the range do not match the root".to_string(),
+                None,
+            ))
+        }
+
+        let merkle_path = generate_merkle_path(&encoded_leaf, leaves)?;
+
+        Ok(RootProof {
+            root_hash: root.root_hash,
+            from_block: root.from_block,
+            to_block: root.to_block,
+            header_hash: root.header_hash,
+            tx_id: root.tx_id,
+            encoded_leaf: encoded_leaf.into(),
+            merkle_path,
+        })
+    }
+}
@@ -8,12 +8,13 @@ use sp_api::ApiExt;
 use sp_avn_common::{
     event_discovery::{
         encode_eth_event_submission_data, events_helpers::EthereumEventsPartitionFactory,
-        DiscoveredEvent, EthBlockRange, EthereumEventsPartition,
+        DiscoveredEvent, EthBlockRange, EthereumEventsPartition, MAX_INCOMING_EVENTS_BATCH_SIZE,
     },
     event_types::{
         AddedValidatorData, AvtGrowthLiftedData, AvtLowerClaimedData, Error, EthEvent, EthEventId,
-        EventData, LiftedData, NftCancelListingData, NftEndBatchListingData, NftMintData,
-        NftTransferToData, ValidEvents,
+        EventData, LiftedData, LiftedToDestinationData, LiftedWithVestingData,
+        NftBatchListingScheduleData, NftCancelListingData, NftEndBatchListingData, NftMintData,
+        NftOfferAcceptedData, NftTransferToData, SftMintData, SftTransferToData, ValidEvents,
     },
     AVN_KEY_ID,
 };
@@ -149,6 +150,66 @@ impl EventRegistry {
                 },
             },
         );
+        m.insert(
+            ValidEvents::LiftedWithVesting.signature(),
+            EventInfo {
+                parser: |data, topics| {
+                    LiftedWithVestingData::parse_bytes(data, topics)
+                        .map_err(|err| AppError::ParsingError(err.into()))
+                        .map(|data| EventData::LogLiftedWithVesting(data))
+                },
+            },
+        );
+        m.insert(
+            ValidEvents::LiftedToDestination.signature(),
+            EventInfo {
+                parser: |data, topics| {
+                    LiftedToDestinationData::parse_bytes(data, topics)
+                        .map_err(|err| AppError::ParsingError(err.into()))
+                        .map(|data| EventData::LogLiftedToDestination(data))
+                },
+            },
+        );
+        m.insert(
+            ValidEvents::SftMint.signature(),
+            EventInfo {
+                parser: |data, topics| {
+                    SftMintData::parse_bytes(data, topics)
+                        .map_err(|err| AppError::ParsingError(err.into()))
+                        .map(|data| EventData::LogSftMinted(data))
+                },
+            },
+        );
+        m.insert(
+            ValidEvents::SftTransferTo.signature(),
+            EventInfo {
+                parser: |data, topics| {
+                    SftTransferToData::parse_bytes(data, topics)
+                        .map_err(|err| AppError::ParsingError(err.into()))
+                        .map(|data| EventData::LogSftTransferTo(data))
+                },
+            },
+        );
+        m.insert(
+            ValidEvents::NftBatchListingScheduled.signature(),
+            EventInfo {
+                parser: |data, topics| {
+                    NftBatchListingScheduleData::parse_bytes(data, topics)
+                        .map_err(|err| AppError::ParsingError(err.into()))
+                        .map(|data| EventData::LogNftBatchListingScheduled(data))
+                },
+            },
+        );
+        m.insert(
+            ValidEvents::NftOfferAccepted.signature(),
+            EventInfo {
+                parser: |data, topics| {
+                    NftOfferAcceptedData::parse_bytes(data, topics)
+                        .map_err(|err| AppError::ParsingError(err.into()))
+                        .map(|data| EventData::LogNftOfferAccepted(data))
+                },
+            },
+        );
         EventRegistry { registry: m }
     }
 
@@ -580,13 +641,23 @@ where
         + ApiExt<Block>
         + BlockBuilder<Block>,
 {
-    let contract_address = config
+    // Runtimes without the api of the registered bridge contracts only lift from the main one
+    let contracts = match config
         .client
         .runtime_api()
-        .query_bridge_contract(config.client.info().best_hash)
-        .map_err(|err| format!("Failed to query bridge contract: {:?}", err))?;
-    let contract_address_web3 = web3::types::H160::from_slice(&contract_address.to_fixed_bytes());
-    let contract_addresses = vec![contract_address_web3];
+        .query_bridge_contracts(config.client.info().best_hash)
+    {
+        Ok(contracts) => contracts,
+        Err(_) => vec![config
+            .client
+            .runtime_api()
+            .query_bridge_contract(config.client.info().best_hash)
+            .map_err(|err| format!("Failed to query bridge contract: {:?}", err))?],
+    };
+    let contract_addresses: Vec<H160> = contracts
+        .iter()
+        .map(|contract| web3::types::H160::from_slice(&contract.to_fixed_bytes()))
+        .collect();
 
     let event_signatures = config
         .client
@@ -656,8 +727,15 @@ where
     .await
     .map_err(|err| format!("Error retrieving events: {:?}", err))?;
 
+    // Runtimes without the query partition events by the maximum batch size
+    let max_events_per_partition = config
+        .client
+        .runtime_api()
+        .query_max_events_per_partition(config.client.info().best_hash)
+        .unwrap_or(MAX_INCOMING_EVENTS_BATCH_SIZE);
+
     let ethereum_events_partitions =
-        EthereumEventsPartitionFactory::create_partitions(range, events);
+        EthereumEventsPartitionFactory::create_partitions(range, events, max_events_per_partition);
     let partition = ethereum_events_partitions
         .iter()
         .find(|p| p.partition() == partition_id)
@@ -9,9 +9,9 @@ use crate::chain_spec::{
     ImOnlineId, ParaId,
 };
 use avn_parachain_runtime::{
-    self as avn_runtime, AuthorityDiscoveryConfig, EthBridgeConfig, EthereumEventsConfig,
-    ImOnlineConfig, ParachainStakingConfig, SudoConfig, SummaryConfig, TokenManagerConfig,
-    ValidatorsManagerConfig,
+    self as avn_runtime, AnchorSummaryConfig, AuthorityDiscoveryConfig, EthBridgeConfig,
+    EthereumEventsConfig, ImOnlineConfig, ParachainStakingConfig, SudoConfig, SummaryConfig,
+    TokenManagerConfig, ValidatorsManagerConfig,
 };
 use node_primitives::AccountId;
 
@@ -129,6 +129,12 @@ pub(crate) fn testnet_genesis(
         },
         sudo: SudoConfig { key: Some(sudo_account) },
         summary: SummaryConfig { schedule_period, voting_period, _phantom: Default::default() },
+        // Roots anchored on the AvN are cheap, so they can be created more often
+        anchor_summary: AnchorSummaryConfig {
+            schedule_period: HALF_HOUR_SCHEDULE_PERIOD,
+            voting_period: SMALL_VOTING_PERIOD,
+            _phantom: Default::default(),
+        },
         token_manager: TokenManagerConfig {
             _phantom: Default::default(),
             lower_account_id: H256(hex!(
@@ -149,5 +155,6 @@ pub(crate) fn testnet_genesis(
                 }
             },
         },
+        treasury: Default::default(),
     }
 }
@@ -146,5 +146,6 @@ pub(crate) fn avn_test_runtime_genesis(
             lower_schedule_period: 10,
             balances: vec![],
         },
+        treasury: Default::default(),
     }
 }
@@ -1,5 +1,5 @@
 use codec::Codec;
-use node_primitives::{AccountId, Balance, Block as BlockT, Nonce};
+use node_primitives::{AccountId, Balance, Block as BlockT, BlockNumber, Nonce};
 use sp_consensus_aura::sr25519::AuthorityId as AuraId;
 
 use sc_cli::ChainSpec;
@@ -28,6 +28,10 @@ pub trait AvnRuntimeApiCollection:
     + cumulus_primitives_core::CollectCollationInfo<BlockT>
     + sp_consensus_aura::AuraApi<BlockT, AuraId>
     + pallet_eth_bridge_runtime_api::EthEventHandlerApi<BlockT, AccountId>
+    + pallet_ethereum_events_runtime_api::EthEventStatusApi<BlockT, BlockNumber>
+    + pallet_summary_runtime_api::SummaryApi<BlockT, BlockNumber>
+    + pallet_avn_runtime_api::AvnConfigApi<BlockT>
+    + pallet_avn_runtime_api::AvnFeeApi<BlockT, Balance, u32>
 where
     AccountId: Codec,
 {
@@ -45,6 +49,10 @@ impl<Api> AvnRuntimeApiCollection for Api where
         + cumulus_primitives_core::CollectCollationInfo<BlockT>
         + sp_consensus_aura::AuraApi<BlockT, AuraId>
         + pallet_eth_bridge_runtime_api::EthEventHandlerApi<BlockT, AccountId>
+        + pallet_ethereum_events_runtime_api::EthEventStatusApi<BlockT, BlockNumber>
+        + pallet_summary_runtime_api::SummaryApi<BlockT, BlockNumber>
+        + pallet_avn_runtime_api::AvnConfigApi<BlockT>
+        + pallet_avn_runtime_api::AvnFeeApi<BlockT, Balance, u32>
 {
 }
 
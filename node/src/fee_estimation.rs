@@ -0,0 +1,122 @@
+//! RPC estimating what an extrinsic costs its sender, including the AvN specific fees.
+
+use std::sync::Arc;
+
+use jsonrpsee::{
+    core::{Error as JsonRpseeError, RpcResult},
+    proc_macros::rpc,
+    types::error::{CallError, ErrorCode, ErrorObject},
+};
+use node_primitives::Balance;
+use pallet_avn_runtime_api::{AvnFeeApi, FeeBreakdown};
+use serde::{Deserialize, Serialize};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_core::{Bytes, H160};
+use sp_runtime::traits::Block as BlockT;
+
+/// The id of an asset fees can be paid in
+pub type AssetId = u32;
+
+/// An amount paid in a token or asset other than AVT
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct Payment<Id> {
+    /// What the amount is paid in
+    pub id: Id,
+    /// Decimal amount
+    pub amount: String,
+}
+
+/// The fees of an extrinsic. Amounts are decimal strings so clients don't lose precision.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct FeeEstimate {
+    /// Computation time of the extrinsic, in picoseconds
+    pub weight_ref_time: u64,
+    /// Size of the proof of the extrinsic, in bytes
+    pub weight_proof_size: u64,
+    /// Fee of the dispatch class of the extrinsic
+    pub base_fee: String,
+    /// Fee for the encoded length of the extrinsic
+    pub length_fee: String,
+    /// Fee for the weight of the extrinsic
+    pub weight_fee: String,
+    /// Tip paid to the block author
+    pub tip: String,
+    /// The AVT fee charged to the sender, once its known sender adjustment is applied
+    pub total_fee: String,
+    /// The fee in the asset the sender pays its fees in, if it doesn't pay in AVT
+    pub fee_asset: Option<Payment<AssetId>>,
+    /// The token and amount paid to the relayer of a proxied call
+    pub relayer_fee: Option<Payment<H160>>,
+}
+
+impl From<FeeBreakdown<Balance, AssetId>> for FeeEstimate {
+    fn from(breakdown: FeeBreakdown<Balance, AssetId>) -> Self {
+        FeeEstimate {
+            weight_ref_time: breakdown.weight_ref_time,
+            weight_proof_size: breakdown.weight_proof_size,
+            base_fee: breakdown.base_fee.to_string(),
+            length_fee: breakdown.length_fee.to_string(),
+            weight_fee: breakdown.weight_fee.to_string(),
+            tip: breakdown.tip.to_string(),
+            total_fee: breakdown.adjusted_fee.to_string(),
+            fee_asset: breakdown
+                .fee_asset
+                .map(|(id, amount)| Payment { id, amount: amount.to_string() }),
+            relayer_fee: breakdown
+                .relayer_fee
+                .map(|(id, amount)| Payment { id, amount: amount.to_string() }),
+        }
+    }
+}
+
+/// Fee estimation RPC methods
+#[rpc(server, namespace = "avn")]
+pub trait FeeEstimationApi {
+    /// Breaks down what the SCALE encoded `extrinsic` would cost at the best block, including
+    /// known sender adjustments, fees paid in assets and relayer fees of proxied calls.
+    #[method(name = "estimateFee")]
+    fn estimate_fee(&self, extrinsic: Bytes) -> RpcResult<FeeEstimate>;
+}
+
+/// Serves the fee estimation RPC methods
+pub struct FeeEstimation<C, Block> {
+    client: Arc<C>,
+    _marker: std::marker::PhantomData<Block>,
+}
+
+impl<C, Block> FeeEstimation<C, Block> {
+    /// Creates a new instance of the fee estimation RPC
+    pub fn new(client: Arc<C>) -> Self {
+        Self { client, _marker: Default::default() }
+    }
+}
+
+fn call_error(code: ErrorCode, message: &str, data: Option<String>) -> JsonRpseeError {
+    JsonRpseeError::Call(CallError::Custom(ErrorObject::owned(code.code(), message, data)))
+}
+
+impl<C, Block> FeeEstimationApiServer for FeeEstimation<C, Block>
+where
+    Block: BlockT,
+    C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+    C::Api: AvnFeeApi<Block, Balance, AssetId>,
+{
+    fn estimate_fee(&self, extrinsic: Bytes) -> RpcResult<FeeEstimate> {
+        let best_hash = self.client.info().best_hash;
+        self.client
+            .runtime_api()
+            .estimate_fee(best_hash, extrinsic.to_vec())
+            .map_err(|e| {
+                call_error(
+                    ErrorCode::InternalError,
+                    "Error estimating the fee",
+                    Some(format!("{:?}", e)),
+                )
+            })?
+            .map(FeeEstimate::from)
+            .ok_or_else(|| call_error(ErrorCode::InvalidParams, "Invalid extrinsic", None))
+    }
+}
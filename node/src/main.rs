@@ -9,6 +9,8 @@ mod avn_config;
 mod cli;
 mod command;
 mod common;
+mod fee_estimation;
+mod protocol_pool;
 mod rpc;
 
 fn main() -> sc_cli::Result<()> {
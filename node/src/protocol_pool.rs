@@ -0,0 +1,96 @@
+// Copyright 2024 Aventus Network Services (UK) Ltd.
+
+//! Submission path for the AvN protocol transactions.
+//!
+//! Summary votes, event checks and corroborations are unsigned transactions created by the
+//! offchain workers and the AvN service. They don't pay fees, so they can't outbid user
+//! transactions, and the node must not hold them back because of heuristics meant for user
+//! traffic. They are validated directly against the runtime as local transactions and inserted
+//! into the validated pool, skipping the ban list. The pool then ranks them with the priority
+//! given by the runtime, which is the maximum for protocol transactions, so when the pool is full
+//! user transactions are evicted first.
+//!
+//! Like the local submissions of the full pool, the transactions are validated with a blocking
+//! call into the runtime, so no async executor is driven from the offchain worker threads.
+
+use sc_client_api::{blockchain::HeaderBackend, BlockBackend};
+use sc_transaction_pool::{error::Error, ChainApi, FullChainApi, FullPool, ValidatedTransaction};
+use sc_transaction_pool_api::{
+    error::Error as PoolError, LocalTransactionFor, LocalTransactionPool, TransactionSource,
+};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderMetadata;
+use sp_runtime::{
+    generic::BlockId,
+    traits::{Block as BlockT, BlockIdTo, SaturatedConversion},
+    transaction_validity::TransactionValidityError,
+};
+use sp_transaction_pool::runtime_api::TaggedTransactionQueue;
+use std::sync::Arc;
+
+/// Local transaction pool handed to the AvN offchain submitters
+pub struct ProtocolTransactionPool<Block: BlockT, Client> {
+    pool: Arc<FullPool<Block, Client>>,
+}
+
+impl<Block: BlockT, Client> ProtocolTransactionPool<Block, Client> {
+    pub fn new(pool: Arc<FullPool<Block, Client>>) -> Self {
+        Self { pool }
+    }
+}
+
+impl<Block: BlockT, Client> Clone for ProtocolTransactionPool<Block, Client> {
+    fn clone(&self) -> Self {
+        Self { pool: self.pool.clone() }
+    }
+}
+
+impl<Block, Client> LocalTransactionPool for ProtocolTransactionPool<Block, Client>
+where
+    Block: BlockT,
+    Client: ProvideRuntimeApi<Block>
+        + BlockBackend<Block>
+        + HeaderBackend<Block>
+        + BlockIdTo<Block>
+        + HeaderMetadata<Block, Error = sp_blockchain::Error>
+        + Send
+        + Sync
+        + 'static,
+    Client::Api: TaggedTransactionQueue<Block>,
+{
+    type Block = Block;
+    type Hash = Block::Hash;
+    type Error = Error;
+
+    fn submit_local(
+        &self,
+        at: Block::Hash,
+        xt: LocalTransactionFor<Self>,
+    ) -> Result<Self::Hash, Self::Error> {
+        let api: &FullChainApi<Client, Block> = self.pool.api();
+
+        let validity = api
+            .validate_transaction_blocking(at, TransactionSource::Local, xt.clone())?
+            .map_err(|e| match e {
+                TransactionValidityError::Invalid(i) => PoolError::InvalidTransaction(i),
+                TransactionValidityError::Unknown(u) => PoolError::UnknownTransaction(u),
+            })?;
+
+        let block_number = api
+            .block_id_to_number(&BlockId::Hash(at))?
+            .ok_or_else(|| Error::BlockIdConversion(format!("{:?}", at)))?;
+
+        let (hash, bytes) = api.hash_and_length(&xt);
+        let validated = ValidatedTransaction::valid_at(
+            block_number.saturated_into::<u64>(),
+            hash,
+            TransactionSource::Local,
+            xt,
+            bytes,
+            validity,
+        );
+
+        log::debug!(target: "avn-service", "Submitting AvN protocol transaction {:?}", hash);
+        self.pool.pool().validated_pool().submit(vec![validated]).remove(0)
+    }
+}
@@ -7,7 +7,7 @@
 
 use std::sync::Arc;
 
-use node_primitives::{AccountId, Balance, Nonce};
+use node_primitives::{AccountId, Balance, BlockNumber, Nonce};
 use runtime_common::opaque::Block;
 
 use sc_client_api::{client::BlockBackend, AuxStore, UsageProvider};
@@ -16,23 +16,26 @@ use sc_transaction_pool_api::TransactionPool;
 use sp_api::ProvideRuntimeApi;
 use sp_block_builder::BlockBuilder;
 use sp_blockchain::{Error as BlockChainError, HeaderBackend, HeaderMetadata};
+use sp_core::offchain::OffchainStorage;
 
 /// A type representing all RPC extensions.
 pub type RpcExtension = jsonrpsee::RpcModule<()>;
 
 /// Full client dependencies
-pub struct FullDeps<C, P> {
+pub struct FullDeps<C, P, S> {
     /// The client instance to use.
     pub client: Arc<C>,
     /// Transaction pool instance.
     pub pool: Arc<P>,
     /// Whether to deny unsafe calls
     pub deny_unsafe: DenyUnsafe,
+    /// The offchain storage of the node, if it has one
+    pub offchain_storage: Option<S>,
 }
 
 /// Instantiate all RPC extensions.
-pub fn create_full<C, P>(
-    deps: FullDeps<C, P>,
+pub fn create_full<C, P, S>(
+    deps: FullDeps<C, P, S>,
 ) -> Result<RpcExtension, Box<dyn std::error::Error + Send + Sync>>
 where
     C: ProvideRuntimeApi<Block>
@@ -47,19 +50,35 @@ where
     C::Api: pallet_transaction_payment_rpc::TransactionPaymentRuntimeApi<Block, Balance>,
     C::Api: substrate_frame_rpc_system::AccountNonceApi<Block, AccountId, Nonce>,
     C::Api: BlockBuilder<Block>,
+    C::Api: pallet_ethereum_events_runtime_api::EthEventStatusApi<Block, BlockNumber>,
+    C::Api: pallet_summary_runtime_api::SummaryApi<Block, BlockNumber>,
+    C::Api: pallet_avn_runtime_api::AvnFeeApi<Block, Balance, crate::fee_estimation::AssetId>,
     P: TransactionPool + Sync + Send + 'static,
+    S: OffchainStorage + 'static,
 {
+    use crate::fee_estimation::{FeeEstimation, FeeEstimationApiServer};
     use avn_lower_rpc::{LowerDataProvider, LowerDataProviderRpcServer};
+    use avn_rpc::{
+        EventStatusApiServer, EventStatusProvider, ProcessedEvents, ProcessedEventsApiServer,
+        RootProofApiServer, RootProofProvider,
+    };
     use pallet_transaction_payment_rpc::{TransactionPayment, TransactionPaymentApiServer};
     use substrate_frame_rpc_system::{System, SystemApiServer};
 
     let mut module = RpcExtension::new(());
-    let FullDeps { client, pool, deny_unsafe } = deps;
+    let FullDeps { client, pool, deny_unsafe, offchain_storage } = deps;
 
     module.merge(System::new(client.clone(), pool.clone(), deny_unsafe).into_rpc())?;
     module.merge(TransactionPayment::new(client.clone()).into_rpc())?;
+    module.merge(FeeEstimation::new(client.clone()).into_rpc())?;
 
+    module.merge(EventStatusProvider::new(client.clone()).into_rpc())?;
+    module.merge(RootProofProvider::new(client.clone()).into_rpc())?;
     module.merge(LowerDataProvider::new(client).into_rpc())?;
 
+    if let Some(offchain_storage) = offchain_storage {
+        module.merge(ProcessedEvents::new(offchain_storage).into_rpc())?;
+    }
+
     Ok(module)
 }
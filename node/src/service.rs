@@ -4,7 +4,10 @@
 use codec::Encode;
 use cumulus_client_cli::CollatorOptions;
 use futures::lock::Mutex;
-use runtime_common::opaque::{Block, Hash};
+use runtime_common::{
+    constants::time::RELAY_CHAIN_SLOT_DURATION_MILLIS,
+    opaque::{Block, Hash},
+};
 use sc_client_api::Backend;
 use sc_network_sync::SyncingService;
 use sp_api::ConstructRuntimeApi;
@@ -38,7 +41,9 @@ use sp_avn_common::{DEFAULT_EXTERNAL_SERVICE_PORT_NUMBER, EXTERNAL_SERVICE_PORT_
 use sp_keystore::KeystorePtr;
 use substrate_prometheus_endpoint::Registry;
 
-use crate::{avn_config::*, common::AvnRuntimeApiCollection};
+use crate::{
+    avn_config::*, common::AvnRuntimeApiCollection, protocol_pool::ProtocolTransactionPool,
+};
 use avn_service::{self, web3_utils::Web3Data};
 use sc_transaction_pool_api::OffchainTransactionPoolFactory;
 
@@ -225,7 +230,7 @@ where
                 keystore: Some(params.keystore_container.keystore()),
                 offchain_db: backend.offchain_storage(),
                 transaction_pool: Some(OffchainTransactionPoolFactory::new(
-                    transaction_pool.clone(),
+                    ProtocolTransactionPool::new(transaction_pool.clone()),
                 )),
                 network_provider: network.clone(),
                 is_validator: parachain_config.role.is_authority(),
@@ -240,12 +245,14 @@ where
     let rpc_builder = {
         let client = client.clone();
         let transaction_pool = transaction_pool.clone();
+        let offchain_storage = backend.offchain_storage();
 
         Box::new(move |deny_unsafe, _| {
             let deps = crate::rpc::FullDeps {
                 client: client.clone(),
                 pool: transaction_pool.clone(),
                 deny_unsafe,
+                offchain_storage: offchain_storage.clone(),
             };
 
             crate::rpc::create_full(deps).map_err(Into::into)
@@ -288,7 +295,7 @@ where
         Arc::new(move |hash, data| sync_service.announce_block(hash, data))
     };
 
-    let relay_chain_slot_duration = Duration::from_secs(6);
+    let relay_chain_slot_duration = Duration::from_millis(RELAY_CHAIN_SLOT_DURATION_MILLIS as u64);
 
     let overseer_handle = relay_chain_interface
         .overseer_handle()
@@ -337,7 +344,7 @@ where
                 client: client.clone(),
                 _block: Default::default(),
                 offchain_transaction_pool_factory: OffchainTransactionPoolFactory::new(
-                    transaction_pool.clone(),
+                    ProtocolTransactionPool::new(transaction_pool.clone()),
                 ),
             };
 
@@ -429,8 +436,8 @@ where
     RuntimeApi: ConstructRuntimeApi<Block, ParachainClient<RuntimeApi>> + Send + Sync + 'static,
     RuntimeApi::RuntimeApi: AvnRuntimeApiCollection,
 {
-    use cumulus_client_consensus_aura::collators::basic::{
-        self as basic_aura, Params as BasicAuraParams,
+    use cumulus_client_consensus_aura::collators::lookahead::{
+        self as lookahead_aura, Params as LookaheadAuraParams,
     };
 
     // NOTE: because we use Aura here explicitly, we can use `CollatorSybilResistance::Resistant`
@@ -455,7 +462,7 @@ where
         client.clone(),
     );
 
-    let params = BasicAuraParams {
+    let params = LookaheadAuraParams {
         create_inherent_data_providers: move |_, ()| async move { Ok(()) },
         block_import,
         para_client: client,
@@ -469,14 +476,22 @@ where
         relay_chain_slot_duration,
         proposer,
         collator_service,
-        // Very limited proposal time.
-        authoring_duration: Duration::from_millis(500),
+        // Blocks are built ahead of their inclusion in the relay chain, which leaves time for the
+        // runtime's 2 seconds of compute.
+        authoring_duration: Duration::from_millis(1500),
     };
 
-    let fut =
-        basic_aura::run::<Block, sp_consensus_aura::sr25519::AuthorityPair, _, _, _, _, _, _, _>(
-            params,
-        );
+    let fut = lookahead_aura::run::<
+        Block,
+        sp_consensus_aura::sr25519::AuthorityPair,
+        _,
+        _,
+        _,
+        _,
+        _,
+        _,
+        _,
+    >(params);
     task_manager.spawn_essential_handle().spawn("aura", None, fut);
 
     Ok(())
@@ -0,0 +1,98 @@
+//! Weights for pallet_assets_bridge
+//!
+//! The weight of the XCM transfer itself is provided by the runtime via `ReserveTransfer::weight`.
+//! The weights below only cover the bookkeeping done by this pallet.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+
+/// Weight functions needed for pallet_assets_bridge.
+pub trait WeightInfo {
+	fn limited_reserve_transfer() -> Weight;
+	fn register_foreign_asset() -> Weight;
+	fn deregister_foreign_asset() -> Weight;
+}
+
+/// Weights for pallet_assets_bridge using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	fn limited_reserve_transfer() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 9_000_000 picoseconds.
+		Weight::from_parts(10_000_000, 0)
+	}
+	/// Storage: `Assets::Asset` (r:1 w:0)
+	/// Proof: `Assets::Asset` (`max_values`: None, `max_size`: Some(210), added: 2685, mode: `MaxEncodedLen`)
+	/// Storage: `AssetsBridge::ForeignAssetIds` (r:1 w:1)
+	/// Proof: `AssetsBridge::ForeignAssetIds` (`max_values`: None, `max_size`: Some(620), added: 3095, mode: `MaxEncodedLen`)
+	/// Storage: `AssetsBridge::ForeignAssetLocations` (r:1 w:1)
+	/// Proof: `AssetsBridge::ForeignAssetLocations` (`max_values`: None, `max_size`: Some(620), added: 3095, mode: `MaxEncodedLen`)
+	fn register_foreign_asset() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `276`
+		//  Estimated: `4085`
+		// Minimum execution time: 21_310_000 picoseconds.
+		Weight::from_parts(22_046_000, 4085)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `AssetsBridge::ForeignAssetLocations` (r:1 w:1)
+	/// Proof: `AssetsBridge::ForeignAssetLocations` (`max_values`: None, `max_size`: Some(620), added: 3095, mode: `MaxEncodedLen`)
+	/// Storage: `AssetsBridge::ForeignAssetIds` (r:0 w:1)
+	/// Proof: `AssetsBridge::ForeignAssetIds` (`max_values`: None, `max_size`: Some(620), added: 3095, mode: `MaxEncodedLen`)
+	fn deregister_foreign_asset() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `167`
+		//  Estimated: `4085`
+		// Minimum execution time: 15_122_000 picoseconds.
+		Weight::from_parts(15_780_000, 4085)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+}
+
+// For backwards compatibility and tests.
+impl WeightInfo for () {
+	fn limited_reserve_transfer() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 9_000_000 picoseconds.
+		Weight::from_parts(10_000_000, 0)
+	}
+	/// Storage: `Assets::Asset` (r:1 w:0)
+	/// Proof: `Assets::Asset` (`max_values`: None, `max_size`: Some(210), added: 2685, mode: `MaxEncodedLen`)
+	/// Storage: `AssetsBridge::ForeignAssetIds` (r:1 w:1)
+	/// Proof: `AssetsBridge::ForeignAssetIds` (`max_values`: None, `max_size`: Some(620), added: 3095, mode: `MaxEncodedLen`)
+	/// Storage: `AssetsBridge::ForeignAssetLocations` (r:1 w:1)
+	/// Proof: `AssetsBridge::ForeignAssetLocations` (`max_values`: None, `max_size`: Some(620), added: 3095, mode: `MaxEncodedLen`)
+	fn register_foreign_asset() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `276`
+		//  Estimated: `4085`
+		// Minimum execution time: 21_310_000 picoseconds.
+		Weight::from_parts(22_046_000, 4085)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `AssetsBridge::ForeignAssetLocations` (r:1 w:1)
+	/// Proof: `AssetsBridge::ForeignAssetLocations` (`max_values`: None, `max_size`: Some(620), added: 3095, mode: `MaxEncodedLen`)
+	/// Storage: `AssetsBridge::ForeignAssetIds` (r:0 w:1)
+	/// Proof: `AssetsBridge::ForeignAssetIds` (`max_values`: None, `max_size`: Some(620), added: 3095, mode: `MaxEncodedLen`)
+	fn deregister_foreign_asset() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `167`
+		//  Estimated: `4085`
+		// Minimum execution time: 15_122_000 picoseconds.
+		Weight::from_parts(15_780_000, 4085)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+}
\ No newline at end of file
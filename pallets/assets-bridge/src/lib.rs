@@ -0,0 +1,256 @@
+//! # Assets bridge
+// Copyright 2024 Aventus Network Services (UK) Ltd.
+
+//! This pallet allows AVT to be reserve-transferred from AvN to Asset Hub and to sibling
+//! parachains. AvN is the reserve for AVT: transferred tokens are held in the sovereign account of
+//! the destination chain and a derivative is minted there. Tokens returning to AvN are withdrawn
+//! from the sovereign account by the XCM executor, as configured in the runtime.
+//!
+//! It also keeps a registry of the foreign assets (eg USDC from Asset Hub) that AvN accepts over
+//! XCM, mapping their location to a local asset id.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use frame_support::{
+    dispatch::DispatchResult,
+    traits::{fungibles, ContainsPair, Currency},
+    weights::Weight,
+};
+use sp_runtime::traits::{MaybeEquivalence, UniqueSaturatedInto};
+use sp_std::{boxed::Box, marker::PhantomData};
+use xcm::{latest::prelude::*, VersionedMultiLocation};
+
+pub use pallet::*;
+
+pub mod default_weights;
+pub use default_weights::WeightInfo;
+
+/// Executes a reserve based transfer of a local asset to another consensus system.
+pub trait ReserveTransfer<AccountId> {
+    fn limited_reserve_transfer(
+        sender: AccountId,
+        dest: MultiLocation,
+        beneficiary: MultiLocation,
+        asset: MultiAsset,
+        weight_limit: WeightLimit,
+    ) -> DispatchResult;
+
+    /// The weight of sending a reserve transfer
+    fn weight() -> Weight;
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+    use super::*;
+    use frame_support::pallet_prelude::*;
+    use frame_system::pallet_prelude::*;
+
+    #[pallet::config]
+    pub trait Config: frame_system::Config {
+        /// The overarching event type.
+        type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+        /// Currency used to represent AVT
+        type Currency: Currency<Self::AccountId>;
+
+        /// The means of sending AVT to another parachain using XCM
+        type XcmTransfer: ReserveTransfer<Self::AccountId>;
+
+        /// The minimum amount of AVT that can be transferred to another parachain
+        #[pallet::constant]
+        type MinimumTransferAmount: Get<BalanceOf<Self>>;
+
+        /// The local id of a foreign asset
+        type AssetId: Member + Parameter + Copy + MaxEncodedLen;
+
+        /// The pallet holding foreign assets on this chain
+        type Assets: fungibles::Inspect<Self::AccountId, AssetId = Self::AssetId>;
+
+        type WeightInfo: WeightInfo;
+    }
+
+    #[pallet::pallet]
+    pub struct Pallet<T>(_);
+
+    #[pallet::event]
+    #[pallet::generate_deposit(pub(super) fn deposit_event)]
+    pub enum Event<T: Config> {
+        AvtTransferredToParachain {
+            sender: T::AccountId,
+            para_id: u32,
+            recipient: [u8; 32],
+            amount: BalanceOf<T>,
+        },
+        ForeignAssetRegistered {
+            asset_id: T::AssetId,
+            location: MultiLocation,
+        },
+        ForeignAssetDeregistered {
+            asset_id: T::AssetId,
+            location: MultiLocation,
+        },
+    }
+
+    #[pallet::error]
+    pub enum Error<T> {
+        AmountBelowMinimum,
+        BadLocation,
+        AssetNotFound,
+        ForeignAssetAlreadyRegistered,
+        ForeignAssetNotRegistered,
+    }
+
+    /// The local asset id of each foreign asset accepted over XCM
+    #[pallet::storage]
+    #[pallet::getter(fn foreign_asset_id)]
+    pub type ForeignAssetIds<T: Config> =
+        StorageMap<_, Blake2_128Concat, MultiLocation, T::AssetId, OptionQuery>;
+
+    /// The location of each registered foreign asset
+    #[pallet::storage]
+    #[pallet::getter(fn foreign_asset_location)]
+    pub type ForeignAssetLocations<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AssetId, MultiLocation, OptionQuery>;
+
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {
+        /// Reserve-transfer `amount` AVT to `recipient` on the parachain `para_id`. The sender
+        /// pays the XCM execution fees on the destination out of the transferred amount, limited
+        /// by `weight_limit`.
+        #[pallet::call_index(0)]
+        #[pallet::weight(
+            <T as Config>::WeightInfo::limited_reserve_transfer()
+                .saturating_add(T::XcmTransfer::weight())
+        )]
+        pub fn limited_reserve_transfer(
+            origin: OriginFor<T>,
+            para_id: u32,
+            recipient: [u8; 32],
+            amount: BalanceOf<T>,
+            weight_limit: WeightLimit,
+        ) -> DispatchResult {
+            let sender = ensure_signed(origin)?;
+            ensure!(amount >= T::MinimumTransferAmount::get(), Error::<T>::AmountBelowMinimum);
+
+            T::XcmTransfer::limited_reserve_transfer(
+                sender.clone(),
+                Self::sibling_location(para_id),
+                Self::beneficiary_location(recipient),
+                Self::avt_asset(amount),
+                weight_limit,
+            )?;
+
+            Self::deposit_event(Event::<T>::AvtTransferredToParachain {
+                sender,
+                para_id,
+                recipient,
+                amount,
+            });
+
+            Ok(())
+        }
+
+        /// Accept the foreign asset at `location` over XCM and hold it as the local asset
+        /// `asset_id`. The local asset must already exist.
+        #[pallet::call_index(1)]
+        #[pallet::weight(<T as Config>::WeightInfo::register_foreign_asset())]
+        pub fn register_foreign_asset(
+            origin: OriginFor<T>,
+            asset_id: T::AssetId,
+            location: Box<VersionedMultiLocation>,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+            let location: MultiLocation =
+                (*location).try_into().map_err(|_| Error::<T>::BadLocation)?;
+
+            ensure!(
+                <T::Assets as fungibles::Inspect<T::AccountId>>::asset_exists(asset_id),
+                Error::<T>::AssetNotFound
+            );
+            ensure!(
+                !ForeignAssetIds::<T>::contains_key(&location) &&
+                    !ForeignAssetLocations::<T>::contains_key(asset_id),
+                Error::<T>::ForeignAssetAlreadyRegistered
+            );
+
+            ForeignAssetIds::<T>::insert(&location, asset_id);
+            ForeignAssetLocations::<T>::insert(asset_id, &location);
+
+            Self::deposit_event(Event::<T>::ForeignAssetRegistered { asset_id, location });
+
+            Ok(())
+        }
+
+        /// Stop accepting the foreign asset mapped to `asset_id` over XCM
+        #[pallet::call_index(2)]
+        #[pallet::weight(<T as Config>::WeightInfo::deregister_foreign_asset())]
+        pub fn deregister_foreign_asset(
+            origin: OriginFor<T>,
+            asset_id: T::AssetId,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+            let location = ForeignAssetLocations::<T>::take(asset_id)
+                .ok_or(Error::<T>::ForeignAssetNotRegistered)?;
+            ForeignAssetIds::<T>::remove(&location);
+
+            Self::deposit_event(Event::<T>::ForeignAssetDeregistered { asset_id, location });
+
+            Ok(())
+        }
+    }
+}
+
+pub type BalanceOf<T> =
+    <<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+
+impl<T: Config> Pallet<T> {
+    /// AVT is the native asset of this chain, so it is identified by `Here`
+    pub fn avt_asset(amount: BalanceOf<T>) -> MultiAsset {
+        let amount: u128 = amount.unique_saturated_into();
+        (MultiLocation::here(), amount).into()
+    }
+
+    pub fn sibling_location(para_id: u32) -> MultiLocation {
+        MultiLocation::new(1, X1(Parachain(para_id)))
+    }
+
+    pub fn beneficiary_location(recipient: [u8; 32]) -> MultiLocation {
+        MultiLocation::new(0, X1(AccountId32 { network: None, id: recipient }))
+    }
+}
+
+/// Converts between the location of a registered foreign asset and its local asset id
+pub struct ForeignAssetIdConverter<T>(PhantomData<T>);
+impl<T: Config> MaybeEquivalence<MultiLocation, T::AssetId> for ForeignAssetIdConverter<T> {
+    fn convert(location: &MultiLocation) -> Option<T::AssetId> {
+        ForeignAssetIds::<T>::get(location)
+    }
+
+    fn convert_back(asset_id: &T::AssetId) -> Option<MultiLocation> {
+        ForeignAssetLocations::<T>::get(asset_id)
+    }
+}
+
+/// Accepts a registered foreign asset as a reserve asset if it comes from the chain that hosts it
+pub struct RegisteredForeignReserve<T>(PhantomData<T>);
+impl<T: Config> ContainsPair<MultiAsset, MultiLocation> for RegisteredForeignReserve<T> {
+    fn contains(asset: &MultiAsset, origin: &MultiLocation) -> bool {
+        match asset.id {
+            Concrete(ref location) =>
+                location.starts_with(origin) && ForeignAssetIds::<T>::contains_key(location),
+            _ => false,
+        }
+    }
+}
+
+#[cfg(test)]
+#[path = "tests/mock.rs"]
+mod mock;
+
+#[cfg(test)]
+#[path = "tests/limited_reserve_transfer_tests.rs"]
+mod limited_reserve_transfer_tests;
+
+#[cfg(test)]
+#[path = "tests/foreign_assets_tests.rs"]
+mod foreign_assets_tests;
@@ -0,0 +1,143 @@
+// Copyright 2024 Aventus Network Services (UK) Ltd.
+
+use crate::{mock::*, *};
+use frame_support::{assert_noop, assert_ok};
+use sp_runtime::traits::BadOrigin;
+
+fn register_usdc() -> DispatchResult {
+    AssetsBridge::register_foreign_asset(
+        RuntimeOrigin::root(),
+        USDC_ASSET_ID,
+        Box::new(usdc_location().into()),
+    )
+}
+
+mod register_foreign_asset {
+    use super::*;
+
+    #[test]
+    fn succeeds_when_the_asset_exists() {
+        new_test_ext().execute_with(|| {
+            create_usdc_asset();
+            assert_ok!(register_usdc());
+
+            assert_eq!(AssetsBridge::foreign_asset_id(usdc_location()), Some(USDC_ASSET_ID));
+            assert_eq!(AssetsBridge::foreign_asset_location(USDC_ASSET_ID), Some(usdc_location()));
+            assert!(event_emitted(&RuntimeEvent::AssetsBridge(
+                crate::Event::<TestRuntime>::ForeignAssetRegistered {
+                    asset_id: USDC_ASSET_ID,
+                    location: usdc_location(),
+                }
+            )));
+        });
+    }
+
+    #[test]
+    fn fails_when_origin_is_not_root() {
+        new_test_ext().execute_with(|| {
+            create_usdc_asset();
+            assert_noop!(
+                AssetsBridge::register_foreign_asset(
+                    RuntimeOrigin::signed(SENDER),
+                    USDC_ASSET_ID,
+                    Box::new(usdc_location().into()),
+                ),
+                BadOrigin
+            );
+        });
+    }
+
+    #[test]
+    fn fails_when_the_asset_does_not_exist() {
+        new_test_ext().execute_with(|| {
+            assert_noop!(register_usdc(), Error::<TestRuntime>::AssetNotFound);
+        });
+    }
+
+    #[test]
+    fn fails_when_the_asset_is_already_registered() {
+        new_test_ext().execute_with(|| {
+            create_usdc_asset();
+            assert_ok!(register_usdc());
+            assert_noop!(register_usdc(), Error::<TestRuntime>::ForeignAssetAlreadyRegistered);
+        });
+    }
+}
+
+mod deregister_foreign_asset {
+    use super::*;
+
+    #[test]
+    fn succeeds_when_the_asset_is_registered() {
+        new_test_ext().execute_with(|| {
+            create_usdc_asset();
+            assert_ok!(register_usdc());
+
+            assert_ok!(AssetsBridge::deregister_foreign_asset(
+                RuntimeOrigin::root(),
+                USDC_ASSET_ID
+            ));
+
+            assert_eq!(AssetsBridge::foreign_asset_id(usdc_location()), None);
+            assert_eq!(AssetsBridge::foreign_asset_location(USDC_ASSET_ID), None);
+            assert!(event_emitted(&RuntimeEvent::AssetsBridge(
+                crate::Event::<TestRuntime>::ForeignAssetDeregistered {
+                    asset_id: USDC_ASSET_ID,
+                    location: usdc_location(),
+                }
+            )));
+        });
+    }
+
+    #[test]
+    fn fails_when_the_asset_is_not_registered() {
+        new_test_ext().execute_with(|| {
+            assert_noop!(
+                AssetsBridge::deregister_foreign_asset(RuntimeOrigin::root(), USDC_ASSET_ID),
+                Error::<TestRuntime>::ForeignAssetNotRegistered
+            );
+        });
+    }
+}
+
+mod xcm_adapters {
+    use super::*;
+
+    #[test]
+    fn convert_registered_locations_to_asset_ids() {
+        new_test_ext().execute_with(|| {
+            create_usdc_asset();
+            assert_ok!(register_usdc());
+
+            assert_eq!(
+                ForeignAssetIdConverter::<TestRuntime>::convert(&usdc_location()),
+                Some(USDC_ASSET_ID)
+            );
+            assert_eq!(
+                ForeignAssetIdConverter::<TestRuntime>::convert_back(&USDC_ASSET_ID),
+                Some(usdc_location())
+            );
+            assert_eq!(
+                ForeignAssetIdConverter::<TestRuntime>::convert(&MultiLocation::parent()),
+                None
+            );
+        });
+    }
+
+    #[test]
+    fn accept_registered_assets_only_from_their_reserve() {
+        new_test_ext().execute_with(|| {
+            let usdc: MultiAsset = (usdc_location(), 100u128).into();
+            let asset_hub = MultiLocation::new(1, X1(Parachain(ASSET_HUB_PARA_ID)));
+            let other_chain = MultiLocation::new(1, X1(Parachain(2000)));
+
+            assert!(!RegisteredForeignReserve::<TestRuntime>::contains(&usdc, &asset_hub));
+
+            create_usdc_asset();
+            assert_ok!(register_usdc());
+
+            assert!(RegisteredForeignReserve::<TestRuntime>::contains(&usdc, &asset_hub));
+            assert!(!RegisteredForeignReserve::<TestRuntime>::contains(&usdc, &other_chain));
+        });
+    }
+}
@@ -0,0 +1,103 @@
+// Copyright 2024 Aventus Network Services (UK) Ltd.
+
+use crate::{mock::*, *};
+use frame_support::{assert_noop, assert_ok};
+use sp_runtime::traits::BadOrigin;
+
+fn transfer(amount: u128) -> DispatchResult {
+    AssetsBridge::limited_reserve_transfer(
+        RuntimeOrigin::signed(SENDER),
+        ASSET_HUB_PARA_ID,
+        RECIPIENT,
+        amount,
+        WeightLimit::Unlimited,
+    )
+}
+
+mod limited_reserve_transfer {
+    use super::*;
+
+    mod succeeds_when {
+        use super::*;
+
+        #[test]
+        fn amount_is_above_minimum() {
+            new_test_ext().execute_with(|| {
+                let amount = MINIMUM_TRANSFER_AMOUNT + 1;
+                assert_ok!(transfer(amount));
+
+                assert_eq!(
+                    sent_transfers(),
+                    vec![SentTransfer {
+                        sender: SENDER,
+                        dest: MultiLocation::new(1, X1(Parachain(ASSET_HUB_PARA_ID))),
+                        beneficiary: MultiLocation::new(
+                            0,
+                            X1(AccountId32 { network: None, id: RECIPIENT })
+                        ),
+                        asset: (MultiLocation::here(), amount).into(),
+                        weight_limit: WeightLimit::Unlimited,
+                    }]
+                );
+
+                assert!(event_emitted(&RuntimeEvent::AssetsBridge(
+                    crate::Event::<TestRuntime>::AvtTransferredToParachain {
+                        sender: SENDER,
+                        para_id: ASSET_HUB_PARA_ID,
+                        recipient: RECIPIENT,
+                        amount,
+                    }
+                )));
+            });
+        }
+
+        #[test]
+        fn amount_is_exactly_the_minimum() {
+            new_test_ext().execute_with(|| {
+                assert_ok!(transfer(MINIMUM_TRANSFER_AMOUNT));
+                assert_eq!(sent_transfers().len(), 1);
+            });
+        }
+    }
+
+    mod fails_when {
+        use super::*;
+
+        #[test]
+        fn origin_is_unsigned() {
+            new_test_ext().execute_with(|| {
+                assert_noop!(
+                    AssetsBridge::limited_reserve_transfer(
+                        RuntimeOrigin::none(),
+                        ASSET_HUB_PARA_ID,
+                        RECIPIENT,
+                        MINIMUM_TRANSFER_AMOUNT,
+                        WeightLimit::Unlimited,
+                    ),
+                    BadOrigin
+                );
+            });
+        }
+
+        #[test]
+        fn amount_is_below_minimum() {
+            new_test_ext().execute_with(|| {
+                assert_noop!(
+                    transfer(MINIMUM_TRANSFER_AMOUNT - 1),
+                    Error::<TestRuntime>::AmountBelowMinimum
+                );
+                assert!(sent_transfers().is_empty());
+            });
+        }
+
+        #[test]
+        fn xcm_transfer_fails() {
+            new_test_ext().execute_with(|| {
+                set_transfer_fails(true);
+
+                assert_noop!(transfer(MINIMUM_TRANSFER_AMOUNT), TRANSFER_ERROR);
+                assert!(sent_transfers().is_empty());
+            });
+        }
+    }
+}
@@ -0,0 +1,186 @@
+use crate::{self as pallet_assets_bridge, ReserveTransfer};
+use frame_support::{
+    assert_ok,
+    dispatch::DispatchResult,
+    parameter_types,
+    traits::{AsEnsureOriginWithArg, ConstU128, ConstU16, ConstU32, ConstU64},
+    weights::Weight,
+};
+use frame_system::{EnsureRoot, EnsureSigned};
+use sp_core::H256;
+use sp_runtime::{
+    traits::{BlakeTwo256, IdentityLookup},
+    BuildStorage, DispatchError,
+};
+use std::cell::RefCell;
+use xcm::latest::prelude::*;
+
+pub type AccountId = u64;
+
+type Block = frame_system::mocking::MockBlock<TestRuntime>;
+
+pub const SENDER: AccountId = 1;
+pub const RECIPIENT: [u8; 32] = [7u8; 32];
+pub const ASSET_HUB_PARA_ID: u32 = 1000;
+pub const MINIMUM_TRANSFER_AMOUNT: u128 = 10;
+pub const USDC_ASSET_ID: u32 = 1337;
+
+frame_support::construct_runtime!(
+    pub enum TestRuntime
+    {
+        System: frame_system::{Pallet, Call, Config<T>, Storage, Event<T>},
+        Balances: pallet_balances::{Pallet, Call, Storage, Event<T>},
+        Assets: pallet_assets::{Pallet, Call, Storage, Event<T>},
+        AssetsBridge: pallet_assets_bridge::{Pallet, Call, Event<T>},
+    }
+);
+
+impl frame_system::Config for TestRuntime {
+    type BaseCallFilter = frame_support::traits::Everything;
+    type BlockWeights = ();
+    type BlockLength = ();
+    type DbWeight = ();
+    type RuntimeOrigin = RuntimeOrigin;
+    type RuntimeCall = RuntimeCall;
+    type Nonce = u64;
+    type Hash = H256;
+    type Hashing = BlakeTwo256;
+    type AccountId = AccountId;
+    type Lookup = IdentityLookup<Self::AccountId>;
+    type Block = Block;
+    type RuntimeEvent = RuntimeEvent;
+    type BlockHashCount = ConstU64<250>;
+    type Version = ();
+    type PalletInfo = PalletInfo;
+    type AccountData = pallet_balances::AccountData<u128>;
+    type OnNewAccount = ();
+    type OnKilledAccount = ();
+    type SystemWeightInfo = ();
+    type SS58Prefix = ConstU16<42>;
+    type OnSetCode = ();
+    type MaxConsumers = ConstU32<16>;
+}
+
+impl pallet_balances::Config for TestRuntime {
+    type MaxLocks = ConstU32<1024>;
+    type MaxReserves = ();
+    type ReserveIdentifier = [u8; 8];
+    type Balance = u128;
+    type RuntimeEvent = RuntimeEvent;
+    type DustRemoval = ();
+    type ExistentialDeposit = ConstU128<0>;
+    type AccountStore = System;
+    type WeightInfo = ();
+    type FreezeIdentifier = ();
+    type MaxFreezes = ();
+    type MaxHolds = ();
+    type RuntimeHoldReason = ();
+}
+
+impl pallet_assets::Config for TestRuntime {
+    type RuntimeEvent = RuntimeEvent;
+    type Balance = u128;
+    type RemoveItemsLimit = ConstU32<5>;
+    type AssetId = u32;
+    type AssetIdParameter = u32;
+    type Currency = Balances;
+    type CreateOrigin = AsEnsureOriginWithArg<EnsureSigned<AccountId>>;
+    type ForceOrigin = EnsureRoot<AccountId>;
+    type AssetDeposit = ConstU128<0>;
+    type AssetAccountDeposit = ConstU128<0>;
+    type MetadataDepositBase = ConstU128<0>;
+    type MetadataDepositPerByte = ConstU128<0>;
+    type ApprovalDeposit = ConstU128<0>;
+    type StringLimit = ConstU32<50>;
+    type Freezer = ();
+    type Extra = ();
+    type CallbackHandle = ();
+    type WeightInfo = ();
+    #[cfg(feature = "runtime-benchmarks")]
+    type BenchmarkHelper = ();
+}
+
+parameter_types! {
+    pub const MinimumTransferAmount: u128 = MINIMUM_TRANSFER_AMOUNT;
+}
+
+impl pallet_assets_bridge::Config for TestRuntime {
+    type RuntimeEvent = RuntimeEvent;
+    type Currency = Balances;
+    type XcmTransfer = TestXcmTransfer;
+    type MinimumTransferAmount = MinimumTransferAmount;
+    type AssetId = u32;
+    type Assets = Assets;
+    type WeightInfo = ();
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct SentTransfer {
+    pub sender: AccountId,
+    pub dest: MultiLocation,
+    pub beneficiary: MultiLocation,
+    pub asset: MultiAsset,
+    pub weight_limit: WeightLimit,
+}
+
+thread_local! {
+    pub static SENT_TRANSFERS: RefCell<Vec<SentTransfer>> = RefCell::new(vec![]);
+    pub static TRANSFER_FAILS: RefCell<bool> = RefCell::new(false);
+}
+
+pub const TRANSFER_ERROR: DispatchError = DispatchError::Other("Xcm transfer failed");
+
+pub struct TestXcmTransfer;
+impl ReserveTransfer<AccountId> for TestXcmTransfer {
+    fn limited_reserve_transfer(
+        sender: AccountId,
+        dest: MultiLocation,
+        beneficiary: MultiLocation,
+        asset: MultiAsset,
+        weight_limit: WeightLimit,
+    ) -> DispatchResult {
+        if TRANSFER_FAILS.with(|f| *f.borrow()) {
+            return Err(TRANSFER_ERROR)
+        }
+
+        SENT_TRANSFERS.with(|t| {
+            t.borrow_mut()
+                .push(SentTransfer { sender, dest, beneficiary, asset, weight_limit })
+        });
+        Ok(())
+    }
+
+    fn weight() -> Weight {
+        Weight::zero()
+    }
+}
+
+pub fn set_transfer_fails(fails: bool) {
+    TRANSFER_FAILS.with(|f| *f.borrow_mut() = fails);
+}
+
+pub fn sent_transfers() -> Vec<SentTransfer> {
+    SENT_TRANSFERS.with(|t| t.borrow().clone())
+}
+
+pub fn usdc_location() -> MultiLocation {
+    MultiLocation::new(
+        1,
+        X3(Parachain(ASSET_HUB_PARA_ID), PalletInstance(50), GeneralIndex(USDC_ASSET_ID.into())),
+    )
+}
+
+pub fn create_usdc_asset() {
+    assert_ok!(Assets::force_create(RuntimeOrigin::root(), USDC_ASSET_ID, SENDER, true, 1));
+}
+
+pub fn new_test_ext() -> sp_io::TestExternalities {
+    let t = frame_system::GenesisConfig::<TestRuntime>::default().build_storage().unwrap();
+    let mut ext = sp_io::TestExternalities::new(t);
+    ext.execute_with(|| System::set_block_number(1));
+    ext
+}
+
+pub fn event_emitted(event: &RuntimeEvent) -> bool {
+    return System::events().iter().any(|a| a.event == *event)
+}
@@ -28,6 +28,8 @@ pub type MaximumHandlersBound = ConstU32<256>;
 
 pub type ChainNameLimit = ConstU32<32>;
 
+/// Prefix of the payloads signed for this pallet, ahead of the per call context
+pub const SIGNING_DOMAIN: &'static [u8] = b"avn_anchor";
 pub const REGISTER_CHAIN_HANDLER: &'static [u8] = b"register_chain_handler";
 pub const UPDATE_CHAIN_HANDLER: &'static [u8] = b"update_chain_handler";
 pub const SUBMIT_CHECKPOINT: &'static [u8] = b"submit_checkpoint";
@@ -44,7 +46,9 @@ pub mod pallet {
     use super::*;
     use frame_support::{dispatch::GetDispatchInfo, pallet_prelude::*, traits::IsSubType};
     use frame_system::pallet_prelude::*;
-    use sp_avn_common::{verify_signature, FeePaymentHandler, InnerCallValidator, Proof};
+    use sp_avn_common::{
+        verify_domain_separated_signature, FeePaymentHandler, InnerCallValidator, Proof,
+    };
     use sp_core::H160;
     use sp_runtime::traits::{Dispatchable, IdentifyAccount, Verify};
 
@@ -247,7 +251,7 @@ pub mod pallet {
                 encode_signed_register_chain_handler_params::<T>(&proof.relayer, &handler, &name);
 
             ensure!(
-                verify_signature::<T::Signature, T::AccountId>(&proof, &signed_payload).is_ok(),
+                Self::verify_signed_payload(&proof, &signed_payload).is_ok(),
                 Error::<T>::UnauthorizedSignedTransaction
             );
 
@@ -279,8 +283,7 @@ pub mod pallet {
             );
 
             ensure!(
-                verify_signature::<T::Signature, T::AccountId>(&proof, &signed_payload.as_slice())
-                    .is_ok(),
+                Self::verify_signed_payload(&proof, &signed_payload).is_ok(),
                 Error::<T>::UnauthorizedSignedTransaction
             );
 
@@ -315,8 +318,7 @@ pub mod pallet {
             );
 
             ensure!(
-                verify_signature::<T::Signature, T::AccountId>(&proof, &signed_payload.as_slice())
-                    .is_ok(),
+                Self::verify_signed_payload(&proof, &signed_payload).is_ok(),
                 Error::<T>::UnauthorizedSignedTransaction
             );
 
@@ -342,6 +344,19 @@ pub mod pallet {
     }
 
     impl<T: Config> Pallet<T> {
+        /// Verifies `proof` over a payload built by one of the `encode_signed_*` functions
+        fn verify_signed_payload(
+            proof: &Proof<T::Signature, T::AccountId>,
+            signed_payload: &[u8],
+        ) -> Result<(), ()> {
+            verify_domain_separated_signature::<T::Signature, T::AccountId>(
+                proof,
+                SIGNING_DOMAIN,
+                signed_payload,
+                pallet_avn::Pallet::<T>::legacy_signed_payloads_accepted(),
+            )
+        }
+
         pub(crate) fn charge_fee(handler: T::AccountId, chain_id: ChainId) -> DispatchResult {
             let checkpoint_fee = Self::checkpoint_fee(chain_id);
 
@@ -537,11 +552,7 @@ pub mod pallet {
 
         fn signature_is_valid(call: &Box<Self::Call>) -> bool {
             if let Some((proof, signed_payload)) = Self::get_encoded_call_param(call) {
-                return verify_signature::<T::Signature, T::AccountId>(
-                    &proof,
-                    &signed_payload.as_slice(),
-                )
-                .is_ok()
+                return Self::verify_signed_payload(&proof, &signed_payload).is_ok()
             }
 
             return false
@@ -554,7 +565,7 @@ pub fn encode_signed_register_chain_handler_params<T: Config>(
     handler: &T::AccountId,
     name: &BoundedVec<u8, ChainNameLimit>,
 ) -> Vec<u8> {
-    (REGISTER_CHAIN_HANDLER, relayer, handler, name).encode()
+    (SIGNING_DOMAIN, REGISTER_CHAIN_HANDLER, relayer, handler, name).encode()
 }
 
 pub fn encode_signed_update_chain_handler_params<T: Config>(
@@ -564,7 +575,16 @@ pub fn encode_signed_update_chain_handler_params<T: Config>(
     chain_id: ChainId,
     nonce: u64,
 ) -> Vec<u8> {
-    (UPDATE_CHAIN_HANDLER, relayer.clone(), old_handler, new_handler, chain_id, nonce).encode()
+    (
+        SIGNING_DOMAIN,
+        UPDATE_CHAIN_HANDLER,
+        relayer.clone(),
+        old_handler,
+        new_handler,
+        chain_id,
+        nonce,
+    )
+        .encode()
 }
 
 pub fn encode_signed_submit_checkpoint_params<T: Config>(
@@ -574,7 +594,8 @@ pub fn encode_signed_submit_checkpoint_params<T: Config>(
     chain_id: ChainId,
     nonce: u64,
 ) -> Vec<u8> {
-    (SUBMIT_CHECKPOINT, relayer.clone(), handler, checkpoint, chain_id, nonce).encode()
+    (SIGNING_DOMAIN, SUBMIT_CHECKPOINT, relayer.clone(), handler, checkpoint, chain_id, nonce)
+        .encode()
 }
 
 pub fn get_chain_data_for_handler<T: Config>(handler: &T::AccountId) -> Option<ChainDataStruct> {
@@ -4,7 +4,9 @@ use core::cell::RefCell;
 use frame_support::{
     pallet_prelude::*,
     parameter_types,
-    traits::{ConstU16, ConstU32, ConstU64, EqualPrivilegeOnly, Everything},
+    traits::{
+        ConstU128, ConstU16, ConstU32, ConstU64, EqualPrivilegeOnly, Everything, WithdrawReasons,
+    },
     PalletId,
 };
 use frame_system::{self as system, limits::BlockWeights, EnsureRoot};
@@ -86,10 +88,11 @@ frame_support::construct_runtime!(
     {
         System: frame_system::{Pallet, Call, Config<T>, Storage, Event<T>},
         Balances: pallet_balances::{Pallet, Call, Storage, Config<T>, Event<T>},
-        Avn: pallet_avn::{Pallet, Storage, Event},
+        Avn: pallet_avn::{Pallet, Storage, Event<T>},
         AvnProxy: avn_proxy::{Pallet, Call, Storage, Event<T>},
         AvnAnchor: avn_anchor::{Pallet, Call, Storage, Event<T>},
         TokenManager: pallet_token_manager::{Pallet, Call, Storage, Event<T>},
+        Vesting: pallet_vesting::{Pallet, Call, Storage, Event<T>},
         EthBridge: pallet_eth_bridge::{Pallet, Call, Storage, Event<T>},
         Session: pallet_session::{Pallet, Call, Storage, Event, Config<T>},
         Scheduler: pallet_scheduler::{Pallet, Call, Storage, Event<T>},
@@ -191,6 +194,23 @@ impl pallet_token_manager::Config for TestRuntime {
     type Preimages = ();
     type PalletsOrigin = OriginCaller;
     type BridgeInterface = EthBridge;
+    type Vesting = Vesting;
+    type LiftDestinations = ();
+}
+
+parameter_types! {
+    pub UnvestedFundsAllowedWithdrawReasons: WithdrawReasons =
+        WithdrawReasons::except(WithdrawReasons::TRANSFER | WithdrawReasons::RESERVE);
+}
+
+impl pallet_vesting::Config for TestRuntime {
+    type RuntimeEvent = RuntimeEvent;
+    type Currency = Balances;
+    type BlockNumberToBalance = ConvertInto;
+    type MinVestedTransfer = ConstU128<1>;
+    type WeightInfo = ();
+    type UnvestedFundsAllowedWithdrawReasons = UnvestedFundsAllowedWithdrawReasons;
+    const MAX_VESTING_SCHEDULES: u32 = 3;
 }
 
 impl pallet_balances::Config for TestRuntime {
@@ -228,6 +248,10 @@ impl avn_proxy::Config for TestRuntime {
     type WeightInfo = ();
     type FeeHandler = Self;
     type Token = sp_core::H160;
+    type EthereumAddressMapping = ();
+    type EthereumCallFilter = frame_support::traits::Nothing;
+    type AdminOrigin = frame_system::EnsureRoot<AccountId>;
+    type MaxSessionKeyDuration = frame_support::traits::ConstU64<100>;
 }
 
 impl pallet_eth_bridge::Config for TestRuntime {
@@ -242,6 +266,7 @@ impl pallet_eth_bridge::Config for TestRuntime {
     type ReportCorroborationOffence = ();
     type ProcessedEventsChecker = ();
     type EthereumEventsFilter = ();
+    type AdminOrigin = frame_system::EnsureRoot<Self::AccountId>;
 }
 
 impl pallet_timestamp::Config for TestRuntime {
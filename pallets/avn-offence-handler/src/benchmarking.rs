@@ -8,6 +8,7 @@
 use super::*;
 use frame_benchmarking::{benchmarks, impl_benchmark_test_suite};
 use frame_system::{EventRecord, RawOrigin};
+use sp_runtime::traits::TrailingZeroInput;
 
 benchmarks! {
     configure_slashing {
@@ -19,6 +20,46 @@ benchmarks! {
             Event::<T>::SlashingConfigurationUpdated{ slashing_enabled: enabled }.into()
         );
     }
+
+    cancel_admin_change {
+        let (schedule_name, _) =
+            Pallet::<T>::announce_admin_change(Call::<T>::configure_slashing { enabled: true })?;
+    }: _(RawOrigin::Root, schedule_name)
+    verify {
+        assert!(!<PendingAdminChanges<T>>::contains_key(schedule_name));
+        assert_last_event::<T>(Event::<T>::AdminChangeCancelled { schedule_name }.into());
+    }
+
+    publish_slash_batch {
+        let offender = <T as session::Config>::ValidatorId::decode(
+            &mut TrailingZeroInput::zeroes()
+        ).expect("Infinite input is never exhausted");
+        <SlashBatches<T>>::insert(0, SlashBatch {
+            offenders: vec![offender].try_into().expect("A single offender fits in a batch"),
+            tx_id: None,
+            attempts: 0,
+        });
+        session::CurrentIndex::<T>::put(1);
+    }: {
+        Pallet::<T>::publish_next_slash_batch();
+    }
+    verify {
+        assert!(<SlashBatches<T>>::get(0).expect("Batch is kept until confirmed").tx_id.is_some());
+    }
+
+    enable_offender {
+        let offender = <T as session::Config>::ValidatorId::decode(
+            &mut TrailingZeroInput::zeroes()
+        ).expect("Infinite input is never exhausted");
+        <DisabledOffenders<T>>::insert(
+            &offender,
+            DisabledOffender { session_index: 0, chilled: false },
+        );
+    }: _(RawOrigin::Root, offender.clone())
+    verify {
+        assert!(!<DisabledOffenders<T>>::contains_key(&offender));
+        assert_last_event::<T>(Event::<T>::OffenderEnabled { offender }.into());
+    }
 }
 
 impl_benchmark_test_suite!(
@@ -38,6 +38,9 @@ use core::marker::PhantomData;
 /// Weight functions needed for pallet_avn_offence_handler.
 pub trait WeightInfo {
 	fn configure_slashing() -> Weight;
+	fn cancel_admin_change() -> Weight;
+	fn publish_slash_batch() -> Weight;
+	fn enable_offender() -> Weight;
 }
 
 /// Weights for pallet_avn_offence_handler using the Substrate node and recommended hardware.
@@ -53,6 +56,61 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		Weight::from_parts(8_564_000, 0)
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
+	/// Storage: `AvnOffenceHandler::PendingAdminChanges` (r:1 w:1)
+	/// Proof: `AvnOffenceHandler::PendingAdminChanges` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::Lookup` (r:1 w:1)
+	/// Proof: `Scheduler::Lookup` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::Agenda` (r:1 w:1)
+	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(38963), added: 41438, mode: `MaxEncodedLen`)
+	fn cancel_admin_change() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `4242`
+		//  Estimated: `42428`
+		// Minimum execution time: 31_250_000 picoseconds.
+		Weight::from_parts(31_250_000, 42428)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: `EthBridge::NextTxId` (r:1 w:1)
+	/// Proof: `EthBridge::NextTxId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `EthBridge::ActiveRequest` (r:1 w:1)
+	/// Proof: `EthBridge::ActiveRequest` (`max_values`: Some(1), `max_size`: Some(20944), added: 21439, mode: `MaxEncodedLen`)
+	/// Storage: `EthBridge::EthTxLifetimeSecs` (r:1 w:0)
+	/// Proof: `EthBridge::EthTxLifetimeSecs` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `AvnOffenceHandler::PublishedSlashBatches` (r:0 w:1)
+	/// Proof: `AvnOffenceHandler::PublishedSlashBatches` (`max_values`: None, `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+	/// Storage: `AvnOffenceHandler::SlashBatches` (r:0 w:1)
+	/// Proof: `AvnOffenceHandler::SlashBatches` (`max_values`: None, `max_size`: Some(8219), added: 10694, mode: `MaxEncodedLen`)
+	fn publish_slash_batch() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2242`
+		//  Estimated: `22429`
+		// Minimum execution time: 38_750_000 picoseconds.
+		Weight::from_parts(38_750_000, 22429)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: `AvnOffenceHandler::DisabledOffenders` (r:1 w:1)
+	/// Proof: `AvnOffenceHandler::DisabledOffenders` (`max_values`: None, `max_size`: Some(53), added: 2528, mode: `MaxEncodedLen`)
+	/// Storage: `Session::Validators` (r:1 w:0)
+	/// Proof: `Session::Validators` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Session::DisabledValidators` (r:1 w:1)
+	/// Proof: `Session::DisabledValidators` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `ParachainStaking::CandidateInfo` (r:1 w:1)
+	/// Proof: `ParachainStaking::CandidateInfo` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `ParachainStaking::CandidatePool` (r:1 w:1)
+	/// Proof: `ParachainStaking::CandidatePool` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	fn enable_offender() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `891`
+		//  Estimated: `8914`
+		// Minimum execution time: 41_230_000 picoseconds.
+		Weight::from_parts(41_230_000, 8914)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
 }
 
 // For backwards compatibility and tests.
@@ -67,4 +125,59 @@ impl WeightInfo for () {
 		Weight::from_parts(8_564_000, 0)
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
+	/// Storage: `AvnOffenceHandler::PendingAdminChanges` (r:1 w:1)
+	/// Proof: `AvnOffenceHandler::PendingAdminChanges` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::Lookup` (r:1 w:1)
+	/// Proof: `Scheduler::Lookup` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::Agenda` (r:1 w:1)
+	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(38963), added: 41438, mode: `MaxEncodedLen`)
+	fn cancel_admin_change() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `4242`
+		//  Estimated: `42428`
+		// Minimum execution time: 31_250_000 picoseconds.
+		Weight::from_parts(31_250_000, 42428)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: `EthBridge::NextTxId` (r:1 w:1)
+	/// Proof: `EthBridge::NextTxId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `EthBridge::ActiveRequest` (r:1 w:1)
+	/// Proof: `EthBridge::ActiveRequest` (`max_values`: Some(1), `max_size`: Some(20944), added: 21439, mode: `MaxEncodedLen`)
+	/// Storage: `EthBridge::EthTxLifetimeSecs` (r:1 w:0)
+	/// Proof: `EthBridge::EthTxLifetimeSecs` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `AvnOffenceHandler::PublishedSlashBatches` (r:0 w:1)
+	/// Proof: `AvnOffenceHandler::PublishedSlashBatches` (`max_values`: None, `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+	/// Storage: `AvnOffenceHandler::SlashBatches` (r:0 w:1)
+	/// Proof: `AvnOffenceHandler::SlashBatches` (`max_values`: None, `max_size`: Some(8219), added: 10694, mode: `MaxEncodedLen`)
+	fn publish_slash_batch() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2242`
+		//  Estimated: `22429`
+		// Minimum execution time: 38_750_000 picoseconds.
+		Weight::from_parts(38_750_000, 22429)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: `AvnOffenceHandler::DisabledOffenders` (r:1 w:1)
+	/// Proof: `AvnOffenceHandler::DisabledOffenders` (`max_values`: None, `max_size`: Some(53), added: 2528, mode: `MaxEncodedLen`)
+	/// Storage: `Session::Validators` (r:1 w:0)
+	/// Proof: `Session::Validators` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Session::DisabledValidators` (r:1 w:1)
+	/// Proof: `Session::DisabledValidators` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `ParachainStaking::CandidateInfo` (r:1 w:1)
+	/// Proof: `ParachainStaking::CandidateInfo` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `ParachainStaking::CandidatePool` (r:1 w:1)
+	/// Proof: `ParachainStaking::CandidatePool` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	fn enable_offender() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `891`
+		//  Estimated: `8914`
+		// Minimum execution time: 41_230_000 picoseconds.
+		Weight::from_parts(41_230_000, 8914)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
 }
\ No newline at end of file
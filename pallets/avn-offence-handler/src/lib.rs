@@ -5,12 +5,30 @@
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
-use frame_support::{dispatch::DispatchResult, traits::Get, weights::Weight};
-use frame_system::ensure_root;
+#[cfg(not(feature = "std"))]
+extern crate alloc;
+
+use codec::{Decode, Encode, MaxEncodedLen};
+use frame_support::{
+    dispatch::DispatchResult,
+    traits::{
+        schedule::{
+            v3::{Named as ScheduleNamed, TaskName},
+            DispatchTime, HARD_DEADLINE,
+        },
+        Get, QueryPreimage, StorePreimage,
+    },
+    weights::Weight,
+};
+use frame_system::{ensure_root, pallet_prelude::BlockNumberFor};
 pub use pallet::*;
-use pallet_avn::{Enforcer, ValidatorRegistrationNotifier};
+use pallet_avn::{
+    BridgeInterface, BridgeInterfaceNotification, DisabledOffenderCheck, Enforcer, OffenderChiller,
+    ValidatorRegistrationNotifier, ValidatorSetCheckpointProvider,
+};
 use pallet_session::{self as session, historical::IdentificationTuple};
-use sp_runtime::Perbill;
+use scale_info::TypeInfo;
+use sp_runtime::{traits::Saturating, DispatchError, Perbill};
 use sp_staking::{
     offence::{DisableStrategy, OffenceDetails, OnOffenceHandler},
     SessionIndex,
@@ -25,9 +43,53 @@ mod tests;
 
 mod benchmarking;
 
+mod slash_batch;
+pub use slash_batch::SlashBatch;
+
 pub mod default_weights;
 pub use default_weights::WeightInfo;
 
+pub const PALLET_ID: &'static [u8; 19] = b"avn_offence_handler";
+
+pub type EthereumTransactionId = u32;
+
+/// When an offender is disabled. This is the `DisableStrategy` of the offence, kept with the
+/// offenders that are deferred.
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+pub enum OffenderDisabling {
+    Never,
+    WhenSlashed,
+    Always,
+}
+
+impl OffenderDisabling {
+    fn applies(&self, slashed: bool) -> bool {
+        match self {
+            OffenderDisabling::Never => false,
+            OffenderDisabling::WhenSlashed => slashed,
+            OffenderDisabling::Always => true,
+        }
+    }
+}
+
+impl From<DisableStrategy> for OffenderDisabling {
+    fn from(strategy: DisableStrategy) -> Self {
+        match strategy {
+            DisableStrategy::Never => OffenderDisabling::Never,
+            DisableStrategy::WhenSlashed => OffenderDisabling::WhenSlashed,
+            DisableStrategy::Always => OffenderDisabling::Always,
+        }
+    }
+}
+
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+pub struct DisabledOffender {
+    /// The session the offender was disabled in
+    pub session_index: SessionIndex,
+    /// Whether the offender was taken out of the staking candidates
+    pub chilled: bool,
+}
+
 #[frame_support::pallet]
 pub mod pallet {
     use super::*;
@@ -45,8 +107,43 @@ pub mod pallet {
         /// A trait responsible for punishing malicious validators
         type Enforcer: Enforcer<<Self as session::Config>::ValidatorId>;
 
+        /// Provides the validator set checkpoint referenced by slash calls
+        type CheckpointProvider: ValidatorSetCheckpointProvider;
+
+        /// The origin allowed to enable or disable slashing
+        type AdminOrigin: EnsureOrigin<<Self as frame_system::Config>::RuntimeOrigin>;
+
         /// Weight information for the extrinsics in this pallet.
         type WeightInfo: WeightInfo;
+
+        /// The overarching call type, used to schedule the changes announced by the admin origin
+        type RuntimeCall: Parameter + From<Call<Self>>;
+        /// The scheduler executing the changes announced by the admin origin
+        type Scheduler: ScheduleNamed<
+            BlockNumberFor<Self>,
+            <Self as Config>::RuntimeCall,
+            Self::PalletsOrigin,
+        >;
+        /// The preimage provider used to store the announced changes
+        type Preimages: QueryPreimage + StorePreimage;
+        /// Overarching type of all pallets origins
+        type PalletsOrigin: From<frame_system::RawOrigin<Self::AccountId>>;
+        /// Number of blocks between the announcement of a slashing toggle and its execution
+        #[pallet::constant]
+        type AdminChangeDelay: Get<BlockNumberFor<Self>>;
+
+        /// Maximum number of offenders processed in a single call. Offenders above it are
+        /// deferred and processed in the following blocks.
+        type MaxOffendersPerBlock: Get<u32>;
+
+        /// The bridge used to slash the offenders on Ethereum
+        type BridgeInterface: BridgeInterface;
+        /// Number of attempts made to publish a batch of slashes before giving up
+        #[pallet::constant]
+        type MaxSlashPublishAttempts: Get<u32>;
+
+        /// Takes the disabled offenders out of the staking candidates
+        type Chiller: OffenderChiller<<Self as session::Config>::ValidatorId>;
     }
 
     #[pallet::pallet]
@@ -61,10 +158,51 @@ pub mod pallet {
         ReportedOffence { offender: T::ValidatorId },
         /// True if slashing is enable, otherwise False
         SlashingConfigurationUpdated { slashing_enabled: bool },
+        /// A validator has been unresponsive for too long and is scheduled for removal.
+        UnresponsiveValidatorRemovalScheduled { offender: T::ValidatorId },
+        /// Enabling or disabling slashing has been announced. It executes at `execute_at` unless
+        /// it is cancelled before.
+        SlashingConfigurationChangeAnnounced {
+            slashing_enabled: bool,
+            execute_at: BlockNumberFor<T>,
+            schedule_name: TaskName,
+        },
+        /// An announced slashing toggle has been cancelled
+        AdminChangeCancelled { schedule_name: TaskName },
+        /// The processing cap has been reached and the offender will be processed in a later block
+        OffenderDeferred { offender: T::ValidatorId },
+        /// The offenders slashed during a session have been sent to Ethereum
+        SlashBatchPublished {
+            session_index: SessionIndex,
+            tx_id: EthereumTransactionId,
+            offenders: Vec<T::ValidatorId>,
+        },
+        /// Ethereum has slashed the offenders of a session
+        T1SlashConfirmed {
+            session_index: SessionIndex,
+            tx_id: EthereumTransactionId,
+            offenders: Vec<T::ValidatorId>,
+        },
+        /// Ethereum failed to slash the offenders of a session. The batch is sent again if
+        /// `will_retry` is true.
+        T1SlashFailed {
+            session_index: SessionIndex,
+            tx_id: EthereumTransactionId,
+            attempts: u32,
+            will_retry: bool,
+        },
+        /// An offender has been disabled in the session, and taken offline in staking if
+        /// `chilled` is true
+        OffenderDisabled { offender: T::ValidatorId, chilled: bool },
+        /// A disabled offender has been enabled again
+        OffenderEnabled { offender: T::ValidatorId },
     }
 
     #[pallet::error]
-    pub enum Error<T> {}
+    pub enum Error<T> {
+        AdminChangeNotFound,
+        OffenderNotDisabled,
+    }
 
     /// A false value means the offence for the validator was not applied successfully.
     #[pallet::storage]
@@ -77,12 +215,62 @@ pub mod pallet {
     #[pallet::getter(fn can_slash)]
     pub type SlashingEnabled<T: Config> = StorageValue<_, bool, ValueQuery>;
 
+    /// The slashing toggles waiting to be executed by the scheduler, with the block they execute at
+    #[pallet::storage]
+    pub type PendingAdminChanges<T: Config> =
+        StorageMap<_, Blake2_128Concat, TaskName, BlockNumberFor<T>, OptionQuery>;
+
+    /// Offenders waiting to be processed because the processing cap was reached when they were
+    /// reported, with when they must be disabled
+    #[pallet::storage]
+    #[pallet::getter(fn deferred_offenders)]
+    pub type DeferredOffenders<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::ValidatorId, OffenderDisabling, OptionQuery>;
+
+    /// The offenders disabled because of an offence, until they are enabled again
+    #[pallet::storage]
+    #[pallet::getter(fn disabled_offender)]
+    pub type DisabledOffenders<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::ValidatorId, DisabledOffender, OptionQuery>;
+
+    /// The offenders slashed in each session that still have to be slashed on Ethereum
+    #[pallet::storage]
+    #[pallet::getter(fn slash_batch)]
+    pub type SlashBatches<T: Config> =
+        StorageMap<_, Twox64Concat, SessionIndex, SlashBatch<T::ValidatorId>, OptionQuery>;
+
+    /// The session of the batch sent in each Ethereum transaction waiting for a result
+    #[pallet::storage]
+    pub type PublishedSlashBatches<T: Config> =
+        StorageMap<_, Twox64Concat, EthereumTransactionId, SessionIndex, OptionQuery>;
+
+    #[pallet::hooks]
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        fn on_initialize(n: BlockNumberFor<T>) -> Weight {
+            Self::process_deferred_offenders()
+                .saturating_add(Self::publish_next_slash_batch())
+                .saturating_add(Self::clear_expired_admin_changes(n))
+        }
+    }
+
     #[pallet::call]
     impl<T: Config> Pallet<T> {
         #[pallet::weight(<T as pallet::Config>::WeightInfo::configure_slashing())]
         #[pallet::call_index(0)]
         pub fn configure_slashing(origin: OriginFor<T>, enabled: bool) -> DispatchResult {
-            let _sender = ensure_root(origin)?;
+            let call = Call::<T>::configure_slashing { enabled };
+            if ensure_root(origin.clone()).is_err() {
+                T::AdminOrigin::ensure_origin(origin)?;
+                let (schedule_name, execute_at) = Self::announce_admin_change(call)?;
+                Self::deposit_event(Event::<T>::SlashingConfigurationChangeAnnounced {
+                    slashing_enabled: enabled,
+                    execute_at,
+                    schedule_name,
+                });
+                return Ok(())
+            }
+
+            Self::clear_pending_admin_changes(&call);
             <SlashingEnabled<T>>::put(enabled);
 
             Self::deposit_event(Event::<T>::SlashingConfigurationUpdated {
@@ -90,12 +278,181 @@ pub mod pallet {
             });
             Ok(())
         }
+
+        /// Cancel a slashing toggle announced by the admin origin, before it is executed
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::cancel_admin_change())]
+        #[pallet::call_index(1)]
+        pub fn cancel_admin_change(
+            origin: OriginFor<T>,
+            schedule_name: TaskName,
+        ) -> DispatchResult {
+            T::AdminOrigin::ensure_origin(origin)?;
+            ensure!(
+                <PendingAdminChanges<T>>::contains_key(schedule_name),
+                Error::<T>::AdminChangeNotFound
+            );
+
+            T::Scheduler::cancel_named(schedule_name)?;
+            <PendingAdminChanges<T>>::remove(schedule_name);
+
+            Self::deposit_event(Event::<T>::AdminChangeCancelled { schedule_name });
+
+            Ok(())
+        }
+
+        /// Enable an offender disabled because of an offence, and bring it back online in staking
+        /// if it was taken offline
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::enable_offender())]
+        #[pallet::call_index(2)]
+        pub fn enable_offender(origin: OriginFor<T>, offender: T::ValidatorId) -> DispatchResult {
+            T::AdminOrigin::ensure_origin(origin)?;
+            let disabled_offender =
+                <DisabledOffenders<T>>::take(&offender).ok_or(Error::<T>::OffenderNotDisabled)?;
+
+            if let Some(index) =
+                <session::Pallet<T>>::validators().iter().position(|v| v == &offender)
+            {
+                session::DisabledValidators::<T>::mutate(|disabled| {
+                    disabled.retain(|disabled_index| *disabled_index != index as u32)
+                });
+            }
+            if disabled_offender.chilled {
+                T::Chiller::unchill(&offender)?;
+            }
+
+            Self::deposit_event(Event::<T>::OffenderEnabled { offender });
+
+            Ok(())
+        }
     }
 }
 
 impl<T: Config> Pallet<T> {
     pub fn setup_for_new_validator(new_validator_id: &<T as session::Config>::ValidatorId) {
         <ReportedOffenders<T>>::remove(new_validator_id);
+        <DeferredOffenders<T>>::remove(new_validator_id);
+        <DisabledOffenders<T>>::remove(new_validator_id);
+    }
+
+    /// Reports and, if slashing is enabled, slashes a single offender. The offender is then
+    /// disabled if `disabling` applies.
+    fn process_offender(
+        offender: &<T as session::Config>::ValidatorId,
+        disabling: OffenderDisabling,
+    ) -> Weight {
+        Self::deposit_event(Event::<T>::ReportedOffence { offender: offender.clone() });
+
+        let mut result: bool = false;
+        if Self::can_slash() {
+            let checkpoint = T::CheckpointProvider::latest_published_checkpoint();
+            result = T::Enforcer::slash_validator(offender, checkpoint).is_ok();
+        }
+
+        <ReportedOffenders<T>>::insert(offender.clone(), result);
+
+        // [Read]: can_slash, latest checkpoint, [Write]: ReportedOffenders
+        let mut consumed_weight = T::DbWeight::get().reads_writes(2, 1);
+        if result {
+            consumed_weight = consumed_weight.saturating_add(Self::add_to_slash_batch(offender));
+        }
+        if disabling.applies(result) {
+            consumed_weight = consumed_weight.saturating_add(Self::disable_offender(offender));
+        }
+
+        consumed_weight
+    }
+
+    /// Disables an offender in the current session and takes it offline in staking, so it is not
+    /// selected again until it is enabled
+    fn disable_offender(offender: &<T as session::Config>::ValidatorId) -> Weight {
+        <session::Pallet<T>>::disable(offender);
+        let chilled = T::Chiller::chill(offender);
+        <DisabledOffenders<T>>::insert(
+            offender,
+            DisabledOffender { session_index: <session::Pallet<T>>::current_index(), chilled },
+        );
+
+        Self::deposit_event(Event::<T>::OffenderDisabled { offender: offender.clone(), chilled });
+
+        // [Read]: Validators, CurrentIndex, [Read/Write]: DisabledValidators, and the candidate
+        // state and pool of the staking pallet, [Write]: DisabledOffenders
+        T::DbWeight::get().reads_writes(5, 4)
+    }
+
+    /// Processes the offenders deferred by previous blocks, up to `MaxOffendersPerBlock`
+    pub(crate) fn process_deferred_offenders() -> Weight {
+        let offenders: Vec<<T as session::Config>::ValidatorId> =
+            <DeferredOffenders<T>>::iter_keys()
+                .take(T::MaxOffendersPerBlock::get() as usize)
+                .collect();
+
+        // [Read]: MaxOffendersPerBlock, each deferred offender and the end of the map
+        let mut consumed_weight = T::DbWeight::get().reads(offenders.len() as u64 + 2);
+        for offender in offenders {
+            let disabling =
+                <DeferredOffenders<T>>::take(&offender).unwrap_or(OffenderDisabling::Never);
+            consumed_weight += T::DbWeight::get().reads_writes(1, 1);
+            consumed_weight += Self::process_offender(&offender, disabling);
+        }
+
+        consumed_weight
+    }
+
+    pub(crate) fn admin_change_name(call: &Call<T>) -> TaskName {
+        let call = <T as Config>::RuntimeCall::from(call.clone());
+        ("AdminChange", call).using_encoded(sp_io::hashing::blake2_256)
+    }
+
+    /// Schedules `call` to be dispatched by root once `AdminChangeDelay` blocks have passed
+    pub(crate) fn announce_admin_change(
+        call: Call<T>,
+    ) -> Result<(TaskName, BlockNumberFor<T>), DispatchError> {
+        Self::clear_pending_admin_changes(&call);
+
+        let schedule_name = Self::admin_change_name(&call);
+        let execute_at =
+            <frame_system::Pallet<T>>::block_number().saturating_add(T::AdminChangeDelay::get());
+
+        T::Scheduler::schedule_named(
+            schedule_name,
+            DispatchTime::At(execute_at),
+            None,
+            HARD_DEADLINE,
+            frame_system::RawOrigin::Root.into(),
+            T::Preimages::bound(<T as Config>::RuntimeCall::from(call))?,
+        )?;
+        <PendingAdminChanges<T>>::insert(schedule_name, execute_at);
+
+        Ok((schedule_name, execute_at))
+    }
+
+    /// Drops the announced toggles that have not been executed, as `call` supersedes them. Those
+    /// other than `call` itself are cancelled.
+    fn clear_pending_admin_changes(call: &Call<T>) {
+        let superseded_by = Self::admin_change_name(call);
+        for (schedule_name, _) in <PendingAdminChanges<T>>::drain() {
+            // The toggle being executed by the scheduler is no longer in its agenda
+            let _ = T::Scheduler::cancel_named(schedule_name);
+            if schedule_name != superseded_by {
+                Self::deposit_event(Event::<T>::AdminChangeCancelled { schedule_name });
+            }
+        }
+    }
+
+    /// Removes the announced toggles whose execution block has passed. They are only left when
+    /// their execution failed.
+    fn clear_expired_admin_changes(now: BlockNumberFor<T>) -> Weight {
+        let pending: Vec<(TaskName, BlockNumberFor<T>)> =
+            <PendingAdminChanges<T>>::iter().collect();
+        let mut expired = 0u64;
+        for (schedule_name, execute_at) in pending.iter() {
+            if *execute_at < now {
+                <PendingAdminChanges<T>>::remove(schedule_name);
+                expired += 1;
+            }
+        }
+
+        T::DbWeight::get().reads_writes(pending.len() as u64 + 1, expired)
     }
 }
 
@@ -105,44 +462,67 @@ impl<T: Config> OnOffenceHandler<T::AccountId, IdentificationTuple<T>, Weight> f
         offenders: &[OffenceDetails<T::AccountId, IdentificationTuple<T>>], /* A list containing both current offenders and previous offenders */
         _slash_fraction: &[Perbill],
         _session: SessionIndex,
-        _disable_strategy: DisableStrategy,
+        disable_strategy: DisableStrategy,
     ) -> Weight {
-        let mut consumed_weight: Weight = Weight::from_parts(0 as u64, 0);
-        let mut add_db_reads_writes = |reads, writes| {
-            consumed_weight += T::DbWeight::get().reads_writes(reads, writes);
-        };
+        let disabling = OffenderDisabling::from(disable_strategy);
+        let mut remaining = T::MaxOffendersPerBlock::get();
 
-        // [Read]: each item is checked by `ReportedOffenders::contains_key`
-        add_db_reads_writes(offenders.len() as u64, 0);
+        // [Read]: MaxOffendersPerBlock, and each item is checked by
+        // `ReportedOffenders::contains_key` and `DeferredOffenders::contains_key`
+        let mut consumed_weight =
+            T::DbWeight::get().reads((offenders.len() as u64).saturating_mul(2) + 1);
 
         offenders
             .iter()
-            .filter(|&detail| !<ReportedOffenders<T>>::contains_key(&detail.offender.0))
+            .filter(|&detail| {
+                !<ReportedOffenders<T>>::contains_key(&detail.offender.0) &&
+                    !<DeferredOffenders<T>>::contains_key(&detail.offender.0)
+            })
             .for_each(|detail| {
                 let offender_account_id = &detail.offender.0;
-                Self::deposit_event(Event::<T>::ReportedOffence {
-                    offender: offender_account_id.clone(),
-                });
-
-                let mut result: bool = false;
-
-                // [Read]: can_slash
-                add_db_reads_writes(1, 0);
-                if Self::can_slash() {
-                    result = T::Enforcer::slash_validator(&offender_account_id.clone()).is_ok();
+                if remaining > 0 {
+                    remaining -= 1;
+                    consumed_weight += Self::process_offender(offender_account_id, disabling);
+                } else {
+                    <DeferredOffenders<T>>::insert(offender_account_id, disabling);
+                    Self::deposit_event(Event::<T>::OffenderDeferred {
+                        offender: offender_account_id.clone(),
+                    });
+                    // [Write]: DeferredOffenders
+                    consumed_weight += T::DbWeight::get().writes(1);
                 }
-
-                <ReportedOffenders<T>>::insert(offender_account_id.clone(), result);
-                // [Write]: ReportedOffenders
-                add_db_reads_writes(0, 1);
             });
 
         return consumed_weight
     }
 }
 
+impl<T: Config> BridgeInterfaceNotification for Pallet<T> {
+    fn process_result(tx_id: u32, caller_id: Vec<u8>, succeeded: bool) -> DispatchResult {
+        if caller_id == PALLET_ID.to_vec() {
+            Self::process_slash_batch_result(tx_id, succeeded);
+        }
+
+        Ok(())
+    }
+}
+
+impl<T: Config> DisabledOffenderCheck<<T as session::Config>::ValidatorId> for Pallet<T> {
+    fn is_disabled(offender: &<T as session::Config>::ValidatorId) -> bool {
+        <DisabledOffenders<T>>::contains_key(offender)
+    }
+}
+
 impl<T: Config> ValidatorRegistrationNotifier<<T as session::Config>::ValidatorId> for Pallet<T> {
     fn on_validator_registration(validator_id: &<T as session::Config>::ValidatorId) {
         Self::setup_for_new_validator(validator_id);
     }
+
+    fn on_unresponsive_validator_removal_scheduled(
+        validator_id: &<T as session::Config>::ValidatorId,
+    ) {
+        Self::deposit_event(Event::<T>::UnresponsiveValidatorRemovalScheduled {
+            offender: validator_id.clone(),
+        });
+    }
 }
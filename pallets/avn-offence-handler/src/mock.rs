@@ -1,11 +1,16 @@
 //Copyright 2022 Aventus Systems (UK) Ltd.
 
 use crate::{self as avn_offence_handler, *};
-use frame_support::parameter_types;
+use frame_support::{
+    parameter_types,
+    traits::{ConstU128, ConstU32, ConstU64, EitherOfDiverse, SortedMembers},
+    weights::Weight,
+};
 use sp_runtime::{DispatchError, DispatchResult};
 use sp_state_machine::BasicExternalities;
 
 use frame_system as system;
+use pallet_avn::{LowerParams, OffenderChiller, ValidatorSetCheckpointId};
 use pallet_session as session;
 use sp_core::H256;
 use sp_runtime::{
@@ -25,18 +30,89 @@ type Block = frame_system::mocking::MockBlock<TestRuntime>;
 frame_support::construct_runtime!(
     pub enum TestRuntime {
         System: frame_system::{Pallet, Call, Config<T>, Storage, Event<T>},
+        Balances: pallet_balances::{Pallet, Call, Storage, Config<T>, Event<T>},
         Session: pallet_session::{Pallet, Call, Storage, Event, Config<T>},
-        AVN: pallet_avn::{Pallet, Storage, Event},
+        AVN: pallet_avn::{Pallet, Storage, Event<T>},
         AvnOffenceHandler: avn_offence_handler::{Pallet, Call, Storage, Event<T>},
+        Preimage: pallet_preimage,
+        Scheduler: pallet_scheduler::{Pallet, Call, Storage, Event<T>},
     }
 );
 
+parameter_types! {
+    pub MaximumSchedulerWeight: Weight = Weight::MAX;
+    pub static MaxOffendersPerBlock: u32 = 2;
+    pub static LatestPublishedCheckpoint: Option<ValidatorSetCheckpointId> = None;
+    pub static PublishSucceeds: bool = true;
+}
+
+pub const MAX_SLASH_PUBLISH_ATTEMPTS: u32 = 2;
+
+impl pallet_preimage::Config for TestRuntime {
+    type RuntimeEvent = RuntimeEvent;
+    type WeightInfo = ();
+    type Currency = Balances;
+    type ManagerOrigin = frame_system::EnsureRoot<u64>;
+    type BaseDeposit = ConstU128<0>;
+    type ByteDeposit = ConstU128<0>;
+}
+
+impl pallet_scheduler::Config for TestRuntime {
+    type RuntimeEvent = RuntimeEvent;
+    type RuntimeOrigin = RuntimeOrigin;
+    type PalletsOrigin = OriginCaller;
+    type RuntimeCall = RuntimeCall;
+    type MaximumWeight = MaximumSchedulerWeight;
+    type ScheduleOrigin = frame_system::EnsureRoot<u64>;
+    type MaxScheduledPerBlock = ConstU32<100>;
+    type WeightInfo = ();
+    type OriginPrivilegeCmp = frame_support::traits::EqualPrivilegeOnly;
+    type Preimages = Preimage;
+}
+
 pub type ValidatorId = <TestRuntime as session::Config>::ValidatorId;
 
 impl Config for TestRuntime {
     type RuntimeEvent = RuntimeEvent;
     type Enforcer = Self;
+    type CheckpointProvider = Self;
+    type AdminOrigin =
+        EitherOfDiverse<frame_system::EnsureRoot<u64>, frame_system::EnsureSignedBy<Admin, u64>>;
+    type WeightInfo = ();
+    type RuntimeCall = RuntimeCall;
+    type Scheduler = Scheduler;
+    type Preimages = Preimage;
+    type PalletsOrigin = OriginCaller;
+    type AdminChangeDelay = ConstU64<10>;
+    type MaxOffendersPerBlock = MaxOffendersPerBlock;
+    type BridgeInterface = Self;
+    type MaxSlashPublishAttempts = ConstU32<MAX_SLASH_PUBLISH_ATTEMPTS>;
+    type Chiller = Self;
+}
+
+pub const ADMIN_ACCOUNT: u64 = 100;
+
+pub struct Admin;
+impl SortedMembers<u64> for Admin {
+    fn sorted_members() -> Vec<u64> {
+        vec![ADMIN_ACCOUNT]
+    }
+}
+
+impl pallet_balances::Config for TestRuntime {
+    type MaxLocks = ConstU32<1024>;
+    type MaxReserves = ();
+    type ReserveIdentifier = [u8; 8];
+    type Balance = u128;
+    type RuntimeEvent = RuntimeEvent;
+    type DustRemoval = ();
+    type ExistentialDeposit = ConstU128<1>;
+    type AccountStore = System;
     type WeightInfo = ();
+    type RuntimeHoldReason = ();
+    type FreezeIdentifier = ();
+    type MaxHolds = ();
+    type MaxFreezes = ();
 }
 
 impl pallet_session::historical::Config for TestRuntime {
@@ -101,7 +177,7 @@ impl system::Config for TestRuntime {
     type BlockHashCount = BlockHashCount;
     type Version = ();
     type PalletInfo = PalletInfo;
-    type AccountData = ();
+    type AccountData = pallet_balances::AccountData<u128>;
     type OnNewAccount = ();
     type OnKilledAccount = ();
     type SystemWeightInfo = ();
@@ -111,15 +187,76 @@ impl system::Config for TestRuntime {
 }
 
 impl Enforcer<ValidatorId> for TestRuntime {
-    fn slash_validator(slashed_validator_id: &ValidatorId) -> DispatchResult {
+    fn slash_validator(
+        slashed_validator_id: &ValidatorId,
+        checkpoint: Option<ValidatorSetCheckpointId>,
+    ) -> DispatchResult {
         if slashed_validator_id == &VALIDATOR_ID_CAN_CAUSE_SLASH_ERROR {
             return Err(DispatchError::Other("Slash validator failed"))
         }
+        SLASH_CHECKPOINTS.with(|c| c.borrow_mut().push((*slashed_validator_id, checkpoint)));
+        Ok(())
+    }
+}
+
+impl BridgeInterface for TestRuntime {
+    fn publish(
+        function_name: &[u8],
+        params: &[(Vec<u8>, Vec<u8>)],
+        caller_id: Vec<u8>,
+    ) -> Result<u32, DispatchError> {
+        if !PublishSucceeds::get() {
+            return Err(DispatchError::Other("Publish failed"))
+        }
+        PUBLISHED_TRANSACTIONS.with(|t| {
+            let mut transactions = t.borrow_mut();
+            transactions.push((function_name.to_vec(), params.to_vec(), caller_id));
+            Ok(transactions.len() as u32)
+        })
+    }
+
+    fn generate_lower_proof(_: u32, _: &LowerParams, _: Vec<u8>) -> Result<(), DispatchError> {
+        Ok(())
+    }
+
+    fn read_bridge_contract(
+        _: Vec<u8>,
+        _: &[u8],
+        _: &[(Vec<u8>, Vec<u8>)],
+        _: Option<u32>,
+    ) -> Result<Vec<u8>, DispatchError> {
+        Ok(vec![])
+    }
+
+    fn latest_finalised_ethereum_block() -> Result<u32, DispatchError> {
+        Ok(0)
+    }
+}
+
+impl OffenderChiller<ValidatorId> for TestRuntime {
+    fn chill(offender: &ValidatorId) -> bool {
+        CHILLED_OFFENDERS.with(|c| c.borrow_mut().push(*offender));
+        true
+    }
+
+    fn unchill(offender: &ValidatorId) -> DispatchResult {
+        CHILLED_OFFENDERS.with(|c| c.borrow_mut().retain(|chilled| chilled != offender));
         Ok(())
     }
 }
 
+impl ValidatorSetCheckpointProvider for TestRuntime {
+    fn latest_published_checkpoint() -> Option<ValidatorSetCheckpointId> {
+        LatestPublishedCheckpoint::get()
+    }
+}
+
 thread_local! {
+    pub static SLASH_CHECKPOINTS: RefCell<Vec<(ValidatorId, Option<ValidatorSetCheckpointId>)>> =
+        RefCell::new(vec![]);
+    pub static CHILLED_OFFENDERS: RefCell<Vec<ValidatorId>> = RefCell::new(vec![]);
+    pub static PUBLISHED_TRANSACTIONS: RefCell<Vec<(Vec<u8>, Vec<(Vec<u8>, Vec<u8>)>, Vec<u8>)>> =
+        RefCell::new(vec![]);
     static VALIDATORS: RefCell<Option<Vec<u64>>> = RefCell::new(Some(vec![
         VALIDATOR_ID_1,
         VALIDATOR_ID_2,
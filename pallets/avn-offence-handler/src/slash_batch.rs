@@ -0,0 +1,124 @@
+//! Slashes applied on the AvN, published to Ethereum so the offenders are also slashed on T1.
+//!
+//! The offenders slashed during a session are collected in a batch, which is published once the
+//! session has ended. The result of the transaction is reported back by the bridge: a batch that
+//! failed to publish is sent again in a later block, up to `MaxSlashPublishAttempts` attempts.
+
+#[cfg(not(feature = "std"))]
+use alloc::format;
+
+use crate::*;
+use frame_support::BoundedVec;
+use sp_avn_common::{bounds::MaximumValidatorsBound, BridgeContractMethod};
+
+#[derive(Clone, Eq, PartialEq, Encode, Decode, Debug, TypeInfo, MaxEncodedLen)]
+pub struct SlashBatch<ValidatorId> {
+    pub offenders: BoundedVec<ValidatorId, MaximumValidatorsBound>,
+    /// The Ethereum transaction of the attempt in flight, if any
+    pub tx_id: Option<EthereumTransactionId>,
+    pub attempts: u32,
+}
+
+impl<T: Config> Pallet<T> {
+    /// Adds an offender slashed on the AvN to the batch of the current session
+    pub(crate) fn add_to_slash_batch(offender: &<T as session::Config>::ValidatorId) -> Weight {
+        let session_index = <session::Pallet<T>>::current_index();
+        <SlashBatches<T>>::mutate(session_index, |batch| {
+            let batch = batch.get_or_insert_with(|| SlashBatch {
+                offenders: BoundedVec::default(),
+                tx_id: None,
+                attempts: 0,
+            });
+            // An offender is only slashed once, so a batch cannot outgrow the validator set
+            let _ = batch.offenders.try_push(offender.clone());
+        });
+
+        // [Read]: CurrentIndex, SlashBatches, [Write]: SlashBatches
+        T::DbWeight::get().reads_writes(2, 1)
+    }
+
+    /// Publishes the oldest batch of an ended session that is not waiting for a result
+    pub(crate) fn publish_next_slash_batch() -> Weight {
+        let current_session = <session::Pallet<T>>::current_index();
+        let mut batches_read: u64 = 0;
+        let next_batch = <SlashBatches<T>>::iter()
+            .inspect(|_| batches_read += 1)
+            .filter(|(session_index, batch)| {
+                *session_index < current_session && batch.tx_id.is_none()
+            })
+            .min_by_key(|(session_index, _)| *session_index);
+
+        // [Read]: CurrentIndex, each batch and the end of the map
+        let consumed_weight = T::DbWeight::get().reads(batches_read.saturating_add(2));
+        match next_batch {
+            Some((session_index, batch)) => {
+                Self::publish_slash_batch(session_index, batch);
+                consumed_weight.saturating_add(<T as Config>::WeightInfo::publish_slash_batch())
+            },
+            None => consumed_weight,
+        }
+    }
+
+    fn publish_slash_batch(
+        session_index: SessionIndex,
+        mut batch: SlashBatch<<T as session::Config>::ValidatorId>,
+    ) {
+        let function_name = BridgeContractMethod::SlashValidators.as_bytes();
+        let offenders: Vec<u8> =
+            batch.offenders.iter().flat_map(|offender| offender.encode()).collect();
+        let params = vec![
+            (b"bytes".to_vec(), offenders),
+            (b"uint32".to_vec(), format!("{}", session_index).as_bytes().to_vec()),
+        ];
+
+        // A batch that cannot be sent now stays queued and is sent in a later block
+        if let Ok(tx_id) = T::BridgeInterface::publish(function_name, &params, PALLET_ID.to_vec()) {
+            batch.tx_id = Some(tx_id);
+            batch.attempts = batch.attempts.saturating_add(1);
+            <PublishedSlashBatches<T>>::insert(tx_id, session_index);
+            Self::deposit_event(Event::<T>::SlashBatchPublished {
+                session_index,
+                tx_id,
+                offenders: batch.offenders.to_vec(),
+            });
+            <SlashBatches<T>>::insert(session_index, batch);
+        }
+    }
+
+    /// Records the result of publishing a batch. Transactions that did not send a batch are
+    /// ignored.
+    pub(crate) fn process_slash_batch_result(tx_id: EthereumTransactionId, succeeded: bool) {
+        let session_index = match <PublishedSlashBatches<T>>::take(tx_id) {
+            Some(session_index) => session_index,
+            None => return,
+        };
+        let mut batch = match <SlashBatches<T>>::get(session_index) {
+            Some(batch) if batch.tx_id == Some(tx_id) => batch,
+            _ => return,
+        };
+
+        if succeeded {
+            <SlashBatches<T>>::remove(session_index);
+            Self::deposit_event(Event::<T>::T1SlashConfirmed {
+                session_index,
+                tx_id,
+                offenders: batch.offenders.to_vec(),
+            });
+            return
+        }
+
+        let will_retry = batch.attempts < T::MaxSlashPublishAttempts::get();
+        if will_retry {
+            batch.tx_id = None;
+            <SlashBatches<T>>::insert(session_index, &batch);
+        } else {
+            <SlashBatches<T>>::remove(session_index);
+        }
+        Self::deposit_event(Event::<T>::T1SlashFailed {
+            session_index,
+            tx_id,
+            attempts: batch.attempts,
+            will_retry,
+        });
+    }
+}
@@ -60,6 +60,28 @@ mod on_offence {
             });
         }
 
+        #[test]
+        fn when_slashing_with_the_latest_published_checkpoint() {
+            let mut ext = ExtBuilder::build_default().with_validators().as_externality();
+
+            ext.execute_with(|| {
+                LatestPublishedCheckpoint::set(Some(7));
+                let context = Context::default(vec![VALIDATOR_ID_1]);
+
+                AvnOffenceHandler::on_offence(
+                    &context.offenders,
+                    &context.slash_fraction,
+                    context.session_index,
+                    DisableStrategy::Never,
+                );
+
+                assert_eq!(
+                    SLASH_CHECKPOINTS.with(|c| c.borrow().clone()),
+                    vec![(VALIDATOR_ID_1, Some(7))]
+                );
+            });
+        }
+
         mod with_slashing_enable {
             use super::*;
 
@@ -273,6 +295,607 @@ mod on_offence {
     }
 }
 
+mod offender_processing_cap {
+    use super::*;
+    use frame_support::traits::Hooks;
+
+    fn report(offender_ids: Vec<u64>) {
+        AvnOffenceHandler::enable_offence();
+        let offenders: Vec<OffenceDetails<u64, IdentificationTuple<TestRuntime>>> = offender_ids
+            .into_iter()
+            .map(|offender_id| OffenceDetails {
+                offender: (offender_id, offender_id),
+                reporters: vec![],
+            })
+            .collect();
+
+        AvnOffenceHandler::on_offence(
+            &offenders,
+            &[Perbill::from_percent(100)],
+            1,
+            DisableStrategy::Never,
+        );
+    }
+
+    #[test]
+    fn defers_the_offenders_above_the_cap() {
+        let mut ext = ExtBuilder::build_default().with_validators().as_externality();
+        ext.execute_with(|| {
+            MaxOffendersPerBlock::set(1);
+
+            report(vec![VALIDATOR_ID_1, VALIDATOR_ID_2]);
+
+            assert!(ReportedOffenders::<TestRuntime>::contains_key(&VALIDATOR_ID_1));
+            assert!(!ReportedOffenders::<TestRuntime>::contains_key(&VALIDATOR_ID_2));
+            assert_eq!(
+                AvnOffenceHandler::deferred_offenders(&VALIDATOR_ID_2),
+                Some(OffenderDisabling::Never)
+            );
+            assert!(event_emitted(&mock::RuntimeEvent::AvnOffenceHandler(crate::Event::<
+                TestRuntime,
+            >::OffenderDeferred {
+                offender: VALIDATOR_ID_2
+            })));
+        });
+    }
+
+    #[test]
+    fn processes_the_deferred_offenders_in_the_following_blocks() {
+        let mut ext = ExtBuilder::build_default().with_validators().as_externality();
+        ext.execute_with(|| {
+            MaxOffendersPerBlock::set(1);
+            report(vec![VALIDATOR_ID_1, VALIDATOR_ID_2, VALIDATOR_ID_CAN_CAUSE_SLASH_ERROR]);
+
+            AvnOffenceHandler::on_initialize(2);
+            assert_eq!(DeferredOffenders::<TestRuntime>::iter_keys().count(), 1);
+
+            AvnOffenceHandler::on_initialize(3);
+            assert_eq!(DeferredOffenders::<TestRuntime>::iter_keys().count(), 0);
+            assert_eq!(true, AvnOffenceHandler::get_reported_offender(&VALIDATOR_ID_2));
+            assert_eq!(
+                false,
+                AvnOffenceHandler::get_reported_offender(&VALIDATOR_ID_CAN_CAUSE_SLASH_ERROR)
+            );
+            assert!(ReportedOffenders::<TestRuntime>::contains_key(
+                &VALIDATOR_ID_CAN_CAUSE_SLASH_ERROR
+            ));
+        });
+    }
+
+    #[test]
+    fn does_not_defer_an_offender_twice() {
+        let mut ext = ExtBuilder::build_default().with_validators().as_externality();
+        ext.execute_with(|| {
+            MaxOffendersPerBlock::set(1);
+            report(vec![VALIDATOR_ID_1, VALIDATOR_ID_2]);
+            System::reset_events();
+
+            report(vec![VALIDATOR_ID_1, VALIDATOR_ID_2]);
+
+            assert!(System::events().is_empty());
+            assert_eq!(
+                AvnOffenceHandler::deferred_offenders(&VALIDATOR_ID_2),
+                Some(OffenderDisabling::Never)
+            );
+        });
+    }
+}
+
+mod configure_slashing {
+    use super::*;
+    use frame_support::{
+        assert_noop, assert_ok,
+        traits::{Get, Hooks},
+    };
+
+    fn schedule_name(enabled: bool) -> [u8; 32] {
+        AvnOffenceHandler::admin_change_name(&Call::configure_slashing { enabled })
+    }
+
+    fn execution_block() -> u64 {
+        System::block_number() + <TestRuntime as Config>::AdminChangeDelay::get()
+    }
+
+    fn roll_to(block_number: u64) {
+        System::set_block_number(block_number);
+        Scheduler::on_initialize(block_number);
+    }
+
+    #[test]
+    fn is_applied_immediately_by_root() {
+        let mut ext = ExtBuilder::build_default().as_externality();
+        ext.execute_with(|| {
+            assert_ok!(AvnOffenceHandler::configure_slashing(RuntimeOrigin::root(), true));
+
+            assert!(AvnOffenceHandler::can_slash());
+            assert!(event_emitted(&mock::RuntimeEvent::AvnOffenceHandler(
+                crate::Event::<TestRuntime>::SlashingConfigurationUpdated {
+                    slashing_enabled: true
+                }
+            )));
+        });
+    }
+
+    #[test]
+    fn is_announced_by_the_admin_and_applied_after_the_delay() {
+        let mut ext = ExtBuilder::build_default().as_externality();
+        ext.execute_with(|| {
+            let execute_at = execution_block();
+
+            assert_ok!(AvnOffenceHandler::configure_slashing(
+                RuntimeOrigin::signed(ADMIN_ACCOUNT),
+                true
+            ));
+            assert!(!AvnOffenceHandler::can_slash());
+            assert_eq!(
+                <PendingAdminChanges<TestRuntime>>::get(schedule_name(true)),
+                Some(execute_at)
+            );
+            assert!(event_emitted(&mock::RuntimeEvent::AvnOffenceHandler(
+                crate::Event::<TestRuntime>::SlashingConfigurationChangeAnnounced {
+                    slashing_enabled: true,
+                    execute_at,
+                    schedule_name: schedule_name(true),
+                }
+            )));
+
+            roll_to(execute_at - 1);
+            assert!(!AvnOffenceHandler::can_slash());
+
+            roll_to(execute_at);
+            assert!(AvnOffenceHandler::can_slash());
+            assert_eq!(<PendingAdminChanges<TestRuntime>>::get(schedule_name(true)), None);
+        });
+    }
+
+    #[test]
+    fn is_not_applied_when_cancelled() {
+        let mut ext = ExtBuilder::build_default().as_externality();
+        ext.execute_with(|| {
+            let execute_at = execution_block();
+
+            assert_ok!(AvnOffenceHandler::configure_slashing(
+                RuntimeOrigin::signed(ADMIN_ACCOUNT),
+                true
+            ));
+            assert_ok!(AvnOffenceHandler::cancel_admin_change(
+                RuntimeOrigin::signed(ADMIN_ACCOUNT),
+                schedule_name(true)
+            ));
+            assert!(event_emitted(&mock::RuntimeEvent::AvnOffenceHandler(crate::Event::<
+                TestRuntime,
+            >::AdminChangeCancelled {
+                schedule_name: schedule_name(true)
+            })));
+
+            roll_to(execute_at);
+            assert!(!AvnOffenceHandler::can_slash());
+        });
+    }
+
+    #[test]
+    fn is_superseded_by_a_toggle_applied_by_root() {
+        let mut ext = ExtBuilder::build_default().as_externality();
+        ext.execute_with(|| {
+            let execute_at = execution_block();
+
+            assert_ok!(AvnOffenceHandler::configure_slashing(
+                RuntimeOrigin::signed(ADMIN_ACCOUNT),
+                true
+            ));
+            assert_ok!(AvnOffenceHandler::configure_slashing(RuntimeOrigin::root(), false));
+            assert_eq!(<PendingAdminChanges<TestRuntime>>::get(schedule_name(true)), None);
+            assert!(event_emitted(&mock::RuntimeEvent::AvnOffenceHandler(crate::Event::<
+                TestRuntime,
+            >::AdminChangeCancelled {
+                schedule_name: schedule_name(true)
+            })));
+
+            roll_to(execute_at);
+            assert!(!AvnOffenceHandler::can_slash());
+        });
+    }
+
+    #[test]
+    fn is_forgotten_once_its_execution_block_has_passed() {
+        let mut ext = ExtBuilder::build_default().as_externality();
+        ext.execute_with(|| {
+            <PendingAdminChanges<TestRuntime>>::insert(schedule_name(true), 5);
+
+            AvnOffenceHandler::on_initialize(5);
+            assert_eq!(<PendingAdminChanges<TestRuntime>>::get(schedule_name(true)), Some(5));
+
+            AvnOffenceHandler::on_initialize(6);
+            assert_eq!(<PendingAdminChanges<TestRuntime>>::get(schedule_name(true)), None);
+        });
+    }
+
+    #[test]
+    fn cannot_cancel_a_change_that_was_not_announced() {
+        let mut ext = ExtBuilder::build_default().as_externality();
+        ext.execute_with(|| {
+            assert_noop!(
+                AvnOffenceHandler::cancel_admin_change(RuntimeOrigin::root(), schedule_name(true)),
+                Error::<TestRuntime>::AdminChangeNotFound
+            );
+        });
+    }
+}
+
+mod slash_publication {
+    use super::*;
+    use frame_support::{assert_ok, traits::Hooks};
+    use pallet_avn::BridgeInterfaceNotification;
+    use sp_avn_common::BridgeContractMethod;
+
+    const SESSION: SessionIndex = 0;
+
+    fn offences(
+        offender_ids: Vec<u64>,
+    ) -> Vec<OffenceDetails<u64, IdentificationTuple<TestRuntime>>> {
+        offender_ids
+            .into_iter()
+            .map(|offender_id| OffenceDetails {
+                offender: (offender_id, offender_id),
+                reporters: vec![],
+            })
+            .collect()
+    }
+
+    fn report(offender_ids: Vec<u64>) {
+        AvnOffenceHandler::enable_offence();
+        AvnOffenceHandler::on_offence(
+            &offences(offender_ids),
+            &[Perbill::from_percent(100)],
+            SESSION,
+            DisableStrategy::Never,
+        );
+    }
+
+    fn end_session() {
+        session::CurrentIndex::<TestRuntime>::put(SESSION + 1);
+        AvnOffenceHandler::on_initialize(System::block_number());
+    }
+
+    fn tx_id() -> EthereumTransactionId {
+        AvnOffenceHandler::slash_batch(SESSION).unwrap().tx_id.unwrap()
+    }
+
+    fn published_transactions() -> Vec<(Vec<u8>, Vec<(Vec<u8>, Vec<u8>)>, Vec<u8>)> {
+        PUBLISHED_TRANSACTIONS.with(|t| t.borrow().clone())
+    }
+
+    #[test]
+    fn batches_the_offenders_slashed_in_a_session() {
+        let mut ext = ExtBuilder::build_default().with_validators().as_externality();
+        ext.execute_with(|| {
+            report(vec![VALIDATOR_ID_1, VALIDATOR_ID_2, VALIDATOR_ID_CAN_CAUSE_SLASH_ERROR]);
+
+            let batch = AvnOffenceHandler::slash_batch(SESSION).unwrap();
+            assert_eq!(batch.offenders.to_vec(), vec![VALIDATOR_ID_1, VALIDATOR_ID_2]);
+            assert_eq!(batch.tx_id, None);
+        });
+    }
+
+    #[test]
+    fn does_not_batch_offenders_when_slashing_is_disabled() {
+        let mut ext = ExtBuilder::build_default().with_validators().as_externality();
+        ext.execute_with(|| {
+            AvnOffenceHandler::disable_offence();
+
+            AvnOffenceHandler::on_offence(
+                &offences(vec![VALIDATOR_ID_1]),
+                &[Perbill::from_percent(100)],
+                SESSION,
+                DisableStrategy::Never,
+            );
+
+            assert_eq!(AvnOffenceHandler::slash_batch(SESSION), None);
+        });
+    }
+
+    #[test]
+    fn publishes_the_batch_once_the_session_has_ended() {
+        let mut ext = ExtBuilder::build_default().with_validators().as_externality();
+        ext.execute_with(|| {
+            report(vec![VALIDATOR_ID_1, VALIDATOR_ID_2]);
+
+            AvnOffenceHandler::on_initialize(System::block_number());
+            assert!(published_transactions().is_empty());
+
+            end_session();
+
+            let expected_offenders = [VALIDATOR_ID_1.encode(), VALIDATOR_ID_2.encode()].concat();
+            assert_eq!(
+                published_transactions(),
+                vec![(
+                    BridgeContractMethod::SlashValidators.as_bytes().to_vec(),
+                    vec![
+                        (b"bytes".to_vec(), expected_offenders),
+                        (b"uint32".to_vec(), b"0".to_vec()),
+                    ],
+                    PALLET_ID.to_vec(),
+                )]
+            );
+            assert!(event_emitted(&mock::RuntimeEvent::AvnOffenceHandler(crate::Event::<
+                TestRuntime,
+            >::SlashBatchPublished {
+                session_index: SESSION,
+                tx_id: tx_id(),
+                offenders: vec![VALIDATOR_ID_1, VALIDATOR_ID_2],
+            })));
+        });
+    }
+
+    #[test]
+    fn does_not_publish_a_batch_waiting_for_its_result_again() {
+        let mut ext = ExtBuilder::build_default().with_validators().as_externality();
+        ext.execute_with(|| {
+            report(vec![VALIDATOR_ID_1]);
+            end_session();
+
+            AvnOffenceHandler::on_initialize(System::block_number() + 1);
+
+            assert_eq!(published_transactions().len(), 1);
+        });
+    }
+
+    #[test]
+    fn keeps_the_batch_when_it_cannot_be_sent() {
+        let mut ext = ExtBuilder::build_default().with_validators().as_externality();
+        ext.execute_with(|| {
+            report(vec![VALIDATOR_ID_1]);
+            PublishSucceeds::set(false);
+            end_session();
+
+            assert_eq!(AvnOffenceHandler::slash_batch(SESSION).unwrap().tx_id, None);
+
+            PublishSucceeds::set(true);
+            AvnOffenceHandler::on_initialize(System::block_number() + 1);
+
+            assert_eq!(published_transactions().len(), 1);
+        });
+    }
+
+    #[test]
+    fn emits_an_event_when_ethereum_confirms_the_slash() {
+        let mut ext = ExtBuilder::build_default().with_validators().as_externality();
+        ext.execute_with(|| {
+            report(vec![VALIDATOR_ID_1]);
+            end_session();
+            let tx_id = tx_id();
+
+            assert_ok!(AvnOffenceHandler::process_result(tx_id, PALLET_ID.to_vec(), true));
+
+            assert_eq!(AvnOffenceHandler::slash_batch(SESSION), None);
+            assert!(!PublishedSlashBatches::<TestRuntime>::contains_key(tx_id));
+            assert!(event_emitted(&mock::RuntimeEvent::AvnOffenceHandler(crate::Event::<
+                TestRuntime,
+            >::T1SlashConfirmed {
+                session_index: SESSION,
+                tx_id,
+                offenders: vec![VALIDATOR_ID_1],
+            })));
+        });
+    }
+
+    #[test]
+    fn retries_a_failed_batch_until_the_maximum_number_of_attempts() {
+        let mut ext = ExtBuilder::build_default().with_validators().as_externality();
+        ext.execute_with(|| {
+            report(vec![VALIDATOR_ID_1]);
+            end_session();
+
+            for attempt in 1..=MAX_SLASH_PUBLISH_ATTEMPTS {
+                let tx_id = tx_id();
+                assert_ok!(AvnOffenceHandler::process_result(tx_id, PALLET_ID.to_vec(), false));
+
+                let will_retry = attempt < MAX_SLASH_PUBLISH_ATTEMPTS;
+                assert!(event_emitted(&mock::RuntimeEvent::AvnOffenceHandler(crate::Event::<
+                    TestRuntime,
+                >::T1SlashFailed {
+                    session_index: SESSION,
+                    tx_id,
+                    attempts: attempt,
+                    will_retry,
+                })));
+                AvnOffenceHandler::on_initialize(System::block_number() + attempt as u64);
+            }
+
+            assert_eq!(published_transactions().len(), MAX_SLASH_PUBLISH_ATTEMPTS as usize);
+            assert_eq!(AvnOffenceHandler::slash_batch(SESSION), None);
+        });
+    }
+
+    #[test]
+    fn ignores_the_results_of_other_pallets() {
+        let mut ext = ExtBuilder::build_default().with_validators().as_externality();
+        ext.execute_with(|| {
+            report(vec![VALIDATOR_ID_1]);
+            end_session();
+            let tx_id = tx_id();
+
+            assert_ok!(AvnOffenceHandler::process_result(tx_id, b"other_pallet".to_vec(), true));
+
+            assert_eq!(AvnOffenceHandler::slash_batch(SESSION).unwrap().tx_id, Some(tx_id));
+        });
+    }
+}
+
+mod disable_strategy {
+    use super::*;
+    use frame_support::{assert_noop, assert_ok, traits::Hooks};
+    use sp_runtime::DispatchError;
+
+    fn report(offender_ids: Vec<u64>, disable_strategy: DisableStrategy) {
+        let offenders: Vec<OffenceDetails<u64, IdentificationTuple<TestRuntime>>> = offender_ids
+            .into_iter()
+            .map(|offender_id| OffenceDetails {
+                offender: (offender_id, offender_id),
+                reporters: vec![],
+            })
+            .collect();
+
+        AvnOffenceHandler::on_offence(
+            &offenders,
+            &[Perbill::from_percent(100)],
+            1,
+            disable_strategy,
+        );
+    }
+
+    fn is_disabled_in_session(offender: u64) -> bool {
+        let index = Session::validators().iter().position(|v| *v == offender).unwrap() as u32;
+        Session::disabled_validators().contains(&index)
+    }
+
+    fn chilled_offenders() -> Vec<u64> {
+        CHILLED_OFFENDERS.with(|c| c.borrow().clone())
+    }
+
+    #[test]
+    fn never_does_not_disable_the_offenders() {
+        let mut ext = ExtBuilder::build_default().with_validators().as_externality();
+        ext.execute_with(|| {
+            AvnOffenceHandler::enable_offence();
+
+            report(vec![VALIDATOR_ID_1], DisableStrategy::Never);
+
+            assert!(!is_disabled_in_session(VALIDATOR_ID_1));
+            assert_eq!(AvnOffenceHandler::disabled_offender(&VALIDATOR_ID_1), None);
+            assert!(chilled_offenders().is_empty());
+        });
+    }
+
+    #[test]
+    fn when_slashed_only_disables_the_slashed_offenders() {
+        let mut ext = ExtBuilder::build_default().with_validators().as_externality();
+        ext.execute_with(|| {
+            AvnOffenceHandler::enable_offence();
+
+            report(
+                vec![VALIDATOR_ID_1, VALIDATOR_ID_CAN_CAUSE_SLASH_ERROR],
+                DisableStrategy::WhenSlashed,
+            );
+
+            assert!(is_disabled_in_session(VALIDATOR_ID_1));
+            assert!(!is_disabled_in_session(VALIDATOR_ID_CAN_CAUSE_SLASH_ERROR));
+            assert_eq!(
+                AvnOffenceHandler::disabled_offender(&VALIDATOR_ID_1),
+                Some(DisabledOffender { session_index: 0, chilled: true })
+            );
+            assert_eq!(chilled_offenders(), vec![VALIDATOR_ID_1]);
+            assert!(event_emitted(&mock::RuntimeEvent::AvnOffenceHandler(crate::Event::<
+                TestRuntime,
+            >::OffenderDisabled {
+                offender: VALIDATOR_ID_1,
+                chilled: true
+            })));
+        });
+    }
+
+    #[test]
+    fn when_slashed_does_not_disable_the_offenders_when_slashing_is_disabled() {
+        let mut ext = ExtBuilder::build_default().with_validators().as_externality();
+        ext.execute_with(|| {
+            AvnOffenceHandler::disable_offence();
+
+            report(vec![VALIDATOR_ID_1], DisableStrategy::WhenSlashed);
+
+            assert!(!is_disabled_in_session(VALIDATOR_ID_1));
+        });
+    }
+
+    #[test]
+    fn always_disables_the_offenders_even_if_they_are_not_slashed() {
+        let mut ext = ExtBuilder::build_default().with_validators().as_externality();
+        ext.execute_with(|| {
+            AvnOffenceHandler::disable_offence();
+
+            report(vec![VALIDATOR_ID_1], DisableStrategy::Always);
+
+            assert!(is_disabled_in_session(VALIDATOR_ID_1));
+            assert_eq!(chilled_offenders(), vec![VALIDATOR_ID_1]);
+        });
+    }
+
+    #[test]
+    fn deferred_offenders_are_disabled_with_the_strategy_of_their_offence() {
+        let mut ext = ExtBuilder::build_default().with_validators().as_externality();
+        ext.execute_with(|| {
+            MaxOffendersPerBlock::set(1);
+            report(vec![VALIDATOR_ID_1, VALIDATOR_ID_2], DisableStrategy::Always);
+            assert_eq!(
+                AvnOffenceHandler::deferred_offenders(&VALIDATOR_ID_2),
+                Some(OffenderDisabling::Always)
+            );
+
+            AvnOffenceHandler::on_initialize(2);
+
+            assert!(is_disabled_in_session(VALIDATOR_ID_2));
+        });
+    }
+
+    mod enable_offender {
+        use super::*;
+
+        #[test]
+        fn enables_the_offender_and_brings_it_back_online() {
+            let mut ext = ExtBuilder::build_default().with_validators().as_externality();
+            ext.execute_with(|| {
+                report(vec![VALIDATOR_ID_1], DisableStrategy::Always);
+                assert!(<AvnOffenceHandler as DisabledOffenderCheck<ValidatorId>>::is_disabled(
+                    &VALIDATOR_ID_1
+                ));
+
+                assert_ok!(AvnOffenceHandler::enable_offender(
+                    RuntimeOrigin::signed(ADMIN_ACCOUNT),
+                    VALIDATOR_ID_1
+                ));
+
+                assert!(!<AvnOffenceHandler as DisabledOffenderCheck<ValidatorId>>::is_disabled(
+                    &VALIDATOR_ID_1
+                ));
+                assert!(!is_disabled_in_session(VALIDATOR_ID_1));
+                assert_eq!(AvnOffenceHandler::disabled_offender(&VALIDATOR_ID_1), None);
+                assert!(chilled_offenders().is_empty());
+                assert!(event_emitted(&mock::RuntimeEvent::AvnOffenceHandler(crate::Event::<
+                    TestRuntime,
+                >::OffenderEnabled {
+                    offender: VALIDATOR_ID_1
+                })));
+            });
+        }
+
+        #[test]
+        fn fails_when_the_offender_is_not_disabled() {
+            let mut ext = ExtBuilder::build_default().with_validators().as_externality();
+            ext.execute_with(|| {
+                assert_noop!(
+                    AvnOffenceHandler::enable_offender(RuntimeOrigin::root(), VALIDATOR_ID_1),
+                    Error::<TestRuntime>::OffenderNotDisabled
+                );
+            });
+        }
+
+        #[test]
+        fn fails_for_other_origins() {
+            let mut ext = ExtBuilder::build_default().with_validators().as_externality();
+            ext.execute_with(|| {
+                report(vec![VALIDATOR_ID_1], DisableStrategy::Always);
+
+                assert_noop!(
+                    AvnOffenceHandler::enable_offender(
+                        RuntimeOrigin::signed(VALIDATOR_ID_2),
+                        VALIDATOR_ID_1
+                    ),
+                    DispatchError::BadOrigin
+                );
+            });
+        }
+    }
+}
+
 pub fn event_emitted(event: &mock::RuntimeEvent) -> bool {
     return System::events().iter().any(|a| a.event == *event)
 }
@@ -7,7 +7,8 @@
 
 use super::*;
 use crate::Pallet as AvnProxy;
-use frame_benchmarking::{benchmarks, impl_benchmark_test_suite, whitelisted_caller};
+use frame_benchmarking::{account, benchmarks, impl_benchmark_test_suite, whitelisted_caller};
+use frame_system::RawOrigin;
 use hex_literal::hex;
 use sp_core::{sr25519, H256};
 
@@ -72,6 +73,10 @@ fn get_inner_call_proof<T: Config>(
     return (proof, payment_authorisation, signer)
 }
 
+fn remark_call<T: Config>() -> <T as Config>::RuntimeCall {
+    frame_system::Call::<T>::remark { remark: vec![] }.into()
+}
+
 benchmarks! {
     charge_fee {
         let recipient: T::AccountId = whitelisted_caller();
@@ -89,7 +94,7 @@ benchmarks! {
 
         let (proof, payment_authorisation, signer) = get_inner_call_proof::<T>(&recipient, amount, token, signature);
     }: {
-        AvnProxy::<T>::charge_fee(&proof, payment_authorisation)?;
+        AvnProxy::<T>::charge_fee(&proof, &remark_call::<T>(), payment_authorisation)?;
     }
     verify {
         assert_eq!(T::Currency::free_balance(&recipient), amount.into());
@@ -112,11 +117,53 @@ benchmarks! {
         let (proof, payment_authorisation, signer) = get_inner_call_proof::<T>(&recipient, amount, token, signature);
         let previous_payment_nonce = <PaymentNonces::<T>>::get(&signer);
     }: {
-        AvnProxy::<T>::charge_fee(&proof, payment_authorisation)?;
+        AvnProxy::<T>::charge_fee(&proof, &remark_call::<T>(), payment_authorisation)?;
     }
     verify {
         assert_eq!(previous_payment_nonce + 1, <PaymentNonces::<T>>::get(&signer));
     }
+
+    set_relayer_fee_rule {
+        let token: T::Token = H160::repeat_byte(1).into();
+        let rule = RelayerFeeRule::Fixed(10u32.into());
+    }: _(RawOrigin::Root, FeeCategory::Staking, token, Some(rule))
+    verify {
+        assert_eq!(RelayerFeeSchedule::<T>::get(FeeCategory::Staking, token), Some(rule));
+    }
+
+    set_base_relayer_fee {
+        let token: T::Token = H160::repeat_byte(1).into();
+        let base_fee: BalanceOf<T> = 10u32.into();
+    }: _(RawOrigin::Root, token, base_fee)
+    verify {
+        assert_eq!(BaseRelayerFee::<T>::get(token), base_fee);
+    }
+
+    register_session_key {
+        let owner: T::AccountId = whitelisted_caller();
+        let session_key: T::AccountId = account("session_key", 0, 0);
+        let expiry = frame_system::Pallet::<T>::block_number() + T::MaxSessionKeyDuration::get();
+        let scope = vec![FeeCategory::Staking, FeeCategory::TokenTransfer, FeeCategory::Nft];
+    }: _(RawOrigin::Signed(owner.clone()), session_key.clone(), expiry, scope, 10u32.into())
+    verify {
+        assert!(SessionKeys::<T>::contains_key(&owner, &session_key));
+    }
+
+    revoke_session_key {
+        let owner: T::AccountId = whitelisted_caller();
+        let session_key: T::AccountId = account("session_key", 0, 0);
+        let expiry = frame_system::Pallet::<T>::block_number() + T::MaxSessionKeyDuration::get();
+        AvnProxy::<T>::register_session_key(
+            RawOrigin::Signed(owner.clone()).into(),
+            session_key.clone(),
+            expiry,
+            vec![FeeCategory::Staking],
+            10u32.into(),
+        )?;
+    }: _(RawOrigin::Signed(owner.clone()), session_key.clone())
+    verify {
+        assert!(!SessionKeys::<T>::contains_key(&owner, &session_key));
+    }
 }
 
 impl_benchmark_test_suite!(
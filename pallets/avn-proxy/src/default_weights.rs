@@ -39,6 +39,12 @@ use core::marker::PhantomData;
 pub trait WeightInfo {
 	fn charge_fee() -> Weight;
 	fn charge_fee_in_token() -> Weight;
+	fn ethereum_proxy() -> Weight;
+	fn set_relayer_fee_rule() -> Weight;
+	fn set_base_relayer_fee() -> Weight;
+	fn register_session_key() -> Weight;
+	fn revoke_session_key() -> Weight;
+	fn session_key_proxy() -> Weight;
 }
 
 /// Weights for pallet_avn_proxy using the Substrate node and recommended hardware.
@@ -46,6 +52,10 @@ pub struct SubstrateWeight<T>(PhantomData<T>);
 impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Storage: `AvnProxy::PaymentNonces` (r:1 w:1)
 	/// Proof: `AvnProxy::PaymentNonces` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `AvnProxy::RelayerFeeSchedule` (r:1 w:0)
+	/// Proof: `AvnProxy::RelayerFeeSchedule` (`max_values`: None, `max_size`: Some(70), added: 2545, mode: `MaxEncodedLen`)
+	/// Storage: `AvnProxy::BaseRelayerFee` (r:1 w:0)
+	/// Proof: `AvnProxy::BaseRelayerFee` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
 	/// Storage: `TokenManager::AVTTokenContract` (r:1 w:0)
 	/// Proof: `TokenManager::AVTTokenContract` (`max_values`: Some(1), `max_size`: Some(20), added: 515, mode: `MaxEncodedLen`)
 	/// Storage: `System::Account` (r:1 w:1)
@@ -58,11 +68,15 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		//  Estimated: `3593`
 		// Minimum execution time: 148_017_000 picoseconds.
 		Weight::from_parts(167_438_000, 3593)
-			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().reads(6_u64))
 			.saturating_add(T::DbWeight::get().writes(3_u64))
 	}
 	/// Storage: `AvnProxy::PaymentNonces` (r:1 w:1)
 	/// Proof: `AvnProxy::PaymentNonces` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `AvnProxy::RelayerFeeSchedule` (r:1 w:0)
+	/// Proof: `AvnProxy::RelayerFeeSchedule` (`max_values`: None, `max_size`: Some(70), added: 2545, mode: `MaxEncodedLen`)
+	/// Storage: `AvnProxy::BaseRelayerFee` (r:1 w:0)
+	/// Proof: `AvnProxy::BaseRelayerFee` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
 	/// Storage: `TokenManager::AVTTokenContract` (r:1 w:0)
 	/// Proof: `TokenManager::AVTTokenContract` (`max_values`: Some(1), `max_size`: Some(20), added: 515, mode: `MaxEncodedLen`)
 	/// Storage: `TokenManager::Balances` (r:2 w:2)
@@ -75,15 +89,88 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		//  Estimated: `6108`
 		// Minimum execution time: 113_827_000 picoseconds.
 		Weight::from_parts(125_537_000, 6108)
-			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().reads(7_u64))
 			.saturating_add(T::DbWeight::get().writes(4_u64))
 	}
+	/// Storage: `System::BlockHash` (r:1 w:0)
+	/// Proof: `System::BlockHash` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `Avn::EthereumAddressAccounts` (r:1 w:0)
+	/// Proof: `Avn::EthereumAddressAccounts` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+	/// Storage: `AvnProxy::EthereumNonces` (r:1 w:1)
+	/// Proof: `AvnProxy::EthereumNonces` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	fn ethereum_proxy() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `232`
+		//  Estimated: `3533`
+		// Minimum execution time: 68_412_000 picoseconds.
+		Weight::from_parts(70_106_000, 3533)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `AvnProxy::RelayerFeeSchedule` (r:0 w:1)
+	/// Proof: `AvnProxy::RelayerFeeSchedule` (`max_values`: None, `max_size`: Some(70), added: 2545, mode: `MaxEncodedLen`)
+	fn set_relayer_fee_rule() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 11_423_000 picoseconds.
+		Weight::from_parts(11_423_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `AvnProxy::BaseRelayerFee` (r:0 w:1)
+	/// Proof: `AvnProxy::BaseRelayerFee` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	fn set_base_relayer_fee() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 9_871_000 picoseconds.
+		Weight::from_parts(9_871_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `AvnProxy::SessionKeys` (r:0 w:1)
+	/// Proof: `AvnProxy::SessionKeys` (`max_values`: None, `max_size`: Some(151), added: 2626, mode: `MaxEncodedLen`)
+	fn register_session_key() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 16_204_000 picoseconds.
+		Weight::from_parts(16_204_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `AvnProxy::SessionKeys` (r:1 w:1)
+	/// Proof: `AvnProxy::SessionKeys` (`max_values`: None, `max_size`: Some(151), added: 2626, mode: `MaxEncodedLen`)
+	fn revoke_session_key() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `361`
+		//  Estimated: `3616`
+		// Minimum execution time: 19_337_000 picoseconds.
+		Weight::from_parts(19_337_000, 3616)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `AvnProxy::SessionKeys` (r:1 w:1)
+	/// Proof: `AvnProxy::SessionKeys` (`max_values`: None, `max_size`: Some(151), added: 2626, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:0)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn session_key_proxy() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `361`
+		//  Estimated: `3616`
+		// Minimum execution time: 71_590_000 picoseconds.
+		Weight::from_parts(71_590_000, 3616)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
 }
 
 // For backwards compatibility and tests.
 impl WeightInfo for () {
 	/// Storage: `AvnProxy::PaymentNonces` (r:1 w:1)
 	/// Proof: `AvnProxy::PaymentNonces` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `AvnProxy::RelayerFeeSchedule` (r:1 w:0)
+	/// Proof: `AvnProxy::RelayerFeeSchedule` (`max_values`: None, `max_size`: Some(70), added: 2545, mode: `MaxEncodedLen`)
+	/// Storage: `AvnProxy::BaseRelayerFee` (r:1 w:0)
+	/// Proof: `AvnProxy::BaseRelayerFee` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
 	/// Storage: `TokenManager::AVTTokenContract` (r:1 w:0)
 	/// Proof: `TokenManager::AVTTokenContract` (`max_values`: Some(1), `max_size`: Some(20), added: 515, mode: `MaxEncodedLen`)
 	/// Storage: `System::Account` (r:1 w:1)
@@ -96,11 +183,15 @@ impl WeightInfo for () {
 		//  Estimated: `3593`
 		// Minimum execution time: 148_017_000 picoseconds.
 		Weight::from_parts(167_438_000, 3593)
-			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
 			.saturating_add(RocksDbWeight::get().writes(3_u64))
 	}
 	/// Storage: `AvnProxy::PaymentNonces` (r:1 w:1)
 	/// Proof: `AvnProxy::PaymentNonces` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `AvnProxy::RelayerFeeSchedule` (r:1 w:0)
+	/// Proof: `AvnProxy::RelayerFeeSchedule` (`max_values`: None, `max_size`: Some(70), added: 2545, mode: `MaxEncodedLen`)
+	/// Storage: `AvnProxy::BaseRelayerFee` (r:1 w:0)
+	/// Proof: `AvnProxy::BaseRelayerFee` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
 	/// Storage: `TokenManager::AVTTokenContract` (r:1 w:0)
 	/// Proof: `TokenManager::AVTTokenContract` (`max_values`: Some(1), `max_size`: Some(20), added: 515, mode: `MaxEncodedLen`)
 	/// Storage: `TokenManager::Balances` (r:2 w:2)
@@ -113,7 +204,76 @@ impl WeightInfo for () {
 		//  Estimated: `6108`
 		// Minimum execution time: 113_827_000 picoseconds.
 		Weight::from_parts(125_537_000, 6108)
-			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
 			.saturating_add(RocksDbWeight::get().writes(4_u64))
 	}
+	/// Storage: `System::BlockHash` (r:1 w:0)
+	/// Proof: `System::BlockHash` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `Avn::EthereumAddressAccounts` (r:1 w:0)
+	/// Proof: `Avn::EthereumAddressAccounts` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+	/// Storage: `AvnProxy::EthereumNonces` (r:1 w:1)
+	/// Proof: `AvnProxy::EthereumNonces` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	fn ethereum_proxy() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `232`
+		//  Estimated: `3533`
+		// Minimum execution time: 68_412_000 picoseconds.
+		Weight::from_parts(70_106_000, 3533)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `AvnProxy::RelayerFeeSchedule` (r:0 w:1)
+	/// Proof: `AvnProxy::RelayerFeeSchedule` (`max_values`: None, `max_size`: Some(70), added: 2545, mode: `MaxEncodedLen`)
+	fn set_relayer_fee_rule() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 11_423_000 picoseconds.
+		Weight::from_parts(11_423_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `AvnProxy::BaseRelayerFee` (r:0 w:1)
+	/// Proof: `AvnProxy::BaseRelayerFee` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	fn set_base_relayer_fee() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 9_871_000 picoseconds.
+		Weight::from_parts(9_871_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `AvnProxy::SessionKeys` (r:0 w:1)
+	/// Proof: `AvnProxy::SessionKeys` (`max_values`: None, `max_size`: Some(151), added: 2626, mode: `MaxEncodedLen`)
+	fn register_session_key() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 16_204_000 picoseconds.
+		Weight::from_parts(16_204_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `AvnProxy::SessionKeys` (r:1 w:1)
+	/// Proof: `AvnProxy::SessionKeys` (`max_values`: None, `max_size`: Some(151), added: 2626, mode: `MaxEncodedLen`)
+	fn revoke_session_key() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `361`
+		//  Estimated: `3616`
+		// Minimum execution time: 19_337_000 picoseconds.
+		Weight::from_parts(19_337_000, 3616)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `AvnProxy::SessionKeys` (r:1 w:1)
+	/// Proof: `AvnProxy::SessionKeys` (`max_values`: None, `max_size`: Some(151), added: 2626, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:0)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn session_key_proxy() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `361`
+		//  Estimated: `3616`
+		// Minimum execution time: 71_590_000 picoseconds.
+		Weight::from_parts(71_590_000, 3616)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
 }
\ No newline at end of file
@@ -9,24 +9,39 @@
 
 use codec::{Decode, Encode};
 use frame_support::{
-    dispatch::{DispatchResult, DispatchResultWithPostInfo, GetDispatchInfo, PostDispatchInfo},
+    dispatch::{
+        extract_actual_weight, DispatchResult, DispatchResultWithPostInfo, GetDispatchInfo,
+        PostDispatchInfo,
+    },
     ensure,
-    pallet_prelude::ValueQuery,
-    traits::{Currency, IsSubType},
+    pallet_prelude::{BoundedVec, ConstU32, ValueQuery},
+    storage::{with_transaction, TransactionOutcome},
+    traits::{Contains, Currency, EnsureOrigin, IsSubType},
+    weights::Weight,
+};
+use frame_system::{self as system, ensure_signed, pallet_prelude::BlockNumberFor};
+use sp_avn_common::{
+    recover_ethereum_address_from_ecdsa_signature, verify_multi_signature, EthereumAddressMapping,
+    FeePaymentHandler, HashMessageFormat, InnerCallValidator, Proof,
 };
-use frame_system::{self as system, ensure_signed};
-use sp_avn_common::{verify_multi_signature, FeePaymentHandler, InnerCallValidator, Proof};
 
 use core::convert::TryInto;
 pub use pallet::*;
-use sp_core::{MaxEncodedLen, H160};
+use sp_core::{ecdsa, MaxEncodedLen, H160};
 use sp_runtime::{
     scale_info::TypeInfo,
-    traits::{Dispatchable, Hash, IdentifyAccount, Member, Verify},
+    traits::{Dispatchable, Hash, IdentifyAccount, Member, Verify, Zero},
+    DispatchError, FixedPointNumber, FixedU128, SaturatedConversion,
 };
 use sp_std::prelude::*;
 
 pub const PAYMENT_AUTH_CONTEXT: &'static [u8] = b"authorization for proxy payment";
+pub const ETHEREUM_PROXY_CONTEXT: &'static [u8] = b"authorization for ethereum proxy";
+pub const SESSION_KEY_PROXY_CONTEXT: &'static [u8] = b"authorization for session key proxy";
+/// The most call categories a session key can be scoped to
+pub const MAX_SESSION_KEY_SCOPE: u32 = 3;
+/// The most assets the calls authorised by a session key can spend
+pub const MAX_SESSION_KEY_ASSETS: u32 = 8;
 
 #[frame_support::pallet]
 pub mod pallet {
@@ -87,6 +102,19 @@ pub mod pallet {
             TokenBalance = <Self::Currency as Currency<Self::AccountId>>::Balance,
             Error = DispatchError,
         >;
+
+        /// Gives the account an Ethereum address authorises calls for
+        type EthereumAddressMapping: EthereumAddressMapping<Self::AccountId>;
+
+        /// The calls that can be authorised with an Ethereum signature
+        type EthereumCallFilter: Contains<<Self as Config>::RuntimeCall>;
+
+        /// The origin allowed to set the relayer fee schedule
+        type AdminOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+        /// The longest a session key can be registered for
+        #[pallet::constant]
+        type MaxSessionKeyDuration: Get<BlockNumberFor<Self>>;
     }
 
     #[pallet::pallet]
@@ -95,8 +123,45 @@ pub mod pallet {
     #[pallet::event]
     #[pallet::generate_deposit(pub(super) fn deposit_event)]
     pub enum Event<T: Config> {
-        CallDispatched { relayer: T::AccountId, hash: T::Hash },
-        InnerCallFailed { relayer: T::AccountId, hash: T::Hash, dispatch_error: DispatchError },
+        CallDispatched {
+            relayer: T::AccountId,
+            hash: T::Hash,
+        },
+        InnerCallFailed {
+            relayer: T::AccountId,
+            hash: T::Hash,
+            dispatch_error: DispatchError,
+        },
+        EthereumCallDispatched {
+            relayer: T::AccountId,
+            ethereum_address: H160,
+            account: T::AccountId,
+            hash: T::Hash,
+        },
+        RelayerFeeRuleSet {
+            category: FeeCategory,
+            token: T::Token,
+            rule: Option<RelayerFeeRule<BalanceOf<T>>>,
+        },
+        BaseRelayerFeeSet {
+            token: T::Token,
+            base_fee: BalanceOf<T>,
+        },
+        SessionKeyRegistered {
+            owner: T::AccountId,
+            session_key: T::AccountId,
+            expiry: BlockNumberFor<T>,
+        },
+        SessionKeyRevoked {
+            owner: T::AccountId,
+            session_key: T::AccountId,
+        },
+        SessionKeyCallDispatched {
+            relayer: T::AccountId,
+            owner: T::AccountId,
+            session_key: T::AccountId,
+            hash: T::Hash,
+        },
     }
 
     #[pallet::error]
@@ -104,6 +169,25 @@ pub mod pallet {
         TransactionNotSupported,
         UnauthorizedFee,
         UnauthorizedProxyTransaction,
+        /// The proof to validate is not the proof of the call
+        ProofMismatch,
+        CallNotAllowedFromEthereum,
+        EthereumAddressNotRegistered,
+        UnauthorizedEthereumTransaction,
+        /// The payer authorised less than the scheduled relayer fee of the call
+        RelayerFeeAboveAuthorisedAmount,
+        /// The expiry of a session key must be in the future and within `MaxSessionKeyDuration`
+        InvalidSessionKeyExpiry,
+        /// A session key must be scoped to at least one category of calls, other than `Other`
+        InvalidSessionKeyScope,
+        SessionKeyNotFound,
+        SessionKeyExpired,
+        CallOutsideSessionKeyScope,
+        UnauthorizedSessionKeyTransaction,
+        /// The call would make the owner spend more than the session key allows
+        SessionKeySpendLimitExceeded,
+        /// The call would make the session key spend more assets than it can track
+        TooManySessionKeyAssets,
     }
 
     #[pallet::storage]
@@ -113,6 +197,44 @@ pub mod pallet {
     pub type PaymentNonces<T: Config> =
         StorageMap<_, Blake2_128Concat, T::AccountId, u64, ValueQuery>;
 
+    #[pallet::storage]
+    #[pallet::getter(fn ethereum_nonces)]
+    /// The number of calls an Ethereum address has authorised
+    pub type EthereumNonces<T: Config> = StorageMap<_, Blake2_128Concat, H160, u64, ValueQuery>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn relayer_fee_rule)]
+    /// How the relayer fee of the calls of a category is set, by category and the token the fee
+    /// is paid in. A payer of a call without a rule for its token pays the amount it authorised.
+    pub type RelayerFeeSchedule<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        FeeCategory,
+        Blake2_128Concat,
+        T::Token,
+        RelayerFeeRule<BalanceOf<T>>,
+        OptionQuery,
+    >;
+
+    #[pallet::storage]
+    #[pallet::getter(fn base_relayer_fee)]
+    /// The relayer fee the multipliers of the fee schedule apply to, by the token it is paid in
+    pub type BaseRelayerFee<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::Token, BalanceOf<T>, ValueQuery>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn session_key)]
+    /// The session keys an account registered, by owner and session key
+    pub type SessionKeys<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        Blake2_128Concat,
+        T::AccountId,
+        SessionKeyOf<T>,
+        OptionQuery,
+    >;
+
     #[pallet::call]
     impl<T: Config> Pallet<T> {
         #[pallet::call_index(0)]
@@ -129,25 +251,20 @@ pub mod pallet {
             >,
         ) -> DispatchResultWithPostInfo {
             let relayer = ensure_signed(origin)?;
-            let mut final_weight = call
-                .get_dispatch_info()
-                .weight
-                .saturating_add(Weight::from_parts(50_000 as u64, 0));
+            let mut proxy_weight = Weight::from_parts(50_000 as u64, 0);
 
             let proof = <T as Config>::ProxyConfig::get_proof(&call)
                 .ok_or(Error::<T>::TransactionNotSupported)?;
             ensure!(relayer == proof.relayer, Error::<T>::UnauthorizedProxyTransaction);
 
             if let Some(payment_info) = payment_info {
-                final_weight = T::WeightInfo::charge_fee()
-                    .saturating_add(call.get_dispatch_info().weight)
-                    .saturating_add(Weight::from_parts(50_000 as u64, 0));
+                proxy_weight = proxy_weight.saturating_add(T::WeightInfo::charge_fee());
 
                 // Always try to charge a fee, regardless of the outcome of execution.
                 // If the payment signature is not valid, the nonce is not incremented and the
                 // transaction is rejected. This allows to keep the payment nonce in
                 // memory when sending multiple transactions back to back.
-                Self::charge_fee(&proof, *payment_info)?;
+                Self::charge_fee(&proof, &call, *payment_info)?;
             }
 
             // No errors are allowed past this point, otherwise we will undo the payment.
@@ -165,13 +282,13 @@ pub mod pallet {
 
                 // Return an OK even if the signature is bad. The `InnerCallFailed` event will
                 // inform the caller about the failure
-                return Ok(Some(final_weight).into())
+                return Ok(Some(proxy_weight.saturating_add(call.get_dispatch_info().weight)).into())
             }
 
             let sender: T::RuntimeOrigin =
                 frame_system::RawOrigin::Signed(proof.signer.clone()).into();
 
-            let dispatch_result = call.dispatch(sender).map(|_| ()).map_err(|e| e.error);
+            let (dispatch_result, inner_call_weight) = Self::dispatch_inner_call(call, sender);
             match dispatch_result {
                 Ok(_) => {
                     Self::deposit_event(Event::<T>::CallDispatched { relayer, hash: call_hash });
@@ -185,15 +302,384 @@ pub mod pallet {
                 },
             }
 
-            Ok(Some(final_weight).into())
+            Ok(Some(proxy_weight.saturating_add(inner_call_weight)).into())
+        }
+
+        /// Dispatches `call` on behalf of the account `ethereum_address` is registered to, so the
+        /// owner of an Ethereum key can use the chain without a Substrate key. The signature must
+        /// be an Ethereum signed message of `encode_ethereum_proxy_params` and only the calls
+        /// allowed by `EthereumCallFilter` can be dispatched.
+        #[pallet::call_index(1)]
+        #[pallet::weight(
+            <T as pallet::Config>::WeightInfo::ethereum_proxy()
+            .saturating_add(call.get_dispatch_info().weight))
+        ]
+        pub fn ethereum_proxy(
+            origin: OriginFor<T>,
+            call: Box<<T as Config>::RuntimeCall>,
+            ethereum_address: H160,
+            signature: ecdsa::Signature,
+        ) -> DispatchResultWithPostInfo {
+            let relayer = ensure_signed(origin)?;
+            ensure!(T::EthereumCallFilter::contains(&call), Error::<T>::CallNotAllowedFromEthereum);
+
+            let nonce = Self::ethereum_nonces(ethereum_address);
+            let signed_data = Self::encode_ethereum_proxy_params(&relayer, &call, nonce);
+            let signer = recover_ethereum_address_from_ecdsa_signature(
+                &signature,
+                &signed_data,
+                HashMessageFormat::String,
+            )
+            .map_err(|_| Error::<T>::UnauthorizedEthereumTransaction)?;
+            ensure!(
+                H160::from(signer) == ethereum_address,
+                Error::<T>::UnauthorizedEthereumTransaction
+            );
+            let account = T::EthereumAddressMapping::account_of(&ethereum_address)
+                .ok_or(Error::<T>::EthereumAddressNotRegistered)?;
+
+            // The signature can't be replayed, even if the call fails
+            <EthereumNonces<T>>::mutate(ethereum_address, |n| *n += 1);
+
+            let call_hash: T::Hash = T::Hashing::hash_of(&call);
+            let sender: T::RuntimeOrigin = frame_system::RawOrigin::Signed(account.clone()).into();
+            let (dispatch_result, inner_call_weight) = Self::dispatch_inner_call(call, sender);
+            match dispatch_result {
+                Ok(_) => {
+                    Self::deposit_event(Event::<T>::EthereumCallDispatched {
+                        relayer,
+                        ethereum_address,
+                        account,
+                        hash: call_hash,
+                    });
+                },
+                Err(dispatch_error) => {
+                    Self::deposit_event(Event::<T>::InnerCallFailed {
+                        relayer,
+                        hash: call_hash,
+                        dispatch_error,
+                    });
+                },
+            }
+
+            Ok(Some(T::WeightInfo::ethereum_proxy().saturating_add(inner_call_weight)).into())
+        }
+
+        /// Sets the relayer fee of the calls of `category` paid in `token`. Without a rule, the
+        /// payer of a call of the category pays the amount it authorised.
+        #[pallet::call_index(2)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::set_relayer_fee_rule())]
+        pub fn set_relayer_fee_rule(
+            origin: OriginFor<T>,
+            category: FeeCategory,
+            token: T::Token,
+            rule: Option<RelayerFeeRule<BalanceOf<T>>>,
+        ) -> DispatchResult {
+            T::AdminOrigin::ensure_origin(origin)?;
+
+            <RelayerFeeSchedule<T>>::set(category, token, rule.clone());
+            Self::deposit_event(Event::<T>::RelayerFeeRuleSet { category, token, rule });
+
+            Ok(())
+        }
+
+        /// Sets the relayer fee paid in `token` the multipliers of the fee schedule apply to
+        #[pallet::call_index(3)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::set_base_relayer_fee())]
+        pub fn set_base_relayer_fee(
+            origin: OriginFor<T>,
+            token: T::Token,
+            base_fee: BalanceOf<T>,
+        ) -> DispatchResult {
+            T::AdminOrigin::ensure_origin(origin)?;
+
+            <BaseRelayerFee<T>>::insert(token, base_fee);
+            Self::deposit_event(Event::<T>::BaseRelayerFeeSet { token, base_fee });
+
+            Ok(())
+        }
+
+        /// Lets `session_key` authorise, until `expiry`, the calls of the `scope` categories on
+        /// behalf of the sender, as long as they don't make the sender spend more than
+        /// `spend_limit` of any asset in total. Registering a session key again replaces it.
+        #[pallet::call_index(4)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::register_session_key())]
+        pub fn register_session_key(
+            origin: OriginFor<T>,
+            session_key: T::AccountId,
+            expiry: BlockNumberFor<T>,
+            scope: Vec<FeeCategory>,
+            spend_limit: BalanceOf<T>,
+        ) -> DispatchResult {
+            let owner = ensure_signed(origin)?;
+
+            let now = <frame_system::Pallet<T>>::block_number();
+            ensure!(
+                expiry > now && expiry <= now.saturating_add(T::MaxSessionKeyDuration::get()),
+                Error::<T>::InvalidSessionKeyExpiry
+            );
+            ensure!(
+                !scope.is_empty() && !scope.contains(&FeeCategory::Other),
+                Error::<T>::InvalidSessionKeyScope
+            );
+            let scope: BoundedVec<FeeCategory, ConstU32<MAX_SESSION_KEY_SCOPE>> =
+                scope.try_into().map_err(|_| Error::<T>::InvalidSessionKeyScope)?;
+
+            <SessionKeys<T>>::insert(
+                &owner,
+                &session_key,
+                SessionKey { expiry, scope, spend_limit, spent: Default::default(), nonce: 0 },
+            );
+            Self::deposit_event(Event::<T>::SessionKeyRegistered { owner, session_key, expiry });
+
+            Ok(())
+        }
+
+        #[pallet::call_index(5)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::revoke_session_key())]
+        pub fn revoke_session_key(
+            origin: OriginFor<T>,
+            session_key: T::AccountId,
+        ) -> DispatchResult {
+            let owner = ensure_signed(origin)?;
+            ensure!(
+                <SessionKeys<T>>::contains_key(&owner, &session_key),
+                Error::<T>::SessionKeyNotFound
+            );
+
+            <SessionKeys<T>>::remove(&owner, &session_key);
+            Self::deposit_event(Event::<T>::SessionKeyRevoked { owner, session_key });
+
+            Ok(())
+        }
+
+        /// Dispatches `call` on behalf of `owner` with the authorisation of one of its session
+        /// keys. The signature must be by `session_key` over `encode_session_key_proxy_params` and
+        /// the call must be within the scope of the session key. A call that would take the
+        /// spending of the session key of any asset over its limit is reverted.
+        #[pallet::call_index(6)]
+        #[pallet::weight(
+            <T as pallet::Config>::WeightInfo::session_key_proxy()
+            .saturating_add(call.get_dispatch_info().weight))
+        ]
+        pub fn session_key_proxy(
+            origin: OriginFor<T>,
+            call: Box<<T as Config>::RuntimeCall>,
+            owner: T::AccountId,
+            session_key: T::AccountId,
+            signature: T::Signature,
+        ) -> DispatchResultWithPostInfo {
+            let relayer = ensure_signed(origin)?;
+
+            let mut key =
+                Self::session_key(&owner, &session_key).ok_or(Error::<T>::SessionKeyNotFound)?;
+            ensure!(
+                <frame_system::Pallet<T>>::block_number() <= key.expiry,
+                Error::<T>::SessionKeyExpired
+            );
+            ensure!(
+                key.scope.contains(&<T as Config>::ProxyConfig::fee_category(&call)),
+                Error::<T>::CallOutsideSessionKeyScope
+            );
+
+            let signed_data =
+                Self::encode_session_key_proxy_params(&relayer, &owner, &call, key.nonce);
+            ensure!(
+                verify_multi_signature::<T::Signature, T::AccountId>(
+                    &session_key,
+                    &signature,
+                    &signed_data
+                )
+                .is_ok(),
+                Error::<T>::UnauthorizedSessionKeyTransaction
+            );
+
+            // The signature can't be replayed, even if the call fails
+            key.nonce += 1;
+
+            let call_hash: T::Hash = T::Hashing::hash_of(&call);
+            let sender: T::RuntimeOrigin = frame_system::RawOrigin::Signed(owner.clone()).into();
+            let declared_spending = <T as Config>::ProxyConfig::spending(&call);
+            let balance_before = T::Currency::free_balance(&owner);
+            let mut inner_call_weight = Weight::zero();
+            let dispatch_result = with_transaction(|| {
+                let (result, weight) = Self::dispatch_inner_call(call, sender);
+                inner_call_weight = weight;
+                if result.is_err() {
+                    return TransactionOutcome::Commit(result)
+                }
+
+                let native_spent = balance_before.saturating_sub(T::Currency::free_balance(&owner));
+                match Self::meter_session_key_spending(&key, declared_spending, native_spent) {
+                    Ok(spent) => {
+                        key.spent = spent;
+                        TransactionOutcome::Commit(Ok(()))
+                    },
+                    Err(error) => TransactionOutcome::Rollback(Err(error)),
+                }
+            });
+            <SessionKeys<T>>::insert(&owner, &session_key, key);
+
+            match dispatch_result {
+                Ok(_) => {
+                    Self::deposit_event(Event::<T>::SessionKeyCallDispatched {
+                        relayer,
+                        owner,
+                        session_key,
+                        hash: call_hash,
+                    });
+                },
+                Err(dispatch_error) => {
+                    Self::deposit_event(Event::<T>::InnerCallFailed {
+                        relayer,
+                        hash: call_hash,
+                        dispatch_error,
+                    });
+                },
+            }
+
+            Ok(Some(T::WeightInfo::session_key_proxy().saturating_add(inner_call_weight)).into())
         }
     }
 }
 
 pub(crate) type BalanceOf<T> =
     <<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+pub type SessionKeyOf<T> = SessionKey<BlockNumberFor<T>, BalanceOf<T>>;
+pub type SessionKeySpendingOf<T> =
+    BoundedVec<(SpendableAsset, BalanceOf<T>), ConstU32<MAX_SESSION_KEY_ASSETS>>;
 
 impl<T: Config> Pallet<T> {
+    /// Checks that `call` would be dispatched if a relayer proxied it with `proof`, so relayers
+    /// don't pay for transactions that will fail. The proof must be the one in the call, its
+    /// signature must be valid for the current nonce of the signer and the call must succeed. The
+    /// call is executed but its changes are always discarded.
+    pub fn validate_proof(
+        call: Box<<T as Config>::RuntimeCall>,
+        proof: Proof<T::Signature, T::AccountId>,
+    ) -> DispatchResult {
+        let call_proof = <T as Config>::ProxyConfig::get_proof(&call)
+            .ok_or(Error::<T>::TransactionNotSupported)?;
+        ensure!(call_proof == proof, Error::<T>::ProofMismatch);
+
+        Self::validate_inner_call_signature(&call)?;
+
+        let sender: T::RuntimeOrigin = frame_system::RawOrigin::Signed(proof.signer).into();
+        with_transaction(|| {
+            let dispatch_result = call.dispatch(sender).map(|_| ()).map_err(|e| e.error);
+            TransactionOutcome::Rollback(dispatch_result)
+        })
+    }
+
+    /// Dispatches `call` from `sender`, returning its result and the weight it actually consumed,
+    /// so the relayer is only charged for the work the inner call did.
+    fn dispatch_inner_call(
+        call: Box<<T as Config>::RuntimeCall>,
+        sender: T::RuntimeOrigin,
+    ) -> (DispatchResult, Weight) {
+        let call_info = call.get_dispatch_info();
+        let result = call.dispatch(sender);
+        let actual_weight = extract_actual_weight(&result, &call_info);
+
+        (result.map(|_| ()).map_err(|e| e.error), actual_weight)
+    }
+
+    /// The token and amount a relayer is paid for proxying `call`, if the call pays one
+    pub fn relayer_fee(call: &<T as Config>::RuntimeCall) -> Option<(T::Token, BalanceOf<T>)> {
+        match call.is_sub_type() {
+            Some(Call::proxy { call: inner_call, payment_info: Some(payment_info) }) => Some((
+                payment_info.token,
+                Self::fee_to_charge(inner_call, &payment_info.token, payment_info.amount),
+            )),
+            _ => None,
+        }
+    }
+
+    /// The relayer fee the fee schedule sets for proxying `call` when paying in `token`, if its
+    /// category has a rule for the token
+    pub fn scheduled_relayer_fee(
+        call: &<T as Config>::RuntimeCall,
+        token: &T::Token,
+    ) -> Option<BalanceOf<T>> {
+        let category = <T as Config>::ProxyConfig::fee_category(call);
+        Self::relayer_fee_rule(category, token).map(|rule| match rule {
+            RelayerFeeRule::Fixed(fee) => fee,
+            RelayerFeeRule::Multiplier(multiplier) =>
+                multiplier.saturating_mul_int(Self::base_relayer_fee(token)),
+        })
+    }
+
+    /// The scheduled fee of `call` in `token`, or the amount the payer authorised if the call has
+    /// none
+    fn fee_to_charge(
+        call: &<T as Config>::RuntimeCall,
+        token: &T::Token,
+        authorised_amount: BalanceOf<T>,
+    ) -> BalanceOf<T> {
+        Self::scheduled_relayer_fee(call, token).unwrap_or(authorised_amount)
+    }
+
+    /// The data an Ethereum key signs to let `relayer` dispatch `call` with `ethereum_proxy`. It
+    /// includes the genesis hash so the signature can't be replayed on another chain.
+    pub fn encode_ethereum_proxy_params(
+        relayer: &T::AccountId,
+        call: &Box<<T as Config>::RuntimeCall>,
+        nonce: u64,
+    ) -> Vec<u8> {
+        let genesis_hash = <frame_system::Pallet<T>>::block_hash(BlockNumberFor::<T>::zero());
+        (ETHEREUM_PROXY_CONTEXT, genesis_hash, relayer, call, nonce).encode()
+    }
+
+    /// The data a session key signs to let `relayer` dispatch `call` on behalf of `owner` with
+    /// `session_key_proxy`
+    pub fn encode_session_key_proxy_params(
+        relayer: &T::AccountId,
+        owner: &T::AccountId,
+        call: &Box<<T as Config>::RuntimeCall>,
+        nonce: u64,
+    ) -> Vec<u8> {
+        (SESSION_KEY_PROXY_CONTEXT, relayer, owner, call, nonce).encode()
+    }
+
+    /// Adds what a call spent to the spending of a session key, per asset. The native currency is
+    /// charged the larger of what the call declares and what left the free balance of the owner,
+    /// so transfers the call makes without declaring them are metered too.
+    fn meter_session_key_spending(
+        key: &SessionKeyOf<T>,
+        declared_spending: Vec<(SpendableAsset, u128)>,
+        native_spent: BalanceOf<T>,
+    ) -> Result<SessionKeySpendingOf<T>, DispatchError> {
+        let mut call_spending: Vec<(SpendableAsset, BalanceOf<T>)> = Vec::new();
+        call_spending.push((SpendableAsset::Native, Zero::zero()));
+        for (asset, amount) in declared_spending {
+            let amount: BalanceOf<T> = amount.saturated_into();
+            match call_spending.iter_mut().find(|(a, _)| *a == asset) {
+                Some((_, total)) => *total = total.saturating_add(amount),
+                None => call_spending.push((asset, amount)),
+            }
+        }
+        // The native currency is always first
+        call_spending[0].1 = call_spending[0].1.max(native_spent);
+
+        let mut spent = key.spent.clone();
+        for (asset, amount) in call_spending.into_iter().filter(|(_, a)| !a.is_zero()) {
+            match spent.iter_mut().find(|(a, _)| *a == asset) {
+                Some((_, total)) => {
+                    *total = total.saturating_add(amount);
+                    ensure!(*total <= key.spend_limit, Error::<T>::SessionKeySpendLimitExceeded);
+                },
+                None => {
+                    ensure!(amount <= key.spend_limit, Error::<T>::SessionKeySpendLimitExceeded);
+                    spent
+                        .try_push((asset, amount))
+                        .map_err(|_| Error::<T>::TooManySessionKeyAssets)?;
+                },
+            }
+        }
+
+        Ok(spent)
+    }
+
     fn validate_inner_call_signature(call: &Box<<T as Config>::RuntimeCall>) -> DispatchResult {
         let inner_call_sig_valid = <T as Config>::ProxyConfig::signature_is_valid(call);
         if inner_call_sig_valid == false {
@@ -228,8 +714,11 @@ impl<T: Config> Pallet<T> {
         }
     }
 
+    /// Charges the payer the relayer fee of `call`. The amount the payer authorised is the most
+    /// it can be charged.
     pub(crate) fn charge_fee(
         proof: &Proof<T::Signature, T::AccountId>,
+        call: &<T as Config>::RuntimeCall,
         payment_info: PaymentInfo<T::AccountId, BalanceOf<T>, T::Signature, T::Token>,
     ) -> DispatchResult {
         let payment_nonce = Self::payment_nonces(&payment_info.payer);
@@ -239,9 +728,12 @@ impl<T: Config> Pallet<T> {
             Error::<T>::UnauthorizedFee
         );
 
+        let fee = Self::fee_to_charge(call, &payment_info.token, payment_info.amount);
+        ensure!(fee <= payment_info.amount, Error::<T>::RelayerFeeAboveAuthorisedAmount);
+
         T::FeeHandler::pay_fee(
             &payment_info.token,
-            &payment_info.amount,
+            &fee,
             &payment_info.payer,
             &payment_info.recipient,
         )?;
@@ -255,6 +747,59 @@ pub trait ProvableProxy<Call, Signature: scale_info::TypeInfo, AccountId>:
     Sized + Send + Sync
 {
     fn get_proof(call: &Call) -> Option<Proof<Signature, AccountId>>;
+
+    /// The category of the relayer fee schedule `call` is charged under
+    fn fee_category(_call: &Call) -> FeeCategory {
+        FeeCategory::Other
+    }
+
+    /// The assets and amounts `call` spends of the account it is dispatched for, including what
+    /// it reserves or locks. The calls a session key authorises are metered with it.
+    fn spending(_call: &Call) -> Vec<(SpendableAsset, u128)> {
+        Vec::new()
+    }
+}
+
+/// An asset the calls authorised by a session key can spend
+#[derive(Encode, Decode, Copy, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+pub enum SpendableAsset {
+    /// The native currency
+    Native,
+    /// A token of the token manager, by its contract address
+    Token(H160),
+}
+
+/// The groups of proxied calls the relayer fee schedule sets a fee for
+#[derive(Encode, Decode, Copy, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+pub enum FeeCategory {
+    Staking,
+    TokenTransfer,
+    Nft,
+    Other,
+}
+
+/// How the relayer fee of a category of calls is set
+#[derive(Encode, Decode, Copy, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+pub enum RelayerFeeRule<Balance> {
+    /// The fee is this amount
+    Fixed(Balance),
+    /// The fee is the base relayer fee times this multiplier
+    Multiplier(FixedU128),
+}
+
+/// A key an account lets authorise some of its calls for a limited time
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+pub struct SessionKey<BlockNumber, Balance> {
+    /// The last block the session key can be used in
+    pub expiry: BlockNumber,
+    /// The categories of calls the session key can authorise
+    pub scope: BoundedVec<FeeCategory, ConstU32<MAX_SESSION_KEY_SCOPE>>,
+    /// The most the calls authorised by the session key can spend of each asset in total
+    pub spend_limit: Balance,
+    /// What the calls authorised by the session key have spent, by asset
+    pub spent: BoundedVec<(SpendableAsset, Balance), ConstU32<MAX_SESSION_KEY_ASSETS>>,
+    /// The number of calls the session key has authorised
+    pub nonce: u64,
 }
 
 #[derive(Encode, Decode, Copy, Clone, PartialEq, Eq, Default, Debug, TypeInfo)]
@@ -278,6 +823,22 @@ pub mod proxy_tests_no_fees;
 #[path = "tests/proxy_tests_with_fees.rs"]
 pub mod proxy_tests_with_fees;
 
+#[cfg(test)]
+#[path = "tests/ethereum_proxy_tests.rs"]
+pub mod ethereum_proxy_tests;
+
+#[cfg(test)]
+#[path = "tests/validate_proof_tests.rs"]
+pub mod validate_proof_tests;
+
+#[cfg(test)]
+#[path = "tests/fee_schedule_tests.rs"]
+pub mod fee_schedule_tests;
+
+#[cfg(test)]
+#[path = "tests/session_key_tests.rs"]
+pub mod session_key_tests;
+
 pub mod default_weights;
 pub use default_weights::WeightInfo;
 
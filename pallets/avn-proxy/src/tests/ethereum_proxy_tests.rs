@@ -0,0 +1,264 @@
+// Copyright 2024 Aventus Network Services (UK) Ltd.
+
+#![cfg(test)]
+use crate::{mock::*, *};
+use frame_support::{assert_noop, assert_ok};
+use sp_avn_common::{
+    hash_string_data_with_ethereum_prefix, recover_ethereum_address_from_ecdsa_signature,
+    HashMessageFormat,
+};
+use sp_core::{Pair, H256};
+
+const AMOUNT: u128 = ONE_AVT;
+
+struct EthereumUser {
+    pair: ecdsa::Pair,
+    account: AccountId,
+}
+
+impl EthereumUser {
+    fn new() -> Self {
+        let account = TestAccount::new([3u8; 32]).account_id();
+        Balances::make_free_balance_be(&account, HUNDRED_AVT);
+        EthereumUser { pair: ecdsa::Pair::from_seed(&[3u8; 32]), account }
+    }
+
+    fn sign(&self, data: &[u8]) -> ecdsa::Signature {
+        let hash = hash_string_data_with_ethereum_prefix(data).expect("Data can be hashed");
+        self.pair.sign_prehashed(&hash)
+    }
+
+    fn address(&self) -> H160 {
+        let data = b"ethereum address";
+        recover_ethereum_address_from_ecdsa_signature(
+            &self.sign(data),
+            data,
+            HashMessageFormat::String,
+        )
+        .map(H160::from)
+        .expect("Signature is valid")
+    }
+
+    fn register(&self) {
+        pallet_avn::EthereumAddressAccounts::<TestRuntime>::insert(self.address(), &self.account);
+    }
+
+    fn authorise(
+        &self,
+        relayer: &AccountId,
+        call: &Box<RuntimeCall>,
+        nonce: u64,
+    ) -> ecdsa::Signature {
+        self.sign(&AvnProxy::encode_ethereum_proxy_params(relayer, call, nonce))
+    }
+}
+
+fn recipient() -> AccountId {
+    TestAccount::new([8u8; 32]).account_id()
+}
+
+fn transfer_call(value: u128) -> Box<RuntimeCall> {
+    Box::new(RuntimeCall::Balances(BalancesCall::transfer_keep_alive { dest: recipient(), value }))
+}
+
+fn relayer() -> AccountId {
+    ProxyContext::default().relayer.account_id()
+}
+
+mod ethereum_proxy {
+    use super::*;
+
+    #[test]
+    fn dispatches_the_call_for_the_registered_account() {
+        let mut ext = ExtBuilder::build_default().with_balances().as_externality();
+        ext.execute_with(|| {
+            let user = EthereumUser::new();
+            user.register();
+            let call = transfer_call(AMOUNT);
+            let signature = user.authorise(&relayer(), &call, 0);
+
+            assert_ok!(AvnProxy::ethereum_proxy(
+                RuntimeOrigin::signed(relayer()),
+                call.clone(),
+                user.address(),
+                signature
+            ));
+
+            assert_eq!(Balances::free_balance(recipient()), AMOUNT);
+            assert_eq!(Balances::free_balance(&user.account), HUNDRED_AVT - AMOUNT);
+            assert_eq!(AvnProxy::ethereum_nonces(user.address()), 1);
+            assert!(System::events().iter().any(|record| record.event ==
+                RuntimeEvent::AvnProxy(Event::<TestRuntime>::EthereumCallDispatched {
+                    relayer: relayer(),
+                    ethereum_address: user.address(),
+                    account: user.account.clone(),
+                    hash: Hashing::hash_of(&call),
+                })));
+        })
+    }
+
+    #[test]
+    fn uses_the_nonce_when_the_call_fails() {
+        let mut ext = ExtBuilder::build_default().with_balances().as_externality();
+        ext.execute_with(|| {
+            let user = EthereumUser::new();
+            user.register();
+            let call = transfer_call(2 * HUNDRED_AVT);
+            let signature = user.authorise(&relayer(), &call, 0);
+
+            assert_ok!(AvnProxy::ethereum_proxy(
+                RuntimeOrigin::signed(relayer()),
+                call.clone(),
+                user.address(),
+                signature
+            ));
+
+            assert_eq!(Balances::free_balance(recipient()), 0);
+            assert_eq!(AvnProxy::ethereum_nonces(user.address()), 1);
+            assert!(inner_call_failed_event_emitted(relayer(), Hashing::hash_of(&call)));
+        })
+    }
+
+    mod fails_when {
+        use super::*;
+
+        #[test]
+        fn call_is_not_allowed() {
+            let mut ext = ExtBuilder::build_default().with_balances().as_externality();
+            ext.execute_with(|| {
+                let user = EthereumUser::new();
+                user.register();
+                user.register();
+                let call = ProxyContext::default().create_valid_inner_call();
+                let signature = user.authorise(&relayer(), &call, 0);
+
+                assert_noop!(
+                    AvnProxy::ethereum_proxy(
+                        RuntimeOrigin::signed(relayer()),
+                        call,
+                        user.address(),
+                        signature
+                    ),
+                    Error::<TestRuntime>::CallNotAllowedFromEthereum
+                );
+            })
+        }
+
+        #[test]
+        fn address_is_not_the_signer() {
+            let mut ext = ExtBuilder::build_default().with_balances().as_externality();
+            ext.execute_with(|| {
+                let user = EthereumUser::new();
+                user.register();
+                user.register();
+                let call = transfer_call(AMOUNT);
+                let signature = user.authorise(&relayer(), &call, 0);
+
+                assert_noop!(
+                    AvnProxy::ethereum_proxy(
+                        RuntimeOrigin::signed(relayer()),
+                        call,
+                        H160::repeat_byte(1),
+                        signature
+                    ),
+                    Error::<TestRuntime>::UnauthorizedEthereumTransaction
+                );
+            })
+        }
+
+        #[test]
+        fn address_is_not_registered() {
+            let mut ext = ExtBuilder::build_default().with_balances().as_externality();
+            ext.execute_with(|| {
+                let user = EthereumUser::new();
+                let call = transfer_call(AMOUNT);
+                let signature = user.authorise(&relayer(), &call, 0);
+
+                assert_noop!(
+                    AvnProxy::ethereum_proxy(
+                        RuntimeOrigin::signed(relayer()),
+                        call,
+                        user.address(),
+                        signature
+                    ),
+                    Error::<TestRuntime>::EthereumAddressNotRegistered
+                );
+            })
+        }
+
+        #[test]
+        fn signature_is_for_another_chain() {
+            let mut ext = ExtBuilder::build_default().with_balances().as_externality();
+            ext.execute_with(|| {
+                let user = EthereumUser::new();
+                user.register();
+                user.register();
+                let call = transfer_call(AMOUNT);
+                let other_genesis_hash = H256::repeat_byte(1);
+                let signature = user.sign(
+                    &(ETHEREUM_PROXY_CONTEXT, other_genesis_hash, relayer(), &call, 0u64).encode(),
+                );
+
+                assert_noop!(
+                    AvnProxy::ethereum_proxy(
+                        RuntimeOrigin::signed(relayer()),
+                        call,
+                        user.address(),
+                        signature
+                    ),
+                    Error::<TestRuntime>::UnauthorizedEthereumTransaction
+                );
+            })
+        }
+
+        #[test]
+        fn signature_is_replayed() {
+            let mut ext = ExtBuilder::build_default().with_balances().as_externality();
+            ext.execute_with(|| {
+                let user = EthereumUser::new();
+                user.register();
+                user.register();
+                let call = transfer_call(AMOUNT);
+                let signature = user.authorise(&relayer(), &call, 0);
+                assert_ok!(AvnProxy::ethereum_proxy(
+                    RuntimeOrigin::signed(relayer()),
+                    call.clone(),
+                    user.address(),
+                    signature.clone()
+                ));
+
+                assert_noop!(
+                    AvnProxy::ethereum_proxy(
+                        RuntimeOrigin::signed(relayer()),
+                        call,
+                        user.address(),
+                        signature
+                    ),
+                    Error::<TestRuntime>::UnauthorizedEthereumTransaction
+                );
+            })
+        }
+
+        #[test]
+        fn relayer_is_not_the_authorised_one() {
+            let mut ext = ExtBuilder::build_default().with_balances().as_externality();
+            ext.execute_with(|| {
+                let user = EthereumUser::new();
+                user.register();
+                user.register();
+                let call = transfer_call(AMOUNT);
+                let signature = user.authorise(&relayer(), &call, 0);
+
+                assert_noop!(
+                    AvnProxy::ethereum_proxy(
+                        RuntimeOrigin::signed(user.account.clone()),
+                        call,
+                        user.address(),
+                        signature
+                    ),
+                    Error::<TestRuntime>::UnauthorizedEthereumTransaction
+                );
+            })
+        }
+    }
+}
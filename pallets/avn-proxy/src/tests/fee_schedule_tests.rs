@@ -0,0 +1,231 @@
+// Copyright 2024 Aventus Network Services (UK) Ltd.
+
+#![cfg(test)]
+use crate::{mock::*, proxy_tests_with_fees::create_default_payment_authorisation, *};
+use frame_support::{assert_noop, assert_ok, error::BadOrigin};
+use sp_runtime::FixedU128;
+
+fn mint_call_and_payment(
+    context: &ProxyContext,
+) -> (Box<RuntimeCall>, PaymentInfo<AccountId, u128, Signature, Token>) {
+    let single_nft_data: SingleNftContext = Default::default();
+    let proof = get_mint_single_nft_proxy_proof(context, &single_nft_data);
+    let payment = create_default_payment_authorisation(context, proof.clone());
+    (get_signed_mint_single_nft_call(&single_nft_data, &proof), payment)
+}
+
+fn set_nft_fee_rule(rule: RelayerFeeRule<u128>) {
+    assert_ok!(AvnProxy::set_relayer_fee_rule(
+        RuntimeOrigin::root(),
+        FeeCategory::Nft,
+        AVT_TOKEN_CONTRACT,
+        Some(rule)
+    ));
+}
+
+fn proxy_with_payment(context: &ProxyContext) -> DispatchResultWithPostInfo {
+    let (call, payment) = mint_call_and_payment(context);
+    AvnProxy::proxy(
+        RuntimeOrigin::signed(context.relayer.account_id()),
+        call,
+        Some(Box::new(payment)),
+    )
+}
+
+mod set_relayer_fee_rule {
+    use super::*;
+
+    #[test]
+    fn sets_and_clears_the_rule_of_a_category() {
+        let mut ext = ExtBuilder::build_default().as_externality();
+        ext.execute_with(|| {
+            let rule = RelayerFeeRule::Fixed(ONE_AVT / 2);
+
+            set_nft_fee_rule(rule);
+            assert_eq!(
+                AvnProxy::relayer_fee_rule(FeeCategory::Nft, AVT_TOKEN_CONTRACT),
+                Some(rule)
+            );
+            assert_eq!(AvnProxy::relayer_fee_rule(FeeCategory::Nft, NON_AVT_TOKEN_CONTRACT), None);
+            assert!(System::events().iter().any(|e| e.event ==
+                RuntimeEvent::AvnProxy(Event::<TestRuntime>::RelayerFeeRuleSet {
+                    category: FeeCategory::Nft,
+                    token: AVT_TOKEN_CONTRACT,
+                    rule: Some(rule),
+                })));
+
+            assert_ok!(AvnProxy::set_relayer_fee_rule(
+                RuntimeOrigin::root(),
+                FeeCategory::Nft,
+                AVT_TOKEN_CONTRACT,
+                None
+            ));
+            assert_eq!(AvnProxy::relayer_fee_rule(FeeCategory::Nft, AVT_TOKEN_CONTRACT), None);
+        })
+    }
+
+    #[test]
+    fn fails_when_the_origin_is_not_the_admin() {
+        let mut ext = ExtBuilder::build_default().as_externality();
+        ext.execute_with(|| {
+            let context: ProxyContext = Default::default();
+
+            assert_noop!(
+                AvnProxy::set_relayer_fee_rule(
+                    RuntimeOrigin::signed(context.relayer.account_id()),
+                    FeeCategory::Nft,
+                    AVT_TOKEN_CONTRACT,
+                    Some(RelayerFeeRule::Fixed(1))
+                ),
+                BadOrigin
+            );
+            assert_noop!(
+                AvnProxy::set_base_relayer_fee(
+                    RuntimeOrigin::signed(context.relayer.account_id()),
+                    AVT_TOKEN_CONTRACT,
+                    1
+                ),
+                BadOrigin
+            );
+        })
+    }
+}
+
+mod scheduled_relayer_fee {
+    use super::*;
+
+    #[test]
+    fn is_the_fixed_fee_of_the_category() {
+        let mut ext = ExtBuilder::build_default().as_externality();
+        ext.execute_with(|| {
+            let context: ProxyContext = Default::default();
+            let (call, _) = mint_call_and_payment(&context);
+            set_nft_fee_rule(RelayerFeeRule::Fixed(ONE_AVT / 2));
+
+            assert_eq!(
+                AvnProxy::scheduled_relayer_fee(&call, &AVT_TOKEN_CONTRACT),
+                Some(ONE_AVT / 2)
+            );
+            assert_eq!(
+                AvnProxy::scheduled_relayer_fee(
+                    &context.create_valid_inner_call(),
+                    &AVT_TOKEN_CONTRACT
+                ),
+                None
+            );
+        })
+    }
+
+    #[test]
+    fn is_none_when_paying_in_a_token_without_a_rule() {
+        let mut ext = ExtBuilder::build_default().as_externality();
+        ext.execute_with(|| {
+            let context: ProxyContext = Default::default();
+            let (call, _) = mint_call_and_payment(&context);
+            set_nft_fee_rule(RelayerFeeRule::Fixed(ONE_AVT / 2));
+
+            assert_eq!(AvnProxy::scheduled_relayer_fee(&call, &NON_AVT_TOKEN_CONTRACT), None);
+        })
+    }
+
+    #[test]
+    fn is_the_base_fee_times_the_multiplier_of_the_category() {
+        let mut ext = ExtBuilder::build_default().as_externality();
+        ext.execute_with(|| {
+            let context: ProxyContext = Default::default();
+            let (call, _) = mint_call_and_payment(&context);
+            assert_ok!(AvnProxy::set_base_relayer_fee(
+                RuntimeOrigin::root(),
+                AVT_TOKEN_CONTRACT,
+                400
+            ));
+            assert_ok!(AvnProxy::set_base_relayer_fee(
+                RuntimeOrigin::root(),
+                NON_AVT_TOKEN_CONTRACT,
+                800
+            ));
+            set_nft_fee_rule(RelayerFeeRule::Multiplier(FixedU128::from_rational(3, 4)));
+
+            assert_eq!(AvnProxy::scheduled_relayer_fee(&call, &AVT_TOKEN_CONTRACT), Some(300));
+        })
+    }
+}
+
+mod proxy {
+    use super::*;
+
+    #[test]
+    fn charges_the_scheduled_fee_to_the_payer() {
+        let mut ext = ExtBuilder::build_default().with_balances().as_externality();
+        ext.execute_with(|| {
+            let context: ProxyContext = Default::default();
+            let signer_balance = Balances::free_balance(context.signer.account_id());
+            let relayer_balance = Balances::free_balance(context.relayer.account_id());
+            set_nft_fee_rule(RelayerFeeRule::Fixed(ONE_AVT / 4));
+
+            assert_ok!(proxy_with_payment(&context));
+
+            assert_eq!(
+                Balances::free_balance(context.signer.account_id()),
+                signer_balance - ONE_AVT / 4
+            );
+            assert_eq!(
+                Balances::free_balance(context.relayer.account_id()),
+                relayer_balance + ONE_AVT / 4
+            );
+            assert!(single_nft_minted_events_emitted());
+        })
+    }
+
+    #[test]
+    fn charges_the_authorised_amount_without_a_rule() {
+        let mut ext = ExtBuilder::build_default().with_balances().as_externality();
+        ext.execute_with(|| {
+            let context: ProxyContext = Default::default();
+            let signer_balance = Balances::free_balance(context.signer.account_id());
+            assert_ok!(AvnProxy::set_relayer_fee_rule(
+                RuntimeOrigin::root(),
+                FeeCategory::TokenTransfer,
+                AVT_TOKEN_CONTRACT,
+                Some(RelayerFeeRule::Fixed(ONE_AVT / 4))
+            ));
+
+            assert_ok!(proxy_with_payment(&context));
+
+            assert_eq!(
+                Balances::free_balance(context.signer.account_id()),
+                signer_balance - ONE_AVT
+            );
+        })
+    }
+
+    #[test]
+    fn fails_when_the_scheduled_fee_is_above_the_authorised_amount() {
+        let mut ext = ExtBuilder::build_default().with_balances().as_externality();
+        ext.execute_with(|| {
+            let context: ProxyContext = Default::default();
+            set_nft_fee_rule(RelayerFeeRule::Fixed(2 * ONE_AVT));
+
+            assert_noop!(
+                proxy_with_payment(&context),
+                Error::<TestRuntime>::RelayerFeeAboveAuthorisedAmount
+            );
+        })
+    }
+
+    #[test]
+    fn reports_the_scheduled_fee_as_the_relayer_fee() {
+        let mut ext = ExtBuilder::build_default().as_externality();
+        ext.execute_with(|| {
+            let context: ProxyContext = Default::default();
+            let (call, payment) = mint_call_and_payment(&context);
+            set_nft_fee_rule(RelayerFeeRule::Fixed(ONE_AVT / 4));
+            let proxy_call = RuntimeCall::AvnProxy(AvnProxyCall::proxy {
+                call,
+                payment_info: Some(Box::new(payment)),
+            });
+
+            assert_eq!(AvnProxy::relayer_fee(&proxy_call), Some((AVT_TOKEN_CONTRACT, ONE_AVT / 4)));
+        })
+    }
+}
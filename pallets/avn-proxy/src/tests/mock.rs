@@ -5,7 +5,7 @@ use codec::Decode;
 use frame_support::{
     pallet_prelude::{DispatchClass, Weight},
     parameter_types,
-    traits::EqualPrivilegeOnly,
+    traits::{EqualPrivilegeOnly, WithdrawReasons},
     PalletId,
 };
 use frame_system::{self as system, limits::BlockWeights, EnsureRoot};
@@ -14,7 +14,7 @@ use pallet_avn::BridgeInterfaceNotification;
 use pallet_balances;
 use pallet_nft_manager::nft_data::Royalty;
 use pallet_session as session;
-use sp_core::{blake2_256, ecdsa, sr25519, ConstU32, ConstU64, Pair, H160, H256};
+use sp_core::{blake2_256, ecdsa, sr25519, ConstU128, ConstU32, ConstU64, Pair, H160, H256};
 use sp_keystore::{testing::MemoryKeystore, KeystoreExt};
 use sp_runtime::{
     testing::{TestXt, UintAuthorityId},
@@ -62,8 +62,9 @@ frame_support::construct_runtime!(
         Balances: pallet_balances::{Pallet, Call, Storage, Event<T>},
         NftManager: pallet_nft_manager::{Pallet, Call, Storage, Event<T>},
         AvnProxy: avn_proxy::{Pallet, Call, Storage, Event<T>},
-        AVN: pallet_avn::{Pallet, Storage, Event, Config<T>},
+        AVN: pallet_avn::{Pallet, Storage, Event<T>, Config<T>},
         TokenManager: pallet_token_manager::{Pallet, Call, Storage, Event<T>},
+        Vesting: pallet_vesting::{Pallet, Call, Storage, Event<T>},
         Scheduler: pallet_scheduler::{Pallet, Call, Storage, Event<T>},
         EthBridge: pallet_eth_bridge::{Pallet, Call, Storage, Event<T>},
         Timestamp: pallet_timestamp::{Pallet, Call, Storage, Inherent},
@@ -81,6 +82,10 @@ impl Config for TestRuntime {
     type WeightInfo = ();
     type FeeHandler = TokenManager;
     type Token = H160;
+    type EthereumAddressMapping = AVN;
+    type EthereumCallFilter = TestEthereumCallFilter;
+    type AdminOrigin = EnsureRoot<AccountId>;
+    type MaxSessionKeyDuration = ConstU64<100>;
 }
 
 pub type AvnProxyCall = super::Call<TestRuntime>;
@@ -153,6 +158,14 @@ impl pallet_nft_manager::Config for TestRuntime {
     type Signature = Signature;
     type WeightInfo = ();
     type BatchBound = ConstU32<10>;
+    type Currency = Balances;
+    type EthereumAddressMapping = AVN;
+    type MinAuctionDuration = ConstU64<1>;
+    type MaxAuctionDuration = ConstU64<100>;
+    type MaxAuctionsEndingPerBlock = ConstU32<10>;
+    type BridgeInterface = EthBridge;
+    type NftContractChecker = ();
+    type AdminOrigin = frame_system::EnsureRoot<AccountId>;
 }
 
 impl pallet_avn::Config for TestRuntime {
@@ -186,6 +199,23 @@ impl pallet_token_manager::Config for TestRuntime {
     type Preimages = ();
     type PalletsOrigin = OriginCaller;
     type BridgeInterface = EthBridge;
+    type Vesting = Vesting;
+    type LiftDestinations = ();
+}
+
+parameter_types! {
+    pub UnvestedFundsAllowedWithdrawReasons: WithdrawReasons =
+        WithdrawReasons::except(WithdrawReasons::TRANSFER | WithdrawReasons::RESERVE);
+}
+
+impl pallet_vesting::Config for TestRuntime {
+    type RuntimeEvent = RuntimeEvent;
+    type Currency = Balances;
+    type BlockNumberToBalance = ConvertInto;
+    type MinVestedTransfer = ConstU128<1>;
+    type WeightInfo = ();
+    type UnvestedFundsAllowedWithdrawReasons = UnvestedFundsAllowedWithdrawReasons;
+    const MAX_VESTING_SCHEDULES: u32 = 3;
 }
 
 parameter_types! {
@@ -233,6 +263,7 @@ impl pallet_eth_bridge::Config for TestRuntime {
     type ReportCorroborationOffence = ();
     type ProcessedEventsChecker = ();
     type EthereumEventsFilter = ();
+    type AdminOrigin = frame_system::EnsureRoot<Self::AccountId>;
 }
 
 impl pallet_timestamp::Config for TestRuntime {
@@ -292,6 +323,30 @@ impl ProvableProxy<RuntimeCall, Signature, AccountId> for TestAvnProxyConfig {
             _ => None,
         }
     }
+
+    fn fee_category(call: &RuntimeCall) -> FeeCategory {
+        match call {
+            RuntimeCall::NftManager(..) => FeeCategory::Nft,
+            RuntimeCall::TokenManager(..) | RuntimeCall::Balances(..) => FeeCategory::TokenTransfer,
+            _ => FeeCategory::Other,
+        }
+    }
+
+    fn spending(call: &RuntimeCall) -> Vec<(SpendableAsset, u128)> {
+        match call {
+            RuntimeCall::TokenManager(pallet_token_manager::Call::signed_transfer {
+                token_id,
+                amount,
+                ..
+            }) if *token_id == AVT_TOKEN_CONTRACT => vec![(SpendableAsset::Native, *amount)],
+            RuntimeCall::TokenManager(pallet_token_manager::Call::signed_transfer {
+                token_id,
+                amount,
+                ..
+            }) => vec![(SpendableAsset::Token(*token_id), *amount)],
+            _ => Vec::new(),
+        }
+    }
 }
 
 impl InnerCallValidator for TestAvnProxyConfig {
@@ -309,6 +364,20 @@ impl InnerCallValidator for TestAvnProxyConfig {
     }
 }
 
+// Only balance transfers can be authorised with an Ethereum signature
+pub struct TestEthereumCallFilter;
+impl Contains<RuntimeCall> for TestEthereumCallFilter {
+    fn contains(call: &RuntimeCall) -> bool {
+        matches!(
+            call,
+            RuntimeCall::Balances(
+                BalancesCall::transfer_allow_death { .. } |
+                    BalancesCall::transfer_keep_alive { .. }
+            )
+        )
+    }
+}
+
 // ==============================================================================================
 
 pub struct ExtBuilder {
@@ -558,6 +627,7 @@ pub fn get_mint_single_nft_proxy_proof(
     data: &SingleNftContext,
 ) -> Proof<Signature, AccountId> {
     let data_to_sign = (
+        pallet_nft_manager::SIGNING_DOMAIN,
         SIGNED_MINT_SINGLE_NFT_CONTEXT,
         context.relayer.account_id(),
         &data.unique_external_ref,
@@ -581,6 +651,7 @@ pub fn get_mint_single_nft_proxy_proof_ecdsa(
     data: &SingleNftContext,
 ) -> Proof<Signature, AccountId> {
     let data_to_sign = (
+        pallet_nft_manager::SIGNING_DOMAIN,
         SIGNED_MINT_SINGLE_NFT_CONTEXT,
         context.relayer.account_id(),
         &data.unique_external_ref,
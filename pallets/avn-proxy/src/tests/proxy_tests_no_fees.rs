@@ -150,6 +150,34 @@ mod proxy_without_fees {
                 );
             })
         }
+
+        #[test]
+        fn relayer_is_charged_the_actual_weight_of_the_inner_call() {
+            let mut ext = ExtBuilder::build_default().as_externality();
+            ext.execute_with(|| {
+                let context: ProxyContext = Default::default();
+                let inner_call = create_signed_mint_single_nft_call(&context);
+                let declared_weight =
+                    AvnProxyCall::proxy { call: inner_call.clone(), payment_info: None }
+                        .get_dispatch_info()
+                        .weight;
+
+                let post_info = AvnProxy::proxy(
+                    RuntimeOrigin::signed(context.relayer.account_id()),
+                    inner_call,
+                    None,
+                )
+                .expect("Proxy call succeeds");
+
+                // The inner call is declared with the maximum number of royalties but mints
+                // without any
+                let expected_weight = Weight::from_parts(50_000 as u64, 0).saturating_add(
+                    <() as pallet_nft_manager::WeightInfo>::signed_mint_single_nft(0),
+                );
+                assert_eq!(post_info.actual_weight, Some(expected_weight));
+                assert!(expected_weight.ref_time() < declared_weight.ref_time());
+            })
+        }
     }
 
     mod fails_when {
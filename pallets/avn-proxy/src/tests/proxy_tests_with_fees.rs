@@ -526,6 +526,40 @@ mod charging_fees {
     }
 }
 
+mod relayer_fee {
+    use super::*;
+
+    #[test]
+    fn is_the_authorised_payment_of_a_proxied_call() {
+        let mut ext = ExtBuilder::build_default().as_externality();
+        ext.execute_with(|| {
+            let context: ProxyContext = Default::default();
+            let single_nft_data: SingleNftContext = Default::default();
+            let proxy_proof = get_mint_single_nft_proxy_proof(&context, &single_nft_data);
+            let call = RuntimeCall::AvnProxy(AvnProxyCall::proxy {
+                call: get_signed_mint_single_nft_call(&single_nft_data, &proxy_proof),
+                payment_info: Some(Box::new(create_default_payment_authorisation(
+                    &context,
+                    proxy_proof,
+                ))),
+            });
+
+            assert_eq!(AvnProxy::relayer_fee(&call), Some((AVT_TOKEN_CONTRACT, GATEWAY_FEE)));
+        })
+    }
+
+    #[test]
+    fn is_none_without_a_payment() {
+        let mut ext = ExtBuilder::build_default().as_externality();
+        ext.execute_with(|| {
+            let context: ProxyContext = Default::default();
+
+            assert_eq!(AvnProxy::relayer_fee(&context.create_proxy_call()), None);
+            assert_eq!(AvnProxy::relayer_fee(&context.create_valid_inner_call()), None);
+        })
+    }
+}
+
 mod for_token_transfer_extrinsics {
     use super::*;
 
@@ -569,6 +603,7 @@ mod for_token_transfer_extrinsics {
     ) -> Proof<Signature, AccountId> {
         let nonce = 0_u64;
         let data_to_sign = (
+            pallet_token_manager::SIGNING_DOMAIN,
             SIGNED_TRANSFER_CONTEXT,
             context.relayer.account_id(),
             &data.from,
@@ -0,0 +1,324 @@
+// Copyright 2024 Aventus Network Services (UK) Ltd.
+
+#![cfg(test)]
+use crate::{mock::*, *};
+use frame_support::{assert_noop, assert_ok, traits::Get};
+
+const SPEND_LIMIT: u128 = 2 * ONE_AVT;
+
+fn owner() -> AccountId {
+    ProxyContext::default().signer.account_id()
+}
+
+fn relayer() -> AccountId {
+    ProxyContext::default().relayer.account_id()
+}
+
+fn session_key() -> TestAccount {
+    TestAccount::new([5u8; 32])
+}
+
+fn recipient() -> AccountId {
+    TestAccount::new([8u8; 32]).account_id()
+}
+
+fn transfer_call(value: u128) -> Box<RuntimeCall> {
+    Box::new(RuntimeCall::Balances(BalancesCall::transfer_keep_alive { dest: recipient(), value }))
+}
+
+// A transfer of a token other than AVT, which doesn't change the native balance of the owner
+fn token_transfer_call(amount: u128) -> Box<RuntimeCall> {
+    let nonce = TokenManager::nonce(owner());
+    let signed_data = (
+        pallet_token_manager::SIGNING_DOMAIN,
+        pallet_token_manager::SIGNED_TRANSFER_CONTEXT,
+        relayer(),
+        owner(),
+        recipient(),
+        NON_AVT_TOKEN_CONTRACT,
+        amount,
+        nonce,
+    );
+    let proof = Proof {
+        signer: owner(),
+        relayer: relayer(),
+        signature: ProxyContext::default().signer.sign(&signed_data.encode()),
+    };
+    Box::new(RuntimeCall::TokenManager(pallet_token_manager::Call::signed_transfer {
+        proof,
+        from: owner(),
+        to: recipient(),
+        token_id: NON_AVT_TOKEN_CONTRACT,
+        amount,
+    }))
+}
+
+fn register_session_key(scope: Vec<FeeCategory>) {
+    assert_ok!(AvnProxy::register_session_key(
+        RuntimeOrigin::signed(owner()),
+        session_key().account_id(),
+        System::block_number() + 10,
+        scope,
+        SPEND_LIMIT,
+    ));
+}
+
+fn session_key_proxy(call: Box<RuntimeCall>) -> DispatchResultWithPostInfo {
+    let nonce = AvnProxy::session_key(owner(), session_key().account_id()).unwrap().nonce;
+    let signature = session_key().sign(&AvnProxy::encode_session_key_proxy_params(
+        &relayer(),
+        &owner(),
+        &call,
+        nonce,
+    ));
+    AvnProxy::session_key_proxy(
+        RuntimeOrigin::signed(relayer()),
+        call,
+        owner(),
+        session_key().account_id(),
+        signature,
+    )
+}
+
+fn inner_call_failed_with(error: Error<TestRuntime>) -> bool {
+    let error: DispatchError = error.into();
+    System::events().iter().any(|e| {
+        matches!(
+            &e.event,
+            RuntimeEvent::AvnProxy(Event::<TestRuntime>::InnerCallFailed { dispatch_error, .. })
+                if *dispatch_error == error
+        )
+    })
+}
+
+mod register_session_key {
+    use super::*;
+
+    #[test]
+    fn stores_the_session_key() {
+        let mut ext = ExtBuilder::build_default().as_externality();
+        ext.execute_with(|| {
+            register_session_key(vec![FeeCategory::TokenTransfer]);
+
+            let key = AvnProxy::session_key(owner(), session_key().account_id()).unwrap();
+            assert_eq!(key.expiry, System::block_number() + 10);
+            assert_eq!(key.scope.into_inner(), vec![FeeCategory::TokenTransfer]);
+            assert_eq!(key.spend_limit, SPEND_LIMIT);
+            assert_eq!(key.nonce, 0);
+        })
+    }
+
+    mod fails_when {
+        use super::*;
+
+        #[test]
+        fn the_expiry_is_too_far() {
+            let mut ext = ExtBuilder::build_default().as_externality();
+            ext.execute_with(|| {
+                let max_duration: u64 = <TestRuntime as Config>::MaxSessionKeyDuration::get();
+
+                assert_noop!(
+                    AvnProxy::register_session_key(
+                        RuntimeOrigin::signed(owner()),
+                        session_key().account_id(),
+                        System::block_number() + max_duration + 1,
+                        vec![FeeCategory::TokenTransfer],
+                        SPEND_LIMIT,
+                    ),
+                    Error::<TestRuntime>::InvalidSessionKeyExpiry
+                );
+            })
+        }
+
+        #[test]
+        fn the_scope_includes_other_calls() {
+            let mut ext = ExtBuilder::build_default().as_externality();
+            ext.execute_with(|| {
+                assert_noop!(
+                    AvnProxy::register_session_key(
+                        RuntimeOrigin::signed(owner()),
+                        session_key().account_id(),
+                        System::block_number() + 10,
+                        vec![FeeCategory::Nft, FeeCategory::Other],
+                        SPEND_LIMIT,
+                    ),
+                    Error::<TestRuntime>::InvalidSessionKeyScope
+                );
+            })
+        }
+    }
+}
+
+mod revoke_session_key {
+    use super::*;
+
+    #[test]
+    fn the_session_key_can_no_longer_be_used() {
+        let mut ext = ExtBuilder::build_default().with_balances().as_externality();
+        ext.execute_with(|| {
+            register_session_key(vec![FeeCategory::TokenTransfer]);
+            let call = transfer_call(ONE_AVT);
+            let signature = session_key().sign(&AvnProxy::encode_session_key_proxy_params(
+                &relayer(),
+                &owner(),
+                &call,
+                0,
+            ));
+
+            assert_ok!(AvnProxy::revoke_session_key(
+                RuntimeOrigin::signed(owner()),
+                session_key().account_id()
+            ));
+
+            assert_noop!(
+                AvnProxy::session_key_proxy(
+                    RuntimeOrigin::signed(relayer()),
+                    call,
+                    owner(),
+                    session_key().account_id(),
+                    signature,
+                ),
+                Error::<TestRuntime>::SessionKeyNotFound
+            );
+        })
+    }
+}
+
+mod session_key_proxy {
+    use super::*;
+
+    #[test]
+    fn dispatches_the_call_for_the_owner() {
+        let mut ext = ExtBuilder::build_default().with_balances().as_externality();
+        ext.execute_with(|| {
+            register_session_key(vec![FeeCategory::TokenTransfer]);
+            let owner_balance = Balances::free_balance(owner());
+
+            assert_ok!(session_key_proxy(transfer_call(ONE_AVT)));
+
+            assert_eq!(Balances::free_balance(owner()), owner_balance - ONE_AVT);
+            assert_eq!(Balances::free_balance(recipient()), ONE_AVT);
+            let key = AvnProxy::session_key(owner(), session_key().account_id()).unwrap();
+            assert_eq!(key.spent.into_inner(), vec![(SpendableAsset::Native, ONE_AVT)]);
+            assert_eq!(key.nonce, 1);
+        })
+    }
+
+    #[test]
+    fn reverts_a_call_over_the_spend_limit() {
+        let mut ext = ExtBuilder::build_default().with_balances().as_externality();
+        ext.execute_with(|| {
+            register_session_key(vec![FeeCategory::TokenTransfer]);
+            assert_ok!(session_key_proxy(transfer_call(ONE_AVT)));
+            let owner_balance = Balances::free_balance(owner());
+
+            assert_ok!(session_key_proxy(transfer_call(2 * ONE_AVT)));
+
+            assert_eq!(Balances::free_balance(owner()), owner_balance);
+            assert!(inner_call_failed_with(Error::<TestRuntime>::SessionKeySpendLimitExceeded));
+            let key = AvnProxy::session_key(owner(), session_key().account_id()).unwrap();
+            assert_eq!(key.spent.into_inner(), vec![(SpendableAsset::Native, ONE_AVT)]);
+            assert_eq!(key.nonce, 2);
+        })
+    }
+
+    #[test]
+    fn meters_the_spending_of_other_tokens() {
+        let mut ext = ExtBuilder::build_default().with_balances().as_externality();
+        ext.execute_with(|| {
+            register_session_key(vec![FeeCategory::TokenTransfer]);
+
+            assert_ok!(session_key_proxy(token_transfer_call(ONE_AVT)));
+
+            assert_eq!(TokenManager::balance((NON_AVT_TOKEN_CONTRACT, recipient())), ONE_AVT);
+            let key = AvnProxy::session_key(owner(), session_key().account_id()).unwrap();
+            assert_eq!(
+                key.spent.into_inner(),
+                vec![(SpendableAsset::Token(NON_AVT_TOKEN_CONTRACT), ONE_AVT)]
+            );
+        })
+    }
+
+    #[test]
+    fn reverts_a_transfer_of_other_tokens_over_the_spend_limit() {
+        let mut ext = ExtBuilder::build_default().with_balances().as_externality();
+        ext.execute_with(|| {
+            register_session_key(vec![FeeCategory::TokenTransfer]);
+            assert_ok!(session_key_proxy(token_transfer_call(ONE_AVT)));
+
+            assert_ok!(session_key_proxy(token_transfer_call(2 * ONE_AVT)));
+
+            assert_eq!(TokenManager::balance((NON_AVT_TOKEN_CONTRACT, recipient())), ONE_AVT);
+            assert!(inner_call_failed_with(Error::<TestRuntime>::SessionKeySpendLimitExceeded));
+            let key = AvnProxy::session_key(owner(), session_key().account_id()).unwrap();
+            assert_eq!(
+                key.spent.into_inner(),
+                vec![(SpendableAsset::Token(NON_AVT_TOKEN_CONTRACT), ONE_AVT)]
+            );
+        })
+    }
+
+    mod fails_when {
+        use super::*;
+
+        #[test]
+        fn the_call_is_outside_the_scope() {
+            let mut ext = ExtBuilder::build_default().with_balances().as_externality();
+            ext.execute_with(|| {
+                register_session_key(vec![FeeCategory::Nft]);
+
+                assert_noop!(
+                    session_key_proxy(transfer_call(ONE_AVT)),
+                    Error::<TestRuntime>::CallOutsideSessionKeyScope
+                );
+            })
+        }
+
+        #[test]
+        fn the_session_key_expired() {
+            let mut ext = ExtBuilder::build_default().with_balances().as_externality();
+            ext.execute_with(|| {
+                register_session_key(vec![FeeCategory::TokenTransfer]);
+                System::set_block_number(System::block_number() + 11);
+
+                assert_noop!(
+                    session_key_proxy(transfer_call(ONE_AVT)),
+                    Error::<TestRuntime>::SessionKeyExpired
+                );
+            })
+        }
+
+        #[test]
+        fn the_signature_is_replayed() {
+            let mut ext = ExtBuilder::build_default().with_balances().as_externality();
+            ext.execute_with(|| {
+                register_session_key(vec![FeeCategory::TokenTransfer]);
+                let call = transfer_call(ONE_AVT / 2);
+                let signature = session_key().sign(&AvnProxy::encode_session_key_proxy_params(
+                    &relayer(),
+                    &owner(),
+                    &call,
+                    0,
+                ));
+                assert_ok!(AvnProxy::session_key_proxy(
+                    RuntimeOrigin::signed(relayer()),
+                    call.clone(),
+                    owner(),
+                    session_key().account_id(),
+                    signature.clone(),
+                ));
+
+                assert_noop!(
+                    AvnProxy::session_key_proxy(
+                        RuntimeOrigin::signed(relayer()),
+                        call,
+                        owner(),
+                        session_key().account_id(),
+                        signature,
+                    ),
+                    Error::<TestRuntime>::UnauthorizedSessionKeyTransaction
+                );
+            })
+        }
+    }
+}
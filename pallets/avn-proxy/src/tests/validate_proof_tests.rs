@@ -0,0 +1,98 @@
+// Copyright 2024 Aventus Network Services (UK) Ltd.
+
+#![cfg(test)]
+use crate::{mock::*, *};
+use frame_support::{assert_noop, assert_ok};
+
+fn mint_call_and_proof(context: &ProxyContext) -> (Box<RuntimeCall>, Proof<Signature, AccountId>) {
+    let single_nft_data: SingleNftContext = Default::default();
+    let proof = get_mint_single_nft_proxy_proof(context, &single_nft_data);
+    (get_signed_mint_single_nft_call(&single_nft_data, &proof), proof)
+}
+
+mod validate_proof {
+    use super::*;
+
+    #[test]
+    fn succeeds_without_dispatching_the_call() {
+        let mut ext = ExtBuilder::build_default().as_externality();
+        ext.execute_with(|| {
+            let context: ProxyContext = Default::default();
+            let (call, proof) = mint_call_and_proof(&context);
+
+            assert_ok!(AvnProxy::validate_proof(call, proof));
+
+            assert_eq!(false, single_nft_minted_events_emitted());
+            assert!(System::events().is_empty());
+        })
+    }
+
+    mod fails_when {
+        use super::*;
+
+        #[test]
+        fn call_is_not_proxyable() {
+            let mut ext = ExtBuilder::build_default().as_externality();
+            ext.execute_with(|| {
+                let context: ProxyContext = Default::default();
+
+                assert_noop!(
+                    AvnProxy::validate_proof(
+                        context.create_invalid_inner_call(),
+                        context.get_proof()
+                    ),
+                    Error::<TestRuntime>::TransactionNotSupported
+                );
+            })
+        }
+
+        #[test]
+        fn proof_is_not_the_one_of_the_call() {
+            let mut ext = ExtBuilder::build_default().as_externality();
+            ext.execute_with(|| {
+                let context: ProxyContext = Default::default();
+                let (call, _) = mint_call_and_proof(&context);
+
+                assert_noop!(
+                    AvnProxy::validate_proof(call, context.get_proof()),
+                    Error::<TestRuntime>::ProofMismatch
+                );
+            })
+        }
+
+        #[test]
+        fn signature_is_invalid() {
+            let mut ext = ExtBuilder::build_default().as_externality();
+            ext.execute_with(|| {
+                let context: ProxyContext = Default::default();
+                let single_nft_data: SingleNftContext = Default::default();
+                let bad_proof = context.get_proof();
+                let call = get_signed_mint_single_nft_call(&single_nft_data, &bad_proof);
+
+                assert_noop!(
+                    AvnProxy::validate_proof(call, bad_proof),
+                    Error::<TestRuntime>::UnauthorizedProxyTransaction
+                );
+            })
+        }
+
+        #[test]
+        fn call_would_fail() {
+            let mut ext = ExtBuilder::build_default().as_externality();
+            ext.execute_with(|| {
+                let context: ProxyContext = Default::default();
+                assert_ok!(AvnProxy::proxy(
+                    RuntimeOrigin::signed(context.relayer.account_id()),
+                    create_signed_mint_single_nft_call(&context),
+                    None
+                ));
+                let (duplicate_call, proof) = mint_call_and_proof(&context);
+
+                let result = AvnProxy::validate_proof(duplicate_call, proof);
+
+                assert!(result.is_err());
+                assert_eq!(single_nft_minted_events_count(), 1);
+            })
+        }
+    }
+}
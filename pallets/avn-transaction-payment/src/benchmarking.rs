@@ -4,9 +4,10 @@
 #![cfg(feature = "runtime-benchmarks")]
 
 use super::*;
+use codec::Decode;
 use frame_benchmarking::{account, benchmarks, impl_benchmark_test_suite};
 use frame_system::{EventRecord, RawOrigin};
-use sp_runtime::traits::Bounded;
+use sp_runtime::traits::{Bounded, TrailingZeroInput};
 
 use crate::Pallet as AvnTransactionPayment;
 
@@ -24,6 +25,10 @@ fn add_known_sender<T: Config>(known_sender: &T::AccountId) {
     <KnownSenders<T>>::insert(known_sender, adjustment_config);
 }
 
+fn fee_asset_id<T: Config>() -> T::AssetId {
+    T::AssetId::decode(&mut TrailingZeroInput::zeroes()).expect("Infinite input is valid")
+}
+
 benchmarks! {
     set_known_sender {
         let known_sender: T::AccountId = account("known_sender", 1, 1);
@@ -60,6 +65,42 @@ benchmarks! {
             Event::<T>::KnownSenderRemoved{ known_sender }.into()
         );
     }
+
+    set_fee_asset_rate {
+        let asset_id = fee_asset_id::<T>();
+        let rate = FixedU128::from_rational(3, 2);
+    }: {
+        AvnTransactionPayment::<T>::set_fee_asset_rate(RawOrigin::Root.into(), asset_id, rate)?;
+    }
+    verify {
+        assert_eq!(<FeeAssetRates<T>>::get(asset_id), Some(rate));
+        assert_last_event::<T>(Event::<T>::FeeAssetRateSet{ asset_id, rate }.into());
+    }
+
+    remove_fee_asset {
+        let asset_id = fee_asset_id::<T>();
+        <FeeAssetRates<T>>::insert(asset_id, FixedU128::from_rational(3, 2));
+    }: {
+        AvnTransactionPayment::<T>::remove_fee_asset(RawOrigin::Root.into(), asset_id)?;
+    }
+    verify {
+        assert_eq!(<FeeAssetRates<T>>::contains_key(asset_id), false);
+        assert_last_event::<T>(Event::<T>::FeeAssetRemoved{ asset_id }.into());
+    }
+
+    set_fee_payment_asset {
+        let who: T::AccountId = account("sender", 1, 1);
+        let asset_id = fee_asset_id::<T>();
+        <FeeAssetRates<T>>::insert(asset_id, FixedU128::from_rational(3, 2));
+    }: {
+        AvnTransactionPayment::<T>::set_fee_payment_asset(RawOrigin::Signed(who.clone()).into(), Some(asset_id))?;
+    }
+    verify {
+        assert_eq!(<FeePaymentAssets<T>>::get(&who), Some(asset_id));
+        assert_last_event::<T>(
+            Event::<T>::FeePaymentAssetSet{ who, asset_id: Some(asset_id) }.into()
+        );
+    }
 }
 
 impl_benchmark_test_suite!(Pallet, crate::mock::new_test_ext(), crate::mock::TestRuntime,);
@@ -39,6 +39,9 @@ use core::marker::PhantomData;
 pub trait WeightInfo {
 	fn set_known_sender() -> Weight;
 	fn remove_known_sender() -> Weight;
+	fn set_fee_asset_rate() -> Weight;
+	fn remove_fee_asset() -> Weight;
+	fn set_fee_payment_asset() -> Weight;
 }
 
 /// Weights for pallet_avn_transaction_payment using the Substrate node and recommended hardware.
@@ -66,6 +69,40 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(1_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
+	/// Storage: `AvnTransactionPayment::FeeAssetRates` (r:0 w:1)
+	/// Proof: `AvnTransactionPayment::FeeAssetRates` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	fn set_fee_asset_rate() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 9_812_000 picoseconds.
+		Weight::from_parts(9_812_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `AvnTransactionPayment::FeeAssetRates` (r:1 w:1)
+	/// Proof: `AvnTransactionPayment::FeeAssetRates` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	fn remove_fee_asset() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `351`
+		//  Estimated: `3517`
+		// Minimum execution time: 13_120_000 picoseconds.
+		Weight::from_parts(13_120_000, 3517)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `AvnTransactionPayment::FeeAssetRates` (r:1 w:0)
+	/// Proof: `AvnTransactionPayment::FeeAssetRates` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `AvnTransactionPayment::FeePaymentAssets` (r:0 w:1)
+	/// Proof: `AvnTransactionPayment::FeePaymentAssets` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+	fn set_fee_payment_asset() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `351`
+		//  Estimated: `3517`
+		// Minimum execution time: 14_307_000 picoseconds.
+		Weight::from_parts(14_307_000, 3517)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
 }
 
 // For backwards compatibility and tests.
@@ -92,4 +129,38 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(1_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
+	/// Storage: `AvnTransactionPayment::FeeAssetRates` (r:0 w:1)
+	/// Proof: `AvnTransactionPayment::FeeAssetRates` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	fn set_fee_asset_rate() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 9_812_000 picoseconds.
+		Weight::from_parts(9_812_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `AvnTransactionPayment::FeeAssetRates` (r:1 w:1)
+	/// Proof: `AvnTransactionPayment::FeeAssetRates` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	fn remove_fee_asset() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `351`
+		//  Estimated: `3517`
+		// Minimum execution time: 13_120_000 picoseconds.
+		Weight::from_parts(13_120_000, 3517)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `AvnTransactionPayment::FeeAssetRates` (r:1 w:0)
+	/// Proof: `AvnTransactionPayment::FeeAssetRates` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `AvnTransactionPayment::FeePaymentAssets` (r:0 w:1)
+	/// Proof: `AvnTransactionPayment::FeePaymentAssets` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+	fn set_fee_payment_asset() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `351`
+		//  Estimated: `3517`
+		// Minimum execution time: 14_307_000 picoseconds.
+		Weight::from_parts(14_307_000, 3517)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
 }
\ No newline at end of file
@@ -3,12 +3,14 @@
 
 //! This is a wrapper pallet for transaction payment that allows the customisation of chain fees
 //! based on defined adjustment configuration and a sender.
+//! Senders can also choose to pay their fees in a supported asset (eg USDC received from Asset
+//! Hub), converted from AVT using a rate set by governance.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
 use frame_support::{
     dispatch::{DispatchResult, GetDispatchInfo, PostDispatchInfo},
-    traits::{Currency, Imbalance, OnUnbalanced},
+    traits::{fungibles, tokens::Preservation, Currency, Imbalance, OnUnbalanced},
     unsigned::TransactionValidityError,
 };
 use frame_system::{self as system};
@@ -16,8 +18,9 @@ use frame_system::{self as system};
 use core::convert::TryInto;
 pub use pallet::*;
 use sp_runtime::{
-    traits::{DispatchInfoOf, Dispatchable, PostDispatchInfoOf, Saturating},
+    traits::{DispatchInfoOf, Dispatchable, PostDispatchInfoOf, Saturating, Zero},
     transaction_validity::InvalidTransaction,
+    FixedPointNumber, FixedU128, SaturatedConversion,
 };
 
 use pallet_transaction_payment::{CurrencyAdapter, OnChargeTransaction};
@@ -51,6 +54,15 @@ pub mod pallet {
         /// Currency type for processing fee payment
         type Currency: Currency<Self::AccountId>;
 
+        /// The id of an asset that can be used to pay fees
+        type AssetId: Member + Parameter + Copy + MaxEncodedLen;
+
+        /// The assets that can be used to pay fees instead of the native currency
+        type Assets: fungibles::Mutate<Self::AccountId, AssetId = Self::AssetId>;
+
+        /// The account that receives fees paid in assets
+        type FeeAssetCollector: Get<Self::AccountId>;
+
         type WeightInfo: WeightInfo;
     }
 
@@ -79,6 +91,26 @@ pub mod pallet {
             who: T::AccountId,
             fee: BalanceOf<T>,
         },
+        /// Fees can be paid in `asset_id`, at `rate` units of the asset per unit of AVT
+        FeeAssetRateSet {
+            asset_id: T::AssetId,
+            rate: FixedU128,
+        },
+        /// Fees can no longer be paid in `asset_id`
+        FeeAssetRemoved {
+            asset_id: T::AssetId,
+        },
+        /// `who` will pay fees in `asset_id`, or in AVT if it is `None`
+        FeePaymentAssetSet {
+            who: T::AccountId,
+            asset_id: Option<T::AssetId>,
+        },
+        /// A transaction fee of `amount` has been paid by `who` in `asset_id`
+        TransactionFeePaidInAsset {
+            who: T::AccountId,
+            asset_id: T::AssetId,
+            amount: AssetBalanceOf<T>,
+        },
     }
 
     #[pallet::error]
@@ -87,6 +119,8 @@ pub mod pallet {
         InvalidFeeType,
         KnownSenderMustMatchAccount,
         KnownSenderMissing,
+        InvalidFeeAssetRate,
+        FeeAssetNotSupported,
     }
 
     #[pallet::storage]
@@ -95,6 +129,18 @@ pub mod pallet {
     pub type KnownSenders<T: Config> =
         StorageMap<_, Blake2_128Concat, T::AccountId, FeeAdjustmentConfig<T>, ValueQuery>;
 
+    #[pallet::storage]
+    #[pallet::getter(fn fee_asset_rate)]
+    /// Assets that can be used to pay fees, with the number of asset units charged per unit of AVT
+    pub type FeeAssetRates<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AssetId, FixedU128, OptionQuery>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn fee_payment_asset)]
+    /// The asset each account has chosen to pay its fees in
+    pub type FeePaymentAssets<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, T::AssetId, OptionQuery>;
+
     #[pallet::call]
     impl<T: Config> Pallet<T> {
         #[pallet::call_index(0)]
@@ -174,6 +220,58 @@ pub mod pallet {
 
             Ok(())
         }
+
+        #[pallet::call_index(2)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::set_fee_asset_rate())]
+        pub fn set_fee_asset_rate(
+            origin: OriginFor<T>,
+            asset_id: T::AssetId,
+            rate: FixedU128,
+        ) -> DispatchResult {
+            frame_system::ensure_root(origin)?;
+            ensure!(!rate.is_zero(), Error::<T>::InvalidFeeAssetRate);
+
+            <FeeAssetRates<T>>::insert(asset_id, rate);
+            Self::deposit_event(Event::<T>::FeeAssetRateSet { asset_id, rate });
+
+            Ok(())
+        }
+
+        #[pallet::call_index(3)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::remove_fee_asset())]
+        pub fn remove_fee_asset(origin: OriginFor<T>, asset_id: T::AssetId) -> DispatchResult {
+            frame_system::ensure_root(origin)?;
+            ensure!(<FeeAssetRates<T>>::contains_key(asset_id), Error::<T>::FeeAssetNotSupported);
+
+            <FeeAssetRates<T>>::remove(asset_id);
+            Self::deposit_event(Event::<T>::FeeAssetRemoved { asset_id });
+
+            Ok(())
+        }
+
+        #[pallet::call_index(4)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::set_fee_payment_asset())]
+        pub fn set_fee_payment_asset(
+            origin: OriginFor<T>,
+            asset_id: Option<T::AssetId>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            match asset_id {
+                Some(asset_id) => {
+                    ensure!(
+                        <FeeAssetRates<T>>::contains_key(asset_id),
+                        Error::<T>::FeeAssetNotSupported
+                    );
+                    <FeePaymentAssets<T>>::insert(&who, asset_id);
+                },
+                None => <FeePaymentAssets<T>>::remove(&who),
+            }
+
+            Self::deposit_event(Event::<T>::FeePaymentAssetSet { who, asset_id });
+
+            Ok(())
+        }
     }
 }
 
@@ -183,6 +281,17 @@ pub(crate) type BalanceOf<T> =
 type NegativeImbalanceOf<C, T> =
     <C as Currency<<T as frame_system::Config>::AccountId>>::NegativeImbalance;
 
+pub type AssetBalanceOf<T> =
+    <<T as Config>::Assets as fungibles::Inspect<<T as frame_system::Config>::AccountId>>::Balance;
+
+/// How the fee of a transaction was paid before dispatching it
+pub enum InitialPayment<T: Config, Imbalance> {
+    /// The fee was withdrawn in the native currency
+    Native(Imbalance),
+    /// The fee was paid in `asset_id` at `rate` units of the asset per unit of AVT
+    Asset { asset_id: T::AssetId, rate: FixedU128, paid: AssetBalanceOf<T> },
+}
+
 impl<T: Config> Pallet<T> {
     pub fn calculate_refund_amount(
         fee_payer: &T::AccountId,
@@ -212,6 +321,92 @@ impl<T: Config> Pallet<T> {
         let refund_amount = amount_paid.saturating_sub(fee_to_pay);
         return (has_active_config, refund_amount)
     }
+
+    /// The fee `fee_payer` is charged for a transaction of `fee`, including `tip`, once its known
+    /// sender adjustment is applied
+    pub fn adjusted_fee(
+        fee_payer: &T::AccountId,
+        fee: BalanceOf<T>,
+        tip: BalanceOf<T>,
+    ) -> BalanceOf<T> {
+        let (_, refund_amount) = Self::calculate_refund_amount(fee_payer, &fee, fee, tip);
+        fee.saturating_sub(refund_amount)
+    }
+
+    /// The asset `fee_payer` pays its fees in and what `fee` is in that asset, if it doesn't pay in
+    /// the native currency
+    pub fn fee_in_payment_asset(
+        fee_payer: &T::AccountId,
+        fee: BalanceOf<T>,
+    ) -> Option<(T::AssetId, AssetBalanceOf<T>)> {
+        let asset_id = Self::fee_payment_asset(fee_payer)?;
+        Self::fee_in_asset(asset_id, fee).map(|asset_fee| (asset_id, asset_fee))
+    }
+
+    /// Converts a fee in the native currency to `asset_id`, if fees can be paid in that asset
+    pub fn fee_in_asset(asset_id: T::AssetId, fee: BalanceOf<T>) -> Option<AssetBalanceOf<T>> {
+        Self::fee_asset_rate(asset_id).map(|rate| Self::convert_to_asset(rate, fee))
+    }
+
+    fn convert_to_asset(rate: FixedU128, fee: BalanceOf<T>) -> AssetBalanceOf<T> {
+        rate.saturating_mul_int(fee.saturated_into::<u128>()).saturated_into()
+    }
+
+    fn withdraw_fee_in_asset(
+        who: &T::AccountId,
+        asset_id: T::AssetId,
+        rate: FixedU128,
+        fee: BalanceOf<T>,
+    ) -> Result<AssetBalanceOf<T>, TransactionValidityError> {
+        let asset_fee = Self::convert_to_asset(rate, fee);
+        if asset_fee.is_zero() {
+            return Ok(asset_fee)
+        }
+
+        <T::Assets as fungibles::Mutate<T::AccountId>>::transfer(
+            asset_id,
+            who,
+            &T::FeeAssetCollector::get(),
+            asset_fee,
+            Preservation::Expendable,
+        )
+        .map_err(|_| TransactionValidityError::Invalid(InvalidTransaction::Payment))
+    }
+
+    fn refund_fee_in_asset(
+        who: &T::AccountId,
+        asset_id: T::AssetId,
+        rate: FixedU128,
+        paid: AssetBalanceOf<T>,
+        corrected_fee: BalanceOf<T>,
+    ) -> AssetBalanceOf<T> {
+        let corrected_asset_fee = Self::convert_to_asset(rate, corrected_fee);
+        let refund_amount = paid.saturating_sub(corrected_asset_fee);
+        if refund_amount.is_zero() {
+            return paid
+        }
+
+        // If the refund fails the sender keeps paying the full predicted fee
+        match <T::Assets as fungibles::Mutate<T::AccountId>>::transfer(
+            asset_id,
+            &T::FeeAssetCollector::get(),
+            who,
+            refund_amount,
+            Preservation::Expendable,
+        ) {
+            Ok(_) => corrected_asset_fee,
+            Err(e) => {
+                log::error!(
+                    "💔 Failed to refund {:?} of asset {:?} to {:?}: {:?}",
+                    refund_amount,
+                    asset_id,
+                    who,
+                    e
+                );
+                paid
+            },
+        }
+    }
 }
 
 /// Implements the transaction payment for a pallet implementing the `Currency`
@@ -240,7 +435,7 @@ where
     >,
     OU: OnUnbalanced<NegativeImbalanceOf<C, T>>,
 {
-    type LiquidityInfo = Option<NegativeImbalanceOf<C, T>>;
+    type LiquidityInfo = Option<InitialPayment<T, NegativeImbalanceOf<C, T>>>;
     type Balance = <C as Currency<<T as frame_system::Config>::AccountId>>::Balance;
 
     /// Withdraw the predicted fee from the transaction origin.
@@ -253,9 +448,18 @@ where
         fee: Self::Balance,
         tip: Self::Balance,
     ) -> Result<Self::LiquidityInfo, TransactionValidityError> {
+        let fee_asset = Pallet::<T>::fee_payment_asset(who)
+            .and_then(|asset_id| Pallet::<T>::fee_asset_rate(asset_id).map(|r| (asset_id, r)));
+
+        if let Some((asset_id, rate)) = fee_asset {
+            let paid = Pallet::<T>::withdraw_fee_in_asset(who, asset_id, rate, fee)?;
+            return Ok(Some(InitialPayment::Asset { asset_id, rate, paid }))
+        }
+
         return <CurrencyAdapter<C, OU> as OnChargeTransaction<T>>::withdraw_fee(
             who, _call, _info, fee, tip,
         )
+        .map(|paid| paid.map(InitialPayment::Native))
     }
 
     /// Hand the fee and the tip over to the `[OnUnbalanced]` implementation.
@@ -271,7 +475,15 @@ where
         tip: Self::Balance,
         already_withdrawn: Self::LiquidityInfo,
     ) -> Result<(), TransactionValidityError> {
-        if let Some(paid) = already_withdrawn {
+        if let Some(InitialPayment::Asset { asset_id, rate, paid }) = already_withdrawn {
+            let fee = Pallet::<T>::adjusted_fee(who, corrected_fee, tip);
+            let amount = Pallet::<T>::refund_fee_in_asset(who, asset_id, rate, paid, fee);
+            Pallet::<T>::deposit_event(Event::<T>::TransactionFeePaidInAsset {
+                who: who.clone(),
+                asset_id,
+                amount,
+            });
+        } else if let Some(InitialPayment::Native(paid)) = already_withdrawn {
             // Calculate how much refund we should return
             let amount_paid = paid.peek();
             let (has_active_adjustment, refund_amount) =
@@ -315,6 +527,10 @@ pub mod set_known_sender_tests;
 #[path = "tests/adjustment_fee_tests.rs"]
 pub mod adjustment_fee_tests;
 
+#[cfg(test)]
+#[path = "tests/fee_asset_tests.rs"]
+pub mod fee_asset_tests;
+
 pub mod default_weights;
 pub use default_weights::WeightInfo;
 
@@ -483,3 +483,34 @@ mod adjustment_fee_tests {
         }
     }
 }
+
+mod adjusted_fee {
+    use super::*;
+
+    #[test]
+    fn applies_the_adjustment_of_a_known_sender() {
+        new_test_ext().execute_with(|| {
+            let sender = to_acc_id(1u64);
+            let config = AdjustmentInput::<TestRuntime> {
+                fee_type: FeeType::FixedFee(FixedFeeConfig { fee: FIXED_FEE }),
+                adjustment_type: AdjustmentType::None,
+            };
+            set_known_sender(&sender, config);
+
+            assert_eq!(AvnTransactionPayment::adjusted_fee(&sender, BASE_FEE, NO_TIP), FIXED_FEE);
+            assert_eq!(
+                AvnTransactionPayment::adjusted_fee(&sender, BASE_FEE + ONE_ATTO_TIP, ONE_ATTO_TIP),
+                FIXED_FEE + ONE_ATTO_TIP
+            );
+        })
+    }
+
+    #[test]
+    fn is_the_fee_for_other_senders() {
+        new_test_ext().execute_with(|| {
+            let sender = to_acc_id(1u64);
+
+            assert_eq!(AvnTransactionPayment::adjusted_fee(&sender, BASE_FEE, NO_TIP), BASE_FEE);
+        })
+    }
+}
@@ -0,0 +1,327 @@
+use super::*;
+use crate::mock::{
+    event_emitted, new_test_ext, AccountId, Assets, AvnTransactionPayment, Balances,
+    FeeAssetCollector, RuntimeCall, RuntimeEvent, RuntimeOrigin, System, TestAccount, TestRuntime,
+};
+
+use frame_support::{assert_noop, assert_ok, dispatch::DispatchInfo, pallet_prelude::Weight};
+use pallet_transaction_payment::ChargeTransactionPayment;
+use sp_runtime::traits::{BadOrigin, SignedExtension};
+
+const USDC: u32 = 1;
+const INITIAL_ASSET_BALANCE: u128 = 1_000;
+// Fee of a remark with a weight of 1 and a length of 1, see adjustment_fee_tests
+const BASE_FEE: u128 = 14;
+
+fn sender() -> AccountId {
+    TestAccount::new(1u64).account_id()
+}
+
+fn rate() -> FixedU128 {
+    FixedU128::from_u32(2)
+}
+
+fn setup_usdc_fee_payment(who: &AccountId) {
+    assert_ok!(Assets::force_create(RuntimeOrigin::root(), USDC, *who, true, 1));
+    assert_ok!(Assets::mint(RuntimeOrigin::signed(*who), USDC, *who, INITIAL_ASSET_BALANCE));
+    assert_ok!(AvnTransactionPayment::set_fee_asset_rate(RuntimeOrigin::root(), USDC, rate()));
+    assert_ok!(AvnTransactionPayment::set_fee_payment_asset(
+        RuntimeOrigin::signed(*who),
+        Some(USDC)
+    ));
+}
+
+fn pay_fee_for_remark(sender: &AccountId, predicted_weight: u64, actual_weight: u64) {
+    let pre = <ChargeTransactionPayment<TestRuntime> as SignedExtension>::pre_dispatch(
+        ChargeTransactionPayment::from(0),
+        sender,
+        &RuntimeCall::System(frame_system::Call::remark { remark: vec![] }),
+        &DispatchInfo { weight: Weight::from_parts(predicted_weight, 0), ..Default::default() },
+        1,
+    );
+    assert_ok!(&pre);
+
+    assert_ok!(ChargeTransactionPayment::<TestRuntime>::post_dispatch(
+        Some(pre.expect("Checked for error")),
+        &DispatchInfo { weight: Weight::from_parts(predicted_weight, 0), ..Default::default() },
+        &PostDispatchInfo {
+            actual_weight: Some(Weight::from_parts(actual_weight, 0)),
+            pays_fee: Default::default()
+        },
+        1,
+        &Ok(())
+    ));
+}
+
+mod set_fee_asset_rate {
+    use super::*;
+
+    #[test]
+    fn succeeds_when_origin_is_root() {
+        new_test_ext().execute_with(|| {
+            assert_ok!(AvnTransactionPayment::set_fee_asset_rate(
+                RuntimeOrigin::root(),
+                USDC,
+                rate()
+            ));
+
+            assert_eq!(AvnTransactionPayment::fee_asset_rate(USDC), Some(rate()));
+            assert!(event_emitted(&RuntimeEvent::AvnTransactionPayment(crate::Event::<
+                TestRuntime,
+            >::FeeAssetRateSet {
+                asset_id: USDC,
+                rate: rate()
+            })));
+        })
+    }
+
+    #[test]
+    fn fails_when_origin_is_not_root() {
+        new_test_ext().execute_with(|| {
+            assert_noop!(
+                AvnTransactionPayment::set_fee_asset_rate(
+                    RuntimeOrigin::signed(sender()),
+                    USDC,
+                    rate()
+                ),
+                BadOrigin
+            );
+        })
+    }
+
+    #[test]
+    fn fails_when_rate_is_zero() {
+        new_test_ext().execute_with(|| {
+            assert_noop!(
+                AvnTransactionPayment::set_fee_asset_rate(
+                    RuntimeOrigin::root(),
+                    USDC,
+                    FixedU128::zero()
+                ),
+                Error::<TestRuntime>::InvalidFeeAssetRate
+            );
+        })
+    }
+}
+
+mod remove_fee_asset {
+    use super::*;
+
+    #[test]
+    fn succeeds_when_asset_is_supported() {
+        new_test_ext().execute_with(|| {
+            assert_ok!(AvnTransactionPayment::set_fee_asset_rate(
+                RuntimeOrigin::root(),
+                USDC,
+                rate()
+            ));
+            assert_ok!(AvnTransactionPayment::remove_fee_asset(RuntimeOrigin::root(), USDC));
+
+            assert_eq!(AvnTransactionPayment::fee_asset_rate(USDC), None);
+            assert!(event_emitted(&RuntimeEvent::AvnTransactionPayment(crate::Event::<
+                TestRuntime,
+            >::FeeAssetRemoved {
+                asset_id: USDC
+            })));
+        })
+    }
+
+    #[test]
+    fn fails_when_asset_is_not_supported() {
+        new_test_ext().execute_with(|| {
+            assert_noop!(
+                AvnTransactionPayment::remove_fee_asset(RuntimeOrigin::root(), USDC),
+                Error::<TestRuntime>::FeeAssetNotSupported
+            );
+        })
+    }
+}
+
+mod set_fee_payment_asset {
+    use super::*;
+
+    #[test]
+    fn succeeds_when_asset_is_supported() {
+        new_test_ext().execute_with(|| {
+            let sender = sender();
+            assert_ok!(AvnTransactionPayment::set_fee_asset_rate(
+                RuntimeOrigin::root(),
+                USDC,
+                rate()
+            ));
+            assert_ok!(AvnTransactionPayment::set_fee_payment_asset(
+                RuntimeOrigin::signed(sender),
+                Some(USDC)
+            ));
+
+            assert_eq!(AvnTransactionPayment::fee_payment_asset(sender), Some(USDC));
+            assert!(event_emitted(&RuntimeEvent::AvnTransactionPayment(crate::Event::<
+                TestRuntime,
+            >::FeePaymentAssetSet {
+                who: sender,
+                asset_id: Some(USDC)
+            })));
+        })
+    }
+
+    #[test]
+    fn clears_the_preference_when_asset_is_none() {
+        new_test_ext().execute_with(|| {
+            let sender = sender();
+            setup_usdc_fee_payment(&sender);
+
+            assert_ok!(AvnTransactionPayment::set_fee_payment_asset(
+                RuntimeOrigin::signed(sender),
+                None
+            ));
+            assert_eq!(AvnTransactionPayment::fee_payment_asset(sender), None);
+        })
+    }
+
+    #[test]
+    fn fails_when_asset_is_not_supported() {
+        new_test_ext().execute_with(|| {
+            assert_noop!(
+                AvnTransactionPayment::set_fee_payment_asset(
+                    RuntimeOrigin::signed(sender()),
+                    Some(USDC)
+                ),
+                Error::<TestRuntime>::FeeAssetNotSupported
+            );
+        })
+    }
+}
+
+mod paying_fees_in_an_asset {
+    use super::*;
+
+    #[test]
+    fn charges_the_converted_fee_in_the_asset() {
+        new_test_ext().execute_with(|| {
+            let sender = sender();
+            setup_usdc_fee_payment(&sender);
+            let expected_fee = BASE_FEE * 2;
+
+            pay_fee_for_remark(&sender, 1, 1);
+
+            assert_eq!(Assets::balance(USDC, sender), INITIAL_ASSET_BALANCE - expected_fee);
+            assert_eq!(Assets::balance(USDC, FeeAssetCollector::get()), expected_fee);
+            assert_eq!(Balances::free_balance(sender), 0);
+            assert!(event_emitted(&RuntimeEvent::AvnTransactionPayment(
+                crate::Event::<TestRuntime>::TransactionFeePaidInAsset {
+                    who: sender,
+                    asset_id: USDC,
+                    amount: expected_fee,
+                }
+            )));
+        })
+    }
+
+    #[test]
+    fn refunds_the_unused_part_of_the_fee() {
+        new_test_ext().execute_with(|| {
+            let sender = sender();
+            setup_usdc_fee_payment(&sender);
+            let expected_fee = BASE_FEE * 2;
+
+            pay_fee_for_remark(&sender, 5, 1);
+
+            assert_eq!(Assets::balance(USDC, sender), INITIAL_ASSET_BALANCE - expected_fee);
+            assert_eq!(Assets::balance(USDC, FeeAssetCollector::get()), expected_fee);
+        })
+    }
+
+    #[test]
+    fn applies_the_adjustment_of_a_known_sender() {
+        new_test_ext().execute_with(|| {
+            let sender = sender();
+            setup_usdc_fee_payment(&sender);
+            let fixed_fee = 10;
+            assert_ok!(AvnTransactionPayment::set_known_sender(
+                RuntimeOrigin::root(),
+                sender,
+                AdjustmentInput::<TestRuntime> {
+                    fee_type: FeeType::FixedFee(FixedFeeConfig { fee: fixed_fee }),
+                    adjustment_type: AdjustmentType::None,
+                },
+            ));
+            let expected_fee = fixed_fee * 2;
+
+            pay_fee_for_remark(&sender, 1, 1);
+
+            assert_eq!(Assets::balance(USDC, sender), INITIAL_ASSET_BALANCE - expected_fee);
+            assert_eq!(Assets::balance(USDC, FeeAssetCollector::get()), expected_fee);
+            assert!(event_emitted(&RuntimeEvent::AvnTransactionPayment(
+                crate::Event::<TestRuntime>::TransactionFeePaidInAsset {
+                    who: sender,
+                    asset_id: USDC,
+                    amount: expected_fee,
+                }
+            )));
+        })
+    }
+
+    #[test]
+    fn falls_back_to_the_native_currency_when_the_asset_is_removed() {
+        new_test_ext().execute_with(|| {
+            let sender = sender();
+            setup_usdc_fee_payment(&sender);
+            Balances::make_free_balance_be(&sender, 200);
+            assert_ok!(AvnTransactionPayment::remove_fee_asset(RuntimeOrigin::root(), USDC));
+            System::reset_events();
+
+            pay_fee_for_remark(&sender, 1, 1);
+
+            assert_eq!(Assets::balance(USDC, sender), INITIAL_ASSET_BALANCE);
+            assert_eq!(Balances::free_balance(sender), 200 - BASE_FEE);
+        })
+    }
+
+    #[test]
+    fn fails_when_the_asset_balance_is_too_low() {
+        new_test_ext().execute_with(|| {
+            let sender = sender();
+            setup_usdc_fee_payment(&sender);
+            assert_ok!(Assets::burn(
+                RuntimeOrigin::signed(sender),
+                USDC,
+                sender,
+                INITIAL_ASSET_BALANCE
+            ));
+
+            let pre = <ChargeTransactionPayment<TestRuntime> as SignedExtension>::pre_dispatch(
+                ChargeTransactionPayment::from(0),
+                &sender,
+                &RuntimeCall::System(frame_system::Call::remark { remark: vec![] }),
+                &DispatchInfo { weight: Weight::from_parts(1, 0), ..Default::default() },
+                1,
+            );
+
+            assert!(pre.is_err());
+        })
+    }
+}
+
+mod fee_in_payment_asset {
+    use super::*;
+
+    #[test]
+    fn converts_the_fee_to_the_asset_of_the_sender() {
+        new_test_ext().execute_with(|| {
+            let sender = sender();
+            setup_usdc_fee_payment(&sender);
+
+            assert_eq!(
+                AvnTransactionPayment::fee_in_payment_asset(&sender, BASE_FEE),
+                Some((USDC, BASE_FEE * 2))
+            );
+        })
+    }
+
+    #[test]
+    fn is_none_when_the_sender_pays_in_the_native_currency() {
+        new_test_ext().execute_with(|| {
+            assert_eq!(AvnTransactionPayment::fee_in_payment_asset(&sender(), BASE_FEE), None);
+        })
+    }
+}
@@ -5,9 +5,13 @@ use codec::{Decode, Encode};
 use frame_support::{
     pallet_prelude::DispatchClass,
     parameter_types,
-    traits::{ConstU16, ConstU64, ConstU8, Imbalance, OnFinalize, OnInitialize, OnUnbalanced},
+    traits::{
+        AsEnsureOriginWithArg, ConstU128, ConstU16, ConstU32, ConstU64, ConstU8, Imbalance,
+        OnFinalize, OnInitialize, OnUnbalanced,
+    },
     weights::{Weight, WeightToFee as WeightToFeeT},
 };
+use frame_system::{EnsureRoot, EnsureSigned};
 use pallet_balances;
 use sp_core::{sr25519, Pair, H256};
 use sp_runtime::{
@@ -33,7 +37,8 @@ frame_support::construct_runtime!(
         System: frame_system::{Pallet, Call, Config<T>, Storage, Event<T>},
         Balances: pallet_balances::{Pallet, Call, Storage, Event<T>},
         TransactionPayment: pallet_transaction_payment::{Pallet, Storage, Event<T>, Config<T>},
-        AvnTransactionPayment: pallet_avn_transaction_payment::{Pallet, Call, Storage, Event<T>}
+        AvnTransactionPayment: pallet_avn_transaction_payment::{Pallet, Call, Storage, Event<T>},
+        Assets: pallet_assets::{Pallet, Call, Storage, Event<T>}
     }
 );
 
@@ -83,10 +88,17 @@ impl frame_system::Config for TestRuntime {
     type MaxConsumers = frame_support::traits::ConstU32<16>;
 }
 
+parameter_types! {
+    pub FeeAssetCollector: AccountId = TestAccount::new(999).account_id();
+}
+
 impl pallet_avn_transaction_payment::Config for TestRuntime {
     type RuntimeEvent = RuntimeEvent;
     type RuntimeCall = RuntimeCall;
     type Currency = Balances;
+    type AssetId = u32;
+    type Assets = Assets;
+    type FeeAssetCollector = FeeAssetCollector;
     type WeightInfo = pallet_avn_transaction_payment::default_weights::SubstrateWeight<TestRuntime>;
 }
 
@@ -155,6 +167,29 @@ impl pallet_balances::Config for TestRuntime {
     type RuntimeHoldReason = ();
 }
 
+impl pallet_assets::Config for TestRuntime {
+    type RuntimeEvent = RuntimeEvent;
+    type Balance = u128;
+    type RemoveItemsLimit = ConstU32<5>;
+    type AssetId = u32;
+    type AssetIdParameter = u32;
+    type Currency = Balances;
+    type CreateOrigin = AsEnsureOriginWithArg<EnsureSigned<AccountId>>;
+    type ForceOrigin = EnsureRoot<AccountId>;
+    type AssetDeposit = ConstU128<0>;
+    type AssetAccountDeposit = ConstU128<0>;
+    type MetadataDepositBase = ConstU128<0>;
+    type MetadataDepositPerByte = ConstU128<0>;
+    type ApprovalDeposit = ConstU128<0>;
+    type StringLimit = ConstU32<50>;
+    type Freezer = ();
+    type Extra = ();
+    type CallbackHandle = ();
+    type WeightInfo = ();
+    #[cfg(feature = "runtime-benchmarks")]
+    type BenchmarkHelper = ();
+}
+
 impl AvnTransactionPayment {
     pub fn is_known_sender(account_id: <TestRuntime as frame_system::Config>::AccountId) -> bool {
         KnownSenders::<TestRuntime>::contains_key(account_id)
@@ -0,0 +1,124 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+use codec::{Codec, Decode, Encode};
+use scale_info::TypeInfo;
+use sp_core::H160;
+use sp_runtime::{DispatchError, RuntimeDebug};
+use sp_std::vec::Vec;
+
+/// The configuration a client needs to interact with the AvN bridge
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct BridgeConfig {
+    /// Address of the AvN bridge contract on Ethereum
+    pub bridge_contract: H160,
+    /// NFT contracts whose events are accepted by the AvN
+    pub nft_contracts: Vec<H160>,
+    /// The AVT token contract followed by the tokens that can be lifted from the other bridge
+    /// contracts
+    pub token_contracts: Vec<H160>,
+    /// Number of Ethereum blocks mined on top of a transaction before the AvN accepts it
+    pub min_eth_block_confirmation: u64,
+    /// Whether lowering tokens to Ethereum is paused
+    pub lowers_paused: bool,
+}
+
+/// What an extrinsic costs its sender
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct FeeBreakdown<Balance, AssetId> {
+    /// Computation time of the extrinsic, in picoseconds
+    pub weight_ref_time: u64,
+    /// Size of the proof of the extrinsic, in bytes
+    pub weight_proof_size: u64,
+    /// Fee of the dispatch class of the extrinsic
+    pub base_fee: Balance,
+    /// Fee for the encoded length of the extrinsic
+    pub length_fee: Balance,
+    /// Fee for the weight of the extrinsic, adjusted for the congestion of the chain
+    pub weight_fee: Balance,
+    pub tip: Balance,
+    /// The fee the sender is charged, in AVT, once its known sender adjustment is applied
+    pub adjusted_fee: Balance,
+    /// The asset the sender pays its fees in and the adjusted fee in that asset, if the sender
+    /// does not pay in AVT
+    pub fee_asset: Option<(AssetId, Balance)>,
+    /// The token and amount paid to the relayer of a proxied call
+    pub relayer_fee: Option<(H160, Balance)>,
+}
+
+/// The work the offchain worker of a validator has to do at a block
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Default, RuntimeDebug, TypeInfo)]
+pub struct OcwDuties {
+    /// The validator is the slot validator of a summary instance and has to submit its root
+    pub summary_slot_validator: bool,
+    /// The validator is the primary validator of the block and there are Ethereum events to check
+    pub event_checker: bool,
+    /// The transaction sent to Ethereum by another validator is waiting for the validator to
+    /// corroborate its outcome
+    pub pending_corroboration: bool,
+}
+
+sp_api::decl_runtime_apis! {
+
+    #[api_version(1)]
+    pub trait AvnConfigApi
+    {
+        fn bridge_config() -> BridgeConfig;
+    }
+
+    #[api_version(1)]
+    pub trait AvnEventsApi
+    {
+        /// Sequence number the next sequenced event will get. The events of a block are numbered
+        /// from its value at the parent block up to, but excluding, its value at the block.
+        fn next_event_sequence() -> u64;
+    }
+
+    #[api_version(1)]
+    pub trait AvnEthereumAddressApi<AccountId>
+    where
+        AccountId: Codec,
+    {
+        /// The account that registered `ethereum_address`, if any
+        fn account_of(ethereum_address: H160) -> Option<AccountId>;
+
+        /// The Ethereum address registered by `account`, if any
+        fn ethereum_address_of(account: AccountId) -> Option<H160>;
+    }
+
+    #[api_version(2)]
+    pub trait AvnProxyApi<Call, Proof, Balance>
+    where
+        Call: Codec,
+        Proof: Codec,
+        Balance: Codec,
+    {
+        /// Checks, without submitting a transaction, whether `call` would be dispatched when
+        /// proxied with `proof`. The error is the one the proxied call would fail with.
+        fn validate_proof(call: Call, proof: Proof) -> Result<(), DispatchError>;
+
+        /// The relayer fee the fee schedule sets for proxying `call` when the payer pays in
+        /// `token`. None if the payer of the call pays the amount it authorises.
+        fn relayer_fee(call: Call, token: H160) -> Option<Balance>;
+    }
+
+    #[api_version(1)]
+    pub trait AvnFeeApi<Balance, AssetId>
+    where
+        Balance: Codec,
+        AssetId: Codec,
+    {
+        /// Breaks down the fees of an encoded extrinsic, including the AvN specific ones. Returns
+        /// None if the extrinsic can't be decoded.
+        fn estimate_fee(encoded_extrinsic: Vec<u8>) -> Option<FeeBreakdown<Balance, AssetId>>;
+    }
+
+    #[api_version(1)]
+    pub trait AvnOcwApi<AccountId, BlockNumber>
+    where
+        AccountId: Codec,
+        BlockNumber: Codec,
+    {
+        /// What the offchain worker of `account` has to do at `block`, so its node can prepare the
+        /// data it will need before the offchain worker runs
+        fn duties(account: AccountId, block: BlockNumber) -> OcwDuties;
+    }
+}
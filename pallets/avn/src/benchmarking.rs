@@ -7,7 +7,7 @@
 
 use super::*;
 
-use frame_benchmarking::{benchmarks, impl_benchmark_test_suite};
+use frame_benchmarking::{account, benchmarks, impl_benchmark_test_suite};
 use frame_system::{EventRecord, RawOrigin};
 
 fn assert_last_event<T: Config>(generic_event: <T as Config>::RuntimeEvent) {
@@ -31,6 +31,44 @@ benchmarks! {
             }.into()
         );
     }
+
+    unregister_ethereum_address {
+        let account: T::AccountId = account("account", 0, 0);
+        let ethereum_address = H160::from([1; 20]);
+        <EthereumAddressAccounts<T>>::insert(ethereum_address, &account);
+        <AccountEthereumAddresses<T>>::insert(&account, ethereum_address);
+    }: unregister_ethereum_address(RawOrigin::Signed(account.clone()))
+    verify {
+        assert!(!<EthereumAddressAccounts<T>>::contains_key(ethereum_address));
+        assert!(!<AccountEthereumAddresses<T>>::contains_key(&account));
+        assert_last_event::<T>(Event::EthereumAddressUnregistered { account, ethereum_address }.into());
+    }
+
+    register_bridge_contract_token {
+        let bridge_contract = H160::from([2; 20]);
+        let token = H160::from([3; 20]);
+    }: register_bridge_contract_token(RawOrigin::Root, bridge_contract, token)
+    verify {
+        assert!(<BridgeContractTokens<T>>::contains_key(bridge_contract, token));
+        assert_last_event::<T>(Event::BridgeContractTokenRegistered { bridge_contract, token }.into());
+    }
+
+    deregister_bridge_contract_token {
+        let bridge_contract = H160::from([2; 20]);
+        let token = H160::from([3; 20]);
+        <BridgeContractTokens<T>>::insert(bridge_contract, token, ());
+    }: deregister_bridge_contract_token(RawOrigin::Root, bridge_contract, token)
+    verify {
+        assert!(!<BridgeContractTokens<T>>::contains_key(bridge_contract, token));
+        assert_last_event::<T>(Event::BridgeContractTokenDeregistered { bridge_contract, token }.into());
+    }
+
+    set_legacy_signed_payloads_rejected {
+    }: set_legacy_signed_payloads_rejected(RawOrigin::Root, true)
+    verify {
+        assert!(<LegacySignedPayloadsRejected<T>>::get());
+        assert_last_event::<T>(Event::LegacySignedPayloadsRejectionSet { rejected: true }.into());
+    }
 }
 
 impl_benchmark_test_suite!(
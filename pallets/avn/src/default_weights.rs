@@ -38,6 +38,11 @@ use core::marker::PhantomData;
 /// Weight functions needed for pallet_avn.
 pub trait WeightInfo {
 	fn set_bridge_contract() -> Weight;
+	fn register_ethereum_address() -> Weight;
+	fn unregister_ethereum_address() -> Weight;
+	fn register_bridge_contract_token() -> Weight;
+	fn deregister_bridge_contract_token() -> Weight;
+	fn set_legacy_signed_payloads_rejected() -> Weight;
 }
 
 /// Weights for pallet_avn using the Substrate node and recommended hardware.
@@ -54,6 +59,63 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(1_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
+	/// Storage: `Avn::EthereumAddressAccounts` (r:1 w:2)
+	/// Proof: `Avn::EthereumAddressAccounts` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+	/// Storage: `Avn::AccountEthereumAddresses` (r:1 w:1)
+	/// Proof: `Avn::AccountEthereumAddresses` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+	fn register_ethereum_address() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `120`
+		//  Estimated: `3533`
+		// Minimum execution time: 61_204_000 picoseconds.
+		Weight::from_parts(62_518_000, 3533)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: `Avn::AccountEthereumAddresses` (r:1 w:1)
+	/// Proof: `Avn::AccountEthereumAddresses` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+	/// Storage: `Avn::EthereumAddressAccounts` (r:0 w:1)
+	/// Proof: `Avn::EthereumAddressAccounts` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+	fn unregister_ethereum_address() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `190`
+		//  Estimated: `3533`
+		// Minimum execution time: 17_305_000 picoseconds.
+		Weight::from_parts(17_961_000, 3533)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Avn::BridgeContractTokens` (r:0 w:1)
+	/// Proof: `Avn::BridgeContractTokens` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+	fn register_bridge_contract_token() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 11_620_000 picoseconds.
+		Weight::from_parts(12_104_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Avn::BridgeContractTokens` (r:1 w:1)
+	/// Proof: `Avn::BridgeContractTokens` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+	fn deregister_bridge_contract_token() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `196`
+		//  Estimated: `3537`
+		// Minimum execution time: 15_238_000 picoseconds.
+		Weight::from_parts(15_790_000, 3537)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Avn::LegacySignedPayloadsRejected` (r:0 w:1)
+	/// Proof: `Avn::LegacySignedPayloadsRejected` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	fn set_legacy_signed_payloads_rejected() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 9_412_000 picoseconds.
+		Weight::from_parts(9_412_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
 }
 
 // For backwards compatibility and tests.
@@ -69,4 +131,61 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(1_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
+	/// Storage: `Avn::EthereumAddressAccounts` (r:1 w:2)
+	/// Proof: `Avn::EthereumAddressAccounts` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+	/// Storage: `Avn::AccountEthereumAddresses` (r:1 w:1)
+	/// Proof: `Avn::AccountEthereumAddresses` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+	fn register_ethereum_address() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `120`
+		//  Estimated: `3533`
+		// Minimum execution time: 61_204_000 picoseconds.
+		Weight::from_parts(62_518_000, 3533)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: `Avn::AccountEthereumAddresses` (r:1 w:1)
+	/// Proof: `Avn::AccountEthereumAddresses` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+	/// Storage: `Avn::EthereumAddressAccounts` (r:0 w:1)
+	/// Proof: `Avn::EthereumAddressAccounts` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+	fn unregister_ethereum_address() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `190`
+		//  Estimated: `3533`
+		// Minimum execution time: 17_305_000 picoseconds.
+		Weight::from_parts(17_961_000, 3533)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Avn::BridgeContractTokens` (r:0 w:1)
+	/// Proof: `Avn::BridgeContractTokens` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+	fn register_bridge_contract_token() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 11_620_000 picoseconds.
+		Weight::from_parts(12_104_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Avn::BridgeContractTokens` (r:1 w:1)
+	/// Proof: `Avn::BridgeContractTokens` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+	fn deregister_bridge_contract_token() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `196`
+		//  Estimated: `3537`
+		// Minimum execution time: 15_238_000 picoseconds.
+		Weight::from_parts(15_790_000, 3537)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Avn::LegacySignedPayloadsRejected` (r:0 w:1)
+	/// Proof: `Avn::LegacySignedPayloadsRejected` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	fn set_legacy_signed_payloads_rejected() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 9_412_000 picoseconds.
+		Weight::from_parts(9_412_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
 }
\ No newline at end of file
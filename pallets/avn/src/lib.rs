@@ -21,20 +21,26 @@ use alloc::{
     string::{String, ToString},
 };
 
-use codec::{Decode, Encode};
+use codec::{Decode, Encode, MaxEncodedLen};
 use core::convert::TryInto;
-use frame_support::{dispatch::DispatchResult, traits::OneSessionHandler};
+use frame_support::{
+    dispatch::DispatchResult,
+    traits::{ConstU32, OneSessionHandler},
+    BoundedVec,
+};
 use frame_system::{
-    ensure_root,
+    ensure_root, ensure_signed,
     pallet_prelude::{BlockNumberFor, OriginFor},
 };
 pub use pallet::*;
 use sp_application_crypto::RuntimeAppPublic;
 use sp_avn_common::{
     bounds::MaximumValidatorsBound,
-    event_types::{EthEvent, EthEventId, Validator},
+    event_types::{ArchivedEventProof, EthEvent, EthEventId, Validator},
     ocw_lock::{self as OcwLock, OcwStorageError},
-    recover_public_key_from_ecdsa_signature, DEFAULT_EXTERNAL_SERVICE_PORT_NUMBER,
+    ocw_task_queue::{self as OcwTaskQueue, OcwTaskPriority},
+    recover_ethereum_address_from_ecdsa_signature, recover_public_key_from_ecdsa_signature,
+    EthereumAddressMapping, HashMessageFormat, DEFAULT_EXTERNAL_SERVICE_PORT_NUMBER,
     EXTERNAL_SERVICE_PORT_NUMBER_KEY,
 };
 use sp_core::{ecdsa, H160};
@@ -45,13 +51,16 @@ use sp_runtime::{
         storage_lock::{BlockAndTime, StorageLock},
         Duration,
     },
+    scale_info::TypeInfo,
     traits::Member,
     DispatchError, WeakBoundedVec,
 };
 use sp_std::prelude::*;
 
+pub mod migration;
 #[path = "tests/testing.rs"]
 pub mod testing;
+pub mod unsigned_rate_limit;
 pub mod vote;
 
 #[cfg(feature = "runtime-benchmarks")]
@@ -60,9 +69,24 @@ mod benchmarking;
 pub mod default_weights;
 pub use default_weights::WeightInfo;
 
+#[cfg(test)]
+#[path = "tests/test_bridge_contract_tokens.rs"]
+mod test_bridge_contract_tokens;
+#[cfg(test)]
+#[path = "tests/test_ethereum_address_registry.rs"]
+mod test_ethereum_address_registry;
+#[cfg(test)]
+#[path = "tests/test_event_sequence.rs"]
+mod test_event_sequence;
+#[cfg(test)]
+#[path = "tests/test_ocw_task_hints.rs"]
+mod test_ocw_task_hints;
 #[cfg(test)]
 #[path = "tests/test_set_bridge_contract.rs"]
 mod test_set_bridge_contract;
+#[cfg(test)]
+#[path = "tests/test_unsigned_rate_limit.rs"]
+mod test_unsigned_rate_limit;
 
 // Definition of the crypto to use for signing
 pub mod sr25519 {
@@ -81,9 +105,49 @@ const AVN_SERVICE_CALL_EXPIRY: u32 = 300_000;
 // TODO: centralise this with MaximumValidatorsBound
 pub const MAX_VALIDATOR_ACCOUNTS: u32 = 10;
 
-pub const PACKED_LOWER_PARAM_SIZE: usize = 76;
+/// Size of the optional reference a lower can carry to Ethereum, right padded with zeros
+pub const LOWER_MEMO_SIZE: usize = 32;
+/// Size of the claim data of a lower without a memo, the layout the tier 1 contract decodes
+pub const PACKED_LOWER_PARAM_SIZE_WITHOUT_MEMO: usize = 76;
+pub const PACKED_LOWER_PARAM_SIZE: usize = PACKED_LOWER_PARAM_SIZE_WITHOUT_MEMO + LOWER_MEMO_SIZE;
 pub type LowerParams = [u8; PACKED_LOWER_PARAM_SIZE];
 
+/// The claim data of a lower, which its proof is generated for. A lower without a memo keeps the
+/// 76 bytes layout, only a lower with a memo uses the extended layout. A memo made of zeros only is
+/// the same as no memo.
+pub fn packed_lower_params(params: &LowerParams) -> &[u8] {
+    let has_memo = params[PACKED_LOWER_PARAM_SIZE_WITHOUT_MEMO..].iter().any(|byte| *byte != 0);
+    if has_memo {
+        &params[..]
+    } else {
+        &params[..PACKED_LOWER_PARAM_SIZE_WITHOUT_MEMO]
+    }
+}
+
+/// Context of the message signed with an Ethereum key to register its address for an account
+pub const REGISTER_ETHEREUM_ADDRESS_CONTEXT: &'static [u8] = b"avn_register_ethereum_address";
+
+pub const MAX_OCW_TASK_HINTS: u32 = 64;
+pub const MAX_OCW_TASK_OWNER_LENGTH: u32 = 32;
+pub const MAX_OCW_TASK_ID_LENGTH: u32 = 64;
+
+/// A task registered on chain for the offchain worker of `owner`, to be added to the offchain task
+/// queue of the validators
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+pub struct OcwTaskHint {
+    pub owner: BoundedVec<u8, ConstU32<MAX_OCW_TASK_OWNER_LENGTH>>,
+    pub id: BoundedVec<u8, ConstU32<MAX_OCW_TASK_ID_LENGTH>>,
+    pub priority: OcwTaskPriority,
+}
+
+/// The blocks of a slot for which a validator was reported by an instance of a pallet
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+pub struct ReportedSlotOffence<BlockNumber> {
+    pub instance_id: u8,
+    pub from_block: BlockNumber,
+    pub to_block: BlockNumber,
+}
+
 #[frame_support::pallet]
 pub mod pallet {
     use frame_support::pallet_prelude::*;
@@ -93,14 +157,39 @@ pub mod pallet {
 
     #[pallet::event]
     #[pallet::generate_deposit(pub(crate) fn deposit_event)]
-    pub enum Event {
-        AvnBridgeContractUpdated { old_contract: H160, new_contract: H160 },
+    pub enum Event<T: Config> {
+        AvnBridgeContractUpdated {
+            old_contract: H160,
+            new_contract: H160,
+        },
+        /// An account has proven it owns an Ethereum address
+        EthereumAddressRegistered {
+            account: T::AccountId,
+            ethereum_address: H160,
+        },
+        EthereumAddressUnregistered {
+            account: T::AccountId,
+            ethereum_address: H160,
+        },
+        /// Lifts of `token` are accepted from `bridge_contract`
+        BridgeContractTokenRegistered {
+            bridge_contract: H160,
+            token: H160,
+        },
+        BridgeContractTokenDeregistered {
+            bridge_contract: H160,
+            token: H160,
+        },
+        /// Signed payloads built without a pallet signing domain are accepted unless `rejected`
+        LegacySignedPayloadsRejectionSet {
+            rejected: bool,
+        },
     }
 
     #[pallet::config]
     pub trait Config: frame_system::Config {
         /// Overarching event type
-        type RuntimeEvent: From<Event> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+        type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
 
         /// The identifier type for an authority.
         type AuthorityId: Member
@@ -147,6 +236,13 @@ pub mod pallet {
         RequestFailed,
         ErrorGettingFinalisedBlock,
         ErrorDecodingU32,
+        /// The signature was not made by the key of the Ethereum address
+        InvalidEthereumAddressSignature,
+        EthereumAddressRegisteredToAnotherAccount,
+        NoEthereumAddressRegistered,
+        BridgeContractTokenNotRegistered,
+        /// The voter has not voted, already replaced its vote or is casting the same vote again
+        VoteCannotBeReplaced,
     }
 
     #[pallet::storage]
@@ -167,6 +263,57 @@ pub mod pallet {
     #[pallet::getter(fn get_primary_collator)]
     pub type PrimaryCollatorIndexForSending<T: Config> = StorageValue<_, u8, ValueQuery>;
 
+    /// Sequence number of the next event deposited with `deposit_sequenced_event`. It is shared by
+    /// all the pallets and never reused, so indexers can use it to reference an event uniquely.
+    #[pallet::storage]
+    #[pallet::getter(fn next_event_sequence)]
+    pub type NextEventSequence<T: Config> = StorageValue<_, u64, ValueQuery>;
+
+    /// Account that proved it owns an Ethereum address
+    #[pallet::storage]
+    #[pallet::getter(fn account_of_ethereum_address)]
+    pub type EthereumAddressAccounts<T: Config> =
+        StorageMap<_, Blake2_128Concat, H160, T::AccountId, OptionQuery>;
+
+    /// Ethereum address registered by an account
+    #[pallet::storage]
+    #[pallet::getter(fn ethereum_address_of_account)]
+    pub type AccountEthereumAddresses<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, H160, OptionQuery>;
+
+    /// Bridge contracts, other than `AvnBridgeContractAddress`, and the tokens that can be lifted
+    /// from them. This allows lifts to be migrated to a new bridge contract one token at a time.
+    #[pallet::storage]
+    pub type BridgeContractTokens<T: Config> =
+        StorageDoubleMap<_, Blake2_128Concat, H160, Blake2_128Concat, H160, (), OptionQuery>;
+
+    /// When set, proofs signed over payloads without the signing domain of the verifying pallet
+    /// are rejected. It is unset until clients have moved to the domain separated payloads.
+    #[pallet::storage]
+    #[pallet::getter(fn legacy_signed_payloads_rejected)]
+    pub type LegacySignedPayloadsRejected<T: Config> = StorageValue<_, bool, ValueQuery>;
+
+    /// Tasks registered for the offchain workers in the current block. The offchain workers add
+    /// them to the task queue of the node, and they are cleared at the start of the next block.
+    #[pallet::storage]
+    #[pallet::getter(fn ocw_task_hints)]
+    pub type OcwTaskHints<T: Config> =
+        StorageValue<_, BoundedVec<OcwTaskHint, ConstU32<MAX_OCW_TASK_HINTS>>, ValueQuery>;
+
+    /// The last slot offence of each type each validator was reported for, keyed by the offence
+    /// type of the reporting pallet. It is shared by the instances of a pallet, so that a validator
+    /// that is down is not reported by every instance whose slot it missed in the same blocks.
+    #[pallet::storage]
+    pub type ReportedSlotOffences<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        Twox64Concat,
+        u8,
+        ReportedSlotOffence<BlockNumberFor<T>>,
+        OptionQuery,
+    >;
+
     #[pallet::genesis_config]
     pub struct GenesisConfig<T: Config> {
         pub _phantom: sp_std::marker::PhantomData<T>,
@@ -186,6 +333,18 @@ pub mod pallet {
         }
     }
 
+    #[pallet::hooks]
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        fn on_initialize(_n: BlockNumberFor<T>) -> Weight {
+            if <OcwTaskHints<T>>::exists() {
+                <OcwTaskHints<T>>::kill();
+                return T::DbWeight::get().reads_writes(1, 1)
+            }
+
+            T::DbWeight::get().reads(1)
+        }
+    }
+
     #[pallet::call]
     impl<T: Config> Pallet<T> {
         #[pallet::call_index(0)]
@@ -202,10 +361,183 @@ pub mod pallet {
             });
             Ok(())
         }
+
+        /// Maps the sender to `ethereum_address`. The signature must be an Ethereum signed message
+        /// of `encode_register_ethereum_address_params` made by the key of the address. A
+        /// previously registered address of the sender is replaced.
+        #[pallet::call_index(1)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::register_ethereum_address())]
+        pub fn register_ethereum_address(
+            origin: OriginFor<T>,
+            ethereum_address: H160,
+            signature: ecdsa::Signature,
+        ) -> DispatchResult {
+            let account = ensure_signed(origin)?;
+
+            let signed_data = Self::encode_register_ethereum_address_params(&account);
+            let signer = recover_ethereum_address_from_ecdsa_signature(
+                &signature,
+                &signed_data,
+                HashMessageFormat::String,
+            )
+            .map_err(|_| Error::<T>::InvalidEthereumAddressSignature)?;
+            ensure!(
+                H160::from(signer) == ethereum_address,
+                Error::<T>::InvalidEthereumAddressSignature
+            );
+            ensure!(
+                <EthereumAddressAccounts<T>>::get(ethereum_address)
+                    .map_or(true, |owner| owner == account),
+                Error::<T>::EthereumAddressRegisteredToAnotherAccount
+            );
+
+            if let Some(previous_address) = <AccountEthereumAddresses<T>>::get(&account) {
+                <EthereumAddressAccounts<T>>::remove(previous_address);
+            }
+            <EthereumAddressAccounts<T>>::insert(ethereum_address, &account);
+            <AccountEthereumAddresses<T>>::insert(&account, ethereum_address);
+
+            Self::deposit_event(Event::EthereumAddressRegistered { account, ethereum_address });
+            Ok(())
+        }
+
+        /// Removes the Ethereum address registered by the sender
+        #[pallet::call_index(2)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::unregister_ethereum_address())]
+        pub fn unregister_ethereum_address(origin: OriginFor<T>) -> DispatchResult {
+            let account = ensure_signed(origin)?;
+
+            let ethereum_address = <AccountEthereumAddresses<T>>::take(&account)
+                .ok_or(Error::<T>::NoEthereumAddressRegistered)?;
+            <EthereumAddressAccounts<T>>::remove(ethereum_address);
+
+            Self::deposit_event(Event::EthereumAddressUnregistered { account, ethereum_address });
+            Ok(())
+        }
+
+        /// Accepts lifts of `token` from `bridge_contract`, in addition to the lifts from
+        /// `AvnBridgeContractAddress`
+        #[pallet::call_index(3)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::register_bridge_contract_token())]
+        pub fn register_bridge_contract_token(
+            origin: OriginFor<T>,
+            bridge_contract: H160,
+            token: H160,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+            ensure!(
+                !bridge_contract.is_zero() && !token.is_zero(),
+                Error::<T>::InvalidContractAddress
+            );
+
+            <BridgeContractTokens<T>>::insert(bridge_contract, token, ());
+            Self::deposit_event(Event::BridgeContractTokenRegistered { bridge_contract, token });
+            Ok(())
+        }
+
+        /// Stops accepting lifts of `token` from `bridge_contract`
+        #[pallet::call_index(4)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::deregister_bridge_contract_token())]
+        pub fn deregister_bridge_contract_token(
+            origin: OriginFor<T>,
+            bridge_contract: H160,
+            token: H160,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+            ensure!(
+                <BridgeContractTokens<T>>::contains_key(bridge_contract, token),
+                Error::<T>::BridgeContractTokenNotRegistered
+            );
+
+            <BridgeContractTokens<T>>::remove(bridge_contract, token);
+            Self::deposit_event(Event::BridgeContractTokenDeregistered { bridge_contract, token });
+            Ok(())
+        }
+
+        /// Stops (or resumes) accepting signed payloads that were built without the signing domain
+        /// of the pallet verifying them
+        #[pallet::call_index(5)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::set_legacy_signed_payloads_rejected())]
+        pub fn set_legacy_signed_payloads_rejected(
+            origin: OriginFor<T>,
+            rejected: bool,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+
+            <LegacySignedPayloadsRejected<T>>::put(rejected);
+            Self::deposit_event(Event::LegacySignedPayloadsRejectionSet { rejected });
+            Ok(())
+        }
     }
 }
 
 impl<T: Config> Pallet<T> {
+    /// Whether proofs over payloads without a pallet signing domain can still be verified
+    pub fn legacy_signed_payloads_accepted() -> bool {
+        !Self::legacy_signed_payloads_rejected()
+    }
+
+    /// Deposits the event built from the next global event sequence number, which is also the
+    /// topic of the event, and returns the number
+    pub fn deposit_sequenced_event(
+        event: impl FnOnce(u64) -> <T as frame_system::Config>::RuntimeEvent,
+    ) -> u64 {
+        let sequence = <NextEventSequence<T>>::mutate(|next| {
+            let sequence = *next;
+            *next = next.saturating_add(1);
+            sequence
+        });
+        <frame_system::Pallet<T>>::deposit_event_indexed(
+            &[Self::event_sequence_topic(sequence)],
+            event(sequence),
+        );
+        sequence
+    }
+
+    /// The data an Ethereum key signs to register its address for `account`
+    pub fn encode_register_ethereum_address_params(account: &T::AccountId) -> Vec<u8> {
+        (REGISTER_ETHEREUM_ADDRESS_CONTEXT, account).encode()
+    }
+
+    /// Whether lifts of `token` are accepted from `contract_address`. The main bridge contract
+    /// accepts all tokens.
+    pub fn is_lift_contract_valid(contract_address: &H160, token: &H160) -> bool {
+        *contract_address == Self::get_bridge_contract_address() ||
+            <BridgeContractTokens<T>>::contains_key(contract_address, token)
+    }
+
+    /// The main bridge contract followed by the other bridge contracts that lifts are accepted
+    /// from
+    pub fn bridge_contracts() -> Vec<H160> {
+        let mut contracts = vec![Self::get_bridge_contract_address()];
+        for contract in <BridgeContractTokens<T>>::iter_keys().map(|(contract, _)| contract) {
+            if !contracts.contains(&contract) {
+                contracts.push(contract);
+            }
+        }
+        contracts
+    }
+
+    /// The tokens that can be lifted from the bridge contracts other than the main one, each
+    /// listed once
+    pub fn bridge_contract_tokens() -> Vec<H160> {
+        let mut tokens = Vec::new();
+        for token in <BridgeContractTokens<T>>::iter_keys().map(|(_, token)| token) {
+            if !tokens.contains(&token) {
+                tokens.push(token);
+            }
+        }
+        tokens
+    }
+
+    /// The topic of a sequenced event: the sequence number in big endian, left padded with zeros
+    pub fn event_sequence_topic(sequence: u64) -> T::Hash {
+        let mut topic = T::Hash::default();
+        let len = topic.as_ref().len();
+        topic.as_mut()[len - 8..].copy_from_slice(&sequence.to_be_bytes());
+        topic
+    }
+
     pub fn pre_run_setup(
         block_number: BlockNumberFor<T>,
         caller_id: Vec<u8>,
@@ -569,6 +901,42 @@ impl<T: Config> Pallet<T> {
         Ok(response.body().collect())
     }
 
+    /// Registers a task for the offchain worker of `owner`. Hints are best effort: pallets still
+    /// enqueue the work they find in their own storage, so a hint that does not fit is dropped.
+    pub fn hint_ocw_task(owner: &[u8], id: &[u8], priority: OcwTaskPriority) {
+        let hint = match (owner.to_vec().try_into(), id.to_vec().try_into()) {
+            (Ok(owner), Ok(id)) => OcwTaskHint { owner, id, priority },
+            _ => {
+                log::warn!("❌ Offchain worker task id {:?} of {:?} is too long", id, owner);
+                return
+            },
+        };
+
+        if <OcwTaskHints<T>>::try_append(hint).is_err() {
+            log::warn!(
+                "❌ Too many offchain worker tasks hinted, task {:?} of {:?} dropped",
+                id,
+                owner
+            );
+        }
+    }
+
+    /// Adds the tasks hinted for `owner` in this block to the offchain task queue. Returns the
+    /// number of tasks that were not queued yet.
+    pub fn enqueue_hinted_ocw_tasks(owner: &[u8], block_number: BlockNumberFor<T>) -> u32 {
+        Self::ocw_task_hints()
+            .into_iter()
+            .filter(|hint| hint.owner.as_slice() == owner)
+            .filter(|hint| {
+                OcwTaskQueue::enqueue(owner, &hint.id, hint.priority, block_number)
+                    .map_err(|e| {
+                        log::error!("❌ Unable to enqueue offchain worker task {:?}: {:?}", hint, e)
+                    })
+                    .unwrap_or(false)
+            })
+            .count() as u32
+    }
+
     pub fn get_ocw_locker<'a>(
         lock_name: &'a [u8],
     ) -> StorageLock<'a, BlockAndTime<frame_system::Pallet<T>>> {
@@ -654,6 +1022,12 @@ pub trait AccountToBytesConverter<AccountId: Decode + Encode> {
     fn try_from_any(bytes: Vec<u8>) -> Result<AccountId, DispatchError>;
 }
 
+impl<T: Config> EthereumAddressMapping<T::AccountId> for Pallet<T> {
+    fn account_of(ethereum_address: &H160) -> Option<T::AccountId> {
+        Self::account_of_ethereum_address(ethereum_address)
+    }
+}
+
 impl<T: Config> AccountToBytesConverter<T::AccountId> for Pallet<T> {
     fn into_bytes(account: &T::AccountId) -> [u8; 32] {
         let bytes = account.encode();
@@ -707,6 +1081,7 @@ impl<AccountId: Member> DisabledValidatorChecker<AccountId> for () {
 
 pub trait ValidatorRegistrationNotifier<ValidatorId: Member> {
     fn on_validator_registration(validator_id: &ValidatorId);
+    fn on_unresponsive_validator_removal_scheduled(_validator_id: &ValidatorId) {}
 }
 
 impl<ValidatorId: Member> ValidatorRegistrationNotifier<ValidatorId> for () {
@@ -714,18 +1089,74 @@ impl<ValidatorId: Member> ValidatorRegistrationNotifier<ValidatorId> for () {
 }
 
 pub trait Enforcer<ValidatorId: Member> {
-    fn slash_validator(slashed_validator_id: &ValidatorId) -> DispatchResult;
+    /// Slashes a validator. `checkpoint` is the most recent validator set checkpoint published on
+    /// Ethereum, which Ethereum needs to verify the validator was part of the set.
+    fn slash_validator(
+        slashed_validator_id: &ValidatorId,
+        checkpoint: Option<ValidatorSetCheckpointId>,
+    ) -> DispatchResult;
 }
 
 impl<ValidatorId: Member> Enforcer<ValidatorId> for () {
-    fn slash_validator(_slashed_validator_id: &ValidatorId) -> DispatchResult {
+    fn slash_validator(
+        _slashed_validator_id: &ValidatorId,
+        _checkpoint: Option<ValidatorSetCheckpointId>,
+    ) -> DispatchResult {
         Ok(())
     }
 }
 
+pub trait OffenderChiller<ValidatorId: Member> {
+    /// Stops a disabled offender from being selected as a validator. Returns true if the offender
+    /// was taken offline.
+    fn chill(offender: &ValidatorId) -> bool;
+    /// Lets a re-enabled offender be selected again
+    fn unchill(offender: &ValidatorId) -> DispatchResult;
+}
+
+impl<ValidatorId: Member> OffenderChiller<ValidatorId> for () {
+    fn chill(_offender: &ValidatorId) -> bool {
+        false
+    }
+
+    fn unchill(_offender: &ValidatorId) -> DispatchResult {
+        Ok(())
+    }
+}
+
+pub trait DisabledOffenderCheck<ValidatorId: Member> {
+    /// Whether the offender is disabled because of an offence and has not been enabled again
+    fn is_disabled(offender: &ValidatorId) -> bool;
+}
+
+impl<ValidatorId: Member> DisabledOffenderCheck<ValidatorId> for () {
+    fn is_disabled(_offender: &ValidatorId) -> bool {
+        false
+    }
+}
+
+/// Identifies a snapshot of the validator set and its stake published on Ethereum
+pub type ValidatorSetCheckpointId = u32;
+
+pub trait ValidatorSetCheckpointProvider {
+    /// The most recent checkpoint Ethereum has confirmed
+    fn latest_published_checkpoint() -> Option<ValidatorSetCheckpointId>;
+}
+
+impl ValidatorSetCheckpointProvider for () {
+    fn latest_published_checkpoint() -> Option<ValidatorSetCheckpointId> {
+        None
+    }
+}
+
 pub trait ProcessedEventsChecker {
     fn processed_event_exists(event_id: &EthEventId) -> bool;
     fn add_processed_event(event_id: &EthEventId, accepted: bool);
+
+    /// Checks an event that is no longer in storage because its processing period was archived
+    fn archived_event_exists(_event_id: &EthEventId, _proof: &ArchivedEventProof) -> bool {
+        false
+    }
 }
 
 impl ProcessedEventsChecker for () {
@@ -736,6 +1167,17 @@ impl ProcessedEventsChecker for () {
     fn add_processed_event(_event_id: &EthEventId, _accepted: bool) {}
 }
 
+pub trait NftContractChecker {
+    /// Returns true if `contract` is a tier 1 nft contract known to AvN
+    fn is_nft_contract(contract: &H160) -> bool;
+}
+
+impl NftContractChecker for () {
+    fn is_nft_contract(_contract: &H160) -> bool {
+        false
+    }
+}
+
 pub trait OnGrowthLiftedHandler<Balance> {
     fn on_growth_lifted(amount: Balance, growth_period: u32) -> DispatchResult;
 }
@@ -746,13 +1188,33 @@ impl<Balance> OnGrowthLiftedHandler<Balance> for () {
     }
 }
 
-// Trait that handles dust amounts after paying collators for producing blocks
+// Trait that handles dust amounts after paying collators for producing blocks in `growth_period`
 pub trait CollatorPayoutDustHandler<Balance> {
-    fn handle_dust(imbalance: Balance);
+    fn handle_dust(imbalance: Balance, growth_period: u32);
 }
 
 impl<Balance> CollatorPayoutDustHandler<Balance> for () {
-    fn handle_dust(_imbalance: Balance) {}
+    fn handle_dust(_imbalance: Balance, _growth_period: u32) {}
+}
+
+/// Notified of each vote a validator casts, so its participation in the votes can be rewarded
+pub trait VotingParticipationHandler<AccountId: Member> {
+    fn on_vote_cast(voter: &AccountId);
+}
+
+impl<AccountId: Member> VotingParticipationHandler<AccountId> for () {
+    fn on_vote_cast(_voter: &AccountId) {}
+}
+
+/// Gives the stake backing a validator in the current era
+pub trait ValidatorStakeProvider<AccountId: Member> {
+    fn backing_stake(validator: &AccountId) -> u128;
+}
+
+impl<AccountId: Member> ValidatorStakeProvider<AccountId> for () {
+    fn backing_stake(_validator: &AccountId) -> u128 {
+        0
+    }
 }
 
 pub trait BridgeInterface {
@@ -0,0 +1,102 @@
+//! Multi-block storage migrations for the AvN pallets.
+//!
+//! A migration moves the storage of a pallet from one storage version to the next. It runs in
+//! steps bounded by a weight limit, so a migration touching many keys can be spread over several
+//! blocks. The cursor of the migration in progress is stored under the prefix of the pallet, so
+//! it resumes where it stopped in the next block, and the storage version of the pallet is only
+//! bumped once the last step completes.
+
+use codec::FullCodec;
+use frame_support::{
+    storage::unhashed,
+    traits::{Get, PalletInfoAccess, StorageVersion},
+    weights::Weight,
+};
+use sp_io::hashing::twox_128;
+use sp_runtime::Perbill;
+use sp_std::prelude::*;
+
+/// Share of the maximum block weight a pallet can use to run its migrations in a block
+pub const MAX_BLOCK_WEIGHT_SHARE: Perbill = Perbill::from_percent(10);
+
+const MIGRATION_CURSOR: &[u8] = b"MigrationCursor";
+
+/// A storage migration that can be split over several blocks
+pub trait SteppedMigration {
+    /// The pallet whose storage is migrated
+    type Pallet: PalletInfoAccess;
+    /// The position to resume the migration from
+    type Cursor: FullCodec;
+
+    /// The storage version the migration applies to
+    const FROM: u16;
+    /// The storage version of the pallet once the migration is complete
+    const TO: u16;
+
+    /// Migrates the storage from `cursor`, or from the start if there is none, without using more
+    /// than `limit`. Returns the weight used and the cursor to resume from, or None once the
+    /// migration is complete.
+    fn step(cursor: Option<Self::Cursor>, limit: Weight) -> (Weight, Option<Self::Cursor>);
+}
+
+/// An ordered list of migrations. Each one only runs once the pallet reaches its `FROM` version.
+pub trait SteppedMigrations {
+    /// Runs the pending migrations without using more than `limit`. Returns the weight used.
+    fn step<T: frame_system::Config>(limit: Weight) -> Weight;
+}
+
+#[impl_trait_for_tuples::impl_for_tuples(10)]
+#[tuple_types_custom_trait_bound(SteppedMigration)]
+impl SteppedMigrations for Tuple {
+    fn step<T: frame_system::Config>(limit: Weight) -> Weight {
+        let mut used = Weight::zero();
+        for_tuples!( #(
+            used = used.saturating_add(step_migration::<T, Tuple>(limit.saturating_sub(used)));
+        )* );
+        used
+    }
+}
+
+/// The maximum weight a pallet should use for its migrations in a block
+pub fn block_limit<T: frame_system::Config>() -> Weight {
+    MAX_BLOCK_WEIGHT_SHARE * T::BlockWeights::get().max_block
+}
+
+/// Runs the next steps of `Migrations` within the migration weight limit of a block
+pub fn migrate<T: frame_system::Config, Migrations: SteppedMigrations>() -> Weight {
+    Migrations::step::<T>(block_limit::<T>())
+}
+
+fn cursor_key<P: PalletInfoAccess>() -> Vec<u8> {
+    [twox_128(P::name().as_bytes()), twox_128(MIGRATION_CURSOR)].concat()
+}
+
+fn step_migration<T: frame_system::Config, M: SteppedMigration>(limit: Weight) -> Weight {
+    let mut used = T::DbWeight::get().reads(1);
+    if StorageVersion::get::<M::Pallet>() != M::FROM || used.any_gt(limit) {
+        return used;
+    }
+
+    let key = cursor_key::<M::Pallet>();
+    let cursor = unhashed::get::<M::Cursor>(&key);
+    used = used.saturating_add(T::DbWeight::get().reads_writes(1, 2));
+
+    let (step_weight, next_cursor) = M::step(cursor, limit.saturating_sub(used));
+    used = used.saturating_add(step_weight);
+
+    match next_cursor {
+        Some(cursor) => unhashed::put(&key, &cursor),
+        None => {
+            unhashed::kill(&key);
+            StorageVersion::new(M::TO).put::<M::Pallet>();
+            log::info!(
+                "✅ {} storage migrated from version {} to {}",
+                M::Pallet::name(),
+                M::FROM,
+                M::TO
+            );
+        },
+    }
+
+    used
+}
@@ -0,0 +1,152 @@
+// Copyright 2024 Aventus Network Services (UK) Ltd.
+#![cfg(test)]
+
+use crate::{mock::*, *};
+use frame_support::{assert_noop, assert_ok};
+use frame_system::RawOrigin;
+use sp_runtime::traits::BadOrigin;
+
+const NEW_BRIDGE_CONTRACT: H160 = H160([15u8; 20]);
+const TOKEN: H160 = H160([16u8; 20]);
+const OTHER_TOKEN: H160 = H160([17u8; 20]);
+const OTHER_BRIDGE_CONTRACT: H160 = H160([18u8; 20]);
+
+fn event_emitted(event: Event<TestRuntime>) -> bool {
+    System::events().iter().any(|a| a.event == RuntimeEvent::Avn(event.clone()))
+}
+
+fn register(bridge_contract: H160, token: H160) -> DispatchResult {
+    AVN::register_bridge_contract_token(RawOrigin::Root.into(), bridge_contract, token)
+}
+
+mod register_bridge_contract_token {
+    use super::*;
+
+    #[test]
+    fn accepts_lifts_of_the_token_from_the_contract() {
+        let mut ext = ExtBuilder::build_default().with_genesis_config().as_externality();
+        ext.execute_with(|| {
+            assert!(!AVN::is_lift_contract_valid(&NEW_BRIDGE_CONTRACT, &TOKEN));
+
+            assert_ok!(register(NEW_BRIDGE_CONTRACT, TOKEN));
+
+            assert!(AVN::is_lift_contract_valid(&NEW_BRIDGE_CONTRACT, &TOKEN));
+            assert!(!AVN::is_lift_contract_valid(&NEW_BRIDGE_CONTRACT, &OTHER_TOKEN));
+            assert!(event_emitted(Event::BridgeContractTokenRegistered {
+                bridge_contract: NEW_BRIDGE_CONTRACT,
+                token: TOKEN,
+            }));
+        });
+    }
+
+    #[test]
+    fn keeps_accepting_all_tokens_from_the_main_contract() {
+        let mut ext = ExtBuilder::build_default().with_genesis_config().as_externality();
+        ext.execute_with(|| {
+            assert_ok!(register(NEW_BRIDGE_CONTRACT, TOKEN));
+
+            assert!(AVN::is_lift_contract_valid(&CUSTOM_BRIDGE_CONTRACT, &TOKEN));
+            assert!(AVN::is_lift_contract_valid(&CUSTOM_BRIDGE_CONTRACT, &OTHER_TOKEN));
+        });
+    }
+
+    mod fails_when {
+        use super::*;
+
+        #[test]
+        fn an_address_is_zero() {
+            let mut ext = ExtBuilder::build_default().with_genesis_config().as_externality();
+            ext.execute_with(|| {
+                assert_noop!(
+                    register(H160::zero(), TOKEN),
+                    Error::<TestRuntime>::InvalidContractAddress
+                );
+                assert_noop!(
+                    register(NEW_BRIDGE_CONTRACT, H160::zero()),
+                    Error::<TestRuntime>::InvalidContractAddress
+                );
+            });
+        }
+
+        #[test]
+        fn origin_is_not_root() {
+            let mut ext = ExtBuilder::build_default().with_genesis_config().as_externality();
+            ext.execute_with(|| {
+                assert_noop!(
+                    AVN::register_bridge_contract_token(
+                        RuntimeOrigin::signed(Default::default()),
+                        NEW_BRIDGE_CONTRACT,
+                        TOKEN
+                    ),
+                    BadOrigin
+                );
+            });
+        }
+    }
+}
+
+mod deregister_bridge_contract_token {
+    use super::*;
+
+    #[test]
+    fn stops_accepting_lifts_of_the_token_from_the_contract() {
+        let mut ext = ExtBuilder::build_default().with_genesis_config().as_externality();
+        ext.execute_with(|| {
+            assert_ok!(register(NEW_BRIDGE_CONTRACT, TOKEN));
+
+            assert_ok!(AVN::deregister_bridge_contract_token(
+                RawOrigin::Root.into(),
+                NEW_BRIDGE_CONTRACT,
+                TOKEN
+            ));
+
+            assert!(!AVN::is_lift_contract_valid(&NEW_BRIDGE_CONTRACT, &TOKEN));
+            assert!(event_emitted(Event::BridgeContractTokenDeregistered {
+                bridge_contract: NEW_BRIDGE_CONTRACT,
+                token: TOKEN,
+            }));
+        });
+    }
+
+    #[test]
+    fn fails_when_the_token_is_not_registered() {
+        let mut ext = ExtBuilder::build_default().with_genesis_config().as_externality();
+        ext.execute_with(|| {
+            assert_noop!(
+                AVN::deregister_bridge_contract_token(
+                    RawOrigin::Root.into(),
+                    NEW_BRIDGE_CONTRACT,
+                    TOKEN
+                ),
+                Error::<TestRuntime>::BridgeContractTokenNotRegistered
+            );
+        });
+    }
+}
+
+#[test]
+fn bridge_contracts_are_the_main_contract_and_each_registered_contract_once() {
+    let mut ext = ExtBuilder::build_default().with_genesis_config().as_externality();
+    ext.execute_with(|| {
+        assert_ok!(register(NEW_BRIDGE_CONTRACT, TOKEN));
+        assert_ok!(register(NEW_BRIDGE_CONTRACT, OTHER_TOKEN));
+
+        assert_eq!(AVN::bridge_contracts(), vec![CUSTOM_BRIDGE_CONTRACT, NEW_BRIDGE_CONTRACT]);
+    });
+}
+
+#[test]
+fn bridge_contract_tokens_are_listed_once() {
+    let mut ext = ExtBuilder::build_default().with_genesis_config().as_externality();
+    ext.execute_with(|| {
+        assert!(AVN::bridge_contract_tokens().is_empty());
+
+        assert_ok!(register(NEW_BRIDGE_CONTRACT, TOKEN));
+        assert_ok!(register(OTHER_BRIDGE_CONTRACT, TOKEN));
+        assert_ok!(register(OTHER_BRIDGE_CONTRACT, OTHER_TOKEN));
+
+        let mut tokens = AVN::bridge_contract_tokens();
+        tokens.sort();
+        assert_eq!(tokens, vec![TOKEN, OTHER_TOKEN]);
+    });
+}
@@ -0,0 +1,191 @@
+// Copyright 2024 Aventus Network Services (UK) Ltd.
+#![cfg(test)]
+
+use crate::{mock::*, *};
+use frame_support::{assert_noop, assert_ok};
+use sp_avn_common::hash_string_data_with_ethereum_prefix;
+use sp_core::{ecdsa, Pair};
+use sp_runtime::traits::BadOrigin;
+
+const ACCOUNT: AccountId = 7;
+const OTHER_ACCOUNT: AccountId = 8;
+
+struct EthereumKey {
+    pair: ecdsa::Pair,
+}
+
+impl EthereumKey {
+    fn new(seed: u8) -> Self {
+        EthereumKey { pair: ecdsa::Pair::from_seed(&[seed; 32]) }
+    }
+
+    fn sign_registration(&self, account: AccountId) -> ecdsa::Signature {
+        let signed_data = AVN::encode_register_ethereum_address_params(&account);
+        let hash = hash_string_data_with_ethereum_prefix(&signed_data).expect("Data can be hashed");
+        self.pair.sign_prehashed(&hash)
+    }
+
+    fn address(&self) -> H160 {
+        let signed_data = AVN::encode_register_ethereum_address_params(&ACCOUNT);
+        recover_ethereum_address_from_ecdsa_signature(
+            &self.sign_registration(ACCOUNT),
+            &signed_data,
+            HashMessageFormat::String,
+        )
+        .map(H160::from)
+        .expect("Signature is valid")
+    }
+
+    fn register(&self, account: AccountId) -> DispatchResult {
+        AVN::register_ethereum_address(
+            RuntimeOrigin::signed(account),
+            self.address(),
+            self.sign_registration(account),
+        )
+    }
+}
+
+fn event_emitted(event: Event<TestRuntime>) -> bool {
+    System::events()
+        .iter()
+        .any(|record| record.event == RuntimeEvent::Avn(event.clone()))
+}
+
+mod register_ethereum_address {
+    use super::*;
+
+    #[test]
+    fn maps_the_address_both_ways() {
+        let mut ext = ExtBuilder::build_default().as_externality();
+        ext.execute_with(|| {
+            System::set_block_number(1);
+            let key = EthereumKey::new(1);
+
+            assert_ok!(key.register(ACCOUNT));
+
+            assert_eq!(AVN::account_of_ethereum_address(key.address()), Some(ACCOUNT));
+            assert_eq!(AVN::ethereum_address_of_account(ACCOUNT), Some(key.address()));
+            assert!(event_emitted(Event::EthereumAddressRegistered {
+                account: ACCOUNT,
+                ethereum_address: key.address(),
+            }));
+        });
+    }
+
+    #[test]
+    fn replaces_the_previous_address_of_the_account() {
+        let mut ext = ExtBuilder::build_default().as_externality();
+        ext.execute_with(|| {
+            let old_key = EthereumKey::new(1);
+            let new_key = EthereumKey::new(2);
+            assert_ok!(old_key.register(ACCOUNT));
+
+            assert_ok!(new_key.register(ACCOUNT));
+
+            assert_eq!(AVN::account_of_ethereum_address(old_key.address()), None);
+            assert_eq!(AVN::account_of_ethereum_address(new_key.address()), Some(ACCOUNT));
+            assert_eq!(AVN::ethereum_address_of_account(ACCOUNT), Some(new_key.address()));
+        });
+    }
+
+    mod fails_when {
+        use super::*;
+
+        #[test]
+        fn origin_is_not_signed() {
+            let mut ext = ExtBuilder::build_default().as_externality();
+            ext.execute_with(|| {
+                let key = EthereumKey::new(1);
+                assert_noop!(
+                    AVN::register_ethereum_address(
+                        RuntimeOrigin::root(),
+                        key.address(),
+                        key.sign_registration(ACCOUNT)
+                    ),
+                    BadOrigin
+                );
+            });
+        }
+
+        #[test]
+        fn signature_is_for_another_account() {
+            let mut ext = ExtBuilder::build_default().as_externality();
+            ext.execute_with(|| {
+                let key = EthereumKey::new(1);
+                assert_noop!(
+                    AVN::register_ethereum_address(
+                        RuntimeOrigin::signed(OTHER_ACCOUNT),
+                        key.address(),
+                        key.sign_registration(ACCOUNT)
+                    ),
+                    Error::<TestRuntime>::InvalidEthereumAddressSignature
+                );
+            });
+        }
+
+        #[test]
+        fn signature_is_not_from_the_address() {
+            let mut ext = ExtBuilder::build_default().as_externality();
+            ext.execute_with(|| {
+                let key = EthereumKey::new(1);
+                let other_key = EthereumKey::new(2);
+                assert_noop!(
+                    AVN::register_ethereum_address(
+                        RuntimeOrigin::signed(ACCOUNT),
+                        other_key.address(),
+                        key.sign_registration(ACCOUNT)
+                    ),
+                    Error::<TestRuntime>::InvalidEthereumAddressSignature
+                );
+            });
+        }
+
+        #[test]
+        fn address_is_registered_to_another_account() {
+            let mut ext = ExtBuilder::build_default().as_externality();
+            ext.execute_with(|| {
+                let key = EthereumKey::new(1);
+                assert_ok!(key.register(OTHER_ACCOUNT));
+
+                assert_noop!(
+                    key.register(ACCOUNT),
+                    Error::<TestRuntime>::EthereumAddressRegisteredToAnotherAccount
+                );
+            });
+        }
+    }
+}
+
+mod unregister_ethereum_address {
+    use super::*;
+
+    #[test]
+    fn removes_both_mappings() {
+        let mut ext = ExtBuilder::build_default().as_externality();
+        ext.execute_with(|| {
+            System::set_block_number(1);
+            let key = EthereumKey::new(1);
+            assert_ok!(key.register(ACCOUNT));
+
+            assert_ok!(AVN::unregister_ethereum_address(RuntimeOrigin::signed(ACCOUNT)));
+
+            assert_eq!(AVN::account_of_ethereum_address(key.address()), None);
+            assert_eq!(AVN::ethereum_address_of_account(ACCOUNT), None);
+            assert!(event_emitted(Event::EthereumAddressUnregistered {
+                account: ACCOUNT,
+                ethereum_address: key.address(),
+            }));
+        });
+    }
+
+    #[test]
+    fn fails_when_no_address_is_registered() {
+        let mut ext = ExtBuilder::build_default().as_externality();
+        ext.execute_with(|| {
+            assert_noop!(
+                AVN::unregister_ethereum_address(RuntimeOrigin::signed(ACCOUNT)),
+                Error::<TestRuntime>::NoEthereumAddressRegistered
+            );
+        });
+    }
+}
@@ -0,0 +1,74 @@
+// Copyright 2024 Aventus Network Services (UK) Ltd.
+#![cfg(test)]
+
+use crate::{mock::*, *};
+use sp_core::H256;
+
+fn bridge_contract_updated(new_contract: H160) -> RuntimeEvent {
+    RuntimeEvent::Avn(Event::AvnBridgeContractUpdated { old_contract: H160::zero(), new_contract })
+}
+
+#[test]
+fn sequenced_events_get_consecutive_numbers() {
+    let mut ext = ExtBuilder::build_default().as_externality();
+    ext.execute_with(|| {
+        assert_eq!(AVN::next_event_sequence(), 0);
+
+        assert_eq!(
+            AVN::deposit_sequenced_event(|_| bridge_contract_updated(H160::from([1u8; 20]))),
+            0
+        );
+        assert_eq!(
+            AVN::deposit_sequenced_event(|_| bridge_contract_updated(H160::from([2u8; 20]))),
+            1
+        );
+
+        assert_eq!(AVN::next_event_sequence(), 2);
+    });
+}
+
+#[test]
+fn sequenced_events_are_indexed_by_their_number() {
+    let mut ext = ExtBuilder::build_default().as_externality();
+    ext.execute_with(|| {
+        <NextEventSequence<TestRuntime>>::put(0x0102);
+        let event = bridge_contract_updated(H160::from([1u8; 20]));
+
+        AVN::deposit_sequenced_event(|_| event.clone());
+
+        let record = System::events().pop().expect("Event is deposited");
+        assert_eq!(record.event, event);
+        assert_eq!(record.topics, vec![H256::from_low_u64_be(0x0102)]);
+        assert_eq!(System::event_topics(H256::from_low_u64_be(0x0102)), vec![(1, 0)]);
+    });
+}
+
+#[test]
+fn sequence_is_not_reset_between_blocks() {
+    let mut ext = ExtBuilder::build_default().as_externality();
+    ext.execute_with(|| {
+        AVN::deposit_sequenced_event(|_| bridge_contract_updated(H160::from([1u8; 20])));
+        System::reset_events();
+        System::set_block_number(2);
+
+        assert_eq!(
+            AVN::deposit_sequenced_event(|_| bridge_contract_updated(H160::from([2u8; 20]))),
+            1
+        );
+    });
+}
+
+#[test]
+fn sequenced_events_are_built_with_their_number() {
+    let mut ext = ExtBuilder::build_default().as_externality();
+    ext.execute_with(|| {
+        <NextEventSequence<TestRuntime>>::put(7);
+
+        AVN::deposit_sequenced_event(|sequence| {
+            bridge_contract_updated(H160::from_low_u64_be(sequence))
+        });
+
+        let record = System::events().pop().expect("Event is deposited");
+        assert_eq!(record.event, bridge_contract_updated(H160::from_low_u64_be(7)));
+    });
+}
@@ -0,0 +1,91 @@
+// Copyright 2024 Aventus Network Services (UK) Ltd.
+#![cfg(test)]
+
+use crate::{mock::*, *};
+use frame_support::traits::Hooks;
+use sp_avn_common::ocw_task_queue::queued_tasks;
+use sp_core::offchain::{testing::TestOffchainExt, OffchainDbExt};
+
+const SUMMARY: &[u8] = b"summary";
+const BRIDGE: &[u8] = b"eth_bridge";
+
+fn offchain_externality() -> sp_io::TestExternalities {
+    let mut ext = ExtBuilder::build_default().as_externality();
+    let (offchain, _state) = TestOffchainExt::new();
+    ext.register_extension(OffchainDbExt::new(offchain));
+    ext
+}
+
+#[test]
+fn hinted_tasks_are_stored_for_the_block() {
+    let mut ext = ExtBuilder::build_default().as_externality();
+    ext.execute_with(|| {
+        AVN::hint_ocw_task(SUMMARY, b"root", OcwTaskPriority::High);
+
+        assert_eq!(
+            AVN::ocw_task_hints().into_inner(),
+            vec![OcwTaskHint {
+                owner: SUMMARY.to_vec().try_into().unwrap(),
+                id: b"root".to_vec().try_into().unwrap(),
+                priority: OcwTaskPriority::High,
+            }]
+        );
+    });
+}
+
+#[test]
+fn hints_are_cleared_at_the_start_of_the_next_block() {
+    let mut ext = ExtBuilder::build_default().as_externality();
+    ext.execute_with(|| {
+        AVN::hint_ocw_task(SUMMARY, b"root", OcwTaskPriority::High);
+
+        AVN::on_initialize(2);
+
+        assert!(AVN::ocw_task_hints().is_empty());
+    });
+}
+
+#[test]
+fn hints_that_do_not_fit_are_dropped() {
+    let mut ext = ExtBuilder::build_default().as_externality();
+    ext.execute_with(|| {
+        AVN::hint_ocw_task(
+            SUMMARY,
+            &[1u8; MAX_OCW_TASK_ID_LENGTH as usize + 1],
+            OcwTaskPriority::High,
+        );
+        assert!(AVN::ocw_task_hints().is_empty());
+
+        for i in 0..MAX_OCW_TASK_HINTS + 1 {
+            AVN::hint_ocw_task(SUMMARY, &i.encode(), OcwTaskPriority::Normal);
+        }
+        assert_eq!(AVN::ocw_task_hints().len() as u32, MAX_OCW_TASK_HINTS);
+    });
+}
+
+#[test]
+fn the_hints_of_an_owner_are_added_to_the_task_queue() {
+    offchain_externality().execute_with(|| {
+        AVN::hint_ocw_task(SUMMARY, b"root", OcwTaskPriority::High);
+        AVN::hint_ocw_task(BRIDGE, b"request", OcwTaskPriority::Normal);
+
+        assert_eq!(AVN::enqueue_hinted_ocw_tasks(SUMMARY, 1), 1);
+
+        let tasks = queued_tasks::<BlockNumberFor<TestRuntime>>(None);
+        assert_eq!(tasks.len(), 1);
+        assert_eq!(tasks[0].owner, SUMMARY.to_vec());
+        assert_eq!(tasks[0].id, b"root".to_vec());
+        assert_eq!(tasks[0].priority, OcwTaskPriority::High);
+    });
+}
+
+#[test]
+fn hints_already_in_the_task_queue_are_not_added_again() {
+    offchain_externality().execute_with(|| {
+        AVN::hint_ocw_task(SUMMARY, b"root", OcwTaskPriority::High);
+        assert_eq!(AVN::enqueue_hinted_ocw_tasks(SUMMARY, 1), 1);
+
+        assert_eq!(AVN::enqueue_hinted_ocw_tasks(SUMMARY, 1), 0);
+        assert_eq!(queued_tasks::<BlockNumberFor<TestRuntime>>(Some(SUMMARY)).len(), 1);
+    });
+}
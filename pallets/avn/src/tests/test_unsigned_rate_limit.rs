@@ -0,0 +1,82 @@
+// Copyright 2024 Aventus Network Services (UK) Ltd.
+
+#![cfg(test)]
+
+use crate::unsigned_rate_limit::*;
+
+const LIMIT: UnsignedRateLimit<u64> =
+    UnsignedRateLimit { max_submissions: 2, window: 10, abuse_threshold: 3 };
+
+fn saturate_window(window: &mut SubmissionWindow<u64>, now: u64) -> bool {
+    let first = window.record_submission(now, &LIMIT);
+    let second = window.record_submission(now, &LIMIT);
+    assert!(!first);
+    second
+}
+
+#[test]
+fn submissions_are_allowed_until_the_limit_of_the_window_is_reached() {
+    let mut window = SubmissionWindow::<u64>::default();
+
+    assert!(window.allows_submission(1, &LIMIT));
+    window.record_submission(1, &LIMIT);
+    assert!(window.allows_submission(1, &LIMIT));
+    window.record_submission(1, &LIMIT);
+
+    assert!(!window.allows_submission(1, &LIMIT));
+    assert!(!window.allows_submission(10, &LIMIT));
+    assert!(window.allows_submission(11, &LIMIT));
+}
+
+#[test]
+fn a_new_window_resets_the_submissions() {
+    let mut window = SubmissionWindow::<u64>::default();
+    saturate_window(&mut window, 1);
+
+    window.record_submission(11, &LIMIT);
+
+    assert_eq!(window, SubmissionWindow { start: 11, submissions: 1, saturated_windows: 1 });
+}
+
+#[test]
+fn reaching_the_limit_in_consecutive_windows_is_reported_as_abuse() {
+    let mut window = SubmissionWindow::<u64>::default();
+
+    assert!(!saturate_window(&mut window, 1));
+    assert!(!saturate_window(&mut window, 11));
+    assert!(saturate_window(&mut window, 21));
+
+    assert_eq!(window.saturated_windows, 0);
+}
+
+#[test]
+fn a_window_below_the_limit_breaks_the_streak() {
+    let mut window = SubmissionWindow::<u64>::default();
+
+    saturate_window(&mut window, 1);
+    saturate_window(&mut window, 11);
+    window.record_submission(21, &LIMIT);
+
+    assert!(!saturate_window(&mut window, 31));
+    assert_eq!(window.saturated_windows, 1);
+}
+
+#[test]
+fn an_idle_window_breaks_the_streak() {
+    let mut window = SubmissionWindow::<u64>::default();
+
+    saturate_window(&mut window, 1);
+    saturate_window(&mut window, 11);
+
+    assert!(!saturate_window(&mut window, 41));
+    assert_eq!(window.saturated_windows, 1);
+}
+
+#[test]
+fn a_window_expires_once_it_can_no_longer_extend_the_streak() {
+    let mut window = SubmissionWindow::<u64>::default();
+    saturate_window(&mut window, 1);
+
+    assert!(!window.is_expired(20, &LIMIT));
+    assert!(window.is_expired(21, &LIMIT));
+}
@@ -0,0 +1,128 @@
+//! Limits on how often a validator can submit unsigned transactions of a given type.
+//!
+//! Unsigned transactions are validated with the highest priority, so a faulty validator could
+//! otherwise fill the transaction pool with them. Pallets count the submissions of each validator
+//! when the transactions are executed, and reject in `validate_unsigned` the transactions of a
+//! validator that has used its allowance for the current window. Reaching the limit in several
+//! consecutive windows is reported as abuse, so the pallet can raise an offence. Once a window has
+//! expired it no longer affects the validator, so pallets remove it when they are idle.
+
+use codec::{Decode, Encode, FullCodec, MaxEncodedLen};
+use frame_support::{
+    storage::IterableStorageDoubleMap,
+    weights::{RuntimeDbWeight, Weight},
+};
+use sp_runtime::{scale_info::TypeInfo, traits::AtLeast32BitUnsigned};
+use sp_std::prelude::*;
+
+/// The allowance of a validator for one type of unsigned transaction
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, Debug, TypeInfo)]
+pub struct UnsignedRateLimit<BlockNumber> {
+    /// Transactions that can be executed in a window
+    pub max_submissions: u32,
+    /// Length of a window, in blocks
+    pub window: BlockNumber,
+    /// Consecutive windows in which the limit is reached before it is reported as abuse
+    pub abuse_threshold: u32,
+}
+
+/// The unsigned transactions of one type executed for a validator in the current window
+#[derive(Encode, Decode, Default, Clone, Copy, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+pub struct SubmissionWindow<BlockNumber> {
+    pub start: BlockNumber,
+    pub submissions: u32,
+    /// Consecutive windows, up to and including the current one, in which the limit was reached
+    pub saturated_windows: u32,
+}
+
+impl<BlockNumber: AtLeast32BitUnsigned + Copy> SubmissionWindow<BlockNumber> {
+    fn is_open(&self, now: BlockNumber, limit: &UnsignedRateLimit<BlockNumber>) -> bool {
+        now < self.start.saturating_add(limit.window)
+    }
+
+    /// Whether the window no longer affects the allowance or the count of saturated windows, so
+    /// it can be removed
+    pub fn is_expired(&self, now: BlockNumber, limit: &UnsignedRateLimit<BlockNumber>) -> bool {
+        now >= self.start.saturating_add(limit.window).saturating_add(limit.window)
+    }
+
+    /// Whether another transaction can be accepted at block `now`
+    pub fn allows_submission(
+        &self,
+        now: BlockNumber,
+        limit: &UnsignedRateLimit<BlockNumber>,
+    ) -> bool {
+        !self.is_open(now, limit) || self.submissions < limit.max_submissions
+    }
+
+    /// Counts a transaction executed at block `now`. Returns true if this submission reaches the
+    /// limit for the `abuse_threshold`th consecutive window, in which case the count of saturated
+    /// windows starts again.
+    pub fn record_submission(
+        &mut self,
+        now: BlockNumber,
+        limit: &UnsignedRateLimit<BlockNumber>,
+    ) -> bool {
+        if !self.is_open(now, limit) {
+            let previous_window_saturated = self.submissions >= limit.max_submissions;
+            if !previous_window_saturated || self.is_expired(now, limit) {
+                self.saturated_windows = 0;
+            }
+            self.start = now;
+            self.submissions = 0;
+        }
+
+        self.submissions = self.submissions.saturating_add(1);
+        if self.submissions != limit.max_submissions {
+            return false
+        }
+
+        self.saturated_windows = self.saturated_windows.saturating_add(1);
+        if self.saturated_windows < limit.abuse_threshold {
+            return false
+        }
+
+        self.saturated_windows = 0;
+        true
+    }
+}
+
+/// Removes the expired windows stored in `Windows`, reading no more windows than `weight_limit`
+/// allows. The windows are only checked once per window length, in the blocks that are a multiple
+/// of it.
+pub fn prune_expired_windows<Windows, Validator, CallType, BlockNumber>(
+    now: BlockNumber,
+    limit: &UnsignedRateLimit<BlockNumber>,
+    db_weight: RuntimeDbWeight,
+    weight_limit: Weight,
+) -> Weight
+where
+    Windows: IterableStorageDoubleMap<Validator, CallType, SubmissionWindow<BlockNumber>>,
+    Validator: FullCodec,
+    CallType: FullCodec,
+    BlockNumber: AtLeast32BitUnsigned + Copy + FullCodec,
+{
+    let mut used = Weight::zero();
+    if limit.window.is_zero() || !(now % limit.window).is_zero() {
+        return used
+    }
+
+    let window_weight = db_weight.reads_writes(1, 1);
+    let mut expired = Vec::new();
+    for (validator, call_type, window) in Windows::iter() {
+        if used.saturating_add(window_weight).any_gt(weight_limit) {
+            break
+        }
+        used = used.saturating_add(window_weight);
+
+        if window.is_expired(now, limit) {
+            expired.push((validator, call_type));
+        }
+    }
+
+    for (validator, call_type) in expired {
+        Windows::remove(validator, call_type);
+    }
+
+    used
+}
@@ -31,6 +31,7 @@ pub const APPROVE_VOTE_IS_NOT_VALID: u8 = 2;
 pub const REJECT_VOTE_IS_NOT_VALID: u8 = 3;
 pub const VOTE_SESSION_IS_NOT_VALID: u8 = 4;
 pub const VOTING_SESSION_DATA_IS_NOT_FOUND: u8 = 5;
+pub const REPLACE_VOTE_IS_NOT_VALID: u8 = 6;
 pub const APPROVE_VOTE: bool = true;
 pub const REJECT_VOTE: bool = false;
 
@@ -49,6 +50,8 @@ pub struct VotingSessionData<AccountId, BlockNumber> {
     pub end_of_voting_period: BlockNumber,
     /// The block number this session was created on
     pub created_at_block: BlockNumber,
+    /// The voters that replaced their vote. A vote can only be replaced once per session.
+    pub replaced_votes: BoundedVec<AccountId, MaximumValidatorsBound>,
 }
 
 // AccountId cannot be defaulted by the `Default` derive macro anymore so we
@@ -62,6 +65,7 @@ impl<AccountId, BlockNumber: Zero> Default for VotingSessionData<AccountId, Bloc
             nays: BoundedVec::default(),
             end_of_voting_period: Zero::zero(),
             created_at_block: Zero::zero(),
+            replaced_votes: BoundedVec::default(),
         }
     }
 }
@@ -80,6 +84,7 @@ impl<AccountId: Member, BlockNumber: Member> VotingSessionData<AccountId, BlockN
             nays: BoundedVec::default(),
             end_of_voting_period,
             created_at_block,
+            replaced_votes: BoundedVec::default(),
         }
     }
 
@@ -97,11 +102,35 @@ impl<AccountId: Member, BlockNumber: Member> VotingSessionData<AccountId, BlockN
     pub fn has_voted(&self, voter: &AccountId) -> bool {
         return self.ayes.contains(voter) || self.nays.contains(voter)
     }
+
+    // The voter cast the opposite vote and has not replaced a vote in this session yet
+    pub fn can_replace_vote(&self, voter: &AccountId, approve: bool) -> bool {
+        let opposite_votes = if approve { &self.nays } else { &self.ayes };
+        return opposite_votes.contains(voter) && !self.replaced_votes.contains(voter)
+    }
+
+    /// Moves the vote of `voter` to the opposite side
+    pub fn replace_vote(&mut self, voter: AccountId, approve: bool) -> Result<(), ()> {
+        if !self.can_replace_vote(&voter, approve) {
+            return Err(())
+        }
+
+        let (from, to) = if approve {
+            (&mut self.nays, &mut self.ayes)
+        } else {
+            (&mut self.ayes, &mut self.nays)
+        };
+        from.retain(|v| v != &voter);
+        to.try_push(voter.clone()).map_err(|_| ())?;
+        self.replaced_votes.try_push(voter).map_err(|_| ())
+    }
 }
 
 pub trait VotingSessionManager<AccountId, BlockNumber> {
     fn cast_vote_context(&self) -> &'static [u8];
 
+    fn replace_vote_context(&self) -> &'static [u8];
+
     fn end_voting_period_context(&self) -> &'static [u8];
 
     fn state(&self) -> Result<VotingSessionData<AccountId, BlockNumber>, DispatchError>;
@@ -117,6 +146,8 @@ pub trait VotingSessionManager<AccountId, BlockNumber> {
 
     fn record_reject_vote(&self, voter: AccountId) -> DispatchResult;
 
+    fn record_replaced_vote(&self, voter: AccountId, approve: bool) -> DispatchResult;
+
     fn end_voting_session(&self, sender: AccountId) -> DispatchResult;
 }
 
@@ -150,6 +181,31 @@ pub fn process_reject_vote<T: Config>(
     Ok(())
 }
 
+pub fn process_replace_vote<T: Config>(
+    voting_session: &Box<dyn VotingSessionManager<T::AccountId, BlockNumberFor<T>>>,
+    voter: T::AccountId,
+    approve: bool,
+) -> DispatchResult {
+    validate_vote_replacement::<T>(voting_session, &voter, approve)?;
+    voting_session.record_replaced_vote(voter.clone(), approve)?;
+    end_voting_if_outcome_reached::<T>(voting_session, voter)?;
+    Ok(())
+}
+
+pub fn validate_vote_replacement<T: Config>(
+    voting_session: &Box<dyn VotingSessionManager<T::AccountId, BlockNumberFor<T>>>,
+    voter: &T::AccountId,
+    approve: bool,
+) -> DispatchResult {
+    ensure!(AVN::<T>::is_validator(voter), Error::<T>::NotAValidator);
+    ensure!(voting_session.is_active(), Error::<T>::InvalidVote);
+    ensure!(
+        voting_session.state()?.can_replace_vote(voter, approve),
+        Error::<T>::VoteCannotBeReplaced
+    );
+    Ok(())
+}
+
 fn end_voting_if_outcome_reached<T: Config>(
     voting_session: &Box<dyn VotingSessionManager<T::AccountId, BlockNumberFor<T>>>,
     voter: T::AccountId,
@@ -280,3 +336,39 @@ pub fn reject_vote_validate_unsigned<T: Config>(
         .propagate(true)
         .build()
 }
+
+pub fn replace_vote_validate_unsigned<T: Config>(
+    voting_session: &Box<dyn VotingSessionManager<T::AccountId, BlockNumberFor<T>>>,
+    validator: &Validator<T::AuthorityId, T::AccountId>,
+    approve: bool,
+    signature: &<T::AuthorityId as RuntimeAppPublic>::Signature,
+) -> TransactionValidity {
+    if validate_vote_replacement::<T>(voting_session, &validator.account_id, approve).is_err() {
+        return InvalidTransaction::Custom(REPLACE_VOTE_IS_NOT_VALID).into()
+    }
+
+    let voting_session_data = voting_session.state();
+    if voting_session_data.is_err() {
+        return InvalidTransaction::Custom(VOTING_SESSION_DATA_IS_NOT_FOUND).into()
+    }
+
+    let voting_session_id =
+        voting_session_data.expect("voting session data is ok").voting_session_id;
+
+    if !AVN::<T>::signature_is_valid(
+        &(voting_session.replace_vote_context(), &voting_session_id, approve),
+        &validator,
+        signature,
+    ) {
+        return InvalidTransaction::BadProof.into()
+    };
+
+    return ValidTransaction::with_tag_prefix("vote")
+        .priority(TransactionPriority::max_value())
+        .and_provides(vec![
+            (voting_session.replace_vote_context(), voting_session_id, validator).encode()
+        ])
+        .longevity(64_u64)
+        .propagate(true)
+        .build()
+}
@@ -6,7 +6,7 @@ use sp_core::{H160, H256};
 
 sp_api::decl_runtime_apis! {
 
-    #[api_version(1)]
+    #[api_version(3)]
     pub trait EthEventHandlerApi<AccountId>
             where
         AccountId: Codec,
@@ -26,6 +26,9 @@ sp_api::decl_runtime_apis! {
             latest_seen_block: u32,
             signature: sp_core::sr25519::Signature
         ) -> Option<()>;
-
+        #[api_version(2)]
+        fn query_max_events_per_partition() -> u32;
+        #[api_version(3)]
+        fn query_bridge_contracts() -> Vec<H160>;
     }
 }
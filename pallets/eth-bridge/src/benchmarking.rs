@@ -325,6 +325,17 @@ benchmarks! {
         assert_eq!(NextTxId::<T>::get(), eth_tx_id);
     }
 
+    set_event_discovery_config {
+        let range_size = 50u32;
+        let max_events_per_partition = 16u32;
+        let start_block = 1000u32;
+    }: _(RawOrigin::Root, Some(range_size), Some(max_events_per_partition), Some(start_block))
+    verify {
+        assert_eq!(EthBlockRangeSize::<T>::get(), range_size);
+        assert_eq!(PendingMaxEventsPerPartition::<T>::get(), Some(max_events_per_partition));
+        assert_eq!(DiscoveryStartBlock::<T>::get(), Some(start_block));
+    }
+
     add_confirmation {
         let v in 1 .. MAX_CONFIRMATIONS;
         let authors = setup_authors::<T>(v + 4);
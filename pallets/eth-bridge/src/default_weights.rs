@@ -47,6 +47,7 @@ pub trait WeightInfo {
 	fn submit_ethereum_events_and_process_batch(c: u32, e: u32, ) -> Weight;
 	fn submit_latest_ethereum_block(c: u32, ) -> Weight;
 	fn submit_latest_ethereum_block_with_quorum(c: u32, ) -> Weight;
+	fn set_event_discovery_config() -> Weight;
 }
 
 /// Weights for pallet_eth_bridge using the Substrate node and recommended hardware.
@@ -245,6 +246,23 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(5_u64))
 			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
+	/// Storage: `EthBridge::EthBlockRangeSize` (r:1 w:1)
+	/// Proof: `EthBridge::EthBlockRangeSize` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `EthBridge::PendingMaxEventsPerPartition` (r:1 w:1)
+	/// Proof: `EthBridge::PendingMaxEventsPerPartition` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `EthBridge::MaxEventsPerPartition` (r:1 w:0)
+	/// Proof: `EthBridge::MaxEventsPerPartition` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `EthBridge::DiscoveryStartBlock` (r:1 w:1)
+	/// Proof: `EthBridge::DiscoveryStartBlock` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	fn set_event_discovery_config() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `1489`
+		// Minimum execution time: 13_020_000 picoseconds.
+		Weight::from_parts(13_460_000, 1489)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
 }
 
 // For backwards compatibility and tests.
@@ -442,4 +460,21 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(5_u64))
 			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
+	/// Storage: `EthBridge::EthBlockRangeSize` (r:1 w:1)
+	/// Proof: `EthBridge::EthBlockRangeSize` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `EthBridge::PendingMaxEventsPerPartition` (r:1 w:1)
+	/// Proof: `EthBridge::PendingMaxEventsPerPartition` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `EthBridge::MaxEventsPerPartition` (r:1 w:0)
+	/// Proof: `EthBridge::MaxEventsPerPartition` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `EthBridge::DiscoveryStartBlock` (r:1 w:1)
+	/// Proof: `EthBridge::DiscoveryStartBlock` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	fn set_event_discovery_config() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `1489`
+		// Minimum execution time: 13_020_000 picoseconds.
+		Weight::from_parts(13_460_000, 1489)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
 }
\ No newline at end of file
@@ -136,7 +136,7 @@ pub fn generate_encoded_lower_proof<T: Config>(
 ) -> Vec<u8> {
     let concatenated_confirmations = encode_confirmations(&confirmations);
     let mut compact_lower_data = Vec::new();
-    compact_lower_data.extend_from_slice(&lower_req.params.to_vec());
+    compact_lower_data.extend_from_slice(avn::packed_lower_params(&lower_req.params));
     compact_lower_data.extend_from_slice(&concatenated_confirmations);
 
     return compact_lower_data
@@ -63,7 +63,7 @@ use frame_support::{
     BoundedBTreeSet, BoundedVec,
 };
 use frame_system::{
-    ensure_none, ensure_root,
+    ensure_none,
     offchain::{SendTransactionTypes, SubmitTransaction},
     pallet_prelude::{BlockNumberFor, OriginFor},
 };
@@ -75,7 +75,12 @@ use pallet_session::historical::IdentificationTuple;
 use sp_staking::offence::ReportOffence;
 
 use sp_application_crypto::RuntimeAppPublic;
-use sp_avn_common::{bounds::MaximumValidatorsBound, event_discovery::*, event_types::Validator};
+use sp_avn_common::{
+    bounds::MaximumValidatorsBound,
+    event_discovery::*,
+    event_types::{EthEventId, Validator},
+    ocw_task_queue::{self as OcwTaskQueue, OcwTaskPriority},
+};
 use sp_core::{ecdsa, ConstU32, H160, H256};
 use sp_io::hashing::keccak_256;
 use sp_runtime::{scale_info::TypeInfo, traits::Dispatchable, Saturating};
@@ -140,7 +145,7 @@ const ADD_ETH_TX_HASH_CONTEXT: &'static [u8] = b"EthBridgeEthTxHash";
 pub const SUBMIT_ETHEREUM_EVENTS_HASH_CONTEXT: &'static [u8] = b"EthBridgeDiscoveredEthEventsHash";
 pub const SUBMIT_LATEST_ETH_BLOCK_CONTEXT: &'static [u8] = b"EthBridgeLatestEthereumBlockHash";
 
-const STORAGE_VERSION: StorageVersion = StorageVersion::new(2);
+const STORAGE_VERSION: StorageVersion = StorageVersion::new(3);
 
 #[frame_support::pallet]
 pub mod pallet {
@@ -184,6 +189,8 @@ pub mod pallet {
         >;
         type ProcessedEventsChecker: ProcessedEventsChecker;
         type EthereumEventsFilter: EthereumEventsFilterTrait;
+        /// The origin allowed to change the configuration of this pallet
+        type AdminOrigin: EnsureOrigin<<Self as frame_system::Config>::RuntimeOrigin>;
     }
 
     #[pallet::event]
@@ -226,6 +233,11 @@ pub mod pallet {
             eth_event_id: EthEventId,
             reason: DispatchError,
         },
+        EventDiscoveryConfigUpdated {
+            range_size: u32,
+            max_events_per_partition: u32,
+            start_block: Option<u32>,
+        },
     }
 
     #[pallet::pallet]
@@ -278,6 +290,27 @@ pub mod pallet {
     #[pallet::storage]
     pub type EthBlockRangeSize<T: Config> = StorageValue<_, u32, ValueQuery>;
 
+    #[pallet::type_value]
+    pub fn DefaultMaxEventsPerPartition() -> u32 {
+        MAX_INCOMING_EVENTS_BATCH_SIZE
+    }
+
+    // The maximum number of events submitted in a single partition of a range
+    #[pallet::storage]
+    #[pallet::getter(fn max_events_per_partition)]
+    pub type MaxEventsPerPartition<T: Config> =
+        StorageValue<_, u32, ValueQuery, DefaultMaxEventsPerPartition>;
+
+    // The maximum number of events per partition that applies when the next range starts
+    #[pallet::storage]
+    #[pallet::getter(fn pending_max_events_per_partition)]
+    pub type PendingMaxEventsPerPartition<T: Config> = StorageValue<_, u32, OptionQuery>;
+
+    // The block the next range starts at, instead of following the previous range
+    #[pallet::storage]
+    #[pallet::getter(fn discovery_start_block)]
+    pub type DiscoveryStartBlock<T: Config> = StorageValue<_, u32, OptionQuery>;
+
     #[pallet::genesis_config]
     pub struct GenesisConfig<T: Config> {
         pub _phantom: sp_std::marker::PhantomData<T>,
@@ -363,6 +396,8 @@ pub mod pallet {
         ErrorGettingFinalisedEthereumBlock,
         InvalidResponse,
         ErrorDecodingU32,
+        InvalidEventDiscoveryConfig,
+        TooManyEventsInPartition,
     }
 
     #[pallet::call]
@@ -373,7 +408,7 @@ pub mod pallet {
             origin: OriginFor<T>,
             eth_tx_lifetime_secs: u64,
         ) -> DispatchResultWithPostInfo {
-            ensure_root(origin)?;
+            T::AdminOrigin::ensure_origin(origin)?;
             EthTxLifetimeSecs::<T>::put(eth_tx_lifetime_secs);
             Self::deposit_event(Event::<T>::EthTxLifetimeUpdated { eth_tx_lifetime_secs });
             Ok(().into())
@@ -385,7 +420,7 @@ pub mod pallet {
             origin: OriginFor<T>,
             eth_tx_id: EthereumId,
         ) -> DispatchResultWithPostInfo {
-            ensure_root(origin)?;
+            T::AdminOrigin::ensure_origin(origin)?;
             NextTxId::<T>::put(eth_tx_id);
             Self::deposit_event(Event::<T>::EthTxIdUpdated { eth_tx_id });
             Ok(().into())
@@ -537,7 +572,7 @@ pub mod pallet {
         #[pallet::call_index(5)]
         #[pallet::weight(<T as Config>::WeightInfo::remove_active_request())]
         pub fn remove_active_request(origin: OriginFor<T>) -> DispatchResultWithPostInfo {
-            ensure_root(origin)?;
+            T::AdminOrigin::ensure_origin(origin)?;
 
             let req = ActiveRequest::<T>::get();
             ensure!(req.is_some(), Error::<T>::NoActiveRequest);
@@ -586,6 +621,10 @@ pub mod pallet {
                     events_partition.partition() == active_range.partition,
                 Error::<T>::NonActiveEthereumRange
             );
+            ensure!(
+                events_partition.events().len() as u32 <= MaxEventsPerPartition::<T>::get(),
+                Error::<T>::TooManyEventsInPartition
+            );
             ensure!(
                 author_has_cast_event_vote::<T>(&author.account_id) == false,
                 Error::<T>::EventVoteExists
@@ -677,6 +716,11 @@ pub mod pallet {
                     }
                 }
 
+                if let Some(start_block) = DiscoveryStartBlock::<T>::take() {
+                    selected_range.start_block = start_block;
+                }
+                apply_pending_max_events_per_partition::<T>();
+
                 ActiveEthereumRange::<T>::put(ActiveEthRange {
                     range: selected_range,
                     partition: 0,
@@ -699,12 +743,49 @@ pub mod pallet {
 
             Ok(Some(final_weight).into())
         }
+
+        /// Changes how Ethereum events are discovered. All values apply from the next range, so the
+        /// partitions of the range validators are voting on stay the same.
+        #[pallet::call_index(8)]
+        #[pallet::weight(<T as Config>::WeightInfo::set_event_discovery_config())]
+        pub fn set_event_discovery_config(
+            origin: OriginFor<T>,
+            range_size: Option<u32>,
+            max_events_per_partition: Option<u32>,
+            start_block: Option<u32>,
+        ) -> DispatchResultWithPostInfo {
+            T::AdminOrigin::ensure_origin(origin)?;
+
+            if let Some(range_size) = range_size {
+                ensure!(range_size > 0, Error::<T>::InvalidEventDiscoveryConfig);
+                EthBlockRangeSize::<T>::put(range_size);
+            }
+            if let Some(max_events) = max_events_per_partition {
+                ensure!(
+                    max_events > 0 && max_events <= MAX_INCOMING_EVENTS_BATCH_SIZE,
+                    Error::<T>::InvalidEventDiscoveryConfig
+                );
+                PendingMaxEventsPerPartition::<T>::put(max_events);
+            }
+            if let Some(start_block) = start_block {
+                DiscoveryStartBlock::<T>::put(start_block);
+            }
+
+            Self::deposit_event(Event::<T>::EventDiscoveryConfigUpdated {
+                range_size: EthBlockRangeSize::<T>::get(),
+                max_events_per_partition: PendingMaxEventsPerPartition::<T>::get()
+                    .unwrap_or_else(MaxEventsPerPartition::<T>::get),
+                start_block: DiscoveryStartBlock::<T>::get(),
+            });
+            Ok(().into())
+        }
     }
 
     #[pallet::hooks]
     impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
         fn offchain_worker(block_number: BlockNumberFor<T>) {
             if let Ok((author, finalised_block_number)) = setup_ocw::<T>(block_number) {
+                track_active_request_task::<T>(block_number);
                 if let Err(e) = process_active_request::<T>(author, finalised_block_number) {
                     log::error!("❌ Error processing currently active request: {:?}", e);
                 }
@@ -728,6 +809,33 @@ pub mod pallet {
         })
     }
 
+    fn request_task_id(request: &Request) -> Vec<u8> {
+        match request {
+            Request::Send(req) => (b"send", req.tx_id).encode(),
+            Request::LowerProof(req) => (b"lower_proof", req.lower_id).encode(),
+        }
+    }
+
+    // The active request is worked on every block until it is resolved, so its task is not leased:
+    // the queue shows which request the node is working on, and since when.
+    fn track_active_request_task<T: Config>(block_number: BlockNumberFor<T>) {
+        let active_task_id = ActiveRequest::<T>::get().map(|req| request_task_id(&req.request));
+
+        for task in OcwTaskQueue::queued_tasks::<BlockNumberFor<T>>(Some(PALLET_NAME)) {
+            if Some(&task.id) != active_task_id.as_ref() {
+                let _ = OcwTaskQueue::complete::<BlockNumberFor<T>>(PALLET_NAME, &task.id);
+            }
+        }
+
+        if let Some(task_id) = active_task_id {
+            if let Err(e) =
+                OcwTaskQueue::enqueue(PALLET_NAME, &task_id, OcwTaskPriority::High, block_number)
+            {
+                log::error!("❌ Unable to enqueue the active request task: {:?}", e);
+            }
+        }
+    }
+
     // The core logic the OCW employs to fully resolve any currently active transaction:
     fn process_active_request<T: Config>(
         author: Author<T>,
@@ -783,7 +891,6 @@ pub mod pallet {
         tx_has_enough_confirmations: bool,
     ) -> Result<(), DispatchError> {
         let tx_is_sent = tx.data.eth_tx_hash != H256::zero();
-        let tx_is_past_expiry = util::time_now::<T>() > tx.data.expiry;
 
         if self_is_sender && tx_has_enough_confirmations && !tx_is_sent {
             let lock_name =
@@ -801,17 +908,15 @@ pub mod pallet {
                     tx.request.tx_id
                 );
             };
-        } else if !self_is_sender && (tx_is_sent || tx_is_past_expiry) {
-            if util::requires_corroboration::<T>(&tx.data, &author) {
-                match eth::corroborate::<T>(&tx, &author)? {
-                    (Some(status), tx_hash_is_valid) => call::add_corroboration::<T>(
-                        tx.request.tx_id,
-                        status,
-                        tx_hash_is_valid.unwrap_or_default(),
-                        author,
-                    ),
-                    (None, _) => {},
-                }
+        } else if util::is_awaiting_corroboration::<T>(&tx, &author, tx_has_enough_confirmations) {
+            match eth::corroborate::<T>(&tx, &author)? {
+                (Some(status), tx_hash_is_valid) => call::add_corroboration::<T>(
+                    tx.request.tx_id,
+                    status,
+                    tx_hash_is_valid.unwrap_or_default(),
+                    author,
+                ),
+                (None, _) => {},
             }
         }
 
@@ -827,6 +932,28 @@ pub mod pallet {
         false
     }
 
+    /// Whether the active transaction, sent by another author, is waiting for `author` to
+    /// corroborate its outcome. Finalisation is not taken into account, as only the offchain
+    /// worker knows it.
+    pub fn author_has_pending_corroboration<T: Config>(author: &T::AccountId) -> bool {
+        let req = match ActiveRequest::<T>::get() {
+            Some(req) => req,
+            None => return false,
+        };
+        let has_enough_confirmations = request::has_enough_confirmations::<T>(&req);
+        let tx = match req.as_active_tx() {
+            Ok(tx) => tx,
+            Err(_) => return false,
+        };
+
+        AVN::<T>::validators()
+            .into_iter()
+            .find(|validator| &validator.account_id == author)
+            .map_or(false, |author| {
+                util::is_awaiting_corroboration::<T>(&tx, &author, has_enough_confirmations)
+            })
+    }
+
     pub fn author_has_submitted_latest_block<T: Config>(author: &T::AccountId) -> bool {
         for (_block_num, votes) in SubmittedEthBlocks::<T>::iter() {
             if votes.contains(&author) {
@@ -836,13 +963,22 @@ pub mod pallet {
         false
     }
 
+    fn apply_pending_max_events_per_partition<T: Config>() {
+        if let Some(max_events) = PendingMaxEventsPerPartition::<T>::take() {
+            MaxEventsPerPartition::<T>::put(max_events);
+        }
+    }
+
     fn advance_partition<T: Config>(
         active_range: &ActiveEthRange,
         approved_partition: &EthereumEventsPartition,
     ) {
         let next_active_range = if approved_partition.is_last() {
+            let start_block = DiscoveryStartBlock::<T>::take()
+                .unwrap_or_else(|| active_range.range.next_range().start_block);
+            apply_pending_max_events_per_partition::<T>();
             ActiveEthRange {
-                range: active_range.range.next_range(),
+                range: EthBlockRange { start_block, length: EthBlockRangeSize::<T>::get() },
                 partition: 0,
                 event_types_filter: T::EthereumEventsFilter::get(),
             }
@@ -1147,4 +1283,27 @@ impl<T: Config> Pallet<T> {
     pub fn get_bridge_contract() -> H160 {
         AVN::<T>::get_bridge_contract_address()
     }
+
+    /// Returns the events of the Ethereum transaction `tx_hash` that are waiting for authors to
+    /// agree on them, with the most votes cast for any partition containing each event.
+    pub fn discovered_events(tx_hash: H256) -> Vec<(EthEventId, u32)> {
+        let mut discovered_events: Vec<(EthEventId, u32)> = Vec::new();
+
+        for (partition, votes) in EthereumEvents::<T>::iter() {
+            let votes = votes.len() as u32;
+            for discovered_event in partition
+                .events()
+                .iter()
+                .filter(|e| e.event.event_id.transaction_hash == tx_hash)
+            {
+                let event_id = &discovered_event.event.event_id;
+                match discovered_events.iter_mut().find(|(id, _)| id == event_id) {
+                    Some((_, max_votes)) => *max_votes = votes.max(*max_votes),
+                    None => discovered_events.push((event_id.clone(), votes)),
+                }
+            }
+        }
+
+        discovered_events
+    }
 }
@@ -21,7 +21,7 @@ impl<T: Config> OnRuntimeUpgrade for SetBlockRangeSize<T> {
             onchain
         );
 
-        if onchain < 2 && current == 2 {
+        if onchain < 2 {
             return set_block_range_size::<T>()
         }
 
@@ -49,7 +49,7 @@ pub fn set_block_range_size<T: Config>() -> Weight {
     };
 
     EthBlockRangeSize::<T>::put(20u32);
-    STORAGE_VERSION.put::<Pallet<T>>();
+    StorageVersion::new(2).put::<Pallet<T>>();
 
     // 2 Storage writes
     add_weight(0, 2, Weight::from_parts(0 as u64, 0));
@@ -58,3 +58,119 @@ pub fn set_block_range_size<T: Config>() -> Weight {
 
     return consumed_weight + Weight::from_parts(25_000 as u64, 0)
 }
+
+mod v2 {
+    use super::*;
+
+    pub const PACKED_LOWER_PARAM_SIZE: usize = 76;
+
+    #[derive(Encode, Decode)]
+    pub struct LowerProofRequestData {
+        pub lower_id: LowerId,
+        pub params: [u8; PACKED_LOWER_PARAM_SIZE],
+        pub caller_id: BoundedVec<u8, CallerIdLimit>,
+    }
+
+    #[derive(Encode, Decode)]
+    pub enum Request {
+        Send(SendRequestData),
+        LowerProof(LowerProofRequestData),
+    }
+
+    #[derive(Encode, Decode)]
+    pub struct ActiveRequestData<T: Config> {
+        pub request: Request,
+        pub confirmation: ActiveConfirmation,
+        pub tx_data: Option<ActiveEthTransaction<T>>,
+        pub last_updated: BlockNumberFor<T>,
+    }
+}
+
+fn pad_lower_params(old_params: [u8; v2::PACKED_LOWER_PARAM_SIZE]) -> LowerParams {
+    let mut params = [0u8; avn::PACKED_LOWER_PARAM_SIZE];
+    params[0..v2::PACKED_LOWER_PARAM_SIZE].copy_from_slice(&old_params);
+    params
+}
+
+fn migrate_request(request: v2::Request) -> Request {
+    match request {
+        v2::Request::Send(send_req) => Request::Send(send_req),
+        v2::Request::LowerProof(lower_req) => Request::LowerProof(LowerProofRequestData {
+            lower_id: lower_req.lower_id,
+            params: pad_lower_params(lower_req.params),
+            caller_id: lower_req.caller_id,
+        }),
+    }
+}
+
+/// Lower params gained a memo. Queued and active lower proof requests are padded with an empty
+/// memo. A lower without a memo keeps the claim data it had, so the confirmations already given
+/// for the active request are still valid.
+pub struct PadLowerProofRequests<T>(PhantomData<T>);
+impl<T: Config> OnRuntimeUpgrade for PadLowerProofRequests<T> {
+    fn on_runtime_upgrade() -> Weight {
+        let current = Pallet::<T>::current_storage_version();
+        let onchain = Pallet::<T>::on_chain_storage_version();
+
+        log::info!(
+            "ℹ️  Eth bridge `PadLowerProofRequests` invoked with current storage version {:?} / onchain {:?}",
+            current,
+            onchain
+        );
+
+        if onchain < 3 {
+            return pad_lower_proof_requests::<T>()
+        }
+
+        Weight::zero()
+    }
+
+    #[cfg(feature = "try-runtime")]
+    fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+        Ok(Vec::new())
+    }
+
+    #[cfg(feature = "try-runtime")]
+    fn post_upgrade(_input: Vec<u8>) -> Result<(), TryRuntimeError> {
+        frame_support::ensure!(
+            Pallet::<T>::on_chain_storage_version() == 3,
+            "Lower proof requests not migrated"
+        );
+
+        Ok(())
+    }
+}
+
+pub fn pad_lower_proof_requests<T: Config>() -> Weight {
+    let mut consumed_weight: Weight = Weight::from_parts(0 as u64, 0);
+    let mut add_weight = |reads, writes, weight: Weight| {
+        consumed_weight += T::DbWeight::get().reads_writes(reads, writes);
+        consumed_weight += weight;
+    };
+
+    let _ = RequestQueue::<T>::translate::<BoundedVec<v2::Request, T::MaxQueuedTxRequests>, _>(
+        |maybe_queue| {
+            maybe_queue.map(|queue| {
+                BoundedVec::truncate_from(queue.into_iter().map(migrate_request).collect())
+            })
+        },
+    );
+
+    let _ = ActiveRequest::<T>::translate::<v2::ActiveRequestData<T>, _>(|maybe_active| {
+        maybe_active.map(|active| ActiveRequestData {
+            request: migrate_request(active.request),
+            confirmation: active.confirmation,
+            tx_data: active.tx_data,
+            last_updated: active.last_updated,
+        })
+    });
+
+    STORAGE_VERSION.put::<Pallet<T>>();
+
+    // Read and write the queue, the active request and the storage version
+    add_weight(2, 3, Weight::from_parts(0 as u64, 0));
+
+    log::info!("✅ Lower proof requests padded successfully");
+
+    return consumed_weight + Weight::from_parts(25_000 as u64, 0)
+}
@@ -120,7 +120,7 @@ pub fn complete_lower_proof_request<T: Config>(
 }
 
 fn set_up_active_lower_proof<T: Config>(req: LowerProofRequestData) -> Result<(), Error<T>> {
-    let msg_hash = H256::from(keccak_256(&req.params));
+    let msg_hash = H256::from(keccak_256(avn::packed_lower_params(&req.params)));
 
     ActiveRequest::<T>::put(ActiveRequestData {
         request: Request::LowerProof(req),
@@ -102,6 +102,7 @@ impl DiscoveredEthContext {
         events_helpers::EthereumEventsPartitionFactory::create_partitions(
             self.range.clone(),
             self.discovered_events.clone(),
+            EthBridge::max_events_per_partition(),
         )
     }
 }
@@ -455,3 +456,212 @@ mod initial_range_consensus {
         }
     }
 }
+
+mod discovered_events {
+    use super::{DiscoveredEthContext as Context, *};
+    use frame_support::assert_ok;
+
+    #[test]
+    fn returns_events_of_the_transaction_with_their_votes() {
+        let mut ext = ExtBuilder::build_default().with_validators().as_externality();
+        ext.execute_with(|| {
+            init_active_range();
+            let context: Context = Default::default();
+            assert_ok!(context.submit_events_partition(0));
+
+            let event_id = context.partitions()[0]
+                .events()
+                .iter()
+                .next()
+                .expect("Partition has events")
+                .event
+                .event_id
+                .clone();
+
+            assert_eq!(
+                EthBridge::discovered_events(event_id.transaction_hash),
+                vec![(event_id, 1)]
+            );
+        });
+    }
+
+    #[test]
+    fn returns_nothing_for_unknown_transactions() {
+        let mut ext = ExtBuilder::build_default().with_validators().as_externality();
+        ext.execute_with(|| {
+            init_active_range();
+            let context: Context = Default::default();
+            assert_ok!(context.submit_events_partition(0));
+
+            assert!(EthBridge::discovered_events(H256::from([255u8; 32])).is_empty());
+        });
+    }
+}
+
+mod event_discovery_config {
+    use super::{DiscoveredEthContext as Context, *};
+    use frame_support::{assert_noop, assert_ok};
+    use mock::System;
+    use sp_runtime::DispatchError;
+
+    fn finalise_partition(discovered_events: Vec<DiscoveredEvent>) {
+        (1..=AVN::<TestRuntime>::quorum() as u64)
+            .map(|id| Context {
+                author: Author::<TestRuntime> { key: UintAuthorityId(id), account_id: id },
+                discovered_events: discovered_events.clone(),
+                ..Default::default()
+            })
+            .for_each(|context| assert_ok!(context.submit_events_partition(0)));
+    }
+
+    #[test]
+    fn can_be_updated_by_the_admin() {
+        let mut ext = ExtBuilder::build_default().with_validators().as_externality();
+        ext.execute_with(|| {
+            assert_ok!(EthBridge::set_event_discovery_config(
+                RuntimeOrigin::root(),
+                Some(500),
+                Some(10),
+                Some(2000),
+            ));
+
+            assert_eq!(EthBlockRangeSize::<TestRuntime>::get(), 500);
+            assert_eq!(EthBridge::pending_max_events_per_partition(), Some(10));
+            assert_eq!(EthBridge::max_events_per_partition(), MAX_INCOMING_EVENTS_BATCH_SIZE);
+            assert_eq!(EthBridge::discovery_start_block(), Some(2000));
+            assert!(System::events().iter().any(|record| record.event ==
+                mock::RuntimeEvent::EthBridge(
+                    Event::<TestRuntime>::EventDiscoveryConfigUpdated {
+                        range_size: 500,
+                        max_events_per_partition: 10,
+                        start_block: Some(2000),
+                    }
+                )));
+        });
+    }
+
+    #[test]
+    fn applies_to_the_next_range() {
+        let mut ext = ExtBuilder::build_default().with_validators().as_externality();
+        ext.execute_with(|| {
+            init_active_range();
+            assert_ok!(EthBridge::set_event_discovery_config(
+                RuntimeOrigin::root(),
+                Some(500),
+                Some(10),
+                Some(2000),
+            ));
+
+            finalise_partition(empty_event_data_set());
+
+            let active_range = EthBridge::active_ethereum_range().expect("Should be set");
+            assert_eq!(active_range.range, EthBlockRange { start_block: 2000, length: 500 });
+            assert_eq!(active_range.partition, 0);
+            assert_eq!(EthBridge::discovery_start_block(), None);
+            assert_eq!(EthBridge::max_events_per_partition(), 10);
+            assert_eq!(EthBridge::pending_max_events_per_partition(), None);
+        });
+    }
+
+    #[test]
+    fn keeps_the_partitions_of_the_active_range() {
+        let mut ext = ExtBuilder::build_default().with_validators().as_externality();
+        ext.execute_with(|| {
+            init_active_range();
+            let context: Context = Default::default();
+            let partition = context.partitions()[0].clone();
+            let signature = context.generate_signature(0);
+            assert_ok!(EthBridge::set_event_discovery_config(
+                RuntimeOrigin::root(),
+                None,
+                Some(10),
+                None,
+            ));
+
+            assert_ok!(EthBridge::submit_ethereum_events(
+                RuntimeOrigin::none(),
+                context.author.clone(),
+                partition,
+                signature,
+            ));
+            assert_eq!(EthBridge::max_events_per_partition(), MAX_INCOMING_EVENTS_BATCH_SIZE);
+        });
+    }
+
+    #[test]
+    fn limits_the_events_of_a_partition() {
+        let mut ext = ExtBuilder::build_default().with_validators().as_externality();
+        ext.execute_with(|| {
+            init_active_range();
+            let context: Context = Default::default();
+            let partition = context.partitions()[0].clone();
+            let signature = context.generate_signature(0);
+            MaxEventsPerPartition::<TestRuntime>::put(10);
+
+            assert_noop!(
+                EthBridge::submit_ethereum_events(
+                    RuntimeOrigin::none(),
+                    context.author.clone(),
+                    partition,
+                    signature,
+                ),
+                Error::<TestRuntime>::TooManyEventsInPartition,
+            );
+            assert_eq!(context.partitions()[0].events().len(), 10);
+        });
+    }
+
+    mod fails_when {
+        use super::*;
+
+        #[test]
+        fn origin_is_not_the_admin() {
+            let mut ext = ExtBuilder::build_default().as_externality();
+            ext.execute_with(|| {
+                assert_noop!(
+                    EthBridge::set_event_discovery_config(
+                        RuntimeOrigin::signed(1),
+                        Some(500),
+                        None,
+                        None,
+                    ),
+                    DispatchError::BadOrigin,
+                );
+            });
+        }
+
+        #[test]
+        fn range_size_is_zero() {
+            let mut ext = ExtBuilder::build_default().as_externality();
+            ext.execute_with(|| {
+                assert_noop!(
+                    EthBridge::set_event_discovery_config(
+                        RuntimeOrigin::root(),
+                        Some(0),
+                        None,
+                        None,
+                    ),
+                    Error::<TestRuntime>::InvalidEventDiscoveryConfig,
+                );
+            });
+        }
+
+        #[test]
+        fn max_events_per_partition_is_out_of_bounds() {
+            let mut ext = ExtBuilder::build_default().as_externality();
+            ext.execute_with(|| {
+                for max_events in [0, MAX_INCOMING_EVENTS_BATCH_SIZE + 1] {
+                    assert_noop!(
+                        EthBridge::set_event_discovery_config(
+                            RuntimeOrigin::root(),
+                            None,
+                            Some(max_events),
+                            None,
+                        ),
+                        Error::<TestRuntime>::InvalidEventDiscoveryConfig,
+                    );
+                }
+            });
+        }
+    }
+}
@@ -14,8 +14,9 @@ use sp_avn_common::BridgeContractMethod;
 use sp_core::{
     ecdsa,
     offchain::testing::{OffchainState, PendingRequest, PoolState},
-    H160,
+    H160, H256,
 };
+use sp_io::hashing::keccak_256;
 use sp_runtime::{testing::UintAuthorityId, traits::Dispatchable};
 
 pub fn mock_get_finalised_block(state: &mut OffchainState, response: &Option<Vec<u8>>) {
@@ -143,7 +144,7 @@ pub fn concat_lower_data(
     // T1Recipient = 20 bytes
     lower_params[52..72].copy_from_slice(&t1_recipient.as_fixed_bytes()[0..20]);
     // LowerId = 4 bytes
-    lower_params[72..PACKED_LOWER_PARAM_SIZE].copy_from_slice(&lower_id.to_be_bytes()[0..4]);
+    lower_params[72..76].copy_from_slice(&lower_id.to_be_bytes()[0..4]);
 
     return lower_params
 }
@@ -446,4 +447,36 @@ mod lower_proof_encoding {
             }
         })
     }
+
+    #[test]
+    fn lower_proof_of_a_lower_with_a_memo_uses_the_extended_layout() {
+        let (mut ext, _pool_state, _offchain_state) = ExtBuilder::build_default()
+            .with_validators()
+            .with_genesis_config()
+            .for_offchain_worker()
+            .as_externality_with_state();
+
+        ext.execute_with(|| {
+            let lower_id = 0u32;
+            let token_id = H160(hex_literal::hex!("97d9b397189e8b771ffac3cb04cf26c780a93431"));
+            let amount = 10u128;
+            let t1_recipient = H160(hex_literal::hex!("de7e1091cde63c05aa4d82c62e4c54edbc701b22"));
+            let mut params = concat_lower_data(lower_id, token_id, &amount, &t1_recipient);
+            params[76..80].copy_from_slice(b"memo");
+
+            add_new_lower_proof_request::<TestRuntime>(lower_id, &params, &vec![]).unwrap();
+            let active_req = ActiveRequest::<TestRuntime>::get().expect("is active");
+
+            assert_eq!(active_req.confirmation.msg_hash, H256::from(keccak_256(&params)));
+            if let Request::LowerProof(lower_req) = active_req.request {
+                let encoded_proof = generate_encoded_lower_proof::<TestRuntime>(
+                    &lower_req,
+                    active_req.confirmation.confirmations,
+                );
+                assert_eq!(encoded_proof, params.to_vec());
+            } else {
+                assert!(false, "active request is not a lower proof");
+            }
+        })
+    }
 }
@@ -5,7 +5,9 @@ use frame_support::parameter_types;
 use sp_state_machine::BasicExternalities;
 
 use frame_system as system;
-use pallet_avn::{testing::U64To32BytesConverter, EthereumPublicKeyChecker};
+use pallet_avn::{
+    testing::U64To32BytesConverter, EthereumPublicKeyChecker, PACKED_LOWER_PARAM_SIZE,
+};
 use pallet_session as session;
 use parking_lot::RwLock;
 use sp_avn_common::{
@@ -86,7 +88,7 @@ frame_support::construct_runtime!(
     {
         System: frame_system::{Pallet, Call, Config<T>, Storage, Event<T>},
         Timestamp: pallet_timestamp,
-        AVN: pallet_avn::{Pallet, Storage, Event},
+        AVN: pallet_avn::{Pallet, Storage, Event<T>},
         EthBridge: eth_bridge::{Pallet, Call, Storage, Event<T>, Config<T>},
         Session: pallet_session::{Pallet, Call, Storage, Event, Config<T>},
     }
@@ -117,6 +119,7 @@ impl Config for TestRuntime {
     type ReportCorroborationOffence = OffenceHandler;
     type ProcessedEventsChecker = Self;
     type EthereumEventsFilter = ();
+    type AdminOrigin = frame_system::EnsureRoot<Self::AccountId>;
 }
 
 impl system::Config for TestRuntime {
@@ -299,12 +302,12 @@ pub fn setup_context() -> Context {
         confirmation_signature,
         request_function_name: BridgeContractMethod::PublishRoot.as_bytes().to_vec(),
         request_params: vec![(b"bytes32".to_vec(), hex::decode(ROOT_HASH).unwrap())],
-        lower_params: [1u8; 76],
+        lower_params: [1u8; PACKED_LOWER_PARAM_SIZE],
         finalised_block_vec,
         lower_id: 10u32,
         block_number: 1u64,
         // if request_params changes, this should also change
-        expected_lower_msg_hash: "5892dee772ffe3d97e9525b62805bbcd91bac29026536cfa09269623128280ca"
+        expected_lower_msg_hash: "1cede0c501bbfa256a37b7553d4c94a2a38a3376df1b20faf2387e2f18796e0b"
             .to_string(),
         eth_event_id,
         bad_mock_event_partition,
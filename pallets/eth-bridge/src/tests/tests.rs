@@ -551,6 +551,128 @@ mod add_corroboration {
     }
 }
 
+mod author_has_pending_corroboration {
+    use super::*;
+
+    fn set_eth_tx_hash() {
+        let mut active_tx =
+            ActiveRequest::<TestRuntime>::get().expect("is active").as_active_tx().unwrap();
+        active_tx.data.eth_tx_hash = H256::repeat_byte(1);
+        ActiveRequest::<TestRuntime>::put(ActiveRequestData {
+            request: types::Request::Send(active_tx.request),
+            confirmation: active_tx.confirmation,
+            tx_data: Some(active_tx.data),
+            last_updated: 0u64,
+        });
+    }
+
+    fn add_enough_confirmations() {
+        let mut active_request = ActiveRequest::<TestRuntime>::get().expect("is active");
+        for index in 1..AVN::<TestRuntime>::quorum() {
+            active_request
+                .confirmation
+                .confirmations
+                .try_push(ecdsa::Signature::try_from(&[(index + 1) as u8; 65][0..65]).unwrap())
+                .unwrap();
+        }
+        ActiveRequest::<TestRuntime>::put(active_request);
+    }
+
+    fn sender() -> AccountId {
+        ActiveRequest::<TestRuntime>::get()
+            .expect("is active")
+            .as_active_tx()
+            .unwrap()
+            .data
+            .sender
+    }
+
+    #[test]
+    fn is_true_once_the_transaction_is_sent() {
+        let mut ext = ExtBuilder::build_default().with_validators().as_externality();
+        ext.execute_with(|| {
+            let context = setup_context();
+            setup_eth_tx_request(&context);
+            add_enough_confirmations();
+            let author = context.confirming_author.account_id;
+            assert!(!author_has_pending_corroboration::<TestRuntime>(&author));
+
+            set_eth_tx_hash();
+
+            assert!(author_has_pending_corroboration::<TestRuntime>(&author));
+        });
+    }
+
+    #[test]
+    fn is_false_until_the_transaction_has_enough_confirmations() {
+        let mut ext = ExtBuilder::build_default().with_validators().as_externality();
+        ext.execute_with(|| {
+            let context = setup_context();
+            setup_eth_tx_request(&context);
+            set_eth_tx_hash();
+            let author = context.confirming_author.account_id;
+            assert!(!author_has_pending_corroboration::<TestRuntime>(&author));
+
+            add_enough_confirmations();
+
+            assert!(author_has_pending_corroboration::<TestRuntime>(&author));
+        });
+    }
+
+    #[test]
+    fn is_false_for_an_account_that_is_not_a_validator() {
+        let mut ext = ExtBuilder::build_default().with_validators().as_externality();
+        ext.execute_with(|| {
+            let context = setup_context();
+            setup_eth_tx_request(&context);
+            add_enough_confirmations();
+            set_eth_tx_hash();
+
+            assert!(!author_has_pending_corroboration::<TestRuntime>(&1000));
+        });
+    }
+
+    #[test]
+    fn is_false_for_the_sender() {
+        let mut ext = ExtBuilder::build_default().with_validators().as_externality();
+        ext.execute_with(|| {
+            let context = setup_context();
+            setup_eth_tx_request(&context);
+            set_eth_tx_hash();
+
+            assert!(!author_has_pending_corroboration::<TestRuntime>(&sender()));
+        });
+    }
+
+    #[test]
+    fn is_false_once_the_author_has_corroborated() {
+        let mut ext = ExtBuilder::build_default().with_validators().as_externality();
+        ext.execute_with(|| {
+            let context = setup_context();
+            let tx_id = setup_eth_tx_request(&context);
+            set_eth_tx_hash();
+
+            assert_ok!(corroborate_good_transactions(tx_id, &context.confirming_author, &context));
+
+            assert!(!author_has_pending_corroboration::<TestRuntime>(
+                &context.confirming_author.account_id
+            ));
+        });
+    }
+
+    #[test]
+    fn is_false_without_an_active_request() {
+        let mut ext = ExtBuilder::build_default().with_validators().as_externality();
+        ext.execute_with(|| {
+            let context = setup_context();
+
+            assert!(!author_has_pending_corroboration::<TestRuntime>(
+                &context.confirming_author.account_id
+            ));
+        });
+    }
+}
+
 #[test]
 fn publish_to_ethereum_creates_new_transaction_request() {
     let mut ext = ExtBuilder::build_default().with_validators().as_externality();
@@ -31,6 +31,22 @@ pub fn requires_corroboration<T: Config>(
         !eth_tx.failure_corroborations.contains(&author.account_id)
 }
 
+/// Whether `author` has to corroborate the outcome of `tx`: the transaction has been confirmed and
+/// then sent or left to expire, and `author` is neither its sender nor has corroborated it yet
+pub fn is_awaiting_corroboration<T: Config>(
+    tx: &ActiveTransactionData<T>,
+    author: &Author<T>,
+    tx_has_enough_confirmations: bool,
+) -> bool {
+    let tx_is_sent = tx.data.eth_tx_hash != H256::zero();
+    let tx_is_past_expiry = time_now::<T>() > tx.data.expiry;
+
+    author.account_id != tx.data.sender &&
+        tx_has_enough_confirmations &&
+        (tx_is_sent || tx_is_past_expiry) &&
+        requires_corroboration::<T>(&tx.data, author)
+}
+
 pub fn bound_params<T>(
     params: &[(Vec<u8>, Vec<u8>)],
 ) -> Result<
@@ -0,0 +1,17 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+use codec::Codec;
+use sp_api::vec::Vec;
+use sp_avn_common::event_types::{EthEventId, EthEventStatus};
+use sp_core::H256;
+
+sp_api::decl_runtime_apis! {
+
+    #[api_version(1)]
+    pub trait EthEventStatusApi<BlockNumber>
+            where
+        BlockNumber: Codec,
+    {
+        /// The status of every event of the Ethereum transaction `tx_hash` known to the AvN
+        fn event_status(tx_hash: H256) -> Vec<(EthEventId, EthEventStatus<BlockNumber>)>;
+    }
+}
@@ -0,0 +1,212 @@
+// Copyright 2024 Aventus Network Services (UK) Ltd.
+
+//! Archive of processed events.
+//!
+//! Processed events are kept in `ProcessedEvents` so they can't be processed twice. They are also
+//! grouped by the period they were processed in, a period being `ArchivePeriod` blocks. Once a
+//! period is older than `ProcessedEventsRetention` periods, its events are removed from storage
+//! and replaced by the root of a Merkle tree of the events, so a replay can still be proven. The
+//! leaves of the tree are written to the offchain index to let nodes build the proofs. A 16 byte
+//! digest of each archived event id is kept in `ArchivedEventIds` for `ArchivedEventIdsRetention`
+//! more periods, so an archived event is still rejected when it is added again. Once the digests
+//! of a period are removed, a replay of one of its events is removed with a proof of the archive.
+
+use super::*;
+use frame_support::weights::Weight;
+use sp_avn_common::{
+    event_types::{archived_events_index_key, processed_event_leaf, ArchivedEventProof},
+    merkle::{merkle_root, verify_merkle_proof},
+};
+use sp_runtime::traits::SaturatedConversion;
+
+/// Commitment to the events processed in an archived period
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct ArchivedPeriod {
+    /// Merkle root of the processed events
+    pub root: H256,
+    pub number_of_events: u32,
+}
+
+fn archived_event_key(event_id: &EthEventId) -> [u8; 16] {
+    sp_io::hashing::blake2_128(&event_id.encode())
+}
+
+impl<T: Config> Pallet<T> {
+    pub fn current_archive_period() -> u32 {
+        Self::archive_period_of(<frame_system::Pallet<T>>::block_number())
+    }
+
+    fn archive_period_of(block_number: BlockNumberFor<T>) -> u32 {
+        match T::ArchivePeriod::get() {
+            period if period.is_zero() => 0,
+            period => (block_number / period).saturated_into::<u32>(),
+        }
+    }
+
+    /// Marks an event as processed and adds it to the events of the current archive period
+    pub(crate) fn record_processed_event(event_id: &EthEventId, accepted: bool) {
+        <ProcessedEvents<T>>::insert(event_id, accepted);
+
+        let period = Self::current_archive_period();
+        if !<ProcessedEventsByPeriod<T>>::contains_key(period, event_id) {
+            <ProcessedEventsCount<T>>::mutate(period, |count| *count = count.saturating_add(1));
+        }
+        <ProcessedEventsByPeriod<T>>::insert(period, event_id, accepted);
+    }
+
+    /// Removes an event, processed at `processed_at` and not archived yet, from the processed
+    /// events so it can be processed again
+    pub(crate) fn remove_processed_event(event_id: &EthEventId, processed_at: BlockNumberFor<T>) {
+        <ProcessedEvents<T>>::remove(event_id);
+
+        let period = Self::archive_period_of(processed_at);
+        if <ProcessedEventsByPeriod<T>>::take(period, event_id).is_some() {
+            <ProcessedEventsCount<T>>::mutate(period, |count| *count = count.saturating_sub(1));
+        }
+    }
+
+    /// Archives the periods that are past their retention without using more than `limit`
+    pub(crate) fn archive_expired_periods(limit: Weight) -> Weight {
+        let db_weight = T::DbWeight::get();
+        let mut used = db_weight.reads_writes(3, 1);
+        if used.any_gt(limit) {
+            return Weight::zero()
+        }
+
+        // The events processed before the archive existed must all be in a period first
+        if <StorageVersion<T>>::get() == Releases::V6_0_0 {
+            return db_weight.reads(1)
+        }
+
+        let current_period = Self::current_archive_period();
+        let first_period = Self::next_period_to_archive();
+        let mut period = first_period;
+        while period.saturating_add(T::ProcessedEventsRetention::get()) < current_period {
+            let number_of_events = Self::processed_events_count(period) as u64;
+            let archive_weight = db_weight.reads_writes(
+                number_of_events.saturating_add(1),
+                number_of_events.saturating_mul(5).saturating_add(2),
+            );
+            if used.saturating_add(archive_weight).any_gt(limit) {
+                break
+            }
+
+            Self::archive_period(period);
+            used = used.saturating_add(archive_weight);
+            period = period.saturating_add(1);
+        }
+
+        if period != first_period {
+            <NextPeriodToArchive<T>>::put(period);
+        }
+
+        used
+    }
+
+    fn archive_period(period: u32) {
+        let events: Vec<(EthEventId, bool)> =
+            <ProcessedEventsByPeriod<T>>::drain_prefix(period).collect();
+        <ProcessedEventsCount<T>>::remove(period);
+        if events.is_empty() {
+            return
+        }
+
+        let leaves: Vec<H256> = events
+            .iter()
+            .map(|(event_id, accepted)| {
+                <ProcessedEvents<T>>::remove(event_id);
+                // Once archived, an invalid event can no longer be revalidated
+                <InvalidEvents<T>>::remove(event_id);
+                let key = archived_event_key(event_id);
+                <ArchivedEventIds<T>>::insert(key, period);
+                <ArchivedEventIdsByPeriod<T>>::insert(period, key, ());
+                processed_event_leaf(event_id, *accepted)
+            })
+            .collect();
+
+        let archived =
+            ArchivedPeriod { root: merkle_root(&leaves), number_of_events: leaves.len() as u32 };
+        <ArchivedPeriods<T>>::insert(period, archived);
+        sp_io::offchain_index::set(&archived_events_index_key(period), &events.encode());
+
+        Self::deposit_event(Event::<T>::ProcessedEventsArchived {
+            period,
+            root: archived.root,
+            number_of_events: archived.number_of_events,
+        });
+    }
+
+    /// Removes the ids of the events of the periods archived more than `ArchivedEventIdsRetention`
+    /// periods ago, without using more than `limit`
+    pub(crate) fn prune_archived_event_ids(limit: Weight) -> Weight {
+        let db_weight = T::DbWeight::get();
+        let mut used = db_weight.reads_writes(2, 1);
+        if used.any_gt(limit) {
+            return Weight::zero()
+        }
+
+        let next_period_to_archive = Self::next_period_to_archive();
+        let first_period = Self::next_archived_period_to_prune();
+        let mut period = first_period;
+        while period.saturating_add(T::ArchivedEventIdsRetention::get()) < next_period_to_archive {
+            let number_of_events =
+                Self::archived_periods(period).map_or(0, |archived| archived.number_of_events);
+            let prune_weight = db_weight.reads_writes(
+                (number_of_events as u64).saturating_mul(2).saturating_add(1),
+                (number_of_events as u64).saturating_mul(2),
+            );
+            if used.saturating_add(prune_weight).any_gt(limit) {
+                break
+            }
+
+            for (key, _) in <ArchivedEventIdsByPeriod<T>>::drain_prefix(period) {
+                // The event may have been archived again in a later period
+                if <ArchivedEventIds<T>>::get(key) == Some(period) {
+                    <ArchivedEventIds<T>>::remove(key);
+                }
+            }
+            used = used.saturating_add(prune_weight);
+            period = period.saturating_add(1);
+        }
+
+        if period != first_period {
+            <NextArchivedPeriodToPrune<T>>::put(period);
+        }
+
+        used
+    }
+
+    /// Whether `event_id` was processed in an archived period whose event ids are still kept
+    pub fn was_archived(event_id: &EthEventId) -> bool {
+        <ArchivedEventIds<T>>::contains_key(archived_event_key(event_id))
+    }
+
+    /// Checks that `proof` shows `event_id` was processed in an archived period
+    pub fn is_archived_event(event_id: &EthEventId, proof: &ArchivedEventProof) -> bool {
+        match Self::archived_periods(proof.period) {
+            Some(archived) => verify_merkle_proof(
+                &archived.root,
+                processed_event_leaf(event_id, proof.accepted),
+                proof.leaf_index,
+                archived.number_of_events,
+                &proof.siblings,
+            ),
+            None => false,
+        }
+    }
+
+    /// Removes a queued event that has already been processed in an archived period
+    pub(crate) fn remove_replayed_event_from_queues(event_id: &EthEventId) -> DispatchResult {
+        let unchecked_index =
+            Self::unchecked_events().iter().position(|(event, _, _)| event == event_id);
+        if let Some(index) = unchecked_index {
+            <UncheckedEvents<T>>::mutate(|events| events.remove(index));
+            return Ok(())
+        }
+
+        let pending_index =
+            Self::get_pending_event_index(event_id).map_err(|_| Error::<T>::MissingEventToCheck)?;
+        <EventsPendingChallenge<T>>::mutate(|events| events.remove(pending_index));
+        Ok(())
+    }
+}
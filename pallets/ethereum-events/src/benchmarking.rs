@@ -308,6 +308,7 @@ benchmarks! {
         let (result, ingress_counter, signature, validator) = setup_extrinsics_inputs::<T>(validators.clone());
 
         setup_events_pending_challenge::<T>(&ValidEvents::AddedValidator, e);
+        StakeWeightedChallenges::<T>::put(true);
         EventsPendingChallenge::<T>::mutate(|events| events.try_push((result.clone(), ingress_counter, 0u32.into())).expect("Cannot push"));
         let required_challenge_votes = (AVN::<T>::active_validators().len() as u32) / <QuorumFactor<T>>::get();
         setup_challenges::<T>(&result.event.event_id.clone(), validators.clone(), required_challenge_votes + 1);
@@ -331,6 +332,7 @@ benchmarks! {
 
         setup_events_pending_challenge::<T>(&ValidEvents::AddedValidator, e);
         result.min_challenge_votes = 3;
+        StakeWeightedChallenges::<T>::put(true);
         EventsPendingChallenge::<T>::mutate(|events| events.try_push((result.clone(), ingress_counter, 0u32.into())).expect("Cannot push"));
         let required_challenge_votes = (AVN::<T>::active_validators().len() as u32) / <QuorumFactor<T>>::get();
         setup_challenges::<T>(&result.event.event_id.clone(), validators.clone(), 1);
@@ -364,7 +366,7 @@ benchmarks! {
         );
     }: _(RawOrigin::None, challenge.clone(), ingress_counter, signature, validator.clone())
     verify {
-        assert_eq!(true, Challenges::<T>::get(result.event.event_id).contains(&challenged_by));
+        assert_eq!(true, Pallet::<T>::challenges(result.event.event_id).contains(&challenged_by));
         assert_last_event::<T>(Event::<T>::EventChallenged {
             eth_event_id: challenge.event_id,
             challenger: challenge.challenged_by,
@@ -380,6 +382,90 @@ benchmarks! {
         assert_eq!(new_event_challenge_period, EventChallengePeriod::<T>::get());
         assert_last_event::<T>(Event::<T>::EventChallengePeriodUpdated{ block: new_event_challenge_period }.into());
     }
+
+    remove_replayed_event {
+        let s in 0 .. 31;
+
+        let event_id = EthEventId { signature: ValidEvents::Lifted.signature(), transaction_hash: H256::from([3; 32]) };
+        let siblings: Vec<H256> = (0..s).map(|i| H256::repeat_byte(i as u8)).collect();
+        let root = siblings.iter().fold(
+            sp_avn_common::event_types::processed_event_leaf(&event_id, true),
+            |node, sibling| H256::from(sp_io::hashing::keccak_256(&[node.as_bytes(), sibling.as_bytes()].concat()))
+        );
+        ArchivedPeriods::<T>::insert(0, ArchivedPeriod { root, number_of_events: 1u32 << s });
+        setup_unchecked_events::<T>(&ValidEvents::AddedValidator, MAX_NUMBER_OF_UNCHECKED_EVENTS_BENCH - 1);
+        UncheckedEvents::<T>::mutate(|events| events.try_push((event_id.clone(), 0, 0u32.into())).expect("Cannot push"));
+
+        let proof = ArchivedEventProof { period: 0, accepted: true, leaf_index: 0, siblings };
+        let caller: T::AccountId = whitelisted_caller();
+    }: _(RawOrigin::Signed(caller), event_id.clone(), proof)
+    verify {
+        assert!(!UncheckedEvents::<T>::get().iter().any(|(event, _, _)| event == &event_id));
+        assert_last_event::<T>(Event::<T>::ReplayedEventRemoved{ eth_event_id: event_id, period: 0 }.into());
+    }
+
+    revalidate_event {
+        let event_id = EthEventId { signature: ValidEvents::Lifted.signature(), transaction_hash: H256::from([4; 32]) };
+        setup_unchecked_events::<T>(&ValidEvents::AddedValidator, MAX_NUMBER_OF_UNCHECKED_EVENTS_BENCH - 1);
+        Pallet::<T>::record_processed_event(&event_id, true);
+        InvalidEvents::<T>::insert(&event_id, frame_system::Pallet::<T>::block_number());
+    }: _(RawOrigin::Root, event_id.clone())
+    verify {
+        assert!(!ProcessedEvents::<T>::contains_key(&event_id));
+        assert!(UncheckedEvents::<T>::get().iter().any(|(event, _, _)| event == &event_id));
+    }
+
+    remove_nft_contract {
+        let contract_address = H160::from([1; 20]);
+        NftT1Contracts::<T>::insert(contract_address, ());
+        setup_unchecked_events::<T>(&ValidEvents::Lifted, MAX_NUMBER_OF_UNCHECKED_EVENTS_BENCH);
+        setup_events_pending_challenge::<T>(&ValidEvents::Lifted, MAX_NUMBER_OF_EVENTS_PENDING_CHALLENGES_BENCH);
+    }: _(RawOrigin::Root, contract_address.clone())
+    verify {
+        assert!(!NftT1Contracts::<T>::contains_key(contract_address));
+        assert_last_event::<T>(Event::<T>::NftContractRemoved{ contract_address }.into());
+    }
+
+    update_nft_contract {
+        let old_contract = H160::from([1; 20]);
+        let new_contract = H160::from([2; 20]);
+        NftT1Contracts::<T>::insert(old_contract, ());
+        setup_unchecked_events::<T>(&ValidEvents::Lifted, MAX_NUMBER_OF_UNCHECKED_EVENTS_BENCH);
+        setup_events_pending_challenge::<T>(&ValidEvents::Lifted, MAX_NUMBER_OF_EVENTS_PENDING_CHALLENGES_BENCH);
+    }: _(RawOrigin::Root, old_contract.clone(), new_contract.clone())
+    verify {
+        assert!(!NftT1Contracts::<T>::contains_key(old_contract));
+        assert!(NftT1Contracts::<T>::contains_key(new_contract));
+        assert_last_event::<T>(Event::<T>::NftContractUpdated{ old_contract, new_contract }.into());
+    }
+
+    set_stake_weighted_challenges {
+        assert!(!StakeWeightedChallenges::<T>::get());
+    }: _(RawOrigin::Root, true)
+    verify {
+        assert!(StakeWeightedChallenges::<T>::get());
+        assert_last_event::<T>(Event::<T>::StakeWeightedChallengesUpdated{ enabled: true }.into());
+    }
+
+    cleanup {
+        let c in 1 .. MAX_CLEANUP_LIMIT;
+
+        let challenger: T::AccountId = account("challenger", 0, 0);
+        for i in 0 .. c {
+            let mut transaction_hash = [0u8; 32];
+            transaction_hash[..4].copy_from_slice(&i.to_le_bytes());
+            let event_id = EthEventId {
+                signature: ValidEvents::Lifted.signature(),
+                transaction_hash: H256::from(transaction_hash),
+            };
+            Challenges::<T>::insert(event_id, BoundedVec::truncate_from(vec![challenger.clone()]));
+        }
+        let caller: T::AccountId = whitelisted_caller();
+    }: _(RawOrigin::Signed(caller), c)
+    verify {
+        assert_eq!(Challenges::<T>::iter_keys().count(), 0);
+        assert_last_event::<T>(Event::<T>::StaleChallengesRemoved{ removed: c }.into());
+    }
 }
 
 impl_benchmark_test_suite!(
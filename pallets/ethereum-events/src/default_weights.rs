@@ -46,6 +46,12 @@ pub trait WeightInfo {
 	fn process_event_without_successful_challenge(v: u32, e: u32, ) -> Weight;
 	fn challenge_event(v: u32, e: u32, c: u32, ) -> Weight;
 	fn set_event_challenge_period() -> Weight;
+	fn remove_replayed_event(s: u32, ) -> Weight;
+	fn revalidate_event() -> Weight;
+	fn remove_nft_contract() -> Weight;
+	fn update_nft_contract() -> Weight;
+	fn set_stake_weighted_challenges() -> Weight;
+	fn cleanup(c: u32, ) -> Weight;
 }
 
 /// Weights for pallet_ethereum_events using the Substrate node and recommended hardware.
@@ -169,6 +175,8 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `EthereumEvents::QuorumFactor` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
 	/// Storage: `EthereumEvents::EventsPendingChallenge` (r:1 w:1)
 	/// Proof: `EthereumEvents::EventsPendingChallenge` (`max_values`: Some(1), `max_size`: Some(62401), added: 62896, mode: `MaxEncodedLen`)
+	/// Storage: `EthereumEvents::UnsignedSubmissions` (r:1 w:1)
+	/// Proof: `EthereumEvents::UnsignedSubmissions` (`max_values`: None, `max_size`: Some(77), added: 2552, mode: `MaxEncodedLen`)
 	/// The range of component `v` is `[1, 10]`.
 	/// The range of component `u` is `[1, 499]`.
 	fn submit_checkevent_result(_v: u32, u: u32, ) -> Weight {
@@ -179,8 +187,8 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		Weight::from_parts(39_824_208, 63886)
 			// Standard Error: 1_949
 			.saturating_add(Weight::from_parts(356_833, 0).saturating_mul(u.into()))
-			.saturating_add(T::DbWeight::get().reads(5_u64))
-			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
 	}
 	/// Storage: `Avn::Validators` (r:1 w:0)
 	/// Proof: `Avn::Validators` (`max_values`: Some(1), `max_size`: Some(16386), added: 16881, mode: `MaxEncodedLen`)
@@ -204,22 +212,33 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `AvnOffenceHandler::SlashingEnabled` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
 	/// Storage: `EthereumEvents::ProcessedEvents` (r:0 w:1)
 	/// Proof: `EthereumEvents::ProcessedEvents` (`max_values`: None, `max_size`: Some(81), added: 2556, mode: `MaxEncodedLen`)
+	/// Storage: `EthereumEvents::UnsignedSubmissions` (r:1 w:1)
+	/// Proof: `EthereumEvents::UnsignedSubmissions` (`max_values`: None, `max_size`: Some(77), added: 2552, mode: `MaxEncodedLen`)
+	/// Storage: `EthereumEvents::OffenceEvidence` (r:0 w:1)
+	/// Proof: `EthereumEvents::OffenceEvidence` (`max_values`: None, `max_size`: Some(187), added: 2662, mode: `MaxEncodedLen`)
+	/// Storage: `EthereumEvents::StakeWeightedChallenges` (r:1 w:0)
+	/// Proof: `EthereumEvents::StakeWeightedChallenges` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::Era` (r:1 w:0)
+	/// Proof: `ParachainStaking::Era` (`max_values`: Some(1), `max_size`: Some(12), added: 507, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::AtStake` (r:20 w:0)
+	/// Proof: `ParachainStaking::AtStake` (`max_values`: None, `max_size`: Some(14486), added: 16961, mode: `MaxEncodedLen`)
 	/// The range of component `v` is `[1, 10]`.
 	/// The range of component `e` is `[1, 49]`.
 	fn process_event_with_successful_challenge(v: u32, e: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `1025 + e * (122 ±0) + v * (74 ±0)`
-		//  Estimated: `63886 + e * (122 ±0) + v * (75 ±0)`
+		//  Estimated: `63886 + e * (122 ±0) + v * (17036 ±0)`
 		// Minimum execution time: 98_412_000 picoseconds.
 		Weight::from_parts(99_912_786, 63886)
 			// Standard Error: 32_217
 			.saturating_add(Weight::from_parts(158_809, 0).saturating_mul(v.into()))
 			// Standard Error: 6_299
 			.saturating_add(Weight::from_parts(868_289, 0).saturating_mul(e.into()))
-			.saturating_add(T::DbWeight::get().reads(10_u64))
-			.saturating_add(T::DbWeight::get().writes(5_u64))
+			.saturating_add(T::DbWeight::get().reads(13_u64))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(v.into())))
+			.saturating_add(T::DbWeight::get().writes(7_u64))
 			.saturating_add(Weight::from_parts(0, 122).saturating_mul(e.into()))
-			.saturating_add(Weight::from_parts(0, 75).saturating_mul(v.into()))
+			.saturating_add(Weight::from_parts(0, 17036).saturating_mul(v.into()))
 	}
 	/// Storage: `Avn::Validators` (r:1 w:0)
 	/// Proof: `Avn::Validators` (`max_values`: Some(1), `max_size`: Some(16386), added: 16881, mode: `MaxEncodedLen`)
@@ -243,22 +262,33 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `AvnOffenceHandler::SlashingEnabled` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
 	/// Storage: `EthereumEvents::ProcessedEvents` (r:0 w:1)
 	/// Proof: `EthereumEvents::ProcessedEvents` (`max_values`: None, `max_size`: Some(81), added: 2556, mode: `MaxEncodedLen`)
+	/// Storage: `EthereumEvents::UnsignedSubmissions` (r:1 w:1)
+	/// Proof: `EthereumEvents::UnsignedSubmissions` (`max_values`: None, `max_size`: Some(77), added: 2552, mode: `MaxEncodedLen`)
+	/// Storage: `EthereumEvents::OffenceEvidence` (r:0 w:1)
+	/// Proof: `EthereumEvents::OffenceEvidence` (`max_values`: None, `max_size`: Some(187), added: 2662, mode: `MaxEncodedLen`)
+	/// Storage: `EthereumEvents::StakeWeightedChallenges` (r:1 w:0)
+	/// Proof: `EthereumEvents::StakeWeightedChallenges` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::Era` (r:1 w:0)
+	/// Proof: `ParachainStaking::Era` (`max_values`: Some(1), `max_size`: Some(12), added: 507, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::AtStake` (r:20 w:0)
+	/// Proof: `ParachainStaking::AtStake` (`max_values`: None, `max_size`: Some(14486), added: 16961, mode: `MaxEncodedLen`)
 	/// The range of component `v` is `[1, 10]`.
 	/// The range of component `e` is `[1, 49]`.
 	fn process_event_without_successful_challenge(v: u32, e: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `1036 + e * (122 ±0) + v * (64 ±0)`
-		//  Estimated: `63886 + e * (122 ±0) + v * (64 ±0)`
+		//  Estimated: `63886 + e * (122 ±0) + v * (17025 ±0)`
 		// Minimum execution time: 96_153_000 picoseconds.
 		Weight::from_parts(95_057_087, 63886)
 			// Standard Error: 27_308
 			.saturating_add(Weight::from_parts(305_756, 0).saturating_mul(v.into()))
 			// Standard Error: 5_339
 			.saturating_add(Weight::from_parts(875_533, 0).saturating_mul(e.into()))
-			.saturating_add(T::DbWeight::get().reads(10_u64))
-			.saturating_add(T::DbWeight::get().writes(5_u64))
+			.saturating_add(T::DbWeight::get().reads(13_u64))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(v.into())))
+			.saturating_add(T::DbWeight::get().writes(7_u64))
 			.saturating_add(Weight::from_parts(0, 122).saturating_mul(e.into()))
-			.saturating_add(Weight::from_parts(0, 64).saturating_mul(v.into()))
+			.saturating_add(Weight::from_parts(0, 17025).saturating_mul(v.into()))
 	}
 	/// Storage: `Avn::Validators` (r:1 w:0)
 	/// Proof: `Avn::Validators` (`max_values`: Some(1), `max_size`: Some(16386), added: 16881, mode: `MaxEncodedLen`)
@@ -266,6 +296,8 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `EthereumEvents::EventsPendingChallenge` (`max_values`: Some(1), `max_size`: Some(62401), added: 62896, mode: `MaxEncodedLen`)
 	/// Storage: `EthereumEvents::Challenges` (r:1 w:1)
 	/// Proof: `EthereumEvents::Challenges` (`max_values`: None, `max_size`: Some(1681), added: 4156, mode: `MaxEncodedLen`)
+	/// Storage: `EthereumEvents::UnsignedSubmissions` (r:1 w:1)
+	/// Proof: `EthereumEvents::UnsignedSubmissions` (`max_values`: None, `max_size`: Some(77), added: 2552, mode: `MaxEncodedLen`)
 	/// The range of component `v` is `[3, 10]`.
 	/// The range of component `e` is `[1, 49]`.
 	/// The range of component `c` is `[1, 49]`.
@@ -281,8 +313,8 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(Weight::from_parts(242_643, 0).saturating_mul(e.into()))
 			// Standard Error: 890
 			.saturating_add(Weight::from_parts(48_141, 0).saturating_mul(c.into()))
-			.saturating_add(T::DbWeight::get().reads(3_u64))
-			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
 	/// Storage: `EthereumEvents::EventChallengePeriod` (r:0 w:1)
 	/// Proof: `EthereumEvents::EventChallengePeriod` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
@@ -294,6 +326,109 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		Weight::from_parts(9_620_000, 0)
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
+	/// Storage: `EthereumEvents::ArchivedPeriods` (r:1 w:0)
+	/// Proof: `EthereumEvents::ArchivedPeriods` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `EthereumEvents::UncheckedEvents` (r:1 w:1)
+	/// Proof: `EthereumEvents::UncheckedEvents` (`max_values`: Some(1), `max_size`: Some(38002), added: 38497, mode: `MaxEncodedLen`)
+	/// Storage: `EthereumEvents::EventsPendingChallenge` (r:1 w:1)
+	/// Proof: `EthereumEvents::EventsPendingChallenge` (`max_values`: Some(1), `max_size`: Some(62401), added: 62896, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[0, 31]`.
+	fn remove_replayed_event(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `11090`
+		//  Estimated: `63886`
+		// Minimum execution time: 46_310_000 picoseconds.
+		Weight::from_parts(48_502_117, 63886)
+			// Standard Error: 3_214
+			.saturating_add(Weight::from_parts(1_093_552, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `EthereumEvents::InvalidEvents` (r:1 w:1)
+	/// Proof: `EthereumEvents::InvalidEvents` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
+	/// Storage: `EthereumEvents::ProcessedEvents` (r:1 w:1)
+	/// Proof: `EthereumEvents::ProcessedEvents` (`max_values`: None, `max_size`: Some(81), added: 2556, mode: `MaxEncodedLen`)
+	/// Storage: `EthereumEvents::ProcessedEventsByPeriod` (r:1 w:1)
+	/// Proof: `EthereumEvents::ProcessedEventsByPeriod` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+	/// Storage: `EthereumEvents::ProcessedEventsCount` (r:1 w:1)
+	/// Proof: `EthereumEvents::ProcessedEventsCount` (`max_values`: None, `max_size`: Some(16), added: 2491, mode: `MaxEncodedLen`)
+	/// Storage: `EthereumEvents::TotalIngresses` (r:1 w:1)
+	/// Proof: `EthereumEvents::TotalIngresses` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `EthereumEvents::UncheckedEvents` (r:1 w:1)
+	/// Proof: `EthereumEvents::UncheckedEvents` (`max_values`: Some(1), `max_size`: Some(38002), added: 38497, mode: `MaxEncodedLen`)
+	fn revalidate_event() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `38426`
+		//  Estimated: `39487`
+		// Minimum execution time: 61_882_000 picoseconds.
+		Weight::from_parts(63_417_000, 39487)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+	}
+	/// Storage: `EthereumEvents::NftT1Contracts` (r:1 w:1)
+	/// Proof: `EthereumEvents::NftT1Contracts` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	/// Storage: `EthereumEvents::UncheckedEvents` (r:1 w:0)
+	/// Proof: `EthereumEvents::UncheckedEvents` (`max_values`: Some(1), `max_size`: Some(38002), added: 38497, mode: `MaxEncodedLen`)
+	/// Storage: `EthereumEvents::EventsPendingChallenge` (r:1 w:0)
+	/// Proof: `EthereumEvents::EventsPendingChallenge` (`max_values`: Some(1), `max_size`: Some(62401), added: 62896, mode: `MaxEncodedLen`)
+	fn remove_nft_contract() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `6388`
+		//  Estimated: `63886`
+		// Minimum execution time: 24_312_000 picoseconds.
+		Weight::from_parts(24_312_000, 63886)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `EthereumEvents::NftT1Contracts` (r:2 w:2)
+	/// Proof: `EthereumEvents::NftT1Contracts` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	/// Storage: `EthereumEvents::UncheckedEvents` (r:1 w:0)
+	/// Proof: `EthereumEvents::UncheckedEvents` (`max_values`: Some(1), `max_size`: Some(38002), added: 38497, mode: `MaxEncodedLen`)
+	/// Storage: `EthereumEvents::EventsPendingChallenge` (r:1 w:0)
+	/// Proof: `EthereumEvents::EventsPendingChallenge` (`max_values`: Some(1), `max_size`: Some(62401), added: 62896, mode: `MaxEncodedLen`)
+	fn update_nft_contract() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `6388`
+		//  Estimated: `63886`
+		// Minimum execution time: 28_947_000 picoseconds.
+		Weight::from_parts(28_947_000, 63886)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `EthereumEvents::StakeWeightedChallenges` (r:0 w:1)
+	/// Proof: `EthereumEvents::StakeWeightedChallenges` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	fn set_stake_weighted_challenges() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 9_410_000 picoseconds.
+		Weight::from_parts(9_410_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `EthereumEvents::EventsPendingChallenge` (r:1 w:0)
+	/// Proof: `EthereumEvents::EventsPendingChallenge` (`max_values`: Some(1), `max_size`: Some(62401), added: 62896, mode: `MaxEncodedLen`)
+	/// Storage: `EthereumEvents::Challenges` (r:101 w:100)
+	/// Proof: `EthereumEvents::Challenges` (`max_values`: None, `max_size`: Some(1681), added: 4156, mode: `MaxEncodedLen`)
+	/// Storage: `EthereumEvents::ChallengesCleanupCursor` (r:1 w:1)
+	/// Proof: `EthereumEvents::ChallengesCleanupCursor` (`max_values`: Some(1), `max_size`: Some(130), added: 625, mode: `MaxEncodedLen`)
+	/// Storage: `EthereumEvents::OffenceEvidence` (r:1 w:0)
+	/// Proof: `EthereumEvents::OffenceEvidence` (`max_values`: None, `max_size`: Some(187), added: 2662, mode: `MaxEncodedLen`)
+	/// Storage: `EthereumEvents::OffenceEvidenceCleanupCursor` (r:1 w:1)
+	/// Proof: `EthereumEvents::OffenceEvidenceCleanupCursor` (`max_values`: Some(1), `max_size`: Some(130), added: 625, mode: `MaxEncodedLen`)
+	/// The range of component `c` is `[1, 100]`.
+	fn cleanup(c: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `6388`
+		//  Estimated: `63886`
+		// Minimum execution time: 14_000_000 picoseconds.
+		Weight::from_parts(14_000_000, 63886)
+			.saturating_add(Weight::from_parts(3_100_000, 0).saturating_mul(c.into()))
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(c.into())))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(c.into())))
+			.saturating_add(Weight::from_parts(0, 4156).saturating_mul(c.into()))
+	}
 }
 
 // For backwards compatibility and tests.
@@ -416,6 +551,8 @@ impl WeightInfo for () {
 	/// Proof: `EthereumEvents::QuorumFactor` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
 	/// Storage: `EthereumEvents::EventsPendingChallenge` (r:1 w:1)
 	/// Proof: `EthereumEvents::EventsPendingChallenge` (`max_values`: Some(1), `max_size`: Some(62401), added: 62896, mode: `MaxEncodedLen`)
+	/// Storage: `EthereumEvents::UnsignedSubmissions` (r:1 w:1)
+	/// Proof: `EthereumEvents::UnsignedSubmissions` (`max_values`: None, `max_size`: Some(77), added: 2552, mode: `MaxEncodedLen`)
 	/// The range of component `v` is `[1, 10]`.
 	/// The range of component `u` is `[1, 499]`.
 	fn submit_checkevent_result(_v: u32, u: u32, ) -> Weight {
@@ -426,8 +563,8 @@ impl WeightInfo for () {
 		Weight::from_parts(39_824_208, 63886)
 			// Standard Error: 1_949
 			.saturating_add(Weight::from_parts(356_833, 0).saturating_mul(u.into()))
-			.saturating_add(RocksDbWeight::get().reads(5_u64))
-			.saturating_add(RocksDbWeight::get().writes(2_u64))
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
 	}
 	/// Storage: `Avn::Validators` (r:1 w:0)
 	/// Proof: `Avn::Validators` (`max_values`: Some(1), `max_size`: Some(16386), added: 16881, mode: `MaxEncodedLen`)
@@ -451,22 +588,33 @@ impl WeightInfo for () {
 	/// Proof: `AvnOffenceHandler::SlashingEnabled` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
 	/// Storage: `EthereumEvents::ProcessedEvents` (r:0 w:1)
 	/// Proof: `EthereumEvents::ProcessedEvents` (`max_values`: None, `max_size`: Some(81), added: 2556, mode: `MaxEncodedLen`)
+	/// Storage: `EthereumEvents::UnsignedSubmissions` (r:1 w:1)
+	/// Proof: `EthereumEvents::UnsignedSubmissions` (`max_values`: None, `max_size`: Some(77), added: 2552, mode: `MaxEncodedLen`)
+	/// Storage: `EthereumEvents::OffenceEvidence` (r:0 w:1)
+	/// Proof: `EthereumEvents::OffenceEvidence` (`max_values`: None, `max_size`: Some(187), added: 2662, mode: `MaxEncodedLen`)
+	/// Storage: `EthereumEvents::StakeWeightedChallenges` (r:1 w:0)
+	/// Proof: `EthereumEvents::StakeWeightedChallenges` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::Era` (r:1 w:0)
+	/// Proof: `ParachainStaking::Era` (`max_values`: Some(1), `max_size`: Some(12), added: 507, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::AtStake` (r:20 w:0)
+	/// Proof: `ParachainStaking::AtStake` (`max_values`: None, `max_size`: Some(14486), added: 16961, mode: `MaxEncodedLen`)
 	/// The range of component `v` is `[1, 10]`.
 	/// The range of component `e` is `[1, 49]`.
 	fn process_event_with_successful_challenge(v: u32, e: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `1025 + e * (122 ±0) + v * (74 ±0)`
-		//  Estimated: `63886 + e * (122 ±0) + v * (75 ±0)`
+		//  Estimated: `63886 + e * (122 ±0) + v * (17036 ±0)`
 		// Minimum execution time: 98_412_000 picoseconds.
 		Weight::from_parts(99_912_786, 63886)
 			// Standard Error: 32_217
 			.saturating_add(Weight::from_parts(158_809, 0).saturating_mul(v.into()))
 			// Standard Error: 6_299
 			.saturating_add(Weight::from_parts(868_289, 0).saturating_mul(e.into()))
-			.saturating_add(RocksDbWeight::get().reads(10_u64))
-			.saturating_add(RocksDbWeight::get().writes(5_u64))
+			.saturating_add(RocksDbWeight::get().reads(13_u64))
+			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(v.into())))
+			.saturating_add(RocksDbWeight::get().writes(7_u64))
 			.saturating_add(Weight::from_parts(0, 122).saturating_mul(e.into()))
-			.saturating_add(Weight::from_parts(0, 75).saturating_mul(v.into()))
+			.saturating_add(Weight::from_parts(0, 17036).saturating_mul(v.into()))
 	}
 	/// Storage: `Avn::Validators` (r:1 w:0)
 	/// Proof: `Avn::Validators` (`max_values`: Some(1), `max_size`: Some(16386), added: 16881, mode: `MaxEncodedLen`)
@@ -490,22 +638,33 @@ impl WeightInfo for () {
 	/// Proof: `AvnOffenceHandler::SlashingEnabled` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
 	/// Storage: `EthereumEvents::ProcessedEvents` (r:0 w:1)
 	/// Proof: `EthereumEvents::ProcessedEvents` (`max_values`: None, `max_size`: Some(81), added: 2556, mode: `MaxEncodedLen`)
+	/// Storage: `EthereumEvents::UnsignedSubmissions` (r:1 w:1)
+	/// Proof: `EthereumEvents::UnsignedSubmissions` (`max_values`: None, `max_size`: Some(77), added: 2552, mode: `MaxEncodedLen`)
+	/// Storage: `EthereumEvents::OffenceEvidence` (r:0 w:1)
+	/// Proof: `EthereumEvents::OffenceEvidence` (`max_values`: None, `max_size`: Some(187), added: 2662, mode: `MaxEncodedLen`)
+	/// Storage: `EthereumEvents::StakeWeightedChallenges` (r:1 w:0)
+	/// Proof: `EthereumEvents::StakeWeightedChallenges` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::Era` (r:1 w:0)
+	/// Proof: `ParachainStaking::Era` (`max_values`: Some(1), `max_size`: Some(12), added: 507, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::AtStake` (r:20 w:0)
+	/// Proof: `ParachainStaking::AtStake` (`max_values`: None, `max_size`: Some(14486), added: 16961, mode: `MaxEncodedLen`)
 	/// The range of component `v` is `[1, 10]`.
 	/// The range of component `e` is `[1, 49]`.
 	fn process_event_without_successful_challenge(v: u32, e: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `1036 + e * (122 ±0) + v * (64 ±0)`
-		//  Estimated: `63886 + e * (122 ±0) + v * (64 ±0)`
+		//  Estimated: `63886 + e * (122 ±0) + v * (17025 ±0)`
 		// Minimum execution time: 96_153_000 picoseconds.
 		Weight::from_parts(95_057_087, 63886)
 			// Standard Error: 27_308
 			.saturating_add(Weight::from_parts(305_756, 0).saturating_mul(v.into()))
 			// Standard Error: 5_339
 			.saturating_add(Weight::from_parts(875_533, 0).saturating_mul(e.into()))
-			.saturating_add(RocksDbWeight::get().reads(10_u64))
-			.saturating_add(RocksDbWeight::get().writes(5_u64))
+			.saturating_add(RocksDbWeight::get().reads(13_u64))
+			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(v.into())))
+			.saturating_add(RocksDbWeight::get().writes(7_u64))
 			.saturating_add(Weight::from_parts(0, 122).saturating_mul(e.into()))
-			.saturating_add(Weight::from_parts(0, 64).saturating_mul(v.into()))
+			.saturating_add(Weight::from_parts(0, 17025).saturating_mul(v.into()))
 	}
 	/// Storage: `Avn::Validators` (r:1 w:0)
 	/// Proof: `Avn::Validators` (`max_values`: Some(1), `max_size`: Some(16386), added: 16881, mode: `MaxEncodedLen`)
@@ -513,6 +672,8 @@ impl WeightInfo for () {
 	/// Proof: `EthereumEvents::EventsPendingChallenge` (`max_values`: Some(1), `max_size`: Some(62401), added: 62896, mode: `MaxEncodedLen`)
 	/// Storage: `EthereumEvents::Challenges` (r:1 w:1)
 	/// Proof: `EthereumEvents::Challenges` (`max_values`: None, `max_size`: Some(1681), added: 4156, mode: `MaxEncodedLen`)
+	/// Storage: `EthereumEvents::UnsignedSubmissions` (r:1 w:1)
+	/// Proof: `EthereumEvents::UnsignedSubmissions` (`max_values`: None, `max_size`: Some(77), added: 2552, mode: `MaxEncodedLen`)
 	/// The range of component `v` is `[3, 10]`.
 	/// The range of component `e` is `[1, 49]`.
 	/// The range of component `c` is `[1, 49]`.
@@ -528,8 +689,8 @@ impl WeightInfo for () {
 			.saturating_add(Weight::from_parts(242_643, 0).saturating_mul(e.into()))
 			// Standard Error: 890
 			.saturating_add(Weight::from_parts(48_141, 0).saturating_mul(c.into()))
-			.saturating_add(RocksDbWeight::get().reads(3_u64))
-			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
 	/// Storage: `EthereumEvents::EventChallengePeriod` (r:0 w:1)
 	/// Proof: `EthereumEvents::EventChallengePeriod` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
@@ -541,4 +702,107 @@ impl WeightInfo for () {
 		Weight::from_parts(9_620_000, 0)
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
+	/// Storage: `EthereumEvents::ArchivedPeriods` (r:1 w:0)
+	/// Proof: `EthereumEvents::ArchivedPeriods` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `EthereumEvents::UncheckedEvents` (r:1 w:1)
+	/// Proof: `EthereumEvents::UncheckedEvents` (`max_values`: Some(1), `max_size`: Some(38002), added: 38497, mode: `MaxEncodedLen`)
+	/// Storage: `EthereumEvents::EventsPendingChallenge` (r:1 w:1)
+	/// Proof: `EthereumEvents::EventsPendingChallenge` (`max_values`: Some(1), `max_size`: Some(62401), added: 62896, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[0, 31]`.
+	fn remove_replayed_event(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `11090`
+		//  Estimated: `63886`
+		// Minimum execution time: 46_310_000 picoseconds.
+		Weight::from_parts(48_502_117, 63886)
+			// Standard Error: 3_214
+			.saturating_add(Weight::from_parts(1_093_552, 0).saturating_mul(s.into()))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `EthereumEvents::InvalidEvents` (r:1 w:1)
+	/// Proof: `EthereumEvents::InvalidEvents` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
+	/// Storage: `EthereumEvents::ProcessedEvents` (r:1 w:1)
+	/// Proof: `EthereumEvents::ProcessedEvents` (`max_values`: None, `max_size`: Some(81), added: 2556, mode: `MaxEncodedLen`)
+	/// Storage: `EthereumEvents::ProcessedEventsByPeriod` (r:1 w:1)
+	/// Proof: `EthereumEvents::ProcessedEventsByPeriod` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+	/// Storage: `EthereumEvents::ProcessedEventsCount` (r:1 w:1)
+	/// Proof: `EthereumEvents::ProcessedEventsCount` (`max_values`: None, `max_size`: Some(16), added: 2491, mode: `MaxEncodedLen`)
+	/// Storage: `EthereumEvents::TotalIngresses` (r:1 w:1)
+	/// Proof: `EthereumEvents::TotalIngresses` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `EthereumEvents::UncheckedEvents` (r:1 w:1)
+	/// Proof: `EthereumEvents::UncheckedEvents` (`max_values`: Some(1), `max_size`: Some(38002), added: 38497, mode: `MaxEncodedLen`)
+	fn revalidate_event() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `38426`
+		//  Estimated: `39487`
+		// Minimum execution time: 61_882_000 picoseconds.
+		Weight::from_parts(63_417_000, 39487)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+	}
+	/// Storage: `EthereumEvents::NftT1Contracts` (r:1 w:1)
+	/// Proof: `EthereumEvents::NftT1Contracts` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	/// Storage: `EthereumEvents::UncheckedEvents` (r:1 w:0)
+	/// Proof: `EthereumEvents::UncheckedEvents` (`max_values`: Some(1), `max_size`: Some(38002), added: 38497, mode: `MaxEncodedLen`)
+	/// Storage: `EthereumEvents::EventsPendingChallenge` (r:1 w:0)
+	/// Proof: `EthereumEvents::EventsPendingChallenge` (`max_values`: Some(1), `max_size`: Some(62401), added: 62896, mode: `MaxEncodedLen`)
+	fn remove_nft_contract() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `6388`
+		//  Estimated: `63886`
+		// Minimum execution time: 24_312_000 picoseconds.
+		Weight::from_parts(24_312_000, 63886)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `EthereumEvents::NftT1Contracts` (r:2 w:2)
+	/// Proof: `EthereumEvents::NftT1Contracts` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	/// Storage: `EthereumEvents::UncheckedEvents` (r:1 w:0)
+	/// Proof: `EthereumEvents::UncheckedEvents` (`max_values`: Some(1), `max_size`: Some(38002), added: 38497, mode: `MaxEncodedLen`)
+	/// Storage: `EthereumEvents::EventsPendingChallenge` (r:1 w:0)
+	/// Proof: `EthereumEvents::EventsPendingChallenge` (`max_values`: Some(1), `max_size`: Some(62401), added: 62896, mode: `MaxEncodedLen`)
+	fn update_nft_contract() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `6388`
+		//  Estimated: `63886`
+		// Minimum execution time: 28_947_000 picoseconds.
+		Weight::from_parts(28_947_000, 63886)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `EthereumEvents::StakeWeightedChallenges` (r:0 w:1)
+	/// Proof: `EthereumEvents::StakeWeightedChallenges` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	fn set_stake_weighted_challenges() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 9_410_000 picoseconds.
+		Weight::from_parts(9_410_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `EthereumEvents::EventsPendingChallenge` (r:1 w:0)
+	/// Proof: `EthereumEvents::EventsPendingChallenge` (`max_values`: Some(1), `max_size`: Some(62401), added: 62896, mode: `MaxEncodedLen`)
+	/// Storage: `EthereumEvents::Challenges` (r:101 w:100)
+	/// Proof: `EthereumEvents::Challenges` (`max_values`: None, `max_size`: Some(1681), added: 4156, mode: `MaxEncodedLen`)
+	/// Storage: `EthereumEvents::ChallengesCleanupCursor` (r:1 w:1)
+	/// Proof: `EthereumEvents::ChallengesCleanupCursor` (`max_values`: Some(1), `max_size`: Some(130), added: 625, mode: `MaxEncodedLen`)
+	/// Storage: `EthereumEvents::OffenceEvidence` (r:1 w:0)
+	/// Proof: `EthereumEvents::OffenceEvidence` (`max_values`: None, `max_size`: Some(187), added: 2662, mode: `MaxEncodedLen`)
+	/// Storage: `EthereumEvents::OffenceEvidenceCleanupCursor` (r:1 w:1)
+	/// Proof: `EthereumEvents::OffenceEvidenceCleanupCursor` (`max_values`: Some(1), `max_size`: Some(130), added: 625, mode: `MaxEncodedLen`)
+	/// The range of component `c` is `[1, 100]`.
+	fn cleanup(c: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `6388`
+		//  Estimated: `63886`
+		// Minimum execution time: 14_000_000 picoseconds.
+		Weight::from_parts(14_000_000, 63886)
+			.saturating_add(Weight::from_parts(3_100_000, 0).saturating_mul(c.into()))
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(c.into())))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(c.into())))
+			.saturating_add(Weight::from_parts(0, 4156).saturating_mul(c.into()))
+	}
 }
\ No newline at end of file
@@ -104,6 +104,15 @@ pub fn get_status(response: &JsonObject) -> Result<u8, SimpleError> {
     }
 }
 
+/// The number of the Ethereum block a transaction receipt is part of
+pub fn get_block_number(response: &JsonObject) -> Result<u64, SimpleError> {
+    let block_number = get_value_of(String::from("blockNumber"), response)?.get_string()?;
+    u64::from_str_radix(block_number.trim_start_matches("0x"), 16).map_err(|e| {
+        log::error!("❌ Block number {:?} is not a valid hex number - {:?}", block_number, e);
+        SimpleError::plain_str("not a valid hex number")
+    })
+}
+
 fn get_topics_with_data(event: &JsonValue) -> Result<(Option<Vec<u8>>, Vec<Vec<u8>>), SimpleError> {
     let topics = get_topics(event)?;
     let data = get_data(event)?;
@@ -11,7 +11,7 @@ extern crate alloc;
 #[cfg(not(feature = "std"))]
 use alloc::string::{String, ToString};
 use frame_support::{
-    dispatch::DispatchResult,
+    dispatch::{DispatchResult, DispatchResultWithPostInfo, Pays, PostDispatchInfo},
     ensure,
     traits::{Get, IsSubType},
 };
@@ -23,7 +23,7 @@ use sp_core::{ConstU32, H160, H256};
 use sp_runtime::{
     offchain::storage::{MutateStorageError, StorageRetrievalError, StorageValueRef},
     scale_info::TypeInfo,
-    traits::{CheckedAdd, Dispatchable, Hash, IdentifyAccount, Verify, Zero},
+    traits::{CheckedAdd, Dispatchable, Hash, IdentifyAccount, Saturating, Verify, Zero},
     transaction_validity::{
         InvalidTransaction, TransactionPriority, TransactionSource, TransactionValidity,
         ValidTransaction,
@@ -37,43 +37,70 @@ use sp_application_crypto::RuntimeAppPublic;
 use sp_avn_common::{
     event_discovery::EthereumEventsFilterTrait,
     event_types::{
-        AddedValidatorData, AvtGrowthLiftedData, AvtLowerClaimedData, Challenge, ChallengeReason,
-        CheckResult, EthEventCheckResult, EthEventId, EventData, LiftedData, NftCancelListingData,
-        NftEndBatchListingData, NftMintData, NftTransferToData, ProcessedEventHandler, ValidEvents,
+        processed_event_index_key, AddedValidatorData, ArchivedEventProof, AvtGrowthLiftedData,
+        AvtLowerClaimedData, Challenge, ChallengeReason, CheckResult, EthEventCheckResult,
+        EthEventId, EthEventStatus, EventData, LiftedData, LiftedToDestinationData,
+        LiftedWithVestingData, NftBatchListingScheduleData, NftCancelListingData,
+        NftEndBatchListingData, NftMintData, NftOfferAcceptedData, NftTransferToData,
+        ProcessedEventHandler, ProcessedEventRecord, SftMintData, SftTransferToData, ValidEvents,
         Validator,
     },
-    verify_signature, EthQueryRequest, EthQueryResponse, EthQueryResponseType, EthTransaction,
-    IngressCounter, InnerCallValidator, Proof,
+    ocw_task_queue::{self as OcwTaskQueue, OcwTaskPriority},
+    verify_domain_separated_signature, EthQueryRequest, EthQueryResponse, EthQueryResponseType,
+    EthTransaction, IngressCounter, InnerCallValidator, Proof,
 };
 
 use pallet_session::historical::IdentificationTuple;
 use sp_staking::offence::ReportOffence;
 
-use pallet_avn::{self as avn, Error as avn_error, ProcessedEventsChecker, MAX_VALIDATOR_ACCOUNTS};
+use pallet_avn::{
+    self as avn,
+    unsigned_rate_limit::{prune_expired_windows, SubmissionWindow, UnsignedRateLimit},
+    Error as avn_error, NftContractChecker, ProcessedEventsChecker, ValidatorStakeProvider,
+    MAX_VALIDATOR_ACCOUNTS,
+};
 pub mod offence;
 use crate::offence::{
     create_and_report_invalid_log_offence, EthereumLogOffenceType, InvalidEthereumLogOffence,
+    InvalidEthereumLogOffenceEvidence,
 };
 
 pub mod event_parser;
-use crate::event_parser::{find_event, get_status, parse_response_to_json};
+use crate::event_parser::{find_event, get_block_number, get_status, parse_response_to_json};
+
+pub mod archive;
+pub use archive::ArchivedPeriod;
+
+#[cfg(any(test, feature = "try-runtime"))]
+mod try_state;
+
 use sp_runtime::BoundedVec;
 
 pub type AVN<T> = avn::Pallet<T>;
 pub use pallet::*;
 
 const VALIDATED_EVENT_LOCAL_STORAGE: &'static [u8; 28] = b"eth_events::validated_events";
+// Prefix of the keys of the final transaction receipts cached by the offchain worker
+const FINAL_RECEIPT_LOCAL_STORAGE_PREFIX: &'static [u8] = b"eth_events::final_receipt::";
 
 const PALLET_ID: &'static [u8; 20] = b"eth_events::last_run";
+/// Owner of the tasks of this pallet in the offchain task queue
+const OCW_TASK_OWNER: &'static [u8] = b"eth_events";
+/// Blocks a check result is given to be included before the event is checked again
+const CHECK_EVENT_LEASE_BLOCKS: u32 = 5;
 
 const ERROR_CODE_EVENT_NOT_IN_UNCHECKED: u8 = 0;
 const ERROR_CODE_INVALID_EVENT_DATA: u8 = 1;
 const ERROR_CODE_IS_PRIMARY_HAS_ERROR: u8 = 2;
 const ERROR_CODE_VALIDATOR_NOT_PRIMARY: u8 = 3;
 const ERROR_CODE_EVENT_NOT_IN_PENDING_CHALLENGES: u8 = 4;
+const ERROR_CODE_EVENTS_PENDING_CHALLENGE_FULL: u8 = 5;
+const ERROR_CODE_UNSIGNED_RATE_LIMIT_REACHED: u8 = 6;
 
 const MINIMUM_EVENT_CHALLENGE_PERIOD: u32 = 60;
 
+/// Prefix of the payloads signed for this pallet, ahead of the per call context
+pub const SIGNING_DOMAIN: &'static [u8] = b"ethereum_events";
 pub const SIGNED_ADD_ETHEREUM_LOG_CONTEXT: &'static [u8] =
     b"authorization for add ethereum log operation";
 #[cfg(test)]
@@ -115,21 +142,71 @@ mod test_set_event_challenge_period;
 #[path = "tests/test_initial_events.rs"]
 mod test_initial_events;
 
+#[cfg(test)]
+#[path = "tests/test_try_state.rs"]
+mod test_try_state;
+
+#[cfg(test)]
+#[path = "tests/test_event_statuses.rs"]
+mod test_event_statuses;
+
+#[cfg(test)]
+#[path = "tests/test_archive.rs"]
+mod test_archive;
+
+#[cfg(test)]
+#[path = "tests/test_revalidate_event.rs"]
+mod test_revalidate_event;
+
+#[cfg(test)]
+#[path = "tests/test_remove_update_nft_contract.rs"]
+mod test_remove_update_nft_contract;
+
 #[cfg(test)]
 #[path = "tests/test_ethereum_logs.rs"]
 mod tests_ethereum_logs;
 
+#[cfg(test)]
+#[path = "tests/test_unsigned_rate_limit.rs"]
+mod test_unsigned_rate_limit;
+
+#[cfg(test)]
+#[path = "tests/test_stake_weighted_challenges.rs"]
+mod test_stake_weighted_challenges;
+
+#[cfg(test)]
+#[path = "tests/test_cleanup.rs"]
+mod test_cleanup;
+
+#[cfg(test)]
+#[path = "tests/test_migration.rs"]
+mod test_migration;
+
+#[cfg(test)]
+#[path = "tests/test_final_receipt_cache.rs"]
+mod test_final_receipt_cache;
+
 mod benchmarking;
 
 pub mod default_weights;
 pub use default_weights::WeightInfo;
 
+pub mod migration;
+
 #[derive(Encode, Decode, Clone, PartialEq, Debug, Eq, TypeInfo)]
 pub enum EthereumContracts {
     AvnBridgeContract,
     NftMarketplace,
 }
 
+/// The types of unsigned transactions whose submissions are limited separately
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+pub enum EthereumEventsUnsignedCall {
+    SubmitCheckEventResult,
+    ProcessEvent,
+    ChallengeEvent,
+}
+
 const SUBMIT_CHECKEVENT_RESULT_CONTEXT: &'static [u8] = b"submit_checkevent_result";
 const CHALLENGE_EVENT_CONTEXT: &'static [u8] = b"challenge_event";
 const PROCESS_EVENT_CONTEXT: &'static [u8] = b"process_event";
@@ -137,6 +214,8 @@ const PROCESS_EVENT_CONTEXT: &'static [u8] = b"process_event";
 const MAX_NUMBER_OF_UNCHECKED_EVENTS: u32 = 500;
 const MAX_NUMBER_OF_EVENTS_PENDING_CHALLENGES: u32 = 50;
 const MAX_CHALLENGES: u32 = 50;
+// Entries of `Challenges` that a single call to `cleanup` can check
+const MAX_CLEANUP_LIMIT: u32 = 100;
 
 pub type MaxUncheckedEvents = ConstU32<MAX_NUMBER_OF_UNCHECKED_EVENTS>;
 pub type MaxEventsPendingChallenges = ConstU32<MAX_NUMBER_OF_EVENTS_PENDING_CHALLENGES>;
@@ -192,6 +271,36 @@ pub mod pallet {
         /// Weight information for the extrinsics in this pallet.
         type WeightInfo: WeightInfo;
         type EthereumEventsFilter: EthereumEventsFilterTrait;
+        /// The origin allowed to change the configuration of this pallet
+        type AdminOrigin: EnsureOrigin<<Self as frame_system::Config>::RuntimeOrigin>;
+        /// Number of blocks in a period of processed events. Processed events are archived a
+        /// whole period at a time.
+        #[pallet::constant]
+        type ArchivePeriod: Get<BlockNumberFor<Self>>;
+        /// Number of periods processed events are kept in storage before they are archived
+        #[pallet::constant]
+        type ProcessedEventsRetention: Get<u32>;
+        /// Number of periods the ids of archived events are kept after their period is archived.
+        /// Until then an archived event is rejected when it is added again, after that a proof of
+        /// the archive is needed to remove it.
+        #[pallet::constant]
+        type ArchivedEventIdsRetention: Get<u32>;
+        /// Number of blocks the evidence of a reported offence is kept before `cleanup` removes
+        /// it
+        #[pallet::constant]
+        type OffenceEvidenceRetention: Get<BlockNumberFor<Self>>;
+        /// Gives the stake backing each validator, used when challenges are weighted by stake
+        type ValidatorStake: ValidatorStakeProvider<Self::AccountId>;
+        /// Number of blocks in a window of unsigned submissions
+        #[pallet::constant]
+        type UnsignedSubmissionWindow: Get<BlockNumberFor<Self>>;
+        /// Unsigned transactions of each type a validator can submit in a window
+        #[pallet::constant]
+        type MaxUnsignedSubmissions: Get<u32>;
+        /// Consecutive windows in which a validator reaches its limit before an offence is
+        /// reported
+        #[pallet::constant]
+        type UnsignedAbuseThreshold: Get<u32>;
     }
 
     #[pallet::pallet]
@@ -219,6 +328,7 @@ pub mod pallet {
             eth_event_id: EthEventId,
             processor: T::AccountId,
             outcome: bool,
+            sequence: u64,
         },
         /// EventChallenged(EthEventId, Challenger, ChallengeReason)
         EventChallenged {
@@ -231,10 +341,11 @@ pub mod pallet {
             eth_event_id: EthEventId,
             check_result: CheckResult,
         },
-        /// OffenceReported(OffenceType, Offenders)
+        /// OffenceReported(OffenceType, Offenders, hash of the evidence in OffenceEvidence)
         OffenceReported {
             offence_type: EthereumLogOffenceType,
             offenders: Vec<IdentificationTuple<T>>,
+            evidence: H256,
         },
         /// EventAccepted(EthEventId)
         EventAccepted {
@@ -259,6 +370,46 @@ pub mod pallet {
             eth_event_id: EthEventId,
             account_id: T::AccountId,
         },
+        /// The events processed in `period` have been removed from storage. They can be proven
+        /// against `root`.
+        ProcessedEventsArchived {
+            period: u32,
+            root: H256,
+            number_of_events: u32,
+        },
+        /// A queued event was removed because it had already been processed in `period`
+        ReplayedEventRemoved {
+            eth_event_id: EthEventId,
+            period: u32,
+        },
+        /// An event declared invalid has been queued to be checked again
+        EventRevalidationRequested {
+            eth_event_id: EthEventId,
+            ingress_counter: IngressCounter,
+        },
+        /// An nft contract was removed from the registry
+        NftContractRemoved {
+            contract_address: H160,
+        },
+        /// A registered nft contract was replaced by `new_contract`
+        NftContractUpdated {
+            old_contract: H160,
+            new_contract: H160,
+        },
+        /// Challenges are now weighted by the stake backing the challengers, or counted if
+        /// `enabled` is false
+        StakeWeightedChallengesUpdated {
+            enabled: bool,
+        },
+        /// The challenges of events that are no longer pending challenge were removed
+        StaleChallengesRemoved {
+            removed: u32,
+        },
+        /// The evidence of offences reported more than `OffenceEvidenceRetention` blocks ago was
+        /// removed
+        ExpiredOffenceEvidenceRemoved {
+            removed: u32,
+        },
     }
 
     #[pallet::error]
@@ -293,6 +444,11 @@ pub mod pallet {
         PrevChallengesOverflow,
         EventsPendingChallengeOverflow,
         ErrorAddingEthereumLog,
+        InvalidArchivedEventProof,
+        EventCannotBeRevalidated,
+        NftContractNotFound,
+        NftContractAlreadyRegistered,
+        NftEventsPending,
     }
 
     #[pallet::storage]
@@ -332,20 +488,83 @@ pub mod pallet {
     pub type ProcessedEvents<T: Config> =
         StorageMap<_, Blake2_128Concat, EthEventId, bool, ValueQuery>;
 
+    /// Processed events that were declared invalid, with the block they were processed at. They can
+    /// be checked again with `revalidate_event` until their period is archived.
+    #[pallet::storage]
+    #[pallet::getter(fn invalid_events)]
+    pub type InvalidEvents<T: Config> =
+        StorageMap<_, Blake2_128Concat, EthEventId, BlockNumberFor<T>, OptionQuery>;
+
+    /// The events processed in each archive period that has not been archived yet
+    #[pallet::storage]
+    pub type ProcessedEventsByPeriod<T: Config> =
+        StorageDoubleMap<_, Twox64Concat, u32, Blake2_128Concat, EthEventId, bool, OptionQuery>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn processed_events_count)]
+    pub type ProcessedEventsCount<T: Config> = StorageMap<_, Twox64Concat, u32, u32, ValueQuery>;
+
+    /// Commitments to the events of the archived periods that had processed events
+    #[pallet::storage]
+    #[pallet::getter(fn archived_periods)]
+    pub type ArchivedPeriods<T: Config> =
+        StorageMap<_, Twox64Concat, u32, ArchivedPeriod, OptionQuery>;
+
+    /// Digests of the ids of the archived events, with the period they were archived in. They
+    /// outlive the processed events so that an archived event can't be added again, until they
+    /// are removed `ArchivedEventIdsRetention` periods later.
+    #[pallet::storage]
+    pub type ArchivedEventIds<T: Config> = StorageMap<_, Identity, [u8; 16], u32, OptionQuery>;
+
+    /// The digests of `ArchivedEventIds` grouped by the period they were archived in
+    #[pallet::storage]
+    pub type ArchivedEventIdsByPeriod<T: Config> =
+        StorageDoubleMap<_, Twox64Concat, u32, Identity, [u8; 16], (), OptionQuery>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn next_period_to_archive)]
+    pub type NextPeriodToArchive<T: Config> = StorageValue<_, u32, ValueQuery>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn next_archived_period_to_prune)]
+    pub type NextArchivedPeriodToPrune<T: Config> = StorageValue<_, u32, ValueQuery>;
+
+    /// Progress of the migration that adds the events processed before the archive existed to
+    /// archive periods
+    #[pallet::storage]
+    pub type ArchiveMigrationCursor<T: Config> =
+        StorageValue<_, migration::ArchiveMigrationProgress, OptionQuery>;
+
     #[pallet::storage]
-    #[pallet::getter(fn challenges)]
     pub type Challenges<T: Config> = StorageMap<
         _,
         Blake2_128Concat,
         EthEventId,
         BoundedVec<T::AccountId, MaxChallenges>,
-        ValueQuery,
+        OptionQuery,
     >;
 
+    #[pallet::storage]
+    /// The raw storage key of the last challenge checked by `cleanup`, so the next call carries on
+    /// from there
+    pub type ChallengesCleanupCursor<T: Config> =
+        StorageValue<_, BoundedVec<u8, ConstU32<128>>, OptionQuery>;
+
+    #[pallet::storage]
+    /// The raw storage key of the last offence evidence checked by `cleanup`, so the next call
+    /// carries on from there
+    pub type OffenceEvidenceCleanupCursor<T: Config> =
+        StorageValue<_, BoundedVec<u8, ConstU32<128>>, OptionQuery>;
+
     #[pallet::storage]
     #[pallet::getter(fn quorum_factor)]
     pub type QuorumFactor<T: Config> = StorageValue<_, u32, ValueQuery>;
 
+    /// Whether a challenge succeeds on the stake backing its challengers rather than their number
+    #[pallet::storage]
+    #[pallet::getter(fn stake_weighted_challenges)]
+    pub type StakeWeightedChallenges<T: Config> = StorageValue<_, bool, ValueQuery>;
+
     #[pallet::storage]
     #[pallet::getter(fn event_challenge_period)]
     pub type EventChallengePeriod<T: Config> = StorageValue<_, BlockNumberFor<T>, ValueQuery>;
@@ -359,6 +578,30 @@ pub mod pallet {
     pub type ProxyNonces<T: Config> =
         StorageMap<_, Blake2_128Concat, T::AccountId, u64, ValueQuery>;
 
+    /// Unsigned transactions of each type executed for a validator in its current window
+    #[pallet::storage]
+    #[pallet::getter(fn unsigned_submissions)]
+    pub type UnsignedSubmissions<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        Blake2_128Concat,
+        EthereumEventsUnsignedCall,
+        SubmissionWindow<BlockNumberFor<T>>,
+        ValueQuery,
+    >;
+
+    /// The evidence of each reported offence, keyed by its hash
+    #[pallet::storage]
+    #[pallet::getter(fn offence_evidence)]
+    pub type OffenceEvidence<T: Config> = StorageMap<
+        _,
+        Identity,
+        H256,
+        InvalidEthereumLogOffenceEvidence<T::AccountId, BlockNumberFor<T>>,
+        OptionQuery,
+    >;
+
     #[pallet::storage]
     pub(crate) type StorageVersion<T> = StorageValue<_, Releases, ValueQuery>;
 
@@ -394,7 +637,10 @@ pub mod pallet {
             EventChallengePeriod::<T>::put(self.event_challenge_period);
 
             for (signature, transaction_hash, value) in self.processed_events.clone().into_iter() {
-                ProcessedEvents::<T>::insert(EthEventId { signature, transaction_hash }, value);
+                Pallet::<T>::record_processed_event(
+                    &EthEventId { signature, transaction_hash },
+                    value,
+                );
             }
 
             for (key, value) in self.nft_t1_contracts.iter() {
@@ -482,6 +728,10 @@ pub mod pallet {
             // TODO [TYPE: test][PRI: medium][CRITICAL][JIRA: 348]: Test if rotating keys will break
             // this.
             ensure!(Self::is_validator(&result.checked_by), Error::<T>::InvalidKey);
+            Self::record_unsigned_submission(
+                &result.checked_by,
+                EthereumEventsUnsignedCall::SubmitCheckEventResult,
+            );
 
             let event_index = Self::unchecked_events().iter().position(|(event, counter, _)| {
                 event == &result.event.event_id && counter == &ingress_counter
@@ -497,13 +747,11 @@ pub mod pallet {
                     (AVN::<T>::active_validators().len() as u32) / Self::quorum_factor();
 
                 // Insert first and remove
-                <EventsPendingChallenge<T>>::mutate(|pending_events| {
-                    if let Err(_) =
-                        pending_events.try_push((result.clone(), ingress_counter, current_block))
-                    {
-                        log::error!("Failed to push to pending_events");
-                    }
-                });
+                <EventsPendingChallenge<T>>::try_mutate(|pending_events| {
+                    pending_events
+                        .try_push((result.clone(), ingress_counter, current_block))
+                        .map_err(|_| Error::<T>::EventsPendingChallengeOverflow)
+                })?;
 
                 <UncheckedEvents<T>>::mutate(|events| events.remove(event_index));
 
@@ -544,6 +792,10 @@ pub mod pallet {
             // TODO [TYPE: test][PRI: medium][CRITICAL][JIRA: 348]: Test if rotating keys will break
             // this.
             ensure!(Self::is_validator(&validator.account_id), Error::<T>::InvalidKey);
+            Self::record_unsigned_submission(
+                &validator.account_id,
+                EthereumEventsUnsignedCall::ProcessEvent,
+            );
 
             let event_index = Self::get_pending_event_index(&event_id)?;
             // Not using the passed in `checked` to be sure the details have not been changed
@@ -566,16 +818,17 @@ pub mod pallet {
             let event_was_declared_invalid = validated.result == CheckResult::Invalid;
             let event_can_be_resubmitted = event_was_declared_invalid && successful_challenge;
             if !event_can_be_resubmitted {
-                <ProcessedEvents<T>>::insert(event_id.clone(), true);
+                Self::record_processed_event(&event_id, true);
             }
             <EventsPendingChallenge<T>>::mutate(|pending_events| {
                 pending_events.remove(event_index)
             });
             // TODO: Remove this event's challenges from the Challenges map too.
-            Self::deposit_event(Event::<T>::EventProcessed {
+            Self::deposit_sequenced_event(|sequence| Event::<T>::EventProcessed {
                 eth_event_id: event_id.clone(),
                 processor: validator.account_id.clone(),
                 outcome: !successful_challenge,
+                sequence,
             });
             if successful_challenge {
                 Self::deposit_event(Event::<T>::ChallengeSucceeded {
@@ -588,6 +841,7 @@ pub mod pallet {
                     &validator.account_id,
                     &vec![validated.checked_by.clone()],
                     EthereumLogOffenceType::IncorrectValidationResultSubmitted,
+                    Some(event_id.clone()),
                 );
             } else {
                 // SYS-536 report the offence for the people who challenged
@@ -595,6 +849,7 @@ pub mod pallet {
                     &validator.account_id,
                     &Self::challenges(event_id.clone()),
                     EthereumLogOffenceType::ChallengeAttemptedOnValidResult,
+                    Some(event_id.clone()),
                 );
             }
 
@@ -604,9 +859,11 @@ pub mod pallet {
                     T::ProcessedEventHandler::on_event_processed(&validated.event);
 
                 if let Ok(_) = processing_outcome {
+                    Self::index_processed_event(&event_id, true);
                     Self::deposit_event(Event::<T>::EventAccepted { eth_event_id: event_id });
                 } else {
                     log::error!("💔 Processing ethereum event failed: {:?}", processing_outcome);
+                    Self::index_processed_event(&event_id, false);
                     Self::deposit_event(Event::<T>::EventRejected {
                         eth_event_id: event_id,
                         check_result: validated.result.clone(),
@@ -614,6 +871,13 @@ pub mod pallet {
                     });
                 }
             } else {
+                if event_was_declared_invalid && !successful_challenge {
+                    <InvalidEvents<T>>::insert(
+                        &event_id,
+                        <frame_system::Pallet<T>>::block_number(),
+                    );
+                }
+                Self::index_processed_event(&event_id, false);
                 Self::deposit_event(Event::<T>::EventRejected {
                     eth_event_id: event_id,
                     check_result: validated.result.clone(),
@@ -652,6 +916,10 @@ pub mod pallet {
         ) -> DispatchResult {
             ensure_none(origin)?;
             ensure!(Self::is_validator(&challenge.challenged_by), Error::<T>::InvalidKey);
+            Self::record_unsigned_submission(
+                &challenge.challenged_by,
+                EthereumEventsUnsignedCall::ChallengeEvent,
+            );
 
             let events_pending_challenge = Self::events_pending_challenge();
             let checked = events_pending_challenge
@@ -671,24 +939,26 @@ pub mod pallet {
             // TODO [TYPE: business logic][PRI: medium][CRITICAL][JIRA: 349]: Make sure the
             // challenge period has not passed. Note: the current block number can be
             // different to the block_number the offchain worker was invoked in
-            if <Challenges<T>>::contains_key(&challenge.event_id) {
-                ensure!(
-                    !Self::challenges(challenge.event_id.clone())
-                        .iter()
-                        .any(|challenger| challenger == &challenge.challenged_by),
-                    Error::<T>::DuplicateChallenge
-                );
-
-                <Challenges<T>>::mutate(challenge.event_id.clone(), |prev_challenges| {
-                    if let Err(_) = prev_challenges.try_push(challenge.challenged_by.clone()) {
-                        log::error!("Failed to push to prev_challenges");
-                    }
-                });
-            } else {
-                <Challenges<T>>::insert(
-                    challenge.event_id.clone(),
-                    BoundedVec::truncate_from(vec![challenge.challenged_by.clone()]),
-                );
+            match <Challenges<T>>::get(&challenge.event_id) {
+                Some(mut prev_challenges) => {
+                    ensure!(
+                        !prev_challenges
+                            .iter()
+                            .any(|challenger| challenger == &challenge.challenged_by),
+                        Error::<T>::DuplicateChallenge
+                    );
+
+                    prev_challenges
+                        .try_push(challenge.challenged_by.clone())
+                        .map_err(|_| Error::<T>::PrevChallengesOverflow)?;
+                    <Challenges<T>>::insert(challenge.event_id.clone(), prev_challenges);
+                },
+                None => {
+                    <Challenges<T>>::insert(
+                        challenge.event_id.clone(),
+                        BoundedVec::truncate_from(vec![challenge.challenged_by.clone()]),
+                    );
+                },
             }
 
             Self::deposit_event(Event::<T>::EventChallenged {
@@ -747,8 +1017,7 @@ pub mod pallet {
                 sender_nonce,
             );
             ensure!(
-                verify_signature::<T::Signature, T::AccountId>(&proof, &signed_payload.as_slice())
-                    .is_ok(),
+                Self::verify_signed_payload(&proof, &signed_payload).is_ok(),
                 Error::<T>::UnauthorizedSignedAddEthereumLogTransaction
             );
 
@@ -763,7 +1032,7 @@ pub mod pallet {
         #[pallet::weight(
             <T as pallet::Config>::WeightInfo::set_nft_contract_map_storage())]
         pub fn insert_nft_contract(origin: OriginFor<T>, contract_address: H160) -> DispatchResult {
-            ensure_root(origin)?;
+            T::AdminOrigin::ensure_origin(origin)?;
             ensure!(&contract_address != &H160::zero(), Error::<T>::InvalidContractAddress);
 
             <NftT1Contracts<T>>::insert(contract_address, ());
@@ -777,7 +1046,7 @@ pub mod pallet {
             origin: OriginFor<T>,
             event_challenge_period_in_blocks: BlockNumberFor<T>,
         ) -> DispatchResult {
-            ensure_root(origin)?;
+            T::AdminOrigin::ensure_origin(origin)?;
             ensure!(
                 event_challenge_period_in_blocks >= MINIMUM_EVENT_CHALLENGE_PERIOD.into(),
                 Error::<T>::InvalidEventChallengePeriod
@@ -788,10 +1057,187 @@ pub mod pallet {
             });
             Ok(())
         }
+
+        /// Removes a queued event that was already processed, using a proof that it is part of an
+        /// archived period. Anyone can submit it.
+        #[pallet::call_index(9)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::remove_replayed_event(
+            proof.siblings.len() as u32
+        ))]
+        pub fn remove_replayed_event(
+            origin: OriginFor<T>,
+            event_id: EthEventId,
+            proof: ArchivedEventProof,
+        ) -> DispatchResult {
+            ensure_signed(origin)?;
+            ensure!(
+                Self::is_archived_event(&event_id, &proof),
+                Error::<T>::InvalidArchivedEventProof
+            );
+
+            Self::remove_replayed_event_from_queues(&event_id)?;
+
+            Self::deposit_event(Event::<T>::ReplayedEventRemoved {
+                eth_event_id: event_id,
+                period: proof.period,
+            });
+            Ok(())
+        }
+
+        /// Queues an event that was declared invalid to be checked again, for instance when the
+        /// validators could not reach Ethereum when they first checked it. The event gets a new
+        /// ingress counter. This is only possible until the period the event was processed in is
+        /// archived.
+        #[pallet::call_index(10)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::revalidate_event())]
+        pub fn revalidate_event(origin: OriginFor<T>, event_id: EthEventId) -> DispatchResult {
+            T::AdminOrigin::ensure_origin(origin)?;
+            let processed_at =
+                <InvalidEvents<T>>::take(&event_id).ok_or(Error::<T>::EventCannotBeRevalidated)?;
+            ensure!(
+                <ProcessedEvents<T>>::contains_key(&event_id),
+                Error::<T>::EventCannotBeRevalidated
+            );
+
+            Self::remove_processed_event(&event_id, processed_at);
+
+            let ingress_counter = Self::get_next_ingress_counter();
+            <UncheckedEvents<T>>::try_append((
+                event_id.clone(),
+                ingress_counter,
+                <frame_system::Pallet<T>>::block_number(),
+            ))
+            .map_err(|_| Error::<T>::UncheckedEventsOverflow)?;
+
+            Self::deposit_event(Event::<T>::EventRevalidationRequested {
+                eth_event_id: event_id,
+                ingress_counter,
+            });
+            Ok(())
+        }
+
+        /// Removes an nft contract from the registry, so that its events are no longer accepted.
+        /// This is refused while nft events are waiting to be checked or challenged, because they
+        /// could come from this contract.
+        #[pallet::call_index(11)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::remove_nft_contract())]
+        pub fn remove_nft_contract(origin: OriginFor<T>, contract_address: H160) -> DispatchResult {
+            T::AdminOrigin::ensure_origin(origin)?;
+            ensure!(
+                <NftT1Contracts<T>>::contains_key(&contract_address),
+                Error::<T>::NftContractNotFound
+            );
+            ensure!(!Self::has_pending_nft_events(), Error::<T>::NftEventsPending);
+
+            <NftT1Contracts<T>>::remove(&contract_address);
+
+            Self::deposit_event(Event::<T>::NftContractRemoved { contract_address });
+            Ok(())
+        }
+
+        /// Replaces a registered nft contract, for instance when a marketplace contract is
+        /// redeployed. The same restriction as `remove_nft_contract` applies.
+        #[pallet::call_index(12)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::update_nft_contract())]
+        pub fn update_nft_contract(
+            origin: OriginFor<T>,
+            old_contract: H160,
+            new_contract: H160,
+        ) -> DispatchResult {
+            T::AdminOrigin::ensure_origin(origin)?;
+            ensure!(&new_contract != &H160::zero(), Error::<T>::InvalidContractAddress);
+            ensure!(
+                <NftT1Contracts<T>>::contains_key(&old_contract),
+                Error::<T>::NftContractNotFound
+            );
+            ensure!(
+                !<NftT1Contracts<T>>::contains_key(&new_contract),
+                Error::<T>::NftContractAlreadyRegistered
+            );
+            ensure!(!Self::has_pending_nft_events(), Error::<T>::NftEventsPending);
+
+            <NftT1Contracts<T>>::remove(&old_contract);
+            <NftT1Contracts<T>>::insert(new_contract, ());
+
+            Self::deposit_event(Event::<T>::NftContractUpdated { old_contract, new_contract });
+            Ok(())
+        }
+
+        /// Sets whether challenges are weighted by the stake backing the challengers. A weighted
+        /// challenge succeeds when its challengers are backed by more than a `QuorumFactor`th of
+        /// the stake of the active validators.
+        #[pallet::call_index(13)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::set_stake_weighted_challenges())]
+        pub fn set_stake_weighted_challenges(
+            origin: OriginFor<T>,
+            enabled: bool,
+        ) -> DispatchResult {
+            T::AdminOrigin::ensure_origin(origin)?;
+            <StakeWeightedChallenges<T>>::put(enabled);
+            Self::deposit_event(Event::<T>::StakeWeightedChallengesUpdated { enabled });
+            Ok(())
+        }
+
+        /// Removes up to `limit` entries of state that is no longer needed: the challenges of
+        /// events that are no longer pending challenge and the evidence of offences reported more
+        /// than `OffenceEvidenceRetention` blocks ago. Each call carries on from where the previous
+        /// one stopped. Anyone can call it, and it is free when it removes something.
+        #[pallet::call_index(14)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::cleanup((*limit).min(MAX_CLEANUP_LIMIT)))]
+        pub fn cleanup(origin: OriginFor<T>, limit: u32) -> DispatchResultWithPostInfo {
+            ensure_signed(origin)?;
+            let limit = limit.min(MAX_CLEANUP_LIMIT);
+
+            let (challenges, challenges_checked) = Self::remove_stale_challenges(limit);
+            let (offence_evidence, evidence_checked) =
+                Self::remove_expired_offence_evidence(limit.saturating_sub(challenges_checked));
+
+            if challenges > 0 {
+                Self::deposit_event(Event::<T>::StaleChallengesRemoved { removed: challenges });
+            }
+            if offence_evidence > 0 {
+                Self::deposit_event(Event::<T>::ExpiredOffenceEvidenceRemoved {
+                    removed: offence_evidence,
+                });
+            }
+
+            let removed = challenges.saturating_add(offence_evidence);
+            Ok(PostDispatchInfo {
+                actual_weight: Some(<T as pallet::Config>::WeightInfo::cleanup(
+                    challenges_checked.saturating_add(evidence_checked),
+                )),
+                pays_fee: if removed > 0 { Pays::No } else { Pays::Yes },
+            })
+        }
     }
 
     #[pallet::hooks]
     impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        fn on_initialize(_n: BlockNumberFor<T>) -> Weight {
+            migration::add_processed_events_to_archive_periods::<T>(
+                pallet_avn::migration::block_limit::<T>(),
+            )
+        }
+
+        fn on_idle(n: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+            let mut used = Self::archive_expired_periods(remaining_weight);
+            used = used.saturating_add(Self::prune_archived_event_ids(
+                remaining_weight.saturating_sub(used),
+            ));
+            used.saturating_add(prune_expired_windows::<UnsignedSubmissions<T>, _, _, _>(
+                n,
+                &Self::unsigned_rate_limit(),
+                T::DbWeight::get(),
+                remaining_weight.saturating_sub(used),
+            ))
+        }
+
+        fn on_runtime_upgrade() -> Weight {
+            migration::add_processed_events_to_archive_periods::<T>(
+                pallet_avn::migration::block_limit::<T>(),
+            )
+        }
+
         /// Offchain Worker entry point.
         fn offchain_worker(block_number: BlockNumberFor<T>) {
             let setup_result = AVN::<T>::pre_run_setup(block_number, PALLET_ID.to_vec());
@@ -825,6 +1271,27 @@ pub mod pallet {
                 Self::try_validate_event(block_number, &this_validator, finalised_block);
             }
         }
+
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::TryRuntimeError> {
+            Self::do_try_state()?;
+            Ok(Vec::new())
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(_state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+            ensure!(
+                StorageVersion::<T>::get() == Releases::default() ||
+                    ArchiveMigrationCursor::<T>::exists(),
+                "Ethereum events storage version does not match the pallet after the upgrade"
+            );
+            Self::do_try_state()
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn try_state(_n: BlockNumberFor<T>) -> Result<(), sp_runtime::TryRuntimeError> {
+            Self::do_try_state()
+        }
     }
 
     // Transactions sent by the validator nodes to report the result of checking an event is free
@@ -837,6 +1304,12 @@ pub mod pallet {
         // TODO [TYPE: security][PRI: high][JIRA: 152][CRITICAL]: Are we open to transaction replay
         // attacks, or signature re-use?
         fn validate_unsigned(_source: TransactionSource, call: &Self::Call) -> TransactionValidity {
+            if let Some((submitter, call_type)) = Self::unsigned_call_submitter(call) {
+                if !Self::unsigned_submission_allowed(submitter, call_type) {
+                    return InvalidTransaction::Custom(ERROR_CODE_UNSIGNED_RATE_LIMIT_REACHED).into()
+                }
+            }
+
             if let Call::submit_checkevent_result {
                 result,
                 ingress_counter,
@@ -850,6 +1323,11 @@ pub mod pallet {
                     return InvalidTransaction::Custom(ERROR_CODE_EVENT_NOT_IN_UNCHECKED).into()
                 }
 
+                if Self::events_pending_challenge_is_full() {
+                    return InvalidTransaction::Custom(ERROR_CODE_EVENTS_PENDING_CHALLENGE_FULL)
+                        .into()
+                }
+
                 if !result.event.event_data.is_valid() {
                     return InvalidTransaction::Custom(ERROR_CODE_INVALID_EVENT_DATA).into()
                 }
@@ -965,25 +1443,218 @@ pub mod pallet {
 
 // implement offchain worker sub-functions
 impl<T: Config> Pallet<T> {
+    /// The validator whose allowance an unsigned call uses. Checks and challenges are counted
+    /// against the validator that made them, which `validate_unsigned` ensures is the signer.
+    fn unsigned_call_submitter(
+        call: &Call<T>,
+    ) -> Option<(&T::AccountId, EthereumEventsUnsignedCall)> {
+        match call {
+            Call::submit_checkevent_result { result, .. } =>
+                Some((&result.checked_by, EthereumEventsUnsignedCall::SubmitCheckEventResult)),
+            Call::process_event { validator, .. } =>
+                Some((&validator.account_id, EthereumEventsUnsignedCall::ProcessEvent)),
+            Call::challenge_event { challenge, .. } =>
+                Some((&challenge.challenged_by, EthereumEventsUnsignedCall::ChallengeEvent)),
+            _ => None,
+        }
+    }
+
+    pub fn unsigned_rate_limit() -> UnsignedRateLimit<BlockNumberFor<T>> {
+        UnsignedRateLimit {
+            max_submissions: T::MaxUnsignedSubmissions::get(),
+            window: T::UnsignedSubmissionWindow::get(),
+            abuse_threshold: T::UnsignedAbuseThreshold::get(),
+        }
+    }
+
+    pub fn unsigned_submission_allowed(
+        validator: &T::AccountId,
+        call_type: EthereumEventsUnsignedCall,
+    ) -> bool {
+        Self::unsigned_submissions(validator, call_type).allows_submission(
+            <frame_system::Pallet<T>>::block_number(),
+            &Self::unsigned_rate_limit(),
+        )
+    }
+
+    fn record_unsigned_submission(validator: &T::AccountId, call_type: EthereumEventsUnsignedCall) {
+        let now = <frame_system::Pallet<T>>::block_number();
+        let limit_reached_repeatedly =
+            <UnsignedSubmissions<T>>::mutate(validator, call_type, |window| {
+                window.record_submission(now, &Self::unsigned_rate_limit())
+            });
+
+        if limit_reached_repeatedly {
+            create_and_report_invalid_log_offence::<T>(
+                validator,
+                &vec![validator.clone()],
+                EthereumLogOffenceType::UnsignedRateLimitReached,
+                None,
+            );
+        }
+    }
+
+    /// Processed events carry the global event sequence number so indexers can reference them
+    fn deposit_sequenced_event(event: impl FnOnce(u64) -> Event<T>) {
+        avn::Pallet::<T>::deposit_sequenced_event(|sequence| {
+            <T as Config>::RuntimeEvent::from(event(sequence)).into()
+        });
+    }
+
+    /// Removes, among the next `limit` entries of `Challenges` after the cleanup cursor, the
+    /// challenges of events that are no longer pending challenge. Returns the number of entries
+    /// removed and checked.
+    fn remove_stale_challenges(limit: u32) -> (u32, u32) {
+        if limit == 0 {
+            return (0, 0)
+        }
+
+        let pending_event_ids = <EventsPendingChallenge<T>>::get()
+            .into_iter()
+            .map(|(check_result, _, _)| check_result.event.event_id)
+            .collect::<Vec<EthEventId>>();
+        let mut keys = match <ChallengesCleanupCursor<T>>::get() {
+            Some(last_key) => <Challenges<T>>::iter_keys_from(last_key.into_inner()),
+            None => <Challenges<T>>::iter_keys(),
+        };
+        let challenged_event_ids = keys.by_ref().take(limit as usize).collect::<Vec<EthEventId>>();
+        if challenged_event_ids.len() < limit as usize {
+            // The sweep is complete, the next one starts over
+            <ChallengesCleanupCursor<T>>::kill();
+        } else {
+            <ChallengesCleanupCursor<T>>::put(BoundedVec::truncate_from(
+                keys.last_raw_key().to_vec(),
+            ));
+        }
+
+        let mut removed: u32 = 0;
+        for event_id in challenged_event_ids.iter() {
+            if !pending_event_ids.contains(event_id) {
+                <Challenges<T>>::remove(event_id);
+                removed.saturating_inc();
+            }
+        }
+
+        (removed, challenged_event_ids.len() as u32)
+    }
+
+    /// Removes the offence evidence, among the next `limit` entries after the cleanup cursor,
+    /// reported more than `OffenceEvidenceRetention` blocks ago. Returns the number of entries
+    /// removed and checked.
+    fn remove_expired_offence_evidence(limit: u32) -> (u32, u32) {
+        if limit == 0 {
+            return (0, 0)
+        }
+
+        let mut entries = match <OffenceEvidenceCleanupCursor<T>>::get() {
+            Some(last_key) => <OffenceEvidence<T>>::iter_from(last_key.into_inner()),
+            None => <OffenceEvidence<T>>::iter(),
+        };
+        let evidence = entries
+            .by_ref()
+            .take(limit as usize)
+            .map(|(hash, evidence)| (hash, evidence.reported_at))
+            .collect::<Vec<(H256, BlockNumberFor<T>)>>();
+        if evidence.len() < limit as usize {
+            <OffenceEvidenceCleanupCursor<T>>::kill();
+        } else {
+            <OffenceEvidenceCleanupCursor<T>>::put(BoundedVec::truncate_from(
+                entries.last_raw_key().to_vec(),
+            ));
+        }
+
+        let expired_before = <frame_system::Pallet<T>>::block_number()
+            .saturating_sub(T::OffenceEvidenceRetention::get());
+        let mut removed: u32 = 0;
+        for (hash, reported_at) in evidence.iter() {
+            if *reported_at < expired_before {
+                <OffenceEvidence<T>>::remove(hash);
+                removed.saturating_inc();
+            }
+        }
+
+        (removed, evidence.len() as u32)
+    }
+
     fn try_check_event(
         block_number: BlockNumberFor<T>,
         validator: &Validator<T::AuthorityId, T::AccountId>,
         finalised_block_number: BlockNumberFor<T>,
     ) {
-        let event_to_check = Self::get_events_to_check_if_required(finalised_block_number);
+        Self::complete_check_tasks_of_checked_events();
+        let event_to_check = match Self::get_events_to_check_if_required(finalised_block_number) {
+            Some(event_to_check) => event_to_check,
+            None => return,
+        };
 
-        if let Some(event_to_check) = event_to_check {
-            log::info!("** Checking events");
+        if let Err(e) = OcwTaskQueue::enqueue(
+            OCW_TASK_OWNER,
+            &event_to_check.0.encode(),
+            OcwTaskPriority::Normal,
+            block_number,
+        ) {
+            log::warn!("Unable to queue the check of event {:?}: {:?}", event_to_check.0, e);
+        }
 
-            // TODO [TYPE: efficiency][PRI: low]: Can we do more than 1 here?
-            let result = Self::check_event_and_submit_result(
-                block_number,
-                &event_to_check.0,
-                event_to_check.1,
-                validator,
-            );
-            if let Err(e) = result {
-                log::error!("Error checking for events: {:#?}", e);
+        let (event_id, ingress_counter) = match OcwTaskQueue::lease_next(
+            OCW_TASK_OWNER,
+            block_number,
+            CHECK_EVENT_LEASE_BLOCKS,
+        ) {
+            Ok(Some(task)) => match Self::checkable_event_of_task(&task.id, finalised_block_number)
+            {
+                Some(event) => event,
+                None => {
+                    let _ = OcwTaskQueue::complete::<BlockNumberFor<T>>(OCW_TASK_OWNER, &task.id);
+                    return
+                },
+            },
+            // The events queued are all being checked
+            Ok(None) => return,
+            Err(e) => {
+                // The queue only tracks the work of the worker, check the event without a
+                // lease when it is unavailable
+                log::warn!("Unable to lease an event to check: {:?}", e);
+                (event_to_check.0, event_to_check.1)
+            },
+        };
+
+        log::info!("** Checking events");
+
+        // TODO [TYPE: efficiency][PRI: low]: Can we do more than 1 here?
+        let result = Self::check_event_and_submit_result(
+            block_number,
+            &event_id,
+            ingress_counter,
+            validator,
+        );
+        if let Err(e) = result {
+            log::error!("Error checking for events: {:#?}", e);
+            let _ = OcwTaskQueue::release::<BlockNumberFor<T>>(OCW_TASK_OWNER, &event_id.encode());
+        }
+    }
+
+    /// The unchecked event a check task is for, if it can still be checked
+    fn checkable_event_of_task(
+        task_id: &[u8],
+        finalised_block_number: BlockNumberFor<T>,
+    ) -> Option<(EthEventId, IngressCounter)> {
+        let event_id = EthEventId::decode(&mut &task_id[..]).ok()?;
+        Self::unchecked_events()
+            .into_iter()
+            .find(|(id, _, block)| *id == event_id && *block <= finalised_block_number)
+            .map(|(id, ingress_counter, _)| (id, ingress_counter))
+    }
+
+    /// Removes the check tasks of events that are no longer waiting to be checked
+    fn complete_check_tasks_of_checked_events() {
+        let unchecked_events = Self::unchecked_events();
+        for task in OcwTaskQueue::queued_tasks::<BlockNumberFor<T>>(Some(OCW_TASK_OWNER)) {
+            let is_unchecked = EthEventId::decode(&mut task.id.as_slice())
+                .map(|event_id| unchecked_events.iter().any(|(id, _, _)| *id == event_id))
+                .unwrap_or(false);
+            if !is_unchecked {
+                let _ = OcwTaskQueue::complete::<BlockNumberFor<T>>(OCW_TASK_OWNER, &task.id);
             }
         }
     }
@@ -1028,18 +1699,55 @@ impl<T: Config> Pallet<T> {
 
 // TODO [TYPE: review][PRI: medium][CRITICAL]: Check error handling. Is this still relevant?
 impl<T: Config> Pallet<T> {
+    /// The validators who challenged the check result of an event
+    pub fn challenges(event_id: EthEventId) -> BoundedVec<T::AccountId, MaxChallenges> {
+        <Challenges<T>>::get(event_id).unwrap_or_default()
+    }
+
+    /// Whether `account` is the primary validator at `block_number` while there are events it can
+    /// check. Finalisation is not taken into account, as only the offchain worker knows it.
+    pub fn has_events_to_check(account: &T::AccountId, block_number: BlockNumberFor<T>) -> bool {
+        !Self::unchecked_events().is_empty() &&
+            !Self::events_pending_challenge_is_full() &&
+            AVN::<T>::is_primary_for_block(block_number, account).unwrap_or(false)
+    }
+
     fn is_challenge_successful(
         validated: &EthEventCheckResult<BlockNumberFor<T>, T::AccountId>,
     ) -> bool {
+        let challengers = Self::challenges(validated.event.event_id.clone());
+        let total_num_of_challenges = challengers.len() as u32;
+        if Self::stake_weighted_challenges() {
+            if let Some(successful) = Self::is_stake_weighted_challenge_successful(&challengers) {
+                // The minimum number of challenges set by the check still applies
+                return successful && total_num_of_challenges > validated.min_challenge_votes
+            }
+        }
+
         let required_challenge_votes =
             (AVN::<T>::active_validators().len() as u32) / Self::quorum_factor();
-        let total_num_of_challenges =
-            Self::challenges(validated.event.event_id.clone()).len() as u32;
 
         return total_num_of_challenges >
             cmp::max(validated.min_challenge_votes, required_challenge_votes)
     }
 
+    /// Compares the stake backing `challengers` with the stake of the active validators. Returns
+    /// `None` if the active validators have no stake, in which case the challengers are counted.
+    fn is_stake_weighted_challenge_successful(challengers: &[T::AccountId]) -> Option<bool> {
+        let total_stake = AVN::<T>::active_validators().iter().fold(0u128, |total, validator| {
+            total.saturating_add(T::ValidatorStake::backing_stake(&validator.account_id))
+        });
+        if total_stake == 0 {
+            return None
+        }
+
+        let challengers_stake = challengers.iter().fold(0u128, |total, challenger| {
+            total.saturating_add(T::ValidatorStake::backing_stake(challenger))
+        });
+
+        Some(challengers_stake > total_stake / Self::quorum_factor() as u128)
+    }
+
     fn get_pending_event_index(event_id: &EthEventId) -> Result<usize, Error<T>> {
         // `rposition: there should be at most one occurrence of this event,
         // but in case there is more, we pick the most recent one
@@ -1074,6 +1782,18 @@ impl<T: Config> Pallet<T> {
                 Error::<T>::EventParsingFailed
             })?;
             return Ok(EventData::LogLiftedToPredictionMarket(event_data))
+        } else if event_id.signature == ValidEvents::LiftedWithVesting.signature() {
+            let event_data = <LiftedWithVestingData>::parse_bytes(data, topics).map_err(|e| {
+                log::warn!("Error parsing T1 LiftedWithVesting Event: {:#?}", e);
+                Error::<T>::EventParsingFailed
+            })?;
+            return Ok(EventData::LogLiftedWithVesting(event_data))
+        } else if event_id.signature == ValidEvents::LiftedToDestination.signature() {
+            let event_data = <LiftedToDestinationData>::parse_bytes(data, topics).map_err(|e| {
+                log::warn!("Error parsing T1 LiftedToDestination Event: {:#?}", e);
+                Error::<T>::EventParsingFailed
+            })?;
+            return Ok(EventData::LogLiftedToDestination(event_data))
         } else if event_id.signature == ValidEvents::NftMint.signature() {
             let event_data = <NftMintData>::parse_bytes(data, topics).map_err(|e| {
                 log::warn!("Error parsing T1 AvnMintTo Event: {:#?}", e);
@@ -1098,6 +1818,31 @@ impl<T: Config> Pallet<T> {
                 Error::<T>::EventParsingFailed
             })?;
             return Ok(EventData::LogNftEndBatchListing(event_data))
+        } else if event_id.signature == ValidEvents::SftMint.signature() {
+            let event_data = <SftMintData>::parse_bytes(data, topics).map_err(|e| {
+                log::warn!("Error parsing T1 AvnMintSemiFungibleTo Event: {:#?}", e);
+                Error::<T>::EventParsingFailed
+            })?;
+            return Ok(EventData::LogSftMinted(event_data))
+        } else if event_id.signature == ValidEvents::SftTransferTo.signature() {
+            let event_data = <SftTransferToData>::parse_bytes(data, topics).map_err(|e| {
+                log::warn!("Error parsing T1 AvnSemiFungibleTransferTo Event: {:#?}", e);
+                Error::<T>::EventParsingFailed
+            })?;
+            return Ok(EventData::LogSftTransferTo(event_data))
+        } else if event_id.signature == ValidEvents::NftBatchListingScheduled.signature() {
+            let event_data =
+                <NftBatchListingScheduleData>::parse_bytes(data, topics).map_err(|e| {
+                    log::warn!("Error parsing T1 AvnBatchListedWithSchedule Event: {:#?}", e);
+                    Error::<T>::EventParsingFailed
+                })?;
+            return Ok(EventData::LogNftBatchListingScheduled(event_data))
+        } else if event_id.signature == ValidEvents::NftOfferAccepted.signature() {
+            let event_data = <NftOfferAcceptedData>::parse_bytes(data, topics).map_err(|e| {
+                log::warn!("Error parsing T1 AvnOfferAccepted Event: {:#?}", e);
+                Error::<T>::EventParsingFailed
+            })?;
+            return Ok(EventData::LogNftOfferAccepted(event_data))
         } else if event_id.signature == ValidEvents::AvtGrowthLifted.signature() {
             let event_data = <AvtGrowthLiftedData>::parse_bytes(data, topics).map_err(|e| {
                 log::warn!("Error parsing T1 LogGrowth Event: {:#?}", e);
@@ -1122,12 +1867,22 @@ impl<T: Config> Pallet<T> {
             return None
         }
 
+        // Checked events can't be accepted until some pending challenges are processed
+        if Self::events_pending_challenge_is_full() {
+            log::warn!("Events pending challenge are full, not checking new events");
+            return None
+        }
+
         return Self::unchecked_events()
             .into_iter()
             .filter(|e| e.2 <= finalised_block_number)
             .nth(0)
     }
 
+    fn events_pending_challenge_is_full() -> bool {
+        Self::events_pending_challenge().len() >= MaxEventsPendingChallenges::get() as usize
+    }
+
     fn get_next_event_to_validate(
         validator_account_id: &T::AccountId,
         finalised_block_number: BlockNumberFor<T>,
@@ -1268,7 +2023,19 @@ impl<T: Config> Pallet<T> {
         ingress_counter: IngressCounter,
         validator: &Validator<T::AuthorityId, T::AccountId>,
     ) -> Result<(), Error<T>> {
-        let validated = Self::check_event(block_number, &checked.event.event_id, validator);
+        let mut validated = Self::check_event(block_number, &checked.event.event_id, validator);
+        if Self::observed_in_different_blocks(&checked, &validated) {
+            // The receipt this node cached may be from a block that has since been reorganised, so
+            // check the event against Ethereum again
+            log::info!(
+                "Event {:?} was checked in Ethereum block {:?} but validated in {:?}, fetching it again",
+                checked.event.event_id,
+                checked.eth_block,
+                validated.eth_block
+            );
+            Self::remove_final_receipt(&checked.event.event_id);
+            validated = Self::check_event(block_number, &checked.event.event_id, validator);
+        }
         if validated.result == CheckResult::HttpErrorCheckingEvent {
             // TODO [TYPE: review][PRI: high][CRITICAL]: should there be a punishment for this?
             log::info!("Http error validating event, not challenging");
@@ -1319,7 +2086,15 @@ impl<T: Config> Pallet<T> {
             validated.event.event_data == checked.event.event_data) ||
             (validated.result == CheckResult::Invalid && checked.result == CheckResult::Invalid)
         {
-            log::info!("Validation matches original check, not challenging");
+            if Self::observed_in_different_blocks(&checked, &validated) {
+                log::info!(
+                    "Validation matches original check in another Ethereum block ({:?} instead of {:?}), not challenging",
+                    validated.eth_block,
+                    checked.eth_block
+                );
+            } else {
+                log::info!("Validation matches original check, not challenging");
+            }
             return None
         }
 
@@ -1372,9 +2147,9 @@ impl<T: Config> Pallet<T> {
         event_id: &EthEventId,
         validator: &Validator<T::AuthorityId, T::AccountId>,
     ) -> EthEventCheckResult<BlockNumberFor<T>, T::AccountId> {
-        // Make an external HTTP request to fetch the event.
+        // Make an external HTTP request to fetch the event, unless its receipt is already cached.
         // Note this call will block until response is received.
-        let body = Self::fetch_event(event_id);
+        let body = Self::fetch_event_receipt(event_id);
 
         // analyse the body to see if the event exists and is correctly formed
         return Self::compute_result(block_number, body, event_id, &validator.account_id)
@@ -1422,6 +2197,9 @@ impl<T: Config> Pallet<T> {
             return invalid_result
         };
 
+        let eth_block = get_block_number(&response_data_object).ok();
+        let invalid_result = invalid_result.observed_at(eth_block);
+
         // make sure the transaction has been successfully executed
         let status = get_status(&response_data_object).unwrap_or(0);
         if status != 1 {
@@ -1436,17 +2214,22 @@ impl<T: Config> Pallet<T> {
         }
         let (data, topics, contract_address) = event_object.expect("Value is not none");
 
-        if Self::is_event_contract_valid(&contract_address, event_id) == false {
-            log::error!("❌ Event contract address {:?} is not recognised", contract_address);
-            return invalid_result
-        }
-
         let parsed_event = Self::parse_tier1_event(event_id.clone(), data, topics);
         if let Err(e) = parsed_event {
             log::error!("❌ Unable to parse tier 1 event data {:?}", e);
             return invalid_result
         }
 
+        if Self::is_event_contract_valid(
+            &contract_address,
+            event_id,
+            parsed_event.as_ref().expect("Value is not an error"),
+        ) == false
+        {
+            log::error!("❌ Event contract address {:?} is not recognised", contract_address);
+            return invalid_result
+        }
+
         if num_confirmations < <T as Config>::MinEthBlockConfirmation::get() {
             log::error!(
                 "📢 There aren't enough confirmations for this event. Current confirmations: {:?}",
@@ -1461,6 +2244,7 @@ impl<T: Config> Pallet<T> {
                 block_number,
                 Default::default(),
             )
+            .observed_at(eth_block)
         }
 
         return EthEventCheckResult::new(
@@ -1472,6 +2256,62 @@ impl<T: Config> Pallet<T> {
             block_number,
             Default::default(),
         )
+        .observed_at(eth_block)
+    }
+
+    /// Fetches the transaction receipt of `event_id`. A receipt with enough confirmations can no
+    /// longer change, so it is cached and later checks of the event use it instead of querying
+    /// Ethereum again.
+    fn fetch_event_receipt(event_id: &EthEventId) -> Result<Vec<u8>, DispatchError> {
+        let storage = StorageValueRef::persistent(&Self::final_receipt_key(event_id));
+        if let Ok(Some(receipt)) = storage.get::<Vec<u8>>() {
+            log::info!("Using the cached final receipt of event {:?}", event_id);
+            return Ok(receipt)
+        }
+
+        let receipt = Self::fetch_event(event_id)?;
+        if let Some(eth_block) = Self::final_receipt_block(&receipt) {
+            log::info!(
+                "Caching the receipt of event {:?}, final at block {:?}",
+                event_id,
+                eth_block
+            );
+            storage.set(&receipt);
+        }
+
+        Ok(receipt)
+    }
+
+    /// The Ethereum block of `receipt` if it has enough confirmations to be final
+    fn final_receipt_block(receipt: &Vec<u8>) -> Option<u64> {
+        let (response_data_object, num_confirmations) =
+            parse_response_to_json(receipt.clone()).ok()?;
+        if num_confirmations < <T as Config>::MinEthBlockConfirmation::get() {
+            return None
+        }
+
+        get_block_number(&response_data_object).ok()
+    }
+
+    /// Whether both results were derived from a receipt but in different Ethereum blocks, which
+    /// means one of them has been reorganised
+    fn observed_in_different_blocks(
+        checked: &EthEventCheckResult<BlockNumberFor<T>, T::AccountId>,
+        validated: &EthEventCheckResult<BlockNumberFor<T>, T::AccountId>,
+    ) -> bool {
+        checked.eth_block.is_some() &&
+            validated.eth_block.is_some() &&
+            checked.eth_block != validated.eth_block
+    }
+
+    fn remove_final_receipt(event_id: &EthEventId) {
+        StorageValueRef::persistent(&Self::final_receipt_key(event_id)).clear();
+    }
+
+    fn final_receipt_key(event_id: &EthEventId) -> Vec<u8> {
+        let mut key = FINAL_RECEIPT_LOCAL_STORAGE_PREFIX.to_vec();
+        key.extend_from_slice(&event_id.encode());
+        key
     }
 
     fn fetch_event(event_id: &EthEventId) -> Result<Vec<u8>, DispatchError> {
@@ -1488,6 +2328,7 @@ impl<T: Config> Pallet<T> {
 
     fn event_exists_in_system(event_id: &EthEventId) -> bool {
         return <ProcessedEvents<T>>::contains_key(&event_id) ||
+            Self::was_archived(event_id) ||
             Self::unchecked_events().iter().any(|(event, _, _)| event == event_id) ||
             Self::events_pending_challenge()
                 .iter()
@@ -1530,13 +2371,36 @@ impl<T: Config> Pallet<T> {
         Ok(())
     }
 
-    fn is_event_contract_valid(contract_address: &H160, event_id: &EthEventId) -> bool {
+    /// The contract an event comes from is only known once it is checked, so any nft event
+    /// still in the queues may have been emitted by any of the registered nft contracts.
+    fn has_pending_nft_events() -> bool {
+        let is_nft_event = |event_id: &EthEventId| {
+            ValidEvents::try_from(&event_id.signature)
+                .map_or(false, |event_type| event_type.is_nft_event())
+        };
+
+        Self::unchecked_events().iter().any(|(event_id, _, _)| is_nft_event(event_id)) ||
+            Self::events_pending_challenge()
+                .iter()
+                .any(|(check_result, _, _)| is_nft_event(&check_result.event.event_id))
+    }
+
+    fn is_event_contract_valid(
+        contract_address: &H160,
+        event_id: &EthEventId,
+        event_data: &EventData,
+    ) -> bool {
         let event_type = ValidEvents::try_from(&event_id.signature);
         if let Some(event_type) = event_type {
             if event_type.is_nft_event() {
                 return <NftT1Contracts<T>>::contains_key(contract_address)
             }
 
+            // Lifts can also come from the additional bridge contracts registered for their token
+            if let Some(token) = event_data.lifted_token() {
+                return AVN::<T>::is_lift_contract_valid(contract_address, &token)
+            }
+
             let non_nft_contract_address = Some(AVN::<T>::get_bridge_contract_address());
             return non_nft_contract_address.is_some() &&
                 non_nft_contract_address.expect("checked for none") == *contract_address
@@ -1566,6 +2430,19 @@ impl<T: Config> Pallet<T> {
         return AVN::<T>::active_validators().into_iter().any(|v| v.account_id == *account_id)
     }
 
+    /// Verifies the proof of a signed call of this pallet
+    fn verify_signed_payload(
+        proof: &Proof<T::Signature, T::AccountId>,
+        signed_payload: &[u8],
+    ) -> Result<(), ()> {
+        verify_domain_separated_signature::<T::Signature, T::AccountId>(
+            proof,
+            SIGNING_DOMAIN,
+            signed_payload,
+            avn::Pallet::<T>::legacy_signed_payloads_accepted(),
+        )
+    }
+
     fn encode_signed_add_ethereum_log_params(
         proof: &Proof<T::Signature, T::AccountId>,
         event_type: &ValidEvents,
@@ -1573,6 +2450,7 @@ impl<T: Config> Pallet<T> {
         sender_nonce: u64,
     ) -> Vec<u8> {
         return (
+            SIGNING_DOMAIN,
             SIGNED_ADD_ETHEREUM_LOG_CONTEXT,
             proof.relayer.clone(),
             event_type,
@@ -1606,6 +2484,54 @@ impl<T: Config> Pallet<T> {
         }
     }
 
+    /// Returns the status of each event of the Ethereum transaction `tx_hash` that is queued or
+    /// has been processed.
+    pub fn event_statuses(tx_hash: H256) -> Vec<(EthEventId, EthEventStatus<BlockNumberFor<T>>)> {
+        let mut statuses = Vec::new();
+
+        for event_type in ValidEvents::values() {
+            let event_id =
+                EthEventId { signature: event_type.signature(), transaction_hash: tx_hash };
+            if <ProcessedEvents<T>>::contains_key(&event_id) {
+                let accepted = <ProcessedEvents<T>>::get(&event_id);
+                statuses.push((event_id, EthEventStatus::Processed { accepted }));
+            }
+        }
+
+        for (checked, _, _) in Self::events_pending_challenge() {
+            if checked.event.event_id.transaction_hash == tx_hash {
+                statuses.push((
+                    checked.event.event_id,
+                    EthEventStatus::PendingChallenge {
+                        check_result: checked.result,
+                        ready_for_processing_after_block: checked.ready_for_processing_after_block,
+                    },
+                ));
+            }
+        }
+
+        for (event_id, _, _) in Self::unchecked_events() {
+            if event_id.transaction_hash == tx_hash {
+                statuses.push((event_id, EthEventStatus::Unchecked));
+            }
+        }
+
+        statuses
+    }
+
+    /// Records the outcome of a processed event in the offchain index. This is only readable by
+    /// nodes running with offchain indexing enabled.
+    fn index_processed_event(event_id: &EthEventId, accepted: bool) {
+        let record = ProcessedEventRecord {
+            event_id: event_id.clone(),
+            block_number: <frame_system::Pallet<T>>::block_number(),
+            accepted,
+        };
+        sp_io::offchain_index::set(&processed_event_index_key(event_id), &record.encode());
+        // The receipt cached by the offchain worker is no longer needed
+        sp_io::offchain_index::clear(&Self::final_receipt_key(event_id));
+    }
+
     pub fn get_next_ingress_counter() -> IngressCounter {
         let ingress_counter = Self::ingress_counter() + 1; // default value in storage is 0, so first root_hash has counter 1
         TotalIngresses::<T>::put(ingress_counter);
@@ -1616,11 +2542,23 @@ impl<T: Config> Pallet<T> {
 impl<T: Config> ProcessedEventsChecker for Pallet<T> {
     fn processed_event_exists(event_id: &EthEventId) -> bool {
         return <ProcessedEvents<T>>::contains_key(event_id) ||
+            Self::was_archived(event_id) ||
             Self::get_pending_event_index(event_id).is_ok()
     }
 
     fn add_processed_event(event_id: &EthEventId, accepted: bool) {
-        <ProcessedEvents<T>>::insert(event_id.clone(), accepted);
+        Self::record_processed_event(event_id, accepted);
+        Self::index_processed_event(event_id, accepted);
+    }
+
+    fn archived_event_exists(event_id: &EthEventId, proof: &ArchivedEventProof) -> bool {
+        Self::is_archived_event(event_id, proof)
+    }
+}
+
+impl<T: Config> NftContractChecker for Pallet<T> {
+    fn is_nft_contract(contract: &H160) -> bool {
+        <NftT1Contracts<T>>::contains_key(contract)
     }
 }
 
@@ -1629,11 +2567,7 @@ impl<T: Config> InnerCallValidator for Pallet<T> {
 
     fn signature_is_valid(call: &Box<Self::Call>) -> bool {
         if let Some((proof, signed_payload)) = Self::get_encoded_call_param(call) {
-            return verify_signature::<T::Signature, T::AccountId>(
-                &proof,
-                &signed_payload.as_slice(),
-            )
-            .is_ok()
+            return Self::verify_signed_payload(&proof, &signed_payload).is_ok()
         }
 
         return false
@@ -1649,11 +2583,14 @@ enum Releases {
     V2_0_0,
     V3_0_0,
     V4_0_0,
+    V5_0_0,
+    V6_0_0,
+    V7_0_0,
 }
 
 //Todo: Change this once merged
 impl Default for Releases {
     fn default() -> Self {
-        Releases::V4_0_0
+        Releases::V7_0_0
     }
 }
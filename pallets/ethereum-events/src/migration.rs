@@ -0,0 +1,259 @@
+use codec::{Decode, Encode, MaxEncodedLen};
+use frame_support::{
+    pallet_prelude::PhantomData,
+    traits::{Get, OnRuntimeUpgrade},
+    weights::Weight,
+};
+use frame_system::pallet_prelude::BlockNumberFor;
+use scale_info::TypeInfo;
+use sp_avn_common::event_types::EthEventId;
+use sp_runtime::{traits::Saturating, RuntimeDebug};
+
+use crate::{
+    ArchiveMigrationCursor, Config, EventChallengePeriod, EventsPendingChallenge,
+    NextPeriodToArchive, Pallet, ProcessedEvents, ProcessedEventsByPeriod, ProcessedEventsCount,
+    Releases, StorageVersion,
+};
+
+#[cfg(feature = "try-runtime")]
+use sp_runtime::TryRuntimeError;
+use sp_std::vec::Vec;
+
+/// Scales the event challenge period by `Factor`, to keep its duration when the block time is
+/// divided by `Factor`
+pub struct ScaleEventChallengePeriod<T, Factor>(PhantomData<(T, Factor)>);
+impl<T: Config, Factor: Get<u32>> OnRuntimeUpgrade for ScaleEventChallengePeriod<T, Factor> {
+    fn on_runtime_upgrade() -> Weight {
+        let onchain = StorageVersion::<T>::get();
+
+        log::info!(
+            "ℹ️  Ethereum events `ScaleEventChallengePeriod` invoked with onchain storage version {:?}",
+            onchain
+        );
+
+        if onchain == Releases::V4_0_0 {
+            return scale_event_challenge_period::<T>(Factor::get())
+        }
+
+        T::DbWeight::get().reads(1)
+    }
+
+    #[cfg(feature = "try-runtime")]
+    fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+        Ok((StorageVersion::<T>::get(), EventChallengePeriod::<T>::get()).encode())
+    }
+
+    #[cfg(feature = "try-runtime")]
+    fn post_upgrade(state: Vec<u8>) -> Result<(), TryRuntimeError> {
+        let (onchain, challenge_period): (Releases, BlockNumberFor<T>) =
+            Decode::decode(&mut &state[..]).map_err(|_| "Invalid pre upgrade state")?;
+
+        if onchain == Releases::V4_0_0 {
+            frame_support::ensure!(
+                EventChallengePeriod::<T>::get() ==
+                    challenge_period.saturating_mul(Factor::get().into()),
+                "Event challenge period not scaled"
+            );
+        }
+
+        Ok(())
+    }
+}
+
+pub fn scale_event_challenge_period<T: Config>(scaling_factor: u32) -> Weight {
+    let factor: BlockNumberFor<T> = scaling_factor.into();
+    EventChallengePeriod::<T>::mutate(|period| *period = period.saturating_mul(factor));
+    StorageVersion::<T>::put(Releases::V5_0_0);
+
+    log::info!(
+        "✅ Event challenge period scaled by {:?}, it is now {:?} blocks",
+        scaling_factor,
+        EventChallengePeriod::<T>::get()
+    );
+
+    T::DbWeight::get().reads_writes(2, 2)
+}
+
+mod v5 {
+    use codec::{Decode, Encode};
+    use sp_avn_common::event_types::{CheckResult, EthEvent};
+    use sp_runtime::traits::Member;
+
+    #[derive(Encode, Decode)]
+    pub struct EthEventCheckResult<BlockNumber: Member, AccountId: Member> {
+        pub event: EthEvent,
+        pub result: CheckResult,
+        pub checked_by: AccountId,
+        pub checked_at_block: BlockNumber,
+        pub ready_for_processing_after_block: BlockNumber,
+        pub min_challenge_votes: u32,
+    }
+}
+
+/// Adds the Ethereum block of the receipt to the results of the events pending challenge. The block
+/// of the results checked before the upgrade is unknown.
+pub struct AddEthBlockToCheckResults<T>(PhantomData<T>);
+impl<T: Config> OnRuntimeUpgrade for AddEthBlockToCheckResults<T> {
+    fn on_runtime_upgrade() -> Weight {
+        let onchain = StorageVersion::<T>::get();
+
+        log::info!(
+            "ℹ️  Ethereum events `AddEthBlockToCheckResults` invoked with onchain storage version {:?}",
+            onchain
+        );
+
+        if onchain == Releases::V5_0_0 {
+            return add_eth_block_to_check_results::<T>()
+        }
+
+        T::DbWeight::get().reads(1)
+    }
+
+    #[cfg(feature = "try-runtime")]
+    fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+        let pending_events = EventsPendingChallenge::<T>::decode_len().unwrap_or_default() as u32;
+        Ok((StorageVersion::<T>::get(), pending_events).encode())
+    }
+
+    #[cfg(feature = "try-runtime")]
+    fn post_upgrade(state: Vec<u8>) -> Result<(), TryRuntimeError> {
+        let (onchain, pending_events): (Releases, u32) =
+            Decode::decode(&mut &state[..]).map_err(|_| "Invalid pre upgrade state")?;
+
+        if onchain == Releases::V5_0_0 {
+            frame_support::ensure!(
+                EventsPendingChallenge::<T>::get().len() as u32 == pending_events,
+                "Some events pending challenge were not migrated"
+            );
+        }
+
+        Ok(())
+    }
+}
+
+pub fn add_eth_block_to_check_results<T: Config>() -> Weight {
+    let translated = EventsPendingChallenge::<T>::translate::<
+        Vec<(
+            v5::EthEventCheckResult<BlockNumberFor<T>, T::AccountId>,
+            sp_avn_common::IngressCounter,
+            BlockNumberFor<T>,
+        )>,
+        _,
+    >(|pending_events| {
+        pending_events.map(|pending_events| {
+            frame_support::BoundedVec::truncate_from(
+                pending_events
+                    .into_iter()
+                    .map(|(checked, ingress_counter, submitted_at_block)| {
+                        let check_result = sp_avn_common::event_types::EthEventCheckResult::new(
+                            checked.ready_for_processing_after_block,
+                            checked.result,
+                            &checked.event.event_id,
+                            &checked.event.event_data,
+                            checked.checked_by,
+                            checked.checked_at_block,
+                            checked.min_challenge_votes,
+                        );
+                        (check_result, ingress_counter, submitted_at_block)
+                    })
+                    .collect(),
+            )
+        })
+    });
+
+    if translated.is_err() {
+        log::error!(
+            "💔 Unable to decode the events pending challenge, the storage version is not updated"
+        );
+        return T::DbWeight::get().reads(2)
+    }
+    StorageVersion::<T>::put(Releases::V6_0_0);
+
+    log::info!(
+        "✅ Added the Ethereum block to {:?} events pending challenge",
+        EventsPendingChallenge::<T>::decode_len().unwrap_or_default()
+    );
+
+    T::DbWeight::get().reads_writes(2, 2)
+}
+
+/// Number of the events processed before the archive existed that are added to each archive period
+pub const MIGRATED_EVENTS_PER_PERIOD: u32 = 1_000;
+
+/// Position of the migration that adds the events processed before the archive existed to archive
+/// periods
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct ArchiveMigrationProgress {
+    /// The last processed event the migration went through
+    pub last_event: EthEventId,
+    /// The archive period the next events are added to
+    pub period: u32,
+    /// The archive period the migration started in. The events processed from then on are
+    /// already in a period.
+    pub started_in: u32,
+}
+
+/// Adds the events processed before the archive existed to archive periods, so they are archived
+/// like the others. The events are spread over the periods from `NextPeriodToArchive`, at most
+/// `MIGRATED_EVENTS_PER_PERIOD` in each, so a period can still be archived in a block. It runs in
+/// steps of at most `limit` from `on_initialize` until all the processed events are in a period,
+/// and no period is archived until then.
+pub fn add_processed_events_to_archive_periods<T: Config>(limit: Weight) -> Weight {
+    let db_weight = T::DbWeight::get();
+    let mut used = db_weight.reads(1);
+    if StorageVersion::<T>::get() != Releases::V6_0_0 || used.any_gt(limit) {
+        return used
+    }
+
+    used = used.saturating_add(db_weight.reads_writes(3, 2));
+    let current_period = Pallet::<T>::current_archive_period();
+    let (mut events, mut period, started_in) = match ArchiveMigrationCursor::<T>::get() {
+        Some(progress) => (
+            ProcessedEvents::<T>::iter_from(ProcessedEvents::<T>::hashed_key_for(
+                &progress.last_event,
+            )),
+            progress.period,
+            progress.started_in,
+        ),
+        None => (ProcessedEvents::<T>::iter(), NextPeriodToArchive::<T>::get(), current_period),
+    };
+
+    let periods_since_start = current_period.saturating_sub(started_in) as u64 + 1;
+    let weight_per_event = db_weight.reads_writes(periods_since_start.saturating_add(2), 2);
+    let mut last_event = None;
+    loop {
+        if used.saturating_add(weight_per_event).any_gt(limit) {
+            if let Some(last_event) = last_event {
+                ArchiveMigrationCursor::<T>::put(ArchiveMigrationProgress {
+                    last_event,
+                    period,
+                    started_in,
+                });
+            }
+            return used
+        }
+
+        let (event_id, accepted) = match events.next() {
+            Some(event) => event,
+            None => break,
+        };
+        used = used.saturating_add(weight_per_event);
+
+        let processed_since_start = (started_in..=current_period)
+            .any(|period| ProcessedEventsByPeriod::<T>::contains_key(period, &event_id));
+        if !processed_since_start {
+            if ProcessedEventsCount::<T>::get(period) >= MIGRATED_EVENTS_PER_PERIOD {
+                period = period.saturating_add(1);
+            }
+            ProcessedEventsByPeriod::<T>::insert(period, &event_id, accepted);
+            ProcessedEventsCount::<T>::mutate(period, |count| *count = count.saturating_add(1));
+        }
+        last_event = Some(event_id);
+    }
+
+    ArchiveMigrationCursor::<T>::kill();
+    StorageVersion::<T>::put(Releases::V7_0_0);
+    log::info!("✅ Processed events added to the archive periods up to period {:?}", period);
+
+    used
+}
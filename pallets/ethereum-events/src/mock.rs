@@ -10,7 +10,10 @@ use sp_runtime::{
     BoundedBTreeSet, BuildStorage, Perbill, WeakBoundedVec,
 };
 use sp_state_machine::BasicExternalities;
-use std::{cell::RefCell, collections::BTreeSet};
+use std::{
+    cell::RefCell,
+    collections::{BTreeMap, BTreeSet},
+};
 
 use frame_system as system;
 use hex_literal::hex;
@@ -29,14 +32,14 @@ use sp_staking::{
 };
 
 use avn::AvnBridgeContractAddress;
-use pallet_avn::{self as avn, Error as avn_error};
+use pallet_avn::{self as avn, Error as avn_error, ValidatorStakeProvider};
 use sp_avn_common::{
     bounds::MaximumValidatorsBound, event_types::EthEvent, EthQueryRequest, EthQueryResponseType,
     FeePaymentHandler,
 };
 use sp_io::TestExternalities;
 
-use crate::{self as pallet_ethereum_events, *};
+use crate::{self as pallet_ethereum_events, offence::InvalidEthereumLogTimeSlot, *};
 
 #[allow(dead_code)]
 pub type Signature = sr25519::Signature;
@@ -52,7 +55,7 @@ frame_support::construct_runtime!(
         System: frame_system::{Pallet, Call, Config<T>, Storage, Event<T>},
         Session: pallet_session::{Pallet, Call, Storage, Event, Config<T>},
         Balances: pallet_balances::{Pallet, Call, Storage, Config<T>, Event<T>},
-        AVN: pallet_avn::{Pallet, Storage, Event, Config<T>},
+        AVN: pallet_avn::{Pallet, Storage, Event<T>, Config<T>},
         AvnProxy: pallet_avn_proxy::{Pallet, Call, Storage, Event<T>},
         EthereumEvents: pallet_ethereum_events::{Pallet, Call, Storage, Event<T>, Config<T>},
         Historical: pallet_session::historical::{Pallet, Storage},
@@ -137,6 +140,15 @@ impl Config for TestRuntime {
     type Signature = Signature;
     type WeightInfo = ();
     type EthereumEventsFilter = MyEthereumEventsFilter;
+    type AdminOrigin = frame_system::EnsureRoot<Self::AccountId>;
+    type ArchivePeriod = ArchivePeriod;
+    type ProcessedEventsRetention = ProcessedEventsRetention;
+    type ArchivedEventIdsRetention = ArchivedEventIdsRetention;
+    type OffenceEvidenceRetention = OffenceEvidenceRetention;
+    type ValidatorStake = TestValidatorStake;
+    type UnsignedSubmissionWindow = UnsignedSubmissionWindow;
+    type MaxUnsignedSubmissions = MaxUnsignedSubmissions;
+    type UnsignedAbuseThreshold = UnsignedAbuseThreshold;
 }
 
 impl<LocalCall> frame_system::offchain::SendTransactionTypes<LocalCall> for TestRuntime
@@ -153,6 +165,13 @@ parameter_types! {
     pub const MaximumBlockLength: u32 = 2 * 1024;
     pub const AvailableBlockRatio: Perbill = Perbill::from_percent(75);
     pub const MinEthBlockConfirmation: u64 = 2;
+    pub const ArchivePeriod: u64 = 10;
+    pub const ProcessedEventsRetention: u32 = 2;
+    pub const ArchivedEventIdsRetention: u32 = 2;
+    pub const OffenceEvidenceRetention: u64 = 1000;
+    pub const UnsignedSubmissionWindow: u64 = 10;
+    pub const MaxUnsignedSubmissions: u32 = 20;
+    pub const UnsignedAbuseThreshold: u32 = 3;
 }
 
 impl system::Config for TestRuntime {
@@ -196,6 +215,20 @@ thread_local! {
     ]));
 
     pub static PROCESS_EVENT_SUCCESS: RefCell<bool> = RefCell::new(true);
+
+    pub static BACKING_STAKES: RefCell<BTreeMap<AccountId, u128>> = RefCell::new(BTreeMap::new());
+}
+
+pub struct TestValidatorStake;
+
+impl ValidatorStakeProvider<AccountId> for TestValidatorStake {
+    fn backing_stake(validator: &AccountId) -> u128 {
+        BACKING_STAKES.with(|stakes| stakes.borrow().get(validator).copied().unwrap_or_default())
+    }
+}
+
+pub fn set_backing_stake(validator: AccountId, stake: u128) {
+    BACKING_STAKES.with(|stakes| stakes.borrow_mut().insert(validator, stake));
 }
 
 impl avn::Config for TestRuntime {
@@ -291,7 +324,10 @@ impl ReportOffence<AccountId, IdentificationTuple, Offence> for OffenceHandler {
         Ok(())
     }
 
-    fn is_known_offence(_offenders: &[IdentificationTuple], _time_slot: &SessionIndex) -> bool {
+    fn is_known_offence(
+        _offenders: &[IdentificationTuple],
+        _time_slot: &InvalidEthereumLogTimeSlot,
+    ) -> bool {
         false
     }
 }
@@ -443,6 +479,12 @@ impl EthereumEvents {
     pub fn event_emitted(event: &RuntimeEvent) -> bool {
         return System::events().iter().any(|a| a.event == *event)
     }
+
+    pub fn offence_evidence_of_type(offence_type: EthereumLogOffenceType) -> Option<H256> {
+        return OffenceEvidence::<TestRuntime>::iter()
+            .find(|(_, evidence)| evidence.offence_type == offence_type)
+            .map(|(hash, _)| hash)
+    }
 }
 
 impl pallet_avn_proxy::Config for TestRuntime {
@@ -455,6 +497,10 @@ impl pallet_avn_proxy::Config for TestRuntime {
     type WeightInfo = ();
     type FeeHandler = Self;
     type Token = sp_core::H160;
+    type EthereumAddressMapping = ();
+    type EthereumCallFilter = frame_support::traits::Nothing;
+    type AdminOrigin = frame_system::EnsureRoot<AccountId>;
+    type MaxSessionKeyDuration = frame_support::traits::ConstU64<100>;
 }
 
 // Test Avn proxy configuration logic
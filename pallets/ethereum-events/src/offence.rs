@@ -6,19 +6,50 @@ use sp_staking::{
     SessionIndex,
 };
 
-use codec::{Decode, Encode};
+use codec::{Decode, Encode, MaxEncodedLen};
 use pallet_session::{historical::IdentificationTuple, Config as SessionConfig};
 use sp_runtime::{scale_info::TypeInfo, traits::Convert};
 use sp_staking::offence::ReportOffence;
 use sp_std::prelude::*;
 
-#[derive(PartialEq, Eq, Clone, Debug, Encode, Decode, TypeInfo)]
+#[derive(PartialEq, Eq, PartialOrd, Ord, Clone, Debug, Encode, Decode, MaxEncodedLen, TypeInfo)]
 pub enum EthereumLogOffenceType {
     IncorrectValidationResultSubmitted,
     ChallengeAttemptedOnValidResult,
+    /// Submitted as many unsigned transactions of a type as allowed in several consecutive windows
+    UnsignedRateLimitReached,
 }
 use crate::*;
 
+/// The facts of a reported offence, kept so that governance can review disputes about it without
+/// relying on indexed logs
+#[derive(PartialEq, Eq, Clone, Debug, Encode, Decode, MaxEncodedLen, TypeInfo)]
+pub struct InvalidEthereumLogOffenceEvidence<AccountId, BlockNumber> {
+    pub offence_type: EthereumLogOffenceType,
+    /// The event whose check or challenge was wrong. None for offences not about an event.
+    pub event_id: Option<EthEventId>,
+    /// Challenges the event had received when the offence was reported
+    pub challenges: u32,
+    pub reporter: AccountId,
+    pub reported_at: BlockNumber,
+}
+
+impl<AccountId: Encode, BlockNumber: Encode>
+    InvalidEthereumLogOffenceEvidence<AccountId, BlockNumber>
+{
+    /// The key of the evidence in storage, referenced by the offence event
+    pub fn hash(&self) -> H256 {
+        H256::from(sp_io::hashing::blake2_256(&self.encode()))
+    }
+}
+
+/// When an invalid log offence happened. Each type of offence is reported once per session.
+#[derive(PartialEq, Eq, PartialOrd, Ord, Clone, Debug, Encode, Decode, TypeInfo)]
+pub struct InvalidEthereumLogTimeSlot {
+    pub session_index: SessionIndex,
+    pub offence_type: EthereumLogOffenceType,
+}
+
 #[derive(PartialEq, Clone, Debug, Encode, Decode)]
 pub struct InvalidEthereumLogOffence<Offender> {
     /// The current session index in which we report the validators that submitted an invalid
@@ -34,7 +65,7 @@ pub struct InvalidEthereumLogOffence<Offender> {
 
 impl<Offender: Clone> Offence<Offender> for InvalidEthereumLogOffence<Offender> {
     const ID: Kind = *b"ethevent:bad-log";
-    type TimeSlot = SessionIndex;
+    type TimeSlot = InvalidEthereumLogTimeSlot;
 
     fn offenders(&self) -> Vec<Offender> {
         self.offenders.clone()
@@ -49,7 +80,10 @@ impl<Offender: Clone> Offence<Offender> for InvalidEthereumLogOffence<Offender>
     }
 
     fn time_slot(&self) -> Self::TimeSlot {
-        self.session_index
+        InvalidEthereumLogTimeSlot {
+            session_index: self.session_index,
+            offence_type: self.offence_type.clone(),
+        }
     }
 
     fn slash_fraction(&self, _offenders_count: u32) -> Perbill {
@@ -73,6 +107,7 @@ pub fn create_and_report_invalid_log_offence<T: crate::Config>(
     reporter: &T::AccountId,
     offenders_accounts: &Vec<T::AccountId>,
     offence_type: EthereumLogOffenceType,
+    event_id: Option<EthEventId>,
 ) {
     let offenders = create_offenders_identification::<T>(offenders_accounts);
 
@@ -84,24 +119,36 @@ pub fn create_and_report_invalid_log_offence<T: crate::Config>(
             offence_type: offence_type.clone(),
         };
 
-        if !T::ReportInvalidEthereumLog::is_known_offence(
-            &invalid_event_offence.offenders(),
-            &invalid_event_offence.time_slot(),
+        // Offences already reported in the same time slot are rejected as duplicates
+        match T::ReportInvalidEthereumLog::report_offence(
+            vec![reporter.clone()],
+            invalid_event_offence,
         ) {
-            let reporters = vec![reporter.clone()];
-            if let Err(e) =
-                T::ReportInvalidEthereumLog::report_offence(reporters, invalid_event_offence)
-            {
-                log::info!(
-                    target: "pallet-ethereum-events",
-                    "ℹ️ Error while reporting offence: {:?}. Stored in deferred",
-                    e
-                );
-            }
-            <crate::Pallet<T>>::deposit_event(Event::<T>::OffenceReported {
+            Ok(()) => {
+                let evidence = InvalidEthereumLogOffenceEvidence {
+                    offence_type: offence_type.clone(),
+                    challenges: event_id
+                        .as_ref()
+                        .map_or(0, |id| <crate::Pallet<T>>::challenges(id.clone()).len() as u32),
+                    event_id,
+                    reporter: reporter.clone(),
+                    reported_at: <frame_system::Pallet<T>>::block_number(),
+                };
+                let evidence_hash = evidence.hash();
+                <OffenceEvidence<T>>::insert(evidence_hash, evidence);
+
+                <crate::Pallet<T>>::deposit_event(Event::<T>::OffenceReported {
+                    offence_type,
+                    offenders,
+                    evidence: evidence_hash,
+                })
+            },
+            Err(e) => log::info!(
+                target: "pallet-ethereum-events",
+                "ℹ️ Offence {:?} not reported: {:?}",
                 offence_type,
-                offenders,
-            });
+                e
+            ),
         }
     }
 }
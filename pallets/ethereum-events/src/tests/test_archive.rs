@@ -0,0 +1,284 @@
+// Copyright 2024 Aventus Network Services (UK) Ltd.
+#![cfg(test)]
+
+use crate::{mock::*, *};
+use frame_support::{assert_noop, assert_ok, weights::Weight};
+use frame_system::RawOrigin;
+use sp_avn_common::{
+    event_types::{archived_events_index_key, processed_event_leaf},
+    merkle::merkle_proof,
+};
+
+const FIRST_PERIOD_BLOCK: u64 = 1;
+// The first period is archived once `ProcessedEventsRetention` more periods have passed
+const FIRST_PERIOD_EXPIRY_BLOCK: u64 = 30;
+
+fn process_events(event_ids: &Vec<EthEventId>) {
+    event_ids
+        .iter()
+        .for_each(|event_id| EthereumEvents::add_processed_event(event_id, true));
+}
+
+fn archive_at(block_number: u64) -> Weight {
+    System::set_block_number(block_number);
+    EthereumEvents::archive_expired_periods(Weight::MAX)
+}
+
+fn indexed_archived_events(
+    ext: &mut sp_io::TestExternalities,
+    period: u32,
+) -> Vec<(EthEventId, bool)> {
+    ext.persist_offchain_overlay();
+    ext.offchain_db()
+        .get(&archived_events_index_key(period))
+        .map(|events| Vec::<(EthEventId, bool)>::decode(&mut &events[..]).expect("Valid events"))
+        .expect("Period is indexed")
+}
+
+fn proof_of(
+    events: &Vec<(EthEventId, bool)>,
+    event_id: &EthEventId,
+    period: u32,
+) -> ArchivedEventProof {
+    let leaves: Vec<H256> = events
+        .iter()
+        .map(|(event_id, accepted)| processed_event_leaf(event_id, *accepted))
+        .collect();
+    let leaf_index = events
+        .iter()
+        .position(|(event, _)| event == event_id)
+        .expect("Event is archived") as u32;
+    ArchivedEventProof {
+        period,
+        accepted: true,
+        leaf_index,
+        siblings: merkle_proof(&leaves, leaf_index).expect("Leaf exists"),
+    }
+}
+
+fn prune_at(block_number: u64) -> Weight {
+    archive_at(block_number);
+    EthereumEvents::prune_archived_event_ids(Weight::MAX)
+}
+
+fn event_ids() -> Vec<EthEventId> {
+    (1..=3).map(EthereumEvents::get_event_id).collect()
+}
+
+mod archiving_processed_events {
+    use super::*;
+
+    #[test]
+    fn keeps_them_during_the_retention_period() {
+        let mut ext = ExtBuilder::build_default().as_externality();
+        ext.execute_with(|| {
+            process_events(&event_ids());
+
+            archive_at(FIRST_PERIOD_EXPIRY_BLOCK - 1);
+
+            assert!(event_ids().iter().all(|e| EthereumEvents::processed_event_exists(e)));
+            assert_eq!(EthereumEvents::archived_periods(0), None);
+            assert_eq!(EthereumEvents::next_period_to_archive(), 0);
+        });
+    }
+
+    #[test]
+    fn replaces_them_with_a_commitment_after_the_retention_period() {
+        let mut ext = ExtBuilder::build_default().as_externality();
+        ext.execute_with(|| {
+            process_events(&event_ids());
+
+            archive_at(FIRST_PERIOD_EXPIRY_BLOCK);
+
+            assert!(event_ids().iter().all(|e| !<ProcessedEvents<TestRuntime>>::contains_key(e)));
+            assert_eq!(<ProcessedEventsByPeriod<TestRuntime>>::iter_prefix(0).count(), 0);
+            assert_eq!(EthereumEvents::processed_events_count(0), 0);
+            assert_eq!(EthereumEvents::next_period_to_archive(), 1);
+
+            let archived = EthereumEvents::archived_periods(0).expect("Period is archived");
+            assert_eq!(archived.number_of_events, 3);
+            assert!(EthereumEvents::event_emitted(&mock::RuntimeEvent::EthereumEvents(
+                crate::Event::<TestRuntime>::ProcessedEventsArchived {
+                    period: 0,
+                    root: archived.root,
+                    number_of_events: 3,
+                }
+            )));
+        });
+    }
+
+    #[test]
+    fn keeps_the_events_of_later_periods() {
+        let mut ext = ExtBuilder::build_default().as_externality();
+        ext.execute_with(|| {
+            let later_event = EthereumEvents::get_event_id(10);
+            System::set_block_number(FIRST_PERIOD_BLOCK + 10);
+            process_events(&vec![later_event.clone()]);
+
+            archive_at(FIRST_PERIOD_EXPIRY_BLOCK);
+
+            assert!(EthereumEvents::processed_event_exists(&later_event));
+            assert_eq!(EthereumEvents::archived_periods(0), None);
+            assert_eq!(EthereumEvents::next_period_to_archive(), 1);
+        });
+    }
+
+    #[test]
+    fn indexes_the_leaves_of_the_period() {
+        let mut ext = ExtBuilder::build_default().as_externality();
+        let root = ext.execute_with(|| {
+            process_events(&event_ids());
+            archive_at(FIRST_PERIOD_EXPIRY_BLOCK);
+            EthereumEvents::archived_periods(0).expect("Period is archived").root
+        });
+
+        let events = indexed_archived_events(&mut ext, 0);
+        let leaves: Vec<H256> = events
+            .iter()
+            .map(|(event_id, accepted)| processed_event_leaf(event_id, *accepted))
+            .collect();
+        assert_eq!(events.len(), 3);
+        assert_eq!(sp_avn_common::merkle::merkle_root(&leaves), root);
+    }
+}
+
+mod archived_events {
+    use super::*;
+
+    #[test]
+    fn can_be_proven() {
+        let mut ext = ExtBuilder::build_default().as_externality();
+        ext.execute_with(|| {
+            process_events(&event_ids());
+            archive_at(FIRST_PERIOD_EXPIRY_BLOCK);
+        });
+
+        let events = indexed_archived_events(&mut ext, 0);
+        ext.execute_with(|| {
+            for event_id in event_ids() {
+                let proof = proof_of(&events, &event_id, 0);
+                assert!(EthereumEvents::archived_event_exists(&event_id, &proof));
+            }
+
+            let unknown_event = EthereumEvents::get_event_id(20);
+            let proof = proof_of(&events, &event_ids()[0], 0);
+            assert!(!EthereumEvents::archived_event_exists(&unknown_event, &proof));
+        });
+    }
+
+    #[test]
+    fn can_be_removed_from_the_queue_when_replayed() {
+        let mut ext = ExtBuilder::build_default().as_externality();
+        ext.execute_with(|| {
+            process_events(&event_ids());
+            archive_at(FIRST_PERIOD_EXPIRY_BLOCK);
+        });
+
+        let events = indexed_archived_events(&mut ext, 0);
+        ext.execute_with(|| {
+            let replayed_event = event_ids()[1].clone();
+            EthereumEvents::insert_to_unchecked_events(&replayed_event, 1);
+
+            assert_ok!(EthereumEvents::remove_replayed_event(
+                RawOrigin::Signed(account_id_0()).into(),
+                replayed_event.clone(),
+                proof_of(&events, &replayed_event, 0)
+            ));
+
+            assert!(EthereumEvents::unchecked_events().is_empty());
+            assert!(EthereumEvents::event_emitted(&mock::RuntimeEvent::EthereumEvents(
+                crate::Event::<TestRuntime>::ReplayedEventRemoved {
+                    eth_event_id: replayed_event,
+                    period: 0,
+                }
+            )));
+        });
+    }
+
+    #[test]
+    fn are_still_known_as_processed() {
+        let mut ext = ExtBuilder::build_default().as_externality();
+        ext.execute_with(|| {
+            process_events(&event_ids());
+            archive_at(FIRST_PERIOD_EXPIRY_BLOCK);
+
+            assert!(event_ids().iter().all(|e| EthereumEvents::processed_event_exists(e)));
+            assert!(!EthereumEvents::processed_event_exists(&EthereumEvents::get_event_id(20)));
+        });
+    }
+
+    #[test]
+    fn are_known_as_processed_during_the_retention_period() {
+        let mut ext = ExtBuilder::build_default().as_externality();
+        ext.execute_with(|| {
+            process_events(&event_ids());
+
+            prune_at(FIRST_PERIOD_EXPIRY_BLOCK + 10);
+
+            assert!(event_ids().iter().all(|e| EthereumEvents::processed_event_exists(e)));
+            assert_eq!(EthereumEvents::next_archived_period_to_prune(), 0);
+        });
+    }
+
+    #[test]
+    fn are_forgotten_after_the_retention_period() {
+        let mut ext = ExtBuilder::build_default().as_externality();
+        ext.execute_with(|| {
+            process_events(&event_ids());
+
+            prune_at(FIRST_PERIOD_EXPIRY_BLOCK + 20);
+
+            assert!(event_ids().iter().all(|e| !EthereumEvents::processed_event_exists(e)));
+            assert_eq!(<ArchivedEventIds<TestRuntime>>::iter().count(), 0);
+            assert_eq!(<ArchivedEventIdsByPeriod<TestRuntime>>::iter_prefix(0).count(), 0);
+            assert_eq!(EthereumEvents::next_archived_period_to_prune(), 1);
+            assert!(EthereumEvents::archived_periods(0).is_some());
+        });
+    }
+
+    #[test]
+    fn can_not_be_added_again() {
+        let mut ext = ExtBuilder::build_default().with_genesis_config().as_externality();
+        ext.execute_with(|| {
+            let archived_event = EthEventId {
+                signature: ValidEvents::NftMint.signature(),
+                transaction_hash: H256::from([5u8; 32]),
+            };
+            process_events(&vec![archived_event.clone()]);
+            archive_at(FIRST_PERIOD_EXPIRY_BLOCK);
+
+            assert_noop!(
+                EthereumEvents::add_ethereum_log(
+                    RuntimeOrigin::signed(account_id_0()),
+                    ValidEvents::NftMint,
+                    archived_event.transaction_hash
+                ),
+                Error::<TestRuntime>::DuplicateEvent
+            );
+        });
+    }
+
+    #[test]
+    fn are_not_removed_from_the_queue_with_an_invalid_proof() {
+        let mut ext = ExtBuilder::build_default().as_externality();
+        ext.execute_with(|| {
+            process_events(&event_ids());
+            archive_at(FIRST_PERIOD_EXPIRY_BLOCK);
+        });
+
+        let events = indexed_archived_events(&mut ext, 0);
+        ext.execute_with(|| {
+            let queued_event = EthereumEvents::get_event_id(20);
+            EthereumEvents::insert_to_unchecked_events(&queued_event, 1);
+
+            assert_noop!(
+                EthereumEvents::remove_replayed_event(
+                    RawOrigin::Signed(account_id_0()).into(),
+                    queued_event,
+                    proof_of(&events, &event_ids()[0], 0)
+                ),
+                Error::<TestRuntime>::InvalidArchivedEventProof
+            );
+        });
+    }
+}
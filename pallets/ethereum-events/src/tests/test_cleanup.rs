@@ -0,0 +1,184 @@
+// Copyright 2024 Aventus Network Services (UK) Ltd.
+#![cfg(test)]
+
+use crate::{
+    mock::{RuntimeEvent as Event, *},
+    *,
+};
+use frame_support::{assert_noop, assert_ok, dispatch::Pays};
+use frame_system::RawOrigin;
+use sp_avn_common::event_types::{CheckResult, EventData};
+use sp_runtime::traits::BadOrigin;
+
+fn challenge(event_id: &EthEventId) {
+    <Challenges<TestRuntime>>::insert(event_id, BoundedVec::truncate_from(vec![account_id_0()]));
+}
+
+fn insert_pending_challenge(event_id: &EthEventId) {
+    EthereumEvents::insert_to_events_pending_challenge(
+        CHECKED_AT_BLOCK,
+        CheckResult::Ok,
+        event_id,
+        1,
+        &EventData::EmptyEvent,
+        checked_by(),
+        CHECKED_AT_BLOCK + EVENT_CHALLENGE_PERIOD,
+        MIN_CHALLENGE_VOTES,
+    );
+}
+
+fn insert_offence_evidence(reported_at: u64) -> H256 {
+    let evidence = InvalidEthereumLogOffenceEvidence {
+        offence_type: EthereumLogOffenceType::UnsignedRateLimitReached,
+        event_id: None,
+        challenges: 0,
+        reporter: account_id_0(),
+        reported_at,
+    };
+    let hash = evidence.hash();
+    <OffenceEvidence<TestRuntime>>::insert(hash, evidence);
+    hash
+}
+
+fn cleanup(limit: u32) -> DispatchResultWithPostInfo {
+    EthereumEvents::cleanup(RuntimeOrigin::signed(account_id_0()), limit)
+}
+
+mod cleanup {
+    use super::*;
+
+    #[test]
+    fn removes_the_challenges_of_events_no_longer_pending_challenge() {
+        let mut ext = ExtBuilder::build_default().as_externality();
+        ext.execute_with(|| {
+            let event_id = EthereumEvents::get_event_id(1);
+            challenge(&event_id);
+
+            assert_ok!(cleanup(10));
+
+            assert!(!<Challenges<TestRuntime>>::contains_key(&event_id));
+            assert!(System::events().iter().any(|record| record.event ==
+                Event::EthereumEvents(crate::Event::<TestRuntime>::StaleChallengesRemoved {
+                    removed: 1
+                })));
+        });
+    }
+
+    #[test]
+    fn keeps_the_challenges_of_events_pending_challenge() {
+        let mut ext = ExtBuilder::build_default().as_externality();
+        ext.execute_with(|| {
+            let event_id = EthereumEvents::get_event_id(1);
+            insert_pending_challenge(&event_id);
+            challenge(&event_id);
+
+            assert_ok!(cleanup(10));
+
+            assert!(<Challenges<TestRuntime>>::contains_key(&event_id));
+        });
+    }
+
+    #[test]
+    fn removes_offence_evidence_once_it_has_expired() {
+        let mut ext = ExtBuilder::build_default().as_externality();
+        ext.execute_with(|| {
+            let evidence = insert_offence_evidence(1);
+            System::set_block_number(OffenceEvidenceRetention::get() + 2);
+
+            assert_ok!(cleanup(10));
+
+            assert!(!<OffenceEvidence<TestRuntime>>::contains_key(evidence));
+            assert!(System::events().iter().any(|record| record.event ==
+                Event::EthereumEvents(
+                    crate::Event::<TestRuntime>::ExpiredOffenceEvidenceRemoved { removed: 1 }
+                )));
+        });
+    }
+
+    #[test]
+    fn keeps_offence_evidence_that_has_not_expired() {
+        let mut ext = ExtBuilder::build_default().as_externality();
+        ext.execute_with(|| {
+            let evidence = insert_offence_evidence(1);
+            System::set_block_number(OffenceEvidenceRetention::get() + 1);
+
+            assert_ok!(cleanup(10));
+
+            assert!(<OffenceEvidence<TestRuntime>>::contains_key(evidence));
+        });
+    }
+
+    #[test]
+    fn checks_at_most_limit_entries() {
+        let mut ext = ExtBuilder::build_default().as_externality();
+        ext.execute_with(|| {
+            for seed in 1..=3 {
+                challenge(&EthereumEvents::get_event_id(seed));
+            }
+
+            assert_ok!(cleanup(2));
+
+            assert_eq!(<Challenges<TestRuntime>>::iter_keys().count(), 1);
+        });
+    }
+
+    #[test]
+    fn carries_on_from_the_last_challenge_checked() {
+        let mut ext = ExtBuilder::build_default().as_externality();
+        ext.execute_with(|| {
+            for seed in 1..=3 {
+                let event_id = EthereumEvents::get_event_id(seed);
+                insert_pending_challenge(&event_id);
+                challenge(&event_id);
+            }
+
+            let post_info = cleanup(2).expect("Cleanup succeeds");
+            assert_eq!(
+                post_info.actual_weight,
+                Some(<TestRuntime as crate::Config>::WeightInfo::cleanup(2))
+            );
+            assert!(<ChallengesCleanupCursor<TestRuntime>>::get().is_some());
+
+            let post_info = cleanup(2).expect("Cleanup succeeds");
+            assert_eq!(
+                post_info.actual_weight,
+                Some(<TestRuntime as crate::Config>::WeightInfo::cleanup(1))
+            );
+            assert!(<ChallengesCleanupCursor<TestRuntime>>::get().is_none());
+        });
+    }
+
+    #[test]
+    fn is_free_when_it_removes_challenges() {
+        let mut ext = ExtBuilder::build_default().as_externality();
+        ext.execute_with(|| {
+            challenge(&EthereumEvents::get_event_id(1));
+
+            let post_info = cleanup(10).expect("Cleanup succeeds");
+
+            assert_eq!(post_info.pays_fee, Pays::No);
+            assert_eq!(
+                post_info.actual_weight,
+                Some(<TestRuntime as crate::Config>::WeightInfo::cleanup(1))
+            );
+        });
+    }
+
+    #[test]
+    fn is_paid_for_when_there_is_nothing_to_remove() {
+        let mut ext = ExtBuilder::build_default().as_externality();
+        ext.execute_with(|| {
+            let post_info = cleanup(10).expect("Cleanup succeeds");
+
+            assert_eq!(post_info.pays_fee, Pays::Yes);
+        });
+    }
+
+    #[test]
+    fn fails_for_an_unsigned_origin() {
+        let mut ext = ExtBuilder::build_default().as_externality();
+        ext.execute_with(|| {
+            assert_noop!(EthereumEvents::cleanup(RawOrigin::None.into(), 10), BadOrigin);
+        });
+    }
+}
@@ -241,6 +241,24 @@ fn get_status_should_return_expected_result_events_when_input_is_valid() {
     assert_eq!(status.unwrap(), 1);
 }
 
+#[test]
+fn get_block_number_should_return_expected_result_when_input_is_valid() {
+    let mock_events_response = MockEthEventsResponse::setup();
+    let block_number = get_block_number(&mock_events_response.valid_result_field);
+
+    assert!(block_number.is_ok());
+    assert_eq!(block_number.unwrap(), 0x2e);
+}
+
+#[test]
+fn get_block_number_should_return_error_when_block_number_is_missing() {
+    let mock_events_response = MockEthEventsResponse::setup();
+    let mut result_field = mock_events_response.valid_result_field.clone();
+    result_field.retain(|(key, _)| *key != "blockNumber".chars().collect::<Vec<char>>());
+
+    assert!(get_block_number(&result_field).is_err());
+}
+
 #[test]
 fn get_status_should_return_error_when_input_is_empty() {
     let s_key: Vec<char> = "s".chars().collect();
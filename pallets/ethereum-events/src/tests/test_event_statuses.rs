@@ -0,0 +1,66 @@
+// Copyright 2024 Aventus (UK) Ltd.
+#![cfg(test)]
+
+use crate::{mock::*, *};
+
+mod event_statuses {
+    use super::*;
+
+    #[test]
+    fn returns_unchecked_events() {
+        let mut ext = ExtBuilder::build_default().with_genesis_config().as_externality();
+        ext.execute_with(|| {
+            let event_id = EthereumEvents::get_event_id(10);
+            EthereumEvents::insert_to_unchecked_events(&event_id, 1);
+
+            assert_eq!(
+                EthereumEvents::event_statuses(event_id.transaction_hash),
+                vec![(event_id, EthEventStatus::Unchecked)]
+            );
+        });
+    }
+
+    #[test]
+    fn returns_events_pending_challenge() {
+        let mut ext = ExtBuilder::build_default().with_genesis_config().as_externality();
+        ext.execute_with(|| {
+            EthereumEvents::populate_events_pending_challenge(&checked_by(), 1);
+            let event_id = EthereumEvents::get_event_id(0);
+
+            assert_eq!(
+                EthereumEvents::event_statuses(event_id.transaction_hash),
+                vec![(
+                    event_id,
+                    EthEventStatus::PendingChallenge {
+                        check_result: CheckResult::Unknown,
+                        ready_for_processing_after_block: EVENT_CHALLENGE_PERIOD,
+                    }
+                )]
+            );
+        });
+    }
+
+    #[test]
+    fn returns_processed_events() {
+        let mut ext = ExtBuilder::build_default().with_genesis_config().as_externality();
+        ext.execute_with(|| {
+            let event_id = EthereumEvents::get_event_id(10);
+            EthereumEvents::insert_to_processed_events(&event_id);
+
+            assert_eq!(
+                EthereumEvents::event_statuses(event_id.transaction_hash),
+                vec![(event_id, EthEventStatus::Processed { accepted: true })]
+            );
+        });
+    }
+
+    #[test]
+    fn returns_nothing_for_unknown_transactions() {
+        let mut ext = ExtBuilder::build_default().with_genesis_config().as_externality();
+        ext.execute_with(|| {
+            EthereumEvents::insert_to_unchecked_events(&EthereumEvents::get_event_id(10), 1);
+
+            assert!(EthereumEvents::event_statuses(H256::from([11u8; 32])).is_empty());
+        });
+    }
+}
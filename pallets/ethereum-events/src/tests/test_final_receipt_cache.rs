@@ -0,0 +1,129 @@
+// Copyright 2024 Aventus Network Services (UK) Ltd.
+#![cfg(test)]
+
+use crate::{mock::*, *};
+use frame_support::assert_ok;
+use sp_avn_common::event_types::{CheckResult, EthEventId, ValidEvents};
+use sp_core::hash::H256;
+use sp_runtime::{offchain::storage::StorageValueRef, testing::UintAuthorityId};
+
+// The block number of the receipts returned by `simulate_http_response`
+const RECEIPT_ETH_BLOCK: u64 = 0x2e;
+
+fn event_id() -> EthEventId {
+    EthEventId {
+        signature: ValidEvents::AddedValidator.signature(),
+        transaction_hash: H256::from_slice(&[1u8; 32]),
+    }
+}
+
+fn check() -> EthEventCheckResult<BlockNumber, AccountId> {
+    UintAuthorityId::set_all_keys(vec![1, 2, 3]);
+    EthereumEvents::check_event(1, &event_id(), &EthereumEvents::validators()[0])
+}
+
+fn cached_receipt() -> Option<Vec<u8>> {
+    StorageValueRef::persistent(&EthereumEvents::final_receipt_key(&event_id()))
+        .get::<Vec<u8>>()
+        .unwrap()
+}
+
+#[test]
+fn the_check_result_records_the_eth_block_of_the_receipt() {
+    let (mut ext, _pool_state, offchain_state) = ExtBuilder::build_default()
+        .with_genesis_config()
+        .with_validators()
+        .for_offchain_worker()
+        .as_externality_with_state();
+
+    ext.execute_with(|| {
+        simulate_http_response(&offchain_state, &event_id(), GOOD_STATUS, GOOD_BLOCK_CONFIRMATIONS);
+
+        let result = check();
+
+        assert_eq!(result.result, CheckResult::Ok);
+        assert_eq!(result.eth_block, Some(RECEIPT_ETH_BLOCK));
+    });
+}
+
+#[test]
+fn a_final_receipt_is_cached_and_not_fetched_again() {
+    let (mut ext, _pool_state, offchain_state) = ExtBuilder::build_default()
+        .with_genesis_config()
+        .with_validators()
+        .for_offchain_worker()
+        .as_externality_with_state();
+
+    ext.execute_with(|| {
+        // Only one response is expected, a second request would fail the test
+        simulate_http_response(&offchain_state, &event_id(), GOOD_STATUS, GOOD_BLOCK_CONFIRMATIONS);
+
+        let first_result = check();
+        assert!(cached_receipt().is_some());
+
+        let second_result = check();
+
+        assert_eq!(second_result, first_result);
+    });
+}
+
+#[test]
+fn a_receipt_without_enough_confirmations_is_not_cached() {
+    let (mut ext, _pool_state, offchain_state) = ExtBuilder::build_default()
+        .with_genesis_config()
+        .with_validators()
+        .for_offchain_worker()
+        .as_externality_with_state();
+
+    ext.execute_with(|| {
+        simulate_http_response(
+            &offchain_state,
+            &event_id(),
+            GOOD_STATUS,
+            GOOD_BLOCK_CONFIRMATIONS - 1,
+        );
+
+        let result = check();
+
+        assert_eq!(result.result, CheckResult::InsufficientConfirmations);
+        assert_eq!(result.eth_block, Some(RECEIPT_ETH_BLOCK));
+        assert_eq!(cached_receipt(), None);
+    });
+}
+
+#[test]
+fn a_receipt_checked_in_another_block_is_fetched_again() {
+    let (mut ext, _pool_state, offchain_state) = ExtBuilder::build_default()
+        .with_genesis_config()
+        .with_validators()
+        .for_offchain_worker()
+        .as_externality_with_state();
+
+    ext.execute_with(|| {
+        // The second response is expected by the validation, the test fails if it is not requested
+        simulate_http_response(&offchain_state, &event_id(), GOOD_STATUS, GOOD_BLOCK_CONFIRMATIONS);
+        simulate_http_response(&offchain_state, &event_id(), GOOD_STATUS, GOOD_BLOCK_CONFIRMATIONS);
+        let mut checked = check();
+        assert!(cached_receipt().is_some());
+
+        checked.eth_block = Some(RECEIPT_ETH_BLOCK + 1);
+        checked.checked_by = EthereumEvents::validators()[1].account_id.clone();
+
+        assert_ok!(EthereumEvents::validate_event(1, checked, 1, &EthereumEvents::validators()[0]));
+        assert!(cached_receipt().is_some());
+    });
+}
+
+#[test]
+fn processing_an_event_removes_its_cached_receipt() {
+    let mut ext = ExtBuilder::build_default().as_externality();
+    let receipt_key = EthereumEvents::final_receipt_key(&event_id());
+
+    ext.execute_with(|| sp_io::offchain_index::set(&receipt_key, &b"receipt".to_vec()));
+    ext.persist_offchain_overlay();
+    assert!(ext.offchain_db().get(&receipt_key).is_some());
+
+    ext.execute_with(|| EthereumEvents::add_processed_event(&event_id(), true));
+    ext.persist_offchain_overlay();
+    assert_eq!(ext.offchain_db().get(&receipt_key), None);
+}
@@ -0,0 +1,135 @@
+// Copyright 2024 Aventus Network Services (UK) Ltd.
+#![cfg(test)]
+
+use crate::{
+    migration::{
+        add_processed_events_to_archive_periods, ArchiveMigrationProgress,
+        MIGRATED_EVENTS_PER_PERIOD,
+    },
+    mock::*,
+    *,
+};
+use frame_support::weights::Weight;
+
+fn event_ids(number_of_events: u64) -> Vec<EthEventId> {
+    (1..=number_of_events)
+        .map(|seed| EthEventId {
+            signature: ValidEvents::AddedValidator.signature(),
+            transaction_hash: H256::from_low_u64_be(seed),
+        })
+        .collect()
+}
+
+fn insert_legacy_processed_events(event_ids: &Vec<EthEventId>) {
+    event_ids
+        .iter()
+        .for_each(|event_id| <ProcessedEvents<TestRuntime>>::insert(event_id, true));
+    <StorageVersion<TestRuntime>>::put(Releases::V6_0_0);
+}
+
+fn migrate() {
+    add_processed_events_to_archive_periods::<TestRuntime>(Weight::MAX);
+}
+
+mod add_processed_events_to_archive_periods {
+    use super::*;
+
+    #[test]
+    fn adds_the_processed_events_to_the_next_period_to_archive() {
+        let mut ext = ExtBuilder::build_default().as_externality();
+        ext.execute_with(|| {
+            <NextPeriodToArchive<TestRuntime>>::put(2);
+            insert_legacy_processed_events(&event_ids(3));
+
+            migrate();
+
+            assert!(event_ids(3)
+                .iter()
+                .all(|e| <ProcessedEventsByPeriod<TestRuntime>>::get(2, e) == Some(true)));
+            assert_eq!(EthereumEvents::processed_events_count(2), 3);
+            assert_eq!(<StorageVersion<TestRuntime>>::get(), Releases::V7_0_0);
+            assert!(!<ArchiveMigrationCursor<TestRuntime>>::exists());
+        });
+    }
+
+    #[test]
+    fn spreads_the_processed_events_over_several_periods() {
+        let mut ext = ExtBuilder::build_default().as_externality();
+        ext.execute_with(|| {
+            insert_legacy_processed_events(&event_ids(MIGRATED_EVENTS_PER_PERIOD as u64 + 1));
+
+            migrate();
+
+            assert_eq!(EthereumEvents::processed_events_count(0), MIGRATED_EVENTS_PER_PERIOD);
+            assert_eq!(EthereumEvents::processed_events_count(1), 1);
+        });
+    }
+
+    #[test]
+    fn does_not_add_the_events_processed_since_it_started() {
+        let mut ext = ExtBuilder::build_default().as_externality();
+        ext.execute_with(|| {
+            insert_legacy_processed_events(&event_ids(3));
+            let new_event = EthereumEvents::get_event_id(20);
+            EthereumEvents::record_processed_event(&new_event, true);
+
+            migrate();
+
+            assert_eq!(EthereumEvents::processed_events_count(0), 4);
+        });
+    }
+
+    #[test]
+    fn resumes_from_the_cursor() {
+        let mut ext = ExtBuilder::build_default().as_externality();
+        ext.execute_with(|| {
+            insert_legacy_processed_events(&event_ids(3));
+            let events: Vec<EthEventId> = <ProcessedEvents<TestRuntime>>::iter_keys().collect();
+            <ArchiveMigrationCursor<TestRuntime>>::put(ArchiveMigrationProgress {
+                last_event: events[0].clone(),
+                period: 5,
+                started_in: 0,
+            });
+
+            migrate();
+
+            assert_eq!(<ProcessedEventsByPeriod<TestRuntime>>::get(5, &events[0]), None);
+            assert_eq!(<ProcessedEventsByPeriod<TestRuntime>>::get(5, &events[1]), Some(true));
+            assert_eq!(<ProcessedEventsByPeriod<TestRuntime>>::get(5, &events[2]), Some(true));
+            assert!(!<ArchiveMigrationCursor<TestRuntime>>::exists());
+        });
+    }
+
+    #[test]
+    fn does_nothing_once_the_events_are_in_periods() {
+        let mut ext = ExtBuilder::build_default().as_externality();
+        ext.execute_with(|| {
+            event_ids(3)
+                .iter()
+                .for_each(|event_id| <ProcessedEvents<TestRuntime>>::insert(event_id, true));
+
+            migrate();
+
+            assert_eq!(EthereumEvents::processed_events_count(0), 0);
+        });
+    }
+
+    #[test]
+    fn holds_the_archive_until_it_completes() {
+        let mut ext = ExtBuilder::build_default().as_externality();
+        ext.execute_with(|| {
+            insert_legacy_processed_events(&event_ids(3));
+            System::set_block_number(30);
+
+            EthereumEvents::archive_expired_periods(Weight::MAX);
+            assert_eq!(EthereumEvents::archived_periods(0), None);
+
+            migrate();
+            EthereumEvents::archive_expired_periods(Weight::MAX);
+            assert_eq!(
+                EthereumEvents::archived_periods(0).map(|archived| archived.number_of_events),
+                Some(3)
+            );
+        });
+    }
+}
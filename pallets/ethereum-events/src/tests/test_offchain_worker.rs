@@ -289,6 +289,40 @@ fn submit_checkevent_result_should_return_error_when_challenge_window_overflow()
     });
 }
 
+#[test]
+fn submit_checkevent_result_should_return_error_when_events_pending_challenge_are_full() {
+    let mut ext = ExtBuilder::build_default().with_validators().as_externality();
+    ext.execute_with(|| {
+        let mock_data = MockData::setup();
+        <UncheckedEvents<TestRuntime>>::try_append(&(
+            mock_data.event_id.clone(),
+            DEFAULT_INGRESS_COUNTER,
+            0,
+        ))
+        .expect("Cannot append");
+        for i in 0..MaxEventsPendingChallenges::get() {
+            <EventsPendingChallenge<TestRuntime>>::try_append((
+                mock_data.eth_event_check_result.clone(),
+                DEFAULT_INGRESS_COUNTER + 1 + i as u64,
+                0,
+            ))
+            .expect("Cannot append");
+        }
+
+        assert_noop!(
+            EthereumEvents::submit_checkevent_result(
+                RawOrigin::None.into(),
+                mock_data.eth_event_check_result.clone(),
+                DEFAULT_INGRESS_COUNTER,
+                mock_data.signature.clone(),
+                mock_data.validator.clone()
+            ),
+            Error::<TestRuntime>::EventsPendingChallengeOverflow
+        );
+        assert_eq!(EthereumEvents::unchecked_events().len(), 1);
+    });
+}
+
 #[test]
 fn process_event_should_return_expected_result_when_challenge_fails() {
     let mut ext = ExtBuilder::build_default().with_validators().as_externality();
@@ -327,6 +361,7 @@ fn process_event_should_return_expected_result_when_challenge_fails() {
                 eth_event_id: mock_data.event_id.clone(),
                 processor: mock_data.validator.account_id,
                 outcome: true,
+                sequence: 0,
             })));
         assert!(!System::events().iter().any(|a| a.event ==
             mock::RuntimeEvent::EthereumEvents(
@@ -398,6 +433,7 @@ fn process_event_should_return_expected_result_when_challenge_is_successful() {
                 eth_event_id: mock_data.event_id.clone(),
                 processor: mock_data.validator.account_id,
                 outcome: false,
+                sequence: 0,
             })));
         assert!(System::events().iter().any(|a| a.event ==
             mock::RuntimeEvent::EthereumEvents(
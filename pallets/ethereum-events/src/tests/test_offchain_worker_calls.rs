@@ -1,8 +1,12 @@
 #![cfg(test)]
 use crate::{mock::*, Call, *};
-use codec::Decode;
+use codec::{Decode, Encode};
+use frame_support::assert_ok;
 use frame_system::pallet_prelude::BlockNumberFor;
-use sp_avn_common::event_types::EthEvent;
+use sp_avn_common::{
+    event_types::EthEvent,
+    ocw_task_queue::{self as OcwTaskQueue, OcwTaskPriority},
+};
 use sp_runtime::testing::UintAuthorityId;
 
 fn mock_event() -> EthEvent {
@@ -41,6 +45,42 @@ fn test_try_check_event_no_change_when_no_events() {
     });
 }
 
+#[test]
+fn try_check_event_checks_the_queued_event_with_the_highest_priority_first() {
+    let (mut ext, _pool_state, offchain_state) = ExtBuilder::build_default()
+        .with_validators()
+        .for_offchain_worker()
+        .as_externality_with_state();
+
+    ext.execute_with(|| {
+        let validator = keys_setup_return_good_validator();
+        let first_event = EthereumEvents::get_event_id(1);
+        let hinted_event = EthereumEvents::get_event_id(2);
+        EthereumEvents::insert_to_unchecked_events(&first_event, 1);
+        EthereumEvents::insert_to_unchecked_events(&hinted_event, 2);
+        assert_ok!(OcwTaskQueue::enqueue(
+            OCW_TASK_OWNER,
+            &hinted_event.encode(),
+            OcwTaskPriority::High,
+            1u64
+        ));
+        inject_ethereum_node_response(
+            &mut offchain_state.write(),
+            &hinted_event.transaction_hash,
+            Some("{\"id\": 1, \"jsonrpc\": \"2.0\", \"result\": null}".into()),
+        );
+
+        EthereumEvents::try_check_event(1u64, &validator, 1u32.into());
+
+        let tasks = OcwTaskQueue::queued_tasks::<BlockNumberFor<TestRuntime>>(Some(OCW_TASK_OWNER));
+        let attempts_of = |event_id: &EthEventId| {
+            tasks.iter().find(|task| task.id == event_id.encode()).map(|task| task.attempts)
+        };
+        assert_eq!(attempts_of(&hinted_event), Some(1));
+        assert_eq!(attempts_of(&first_event), Some(0));
+    });
+}
+
 #[test]
 fn test_check_event_and_submit_result_status_bad() {
     check_event_and_submit_result(
@@ -10,7 +10,9 @@ use frame_system::RawOrigin;
 use sp_core::hash::H256;
 use sp_runtime::testing::{TestSignature, UintAuthorityId};
 
-use sp_avn_common::event_types::{CheckResult, EthEventCheckResult, EventData};
+use sp_avn_common::event_types::{
+    processed_event_index_key, CheckResult, EthEventCheckResult, EventData, ProcessedEventRecord,
+};
 use sp_runtime::BoundedVec;
 
 use offence::EthereumLogOffenceType;
@@ -20,6 +22,16 @@ const PROCESSED: bool = true;
 
 const EXPECTED_SESSION_VALIDATOR_COUNT: u32 = 3;
 
+fn indexed_processed_event(
+    ext: &mut sp_io::TestExternalities,
+    event_id: &EthEventId,
+) -> Option<ProcessedEventRecord<u64>> {
+    ext.persist_offchain_overlay();
+    ext.offchain_db()
+        .get(&processed_event_index_key(event_id))
+        .map(|record| ProcessedEventRecord::decode(&mut &record[..]).expect("Record is valid"))
+}
+
 mod process_event {
     use super::*;
     struct Context {
@@ -165,11 +177,37 @@ mod process_event {
                                 eth_event_id: context.event_id,
                                 processor: context.validator.account_id,
                                 outcome: NOT_PROCESSED,
+                                sequence: 0,
                             });
                         assert_eq!(true, an_event_was_emitted(&event));
                     });
                 }
 
+                #[test]
+                fn records_the_evidence_of_the_offence() {
+                    let mut ext = ExtBuilder::build_default().with_validators().as_externality();
+                    ext.execute_with(|| {
+                        let context = setup();
+                        assert_ok!(call_process_event_result(&context));
+
+                        let evidence_hash = EthereumEvents::offence_evidence_of_type(
+                            EthereumLogOffenceType::IncorrectValidationResultSubmitted,
+                        )
+                        .unwrap();
+                        assert_eq!(
+                            EthereumEvents::offence_evidence(evidence_hash),
+                            Some(InvalidEthereumLogOffenceEvidence {
+                                offence_type:
+                                    EthereumLogOffenceType::IncorrectValidationResultSubmitted,
+                                event_id: Some(context.event_id),
+                                challenges: 2,
+                                reporter: context.validator.account_id,
+                                reported_at: System::block_number(),
+                            })
+                        );
+                    });
+                }
+
                 #[test]
                 fn logs_ethereum_event_rejected() {
                     let mut ext = ExtBuilder::build_default().with_validators().as_externality();
@@ -216,6 +254,10 @@ mod process_event {
                                 offence_type:
                                     EthereumLogOffenceType::IncorrectValidationResultSubmitted,
                                 offenders: vec![(context.checked_by, context.checked_by)],
+                                evidence: EthereumEvents::offence_evidence_of_type(
+                                    EthereumLogOffenceType::IncorrectValidationResultSubmitted,
+                                )
+                                .unwrap(),
                             });
                         assert_eq!(true, an_event_was_emitted(&event));
                     });
@@ -290,6 +332,7 @@ mod process_event {
                                 eth_event_id: context.event_id,
                                 processor: context.validator.account_id,
                                 outcome: NOT_PROCESSED,
+                                sequence: 0,
                             });
                         assert_eq!(true, an_event_was_emitted(&event));
                     });
@@ -347,6 +390,10 @@ mod process_event {
                                 offence_type:
                                     EthereumLogOffenceType::IncorrectValidationResultSubmitted,
                                 offenders: vec![(context.checked_by, context.checked_by)],
+                                evidence: EthereumEvents::offence_evidence_of_type(
+                                    EthereumLogOffenceType::IncorrectValidationResultSubmitted,
+                                )
+                                .unwrap(),
                             });
                         assert_eq!(true, an_event_was_emitted(&event));
                     });
@@ -427,6 +474,7 @@ mod process_event {
                                 eth_event_id: context.event_id,
                                 processor: context.validator.account_id,
                                 outcome: PROCESSED,
+                                sequence: 0,
                             });
                         assert_eq!(true, an_event_was_emitted(&event));
                     });
@@ -462,6 +510,10 @@ mod process_event {
                                     (context.first_validator_id, context.first_validator_id),
                                     (context.second_validator_id, context.second_validator_id),
                                 ],
+                                evidence: EthereumEvents::offence_evidence_of_type(
+                                    EthereumLogOffenceType::ChallengeAttemptedOnValidResult,
+                                )
+                                .unwrap(),
                             });
                         assert_eq!(true, an_event_was_emitted(&event));
                     });
@@ -562,6 +614,7 @@ mod process_event {
                                 eth_event_id: context.event_id.clone(),
                                 processor: context.validator.account_id,
                                 outcome: PROCESSED,
+                                sequence: 0,
                             });
                         assert_eq!(true, an_event_was_emitted(&event));
 
@@ -597,6 +650,10 @@ mod process_event {
                                     (context.first_validator_id, context.first_validator_id),
                                     (context.second_validator_id, context.second_validator_id),
                                 ],
+                                evidence: EthereumEvents::offence_evidence_of_type(
+                                    EthereumLogOffenceType::ChallengeAttemptedOnValidResult,
+                                )
+                                .unwrap(),
                             });
                         assert_eq!(true, an_event_was_emitted(&event));
                     });
@@ -658,6 +715,25 @@ mod process_event {
                     assert_eq!(offences, vec![]);
                 });
             }
+
+            #[test]
+            fn indexes_the_event_as_accepted() {
+                let mut ext = ExtBuilder::build_default().with_validators().as_externality();
+                let (event_id, processed_at_block) = ext.execute_with(|| {
+                    let context = setup();
+                    assert_ok!(call_process_event_result(&context));
+                    (context.event_id, System::block_number())
+                });
+
+                assert_eq!(
+                    indexed_processed_event(&mut ext, &event_id),
+                    Some(ProcessedEventRecord {
+                        event_id,
+                        block_number: processed_at_block,
+                        accepted: true,
+                    })
+                );
+            }
         }
     }
 
@@ -712,6 +788,7 @@ mod process_event {
                                 eth_event_id: context.event_id,
                                 processor: context.validator.account_id,
                                 outcome: NOT_PROCESSED,
+                                sequence: 0,
                             });
                         assert_eq!(true, an_event_was_emitted(&event));
                     });
@@ -763,6 +840,10 @@ mod process_event {
                                 offence_type:
                                     EthereumLogOffenceType::IncorrectValidationResultSubmitted,
                                 offenders: vec![(context.checked_by, context.checked_by)],
+                                evidence: EthereumEvents::offence_evidence_of_type(
+                                    EthereumLogOffenceType::IncorrectValidationResultSubmitted,
+                                )
+                                .unwrap(),
                             });
                         assert_eq!(true, an_event_was_emitted(&event));
                     });
@@ -837,6 +918,7 @@ mod process_event {
                                 eth_event_id: context.event_id,
                                 processor: context.validator.account_id,
                                 outcome: NOT_PROCESSED,
+                                sequence: 0,
                             });
                         assert_eq!(true, an_event_was_emitted(&event));
                     });
@@ -894,6 +976,10 @@ mod process_event {
                                 offence_type:
                                     EthereumLogOffenceType::IncorrectValidationResultSubmitted,
                                 offenders: vec![(context.checked_by, context.checked_by)],
+                                evidence: EthereumEvents::offence_evidence_of_type(
+                                    EthereumLogOffenceType::IncorrectValidationResultSubmitted,
+                                )
+                                .unwrap(),
                             });
                         assert_eq!(true, an_event_was_emitted(&event));
                     });
@@ -983,6 +1069,7 @@ mod process_event {
                                 eth_event_id: context.event_id,
                                 processor: context.validator.account_id,
                                 outcome: PROCESSED,
+                                sequence: 0,
                             });
                         assert_eq!(true, an_event_was_emitted(&event));
                     });
@@ -1022,6 +1109,10 @@ mod process_event {
                                     (context.first_validator_id, context.first_validator_id),
                                     (context.second_validator_id, context.second_validator_id),
                                 ],
+                                evidence: EthereumEvents::offence_evidence_of_type(
+                                    EthereumLogOffenceType::ChallengeAttemptedOnValidResult,
+                                )
+                                .unwrap(),
                             });
                         assert_eq!(true, an_event_was_emitted(&event));
                     });
@@ -1106,6 +1197,7 @@ mod process_event {
                                 eth_event_id: context.event_id,
                                 processor: context.validator.account_id,
                                 outcome: PROCESSED,
+                                sequence: 0,
                             });
                         assert_eq!(true, an_event_was_emitted(&event));
                     });
@@ -1149,6 +1241,10 @@ mod process_event {
                                     (context.first_validator_id, context.first_validator_id),
                                     (context.second_validator_id, context.second_validator_id),
                                 ],
+                                evidence: EthereumEvents::offence_evidence_of_type(
+                                    EthereumLogOffenceType::ChallengeAttemptedOnValidResult,
+                                )
+                                .unwrap(),
                             });
                         assert_eq!(true, an_event_was_emitted(&event));
                     });
@@ -1203,6 +1299,25 @@ mod process_event {
                     assert_eq!(offences, vec![]);
                 });
             }
+
+            #[test]
+            fn indexes_the_event_as_rejected() {
+                let mut ext = ExtBuilder::build_default().with_validators().as_externality();
+                let (event_id, processed_at_block) = ext.execute_with(|| {
+                    let context = setup();
+                    assert_ok!(call_process_event_result(&context));
+                    (context.event_id, System::block_number())
+                });
+
+                assert_eq!(
+                    indexed_processed_event(&mut ext, &event_id),
+                    Some(ProcessedEventRecord {
+                        event_id,
+                        block_number: processed_at_block,
+                        accepted: false,
+                    })
+                );
+            }
         }
     }
 
@@ -1285,3 +1400,25 @@ mod process_event {
         return System::events().iter().any(|a| a.event == *event)
     }
 }
+
+mod add_processed_event {
+    use super::*;
+
+    #[test]
+    fn indexes_the_event() {
+        let mut ext = ExtBuilder::build_default().with_validators().as_externality();
+        let event_id = EthEventId {
+            signature: ValidEvents::Lifted.signature(),
+            transaction_hash: H256::from([2; 32]),
+        };
+
+        ext.execute_with(|| {
+            <EthereumEvents as ProcessedEventsChecker>::add_processed_event(&event_id, true);
+        });
+
+        assert_eq!(
+            indexed_processed_event(&mut ext, &event_id),
+            Some(ProcessedEventRecord { event_id, block_number: 1, accepted: true })
+        );
+    }
+}
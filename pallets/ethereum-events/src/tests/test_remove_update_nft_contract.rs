@@ -0,0 +1,199 @@
+// Copyright 2024 Aventus Network Services (UK) Ltd.
+#![cfg(test)]
+
+use crate::{mock::*, *};
+use frame_support::{assert_noop, assert_ok};
+use frame_system::RawOrigin;
+use sp_runtime::traits::BadOrigin;
+
+const CONTRACT: H160 = H160([15u8; 20]);
+const NEW_CONTRACT: H160 = H160([16u8; 20]);
+
+fn register_contract() {
+    assert_ok!(EthereumEvents::insert_nft_contract(RawOrigin::Root.into(), CONTRACT));
+}
+
+fn queue_unchecked_event(event_type: ValidEvents) {
+    let event_id =
+        EthEventId { signature: event_type.signature(), transaction_hash: H256::from([1u8; 32]) };
+    assert_ok!(<UncheckedEvents<TestRuntime>>::try_append((event_id, 1, 0)));
+}
+
+fn event_emitted(event: crate::Event<TestRuntime>) -> bool {
+    EthereumEvents::event_emitted(&RuntimeEvent::EthereumEvents(event))
+}
+
+mod remove_nft_contract {
+    use super::*;
+
+    #[test]
+    fn removes_the_contract() {
+        let mut ext = ExtBuilder::build_default().with_genesis_config().as_externality();
+        ext.execute_with(|| {
+            register_contract();
+
+            assert_ok!(EthereumEvents::remove_nft_contract(RawOrigin::Root.into(), CONTRACT));
+
+            assert!(!NftT1Contracts::<TestRuntime>::contains_key(CONTRACT));
+            assert!(event_emitted(crate::Event::<TestRuntime>::NftContractRemoved {
+                contract_address: CONTRACT
+            }));
+        });
+    }
+
+    #[test]
+    fn ignores_pending_events_that_are_not_nft_events() {
+        let mut ext = ExtBuilder::build_default().with_genesis_config().as_externality();
+        ext.execute_with(|| {
+            register_contract();
+            queue_unchecked_event(ValidEvents::Lifted);
+
+            assert_ok!(EthereumEvents::remove_nft_contract(RawOrigin::Root.into(), CONTRACT));
+        });
+    }
+
+    mod fails_when {
+        use super::*;
+
+        #[test]
+        fn the_contract_is_not_registered() {
+            let mut ext = ExtBuilder::build_default().with_genesis_config().as_externality();
+            ext.execute_with(|| {
+                assert_noop!(
+                    EthereumEvents::remove_nft_contract(RawOrigin::Root.into(), CONTRACT),
+                    Error::<TestRuntime>::NftContractNotFound
+                );
+            });
+        }
+
+        #[test]
+        fn an_nft_event_is_pending() {
+            let mut ext = ExtBuilder::build_default().with_genesis_config().as_externality();
+            ext.execute_with(|| {
+                register_contract();
+                queue_unchecked_event(ValidEvents::NftMint);
+
+                assert_noop!(
+                    EthereumEvents::remove_nft_contract(RawOrigin::Root.into(), CONTRACT),
+                    Error::<TestRuntime>::NftEventsPending
+                );
+            });
+        }
+
+        #[test]
+        fn origin_is_not_the_admin_origin() {
+            let mut ext = ExtBuilder::build_default().with_genesis_config().as_externality();
+            ext.execute_with(|| {
+                register_contract();
+
+                assert_noop!(
+                    EthereumEvents::remove_nft_contract(
+                        RuntimeOrigin::signed(account_id_0()),
+                        CONTRACT
+                    ),
+                    BadOrigin
+                );
+            });
+        }
+    }
+}
+
+mod update_nft_contract {
+    use super::*;
+
+    #[test]
+    fn replaces_the_contract() {
+        let mut ext = ExtBuilder::build_default().with_genesis_config().as_externality();
+        ext.execute_with(|| {
+            register_contract();
+
+            assert_ok!(EthereumEvents::update_nft_contract(
+                RawOrigin::Root.into(),
+                CONTRACT,
+                NEW_CONTRACT
+            ));
+
+            assert!(!NftT1Contracts::<TestRuntime>::contains_key(CONTRACT));
+            assert!(NftT1Contracts::<TestRuntime>::contains_key(NEW_CONTRACT));
+            assert!(event_emitted(crate::Event::<TestRuntime>::NftContractUpdated {
+                old_contract: CONTRACT,
+                new_contract: NEW_CONTRACT
+            }));
+        });
+    }
+
+    mod fails_when {
+        use super::*;
+
+        #[test]
+        fn the_new_contract_is_zero() {
+            let mut ext = ExtBuilder::build_default().with_genesis_config().as_externality();
+            ext.execute_with(|| {
+                register_contract();
+
+                assert_noop!(
+                    EthereumEvents::update_nft_contract(
+                        RawOrigin::Root.into(),
+                        CONTRACT,
+                        H160::zero()
+                    ),
+                    Error::<TestRuntime>::InvalidContractAddress
+                );
+            });
+        }
+
+        #[test]
+        fn the_old_contract_is_not_registered() {
+            let mut ext = ExtBuilder::build_default().with_genesis_config().as_externality();
+            ext.execute_with(|| {
+                assert_noop!(
+                    EthereumEvents::update_nft_contract(
+                        RawOrigin::Root.into(),
+                        CONTRACT,
+                        NEW_CONTRACT
+                    ),
+                    Error::<TestRuntime>::NftContractNotFound
+                );
+            });
+        }
+
+        #[test]
+        fn the_new_contract_is_already_registered() {
+            let mut ext = ExtBuilder::build_default().with_genesis_config().as_externality();
+            ext.execute_with(|| {
+                register_contract();
+                assert_ok!(EthereumEvents::insert_nft_contract(
+                    RawOrigin::Root.into(),
+                    NEW_CONTRACT
+                ));
+
+                assert_noop!(
+                    EthereumEvents::update_nft_contract(
+                        RawOrigin::Root.into(),
+                        CONTRACT,
+                        NEW_CONTRACT
+                    ),
+                    Error::<TestRuntime>::NftContractAlreadyRegistered
+                );
+            });
+        }
+
+        #[test]
+        fn an_nft_event_is_pending() {
+            let mut ext = ExtBuilder::build_default().with_genesis_config().as_externality();
+            ext.execute_with(|| {
+                register_contract();
+                queue_unchecked_event(ValidEvents::NftTransferTo);
+
+                assert_noop!(
+                    EthereumEvents::update_nft_contract(
+                        RawOrigin::Root.into(),
+                        CONTRACT,
+                        NEW_CONTRACT
+                    ),
+                    Error::<TestRuntime>::NftEventsPending
+                );
+            });
+        }
+    }
+}
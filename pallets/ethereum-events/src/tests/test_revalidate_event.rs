@@ -0,0 +1,136 @@
+// Copyright 2024 Aventus Network Services (UK) Ltd.
+#![cfg(test)]
+
+use crate::{mock::*, *};
+use frame_support::{assert_noop, assert_ok, weights::Weight};
+use frame_system::RawOrigin;
+use sp_avn_common::event_types::{CheckResult, EthEventCheckResult, EventData};
+use sp_runtime::{testing::TestSignature, traits::BadOrigin};
+
+// Block after which an event checked at `CHECKED_AT_BLOCK` can be processed
+const READY_FOR_PROCESSING_AFTER: u64 = CHECKED_AT_BLOCK + EVENT_CHALLENGE_PERIOD;
+
+fn event_id() -> EthEventId {
+    EthereumEvents::get_event_id(1)
+}
+
+fn process_event_with_result(result: CheckResult) {
+    let check_result = EthEventCheckResult::new(
+        CHECKED_AT_BLOCK,
+        result,
+        &event_id(),
+        &EventData::EmptyEvent,
+        checked_by(),
+        READY_FOR_PROCESSING_AFTER,
+        MIN_CHALLENGE_VOTES,
+    );
+    assert_ok!(<EventsPendingChallenge<TestRuntime>>::try_append((
+        check_result,
+        DEFAULT_INGRESS_COUNTER,
+        0
+    )));
+    System::set_block_number(READY_FOR_PROCESSING_AFTER + 1);
+
+    assert_ok!(EthereumEvents::process_event(
+        RawOrigin::None.into(),
+        event_id(),
+        DEFAULT_INGRESS_COUNTER,
+        EthereumEvents::validators()[0].clone(),
+        TestSignature(0, vec![])
+    ));
+}
+
+fn revalidate(event_id: EthEventId) -> DispatchResult {
+    EthereumEvents::revalidate_event(RawOrigin::Root.into(), event_id)
+}
+
+mod revalidate_event {
+    use super::*;
+
+    #[test]
+    fn queues_an_invalid_event_to_be_checked_again() {
+        let mut ext = ExtBuilder::build_default().with_validators().as_externality();
+        ext.execute_with(|| {
+            process_event_with_result(CheckResult::Invalid);
+            assert!(EthereumEvents::invalid_events(event_id()).is_some());
+            let ingress_counter = EthereumEvents::get_next_ingress_counter();
+
+            assert_ok!(revalidate(event_id()));
+
+            assert!(!EthereumEvents::processed_event_exists(&event_id()));
+            assert_eq!(EthereumEvents::invalid_events(event_id()), None);
+            assert_eq!(EthereumEvents::processed_events_count(0), 0);
+            assert!(EthereumEvents::unchecked_events()
+                .iter()
+                .any(|(event, counter, _)| event == &event_id() && *counter == ingress_counter));
+            assert!(EthereumEvents::event_emitted(&RuntimeEvent::EthereumEvents(
+                crate::Event::<TestRuntime>::EventRevalidationRequested {
+                    eth_event_id: event_id(),
+                    ingress_counter,
+                }
+            )));
+        });
+    }
+
+    mod fails_when {
+        use super::*;
+
+        #[test]
+        fn the_event_was_not_declared_invalid() {
+            let mut ext = ExtBuilder::build_default().with_validators().as_externality();
+            ext.execute_with(|| {
+                process_event_with_result(CheckResult::Ok);
+
+                assert_noop!(
+                    revalidate(event_id()),
+                    Error::<TestRuntime>::EventCannotBeRevalidated
+                );
+            });
+        }
+
+        #[test]
+        fn the_event_is_already_queued_again() {
+            let mut ext = ExtBuilder::build_default().with_validators().as_externality();
+            ext.execute_with(|| {
+                process_event_with_result(CheckResult::Invalid);
+                assert_ok!(revalidate(event_id()));
+
+                assert_noop!(
+                    revalidate(event_id()),
+                    Error::<TestRuntime>::EventCannotBeRevalidated
+                );
+            });
+        }
+
+        #[test]
+        fn the_event_has_been_archived() {
+            let mut ext = ExtBuilder::build_default().with_validators().as_externality();
+            ext.execute_with(|| {
+                process_event_with_result(CheckResult::Invalid);
+                System::set_block_number(100);
+                EthereumEvents::archive_expired_periods(Weight::MAX);
+
+                assert_noop!(
+                    revalidate(event_id()),
+                    Error::<TestRuntime>::EventCannotBeRevalidated
+                );
+            });
+        }
+
+        #[test]
+        fn origin_is_not_the_admin_origin() {
+            let mut ext = ExtBuilder::build_default().with_validators().as_externality();
+            ext.execute_with(|| {
+                process_event_with_result(CheckResult::Invalid);
+
+                assert_noop!(
+                    EthereumEvents::revalidate_event(
+                        RuntimeOrigin::signed(account_id_0()),
+                        event_id()
+                    ),
+                    BadOrigin
+                );
+            });
+        }
+    }
+}
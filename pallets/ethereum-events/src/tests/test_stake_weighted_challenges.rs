@@ -0,0 +1,144 @@
+// Copyright 2024 Aventus Network Services (UK) Ltd.
+#![cfg(test)]
+
+use crate::{
+    mock::{RuntimeEvent as Event, *},
+    *,
+};
+use frame_support::{assert_noop, assert_ok};
+use frame_system::RawOrigin;
+use sp_avn_common::event_types::{CheckResult, EthEventCheckResult, EventData};
+use sp_runtime::traits::BadOrigin;
+
+const LARGE_STAKE: u128 = 100;
+const SMALL_STAKE: u128 = 10;
+
+fn event_id() -> EthEventId {
+    EthereumEvents::get_event_id(1)
+}
+
+fn check_result() -> EthEventCheckResult<BlockNumber, AccountId> {
+    check_result_requiring(MIN_CHALLENGE_VOTES)
+}
+
+fn check_result_requiring(min_challenge_votes: u32) -> EthEventCheckResult<BlockNumber, AccountId> {
+    EthEventCheckResult::new(
+        CHECKED_AT_BLOCK,
+        CheckResult::Ok,
+        &event_id(),
+        &EventData::EmptyEvent,
+        checked_by(),
+        CHECKED_AT_BLOCK + EVENT_CHALLENGE_PERIOD,
+        min_challenge_votes,
+    )
+}
+
+fn validator(index: usize) -> AccountId {
+    EthereumEvents::validators()[index].account_id.clone()
+}
+
+// The first validator is backed by more than a third of the stake, the others by little
+fn set_stakes() {
+    set_backing_stake(validator(0), LARGE_STAKE);
+    set_backing_stake(validator(1), SMALL_STAKE);
+    set_backing_stake(validator(2), SMALL_STAKE);
+}
+
+fn challenge_by(challengers: Vec<AccountId>) {
+    <Challenges<TestRuntime>>::insert(event_id(), BoundedVec::truncate_from(challengers));
+}
+
+fn enable_stake_weighted_challenges() {
+    assert_ok!(EthereumEvents::set_stake_weighted_challenges(RawOrigin::Root.into(), true));
+}
+
+mod set_stake_weighted_challenges {
+    use super::*;
+
+    #[test]
+    fn updates_the_option() {
+        let mut ext = ExtBuilder::build_default().as_externality();
+        ext.execute_with(|| {
+            enable_stake_weighted_challenges();
+
+            assert!(EthereumEvents::stake_weighted_challenges());
+            assert!(System::events().iter().any(|record| record.event ==
+                Event::EthereumEvents(
+                    crate::Event::<TestRuntime>::StakeWeightedChallengesUpdated { enabled: true }
+                )));
+        });
+    }
+
+    #[test]
+    fn fails_for_a_non_admin_origin() {
+        let mut ext = ExtBuilder::build_default().as_externality();
+        ext.execute_with(|| {
+            assert_noop!(
+                EthereumEvents::set_stake_weighted_challenges(
+                    RuntimeOrigin::signed(account_id_0()),
+                    true
+                ),
+                BadOrigin
+            );
+        });
+    }
+}
+
+mod when_challenges_are_weighted_by_stake {
+    use super::*;
+
+    #[test]
+    fn a_challenger_backed_by_enough_stake_succeeds_alone() {
+        let mut ext = ExtBuilder::build_default().with_validators().as_externality();
+        ext.execute_with(|| {
+            set_stakes();
+            challenge_by(vec![validator(0)]);
+            assert!(!EthereumEvents::is_challenge_successful(&check_result_requiring(0)));
+
+            enable_stake_weighted_challenges();
+
+            assert!(EthereumEvents::is_challenge_successful(&check_result_requiring(0)));
+        });
+    }
+
+    #[test]
+    fn the_minimum_number_of_challenges_still_applies() {
+        let mut ext = ExtBuilder::build_default().with_validators().as_externality();
+        ext.execute_with(|| {
+            set_stakes();
+            enable_stake_weighted_challenges();
+
+            challenge_by(vec![validator(0)]);
+            assert!(!EthereumEvents::is_challenge_successful(&check_result_requiring(1)));
+
+            challenge_by(vec![validator(0), validator(1)]);
+            assert!(EthereumEvents::is_challenge_successful(&check_result_requiring(1)));
+        });
+    }
+
+    #[test]
+    fn challengers_backed_by_too_little_stake_fail() {
+        let mut ext = ExtBuilder::build_default().with_validators().as_externality();
+        ext.execute_with(|| {
+            set_stakes();
+            challenge_by(vec![validator(1), validator(2)]);
+            assert!(EthereumEvents::is_challenge_successful(&check_result()));
+
+            enable_stake_weighted_challenges();
+
+            assert!(!EthereumEvents::is_challenge_successful(&check_result()));
+        });
+    }
+
+    #[test]
+    fn challengers_are_counted_if_the_validators_have_no_stake() {
+        let mut ext = ExtBuilder::build_default().with_validators().as_externality();
+        ext.execute_with(|| {
+            enable_stake_weighted_challenges();
+
+            challenge_by(vec![validator(1), validator(2)]);
+
+            assert!(EthereumEvents::is_challenge_successful(&check_result()));
+        });
+    }
+}
@@ -0,0 +1,61 @@
+// Copyright 2024 Aventus (UK) Ltd.
+#![cfg(test)]
+
+use crate::{mock::*, *};
+use frame_support::{assert_err, assert_ok};
+
+fn queue_events() {
+    EthereumEvents::insert_to_unchecked_events(&EthereumEvents::get_event_id(10), 1);
+    EthereumEvents::populate_events_pending_challenge(&checked_by(), 2);
+    EthereumEvents::set_ingress_counter(2);
+}
+
+mod try_state {
+    use super::*;
+
+    #[test]
+    fn passes_with_queued_events() {
+        let mut ext = ExtBuilder::build_default().with_genesis_config().as_externality();
+        ext.execute_with(|| {
+            queue_events();
+
+            assert_ok!(EthereumEvents::do_try_state());
+        });
+    }
+
+    #[test]
+    fn fails_when_a_queued_event_was_processed() {
+        let mut ext = ExtBuilder::build_default().with_genesis_config().as_externality();
+        ext.execute_with(|| {
+            queue_events();
+            EthereumEvents::insert_to_processed_events(&EthereumEvents::get_event_id(10));
+
+            assert_err!(EthereumEvents::do_try_state(), "Queued event has already been processed");
+        });
+    }
+
+    #[test]
+    fn fails_when_an_event_is_queued_twice() {
+        let mut ext = ExtBuilder::build_default().with_genesis_config().as_externality();
+        ext.execute_with(|| {
+            queue_events();
+            EthereumEvents::insert_to_unchecked_events(&EthereumEvents::get_event_id(0), 2);
+
+            assert_err!(EthereumEvents::do_try_state(), "Event is queued more than once");
+        });
+    }
+
+    #[test]
+    fn fails_when_an_ingress_counter_is_ahead_of_total_ingresses() {
+        let mut ext = ExtBuilder::build_default().with_genesis_config().as_externality();
+        ext.execute_with(|| {
+            queue_events();
+            EthereumEvents::set_ingress_counter(1);
+
+            assert_err!(
+                EthereumEvents::do_try_state(),
+                "Queued event has an ingress counter greater than TotalIngresses"
+            );
+        });
+    }
+}
@@ -0,0 +1,110 @@
+// Copyright 2024 Aventus Network Services (UK) Ltd.
+
+#![cfg(test)]
+
+use crate::{mock::*, *};
+use frame_support::{
+    assert_noop,
+    traits::{Get, Hooks},
+    unsigned::ValidateUnsigned,
+    weights::Weight,
+};
+use sp_runtime::{testing::TestSignature, transaction_validity::TransactionValidityError};
+
+const CHALLENGE: EthereumEventsUnsignedCall = EthereumEventsUnsignedCall::ChallengeEvent;
+
+fn challenge_call(validator: Validator<AuthorityId, AccountId>) -> Call<TestRuntime> {
+    Call::challenge_event {
+        challenge: Challenge::new(
+            EthEventId {
+                signature: ValidEvents::Lifted.signature(),
+                transaction_hash: H256::zero(),
+            },
+            ChallengeReason::IncorrectResult,
+            validator.account_id.clone(),
+        ),
+        ingress_counter: 1,
+        signature: TestSignature(0, vec![]),
+        validator,
+    }
+}
+
+fn use_allowance(validator: &AccountId) {
+    UnsignedSubmissions::<TestRuntime>::insert(
+        validator,
+        CHALLENGE,
+        SubmissionWindow {
+            start: System::block_number(),
+            submissions: MaxUnsignedSubmissions::get(),
+            saturated_windows: 0,
+        },
+    );
+}
+
+#[test]
+fn transactions_are_rejected_once_the_validator_used_its_allowance() {
+    eth_events_test_with_validators().execute_with(|| {
+        let validator = EthereumEvents::validators()[0].clone();
+        use_allowance(&validator.account_id);
+
+        assert_noop!(
+            EthereumEvents::validate_unsigned(TransactionSource::Local, &challenge_call(validator)),
+            TransactionValidityError::Invalid(InvalidTransaction::Custom(
+                ERROR_CODE_UNSIGNED_RATE_LIMIT_REACHED
+            ))
+        );
+    });
+}
+
+#[test]
+fn the_allowance_of_a_validator_does_not_limit_other_validators() {
+    eth_events_test_with_validators().execute_with(|| {
+        use_allowance(&EthereumEvents::validators()[0].account_id);
+        let other_validator = EthereumEvents::validators()[1].clone();
+
+        assert!(EthereumEvents::unsigned_submission_allowed(
+            &other_validator.account_id,
+            CHALLENGE
+        ));
+        assert_noop!(
+            EthereumEvents::validate_unsigned(
+                TransactionSource::Local,
+                &challenge_call(other_validator)
+            ),
+            TransactionValidityError::Invalid(InvalidTransaction::Custom(
+                ERROR_CODE_EVENT_NOT_IN_PENDING_CHALLENGES
+            ))
+        );
+    });
+}
+
+#[test]
+fn the_allowance_of_a_call_type_does_not_limit_other_call_types() {
+    eth_events_test_with_validators().execute_with(|| {
+        let validator = EthereumEvents::validators()[0].clone();
+        use_allowance(&validator.account_id);
+
+        assert!(EthereumEvents::unsigned_submission_allowed(
+            &validator.account_id,
+            EthereumEventsUnsignedCall::ProcessEvent
+        ));
+    });
+}
+
+#[test]
+fn expired_windows_are_pruned_when_the_pallet_is_idle() {
+    eth_events_test_with_validators().execute_with(|| {
+        let validator = EthereumEvents::validators()[0].account_id.clone();
+        let other_validator = EthereumEvents::validators()[1].account_id.clone();
+        System::set_block_number(1);
+        use_allowance(&validator);
+        System::set_block_number(11);
+        use_allowance(&other_validator);
+
+        System::set_block_number(30);
+        EthereumEvents::on_idle(System::block_number(), Weight::MAX);
+
+        assert!(!UnsignedSubmissions::<TestRuntime>::contains_key(validator, CHALLENGE));
+        assert!(UnsignedSubmissions::<TestRuntime>::contains_key(other_validator, CHALLENGE));
+    });
+}
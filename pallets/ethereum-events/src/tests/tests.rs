@@ -27,6 +27,12 @@ mod test_get_contract_address_for {
             ValidEvents::AvtLowerClaimed => H160::from(BRIDGE_CONTRACT),
             ValidEvents::LiftedToPredictionMarket => H160::from(BRIDGE_CONTRACT),
             ValidEvents::Erc20DirectTransfer => H160::from(BRIDGE_CONTRACT),
+            ValidEvents::LiftedWithVesting => H160::from(BRIDGE_CONTRACT),
+            ValidEvents::LiftedToDestination => H160::from(BRIDGE_CONTRACT),
+            ValidEvents::SftMint => H160::from(NFT_CONTRACT),
+            ValidEvents::SftTransferTo => H160::from(NFT_CONTRACT),
+            ValidEvents::NftBatchListingScheduled => H160::from(NFT_CONTRACT),
+            ValidEvents::NftOfferAccepted => H160::from(NFT_CONTRACT),
         }
     }
 
@@ -449,6 +455,71 @@ fn test_compute_result_invalid_contract_address() {
     });
 }
 
+mod lifts_from_an_additional_bridge_contract {
+    use super::*;
+
+    const LIFTED_TOKEN: H160 = H160(hex_literal::hex!("23aaf097c241897060c0a6b8aae61af5ea48cea3"));
+
+    fn compute_lift_result(contract_address: &H160) -> EthEventCheckResult<u64, AccountId> {
+        let unchecked_event = &EthEventId {
+            signature: ValidEvents::Lifted.signature(),
+            transaction_hash: H256::random(),
+        };
+        let log_data = "0x0000000000000000000000000000000000000000000000000000000005f5e100";
+        let event_topics = "0x00000000000000000000000023aaf097c241897060c0a6b8aae61af5ea48cea3\",
+                          \"0x689d5b000758030ea25304346869b002a345e7647ec5784b8af986e24e971303\",
+                          \"0x689d5b000758030ea25304346869b002a345e7647ec5784b8af986e24e971303";
+        let json = test_json(
+            &unchecked_event.transaction_hash,
+            &unchecked_event.signature,
+            contract_address,
+            log_data,
+            event_topics,
+            GOOD_STATUS,
+            GOOD_BLOCK_CONFIRMATIONS,
+        );
+        EthereumEvents::compute_result(1, Ok(json), unchecked_event, &account_id_1())
+    }
+
+    #[test]
+    fn are_valid_for_the_tokens_registered_for_it() {
+        let mut ext = ExtBuilder::build_default().as_externality();
+        ext.execute_with(|| {
+            EthereumEvents::setup_mock_ethereum_contracts_address();
+            let new_bridge_contract = H160::from([1; 20]);
+            pallet_avn::BridgeContractTokens::<TestRuntime>::insert(
+                new_bridge_contract,
+                LIFTED_TOKEN,
+                (),
+            );
+
+            let result = compute_lift_result(&new_bridge_contract);
+
+            assert_eq!(result.result, CheckResult::Ok);
+            assert!(result.event.event_data.is_valid());
+        });
+    }
+
+    #[test]
+    fn are_invalid_for_other_tokens() {
+        let mut ext = ExtBuilder::build_default().as_externality();
+        ext.execute_with(|| {
+            EthereumEvents::setup_mock_ethereum_contracts_address();
+            let new_bridge_contract = H160::from([1; 20]);
+            pallet_avn::BridgeContractTokens::<TestRuntime>::insert(
+                new_bridge_contract,
+                H160::from([2; 20]),
+                (),
+            );
+
+            let result = compute_lift_result(&new_bridge_contract);
+
+            assert_eq!(result.result, CheckResult::Invalid);
+            assert_eq!(result.event.event_data, EventData::EmptyEvent);
+        });
+    }
+}
+
 #[test]
 fn test_compute_result_invalid_log_data() {
     let mut ext = ExtBuilder::build_default().as_externality();
@@ -0,0 +1,59 @@
+//! Invariants of the ethereum events state, checked by try-runtime before and after runtime
+//! upgrades
+
+use crate::{
+    Config, EventsPendingChallenge, MaxEventsPendingChallenges, MaxUncheckedEvents, Pallet,
+    ProcessedEvents, TotalIngresses, UncheckedEvents,
+};
+use frame_support::{ensure, traits::Get};
+use sp_runtime::DispatchError;
+use sp_std::prelude::*;
+
+impl<T: Config> Pallet<T> {
+    pub(crate) fn do_try_state() -> Result<(), DispatchError> {
+        Self::check_queue_bounds()?;
+        Self::check_queued_events()
+    }
+
+    fn check_queue_bounds() -> Result<(), DispatchError> {
+        ensure!(
+            <UncheckedEvents<T>>::decode_len().unwrap_or_default() <=
+                MaxUncheckedEvents::get() as usize,
+            "UncheckedEvents exceeds its bound"
+        );
+        ensure!(
+            <EventsPendingChallenge<T>>::decode_len().unwrap_or_default() <=
+                MaxEventsPendingChallenges::get() as usize,
+            "EventsPendingChallenge exceeds its bound"
+        );
+        Ok(())
+    }
+
+    /// An event can only be queued once, and only until it is processed
+    fn check_queued_events() -> Result<(), DispatchError> {
+        let total_ingresses = <TotalIngresses<T>>::get();
+        let queued_events = <UncheckedEvents<T>>::get()
+            .into_iter()
+            .map(|(event_id, ingress_counter, _)| (event_id, ingress_counter))
+            .chain(<EventsPendingChallenge<T>>::get().into_iter().map(
+                |(check_result, ingress_counter, _)| (check_result.event.event_id, ingress_counter),
+            ))
+            .collect::<Vec<_>>();
+
+        let mut seen_events = Vec::new();
+        for (event_id, ingress_counter) in queued_events {
+            ensure!(
+                ingress_counter <= total_ingresses,
+                "Queued event has an ingress counter greater than TotalIngresses"
+            );
+            ensure!(
+                !<ProcessedEvents<T>>::contains_key(&event_id),
+                "Queued event has already been processed"
+            );
+            ensure!(!seen_events.contains(&event_id), "Event is queued more than once");
+            seen_events.push(event_id);
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,33 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+use codec::{Codec, Decode, Encode};
+use scale_info::TypeInfo;
+use sp_core::{H160, U256};
+use sp_runtime::RuntimeDebug;
+use sp_std::vec::Vec;
+
+/// What a wallet needs to show an nft
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct NftSummary {
+    pub nft_id: U256,
+    pub info_id: U256,
+    /// Tier 1 authority of the nft
+    pub t1_authority: H160,
+    /// Reference to the metadata of the nft
+    pub unique_external_ref: Vec<u8>,
+    /// Whether the nft is locked by a pending bridge operation
+    pub is_locked: bool,
+}
+
+sp_api::decl_runtime_apis! {
+
+    #[api_version(1)]
+    pub trait NftManagerApi<AccountId>
+    where
+        AccountId: Codec,
+    {
+        /// A page of at most `limit` nfts owned by `account`, starting after the nft
+        /// `start_key`. The first page has no `start_key` and the last one has fewer than `limit`
+        /// nfts. `limit` is capped by the runtime.
+        fn nfts_by_owner(account: AccountId, start_key: Option<U256>, limit: u32) -> Vec<NftSummary>;
+    }
+}
@@ -0,0 +1,121 @@
+// Copyright 2024 Aventus Network Services (UK) Ltd.
+
+//! Operator approvals, in the style of ERC-721.
+//!
+//! The owner of an nft can approve an operator, such as a marketplace or a relayer, to list, sell
+//! and cancel the listing of that nft on their behalf. An owner can also approve an operator for a
+//! whole collection, which covers every nft of an `NftInfo` the owner holds, now or later. The
+//! approval of a single nft is cleared when the nft changes owner, while the approvals of a
+//! collection stay with the owner that gave them.
+
+use crate::*;
+
+pub const SIGNED_APPROVE_NFT_CONTEXT: &'static [u8] = b"authorization for approve nft operation";
+pub const SIGNED_APPROVE_COLLECTION_CONTEXT: &'static [u8] =
+    b"authorization for approve collection operation";
+
+impl<T: Config> Pallet<T> {
+    pub(crate) fn do_approve_nft(
+        owner: T::AccountId,
+        nft_id: NftId,
+        operator: T::AccountId,
+    ) -> DispatchResult {
+        ensure!(owner != operator, Error::<T>::CannotApproveOwner);
+        let nft = Self::try_get_nft(&nft_id)?;
+        ensure!(nft.owner == owner, Error::<T>::SenderIsNotOwner);
+
+        <NftApprovals<T>>::insert(nft_id, &operator);
+
+        Self::deposit_event(Event::<T>::NftApproved { nft_id, owner, operator });
+
+        Ok(())
+    }
+
+    pub(crate) fn do_revoke_nft_approval(owner: T::AccountId, nft_id: NftId) -> DispatchResult {
+        let nft = Self::try_get_nft(&nft_id)?;
+        ensure!(nft.owner == owner, Error::<T>::SenderIsNotOwner);
+        ensure!(<NftApprovals<T>>::contains_key(nft_id), Error::<T>::ApprovalNotFound);
+
+        <NftApprovals<T>>::remove(nft_id);
+
+        Self::deposit_event(Event::<T>::NftApprovalRevoked { nft_id, owner });
+
+        Ok(())
+    }
+
+    pub(crate) fn do_approve_collection(
+        owner: T::AccountId,
+        info_id: NftInfoId,
+        operator: T::AccountId,
+    ) -> DispatchResult {
+        ensure!(owner != operator, Error::<T>::CannotApproveOwner);
+        ensure!(<NftInfos<T>>::contains_key(info_id), Error::<T>::NftInfoMissing);
+
+        <CollectionApprovals<T>>::insert((&owner, info_id), &operator, ());
+
+        Self::deposit_event(Event::<T>::CollectionApproved { info_id, owner, operator });
+
+        Ok(())
+    }
+
+    pub(crate) fn do_revoke_collection_approval(
+        owner: T::AccountId,
+        info_id: NftInfoId,
+        operator: T::AccountId,
+    ) -> DispatchResult {
+        ensure!(
+            <CollectionApprovals<T>>::contains_key((&owner, info_id), &operator),
+            Error::<T>::ApprovalNotFound
+        );
+
+        <CollectionApprovals<T>>::remove((&owner, info_id), &operator);
+
+        Self::deposit_event(Event::<T>::CollectionApprovalRevoked { info_id, owner, operator });
+
+        Ok(())
+    }
+
+    /// Returns true if `operator` can act for the owner of `nft`
+    pub fn is_approved_operator(operator: &T::AccountId, nft: &Nft<T::AccountId>) -> bool {
+        Self::nft_approval(nft.nft_id).as_ref() == Some(operator) ||
+            <CollectionApprovals<T>>::contains_key((&nft.owner, nft.info_id), operator)
+    }
+
+    pub(crate) fn ensure_owner_or_operator(
+        sender: &T::AccountId,
+        nft: &Nft<T::AccountId>,
+    ) -> DispatchResult {
+        ensure!(
+            &nft.owner == sender || Self::is_approved_operator(sender, nft),
+            Error::<T>::SenderIsNotOwner
+        );
+        Ok(())
+    }
+
+    pub(crate) fn encode_approve_nft_params(
+        proof: &Proof<T::Signature, T::AccountId>,
+        nft_id: &NftId,
+        operator: &T::AccountId,
+        nonce: &u64,
+    ) -> Vec<u8> {
+        return (SIGNING_DOMAIN, SIGNED_APPROVE_NFT_CONTEXT, &proof.relayer, nft_id, operator, nonce)
+            .encode()
+    }
+
+    pub(crate) fn encode_approve_collection_params(
+        proof: &Proof<T::Signature, T::AccountId>,
+        info_id: &NftInfoId,
+        operator: &T::AccountId,
+        nonce: &u64,
+    ) -> Vec<u8> {
+        return (
+            SIGNING_DOMAIN,
+            SIGNED_APPROVE_COLLECTION_CONTEXT,
+            &proof.relayer,
+            info_id,
+            operator,
+            nonce,
+        )
+            .encode()
+    }
+}
@@ -0,0 +1,284 @@
+// Copyright 2024 Aventus Network Services (UK) Ltd.
+
+//! Timed auctions of NFTs, settled natively on AvN.
+//!
+//! An auctioned NFT is listed for sale on the `Auction` market, so it can't be listed or sold on
+//! another market until the auction ends. Bids are reserved from the bidders, and an outbid bidder
+//! gets their bid back straight away. At the start of the block the auction ends in, the highest
+//! bid pays the royalties of the NFT and the seller, and the NFT is transferred to the highest
+//! bidder. If the NFT can't be transferred, the auction is cancelled and the bid is returned.
+//! Royalties of a tier 1 address that is not registered to an AvN account are held in the royalty
+//! pot until the owner of the address registers it and claims them.
+//! The seller can cancel an auction as long as nobody has bid on it.
+
+use crate::*;
+use frame_support::{
+    traits::{BalanceStatus, Currency, ExistenceRequirement, ReservableCurrency},
+    PalletId,
+};
+use frame_system::pallet_prelude::BlockNumberFor;
+use sp_avn_common::EthereumAddressMapping;
+use sp_runtime::traits::{AccountIdConversion, Saturating, Zero};
+
+/// The account holding the royalties of tier 1 addresses that are not registered yet
+pub const ROYALTY_POT_ID: PalletId = PalletId(*b"av/nftry");
+
+pub type BalanceOf<T> =
+    <<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+pub type AuctionOf<T> =
+    Auction<<T as frame_system::Config>::AccountId, BalanceOf<T>, BlockNumberFor<T>>;
+
+#[derive(Encode, Decode, Clone, Debug, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+pub struct Bid<AccountId, Balance> {
+    pub bidder: AccountId,
+    /// The amount reserved from the bidder
+    pub amount: Balance,
+}
+
+#[derive(Encode, Decode, Clone, Debug, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+pub struct Auction<AccountId, Balance, BlockNumber> {
+    /// Owner of the NFT when the auction was created
+    pub seller: AccountId,
+    /// Minimum amount of the first bid
+    pub reserve_price: Balance,
+    /// The auction is settled at the start of this block
+    pub end_block: BlockNumber,
+    pub highest_bid: Option<Bid<AccountId, Balance>>,
+}
+
+impl<T: Config> Pallet<T> {
+    pub(crate) fn do_create_auction(
+        seller: T::AccountId,
+        nft_id: NftId,
+        reserve_price: BalanceOf<T>,
+        duration: BlockNumberFor<T>,
+    ) -> DispatchResult {
+        Self::validate_nft_can_be_listed(seller.clone(), nft_id)?;
+        // The proceeds go to the seller, so an approved operator can't auction the nft
+        ensure!(Self::try_get_nft(&nft_id)?.owner == seller, Error::<T>::SenderIsNotOwner);
+        ensure!(!reserve_price.is_zero(), Error::<T>::AuctionReservePriceIsZero);
+        ensure!(
+            duration >= T::MinAuctionDuration::get() && duration <= T::MaxAuctionDuration::get(),
+            Error::<T>::InvalidAuctionDuration
+        );
+
+        let end_block = <frame_system::Pallet<T>>::block_number().saturating_add(duration);
+        <AuctionsEndingAt<T>>::try_mutate(end_block, |nft_ids| nft_ids.try_push(nft_id))
+            .map_err(|_| Error::<T>::TooManyAuctionsEndingInBlock)?;
+
+        Self::open_nft_for_sale(&nft_id, &NftSaleType::Auction);
+        <Auctions<T>>::insert(
+            nft_id,
+            Auction { seller: seller.clone(), reserve_price, end_block, highest_bid: None },
+        );
+
+        Self::deposit_event(Event::<T>::AuctionCreated {
+            nft_id,
+            seller,
+            reserve_price,
+            end_block,
+        });
+
+        Ok(())
+    }
+
+    pub(crate) fn do_bid(
+        bidder: T::AccountId,
+        nft_id: NftId,
+        amount: BalanceOf<T>,
+    ) -> DispatchResult {
+        let mut auction = Self::auction(&nft_id).ok_or(Error::<T>::AuctionDoesNotExist)?;
+        ensure!(
+            <frame_system::Pallet<T>>::block_number() < auction.end_block,
+            Error::<T>::AuctionHasEnded
+        );
+        ensure!(bidder != auction.seller, Error::<T>::SellerCannotBid);
+        Self::ensure_not_frozen(&nft_id)?;
+        ensure!(amount >= auction.reserve_price, Error::<T>::BidBelowReservePrice);
+
+        let outbid = auction.highest_bid.take();
+        let amount_to_reserve = match &outbid {
+            Some(highest_bid) => {
+                ensure!(amount > highest_bid.amount, Error::<T>::BidNotAboveHighestBid);
+                if highest_bid.bidder == bidder {
+                    // Only the increase needs reserving when bidders raise their own bid
+                    amount.saturating_sub(highest_bid.amount)
+                } else {
+                    amount
+                }
+            },
+            None => amount,
+        };
+
+        T::Currency::reserve(&bidder, amount_to_reserve)
+            .map_err(|_| Error::<T>::InsufficientBalanceForBid)?;
+        if let Some(highest_bid) = outbid.filter(|highest_bid| highest_bid.bidder != bidder) {
+            T::Currency::unreserve(&highest_bid.bidder, highest_bid.amount);
+        }
+
+        auction.highest_bid = Some(Bid { bidder: bidder.clone(), amount });
+        <Auctions<T>>::insert(nft_id, auction);
+
+        Self::deposit_event(Event::<T>::AuctionBid { nft_id, bidder, amount });
+
+        Ok(())
+    }
+
+    pub(crate) fn do_cancel_auction(sender: T::AccountId, nft_id: NftId) -> DispatchResult {
+        let auction = Self::auction(&nft_id).ok_or(Error::<T>::AuctionDoesNotExist)?;
+        ensure!(auction.seller == sender, Error::<T>::SenderIsNotOwner);
+        ensure!(auction.highest_bid.is_none(), Error::<T>::AuctionHasBids);
+
+        <AuctionsEndingAt<T>>::mutate(auction.end_block, |nft_ids| {
+            nft_ids.retain(|id| id != &nft_id)
+        });
+        <Auctions<T>>::remove(nft_id);
+        Self::unlist_nft_for_sale(nft_id)?;
+
+        Self::deposit_event(Event::<T>::AuctionCancelled { nft_id });
+
+        Ok(())
+    }
+
+    /// Settles all the auctions that end in `block`
+    pub(crate) fn settle_auctions_ending_at(block: BlockNumberFor<T>) -> Weight {
+        let mut weight = T::DbWeight::get().reads_writes(1, 1);
+
+        for nft_id in <AuctionsEndingAt<T>>::take(block) {
+            let royalties_count = Self::settle_auction(&nft_id);
+            weight =
+                weight.saturating_add(<T as Config>::WeightInfo::settle_auction(royalties_count));
+        }
+
+        weight
+    }
+
+    /// Sells the NFT to the highest bidder, if any, and returns the number of royalties the NFT
+    /// has
+    pub(crate) fn settle_auction(nft_id: &NftId) -> u32 {
+        let auction = match <Auctions<T>>::take(nft_id) {
+            Some(auction) => auction,
+            None => return 0,
+        };
+
+        let highest_bid = match auction.highest_bid {
+            Some(highest_bid) => highest_bid,
+            None => {
+                if let Err(e) = Self::unlist_nft_for_sale(*nft_id) {
+                    log::error!("💔 Error unlisting expired auction of nft {:?}: {:?}", nft_id, e);
+                }
+                Self::deposit_event(Event::<T>::AuctionEndedWithoutBids { nft_id: *nft_id });
+                return 0
+            },
+        };
+
+        // The royalties are worked out before the transfer, which may change them
+        let royalties = Self::royalty_payments(nft_id, highest_bid.amount);
+
+        // Nothing is paid unless the winner gets the nft, e.g. it was frozen during the auction
+        if let Err(e) = Self::transfer_nft(nft_id, &highest_bid.bidder) {
+            log::error!("💔 Error transferring auctioned nft {:?}: {:?}", nft_id, e);
+            T::Currency::unreserve(&highest_bid.bidder, highest_bid.amount);
+            if <NftOpenForSale<T>>::contains_key(nft_id) {
+                if let Err(e) = Self::unlist_nft_for_sale(*nft_id) {
+                    log::error!(
+                        "💔 Error unlisting cancelled auction of nft {:?}: {:?}",
+                        nft_id,
+                        e
+                    );
+                }
+            }
+            Self::deposit_event(Event::<T>::AuctionCancelled { nft_id: *nft_id });
+            return 0
+        }
+
+        let mut seller_proceeds = highest_bid.amount;
+        for (recipient_t1_address, royalty_amount) in royalties.iter().cloned() {
+            let paid = match T::EthereumAddressMapping::account_of(&recipient_t1_address) {
+                Some(recipient) =>
+                    Self::pay_from_bid(&highest_bid.bidder, &recipient, royalty_amount),
+                None =>
+                    Self::hold_royalty(&highest_bid.bidder, recipient_t1_address, royalty_amount),
+            };
+            if paid {
+                seller_proceeds = seller_proceeds.saturating_sub(royalty_amount);
+            }
+        }
+
+        if !Self::pay_from_bid(&highest_bid.bidder, &auction.seller, seller_proceeds) {
+            // Don't leave the rest of the bid reserved forever
+            T::Currency::unreserve(&highest_bid.bidder, seller_proceeds);
+        }
+
+        Self::deposit_event(Event::<T>::AuctionSettled {
+            nft_id: *nft_id,
+            seller: auction.seller,
+            winner: highest_bid.bidder,
+            price: highest_bid.amount,
+        });
+
+        royalties.len() as u32
+    }
+
+    pub fn royalty_pot_account() -> T::AccountId {
+        ROYALTY_POT_ID.into_account_truncating()
+    }
+
+    /// Moves a royalty of `recipient_t1_address` from the reserved bid of `bidder` to the royalty
+    /// pot, until the owner of the address claims it. Returns false if the payment failed.
+    fn hold_royalty(
+        bidder: &T::AccountId,
+        recipient_t1_address: H160,
+        amount: BalanceOf<T>,
+    ) -> bool {
+        if !Self::pay_from_bid(bidder, &Self::royalty_pot_account(), amount) {
+            return false
+        }
+
+        <UnclaimedRoyalties<T>>::mutate(recipient_t1_address, |unclaimed| {
+            *unclaimed = unclaimed.saturating_add(amount)
+        });
+        Self::deposit_event(Event::<T>::RoyaltyHeld { recipient_t1_address, amount });
+        true
+    }
+
+    pub(crate) fn do_claim_royalties(
+        sender: T::AccountId,
+        recipient_t1_address: H160,
+    ) -> DispatchResult {
+        ensure!(
+            T::EthereumAddressMapping::account_of(&recipient_t1_address).as_ref() == Some(&sender),
+            Error::<T>::SenderIsNotRoyaltyRecipient
+        );
+        let amount = <UnclaimedRoyalties<T>>::get(recipient_t1_address);
+        ensure!(!amount.is_zero(), Error::<T>::NoRoyaltiesToClaim);
+
+        T::Currency::transfer(
+            &Self::royalty_pot_account(),
+            &sender,
+            amount,
+            ExistenceRequirement::AllowDeath,
+        )?;
+        <UnclaimedRoyalties<T>>::remove(recipient_t1_address);
+
+        Self::deposit_event(Event::<T>::RoyaltiesClaimed {
+            recipient_t1_address,
+            account: sender,
+            amount,
+        });
+
+        Ok(())
+    }
+
+    /// Moves `amount` from the reserved bid of `bidder` to the free balance of `recipient`.
+    /// Returns false if the payment failed.
+    fn pay_from_bid(bidder: &T::AccountId, recipient: &T::AccountId, amount: BalanceOf<T>) -> bool {
+        match T::Currency::repatriate_reserved(bidder, recipient, amount, BalanceStatus::Free) {
+            Ok(_) => true,
+            Err(e) => {
+                log::error!("💔 Error paying {:?} from an auction bid: {:?}", amount, e);
+                false
+            },
+        }
+    }
+}
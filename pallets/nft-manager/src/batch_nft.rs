@@ -15,11 +15,11 @@
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
 use crate::{
-    keccak_256, BatchInfoId, BatchOpenForSale, Config, Decode, DispatchResult, Encode, Error,
-    EthEventId, Event, Nft, NftBatchId, NftBatches, NftEndBatchListingData, NftExternalRefBound,
-    NftInfo, NftInfoId, NftInfos, NftRoyaltiesBound, NftSaleType, NftUniqueId, Nfts, Pallet,
-    ProcessedEventsChecker, Proof, Royalty, Vec, BATCH_ID_CONTEXT, BATCH_NFT_ID_CONTEXT, H160,
-    U256,
+    keccak_256, BatchInfoId, BatchOpenForSale, BatchPriceSchedules, Config, Decode, DispatchResult,
+    Encode, Error, EthEventId, Event, Nft, NftBatchId, NftBatches, NftEndBatchListingData,
+    NftExternalRefBound, NftInfo, NftInfoId, NftInfos, NftRoyaltiesBound, NftSaleType, NftUniqueId,
+    Nfts, Pallet, ProcessedEventsChecker, Proof, Royalty, Vec, BATCH_ID_CONTEXT,
+    BATCH_NFT_ID_CONTEXT, H160, U256,
 };
 use frame_support::ensure;
 use sp_avn_common::event_types::NftMintData;
@@ -77,9 +77,11 @@ pub fn create_batch<T: Config>(
     t1_authority: H160,
     creator: T::AccountId,
 ) {
+    <MetadataAuthorities<T>>::insert(info_id, &creator);
     let info =
         NftInfo::new_batch(info_id, batch_id, royalties, t1_authority, total_supply, creator);
     <BatchInfoId<T>>::insert(batch_id, &info.info_id);
+    Pallet::<T>::add_batch_to_new_collection(&info);
     <NftInfos<T>>::insert(info.info_id, info);
 }
 
@@ -91,6 +93,7 @@ pub fn encode_create_batch_params<T: Config>(
     nonce: &u64,
 ) -> Vec<u8> {
     return (
+        SIGNING_DOMAIN,
         SIGNED_CREATE_BATCH_CONTEXT,
         &proof.relayer,
         total_supply,
@@ -109,6 +112,7 @@ pub fn encode_mint_batch_nft_params<T: Config>(
     owner: &T::AccountId,
 ) -> Vec<u8> {
     return (
+        SIGNING_DOMAIN,
         SIGNED_MINT_BATCH_NFT_CONTEXT,
         &proof.relayer,
         batch_id,
@@ -125,7 +129,15 @@ pub fn encode_list_batch_for_sale_params<T: Config>(
     market: &NftSaleType,
     nonce: &u64,
 ) -> Vec<u8> {
-    return (SIGNED_LIST_BATCH_FOR_SALE_CONTEXT, &proof.relayer, batch_id, market, nonce).encode()
+    return (
+        SIGNING_DOMAIN,
+        SIGNED_LIST_BATCH_FOR_SALE_CONTEXT,
+        &proof.relayer,
+        batch_id,
+        market,
+        nonce,
+    )
+        .encode()
 }
 
 pub fn encode_end_batch_sale_params<T: Config>(
@@ -133,7 +145,7 @@ pub fn encode_end_batch_sale_params<T: Config>(
     batch_id: &NftBatchId,
     nonce: &u64,
 ) -> Vec<u8> {
-    return (SIGNED_END_BATCH_SALE_CONTEXT, &proof.relayer, batch_id, nonce).encode()
+    return (SIGNING_DOMAIN, SIGNED_END_BATCH_SALE_CONTEXT, &proof.relayer, batch_id, nonce).encode()
 }
 
 pub fn process_mint_batch_nft_event<T: Config>(
@@ -227,6 +239,7 @@ pub fn end_batch_listing<T: Config>(batch_id: &NftBatchId, market: NftSaleType)
     validate_end_batch_listing_request::<T>(&batch_id)?;
 
     <BatchOpenForSale<T>>::remove(batch_id);
+    <BatchPriceSchedules<T>>::remove(batch_id);
     <crate::Pallet<T>>::deposit_event(Event::<T>::BatchSaleEnded {
         batch_nft_id: *batch_id,
         sale_type: market,
@@ -21,7 +21,8 @@
 #![cfg(feature = "runtime-benchmarks")]
 
 use super::*;
-use frame_benchmarking::{benchmarks, impl_benchmark_test_suite};
+use frame_benchmarking::{account, benchmarks, impl_benchmark_test_suite};
+use frame_support::traits::Currency;
 use frame_system::{EventRecord, RawOrigin};
 use hex_literal::hex;
 use pallet_avn::{self as avn};
@@ -67,7 +68,11 @@ fn get_proof<T: Config>(
 }
 
 fn bounded_unique_external_ref() -> BoundedVec<u8, NftExternalRefBound> {
-    BoundedVec::try_from(String::from("Offchain location of NFT").into_bytes())
+    bounded_unique_external_ref_of("Offchain location of NFT")
+}
+
+fn bounded_unique_external_ref_of(external_ref: &str) -> BoundedVec<u8, NftExternalRefBound> {
+    BoundedVec::try_from(String::from(external_ref).into_bytes())
         .expect("Unique external reference bound was exceeded.")
 }
 
@@ -115,6 +120,7 @@ impl<T: Config> MintSingleNft<T> {
         let t1_authority = H160(hex!("0000000000000000000000000000000000000001"));
 
         let signed_payload = (
+            SIGNING_DOMAIN,
             SIGNED_MINT_SINGLE_NFT_CONTEXT,
             &relayer_account_id,
             &unique_external_ref,
@@ -167,6 +173,218 @@ impl<T: Config> MintSingleNft<T> {
     }
 }
 
+struct BatchMintNft<T: Config> {
+    relayer: T::AccountId,
+    nft_owner: T::AccountId,
+    nfts: Vec<MintParams>,
+    t1_authority: H160,
+    signature: Vec<u8>,
+}
+
+impl<T: Config> BatchMintNft<T> {
+    fn new(number_of_nfts: u32) -> Self {
+        let relayer_account_id = get_relayer::<T>();
+        let (nft_owner_key_pair, nft_owner_account_id) = get_user_account::<T>();
+
+        let nfts = (0..number_of_nfts)
+            .map(|i| MintParams {
+                unique_external_ref: (String::from("Offchain location of NFT ") + &i.to_string())
+                    .into_bytes(),
+                royalties: MintSingleNft::<T>::setup_royalties(MAX_NUMBER_OF_ROYALTIES),
+            })
+            .collect::<Vec<_>>();
+        let t1_authority = H160(hex!("0000000000000000000000000000000000000001"));
+
+        let signed_payload = (
+            SIGNING_DOMAIN,
+            SIGNED_BATCH_MINT_NFT_CONTEXT,
+            &relayer_account_id,
+            &nfts,
+            t1_authority,
+        );
+        let signature =
+            nft_owner_key_pair.sign(&signed_payload.encode().as_slice()).unwrap().encode();
+
+        return BatchMintNft {
+            relayer: relayer_account_id,
+            nft_owner: nft_owner_account_id,
+            nfts,
+            t1_authority,
+            signature,
+        }
+    }
+
+    fn generate_signed_batch_mint_nft(&self) -> <T as Config>::RuntimeCall {
+        let proof: Proof<T::Signature, T::AccountId> =
+            get_proof::<T>(self.nft_owner.clone(), self.relayer.clone(), &self.signature);
+        return Call::signed_batch_mint_nft {
+            proof,
+            nfts: self.nfts.clone(),
+            t1_authority: self.t1_authority,
+        }
+        .into()
+    }
+
+    fn assert_minted(&self) {
+        assert_eq!(self.nfts.len() as u128, <NextInfoId<T>>::get().as_u128());
+        for nft in self.nfts.iter() {
+            let bounded_ref =
+                BoundedVec::<u8, NftExternalRefBound>::try_from(nft.unique_external_ref.clone())
+                    .unwrap();
+            assert_eq!(true, <UsedExternalReferences<T>>::get(bounded_ref));
+        }
+    }
+}
+
+struct SemiFungible<T: Config> {
+    owner: T::AccountId,
+    buyer: T::AccountId,
+    sft_id: SftId,
+    unique_external_ref: Vec<u8>,
+    royalties: Vec<Royalty>,
+    t1_authority: H160,
+    supply: u128,
+}
+
+impl<T: Config> SemiFungible<T> {
+    fn new(number_of_royalties: u32) -> Self {
+        let t1_authority = H160(hex!("0000000000000000000000000000000000000001"));
+        let sft_id =
+            Pallet::<T>::generate_nft_id_single_mint(&t1_authority, Pallet::<T>::next_unique_id());
+
+        return SemiFungible {
+            owner: account("owner", 0, 0),
+            buyer: account("buyer", 1, 1),
+            sft_id,
+            unique_external_ref: String::from("Offchain location of SFT").into_bytes(),
+            royalties: MintSingleNft::<T>::setup_royalties(number_of_royalties),
+            t1_authority,
+            supply: 1_000u128,
+        }
+    }
+
+    fn minted(self) -> Self {
+        Pallet::<T>::do_mint_sft(
+            self.owner.clone(),
+            self.unique_external_ref.clone(),
+            self.royalties.clone(),
+            self.t1_authority,
+            self.supply,
+        )
+        .unwrap();
+        return self
+    }
+
+    fn listed(self, market: NftSaleType) -> (Self, SftListingId) {
+        let listing_id = <NextSftListingId<T>>::get();
+        Pallet::<T>::do_list_sft_for_sale(self.owner.clone(), self.sft_id, self.supply, market)
+            .unwrap();
+        return (self, listing_id)
+    }
+}
+
+fn create_collection_for_setup<T: Config>() -> (T::AccountId, CollectionId) {
+    let (_, owner) = get_user_account::<T>();
+    let collection_id = <NextCollectionId<T>>::get();
+    Pallet::<T>::do_create_collection(
+        owner.clone(),
+        H160(hex!("0000000000000000000000000000000000000001")),
+        vec![],
+        vec![],
+        true,
+    )
+    .expect("Collection is created");
+    (owner, collection_id)
+}
+
+struct ApproveOperator<T: Config> {
+    relayer: T::AccountId,
+    owner_key_pair: <T as avn::Config>::AuthorityId,
+    owner: T::AccountId,
+    operator: T::AccountId,
+    nft_id: NftId,
+    info_id: NftInfoId,
+}
+
+impl<T: Config> ApproveOperator<T> {
+    fn new() -> Self {
+        let (owner_key_pair, owner) = get_user_account::<T>();
+        let nft_id = U256::from(1u8);
+        let info_id = U256::one();
+        let t1_authority = H160(hex!("0000000000000000000000000000000000000001"));
+
+        <NftInfos<T>>::insert(info_id, NftInfo::new(info_id, BoundedVec::default(), t1_authority));
+        <Nfts<T>>::insert(
+            nft_id,
+            Nft::new(nft_id, info_id, bounded_unique_external_ref(), owner.clone()),
+        );
+
+        return ApproveOperator {
+            relayer: get_relayer::<T>(),
+            owner_key_pair,
+            owner,
+            operator: account("operator", 0, 0),
+            nft_id,
+            info_id,
+        }
+    }
+
+    fn generate_signed_approve_nft(&self) -> <T as Config>::RuntimeCall {
+        let nonce = <BatchNonces<T>>::get(&self.owner);
+        let signed_payload = (
+            SIGNING_DOMAIN,
+            SIGNED_APPROVE_NFT_CONTEXT,
+            &self.relayer,
+            &self.nft_id,
+            &self.operator,
+            nonce,
+        );
+        let signature =
+            self.owner_key_pair.sign(&signed_payload.encode().as_slice()).unwrap().encode();
+        let proof: Proof<T::Signature, T::AccountId> =
+            get_proof::<T>(self.owner.clone(), self.relayer.clone(), &signature);
+        return Call::signed_approve_nft {
+            proof,
+            nft_id: self.nft_id,
+            operator: self.operator.clone(),
+        }
+        .into()
+    }
+
+    fn generate_signed_burn_nft(&self) -> <T as Config>::RuntimeCall {
+        let nonce = <Nfts<T>>::get(&self.nft_id).unwrap().nonce;
+        let signed_payload =
+            (SIGNING_DOMAIN, SIGNED_BURN_NFT_CONTEXT, &self.relayer, &self.nft_id, nonce);
+        let signature =
+            self.owner_key_pair.sign(&signed_payload.encode().as_slice()).unwrap().encode();
+        let proof: Proof<T::Signature, T::AccountId> =
+            get_proof::<T>(self.owner.clone(), self.relayer.clone(), &signature);
+        return Call::signed_burn_nft { proof, nft_id: self.nft_id }.into()
+    }
+
+    fn generate_signed_approve_collection(&self) -> <T as Config>::RuntimeCall {
+        let nonce = <BatchNonces<T>>::get(&self.owner);
+        let signed_payload = (
+            SIGNING_DOMAIN,
+            SIGNED_APPROVE_COLLECTION_CONTEXT,
+            &self.relayer,
+            &self.info_id,
+            &self.operator,
+            nonce,
+        );
+        let signature =
+            self.owner_key_pair.sign(&signed_payload.encode().as_slice()).unwrap().encode();
+        let proof: Proof<T::Signature, T::AccountId> =
+            get_proof::<T>(self.owner.clone(), self.relayer.clone(), &signature);
+        return Call::signed_approve_collection {
+            proof,
+            info_id: self.info_id,
+            operator: self.operator.clone(),
+        }
+        .into()
+    }
+}
+
 struct ListNftOpenForSale<T: Config> {
     relayer: T::AccountId,
     nft_owner: T::AccountId,
@@ -251,6 +469,7 @@ impl<T: Config> TransferFiatNft<T> {
         let op_id = 0;
 
         let signed_payload = (
+            SIGNING_DOMAIN,
             SIGNED_TRANSFER_FIAT_NFT_CONTEXT,
             &relayer_account_id,
             nft_id,
@@ -314,8 +533,13 @@ impl<T: Config> CancelListFiatNft<T> {
 
         let op_id = 0;
 
-        let signed_payload =
-            (SIGNED_CANCEL_LIST_FIAT_NFT_CONTEXT, &relayer_account_id, nft_id, op_id);
+        let signed_payload = (
+            SIGNING_DOMAIN,
+            SIGNED_CANCEL_LIST_FIAT_NFT_CONTEXT,
+            &relayer_account_id,
+            nft_id,
+            op_id,
+        );
         let signature =
             nft_owner_key_pair.sign(&signed_payload.encode().as_slice()).unwrap().encode();
 
@@ -362,6 +586,7 @@ impl<T: Config> CreateBatch<T> {
         let nonce = 0u64;
 
         let signed_payload = (
+            SIGNING_DOMAIN,
             SIGNED_CREATE_BATCH_CONTEXT,
             &relayer_account_id,
             &total_supply,
@@ -459,6 +684,7 @@ impl<T: Config> MintBatchNft<T> {
         let t1_authority = H160(hex!("0000000000000000000000000000000000000001"));
 
         let signed_payload = (
+            SIGNING_DOMAIN,
             SIGNED_MINT_BATCH_NFT_CONTEXT,
             &relayer_account_id,
             batch_id,
@@ -518,8 +744,14 @@ impl<T: Config> ListBatch<T> {
         let batch_id = batch.create_batch_for_setup();
         let nonce = <BatchNonces<T>>::get(&creator_account_id);
 
-        let signed_payload =
-            (SIGNED_LIST_BATCH_FOR_SALE_CONTEXT, &relayer_account_id, &batch_id, &market, nonce);
+        let signed_payload = (
+            SIGNING_DOMAIN,
+            SIGNED_LIST_BATCH_FOR_SALE_CONTEXT,
+            &relayer_account_id,
+            &batch_id,
+            &market,
+            nonce,
+        );
         let signature =
             creator_key_pair.sign(&signed_payload.encode().as_slice()).unwrap().encode();
 
@@ -566,7 +798,8 @@ impl<T: Config> EndBatchSale<T> {
 
         let nonce = <BatchNonces<T>>::get(&creator_account_id);
 
-        let signed_payload = (SIGNED_END_BATCH_SALE_CONTEXT, &relayer_account_id, &batch_id, nonce);
+        let signed_payload =
+            (SIGNING_DOMAIN, SIGNED_END_BATCH_SALE_CONTEXT, &relayer_account_id, &batch_id, nonce);
         let signature =
             creator_key_pair.sign(&signed_payload.encode().as_slice()).unwrap().encode();
 
@@ -586,6 +819,70 @@ impl<T: Config> EndBatchSale<T> {
     }
 }
 
+struct AuctionNft<T: Config> {
+    seller: T::AccountId,
+    bidder: T::AccountId,
+    nft_id: NftId,
+    reserve_price: BalanceOf<T>,
+    bid_amount: BalanceOf<T>,
+}
+
+impl<T: Config> AuctionNft<T> {
+    fn new(number_of_royalties: u32) -> Self {
+        let seller: T::AccountId = account("seller", 0, 0);
+        let bidder: T::AccountId = account("bidder", 0, 0);
+        let nft_id = U256::from(1u8);
+        let info_id = U256::one();
+
+        // Every royalty recipient is registered so every royalty is paid
+        let royalties = (0..number_of_royalties)
+            .map(|r| {
+                let recipient_t1_address = H160::from_low_u64_be(r as u64 + 1);
+                <avn::EthereumAddressAccounts<T>>::insert(
+                    recipient_t1_address,
+                    account::<T::AccountId>("royalty recipient", r, 0),
+                );
+                Royalty { recipient_t1_address, rate: RoyaltyRate { parts_per_million: 10_000 } }
+            })
+            .collect();
+        <NftInfos<T>>::insert(
+            info_id,
+            NftInfo::new(info_id, bounded_royalties(royalties), H160::from_low_u64_be(1)),
+        );
+        <Nfts<T>>::insert(
+            nft_id,
+            Nft::new(nft_id, info_id, bounded_unique_external_ref(), seller.clone()),
+        );
+
+        let unit = T::Currency::minimum_balance().max(1u32.into());
+        T::Currency::make_free_balance_be(&bidder, unit * 10_000u32.into());
+
+        return AuctionNft {
+            seller,
+            bidder,
+            nft_id,
+            reserve_price: unit * 100u32.into(),
+            bid_amount: unit * 1_000u32.into(),
+        }
+    }
+
+    fn with_auction(self) -> Self {
+        Pallet::<T>::do_create_auction(
+            self.seller.clone(),
+            self.nft_id,
+            self.reserve_price,
+            T::MinAuctionDuration::get(),
+        )
+        .unwrap();
+        return self
+    }
+
+    fn with_bid(self) -> Self {
+        Pallet::<T>::do_bid(self.bidder.clone(), self.nft_id, self.bid_amount).unwrap();
+        return self
+    }
+}
+
 benchmarks! {
     mint_single_nft {
         let r in 1 .. MAX_NUMBER_OF_ROYALTIES;
@@ -651,6 +948,36 @@ benchmarks! {
         }.into());
     }
 
+    batch_mint_nft {
+        let n in 1 .. MAX_NUMBER_OF_NFTS_PER_BATCH_MINT;
+        let batch_mint: BatchMintNft<T> = BatchMintNft::new(n);
+    }: _(
+        RawOrigin::<T::AccountId>::Signed(batch_mint.nft_owner.clone()),
+        batch_mint.nfts.clone(),
+        batch_mint.t1_authority
+    )
+    verify {
+        batch_mint.assert_minted();
+    }
+
+    signed_batch_mint_nft {
+        let n in 1 .. MAX_NUMBER_OF_NFTS_PER_BATCH_MINT;
+        let batch_mint: BatchMintNft<T> = BatchMintNft::new(n);
+        let proof: Proof<T::Signature, T::AccountId> = get_proof::<T>(
+            batch_mint.nft_owner.clone(),
+            batch_mint.relayer.clone(),
+            &batch_mint.signature
+        );
+    }: _(
+        RawOrigin::<T::AccountId>::Signed(batch_mint.nft_owner.clone()),
+        proof,
+        batch_mint.nfts.clone(),
+        batch_mint.t1_authority
+    )
+    verify {
+        batch_mint.assert_minted();
+    }
+
     list_nft_open_for_sale {
         let owner_account_bytes = [1u8;32];
         let nft_owner_account_id = T::AccountId::decode(&mut &owner_account_bytes[..]).unwrap();
@@ -775,6 +1102,18 @@ benchmarks! {
         }.into(), 2);
     }
 
+    proxy_signed_batch_mint_nft {
+        let n in 1 .. MAX_NUMBER_OF_NFTS_PER_BATCH_MINT;
+        let batch_mint: BatchMintNft<T> = BatchMintNft::new(n);
+        let call: <T as Config>::RuntimeCall = batch_mint.generate_signed_batch_mint_nft();
+        let boxed_call: Box<<T as Config>::RuntimeCall> = Box::new(call);
+        let call_hash: T::Hash = T::Hashing::hash_of(&boxed_call);
+    }: proxy(RawOrigin::<T::AccountId>::Signed(batch_mint.relayer.clone()), boxed_call)
+    verify {
+        batch_mint.assert_minted();
+        assert_last_event::<T>(Event::<T>::CallDispatched{ relayer: batch_mint.relayer.clone(), hash: call_hash }.into());
+    }
+
     proxy_signed_list_nft_open_for_sale {
         let open_for_sale: ListNftOpenForSale<T> = ListNftOpenForSale::new().setup();
         let original_nonce = Nfts::<T>::get(open_for_sale.nft_id).unwrap().nonce;
@@ -906,6 +1245,433 @@ benchmarks! {
         assert_last_event::<T>(Event::<T>::CallDispatched{ relayer: context.relayer.clone(), hash: call_hash }.into());
         assert_last_nth_event::<T>(Event::<T>::BatchSaleEnded{ batch_nft_id: context.batch_id, sale_type: context.market }.into(), 2);
     }
+
+    create_auction {
+        let context: AuctionNft<T> = AuctionNft::new(0);
+        let duration = T::MinAuctionDuration::get();
+        let end_block = frame_system::Pallet::<T>::block_number() + duration;
+    }: _(RawOrigin::<T::AccountId>::Signed(context.seller.clone()), context.nft_id, context.reserve_price, duration)
+    verify {
+        assert_eq!(<NftOpenForSale<T>>::get(&context.nft_id), NftSaleType::Auction);
+        assert_eq!(<AuctionsEndingAt<T>>::get(end_block).to_vec(), vec![context.nft_id]);
+        assert_last_event::<T>(Event::<T>::AuctionCreated {
+            nft_id: context.nft_id,
+            seller: context.seller,
+            reserve_price: context.reserve_price,
+            end_block,
+        }.into());
+    }
+
+    bid {
+        // Outbidding releases the previous highest bid
+        let context: AuctionNft<T> = AuctionNft::new(0).with_auction().with_bid();
+        let new_bidder: T::AccountId = account("new bidder", 0, 0);
+        let amount = context.bid_amount + context.reserve_price;
+        T::Currency::make_free_balance_be(&new_bidder, amount + amount);
+    }: _(RawOrigin::<T::AccountId>::Signed(new_bidder.clone()), context.nft_id, amount)
+    verify {
+        assert_eq!(T::Currency::reserved_balance(&context.bidder), 0u32.into());
+        assert_eq!(T::Currency::reserved_balance(&new_bidder), amount);
+        assert_last_event::<T>(Event::<T>::AuctionBid { nft_id: context.nft_id, bidder: new_bidder, amount }.into());
+    }
+
+    cancel_auction {
+        let context: AuctionNft<T> = AuctionNft::new(0).with_auction();
+    }: _(RawOrigin::<T::AccountId>::Signed(context.seller.clone()), context.nft_id)
+    verify {
+        assert_eq!(false, <NftOpenForSale<T>>::contains_key(&context.nft_id));
+        assert_eq!(None, Pallet::<T>::auction(&context.nft_id));
+        assert_last_event::<T>(Event::<T>::AuctionCancelled { nft_id: context.nft_id }.into());
+    }
+
+    mint_sft {
+        let r in 1 .. MAX_NUMBER_OF_ROYALTIES;
+        let context: SemiFungible<T> = SemiFungible::new(r);
+    }: _(
+        RawOrigin::<T::AccountId>::Signed(context.owner.clone()),
+        context.unique_external_ref.clone(),
+        context.royalties.clone(),
+        context.t1_authority,
+        context.supply
+    )
+    verify {
+        assert_eq!(context.supply, Pallet::<T>::sft_balance(&context.sft_id, &context.owner));
+        assert_last_event::<T>(Event::<T>::SftMinted {
+            sft_id: context.sft_id,
+            owner: context.owner,
+            authority: context.t1_authority,
+            amount: context.supply,
+        }.into());
+    }
+
+    transfer_sft {
+        let context: SemiFungible<T> = SemiFungible::new(1).minted();
+        let amount = context.supply / 2;
+    }: _(
+        RawOrigin::<T::AccountId>::Signed(context.owner.clone()),
+        context.sft_id,
+        context.buyer.clone(),
+        amount
+    )
+    verify {
+        assert_eq!(amount, Pallet::<T>::sft_balance(&context.sft_id, &context.buyer));
+        assert_last_event::<T>(Event::<T>::SftTransferred {
+            sft_id: context.sft_id,
+            from: context.owner,
+            to: context.buyer,
+            amount,
+        }.into());
+    }
+
+    burn_sft {
+        let context: SemiFungible<T> = SemiFungible::new(1).minted();
+        let amount = context.supply / 2;
+    }: _(RawOrigin::<T::AccountId>::Signed(context.owner.clone()), context.sft_id, amount)
+    verify {
+        assert_eq!(context.supply - amount, Pallet::<T>::sft(&context.sft_id).unwrap().total_supply);
+        assert_last_event::<T>(Event::<T>::SftBurned {
+            sft_id: context.sft_id,
+            owner: context.owner,
+            amount,
+        }.into());
+    }
+
+    list_sft_for_sale {
+        let context: SemiFungible<T> = SemiFungible::new(1).minted();
+        let listing_id = <NextSftListingId<T>>::get();
+    }: _(
+        RawOrigin::<T::AccountId>::Signed(context.owner.clone()),
+        context.sft_id,
+        context.supply,
+        NftSaleType::Ethereum
+    )
+    verify {
+        assert_eq!(true, <SftListings<T>>::contains_key(listing_id));
+        assert_last_event::<T>(Event::<T>::SftOpenForSale {
+            listing_id,
+            sft_id: context.sft_id,
+            seller: context.owner,
+            amount: context.supply,
+            sale_type: NftSaleType::Ethereum,
+        }.into());
+    }
+
+    transfer_fiat_sft {
+        let (context, listing_id) = SemiFungible::<T>::new(1).minted().listed(NftSaleType::Fiat);
+        let amount = context.supply / 2;
+    }: _(
+        RawOrigin::<T::AccountId>::Signed(context.owner.clone()),
+        listing_id,
+        context.buyer.clone(),
+        amount
+    )
+    verify {
+        assert_eq!(amount, Pallet::<T>::sft_balance(&context.sft_id, &context.buyer));
+        assert_last_event::<T>(Event::<T>::FiatSftTransfer {
+            listing_id,
+            new_owner: context.buyer,
+            amount,
+        }.into());
+    }
+
+    cancel_list_fiat_sft {
+        let (context, listing_id) = SemiFungible::<T>::new(1).minted().listed(NftSaleType::Fiat);
+    }: _(RawOrigin::<T::AccountId>::Signed(context.owner.clone()), listing_id)
+    verify {
+        assert_eq!(false, <SftListings<T>>::contains_key(listing_id));
+        assert_last_event::<T>(Event::<T>::CancelSftFiatListing { listing_id }.into());
+    }
+
+    update_metadata {
+        let mint_nft: MintSingleNft<T> = MintSingleNft::new(1).setup();
+        Pallet::<T>::mint_single_nft(
+            RawOrigin::<T::AccountId>::Signed(mint_nft.nft_owner.clone()).into(),
+            mint_nft.unique_external_ref.to_vec(),
+            mint_nft.royalties.clone(),
+            mint_nft.t1_authority
+        ).unwrap();
+        let full_history = BoundedVec::truncate_from(vec![
+            MetadataChange {
+                previous_external_ref: bounded_unique_external_ref(),
+                changed_at: 0u32.into(),
+            };
+            MAX_METADATA_HISTORY as usize
+        ]);
+        <MetadataHistory<T>>::insert(&mint_nft.nft_id, full_history);
+        let new_external_ref = bounded_unique_external_ref_of("Corrected location of NFT");
+    }: _(
+        RawOrigin::<T::AccountId>::Signed(mint_nft.nft_owner.clone()),
+        mint_nft.nft_id,
+        new_external_ref.to_vec()
+    )
+    verify {
+        assert_eq!(new_external_ref, Nfts::<T>::get(&mint_nft.nft_id).unwrap().unique_external_ref);
+        assert_eq!(MAX_METADATA_HISTORY as usize, <MetadataHistory<T>>::get(&mint_nft.nft_id).len());
+        assert_last_event::<T>(Event::<T>::MetadataUpdated {
+            nft_id: mint_nft.nft_id,
+            unique_external_ref: new_external_ref,
+            updated_by: mint_nft.nft_owner,
+        }.into());
+    }
+
+    set_metadata_authority {
+        let authority: T::AccountId = account("authority", 0, 0);
+        let new_authority: T::AccountId = account("new_authority", 1, 1);
+        let info_id = NftInfoId::zero();
+        <MetadataAuthorities<T>>::insert(info_id, authority.clone());
+    }: _(RawOrigin::<T::AccountId>::Signed(authority), info_id, Some(new_authority.clone()))
+    verify {
+        assert_eq!(Some(new_authority.clone()), Pallet::<T>::metadata_authority(info_id));
+        assert_last_event::<T>(Event::<T>::MetadataAuthoritySet {
+            info_id,
+            authority: Some(new_authority),
+        }.into());
+    }
+
+    approve_nft {
+        let context: ApproveOperator<T> = ApproveOperator::new();
+    }: _(RawOrigin::<T::AccountId>::Signed(context.owner.clone()), context.nft_id, context.operator.clone())
+    verify {
+        assert_eq!(Some(context.operator.clone()), Pallet::<T>::nft_approval(context.nft_id));
+        assert_last_event::<T>(Event::<T>::NftApproved {
+            nft_id: context.nft_id,
+            owner: context.owner,
+            operator: context.operator,
+        }.into());
+    }
+
+    revoke_nft_approval {
+        let context: ApproveOperator<T> = ApproveOperator::new();
+        <NftApprovals<T>>::insert(context.nft_id, context.operator.clone());
+    }: _(RawOrigin::<T::AccountId>::Signed(context.owner.clone()), context.nft_id)
+    verify {
+        assert_eq!(None, Pallet::<T>::nft_approval(context.nft_id));
+        assert_last_event::<T>(Event::<T>::NftApprovalRevoked {
+            nft_id: context.nft_id,
+            owner: context.owner,
+        }.into());
+    }
+
+    approve_collection {
+        let context: ApproveOperator<T> = ApproveOperator::new();
+    }: _(RawOrigin::<T::AccountId>::Signed(context.owner.clone()), context.info_id, context.operator.clone())
+    verify {
+        assert_eq!(true, <CollectionApprovals<T>>::contains_key((&context.owner, context.info_id), &context.operator));
+        assert_last_event::<T>(Event::<T>::CollectionApproved {
+            info_id: context.info_id,
+            owner: context.owner,
+            operator: context.operator,
+        }.into());
+    }
+
+    revoke_collection_approval {
+        let context: ApproveOperator<T> = ApproveOperator::new();
+        <CollectionApprovals<T>>::insert((&context.owner, context.info_id), &context.operator, ());
+    }: _(RawOrigin::<T::AccountId>::Signed(context.owner.clone()), context.info_id, context.operator.clone())
+    verify {
+        assert_eq!(false, <CollectionApprovals<T>>::contains_key((&context.owner, context.info_id), &context.operator));
+        assert_last_event::<T>(Event::<T>::CollectionApprovalRevoked {
+            info_id: context.info_id,
+            owner: context.owner,
+            operator: context.operator,
+        }.into());
+    }
+
+    proxy_signed_approve_nft {
+        let context: ApproveOperator<T> = ApproveOperator::new();
+        let call: <T as Config>::RuntimeCall = context.generate_signed_approve_nft();
+        let boxed_call: Box<<T as Config>::RuntimeCall> = Box::new(call);
+        let call_hash: T::Hash = T::Hashing::hash_of(&boxed_call);
+    }: proxy(RawOrigin::<T::AccountId>::Signed(context.relayer.clone()), boxed_call)
+    verify {
+        assert_eq!(Some(context.operator.clone()), Pallet::<T>::nft_approval(context.nft_id));
+        assert_last_event::<T>(Event::<T>::CallDispatched{ relayer: context.relayer.clone(), hash: call_hash }.into());
+        assert_last_nth_event::<T>(Event::<T>::NftApproved {
+            nft_id: context.nft_id,
+            owner: context.owner,
+            operator: context.operator,
+        }.into(), 2);
+    }
+
+    proxy_signed_approve_collection {
+        let context: ApproveOperator<T> = ApproveOperator::new();
+        let call: <T as Config>::RuntimeCall = context.generate_signed_approve_collection();
+        let boxed_call: Box<<T as Config>::RuntimeCall> = Box::new(call);
+        let call_hash: T::Hash = T::Hashing::hash_of(&boxed_call);
+    }: proxy(RawOrigin::<T::AccountId>::Signed(context.relayer.clone()), boxed_call)
+    verify {
+        assert_eq!(true, <CollectionApprovals<T>>::contains_key((&context.owner, context.info_id), &context.operator));
+        assert_last_event::<T>(Event::<T>::CallDispatched{ relayer: context.relayer.clone(), hash: call_hash }.into());
+        assert_last_nth_event::<T>(Event::<T>::CollectionApproved {
+            info_id: context.info_id,
+            owner: context.owner,
+            operator: context.operator,
+        }.into(), 2);
+    }
+
+    export_nft {
+        let context: ApproveOperator<T> = ApproveOperator::new();
+        let t1_recipient = H160(hex!("afdf36201bf70F1232111b5c6a9a424558755134"));
+    }: _(RawOrigin::<T::AccountId>::Signed(context.owner.clone()), context.nft_id, t1_recipient)
+    verify {
+        assert_eq!(true, Nfts::<T>::get(&context.nft_id).unwrap().is_locked);
+    }
+
+    create_collection {
+        let r in 1 .. MAX_NUMBER_OF_ROYALTIES;
+        let (_, owner) = get_user_account::<T>();
+        let t1_authority = H160(hex!("0000000000000000000000000000000000000001"));
+        let royalties = MintSingleNft::<T>::setup_royalties(r);
+        let base_uri = String::from("https://nfts.aventus.io/collection/").into_bytes();
+        let collection_id = <NextCollectionId<T>>::get();
+    }: _(RawOrigin::<T::AccountId>::Signed(owner.clone()), t1_authority, royalties, base_uri, true)
+    verify {
+        assert_eq!(Some(owner.clone()), Pallet::<T>::collection(collection_id).unwrap().owner);
+        assert_last_event::<T>(Event::<T>::CollectionCreated {
+            collection_id,
+            owner: Some(owner),
+            t1_authority,
+        }.into());
+    }
+
+    update_collection {
+        let r in 1 .. MAX_NUMBER_OF_ROYALTIES;
+        let (owner, collection_id) = create_collection_for_setup::<T>();
+        let royalties = MintSingleNft::<T>::setup_royalties(r);
+        let base_uri = String::from("https://nfts.aventus.io/collection/").into_bytes();
+    }: _(RawOrigin::<T::AccountId>::Signed(owner), collection_id, royalties, base_uri, false)
+    verify {
+        assert_eq!(false, Pallet::<T>::collection(collection_id).unwrap().transferable);
+        assert_last_event::<T>(Event::<T>::CollectionUpdated { collection_id }.into());
+    }
+
+    set_collection_owner {
+        let (owner, collection_id) = create_collection_for_setup::<T>();
+        let new_owner: T::AccountId = account("new_owner", 0, 0);
+    }: _(RawOrigin::<T::AccountId>::Signed(owner), collection_id, Some(new_owner.clone()))
+    verify {
+        assert_eq!(Some(new_owner.clone()), Pallet::<T>::collection(collection_id).unwrap().owner);
+        assert_last_event::<T>(Event::<T>::CollectionOwnerSet {
+            collection_id,
+            owner: Some(new_owner),
+        }.into());
+    }
+
+    mint_collection_nft {
+        let r in 1 .. MAX_NUMBER_OF_ROYALTIES;
+        let (owner, collection_id) = create_collection_for_setup::<T>();
+        let royalties = MintSingleNft::<T>::setup_royalties(r);
+        Pallet::<T>::do_update_collection(owner.clone(), collection_id, royalties, vec![], true)?;
+        let nft_owner: T::AccountId = account("nft_owner", 0, 0);
+        let info_id = <NextInfoId<T>>::get();
+    }: _(
+        RawOrigin::<T::AccountId>::Signed(owner),
+        collection_id,
+        nft_owner.clone(),
+        bounded_unique_external_ref().to_vec()
+    )
+    verify {
+        assert_eq!(Some(collection_id), Pallet::<T>::info_collection(info_id));
+        assert_eq!(r as usize, <NftInfos<T>>::get(info_id).unwrap().royalties.len());
+    }
+
+    mint_soulbound_nft {
+        let r in 1 .. MAX_NUMBER_OF_ROYALTIES;
+        let (owner, collection_id) = create_collection_for_setup::<T>();
+        let royalties = MintSingleNft::<T>::setup_royalties(r);
+        Pallet::<T>::do_update_collection(owner.clone(), collection_id, royalties, vec![], true)?;
+        let nft_owner: T::AccountId = account("nft_owner", 0, 0);
+        let info_id = <NextInfoId<T>>::get();
+    }: _(
+        RawOrigin::<T::AccountId>::Signed(owner),
+        collection_id,
+        nft_owner.clone(),
+        bounded_unique_external_ref().to_vec()
+    )
+    verify {
+        assert_eq!(Some(collection_id), Pallet::<T>::info_collection(info_id));
+        assert_eq!(1, <SoulboundNfts<T>>::iter_keys().count());
+    }
+
+    burn_nft {
+        let context: ApproveOperator<T> = ApproveOperator::new();
+        <NftApprovals<T>>::insert(context.nft_id, &context.operator);
+        <SoulboundNfts<T>>::insert(context.nft_id, ());
+    }: _(RawOrigin::<T::AccountId>::Signed(context.owner.clone()), context.nft_id)
+    verify {
+        assert_eq!(false, Nfts::<T>::contains_key(&context.nft_id));
+        assert_last_event::<T>(Event::<T>::NftBurned {
+            nft_id: context.nft_id,
+            owner: context.owner,
+        }.into());
+    }
+
+    proxy_signed_burn_nft {
+        let context: ApproveOperator<T> = ApproveOperator::new();
+        <NftApprovals<T>>::insert(context.nft_id, &context.operator);
+        let call: <T as Config>::RuntimeCall = context.generate_signed_burn_nft();
+        let boxed_call: Box<<T as Config>::RuntimeCall> = Box::new(call);
+        let call_hash: T::Hash = T::Hashing::hash_of(&boxed_call);
+    }: proxy(RawOrigin::<T::AccountId>::Signed(context.relayer.clone()), boxed_call)
+    verify {
+        assert_eq!(false, Nfts::<T>::contains_key(&context.nft_id));
+        assert_last_event::<T>(Event::<T>::CallDispatched{ relayer: context.relayer.clone(), hash: call_hash }.into());
+        assert_last_nth_event::<T>(Event::<T>::NftBurned {
+            nft_id: context.nft_id,
+            owner: context.owner,
+        }.into(), 2);
+    }
+
+    freeze_nft {
+        let context: ApproveOperator<T> = ApproveOperator::new();
+    }: _(RawOrigin::Root, context.nft_id)
+    verify {
+        assert_eq!(true, FrozenNfts::<T>::contains_key(&context.nft_id));
+        assert_last_event::<T>(Event::<T>::NftFrozen { nft_id: context.nft_id }.into());
+    }
+
+    unfreeze_nft {
+        let context: ApproveOperator<T> = ApproveOperator::new();
+        <FrozenNfts<T>>::insert(context.nft_id, ());
+    }: _(RawOrigin::Root, context.nft_id)
+    verify {
+        assert_eq!(false, FrozenNfts::<T>::contains_key(&context.nft_id));
+        assert_last_event::<T>(Event::<T>::NftUnfrozen { nft_id: context.nft_id }.into());
+    }
+
+    settle_auction {
+        let r in 0 .. MAX_NUMBER_OF_ROYALTIES;
+        let context: AuctionNft<T> = AuctionNft::new(r).with_auction().with_bid();
+    }: {
+        Pallet::<T>::settle_auction(&context.nft_id);
+    }
+    verify {
+        assert_eq!(context.bidder, Nfts::<T>::get(&context.nft_id).unwrap().owner);
+        assert_eq!(None, Pallet::<T>::auction(&context.nft_id));
+        assert_last_event::<T>(Event::<T>::AuctionSettled {
+            nft_id: context.nft_id,
+            seller: context.seller,
+            winner: context.bidder,
+            price: context.bid_amount,
+        }.into());
+    }
+
+    claim_royalties {
+        let recipient: T::AccountId = account("royalty recipient", 0, 0);
+        let recipient_t1_address = H160::from_low_u64_be(1);
+        <avn::EthereumAddressAccounts<T>>::insert(recipient_t1_address, recipient.clone());
+        let amount = T::Currency::minimum_balance().max(1u32.into()) * 100u32.into();
+        T::Currency::make_free_balance_be(&Pallet::<T>::royalty_pot_account(), amount);
+        <UnclaimedRoyalties<T>>::insert(recipient_t1_address, amount);
+    }: _(RawOrigin::Signed(recipient.clone()), recipient_t1_address)
+    verify {
+        assert_eq!(Pallet::<T>::unclaimed_royalties(recipient_t1_address), 0u32.into());
+        assert_last_event::<T>(Event::<T>::RoyaltiesClaimed {
+            recipient_t1_address,
+            account: recipient,
+            amount,
+        }.into());
+    }
 }
 
 impl_benchmark_test_suite!(
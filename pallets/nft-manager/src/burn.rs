@@ -0,0 +1,105 @@
+// Copyright 2024 Aventus Network Services (UK) Ltd.
+
+//! Burning of nfts.
+//!
+//! The owner of an nft can burn it, and so can governance, which is how credentials and tickets
+//! minted as soulbound nfts are revoked. A listed or locked nft has to be unlisted or unlocked
+//! first. The external reference of a burnt nft stays in use, so it can't be minted again.
+//!
+//! When the tier 1 authority of the nft is a registered nft contract, the nft also exists on
+//! Ethereum. Its burn is then published through the bridge and the nft stays locked until the
+//! bridge reports the result: it is removed when the tier 1 burn succeeded, and unlocked for its
+//! owner when it failed, so the nft never exists on one chain only.
+//!
+//! Only the admin origin, which also freezes nfts, can burn an nft that is frozen.
+
+use crate::*;
+use sp_avn_common::BridgeContractMethod;
+
+extern crate alloc;
+use alloc::format;
+
+pub const SIGNED_BURN_NFT_CONTEXT: &'static [u8] = b"authorization for burn nft operation";
+
+pub type NftBurnOf<T> = NftBurn<<T as frame_system::Config>::AccountId>;
+
+#[derive(Encode, Decode, Clone, Debug, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+pub struct NftBurn<AccountId> {
+    pub nft_id: NftId,
+    pub owner: AccountId,
+}
+
+impl<T: Config> Pallet<T> {
+    /// Burns `nft_id`. A `None` sender means root.
+    pub(crate) fn do_burn_nft(sender: Option<T::AccountId>, nft_id: NftId) -> DispatchResult {
+        let nft = Self::try_get_nft(&nft_id)?;
+        if let Some(sender) = sender {
+            ensure!(nft.owner == sender, Error::<T>::SenderIsNotOwner);
+            Self::ensure_not_frozen(&nft_id)?;
+        }
+        ensure!(!<NftOpenForSale<T>>::contains_key(&nft_id), Error::<T>::NftAlreadyListed);
+        ensure!(nft.is_locked == false, Error::<T>::NftIsLocked);
+        let info = Self::nft_infos(&nft.info_id).ok_or(Error::<T>::NftInfoMissing)?;
+
+        if !T::NftContractChecker::is_nft_contract(&info.t1_authority) {
+            Self::remove_burnt_nft(&nft_id);
+            Self::deposit_event(Event::<T>::NftBurned { nft_id, owner: nft.owner });
+            return Ok(())
+        }
+
+        let function_name: &[u8] = BridgeContractMethod::BurnNft.as_bytes();
+        let params = vec![
+            (b"address".to_vec(), info.t1_authority.as_bytes().to_vec()),
+            (b"uint256".to_vec(), format!("{}", nft_id).as_bytes().to_vec()),
+        ];
+        let tx_id = T::BridgeInterface::publish(function_name, &params, PALLET_ID.to_vec())?;
+
+        <Nfts<T>>::mutate(nft_id, |maybe_nft| {
+            maybe_nft.as_mut().map(|nft| {
+                nft.is_locked = true;
+                nft.nonce += 1u64;
+            })
+        });
+        <PendingNftBurns<T>>::insert(tx_id, NftBurn { nft_id, owner: nft.owner.clone() });
+
+        Self::deposit_event(Event::<T>::NftBurnRequested { nft_id, owner: nft.owner, tx_id });
+
+        Ok(())
+    }
+
+    pub(crate) fn process_nft_burn_result(tx_id: u32, succeeded: bool) {
+        let burn = match <PendingNftBurns<T>>::take(tx_id) {
+            Some(burn) => burn,
+            None => return,
+        };
+
+        if succeeded {
+            Self::remove_burnt_nft(&burn.nft_id);
+            Self::deposit_event(Event::<T>::NftBurned { nft_id: burn.nft_id, owner: burn.owner });
+        } else {
+            <Nfts<T>>::mutate(burn.nft_id, |maybe_nft| {
+                maybe_nft.as_mut().map(|nft| nft.is_locked = false)
+            });
+            Self::deposit_event(Event::<T>::NftBurnFailed {
+                nft_id: burn.nft_id,
+                owner: burn.owner,
+                tx_id,
+            });
+        }
+    }
+
+    pub(crate) fn encode_burn_nft_params(
+        proof: &Proof<T::Signature, T::AccountId>,
+        nft_id: &NftId,
+    ) -> Result<Vec<u8>, Error<T>> {
+        let nft = Self::try_get_nft(nft_id)?;
+        return Ok(
+            (SIGNING_DOMAIN, SIGNED_BURN_NFT_CONTEXT, &proof.relayer, nft_id, nft.nonce).encode()
+        )
+    }
+
+    fn remove_burnt_nft(nft_id: &NftId) {
+        Self::remove_nft(nft_id);
+        <SoulboundNfts<T>>::remove(nft_id);
+    }
+}
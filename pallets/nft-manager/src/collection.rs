@@ -0,0 +1,230 @@
+// Copyright 2024 Aventus Network Services (UK) Ltd.
+
+//! Collections of nfts.
+//!
+//! A collection groups the nft infos minted from the same batch or tier 1 contract, and holds the
+//! settings shared by their nfts: the default royalties and tier 1 authority of the nfts minted
+//! into it, a base URI for their metadata and whether they can be sold or transferred. The owner of
+//! a collection updates its settings and mints into it. Updated royalties only apply to the nfts
+//! minted afterwards, as the royalties of an nft are kept in its info. A collection can only be
+//! made non transferable until its first nft is minted.
+//!
+//! Every batch gets its own collection, owned by its creator. Single nfts and sfts minted outside
+//! of a collection join the implicit collection of their tier 1 authority, which has no owner
+//! until one is set by governance.
+//!
+//! The owner of a collection can also mint soulbound nfts into it, for credentials and tickets.
+//! These can't be listed, sold, auctioned or exported by anyone, whatever the settings of the
+//! collection. They leave their owner only when they are burnt.
+
+use crate::*;
+use sp_runtime::DispatchError;
+
+pub type CollectionId = u64;
+
+#[derive(Encode, Decode, Clone, Debug, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+pub struct Collection<AccountId> {
+    /// The account that can update the collection and mint into it
+    pub owner: Option<AccountId>,
+    pub t1_authority: H160,
+    /// Royalties of the nfts minted into the collection
+    pub royalties: BoundedVec<Royalty, NftRoyaltiesBound>,
+    pub base_uri: BoundedVec<u8, NftExternalRefBound>,
+    /// When false, the nfts of the collection can't be listed, sold or exported
+    pub transferable: bool,
+}
+
+impl<T: Config> Pallet<T> {
+    pub(crate) fn do_create_collection(
+        owner: T::AccountId,
+        t1_authority: H160,
+        royalties: Vec<Royalty>,
+        base_uri: Vec<u8>,
+        transferable: bool,
+    ) -> DispatchResult {
+        ensure!(t1_authority.is_zero() == false, Error::<T>::T1AuthorityIsMandatory);
+        let (royalties, base_uri) = Self::validate_collection_settings(royalties, base_uri)?;
+
+        Self::add_collection(Collection {
+            owner: Some(owner),
+            t1_authority,
+            royalties,
+            base_uri,
+            transferable,
+        });
+
+        Ok(())
+    }
+
+    pub(crate) fn do_update_collection(
+        sender: T::AccountId,
+        collection_id: CollectionId,
+        royalties: Vec<Royalty>,
+        base_uri: Vec<u8>,
+        transferable: bool,
+    ) -> DispatchResult {
+        let mut collection = Self::try_get_owned_collection(&sender, collection_id)?;
+        let (royalties, base_uri) = Self::validate_collection_settings(royalties, base_uri)?;
+        // The nfts already minted may have been bought as transferable
+        ensure!(
+            transferable ||
+                !collection.transferable ||
+                !<CollectionsWithNfts<T>>::contains_key(collection_id),
+            Error::<T>::CollectionHasNfts
+        );
+
+        collection.royalties = royalties;
+        collection.base_uri = base_uri;
+        collection.transferable = transferable;
+        <Collections<T>>::insert(collection_id, collection);
+
+        Self::deposit_event(Event::<T>::CollectionUpdated { collection_id });
+
+        Ok(())
+    }
+
+    /// Hands a collection to `owner`. Governance can set the owner of any collection, including
+    /// the implicit ones that have no owner.
+    pub(crate) fn do_set_collection_owner(
+        sender: Option<T::AccountId>,
+        collection_id: CollectionId,
+        owner: Option<T::AccountId>,
+    ) -> DispatchResult {
+        let mut collection = match sender {
+            Some(sender) => Self::try_get_owned_collection(&sender, collection_id)?,
+            None => Self::collection(collection_id).ok_or(Error::<T>::CollectionDoesNotExist)?,
+        };
+
+        collection.owner = owner.clone();
+        <Collections<T>>::insert(collection_id, collection);
+
+        Self::deposit_event(Event::<T>::CollectionOwnerSet { collection_id, owner });
+
+        Ok(())
+    }
+
+    /// Mints an nft to `owner` with the royalties and tier 1 authority of the collection. A
+    /// soulbound nft stays with `owner` until it is burnt.
+    pub(crate) fn do_mint_collection_nft(
+        sender: T::AccountId,
+        collection_id: CollectionId,
+        owner: T::AccountId,
+        unique_external_ref: Vec<u8>,
+        soulbound: bool,
+    ) -> DispatchResult {
+        let collection = Self::try_get_owned_collection(&sender, collection_id)?;
+        let bounded_unique_external_ref =
+            BoundedVec::<u8, NftExternalRefBound>::try_from(unique_external_ref)
+                .map_err(|_| Error::<T>::ExternalRefOutOfBounds)?;
+        Self::validate_external_ref(&bounded_unique_external_ref)?;
+
+        let nft_id = Self::generate_nft_id_single_mint(
+            &collection.t1_authority,
+            Self::get_unique_id_and_advance(),
+        );
+        ensure!(Nfts::<T>::contains_key(&nft_id) == false, Error::<T>::NftAlreadyExists);
+
+        let info_id = Self::get_info_id_and_advance();
+        let info = NftInfo::new(info_id, collection.royalties, collection.t1_authority);
+        let nft = Nft::new(nft_id, info_id, bounded_unique_external_ref, owner.clone());
+        <NftInfos<T>>::insert(info_id, &info);
+        <MetadataAuthorities<T>>::insert(info_id, &sender);
+        <InfoCollections<T>>::insert(info_id, collection_id);
+        if soulbound {
+            <SoulboundNfts<T>>::insert(nft_id, ());
+        }
+        Self::add_nft(&nft);
+
+        Self::deposit_event(Event::<T>::SingleNftMinted {
+            nft_id,
+            owner,
+            authority: info.t1_authority,
+        });
+
+        Ok(())
+    }
+
+    /// Adds the info of a new batch to a collection of its own
+    pub(crate) fn add_batch_to_new_collection(info: &NftInfo<T::AccountId>) {
+        let collection_id = Self::add_collection(Collection {
+            owner: info.creator.clone(),
+            t1_authority: info.t1_authority,
+            royalties: info.royalties.clone(),
+            base_uri: BoundedVec::default(),
+            transferable: true,
+        });
+        <InfoCollections<T>>::insert(info.info_id, collection_id);
+    }
+
+    /// Adds an info to the implicit collection of its tier 1 authority, creating the collection if
+    /// this is the first info of the authority
+    pub(crate) fn add_to_contract_collection(info_id: NftInfoId, t1_authority: H160) {
+        let collection_id = match Self::contract_collection(t1_authority) {
+            Some(collection_id) => collection_id,
+            None => {
+                let collection_id = Self::add_collection(Collection {
+                    owner: None,
+                    t1_authority,
+                    royalties: BoundedVec::default(),
+                    base_uri: BoundedVec::default(),
+                    transferable: true,
+                });
+                <ContractCollections<T>>::insert(t1_authority, collection_id);
+                collection_id
+            },
+        };
+        <InfoCollections<T>>::insert(info_id, collection_id);
+    }
+
+    pub(crate) fn ensure_transferable(nft: &Nft<T::AccountId>) -> DispatchResult {
+        Self::ensure_not_frozen(&nft.nft_id)?;
+        ensure!(!<SoulboundNfts<T>>::contains_key(nft.nft_id), Error::<T>::NftIsSoulbound);
+        let transferable = Self::info_collection(nft.info_id)
+            .and_then(|collection_id| Self::collection(collection_id))
+            .map_or(true, |collection| collection.transferable);
+        ensure!(transferable, Error::<T>::CollectionNotTransferable);
+        Ok(())
+    }
+
+    fn add_collection(collection: Collection<T::AccountId>) -> CollectionId {
+        let collection_id = <NextCollectionId<T>>::mutate(|next_id| {
+            let collection_id = *next_id;
+            *next_id = next_id.saturating_add(1);
+            collection_id
+        });
+
+        Self::deposit_event(Event::<T>::CollectionCreated {
+            collection_id,
+            owner: collection.owner.clone(),
+            t1_authority: collection.t1_authority,
+        });
+        <Collections<T>>::insert(collection_id, collection);
+
+        collection_id
+    }
+
+    fn try_get_owned_collection(
+        sender: &T::AccountId,
+        collection_id: CollectionId,
+    ) -> Result<Collection<T::AccountId>, DispatchError> {
+        let collection =
+            Self::collection(collection_id).ok_or(Error::<T>::CollectionDoesNotExist)?;
+        ensure!(collection.owner.as_ref() == Some(sender), Error::<T>::SenderIsNotCollectionOwner);
+        Ok(collection)
+    }
+
+    fn validate_collection_settings(
+        royalties: Vec<Royalty>,
+        base_uri: Vec<u8>,
+    ) -> Result<
+        (BoundedVec<Royalty, NftRoyaltiesBound>, BoundedVec<u8, NftExternalRefBound>),
+        DispatchError,
+    > {
+        Self::validate_royalties(&royalties)?;
+        let royalties =
+            BoundedVec::try_from(royalties).map_err(|_| Error::<T>::RoyaltiesOutOfBounds)?;
+        let base_uri =
+            BoundedVec::try_from(base_uri).map_err(|_| Error::<T>::BaseUriOutOfBounds)?;
+        Ok((royalties, base_uri))
+    }
+}
@@ -51,6 +51,38 @@ pub trait WeightInfo {
 	fn proxy_signed_mint_batch_nft() -> Weight;
 	fn proxy_signed_list_batch_for_sale() -> Weight;
 	fn proxy_signed_end_batch_sale() -> Weight;
+	fn create_auction() -> Weight;
+	fn bid() -> Weight;
+	fn cancel_auction() -> Weight;
+	fn settle_auction(r: u32, ) -> Weight;
+	fn batch_mint_nft(n: u32, ) -> Weight;
+	fn signed_batch_mint_nft(n: u32, ) -> Weight;
+	fn proxy_signed_batch_mint_nft(n: u32, ) -> Weight;
+	fn mint_sft(r: u32, ) -> Weight;
+	fn transfer_sft() -> Weight;
+	fn burn_sft() -> Weight;
+	fn list_sft_for_sale() -> Weight;
+	fn transfer_fiat_sft() -> Weight;
+	fn cancel_list_fiat_sft() -> Weight;
+	fn update_metadata() -> Weight;
+	fn set_metadata_authority() -> Weight;
+	fn approve_nft() -> Weight;
+	fn revoke_nft_approval() -> Weight;
+	fn approve_collection() -> Weight;
+	fn revoke_collection_approval() -> Weight;
+	fn proxy_signed_approve_nft() -> Weight;
+	fn proxy_signed_approve_collection() -> Weight;
+	fn export_nft() -> Weight;
+	fn create_collection(r: u32, ) -> Weight;
+	fn update_collection(r: u32, ) -> Weight;
+	fn set_collection_owner() -> Weight;
+	fn mint_collection_nft(r: u32, ) -> Weight;
+	fn mint_soulbound_nft(r: u32, ) -> Weight;
+	fn burn_nft() -> Weight;
+	fn proxy_signed_burn_nft() -> Weight;
+	fn freeze_nft() -> Weight;
+	fn unfreeze_nft() -> Weight;
+	fn claim_royalties() -> Weight;
 }
 
 /// Weights for pallet_nft_manager using the Substrate node and recommended hardware.
@@ -290,6 +322,574 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(4_u64))
 			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
+	/// Storage: `NftManager::Nfts` (r:1 w:1)
+	/// Proof: `NftManager::Nfts` (`max_values`: None, `max_size`: Some(1179), added: 3654, mode: `MaxEncodedLen`)
+	/// Storage: `NftManager::NftOpenForSale` (r:1 w:1)
+	/// Proof: `NftManager::NftOpenForSale` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `NftManager::AuctionsEndingAt` (r:1 w:1)
+	/// Proof: `NftManager::AuctionsEndingAt` (`max_values`: None, `max_size`: Some(1613), added: 4088, mode: `MaxEncodedLen`)
+	/// Storage: `NftManager::Auctions` (r:0 w:1)
+	/// Proof: `NftManager::Auctions` (`max_values`: None, `max_size`: Some(149), added: 2624, mode: `MaxEncodedLen`)
+	fn create_auction() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `321`
+		//  Estimated: `5078`
+		// Minimum execution time: 31_274_000 picoseconds.
+		Weight::from_parts(32_015_000, 5078)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: `NftManager::Auctions` (r:1 w:1)
+	/// Proof: `NftManager::Auctions` (`max_values`: None, `max_size`: Some(149), added: 2624, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn bid() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `543`
+		//  Estimated: `6196`
+		// Minimum execution time: 52_813_000 picoseconds.
+		Weight::from_parts(53_902_000, 6196)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: `NftManager::Auctions` (r:1 w:1)
+	/// Proof: `NftManager::Auctions` (`max_values`: None, `max_size`: Some(149), added: 2624, mode: `MaxEncodedLen`)
+	/// Storage: `NftManager::AuctionsEndingAt` (r:1 w:1)
+	/// Proof: `NftManager::AuctionsEndingAt` (`max_values`: None, `max_size`: Some(1613), added: 4088, mode: `MaxEncodedLen`)
+	/// Storage: `NftManager::NftOpenForSale` (r:1 w:1)
+	/// Proof: `NftManager::NftOpenForSale` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `NftManager::Nfts` (r:1 w:1)
+	/// Proof: `NftManager::Nfts` (`max_values`: None, `max_size`: Some(1179), added: 3654, mode: `MaxEncodedLen`)
+	fn cancel_auction() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `498`
+		//  Estimated: `5078`
+		// Minimum execution time: 33_409_000 picoseconds.
+		Weight::from_parts(34_126_000, 5078)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: `NftManager::Auctions` (r:1 w:1)
+	/// Proof: `NftManager::Auctions` (`max_values`: None, `max_size`: Some(149), added: 2624, mode: `MaxEncodedLen`)
+	/// Storage: `NftManager::Nfts` (r:1 w:1)
+	/// Proof: `NftManager::Nfts` (`max_values`: None, `max_size`: Some(1179), added: 3654, mode: `MaxEncodedLen`)
+	/// Storage: `NftManager::NftInfos` (r:1 w:0)
+	/// Proof: `NftManager::NftInfos` (`max_values`: None, `max_size`: Some(559), added: 3034, mode: `MaxEncodedLen`)
+	/// Storage: `Avn::EthereumAddressAccounts` (r:16 w:0)
+	/// Proof: `Avn::EthereumAddressAccounts` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:18 w:18)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `NftManager::NftOpenForSale` (r:1 w:1)
+	/// Proof: `NftManager::NftOpenForSale` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// The range of component `r` is `[0, 16]`.
+	fn settle_auction(r: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1021 + r * (122 ±0)`
+		//  Estimated: `6196 + r * (2603 ±0)`
+		// Minimum execution time: 78_562_000 picoseconds.
+		Weight::from_parts(80_144_913, 6196)
+			// Standard Error: 21_407
+			.saturating_add(Weight::from_parts(31_226_750, 0).saturating_mul(r.into()))
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(r.into())))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(r.into())))
+			.saturating_add(Weight::from_parts(0, 2603).saturating_mul(r.into()))
+	}
+	/// Storage: `NftManager::NextSingleNftUniqueId` (r:1 w:1)
+	/// Proof: `NftManager::NextSingleNftUniqueId` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	/// Storage: `NftManager::NextInfoId` (r:1 w:1)
+	/// Proof: `NftManager::NextInfoId` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	/// Storage: `NftManager::UsedExternalReferences` (r:50 w:50)
+	/// Proof: `NftManager::UsedExternalReferences` (`max_values`: None, `max_size`: Some(1043), added: 3518, mode: `MaxEncodedLen`)
+	/// Storage: `NftManager::Nfts` (r:50 w:50)
+	/// Proof: `NftManager::Nfts` (`max_values`: None, `max_size`: Some(1179), added: 3654, mode: `MaxEncodedLen`)
+	/// Storage: `NftManager::NftInfos` (r:0 w:50)
+	/// Proof: `NftManager::NftInfos` (`max_values`: None, `max_size`: Some(559), added: 3034, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 50]`.
+	fn batch_mint_nft(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `114 + n * (48 ±0)`
+		//  Estimated: `1517 + n * (3654 ±0)`
+		// Minimum execution time: 11_904_000 picoseconds.
+		Weight::from_parts(12_377_452, 1517)
+			// Standard Error: 5_211
+			.saturating_add(Weight::from_parts(38_120_634, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(T::DbWeight::get().writes((3_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 3654).saturating_mul(n.into()))
+	}
+	/// Storage: `NftManager::NextSingleNftUniqueId` (r:1 w:1)
+	/// Proof: `NftManager::NextSingleNftUniqueId` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	/// Storage: `NftManager::NextInfoId` (r:1 w:1)
+	/// Proof: `NftManager::NextInfoId` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	/// Storage: `NftManager::UsedExternalReferences` (r:50 w:50)
+	/// Proof: `NftManager::UsedExternalReferences` (`max_values`: None, `max_size`: Some(1043), added: 3518, mode: `MaxEncodedLen`)
+	/// Storage: `NftManager::Nfts` (r:50 w:50)
+	/// Proof: `NftManager::Nfts` (`max_values`: None, `max_size`: Some(1179), added: 3654, mode: `MaxEncodedLen`)
+	/// Storage: `NftManager::NftInfos` (r:0 w:50)
+	/// Proof: `NftManager::NftInfos` (`max_values`: None, `max_size`: Some(559), added: 3034, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 50]`.
+	fn signed_batch_mint_nft(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `114 + n * (48 ±0)`
+		//  Estimated: `1517 + n * (3654 ±0)`
+		// Minimum execution time: 152_390_000 picoseconds.
+		Weight::from_parts(153_106_208, 1517)
+			// Standard Error: 9_874
+			.saturating_add(Weight::from_parts(39_402_117, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(T::DbWeight::get().writes((3_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 3654).saturating_mul(n.into()))
+	}
+	/// Storage: `NftManager::NextSingleNftUniqueId` (r:1 w:1)
+	/// Proof: `NftManager::NextSingleNftUniqueId` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	/// Storage: `NftManager::NextInfoId` (r:1 w:1)
+	/// Proof: `NftManager::NextInfoId` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	/// Storage: `NftManager::UsedExternalReferences` (r:50 w:50)
+	/// Proof: `NftManager::UsedExternalReferences` (`max_values`: None, `max_size`: Some(1043), added: 3518, mode: `MaxEncodedLen`)
+	/// Storage: `NftManager::Nfts` (r:50 w:50)
+	/// Proof: `NftManager::Nfts` (`max_values`: None, `max_size`: Some(1179), added: 3654, mode: `MaxEncodedLen`)
+	/// Storage: `NftManager::NftInfos` (r:0 w:50)
+	/// Proof: `NftManager::NftInfos` (`max_values`: None, `max_size`: Some(559), added: 3034, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 50]`.
+	fn proxy_signed_batch_mint_nft(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `114 + n * (48 ±0)`
+		//  Estimated: `1517 + n * (3654 ±0)`
+		// Minimum execution time: 155_731_000 picoseconds.
+		Weight::from_parts(156_840_995, 1517)
+			// Standard Error: 10_136
+			.saturating_add(Weight::from_parts(39_516_480, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(T::DbWeight::get().writes((3_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 3654).saturating_mul(n.into()))
+	}
+	/// Storage: `NftManager::UsedExternalReferences` (r:1 w:1)
+	/// Proof: `NftManager::UsedExternalReferences` (`max_values`: None, `max_size`: Some(1043), added: 3518, mode: `MaxEncodedLen`)
+	/// Storage: `NftManager::NextSingleNftUniqueId` (r:1 w:1)
+	/// Proof: `NftManager::NextSingleNftUniqueId` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	/// Storage: `NftManager::SemiFungibleTokens` (r:1 w:1)
+	/// Proof: `NftManager::SemiFungibleTokens` (`max_values`: None, `max_size`: Some(1155), added: 3630, mode: `MaxEncodedLen`)
+	/// Storage: `NftManager::NextInfoId` (r:1 w:1)
+	/// Proof: `NftManager::NextInfoId` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	/// Storage: `NftManager::NftInfos` (r:0 w:1)
+	/// Proof: `NftManager::NftInfos` (`max_values`: None, `max_size`: Some(559), added: 3034, mode: `MaxEncodedLen`)
+	/// Storage: `NftManager::SftBalances` (r:0 w:1)
+	/// Proof: `NftManager::SftBalances` (`max_values`: None, `max_size`: Some(112), added: 2587, mode: `MaxEncodedLen`)
+	/// The range of component `r` is `[1, 16]`.
+	fn mint_sft(r: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `462`
+		//  Estimated: `4620`
+		// Minimum execution time: 31_200_000 picoseconds.
+		Weight::from_parts(31_200_000, 4620)
+			.saturating_add(Weight::from_parts(118_000, 0).saturating_mul(r.into()))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+	}
+	/// Storage: `NftManager::SemiFungibleTokens` (r:1 w:0)
+	/// Proof: `NftManager::SemiFungibleTokens` (`max_values`: None, `max_size`: Some(1155), added: 3630, mode: `MaxEncodedLen`)
+	/// Storage: `NftManager::SftBalances` (r:2 w:2)
+	/// Proof: `NftManager::SftBalances` (`max_values`: None, `max_size`: Some(112), added: 2587, mode: `MaxEncodedLen`)
+	fn transfer_sft() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `616`
+		//  Estimated: `6164`
+		// Minimum execution time: 24_800_000 picoseconds.
+		Weight::from_parts(24_800_000, 6164)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `NftManager::SemiFungibleTokens` (r:1 w:1)
+	/// Proof: `NftManager::SemiFungibleTokens` (`max_values`: None, `max_size`: Some(1155), added: 3630, mode: `MaxEncodedLen`)
+	/// Storage: `NftManager::SftBalances` (r:1 w:1)
+	/// Proof: `NftManager::SftBalances` (`max_values`: None, `max_size`: Some(112), added: 2587, mode: `MaxEncodedLen`)
+	fn burn_sft() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `462`
+		//  Estimated: `4620`
+		// Minimum execution time: 22_100_000 picoseconds.
+		Weight::from_parts(22_100_000, 4620)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `NftManager::SemiFungibleTokens` (r:1 w:0)
+	/// Proof: `NftManager::SemiFungibleTokens` (`max_values`: None, `max_size`: Some(1155), added: 3630, mode: `MaxEncodedLen`)
+	/// Storage: `NftManager::SftBalances` (r:1 w:1)
+	/// Proof: `NftManager::SftBalances` (`max_values`: None, `max_size`: Some(112), added: 2587, mode: `MaxEncodedLen`)
+	/// Storage: `NftManager::NextSftListingId` (r:1 w:1)
+	/// Proof: `NftManager::NextSftListingId` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `NftManager::SftListings` (r:0 w:1)
+	/// Proof: `NftManager::SftListings` (`max_values`: None, `max_size`: Some(105), added: 2580, mode: `MaxEncodedLen`)
+	fn list_sft_for_sale() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `462`
+		//  Estimated: `4620`
+		// Minimum execution time: 27_300_000 picoseconds.
+		Weight::from_parts(27_300_000, 4620)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: `NftManager::SftListings` (r:1 w:1)
+	/// Proof: `NftManager::SftListings` (`max_values`: None, `max_size`: Some(105), added: 2580, mode: `MaxEncodedLen`)
+	/// Storage: `NftManager::SftBalances` (r:1 w:1)
+	/// Proof: `NftManager::SftBalances` (`max_values`: None, `max_size`: Some(112), added: 2587, mode: `MaxEncodedLen`)
+	fn transfer_fiat_sft() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `357`
+		//  Estimated: `3577`
+		// Minimum execution time: 23_500_000 picoseconds.
+		Weight::from_parts(23_500_000, 3577)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `NftManager::SftListings` (r:1 w:1)
+	/// Proof: `NftManager::SftListings` (`max_values`: None, `max_size`: Some(105), added: 2580, mode: `MaxEncodedLen`)
+	/// Storage: `NftManager::SftBalances` (r:1 w:1)
+	/// Proof: `NftManager::SftBalances` (`max_values`: None, `max_size`: Some(112), added: 2587, mode: `MaxEncodedLen`)
+	fn cancel_list_fiat_sft() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `357`
+		//  Estimated: `3577`
+		// Minimum execution time: 22_900_000 picoseconds.
+		Weight::from_parts(22_900_000, 3577)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `NftManager::UsedExternalReferences` (r:1 w:1)
+	/// Proof: `NftManager::UsedExternalReferences` (`max_values`: None, `max_size`: Some(1043), added: 3518, mode: `MaxEncodedLen`)
+	/// Storage: `NftManager::Nfts` (r:1 w:1)
+	/// Proof: `NftManager::Nfts` (`max_values`: None, `max_size`: Some(1179), added: 3654, mode: `MaxEncodedLen`)
+	/// Storage: `NftManager::NftOpenForSale` (r:1 w:0)
+	/// Proof: `NftManager::NftOpenForSale` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `NftManager::MetadataAuthorities` (r:1 w:0)
+	/// Proof: `NftManager::MetadataAuthorities` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `NftManager::MetadataHistory` (r:1 w:1)
+	/// Proof: `NftManager::MetadataHistory` (`max_values`: None, `max_size`: Some(10359), added: 12834, mode: `MaxEncodedLen`)
+	fn update_metadata() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1382`
+		//  Estimated: `13824`
+		// Minimum execution time: 38_400_000 picoseconds.
+		Weight::from_parts(38_400_000, 13824)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: `NftManager::MetadataAuthorities` (r:1 w:1)
+	/// Proof: `NftManager::MetadataAuthorities` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	fn set_metadata_authority() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `354`
+		//  Estimated: `3545`
+		// Minimum execution time: 14_200_000 picoseconds.
+		Weight::from_parts(14_200_000, 3545)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `NftManager::Nfts` (r:1 w:0)
+	/// Proof: `NftManager::Nfts` (`max_values`: None, `max_size`: Some(1179), added: 3654, mode: `MaxEncodedLen`)
+	/// Storage: `NftManager::NftApprovals` (r:0 w:1)
+	/// Proof: `NftManager::NftApprovals` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	fn approve_nft() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `464`
+		//  Estimated: `4644`
+		// Minimum execution time: 17_300_000 picoseconds.
+		Weight::from_parts(17_300_000, 4644)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `NftManager::Nfts` (r:1 w:0)
+	/// Proof: `NftManager::Nfts` (`max_values`: None, `max_size`: Some(1179), added: 3654, mode: `MaxEncodedLen`)
+	/// Storage: `NftManager::NftApprovals` (r:1 w:1)
+	/// Proof: `NftManager::NftApprovals` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	fn revoke_nft_approval() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `464`
+		//  Estimated: `4644`
+		// Minimum execution time: 19_800_000 picoseconds.
+		Weight::from_parts(19_800_000, 4644)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `NftManager::NftInfos` (r:1 w:0)
+	/// Proof: `NftManager::NftInfos` (`max_values`: None, `max_size`: Some(559), added: 3034, mode: `MaxEncodedLen`)
+	/// Storage: `NftManager::CollectionApprovals` (r:0 w:1)
+	/// Proof: `NftManager::CollectionApprovals` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn approve_collection() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `402`
+		//  Estimated: `4024`
+		// Minimum execution time: 16_100_000 picoseconds.
+		Weight::from_parts(16_100_000, 4024)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `NftManager::CollectionApprovals` (r:1 w:1)
+	/// Proof: `NftManager::CollectionApprovals` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn revoke_collection_approval() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `359`
+		//  Estimated: `3593`
+		// Minimum execution time: 15_400_000 picoseconds.
+		Weight::from_parts(15_400_000, 3593)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `NftManager::BatchNonces` (r:1 w:1)
+	/// Proof: `NftManager::BatchNonces` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `NftManager::Nfts` (r:1 w:0)
+	/// Proof: `NftManager::Nfts` (`max_values`: None, `max_size`: Some(1179), added: 3654, mode: `MaxEncodedLen`)
+	/// Storage: `NftManager::NftApprovals` (r:0 w:1)
+	/// Proof: `NftManager::NftApprovals` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	fn proxy_signed_approve_nft() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `464`
+		//  Estimated: `4644`
+		// Minimum execution time: 71_600_000 picoseconds.
+		Weight::from_parts(71_600_000, 4644)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `NftManager::BatchNonces` (r:1 w:1)
+	/// Proof: `NftManager::BatchNonces` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `NftManager::NftInfos` (r:1 w:0)
+	/// Proof: `NftManager::NftInfos` (`max_values`: None, `max_size`: Some(559), added: 3034, mode: `MaxEncodedLen`)
+	/// Storage: `NftManager::CollectionApprovals` (r:0 w:1)
+	/// Proof: `NftManager::CollectionApprovals` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn proxy_signed_approve_collection() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `402`
+		//  Estimated: `4024`
+		// Minimum execution time: 69_900_000 picoseconds.
+		Weight::from_parts(69_900_000, 4024)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `NftManager::Nfts` (r:1 w:1)
+	/// Proof: `NftManager::Nfts` (`max_values`: None, `max_size`: Some(1179), added: 3654, mode: `MaxEncodedLen`)
+	/// Storage: `NftManager::NftOpenForSale` (r:1 w:0)
+	/// Proof: `NftManager::NftOpenForSale` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `NftManager::NftInfos` (r:1 w:0)
+	/// Proof: `NftManager::NftInfos` (`max_values`: None, `max_size`: Some(559), added: 3034, mode: `MaxEncodedLen`)
+	/// Storage: `EthBridge::NextTxId` (r:1 w:1)
+	/// Proof: `EthBridge::NextTxId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `EthBridge::ActiveRequest` (r:1 w:0)
+	/// Proof: `EthBridge::ActiveRequest` (`max_values`: Some(1), `max_size`: Some(20944), added: 21439, mode: `MaxEncodedLen`)
+	/// Storage: `EthBridge::RequestQueue` (r:1 w:1)
+	/// Proof: `EthBridge::RequestQueue` (`max_values`: Some(1), `max_size`: Some(79002), added: 79497, mode: `MaxEncodedLen`)
+	/// Storage: `NftManager::PendingNftExports` (r:0 w:1)
+	/// Proof: `NftManager::PendingNftExports` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
+	fn export_nft() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `8048`
+		//  Estimated: `80487`
+		// Minimum execution time: 48_700_000 picoseconds.
+		Weight::from_parts(48_700_000, 80487)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: `NftManager::NextCollectionId` (r:1 w:1)
+	/// Proof: `NftManager::NextCollectionId` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `NftManager::Collections` (r:0 w:1)
+	/// Proof: `NftManager::Collections` (`max_values`: None, `max_size`: Some(1466), added: 3941, mode: `MaxEncodedLen`)
+	/// The range of component `r` is `[1, 16]`.
+	fn create_collection(r: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `149`
+		//  Estimated: `1493`
+		// Minimum execution time: 24_100_000 picoseconds.
+		Weight::from_parts(24_100_000, 1493)
+			.saturating_add(Weight::from_parts(95_000, 0).saturating_mul(r.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `NftManager::Collections` (r:1 w:1)
+	/// Proof: `NftManager::Collections` (`max_values`: None, `max_size`: Some(1466), added: 3941, mode: `MaxEncodedLen`)
+	/// Storage: `NftManager::CollectionsWithNfts` (r:1 w:0)
+	/// Proof: `NftManager::CollectionsWithNfts` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// The range of component `r` is `[1, 16]`.
+	fn update_collection(r: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `493`
+		//  Estimated: `4931`
+		// Minimum execution time: 21_300_000 picoseconds.
+		Weight::from_parts(21_300_000, 4931)
+			.saturating_add(Weight::from_parts(92_000, 0).saturating_mul(r.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `NftManager::Collections` (r:1 w:1)
+	/// Proof: `NftManager::Collections` (`max_values`: None, `max_size`: Some(1466), added: 3941, mode: `MaxEncodedLen`)
+	fn set_collection_owner() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `493`
+		//  Estimated: `4931`
+		// Minimum execution time: 19_800_000 picoseconds.
+		Weight::from_parts(19_800_000, 4931)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `NftManager::Collections` (r:1 w:0)
+	/// Proof: `NftManager::Collections` (`max_values`: None, `max_size`: Some(1466), added: 3941, mode: `MaxEncodedLen`)
+	/// Storage: `NftManager::UsedExternalReferences` (r:1 w:1)
+	/// Proof: `NftManager::UsedExternalReferences` (`max_values`: None, `max_size`: Some(1043), added: 3518, mode: `MaxEncodedLen`)
+	/// Storage: `NftManager::NextSingleNftUniqueId` (r:1 w:1)
+	/// Proof: `NftManager::NextSingleNftUniqueId` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	/// Storage: `NftManager::Nfts` (r:1 w:1)
+	/// Proof: `NftManager::Nfts` (`max_values`: None, `max_size`: Some(1179), added: 3654, mode: `MaxEncodedLen`)
+	/// Storage: `NftManager::NextInfoId` (r:1 w:1)
+	/// Proof: `NftManager::NextInfoId` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	/// Storage: `NftManager::NftInfos` (r:0 w:1)
+	/// Proof: `NftManager::NftInfos` (`max_values`: None, `max_size`: Some(559), added: 3034, mode: `MaxEncodedLen`)
+	/// Storage: `NftManager::MetadataAuthorities` (r:0 w:1)
+	/// Proof: `NftManager::MetadataAuthorities` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `NftManager::InfoCollections` (r:0 w:1)
+	/// Proof: `NftManager::InfoCollections` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// The range of component `r` is `[1, 16]`.
+	fn mint_collection_nft(r: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `493`
+		//  Estimated: `4931`
+		// Minimum execution time: 38_600_000 picoseconds.
+		Weight::from_parts(38_600_000, 4931)
+			.saturating_add(Weight::from_parts(97_000, 0).saturating_mul(r.into()))
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(7_u64))
+	}
+	/// Storage: `NftManager::Collections` (r:1 w:0)
+	/// Proof: `NftManager::Collections` (`max_values`: None, `max_size`: Some(1466), added: 3941, mode: `MaxEncodedLen`)
+	/// Storage: `NftManager::UsedExternalReferences` (r:1 w:1)
+	/// Proof: `NftManager::UsedExternalReferences` (`max_values`: None, `max_size`: Some(1043), added: 3518, mode: `MaxEncodedLen`)
+	/// Storage: `NftManager::NextSingleNftUniqueId` (r:1 w:1)
+	/// Proof: `NftManager::NextSingleNftUniqueId` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	/// Storage: `NftManager::Nfts` (r:1 w:1)
+	/// Proof: `NftManager::Nfts` (`max_values`: None, `max_size`: Some(1179), added: 3654, mode: `MaxEncodedLen`)
+	/// Storage: `NftManager::NextInfoId` (r:1 w:1)
+	/// Proof: `NftManager::NextInfoId` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	/// Storage: `NftManager::NftInfos` (r:0 w:1)
+	/// Proof: `NftManager::NftInfos` (`max_values`: None, `max_size`: Some(559), added: 3034, mode: `MaxEncodedLen`)
+	/// Storage: `NftManager::MetadataAuthorities` (r:0 w:1)
+	/// Proof: `NftManager::MetadataAuthorities` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `NftManager::InfoCollections` (r:0 w:1)
+	/// Proof: `NftManager::InfoCollections` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `NftManager::SoulboundNfts` (r:0 w:1)
+	/// Proof: `NftManager::SoulboundNfts` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// The range of component `r` is `[1, 16]`.
+	fn mint_soulbound_nft(r: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `493`
+		//  Estimated: `4931`
+		// Minimum execution time: 40_100_000 picoseconds.
+		Weight::from_parts(40_100_000, 4931)
+			.saturating_add(Weight::from_parts(97_000, 0).saturating_mul(r.into()))
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(8_u64))
+	}
+	/// Storage: `NftManager::Nfts` (r:1 w:1)
+	/// Proof: `NftManager::Nfts` (`max_values`: None, `max_size`: Some(1179), added: 3654, mode: `MaxEncodedLen`)
+	/// Storage: `NftManager::NftOpenForSale` (r:1 w:0)
+	/// Proof: `NftManager::NftOpenForSale` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `NftManager::NftInfos` (r:1 w:0)
+	/// Proof: `NftManager::NftInfos` (`max_values`: None, `max_size`: Some(559), added: 3034, mode: `MaxEncodedLen`)
+	/// Storage: `EthereumEvents::NftT1Contracts` (r:1 w:0)
+	/// Proof: `EthereumEvents::NftT1Contracts` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	/// Storage: `EthBridge::NextTxId` (r:1 w:1)
+	/// Proof: `EthBridge::NextTxId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `EthBridge::ActiveRequest` (r:1 w:0)
+	/// Proof: `EthBridge::ActiveRequest` (`max_values`: Some(1), `max_size`: Some(20944), added: 21439, mode: `MaxEncodedLen`)
+	/// Storage: `EthBridge::RequestQueue` (r:1 w:1)
+	/// Proof: `EthBridge::RequestQueue` (`max_values`: Some(1), `max_size`: Some(79002), added: 79497, mode: `MaxEncodedLen`)
+	/// Storage: `NftManager::PendingNftBurns` (r:0 w:1)
+	/// Proof: `NftManager::PendingNftBurns` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `NftManager::NftApprovals` (r:0 w:1)
+	/// Proof: `NftManager::NftApprovals` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `NftManager::SoulboundNfts` (r:0 w:1)
+	/// Proof: `NftManager::SoulboundNfts` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	fn burn_nft() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `8048`
+		//  Estimated: `80487`
+		// Minimum execution time: 47_900_000 picoseconds.
+		Weight::from_parts(47_900_000, 80487)
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+	}
+	/// Storage: `NftManager::Nfts` (r:1 w:1)
+	/// Proof: `NftManager::Nfts` (`max_values`: None, `max_size`: Some(1179), added: 3654, mode: `MaxEncodedLen`)
+	/// Storage: `NftManager::NftOpenForSale` (r:1 w:0)
+	/// Proof: `NftManager::NftOpenForSale` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `NftManager::NftInfos` (r:1 w:0)
+	/// Proof: `NftManager::NftInfos` (`max_values`: None, `max_size`: Some(559), added: 3034, mode: `MaxEncodedLen`)
+	/// Storage: `EthereumEvents::NftT1Contracts` (r:1 w:0)
+	/// Proof: `EthereumEvents::NftT1Contracts` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	/// Storage: `EthBridge::NextTxId` (r:1 w:1)
+	/// Proof: `EthBridge::NextTxId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `EthBridge::ActiveRequest` (r:1 w:0)
+	/// Proof: `EthBridge::ActiveRequest` (`max_values`: Some(1), `max_size`: Some(20944), added: 21439, mode: `MaxEncodedLen`)
+	/// Storage: `EthBridge::RequestQueue` (r:1 w:1)
+	/// Proof: `EthBridge::RequestQueue` (`max_values`: Some(1), `max_size`: Some(79002), added: 79497, mode: `MaxEncodedLen`)
+	/// Storage: `NftManager::PendingNftBurns` (r:0 w:1)
+	/// Proof: `NftManager::PendingNftBurns` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `NftManager::NftApprovals` (r:0 w:1)
+	/// Proof: `NftManager::NftApprovals` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `NftManager::SoulboundNfts` (r:0 w:1)
+	/// Proof: `NftManager::SoulboundNfts` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	fn proxy_signed_burn_nft() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `8048`
+		//  Estimated: `80487`
+		// Minimum execution time: 98_300_000 picoseconds.
+		Weight::from_parts(98_300_000, 80487)
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+	}
+	/// Storage: `NftManager::Nfts` (r:1 w:0)
+	/// Proof: `NftManager::Nfts` (`max_values`: None, `max_size`: Some(1179), added: 3654, mode: `MaxEncodedLen`)
+	/// Storage: `NftManager::FrozenNfts` (r:1 w:1)
+	/// Proof: `NftManager::FrozenNfts` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	fn freeze_nft() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `464`
+		//  Estimated: `4644`
+		// Minimum execution time: 14_213_000 picoseconds.
+		Weight::from_parts(14_213_000, 4644)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `NftManager::FrozenNfts` (r:1 w:1)
+	/// Proof: `NftManager::FrozenNfts` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	fn unfreeze_nft() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `351`
+		//  Estimated: `3513`
+		// Minimum execution time: 11_068_000 picoseconds.
+		Weight::from_parts(11_068_000, 3513)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Avn::EthereumAddressAccounts` (r:1 w:0)
+	/// Proof: `Avn::EthereumAddressAccounts` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
+	/// Storage: `NftManager::UnclaimedRoyalties` (r:1 w:1)
+	/// Proof: `NftManager::UnclaimedRoyalties` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn claim_royalties() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `619`
+		//  Estimated: `6196`
+		// Minimum execution time: 61_250_000 picoseconds.
+		Weight::from_parts(61_250_000, 6196)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
 }
 
 // For backwards compatibility and tests.
@@ -528,4 +1128,572 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(4_u64))
 			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
+	/// Storage: `NftManager::Nfts` (r:1 w:1)
+	/// Proof: `NftManager::Nfts` (`max_values`: None, `max_size`: Some(1179), added: 3654, mode: `MaxEncodedLen`)
+	/// Storage: `NftManager::NftOpenForSale` (r:1 w:1)
+	/// Proof: `NftManager::NftOpenForSale` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `NftManager::AuctionsEndingAt` (r:1 w:1)
+	/// Proof: `NftManager::AuctionsEndingAt` (`max_values`: None, `max_size`: Some(1613), added: 4088, mode: `MaxEncodedLen`)
+	/// Storage: `NftManager::Auctions` (r:0 w:1)
+	/// Proof: `NftManager::Auctions` (`max_values`: None, `max_size`: Some(149), added: 2624, mode: `MaxEncodedLen`)
+	fn create_auction() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `321`
+		//  Estimated: `5078`
+		// Minimum execution time: 31_274_000 picoseconds.
+		Weight::from_parts(32_015_000, 5078)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: `NftManager::Auctions` (r:1 w:1)
+	/// Proof: `NftManager::Auctions` (`max_values`: None, `max_size`: Some(149), added: 2624, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn bid() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `543`
+		//  Estimated: `6196`
+		// Minimum execution time: 52_813_000 picoseconds.
+		Weight::from_parts(53_902_000, 6196)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: `NftManager::Auctions` (r:1 w:1)
+	/// Proof: `NftManager::Auctions` (`max_values`: None, `max_size`: Some(149), added: 2624, mode: `MaxEncodedLen`)
+	/// Storage: `NftManager::AuctionsEndingAt` (r:1 w:1)
+	/// Proof: `NftManager::AuctionsEndingAt` (`max_values`: None, `max_size`: Some(1613), added: 4088, mode: `MaxEncodedLen`)
+	/// Storage: `NftManager::NftOpenForSale` (r:1 w:1)
+	/// Proof: `NftManager::NftOpenForSale` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `NftManager::Nfts` (r:1 w:1)
+	/// Proof: `NftManager::Nfts` (`max_values`: None, `max_size`: Some(1179), added: 3654, mode: `MaxEncodedLen`)
+	fn cancel_auction() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `498`
+		//  Estimated: `5078`
+		// Minimum execution time: 33_409_000 picoseconds.
+		Weight::from_parts(34_126_000, 5078)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: `NftManager::Auctions` (r:1 w:1)
+	/// Proof: `NftManager::Auctions` (`max_values`: None, `max_size`: Some(149), added: 2624, mode: `MaxEncodedLen`)
+	/// Storage: `NftManager::Nfts` (r:1 w:1)
+	/// Proof: `NftManager::Nfts` (`max_values`: None, `max_size`: Some(1179), added: 3654, mode: `MaxEncodedLen`)
+	/// Storage: `NftManager::NftInfos` (r:1 w:0)
+	/// Proof: `NftManager::NftInfos` (`max_values`: None, `max_size`: Some(559), added: 3034, mode: `MaxEncodedLen`)
+	/// Storage: `Avn::EthereumAddressAccounts` (r:16 w:0)
+	/// Proof: `Avn::EthereumAddressAccounts` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:18 w:18)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `NftManager::NftOpenForSale` (r:1 w:1)
+	/// Proof: `NftManager::NftOpenForSale` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// The range of component `r` is `[0, 16]`.
+	fn settle_auction(r: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1021 + r * (122 ±0)`
+		//  Estimated: `6196 + r * (2603 ±0)`
+		// Minimum execution time: 78_562_000 picoseconds.
+		Weight::from_parts(80_144_913, 6196)
+			// Standard Error: 21_407
+			.saturating_add(Weight::from_parts(31_226_750, 0).saturating_mul(r.into()))
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(r.into())))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(r.into())))
+			.saturating_add(Weight::from_parts(0, 2603).saturating_mul(r.into()))
+	}
+	/// Storage: `NftManager::NextSingleNftUniqueId` (r:1 w:1)
+	/// Proof: `NftManager::NextSingleNftUniqueId` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	/// Storage: `NftManager::NextInfoId` (r:1 w:1)
+	/// Proof: `NftManager::NextInfoId` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	/// Storage: `NftManager::UsedExternalReferences` (r:50 w:50)
+	/// Proof: `NftManager::UsedExternalReferences` (`max_values`: None, `max_size`: Some(1043), added: 3518, mode: `MaxEncodedLen`)
+	/// Storage: `NftManager::Nfts` (r:50 w:50)
+	/// Proof: `NftManager::Nfts` (`max_values`: None, `max_size`: Some(1179), added: 3654, mode: `MaxEncodedLen`)
+	/// Storage: `NftManager::NftInfos` (r:0 w:50)
+	/// Proof: `NftManager::NftInfos` (`max_values`: None, `max_size`: Some(559), added: 3034, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 50]`.
+	fn batch_mint_nft(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `114 + n * (48 ±0)`
+		//  Estimated: `1517 + n * (3654 ±0)`
+		// Minimum execution time: 11_904_000 picoseconds.
+		Weight::from_parts(12_377_452, 1517)
+			// Standard Error: 5_211
+			.saturating_add(Weight::from_parts(38_120_634, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+			.saturating_add(RocksDbWeight::get().writes((3_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 3654).saturating_mul(n.into()))
+	}
+	/// Storage: `NftManager::NextSingleNftUniqueId` (r:1 w:1)
+	/// Proof: `NftManager::NextSingleNftUniqueId` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	/// Storage: `NftManager::NextInfoId` (r:1 w:1)
+	/// Proof: `NftManager::NextInfoId` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	/// Storage: `NftManager::UsedExternalReferences` (r:50 w:50)
+	/// Proof: `NftManager::UsedExternalReferences` (`max_values`: None, `max_size`: Some(1043), added: 3518, mode: `MaxEncodedLen`)
+	/// Storage: `NftManager::Nfts` (r:50 w:50)
+	/// Proof: `NftManager::Nfts` (`max_values`: None, `max_size`: Some(1179), added: 3654, mode: `MaxEncodedLen`)
+	/// Storage: `NftManager::NftInfos` (r:0 w:50)
+	/// Proof: `NftManager::NftInfos` (`max_values`: None, `max_size`: Some(559), added: 3034, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 50]`.
+	fn signed_batch_mint_nft(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `114 + n * (48 ±0)`
+		//  Estimated: `1517 + n * (3654 ±0)`
+		// Minimum execution time: 152_390_000 picoseconds.
+		Weight::from_parts(153_106_208, 1517)
+			// Standard Error: 9_874
+			.saturating_add(Weight::from_parts(39_402_117, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+			.saturating_add(RocksDbWeight::get().writes((3_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 3654).saturating_mul(n.into()))
+	}
+	/// Storage: `NftManager::NextSingleNftUniqueId` (r:1 w:1)
+	/// Proof: `NftManager::NextSingleNftUniqueId` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	/// Storage: `NftManager::NextInfoId` (r:1 w:1)
+	/// Proof: `NftManager::NextInfoId` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	/// Storage: `NftManager::UsedExternalReferences` (r:50 w:50)
+	/// Proof: `NftManager::UsedExternalReferences` (`max_values`: None, `max_size`: Some(1043), added: 3518, mode: `MaxEncodedLen`)
+	/// Storage: `NftManager::Nfts` (r:50 w:50)
+	/// Proof: `NftManager::Nfts` (`max_values`: None, `max_size`: Some(1179), added: 3654, mode: `MaxEncodedLen`)
+	/// Storage: `NftManager::NftInfos` (r:0 w:50)
+	/// Proof: `NftManager::NftInfos` (`max_values`: None, `max_size`: Some(559), added: 3034, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 50]`.
+	fn proxy_signed_batch_mint_nft(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `114 + n * (48 ±0)`
+		//  Estimated: `1517 + n * (3654 ±0)`
+		// Minimum execution time: 155_731_000 picoseconds.
+		Weight::from_parts(156_840_995, 1517)
+			// Standard Error: 10_136
+			.saturating_add(Weight::from_parts(39_516_480, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+			.saturating_add(RocksDbWeight::get().writes((3_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 3654).saturating_mul(n.into()))
+	}
+	/// Storage: `NftManager::UsedExternalReferences` (r:1 w:1)
+	/// Proof: `NftManager::UsedExternalReferences` (`max_values`: None, `max_size`: Some(1043), added: 3518, mode: `MaxEncodedLen`)
+	/// Storage: `NftManager::NextSingleNftUniqueId` (r:1 w:1)
+	/// Proof: `NftManager::NextSingleNftUniqueId` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	/// Storage: `NftManager::SemiFungibleTokens` (r:1 w:1)
+	/// Proof: `NftManager::SemiFungibleTokens` (`max_values`: None, `max_size`: Some(1155), added: 3630, mode: `MaxEncodedLen`)
+	/// Storage: `NftManager::NextInfoId` (r:1 w:1)
+	/// Proof: `NftManager::NextInfoId` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	/// Storage: `NftManager::NftInfos` (r:0 w:1)
+	/// Proof: `NftManager::NftInfos` (`max_values`: None, `max_size`: Some(559), added: 3034, mode: `MaxEncodedLen`)
+	/// Storage: `NftManager::SftBalances` (r:0 w:1)
+	/// Proof: `NftManager::SftBalances` (`max_values`: None, `max_size`: Some(112), added: 2587, mode: `MaxEncodedLen`)
+	/// The range of component `r` is `[1, 16]`.
+	fn mint_sft(r: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `462`
+		//  Estimated: `4620`
+		// Minimum execution time: 31_200_000 picoseconds.
+		Weight::from_parts(31_200_000, 4620)
+			.saturating_add(Weight::from_parts(118_000, 0).saturating_mul(r.into()))
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+	}
+	/// Storage: `NftManager::SemiFungibleTokens` (r:1 w:0)
+	/// Proof: `NftManager::SemiFungibleTokens` (`max_values`: None, `max_size`: Some(1155), added: 3630, mode: `MaxEncodedLen`)
+	/// Storage: `NftManager::SftBalances` (r:2 w:2)
+	/// Proof: `NftManager::SftBalances` (`max_values`: None, `max_size`: Some(112), added: 2587, mode: `MaxEncodedLen`)
+	fn transfer_sft() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `616`
+		//  Estimated: `6164`
+		// Minimum execution time: 24_800_000 picoseconds.
+		Weight::from_parts(24_800_000, 6164)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `NftManager::SemiFungibleTokens` (r:1 w:1)
+	/// Proof: `NftManager::SemiFungibleTokens` (`max_values`: None, `max_size`: Some(1155), added: 3630, mode: `MaxEncodedLen`)
+	/// Storage: `NftManager::SftBalances` (r:1 w:1)
+	/// Proof: `NftManager::SftBalances` (`max_values`: None, `max_size`: Some(112), added: 2587, mode: `MaxEncodedLen`)
+	fn burn_sft() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `462`
+		//  Estimated: `4620`
+		// Minimum execution time: 22_100_000 picoseconds.
+		Weight::from_parts(22_100_000, 4620)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `NftManager::SemiFungibleTokens` (r:1 w:0)
+	/// Proof: `NftManager::SemiFungibleTokens` (`max_values`: None, `max_size`: Some(1155), added: 3630, mode: `MaxEncodedLen`)
+	/// Storage: `NftManager::SftBalances` (r:1 w:1)
+	/// Proof: `NftManager::SftBalances` (`max_values`: None, `max_size`: Some(112), added: 2587, mode: `MaxEncodedLen`)
+	/// Storage: `NftManager::NextSftListingId` (r:1 w:1)
+	/// Proof: `NftManager::NextSftListingId` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `NftManager::SftListings` (r:0 w:1)
+	/// Proof: `NftManager::SftListings` (`max_values`: None, `max_size`: Some(105), added: 2580, mode: `MaxEncodedLen`)
+	fn list_sft_for_sale() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `462`
+		//  Estimated: `4620`
+		// Minimum execution time: 27_300_000 picoseconds.
+		Weight::from_parts(27_300_000, 4620)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: `NftManager::SftListings` (r:1 w:1)
+	/// Proof: `NftManager::SftListings` (`max_values`: None, `max_size`: Some(105), added: 2580, mode: `MaxEncodedLen`)
+	/// Storage: `NftManager::SftBalances` (r:1 w:1)
+	/// Proof: `NftManager::SftBalances` (`max_values`: None, `max_size`: Some(112), added: 2587, mode: `MaxEncodedLen`)
+	fn transfer_fiat_sft() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `357`
+		//  Estimated: `3577`
+		// Minimum execution time: 23_500_000 picoseconds.
+		Weight::from_parts(23_500_000, 3577)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `NftManager::SftListings` (r:1 w:1)
+	/// Proof: `NftManager::SftListings` (`max_values`: None, `max_size`: Some(105), added: 2580, mode: `MaxEncodedLen`)
+	/// Storage: `NftManager::SftBalances` (r:1 w:1)
+	/// Proof: `NftManager::SftBalances` (`max_values`: None, `max_size`: Some(112), added: 2587, mode: `MaxEncodedLen`)
+	fn cancel_list_fiat_sft() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `357`
+		//  Estimated: `3577`
+		// Minimum execution time: 22_900_000 picoseconds.
+		Weight::from_parts(22_900_000, 3577)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `NftManager::UsedExternalReferences` (r:1 w:1)
+	/// Proof: `NftManager::UsedExternalReferences` (`max_values`: None, `max_size`: Some(1043), added: 3518, mode: `MaxEncodedLen`)
+	/// Storage: `NftManager::Nfts` (r:1 w:1)
+	/// Proof: `NftManager::Nfts` (`max_values`: None, `max_size`: Some(1179), added: 3654, mode: `MaxEncodedLen`)
+	/// Storage: `NftManager::NftOpenForSale` (r:1 w:0)
+	/// Proof: `NftManager::NftOpenForSale` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `NftManager::MetadataAuthorities` (r:1 w:0)
+	/// Proof: `NftManager::MetadataAuthorities` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `NftManager::MetadataHistory` (r:1 w:1)
+	/// Proof: `NftManager::MetadataHistory` (`max_values`: None, `max_size`: Some(10359), added: 12834, mode: `MaxEncodedLen`)
+	fn update_metadata() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1382`
+		//  Estimated: `13824`
+		// Minimum execution time: 38_400_000 picoseconds.
+		Weight::from_parts(38_400_000, 13824)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: `NftManager::MetadataAuthorities` (r:1 w:1)
+	/// Proof: `NftManager::MetadataAuthorities` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	fn set_metadata_authority() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `354`
+		//  Estimated: `3545`
+		// Minimum execution time: 14_200_000 picoseconds.
+		Weight::from_parts(14_200_000, 3545)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `NftManager::Nfts` (r:1 w:0)
+	/// Proof: `NftManager::Nfts` (`max_values`: None, `max_size`: Some(1179), added: 3654, mode: `MaxEncodedLen`)
+	/// Storage: `NftManager::NftApprovals` (r:0 w:1)
+	/// Proof: `NftManager::NftApprovals` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	fn approve_nft() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `464`
+		//  Estimated: `4644`
+		// Minimum execution time: 17_300_000 picoseconds.
+		Weight::from_parts(17_300_000, 4644)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `NftManager::Nfts` (r:1 w:0)
+	/// Proof: `NftManager::Nfts` (`max_values`: None, `max_size`: Some(1179), added: 3654, mode: `MaxEncodedLen`)
+	/// Storage: `NftManager::NftApprovals` (r:1 w:1)
+	/// Proof: `NftManager::NftApprovals` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	fn revoke_nft_approval() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `464`
+		//  Estimated: `4644`
+		// Minimum execution time: 19_800_000 picoseconds.
+		Weight::from_parts(19_800_000, 4644)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `NftManager::NftInfos` (r:1 w:0)
+	/// Proof: `NftManager::NftInfos` (`max_values`: None, `max_size`: Some(559), added: 3034, mode: `MaxEncodedLen`)
+	/// Storage: `NftManager::CollectionApprovals` (r:0 w:1)
+	/// Proof: `NftManager::CollectionApprovals` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn approve_collection() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `402`
+		//  Estimated: `4024`
+		// Minimum execution time: 16_100_000 picoseconds.
+		Weight::from_parts(16_100_000, 4024)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `NftManager::CollectionApprovals` (r:1 w:1)
+	/// Proof: `NftManager::CollectionApprovals` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn revoke_collection_approval() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `359`
+		//  Estimated: `3593`
+		// Minimum execution time: 15_400_000 picoseconds.
+		Weight::from_parts(15_400_000, 3593)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `NftManager::BatchNonces` (r:1 w:1)
+	/// Proof: `NftManager::BatchNonces` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `NftManager::Nfts` (r:1 w:0)
+	/// Proof: `NftManager::Nfts` (`max_values`: None, `max_size`: Some(1179), added: 3654, mode: `MaxEncodedLen`)
+	/// Storage: `NftManager::NftApprovals` (r:0 w:1)
+	/// Proof: `NftManager::NftApprovals` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	fn proxy_signed_approve_nft() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `464`
+		//  Estimated: `4644`
+		// Minimum execution time: 71_600_000 picoseconds.
+		Weight::from_parts(71_600_000, 4644)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `NftManager::BatchNonces` (r:1 w:1)
+	/// Proof: `NftManager::BatchNonces` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `NftManager::NftInfos` (r:1 w:0)
+	/// Proof: `NftManager::NftInfos` (`max_values`: None, `max_size`: Some(559), added: 3034, mode: `MaxEncodedLen`)
+	/// Storage: `NftManager::CollectionApprovals` (r:0 w:1)
+	/// Proof: `NftManager::CollectionApprovals` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn proxy_signed_approve_collection() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `402`
+		//  Estimated: `4024`
+		// Minimum execution time: 69_900_000 picoseconds.
+		Weight::from_parts(69_900_000, 4024)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `NftManager::Nfts` (r:1 w:1)
+	/// Proof: `NftManager::Nfts` (`max_values`: None, `max_size`: Some(1179), added: 3654, mode: `MaxEncodedLen`)
+	/// Storage: `NftManager::NftOpenForSale` (r:1 w:0)
+	/// Proof: `NftManager::NftOpenForSale` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `NftManager::NftInfos` (r:1 w:0)
+	/// Proof: `NftManager::NftInfos` (`max_values`: None, `max_size`: Some(559), added: 3034, mode: `MaxEncodedLen`)
+	/// Storage: `EthBridge::NextTxId` (r:1 w:1)
+	/// Proof: `EthBridge::NextTxId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `EthBridge::ActiveRequest` (r:1 w:0)
+	/// Proof: `EthBridge::ActiveRequest` (`max_values`: Some(1), `max_size`: Some(20944), added: 21439, mode: `MaxEncodedLen`)
+	/// Storage: `EthBridge::RequestQueue` (r:1 w:1)
+	/// Proof: `EthBridge::RequestQueue` (`max_values`: Some(1), `max_size`: Some(79002), added: 79497, mode: `MaxEncodedLen`)
+	/// Storage: `NftManager::PendingNftExports` (r:0 w:1)
+	/// Proof: `NftManager::PendingNftExports` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
+	fn export_nft() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `8048`
+		//  Estimated: `80487`
+		// Minimum execution time: 48_700_000 picoseconds.
+		Weight::from_parts(48_700_000, 80487)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: `NftManager::NextCollectionId` (r:1 w:1)
+	/// Proof: `NftManager::NextCollectionId` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `NftManager::Collections` (r:0 w:1)
+	/// Proof: `NftManager::Collections` (`max_values`: None, `max_size`: Some(1466), added: 3941, mode: `MaxEncodedLen`)
+	/// The range of component `r` is `[1, 16]`.
+	fn create_collection(r: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `149`
+		//  Estimated: `1493`
+		// Minimum execution time: 24_100_000 picoseconds.
+		Weight::from_parts(24_100_000, 1493)
+			.saturating_add(Weight::from_parts(95_000, 0).saturating_mul(r.into()))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `NftManager::Collections` (r:1 w:1)
+	/// Proof: `NftManager::Collections` (`max_values`: None, `max_size`: Some(1466), added: 3941, mode: `MaxEncodedLen`)
+	/// Storage: `NftManager::CollectionsWithNfts` (r:1 w:0)
+	/// Proof: `NftManager::CollectionsWithNfts` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// The range of component `r` is `[1, 16]`.
+	fn update_collection(r: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `493`
+		//  Estimated: `4931`
+		// Minimum execution time: 21_300_000 picoseconds.
+		Weight::from_parts(21_300_000, 4931)
+			.saturating_add(Weight::from_parts(92_000, 0).saturating_mul(r.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `NftManager::Collections` (r:1 w:1)
+	/// Proof: `NftManager::Collections` (`max_values`: None, `max_size`: Some(1466), added: 3941, mode: `MaxEncodedLen`)
+	fn set_collection_owner() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `493`
+		//  Estimated: `4931`
+		// Minimum execution time: 19_800_000 picoseconds.
+		Weight::from_parts(19_800_000, 4931)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `NftManager::Collections` (r:1 w:0)
+	/// Proof: `NftManager::Collections` (`max_values`: None, `max_size`: Some(1466), added: 3941, mode: `MaxEncodedLen`)
+	/// Storage: `NftManager::UsedExternalReferences` (r:1 w:1)
+	/// Proof: `NftManager::UsedExternalReferences` (`max_values`: None, `max_size`: Some(1043), added: 3518, mode: `MaxEncodedLen`)
+	/// Storage: `NftManager::NextSingleNftUniqueId` (r:1 w:1)
+	/// Proof: `NftManager::NextSingleNftUniqueId` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	/// Storage: `NftManager::Nfts` (r:1 w:1)
+	/// Proof: `NftManager::Nfts` (`max_values`: None, `max_size`: Some(1179), added: 3654, mode: `MaxEncodedLen`)
+	/// Storage: `NftManager::NextInfoId` (r:1 w:1)
+	/// Proof: `NftManager::NextInfoId` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	/// Storage: `NftManager::NftInfos` (r:0 w:1)
+	/// Proof: `NftManager::NftInfos` (`max_values`: None, `max_size`: Some(559), added: 3034, mode: `MaxEncodedLen`)
+	/// Storage: `NftManager::MetadataAuthorities` (r:0 w:1)
+	/// Proof: `NftManager::MetadataAuthorities` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `NftManager::InfoCollections` (r:0 w:1)
+	/// Proof: `NftManager::InfoCollections` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// The range of component `r` is `[1, 16]`.
+	fn mint_collection_nft(r: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `493`
+		//  Estimated: `4931`
+		// Minimum execution time: 38_600_000 picoseconds.
+		Weight::from_parts(38_600_000, 4931)
+			.saturating_add(Weight::from_parts(97_000, 0).saturating_mul(r.into()))
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(7_u64))
+	}
+	/// Storage: `NftManager::Collections` (r:1 w:0)
+	/// Proof: `NftManager::Collections` (`max_values`: None, `max_size`: Some(1466), added: 3941, mode: `MaxEncodedLen`)
+	/// Storage: `NftManager::UsedExternalReferences` (r:1 w:1)
+	/// Proof: `NftManager::UsedExternalReferences` (`max_values`: None, `max_size`: Some(1043), added: 3518, mode: `MaxEncodedLen`)
+	/// Storage: `NftManager::NextSingleNftUniqueId` (r:1 w:1)
+	/// Proof: `NftManager::NextSingleNftUniqueId` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	/// Storage: `NftManager::Nfts` (r:1 w:1)
+	/// Proof: `NftManager::Nfts` (`max_values`: None, `max_size`: Some(1179), added: 3654, mode: `MaxEncodedLen`)
+	/// Storage: `NftManager::NextInfoId` (r:1 w:1)
+	/// Proof: `NftManager::NextInfoId` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	/// Storage: `NftManager::NftInfos` (r:0 w:1)
+	/// Proof: `NftManager::NftInfos` (`max_values`: None, `max_size`: Some(559), added: 3034, mode: `MaxEncodedLen`)
+	/// Storage: `NftManager::MetadataAuthorities` (r:0 w:1)
+	/// Proof: `NftManager::MetadataAuthorities` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `NftManager::InfoCollections` (r:0 w:1)
+	/// Proof: `NftManager::InfoCollections` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `NftManager::SoulboundNfts` (r:0 w:1)
+	/// Proof: `NftManager::SoulboundNfts` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// The range of component `r` is `[1, 16]`.
+	fn mint_soulbound_nft(r: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `493`
+		//  Estimated: `4931`
+		// Minimum execution time: 40_100_000 picoseconds.
+		Weight::from_parts(40_100_000, 4931)
+			.saturating_add(Weight::from_parts(97_000, 0).saturating_mul(r.into()))
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(8_u64))
+	}
+	/// Storage: `NftManager::Nfts` (r:1 w:1)
+	/// Proof: `NftManager::Nfts` (`max_values`: None, `max_size`: Some(1179), added: 3654, mode: `MaxEncodedLen`)
+	/// Storage: `NftManager::NftOpenForSale` (r:1 w:0)
+	/// Proof: `NftManager::NftOpenForSale` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `NftManager::NftInfos` (r:1 w:0)
+	/// Proof: `NftManager::NftInfos` (`max_values`: None, `max_size`: Some(559), added: 3034, mode: `MaxEncodedLen`)
+	/// Storage: `EthereumEvents::NftT1Contracts` (r:1 w:0)
+	/// Proof: `EthereumEvents::NftT1Contracts` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	/// Storage: `EthBridge::NextTxId` (r:1 w:1)
+	/// Proof: `EthBridge::NextTxId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `EthBridge::ActiveRequest` (r:1 w:0)
+	/// Proof: `EthBridge::ActiveRequest` (`max_values`: Some(1), `max_size`: Some(20944), added: 21439, mode: `MaxEncodedLen`)
+	/// Storage: `EthBridge::RequestQueue` (r:1 w:1)
+	/// Proof: `EthBridge::RequestQueue` (`max_values`: Some(1), `max_size`: Some(79002), added: 79497, mode: `MaxEncodedLen`)
+	/// Storage: `NftManager::PendingNftBurns` (r:0 w:1)
+	/// Proof: `NftManager::PendingNftBurns` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `NftManager::NftApprovals` (r:0 w:1)
+	/// Proof: `NftManager::NftApprovals` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `NftManager::SoulboundNfts` (r:0 w:1)
+	/// Proof: `NftManager::SoulboundNfts` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	fn burn_nft() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `8048`
+		//  Estimated: `80487`
+		// Minimum execution time: 47_900_000 picoseconds.
+		Weight::from_parts(47_900_000, 80487)
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+	}
+	/// Storage: `NftManager::Nfts` (r:1 w:1)
+	/// Proof: `NftManager::Nfts` (`max_values`: None, `max_size`: Some(1179), added: 3654, mode: `MaxEncodedLen`)
+	/// Storage: `NftManager::NftOpenForSale` (r:1 w:0)
+	/// Proof: `NftManager::NftOpenForSale` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `NftManager::NftInfos` (r:1 w:0)
+	/// Proof: `NftManager::NftInfos` (`max_values`: None, `max_size`: Some(559), added: 3034, mode: `MaxEncodedLen`)
+	/// Storage: `EthereumEvents::NftT1Contracts` (r:1 w:0)
+	/// Proof: `EthereumEvents::NftT1Contracts` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	/// Storage: `EthBridge::NextTxId` (r:1 w:1)
+	/// Proof: `EthBridge::NextTxId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `EthBridge::ActiveRequest` (r:1 w:0)
+	/// Proof: `EthBridge::ActiveRequest` (`max_values`: Some(1), `max_size`: Some(20944), added: 21439, mode: `MaxEncodedLen`)
+	/// Storage: `EthBridge::RequestQueue` (r:1 w:1)
+	/// Proof: `EthBridge::RequestQueue` (`max_values`: Some(1), `max_size`: Some(79002), added: 79497, mode: `MaxEncodedLen`)
+	/// Storage: `NftManager::PendingNftBurns` (r:0 w:1)
+	/// Proof: `NftManager::PendingNftBurns` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `NftManager::NftApprovals` (r:0 w:1)
+	/// Proof: `NftManager::NftApprovals` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `NftManager::SoulboundNfts` (r:0 w:1)
+	/// Proof: `NftManager::SoulboundNfts` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	fn proxy_signed_burn_nft() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `8048`
+		//  Estimated: `80487`
+		// Minimum execution time: 98_300_000 picoseconds.
+		Weight::from_parts(98_300_000, 80487)
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+	}
+	/// Storage: `NftManager::Nfts` (r:1 w:0)
+	/// Proof: `NftManager::Nfts` (`max_values`: None, `max_size`: Some(1179), added: 3654, mode: `MaxEncodedLen`)
+	/// Storage: `NftManager::FrozenNfts` (r:1 w:1)
+	/// Proof: `NftManager::FrozenNfts` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	fn freeze_nft() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `464`
+		//  Estimated: `4644`
+		// Minimum execution time: 14_213_000 picoseconds.
+		Weight::from_parts(14_213_000, 4644)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `NftManager::FrozenNfts` (r:1 w:1)
+	/// Proof: `NftManager::FrozenNfts` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	fn unfreeze_nft() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `351`
+		//  Estimated: `3513`
+		// Minimum execution time: 11_068_000 picoseconds.
+		Weight::from_parts(11_068_000, 3513)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Avn::EthereumAddressAccounts` (r:1 w:0)
+	/// Proof: `Avn::EthereumAddressAccounts` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
+	/// Storage: `NftManager::UnclaimedRoyalties` (r:1 w:1)
+	/// Proof: `NftManager::UnclaimedRoyalties` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn claim_royalties() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `619`
+		//  Estimated: `6196`
+		// Minimum execution time: 61_250_000 picoseconds.
+		Weight::from_parts(61_250_000, 6196)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
 }
\ No newline at end of file
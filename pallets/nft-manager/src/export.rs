@@ -0,0 +1,104 @@
+// Copyright 2024 Aventus Network Services (UK) Ltd.
+
+//! Export of nfts to Ethereum.
+//!
+//! The owner of an nft exports it by asking the bridge to mint it on tier 1 for an Ethereum
+//! recipient. The nft is locked while the bridge transaction is pending, so it can't be listed,
+//! sold or exported again. When the bridge reports that the transaction succeeded the nft is
+//! burnt on AvN, keeping its external reference in use. When the transaction fails the nft is
+//! unlocked and stays with its owner, who can export it again.
+//!
+//! The royalties of the nft are exported with it, so tier 1 pays the same split as AvN. They are
+//! packed in a `bytes` param, 24 bytes per royalty: the 20 bytes of the recipient address followed
+//! by the rate in parts per million as a big endian `uint32`.
+
+use crate::*;
+use sp_avn_common::BridgeContractMethod;
+
+extern crate alloc;
+use alloc::format;
+
+pub type NftExportOf<T> = NftExport<<T as frame_system::Config>::AccountId>;
+
+#[derive(Encode, Decode, Clone, Debug, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+pub struct NftExport<AccountId> {
+    pub nft_id: NftId,
+    pub owner: AccountId,
+    pub t1_recipient: H160,
+}
+
+impl<T: Config> Pallet<T> {
+    pub(crate) fn do_export_nft(
+        owner: T::AccountId,
+        nft_id: NftId,
+        t1_recipient: H160,
+    ) -> DispatchResult {
+        ensure!(!t1_recipient.is_zero(), Error::<T>::T1RecipientIsMandatory);
+        let nft = Self::try_get_nft(&nft_id)?;
+        ensure!(nft.owner == owner, Error::<T>::SenderIsNotOwner);
+        ensure!(!<NftOpenForSale<T>>::contains_key(&nft_id), Error::<T>::NftAlreadyListed);
+        ensure!(nft.is_locked == false, Error::<T>::NftIsLocked);
+        Self::ensure_transferable(&nft)?;
+        let info = Self::nft_infos(&nft.info_id).ok_or(Error::<T>::NftInfoMissing)?;
+
+        let function_name: &[u8] = BridgeContractMethod::ExportNft.as_bytes();
+        let params = vec![
+            (b"address".to_vec(), info.t1_authority.as_bytes().to_vec()),
+            (b"uint256".to_vec(), format!("{}", nft_id).as_bytes().to_vec()),
+            (b"address".to_vec(), t1_recipient.as_bytes().to_vec()),
+            (b"bytes".to_vec(), Self::encode_royalties_for_export(&info.royalties)),
+        ];
+        let tx_id = T::BridgeInterface::publish(function_name, &params, PALLET_ID.to_vec())?;
+
+        <Nfts<T>>::mutate(nft_id, |maybe_nft| {
+            maybe_nft.as_mut().map(|nft| {
+                nft.is_locked = true;
+                nft.nonce += 1u64;
+            })
+        });
+        <PendingNftExports<T>>::insert(
+            tx_id,
+            NftExport { nft_id, owner: owner.clone(), t1_recipient },
+        );
+
+        Self::deposit_event(Event::<T>::NftExportRequested { nft_id, owner, t1_recipient, tx_id });
+
+        Ok(())
+    }
+
+    pub(crate) fn encode_royalties_for_export(royalties: &[Royalty]) -> Vec<u8> {
+        royalties
+            .iter()
+            .flat_map(|royalty| {
+                let mut packed = royalty.recipient_t1_address.as_bytes().to_vec();
+                packed.extend_from_slice(&royalty.rate.parts_per_million.to_be_bytes());
+                packed
+            })
+            .collect()
+    }
+
+    pub(crate) fn process_nft_export_result(tx_id: u32, succeeded: bool) {
+        let export = match <PendingNftExports<T>>::take(tx_id) {
+            Some(export) => export,
+            None => return,
+        };
+
+        if succeeded {
+            Self::remove_nft(&export.nft_id);
+            Self::deposit_event(Event::<T>::NftExported {
+                nft_id: export.nft_id,
+                t1_recipient: export.t1_recipient,
+                tx_id,
+            });
+        } else {
+            <Nfts<T>>::mutate(export.nft_id, |maybe_nft| {
+                maybe_nft.as_mut().map(|nft| nft.is_locked = false)
+            });
+            Self::deposit_event(Event::<T>::NftExportFailed {
+                nft_id: export.nft_id,
+                owner: export.owner,
+                tx_id,
+            });
+        }
+    }
+}
@@ -0,0 +1,35 @@
+// Copyright 2024 Aventus Network Services (UK) Ltd.
+
+//! Freezing of disputed nfts.
+//!
+//! Governance can freeze an nft while a dispute about it is resolved. A frozen nft can't be
+//! listed, sold, auctioned, transferred or exported, whether the request comes from AvN or from a
+//! tier 1 event. An auction that ends while its nft is frozen is cancelled and the highest bid is
+//! returned. The owner can't burn a frozen nft, but governance can, so a dispute can end with the
+//! nft being revoked.
+
+use crate::*;
+
+impl<T: Config> Pallet<T> {
+    pub(crate) fn do_freeze_nft(nft_id: NftId) -> DispatchResult {
+        ensure!(<Nfts<T>>::contains_key(&nft_id), Error::<T>::NftIdDoesNotExist);
+        ensure!(!<FrozenNfts<T>>::contains_key(&nft_id), Error::<T>::NftAlreadyFrozen);
+
+        <FrozenNfts<T>>::insert(nft_id, ());
+
+        Self::deposit_event(Event::<T>::NftFrozen { nft_id });
+        Ok(())
+    }
+
+    pub(crate) fn do_unfreeze_nft(nft_id: NftId) -> DispatchResult {
+        ensure!(<FrozenNfts<T>>::take(&nft_id).is_some(), Error::<T>::NftNotFrozen);
+
+        Self::deposit_event(Event::<T>::NftUnfrozen { nft_id });
+        Ok(())
+    }
+
+    pub(crate) fn ensure_not_frozen(nft_id: &NftId) -> DispatchResult {
+        ensure!(!<FrozenNfts<T>>::contains_key(nft_id), Error::<T>::NftIsFrozen);
+        Ok(())
+    }
+}
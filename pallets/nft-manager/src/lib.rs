@@ -30,21 +30,24 @@ use frame_support::{
     weights::Weight,
     Parameter,
 };
-use frame_system::ensure_signed;
-use pallet_avn::{self as avn, BridgeInterfaceNotification, ProcessedEventsChecker};
+use frame_system::{ensure_signed, ensure_signed_or_root};
+use pallet_avn::{
+    self as avn, BridgeInterface, BridgeInterfaceNotification, NftContractChecker,
+    ProcessedEventsChecker,
+};
 use sp_avn_common::{
     event_types::{
         EthEvent, EthEventId, EventData, NftCancelListingData, NftEndBatchListingData,
-        NftTransferToData, ProcessedEventHandler,
+        NftTransferToData, PriceSchedule, ProcessedEventHandler,
     },
-    verify_signature, CallDecoder, InnerCallValidator, Proof,
+    verify_domain_separated_signature, CallDecoder, InnerCallValidator, Proof,
 };
 use sp_core::{ConstU32, H160, H256, U256};
 use sp_io::hashing::keccak_256;
 use sp_runtime::{
     scale_info::TypeInfo,
     traits::{Dispatchable, Hash, IdentifyAccount, Member, Verify},
-    BoundedVec,
+    BoundedVec, Permill,
 };
 use sp_std::prelude::*;
 
@@ -56,12 +59,43 @@ use crate::nft_data::*;
 pub mod batch_nft;
 use crate::batch_nft::*;
 
+pub mod auction;
+pub use crate::auction::*;
+
+pub mod semi_fungible;
+pub use crate::semi_fungible::*;
+
+pub mod metadata;
+pub use crate::metadata::*;
+
+pub mod approvals;
+pub use crate::approvals::*;
+
+pub mod export;
+pub use crate::export::*;
+
+pub mod collection;
+pub use crate::collection::*;
+
+pub mod burn;
+pub use crate::burn::*;
+
+pub mod marketplace_v2;
+
+pub mod freeze;
+
+pub mod migration;
+
 pub mod default_weights;
 pub use default_weights::WeightInfo;
 
+pub const PALLET_ID: &'static [u8; 11] = b"nft_manager";
+
 const SINGLE_NFT_ID_CONTEXT: &'static [u8; 1] = b"A";
 const BATCH_NFT_ID_CONTEXT: &'static [u8; 1] = b"B";
 const BATCH_ID_CONTEXT: &'static [u8; 1] = b"G";
+/// Prefix of the payloads signed for nft operations, including the batch, approval and burn ones
+pub const SIGNING_DOMAIN: &'static [u8] = b"nft_manager";
 pub const SIGNED_MINT_SINGLE_NFT_CONTEXT: &'static [u8] =
     b"authorization for mint single nft operation";
 pub const SIGNED_LIST_NFT_OPEN_FOR_SALE_CONTEXT: &'static [u8] =
@@ -72,10 +106,16 @@ pub const SIGNED_CANCEL_LIST_FIAT_NFT_CONTEXT: &'static [u8] =
     b"authorization for cancel list fiat nft for sale operation";
 pub const SIGNED_MINT_BATCH_NFT_CONTEXT: &'static [u8] =
     b"authorization for mint batch nft operation";
+pub const SIGNED_BATCH_MINT_NFT_CONTEXT: &'static [u8] =
+    b"authorization for batch mint nft operation";
 
 const MAX_NUMBER_OF_ROYALTIES: u32 = 16;
 /// Bound used for number of Royalties an NFTs that can have
 pub(crate) type NftRoyaltiesBound = ConstU32<MAX_NUMBER_OF_ROYALTIES>;
+/// Maximum number of NFTs minted by a single `batch_mint_nft` call
+const MAX_NUMBER_OF_NFTS_PER_BATCH_MINT: u32 = 50;
+/// Maximum number of NFTs returned by a single `nfts_by_owner` query
+pub const MAX_NFTS_PER_PAGE: u32 = 100;
 
 pub type NftId = U256;
 pub type NftInfoId = U256;
@@ -88,8 +128,9 @@ pub type BatchNftBound = ConstU32<16384>;
 #[frame_support::pallet]
 pub mod pallet {
     use super::*;
-    use frame_support::{pallet_prelude::*, Blake2_128Concat};
+    use frame_support::{pallet_prelude::*, traits::ReservableCurrency, Blake2_128Concat};
     use frame_system::pallet_prelude::*;
+    use sp_avn_common::EthereumAddressMapping;
 
     // Public interface of this pallet
     #[pallet::config]
@@ -124,6 +165,33 @@ pub mod pallet {
 
         #[pallet::constant]
         type BatchBound: Get<u32>;
+
+        /// The currency bids on auctions are reserved in
+        type Currency: ReservableCurrency<Self::AccountId>;
+
+        /// Gives the account that receives the royalties of a tier 1 address
+        type EthereumAddressMapping: EthereumAddressMapping<Self::AccountId>;
+
+        /// The shortest an auction can last, in blocks
+        #[pallet::constant]
+        type MinAuctionDuration: Get<BlockNumberFor<Self>>;
+
+        /// The longest an auction can last, in blocks
+        #[pallet::constant]
+        type MaxAuctionDuration: Get<BlockNumberFor<Self>>;
+
+        /// The maximum number of auctions that can end in the same block
+        #[pallet::constant]
+        type MaxAuctionsEndingPerBlock: Get<u32>;
+
+        /// The bridge that mints exported nfts on tier 1 and burns the tier 1 copies of burnt nfts
+        type BridgeInterface: avn::BridgeInterface;
+
+        /// Tells which tier 1 authorities are registered nft contracts
+        type NftContractChecker: avn::NftContractChecker;
+
+        /// The origin allowed to freeze nfts and to burn them without being their owner
+        type AdminOrigin: EnsureOrigin<<Self as frame_system::Config>::RuntimeOrigin>;
     }
 
     #[pallet::genesis_config]
@@ -221,6 +289,182 @@ pub mod pallet {
             batch_nft_id: NftBatchId,
             sale_type: NftSaleType,
         },
+        AuctionCreated {
+            nft_id: NftId,
+            seller: T::AccountId,
+            reserve_price: BalanceOf<T>,
+            end_block: BlockNumberFor<T>,
+        },
+        AuctionBid {
+            nft_id: NftId,
+            bidder: T::AccountId,
+            amount: BalanceOf<T>,
+        },
+        AuctionCancelled {
+            nft_id: NftId,
+        },
+        /// The nft was sold to the highest bidder of the auction
+        AuctionSettled {
+            nft_id: NftId,
+            seller: T::AccountId,
+            winner: T::AccountId,
+            price: BalanceOf<T>,
+        },
+        AuctionEndedWithoutBids {
+            nft_id: NftId,
+        },
+        SftMinted {
+            sft_id: SftId,
+            owner: T::AccountId,
+            authority: H160,
+            amount: u128,
+        },
+        /// More of an sft was minted on tier 1
+        EthSftMinted {
+            sft_id: SftId,
+            owner: T::AccountId,
+            amount: u128,
+            eth_event_id: EthEventId,
+        },
+        SftTransferred {
+            sft_id: SftId,
+            from: T::AccountId,
+            to: T::AccountId,
+            amount: u128,
+        },
+        SftBurned {
+            sft_id: SftId,
+            owner: T::AccountId,
+            amount: u128,
+        },
+        SftOpenForSale {
+            listing_id: SftListingId,
+            sft_id: SftId,
+            seller: T::AccountId,
+            amount: u128,
+            sale_type: NftSaleType,
+        },
+        EthSftTransfer {
+            listing_id: SftListingId,
+            new_owner: T::AccountId,
+            amount: u128,
+            eth_event_id: EthEventId,
+        },
+        FiatSftTransfer {
+            listing_id: SftListingId,
+            new_owner: T::AccountId,
+            amount: u128,
+        },
+        CancelSftFiatListing {
+            listing_id: SftListingId,
+        },
+        /// The unique external reference of an nft or sft was replaced
+        MetadataUpdated {
+            nft_id: NftId,
+            unique_external_ref: BoundedVec<u8, NftExternalRefBound>,
+            updated_by: T::AccountId,
+        },
+        MetadataAuthoritySet {
+            info_id: NftInfoId,
+            authority: Option<T::AccountId>,
+        },
+        NftApproved {
+            nft_id: NftId,
+            owner: T::AccountId,
+            operator: T::AccountId,
+        },
+        NftApprovalRevoked {
+            nft_id: NftId,
+            owner: T::AccountId,
+        },
+        /// The operator can act for the owner on all the nfts of the info that the owner holds
+        CollectionApproved {
+            info_id: NftInfoId,
+            owner: T::AccountId,
+            operator: T::AccountId,
+        },
+        CollectionApprovalRevoked {
+            info_id: NftInfoId,
+            owner: T::AccountId,
+            operator: T::AccountId,
+        },
+        /// The nft is locked until the bridge transaction `tx_id` mints it on tier 1
+        NftExportRequested {
+            nft_id: NftId,
+            owner: T::AccountId,
+            t1_recipient: H160,
+            tx_id: u32,
+        },
+        NftExported {
+            nft_id: NftId,
+            t1_recipient: H160,
+            tx_id: u32,
+        },
+        /// The nft could not be minted on tier 1 and was unlocked for its owner
+        NftExportFailed {
+            nft_id: NftId,
+            owner: T::AccountId,
+            tx_id: u32,
+        },
+        CollectionCreated {
+            collection_id: CollectionId,
+            owner: Option<T::AccountId>,
+            t1_authority: H160,
+        },
+        CollectionUpdated {
+            collection_id: CollectionId,
+        },
+        CollectionOwnerSet {
+            collection_id: CollectionId,
+            owner: Option<T::AccountId>,
+        },
+        NftBurned {
+            nft_id: NftId,
+            owner: T::AccountId,
+        },
+        /// The burn of the tier 1 copy of the nft was published. The nft is locked until the
+        /// bridge reports the result.
+        NftBurnRequested {
+            nft_id: NftId,
+            owner: T::AccountId,
+            tx_id: u32,
+        },
+        /// The nft could not be burnt on tier 1 and was unlocked for its owner
+        NftBurnFailed {
+            nft_id: NftId,
+            owner: T::AccountId,
+            tx_id: u32,
+        },
+        /// A v2 marketplace set the price schedule of a batch listed for Ethereum sale
+        BatchPriceScheduleSet {
+            batch_id: NftBatchId,
+            schedule: PriceSchedule,
+            eth_event_id: EthEventId,
+        },
+        /// An offer for an nft listed for Ethereum sale was accepted on a v2 marketplace
+        EthNftOfferAccepted {
+            nft_id: NftId,
+            new_owner: T::AccountId,
+            price: u128,
+            op_id: u64,
+            eth_event_id: EthEventId,
+        },
+        NftFrozen {
+            nft_id: NftId,
+        },
+        NftUnfrozen {
+            nft_id: NftId,
+        },
+        /// A royalty of a tier 1 address that is not registered was held until it is claimed
+        RoyaltyHeld {
+            recipient_t1_address: H160,
+            amount: BalanceOf<T>,
+        },
+        RoyaltiesClaimed {
+            recipient_t1_address: H160,
+            account: T::AccountId,
+            amount: BalanceOf<T>,
+        },
     }
 
     #[pallet::error]
@@ -278,6 +522,57 @@ pub mod pallet {
         RoyaltiesOutOfBounds,
         /// Batch size is out of bounds
         BatchOutOfBounds,
+        AuctionDoesNotExist,
+        AuctionReservePriceIsZero,
+        /// The auction duration is outside the allowed range
+        InvalidAuctionDuration,
+        /// The maximum number of auctions already end in the block this auction would end in
+        TooManyAuctionsEndingInBlock,
+        AuctionHasEnded,
+        /// An auction with bids can't be cancelled
+        AuctionHasBids,
+        SellerCannotBid,
+        BidBelowReservePrice,
+        /// A bid must be higher than the current highest bid
+        BidNotAboveHighestBid,
+        InsufficientBalanceForBid,
+        BatchMintIsEmpty,
+        /// More NFTs than `MAX_NUMBER_OF_NFTS_PER_BATCH_MINT` were minted in one call
+        TooManyNftsInBatchMint,
+        UnauthorizedSignedBatchMintNftTransaction,
+        SftDoesNotExist,
+        SftAmountIsZero,
+        InsufficientSftBalance,
+        /// The supply of an sft can't exceed u128::MAX
+        SftSupplyOverflow,
+        SftListingDoesNotExist,
+        /// More was sold than the amount left in the listing
+        SftListingAmountExceeded,
+        SftNotListedForEthereumSale,
+        SftNotListedForFiatSale,
+        SenderIsNotMetadataAuthority,
+        /// An owner can't approve themselves as an operator
+        CannotApproveOwner,
+        ApprovalNotFound,
+        UnauthorizedSignedApproveNftTransaction,
+        UnauthorizedSignedApproveCollectionTransaction,
+        UnauthorizedSignedBurnNftTransaction,
+        T1RecipientIsMandatory,
+        CollectionDoesNotExist,
+        SenderIsNotCollectionOwner,
+        /// The nfts of the collection can't be listed, sold or exported
+        CollectionNotTransferable,
+        /// Base URI size is out of bounds
+        BaseUriOutOfBounds,
+        /// The nft can't change owner, it can only be burnt
+        NftIsSoulbound,
+        NftIsFrozen,
+        NftAlreadyFrozen,
+        NftNotFrozen,
+        /// The tier 1 address of the royalties is not registered to the sender
+        SenderIsNotRoyaltyRecipient,
+        NoRoyaltiesToClaim,
+        CollectionHasNfts,
     }
 
     /// A mapping between NFT Id and data
@@ -286,6 +581,18 @@ pub mod pallet {
     pub type Nfts<T: Config> =
         StorageMap<_, Blake2_128Concat, NftId, Nft<T::AccountId>, OptionQuery>;
 
+    /// The nfts of each owner
+    #[pallet::storage]
+    pub type NftsByOwner<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        Blake2_128Concat,
+        NftId,
+        (),
+        OptionQuery,
+    >;
+
     /// A mapping between NFT info Id and info data
     #[pallet::storage]
     #[pallet::getter(fn nft_infos)]
@@ -339,6 +646,146 @@ pub mod pallet {
     pub type BatchOpenForSale<T: Config> =
         StorageMap<_, Blake2_128Concat, NftBatchId, NftSaleType, ValueQuery>;
 
+    /// The price schedules set by v2 marketplaces for batches listed for Ethereum sale
+    #[pallet::storage]
+    #[pallet::getter(fn batch_price_schedule)]
+    pub type BatchPriceSchedules<T: Config> =
+        StorageMap<_, Blake2_128Concat, NftBatchId, PriceSchedule, OptionQuery>;
+
+    /// The auctions that have not ended yet
+    #[pallet::storage]
+    #[pallet::getter(fn auction)]
+    pub type Auctions<T: Config> =
+        StorageMap<_, Blake2_128Concat, NftId, AuctionOf<T>, OptionQuery>;
+
+    /// The nfts whose auction ends at the start of a block
+    #[pallet::storage]
+    #[pallet::getter(fn auctions_ending_at)]
+    pub type AuctionsEndingAt<T: Config> = StorageMap<
+        _,
+        Twox64Concat,
+        BlockNumberFor<T>,
+        BoundedVec<NftId, T::MaxAuctionsEndingPerBlock>,
+        ValueQuery,
+    >;
+
+    /// The royalties held for tier 1 addresses that were not registered when they were paid
+    #[pallet::storage]
+    #[pallet::getter(fn unclaimed_royalties)]
+    pub type UnclaimedRoyalties<T: Config> =
+        StorageMap<_, Blake2_128Concat, H160, BalanceOf<T>, ValueQuery>;
+
+    /// The semi-fungible tokens
+    #[pallet::storage]
+    #[pallet::getter(fn sft)]
+    pub type SemiFungibleTokens<T: Config> =
+        StorageMap<_, Blake2_128Concat, SftId, SemiFungibleToken<T::AccountId>, OptionQuery>;
+
+    /// The amount of an sft an account owns, excluding the amount it has listed for sale
+    #[pallet::storage]
+    #[pallet::getter(fn sft_balance)]
+    pub type SftBalances<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        SftId,
+        Blake2_128Concat,
+        T::AccountId,
+        u128,
+        ValueQuery,
+    >;
+
+    /// The amounts of sfts listed for sale
+    #[pallet::storage]
+    #[pallet::getter(fn sft_listing)]
+    pub type SftListings<T: Config> =
+        StorageMap<_, Blake2_128Concat, SftListingId, SftListing<T::AccountId>, OptionQuery>;
+
+    /// The Id that will be used for the next sft listing
+    #[pallet::storage]
+    pub type NextSftListingId<T: Config> = StorageValue<_, SftListingId, ValueQuery>;
+
+    /// The account allowed to update the metadata of the tokens of an nft info
+    #[pallet::storage]
+    #[pallet::getter(fn metadata_authority)]
+    pub type MetadataAuthorities<T: Config> =
+        StorageMap<_, Blake2_128Concat, NftInfoId, T::AccountId, OptionQuery>;
+
+    /// The latest metadata changes of an nft or sft, oldest first
+    #[pallet::storage]
+    #[pallet::getter(fn metadata_history)]
+    pub type MetadataHistory<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        NftId,
+        BoundedVec<MetadataChangeOf<T>, MetadataHistoryBound>,
+        ValueQuery,
+    >;
+
+    /// The operator approved to act for the owner of an nft, until the nft changes owner
+    #[pallet::storage]
+    #[pallet::getter(fn nft_approval)]
+    pub type NftApprovals<T: Config> =
+        StorageMap<_, Blake2_128Concat, NftId, T::AccountId, OptionQuery>;
+
+    /// The operators approved by an owner for all of their nfts of an nft info
+    #[pallet::storage]
+    pub type CollectionApprovals<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        (T::AccountId, NftInfoId),
+        Blake2_128Concat,
+        T::AccountId,
+        (),
+        OptionQuery,
+    >;
+
+    /// The nft exports waiting for the result of their bridge transaction
+    #[pallet::storage]
+    #[pallet::getter(fn pending_nft_export)]
+    pub type PendingNftExports<T: Config> =
+        StorageMap<_, Blake2_128Concat, u32, NftExportOf<T>, OptionQuery>;
+
+    /// The nft burns waiting for the result of their bridge transaction
+    #[pallet::storage]
+    #[pallet::getter(fn pending_nft_burn)]
+    pub type PendingNftBurns<T: Config> =
+        StorageMap<_, Blake2_128Concat, u32, NftBurnOf<T>, OptionQuery>;
+
+    /// The collections of nfts
+    #[pallet::storage]
+    #[pallet::getter(fn collection)]
+    pub type Collections<T: Config> =
+        StorageMap<_, Blake2_128Concat, CollectionId, Collection<T::AccountId>, OptionQuery>;
+
+    /// The collection an nft info belongs to
+    #[pallet::storage]
+    #[pallet::getter(fn info_collection)]
+    pub type InfoCollections<T: Config> =
+        StorageMap<_, Blake2_128Concat, NftInfoId, CollectionId, OptionQuery>;
+
+    /// The implicit collection of the single nfts of a tier 1 authority
+    #[pallet::storage]
+    #[pallet::getter(fn contract_collection)]
+    pub type ContractCollections<T: Config> =
+        StorageMap<_, Blake2_128Concat, H160, CollectionId, OptionQuery>;
+
+    /// The Id that will be used for the next collection
+    #[pallet::storage]
+    pub type NextCollectionId<T: Config> = StorageValue<_, CollectionId, ValueQuery>;
+
+    /// Collections that nfts have been minted into. They can't be made non transferable anymore.
+    #[pallet::storage]
+    pub type CollectionsWithNfts<T: Config> =
+        StorageMap<_, Blake2_128Concat, CollectionId, (), OptionQuery>;
+
+    /// Nfts that are bound to the account they were minted to
+    #[pallet::storage]
+    pub type SoulboundNfts<T: Config> = StorageMap<_, Blake2_128Concat, NftId, (), OptionQuery>;
+
+    /// Nfts frozen by governance while a dispute is resolved
+    #[pallet::storage]
+    pub type FrozenNfts<T: Config> = StorageMap<_, Blake2_128Concat, NftId, (), OptionQuery>;
+
     #[pallet::call]
     impl<T: Config> Pallet<T> {
         /// Mint a single NFT
@@ -395,7 +842,7 @@ pub mod pallet {
             unique_external_ref: Vec<u8>,
             royalties: Vec<Royalty>,
             t1_authority: H160,
-        ) -> DispatchResult {
+        ) -> DispatchResultWithPostInfo {
             let sender = ensure_signed(origin)?;
             ensure!(sender == proof.signer, Error::<T>::SenderIsNotSigner);
             let bounded_unique_external_ref =
@@ -414,11 +861,12 @@ pub mod pallet {
                 &t1_authority,
             );
             ensure!(
-                verify_signature::<T::Signature, T::AccountId>(&proof, &signed_payload.as_slice())
-                    .is_ok(),
+                Self::verify_signed_payload(&proof, &signed_payload).is_ok(),
                 Error::<T>::UnauthorizedSignedMintSingleNftTransaction
             );
 
+            let royalties_count = royalties.len() as u32;
+
             // We trust the input for the value of t1_authority
             let nft_id =
                 Self::generate_nft_id_single_mint(&t1_authority, Self::get_unique_id_and_advance());
@@ -441,7 +889,8 @@ pub mod pallet {
                 authority: info.t1_authority,
             });
 
-            Ok(())
+            Ok(Some(<T as pallet::Config>::WeightInfo::signed_mint_single_nft(royalties_count))
+                .into())
         }
 
         /// List an nft open for sale
@@ -474,8 +923,7 @@ pub mod pallet {
 
             let signed_payload = Self::encode_list_nft_for_sale_params(&proof, &nft_id, &market)?;
             ensure!(
-                verify_signature::<T::Signature, T::AccountId>(&proof, &signed_payload.as_slice())
-                    .is_ok(),
+                Self::verify_signed_payload(&proof, &signed_payload).is_ok(),
                 Error::<T>::UnauthorizedSignedLiftNftOpenForSaleTransaction
             );
 
@@ -506,8 +954,7 @@ pub mod pallet {
             let signed_payload =
                 Self::encode_transfer_fiat_nft_params(&proof, &nft_id, &t2_transfer_to_public_key)?;
             ensure!(
-                verify_signature::<T::Signature, T::AccountId>(&proof, &signed_payload.as_slice())
-                    .is_ok(),
+                Self::verify_signed_payload(&proof, &signed_payload).is_ok(),
                 Error::<T>::UnauthorizedSignedTransferFiatNftTransaction
             );
 
@@ -542,8 +989,7 @@ pub mod pallet {
             let nft = Self::try_get_nft(&nft_id)?;
             let signed_payload = Self::encode_cancel_list_fiat_nft_params(&proof, &nft_id)?;
             ensure!(
-                verify_signature::<T::Signature, T::AccountId>(&proof, &signed_payload.as_slice())
-                    .is_ok(),
+                Self::verify_signed_payload(&proof, &signed_payload).is_ok(),
                 Error::<T>::UnauthorizedSignedCancelListFiatNftTransaction
             );
 
@@ -568,7 +1014,11 @@ pub mod pallet {
         #[pallet::weight(<T as pallet::Config>::WeightInfo::proxy_signed_list_nft_open_for_sale()
             .max(<T as pallet::Config>::WeightInfo::proxy_signed_mint_single_nft(MAX_NUMBER_OF_ROYALTIES))
             .max(<T as pallet::Config>::WeightInfo::proxy_signed_transfer_fiat_nft())
-            .max(<T as pallet::Config>::WeightInfo::proxy_signed_cancel_list_fiat_nft()))]
+            .max(<T as pallet::Config>::WeightInfo::proxy_signed_cancel_list_fiat_nft())
+            .max(<T as pallet::Config>::WeightInfo::proxy_signed_batch_mint_nft(MAX_NUMBER_OF_NFTS_PER_BATCH_MINT))
+            .max(<T as pallet::Config>::WeightInfo::proxy_signed_approve_nft())
+            .max(<T as pallet::Config>::WeightInfo::proxy_signed_approve_collection())
+            .max(<T as pallet::Config>::WeightInfo::proxy_signed_burn_nft()))]
         pub fn proxy(
             origin: OriginFor<T>,
             call: Box<<T as Config>::RuntimeCall>,
@@ -615,8 +1065,7 @@ pub mod pallet {
                 &sender_nonce,
             );
             ensure!(
-                verify_signature::<T::Signature, T::AccountId>(&proof, &signed_payload.as_slice())
-                    .is_ok(),
+                Self::verify_signed_payload(&proof, &signed_payload).is_ok(),
                 Error::<T>::UnauthorizedSignedCreateBatchTransaction
             );
 
@@ -682,8 +1131,7 @@ pub mod pallet {
                 &owner,
             );
             ensure!(
-                verify_signature::<T::Signature, T::AccountId>(&proof, &signed_payload.as_slice())
-                    .is_ok(),
+                Self::verify_signed_payload(&proof, &signed_payload).is_ok(),
                 Error::<T>::UnauthorizedSignedMintBatchNftTransaction
             );
 
@@ -704,7 +1152,7 @@ pub mod pallet {
             ensure!(sender == proof.signer, Error::<T>::SenderIsNotSigner);
             ensure!(batch_id.is_zero() == false, Error::<T>::BatchIdIsMandatory);
             ensure!(<BatchInfoId<T>>::contains_key(&batch_id), Error::<T>::BatchDoesNotExist);
-            ensure!(market != NftSaleType::Unknown, Error::<T>::UnsupportedMarket);
+            ensure!(market.is_listing_market(), Error::<T>::UnsupportedMarket);
 
             let sender_nonce = Self::batch_nonce(&sender);
             let nft_info = get_nft_info_for_batch::<T>(&batch_id)?;
@@ -722,8 +1170,7 @@ pub mod pallet {
             let signed_payload =
                 encode_list_batch_for_sale_params::<T>(&proof, &batch_id, &market, &sender_nonce);
             ensure!(
-                verify_signature::<T::Signature, T::AccountId>(&proof, &signed_payload.as_slice())
-                    .is_ok(),
+                Self::verify_signed_payload(&proof, &signed_payload).is_ok(),
                 Error::<T>::UnauthorizedSignedListBatchForSaleTransaction
             );
 
@@ -761,8 +1208,7 @@ pub mod pallet {
             let signed_payload =
                 encode_end_batch_sale_params::<T>(&proof, &batch_id, &sender_nonce);
             ensure!(
-                verify_signature::<T::Signature, T::AccountId>(&proof, &signed_payload.as_slice())
-                    .is_ok(),
+                Self::verify_signed_payload(&proof, &signed_payload).is_ok(),
                 Error::<T>::UnauthorizedSignedEndBatchSaleTransaction
             );
 
@@ -772,10 +1218,406 @@ pub mod pallet {
 
             Ok(())
         }
+
+        /// Auction an nft for `duration` blocks. Bids below `reserve_price` are rejected.
+        #[pallet::call_index(11)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::create_auction())]
+        pub fn create_auction(
+            origin: OriginFor<T>,
+            nft_id: NftId,
+            reserve_price: BalanceOf<T>,
+            duration: BlockNumberFor<T>,
+        ) -> DispatchResult {
+            let sender = ensure_signed(origin)?;
+            Self::do_create_auction(sender, nft_id, reserve_price, duration)
+        }
+
+        /// Bid on an auctioned nft. The bid is reserved until it is outbid or the auction ends.
+        #[pallet::call_index(12)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::bid())]
+        pub fn bid(origin: OriginFor<T>, nft_id: NftId, amount: BalanceOf<T>) -> DispatchResult {
+            let sender = ensure_signed(origin)?;
+            Self::do_bid(sender, nft_id, amount)
+        }
+
+        /// Cancel an auction that has no bids
+        #[pallet::call_index(13)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::cancel_auction())]
+        pub fn cancel_auction(origin: OriginFor<T>, nft_id: NftId) -> DispatchResult {
+            let sender = ensure_signed(origin)?;
+            Self::do_cancel_auction(sender, nft_id)
+        }
+
+        /// Mint several single NFTs of the same authority. Either every NFT is minted or none is.
+        #[pallet::call_index(14)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::batch_mint_nft(nfts.len() as u32))]
+        pub fn batch_mint_nft(
+            origin: OriginFor<T>,
+            nfts: Vec<MintParams>,
+            t1_authority: H160,
+        ) -> DispatchResult {
+            let sender = ensure_signed(origin)?;
+            Self::mint_single_nfts(sender, nfts, t1_authority)
+        }
+
+        /// Mint several single NFTs of the same authority, signed by the nfts owner. Either every
+        /// NFT is minted or none is.
+        #[pallet::call_index(15)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::signed_batch_mint_nft(nfts.len() as u32))]
+        pub fn signed_batch_mint_nft(
+            origin: OriginFor<T>,
+            proof: Proof<T::Signature, T::AccountId>,
+            nfts: Vec<MintParams>,
+            t1_authority: H160,
+        ) -> DispatchResult {
+            let sender = ensure_signed(origin)?;
+            ensure!(sender == proof.signer, Error::<T>::SenderIsNotSigner);
+
+            let signed_payload = Self::encode_batch_mint_nft_params(&proof, &nfts, &t1_authority);
+            ensure!(
+                Self::verify_signed_payload(&proof, &signed_payload).is_ok(),
+                Error::<T>::UnauthorizedSignedBatchMintNftTransaction
+            );
+
+            Self::mint_single_nfts(proof.signer, nfts, t1_authority)
+        }
+
+        /// Create a semi-fungible token and mint its initial `supply` to the sender
+        #[pallet::call_index(16)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::mint_sft(MAX_NUMBER_OF_ROYALTIES))]
+        pub fn mint_sft(
+            origin: OriginFor<T>,
+            unique_external_ref: Vec<u8>,
+            royalties: Vec<Royalty>,
+            t1_authority: H160,
+            supply: u128,
+        ) -> DispatchResult {
+            let sender = ensure_signed(origin)?;
+            Self::do_mint_sft(sender, unique_external_ref, royalties, t1_authority, supply)
+        }
+
+        #[pallet::call_index(17)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::transfer_sft())]
+        pub fn transfer_sft(
+            origin: OriginFor<T>,
+            sft_id: SftId,
+            to: T::AccountId,
+            amount: u128,
+        ) -> DispatchResult {
+            let sender = ensure_signed(origin)?;
+            Self::do_transfer_sft(sender, sft_id, to, amount)
+        }
+
+        #[pallet::call_index(18)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::burn_sft())]
+        pub fn burn_sft(origin: OriginFor<T>, sft_id: SftId, amount: u128) -> DispatchResult {
+            let sender = ensure_signed(origin)?;
+            Self::do_burn_sft(sender, sft_id, amount)
+        }
+
+        /// List `amount` of the sender's sft for sale on the `Ethereum` or `Fiat` market
+        #[pallet::call_index(19)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::list_sft_for_sale())]
+        pub fn list_sft_for_sale(
+            origin: OriginFor<T>,
+            sft_id: SftId,
+            amount: u128,
+            market: NftSaleType,
+        ) -> DispatchResult {
+            let sender = ensure_signed(origin)?;
+            Self::do_list_sft_for_sale(sender, sft_id, amount, market)
+        }
+
+        /// Complete the sale of part of a listing on the `Fiat` market
+        #[pallet::call_index(20)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::transfer_fiat_sft())]
+        pub fn transfer_fiat_sft(
+            origin: OriginFor<T>,
+            listing_id: SftListingId,
+            new_owner: T::AccountId,
+            amount: u128,
+        ) -> DispatchResult {
+            let sender = ensure_signed(origin)?;
+            Self::do_transfer_fiat_sft(sender, listing_id, new_owner, amount)
+        }
+
+        /// Cancel a listing on the `Fiat` market, returning what is left of it to the seller
+        #[pallet::call_index(21)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::cancel_list_fiat_sft())]
+        pub fn cancel_list_fiat_sft(
+            origin: OriginFor<T>,
+            listing_id: SftListingId,
+        ) -> DispatchResult {
+            let sender = ensure_signed(origin)?;
+            Self::do_cancel_list_fiat_sft(sender, listing_id)
+        }
+
+        /// Replace the unique external reference of an nft or sft. Only the metadata authority of
+        /// the token's info can call this.
+        #[pallet::call_index(22)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::update_metadata())]
+        pub fn update_metadata(
+            origin: OriginFor<T>,
+            nft_id: NftId,
+            unique_external_ref: Vec<u8>,
+        ) -> DispatchResult {
+            let sender = ensure_signed(origin)?;
+            Self::do_update_metadata(sender, nft_id, unique_external_ref)
+        }
+
+        /// Hand the metadata authority of an nft info to another account, or remove it with `None`
+        /// to make the metadata immutable
+        #[pallet::call_index(23)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::set_metadata_authority())]
+        pub fn set_metadata_authority(
+            origin: OriginFor<T>,
+            info_id: NftInfoId,
+            authority: Option<T::AccountId>,
+        ) -> DispatchResult {
+            let sender = ensure_signed(origin)?;
+            Self::do_set_metadata_authority(sender, info_id, authority)
+        }
+
+        /// Approve `operator` to list, sell and cancel the listing of an nft for its owner
+        #[pallet::call_index(24)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::approve_nft())]
+        pub fn approve_nft(
+            origin: OriginFor<T>,
+            nft_id: NftId,
+            operator: T::AccountId,
+        ) -> DispatchResult {
+            let sender = ensure_signed(origin)?;
+            Self::do_approve_nft(sender, nft_id, operator)
+        }
+
+        #[pallet::call_index(25)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::revoke_nft_approval())]
+        pub fn revoke_nft_approval(origin: OriginFor<T>, nft_id: NftId) -> DispatchResult {
+            let sender = ensure_signed(origin)?;
+            Self::do_revoke_nft_approval(sender, nft_id)
+        }
+
+        /// Approve `operator` for all the nfts of `info_id` the sender holds
+        #[pallet::call_index(26)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::approve_collection())]
+        pub fn approve_collection(
+            origin: OriginFor<T>,
+            info_id: NftInfoId,
+            operator: T::AccountId,
+        ) -> DispatchResult {
+            let sender = ensure_signed(origin)?;
+            Self::do_approve_collection(sender, info_id, operator)
+        }
+
+        #[pallet::call_index(27)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::revoke_collection_approval())]
+        pub fn revoke_collection_approval(
+            origin: OriginFor<T>,
+            info_id: NftInfoId,
+            operator: T::AccountId,
+        ) -> DispatchResult {
+            let sender = ensure_signed(origin)?;
+            Self::do_revoke_collection_approval(sender, info_id, operator)
+        }
+
+        /// Approve an operator for an nft, by a relayer
+        #[pallet::call_index(28)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::proxy_signed_approve_nft())]
+        pub fn signed_approve_nft(
+            origin: OriginFor<T>,
+            proof: Proof<T::Signature, T::AccountId>,
+            nft_id: NftId,
+            operator: T::AccountId,
+        ) -> DispatchResult {
+            let sender = ensure_signed(origin)?;
+            ensure!(sender == proof.signer, Error::<T>::SenderIsNotSigner);
+
+            let sender_nonce = Self::batch_nonce(&sender);
+            let signed_payload =
+                Self::encode_approve_nft_params(&proof, &nft_id, &operator, &sender_nonce);
+            ensure!(
+                Self::verify_signed_payload(&proof, &signed_payload).is_ok(),
+                Error::<T>::UnauthorizedSignedApproveNftTransaction
+            );
+
+            Self::do_approve_nft(sender.clone(), nft_id, operator)?;
+            <BatchNonces<T>>::mutate(&sender, |n| *n += 1);
+
+            Ok(())
+        }
+
+        /// Approve an operator for a collection, by a relayer
+        #[pallet::call_index(29)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::proxy_signed_approve_collection())]
+        pub fn signed_approve_collection(
+            origin: OriginFor<T>,
+            proof: Proof<T::Signature, T::AccountId>,
+            info_id: NftInfoId,
+            operator: T::AccountId,
+        ) -> DispatchResult {
+            let sender = ensure_signed(origin)?;
+            ensure!(sender == proof.signer, Error::<T>::SenderIsNotSigner);
+
+            let sender_nonce = Self::batch_nonce(&sender);
+            let signed_payload =
+                Self::encode_approve_collection_params(&proof, &info_id, &operator, &sender_nonce);
+            ensure!(
+                Self::verify_signed_payload(&proof, &signed_payload).is_ok(),
+                Error::<T>::UnauthorizedSignedApproveCollectionTransaction
+            );
+
+            Self::do_approve_collection(sender.clone(), info_id, operator)?;
+            <BatchNonces<T>>::mutate(&sender, |n| *n += 1);
+
+            Ok(())
+        }
+
+        /// Export an nft to `t1_recipient` on Ethereum. The nft is burnt once the bridge has
+        /// minted it on tier 1.
+        #[pallet::call_index(30)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::export_nft())]
+        pub fn export_nft(
+            origin: OriginFor<T>,
+            nft_id: NftId,
+            t1_recipient: H160,
+        ) -> DispatchResult {
+            let sender = ensure_signed(origin)?;
+            Self::do_export_nft(sender, nft_id, t1_recipient)
+        }
+
+        /// Create a collection owned by the sender
+        #[pallet::call_index(31)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::create_collection(MAX_NUMBER_OF_ROYALTIES))]
+        pub fn create_collection(
+            origin: OriginFor<T>,
+            t1_authority: H160,
+            royalties: Vec<Royalty>,
+            base_uri: Vec<u8>,
+            transferable: bool,
+        ) -> DispatchResult {
+            let sender = ensure_signed(origin)?;
+            Self::do_create_collection(sender, t1_authority, royalties, base_uri, transferable)
+        }
+
+        /// Replace the settings of a collection. Only the collection owner can call this.
+        #[pallet::call_index(32)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::update_collection(MAX_NUMBER_OF_ROYALTIES))]
+        pub fn update_collection(
+            origin: OriginFor<T>,
+            collection_id: CollectionId,
+            royalties: Vec<Royalty>,
+            base_uri: Vec<u8>,
+            transferable: bool,
+        ) -> DispatchResult {
+            let sender = ensure_signed(origin)?;
+            Self::do_update_collection(sender, collection_id, royalties, base_uri, transferable)
+        }
+
+        /// Hand a collection to another account, or remove its owner with `None`. Callable by the
+        /// collection owner or root.
+        #[pallet::call_index(33)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::set_collection_owner())]
+        pub fn set_collection_owner(
+            origin: OriginFor<T>,
+            collection_id: CollectionId,
+            owner: Option<T::AccountId>,
+        ) -> DispatchResult {
+            let sender = ensure_signed_or_root(origin)?;
+            Self::do_set_collection_owner(sender, collection_id, owner)
+        }
+
+        /// Mint an nft into a collection of the sender
+        #[pallet::call_index(34)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::mint_collection_nft(MAX_NUMBER_OF_ROYALTIES))]
+        pub fn mint_collection_nft(
+            origin: OriginFor<T>,
+            collection_id: CollectionId,
+            owner: T::AccountId,
+            unique_external_ref: Vec<u8>,
+        ) -> DispatchResult {
+            let sender = ensure_signed(origin)?;
+            Self::do_mint_collection_nft(sender, collection_id, owner, unique_external_ref, false)
+        }
+
+        /// Mint an nft into a collection of the sender that can never leave `owner`. It can still
+        /// be burnt by its owner or root.
+        #[pallet::call_index(35)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::mint_soulbound_nft(MAX_NUMBER_OF_ROYALTIES))]
+        pub fn mint_soulbound_nft(
+            origin: OriginFor<T>,
+            collection_id: CollectionId,
+            owner: T::AccountId,
+            unique_external_ref: Vec<u8>,
+        ) -> DispatchResult {
+            let sender = ensure_signed(origin)?;
+            Self::do_mint_collection_nft(sender, collection_id, owner, unique_external_ref, true)
+        }
+
+        /// Destroy an nft. Callable by the owner of the nft or the admin origin.
+        #[pallet::call_index(36)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::burn_nft())]
+        pub fn burn_nft(origin: OriginFor<T>, nft_id: NftId) -> DispatchResult {
+            let sender = match T::AdminOrigin::try_origin(origin) {
+                Ok(_) => None,
+                Err(origin) => Some(ensure_signed(origin)?),
+            };
+            Self::do_burn_nft(sender, nft_id)
+        }
+
+        /// Burn an nft by a relayer
+        #[pallet::call_index(37)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::proxy_signed_burn_nft())]
+        pub fn signed_burn_nft(
+            origin: OriginFor<T>,
+            proof: Proof<T::Signature, T::AccountId>,
+            nft_id: NftId,
+        ) -> DispatchResult {
+            let sender = ensure_signed(origin)?;
+            ensure!(sender == proof.signer, Error::<T>::SenderIsNotSigner);
+
+            let signed_payload = Self::encode_burn_nft_params(&proof, &nft_id)?;
+            ensure!(
+                Self::verify_signed_payload(&proof, &signed_payload).is_ok(),
+                Error::<T>::UnauthorizedSignedBurnNftTransaction
+            );
+
+            Self::do_burn_nft(Some(sender), nft_id)
+        }
+
+        /// Freeze a disputed nft, so that it can't change hands until it is unfrozen
+        #[pallet::call_index(38)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::freeze_nft())]
+        pub fn freeze_nft(origin: OriginFor<T>, nft_id: NftId) -> DispatchResult {
+            T::AdminOrigin::ensure_origin(origin)?;
+            Self::do_freeze_nft(nft_id)
+        }
+
+        #[pallet::call_index(39)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::unfreeze_nft())]
+        pub fn unfreeze_nft(origin: OriginFor<T>, nft_id: NftId) -> DispatchResult {
+            T::AdminOrigin::ensure_origin(origin)?;
+            Self::do_unfreeze_nft(nft_id)
+        }
+
+        /// Claim the auction royalties held for `recipient_t1_address`. The address must be
+        /// registered to the sender.
+        #[pallet::call_index(40)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::claim_royalties())]
+        pub fn claim_royalties(origin: OriginFor<T>, recipient_t1_address: H160) -> DispatchResult {
+            let sender = ensure_signed(origin)?;
+            Self::do_claim_royalties(sender, recipient_t1_address)
+        }
     }
 
     #[pallet::hooks]
     impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        fn on_initialize(n: BlockNumberFor<T>) -> Weight {
+            Self::settle_auctions_ending_at(n)
+                .saturating_add(
+                    pallet_avn::migration::migrate::<T, migration::SteppedMigrations<T>>(),
+                )
+        }
+
         // Note: this "special" function will run during every runtime upgrade. Any complicated
         // migration logic should be done in a separate function so it can be tested
         // properly.
@@ -786,7 +1628,7 @@ pub mod pallet {
                 onchain_version,
                 Pallet::<T>::current_storage_version(),
             );
-            return Weight::from_parts(0 as u64, 0)
+            pallet_avn::migration::migrate::<T, migration::SteppedMigrations<T>>()
         }
     }
 }
@@ -805,6 +1647,52 @@ impl<T: Config> Pallet<T> {
         Ok(())
     }
 
+    /// Mints every NFT of `nfts` to `owner`. The whole call is reverted if any of them fails.
+    fn mint_single_nfts(
+        owner: T::AccountId,
+        nfts: Vec<MintParams>,
+        t1_authority: H160,
+    ) -> DispatchResult {
+        ensure!(!nfts.is_empty(), Error::<T>::BatchMintIsEmpty);
+        ensure!(
+            nfts.len() as u32 <= MAX_NUMBER_OF_NFTS_PER_BATCH_MINT,
+            Error::<T>::TooManyNftsInBatchMint
+        );
+
+        for MintParams { unique_external_ref, royalties } in nfts.into_iter() {
+            let bounded_unique_external_ref =
+                BoundedVec::<u8, NftExternalRefBound>::try_from(unique_external_ref)
+                    .map_err(|_| Error::<T>::ExternalRefOutOfBounds)?;
+            Self::validate_mint_single_nft_request(
+                &bounded_unique_external_ref,
+                &royalties,
+                t1_authority,
+            )?;
+
+            let nft_id =
+                Self::generate_nft_id_single_mint(&t1_authority, Self::get_unique_id_and_advance());
+            ensure!(Nfts::<T>::contains_key(&nft_id) == false, Error::<T>::NftAlreadyExists);
+
+            let info_id = Self::get_info_id_and_advance();
+            let (nft, info) = Self::insert_single_nft_into_chain(
+                info_id,
+                BoundedVec::try_from(royalties).map_err(|_| Error::<T>::RoyaltiesOutOfBounds)?,
+                t1_authority,
+                nft_id,
+                bounded_unique_external_ref,
+                owner.clone(),
+            );
+
+            Self::deposit_event(Event::<T>::SingleNftMinted {
+                nft_id: nft.nft_id,
+                owner: nft.owner,
+                authority: info.t1_authority,
+            });
+        }
+
+        Ok(())
+    }
+
     fn validate_external_ref(
         unique_external_ref: &BoundedVec<u8, NftExternalRefBound>,
     ) -> DispatchResult {
@@ -829,18 +1717,39 @@ impl<T: Config> Pallet<T> {
         Ok(())
     }
 
+    /// The amount each royalty recipient of the NFT is owed when it is sold for `price`.
+    /// Every on-chain sale should pay these before paying the seller.
+    pub fn royalty_payments(nft_id: &NftId, price: BalanceOf<T>) -> Vec<(H160, BalanceOf<T>)> {
+        Self::try_get_nft(nft_id)
+            .ok()
+            .and_then(|nft| Self::nft_infos(nft.info_id))
+            .map(|info| info.royalties.into_inner())
+            .unwrap_or_default()
+            .into_iter()
+            .map(|royalty| {
+                let amount = Permill::from_parts(royalty.rate.parts_per_million).mul_floor(price);
+                (royalty.recipient_t1_address, amount)
+            })
+            .collect()
+    }
+
     fn validate_open_for_sale_request(
         sender: T::AccountId,
         nft_id: NftId,
         market: NftSaleType,
     ) -> DispatchResult {
-        ensure!(market != NftSaleType::Unknown, Error::<T>::UnsupportedMarket);
+        ensure!(market.is_listing_market(), Error::<T>::UnsupportedMarket);
+        Self::validate_nft_can_be_listed(sender, nft_id)
+    }
+
+    fn validate_nft_can_be_listed(sender: T::AccountId, nft_id: NftId) -> DispatchResult {
         ensure!(<Nfts<T>>::contains_key(&nft_id) == true, Error::<T>::NftIdDoesNotExist);
         ensure!(<NftOpenForSale<T>>::contains_key(&nft_id) == false, Error::<T>::NftAlreadyListed);
 
         let nft = Self::try_get_nft(&nft_id)?;
-        ensure!(nft.owner == sender, Error::<T>::SenderIsNotOwner);
+        Self::ensure_owner_or_operator(&sender, &nft)?;
         ensure!(nft.is_locked == false, Error::<T>::NftIsLocked);
+        Self::ensure_transferable(&nft)?;
 
         Ok(())
     }
@@ -854,8 +1763,9 @@ impl<T: Config> Pallet<T> {
 
         let nft = Self::try_get_nft(&nft_id)?;
 
-        ensure!(nft.owner == sender, Error::<T>::SenderIsNotOwner);
+        Self::ensure_owner_or_operator(&sender, &nft)?;
         ensure!(nft.is_locked == false, Error::<T>::NftIsLocked);
+        Self::ensure_transferable(&nft)?;
 
         Ok(())
     }
@@ -887,6 +1797,8 @@ impl<T: Config> Pallet<T> {
         let nft = Nft::new(nft_id, info_id, unique_external_ref, owner.clone());
 
         <NftInfos<T>>::insert(info.info_id, &info);
+        <MetadataAuthorities<T>>::insert(info.info_id, owner);
+        Self::add_to_contract_collection(info.info_id, t1_authority);
 
         Self::add_nft(&nft);
         return (nft, info)
@@ -946,6 +1858,7 @@ impl<T: Config> Pallet<T> {
     }
 
     fn transfer_nft(nft_id: &NftId, new_nft_owner: &T::AccountId) -> DispatchResult {
+        Self::ensure_not_frozen(nft_id)?;
         Self::remove_listing_from_open_for_sale(nft_id)?;
         Self::update_owner_for_transfer(nft_id, new_nft_owner);
         Ok(())
@@ -956,17 +1869,53 @@ impl<T: Config> Pallet<T> {
     fn update_owner_for_transfer(nft_id: &NftId, new_nft_owner: &T::AccountId) {
         <Nfts<T>>::mutate(nft_id, |maybe_nft| {
             maybe_nft.as_mut().map(|nft| {
+                <NftsByOwner<T>>::remove(&nft.owner, nft_id);
+                <NftsByOwner<T>>::insert(new_nft_owner, nft_id, ());
                 nft.owner = new_nft_owner.clone();
                 nft.nonce += 1u64;
             })
         });
+        <NftApprovals<T>>::remove(nft_id);
     }
 
     // See https://github.com/Aventus-Network-Services/avn-tier2/pull/991#discussion_r832470480 for details of why we have this
     // as a separate function
     fn add_nft(nft: &Nft<T::AccountId>) {
         <Nfts<T>>::insert(nft.nft_id, &nft);
+        <NftsByOwner<T>>::insert(&nft.owner, nft.nft_id, ());
         <UsedExternalReferences<T>>::insert(&nft.unique_external_ref, true);
+        if let Some(collection_id) = Self::info_collection(nft.info_id) {
+            <CollectionsWithNfts<T>>::insert(collection_id, ());
+        }
+    }
+
+    /// Removes an nft that left AvN. Its external reference stays in use.
+    fn remove_nft(nft_id: &NftId) {
+        if let Some(nft) = <Nfts<T>>::take(nft_id) {
+            <NftsByOwner<T>>::remove(&nft.owner, nft_id);
+        }
+        <NftApprovals<T>>::remove(nft_id);
+        <FrozenNfts<T>>::remove(nft_id);
+    }
+
+    /// Returns up to `limit` nfts of `owner`, starting after `start_key`. Pass the id of the last
+    /// nft of a page as the `start_key` of the next one. The order is stable but is not the order
+    /// of the nft ids.
+    pub fn nfts_by_owner(
+        owner: &T::AccountId,
+        start_key: Option<NftId>,
+        limit: u32,
+    ) -> Vec<Nft<T::AccountId>> {
+        let limit = limit.min(MAX_NFTS_PER_PAGE) as usize;
+        let nft_ids = match start_key {
+            Some(start_key) => <NftsByOwner<T>>::iter_key_prefix_from(
+                owner,
+                <NftsByOwner<T>>::hashed_key_for(owner, start_key),
+            ),
+            None => <NftsByOwner<T>>::iter_key_prefix(owner),
+        };
+
+        nft_ids.take(limit).filter_map(|nft_id| Self::nfts(nft_id)).collect()
     }
 
     fn cancel_eth_nft_listing(
@@ -1017,6 +1966,16 @@ impl<T: Config> Pallet<T> {
                         <T as pallet::Config>::WeightInfo::proxy_signed_transfer_fiat_nft(),
                     Call::signed_cancel_list_fiat_nft { .. } =>
                         <T as pallet::Config>::WeightInfo::proxy_signed_cancel_list_fiat_nft(),
+                    Call::signed_batch_mint_nft { nfts, .. } =>
+                        <T as pallet::Config>::WeightInfo::proxy_signed_batch_mint_nft(
+                            nfts.len() as u32
+                        ),
+                    Call::signed_approve_nft { .. } =>
+                        <T as pallet::Config>::WeightInfo::proxy_signed_approve_nft(),
+                    Call::signed_approve_collection { .. } =>
+                        <T as pallet::Config>::WeightInfo::proxy_signed_approve_collection(),
+                    Call::signed_burn_nft { .. } =>
+                        <T as pallet::Config>::WeightInfo::proxy_signed_burn_nft(),
                     _ => <T as pallet::Config>::WeightInfo::proxy_signed_list_nft_open_for_sale()
                         .max(<T as pallet::Config>::WeightInfo::proxy_signed_mint_single_nft(
                             MAX_NUMBER_OF_ROYALTIES,
@@ -1034,6 +1993,19 @@ impl<T: Config> Pallet<T> {
         }
     }
 
+    /// Verifies `proof` over a payload built with `SIGNING_DOMAIN`
+    fn verify_signed_payload(
+        proof: &Proof<T::Signature, T::AccountId>,
+        signed_payload: &[u8],
+    ) -> Result<(), ()> {
+        verify_domain_separated_signature::<T::Signature, T::AccountId>(
+            proof,
+            SIGNING_DOMAIN,
+            signed_payload,
+            avn::Pallet::<T>::legacy_signed_payloads_accepted(),
+        )
+    }
+
     fn encode_mint_single_nft_params(
         proof: &Proof<T::Signature, T::AccountId>,
         unique_external_ref: &Vec<u8>,
@@ -1041,6 +2013,7 @@ impl<T: Config> Pallet<T> {
         t1_authority: &H160,
     ) -> Vec<u8> {
         return (
+            SIGNING_DOMAIN,
             SIGNED_MINT_SINGLE_NFT_CONTEXT,
             &proof.relayer,
             unique_external_ref,
@@ -1050,6 +2023,15 @@ impl<T: Config> Pallet<T> {
             .encode()
     }
 
+    fn encode_batch_mint_nft_params(
+        proof: &Proof<T::Signature, T::AccountId>,
+        nfts: &Vec<MintParams>,
+        t1_authority: &H160,
+    ) -> Vec<u8> {
+        return (SIGNING_DOMAIN, SIGNED_BATCH_MINT_NFT_CONTEXT, &proof.relayer, nfts, t1_authority)
+            .encode()
+    }
+
     fn encode_list_nft_for_sale_params(
         proof: &Proof<T::Signature, T::AccountId>,
         nft_id: &NftId,
@@ -1057,6 +2039,7 @@ impl<T: Config> Pallet<T> {
     ) -> Result<Vec<u8>, Error<T>> {
         let nft = Self::try_get_nft(nft_id)?;
         return Ok((
+            SIGNING_DOMAIN,
             SIGNED_LIST_NFT_OPEN_FOR_SALE_CONTEXT,
             &proof.relayer,
             nft_id,
@@ -1072,7 +2055,14 @@ impl<T: Config> Pallet<T> {
         recipient: &H256,
     ) -> Result<Vec<u8>, Error<T>> {
         let nft = Self::try_get_nft(nft_id)?;
-        return Ok((SIGNED_TRANSFER_FIAT_NFT_CONTEXT, &proof.relayer, nft_id, recipient, nft.nonce)
+        return Ok((
+            SIGNING_DOMAIN,
+            SIGNED_TRANSFER_FIAT_NFT_CONTEXT,
+            &proof.relayer,
+            nft_id,
+            recipient,
+            nft.nonce,
+        )
             .encode())
     }
 
@@ -1081,7 +2071,14 @@ impl<T: Config> Pallet<T> {
         nft_id: &NftId,
     ) -> Result<Vec<u8>, Error<T>> {
         let nft = Self::try_get_nft(nft_id)?;
-        return Ok((SIGNED_CANCEL_LIST_FIAT_NFT_CONTEXT, &proof.relayer, nft_id, nft.nonce).encode())
+        return Ok((
+            SIGNING_DOMAIN,
+            SIGNED_CANCEL_LIST_FIAT_NFT_CONTEXT,
+            &proof.relayer,
+            nft_id,
+            nft.nonce,
+        )
+            .encode())
     }
 
     fn get_encoded_call_param(
@@ -1172,6 +2169,30 @@ impl<T: Config> Pallet<T> {
                     encode_end_batch_sale_params::<T>(proof, batch_id, &sender_nonce),
                 ))
             },
+            Call::signed_batch_mint_nft { proof, nfts, t1_authority } =>
+                return Some((proof, Self::encode_batch_mint_nft_params(proof, nfts, t1_authority))),
+            Call::signed_approve_nft { proof, nft_id, operator } => {
+                let sender_nonce = Self::batch_nonce(&proof.signer);
+                return Some((
+                    proof,
+                    Self::encode_approve_nft_params(proof, nft_id, operator, &sender_nonce),
+                ))
+            },
+            Call::signed_approve_collection { proof, info_id, operator } => {
+                let sender_nonce = Self::batch_nonce(&proof.signer);
+                return Some((
+                    proof,
+                    Self::encode_approve_collection_params(proof, info_id, operator, &sender_nonce),
+                ))
+            },
+            Call::signed_burn_nft { proof, nft_id } => {
+                let encoded_data = Self::encode_burn_nft_params(proof, nft_id);
+                if encoded_data.is_err() {
+                    return None
+                }
+
+                return Some((proof, encoded_data.expect("checked for none")))
+            },
             _ => return None,
         }
     }
@@ -1195,6 +2216,12 @@ impl<T: Config> Pallet<T> {
                 process_mint_batch_nft_event::<T>(&event.event_id, data),
             EventData::LogNftEndBatchListing(data) =>
                 process_end_batch_listing_event::<T>(&event.event_id, data),
+            EventData::LogSftMinted(data) => Self::mint_eth_sft(&event.event_id, data),
+            EventData::LogSftTransferTo(data) => Self::transfer_eth_sft(&event.event_id, data),
+            EventData::LogNftBatchListingScheduled(data) =>
+                Self::set_eth_batch_price_schedule(&event.event_id, data),
+            EventData::LogNftOfferAccepted(data) =>
+                Self::accept_eth_nft_offer(&event.event_id, data),
             _ => Ok(()),
         }
     }
@@ -1207,7 +2234,14 @@ impl<T: Config> ProcessedEventHandler for Pallet<T> {
 }
 
 impl<T: Config> BridgeInterfaceNotification for Pallet<T> {
-    fn process_result(_: u32, _: Vec<u8>, _: bool) -> DispatchResult {
+    fn process_result(tx_id: u32, caller_id: Vec<u8>, succeeded: bool) -> DispatchResult {
+        if caller_id == PALLET_ID.to_vec() {
+            if <PendingNftExports<T>>::contains_key(tx_id) {
+                Self::process_nft_export_result(tx_id, succeeded);
+            } else {
+                Self::process_nft_burn_result(tx_id, succeeded);
+            }
+        }
         Ok(())
     }
     #[cfg(feature = "ethbridge-all-events")]
@@ -1239,6 +2273,10 @@ impl<T: Config> CallDecoder for Pallet<T> {
             Call::signed_mint_batch_nft { proof, .. } => return Ok(proof.clone()),
             Call::signed_list_batch_for_sale { proof, .. } => return Ok(proof.clone()),
             Call::signed_end_batch_sale { proof, .. } => return Ok(proof.clone()),
+            Call::signed_batch_mint_nft { proof, .. } => return Ok(proof.clone()),
+            Call::signed_approve_nft { proof, .. } => return Ok(proof.clone()),
+            Call::signed_approve_collection { proof, .. } => return Ok(proof.clone()),
+            Call::signed_burn_nft { proof, .. } => return Ok(proof.clone()),
             _ => return Err(Error::TransactionNotSupported),
         }
     }
@@ -1249,18 +2287,14 @@ impl<T: Config> InnerCallValidator for Pallet<T> {
 
     fn signature_is_valid(call: &Box<Self::Call>) -> bool {
         if let Some((proof, signed_payload)) = Self::get_encoded_call_param(call) {
-            return verify_signature::<T::Signature, T::AccountId>(
-                &proof,
-                &signed_payload.as_slice(),
-            )
-            .is_ok()
+            return Self::verify_signed_payload(&proof, &signed_payload).is_ok()
         }
 
         return false
     }
 }
 
-const STORAGE_VERSION: StorageVersion = StorageVersion::new(4);
+const STORAGE_VERSION: StorageVersion = StorageVersion::new(6);
 
 #[cfg(test)]
 #[path = "tests/mock.rs"]
@@ -1302,4 +2336,52 @@ pub mod cancel_single_nft_listing_tests;
 #[path = "tests/batch_nft_tests.rs"]
 pub mod batch_nft_tests;
 
+#[cfg(test)]
+#[path = "tests/auction_tests.rs"]
+pub mod auction_tests;
+
+#[cfg(test)]
+#[path = "tests/batch_mint_tests.rs"]
+pub mod batch_mint_tests;
+
+#[cfg(test)]
+#[path = "tests/semi_fungible_tests.rs"]
+pub mod semi_fungible_tests;
+
+#[cfg(test)]
+#[path = "tests/metadata_tests.rs"]
+pub mod metadata_tests;
+
+#[cfg(test)]
+#[path = "tests/approval_tests.rs"]
+pub mod approval_tests;
+
+#[cfg(test)]
+#[path = "tests/export_tests.rs"]
+pub mod export_tests;
+
+#[cfg(test)]
+#[path = "tests/collection_tests.rs"]
+pub mod collection_tests;
+
+#[cfg(test)]
+#[path = "tests/soulbound_tests.rs"]
+pub mod soulbound_tests;
+
+#[cfg(test)]
+#[path = "tests/burn_tests.rs"]
+pub mod burn_tests;
+
+#[cfg(test)]
+#[path = "tests/nfts_by_owner_tests.rs"]
+pub mod nfts_by_owner_tests;
+
+#[cfg(test)]
+#[path = "tests/marketplace_v2_tests.rs"]
+pub mod marketplace_v2_tests;
+
+#[cfg(test)]
+#[path = "tests/freeze_tests.rs"]
+pub mod freeze_tests;
+
 mod benchmarking;
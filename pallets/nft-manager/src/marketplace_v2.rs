@@ -0,0 +1,69 @@
+// Copyright 2024 Aventus Network Services (UK) Ltd.
+
+//! Handling of the events of the v2 nft marketplace contracts.
+//!
+//! A v2 marketplace can sell a batch listed for Ethereum sale at a price that changes over time.
+//! The price schedule it announces is recorded against the batch until the listing ends, so
+//! wallets can show the current price without querying Ethereum. It can also sell an nft listed
+//! for Ethereum sale by accepting an offer, which transfers the nft to the buyer like a sale does.
+//!
+//! Like the events of the original marketplace, these events are only accepted when they come
+//! from a contract registered as an nft contract in the ethereum-events pallet.
+
+use crate::*;
+use sp_avn_common::event_types::{NftBatchListingScheduleData, NftOfferAcceptedData};
+
+impl<T: Config> Pallet<T> {
+    pub(crate) fn set_eth_batch_price_schedule(
+        event_id: &EthEventId,
+        data: &NftBatchListingScheduleData,
+    ) -> DispatchResult {
+        ensure!(
+            T::ProcessedEventsChecker::processed_event_exists(event_id),
+            Error::<T>::NoTier1EventForNftOperation
+        );
+        ensure!(
+            <BatchOpenForSale<T>>::get(data.batch_id) == NftSaleType::Ethereum,
+            Error::<T>::BatchNotListedForEthereumSale
+        );
+
+        <BatchPriceSchedules<T>>::insert(data.batch_id, data.schedule.clone());
+
+        Self::deposit_event(Event::<T>::BatchPriceScheduleSet {
+            batch_id: data.batch_id,
+            schedule: data.schedule.clone(),
+            eth_event_id: event_id.clone(),
+        });
+
+        Ok(())
+    }
+
+    pub(crate) fn accept_eth_nft_offer(
+        event_id: &EthEventId,
+        data: &NftOfferAcceptedData,
+    ) -> DispatchResult {
+        let market = Self::get_nft_open_for_sale_on(data.nft_id);
+        ensure!(market == NftSaleType::Ethereum, Error::<T>::NftNotListedForEthereumSale);
+
+        let nft = Self::try_get_nft(&data.nft_id)?;
+        ensure!(data.op_id == nft.nonce, Error::<T>::NftNonceMismatch);
+        ensure!(
+            T::ProcessedEventsChecker::processed_event_exists(event_id),
+            Error::<T>::NoTier1EventForNftOperation
+        );
+
+        let buyer = T::AccountId::decode(&mut data.t2_transfer_to_public_key.as_bytes())
+            .expect("32 bytes will always decode into an AccountId");
+        Self::transfer_nft(&data.nft_id, &buyer)?;
+
+        Self::deposit_event(Event::<T>::EthNftOfferAccepted {
+            nft_id: data.nft_id,
+            new_owner: buyer,
+            price: data.price,
+            op_id: data.op_id,
+            eth_event_id: event_id.clone(),
+        });
+
+        Ok(())
+    }
+}
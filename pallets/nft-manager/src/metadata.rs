@@ -0,0 +1,97 @@
+// Copyright 2024 Aventus Network Services (UK) Ltd.
+
+//! Corrections of the metadata of nfts and sfts.
+//!
+//! The metadata of a token is its unique external reference. Each `NftInfo` has a metadata
+//! authority, the account that minted it, which can replace the external reference of the tokens
+//! of the info and hand the role to another account. A replaced reference stays in use, so it can't
+//! be given to a new token, and is kept in a bounded history of the token. The metadata of an nft
+//! can't change while it is listed for sale.
+
+use crate::*;
+use frame_system::pallet_prelude::BlockNumberFor;
+
+/// The number of metadata changes kept for a token. The oldest change is dropped first.
+pub const MAX_METADATA_HISTORY: u32 = 10;
+pub type MetadataHistoryBound = ConstU32<MAX_METADATA_HISTORY>;
+pub type MetadataChangeOf<T> = MetadataChange<BlockNumberFor<T>>;
+
+#[derive(Encode, Decode, Clone, Debug, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+pub struct MetadataChange<BlockNumber> {
+    pub previous_external_ref: BoundedVec<u8, NftExternalRefBound>,
+    pub changed_at: BlockNumber,
+}
+
+impl<T: Config> Pallet<T> {
+    pub(crate) fn do_update_metadata(
+        sender: T::AccountId,
+        nft_id: NftId,
+        unique_external_ref: Vec<u8>,
+    ) -> DispatchResult {
+        let unique_external_ref =
+            BoundedVec::<u8, NftExternalRefBound>::try_from(unique_external_ref)
+                .map_err(|_| Error::<T>::ExternalRefOutOfBounds)?;
+        Self::validate_external_ref(&unique_external_ref)?;
+
+        let previous_external_ref = if let Some(mut nft) = Self::nfts(&nft_id) {
+            ensure!(
+                Self::get_nft_open_for_sale_on(&nft_id) == NftSaleType::Unknown,
+                Error::<T>::NftAlreadyListed
+            );
+            Self::ensure_metadata_authority(&sender, &nft.info_id)?;
+            let previous_external_ref = nft.unique_external_ref;
+            nft.unique_external_ref = unique_external_ref.clone();
+            <Nfts<T>>::insert(nft_id, nft);
+            previous_external_ref
+        } else if let Some(mut sft) = Self::sft(&nft_id) {
+            Self::ensure_metadata_authority(&sender, &sft.info_id)?;
+            let previous_external_ref = sft.unique_external_ref;
+            sft.unique_external_ref = unique_external_ref.clone();
+            <SemiFungibleTokens<T>>::insert(nft_id, sft);
+            previous_external_ref
+        } else {
+            Err(Error::<T>::NftIdDoesNotExist)?
+        };
+
+        <UsedExternalReferences<T>>::insert(&unique_external_ref, true);
+        <MetadataHistory<T>>::mutate(nft_id, |history| {
+            if history.is_full() {
+                history.remove(0);
+            }
+            let _ = history.try_push(MetadataChange {
+                previous_external_ref,
+                changed_at: <frame_system::Pallet<T>>::block_number(),
+            });
+        });
+
+        Self::deposit_event(Event::<T>::MetadataUpdated {
+            nft_id,
+            unique_external_ref,
+            updated_by: sender,
+        });
+
+        Ok(())
+    }
+
+    pub(crate) fn do_set_metadata_authority(
+        sender: T::AccountId,
+        info_id: NftInfoId,
+        authority: Option<T::AccountId>,
+    ) -> DispatchResult {
+        Self::ensure_metadata_authority(&sender, &info_id)?;
+
+        <MetadataAuthorities<T>>::set(info_id, authority.clone());
+
+        Self::deposit_event(Event::<T>::MetadataAuthoritySet { info_id, authority });
+
+        Ok(())
+    }
+
+    fn ensure_metadata_authority(sender: &T::AccountId, info_id: &NftInfoId) -> DispatchResult {
+        ensure!(
+            Self::metadata_authority(info_id).as_ref() == Some(sender),
+            Error::<T>::SenderIsNotMetadataAuthority
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,104 @@
+// Copyright 2024 Aventus Network Services (UK) Ltd.
+
+use crate::{
+    CollectionsWithNfts, Config, InfoCollections, NftBatches, NftInfos, Nfts, NftsByOwner, Pallet,
+    Vec,
+};
+use frame_support::{pallet_prelude::PhantomData, traits::Get, weights::Weight};
+use pallet_avn::migration::SteppedMigration;
+
+/// Migrations run over several blocks by the pallet hooks
+pub type SteppedMigrations<T> = (AssignNftsToCollections<T>, IndexNftsByOwner<T>);
+
+/// Migration to put every nft info minted before collections existed into a collection. The cursor
+/// is the raw storage key of the last nft info assigned.
+pub struct AssignNftsToCollections<T>(PhantomData<T>);
+impl<T: Config> SteppedMigration for AssignNftsToCollections<T> {
+    type Pallet = Pallet<T>;
+    type Cursor = Vec<u8>;
+
+    const FROM: u16 = 4;
+    const TO: u16 = 5;
+
+    fn step(cursor: Option<Vec<u8>>, limit: Weight) -> (Weight, Option<Vec<u8>>) {
+        //Read: [NftInfos, InfoCollections, ContractCollections, NextCollectionId, NftBatches]
+        //Write: [Collections, InfoCollections, ContractCollections, NextCollectionId,
+        //        CollectionsWithNfts]
+        let weight_per_info = T::DbWeight::get().reads_writes(5, 5);
+        let mut used = Weight::zero();
+
+        let mut infos = match cursor {
+            Some(last_key) => <NftInfos<T>>::iter_from(last_key),
+            None => <NftInfos<T>>::iter(),
+        };
+
+        loop {
+            if used.saturating_add(weight_per_info).any_gt(limit) {
+                return (used, Some(infos.last_raw_key().to_vec()))
+            }
+
+            let info = match infos.next() {
+                Some((_, info)) => info,
+                None => return (used, None),
+            };
+            used = used.saturating_add(weight_per_info);
+
+            if <InfoCollections<T>>::contains_key(info.info_id) {
+                continue
+            }
+
+            let has_nfts = match info.batch_id {
+                Some(batch_id) => {
+                    Pallet::<T>::add_batch_to_new_collection(&info);
+                    <NftBatches<T>>::decode_len(batch_id).unwrap_or_default() > 0
+                },
+                None => {
+                    // A single nft is minted with its info
+                    Pallet::<T>::add_to_contract_collection(info.info_id, info.t1_authority);
+                    true
+                },
+            };
+            if has_nfts {
+                if let Some(collection_id) = <InfoCollections<T>>::get(info.info_id) {
+                    <CollectionsWithNfts<T>>::insert(collection_id, ());
+                }
+            }
+        }
+    }
+}
+
+/// Migration to build the index of nfts by owner. The cursor is the raw storage key of the last
+/// nft indexed.
+pub struct IndexNftsByOwner<T>(PhantomData<T>);
+impl<T: Config> SteppedMigration for IndexNftsByOwner<T> {
+    type Pallet = Pallet<T>;
+    type Cursor = Vec<u8>;
+
+    const FROM: u16 = 5;
+    const TO: u16 = 6;
+
+    fn step(cursor: Option<Vec<u8>>, limit: Weight) -> (Weight, Option<Vec<u8>>) {
+        //Read: [Nfts], Write: [NftsByOwner]
+        let weight_per_nft = T::DbWeight::get().reads_writes(1, 1);
+        let mut used = Weight::zero();
+
+        let mut nfts = match cursor {
+            Some(last_key) => <Nfts<T>>::iter_from(last_key),
+            None => <Nfts<T>>::iter(),
+        };
+
+        loop {
+            if used.saturating_add(weight_per_nft).any_gt(limit) {
+                return (used, Some(nfts.last_raw_key().to_vec()))
+            }
+
+            let nft = match nfts.next() {
+                Some((_, nft)) => nft,
+                None => return (used, None),
+            };
+            used = used.saturating_add(weight_per_nft);
+
+            <NftsByOwner<T>>::insert(&nft.owner, nft.nft_id, ());
+        }
+    }
+}
@@ -27,6 +27,13 @@ pub struct Royalty {
     pub rate: RoyaltyRate,
 }
 
+/// The parameters of one of the NFTs minted by `batch_mint_nft`
+#[derive(Encode, Decode, Default, Debug, Clone, PartialEq, TypeInfo)]
+pub struct MintParams {
+    pub unique_external_ref: Vec<u8>,
+    pub royalties: Vec<Royalty>,
+}
+
 /// Royalty Rate Examples:
 ///     - 1%: { parts_per_million = 10000 }
 ///     - 0.03%: { parts_per_million = 300 }
@@ -136,6 +143,16 @@ pub enum NftSaleType {
     Unknown, // value used by Default interface. Needed for Maps default value.
     Ethereum,
     Fiat,
+    /// Sold natively on AvN by an auction of the nft-manager
+    Auction,
+}
+
+impl NftSaleType {
+    /// Returns true for the markets an nft or batch can be listed on directly. Nfts are put on
+    /// the `Auction` market by creating an auction.
+    pub fn is_listing_market(&self) -> bool {
+        return *self == NftSaleType::Ethereum || *self == NftSaleType::Fiat
+    }
 }
 
 impl Default for NftSaleType {
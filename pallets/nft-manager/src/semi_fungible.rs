@@ -0,0 +1,281 @@
+// Copyright 2024 Aventus Network Services (UK) Ltd.
+
+//! Semi-fungible tokens, in the style of ERC-1155.
+//!
+//! A semi-fungible token (sft) has a supply shared between accounts, instead of a single owner.
+//! Its royalties and authority are kept in an `NftInfo`, like those of an nft, and its id is
+//! generated from the same unique id as single nfts so the two never collide. The creator mints
+//! the initial supply, and tier 1 can mint more of it to an account with an `AvnMintSemiFungibleTo`
+//! event.
+//!
+//! An owner lists part of their balance for sale on a market. The listed amount is taken from
+//! their balance until it is sold or the listing is cancelled. Listings on the `Fiat` market are
+//! sold and cancelled by the seller. Listings on the `Ethereum` market are sold by
+//! `AvnSemiFungibleTransferTo` events, and tier 1 ends them by transferring what is left back to
+//! the seller.
+
+use crate::*;
+use sp_avn_common::event_types::{SftMintData, SftTransferToData};
+
+pub type SftId = U256;
+pub type SftListingId = u64;
+
+#[derive(Encode, Decode, Clone, Debug, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+pub struct SemiFungibleToken<AccountId> {
+    pub info_id: NftInfoId,
+    pub unique_external_ref: BoundedVec<u8, NftExternalRefBound>,
+    pub creator: AccountId,
+    /// Sum of the balances and listed amounts of the token
+    pub total_supply: u128,
+}
+
+#[derive(Encode, Decode, Clone, Debug, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+pub struct SftListing<AccountId> {
+    pub sft_id: SftId,
+    pub seller: AccountId,
+    /// The amount left for sale
+    pub amount: u128,
+    pub market: NftSaleType,
+}
+
+impl<T: Config> Pallet<T> {
+    pub(crate) fn do_mint_sft(
+        creator: T::AccountId,
+        unique_external_ref: Vec<u8>,
+        royalties: Vec<Royalty>,
+        t1_authority: H160,
+        supply: u128,
+    ) -> DispatchResult {
+        ensure!(supply > 0, Error::<T>::SftAmountIsZero);
+        let bounded_unique_external_ref =
+            BoundedVec::<u8, NftExternalRefBound>::try_from(unique_external_ref)
+                .map_err(|_| Error::<T>::ExternalRefOutOfBounds)?;
+        Self::validate_mint_single_nft_request(
+            &bounded_unique_external_ref,
+            &royalties,
+            t1_authority,
+        )?;
+
+        let sft_id =
+            Self::generate_nft_id_single_mint(&t1_authority, Self::get_unique_id_and_advance());
+        ensure!(!<SemiFungibleTokens<T>>::contains_key(&sft_id), Error::<T>::NftAlreadyExists);
+
+        let info_id = Self::get_info_id_and_advance();
+        let info = NftInfo {
+            creator: Some(creator.clone()),
+            ..NftInfo::new(
+                info_id,
+                BoundedVec::try_from(royalties).map_err(|_| Error::<T>::RoyaltiesOutOfBounds)?,
+                t1_authority,
+            )
+        };
+        <NftInfos<T>>::insert(info_id, info);
+        <MetadataAuthorities<T>>::insert(info_id, &creator);
+        Self::add_to_contract_collection(info_id, t1_authority);
+        <UsedExternalReferences<T>>::insert(&bounded_unique_external_ref, true);
+        <SemiFungibleTokens<T>>::insert(
+            sft_id,
+            SemiFungibleToken {
+                info_id,
+                unique_external_ref: bounded_unique_external_ref,
+                creator: creator.clone(),
+                total_supply: supply,
+            },
+        );
+        <SftBalances<T>>::insert(sft_id, &creator, supply);
+
+        Self::deposit_event(Event::<T>::SftMinted {
+            sft_id,
+            owner: creator,
+            authority: t1_authority,
+            amount: supply,
+        });
+
+        Ok(())
+    }
+
+    pub(crate) fn do_transfer_sft(
+        from: T::AccountId,
+        sft_id: SftId,
+        to: T::AccountId,
+        amount: u128,
+    ) -> DispatchResult {
+        ensure!(amount > 0, Error::<T>::SftAmountIsZero);
+        ensure!(<SemiFungibleTokens<T>>::contains_key(&sft_id), Error::<T>::SftDoesNotExist);
+
+        Self::withdraw_sft(&sft_id, &from, amount)?;
+        Self::deposit_sft(&sft_id, &to, amount)?;
+
+        Self::deposit_event(Event::<T>::SftTransferred { sft_id, from, to, amount });
+
+        Ok(())
+    }
+
+    pub(crate) fn do_burn_sft(owner: T::AccountId, sft_id: SftId, amount: u128) -> DispatchResult {
+        ensure!(amount > 0, Error::<T>::SftAmountIsZero);
+        let mut sft = Self::sft(&sft_id).ok_or(Error::<T>::SftDoesNotExist)?;
+
+        Self::withdraw_sft(&sft_id, &owner, amount)?;
+        sft.total_supply = sft.total_supply.saturating_sub(amount);
+        <SemiFungibleTokens<T>>::insert(sft_id, sft);
+
+        Self::deposit_event(Event::<T>::SftBurned { sft_id, owner, amount });
+
+        Ok(())
+    }
+
+    pub(crate) fn do_list_sft_for_sale(
+        seller: T::AccountId,
+        sft_id: SftId,
+        amount: u128,
+        market: NftSaleType,
+    ) -> DispatchResult {
+        ensure!(market.is_listing_market(), Error::<T>::UnsupportedMarket);
+        ensure!(amount > 0, Error::<T>::SftAmountIsZero);
+        ensure!(<SemiFungibleTokens<T>>::contains_key(&sft_id), Error::<T>::SftDoesNotExist);
+
+        Self::withdraw_sft(&sft_id, &seller, amount)?;
+
+        let listing_id = <NextSftListingId<T>>::mutate(|next_id| {
+            let listing_id = *next_id;
+            *next_id = next_id.saturating_add(1);
+            listing_id
+        });
+        <SftListings<T>>::insert(
+            listing_id,
+            SftListing { sft_id, seller: seller.clone(), amount, market },
+        );
+
+        Self::deposit_event(Event::<T>::SftOpenForSale {
+            listing_id,
+            sft_id,
+            seller,
+            amount,
+            sale_type: market,
+        });
+
+        Ok(())
+    }
+
+    pub(crate) fn do_transfer_fiat_sft(
+        sender: T::AccountId,
+        listing_id: SftListingId,
+        new_owner: T::AccountId,
+        amount: u128,
+    ) -> DispatchResult {
+        let listing = Self::sft_listing(listing_id).ok_or(Error::<T>::SftListingDoesNotExist)?;
+        ensure!(listing.market == NftSaleType::Fiat, Error::<T>::SftNotListedForFiatSale);
+        ensure!(listing.seller == sender, Error::<T>::SenderIsNotOwner);
+
+        Self::sell_from_listing(listing_id, listing, &new_owner, amount)?;
+
+        Self::deposit_event(Event::<T>::FiatSftTransfer { listing_id, new_owner, amount });
+
+        Ok(())
+    }
+
+    pub(crate) fn do_cancel_list_fiat_sft(
+        sender: T::AccountId,
+        listing_id: SftListingId,
+    ) -> DispatchResult {
+        let listing = Self::sft_listing(listing_id).ok_or(Error::<T>::SftListingDoesNotExist)?;
+        ensure!(listing.market == NftSaleType::Fiat, Error::<T>::SftNotListedForFiatSale);
+        ensure!(listing.seller == sender, Error::<T>::SenderIsNotOwner);
+
+        <SftListings<T>>::remove(listing_id);
+        Self::deposit_sft(&listing.sft_id, &listing.seller, listing.amount)?;
+
+        Self::deposit_event(Event::<T>::CancelSftFiatListing { listing_id });
+
+        Ok(())
+    }
+
+    pub(crate) fn mint_eth_sft(event_id: &EthEventId, data: &SftMintData) -> DispatchResult {
+        let mut sft = Self::sft(&data.sft_id).ok_or(Error::<T>::SftDoesNotExist)?;
+        ensure!(
+            T::ProcessedEventsChecker::processed_event_exists(event_id),
+            Error::<T>::NoTier1EventForNftOperation
+        );
+
+        let owner = T::AccountId::decode(&mut data.t2_owner_public_key.as_bytes())
+            .expect("32 bytes will always decode into an AccountId");
+        sft.total_supply =
+            sft.total_supply.checked_add(data.amount).ok_or(Error::<T>::SftSupplyOverflow)?;
+        // A balance can't overflow when the total supply doesn't
+        Self::deposit_sft(&data.sft_id, &owner, data.amount)?;
+        <SemiFungibleTokens<T>>::insert(data.sft_id, sft);
+
+        Self::deposit_event(Event::<T>::EthSftMinted {
+            sft_id: data.sft_id,
+            owner,
+            amount: data.amount,
+            eth_event_id: event_id.clone(),
+        });
+
+        Ok(())
+    }
+
+    pub(crate) fn transfer_eth_sft(
+        event_id: &EthEventId,
+        data: &SftTransferToData,
+    ) -> DispatchResult {
+        let listing =
+            Self::sft_listing(data.listing_id).ok_or(Error::<T>::SftListingDoesNotExist)?;
+        ensure!(listing.market == NftSaleType::Ethereum, Error::<T>::SftNotListedForEthereumSale);
+        ensure!(
+            T::ProcessedEventsChecker::processed_event_exists(event_id),
+            Error::<T>::NoTier1EventForNftOperation
+        );
+
+        let new_owner = T::AccountId::decode(&mut data.t2_transfer_to_public_key.as_bytes())
+            .expect("32 bytes will always decode into an AccountId");
+        Self::sell_from_listing(data.listing_id, listing, &new_owner, data.amount)?;
+
+        Self::deposit_event(Event::<T>::EthSftTransfer {
+            listing_id: data.listing_id,
+            new_owner,
+            amount: data.amount,
+            eth_event_id: event_id.clone(),
+        });
+
+        Ok(())
+    }
+
+    fn sell_from_listing(
+        listing_id: SftListingId,
+        mut listing: SftListing<T::AccountId>,
+        new_owner: &T::AccountId,
+        amount: u128,
+    ) -> DispatchResult {
+        ensure!(amount > 0, Error::<T>::SftAmountIsZero);
+        listing.amount =
+            listing.amount.checked_sub(amount).ok_or(Error::<T>::SftListingAmountExceeded)?;
+
+        Self::deposit_sft(&listing.sft_id, new_owner, amount)?;
+        if listing.amount == 0 {
+            <SftListings<T>>::remove(listing_id);
+        } else {
+            <SftListings<T>>::insert(listing_id, listing);
+        }
+
+        Ok(())
+    }
+
+    fn withdraw_sft(sft_id: &SftId, owner: &T::AccountId, amount: u128) -> DispatchResult {
+        <SftBalances<T>>::try_mutate_exists(sft_id, owner, |balance| {
+            let remaining = balance
+                .unwrap_or_default()
+                .checked_sub(amount)
+                .ok_or(Error::<T>::InsufficientSftBalance)?;
+            *balance = Some(remaining).filter(|remaining| *remaining > 0);
+            Ok(())
+        })
+    }
+
+    fn deposit_sft(sft_id: &SftId, owner: &T::AccountId, amount: u128) -> DispatchResult {
+        <SftBalances<T>>::try_mutate(sft_id, owner, |balance| {
+            *balance = balance.checked_add(amount).ok_or(Error::<T>::SftSupplyOverflow)?;
+            Ok(())
+        })
+    }
+}
@@ -0,0 +1,440 @@
+// Copyright 2024 Aventus Network Services (UK) Ltd.
+
+#![cfg(test)]
+
+use super::*;
+use crate::mock::{
+    AccountId, RuntimeCall as MockCall, RuntimeEvent as Event, RuntimeOrigin as Origin, *,
+};
+use frame_support::{assert_noop, assert_ok};
+use hex_literal::hex;
+use sp_core::sr25519::Pair;
+
+const T1_AUTHORITY: H160 = H160(hex!("11111AAAAA22222BBBBB11111AAAAA22222BBBBB"));
+
+fn owner() -> TestAccount {
+    TestAccount::new([1u8; 32])
+}
+
+fn operator() -> TestAccount {
+    TestAccount::new([2u8; 32])
+}
+
+fn relayer() -> AccountId {
+    TestAccount::new([3u8; 32]).account_id()
+}
+
+fn buyer_public_key() -> H256 {
+    H256::repeat_byte(4)
+}
+
+fn mint_nft() -> NftId {
+    let unique_id = NftManager::next_unique_id();
+    let nft_id = NftManager::generate_nft_id_single_mint(&T1_AUTHORITY, unique_id);
+    assert_ok!(NftManager::mint_single_nft(
+        Origin::signed(owner().account_id()),
+        format!("Offchain location of NFT {}", unique_id).into_bytes(),
+        vec![],
+        T1_AUTHORITY,
+    ));
+    nft_id
+}
+
+fn info_id_of(nft_id: NftId) -> NftInfoId {
+    NftManager::nfts(nft_id).unwrap().info_id
+}
+
+fn event_emitted(event: crate::Event<TestRuntime>) -> bool {
+    System::events().iter().any(|a| a.event == Event::NftManager(event.clone()))
+}
+
+fn transfer_fiat_nft_as(signer: &TestAccount, nft_id: NftId) -> DispatchResult {
+    let nonce = NftManager::nfts(nft_id).unwrap().nonce;
+    let relayer = relayer();
+    let data_to_sign =
+        (SIGNED_TRANSFER_FIAT_NFT_CONTEXT, &relayer, nft_id, buyer_public_key(), nonce);
+    let proof = Proof {
+        signer: signer.account_id(),
+        relayer,
+        signature: sign(&signer.key_pair(), &data_to_sign.encode()),
+    };
+    NftManager::signed_transfer_fiat_nft(
+        Origin::signed(signer.account_id()),
+        proof,
+        nft_id,
+        buyer_public_key(),
+    )
+}
+
+fn signed_approve_nft_call(
+    owner_key_pair: &Pair,
+    nft_id: NftId,
+    nonce: u64,
+) -> Box<<TestRuntime as Config>::RuntimeCall> {
+    let relayer = relayer();
+    let operator = operator().account_id();
+    let data_to_sign = (SIGNED_APPROVE_NFT_CONTEXT, &relayer, nft_id, &operator, nonce);
+    let proof = Proof {
+        signer: owner().account_id(),
+        relayer,
+        signature: sign(owner_key_pair, &data_to_sign.encode()),
+    };
+    Box::new(MockCall::NftManager(super::Call::<TestRuntime>::signed_approve_nft {
+        proof,
+        nft_id,
+        operator,
+    }))
+}
+
+mod approve_nft {
+    use super::*;
+
+    #[test]
+    fn records_the_operator() {
+        let mut ext = ExtBuilder::build_default().as_externality();
+        ext.execute_with(|| {
+            let nft_id = mint_nft();
+
+            assert_ok!(NftManager::approve_nft(
+                Origin::signed(owner().account_id()),
+                nft_id,
+                operator().account_id()
+            ));
+
+            assert_eq!(NftManager::nft_approval(nft_id), Some(operator().account_id()));
+            assert!(event_emitted(crate::Event::<TestRuntime>::NftApproved {
+                nft_id,
+                owner: owner().account_id(),
+                operator: operator().account_id(),
+            }));
+        });
+    }
+
+    #[test]
+    fn lets_the_operator_sell_the_nft() {
+        let mut ext = ExtBuilder::build_default().as_externality();
+        ext.execute_with(|| {
+            let nft_id = mint_nft();
+            assert_ok!(NftManager::approve_nft(
+                Origin::signed(owner().account_id()),
+                nft_id,
+                operator().account_id()
+            ));
+
+            assert_ok!(NftManager::list_nft_open_for_sale(
+                Origin::signed(operator().account_id()),
+                nft_id,
+                NftSaleType::Fiat
+            ));
+            assert_ok!(transfer_fiat_nft_as(&operator(), nft_id));
+
+            let buyer = AccountId::decode(&mut buyer_public_key().as_bytes()).unwrap();
+            assert_eq!(NftManager::nfts(nft_id).unwrap().owner, buyer);
+        });
+    }
+
+    #[test]
+    fn is_cleared_when_the_nft_changes_owner() {
+        let mut ext = ExtBuilder::build_default().as_externality();
+        ext.execute_with(|| {
+            let nft_id = mint_nft();
+            assert_ok!(NftManager::approve_nft(
+                Origin::signed(owner().account_id()),
+                nft_id,
+                operator().account_id()
+            ));
+            assert_ok!(NftManager::list_nft_open_for_sale(
+                Origin::signed(owner().account_id()),
+                nft_id,
+                NftSaleType::Fiat
+            ));
+
+            assert_ok!(transfer_fiat_nft_as(&owner(), nft_id));
+
+            assert_eq!(NftManager::nft_approval(nft_id), None);
+        });
+    }
+
+    mod fails_when {
+        use super::*;
+
+        #[test]
+        fn the_sender_is_not_the_owner() {
+            let mut ext = ExtBuilder::build_default().as_externality();
+            ext.execute_with(|| {
+                let nft_id = mint_nft();
+
+                assert_noop!(
+                    NftManager::approve_nft(
+                        Origin::signed(operator().account_id()),
+                        nft_id,
+                        operator().account_id()
+                    ),
+                    Error::<TestRuntime>::SenderIsNotOwner
+                );
+            });
+        }
+
+        #[test]
+        fn the_owner_approves_themselves() {
+            let mut ext = ExtBuilder::build_default().as_externality();
+            ext.execute_with(|| {
+                let nft_id = mint_nft();
+
+                assert_noop!(
+                    NftManager::approve_nft(
+                        Origin::signed(owner().account_id()),
+                        nft_id,
+                        owner().account_id()
+                    ),
+                    Error::<TestRuntime>::CannotApproveOwner
+                );
+            });
+        }
+
+        #[test]
+        fn the_nft_does_not_exist() {
+            let mut ext = ExtBuilder::build_default().as_externality();
+            ext.execute_with(|| {
+                assert_noop!(
+                    NftManager::approve_nft(
+                        Origin::signed(owner().account_id()),
+                        U256::one(),
+                        operator().account_id()
+                    ),
+                    Error::<TestRuntime>::NftIdDoesNotExist
+                );
+            });
+        }
+    }
+}
+
+mod revoke_nft_approval {
+    use super::*;
+
+    #[test]
+    fn stops_the_operator_selling_the_nft() {
+        let mut ext = ExtBuilder::build_default().as_externality();
+        ext.execute_with(|| {
+            let nft_id = mint_nft();
+            assert_ok!(NftManager::approve_nft(
+                Origin::signed(owner().account_id()),
+                nft_id,
+                operator().account_id()
+            ));
+            assert_ok!(NftManager::list_nft_open_for_sale(
+                Origin::signed(owner().account_id()),
+                nft_id,
+                NftSaleType::Fiat
+            ));
+
+            assert_ok!(NftManager::revoke_nft_approval(
+                Origin::signed(owner().account_id()),
+                nft_id
+            ));
+
+            assert_eq!(NftManager::nft_approval(nft_id), None);
+            assert!(event_emitted(crate::Event::<TestRuntime>::NftApprovalRevoked {
+                nft_id,
+                owner: owner().account_id(),
+            }));
+            assert_noop!(
+                transfer_fiat_nft_as(&operator(), nft_id),
+                Error::<TestRuntime>::SenderIsNotOwner
+            );
+        });
+    }
+
+    #[test]
+    fn fails_when_there_is_no_approval() {
+        let mut ext = ExtBuilder::build_default().as_externality();
+        ext.execute_with(|| {
+            let nft_id = mint_nft();
+
+            assert_noop!(
+                NftManager::revoke_nft_approval(Origin::signed(owner().account_id()), nft_id),
+                Error::<TestRuntime>::ApprovalNotFound
+            );
+        });
+    }
+}
+
+mod approve_collection {
+    use super::*;
+
+    #[test]
+    fn lets_the_operator_sell_the_nfts_of_the_owner() {
+        let mut ext = ExtBuilder::build_default().as_externality();
+        ext.execute_with(|| {
+            let nft_id = mint_nft();
+            let info_id = info_id_of(nft_id);
+
+            assert_ok!(NftManager::approve_collection(
+                Origin::signed(owner().account_id()),
+                info_id,
+                operator().account_id()
+            ));
+
+            assert!(event_emitted(crate::Event::<TestRuntime>::CollectionApproved {
+                info_id,
+                owner: owner().account_id(),
+                operator: operator().account_id(),
+            }));
+            assert_ok!(NftManager::list_nft_open_for_sale(
+                Origin::signed(operator().account_id()),
+                nft_id,
+                NftSaleType::Fiat
+            ));
+            assert_ok!(transfer_fiat_nft_as(&operator(), nft_id));
+        });
+    }
+
+    #[test]
+    fn does_not_cover_the_nfts_of_the_next_owner() {
+        let mut ext = ExtBuilder::build_default().as_externality();
+        ext.execute_with(|| {
+            let nft_id = mint_nft();
+            assert_ok!(NftManager::approve_collection(
+                Origin::signed(owner().account_id()),
+                info_id_of(nft_id),
+                operator().account_id()
+            ));
+            assert_ok!(NftManager::list_nft_open_for_sale(
+                Origin::signed(owner().account_id()),
+                nft_id,
+                NftSaleType::Fiat
+            ));
+            assert_ok!(transfer_fiat_nft_as(&owner(), nft_id));
+
+            assert_noop!(
+                NftManager::list_nft_open_for_sale(
+                    Origin::signed(operator().account_id()),
+                    nft_id,
+                    NftSaleType::Fiat
+                ),
+                Error::<TestRuntime>::SenderIsNotOwner
+            );
+        });
+    }
+
+    #[test]
+    fn can_be_revoked() {
+        let mut ext = ExtBuilder::build_default().as_externality();
+        ext.execute_with(|| {
+            let nft_id = mint_nft();
+            let info_id = info_id_of(nft_id);
+            assert_ok!(NftManager::approve_collection(
+                Origin::signed(owner().account_id()),
+                info_id,
+                operator().account_id()
+            ));
+
+            assert_ok!(NftManager::revoke_collection_approval(
+                Origin::signed(owner().account_id()),
+                info_id,
+                operator().account_id()
+            ));
+
+            assert!(event_emitted(crate::Event::<TestRuntime>::CollectionApprovalRevoked {
+                info_id,
+                owner: owner().account_id(),
+                operator: operator().account_id(),
+            }));
+            assert_noop!(
+                NftManager::list_nft_open_for_sale(
+                    Origin::signed(operator().account_id()),
+                    nft_id,
+                    NftSaleType::Fiat
+                ),
+                Error::<TestRuntime>::SenderIsNotOwner
+            );
+        });
+    }
+
+    mod fails_when {
+        use super::*;
+
+        #[test]
+        fn the_collection_does_not_exist() {
+            let mut ext = ExtBuilder::build_default().as_externality();
+            ext.execute_with(|| {
+                assert_noop!(
+                    NftManager::approve_collection(
+                        Origin::signed(owner().account_id()),
+                        U256::from(7u8),
+                        operator().account_id()
+                    ),
+                    Error::<TestRuntime>::NftInfoMissing
+                );
+            });
+        }
+
+        #[test]
+        fn revoking_an_approval_that_does_not_exist() {
+            let mut ext = ExtBuilder::build_default().as_externality();
+            ext.execute_with(|| {
+                let nft_id = mint_nft();
+
+                assert_noop!(
+                    NftManager::revoke_collection_approval(
+                        Origin::signed(owner().account_id()),
+                        info_id_of(nft_id),
+                        operator().account_id()
+                    ),
+                    Error::<TestRuntime>::ApprovalNotFound
+                );
+            });
+        }
+    }
+}
+
+mod proxy_signed_approve_nft {
+    use super::*;
+
+    #[test]
+    fn approves_the_operator_and_advances_the_nonce() {
+        let mut ext = ExtBuilder::build_default().as_externality();
+        ext.execute_with(|| {
+            let nft_id = mint_nft();
+            let call = signed_approve_nft_call(&owner().key_pair(), nft_id, 0);
+
+            assert_ok!(NftManager::proxy(Origin::signed(relayer()), call));
+
+            assert_eq!(NftManager::nft_approval(nft_id), Some(operator().account_id()));
+            assert_eq!(NftManager::batch_nonce(owner().account_id()), 1);
+        });
+    }
+
+    #[test]
+    fn cannot_be_replayed() {
+        let mut ext = ExtBuilder::build_default().as_externality();
+        ext.execute_with(|| {
+            let nft_id = mint_nft();
+            let call = signed_approve_nft_call(&owner().key_pair(), nft_id, 0);
+            assert_ok!(NftManager::proxy(Origin::signed(relayer()), call.clone()));
+            assert_ok!(NftManager::revoke_nft_approval(
+                Origin::signed(owner().account_id()),
+                nft_id
+            ));
+
+            assert_noop!(
+                NftManager::proxy(Origin::signed(relayer()), call),
+                Error::<TestRuntime>::UnauthorizedSignedApproveNftTransaction
+            );
+        });
+    }
+
+    #[test]
+    fn fails_when_the_signature_is_not_from_the_owner() {
+        let mut ext = ExtBuilder::build_default().as_externality();
+        ext.execute_with(|| {
+            let nft_id = mint_nft();
+            let call = signed_approve_nft_call(&operator().key_pair(), nft_id, 0);
+
+            assert_noop!(
+                NftManager::proxy(Origin::signed(relayer()), call),
+                Error::<TestRuntime>::UnauthorizedSignedApproveNftTransaction
+            );
+        });
+    }
+}
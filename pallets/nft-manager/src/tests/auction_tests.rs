@@ -0,0 +1,542 @@
+// Copyright 2024 Aventus Network Services (UK) Ltd.
+
+#![cfg(test)]
+
+use super::*;
+use crate::mock::{AccountId, RuntimeEvent as Event, RuntimeOrigin as Origin, *};
+use frame_support::{
+    assert_noop, assert_ok,
+    traits::{Currency, Hooks},
+};
+use hex_literal::hex;
+
+const RESERVE_PRICE: u128 = 100;
+const DURATION: u64 = 20;
+const INITIAL_BALANCE: u128 = 10_000;
+// 10%
+const ROYALTY_RATE: u32 = 100_000;
+const T1_AUTHORITY: H160 = H160(hex!("11111AAAAA22222BBBBB11111AAAAA22222BBBBB"));
+const ROYALTY_RECIPIENT_T1_ADDRESS: H160 = H160(hex!("33333CCCCC44444DDDDD33333CCCCC44444DDDDD"));
+
+fn seller() -> AccountId {
+    TestAccount::new([1u8; 32]).account_id()
+}
+
+fn bidder() -> AccountId {
+    TestAccount::new([2u8; 32]).account_id()
+}
+
+fn other_bidder() -> AccountId {
+    TestAccount::new([3u8; 32]).account_id()
+}
+
+fn royalty_recipient() -> AccountId {
+    TestAccount::new([4u8; 32]).account_id()
+}
+
+fn build_ext() -> sp_io::TestExternalities {
+    ExtBuilder::build_default()
+        .with_balances(vec![(bidder(), INITIAL_BALANCE), (other_bidder(), INITIAL_BALANCE)])
+        .as_externality()
+}
+
+fn mint_nft() -> NftId {
+    let unique_id = NftManager::next_unique_id();
+    let nft_id = NftManager::generate_nft_id_single_mint(&T1_AUTHORITY, unique_id);
+    assert_ok!(NftManager::mint_single_nft(
+        Origin::signed(seller()),
+        format!("Offchain location of NFT {}", unique_id).into_bytes(),
+        vec![Royalty {
+            recipient_t1_address: ROYALTY_RECIPIENT_T1_ADDRESS,
+            rate: RoyaltyRate { parts_per_million: ROYALTY_RATE },
+        }],
+        T1_AUTHORITY,
+    ));
+    nft_id
+}
+
+fn create_auction(nft_id: NftId) {
+    assert_ok!(NftManager::create_auction(
+        Origin::signed(seller()),
+        nft_id,
+        RESERVE_PRICE,
+        DURATION
+    ));
+}
+
+fn bid(bidder: AccountId, nft_id: NftId, amount: u128) {
+    assert_ok!(NftManager::bid(Origin::signed(bidder), nft_id, amount));
+}
+
+fn end_block() -> u64 {
+    System::block_number() + DURATION
+}
+
+fn end_auctions(block: u64) {
+    System::set_block_number(block);
+    NftManager::on_initialize(block);
+}
+
+fn register_royalty_recipient() {
+    pallet_avn::EthereumAddressAccounts::<TestRuntime>::insert(
+        ROYALTY_RECIPIENT_T1_ADDRESS,
+        royalty_recipient(),
+    );
+}
+
+fn event_emitted(event: crate::Event<TestRuntime>) -> bool {
+    System::events().iter().any(|a| a.event == Event::NftManager(event.clone()))
+}
+
+mod create_auction {
+    use super::*;
+
+    #[test]
+    fn lists_the_nft_on_the_auction_market() {
+        build_ext().execute_with(|| {
+            let nft_id = mint_nft();
+            let end_block = end_block();
+
+            create_auction(nft_id);
+
+            assert_eq!(NftManager::get_nft_open_for_sale_on(nft_id), NftSaleType::Auction);
+            assert_eq!(NftManager::auctions_ending_at(end_block).to_vec(), vec![nft_id]);
+            assert_eq!(NftManager::nfts(nft_id).unwrap().nonce, 1);
+            assert!(event_emitted(crate::Event::<TestRuntime>::AuctionCreated {
+                nft_id,
+                seller: seller(),
+                reserve_price: RESERVE_PRICE,
+                end_block,
+            }));
+        });
+    }
+
+    #[test]
+    fn fails_when_the_sender_is_not_the_owner() {
+        build_ext().execute_with(|| {
+            let nft_id = mint_nft();
+
+            assert_noop!(
+                NftManager::create_auction(
+                    Origin::signed(bidder()),
+                    nft_id,
+                    RESERVE_PRICE,
+                    DURATION
+                ),
+                Error::<TestRuntime>::SenderIsNotOwner
+            );
+        });
+    }
+
+    #[test]
+    fn fails_when_the_nft_is_already_listed() {
+        build_ext().execute_with(|| {
+            let nft_id = mint_nft();
+            assert_ok!(NftManager::list_nft_open_for_sale(
+                Origin::signed(seller()),
+                nft_id,
+                NftSaleType::Fiat
+            ));
+
+            assert_noop!(
+                NftManager::create_auction(
+                    Origin::signed(seller()),
+                    nft_id,
+                    RESERVE_PRICE,
+                    DURATION
+                ),
+                Error::<TestRuntime>::NftAlreadyListed
+            );
+        });
+    }
+
+    #[test]
+    fn fails_when_the_duration_is_out_of_range() {
+        build_ext().execute_with(|| {
+            let nft_id = mint_nft();
+
+            for duration in [MinAuctionDuration::get() - 1, MaxAuctionDuration::get() + 1] {
+                assert_noop!(
+                    NftManager::create_auction(
+                        Origin::signed(seller()),
+                        nft_id,
+                        RESERVE_PRICE,
+                        duration
+                    ),
+                    Error::<TestRuntime>::InvalidAuctionDuration
+                );
+            }
+        });
+    }
+
+    #[test]
+    fn fails_when_too_many_auctions_end_in_the_same_block() {
+        build_ext().execute_with(|| {
+            create_auction(mint_nft());
+            create_auction(mint_nft());
+
+            assert_noop!(
+                NftManager::create_auction(
+                    Origin::signed(seller()),
+                    mint_nft(),
+                    RESERVE_PRICE,
+                    DURATION
+                ),
+                Error::<TestRuntime>::TooManyAuctionsEndingInBlock
+            );
+        });
+    }
+
+    #[test]
+    fn the_auction_market_can_not_be_listed_on_directly() {
+        build_ext().execute_with(|| {
+            let nft_id = mint_nft();
+
+            assert_noop!(
+                NftManager::list_nft_open_for_sale(
+                    Origin::signed(seller()),
+                    nft_id,
+                    NftSaleType::Auction
+                ),
+                Error::<TestRuntime>::UnsupportedMarket
+            );
+        });
+    }
+}
+
+mod bid {
+    use super::*;
+
+    #[test]
+    fn reserves_the_bid() {
+        build_ext().execute_with(|| {
+            let nft_id = mint_nft();
+            create_auction(nft_id);
+
+            bid(bidder(), nft_id, RESERVE_PRICE);
+
+            assert_eq!(Balances::reserved_balance(bidder()), RESERVE_PRICE);
+            assert_eq!(
+                NftManager::auction(nft_id).unwrap().highest_bid,
+                Some(Bid { bidder: bidder(), amount: RESERVE_PRICE })
+            );
+            assert!(event_emitted(crate::Event::<TestRuntime>::AuctionBid {
+                nft_id,
+                bidder: bidder(),
+                amount: RESERVE_PRICE,
+            }));
+        });
+    }
+
+    #[test]
+    fn releases_the_outbid_bid() {
+        build_ext().execute_with(|| {
+            let nft_id = mint_nft();
+            create_auction(nft_id);
+            bid(bidder(), nft_id, RESERVE_PRICE);
+
+            bid(other_bidder(), nft_id, RESERVE_PRICE + 1);
+
+            assert_eq!(Balances::reserved_balance(bidder()), 0);
+            assert_eq!(Balances::free_balance(bidder()), INITIAL_BALANCE);
+            assert_eq!(Balances::reserved_balance(other_bidder()), RESERVE_PRICE + 1);
+        });
+    }
+
+    #[test]
+    fn raising_the_highest_bid_only_reserves_the_increase() {
+        build_ext().execute_with(|| {
+            let nft_id = mint_nft();
+            create_auction(nft_id);
+            bid(bidder(), nft_id, RESERVE_PRICE);
+
+            bid(bidder(), nft_id, INITIAL_BALANCE);
+
+            assert_eq!(Balances::reserved_balance(bidder()), INITIAL_BALANCE);
+        });
+    }
+
+    #[test]
+    fn fails_below_the_reserve_price() {
+        build_ext().execute_with(|| {
+            let nft_id = mint_nft();
+            create_auction(nft_id);
+
+            assert_noop!(
+                NftManager::bid(Origin::signed(bidder()), nft_id, RESERVE_PRICE - 1),
+                Error::<TestRuntime>::BidBelowReservePrice
+            );
+        });
+    }
+
+    #[test]
+    fn fails_when_not_above_the_highest_bid() {
+        build_ext().execute_with(|| {
+            let nft_id = mint_nft();
+            create_auction(nft_id);
+            bid(bidder(), nft_id, RESERVE_PRICE);
+
+            assert_noop!(
+                NftManager::bid(Origin::signed(other_bidder()), nft_id, RESERVE_PRICE),
+                Error::<TestRuntime>::BidNotAboveHighestBid
+            );
+        });
+    }
+
+    #[test]
+    fn fails_for_the_seller() {
+        build_ext().execute_with(|| {
+            let nft_id = mint_nft();
+            create_auction(nft_id);
+
+            assert_noop!(
+                NftManager::bid(Origin::signed(seller()), nft_id, RESERVE_PRICE),
+                Error::<TestRuntime>::SellerCannotBid
+            );
+        });
+    }
+
+    #[test]
+    fn fails_without_enough_balance() {
+        build_ext().execute_with(|| {
+            let nft_id = mint_nft();
+            create_auction(nft_id);
+
+            assert_noop!(
+                NftManager::bid(Origin::signed(bidder()), nft_id, INITIAL_BALANCE + 1),
+                Error::<TestRuntime>::InsufficientBalanceForBid
+            );
+        });
+    }
+
+    #[test]
+    fn fails_once_the_auction_has_ended() {
+        build_ext().execute_with(|| {
+            let nft_id = mint_nft();
+            let end_block = end_block();
+            create_auction(nft_id);
+
+            System::set_block_number(end_block);
+
+            assert_noop!(
+                NftManager::bid(Origin::signed(bidder()), nft_id, RESERVE_PRICE),
+                Error::<TestRuntime>::AuctionHasEnded
+            );
+        });
+    }
+}
+
+mod cancel_auction {
+    use super::*;
+
+    #[test]
+    fn unlists_the_nft() {
+        build_ext().execute_with(|| {
+            let nft_id = mint_nft();
+            let end_block = end_block();
+            create_auction(nft_id);
+
+            assert_ok!(NftManager::cancel_auction(Origin::signed(seller()), nft_id));
+
+            assert_eq!(NftManager::auction(nft_id), None);
+            assert!(NftManager::auctions_ending_at(end_block).is_empty());
+            assert_eq!(<NftOpenForSale<TestRuntime>>::contains_key(nft_id), false);
+            assert!(event_emitted(crate::Event::<TestRuntime>::AuctionCancelled { nft_id }));
+        });
+    }
+
+    #[test]
+    fn fails_when_the_auction_has_bids() {
+        build_ext().execute_with(|| {
+            let nft_id = mint_nft();
+            create_auction(nft_id);
+            bid(bidder(), nft_id, RESERVE_PRICE);
+
+            assert_noop!(
+                NftManager::cancel_auction(Origin::signed(seller()), nft_id),
+                Error::<TestRuntime>::AuctionHasBids
+            );
+        });
+    }
+
+    #[test]
+    fn fails_when_the_sender_is_not_the_seller() {
+        build_ext().execute_with(|| {
+            let nft_id = mint_nft();
+            create_auction(nft_id);
+
+            assert_noop!(
+                NftManager::cancel_auction(Origin::signed(bidder()), nft_id),
+                Error::<TestRuntime>::SenderIsNotOwner
+            );
+        });
+    }
+}
+
+mod settle_auction {
+    use super::*;
+
+    #[test]
+    fn sells_the_nft_to_the_highest_bidder_and_pays_the_royalties() {
+        build_ext().execute_with(|| {
+            register_royalty_recipient();
+            let nft_id = mint_nft();
+            let end_block = end_block();
+            create_auction(nft_id);
+            bid(bidder(), nft_id, 1_000);
+
+            end_auctions(end_block);
+
+            let nft = NftManager::nfts(nft_id).unwrap();
+            assert_eq!(nft.owner, bidder());
+            assert_eq!(<NftOpenForSale<TestRuntime>>::contains_key(nft_id), false);
+            assert_eq!(NftManager::auction(nft_id), None);
+            assert_eq!(Balances::total_balance(&bidder()), INITIAL_BALANCE - 1_000);
+            assert_eq!(Balances::free_balance(royalty_recipient()), 100);
+            assert_eq!(Balances::free_balance(seller()), 900);
+            assert!(event_emitted(crate::Event::<TestRuntime>::AuctionSettled {
+                nft_id,
+                seller: seller(),
+                winner: bidder(),
+                price: 1_000,
+            }));
+        });
+    }
+
+    #[test]
+    fn holds_the_royalties_of_unregistered_recipients() {
+        build_ext().execute_with(|| {
+            let nft_id = mint_nft();
+            let end_block = end_block();
+            create_auction(nft_id);
+            bid(bidder(), nft_id, 1_000);
+
+            end_auctions(end_block);
+
+            assert_eq!(Balances::free_balance(seller()), 900);
+            assert_eq!(Balances::free_balance(NftManager::royalty_pot_account()), 100);
+            assert_eq!(NftManager::unclaimed_royalties(ROYALTY_RECIPIENT_T1_ADDRESS), 100);
+            assert_eq!(NftManager::nfts(nft_id).unwrap().owner, bidder());
+            assert!(event_emitted(crate::Event::<TestRuntime>::RoyaltyHeld {
+                recipient_t1_address: ROYALTY_RECIPIENT_T1_ADDRESS,
+                amount: 100,
+            }));
+        });
+    }
+
+    #[test]
+    fn returns_the_bid_when_the_nft_can_not_be_transferred() {
+        build_ext().execute_with(|| {
+            let nft_id = mint_nft();
+            let end_block = end_block();
+            register_royalty_recipient();
+            create_auction(nft_id);
+            bid(bidder(), nft_id, 1_000);
+            // The listing is gone, so the nft can't be transferred to the winner
+            <NftOpenForSale<TestRuntime>>::remove(nft_id);
+
+            end_auctions(end_block);
+
+            assert_eq!(NftManager::nfts(nft_id).unwrap().owner, seller());
+            assert_eq!(Balances::free_balance(bidder()), INITIAL_BALANCE);
+            assert_eq!(Balances::reserved_balance(bidder()), 0);
+            assert_eq!(Balances::free_balance(seller()), 0);
+            assert_eq!(Balances::free_balance(royalty_recipient()), 0);
+            assert!(event_emitted(crate::Event::<TestRuntime>::AuctionCancelled { nft_id }));
+        });
+    }
+
+    #[test]
+    fn unlists_the_nft_when_nobody_bid() {
+        build_ext().execute_with(|| {
+            let nft_id = mint_nft();
+            let end_block = end_block();
+            create_auction(nft_id);
+
+            end_auctions(end_block);
+
+            assert_eq!(NftManager::nfts(nft_id).unwrap().owner, seller());
+            assert_eq!(<NftOpenForSale<TestRuntime>>::contains_key(nft_id), false);
+            assert!(event_emitted(crate::Event::<TestRuntime>::AuctionEndedWithoutBids { nft_id }));
+        });
+    }
+
+    #[test]
+    fn does_not_settle_auctions_before_they_end() {
+        build_ext().execute_with(|| {
+            let nft_id = mint_nft();
+            let end_block = end_block();
+            create_auction(nft_id);
+            bid(bidder(), nft_id, RESERVE_PRICE);
+
+            end_auctions(end_block - 1);
+
+            assert_eq!(NftManager::nfts(nft_id).unwrap().owner, seller());
+            assert_eq!(Balances::reserved_balance(bidder()), RESERVE_PRICE);
+        });
+    }
+}
+
+mod claim_royalties {
+    use super::*;
+
+    fn sell_with_unregistered_royalty_recipient() {
+        let nft_id = mint_nft();
+        let end_block = end_block();
+        create_auction(nft_id);
+        bid(bidder(), nft_id, 1_000);
+        end_auctions(end_block);
+    }
+
+    #[test]
+    fn pays_the_held_royalties_once_the_recipient_registered() {
+        build_ext().execute_with(|| {
+            sell_with_unregistered_royalty_recipient();
+            register_royalty_recipient();
+
+            assert_ok!(NftManager::claim_royalties(
+                Origin::signed(royalty_recipient()),
+                ROYALTY_RECIPIENT_T1_ADDRESS
+            ));
+
+            assert_eq!(Balances::free_balance(royalty_recipient()), 100);
+            assert_eq!(Balances::free_balance(NftManager::royalty_pot_account()), 0);
+            assert_eq!(NftManager::unclaimed_royalties(ROYALTY_RECIPIENT_T1_ADDRESS), 0);
+            assert!(event_emitted(crate::Event::<TestRuntime>::RoyaltiesClaimed {
+                recipient_t1_address: ROYALTY_RECIPIENT_T1_ADDRESS,
+                account: royalty_recipient(),
+                amount: 100,
+            }));
+        });
+    }
+
+    #[test]
+    fn fails_when_the_address_is_not_registered_to_the_sender() {
+        build_ext().execute_with(|| {
+            sell_with_unregistered_royalty_recipient();
+
+            assert_noop!(
+                NftManager::claim_royalties(
+                    Origin::signed(royalty_recipient()),
+                    ROYALTY_RECIPIENT_T1_ADDRESS
+                ),
+                Error::<TestRuntime>::SenderIsNotRoyaltyRecipient
+            );
+        });
+    }
+
+    #[test]
+    fn fails_when_there_is_nothing_to_claim() {
+        build_ext().execute_with(|| {
+            register_royalty_recipient();
+
+            assert_noop!(
+                NftManager::claim_royalties(
+                    Origin::signed(royalty_recipient()),
+                    ROYALTY_RECIPIENT_T1_ADDRESS
+                ),
+                Error::<TestRuntime>::NoRoyaltiesToClaim
+            );
+        });
+    }
+}
@@ -0,0 +1,242 @@
+// Copyright 2024 Aventus Network Services (UK) Ltd.
+
+#![cfg(test)]
+
+use super::*;
+use crate::mock::{
+    AccountId, RuntimeCall as MockCall, RuntimeEvent as Event, RuntimeOrigin as Origin, *,
+};
+use frame_support::{assert_noop, assert_ok};
+use hex_literal::hex;
+
+const T1_AUTHORITY: H160 = H160(hex!("11111AAAAA22222BBBBB11111AAAAA22222BBBBB"));
+const ROYALTY_RECIPIENT_T1_ADDRESS: H160 = H160(hex!("33333CCCCC44444DDDDD33333CCCCC44444DDDDD"));
+
+fn nft_owner() -> TestAccount {
+    TestAccount::new([1u8; 32])
+}
+
+fn relayer() -> AccountId {
+    TestAccount::new([2u8; 32]).account_id()
+}
+
+fn mint_params(index: u32) -> MintParams {
+    MintParams {
+        unique_external_ref: format!("Offchain location of NFT {}", index).into_bytes(),
+        royalties: vec![Royalty {
+            recipient_t1_address: ROYALTY_RECIPIENT_T1_ADDRESS,
+            rate: RoyaltyRate { parts_per_million: 1_000 },
+        }],
+    }
+}
+
+fn batch(size: u32) -> Vec<MintParams> {
+    (0..size).map(mint_params).collect()
+}
+
+fn expected_nft_ids(size: u32) -> Vec<NftId> {
+    let first_unique_id = NftManager::next_unique_id();
+    (0..size)
+        .map(|i| {
+            NftManager::generate_nft_id_single_mint(&T1_AUTHORITY, first_unique_id + U256::from(i))
+        })
+        .collect()
+}
+
+fn signed_batch_mint_proof(
+    signer: &TestAccount,
+    nfts: &Vec<MintParams>,
+) -> Proof<Signature, AccountId> {
+    let signed_payload = (SIGNED_BATCH_MINT_NFT_CONTEXT, relayer(), nfts, T1_AUTHORITY).encode();
+    Proof {
+        signer: signer.account_id(),
+        relayer: relayer(),
+        signature: sign(&signer.key_pair(), &signed_payload),
+    }
+}
+
+fn assert_minted(nfts: &Vec<MintParams>, nft_ids: &Vec<NftId>) {
+    for (params, nft_id) in nfts.iter().zip(nft_ids.iter()) {
+        let nft = NftManager::nfts(nft_id).unwrap();
+        assert_eq!(nft.owner, nft_owner().account_id());
+        assert_eq!(nft.unique_external_ref.to_vec(), params.unique_external_ref);
+        assert_eq!(
+            NftManager::nft_infos(nft.info_id).unwrap().royalties.to_vec(),
+            params.royalties
+        );
+        assert!(System::events().iter().any(|a| a.event ==
+            Event::NftManager(crate::Event::<TestRuntime>::SingleNftMinted {
+                nft_id: *nft_id,
+                owner: nft_owner().account_id(),
+                authority: T1_AUTHORITY,
+            })));
+    }
+}
+
+mod batch_mint_nft {
+    use super::*;
+
+    #[test]
+    fn mints_every_nft_of_the_batch() {
+        let mut ext = ExtBuilder::build_default().as_externality();
+        ext.execute_with(|| {
+            let nfts = batch(3);
+            let nft_ids = expected_nft_ids(3);
+
+            assert_ok!(NftManager::batch_mint_nft(
+                Origin::signed(nft_owner().account_id()),
+                nfts.clone(),
+                T1_AUTHORITY
+            ));
+
+            assert_minted(&nfts, &nft_ids);
+        });
+    }
+
+    #[test]
+    fn mints_the_maximum_number_of_nfts() {
+        let mut ext = ExtBuilder::build_default().as_externality();
+        ext.execute_with(|| {
+            let nfts = batch(MAX_NUMBER_OF_NFTS_PER_BATCH_MINT);
+            let nft_ids = expected_nft_ids(MAX_NUMBER_OF_NFTS_PER_BATCH_MINT);
+
+            assert_ok!(NftManager::batch_mint_nft(
+                Origin::signed(nft_owner().account_id()),
+                nfts.clone(),
+                T1_AUTHORITY
+            ));
+
+            assert_minted(&nfts, &nft_ids);
+        });
+    }
+
+    mod fails_when {
+        use super::*;
+
+        #[test]
+        fn the_batch_is_empty() {
+            let mut ext = ExtBuilder::build_default().as_externality();
+            ext.execute_with(|| {
+                assert_noop!(
+                    NftManager::batch_mint_nft(
+                        Origin::signed(nft_owner().account_id()),
+                        vec![],
+                        T1_AUTHORITY
+                    ),
+                    Error::<TestRuntime>::BatchMintIsEmpty
+                );
+            });
+        }
+
+        #[test]
+        fn the_batch_is_too_big() {
+            let mut ext = ExtBuilder::build_default().as_externality();
+            ext.execute_with(|| {
+                assert_noop!(
+                    NftManager::batch_mint_nft(
+                        Origin::signed(nft_owner().account_id()),
+                        batch(MAX_NUMBER_OF_NFTS_PER_BATCH_MINT + 1),
+                        T1_AUTHORITY
+                    ),
+                    Error::<TestRuntime>::TooManyNftsInBatchMint
+                );
+            });
+        }
+
+        #[test]
+        fn an_external_ref_is_repeated_in_the_batch() {
+            let mut ext = ExtBuilder::build_default().as_externality();
+            ext.execute_with(|| {
+                assert_noop!(
+                    NftManager::batch_mint_nft(
+                        Origin::signed(nft_owner().account_id()),
+                        vec![mint_params(0), mint_params(1), mint_params(0)],
+                        T1_AUTHORITY
+                    ),
+                    Error::<TestRuntime>::ExternalRefIsAlreadyInUse
+                );
+            });
+        }
+
+        #[test]
+        fn an_nft_of_the_batch_is_invalid() {
+            let mut ext = ExtBuilder::build_default().as_externality();
+            ext.execute_with(|| {
+                let mut invalid_nft = mint_params(1);
+                invalid_nft.royalties[0].rate.parts_per_million = 1_000_001;
+
+                assert_noop!(
+                    NftManager::batch_mint_nft(
+                        Origin::signed(nft_owner().account_id()),
+                        vec![mint_params(0), invalid_nft],
+                        T1_AUTHORITY
+                    ),
+                    Error::<TestRuntime>::RoyaltyRateIsNotValid
+                );
+            });
+        }
+    }
+}
+
+mod signed_batch_mint_nft {
+    use super::*;
+
+    #[test]
+    fn mints_every_nft_of_the_batch_through_the_proxy() {
+        let mut ext = ExtBuilder::build_default().as_externality();
+        ext.execute_with(|| {
+            let nfts = batch(3);
+            let nft_ids = expected_nft_ids(3);
+            let call =
+                Box::new(MockCall::NftManager(super::Call::<TestRuntime>::signed_batch_mint_nft {
+                    proof: signed_batch_mint_proof(&nft_owner(), &nfts),
+                    nfts: nfts.clone(),
+                    t1_authority: T1_AUTHORITY,
+                }));
+
+            assert_ok!(NftManager::proxy(Origin::signed(relayer()), call));
+
+            assert_minted(&nfts, &nft_ids);
+        });
+    }
+
+    mod fails_when {
+        use super::*;
+
+        #[test]
+        fn the_sender_is_not_the_signer() {
+            let mut ext = ExtBuilder::build_default().as_externality();
+            ext.execute_with(|| {
+                let nfts = batch(2);
+
+                assert_noop!(
+                    NftManager::signed_batch_mint_nft(
+                        Origin::signed(relayer()),
+                        signed_batch_mint_proof(&nft_owner(), &nfts),
+                        nfts,
+                        T1_AUTHORITY
+                    ),
+                    Error::<TestRuntime>::SenderIsNotSigner
+                );
+            });
+        }
+
+        #[test]
+        fn the_signature_does_not_cover_the_batch() {
+            let mut ext = ExtBuilder::build_default().as_externality();
+            ext.execute_with(|| {
+                let nfts = batch(2);
+
+                assert_noop!(
+                    NftManager::signed_batch_mint_nft(
+                        Origin::signed(nft_owner().account_id()),
+                        signed_batch_mint_proof(&nft_owner(), &nfts),
+                        batch(3),
+                        T1_AUTHORITY
+                    ),
+                    Error::<TestRuntime>::UnauthorizedSignedBatchMintNftTransaction
+                );
+            });
+        }
+    }
+}
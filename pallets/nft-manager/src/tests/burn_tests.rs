@@ -0,0 +1,220 @@
+// Copyright 2024 Aventus Network Services (UK) Ltd.
+
+#![cfg(test)]
+
+use super::*;
+use crate::mock::{
+    AccountId, RuntimeCall as MockCall, RuntimeEvent as Event, RuntimeOrigin as Origin, *,
+};
+use frame_support::{assert_noop, assert_ok};
+use hex_literal::hex;
+use sp_avn_common::BridgeContractMethod;
+
+const T1_CONTRACT: H160 = H160(hex!("11111AAAAA22222BBBBB11111AAAAA22222BBBBB"));
+const T1_AUTHORITY: H160 = H160(hex!("33333CCCCC44444DDDDD33333CCCCC44444DDDDD"));
+
+fn owner() -> TestAccount {
+    TestAccount::new([1u8; 32])
+}
+
+fn relayer() -> AccountId {
+    TestAccount::new([3u8; 32]).account_id()
+}
+
+fn mint_nft(t1_authority: H160) -> NftId {
+    let unique_id = NftManager::next_unique_id();
+    let nft_id = NftManager::generate_nft_id_single_mint(&t1_authority, unique_id);
+    assert_ok!(NftManager::mint_single_nft(
+        Origin::signed(owner().account_id()),
+        format!("Offchain location of NFT {}", unique_id).into_bytes(),
+        vec![],
+        t1_authority,
+    ));
+    nft_id
+}
+
+fn mint_t1_nft() -> NftId {
+    register_nft_contract(T1_CONTRACT);
+    mint_nft(T1_CONTRACT)
+}
+
+fn burn(nft_id: NftId) -> u32 {
+    let tx_id = published_transactions().len() as u32;
+    assert_ok!(NftManager::burn_nft(Origin::signed(owner().account_id()), nft_id));
+    tx_id
+}
+
+fn signed_burn_call(signer: &TestAccount, nft_id: NftId) -> Box<MockCall> {
+    let nonce = NftManager::nfts(nft_id).unwrap().nonce;
+    let relayer = relayer();
+    let data_to_sign = (SIGNED_BURN_NFT_CONTEXT, &relayer, nft_id, nonce);
+    let proof = Proof {
+        signer: signer.account_id(),
+        relayer,
+        signature: sign(&signer.key_pair(), &data_to_sign.encode()),
+    };
+    Box::new(MockCall::NftManager(super::Call::<TestRuntime>::signed_burn_nft { proof, nft_id }))
+}
+
+fn event_emitted(event: crate::Event<TestRuntime>) -> bool {
+    System::events().iter().any(|a| a.event == Event::NftManager(event.clone()))
+}
+
+mod burn_nft {
+    use super::*;
+
+    #[test]
+    fn burns_an_nft_without_a_t1_contract_straight_away() {
+        let mut ext = ExtBuilder::build_default().as_externality();
+        ext.execute_with(|| {
+            let nft_id = mint_nft(T1_AUTHORITY);
+
+            assert_ok!(NftManager::burn_nft(Origin::signed(owner().account_id()), nft_id));
+
+            assert_eq!(NftManager::nfts(nft_id), None);
+            assert!(published_transactions().is_empty());
+        });
+    }
+
+    #[test]
+    fn publishes_the_burn_of_an_nft_from_a_t1_contract() {
+        let mut ext = ExtBuilder::build_default().as_externality();
+        ext.execute_with(|| {
+            let nft_id = mint_t1_nft();
+
+            let tx_id = burn(nft_id);
+
+            assert!(NftManager::nfts(nft_id).unwrap().is_locked);
+            assert_eq!(
+                NftManager::pending_nft_burn(tx_id),
+                Some(NftBurn { nft_id, owner: owner().account_id() })
+            );
+            assert_eq!(
+                published_transactions()[tx_id as usize],
+                (
+                    BridgeContractMethod::BurnNft.as_bytes().to_vec(),
+                    vec![
+                        (b"address".to_vec(), T1_CONTRACT.as_bytes().to_vec()),
+                        (b"uint256".to_vec(), nft_id.to_string().into_bytes()),
+                    ]
+                )
+            );
+            assert!(event_emitted(crate::Event::<TestRuntime>::NftBurnRequested {
+                nft_id,
+                owner: owner().account_id(),
+                tx_id,
+            }));
+        });
+    }
+
+    #[test]
+    fn can_be_relayed() {
+        let mut ext = ExtBuilder::build_default().as_externality();
+        ext.execute_with(|| {
+            let nft_id = mint_nft(T1_AUTHORITY);
+
+            assert_ok!(NftManager::proxy(
+                Origin::signed(relayer()),
+                signed_burn_call(&owner(), nft_id)
+            ));
+
+            assert_eq!(NftManager::nfts(nft_id), None);
+        });
+    }
+
+    mod fails_when {
+        use super::*;
+
+        #[test]
+        fn the_burn_is_already_pending() {
+            let mut ext = ExtBuilder::build_default().as_externality();
+            ext.execute_with(|| {
+                let nft_id = mint_t1_nft();
+                burn(nft_id);
+
+                assert_noop!(
+                    NftManager::burn_nft(Origin::signed(owner().account_id()), nft_id),
+                    Error::<TestRuntime>::NftIsLocked
+                );
+            });
+        }
+
+        #[test]
+        fn the_relayed_burn_is_not_signed_by_the_owner() {
+            let mut ext = ExtBuilder::build_default().as_externality();
+            ext.execute_with(|| {
+                let nft_id = mint_nft(T1_AUTHORITY);
+                let other_account = TestAccount::new([2u8; 32]);
+                let call = signed_burn_call(&other_account, nft_id);
+
+                assert_noop!(
+                    NftManager::proxy(Origin::signed(relayer()), call),
+                    Error::<TestRuntime>::SenderIsNotOwner
+                );
+            });
+        }
+    }
+}
+
+mod burn_result {
+    use super::*;
+
+    #[test]
+    fn removes_the_nft_when_the_t1_burn_succeeds() {
+        let mut ext = ExtBuilder::build_default().as_externality();
+        ext.execute_with(|| {
+            let nft_id = mint_t1_nft();
+            let tx_id = burn(nft_id);
+
+            assert_ok!(NftManager::process_result(tx_id, PALLET_ID.to_vec(), true));
+
+            assert_eq!(NftManager::nfts(nft_id), None);
+            assert_eq!(NftManager::pending_nft_burn(tx_id), None);
+            assert!(event_emitted(crate::Event::<TestRuntime>::NftBurned {
+                nft_id,
+                owner: owner().account_id(),
+            }));
+        });
+    }
+
+    #[test]
+    fn unlocks_the_nft_when_the_t1_burn_fails() {
+        let mut ext = ExtBuilder::build_default().as_externality();
+        ext.execute_with(|| {
+            let nft_id = mint_t1_nft();
+            let tx_id = burn(nft_id);
+
+            assert_ok!(NftManager::process_result(tx_id, PALLET_ID.to_vec(), false));
+
+            assert!(!NftManager::nfts(nft_id).unwrap().is_locked);
+            assert_eq!(NftManager::pending_nft_burn(tx_id), None);
+            assert!(event_emitted(crate::Event::<TestRuntime>::NftBurnFailed {
+                nft_id,
+                owner: owner().account_id(),
+                tx_id,
+            }));
+        });
+    }
+
+    #[test]
+    fn does_not_affect_pending_exports() {
+        let mut ext = ExtBuilder::build_default().as_externality();
+        ext.execute_with(|| {
+            let exported_nft = mint_t1_nft();
+            let export_tx_id = published_transactions().len() as u32;
+            assert_ok!(NftManager::export_nft(
+                Origin::signed(owner().account_id()),
+                exported_nft,
+                H160(hex!("afdf36201bf70F1232111b5c6a9a424558755134")),
+            ));
+            let burnt_nft = mint_t1_nft();
+            let burn_tx_id = burn(burnt_nft);
+
+            assert_ok!(NftManager::process_result(burn_tx_id, PALLET_ID.to_vec(), true));
+
+            assert_eq!(NftManager::nfts(burnt_nft), None);
+            assert!(NftManager::nfts(exported_nft).unwrap().is_locked);
+            assert!(NftManager::pending_nft_export(export_tx_id).is_some());
+        });
+    }
+}
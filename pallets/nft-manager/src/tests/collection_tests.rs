@@ -0,0 +1,584 @@
+// Copyright 2024 Aventus Network Services (UK) Ltd.
+
+#![cfg(test)]
+
+use super::*;
+use crate::mock::{AccountId, RuntimeEvent as Event, RuntimeOrigin as Origin, *};
+use frame_support::{assert_noop, assert_ok, error::BadOrigin, traits::GetStorageVersion};
+use hex_literal::hex;
+
+const T1_AUTHORITY: H160 = H160(hex!("11111AAAAA22222BBBBB11111AAAAA22222BBBBB"));
+const T1_RECIPIENT: H160 = H160(hex!("afdf36201bf70F1232111b5c6a9a424558755134"));
+
+fn owner() -> AccountId {
+    TestAccount::new([1u8; 32]).account_id()
+}
+
+fn other_account() -> AccountId {
+    TestAccount::new([2u8; 32]).account_id()
+}
+
+fn royalties() -> Vec<Royalty> {
+    vec![Royalty {
+        recipient_t1_address: H160(hex!("33333CCCCC44444DDDDD33333CCCCC44444DDDDD")),
+        rate: RoyaltyRate { parts_per_million: 1_000 },
+    }]
+}
+
+fn create_collection(transferable: bool) -> CollectionId {
+    let collection_id = NextCollectionId::<TestRuntime>::get();
+    assert_ok!(NftManager::create_collection(
+        Origin::signed(owner()),
+        T1_AUTHORITY,
+        royalties(),
+        b"https://nfts.aventus.io/".to_vec(),
+        transferable,
+    ));
+    collection_id
+}
+
+fn mint_into(collection_id: CollectionId) -> NftId {
+    let unique_id = NftManager::next_unique_id();
+    let nft_id = NftManager::generate_nft_id_single_mint(&T1_AUTHORITY, unique_id);
+    assert_ok!(NftManager::mint_collection_nft(
+        Origin::signed(owner()),
+        collection_id,
+        owner(),
+        format!("Offchain location of NFT {}", unique_id).into_bytes(),
+    ));
+    nft_id
+}
+
+fn mint_single_nft() -> NftId {
+    let unique_id = NftManager::next_unique_id();
+    let nft_id = NftManager::generate_nft_id_single_mint(&T1_AUTHORITY, unique_id);
+    assert_ok!(NftManager::mint_single_nft(
+        Origin::signed(owner()),
+        format!("Offchain location of NFT {}", unique_id).into_bytes(),
+        vec![],
+        T1_AUTHORITY,
+    ));
+    nft_id
+}
+
+fn event_emitted(event: crate::Event<TestRuntime>) -> bool {
+    System::events().iter().any(|a| a.event == Event::NftManager(event.clone()))
+}
+
+mod create_collection {
+    use super::*;
+
+    #[test]
+    fn stores_the_collection_settings() {
+        let mut ext = ExtBuilder::build_default().as_externality();
+        ext.execute_with(|| {
+            let collection_id = create_collection(true);
+
+            let collection = NftManager::collection(collection_id).unwrap();
+            assert_eq!(collection.owner, Some(owner()));
+            assert_eq!(collection.t1_authority, T1_AUTHORITY);
+            assert_eq!(collection.royalties.to_vec(), royalties());
+            assert_eq!(collection.base_uri.to_vec(), b"https://nfts.aventus.io/".to_vec());
+            assert!(collection.transferable);
+            assert_eq!(NextCollectionId::<TestRuntime>::get(), collection_id + 1);
+            assert!(event_emitted(crate::Event::<TestRuntime>::CollectionCreated {
+                collection_id,
+                owner: Some(owner()),
+                t1_authority: T1_AUTHORITY,
+            }));
+        });
+    }
+
+    mod fails_when {
+        use super::*;
+
+        #[test]
+        fn the_t1_authority_is_missing() {
+            let mut ext = ExtBuilder::build_default().as_externality();
+            ext.execute_with(|| {
+                assert_noop!(
+                    NftManager::create_collection(
+                        Origin::signed(owner()),
+                        H160::zero(),
+                        vec![],
+                        vec![],
+                        true
+                    ),
+                    Error::<TestRuntime>::T1AuthorityIsMandatory
+                );
+            });
+        }
+
+        #[test]
+        fn the_royalties_are_invalid() {
+            let mut ext = ExtBuilder::build_default().as_externality();
+            ext.execute_with(|| {
+                let invalid_royalties = vec![Royalty {
+                    recipient_t1_address: H160::zero(),
+                    rate: RoyaltyRate { parts_per_million: 1_000_001 },
+                }];
+
+                assert_noop!(
+                    NftManager::create_collection(
+                        Origin::signed(owner()),
+                        T1_AUTHORITY,
+                        invalid_royalties,
+                        vec![],
+                        true
+                    ),
+                    Error::<TestRuntime>::RoyaltyRateIsNotValid
+                );
+            });
+        }
+
+        #[test]
+        fn the_base_uri_is_too_long() {
+            let mut ext = ExtBuilder::build_default().as_externality();
+            ext.execute_with(|| {
+                let base_uri = vec![1u8; NftExternalRefBound::get() as usize + 1];
+
+                assert_noop!(
+                    NftManager::create_collection(
+                        Origin::signed(owner()),
+                        T1_AUTHORITY,
+                        vec![],
+                        base_uri,
+                        true
+                    ),
+                    Error::<TestRuntime>::BaseUriOutOfBounds
+                );
+            });
+        }
+    }
+}
+
+mod update_collection {
+    use super::*;
+
+    #[test]
+    fn replaces_the_collection_settings() {
+        let mut ext = ExtBuilder::build_default().as_externality();
+        ext.execute_with(|| {
+            let collection_id = create_collection(true);
+
+            assert_ok!(NftManager::update_collection(
+                Origin::signed(owner()),
+                collection_id,
+                vec![],
+                vec![],
+                false,
+            ));
+
+            let collection = NftManager::collection(collection_id).unwrap();
+            assert!(collection.royalties.is_empty());
+            assert!(collection.base_uri.is_empty());
+            assert!(!collection.transferable);
+            assert!(event_emitted(crate::Event::<TestRuntime>::CollectionUpdated {
+                collection_id
+            }));
+        });
+    }
+
+    #[test]
+    fn does_not_change_the_royalties_of_minted_nfts() {
+        let mut ext = ExtBuilder::build_default().as_externality();
+        ext.execute_with(|| {
+            let collection_id = create_collection(true);
+            let nft_id = mint_into(collection_id);
+
+            assert_ok!(NftManager::update_collection(
+                Origin::signed(owner()),
+                collection_id,
+                vec![],
+                vec![],
+                true,
+            ));
+
+            let info_id = NftManager::nfts(nft_id).unwrap().info_id;
+            assert_eq!(NftManager::nft_infos(info_id).unwrap().royalties.to_vec(), royalties());
+        });
+    }
+
+    mod fails_when {
+        use super::*;
+
+        #[test]
+        fn the_sender_is_not_the_collection_owner() {
+            let mut ext = ExtBuilder::build_default().as_externality();
+            ext.execute_with(|| {
+                let collection_id = create_collection(true);
+
+                assert_noop!(
+                    NftManager::update_collection(
+                        Origin::signed(other_account()),
+                        collection_id,
+                        vec![],
+                        vec![],
+                        false
+                    ),
+                    Error::<TestRuntime>::SenderIsNotCollectionOwner
+                );
+            });
+        }
+
+        #[test]
+        fn the_collection_does_not_exist() {
+            let mut ext = ExtBuilder::build_default().as_externality();
+            ext.execute_with(|| {
+                assert_noop!(
+                    NftManager::update_collection(
+                        Origin::signed(owner()),
+                        7,
+                        vec![],
+                        vec![],
+                        false
+                    ),
+                    Error::<TestRuntime>::CollectionDoesNotExist
+                );
+            });
+        }
+
+        #[test]
+        fn a_transferable_collection_with_nfts_is_made_non_transferable() {
+            let mut ext = ExtBuilder::build_default().as_externality();
+            ext.execute_with(|| {
+                let collection_id = create_collection(true);
+                mint_into(collection_id);
+
+                assert_noop!(
+                    NftManager::update_collection(
+                        Origin::signed(owner()),
+                        collection_id,
+                        vec![],
+                        vec![],
+                        false
+                    ),
+                    Error::<TestRuntime>::CollectionHasNfts
+                );
+            });
+        }
+    }
+}
+
+mod set_collection_owner {
+    use super::*;
+
+    #[test]
+    fn the_owner_can_hand_over_the_collection() {
+        let mut ext = ExtBuilder::build_default().as_externality();
+        ext.execute_with(|| {
+            let collection_id = create_collection(true);
+
+            assert_ok!(NftManager::set_collection_owner(
+                Origin::signed(owner()),
+                collection_id,
+                Some(other_account()),
+            ));
+
+            assert_eq!(NftManager::collection(collection_id).unwrap().owner, Some(other_account()));
+            assert!(event_emitted(crate::Event::<TestRuntime>::CollectionOwnerSet {
+                collection_id,
+                owner: Some(other_account()),
+            }));
+        });
+    }
+
+    #[test]
+    fn root_can_set_the_owner_of_a_contract_collection() {
+        let mut ext = ExtBuilder::build_default().as_externality();
+        ext.execute_with(|| {
+            mint_single_nft();
+            let collection_id = NftManager::contract_collection(T1_AUTHORITY).unwrap();
+            assert_eq!(NftManager::collection(collection_id).unwrap().owner, None);
+
+            assert_ok!(NftManager::set_collection_owner(
+                Origin::root(),
+                collection_id,
+                Some(owner()),
+            ));
+
+            assert_eq!(NftManager::collection(collection_id).unwrap().owner, Some(owner()));
+        });
+    }
+
+    mod fails_when {
+        use super::*;
+
+        #[test]
+        fn the_sender_is_not_the_collection_owner() {
+            let mut ext = ExtBuilder::build_default().as_externality();
+            ext.execute_with(|| {
+                let collection_id = create_collection(true);
+
+                assert_noop!(
+                    NftManager::set_collection_owner(
+                        Origin::signed(other_account()),
+                        collection_id,
+                        Some(other_account())
+                    ),
+                    Error::<TestRuntime>::SenderIsNotCollectionOwner
+                );
+            });
+        }
+
+        #[test]
+        fn the_origin_is_unsigned() {
+            let mut ext = ExtBuilder::build_default().as_externality();
+            ext.execute_with(|| {
+                let collection_id = create_collection(true);
+
+                assert_noop!(
+                    NftManager::set_collection_owner(Origin::none(), collection_id, None),
+                    BadOrigin
+                );
+            });
+        }
+    }
+}
+
+mod mint_collection_nft {
+    use super::*;
+
+    #[test]
+    fn mints_with_the_collection_settings() {
+        let mut ext = ExtBuilder::build_default().as_externality();
+        ext.execute_with(|| {
+            let collection_id = create_collection(true);
+
+            let nft_id = mint_into(collection_id);
+
+            let nft = NftManager::nfts(nft_id).unwrap();
+            let info = NftManager::nft_infos(nft.info_id).unwrap();
+            assert_eq!(nft.owner, owner());
+            assert_eq!(info.royalties.to_vec(), royalties());
+            assert_eq!(info.t1_authority, T1_AUTHORITY);
+            assert_eq!(NftManager::info_collection(nft.info_id), Some(collection_id));
+            assert!(event_emitted(crate::Event::<TestRuntime>::SingleNftMinted {
+                nft_id,
+                owner: owner(),
+                authority: T1_AUTHORITY,
+            }));
+        });
+    }
+
+    mod fails_when {
+        use super::*;
+
+        #[test]
+        fn the_sender_is_not_the_collection_owner() {
+            let mut ext = ExtBuilder::build_default().as_externality();
+            ext.execute_with(|| {
+                let collection_id = create_collection(true);
+
+                assert_noop!(
+                    NftManager::mint_collection_nft(
+                        Origin::signed(other_account()),
+                        collection_id,
+                        other_account(),
+                        b"Offchain location of NFT".to_vec()
+                    ),
+                    Error::<TestRuntime>::SenderIsNotCollectionOwner
+                );
+            });
+        }
+
+        #[test]
+        fn the_external_ref_is_used() {
+            let mut ext = ExtBuilder::build_default().as_externality();
+            ext.execute_with(|| {
+                let collection_id = create_collection(true);
+                let nft_id = mint_into(collection_id);
+                let external_ref = NftManager::nfts(nft_id).unwrap().unique_external_ref;
+
+                assert_noop!(
+                    NftManager::mint_collection_nft(
+                        Origin::signed(owner()),
+                        collection_id,
+                        owner(),
+                        external_ref.to_vec()
+                    ),
+                    Error::<TestRuntime>::ExternalRefIsAlreadyInUse
+                );
+            });
+        }
+    }
+}
+
+mod implicit_collections {
+    use super::*;
+
+    #[test]
+    fn single_nfts_of_an_authority_share_a_collection() {
+        let mut ext = ExtBuilder::build_default().as_externality();
+        ext.execute_with(|| {
+            let first_nft = mint_single_nft();
+            let second_nft = mint_single_nft();
+
+            let collection_id = NftManager::contract_collection(T1_AUTHORITY).unwrap();
+            for nft_id in [first_nft, second_nft] {
+                let info_id = NftManager::nfts(nft_id).unwrap().info_id;
+                assert_eq!(NftManager::info_collection(info_id), Some(collection_id));
+            }
+            assert_eq!(NextCollectionId::<TestRuntime>::get(), collection_id + 1);
+        });
+    }
+
+    #[test]
+    fn a_batch_gets_a_collection_owned_by_its_creator() {
+        let mut ext = ExtBuilder::build_default().as_externality();
+        ext.execute_with(|| {
+            let info_id = NftManager::next_info_id();
+            let collection_id = NextCollectionId::<TestRuntime>::get();
+
+            create_batch::<TestRuntime>(
+                info_id,
+                U256::from(1u8),
+                BoundedVec::default(),
+                5,
+                T1_AUTHORITY,
+                owner(),
+            );
+
+            assert_eq!(NftManager::info_collection(info_id), Some(collection_id));
+            assert_eq!(NftManager::collection(collection_id).unwrap().owner, Some(owner()));
+            assert_eq!(NftManager::contract_collection(T1_AUTHORITY), None);
+        });
+    }
+}
+
+mod transferability {
+    use super::*;
+
+    #[test]
+    fn nfts_of_a_non_transferable_collection_cannot_be_listed() {
+        let mut ext = ExtBuilder::build_default().as_externality();
+        ext.execute_with(|| {
+            let collection_id = create_collection(false);
+            let nft_id = mint_into(collection_id);
+
+            assert_noop!(
+                NftManager::list_nft_open_for_sale(
+                    Origin::signed(owner()),
+                    nft_id,
+                    NftSaleType::Fiat
+                ),
+                Error::<TestRuntime>::CollectionNotTransferable
+            );
+        });
+    }
+
+    #[test]
+    fn nfts_of_a_non_transferable_collection_cannot_be_exported() {
+        let mut ext = ExtBuilder::build_default().as_externality();
+        ext.execute_with(|| {
+            let collection_id = create_collection(false);
+            let nft_id = mint_into(collection_id);
+
+            assert_noop!(
+                NftManager::export_nft(Origin::signed(owner()), nft_id, T1_RECIPIENT),
+                Error::<TestRuntime>::CollectionNotTransferable
+            );
+        });
+    }
+
+    #[test]
+    fn nfts_can_be_listed_once_the_collection_is_made_transferable() {
+        let mut ext = ExtBuilder::build_default().as_externality();
+        ext.execute_with(|| {
+            let collection_id = create_collection(false);
+            let nft_id = mint_into(collection_id);
+
+            assert_ok!(NftManager::update_collection(
+                Origin::signed(owner()),
+                collection_id,
+                royalties(),
+                vec![],
+                true,
+            ));
+
+            assert_ok!(NftManager::list_nft_open_for_sale(
+                Origin::signed(owner()),
+                nft_id,
+                NftSaleType::Fiat
+            ));
+        });
+    }
+}
+
+mod migration {
+    use super::*;
+    use frame_support::traits::Get;
+    use pallet_avn::migration::SteppedMigration;
+
+    #[test]
+    fn assigns_existing_infos_to_collections() {
+        let mut ext = ExtBuilder::build_default().as_externality();
+        ext.execute_with(|| {
+            let single_info_id = U256::from(1u8);
+            let batch_info_id = U256::from(2u8);
+            <NftInfos<TestRuntime>>::insert(
+                single_info_id,
+                NftInfo::new(single_info_id, BoundedVec::default(), T1_AUTHORITY),
+            );
+            <NftInfos<TestRuntime>>::insert(
+                batch_info_id,
+                NftInfo::new_batch(
+                    batch_info_id,
+                    U256::from(1u8),
+                    BoundedVec::default(),
+                    T1_AUTHORITY,
+                    5,
+                    owner(),
+                ),
+            );
+            StorageVersion::new(4).put::<NftManager>();
+
+            pallet_avn::migration::migrate::<
+                TestRuntime,
+                crate::migration::SteppedMigrations<TestRuntime>,
+            >();
+
+            let contract_collection = NftManager::contract_collection(T1_AUTHORITY).unwrap();
+            assert_eq!(NftManager::info_collection(single_info_id), Some(contract_collection));
+            let batch_collection = NftManager::info_collection(batch_info_id).unwrap();
+            assert_ne!(batch_collection, contract_collection);
+            assert_eq!(NftManager::collection(batch_collection).unwrap().owner, Some(owner()));
+            assert!(CollectionsWithNfts::<TestRuntime>::contains_key(contract_collection));
+            assert!(!CollectionsWithNfts::<TestRuntime>::contains_key(batch_collection));
+            assert_eq!(NftManager::on_chain_storage_version(), StorageVersion::new(5));
+        });
+    }
+
+    #[test]
+    fn resumes_from_the_last_assigned_info() {
+        let mut ext = ExtBuilder::build_default().as_externality();
+        ext.execute_with(|| {
+            let info_ids = [U256::from(1u8), U256::from(2u8), U256::from(3u8)];
+            for info_id in info_ids {
+                <NftInfos<TestRuntime>>::insert(
+                    info_id,
+                    NftInfo::new(info_id, BoundedVec::default(), T1_AUTHORITY),
+                );
+            }
+            let weight_per_info =
+                <TestRuntime as frame_system::Config>::DbWeight::get().reads_writes(5, 5);
+
+            let (_, cursor) = crate::migration::AssignNftsToCollections::<TestRuntime>::step(
+                None,
+                weight_per_info,
+            );
+            assert!(cursor.is_some());
+            assert_eq!(
+                info_ids.iter().filter(|id| NftManager::info_collection(*id).is_some()).count(),
+                1
+            );
+
+            let (_, cursor) = crate::migration::AssignNftsToCollections::<TestRuntime>::step(
+                cursor,
+                weight_per_info * 10,
+            );
+            assert!(cursor.is_none());
+            assert!(info_ids.iter().all(|id| NftManager::info_collection(*id).is_some()));
+        });
+    }
+}
@@ -0,0 +1,231 @@
+// Copyright 2024 Aventus Network Services (UK) Ltd.
+
+#![cfg(test)]
+
+use super::*;
+use crate::mock::{AccountId, RuntimeEvent as Event, RuntimeOrigin as Origin, *};
+use frame_support::{assert_noop, assert_ok};
+use hex_literal::hex;
+use sp_avn_common::BridgeContractMethod;
+
+const T1_AUTHORITY: H160 = H160(hex!("11111AAAAA22222BBBBB11111AAAAA22222BBBBB"));
+const T1_RECIPIENT: H160 = H160(hex!("afdf36201bf70F1232111b5c6a9a424558755134"));
+const ROYALTY_RECIPIENT: H160 = H160(hex!("33333CCCCC44444DDDDD33333CCCCC44444DDDDD"));
+const ROYALTY_PARTS_PER_MILLION: u32 = 25_000;
+
+fn owner() -> AccountId {
+    TestAccount::new([1u8; 32]).account_id()
+}
+
+fn mint_nft() -> NftId {
+    let unique_id = NftManager::next_unique_id();
+    let nft_id = NftManager::generate_nft_id_single_mint(&T1_AUTHORITY, unique_id);
+    assert_ok!(NftManager::mint_single_nft(
+        Origin::signed(owner()),
+        format!("Offchain location of NFT {}", unique_id).into_bytes(),
+        vec![Royalty {
+            recipient_t1_address: ROYALTY_RECIPIENT,
+            rate: RoyaltyRate { parts_per_million: ROYALTY_PARTS_PER_MILLION },
+        }],
+        T1_AUTHORITY,
+    ));
+    nft_id
+}
+
+fn export(nft_id: NftId) -> u32 {
+    let tx_id = published_transactions().len() as u32;
+    assert_ok!(NftManager::export_nft(Origin::signed(owner()), nft_id, T1_RECIPIENT));
+    tx_id
+}
+
+fn event_emitted(event: crate::Event<TestRuntime>) -> bool {
+    System::events().iter().any(|a| a.event == Event::NftManager(event.clone()))
+}
+
+mod export_nft {
+    use super::*;
+
+    #[test]
+    fn locks_the_nft_and_publishes_the_export() {
+        let mut ext = ExtBuilder::build_default().as_externality();
+        ext.execute_with(|| {
+            let nft_id = mint_nft();
+
+            let tx_id = export(nft_id);
+
+            assert!(NftManager::nfts(nft_id).unwrap().is_locked);
+            assert_eq!(
+                NftManager::pending_nft_export(tx_id),
+                Some(NftExport { nft_id, owner: owner(), t1_recipient: T1_RECIPIENT })
+            );
+            assert_eq!(
+                published_transactions()[tx_id as usize],
+                (
+                    BridgeContractMethod::ExportNft.as_bytes().to_vec(),
+                    vec![
+                        (b"address".to_vec(), T1_AUTHORITY.as_bytes().to_vec()),
+                        (b"uint256".to_vec(), nft_id.to_string().into_bytes()),
+                        (b"address".to_vec(), T1_RECIPIENT.as_bytes().to_vec()),
+                        (
+                            b"bytes".to_vec(),
+                            [
+                                ROYALTY_RECIPIENT.as_bytes(),
+                                &ROYALTY_PARTS_PER_MILLION.to_be_bytes()[..]
+                            ]
+                            .concat()
+                        ),
+                    ]
+                )
+            );
+            assert!(event_emitted(crate::Event::<TestRuntime>::NftExportRequested {
+                nft_id,
+                owner: owner(),
+                t1_recipient: T1_RECIPIENT,
+                tx_id,
+            }));
+        });
+    }
+
+    #[test]
+    fn stops_the_nft_being_listed() {
+        let mut ext = ExtBuilder::build_default().as_externality();
+        ext.execute_with(|| {
+            let nft_id = mint_nft();
+            export(nft_id);
+
+            assert_noop!(
+                NftManager::list_nft_open_for_sale(
+                    Origin::signed(owner()),
+                    nft_id,
+                    NftSaleType::Fiat
+                ),
+                Error::<TestRuntime>::NftIsLocked
+            );
+        });
+    }
+
+    mod fails_when {
+        use super::*;
+
+        #[test]
+        fn the_sender_is_not_the_owner() {
+            let mut ext = ExtBuilder::build_default().as_externality();
+            ext.execute_with(|| {
+                let nft_id = mint_nft();
+                let other_account = TestAccount::new([2u8; 32]).account_id();
+
+                assert_noop!(
+                    NftManager::export_nft(Origin::signed(other_account), nft_id, T1_RECIPIENT),
+                    Error::<TestRuntime>::SenderIsNotOwner
+                );
+            });
+        }
+
+        #[test]
+        fn the_nft_is_listed() {
+            let mut ext = ExtBuilder::build_default().as_externality();
+            ext.execute_with(|| {
+                let nft_id = mint_nft();
+                assert_ok!(NftManager::list_nft_open_for_sale(
+                    Origin::signed(owner()),
+                    nft_id,
+                    NftSaleType::Fiat
+                ));
+
+                assert_noop!(
+                    NftManager::export_nft(Origin::signed(owner()), nft_id, T1_RECIPIENT),
+                    Error::<TestRuntime>::NftAlreadyListed
+                );
+            });
+        }
+
+        #[test]
+        fn the_nft_is_already_being_exported() {
+            let mut ext = ExtBuilder::build_default().as_externality();
+            ext.execute_with(|| {
+                let nft_id = mint_nft();
+                export(nft_id);
+
+                assert_noop!(
+                    NftManager::export_nft(Origin::signed(owner()), nft_id, T1_RECIPIENT),
+                    Error::<TestRuntime>::NftIsLocked
+                );
+            });
+        }
+
+        #[test]
+        fn the_recipient_is_missing() {
+            let mut ext = ExtBuilder::build_default().as_externality();
+            ext.execute_with(|| {
+                let nft_id = mint_nft();
+
+                assert_noop!(
+                    NftManager::export_nft(Origin::signed(owner()), nft_id, H160::zero()),
+                    Error::<TestRuntime>::T1RecipientIsMandatory
+                );
+            });
+        }
+    }
+}
+
+mod export_result {
+    use super::*;
+
+    #[test]
+    fn burns_the_nft_when_the_export_succeeds() {
+        let mut ext = ExtBuilder::build_default().as_externality();
+        ext.execute_with(|| {
+            let nft_id = mint_nft();
+            let external_ref = NftManager::nfts(nft_id).unwrap().unique_external_ref;
+            let tx_id = export(nft_id);
+
+            assert_ok!(NftManager::process_result(tx_id, PALLET_ID.to_vec(), true));
+
+            assert_eq!(NftManager::nfts(nft_id), None);
+            assert_eq!(NftManager::pending_nft_export(tx_id), None);
+            assert!(NftManager::is_external_ref_used(external_ref));
+            assert!(event_emitted(crate::Event::<TestRuntime>::NftExported {
+                nft_id,
+                t1_recipient: T1_RECIPIENT,
+                tx_id,
+            }));
+        });
+    }
+
+    #[test]
+    fn returns_the_nft_to_its_owner_when_the_export_fails() {
+        let mut ext = ExtBuilder::build_default().as_externality();
+        ext.execute_with(|| {
+            let nft_id = mint_nft();
+            let tx_id = export(nft_id);
+
+            assert_ok!(NftManager::process_result(tx_id, PALLET_ID.to_vec(), false));
+
+            let nft = NftManager::nfts(nft_id).unwrap();
+            assert_eq!(nft.owner, owner());
+            assert!(!nft.is_locked);
+            assert!(event_emitted(crate::Event::<TestRuntime>::NftExportFailed {
+                nft_id,
+                owner: owner(),
+                tx_id,
+            }));
+
+            // The owner can try again
+            export(nft_id);
+        });
+    }
+
+    #[test]
+    fn ignores_the_results_of_other_pallets() {
+        let mut ext = ExtBuilder::build_default().as_externality();
+        ext.execute_with(|| {
+            let nft_id = mint_nft();
+            let tx_id = export(nft_id);
+
+            assert_ok!(NftManager::process_result(tx_id, b"summary".to_vec(), true));
+
+            assert!(NftManager::nfts(nft_id).is_some());
+            assert!(NftManager::pending_nft_export(tx_id).is_some());
+        });
+    }
+}
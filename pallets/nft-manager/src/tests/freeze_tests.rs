@@ -0,0 +1,264 @@
+// Copyright 2024 Aventus Network Services (UK) Ltd.
+
+#![cfg(test)]
+
+use super::*;
+use crate::mock::{AccountId, RuntimeEvent as Event, RuntimeOrigin as Origin, *};
+use frame_support::{
+    assert_noop, assert_ok,
+    error::BadOrigin,
+    traits::{Currency, Hooks},
+};
+use hex_literal::hex;
+
+const T1_AUTHORITY: H160 = H160(hex!("11111AAAAA22222BBBBB11111AAAAA22222BBBBB"));
+const T1_RECIPIENT: H160 = H160(hex!("afdf36201bf70F1232111b5c6a9a424558755134"));
+const BID: u128 = 100;
+const INITIAL_BALANCE: u128 = 10_000;
+
+fn owner() -> TestAccount {
+    TestAccount::new([1u8; 32])
+}
+
+fn bidder() -> AccountId {
+    TestAccount::new([2u8; 32]).account_id()
+}
+
+fn relayer() -> AccountId {
+    TestAccount::new([3u8; 32]).account_id()
+}
+
+fn mint_nft() -> NftId {
+    let unique_id = NftManager::next_unique_id();
+    let nft_id = NftManager::generate_nft_id_single_mint(&T1_AUTHORITY, unique_id);
+    assert_ok!(NftManager::mint_single_nft(
+        Origin::signed(owner().account_id()),
+        format!("Offchain location of NFT {}", unique_id).into_bytes(),
+        vec![],
+        T1_AUTHORITY,
+    ));
+    nft_id
+}
+
+fn mint_frozen_nft() -> NftId {
+    let nft_id = mint_nft();
+    assert_ok!(NftManager::freeze_nft(Origin::root(), nft_id));
+    nft_id
+}
+
+fn signed_transfer_fiat_nft(nft_id: NftId) -> DispatchResult {
+    let nonce = NftManager::nfts(nft_id).unwrap().nonce;
+    let relayer = relayer();
+    let buyer = H256::from_slice(&bidder().encode());
+    let data_to_sign = (SIGNED_TRANSFER_FIAT_NFT_CONTEXT, &relayer, nft_id, buyer, nonce);
+    let proof = Proof {
+        signer: owner().account_id(),
+        relayer,
+        signature: sign(&owner().key_pair(), &data_to_sign.encode()),
+    };
+    NftManager::signed_transfer_fiat_nft(Origin::signed(owner().account_id()), proof, nft_id, buyer)
+}
+
+fn event_emitted(event: crate::Event<TestRuntime>) -> bool {
+    System::events().iter().any(|a| a.event == Event::NftManager(event.clone()))
+}
+
+mod freeze_nft {
+    use super::*;
+
+    #[test]
+    fn freezes_the_nft() {
+        let mut ext = ExtBuilder::build_default().as_externality();
+        ext.execute_with(|| {
+            let nft_id = mint_nft();
+
+            assert_ok!(NftManager::freeze_nft(Origin::root(), nft_id));
+
+            assert!(FrozenNfts::<TestRuntime>::contains_key(nft_id));
+            assert!(event_emitted(crate::Event::<TestRuntime>::NftFrozen { nft_id }));
+        });
+    }
+
+    mod fails_when {
+        use super::*;
+
+        #[test]
+        fn the_nft_does_not_exist() {
+            let mut ext = ExtBuilder::build_default().as_externality();
+            ext.execute_with(|| {
+                assert_noop!(
+                    NftManager::freeze_nft(Origin::root(), U256::from(1u8)),
+                    Error::<TestRuntime>::NftIdDoesNotExist
+                );
+            });
+        }
+
+        #[test]
+        fn the_nft_is_already_frozen() {
+            let mut ext = ExtBuilder::build_default().as_externality();
+            ext.execute_with(|| {
+                let nft_id = mint_frozen_nft();
+
+                assert_noop!(
+                    NftManager::freeze_nft(Origin::root(), nft_id),
+                    Error::<TestRuntime>::NftAlreadyFrozen
+                );
+            });
+        }
+
+        #[test]
+        fn the_origin_is_not_the_admin_origin() {
+            let mut ext = ExtBuilder::build_default().as_externality();
+            ext.execute_with(|| {
+                let nft_id = mint_nft();
+
+                assert_noop!(
+                    NftManager::freeze_nft(Origin::signed(owner().account_id()), nft_id),
+                    BadOrigin
+                );
+            });
+        }
+    }
+}
+
+mod unfreeze_nft {
+    use super::*;
+
+    #[test]
+    fn lets_the_nft_change_hands_again() {
+        let mut ext = ExtBuilder::build_default().as_externality();
+        ext.execute_with(|| {
+            let nft_id = mint_frozen_nft();
+
+            assert_ok!(NftManager::unfreeze_nft(Origin::root(), nft_id));
+
+            assert!(!FrozenNfts::<TestRuntime>::contains_key(nft_id));
+            assert!(event_emitted(crate::Event::<TestRuntime>::NftUnfrozen { nft_id }));
+            assert_ok!(NftManager::list_nft_open_for_sale(
+                Origin::signed(owner().account_id()),
+                nft_id,
+                NftSaleType::Fiat
+            ));
+        });
+    }
+
+    mod fails_when {
+        use super::*;
+
+        #[test]
+        fn the_nft_is_not_frozen() {
+            let mut ext = ExtBuilder::build_default().as_externality();
+            ext.execute_with(|| {
+                let nft_id = mint_nft();
+
+                assert_noop!(
+                    NftManager::unfreeze_nft(Origin::root(), nft_id),
+                    Error::<TestRuntime>::NftNotFrozen
+                );
+            });
+        }
+    }
+}
+
+mod a_frozen_nft {
+    use super::*;
+
+    #[test]
+    fn cannot_be_listed() {
+        let mut ext = ExtBuilder::build_default().as_externality();
+        ext.execute_with(|| {
+            let nft_id = mint_frozen_nft();
+
+            assert_noop!(
+                NftManager::list_nft_open_for_sale(
+                    Origin::signed(owner().account_id()),
+                    nft_id,
+                    NftSaleType::Fiat
+                ),
+                Error::<TestRuntime>::NftIsFrozen
+            );
+        });
+    }
+
+    #[test]
+    fn cannot_be_sold_when_it_was_already_listed() {
+        let mut ext = ExtBuilder::build_default().as_externality();
+        ext.execute_with(|| {
+            let nft_id = mint_nft();
+            assert_ok!(NftManager::list_nft_open_for_sale(
+                Origin::signed(owner().account_id()),
+                nft_id,
+                NftSaleType::Fiat
+            ));
+            assert_ok!(NftManager::freeze_nft(Origin::root(), nft_id));
+
+            assert_noop!(signed_transfer_fiat_nft(nft_id), Error::<TestRuntime>::NftIsFrozen);
+        });
+    }
+
+    #[test]
+    fn cannot_be_exported() {
+        let mut ext = ExtBuilder::build_default().as_externality();
+        ext.execute_with(|| {
+            let nft_id = mint_frozen_nft();
+
+            assert_noop!(
+                NftManager::export_nft(Origin::signed(owner().account_id()), nft_id, T1_RECIPIENT),
+                Error::<TestRuntime>::NftIsFrozen
+            );
+        });
+    }
+
+    #[test]
+    fn cannot_be_burnt_by_its_owner() {
+        let mut ext = ExtBuilder::build_default().as_externality();
+        ext.execute_with(|| {
+            let nft_id = mint_frozen_nft();
+
+            assert_noop!(
+                NftManager::burn_nft(Origin::signed(owner().account_id()), nft_id),
+                Error::<TestRuntime>::NftIsFrozen
+            );
+        });
+    }
+
+    #[test]
+    fn can_be_burnt_by_the_admin_origin() {
+        let mut ext = ExtBuilder::build_default().as_externality();
+        ext.execute_with(|| {
+            let nft_id = mint_frozen_nft();
+
+            assert_ok!(NftManager::burn_nft(Origin::root(), nft_id));
+
+            assert_eq!(NftManager::nfts(nft_id), None);
+            assert!(!FrozenNfts::<TestRuntime>::contains_key(nft_id));
+        });
+    }
+
+    #[test]
+    fn is_not_sold_when_its_auction_ends() {
+        let mut ext = ExtBuilder::build_default()
+            .with_balances(vec![(bidder(), INITIAL_BALANCE)])
+            .as_externality();
+        ext.execute_with(|| {
+            let nft_id = mint_nft();
+            let end_block = System::block_number() + MinAuctionDuration::get();
+            assert_ok!(NftManager::create_auction(
+                Origin::signed(owner().account_id()),
+                nft_id,
+                BID,
+                MinAuctionDuration::get()
+            ));
+            assert_ok!(NftManager::bid(Origin::signed(bidder()), nft_id, BID));
+            assert_ok!(NftManager::freeze_nft(Origin::root(), nft_id));
+
+            System::set_block_number(end_block);
+            NftManager::on_initialize(end_block);
+
+            assert_eq!(NftManager::nfts(nft_id).unwrap().owner, owner().account_id());
+            assert_eq!(Balances::free_balance(bidder()), INITIAL_BALANCE);
+            assert!(!<NftOpenForSale<TestRuntime>>::contains_key(nft_id));
+            assert!(event_emitted(crate::Event::<TestRuntime>::AuctionCancelled { nft_id }));
+        });
+    }
+}
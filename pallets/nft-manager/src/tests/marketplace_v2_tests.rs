@@ -0,0 +1,188 @@
+// Copyright 2024 Aventus Network Services (UK) Ltd.
+
+#![cfg(test)]
+
+use super::*;
+use crate::mock::{AccountId, RuntimeEvent as Event, RuntimeOrigin as Origin, *};
+use frame_support::{assert_noop, assert_ok};
+use hex_literal::hex;
+use sp_avn_common::event_types::{NftBatchListingScheduleData, NftOfferAcceptedData, ValidEvents};
+
+const T1_AUTHORITY: H160 = H160(hex!("11111AAAAA22222BBBBB11111AAAAA22222BBBBB"));
+const PRICE: u128 = 1_000;
+
+fn owner() -> AccountId {
+    TestAccount::new([1u8; 32]).account_id()
+}
+
+fn buyer() -> AccountId {
+    TestAccount::new([2u8; 32]).account_id()
+}
+
+fn eth_event(signature: H256, transaction_hash: H256, event_data: EventData) -> EthEvent {
+    let event_id = EthEventId { signature, transaction_hash };
+    insert_to_mock_processed_events(&event_id);
+    EthEvent { event_id, event_data }
+}
+
+fn event_emitted(event: crate::Event<TestRuntime>) -> bool {
+    System::events().iter().any(|a| a.event == Event::NftManager(event.clone()))
+}
+
+mod batch_listing_scheduled {
+    use super::*;
+
+    fn schedule() -> PriceSchedule {
+        PriceSchedule { start_price: 2 * PRICE, end_price: PRICE, start_time: 100, end_time: 200 }
+    }
+
+    fn list_batch(market: NftSaleType) -> NftBatchId {
+        let batch_id = U256::from(7u8);
+        <BatchInfoId<TestRuntime>>::insert(batch_id, NftInfoId::from(1u8));
+        <BatchOpenForSale<TestRuntime>>::insert(batch_id, market);
+        batch_id
+    }
+
+    fn schedule_event(batch_id: NftBatchId) -> EthEvent {
+        eth_event(
+            ValidEvents::NftBatchListingScheduled.signature(),
+            H256::repeat_byte(1),
+            EventData::LogNftBatchListingScheduled(NftBatchListingScheduleData {
+                batch_id,
+                schedule: schedule(),
+            }),
+        )
+    }
+
+    #[test]
+    fn records_the_price_schedule_of_the_batch() {
+        let mut ext = ExtBuilder::build_default().as_externality();
+        ext.execute_with(|| {
+            let batch_id = list_batch(NftSaleType::Ethereum);
+            let event = schedule_event(batch_id);
+
+            assert_ok!(NftManager::on_event_processed(&event));
+
+            assert_eq!(NftManager::batch_price_schedule(batch_id), Some(schedule()));
+            assert!(event_emitted(crate::Event::<TestRuntime>::BatchPriceScheduleSet {
+                batch_id,
+                schedule: schedule(),
+                eth_event_id: event.event_id,
+            }));
+        });
+    }
+
+    #[test]
+    fn the_schedule_is_removed_when_the_listing_ends() {
+        let mut ext = ExtBuilder::build_default().as_externality();
+        ext.execute_with(|| {
+            let batch_id = list_batch(NftSaleType::Ethereum);
+            assert_ok!(NftManager::on_event_processed(&schedule_event(batch_id)));
+
+            assert_ok!(end_batch_listing::<TestRuntime>(&batch_id, NftSaleType::Ethereum));
+
+            assert_eq!(NftManager::batch_price_schedule(batch_id), None);
+        });
+    }
+
+    mod fails_when {
+        use super::*;
+
+        #[test]
+        fn the_batch_is_not_listed_for_ethereum_sale() {
+            let mut ext = ExtBuilder::build_default().as_externality();
+            ext.execute_with(|| {
+                let batch_id = list_batch(NftSaleType::Fiat);
+
+                assert_noop!(
+                    NftManager::on_event_processed(&schedule_event(batch_id)),
+                    Error::<TestRuntime>::BatchNotListedForEthereumSale
+                );
+            });
+        }
+    }
+}
+
+mod offer_accepted {
+    use super::*;
+
+    fn mint_and_list_nft(market: NftSaleType) -> NftId {
+        let unique_id = NftManager::next_unique_id();
+        let nft_id = NftManager::generate_nft_id_single_mint(&T1_AUTHORITY, unique_id);
+        assert_ok!(NftManager::mint_single_nft(
+            Origin::signed(owner()),
+            format!("Offchain location of NFT {}", unique_id).into_bytes(),
+            vec![],
+            T1_AUTHORITY,
+        ));
+        assert_ok!(NftManager::list_nft_open_for_sale(Origin::signed(owner()), nft_id, market));
+        nft_id
+    }
+
+    fn offer_accepted_event(nft_id: NftId, op_id: u64) -> EthEvent {
+        eth_event(
+            ValidEvents::NftOfferAccepted.signature(),
+            H256::repeat_byte(2),
+            EventData::LogNftOfferAccepted(NftOfferAcceptedData {
+                nft_id,
+                t2_transfer_to_public_key: H256::from_slice(&buyer().encode()),
+                op_id,
+                price: PRICE,
+            }),
+        )
+    }
+
+    #[test]
+    fn transfers_the_nft_to_the_buyer() {
+        let mut ext = ExtBuilder::build_default().as_externality();
+        ext.execute_with(|| {
+            let nft_id = mint_and_list_nft(NftSaleType::Ethereum);
+            let op_id = NftManager::nfts(nft_id).unwrap().nonce;
+            let event = offer_accepted_event(nft_id, op_id);
+
+            assert_ok!(NftManager::on_event_processed(&event));
+
+            assert_eq!(NftManager::nfts(nft_id).unwrap().owner, buyer());
+            assert!(!<NftOpenForSale<TestRuntime>>::contains_key(nft_id));
+            assert!(event_emitted(crate::Event::<TestRuntime>::EthNftOfferAccepted {
+                nft_id,
+                new_owner: buyer(),
+                price: PRICE,
+                op_id,
+                eth_event_id: event.event_id,
+            }));
+        });
+    }
+
+    mod fails_when {
+        use super::*;
+
+        #[test]
+        fn the_nft_is_not_listed_for_ethereum_sale() {
+            let mut ext = ExtBuilder::build_default().as_externality();
+            ext.execute_with(|| {
+                let nft_id = mint_and_list_nft(NftSaleType::Fiat);
+                let op_id = NftManager::nfts(nft_id).unwrap().nonce;
+
+                assert_noop!(
+                    NftManager::on_event_processed(&offer_accepted_event(nft_id, op_id)),
+                    Error::<TestRuntime>::NftNotListedForEthereumSale
+                );
+            });
+        }
+
+        #[test]
+        fn the_op_id_does_not_match_the_nonce_of_the_nft() {
+            let mut ext = ExtBuilder::build_default().as_externality();
+            ext.execute_with(|| {
+                let nft_id = mint_and_list_nft(NftSaleType::Ethereum);
+                let op_id = NftManager::nfts(nft_id).unwrap().nonce + 1;
+
+                assert_noop!(
+                    NftManager::on_event_processed(&offer_accepted_event(nft_id, op_id)),
+                    Error::<TestRuntime>::NftNonceMismatch
+                );
+            });
+        }
+    }
+}
@@ -0,0 +1,258 @@
+// Copyright 2024 Aventus Network Services (UK) Ltd.
+
+#![cfg(test)]
+
+use super::*;
+use crate::mock::{AccountId, RuntimeEvent as Event, RuntimeOrigin as Origin, *};
+use frame_support::{assert_noop, assert_ok};
+use hex_literal::hex;
+
+const T1_AUTHORITY: H160 = H160(hex!("11111AAAAA22222BBBBB11111AAAAA22222BBBBB"));
+
+fn minter() -> AccountId {
+    TestAccount::new([1u8; 32]).account_id()
+}
+
+fn other_account() -> AccountId {
+    TestAccount::new([2u8; 32]).account_id()
+}
+
+fn corrected_ref() -> Vec<u8> {
+    b"Corrected location of NFT".to_vec()
+}
+
+fn mint_nft() -> NftId {
+    let unique_id = NftManager::next_unique_id();
+    let nft_id = NftManager::generate_nft_id_single_mint(&T1_AUTHORITY, unique_id);
+    assert_ok!(NftManager::mint_single_nft(
+        Origin::signed(minter()),
+        format!("Offchain location of NFT {}", unique_id).into_bytes(),
+        vec![],
+        T1_AUTHORITY,
+    ));
+    nft_id
+}
+
+fn external_ref_of(nft_id: NftId) -> Vec<u8> {
+    NftManager::nfts(nft_id).unwrap().unique_external_ref.to_vec()
+}
+
+mod update_metadata {
+    use super::*;
+
+    #[test]
+    fn replaces_the_external_ref_and_records_the_change() {
+        let mut ext = ExtBuilder::build_default().as_externality();
+        ext.execute_with(|| {
+            System::set_block_number(5);
+            let nft_id = mint_nft();
+            let previous_ref = external_ref_of(nft_id);
+
+            assert_ok!(NftManager::update_metadata(
+                Origin::signed(minter()),
+                nft_id,
+                corrected_ref()
+            ));
+
+            assert_eq!(external_ref_of(nft_id), corrected_ref());
+            let history = NftManager::metadata_history(nft_id);
+            assert_eq!(history.len(), 1);
+            assert_eq!(history[0].previous_external_ref.to_vec(), previous_ref);
+            assert_eq!(history[0].changed_at, 5);
+            assert!(System::events().iter().any(|a| a.event ==
+                Event::NftManager(crate::Event::<TestRuntime>::MetadataUpdated {
+                    nft_id,
+                    unique_external_ref: BoundedVec::truncate_from(corrected_ref()),
+                    updated_by: minter(),
+                })));
+        });
+    }
+
+    #[test]
+    fn keeps_the_previous_external_ref_in_use() {
+        let mut ext = ExtBuilder::build_default().as_externality();
+        ext.execute_with(|| {
+            let nft_id = mint_nft();
+            let previous_ref = external_ref_of(nft_id);
+
+            assert_ok!(NftManager::update_metadata(
+                Origin::signed(minter()),
+                nft_id,
+                corrected_ref()
+            ));
+
+            assert!(NftManager::is_external_ref_used(BoundedVec::truncate_from(previous_ref)));
+            assert!(NftManager::is_external_ref_used(BoundedVec::truncate_from(corrected_ref())));
+        });
+    }
+
+    #[test]
+    fn drops_the_oldest_change_when_the_history_is_full() {
+        let mut ext = ExtBuilder::build_default().as_externality();
+        ext.execute_with(|| {
+            let nft_id = mint_nft();
+            let first_ref = external_ref_of(nft_id);
+
+            for i in 0..=MAX_METADATA_HISTORY {
+                assert_ok!(NftManager::update_metadata(
+                    Origin::signed(minter()),
+                    nft_id,
+                    format!("Corrected location of NFT {}", i).into_bytes()
+                ));
+            }
+
+            let history = NftManager::metadata_history(nft_id);
+            assert_eq!(history.len(), MAX_METADATA_HISTORY as usize);
+            assert!(history
+                .iter()
+                .all(|change| change.previous_external_ref.to_vec() != first_ref));
+        });
+    }
+
+    #[test]
+    fn works_for_a_delegated_authority() {
+        let mut ext = ExtBuilder::build_default().as_externality();
+        ext.execute_with(|| {
+            let nft_id = mint_nft();
+            let info_id = NftManager::nfts(nft_id).unwrap().info_id;
+
+            assert_ok!(NftManager::set_metadata_authority(
+                Origin::signed(minter()),
+                info_id,
+                Some(other_account())
+            ));
+            assert_ok!(NftManager::update_metadata(
+                Origin::signed(other_account()),
+                nft_id,
+                corrected_ref()
+            ));
+
+            assert_eq!(external_ref_of(nft_id), corrected_ref());
+            assert_noop!(
+                NftManager::set_metadata_authority(Origin::signed(minter()), info_id, None),
+                Error::<TestRuntime>::SenderIsNotMetadataAuthority
+            );
+        });
+    }
+
+    #[test]
+    fn works_for_an_sft() {
+        let mut ext = ExtBuilder::build_default().as_externality();
+        ext.execute_with(|| {
+            let sft_id = NftManager::generate_nft_id_single_mint(
+                &T1_AUTHORITY,
+                NftManager::next_unique_id(),
+            );
+            assert_ok!(NftManager::mint_sft(
+                Origin::signed(minter()),
+                b"Offchain location of SFT".to_vec(),
+                vec![],
+                T1_AUTHORITY,
+                10
+            ));
+
+            assert_ok!(NftManager::update_metadata(
+                Origin::signed(minter()),
+                sft_id,
+                corrected_ref()
+            ));
+
+            assert_eq!(
+                NftManager::sft(sft_id).unwrap().unique_external_ref.to_vec(),
+                corrected_ref()
+            );
+            assert_eq!(NftManager::metadata_history(sft_id).len(), 1);
+        });
+    }
+
+    mod fails_when {
+        use super::*;
+
+        #[test]
+        fn the_sender_is_not_the_metadata_authority() {
+            let mut ext = ExtBuilder::build_default().as_externality();
+            ext.execute_with(|| {
+                let nft_id = mint_nft();
+
+                assert_noop!(
+                    NftManager::update_metadata(
+                        Origin::signed(other_account()),
+                        nft_id,
+                        corrected_ref()
+                    ),
+                    Error::<TestRuntime>::SenderIsNotMetadataAuthority
+                );
+            });
+        }
+
+        #[test]
+        fn the_authority_was_removed() {
+            let mut ext = ExtBuilder::build_default().as_externality();
+            ext.execute_with(|| {
+                let nft_id = mint_nft();
+                let info_id = NftManager::nfts(nft_id).unwrap().info_id;
+                assert_ok!(NftManager::set_metadata_authority(
+                    Origin::signed(minter()),
+                    info_id,
+                    None
+                ));
+
+                assert_noop!(
+                    NftManager::update_metadata(Origin::signed(minter()), nft_id, corrected_ref()),
+                    Error::<TestRuntime>::SenderIsNotMetadataAuthority
+                );
+            });
+        }
+
+        #[test]
+        fn the_nft_is_listed() {
+            let mut ext = ExtBuilder::build_default().as_externality();
+            ext.execute_with(|| {
+                let nft_id = mint_nft();
+                assert_ok!(NftManager::list_nft_open_for_sale(
+                    Origin::signed(minter()),
+                    nft_id,
+                    NftSaleType::Ethereum
+                ));
+
+                assert_noop!(
+                    NftManager::update_metadata(Origin::signed(minter()), nft_id, corrected_ref()),
+                    Error::<TestRuntime>::NftAlreadyListed
+                );
+            });
+        }
+
+        #[test]
+        fn the_external_ref_is_taken() {
+            let mut ext = ExtBuilder::build_default().as_externality();
+            ext.execute_with(|| {
+                let nft_id = mint_nft();
+                let other_nft_id = mint_nft();
+
+                assert_noop!(
+                    NftManager::update_metadata(
+                        Origin::signed(minter()),
+                        nft_id,
+                        external_ref_of(other_nft_id)
+                    ),
+                    Error::<TestRuntime>::ExternalRefIsAlreadyInUse
+                );
+            });
+        }
+
+        #[test]
+        fn the_nft_does_not_exist() {
+            let mut ext = ExtBuilder::build_default().as_externality();
+            ext.execute_with(|| {
+                assert_noop!(
+                    NftManager::update_metadata(
+                        Origin::signed(minter()),
+                        U256::from(1u8),
+                        corrected_ref()
+                    ),
+                    Error::<TestRuntime>::NftIdDoesNotExist
+                );
+            });
+        }
+    }
+}
@@ -18,11 +18,11 @@
 
 use frame_support::parameter_types;
 use frame_system as system;
-use sp_core::{sr25519, ConstU32, Pair, H256};
+use sp_core::{sr25519, ConstU128, ConstU32, Pair, H256};
 use sp_keystore::{testing::MemoryKeystore, KeystoreExt};
 use sp_runtime::{
     traits::{BlakeTwo256, IdentityLookup, Verify},
-    BuildStorage,
+    BuildStorage, DispatchError,
 };
 use std::cell::RefCell;
 
@@ -43,7 +43,8 @@ frame_support::construct_runtime!(
     pub enum TestRuntime
     {
         System: frame_system::{Pallet, Call, Config<T>, Storage, Event<T>},
-        AVN: pallet_avn::{Pallet, Storage, Event},
+        AVN: pallet_avn::{Pallet, Storage, Event<T>},
+        Balances: pallet_balances::{Pallet, Call, Storage, Event<T>},
         NftManager: nft_manager::{Pallet, Call, Storage, Event<T>},
     }
 );
@@ -56,10 +57,20 @@ impl Config for TestRuntime {
     type Signature = Signature;
     type WeightInfo = ();
     type BatchBound = MockNftBatchBound;
+    type Currency = Balances;
+    type EthereumAddressMapping = AVN;
+    type MinAuctionDuration = MinAuctionDuration;
+    type MaxAuctionDuration = MaxAuctionDuration;
+    type MaxAuctionsEndingPerBlock = ConstU32<2>;
+    type BridgeInterface = Self;
+    type NftContractChecker = Self;
+    type AdminOrigin = frame_system::EnsureRoot<AccountId>;
 }
 
 parameter_types! {
     pub const BlockHashCount: u64 = 250;
+    pub const MinAuctionDuration: u64 = 10;
+    pub const MaxAuctionDuration: u64 = 100;
 }
 
 impl system::Config for TestRuntime {
@@ -79,7 +90,7 @@ impl system::Config for TestRuntime {
     type BlockHashCount = BlockHashCount;
     type Version = ();
     type PalletInfo = PalletInfo;
-    type AccountData = ();
+    type AccountData = pallet_balances::AccountData<u128>;
     type OnNewAccount = ();
     type OnKilledAccount = ();
     type SystemWeightInfo = ();
@@ -88,6 +99,22 @@ impl system::Config for TestRuntime {
     type MaxConsumers = frame_support::traits::ConstU32<16>;
 }
 
+impl pallet_balances::Config for TestRuntime {
+    type MaxLocks = ();
+    type MaxReserves = ();
+    type ReserveIdentifier = [u8; 8];
+    type Balance = u128;
+    type RuntimeEvent = RuntimeEvent;
+    type DustRemoval = ();
+    type ExistentialDeposit = ConstU128<0>;
+    type AccountStore = System;
+    type WeightInfo = ();
+    type RuntimeHoldReason = ();
+    type FreezeIdentifier = ();
+    type MaxHolds = ();
+    type MaxFreezes = ();
+}
+
 impl avn::Config for TestRuntime {
     type RuntimeEvent = mock::RuntimeEvent;
     type AuthorityId = avn::sr25519::AuthorityId;
@@ -107,6 +134,12 @@ impl ExtBuilder {
         Self { storage }
     }
 
+    pub fn with_balances(mut self, balances: Vec<(AccountId, u128)>) -> Self {
+        let _ = pallet_balances::GenesisConfig::<TestRuntime> { balances }
+            .assimilate_storage(&mut self.storage);
+        self
+    }
+
     pub fn as_externality(self) -> sp_io::TestExternalities {
         let keystore = MemoryKeystore::new();
 
@@ -133,6 +166,66 @@ impl ProcessedEventsChecker for TestRuntime {
     fn add_processed_event(_event_id: &EthEventId, _accepted: bool) {}
 }
 
+thread_local! {
+    static NFT_CONTRACTS: RefCell<Vec<H160>> = RefCell::new(vec![]);
+}
+
+pub fn register_nft_contract(contract: H160) {
+    NFT_CONTRACTS.with(|c| c.borrow_mut().push(contract));
+}
+
+impl NftContractChecker for TestRuntime {
+    fn is_nft_contract(contract: &H160) -> bool {
+        NFT_CONTRACTS.with(|c| c.borrow().contains(contract))
+    }
+}
+
+thread_local! {
+    static PUBLISHED_TRANSACTIONS: RefCell<Vec<(Vec<u8>, Vec<(Vec<u8>, Vec<u8>)>)>> =
+        RefCell::new(vec![]);
+}
+
+/// The function names and params published to the bridge, in order. The id of a transaction is
+/// its index.
+pub fn published_transactions() -> Vec<(Vec<u8>, Vec<(Vec<u8>, Vec<u8>)>)> {
+    PUBLISHED_TRANSACTIONS.with(|p| p.borrow().clone())
+}
+
+impl BridgeInterface for TestRuntime {
+    fn publish(
+        function_name: &[u8],
+        params: &[(Vec<u8>, Vec<u8>)],
+        _caller_id: Vec<u8>,
+    ) -> Result<u32, DispatchError> {
+        PUBLISHED_TRANSACTIONS.with(|p| {
+            let mut published = p.borrow_mut();
+            published.push((function_name.to_vec(), params.to_vec()));
+            Ok(published.len() as u32 - 1)
+        })
+    }
+
+    fn generate_lower_proof(
+        _: u32,
+        _: &pallet_avn::LowerParams,
+        _: Vec<u8>,
+    ) -> Result<(), DispatchError> {
+        Ok(())
+    }
+
+    fn read_bridge_contract(
+        _: Vec<u8>,
+        _: &[u8],
+        _: &[(Vec<u8>, Vec<u8>)],
+        _: Option<u32>,
+    ) -> Result<Vec<u8>, DispatchError> {
+        Ok(vec![])
+    }
+
+    fn latest_finalised_ethereum_block() -> Result<u32, DispatchError> {
+        Ok(0)
+    }
+}
+
 pub struct TestAccount {
     pub seed: [u8; 32],
 }
@@ -0,0 +1,132 @@
+// Copyright 2024 Aventus Network Services (UK) Ltd.
+
+#![cfg(test)]
+
+use super::*;
+use crate::mock::{AccountId, RuntimeOrigin as Origin, *};
+use frame_support::{assert_ok, traits::GetStorageVersion};
+use hex_literal::hex;
+
+const T1_AUTHORITY: H160 = H160(hex!("11111AAAAA22222BBBBB11111AAAAA22222BBBBB"));
+
+fn owner() -> AccountId {
+    TestAccount::new([1u8; 32]).account_id()
+}
+
+fn other_account() -> AccountId {
+    TestAccount::new([2u8; 32]).account_id()
+}
+
+fn mint_nfts(count: u32) -> Vec<NftId> {
+    (0..count)
+        .map(|_| {
+            let unique_id = NftManager::next_unique_id();
+            let nft_id = NftManager::generate_nft_id_single_mint(&T1_AUTHORITY, unique_id);
+            assert_ok!(NftManager::mint_single_nft(
+                Origin::signed(owner()),
+                format!("Offchain location of NFT {}", unique_id).into_bytes(),
+                vec![],
+                T1_AUTHORITY,
+            ));
+            nft_id
+        })
+        .collect()
+}
+
+fn ids_of(nfts: Vec<Nft<AccountId>>) -> Vec<NftId> {
+    nfts.into_iter().map(|nft| nft.nft_id).collect()
+}
+
+fn sorted(mut nft_ids: Vec<NftId>) -> Vec<NftId> {
+    nft_ids.sort();
+    nft_ids
+}
+
+mod nfts_by_owner {
+    use super::*;
+
+    #[test]
+    fn returns_the_nfts_of_the_owner_page_by_page() {
+        let mut ext = ExtBuilder::build_default().as_externality();
+        ext.execute_with(|| {
+            let nft_ids = mint_nfts(5);
+
+            let first_page = ids_of(NftManager::nfts_by_owner(&owner(), None, 2));
+            let second_page =
+                ids_of(NftManager::nfts_by_owner(&owner(), first_page.last().cloned(), 2));
+            let last_page =
+                ids_of(NftManager::nfts_by_owner(&owner(), second_page.last().cloned(), 2));
+
+            assert_eq!(first_page.len(), 2);
+            assert_eq!(second_page.len(), 2);
+            assert_eq!(last_page.len(), 1);
+            assert_eq!(sorted([first_page, second_page, last_page].concat()), sorted(nft_ids));
+        });
+    }
+
+    #[test]
+    fn caps_the_size_of_a_page() {
+        let mut ext = ExtBuilder::build_default().as_externality();
+        ext.execute_with(|| {
+            mint_nfts(MAX_NFTS_PER_PAGE + 1);
+
+            assert_eq!(
+                NftManager::nfts_by_owner(&owner(), None, u32::MAX).len(),
+                MAX_NFTS_PER_PAGE as usize
+            );
+        });
+    }
+
+    #[test]
+    fn follows_transfers() {
+        let mut ext = ExtBuilder::build_default().as_externality();
+        ext.execute_with(|| {
+            let nft_ids = mint_nfts(2);
+
+            NftManager::update_owner_for_transfer(&nft_ids[0], &other_account());
+
+            assert_eq!(ids_of(NftManager::nfts_by_owner(&owner(), None, 10)), vec![nft_ids[1]]);
+            assert_eq!(
+                ids_of(NftManager::nfts_by_owner(&other_account(), None, 10)),
+                vec![nft_ids[0]]
+            );
+        });
+    }
+
+    #[test]
+    fn drops_burnt_nfts() {
+        let mut ext = ExtBuilder::build_default().as_externality();
+        ext.execute_with(|| {
+            let nft_ids = mint_nfts(2);
+
+            assert_ok!(NftManager::burn_nft(Origin::signed(owner()), nft_ids[0]));
+
+            assert_eq!(ids_of(NftManager::nfts_by_owner(&owner(), None, 10)), vec![nft_ids[1]]);
+        });
+    }
+}
+
+mod migration {
+    use super::*;
+
+    #[test]
+    fn indexes_existing_nfts() {
+        let mut ext = ExtBuilder::build_default().as_externality();
+        ext.execute_with(|| {
+            let nft_ids = mint_nfts(3);
+            let _ = <NftsByOwner<TestRuntime>>::clear(u32::MAX, None);
+            StorageVersion::new(5).put::<NftManager>();
+
+            pallet_avn::migration::migrate::<
+                TestRuntime,
+                crate::migration::SteppedMigrations<TestRuntime>,
+            >();
+
+            assert_eq!(
+                sorted(ids_of(NftManager::nfts_by_owner(&owner(), None, 10))),
+                sorted(nft_ids)
+            );
+            assert_eq!(NftManager::on_chain_storage_version(), StorageVersion::new(6));
+        });
+    }
+}
@@ -0,0 +1,517 @@
+// Copyright 2024 Aventus Network Services (UK) Ltd.
+
+#![cfg(test)]
+
+use super::*;
+use crate::mock::{AccountId, RuntimeEvent as Event, RuntimeOrigin as Origin, *};
+use frame_support::{assert_noop, assert_ok};
+use hex_literal::hex;
+use sp_avn_common::event_types::{SftMintData, SftTransferToData, ValidEvents};
+
+const SUPPLY: u128 = 100;
+const T1_AUTHORITY: H160 = H160(hex!("11111AAAAA22222BBBBB11111AAAAA22222BBBBB"));
+const ROYALTY_RECIPIENT_T1_ADDRESS: H160 = H160(hex!("33333CCCCC44444DDDDD33333CCCCC44444DDDDD"));
+
+fn creator() -> AccountId {
+    TestAccount::new([1u8; 32]).account_id()
+}
+
+fn buyer() -> AccountId {
+    TestAccount::new([2u8; 32]).account_id()
+}
+
+fn royalties() -> Vec<Royalty> {
+    vec![Royalty {
+        recipient_t1_address: ROYALTY_RECIPIENT_T1_ADDRESS,
+        rate: RoyaltyRate { parts_per_million: 1_000 },
+    }]
+}
+
+fn mint_sft() -> SftId {
+    let unique_id = NftManager::next_unique_id();
+    let sft_id = NftManager::generate_nft_id_single_mint(&T1_AUTHORITY, unique_id);
+    assert_ok!(NftManager::mint_sft(
+        Origin::signed(creator()),
+        format!("Offchain location of SFT {}", unique_id).into_bytes(),
+        royalties(),
+        T1_AUTHORITY,
+        SUPPLY,
+    ));
+    sft_id
+}
+
+fn list_sft(sft_id: SftId, amount: u128, market: NftSaleType) -> SftListingId {
+    let listing_id = NextSftListingId::<TestRuntime>::get();
+    assert_ok!(NftManager::list_sft_for_sale(Origin::signed(creator()), sft_id, amount, market));
+    listing_id
+}
+
+fn eth_event(signature: H256, transaction_hash: H256, event_data: EventData) -> EthEvent {
+    let event_id = EthEventId { signature, transaction_hash };
+    insert_to_mock_processed_events(&event_id);
+    EthEvent { event_id, event_data }
+}
+
+fn event_emitted(event: crate::Event<TestRuntime>) -> bool {
+    System::events().iter().any(|a| a.event == Event::NftManager(event.clone()))
+}
+
+mod mint_sft {
+    use super::*;
+
+    #[test]
+    fn mints_the_supply_to_the_creator() {
+        let mut ext = ExtBuilder::build_default().as_externality();
+        ext.execute_with(|| {
+            let sft_id = mint_sft();
+
+            let sft = NftManager::sft(sft_id).unwrap();
+            assert_eq!(sft.creator, creator());
+            assert_eq!(sft.total_supply, SUPPLY);
+            assert_eq!(NftManager::sft_balance(sft_id, creator()), SUPPLY);
+            let info = NftManager::nft_infos(sft.info_id).unwrap();
+            assert_eq!(info.royalties.to_vec(), royalties());
+            assert_eq!(info.t1_authority, T1_AUTHORITY);
+            assert!(NftManager::is_external_ref_used(sft.unique_external_ref));
+            assert!(event_emitted(crate::Event::<TestRuntime>::SftMinted {
+                sft_id,
+                owner: creator(),
+                authority: T1_AUTHORITY,
+                amount: SUPPLY,
+            }));
+        });
+    }
+
+    mod fails_when {
+        use super::*;
+
+        #[test]
+        fn the_supply_is_zero() {
+            let mut ext = ExtBuilder::build_default().as_externality();
+            ext.execute_with(|| {
+                assert_noop!(
+                    NftManager::mint_sft(
+                        Origin::signed(creator()),
+                        b"Offchain location of SFT".to_vec(),
+                        royalties(),
+                        T1_AUTHORITY,
+                        0
+                    ),
+                    Error::<TestRuntime>::SftAmountIsZero
+                );
+            });
+        }
+
+        #[test]
+        fn the_external_ref_is_taken() {
+            let mut ext = ExtBuilder::build_default().as_externality();
+            ext.execute_with(|| {
+                let sft_id = mint_sft();
+                let external_ref = NftManager::sft(sft_id).unwrap().unique_external_ref.to_vec();
+
+                assert_noop!(
+                    NftManager::mint_sft(
+                        Origin::signed(creator()),
+                        external_ref,
+                        royalties(),
+                        T1_AUTHORITY,
+                        SUPPLY
+                    ),
+                    Error::<TestRuntime>::ExternalRefIsAlreadyInUse
+                );
+            });
+        }
+    }
+}
+
+mod transfer_sft {
+    use super::*;
+
+    #[test]
+    fn moves_the_amount_between_accounts() {
+        let mut ext = ExtBuilder::build_default().as_externality();
+        ext.execute_with(|| {
+            let sft_id = mint_sft();
+
+            assert_ok!(NftManager::transfer_sft(Origin::signed(creator()), sft_id, buyer(), 30));
+
+            assert_eq!(NftManager::sft_balance(sft_id, creator()), SUPPLY - 30);
+            assert_eq!(NftManager::sft_balance(sft_id, buyer()), 30);
+            assert!(event_emitted(crate::Event::<TestRuntime>::SftTransferred {
+                sft_id,
+                from: creator(),
+                to: buyer(),
+                amount: 30,
+            }));
+        });
+    }
+
+    #[test]
+    fn removes_the_balance_of_an_account_that_transfers_everything() {
+        let mut ext = ExtBuilder::build_default().as_externality();
+        ext.execute_with(|| {
+            let sft_id = mint_sft();
+
+            assert_ok!(NftManager::transfer_sft(
+                Origin::signed(creator()),
+                sft_id,
+                buyer(),
+                SUPPLY
+            ));
+
+            assert!(!SftBalances::<TestRuntime>::contains_key(sft_id, creator()));
+        });
+    }
+
+    mod fails_when {
+        use super::*;
+
+        #[test]
+        fn the_balance_is_too_low() {
+            let mut ext = ExtBuilder::build_default().as_externality();
+            ext.execute_with(|| {
+                let sft_id = mint_sft();
+
+                assert_noop!(
+                    NftManager::transfer_sft(Origin::signed(buyer()), sft_id, creator(), 1),
+                    Error::<TestRuntime>::InsufficientSftBalance
+                );
+            });
+        }
+
+        #[test]
+        fn the_sft_does_not_exist() {
+            let mut ext = ExtBuilder::build_default().as_externality();
+            ext.execute_with(|| {
+                assert_noop!(
+                    NftManager::transfer_sft(
+                        Origin::signed(creator()),
+                        U256::from(1u8),
+                        buyer(),
+                        1
+                    ),
+                    Error::<TestRuntime>::SftDoesNotExist
+                );
+            });
+        }
+    }
+}
+
+mod burn_sft {
+    use super::*;
+
+    #[test]
+    fn reduces_the_balance_and_the_supply() {
+        let mut ext = ExtBuilder::build_default().as_externality();
+        ext.execute_with(|| {
+            let sft_id = mint_sft();
+
+            assert_ok!(NftManager::burn_sft(Origin::signed(creator()), sft_id, 40));
+
+            assert_eq!(NftManager::sft_balance(sft_id, creator()), SUPPLY - 40);
+            assert_eq!(NftManager::sft(sft_id).unwrap().total_supply, SUPPLY - 40);
+            assert!(event_emitted(crate::Event::<TestRuntime>::SftBurned {
+                sft_id,
+                owner: creator(),
+                amount: 40,
+            }));
+        });
+    }
+
+    #[test]
+    fn fails_when_the_balance_is_too_low() {
+        let mut ext = ExtBuilder::build_default().as_externality();
+        ext.execute_with(|| {
+            let sft_id = mint_sft();
+
+            assert_noop!(
+                NftManager::burn_sft(Origin::signed(creator()), sft_id, SUPPLY + 1),
+                Error::<TestRuntime>::InsufficientSftBalance
+            );
+        });
+    }
+}
+
+mod fiat_listing {
+    use super::*;
+
+    #[test]
+    fn takes_the_listed_amount_from_the_balance() {
+        let mut ext = ExtBuilder::build_default().as_externality();
+        ext.execute_with(|| {
+            let sft_id = mint_sft();
+
+            let listing_id = list_sft(sft_id, 60, NftSaleType::Fiat);
+
+            assert_eq!(NftManager::sft_balance(sft_id, creator()), SUPPLY - 60);
+            assert_eq!(
+                NftManager::sft_listing(listing_id),
+                Some(SftListing {
+                    sft_id,
+                    seller: creator(),
+                    amount: 60,
+                    market: NftSaleType::Fiat
+                })
+            );
+            assert!(event_emitted(crate::Event::<TestRuntime>::SftOpenForSale {
+                listing_id,
+                sft_id,
+                seller: creator(),
+                amount: 60,
+                sale_type: NftSaleType::Fiat,
+            }));
+        });
+    }
+
+    #[test]
+    fn is_sold_in_parts_by_the_seller() {
+        let mut ext = ExtBuilder::build_default().as_externality();
+        ext.execute_with(|| {
+            let sft_id = mint_sft();
+            let listing_id = list_sft(sft_id, 60, NftSaleType::Fiat);
+
+            assert_ok!(NftManager::transfer_fiat_sft(
+                Origin::signed(creator()),
+                listing_id,
+                buyer(),
+                20
+            ));
+            assert_eq!(NftManager::sft_listing(listing_id).unwrap().amount, 40);
+
+            assert_ok!(NftManager::transfer_fiat_sft(
+                Origin::signed(creator()),
+                listing_id,
+                buyer(),
+                40
+            ));
+            assert_eq!(NftManager::sft_listing(listing_id), None);
+            assert_eq!(NftManager::sft_balance(sft_id, buyer()), 60);
+            assert!(event_emitted(crate::Event::<TestRuntime>::FiatSftTransfer {
+                listing_id,
+                new_owner: buyer(),
+                amount: 40,
+            }));
+        });
+    }
+
+    #[test]
+    fn cancelling_returns_what_is_left_to_the_seller() {
+        let mut ext = ExtBuilder::build_default().as_externality();
+        ext.execute_with(|| {
+            let sft_id = mint_sft();
+            let listing_id = list_sft(sft_id, 60, NftSaleType::Fiat);
+            assert_ok!(NftManager::transfer_fiat_sft(
+                Origin::signed(creator()),
+                listing_id,
+                buyer(),
+                20
+            ));
+
+            assert_ok!(NftManager::cancel_list_fiat_sft(Origin::signed(creator()), listing_id));
+
+            assert_eq!(NftManager::sft_listing(listing_id), None);
+            assert_eq!(NftManager::sft_balance(sft_id, creator()), SUPPLY - 20);
+            assert!(event_emitted(crate::Event::<TestRuntime>::CancelSftFiatListing {
+                listing_id
+            }));
+        });
+    }
+
+    mod fails_when {
+        use super::*;
+
+        #[test]
+        fn the_market_is_not_a_listing_market() {
+            let mut ext = ExtBuilder::build_default().as_externality();
+            ext.execute_with(|| {
+                let sft_id = mint_sft();
+
+                assert_noop!(
+                    NftManager::list_sft_for_sale(
+                        Origin::signed(creator()),
+                        sft_id,
+                        10,
+                        NftSaleType::Auction
+                    ),
+                    Error::<TestRuntime>::UnsupportedMarket
+                );
+            });
+        }
+
+        #[test]
+        fn more_is_sold_than_listed() {
+            let mut ext = ExtBuilder::build_default().as_externality();
+            ext.execute_with(|| {
+                let sft_id = mint_sft();
+                let listing_id = list_sft(sft_id, 60, NftSaleType::Fiat);
+
+                assert_noop!(
+                    NftManager::transfer_fiat_sft(
+                        Origin::signed(creator()),
+                        listing_id,
+                        buyer(),
+                        61
+                    ),
+                    Error::<TestRuntime>::SftListingAmountExceeded
+                );
+            });
+        }
+
+        #[test]
+        fn the_sender_is_not_the_seller() {
+            let mut ext = ExtBuilder::build_default().as_externality();
+            ext.execute_with(|| {
+                let sft_id = mint_sft();
+                let listing_id = list_sft(sft_id, 60, NftSaleType::Fiat);
+
+                assert_noop!(
+                    NftManager::cancel_list_fiat_sft(Origin::signed(buyer()), listing_id),
+                    Error::<TestRuntime>::SenderIsNotOwner
+                );
+            });
+        }
+
+        #[test]
+        fn the_listing_is_on_the_ethereum_market() {
+            let mut ext = ExtBuilder::build_default().as_externality();
+            ext.execute_with(|| {
+                let sft_id = mint_sft();
+                let listing_id = list_sft(sft_id, 60, NftSaleType::Ethereum);
+
+                assert_noop!(
+                    NftManager::cancel_list_fiat_sft(Origin::signed(creator()), listing_id),
+                    Error::<TestRuntime>::SftNotListedForFiatSale
+                );
+            });
+        }
+    }
+}
+
+mod tier1_events {
+    use super::*;
+
+    fn sft_mint_event(sft_id: SftId, amount: u128) -> EthEvent {
+        eth_event(
+            ValidEvents::SftMint.signature(),
+            H256::repeat_byte(1),
+            EventData::LogSftMinted(SftMintData {
+                sft_id,
+                t2_owner_public_key: H256::from_slice(&buyer().encode()),
+                amount,
+            }),
+        )
+    }
+
+    fn sft_transfer_event(listing_id: SftListingId, amount: u128) -> EthEvent {
+        eth_event(
+            ValidEvents::SftTransferTo.signature(),
+            H256::repeat_byte(2),
+            EventData::LogSftTransferTo(SftTransferToData {
+                listing_id,
+                t2_transfer_to_public_key: H256::from_slice(&buyer().encode()),
+                amount,
+            }),
+        )
+    }
+
+    #[test]
+    fn mint_event_adds_to_the_supply() {
+        let mut ext = ExtBuilder::build_default().as_externality();
+        ext.execute_with(|| {
+            let sft_id = mint_sft();
+            let event = sft_mint_event(sft_id, 25);
+
+            assert_ok!(NftManager::on_event_processed(&event));
+
+            assert_eq!(NftManager::sft_balance(sft_id, buyer()), 25);
+            assert_eq!(NftManager::sft(sft_id).unwrap().total_supply, SUPPLY + 25);
+            assert!(event_emitted(crate::Event::<TestRuntime>::EthSftMinted {
+                sft_id,
+                owner: buyer(),
+                amount: 25,
+                eth_event_id: event.event_id,
+            }));
+        });
+    }
+
+    #[test]
+    fn transfer_event_sells_from_an_ethereum_listing() {
+        let mut ext = ExtBuilder::build_default().as_externality();
+        ext.execute_with(|| {
+            let sft_id = mint_sft();
+            let listing_id = list_sft(sft_id, 60, NftSaleType::Ethereum);
+            let event = sft_transfer_event(listing_id, 15);
+
+            assert_ok!(NftManager::on_event_processed(&event));
+
+            assert_eq!(NftManager::sft_balance(sft_id, buyer()), 15);
+            assert_eq!(NftManager::sft_listing(listing_id).unwrap().amount, 45);
+            assert!(event_emitted(crate::Event::<TestRuntime>::EthSftTransfer {
+                listing_id,
+                new_owner: buyer(),
+                amount: 15,
+                eth_event_id: event.event_id,
+            }));
+        });
+    }
+
+    mod fails_when {
+        use super::*;
+
+        #[test]
+        fn the_minted_sft_does_not_exist() {
+            let mut ext = ExtBuilder::build_default().as_externality();
+            ext.execute_with(|| {
+                let event = sft_mint_event(U256::from(1u8), 25);
+
+                assert_noop!(
+                    NftManager::on_event_processed(&event),
+                    Error::<TestRuntime>::SftDoesNotExist
+                );
+            });
+        }
+
+        #[test]
+        fn the_mint_event_has_not_been_processed() {
+            let mut ext = ExtBuilder::build_default().as_externality();
+            ext.execute_with(|| {
+                let sft_id = mint_sft();
+                let mut event = sft_mint_event(sft_id, 25);
+                event.event_id.transaction_hash = H256::repeat_byte(3);
+
+                assert_noop!(
+                    NftManager::on_event_processed(&event),
+                    Error::<TestRuntime>::NoTier1EventForNftOperation
+                );
+            });
+        }
+
+        #[test]
+        fn the_transferred_listing_is_on_the_fiat_market() {
+            let mut ext = ExtBuilder::build_default().as_externality();
+            ext.execute_with(|| {
+                let sft_id = mint_sft();
+                let listing_id = list_sft(sft_id, 60, NftSaleType::Fiat);
+
+                assert_noop!(
+                    NftManager::on_event_processed(&sft_transfer_event(listing_id, 15)),
+                    Error::<TestRuntime>::SftNotListedForEthereumSale
+                );
+            });
+        }
+
+        #[test]
+        fn more_is_transferred_than_listed() {
+            let mut ext = ExtBuilder::build_default().as_externality();
+            ext.execute_with(|| {
+                let sft_id = mint_sft();
+                let listing_id = list_sft(sft_id, 60, NftSaleType::Ethereum);
+
+                assert_noop!(
+                    NftManager::on_event_processed(&sft_transfer_event(listing_id, 61)),
+                    Error::<TestRuntime>::SftListingAmountExceeded
+                );
+            });
+        }
+    }
+}
@@ -0,0 +1,246 @@
+// Copyright 2024 Aventus Network Services (UK) Ltd.
+
+#![cfg(test)]
+
+use super::*;
+use crate::mock::{AccountId, RuntimeEvent as Event, RuntimeOrigin as Origin, *};
+use frame_support::{assert_noop, assert_ok, error::BadOrigin};
+use hex_literal::hex;
+
+const T1_AUTHORITY: H160 = H160(hex!("11111AAAAA22222BBBBB11111AAAAA22222BBBBB"));
+const T1_RECIPIENT: H160 = H160(hex!("afdf36201bf70F1232111b5c6a9a424558755134"));
+
+fn issuer() -> AccountId {
+    TestAccount::new([1u8; 32]).account_id()
+}
+
+fn holder() -> TestAccount {
+    TestAccount::new([2u8; 32])
+}
+
+fn relayer() -> AccountId {
+    TestAccount::new([3u8; 32]).account_id()
+}
+
+fn create_collection() -> CollectionId {
+    let collection_id = NextCollectionId::<TestRuntime>::get();
+    assert_ok!(NftManager::create_collection(
+        Origin::signed(issuer()),
+        T1_AUTHORITY,
+        vec![],
+        vec![],
+        true,
+    ));
+    collection_id
+}
+
+fn mint_soulbound_nft() -> NftId {
+    let collection_id = create_collection();
+    let unique_id = NftManager::next_unique_id();
+    let nft_id = NftManager::generate_nft_id_single_mint(&T1_AUTHORITY, unique_id);
+    assert_ok!(NftManager::mint_soulbound_nft(
+        Origin::signed(issuer()),
+        collection_id,
+        holder().account_id(),
+        format!("Ticket {}", unique_id).into_bytes(),
+    ));
+    nft_id
+}
+
+fn event_emitted(event: crate::Event<TestRuntime>) -> bool {
+    System::events().iter().any(|a| a.event == Event::NftManager(event.clone()))
+}
+
+mod mint_soulbound_nft {
+    use super::*;
+
+    #[test]
+    fn mints_the_nft_to_its_owner() {
+        let mut ext = ExtBuilder::build_default().as_externality();
+        ext.execute_with(|| {
+            let nft_id = mint_soulbound_nft();
+
+            assert_eq!(NftManager::nfts(nft_id).unwrap().owner, holder().account_id());
+            assert!(SoulboundNfts::<TestRuntime>::contains_key(nft_id));
+        });
+    }
+
+    mod and_the_nft_cannot_be {
+        use super::*;
+
+        #[test]
+        fn listed() {
+            let mut ext = ExtBuilder::build_default().as_externality();
+            ext.execute_with(|| {
+                let nft_id = mint_soulbound_nft();
+
+                assert_noop!(
+                    NftManager::list_nft_open_for_sale(
+                        Origin::signed(holder().account_id()),
+                        nft_id,
+                        NftSaleType::Fiat
+                    ),
+                    Error::<TestRuntime>::NftIsSoulbound
+                );
+            });
+        }
+
+        #[test]
+        fn listed_through_a_relayer() {
+            let mut ext = ExtBuilder::build_default().as_externality();
+            ext.execute_with(|| {
+                let nft_id = mint_soulbound_nft();
+                let nonce = NftManager::nfts(nft_id).unwrap().nonce;
+                let relayer = relayer();
+                let data_to_sign = (
+                    SIGNED_LIST_NFT_OPEN_FOR_SALE_CONTEXT,
+                    &relayer,
+                    nft_id,
+                    NftSaleType::Fiat,
+                    nonce,
+                );
+                let proof = Proof {
+                    signer: holder().account_id(),
+                    relayer,
+                    signature: sign(&holder().key_pair(), &data_to_sign.encode()),
+                };
+
+                assert_noop!(
+                    NftManager::signed_list_nft_open_for_sale(
+                        Origin::signed(holder().account_id()),
+                        proof,
+                        nft_id,
+                        NftSaleType::Fiat
+                    ),
+                    Error::<TestRuntime>::NftIsSoulbound
+                );
+            });
+        }
+
+        #[test]
+        fn auctioned() {
+            let mut ext = ExtBuilder::build_default().as_externality();
+            ext.execute_with(|| {
+                let nft_id = mint_soulbound_nft();
+
+                assert_noop!(
+                    NftManager::create_auction(
+                        Origin::signed(holder().account_id()),
+                        nft_id,
+                        100,
+                        10
+                    ),
+                    Error::<TestRuntime>::NftIsSoulbound
+                );
+            });
+        }
+
+        #[test]
+        fn exported() {
+            let mut ext = ExtBuilder::build_default().as_externality();
+            ext.execute_with(|| {
+                let nft_id = mint_soulbound_nft();
+
+                assert_noop!(
+                    NftManager::export_nft(
+                        Origin::signed(holder().account_id()),
+                        nft_id,
+                        T1_RECIPIENT
+                    ),
+                    Error::<TestRuntime>::NftIsSoulbound
+                );
+            });
+        }
+    }
+}
+
+mod burn_nft {
+    use super::*;
+
+    #[test]
+    fn the_owner_can_burn_a_soulbound_nft() {
+        let mut ext = ExtBuilder::build_default().as_externality();
+        ext.execute_with(|| {
+            let nft_id = mint_soulbound_nft();
+            let external_ref = NftManager::nfts(nft_id).unwrap().unique_external_ref;
+
+            assert_ok!(NftManager::burn_nft(Origin::signed(holder().account_id()), nft_id));
+
+            assert_eq!(NftManager::nfts(nft_id), None);
+            assert!(!SoulboundNfts::<TestRuntime>::contains_key(nft_id));
+            assert!(NftManager::is_external_ref_used(external_ref));
+            assert!(event_emitted(crate::Event::<TestRuntime>::NftBurned {
+                nft_id,
+                owner: holder().account_id(),
+            }));
+        });
+    }
+
+    #[test]
+    fn root_can_burn_any_nft() {
+        let mut ext = ExtBuilder::build_default().as_externality();
+        ext.execute_with(|| {
+            let nft_id = mint_soulbound_nft();
+
+            assert_ok!(NftManager::burn_nft(Origin::root(), nft_id));
+
+            assert_eq!(NftManager::nfts(nft_id), None);
+        });
+    }
+
+    mod fails_when {
+        use super::*;
+
+        #[test]
+        fn the_sender_is_not_the_owner() {
+            let mut ext = ExtBuilder::build_default().as_externality();
+            ext.execute_with(|| {
+                let nft_id = mint_soulbound_nft();
+
+                // Not even the issuer of a soulbound nft can burn it
+                assert_noop!(
+                    NftManager::burn_nft(Origin::signed(issuer()), nft_id),
+                    Error::<TestRuntime>::SenderIsNotOwner
+                );
+            });
+        }
+
+        #[test]
+        fn the_nft_is_listed() {
+            let mut ext = ExtBuilder::build_default().as_externality();
+            ext.execute_with(|| {
+                let collection_id = create_collection();
+                let nft_id = NftManager::generate_nft_id_single_mint(
+                    &T1_AUTHORITY,
+                    NftManager::next_unique_id(),
+                );
+                assert_ok!(NftManager::mint_collection_nft(
+                    Origin::signed(issuer()),
+                    collection_id,
+                    holder().account_id(),
+                    b"Ticket".to_vec(),
+                ));
+                assert_ok!(NftManager::list_nft_open_for_sale(
+                    Origin::signed(holder().account_id()),
+                    nft_id,
+                    NftSaleType::Fiat
+                ));
+
+                assert_noop!(
+                    NftManager::burn_nft(Origin::signed(holder().account_id()), nft_id),
+                    Error::<TestRuntime>::NftAlreadyListed
+                );
+            });
+        }
+
+        #[test]
+        fn the_origin_is_unsigned() {
+            let mut ext = ExtBuilder::build_default().as_externality();
+            ext.execute_with(|| {
+                let nft_id = mint_soulbound_nft();
+
+                assert_noop!(NftManager::burn_nft(Origin::none(), nft_id), BadOrigin);
+            });
+        }
+    }
+}
@@ -0,0 +1,54 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+use codec::Codec;
+use pallet_parachain_staking::{
+    CandidateNominatorCounts, CandidateOverview, CollatorPayoutStatement, EraIndex,
+    GrowthPeriodIndex, GrowthStatus, RewardPotReconciliation, RewardsHistoryEntry, StakingOverview,
+};
+use sp_std::vec::Vec;
+
+sp_api::decl_runtime_apis! {
+
+    #[api_version(5)]
+    pub trait StakingInfoApi<AccountId, Balance>
+            where
+        AccountId: Codec,
+        Balance: Codec,
+    {
+        fn overview() -> StakingOverview<Balance>;
+
+        /// All the collator candidates with the identity of their operator
+        #[api_version(2)]
+        fn candidates() -> Vec<CandidateOverview<AccountId, Balance>>;
+
+        /// The difference between the payouts locked for past eras and the reward pot balance
+        #[api_version(3)]
+        fn reward_pot_reconciliation() -> RewardPotReconciliation<Balance>;
+
+        /// The number of nominators of a candidate and the cap it has set, if it is a candidate
+        #[api_version(4)]
+        fn candidate_nominator_counts(candidate: AccountId) -> Option<CandidateNominatorCounts>;
+
+        /// The staking rewards paid to an account for the eras from `from_era` to `to_era`
+        #[api_version(5)]
+        fn rewards_history(
+            account: AccountId,
+            from_era: EraIndex,
+            to_era: EraIndex,
+        ) -> Vec<RewardsHistoryEntry<AccountId, Balance>>;
+    }
+
+    #[api_version(2)]
+    pub trait StakingGrowthApi<Balance>
+            where
+        Balance: Codec,
+    {
+        /// The progress of growth `period`: its accumulation, its trigger on Ethereum and its
+        /// payout. None if the period has not started.
+        fn growth_status(period: GrowthPeriodIndex) -> Option<GrowthStatus<Balance>>;
+
+        /// How the growth of `period` was paid to the collators, if it was paid in the last
+        /// `PAYOUT_STATEMENTS_DEPTH` periods
+        #[api_version(2)]
+        fn payout_statement(period: GrowthPeriodIndex) -> Option<CollatorPayoutStatement<Balance>>;
+    }
+}
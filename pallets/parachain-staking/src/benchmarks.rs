@@ -19,14 +19,17 @@
 //! Benchmarking
 use super::*;
 use crate::{
-    encode_signed_bond_extra_params, encode_signed_candidate_bond_extra_params,
-    encode_signed_execute_candidate_unbond_params, encode_signed_execute_leave_nominators_params,
+    encode_signed_bond_extra_params, encode_signed_cancel_leave_candidates_params,
+    encode_signed_candidate_bond_extra_params, encode_signed_execute_candidate_unbond_params,
+    encode_signed_execute_leave_candidates_params, encode_signed_execute_leave_nominators_params,
     encode_signed_execute_nomination_request_params, encode_signed_nominate_params,
-    encode_signed_schedule_candidate_unbond_params, encode_signed_schedule_leave_nominators_params,
-    encode_signed_schedule_nominator_unbond_params,
+    encode_signed_replace_nomination_request_params,
+    encode_signed_schedule_candidate_unbond_params, encode_signed_schedule_leave_candidates_params,
+    encode_signed_schedule_leave_nominators_params, encode_signed_schedule_nominator_unbond_params,
     encode_signed_schedule_revoke_nomination_params, AdminSettings, AwardedPts, BalanceOf, Call,
     CandidateBondLessRequest, Config, Delay, Era, MinCollatorStake, MinTotalNominatorStake,
-    NominationAction, Pallet, Points, Proof, ScheduledRequest,
+    NominationAction, NominationScheduledRequests, Pallet, PendingAdminChanges, Points, PoolId,
+    Proof, ScheduledRequest, TotalSelected,
 };
 use codec::{Decode, Encode};
 use frame_benchmarking::{account, benchmarks, impl_benchmark_test_suite};
@@ -123,6 +126,41 @@ fn create_funded_collator<T: Config>(
     Ok(user)
 }
 
+/// Moves all the top nominations of `candidate` to its bottom nominations
+fn demote_top_nominations<T: Config>(candidate: &T::AccountId) {
+    let top_nominations = <TopNominations<T>>::get(candidate).expect("candidate exists");
+    let mut state = <CandidateInfo<T>>::get(candidate).expect("candidate exists");
+    state.reset_bottom_data::<T>(&top_nominations);
+    state.reset_top_data::<T>(candidate.clone(), &Default::default());
+
+    <TopNominations<T>>::insert(candidate, Nominations::default());
+    <BottomNominations<T>>::insert(candidate, top_nominations);
+    <CandidateInfo<T>>::insert(candidate, state);
+}
+
+/// Creates a pool nominating a new collator, with a depositor that has bonded `depositor_bonds`
+/// times the minimum nominator stake
+fn create_nomination_pool<T: Config>(
+    depositor_bonds: u32,
+) -> Result<(PoolId, T::AccountId), &'static str> {
+    let collator = create_funded_collator::<T>(
+        "collator",
+        USER_SEED,
+        0u32.into(),
+        true,
+        get_collator_count::<T>(),
+    )?;
+    let amount = min_nominator_stk::<T>() * depositor_bonds.into();
+    let (depositor, _) = create_funded_user::<T>("depositor", USER_SEED, amount);
+    let pool_id = Pallet::<T>::next_pool_id();
+    Pallet::<T>::create_nomination_pool(
+        RawOrigin::Signed(depositor.clone()).into(),
+        collator,
+        amount,
+    )?;
+    Ok((pool_id, depositor))
+}
+
 fn set_account_as_collator<T: Config>(
     account: &T::AccountId,
     additional_bond: BalanceOf<T>,
@@ -467,6 +505,28 @@ benchmarks! {
         assert!(Pallet::<T>::candidate_info(&caller).unwrap().is_leaving());
     }
 
+    signed_schedule_leave_candidates {
+        let x in 3..get_allowed_max_collators::<T>(<<T as Config>::MaxCandidates as Get<u32>>::get());
+        let mut candidate_count = get_collator_count::<T>();
+        for i in 2..x {
+            let seed = USER_SEED - i;
+            let collator = create_funded_collator::<T>(
+                "collator",
+                seed,
+                0u32.into(),
+                true,
+                candidate_count
+            )?;
+            candidate_count += 1u32;
+        }
+        let (caller, proof) = get_caller::<T, _>(|relayer, nonce| encode_signed_schedule_leave_candidates_params::<T>(relayer, nonce, 0))?;
+        fund_account::<T>(&caller, min_candidate_stk::<T>() * 2u32.into());
+        set_account_as_collator::<T>(&caller, 0u32.into(), candidate_count)?;
+    }: _(RawOrigin::Signed(caller.clone()), proof)
+    verify {
+        assert!(Pallet::<T>::candidate_info(&caller).unwrap().is_leaving());
+    }
+
     execute_leave_candidates {
         // x is total number of nominations for the candidate
         let x in 2..(<<T as Config>::MaxTopNominationsPerCandidate as Get<u32>>::get()
@@ -542,6 +602,76 @@ benchmarks! {
         }
     }
 
+    signed_execute_leave_candidates {
+        // x is total number of nominations for the candidate
+        let x in 2..(<<T as Config>::MaxTopNominationsPerCandidate as Get<u32>>::get()
+            + <<T as Config>::MaxBottomNominationsPerCandidate as Get<u32>>::get());
+
+        let mut candidate_count = get_collator_count::<T>();
+        for c in 1..=<<T as Config>::MinSelectedCandidates as Get<u32>>::get() {
+            let candidate: T::AccountId = create_funded_collator::<T>(
+                "setup_candidate",
+                USER_SEED - c,
+                0u32.into(),
+                true,
+                candidate_count,
+            )?;
+            candidate_count += 1u32;
+        }
+
+        let candidate: T::AccountId = create_funded_collator::<T>(
+            "unique_caller",
+            USER_SEED - 100,
+            0u32.into(),
+            true,
+            candidate_count,
+        )?;
+        let second_candidate: T::AccountId = create_funded_collator::<T>(
+            "unique__caller",
+            USER_SEED - 99,
+            0u32.into(),
+            true,
+            candidate_count + 1u32,
+        )?;
+        let mut nominators: Vec<T::AccountId> = Vec::new();
+        let mut col_del_count = 0u32;
+        for i in 1..x {
+            let seed = USER_SEED + i;
+            let nominator = create_funded_nominator::<T>(
+                "nominator",
+                seed,
+                min_nominator_stk::<T>(),
+                candidate.clone(),
+                true,
+                col_del_count,
+            )?;
+            Pallet::<T>::nominate(
+                RawOrigin::Signed(nominator.clone()).into(),
+                second_candidate.clone(),
+                min_nominator_stk::<T>(),
+                col_del_count,
+                1u32,
+            )?;
+            nominators.push(nominator);
+            col_del_count += 1u32;
+        }
+
+        Pallet::<T>::schedule_leave_candidates(
+            RawOrigin::Signed(candidate.clone()).into(), candidate_count + 2u32
+        )?;
+
+        roll_to_and_author::<T>(2, candidate.clone());
+
+        let (caller, proof) = get_caller::<T, _>(|relayer, nonce| encode_signed_execute_leave_candidates_params::<T>(relayer, &candidate, nonce, 0))?;
+    }: _(RawOrigin::Signed(caller.clone()), proof, candidate.clone())
+    verify {
+        assert!(Pallet::<T>::candidate_info(&candidate).is_none());
+        assert!(Pallet::<T>::candidate_info(&second_candidate).is_some());
+        for nominator in nominators {
+            assert!(Pallet::<T>::is_nominator(&nominator));
+        }
+    }
+
     cancel_leave_candidates {
         let x in 3..get_allowed_max_collators::<T>(<<T as Config>::MaxCandidates as Get<u32>>::get());
         // Worst Case Complexity is removal from an ordered list so \exists full list before call
@@ -575,6 +705,33 @@ benchmarks! {
         assert!(Pallet::<T>::candidate_info(&caller).unwrap().is_active());
     }
 
+    signed_cancel_leave_candidates {
+        let x in 3..get_allowed_max_collators::<T>(<<T as Config>::MaxCandidates as Get<u32>>::get());
+        let mut candidate_count = get_collator_count::<T>();
+        for i in 2..x {
+            let seed = USER_SEED - i;
+            let collator = create_funded_collator::<T>(
+                "collator",
+                seed,
+                0u32.into(),
+                true,
+                candidate_count
+            )?;
+            candidate_count += 1u32;
+        }
+        let (caller, proof) = get_caller::<T, _>(|relayer, nonce| encode_signed_cancel_leave_candidates_params::<T>(relayer, nonce, 0))?;
+        fund_account::<T>(&caller, min_candidate_stk::<T>() * 2u32.into());
+        set_account_as_collator::<T>(&caller, 0u32.into(), candidate_count)?;
+        candidate_count += 1u32;
+        Pallet::<T>::schedule_leave_candidates(
+            RawOrigin::Signed(caller.clone()).into(),
+            candidate_count
+        )?;
+    }: _(RawOrigin::Signed(caller.clone()), proof)
+    verify {
+        assert!(Pallet::<T>::candidate_info(&caller).unwrap().is_active());
+    }
+
     go_offline {
         let caller: T::AccountId = create_funded_collator::<T>(
             "collator",
@@ -622,7 +779,7 @@ benchmarks! {
 
     signed_candidate_bond_extra {
         let more = min_candidate_stk::<T>();
-        let (caller, proof) = get_caller::<T, _>(|relayer, nonce| encode_signed_candidate_bond_extra_params::<T>(relayer, &more, nonce))?;
+        let (caller, proof) = get_caller::<T, _>(|relayer, nonce| encode_signed_candidate_bond_extra_params::<T>(relayer, &more, nonce, 0))?;
         fund_account::<T>(&caller, more * 2u32.into());
         set_account_as_collator::<T>(&caller, BalanceOf::<T>::zero(), get_collator_count::<T>())?;
     }: _(RawOrigin::Signed(caller.clone()), proof, more)
@@ -657,7 +814,7 @@ benchmarks! {
 
     signed_schedule_candidate_unbond {
         let min_candidate_stk = min_candidate_stk::<T>();
-        let (caller, proof) = get_caller::<T, _>(|relayer, nonce| encode_signed_schedule_candidate_unbond_params::<T>(relayer, &min_candidate_stk, nonce))?;
+        let (caller, proof) = get_caller::<T, _>(|relayer, nonce| encode_signed_schedule_candidate_unbond_params::<T>(relayer, &min_candidate_stk, nonce, 0))?;
         fund_account::<T>(&caller, min_candidate_stk * 2u32.into());
         set_account_as_collator::<T>(&caller, min_candidate_stk, get_collator_count::<T>())?;
     }: _(RawOrigin::Signed(caller.clone()), proof, min_candidate_stk)
@@ -700,7 +857,7 @@ benchmarks! {
 
     signed_execute_candidate_unbond {
         let min_candidate_stk = min_candidate_stk::<T>();
-        let (caller, proof) = get_caller::<T, _>(|relayer, nonce| encode_signed_execute_candidate_unbond_params::<T>(relayer.clone(), &relayer, nonce))?;
+        let (caller, proof) = get_caller::<T, _>(|relayer, nonce| encode_signed_execute_candidate_unbond_params::<T>(relayer.clone(), &relayer, nonce, 0))?;
         fund_account::<T>(&caller, min_candidate_stk * 2u32.into());
         set_account_as_collator::<T>(&caller, min_candidate_stk, get_collator_count::<T>())?;
 
@@ -771,7 +928,7 @@ benchmarks! {
         let mut targets: Vec<<T::Lookup as StaticLookup>::Source> = collators.into_iter().map(|c| T::Lookup::unlookup(c)).collect::<_>();
         targets.push(T::Lookup::unlookup(collator));
 
-        let (caller, proof) = get_caller::<T, _>(|relayer, nonce| encode_signed_nominate_params::<T>(relayer, &targets, &bond, nonce))?;
+        let (caller, proof) = get_caller::<T, _>(|relayer, nonce| encode_signed_nominate_params::<T>(relayer, &targets, &bond, nonce, 0))?;
         fund_account::<T>(&caller, bond * 2u32.into());
     }: _(RawOrigin::Signed(caller.clone()), proof, targets, bond)
     verify {
@@ -814,7 +971,7 @@ benchmarks! {
         )?;
 
         let bond = <MinTotalNominatorStake<T>>::get();
-        let (caller, proof) = get_caller::<T, _>(|relayer, nonce| encode_signed_schedule_leave_nominators_params::<T>(relayer, nonce))?;
+        let (caller, proof) = get_caller::<T, _>(|relayer, nonce| encode_signed_schedule_leave_nominators_params::<T>(relayer, nonce, 0))?;
         fund_account::<T>(&caller, bond * 2u32.into());
 
         Pallet::<T>::nominate(RawOrigin::Signed(
@@ -847,7 +1004,7 @@ benchmarks! {
 
     signed_execute_leave_nominators {
         let x in 2..<<T as Config>::MaxNominationsPerNominator as Get<u32>>::get();
-        let (caller, proof) = get_caller::<T, _>(|relayer, nonce| encode_signed_execute_leave_nominators_params::<T>(relayer.clone(), &relayer, nonce))?;
+        let (caller, proof) = get_caller::<T, _>(|relayer, nonce| encode_signed_execute_leave_nominators_params::<T>(relayer.clone(), &relayer, nonce, 0))?;
         fund_account::<T>(&caller, min_nominator_stk::<T>());
 
         setup_leave_nominator_state::<T>(x, &caller)?;
@@ -921,7 +1078,7 @@ benchmarks! {
             get_collator_count::<T>()
         )?;
 
-        let (caller, proof) = get_caller::<T, _>(|relayer, nonce| encode_signed_schedule_revoke_nomination_params::<T>(relayer.clone(), &collator, nonce))?;
+        let (caller, proof) = get_caller::<T, _>(|relayer, nonce| encode_signed_schedule_revoke_nomination_params::<T>(relayer.clone(), &collator, nonce, 0))?;
         fund_account::<T>(&caller, min_nominator_stk::<T>());
 
         let bond = <MinTotalNominatorStake<T>>::get();
@@ -980,7 +1137,7 @@ benchmarks! {
         )?;
 
         let bond = <MinTotalNominatorStake<T>>::get() * 10u32.into();
-        let (caller, proof) = get_caller::<T, _>(|relayer, nonce| encode_signed_bond_extra_params::<T>(relayer, &bond, nonce))?;
+        let (caller, proof) = get_caller::<T, _>(|relayer, nonce| encode_signed_bond_extra_params::<T>(relayer, &bond, nonce, 0))?;
         fund_account::<T>(&caller, bond * 2u32.into());
 
         Pallet::<T>::nominate(
@@ -1044,7 +1201,7 @@ benchmarks! {
         )?;
 
         let bond_less = <MinTotalNominatorStake<T>>::get();
-        let (caller, proof) = get_caller::<T, _>(|relayer, nonce| encode_signed_schedule_nominator_unbond_params::<T>(relayer, &bond_less, nonce))?;
+        let (caller, proof) = get_caller::<T, _>(|relayer, nonce| encode_signed_schedule_nominator_unbond_params::<T>(relayer, &bond_less, nonce, 0))?;
         fund_account::<T>(&caller, bond_less * (num_collators * 3u32).into());
 
         Pallet::<T>::nominate(RawOrigin::Signed(
@@ -1151,7 +1308,7 @@ benchmarks! {
         )?;
 
         let amount = min_nominator_stk::<T>();
-        let (caller, proof) = get_caller::<T, _>(|relayer, nonce| encode_signed_execute_nomination_request_params::<T>(relayer.clone(), &relayer, nonce))?;
+        let (caller, proof) = get_caller::<T, _>(|relayer, nonce| encode_signed_execute_nomination_request_params::<T>(relayer.clone(), &relayer, nonce, 0))?;
         fund_account::<T>(&caller, amount * (num_collators * 3u32).into());
 
         Pallet::<T>::nominate(RawOrigin::Signed(
@@ -1572,6 +1729,285 @@ benchmarks! {
     verify {
         assert_eq!(new_delay_value, <Delay<T>>::get());
     }
+
+    cancel_admin_change {
+        let new = <TotalSelected<T>>::get() + 1;
+        let (schedule_name, _) =
+            Pallet::<T>::announce_admin_change(Call::<T>::set_total_selected { new })?;
+    }: _(RawOrigin::Root, schedule_name)
+    verify {
+        assert!(!<PendingAdminChanges<T>>::contains_key(schedule_name));
+    }
+
+    retry_failed_payout {
+        let payee = create_funded_user::<T>("payee", USER_SEED - 1, 0u32.into()).0;
+        let amount = min_candidate_stk::<T>();
+        fund_account::<T>(&Pallet::<T>::compute_reward_pot_account_id(), amount * 10u32.into());
+        <LockedEraPayout<T>>::put(amount);
+        <FailedPayouts<T>>::insert(&payee, FailedPayout { amount, retries: 0 });
+        let caller = create_funded_user::<T>("caller", USER_SEED, 0u32.into()).0;
+    }: _(RawOrigin::Signed(caller), payee.clone())
+    verify {
+        assert!(!<FailedPayouts<T>>::contains_key(&payee));
+        assert_eq!(<LockedEraPayout<T>>::get(), 0u32.into());
+    }
+
+    audit_growth {
+        let e in 1 .. T::ErasPerGrowthPeriod::get();
+        let c in 1 .. T::MaxCandidates::get();
+
+        let growth_period: GrowthPeriodIndex = 1;
+        let start_era: EraIndex = 1;
+        let points: RewardPoint = 10;
+        for era in start_era..start_era + e {
+            <DelayedPayouts<T>>::insert(era, DelayedPayout {
+                total_staking_reward: min_candidate_stk::<T>(),
+            });
+            <Points<T>>::insert(era, points * c);
+            for i in 0..c {
+                let collator: T::AccountId = account("collator", i, 0);
+                <AwardedPts<T>>::insert(era, collator, points);
+            }
+        }
+        <Growth<T>>::insert(growth_period, GrowthInfo::<T::AccountId, BalanceOf<T>>::new(e));
+        <GrowthStartEra<T>>::insert(growth_period, start_era);
+    }: _(RawOrigin::Root, growth_period, true)
+    verify {
+        let growth = <Growth<T>>::get(growth_period);
+        assert_eq!(growth.total_points, points * c * e);
+        assert_eq!(growth.collator_scores.len() as u32, c);
+    }
+
+    rebase_locked_era_payout {
+        let f in 0 .. 100;
+
+        let new_locked_era_payout = min_candidate_stk::<T>();
+        fund_account::<T>(&Pallet::<T>::compute_reward_pot_account_id(), new_locked_era_payout);
+        <LockedEraPayout<T>>::put(new_locked_era_payout * 3u32.into());
+        for i in 0..f {
+            let payee: T::AccountId = account("payee", i, 0);
+            <FailedPayouts<T>>::insert(&payee, FailedPayout { amount: 1u32.into(), retries: 0 });
+        }
+    }: _(RawOrigin::Root, new_locked_era_payout, f)
+    verify {
+        assert_eq!(<LockedEraPayout<T>>::get(), new_locked_era_payout);
+    }
+
+    replace_nomination_request {
+        let collator: T::AccountId = create_funded_collator::<T>(
+            "collator",
+            USER_SEED,
+            0u32.into(),
+            true,
+            get_collator_count::<T>()
+        )?;
+        let (caller, total) = create_funded_user::<T>("caller", USER_SEED, 0u32.into());
+        Pallet::<T>::nominate(RawOrigin::Signed(
+            caller.clone()).into(),
+            collator.clone(),
+            total,
+            0u32,
+            0u32
+        )?;
+        let bond_less = <MinTotalNominatorStake<T>>::get();
+        Pallet::<T>::schedule_nominator_unbond(
+            RawOrigin::Signed(caller.clone()).into(),
+            collator.clone(),
+            bond_less
+        )?;
+    }: _(RawOrigin::Signed(caller.clone()), collator.clone(), NominationAction::Revoke(Zero::zero()))
+    verify {
+        assert_eq!(
+            Pallet::<T>::nomination_scheduled_requests(&collator),
+            vec![ScheduledRequest {
+                nominator: caller,
+                when_executable: 3,
+                action: NominationAction::Revoke(total),
+            }],
+        );
+    }
+
+    signed_replace_nomination_request {
+        let collator: T::AccountId = create_funded_collator::<T>(
+            "collator",
+            USER_SEED,
+            0u32.into(),
+            true,
+            get_collator_count::<T>()
+        )?;
+        let action = NominationAction::Revoke(Zero::zero());
+        let (caller, proof) = get_caller::<T, _>(|relayer, nonce| encode_signed_replace_nomination_request_params::<T>(relayer, &collator, &action, nonce, 0))?;
+        let bond = <MinTotalNominatorStake<T>>::get() * 2u32.into();
+        fund_account::<T>(&caller, bond);
+        Pallet::<T>::nominate(RawOrigin::Signed(
+            caller.clone()).into(),
+            collator.clone(),
+            bond,
+            0u32,
+            0u32
+        )?;
+        Pallet::<T>::schedule_nominator_unbond(
+            RawOrigin::Signed(caller.clone()).into(),
+            collator.clone(),
+            <MinTotalNominatorStake<T>>::get()
+        )?;
+    }: _(RawOrigin::Signed(caller.clone()), proof, collator.clone(), action)
+    verify {
+        assert_eq!(
+            Pallet::<T>::nomination_scheduled_requests(&collator),
+            vec![ScheduledRequest {
+                nominator: caller,
+                when_executable: 3,
+                action: NominationAction::Revoke(bond),
+            }],
+        );
+    }
+
+    set_candidate_nominator_cap {
+        let candidate: T::AccountId = create_funded_collator::<T>(
+            "collator",
+            USER_SEED,
+            0u32.into(),
+            true,
+            get_collator_count::<T>()
+        )?;
+        let cap = T::MaxTopNominationsPerCandidate::get();
+    }: _(RawOrigin::Signed(candidate.clone()), Some(cap))
+    verify {
+        assert_eq!(Pallet::<T>::candidate_nominator_cap(&candidate), Some(cap));
+    }
+
+    claim_missed_growth_payment {
+        let growth_period: GrowthPeriodIndex = 1;
+        let collator = create_funded_user::<T>("collator", USER_SEED, 0u32.into()).0;
+        <MissedGrowthPayments<T>>::insert(growth_period, &collator, min_candidate_stk::<T>());
+    }: _(RawOrigin::Signed(collator.clone()), growth_period)
+    verify {
+        assert!(!<MissedGrowthPayments<T>>::contains_key(growth_period, &collator));
+    }
+
+    create_nomination_pool {
+        let collator = create_funded_collator::<T>(
+            "collator",
+            USER_SEED,
+            0u32.into(),
+            true,
+            get_collator_count::<T>()
+        )?;
+        let amount = min_nominator_stk::<T>();
+        let (depositor, _) = create_funded_user::<T>("depositor", USER_SEED, amount);
+    }: _(RawOrigin::Signed(depositor.clone()), collator, amount)
+    verify {
+        assert_eq!(Pallet::<T>::pool_member(0, &depositor).map(|member| member.points), Some(amount));
+    }
+
+    join_nomination_pool {
+        let (pool_id, _) = create_nomination_pool::<T>(1)?;
+        let amount = min_nominator_stk::<T>();
+        let (caller, _) = create_funded_user::<T>("caller", USER_SEED, amount);
+    }: _(RawOrigin::Signed(caller.clone()), pool_id, amount)
+    verify {
+        assert_eq!(Pallet::<T>::pool_member(pool_id, &caller).map(|member| member.points), Some(amount));
+    }
+
+    pool_bond_extra {
+        let (pool_id, depositor) = create_nomination_pool::<T>(1)?;
+        let amount = min_nominator_stk::<T>();
+        T::Currency::deposit_creating(&Pallet::<T>::pool_account_id(pool_id), amount);
+    }: _(RawOrigin::Signed(depositor.clone()), pool_id, amount)
+    verify {
+        assert_eq!(
+            Pallet::<T>::pool_member(pool_id, &depositor).map(|member| member.points),
+            Some(amount * 2u32.into())
+        );
+    }
+
+    pool_unbond {
+        let (pool_id, depositor) = create_nomination_pool::<T>(2)?;
+        let amount = min_nominator_stk::<T>();
+    }: _(RawOrigin::Signed(depositor.clone()), pool_id, amount)
+    verify {
+        assert!(Pallet::<T>::pool_member(pool_id, &depositor).and_then(|member| member.unbonding).is_some());
+    }
+
+    pool_withdraw_unbonded {
+        let (pool_id, depositor) = create_nomination_pool::<T>(2)?;
+        let amount = min_nominator_stk::<T>();
+        Pallet::<T>::pool_unbond(RawOrigin::Signed(depositor.clone()).into(), pool_id, amount)?;
+        <Era<T>>::mutate(|era| era.current = era.current.saturating_add(<Delay<T>>::get()));
+    }: _(RawOrigin::Signed(depositor.clone()), pool_id)
+    verify {
+        assert!(Pallet::<T>::pool_member(pool_id, &depositor).and_then(|member| member.unbonding).is_none());
+    }
+
+    claim_pool_rewards {
+        let (pool_id, depositor) = create_nomination_pool::<T>(1)?;
+        T::Currency::deposit_creating(&Pallet::<T>::pool_account_id(pool_id), min_nominator_stk::<T>());
+    }: _(RawOrigin::Signed(depositor.clone()), pool_id)
+    verify {
+        assert!(Pallet::<T>::pending_pool_rewards(pool_id, &depositor).is_zero());
+    }
+
+    rebalance_nominations_for {
+        let x in 1..<<T as Config>::MaxBottomNominationsPerCandidate as Get<u32>>::get()
+            .min(<<T as Config>::MaxTopNominationsPerCandidate as Get<u32>>::get());
+
+        let collator = create_funded_collator::<T>(
+            "collator",
+            USER_SEED,
+            0u32.into(),
+            true,
+            get_collator_count::<T>()
+        )?;
+        for i in 0..x {
+            create_funded_nominator::<T>("nominator", i, 0u32.into(), collator.clone(), true, i)?;
+        }
+        // Leave room in the top nominations, as if MaxTopNominationsPerCandidate had been raised
+        demote_top_nominations::<T>(&collator);
+        let (caller, _) = create_funded_user::<T>("caller", USER_SEED, 0u32.into());
+    }: _(RawOrigin::Signed(caller), collator.clone())
+    verify {
+        let top_nominations = Pallet::<T>::top_nominations(&collator).expect("collator exists");
+        assert_eq!(top_nominations.nominations.len() as u32, x);
+    }
+
+    cleanup_nomination_requests {
+        let x in 1..<<T as Config>::MaxNominationsPerNominator as Get<u32>>::get()
+            .min(<<T as Config>::MaxTopNominationsPerCandidate as Get<u32>>::get()
+                + <<T as Config>::MaxBottomNominationsPerCandidate as Get<u32>>::get());
+
+        let collator = create_funded_collator::<T>(
+            "collator",
+            USER_SEED,
+            0u32.into(),
+            true,
+            get_collator_count::<T>()
+        )?;
+        let other_collator = create_funded_collator::<T>(
+            "other_collator",
+            USER_SEED - 1,
+            0u32.into(),
+            true,
+            get_collator_count::<T>()
+        )?;
+        // Worst case: every request is from a nominator that does not nominate the candidate
+        let mut requests = Vec::new();
+        for i in 0..x {
+            let nominator =
+                create_funded_nominator::<T>("nominator", i, 0u32.into(), collator.clone(), true, i)?;
+            requests.push(ScheduledRequest {
+                nominator,
+                when_executable: 1,
+                action: NominationAction::Revoke(min_nominator_stk::<T>()),
+            });
+        }
+        <NominationScheduledRequests<T>>::insert(&other_collator, BoundedVec::truncate_from(requests));
+    }: {
+        Pallet::<T>::remove_orphaned_nomination_requests(&other_collator);
+    }
+    verify {
+        assert!(!<NominationScheduledRequests<T>>::contains_key(&other_collator));
+    }
 }
 
 #[cfg(test)]
@@ -14,11 +14,13 @@ impl<T: Config> Pallet<T> {
             Error::<T>::InsufficientBalance
         );
 
+        let mut actual_nomination_count = 0u32;
         let mut nominator_state = if let Some(mut state) = <NominatorState<T>>::get(nominator) {
             // The min amount for subsequent nominations on additional collators.
             ensure!(amount >= T::MinNominationPerCollator::get(), Error::<T>::NominationBelowMin);
+            actual_nomination_count = state.nominations.0.len() as u32;
             ensure!(
-                nomination_count >= state.nominations.0.len() as u32,
+                nomination_count >= actual_nomination_count,
                 Error::<T>::TooLowNominationCountToNominate
             );
             ensure!(
@@ -41,10 +43,17 @@ impl<T: Config> Pallet<T> {
         };
 
         let mut state = <CandidateInfo<T>>::get(&candidate).ok_or(Error::<T>::CandidateDNE)?;
+        let actual_candidate_nomination_count = state.nomination_count;
         ensure!(
-            candidate_nomination_count >= state.nomination_count,
+            candidate_nomination_count >= actual_candidate_nomination_count,
             Error::<T>::TooLowCandidateNominationCountToNominate
         );
+        if let Some(cap) = <CandidateNominatorCap<T>>::get(&candidate) {
+            ensure!(
+                actual_candidate_nomination_count < cap,
+                Error::<T>::CandidateNominatorCapReached
+            );
+        }
 
         let (nominator_position, less_total_staked) =
             state.add_nomination::<T>(&candidate, Bond { owner: nominator.clone(), amount })?;
@@ -68,7 +77,11 @@ impl<T: Config> Pallet<T> {
             nominator_position,
         });
 
-        Ok(().into())
+        Ok(Some(<T as Config>::WeightInfo::nominate(
+            actual_candidate_nomination_count,
+            actual_nomination_count,
+        ))
+        .into())
     }
 
     pub fn call_bond_extra(
@@ -128,4 +141,129 @@ impl<T: Config> Pallet<T> {
 
         Ok(().into())
     }
+
+    /// Removes `collator` from the candidate pool and schedules its exit. Returns the number of
+    /// candidates in the pool before the removal.
+    pub fn call_schedule_leave_candidates(
+        collator: &T::AccountId,
+        candidate_count: u32,
+    ) -> Result<u32, DispatchError> {
+        let mut state = <CandidateInfo<T>>::get(collator).ok_or(Error::<T>::CandidateDNE)?;
+        let (now, when) = state.schedule_leave::<T>()?;
+        let mut candidates = <CandidatePool<T>>::get();
+        let actual_candidate_count = candidates.0.len() as u32;
+        ensure!(
+            candidate_count >= actual_candidate_count,
+            Error::<T>::TooLowCandidateCountToLeaveCandidates
+        );
+        if candidates.remove(&Bond::from_owner(collator.clone())) {
+            <CandidatePool<T>>::put(candidates);
+        }
+        <CandidateInfo<T>>::insert(collator, state);
+        Self::deposit_event(Event::CandidateScheduledExit {
+            exit_allowed_era: now,
+            candidate: collator.clone(),
+            scheduled_exit: when,
+        });
+        Ok(actual_candidate_count)
+    }
+
+    /// Returns the stake of `candidate` and of its nominators once its exit is due. Returns the
+    /// number of nominations of the candidate.
+    pub fn call_execute_leave_candidates(
+        candidate: &T::AccountId,
+        candidate_nomination_count: u32,
+    ) -> Result<u32, DispatchError> {
+        let state = <CandidateInfo<T>>::get(candidate).ok_or(Error::<T>::CandidateDNE)?;
+        ensure!(
+            state.nomination_count <= candidate_nomination_count,
+            Error::<T>::TooLowCandidateNominationCountToLeaveCandidates
+        );
+        state.can_leave::<T>()?;
+        let return_stake = |bond: Bond<T::AccountId, BalanceOf<T>>| -> DispatchResult {
+            // remove nomination from nominator state
+            let mut nominator = NominatorState::<T>::get(&bond.owner).expect(
+                "Collator state and nominator state are consistent.
+						Collator state has a record of this nomination. Therefore,
+						Nominator state also has a record. qed.",
+            );
+
+            if let Some(remaining) = nominator.rm_nomination::<T>(candidate) {
+                Self::nomination_remove_request_with_state(candidate, &bond.owner, &mut nominator);
+
+                if remaining.is_zero() {
+                    // we do not remove the scheduled nomination requests from other collators
+                    // since it is assumed that they were removed incrementally before only the
+                    // last nomination was left.
+                    <NominatorState<T>>::remove(&bond.owner);
+                    T::Currency::remove_lock(NOMINATOR_LOCK_ID, &bond.owner);
+                } else {
+                    <NominatorState<T>>::insert(&bond.owner, nominator);
+                }
+            } else {
+                // TODO: review. we assume here that this nominator has no remaining staked
+                // balance, so we ensure the lock is cleared
+                T::Currency::remove_lock(NOMINATOR_LOCK_ID, &bond.owner);
+            }
+            Ok(())
+        };
+        // total backing stake is at least the candidate self bond
+        let mut total_backing = state.bond;
+        // return all top nominations
+        let top_nominations =
+            <TopNominations<T>>::take(candidate).expect("CandidateInfo existence checked");
+        for bond in top_nominations.nominations {
+            return_stake(bond)?;
+        }
+        total_backing = total_backing.saturating_add(top_nominations.total);
+        // return all bottom nominations
+        let bottom_nominations =
+            <BottomNominations<T>>::take(candidate).expect("CandidateInfo existence checked");
+        for bond in bottom_nominations.nominations {
+            return_stake(bond)?;
+        }
+        total_backing = total_backing.saturating_add(bottom_nominations.total);
+        // return stake to collator
+        T::Currency::remove_lock(COLLATOR_LOCK_ID, candidate);
+        <CandidateInfo<T>>::remove(candidate);
+        <NominationScheduledRequests<T>>::remove(candidate);
+        <TopNominations<T>>::remove(candidate);
+        <BottomNominations<T>>::remove(candidate);
+        <CandidateNominatorCap<T>>::remove(candidate);
+        let new_total_staked = <Total<T>>::get().saturating_sub(total_backing);
+        <Total<T>>::put(new_total_staked);
+        Self::deposit_event(Event::CandidateLeft {
+            ex_candidate: candidate.clone(),
+            unlocked_amount: total_backing,
+            new_total_amt_locked: new_total_staked,
+        });
+        Ok(state.nomination_count)
+    }
+
+    /// Cancels the scheduled exit of `collator` and puts it back in the candidate pool. Returns
+    /// the number of candidates in the pool before the insertion.
+    pub fn call_cancel_leave_candidates(
+        collator: &T::AccountId,
+        candidate_count: u32,
+    ) -> Result<u32, DispatchError> {
+        let mut state = <CandidateInfo<T>>::get(collator).ok_or(Error::<T>::CandidateDNE)?;
+        ensure!(state.is_leaving(), Error::<T>::CandidateNotLeaving);
+        state.go_online();
+        let mut candidates = <CandidatePool<T>>::get();
+        let actual_candidate_count = candidates.0.len() as u32;
+        ensure!(
+            actual_candidate_count <= candidate_count,
+            Error::<T>::TooLowCandidateCountWeightHintCancelLeaveCandidates
+        );
+
+        match candidates.try_insert(Bond { owner: collator.clone(), amount: state.total_counted }) {
+            Err(_) => Err(Error::<T>::CandidateLimitReached)?,
+            Ok(false) => Err(Error::<T>::AlreadyActive)?,
+            Ok(true) => {},
+        };
+        <CandidatePool<T>>::put(candidates);
+        <CandidateInfo<T>>::insert(collator, state);
+        Self::deposit_event(Event::CancelledCandidateExit { candidate: collator.clone() });
+        Ok(actual_candidate_count)
+    }
 }
@@ -50,10 +50,16 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
 pub mod calls;
+pub mod migration;
+mod nomination_pools;
+mod nomination_rebalance;
 mod nomination_requests;
+mod nomination_requests_cleanup;
 pub mod proxy_methods;
 pub mod session_handler;
 mod set;
+#[cfg(any(test, feature = "try-runtime"))]
+mod try_state;
 pub mod types;
 pub mod weights;
 
@@ -63,12 +69,18 @@ mod benchmarks;
 #[path = "tests/bond_extra_tests.rs"]
 mod bond_extra_tests;
 #[cfg(test)]
+#[path = "tests/leave_candidates_tests.rs"]
+mod leave_candidates_tests;
+#[cfg(test)]
 #[path = "tests/mock.rs"]
 mod mock;
 #[cfg(test)]
 #[path = "tests/nominate_tests.rs"]
 mod nominate_tests;
 #[cfg(test)]
+#[path = "tests/replace_nomination_request_tests.rs"]
+mod replace_nomination_request_tests;
+#[cfg(test)]
 #[path = "tests/schedule_revoke_nomination_tests.rs"]
 mod schedule_revoke_nomination_tests;
 #[cfg(test)]
@@ -81,28 +93,86 @@ mod test_admin_settings;
 #[path = "tests/test_bounded_ordered_set.rs"]
 mod test_bounded_ordered_set;
 #[cfg(test)]
+#[path = "tests/test_delayed_admin_changes.rs"]
+mod test_delayed_admin_changes;
+#[cfg(test)]
 #[path = "tests/test_growth.rs"]
 mod test_growth;
 #[cfg(test)]
+#[path = "tests/test_growth_status.rs"]
+mod test_growth_status;
+#[cfg(test)]
+#[path = "tests/test_multisig_admin.rs"]
+mod test_multisig_admin;
+#[cfg(test)]
+#[path = "tests/test_nomination_pools.rs"]
+mod test_nomination_pools;
+#[cfg(test)]
+#[path = "tests/test_nomination_rebalance.rs"]
+mod test_nomination_rebalance;
+#[cfg(test)]
+#[path = "tests/test_nomination_requests_cleanup.rs"]
+mod test_nomination_requests_cleanup;
+#[cfg(test)]
+#[path = "tests/test_nominator_cap.rs"]
+mod test_nominator_cap;
+#[cfg(test)]
+#[path = "tests/test_operator_identity.rs"]
+mod test_operator_identity;
+#[cfg(test)]
+#[path = "tests/test_proxy_nonce_epoch.rs"]
+mod test_proxy_nonce_epoch;
+#[cfg(test)]
 #[path = "tests/test_reward_payout.rs"]
 mod test_reward_payout;
 #[cfg(test)]
+#[path = "tests/test_reward_pot_alerts.rs"]
+mod test_reward_pot_alerts;
+#[cfg(test)]
+#[path = "tests/test_reward_pot_reconciliation.rs"]
+mod test_reward_pot_reconciliation;
+#[cfg(test)]
+#[path = "tests/test_rewards_history.rs"]
+mod test_rewards_history;
+#[cfg(test)]
+#[path = "tests/test_session_keys.rs"]
+mod test_session_keys;
+#[cfg(test)]
+#[path = "tests/test_split_amount.rs"]
+mod test_split_amount;
+#[cfg(test)]
+#[path = "tests/test_staking_overview.rs"]
+mod test_staking_overview;
+#[cfg(test)]
 #[path = "tests/test_staking_pot.rs"]
 mod test_staking_pot;
 #[cfg(test)]
+#[path = "tests/test_try_state.rs"]
+mod test_try_state;
+#[cfg(test)]
+#[path = "tests/test_voting_rewards.rs"]
+mod test_voting_rewards;
+#[cfg(test)]
+#[path = "tests/test_weight_refunds.rs"]
+mod test_weight_refunds;
+#[cfg(test)]
 #[path = "tests/tests.rs"]
 mod tests;
 
 use frame_support::pallet;
 pub use weights::WeightInfo;
 
+pub use nomination_pools::{NominationPool, PoolId, PoolMember, PoolUnbonding};
 pub use nomination_requests::{CancelledScheduledRequest, NominationAction, ScheduledRequest};
 pub use pallet::*;
 pub use types::*;
 
 pub type AVN<T> = pallet_avn::Pallet<T>;
 pub const PALLET_ID: &'static [u8; 17] = b"parachain_staking";
-pub const MAX_OFFENDERS: u32 = 2;
+/// Maximum number of expired rewards history records removed in a block
+pub const MAX_REWARDS_HISTORY_REMOVALS: u32 = 100;
+/// Number of growth periods whose collator payout statement is kept
+pub const PAYOUT_STATEMENTS_DEPTH: u32 = 24;
 #[pallet]
 pub mod pallet {
     #[cfg(not(feature = "std"))]
@@ -113,18 +183,25 @@ pub mod pallet {
     use crate::set::BoundedOrderedSet;
     pub use crate::{
         calls::*,
+        nomination_pools::{NominationPool, PoolId, PoolMember, PoolUnbonding},
         nomination_requests::{CancelledScheduledRequest, NominationAction, ScheduledRequest},
         proxy_methods::*,
         set::OrderedSet,
         types::*,
-        WeightInfo, AVN, MAX_OFFENDERS, PALLET_ID,
+        WeightInfo, AVN, PALLET_ID,
     };
     pub use frame_support::{
         dispatch::{GetDispatchInfo, PostDispatchInfo},
         pallet_prelude::*,
         traits::{
-            tokens::WithdrawReasons, Currency, ExistenceRequirement, Get, Imbalance, IsSubType,
-            LockIdentifier, LockableCurrency, ReservableCurrency, ValidatorRegistration,
+            schedule::{
+                v3::{Named as ScheduleNamed, TaskName},
+                DispatchTime, HARD_DEADLINE,
+            },
+            tokens::WithdrawReasons,
+            Currency, ExistenceRequirement, Get, Imbalance, IsSubType, LockIdentifier,
+            LockableCurrency, OnKilledAccount, OnUnbalanced, QueryPreimage, ReservableCurrency,
+            StorePreimage, ValidatorRegistration,
         },
         transactional, PalletId,
     };
@@ -134,8 +211,8 @@ pub mod pallet {
     };
     pub use pallet_avn::{
         self as avn, AccountToBytesConverter, BridgeInterface, BridgeInterfaceNotification,
-        CollatorPayoutDustHandler, Error as avn_error, OnGrowthLiftedHandler,
-        ProcessedEventsChecker,
+        CollatorPayoutDustHandler, DisabledOffenderCheck, Error as avn_error, OffenderChiller,
+        OnGrowthLiftedHandler, ProcessedEventsChecker,
     };
 
     pub use sp_avn_common::{
@@ -144,13 +221,13 @@ pub mod pallet {
     };
     pub use sp_runtime::{
         traits::{
-            AccountIdConversion, Bounded, CheckedAdd, CheckedDiv, CheckedSub, Dispatchable,
-            IdentifyAccount, Member, Saturating, StaticLookup, Verify, Zero,
+            AccountIdConversion, Bounded, CheckedAdd, CheckedDiv, CheckedMul, CheckedSub,
+            Dispatchable, IdentifyAccount, Member, Saturating, StaticLookup, Verify, Zero,
         },
-        Perbill,
+        Perbill, SaturatedConversion,
     };
     pub use sp_std::{collections::btree_map::BTreeMap, prelude::*};
-    pub const STORAGE_VERSION: StorageVersion = StorageVersion::new(3);
+    pub const STORAGE_VERSION: StorageVersion = StorageVersion::new(5);
 
     /// Pallet for parachain staking
     #[pallet::pallet]
@@ -166,12 +243,22 @@ pub mod pallet {
     pub type PositiveImbalanceOf<T> = <<T as Config>::Currency as Currency<
         <T as frame_system::Config>::AccountId,
     >>::PositiveImbalance;
+    pub type NegativeImbalanceOf<T> = <<T as Config>::Currency as Currency<
+        <T as frame_system::Config>::AccountId,
+    >>::NegativeImbalance;
 
     pub const COLLATOR_LOCK_ID: LockIdentifier = *b"stkngcol";
     pub const NOMINATOR_LOCK_ID: LockIdentifier = *b"stkngnom";
 
     const MAX_GROWTHS_TO_PROCESS: usize = 10;
 
+    /// Number of times a failed reward transfer can be retried before it is given up
+    pub const MAX_PAYOUT_RETRIES: u32 = 5;
+
+    /// Maximum number of eras whose payout is prepared, and pending, at the same time. Eras left
+    /// behind by a reduction of `RewardPaymentDelay` are caught up this many at a time.
+    pub const MAX_PAYOUT_ERAS_PER_ERA: EraIndex = 4;
+
     pub type CollatorMaxScores = ConstU32<10000>;
 
     /// Configuration trait of this pallet.
@@ -188,6 +275,7 @@ pub mod pallet {
             + Dispatchable<RuntimeOrigin = Self::RuntimeOrigin, PostInfo = PostDispatchInfo>
             + GetDispatchInfo
             + From<frame_system::Call<Self>>
+            + From<Call<Self>>
             + IsSubType<Call<Self>>;
         /// Overarching event type
         type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
@@ -201,6 +289,9 @@ pub mod pallet {
         /// Number of eras after which block authors are rewarded
         #[pallet::constant]
         type RewardPaymentDelay: Get<EraIndex>;
+        /// Number of paid eras for which the rewards of each account are kept
+        #[pallet::constant]
+        type HistoryDepth: Get<EraIndex>;
         /// Minimum number of selected candidates every era
         #[pallet::constant]
         type MinSelectedCandidates: Get<u32>;
@@ -240,6 +331,9 @@ pub mod pallet {
         type CollatorSessionRegistration: ValidatorRegistration<Self::AccountId>;
         /// A handler to notify the runtime of any remaining amount after paying collators
         type CollatorPayoutDustHandler: CollatorPayoutDustHandler<BalanceOf<Self>>;
+        /// Tells if a candidate is disabled because of an offence, so it can't go back online
+        /// until it is enabled again
+        type DisabledOffenderCheck: DisabledOffenderCheck<Self::AccountId>;
         /// Weight information for extrinsics in this pallet.
         type WeightInfo: WeightInfo;
         /// Maximum candidates
@@ -252,6 +346,30 @@ pub mod pallet {
 
         #[pallet::constant]
         type GrowthEnabled: Get<bool>;
+
+        /// The origin allowed to change the staking parameters
+        type AdminOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+        /// Provides the identity of the collator operators
+        type IdentityProvider: OperatorIdentityProvider<Self::AccountId>;
+        /// Funds the reward pot when it is low, if enabled by the admin origin
+        type RewardPotTopUp: RewardPotTopUp<Self::AccountId, BalanceOf<Self>>;
+        /// Maximum candidates whose nomination requests are checked for orphaned requests in a
+        /// block
+        #[pallet::constant]
+        type MaxNominationRequestCleanupsPerBlock: Get<u32>;
+        /// The scheduler executing the changes announced by the admin origin
+        type Scheduler: ScheduleNamed<
+            BlockNumberFor<Self>,
+            <Self as Config>::RuntimeCall,
+            Self::PalletsOrigin,
+        >;
+        /// The preimage provider used to store the announced changes
+        type Preimages: QueryPreimage + StorePreimage;
+        /// Overarching type of all pallets origins
+        type PalletsOrigin: From<frame_system::RawOrigin<Self::AccountId>>;
+        /// Number of blocks between the announcement of an admin change and its execution
+        #[pallet::constant]
+        type AdminChangeDelay: Get<BlockNumberFor<Self>>;
     }
 
     #[pallet::error]
@@ -318,6 +436,30 @@ pub mod pallet {
         ErrorConvertingBalance,
         Overflow,
         ErrorPublishingGrowth,
+        AdminChangeNotFound,
+        NoNominationTargets,
+        NoFailedPayout,
+        GrowthErasUnknown,
+        GrowthAlreadyPublished,
+        LockedEraPayoutExceedsRewardPot,
+        UnauthorizedSignedReplaceNominationRequestTransaction,
+        CandidateNominatorCapReached,
+        InvalidNominatorCap,
+        NoMissedGrowthPayment,
+        NominationPoolDNE,
+        NominationPoolClosed,
+        PoolMemberDNE,
+        AlreadyPoolMember,
+        PoolUnbondExceedsBond,
+        NoPoolUnbonding,
+        UnauthorizedSignedScheduleLeaveCandidatesTransaction,
+        UnauthorizedSignedExecuteLeaveCandidatesTransaction,
+        UnauthorizedSignedCancelLeaveCandidatesTransaction,
+        /// The collator has as many scheduled nomination requests as it can hold
+        TooManyScheduledRequests,
+        /// The candidate is disabled because of an offence
+        CandidateDisabled,
+        TooLowFailedPayoutCountWeightHintRebaseLockedEraPayout,
     }
 
     #[pallet::event]
@@ -471,6 +613,128 @@ pub mod pallet {
         AdminSettingsUpdated { value: AdminSettings<BalanceOf<T>> },
         /// Starting a new growth trigger for the specified period.
         TriggeringGrowth { growth_period: u32 },
+        /// A change of the number of selected candidates has been announced. It executes at
+        /// `execute_at` unless it is cancelled before.
+        TotalSelectedChangeAnnounced {
+            new: u32,
+            execute_at: BlockNumberFor<T>,
+            schedule_name: TaskName,
+        },
+        /// An announced admin change has been cancelled
+        AdminChangeCancelled { schedule_name: TaskName },
+        /// Identity of a collator chosen for the era, emitted after `CollatorChosen` when the
+        /// operator has registered one.
+        ChosenCollatorIdentity {
+            era: EraIndex,
+            collator_account: T::AccountId,
+            identity: OperatorIdentity,
+        },
+        /// The collator scores of an era exceeded `CollatorMaxScores` and `dropped` scores were
+        /// left out of the growth calculation
+        CollatorScoresDropped { era: EraIndex, dropped: u32 },
+        /// A staking reward failed `MAX_PAYOUT_RETRIES` times and was returned to the reward pot
+        FailedPayoutAbandoned { payee: T::AccountId, rewards: BalanceOf<T> },
+        /// The growth record of a period doesn't match the staking data of its eras
+        GrowthDiscrepancyFound {
+            growth_period: GrowthPeriodIndex,
+            discrepancy: GrowthDiscrepancy<BalanceOf<T>>,
+        },
+        /// The growth record of a period has been audited
+        GrowthAudited { growth_period: GrowthPeriodIndex, discrepancies: u32, corrected: bool },
+        /// LockedEraPayout has been set to a new value to match the reward pot
+        LockedEraPayoutRebased {
+            old_locked_era_payout: BalanceOf<T>,
+            new_locked_era_payout: BalanceOf<T>,
+            reward_pot_balance: BalanceOf<T>,
+        },
+        /// Transaction fees added to the reward pot during an era
+        RewardPotFeesCollected { era: EraIndex, amount: BalanceOf<T> },
+        /// A candidate has changed the maximum number of its nominators
+        CandidateNominatorCapSet { candidate: T::AccountId, cap: Option<u32> },
+        /// A candidate no longer has session keys. It has been skipped from the selection and
+        /// taken offline until it registers keys and goes back online.
+        CandidateSkippedNoKeys { era: EraIndex, candidate: T::AccountId },
+        /// All the collators of an era have been paid. `locked_era_payout` is what remains locked
+        /// for the eras still to be paid and the failed payments.
+        EraPaidOut {
+            era: EraIndex,
+            total_staker_reward: BalanceOf<T>,
+            collators_paid: u32,
+            failed_payments: u32,
+            locked_era_payout: BalanceOf<T>,
+        },
+        /// The growth of a period has been split between its collators using `distribution`
+        GrowthDistributed {
+            period: GrowthPeriodIndex,
+            amount: BalanceOf<T>,
+            distribution: GrowthDistribution,
+        },
+        /// A collator could not be paid its growth. It can claim it with
+        /// `claim_missed_growth_payment`.
+        GrowthPaymentMissed {
+            account: T::AccountId,
+            amount: BalanceOf<T>,
+            period: GrowthPeriodIndex,
+        },
+        /// The growth of a period has been paid out to its collators
+        CollatorPayoutCompleted {
+            period: GrowthPeriodIndex,
+            statement: CollatorPayoutStatement<BalanceOf<T>>,
+        },
+        /// The reward pot only covers `eras_covered` eras of projected payouts, fewer than the
+        /// alert threshold
+        RewardPotBalanceLow {
+            reward_pot_balance: BalanceOf<T>,
+            projected_era_payout: BalanceOf<T>,
+            eras_covered: EraIndex,
+        },
+        /// Funds have been added to the low reward pot by the top-up hook
+        RewardPotToppedUp { amount: BalanceOf<T> },
+        /// A collator that is no longer a candidate earned `points` in a growth period, and its
+        /// share has been redistributed to the remaining collators
+        ExitedCollatorGrowthRedistributed {
+            account: T::AccountId,
+            points: RewardPoint,
+            period: GrowthPeriodIndex,
+        },
+        /// A collator has claimed a growth payment it missed
+        MissedGrowthPaymentClaimed {
+            account: T::AccountId,
+            amount: BalanceOf<T>,
+            period: GrowthPeriodIndex,
+        },
+        /// A nomination pool has been created. `pool_account` nominates `collator` with the stake
+        /// of the members.
+        NominationPoolCreated {
+            pool_id: PoolId,
+            depositor: T::AccountId,
+            collator: T::AccountId,
+            pool_account: T::AccountId,
+            amount: BalanceOf<T>,
+        },
+        /// A member has bonded stake to a nomination pool
+        PoolMemberBonded { pool_id: PoolId, member: T::AccountId, amount: BalanceOf<T> },
+        /// A member has started unbonding stake from a nomination pool
+        PoolMemberUnbonded {
+            pool_id: PoolId,
+            member: T::AccountId,
+            amount: BalanceOf<T>,
+            withdrawable_era: EraIndex,
+        },
+        /// A member has withdrawn its unbonded stake from a nomination pool
+        PoolMemberWithdrawn { pool_id: PoolId, member: T::AccountId, amount: BalanceOf<T> },
+        /// A member has been paid its share of the rewards of a nomination pool
+        PoolRewardsClaimed { pool_id: PoolId, member: T::AccountId, amount: BalanceOf<T> },
+        /// A bottom nomination has been moved to the top nominations of the candidate
+        NominationPromoted {
+            nominator: T::AccountId,
+            candidate: T::AccountId,
+            amount: BalanceOf<T>,
+        },
+        /// A validator has been rewarded for the `votes` it cast in `era`
+        VotingRewarded { era: EraIndex, voter: T::AccountId, votes: u32, amount: BalanceOf<T> },
+        /// Nomination requests towards `candidate` that could no longer be executed were removed
+        OrphanedNominationRequestsRemoved { candidate: T::AccountId, removed: u32 },
     }
 
     #[pallet::hooks]
@@ -485,6 +749,7 @@ pub mod pallet {
             }
 
             weight = weight.saturating_add(Self::handle_delayed_payouts(era.current));
+            weight = weight.saturating_add(Self::clear_expired_admin_changes(n));
 
             // add on_finalize weight
             weight = weight.saturating_add(
@@ -492,7 +757,42 @@ pub mod pallet {
                 // write Points, AwardedPts
                 T::DbWeight::get().reads(3).saturating_add(T::DbWeight::get().writes(2)),
             );
-            weight
+
+            weight.saturating_add(pallet_avn::migration::migrate::<
+                T,
+                crate::migration::SteppedMigrations<T>,
+            >())
+        }
+
+        fn on_idle(_n: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+            let consumed = Self::rebalance_nominations_on_idle(remaining_weight);
+            consumed.saturating_add(Self::cleanup_nomination_requests_on_idle(
+                remaining_weight.saturating_sub(consumed),
+            ))
+        }
+
+        fn on_runtime_upgrade() -> Weight {
+            pallet_avn::migration::migrate::<T, crate::migration::SteppedMigrations<T>>()
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::TryRuntimeError> {
+            Self::do_try_state()?;
+            Ok(Vec::new())
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(_state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+            ensure!(
+                Pallet::<T>::on_chain_storage_version() == STORAGE_VERSION,
+                "Staking storage version does not match the pallet after the upgrade"
+            );
+            Self::do_try_state()
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn try_state(_n: BlockNumberFor<T>) -> Result<(), sp_runtime::TryRuntimeError> {
+            Self::do_try_state()
         }
     }
 
@@ -506,6 +806,16 @@ pub mod pallet {
     /// The total candidates selected every era
     pub type TotalSelected<T: Config> = StorageValue<_, u32, ValueQuery>;
 
+    #[pallet::storage]
+    /// The `TotalSelected` the current `SelectedCandidates` were chosen with. `TotalSelected` can
+    /// change mid-era but only applies from the next selection.
+    pub type TotalSelectedAtSelection<T: Config> = StorageValue<_, u32, OptionQuery>;
+
+    #[pallet::storage]
+    /// The admin changes waiting to be executed by the scheduler, with the block they execute at
+    pub type PendingAdminChanges<T: Config> =
+        StorageMap<_, Blake2_128Concat, TaskName, BlockNumberFor<T>, OptionQuery>;
+
     #[pallet::storage]
     #[pallet::getter(fn era)]
     /// Current era index and next era scheduled transition
@@ -561,6 +871,13 @@ pub mod pallet {
         OptionQuery,
     >;
 
+    #[pallet::storage]
+    #[pallet::getter(fn candidate_nominator_cap)]
+    /// Maximum number of nominators a candidate accepts. New nominations are rejected once it is
+    /// reached, instead of replacing the lowest bottom nomination.
+    pub type CandidateNominatorCap<T: Config> =
+        StorageMap<_, Twox64Concat, T::AccountId, u32, OptionQuery>;
+
     #[pallet::storage]
     #[pallet::getter(fn selected_candidates)]
     /// The collator candidates selected for the current era
@@ -600,6 +917,12 @@ pub mod pallet {
     pub type DelayedPayouts<T: Config> =
         StorageMap<_, Twox64Concat, EraIndex, DelayedPayout<BalanceOf<T>>, OptionQuery>;
 
+    #[pallet::storage]
+    #[pallet::getter(fn last_prepared_payout_era)]
+    /// The last era whose payout has been prepared. Eras are prepared in order, so a change of
+    /// `RewardPaymentDelay` never skips an era or prepares one twice.
+    pub type LastPreparedPayoutEra<T: Config> = StorageValue<_, EraIndex, OptionQuery>;
+
     #[pallet::storage]
     #[pallet::getter(fn staked)]
     /// Total counted stake for selected candidates in the era
@@ -644,11 +967,46 @@ pub mod pallet {
         ValueQuery,
     >;
 
+    #[pallet::storage]
+    #[pallet::getter(fn growth_start_era)]
+    /// The first era accumulated in each growth period that has not been paid yet
+    pub type GrowthStartEra<T: Config> =
+        StorageMap<_, Twox64Concat, GrowthPeriodIndex, EraIndex, OptionQuery>;
+
     #[pallet::storage]
     #[pallet::getter(fn processed_growth_periods)]
     pub type ProcessedGrowthPeriods<T: Config> =
         StorageMap<_, Twox64Concat, GrowthPeriodIndex, (), ValueQuery>;
 
+    /// Growth payments that could not be deposited to their collator when the period was paid out
+    #[pallet::storage]
+    #[pallet::getter(fn missed_growth_payment)]
+    pub type MissedGrowthPayments<T: Config> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        GrowthPeriodIndex,
+        Twox64Concat,
+        T::AccountId,
+        BalanceOf<T>,
+        OptionQuery,
+    >;
+
+    /// Summary of the collator payout of the last `PAYOUT_STATEMENTS_DEPTH` paid growth periods
+    #[pallet::storage]
+    #[pallet::getter(fn payout_statement)]
+    pub type CollatorPayoutStatements<T: Config> = StorageMap<
+        _,
+        Twox64Concat,
+        GrowthPeriodIndex,
+        CollatorPayoutStatement<BalanceOf<T>>,
+        OptionQuery,
+    >;
+
+    /// How the growth lifted for a period is split between its collators
+    #[pallet::storage]
+    #[pallet::getter(fn growth_distribution)]
+    pub type GrowthDistributionPolicy<T: Config> = StorageValue<_, GrowthDistribution, ValueQuery>;
+
     #[pallet::storage]
     #[pallet::getter(fn new_era_forced)]
     pub type ForceNewEra<T: Config> = StorageValue<_, bool, ValueQuery>;
@@ -669,6 +1027,13 @@ pub mod pallet {
     /// An account nonce that represents the number of proxy transactions from this account
     pub type ProxyNonces<T: Config> = StorageMap<_, Twox64Concat, T::AccountId, u64, ValueQuery>;
 
+    #[pallet::storage]
+    #[pallet::getter(fn proxy_nonce_epoch)]
+    /// The number of times the proxy nonce of an account has been reset. It is part of the signed
+    /// payload of proxy transactions, so proofs signed before a reset can't be replayed.
+    pub type ProxyNonceEpochs<T: Config> =
+        StorageMap<_, Twox64Concat, T::AccountId, u32, ValueQuery>;
+
     #[pallet::storage]
     #[pallet::getter(fn get_pending_growths)]
     pub type PendingApproval<T: Config> =
@@ -685,6 +1050,133 @@ pub mod pallet {
     pub type PublishedGrowth<T: Config> =
         StorageMap<_, Twox64Concat, EthereumTransactionId, GrowthPeriodIndex, ValueQuery>;
 
+    #[pallet::storage]
+    #[pallet::getter(fn failed_payout)]
+    /// Staking rewards that could not be transferred. They remain part of `LockedEraPayout` until
+    /// they are paid or abandoned.
+    pub type FailedPayouts<T: Config> =
+        StorageMap<_, Twox64Concat, T::AccountId, FailedPayout<BalanceOf<T>>, OptionQuery>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn era_payout_counts)]
+    /// Number of collators paid and of failed payments for an era whose payout is in progress
+    pub type EraPayoutCounts<T: Config> =
+        StorageMap<_, Twox64Concat, EraIndex, PayoutCounts, ValueQuery>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn era_rewards)]
+    /// Rewards paid to each account for an era, with the collator they were earned from. Only the
+    /// last `HistoryDepth` paid eras are kept.
+    pub type RewardsHistory<T: Config> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        EraIndex,
+        Twox64Concat,
+        T::AccountId,
+        BoundedVec<EraReward<T::AccountId, BalanceOf<T>>, T::MaxNominationsPerNominator>,
+        ValueQuery,
+    >;
+
+    #[pallet::storage]
+    #[pallet::getter(fn era_fees_to_reward_pot)]
+    /// Transaction fees added to the reward pot in each era, kept until the era is paid out
+    pub type EraFeesToRewardPot<T: Config> =
+        StorageMap<_, Twox64Concat, EraIndex, BalanceOf<T>, ValueQuery>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn voting_reward_per_era)]
+    /// Amount shared each era between the validators that voted on roots
+    pub type VotingRewardPerEra<T: Config> = StorageValue<_, BalanceOf<T>, ValueQuery>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn reward_pot_alert_threshold)]
+    /// Number of eras of projected payouts below which the reward pot is reported as low
+    pub type RewardPotAlertThreshold<T: Config> = StorageValue<_, EraIndex, ValueQuery>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn reward_pot_top_up_enabled)]
+    /// Whether a low reward pot is topped up
+    pub type RewardPotTopUpEnabled<T: Config> = StorageValue<_, bool, ValueQuery>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn exited_collator_growth)]
+    /// How the growth earned by collators that are no longer candidates is settled
+    pub type ExitedCollatorGrowth<T: Config> =
+        StorageValue<_, ExitedCollatorGrowthPolicy, ValueQuery>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn projected_era_payout)]
+    /// The latest non zero era payout, used to project the payouts of the next eras
+    pub type ProjectedEraPayout<T: Config> = StorageValue<_, BalanceOf<T>, ValueQuery>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn voting_participation)]
+    /// Votes cast by each validator in an era, removed once the era's voting reward is paid
+    pub type VotingParticipation<T: Config> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        EraIndex,
+        Blake2_128Concat,
+        T::AccountId,
+        u32,
+        ValueQuery,
+    >;
+
+    #[pallet::storage]
+    #[pallet::getter(fn dropped_collator_scores)]
+    /// Total number of collator scores that didn't fit in `CollatorMaxScores`
+    pub type DroppedCollatorScores<T: Config> = StorageValue<_, u64, ValueQuery>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn next_pool_id)]
+    /// Id of the next nomination pool to be created
+    pub type NextPoolId<T: Config> = StorageValue<_, PoolId, ValueQuery>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn nomination_pool)]
+    pub type NominationPools<T: Config> = StorageMap<
+        _,
+        Twox64Concat,
+        PoolId,
+        NominationPool<T::AccountId, BalanceOf<T>>,
+        OptionQuery,
+    >;
+
+    #[pallet::storage]
+    #[pallet::getter(fn pool_member)]
+    pub type PoolMembers<T: Config> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        PoolId,
+        Twox64Concat,
+        T::AccountId,
+        PoolMember<BalanceOf<T>>,
+        OptionQuery,
+    >;
+
+    #[pallet::storage]
+    #[pallet::getter(fn nominations_rebalanced_for)]
+    /// The `MaxTopNominationsPerCandidate` the nominations of all the candidates were last
+    /// rebalanced for
+    pub type NominationsRebalancedFor<T: Config> = StorageValue<_, u32, ValueQuery>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn pending_nomination_rebalances)]
+    /// The candidates left to rebalance in the current `on_idle` sweep
+    pub type PendingNominationRebalances<T: Config> =
+        StorageValue<_, BoundedVec<T::AccountId, T::MaxCandidates>, ValueQuery>;
+
+    #[pallet::storage]
+    /// The raw storage key of the last candidate checked by the `on_idle` sweep of
+    /// `NominationScheduledRequests` in progress
+    pub type NominationRequestsSweepCursor<T: Config> =
+        StorageValue<_, BoundedVec<u8, ConstU32<128>>, OptionQuery>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn nomination_requests_swept_era)]
+    /// The era of the last completed sweep of `NominationScheduledRequests`
+    pub type NominationRequestsSweptEra<T: Config> = StorageValue<_, EraIndex, ValueQuery>;
+
     #[pallet::genesis_config]
     pub struct GenesisConfig<T: Config> {
         pub candidates: Vec<(T::AccountId, BalanceOf<T>)>,
@@ -810,9 +1302,11 @@ pub mod pallet {
         #[pallet::weight(<T as Config>::WeightInfo::set_total_selected())]
         /// Set the total number of collator candidates selected per era
         /// - changes are not applied until the start of the next era
+        /// - changes requested by the admin origin are announced and executed by root after
+        ///   `AdminChangeDelay` blocks
         #[pallet::call_index(0)]
         pub fn set_total_selected(origin: OriginFor<T>, new: u32) -> DispatchResultWithPostInfo {
-            frame_system::ensure_root(origin)?;
+            let execute_now = Self::ensure_admin_change_origin(origin)?;
             ensure!(new >= T::MinSelectedCandidates::get(), Error::<T>::CannotSetBelowMin);
             let old = <TotalSelected<T>>::get();
             ensure!(old != new, Error::<T>::NoWritingSameValue);
@@ -820,6 +1314,19 @@ pub mod pallet {
                 new <= <Era<T>>::get().length,
                 Error::<T>::EraLengthMustBeAtLeastTotalSelectedCollators,
             );
+
+            let call = Call::<T>::set_total_selected { new };
+            if !execute_now {
+                let (schedule_name, execute_at) = Self::announce_admin_change(call)?;
+                Self::deposit_event(Event::TotalSelectedChangeAnnounced {
+                    new,
+                    execute_at,
+                    schedule_name,
+                });
+                return Ok(().into())
+            }
+
+            Self::clear_pending_admin_changes(&call);
             <TotalSelected<T>>::put(new);
             Self::deposit_event(Event::TotalSelectedSet { old, new });
             Ok(().into())
@@ -831,7 +1338,7 @@ pub mod pallet {
         /// in the next block
         #[pallet::call_index(1)]
         pub fn set_blocks_per_era(origin: OriginFor<T>, new: u32) -> DispatchResultWithPostInfo {
-            frame_system::ensure_root(origin)?;
+            T::AdminOrigin::ensure_origin(origin)?;
             ensure!(new >= T::MinBlocksPerEra::get(), Error::<T>::CannotSetBelowMin);
             let mut era = <Era<T>>::get();
             let (now, first, old) = (era.current, era.first, era.length);
@@ -901,7 +1408,7 @@ pub mod pallet {
                 amount_locked: bond,
                 new_total_amt_locked: new_total,
             });
-            Ok(().into())
+            Ok(Some(<T as Config>::WeightInfo::join_candidates(old_count)).into())
         }
 
         #[pallet::weight(<T as Config>::WeightInfo::schedule_leave_candidates(*candidate_count))]
@@ -913,23 +1420,44 @@ pub mod pallet {
             candidate_count: u32,
         ) -> DispatchResultWithPostInfo {
             let collator = ensure_signed(origin)?;
-            let mut state = <CandidateInfo<T>>::get(&collator).ok_or(Error::<T>::CandidateDNE)?;
-            let (now, when) = state.schedule_leave::<T>()?;
-            let mut candidates = <CandidatePool<T>>::get();
+            let actual_candidate_count =
+                Self::call_schedule_leave_candidates(&collator, candidate_count)?;
+            Ok(Some(<T as Config>::WeightInfo::schedule_leave_candidates(actual_candidate_count))
+                .into())
+        }
+
+        #[pallet::weight(<T as Config>::WeightInfo::signed_schedule_leave_candidates(
+            T::MaxCandidates::get()
+        ))]
+        #[transactional]
+        #[pallet::call_index(48)]
+        pub fn signed_schedule_leave_candidates(
+            origin: OriginFor<T>,
+            proof: Proof<T::Signature, T::AccountId>,
+        ) -> DispatchResultWithPostInfo {
+            let collator = ensure_signed(origin)?;
+
+            ensure!(collator == proof.signer, Error::<T>::SenderIsNotSigner);
+
+            let collator_nonce = Self::proxy_nonce(&collator);
+            let signed_payload = encode_signed_schedule_leave_candidates_params::<T>(
+                proof.relayer.clone(),
+                collator_nonce,
+                Self::proxy_nonce_epoch(&collator),
+            );
+
             ensure!(
-                candidate_count >= candidates.0.len() as u32,
-                Error::<T>::TooLowCandidateCountToLeaveCandidates
+                verify_signed_payload::<T>(&proof, &signed_payload).is_ok(),
+                Error::<T>::UnauthorizedSignedScheduleLeaveCandidatesTransaction
             );
-            if candidates.remove(&Bond::from_owner(collator.clone())) {
-                <CandidatePool<T>>::put(candidates);
-            }
-            <CandidateInfo<T>>::insert(&collator, state);
-            Self::deposit_event(Event::CandidateScheduledExit {
-                exit_allowed_era: now,
-                candidate: collator,
-                scheduled_exit: when,
-            });
-            Ok(().into())
+
+            let candidate_count = <CandidatePool<T>>::get().0.len() as u32;
+            Self::call_schedule_leave_candidates(&collator, candidate_count)?;
+
+            <ProxyNonces<T>>::mutate(&collator, |n| *n += 1);
+
+            Ok(Some(<T as Config>::WeightInfo::signed_schedule_leave_candidates(candidate_count))
+                .into())
         }
 
         #[pallet::weight(
@@ -943,73 +1471,48 @@ pub mod pallet {
             candidate_nomination_count: u32,
         ) -> DispatchResultWithPostInfo {
             ensure_signed(origin)?;
-            let state = <CandidateInfo<T>>::get(&candidate).ok_or(Error::<T>::CandidateDNE)?;
+            let nomination_count =
+                Self::call_execute_leave_candidates(&candidate, candidate_nomination_count)?;
+            Ok(Some(<T as Config>::WeightInfo::execute_leave_candidates(nomination_count)).into())
+        }
+
+        /// Execute the leave candidates request of `candidate`.
+        /// Any account can call this extrinsic
+        #[pallet::weight(<T as Config>::WeightInfo::signed_execute_leave_candidates(
+            T::MaxTopNominationsPerCandidate::get() + T::MaxBottomNominationsPerCandidate::get()
+        ))]
+        #[transactional]
+        #[pallet::call_index(49)]
+        pub fn signed_execute_leave_candidates(
+            origin: OriginFor<T>,
+            proof: Proof<T::Signature, T::AccountId>,
+            candidate: T::AccountId,
+        ) -> DispatchResultWithPostInfo {
+            let sender = ensure_signed(origin)?;
+
+            ensure!(sender == proof.signer, Error::<T>::SenderIsNotSigner);
+
+            let sender_nonce = Self::proxy_nonce(&sender);
+            let signed_payload = encode_signed_execute_leave_candidates_params::<T>(
+                proof.relayer.clone(),
+                &candidate,
+                sender_nonce,
+                Self::proxy_nonce_epoch(&sender),
+            );
+
             ensure!(
-                state.nomination_count <= candidate_nomination_count,
-                Error::<T>::TooLowCandidateNominationCountToLeaveCandidates
+                verify_signed_payload::<T>(&proof, &signed_payload).is_ok(),
+                Error::<T>::UnauthorizedSignedExecuteLeaveCandidatesTransaction
             );
-            state.can_leave::<T>()?;
-            let return_stake = |bond: Bond<T::AccountId, BalanceOf<T>>| -> DispatchResult {
-                // remove nomination from nominator state
-                let mut nominator = NominatorState::<T>::get(&bond.owner).expect(
-                    "Collator state and nominator state are consistent.
-						Collator state has a record of this nomination. Therefore,
-						Nominator state also has a record. qed.",
-                );
 
-                if let Some(remaining) = nominator.rm_nomination::<T>(&candidate) {
-                    Self::nomination_remove_request_with_state(
-                        &candidate,
-                        &bond.owner,
-                        &mut nominator,
-                    );
+            let state = <CandidateInfo<T>>::get(&candidate).ok_or(Error::<T>::CandidateDNE)?;
+            let nomination_count =
+                Self::call_execute_leave_candidates(&candidate, state.nomination_count)?;
 
-                    if remaining.is_zero() {
-                        // we do not remove the scheduled nomination requests from other collators
-                        // since it is assumed that they were removed incrementally before only the
-                        // last nomination was left.
-                        <NominatorState<T>>::remove(&bond.owner);
-                        T::Currency::remove_lock(NOMINATOR_LOCK_ID, &bond.owner);
-                    } else {
-                        <NominatorState<T>>::insert(&bond.owner, nominator);
-                    }
-                } else {
-                    // TODO: review. we assume here that this nominator has no remaining staked
-                    // balance, so we ensure the lock is cleared
-                    T::Currency::remove_lock(NOMINATOR_LOCK_ID, &bond.owner);
-                }
-                Ok(())
-            };
-            // total backing stake is at least the candidate self bond
-            let mut total_backing = state.bond;
-            // return all top nominations
-            let top_nominations =
-                <TopNominations<T>>::take(&candidate).expect("CandidateInfo existence checked");
-            for bond in top_nominations.nominations {
-                return_stake(bond)?;
-            }
-            total_backing = total_backing.saturating_add(top_nominations.total);
-            // return all bottom nominations
-            let bottom_nominations =
-                <BottomNominations<T>>::take(&candidate).expect("CandidateInfo existence checked");
-            for bond in bottom_nominations.nominations {
-                return_stake(bond)?;
-            }
-            total_backing = total_backing.saturating_add(bottom_nominations.total);
-            // return stake to collator
-            T::Currency::remove_lock(COLLATOR_LOCK_ID, &candidate);
-            <CandidateInfo<T>>::remove(&candidate);
-            <NominationScheduledRequests<T>>::remove(&candidate);
-            <TopNominations<T>>::remove(&candidate);
-            <BottomNominations<T>>::remove(&candidate);
-            let new_total_staked = <Total<T>>::get().saturating_sub(total_backing);
-            <Total<T>>::put(new_total_staked);
-            Self::deposit_event(Event::CandidateLeft {
-                ex_candidate: candidate,
-                unlocked_amount: total_backing,
-                new_total_amt_locked: new_total_staked,
-            });
-            Ok(().into())
+            <ProxyNonces<T>>::mutate(&sender, |n| *n += 1);
+
+            Ok(Some(<T as Config>::WeightInfo::signed_execute_leave_candidates(nomination_count))
+                .into())
         }
 
         #[pallet::weight(<T as Config>::WeightInfo::cancel_leave_candidates(*candidate_count))]
@@ -1022,42 +1525,52 @@ pub mod pallet {
             candidate_count: u32,
         ) -> DispatchResultWithPostInfo {
             let collator = ensure_signed(origin)?;
-            let mut state = <CandidateInfo<T>>::get(&collator).ok_or(Error::<T>::CandidateDNE)?;
-            ensure!(state.is_leaving(), Error::<T>::CandidateNotLeaving);
-            state.go_online();
-            let mut candidates = <CandidatePool<T>>::get();
+            let actual_candidate_count =
+                Self::call_cancel_leave_candidates(&collator, candidate_count)?;
+            Ok(Some(<T as Config>::WeightInfo::cancel_leave_candidates(actual_candidate_count))
+                .into())
+        }
+
+        #[pallet::weight(<T as Config>::WeightInfo::signed_cancel_leave_candidates(
+            T::MaxCandidates::get()
+        ))]
+        #[transactional]
+        #[pallet::call_index(50)]
+        pub fn signed_cancel_leave_candidates(
+            origin: OriginFor<T>,
+            proof: Proof<T::Signature, T::AccountId>,
+        ) -> DispatchResultWithPostInfo {
+            let collator = ensure_signed(origin)?;
+
+            ensure!(collator == proof.signer, Error::<T>::SenderIsNotSigner);
+
+            let collator_nonce = Self::proxy_nonce(&collator);
+            let signed_payload = encode_signed_cancel_leave_candidates_params::<T>(
+                proof.relayer.clone(),
+                collator_nonce,
+                Self::proxy_nonce_epoch(&collator),
+            );
+
             ensure!(
-                candidates.0.len() as u32 <= candidate_count,
-                Error::<T>::TooLowCandidateCountWeightHintCancelLeaveCandidates
+                verify_signed_payload::<T>(&proof, &signed_payload).is_ok(),
+                Error::<T>::UnauthorizedSignedCancelLeaveCandidatesTransaction
             );
 
-            match candidates
-                .try_insert(Bond { owner: collator.clone(), amount: state.total_counted })
-            {
-                Err(_) => Err(Error::<T>::CandidateLimitReached)?,
-                Ok(false) => Err(Error::<T>::AlreadyActive)?,
-                Ok(true) => {},
-            };
-            <CandidatePool<T>>::put(candidates);
-            <CandidateInfo<T>>::insert(&collator, state);
-            Self::deposit_event(Event::CancelledCandidateExit { candidate: collator });
-            Ok(().into())
-        }
+            let candidate_count = <CandidatePool<T>>::get().0.len() as u32;
+            Self::call_cancel_leave_candidates(&collator, candidate_count)?;
 
-        #[pallet::weight(<T as Config>::WeightInfo::go_offline())]
+            <ProxyNonces<T>>::mutate(&collator, |n| *n += 1);
+
+            Ok(Some(<T as Config>::WeightInfo::signed_cancel_leave_candidates(candidate_count))
+                .into())
+        }
+
+        #[pallet::weight(<T as Config>::WeightInfo::go_offline())]
         /// Temporarily leave the set of collator candidates without unbonding
         #[pallet::call_index(6)]
         pub fn go_offline(origin: OriginFor<T>) -> DispatchResultWithPostInfo {
             let collator = ensure_signed(origin)?;
-            let mut state = <CandidateInfo<T>>::get(&collator).ok_or(Error::<T>::CandidateDNE)?;
-            ensure!(state.is_active(), Error::<T>::AlreadyOffline);
-            state.go_offline();
-            let mut candidates = <CandidatePool<T>>::get();
-            if candidates.remove(&Bond::from_owner(collator.clone())) {
-                <CandidatePool<T>>::put(candidates);
-            }
-            <CandidateInfo<T>>::insert(&collator, state);
-            Self::deposit_event(Event::CandidateWentOffline { candidate: collator });
+            Self::take_candidate_offline(&collator)?;
             Ok(().into())
         }
 
@@ -1066,19 +1579,11 @@ pub mod pallet {
         #[pallet::call_index(7)]
         pub fn go_online(origin: OriginFor<T>) -> DispatchResultWithPostInfo {
             let collator = ensure_signed(origin)?;
-            let mut state = <CandidateInfo<T>>::get(&collator).ok_or(Error::<T>::CandidateDNE)?;
-            ensure!(!state.is_active(), Error::<T>::AlreadyActive);
-            ensure!(!state.is_leaving(), Error::<T>::CannotGoOnlineIfLeaving);
-            state.go_online();
-            let mut candidates = <CandidatePool<T>>::get();
-            let maybe_inserted_candidate = candidates
-                .try_insert(Bond { owner: collator.clone(), amount: state.total_counted })
-                .map_err(|_| Error::<T>::CandidateLimitReached)?;
-            ensure!(maybe_inserted_candidate, Error::<T>::AlreadyActive);
-
-            <CandidatePool<T>>::put(candidates);
-            <CandidateInfo<T>>::insert(&collator, state);
-            Self::deposit_event(Event::CandidateBackOnline { candidate: collator });
+            ensure!(
+                !T::DisabledOffenderCheck::is_disabled(&collator),
+                Error::<T>::CandidateDisabled
+            );
+            Self::bring_candidate_online(&collator)?;
             Ok(().into())
         }
 
@@ -1111,10 +1616,10 @@ pub mod pallet {
                 proof.relayer.clone(),
                 &extra_amount,
                 collator_nonce,
+                Self::proxy_nonce_epoch(&collator),
             );
             ensure!(
-                verify_signature::<T::Signature, T::AccountId>(&proof, &signed_payload.as_slice())
-                    .is_ok(),
+                verify_signed_payload::<T>(&proof, &signed_payload).is_ok(),
                 Error::<T>::UnauthorizedSignedCandidateBondExtraTransaction
             );
 
@@ -1166,11 +1671,11 @@ pub mod pallet {
                 proof.relayer.clone(),
                 &candidate,
                 sender_nonce,
+                Self::proxy_nonce_epoch(&sender),
             );
 
             ensure!(
-                verify_signature::<T::Signature, T::AccountId>(&proof, &signed_payload.as_slice())
-                    .is_ok(),
+                verify_signed_payload::<T>(&proof, &signed_payload).is_ok(),
                 Error::<T>::UnauthorizedSignedExecuteCandidateUnbondTransaction
             );
 
@@ -1210,11 +1715,11 @@ pub mod pallet {
                 proof.relayer.clone(),
                 &less,
                 collator_nonce,
+                Self::proxy_nonce_epoch(&collator),
             );
 
             ensure!(
-                verify_signature::<T::Signature, T::AccountId>(&proof, &signed_payload.as_slice())
-                    .is_ok(),
+                verify_signed_payload::<T>(&proof, &signed_payload).is_ok(),
                 Error::<T>::UnauthorizedSignedCandidateUnbondTransaction
             );
 
@@ -1272,10 +1777,10 @@ pub mod pallet {
                 &targets,
                 &amount,
                 nominator_nonce,
+                Self::proxy_nonce_epoch(&nominator),
             );
             ensure!(
-                verify_signature::<T::Signature, T::AccountId>(&proof, &signed_payload.as_slice())
-                    .is_ok(),
+                verify_signed_payload::<T>(&proof, &signed_payload).is_ok(),
                 Error::<T>::UnauthorizedSignedNominateTransaction
             );
 
@@ -1311,10 +1816,10 @@ pub mod pallet {
             let signed_payload = encode_signed_schedule_leave_nominators_params::<T>(
                 proof.relayer.clone(),
                 nominator_nonce,
+                Self::proxy_nonce_epoch(&nominator),
             );
             ensure!(
-                verify_signature::<T::Signature, T::AccountId>(&proof, &signed_payload.as_slice())
-                    .is_ok(),
+                verify_signed_payload::<T>(&proof, &signed_payload).is_ok(),
                 Error::<T>::UnauthorizedSignedScheduleLeaveNominatorsTransaction
             );
 
@@ -1356,11 +1861,11 @@ pub mod pallet {
                 proof.relayer.clone(),
                 &nominator,
                 sender_nonce,
+                Self::proxy_nonce_epoch(&sender),
             );
 
             ensure!(
-                verify_signature::<T::Signature, T::AccountId>(&proof, &signed_payload.as_slice())
-                    .is_ok(),
+                verify_signed_payload::<T>(&proof, &signed_payload).is_ok(),
                 Error::<T>::UnauthorizedSignedExecuteLeaveNominatorsTransaction
             );
 
@@ -1371,7 +1876,10 @@ pub mod pallet {
 
                 <ProxyNonces<T>>::mutate(&sender, |n| *n += 1);
 
-                return Ok(().into())
+                return Ok(Some(<T as Config>::WeightInfo::signed_execute_leave_nominators(
+                    nomination_count,
+                ))
+                .into())
             }
 
             Err(Error::<T>::NominatorDNE)?
@@ -1417,10 +1925,10 @@ pub mod pallet {
                 proof.relayer.clone(),
                 &collator,
                 nominator_nonce,
+                Self::proxy_nonce_epoch(&nominator),
             );
             ensure!(
-                verify_signature::<T::Signature, T::AccountId>(&proof, &signed_payload.as_slice())
-                    .is_ok(),
+                verify_signed_payload::<T>(&proof, &signed_payload).is_ok(),
                 Error::<T>::UnauthorizedSignedRemoveBondTransaction
             );
 
@@ -1460,10 +1968,10 @@ pub mod pallet {
                 proof.relayer.clone(),
                 &extra_amount,
                 nominator_nonce,
+                Self::proxy_nonce_epoch(&nominator),
             );
             ensure!(
-                verify_signature::<T::Signature, T::AccountId>(&proof, &signed_payload.as_slice())
-                    .is_ok(),
+                verify_signed_payload::<T>(&proof, &signed_payload).is_ok(),
                 Error::<T>::UnauthorizedSignedBondExtraTransaction
             );
 
@@ -1476,20 +1984,20 @@ pub mod pallet {
             let state = <NominatorState<T>>::get(&nominator).ok_or(<Error<T>>::NominatorDNE)?;
             let nominations = state.nominations.0;
             let num_nominations = nominations.len() as u32;
-            let amount_per_collator = Perbill::from_rational(1, num_nominations) * extra_amount;
+            let (amount_per_collator, dust) = Self::split_amount(extra_amount, num_nominations)?;
             ensure!(
                 amount_per_collator >= T::MinNominationPerCollator::get(),
                 Error::<T>::NominationBelowMin
             );
 
-            let dust = extra_amount.saturating_sub(amount_per_collator * num_nominations.into());
             let mut remaining_amount_to_nominate = extra_amount;
             // This is only possible because we won't have more than 20 collators. If that changes,
             // we should not use a loop here.
             for (index, nomination) in nominations.into_iter().enumerate() {
                 let mut actual_amount = amount_per_collator;
                 if Self::collator_should_get_dust(dust, num_nominations.into(), index as u64) {
-                    actual_amount = amount_per_collator + dust;
+                    actual_amount =
+                        amount_per_collator.checked_add(&dust).ok_or(Error::<T>::Overflow)?;
                 }
 
                 // make sure we don't bond more than what the user asked
@@ -1497,7 +2005,9 @@ pub mod pallet {
 
                 Self::call_bond_extra(&nominator, nomination.owner, actual_amount)?;
 
-                remaining_amount_to_nominate -= actual_amount;
+                remaining_amount_to_nominate = remaining_amount_to_nominate
+                    .checked_sub(&actual_amount)
+                    .ok_or(Error::<T>::Overflow)?;
             }
 
             <ProxyNonces<T>>::mutate(&nominator, |n| *n += 1);
@@ -1534,10 +2044,10 @@ pub mod pallet {
                 proof.relayer.clone(),
                 &less,
                 nominator_nonce,
+                Self::proxy_nonce_epoch(&nominator),
             );
             ensure!(
-                verify_signature::<T::Signature, T::AccountId>(&proof, &signed_payload.as_slice())
-                    .is_ok(),
+                verify_signed_payload::<T>(&proof, &signed_payload).is_ok(),
                 Error::<T>::UnauthorizedSignedUnbondTransaction
             );
 
@@ -1600,11 +2110,11 @@ pub mod pallet {
                 proof.relayer.clone(),
                 &nominator,
                 sender_nonce,
+                Self::proxy_nonce_epoch(&sender),
             );
 
             ensure!(
-                verify_signature::<T::Signature, T::AccountId>(&proof, &signed_payload.as_slice())
-                    .is_ok(),
+                verify_signed_payload::<T>(&proof, &signed_payload).is_ok(),
                 Error::<T>::UnauthorizedSignedExecuteNominationRequestTransaction
             );
 
@@ -1640,31 +2150,76 @@ pub mod pallet {
             Self::nomination_cancel_request(candidate, nominator)
         }
 
-        /// Hotfix to remove existing empty entries for candidates that have left.
-        #[pallet::weight(
-			T::DbWeight::get().reads_writes(2 * candidates.len() as u64, candidates.len() as u64)
-		)]
-        #[pallet::call_index(31)]
-        pub fn hotfix_remove_nomination_requests_exited_candidates(
+        #[pallet::weight(<T as Config>::WeightInfo::set_candidate_nominator_cap())]
+        /// Set the maximum number of nominators of the calling candidate, or remove it with
+        /// `None`. Nominations beyond the cap are rejected. Existing nominations are kept.
+        #[pallet::call_index(39)]
+        pub fn set_candidate_nominator_cap(
             origin: OriginFor<T>,
-            candidates: Vec<T::AccountId>,
+            cap: Option<u32>,
         ) -> DispatchResult {
-            ensure_signed(origin)?;
-            ensure!(candidates.len() < 100, <Error<T>>::InsufficientBalance);
-            for candidate in &candidates {
-                ensure!(
-                    <CandidateInfo<T>>::get(&candidate).is_none(),
-                    <Error<T>>::CandidateNotLeaving
-                );
-                ensure!(
-                    <NominationScheduledRequests<T>>::get(&candidate).is_empty(),
-                    <Error<T>>::CandidateNotLeaving
-                );
+            let candidate = ensure_signed(origin)?;
+            ensure!(Self::is_candidate(&candidate), Error::<T>::CandidateDNE);
+
+            match cap {
+                Some(cap) => {
+                    let max_nominators = T::MaxTopNominationsPerCandidate::get()
+                        .saturating_add(T::MaxBottomNominationsPerCandidate::get());
+                    ensure!(cap > 0 && cap <= max_nominators, Error::<T>::InvalidNominatorCap);
+                    <CandidateNominatorCap<T>>::insert(&candidate, cap);
+                },
+                None => <CandidateNominatorCap<T>>::remove(&candidate),
             }
 
-            for candidate in candidates {
-                <NominationScheduledRequests<T>>::remove(candidate);
-            }
+            Self::deposit_event(Event::CandidateNominatorCapSet { candidate, cap });
+
+            Ok(())
+        }
+
+        #[pallet::weight(<T as Config>::WeightInfo::replace_nomination_request())]
+        #[transactional]
+        /// Cancel the pending request to change a nomination and schedule `action` instead. The
+        /// new request is executable after the usual delay, counted from the current era.
+        #[pallet::call_index(37)]
+        pub fn replace_nomination_request(
+            origin: OriginFor<T>,
+            candidate: T::AccountId,
+            action: NominationAction<BalanceOf<T>>,
+        ) -> DispatchResultWithPostInfo {
+            let nominator = ensure_signed(origin)?;
+            Self::nomination_replace_request(candidate, nominator, action)
+        }
+
+        #[pallet::weight(<T as Config>::WeightInfo::signed_replace_nomination_request())]
+        #[transactional]
+        /// Signed request to cancel the pending request to change a nomination and schedule
+        /// `action` instead.
+        #[pallet::call_index(38)]
+        pub fn signed_replace_nomination_request(
+            origin: OriginFor<T>,
+            proof: Proof<T::Signature, T::AccountId>,
+            candidate: T::AccountId,
+            action: NominationAction<BalanceOf<T>>,
+        ) -> DispatchResultWithPostInfo {
+            let nominator = ensure_signed(origin)?;
+            ensure!(nominator == proof.signer, Error::<T>::SenderIsNotSigner);
+
+            let nominator_nonce = Self::proxy_nonce(&nominator);
+            let signed_payload = encode_signed_replace_nomination_request_params::<T>(
+                proof.relayer.clone(),
+                &candidate,
+                &action,
+                nominator_nonce,
+                Self::proxy_nonce_epoch(&nominator),
+            );
+            ensure!(
+                verify_signed_payload::<T>(&proof, &signed_payload).is_ok(),
+                Error::<T>::UnauthorizedSignedReplaceNominationRequestTransaction
+            );
+
+            Self::nomination_replace_request(candidate, nominator.clone(), action)?;
+
+            <ProxyNonces<T>>::mutate(&nominator, |n| *n += 1);
 
             Ok(().into())
         }
@@ -1675,32 +2230,387 @@ pub mod pallet {
             origin: OriginFor<T>,
             value: AdminSettings<BalanceOf<T>>,
         ) -> DispatchResult {
-            frame_system::ensure_root(origin)?;
+            T::AdminOrigin::ensure_origin(origin)?;
             ensure!(value.is_valid::<T>(), Error::<T>::AdminSettingsValueIsNotValid);
 
             match value {
                 AdminSettings::Delay(d) => <Delay<T>>::put(d),
                 AdminSettings::MinCollatorStake(s) => <MinCollatorStake<T>>::put(s),
                 AdminSettings::MinTotalNominatorStake(s) => <MinTotalNominatorStake<T>>::put(s),
+                AdminSettings::GrowthEqualShare(equal_share) =>
+                    <GrowthDistributionPolicy<T>>::mutate(|distribution| {
+                        distribution.version = distribution.version.saturating_add(1);
+                        distribution.equal_share = equal_share;
+                    }),
+                AdminSettings::VotingRewardPerEra(amount) => <VotingRewardPerEra<T>>::put(amount),
+                AdminSettings::RewardPotAlertThreshold(eras) =>
+                    <RewardPotAlertThreshold<T>>::put(eras),
+                AdminSettings::RewardPotTopUpEnabled(enabled) =>
+                    <RewardPotTopUpEnabled<T>>::put(enabled),
+                AdminSettings::ExitedCollatorGrowth(policy) =>
+                    <ExitedCollatorGrowth<T>>::put(policy),
             }
 
             Self::deposit_event(Event::AdminSettingsUpdated { value });
 
             Ok(())
         }
+
+        /// Cancel an admin change announced by the admin origin, before it is executed
+        #[pallet::weight(<T as Config>::WeightInfo::cancel_admin_change())]
+        #[pallet::call_index(33)]
+        pub fn cancel_admin_change(
+            origin: OriginFor<T>,
+            schedule_name: TaskName,
+        ) -> DispatchResult {
+            T::AdminOrigin::ensure_origin(origin)?;
+            ensure!(
+                <PendingAdminChanges<T>>::contains_key(schedule_name),
+                Error::<T>::AdminChangeNotFound
+            );
+
+            T::Scheduler::cancel_named(schedule_name)?;
+            <PendingAdminChanges<T>>::remove(schedule_name);
+
+            Self::deposit_event(Event::AdminChangeCancelled { schedule_name });
+
+            Ok(())
+        }
+
+        /// Retry paying a staking reward that could not be transferred. Anyone can call it, but
+        /// only the failed retries of the payee or the admin origin count: the reward is returned
+        /// to the reward pot after `MAX_PAYOUT_RETRIES` of them.
+        #[pallet::weight(<T as Config>::WeightInfo::retry_failed_payout())]
+        #[pallet::call_index(34)]
+        pub fn retry_failed_payout(origin: OriginFor<T>, payee: T::AccountId) -> DispatchResult {
+            let retry_counts = match T::AdminOrigin::try_origin(origin) {
+                Ok(_) => true,
+                Err(origin) => ensure_signed(origin)? == payee,
+            };
+            let mut failed_payout =
+                <FailedPayouts<T>>::get(&payee).ok_or(Error::<T>::NoFailedPayout)?;
+            let amount = failed_payout.amount;
+
+            if Self::transfer_reward(&payee, amount).is_ok() {
+                <FailedPayouts<T>>::remove(&payee);
+                Self::release_locked_era_payout(amount);
+                Self::deposit_event(Event::Rewarded { account: payee, rewards: amount });
+                return Ok(())
+            }
+
+            if retry_counts {
+                failed_payout.retries = failed_payout.retries.saturating_add(1);
+            }
+            if failed_payout.retries >= MAX_PAYOUT_RETRIES {
+                <FailedPayouts<T>>::remove(&payee);
+                Self::release_locked_era_payout(amount);
+                Self::deposit_event(Event::FailedPayoutAbandoned { payee, rewards: amount });
+            } else {
+                <FailedPayouts<T>>::insert(&payee, failed_payout);
+                Self::deposit_event(Event::ErrorPayingStakingReward { payee, rewards: amount });
+            }
+
+            Ok(())
+        }
+
+        /// Claim the growth of `growth_period` that could not be paid to the sender when the
+        /// period was paid out.
+        #[pallet::weight(<T as Config>::WeightInfo::claim_missed_growth_payment())]
+        #[pallet::call_index(40)]
+        pub fn claim_missed_growth_payment(
+            origin: OriginFor<T>,
+            growth_period: GrowthPeriodIndex,
+        ) -> DispatchResult {
+            let collator = ensure_signed(origin)?;
+            let amount = <MissedGrowthPayments<T>>::get(growth_period, &collator)
+                .ok_or(Error::<T>::NoMissedGrowthPayment)?;
+
+            let amount_paid = T::Currency::deposit_into_existing(&collator, amount)
+                .map_err(|_| Error::<T>::ErrorPayingCollator)?;
+
+            <MissedGrowthPayments<T>>::remove(growth_period, &collator);
+            Self::deposit_event(Event::MissedGrowthPaymentClaimed {
+                account: collator,
+                amount: amount_paid.peek(),
+                period: growth_period,
+            });
+
+            Ok(())
+        }
+
+        /// Create a nomination pool nominating `collator`. The sender becomes the first member of
+        /// the pool with `amount`, which must be at least the minimum nominator stake.
+        #[pallet::weight(<T as Config>::WeightInfo::create_nomination_pool())]
+        #[pallet::call_index(41)]
+        pub fn create_nomination_pool(
+            origin: OriginFor<T>,
+            collator: T::AccountId,
+            amount: BalanceOf<T>,
+        ) -> DispatchResultWithPostInfo {
+            let depositor = ensure_signed(origin)?;
+            Self::create_pool(depositor, collator, amount)
+        }
+
+        /// Join a nomination pool with `amount`
+        #[pallet::weight(<T as Config>::WeightInfo::join_nomination_pool())]
+        #[pallet::call_index(42)]
+        pub fn join_nomination_pool(
+            origin: OriginFor<T>,
+            pool_id: PoolId,
+            amount: BalanceOf<T>,
+        ) -> DispatchResultWithPostInfo {
+            let account = ensure_signed(origin)?;
+            Self::join_pool(account, pool_id, amount)
+        }
+
+        /// Bond more stake to a nomination pool the sender is a member of. The rewards of the
+        /// member are paid first.
+        #[pallet::weight(<T as Config>::WeightInfo::pool_bond_extra())]
+        #[pallet::call_index(43)]
+        pub fn pool_bond_extra(
+            origin: OriginFor<T>,
+            pool_id: PoolId,
+            amount: BalanceOf<T>,
+        ) -> DispatchResultWithPostInfo {
+            let account = ensure_signed(origin)?;
+            Self::bond_extra_to_pool(account, pool_id, amount)
+        }
+
+        /// Start unbonding `amount` from a nomination pool. It can be withdrawn after the usual
+        /// nomination delay. The rewards of the member are paid first.
+        #[pallet::weight(<T as Config>::WeightInfo::pool_unbond())]
+        #[pallet::call_index(44)]
+        pub fn pool_unbond(
+            origin: OriginFor<T>,
+            pool_id: PoolId,
+            amount: BalanceOf<T>,
+        ) -> DispatchResultWithPostInfo {
+            let account = ensure_signed(origin)?;
+            Self::unbond_from_pool(account, pool_id, amount)
+        }
+
+        /// Withdraw the stake the sender has unbonded from a nomination pool
+        #[pallet::weight(<T as Config>::WeightInfo::pool_withdraw_unbonded())]
+        #[pallet::call_index(45)]
+        pub fn pool_withdraw_unbonded(
+            origin: OriginFor<T>,
+            pool_id: PoolId,
+        ) -> DispatchResultWithPostInfo {
+            let account = ensure_signed(origin)?;
+            Self::withdraw_unbonded_from_pool(account, pool_id)
+        }
+
+        /// Claim the share of the sender of the rewards of a nomination pool
+        #[pallet::weight(<T as Config>::WeightInfo::claim_pool_rewards())]
+        #[pallet::call_index(46)]
+        pub fn claim_pool_rewards(
+            origin: OriginFor<T>,
+            pool_id: PoolId,
+        ) -> DispatchResultWithPostInfo {
+            let account = ensure_signed(origin)?;
+            Self::claim_rewards_from_pool(account, pool_id)
+        }
+
+        /// Move the bottom nominations of `candidate` to its top nominations while there is room
+        /// for them, for example after `MaxTopNominationsPerCandidate` has been raised. Anyone can
+        /// call this.
+        #[pallet::weight(<T as Config>::WeightInfo::rebalance_nominations_for(
+            T::MaxBottomNominationsPerCandidate::get()
+        ))]
+        #[pallet::call_index(47)]
+        pub fn rebalance_nominations_for(
+            origin: OriginFor<T>,
+            candidate: T::AccountId,
+        ) -> DispatchResultWithPostInfo {
+            ensure_signed(origin)?;
+            let promoted = Self::rebalance_candidate_nominations(&candidate)?;
+
+            Ok(Some(<T as Config>::WeightInfo::rebalance_nominations_for(promoted)).into())
+        }
+
+        /// Recompute the totals of a growth period from the staking data of its eras that is
+        /// still in storage, and report the values of the growth record that don't match. When
+        /// `correct` is true, the record is updated with the recomputed values. This is only
+        /// allowed before the growth is published to Ethereum.
+        #[pallet::weight(<T as Config>::WeightInfo::audit_growth(
+            T::ErasPerGrowthPeriod::get(),
+            T::MaxCandidates::get()
+        ))]
+        #[pallet::call_index(35)]
+        pub fn audit_growth(
+            origin: OriginFor<T>,
+            growth_period: GrowthPeriodIndex,
+            correct: bool,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+            ensure!(<Growth<T>>::contains_key(growth_period), Error::<T>::GrowthDataNotFound);
+            let start_era =
+                <GrowthStartEra<T>>::get(growth_period).ok_or(Error::<T>::GrowthErasUnknown)?;
+
+            let mut growth = <Growth<T>>::get(growth_period);
+            if correct {
+                ensure!(
+                    growth.tx_id.is_none() && growth.triggered.is_none(),
+                    Error::<T>::GrowthAlreadyPublished
+                );
+            }
+
+            let eras = start_era..start_era.saturating_add(growth.number_of_accumulations);
+            let discrepancies = Self::audit_growth_record(eras, &mut growth);
+            for discrepancy in discrepancies.iter() {
+                Self::deposit_event(Event::GrowthDiscrepancyFound {
+                    growth_period,
+                    discrepancy: discrepancy.clone(),
+                });
+            }
+
+            let corrected = correct && !discrepancies.is_empty();
+            if corrected {
+                <Growth<T>>::insert(growth_period, growth);
+            }
+
+            Self::deposit_event(Event::GrowthAudited {
+                growth_period,
+                discrepancies: discrepancies.len() as u32,
+                corrected,
+            });
+
+            Ok(())
+        }
+
+        /// Set the amount of the reward pot locked for eras that have not been paid out yet. This
+        /// is used to fix a drift between `LockedEraPayout` and the reward pot, which would
+        /// otherwise reduce or stop future era payouts. The failed payouts are still owed, so the
+        /// new value is raised to their total if it is lower.
+        #[pallet::weight(<T as Config>::WeightInfo::rebase_locked_era_payout(*failed_payout_count))]
+        #[pallet::call_index(36)]
+        pub fn rebase_locked_era_payout(
+            origin: OriginFor<T>,
+            #[pallet::compact] new_locked_era_payout: BalanceOf<T>,
+            failed_payout_count: u32,
+        ) -> DispatchResultWithPostInfo {
+            ensure_root(origin)?;
+            let (failed_payouts, actual_failed_payout_count) = <FailedPayouts<T>>::iter_values()
+                .take(failed_payout_count.saturating_add(1) as usize)
+                .fold((BalanceOf::<T>::zero(), 0u32), |(total, count), payout| {
+                    (total.saturating_add(payout.amount), count.saturating_add(1))
+                });
+            ensure!(
+                failed_payout_count >= actual_failed_payout_count,
+                Error::<T>::TooLowFailedPayoutCountWeightHintRebaseLockedEraPayout
+            );
+
+            let new_locked_era_payout = new_locked_era_payout.max(failed_payouts);
+            let reward_pot_balance = Self::reward_pot();
+            ensure!(
+                new_locked_era_payout <= reward_pot_balance,
+                Error::<T>::LockedEraPayoutExceedsRewardPot
+            );
+
+            let old_locked_era_payout = <LockedEraPayout<T>>::get();
+            <LockedEraPayout<T>>::put(new_locked_era_payout);
+
+            Self::deposit_event(Event::LockedEraPayoutRebased {
+                old_locked_era_payout,
+                new_locked_era_payout,
+                reward_pot_balance,
+            });
+
+            Ok(Some(<T as Config>::WeightInfo::rebase_locked_era_payout(
+                actual_failed_payout_count,
+            ))
+            .into())
+        }
     }
 
     impl<T: Config> Pallet<T> {
+        /// Root, which is also the origin of the announced changes once their delay has passed,
+        /// changes the parameters straight away. The admin origin can only announce changes.
+        fn ensure_admin_change_origin(origin: OriginFor<T>) -> Result<bool, DispatchError> {
+            if ensure_root(origin.clone()).is_ok() {
+                return Ok(true)
+            }
+
+            T::AdminOrigin::ensure_origin(origin)?;
+            Ok(false)
+        }
+
+        /// The name of the scheduled task is derived from the overarching call, so that it cannot
+        /// clash with the changes announced by other pallets.
+        pub(crate) fn admin_change_name(call: &Call<T>) -> TaskName {
+            let call = <T as Config>::RuntimeCall::from(call.clone());
+            ("AdminChange", call).using_encoded(sp_io::hashing::blake2_256)
+        }
+
+        pub(crate) fn announce_admin_change(
+            call: Call<T>,
+        ) -> Result<(TaskName, BlockNumberFor<T>), DispatchError> {
+            Self::clear_pending_admin_changes(&call);
+
+            let schedule_name = Self::admin_change_name(&call);
+            let execute_at = <frame_system::Pallet<T>>::block_number()
+                .saturating_add(T::AdminChangeDelay::get());
+
+            T::Scheduler::schedule_named(
+                schedule_name,
+                DispatchTime::At(execute_at),
+                None,
+                HARD_DEADLINE,
+                frame_system::RawOrigin::Root.into(),
+                T::Preimages::bound(<T as Config>::RuntimeCall::from(call))?,
+            )?;
+            <PendingAdminChanges<T>>::insert(schedule_name, execute_at);
+
+            Ok((schedule_name, execute_at))
+        }
+
+        /// Drops the announced changes that have not been executed, as `call` supersedes them.
+        /// Those other than `call` itself are cancelled.
+        fn clear_pending_admin_changes(call: &Call<T>) {
+            let superseded_by = Self::admin_change_name(call);
+            for (schedule_name, _) in <PendingAdminChanges<T>>::drain() {
+                // The change being executed by the scheduler is no longer in its agenda
+                let _ = T::Scheduler::cancel_named(schedule_name);
+                if schedule_name != superseded_by {
+                    Self::deposit_event(Event::AdminChangeCancelled { schedule_name });
+                }
+            }
+        }
+
+        /// Removes the announced changes whose execution block has passed. They are only left
+        /// when their execution failed.
+        fn clear_expired_admin_changes(now: BlockNumberFor<T>) -> Weight {
+            let pending: Vec<(TaskName, BlockNumberFor<T>)> =
+                <PendingAdminChanges<T>>::iter().collect();
+            let mut expired = 0u64;
+            for (schedule_name, execute_at) in pending.iter() {
+                if *execute_at < now {
+                    <PendingAdminChanges<T>>::remove(schedule_name);
+                    expired += 1;
+                }
+            }
+
+            T::DbWeight::get().reads_writes(pending.len() as u64 + 1, expired)
+        }
+
         pub fn start_new_era(
             block_number: BlockNumberFor<T>,
             mut era: EraInfo<BlockNumberFor<T>>,
         ) -> (EraInfo<BlockNumberFor<T>>, Weight) {
+            let ended_era = era.current;
             // mutate era
             era.update(block_number);
 
+            Self::report_era_fees(ended_era, era.current);
+
+            // Paid before the staking payout locks the rest of the reward pot
+            let voting_reward_weight = Self::pay_voting_rewards(ended_era);
+
             // pay all stakers for T::RewardPaymentDelay eras ago
             Self::prepare_staking_payouts(era.current);
 
+            let chill_weight = Self::chill_candidates_without_keys(era.current);
+
             // select top collator candidates for next era
             let (collator_count, nomination_count, total_staked) =
                 Self::select_top_candidates(era.current);
@@ -1720,10 +2630,138 @@ pub mod pallet {
             let weight = <T as Config>::WeightInfo::era_transition_on_initialize(
                 collator_count,
                 nomination_count,
-            );
+            )
+            .saturating_add(chill_weight)
+            .saturating_add(voting_reward_weight);
             return (era, weight)
         }
 
+        /// Shares the voting reward of `era` between the validators that voted in it, in
+        /// proportion to their votes. Only the part of the reward pot not locked for staking
+        /// payouts is used. The participation of the era is removed once it is paid.
+        fn pay_voting_rewards(era: EraIndex) -> Weight {
+            let participation: Vec<(T::AccountId, u32)> =
+                <VotingParticipation<T>>::drain_prefix(era).collect();
+            // [Read/Write]: the participation of each voter, [Read]: VotingRewardPerEra
+            let mut weight = T::DbWeight::get()
+                .reads_writes(participation.len() as u64 + 1, participation.len() as u64);
+
+            let total_votes = participation
+                .iter()
+                .fold(0u32, |total, (_, votes)| total.saturating_add(*votes));
+            let voting_reward = <VotingRewardPerEra<T>>::get();
+            if total_votes == 0 || voting_reward.is_zero() {
+                return weight
+            }
+
+            let available = Self::reward_pot().saturating_sub(Self::locked_era_payout());
+            let voting_reward = voting_reward.min(available);
+            // [Read]: reward pot, LockedEraPayout
+            weight = weight.saturating_add(T::DbWeight::get().reads(2));
+
+            for (voter, votes) in participation {
+                let amount = Perbill::from_rational(votes, total_votes) * voting_reward;
+                if amount.is_zero() {
+                    continue
+                }
+
+                if Self::transfer_reward(&voter, amount).is_err() {
+                    // Locked until the failed payout is retried or abandoned
+                    <LockedEraPayout<T>>::mutate(|p| *p = p.saturating_add(amount));
+                    Self::queue_failed_payout(&voter, amount);
+                } else {
+                    Self::deposit_event(Event::VotingRewarded { era, voter, votes, amount });
+                }
+                // [Read/Write]: reward pot, voter account
+                weight = weight.saturating_add(T::DbWeight::get().reads_writes(2, 2));
+            }
+
+            weight
+        }
+
+        /// Removes a candidate from the candidate pool, without unbonding
+        pub(crate) fn take_candidate_offline(collator: &T::AccountId) -> DispatchResult {
+            let mut state = <CandidateInfo<T>>::get(collator).ok_or(Error::<T>::CandidateDNE)?;
+            ensure!(state.is_active(), Error::<T>::AlreadyOffline);
+            state.go_offline();
+            let mut candidates = <CandidatePool<T>>::get();
+            if candidates.remove(&Bond::from_owner(collator.clone())) {
+                <CandidatePool<T>>::put(candidates);
+            }
+            <CandidateInfo<T>>::insert(collator, state);
+            Self::deposit_event(Event::CandidateWentOffline { candidate: collator.clone() });
+            Ok(())
+        }
+
+        /// Puts an offline candidate back in the candidate pool
+        pub(crate) fn bring_candidate_online(collator: &T::AccountId) -> DispatchResult {
+            let mut state = <CandidateInfo<T>>::get(collator).ok_or(Error::<T>::CandidateDNE)?;
+            ensure!(!state.is_active(), Error::<T>::AlreadyActive);
+            ensure!(!state.is_leaving(), Error::<T>::CannotGoOnlineIfLeaving);
+            state.go_online();
+            let mut candidates = <CandidatePool<T>>::get();
+            let maybe_inserted_candidate = candidates
+                .try_insert(Bond { owner: collator.clone(), amount: state.total_counted })
+                .map_err(|_| Error::<T>::CandidateLimitReached)?;
+            ensure!(maybe_inserted_candidate, Error::<T>::AlreadyActive);
+
+            <CandidatePool<T>>::put(candidates);
+            <CandidateInfo<T>>::insert(collator, state);
+            Self::deposit_event(Event::CandidateBackOnline { candidate: collator.clone() });
+            Ok(())
+        }
+
+        /// Takes offline the candidates whose session keys are no longer registered, so they are
+        /// not selected for `era`
+        fn chill_candidates_without_keys(era: EraIndex) -> Weight {
+            let mut candidates = <CandidatePool<T>>::get();
+            let unregistered: Vec<T::AccountId> = candidates
+                .0
+                .iter()
+                .map(|bond| bond.owner.clone())
+                .filter(|candidate| !T::CollatorSessionRegistration::is_registered(candidate))
+                .collect();
+
+            // [Read]: CandidatePool and the session keys of each candidate
+            let weight = T::DbWeight::get().reads(candidates.0.len() as u64 + 1);
+            if unregistered.is_empty() {
+                return weight
+            }
+
+            for candidate in unregistered.iter() {
+                if let Some(mut state) = <CandidateInfo<T>>::get(candidate) {
+                    state.go_offline();
+                    <CandidateInfo<T>>::insert(candidate, state);
+                }
+                candidates.remove(&Bond::from_owner(candidate.clone()));
+                Self::deposit_event(Event::CandidateSkippedNoKeys {
+                    era,
+                    candidate: candidate.clone(),
+                });
+            }
+            <CandidatePool<T>>::put(candidates);
+
+            // [Read/Write]: CandidateInfo of each candidate, [Write]: CandidatePool
+            let chilled = unregistered.len() as u64;
+            weight.saturating_add(T::DbWeight::get().reads_writes(chilled, chilled + 1))
+        }
+
+        fn report_era_fees(ended_era: EraIndex, new_era: EraIndex) {
+            let amount = <EraFeesToRewardPot<T>>::get(ended_era);
+            if !amount.is_zero() {
+                Self::deposit_event(Event::RewardPotFeesCollected { era: ended_era, amount });
+            }
+
+            // The fees of an era are kept until the era after it is prepared for payout
+            let oldest_kept_era = match <LastPreparedPayoutEra<T>>::get() {
+                Some(last_prepared_era) => last_prepared_era,
+                None => new_era.saturating_sub(T::RewardPaymentDelay::get()).saturating_sub(1),
+            };
+            if !oldest_kept_era.is_zero() {
+                <EraFeesToRewardPot<T>>::remove(oldest_kept_era.saturating_sub(1));
+            }
+        }
+
         pub fn is_nominator(acc: &T::AccountId) -> bool {
             <NominatorState<T>>::get(acc).is_some()
         }
@@ -1793,6 +2831,43 @@ pub mod pallet {
             return payout
         }
 
+        /// Reports the reward pot when it covers fewer eras of projected payouts than the alert
+        /// threshold, and tops it up to the threshold if enabled
+        pub(crate) fn check_reward_pot_balance(era_payout: BalanceOf<T>) {
+            if !era_payout.is_zero() {
+                <ProjectedEraPayout<T>>::put(era_payout);
+            }
+
+            let threshold = <RewardPotAlertThreshold<T>>::get();
+            let projected_era_payout = <ProjectedEraPayout<T>>::get();
+            if threshold == 0 || projected_era_payout.is_zero() {
+                return
+            }
+
+            let reward_pot_balance = Self::reward_pot();
+            let required_balance = projected_era_payout.saturating_mul(threshold.into());
+            if reward_pot_balance >= required_balance {
+                return
+            }
+
+            let eras_covered = (reward_pot_balance / projected_era_payout).saturated_into();
+            Self::deposit_event(Event::RewardPotBalanceLow {
+                reward_pot_balance,
+                projected_era_payout,
+                eras_covered,
+            });
+
+            if <RewardPotTopUpEnabled<T>>::get() {
+                let amount = T::RewardPotTopUp::top_up(
+                    &Self::compute_reward_pot_account_id(),
+                    required_balance.saturating_sub(reward_pot_balance),
+                );
+                if !amount.is_zero() {
+                    Self::deposit_event(Event::RewardPotToppedUp { amount });
+                }
+            }
+        }
+
         /// Remove nomination from candidate state
         /// Amount input should be retrieved from nominator and it informs the storage lookups
         pub(crate) fn nominator_leaves_candidate(
@@ -1815,21 +2890,62 @@ pub mod pallet {
             Ok(())
         }
 
+        /// Prepares the payout of the eras that ended `RewardPaymentDelay` eras ago. Eras are
+        /// prepared in order from the last prepared one:
+        /// * after the delay is increased, nothing is prepared until the next era is due
+        /// * after the delay is decreased, the eras it skips are prepared with the due one, up to
+        ///   `MAX_PAYOUT_ERAS_PER_ERA` at a time, and share the reward pot
         fn prepare_staking_payouts(now: EraIndex) {
             // payout is now - delay eras ago => now - delay > 0 else return early
             let delay = T::RewardPaymentDelay::get();
             if now <= delay {
                 return
             }
-            let era_to_payout = now.saturating_sub(delay);
-            let total_points = <Points<T>>::get(era_to_payout);
-            if total_points.is_zero() {
+            let due_era = now.saturating_sub(delay);
+            let first_era = match <LastPreparedPayoutEra<T>>::get() {
+                Some(last_prepared_era) => last_prepared_era.saturating_add(1),
+                None => due_era,
+            };
+            if first_era > due_era {
+                return
+            }
+            let last_era =
+                due_era.min(first_era.saturating_add(MAX_PAYOUT_ERAS_PER_ERA.saturating_sub(1)));
+            <LastPreparedPayoutEra<T>>::put(last_era);
+
+            let eras_to_payout: Vec<(EraIndex, RewardPoint)> = (first_era..=last_era)
+                .map(|era| (era, <Points<T>>::get(era)))
+                .filter(|(_, total_points)| !total_points.is_zero())
+                .collect();
+            if eras_to_payout.is_empty() {
                 return
             }
-            // Remove stake because it has been processed.
-            let total_staked = <Staked<T>>::take(era_to_payout);
 
             let total_reward_to_pay = Self::compute_total_reward_to_pay();
+            Self::check_reward_pot_balance(total_reward_to_pay);
+
+            let eras_count = eras_to_payout.len() as u32;
+            let era_reward = total_reward_to_pay / eras_count.into();
+            // The last era gets the remainder of the division
+            let last_era_reward = total_reward_to_pay
+                .saturating_sub(era_reward.saturating_mul((eras_count.saturating_sub(1)).into()));
+            for (index, (era_to_payout, total_points)) in eras_to_payout.into_iter().enumerate() {
+                let reward = if index as u32 == eras_count.saturating_sub(1) {
+                    last_era_reward
+                } else {
+                    era_reward
+                };
+                Self::prepare_era_payout(era_to_payout, total_points, reward);
+            }
+        }
+
+        fn prepare_era_payout(
+            era_to_payout: EraIndex,
+            total_points: RewardPoint,
+            total_reward_to_pay: BalanceOf<T>,
+        ) {
+            // Remove stake because it has been processed.
+            let total_staked = <Staked<T>>::take(era_to_payout);
 
             let payout = DelayedPayout {
                 total_staking_reward: total_reward_to_pay, /* TODO: Remove one of the duplicated
@@ -1844,7 +2960,8 @@ pub mod pallet {
                     <AwardedPts<T>>::iter_prefix(era_to_payout)
                         .map(|(collator, points)| CollatorScore::new(collator, points))
                         .collect::<Vec<CollatorScore<T::AccountId>>>();
-                let collator_scores = BoundedVec::truncate_from(collator_scores_vec);
+                let collator_scores =
+                    Self::bound_collator_scores(era_to_payout, collator_scores_vec);
                 Self::update_collator_payout(
                     era_to_payout,
                     total_staked,
@@ -1857,32 +2974,89 @@ pub mod pallet {
 
         /// Wrapper around pay_one_collator_reward which handles the following logic:
         /// * whether or not a payout needs to be made
-        /// * cleaning up when payouts are done
+        /// * cleaning up and reporting the era payout when payouts are done
+        /// * pruning the rewards of the era that no longer fits in the rewards history
         /// * returns the weight consumed by pay_one_collator_reward if applicable
         fn handle_delayed_payouts(now: EraIndex) -> Weight {
-            let delay = T::RewardPaymentDelay::get();
-
-            // don't underflow uint
-            if now < delay {
-                return Weight::from_parts(0 as u64, 0).into()
-            }
-
-            let paid_for_era = now.saturating_sub(delay);
+            let (paid_for_era, lookup_weight) = match <LastPreparedPayoutEra<T>>::get() {
+                // Pay the oldest era still pending
+                Some(last_prepared_era) => {
+                    let first_pending_era =
+                        last_prepared_era.saturating_sub(MAX_PAYOUT_ERAS_PER_ERA.saturating_sub(1));
+                    let paid_for_era = (first_pending_era..=last_prepared_era)
+                        .find(|era| <DelayedPayouts<T>>::contains_key(era))
+                        .unwrap_or(last_prepared_era);
+                    let lookups = paid_for_era.saturating_sub(first_pending_era).saturating_add(1);
+                    // [Read]: LastPreparedPayoutEra, DelayedPayouts of each era looked up
+                    (paid_for_era, T::DbWeight::get().reads(lookups as u64 + 1))
+                },
+                None => {
+                    let delay = T::RewardPaymentDelay::get();
+                    // don't underflow uint
+                    if now < delay {
+                        return Weight::from_parts(0 as u64, 0).into()
+                    }
+                    (now.saturating_sub(delay), T::DbWeight::get().reads(1))
+                },
+            };
+            let prune_weight =
+                Self::prune_rewards_history(paid_for_era).saturating_add(lookup_weight);
 
             if let Some(payout_info) = <DelayedPayouts<T>>::get(paid_for_era) {
+                let total_staker_reward = payout_info.total_staking_reward;
                 let result = Self::pay_one_collator_reward(paid_for_era, payout_info);
                 if result.0.is_none() {
                     // result.0 indicates whether or not a payout was made
                     // clean up storage items that we no longer need
                     <DelayedPayouts<T>>::remove(paid_for_era);
                     <Points<T>>::remove(paid_for_era);
+
+                    let counts = <EraPayoutCounts<T>>::take(paid_for_era);
+                    Self::deposit_event(Event::EraPaidOut {
+                        era: paid_for_era,
+                        total_staker_reward,
+                        collators_paid: counts.collators_paid,
+                        failed_payments: counts.failed_payments,
+                        locked_era_payout: Self::locked_era_payout(),
+                    });
                 }
-                result.1 // weight consumed by pay_one_collator_reward
+                // weight consumed by pay_one_collator_reward
+                result.1.saturating_add(prune_weight)
             } else {
-                Weight::from_parts(0 as u64, 0).into()
+                prune_weight
             }
         }
 
+        /// Removes some of the rewards paid for the era that no longer fits in the history
+        fn prune_rewards_history(paid_for_era: EraIndex) -> Weight {
+            let expired_era = match paid_for_era.checked_sub(T::HistoryDepth::get()) {
+                Some(era) => era,
+                None => return Weight::zero(),
+            };
+
+            let removal =
+                <RewardsHistory<T>>::clear_prefix(expired_era, MAX_REWARDS_HISTORY_REMOVALS, None);
+            T::DbWeight::get().reads_writes(removal.loops.into(), removal.unique.into())
+        }
+
+        fn record_reward(
+            paid_for_era: EraIndex,
+            payee: &T::AccountId,
+            collator: &T::AccountId,
+            amount: BalanceOf<T>,
+        ) {
+            <RewardsHistory<T>>::mutate(paid_for_era, payee, |rewards| {
+                let reward = EraReward { collator: collator.clone(), amount };
+                if rewards.try_push(reward).is_err() {
+                    log::warn!(
+                        "💔 Too many rewards to record for {:?} in era {}",
+                        payee,
+                        paid_for_era
+                    );
+                }
+            });
+        }
+
         /// Payout a single collator from the given era.
         ///
         /// Returns an optional tuple of (Collator's AccountId, total paid)
@@ -1904,27 +3078,24 @@ pub mod pallet {
                 return (None, Weight::from_parts(0 as u64, 0).into())
             }
 
-            let reward_pot_account_id = Self::compute_reward_pot_account_id();
-            let pay_reward = |amount: BalanceOf<T>, to: T::AccountId| {
-                let result = T::Currency::transfer(
-                    &reward_pot_account_id,
-                    &to,
-                    amount,
-                    ExistenceRequirement::KeepAlive,
-                );
+            let pay_reward = |amount: BalanceOf<T>, to: T::AccountId, collator: &T::AccountId| {
+                let result = Self::transfer_reward(&to, amount);
                 if let Ok(_) = result {
+                    Self::record_reward(paid_for_era, &to, collator, amount);
                     Self::deposit_event(Event::Rewarded { account: to.clone(), rewards: amount });
 
                     // Update storage with the amount we paid
-                    <LockedEraPayout<T>>::mutate(|p| {
-                        *p = p.saturating_sub(amount.into());
-                    });
+                    Self::release_locked_era_payout(amount);
+                    true
                 } else {
                     log::error!("💔 Error paying staking reward: {:?}", result);
+                    // The reward stays locked until it is retried
+                    Self::queue_failed_payout(&to, amount);
                     Self::deposit_event(Event::ErrorPayingStakingReward {
                         payee: to.clone(),
                         rewards: amount,
                     });
+                    false
                 }
             };
 
@@ -1940,17 +3111,27 @@ pub mod pallet {
                 // pay collator's due portion first
                 let collator_pct = Perbill::from_rational(state.bond, state.total);
                 let collator_reward = collator_pct * total_reward_for_collator;
-                pay_reward(collator_reward, collator.clone());
+                let mut failed_payments: u32 = 0;
+                if !pay_reward(collator_reward, collator.clone(), &collator) {
+                    failed_payments.saturating_inc();
+                }
 
                 // pay nominators due portion, if there are any
                 for Bond { owner, amount } in state.nominations {
                     let percent = Perbill::from_rational(amount, state.total);
                     let nominator_reward = percent * total_reward_for_collator;
-                    if !nominator_reward.is_zero() {
-                        pay_reward(nominator_reward, owner.clone());
+                    if !nominator_reward.is_zero() &&
+                        !pay_reward(nominator_reward, owner.clone(), &collator)
+                    {
+                        failed_payments.saturating_inc();
                     }
                 }
 
+                <EraPayoutCounts<T>>::mutate(paid_for_era, |counts| {
+                    counts.collators_paid.saturating_inc();
+                    counts.failed_payments.saturating_accrue(failed_payments);
+                });
+
                 (
                     Some((collator, total_reward_for_collator)),
                     <T as Config>::WeightInfo::pay_one_collator_reward(num_nominators as u32),
@@ -1962,6 +3143,29 @@ pub mod pallet {
             }
         }
 
+        fn transfer_reward(to: &T::AccountId, amount: BalanceOf<T>) -> DispatchResult {
+            T::Currency::transfer(
+                &Self::compute_reward_pot_account_id(),
+                to,
+                amount,
+                ExistenceRequirement::KeepAlive,
+            )
+        }
+
+        fn release_locked_era_payout(amount: BalanceOf<T>) {
+            <LockedEraPayout<T>>::mutate(|p| {
+                *p = p.saturating_sub(amount);
+            });
+        }
+
+        fn queue_failed_payout(payee: &T::AccountId, amount: BalanceOf<T>) {
+            <FailedPayouts<T>>::mutate(payee, |failed_payout| match failed_payout {
+                Some(failed_payout) =>
+                    failed_payout.amount = failed_payout.amount.saturating_add(amount),
+                None => *failed_payout = Some(FailedPayout { amount, retries: 0 }),
+            });
+        }
+
         /// Compute the top `TotalSelected` candidates in the CandidatePool and return
         /// a vec of their AccountIds (in the order of selection)
         pub fn compute_top_candidates() -> Vec<T::AccountId> {
@@ -2007,11 +3211,7 @@ pub mod pallet {
                     let snapshot_total = total_per_candidate
                         .get(&candidate)
                         .expect("all selected candidates have snapshots");
-                    Self::deposit_event(Event::CollatorChosen {
-                        era: now,
-                        collator_account: candidate,
-                        total_exposed_amount: *snapshot_total,
-                    })
+                    Self::deposit_collator_chosen_events(now, candidate, *snapshot_total);
                 }
                 return (collator_count, nomination_count, total)
             }
@@ -2034,17 +3234,14 @@ pub mod pallet {
                     total: total_counted,
                 };
                 <AtStake<T>>::insert(now, account, snapshot);
-                Self::deposit_event(Event::CollatorChosen {
-                    era: now,
-                    collator_account: account.clone(),
-                    total_exposed_amount: state.total_counted,
-                });
+                Self::deposit_collator_chosen_events(now, account.clone(), state.total_counted);
             }
             // insert canonical collator set
             <SelectedCandidates<T>>::put(
                 BoundedVec::try_from(collators)
                     .expect("subset of collators is always less than or equal to max candidates"),
             );
+            <TotalSelectedAtSelection<T>>::put(<TotalSelected<T>>::get());
             (collator_count, nomination_count, total)
         }
 
@@ -2111,6 +3308,202 @@ pub mod pallet {
                 .saturating_sub(T::Currency::minimum_balance())
         }
 
+        /// Network-wide staking statistics. The APR is estimated by repeating the next era payout
+        /// for every era that fits in `blocks_per_year`.
+        pub fn staking_overview(blocks_per_year: u32) -> StakingOverview<BalanceOf<T>> {
+            let selected_stakes: Vec<BalanceOf<T>> = <SelectedCandidates<T>>::get()
+                .iter()
+                .filter_map(|collator| <CandidateInfo<T>>::get(collator))
+                .map(|info| info.total_counted)
+                .collect();
+
+            let total_selected = selected_stakes.len() as u32;
+            let total_selected_stake = selected_stakes
+                .iter()
+                .fold(BalanceOf::<T>::zero(), |total, stake| total.saturating_add(*stake));
+            let average_collator_stake = total_selected_stake
+                .checked_div(&total_selected.into())
+                .unwrap_or_else(Zero::zero);
+
+            let min_collator_stake =
+                selected_stakes.iter().min().copied().unwrap_or_else(Zero::zero);
+            let max_collator_stake =
+                selected_stakes.iter().max().copied().unwrap_or_else(Zero::zero);
+
+            let reward_pot_balance = Self::reward_pot();
+            let locked_era_payout = Self::locked_era_payout();
+            let eras_per_year = blocks_per_year.checked_div(Self::era().length).unwrap_or(0);
+            let yearly_reward = reward_pot_balance
+                .saturating_sub(locked_era_payout)
+                .saturating_mul(eras_per_year.into());
+            let estimated_apr = if total_selected_stake.is_zero() {
+                Perbill::zero()
+            } else {
+                Perbill::from_rational(yearly_reward, total_selected_stake)
+            };
+
+            StakingOverview {
+                total_staked: Self::total(),
+                total_selected,
+                average_collator_stake,
+                min_collator_stake,
+                max_collator_stake,
+                reward_pot_balance,
+                locked_era_payout,
+                estimated_apr,
+            }
+        }
+
+        /// Compares the payouts locked for past eras with the funds of the reward pot
+        pub fn reward_pot_reconciliation() -> RewardPotReconciliation<BalanceOf<T>> {
+            let reward_pot_balance = Self::reward_pot();
+            let locked_era_payout = Self::locked_era_payout();
+            let failed_payouts = <FailedPayouts<T>>::iter_values()
+                .fold(BalanceOf::<T>::zero(), |total, payout| total.saturating_add(payout.amount));
+
+            RewardPotReconciliation {
+                reward_pot_balance,
+                locked_era_payout,
+                failed_payouts,
+                unlocked: reward_pot_balance.saturating_sub(locked_era_payout),
+                deficit: locked_era_payout.saturating_sub(reward_pot_balance),
+            }
+        }
+
+        fn deposit_collator_chosen_events(
+            era: EraIndex,
+            collator_account: T::AccountId,
+            total_exposed_amount: BalanceOf<T>,
+        ) {
+            let identity = T::IdentityProvider::identity(&collator_account);
+            Self::deposit_event(Event::CollatorChosen {
+                era,
+                collator_account: collator_account.clone(),
+                total_exposed_amount,
+            });
+
+            if let Some(identity) = identity {
+                Self::deposit_event(Event::ChosenCollatorIdentity {
+                    era,
+                    collator_account,
+                    identity,
+                });
+            }
+        }
+
+        /// All the collator candidates, with the identity of their operator
+        pub fn candidates_overview() -> Vec<CandidateOverview<T::AccountId, BalanceOf<T>>> {
+            let selected = <SelectedCandidates<T>>::get();
+            <CandidatePool<T>>::get()
+                .0
+                .into_iter()
+                .map(|bond| CandidateOverview {
+                    is_selected: selected.contains(&bond.owner),
+                    identity: T::IdentityProvider::identity(&bond.owner),
+                    account: bond.owner,
+                    total_counted: bond.amount,
+                })
+                .collect()
+        }
+
+        /// Rewards paid to `account` for the eras from `from_era` to `to_era`, both included. Only
+        /// the eras still in the rewards history are returned.
+        pub fn rewards_history(
+            account: &T::AccountId,
+            from_era: EraIndex,
+            to_era: EraIndex,
+        ) -> Vec<RewardsHistoryEntry<T::AccountId, BalanceOf<T>>> {
+            let last_paid_era = <LastPreparedPayoutEra<T>>::get().unwrap_or_else(|| {
+                <Era<T>>::get().current.saturating_sub(T::RewardPaymentDelay::get())
+            });
+            let first_kept_era = last_paid_era.saturating_sub(T::HistoryDepth::get());
+            let to_era = to_era.min(last_paid_era);
+
+            (from_era.max(first_kept_era)..=to_era)
+                .flat_map(|era| {
+                    <RewardsHistory<T>>::get(era, account).into_iter().map(move |reward| {
+                        RewardsHistoryEntry {
+                            era,
+                            collator: reward.collator,
+                            amount: reward.amount,
+                        }
+                    })
+                })
+                .collect()
+        }
+
+        /// Number of top and bottom nominators of a candidate, with its nominator cap
+        pub fn candidate_nominator_counts(
+            candidate: &T::AccountId,
+        ) -> Option<CandidateNominatorCounts> {
+            let count = |nominations: Option<Nominations<T::AccountId, BalanceOf<T>>>| {
+                nominations.map_or(0, |n| n.nominations.len() as u32)
+            };
+
+            Self::is_candidate(candidate).then(|| CandidateNominatorCounts {
+                top: count(<TopNominations<T>>::get(candidate)),
+                bottom: count(<BottomNominations<T>>::get(candidate)),
+                cap: <CandidateNominatorCap<T>>::get(candidate),
+            })
+        }
+
+        /// The progress of growth `period`, if it has started
+        pub fn growth_status(period: GrowthPeriodIndex) -> Option<GrowthStatus<BalanceOf<T>>> {
+            if period > Self::growth_period_info().index {
+                return None
+            }
+
+            let growth = <Growth<T>>::contains_key(period).then(|| <Growth<T>>::get(period));
+            let accumulation = growth.as_ref().map(|growth| GrowthAccumulation {
+                start_era: <GrowthStartEra<T>>::get(period),
+                eras_accumulated: growth.number_of_accumulations,
+                eras_per_period: T::ErasPerGrowthPeriod::get(),
+                total_stake_accumulated: growth.total_stake_accumulated,
+                total_staker_reward: growth.total_staker_reward,
+                total_points: growth.total_points,
+            });
+
+            let is_paid = <ProcessedGrowthPeriods<T>>::contains_key(period);
+            let is_published_in = |tx_id: EthereumTransactionId| {
+                <PublishedGrowth<T>>::contains_key(tx_id) &&
+                    <PublishedGrowth<T>>::get(tx_id) == period
+            };
+            let trigger = match growth {
+                Some(GrowthInfo { tx_id: Some(tx_id), triggered, .. })
+                    if is_published_in(tx_id) =>
+                    GrowthTrigger::Published {
+                        tx_id,
+                        confirmation: match triggered {
+                            None => T1Confirmation::Pending,
+                            Some(true) => T1Confirmation::Succeeded,
+                            Some(false) => T1Confirmation::Failed,
+                        },
+                    },
+                // Periods with nothing to grow are marked with a transaction id of 0
+                Some(GrowthInfo { tx_id: Some(_), .. }) => GrowthTrigger::Skipped,
+                Some(_) => GrowthTrigger::NotTriggered,
+                // The growth data of a paid period is removed, but the growth was lifted so the
+                // transaction that triggered it succeeded
+                None => <PublishedGrowth<T>>::iter()
+                    .find(|(_, published_period)| *published_period == period)
+                    .filter(|_| is_paid)
+                    .map_or(GrowthTrigger::NotTriggered, |(tx_id, _)| GrowthTrigger::Published {
+                        tx_id,
+                        confirmation: T1Confirmation::Succeeded,
+                    }),
+            };
+
+            let payout = if is_paid {
+                GrowthPayout::Paid {
+                    missed_payments: <MissedGrowthPayments<T>>::iter_prefix(period).count() as u32,
+                }
+            } else {
+                GrowthPayout::NotPaid
+            };
+
+            Some(GrowthStatus { period, accumulation, trigger, payout })
+        }
+
         pub fn update_collator_payout(
             payout_era: EraIndex,
             total_staked: BalanceOf<T>,
@@ -2136,10 +3529,12 @@ pub mod pallet {
                 new_payout_info.collator_scores = current_collator_scores;
 
                 <Growth<T>>::insert(new_growth_period, new_payout_info);
+                <GrowthStartEra<T>>::insert(new_growth_period, payout_era);
 
                 Self::trigger_outstanding_growths(&(new_growth_period - 1));
             } else {
                 Self::accumulate_payout_for_period(
+                    payout_era,
                     collator_payout_period.index,
                     total_staked,
                     staking_reward_paid_in_era,
@@ -2159,6 +3554,7 @@ pub mod pallet {
         }
 
         fn accumulate_payout_for_period(
+            payout_era: EraIndex,
             growth_index: GrowthPeriodIndex,
             total_staked: BalanceOf<T>,
             staking_reward_paid_in_era: BalanceOf<T>,
@@ -2172,12 +3568,16 @@ pub mod pallet {
                 info.total_staker_reward =
                     info.total_staker_reward.saturating_add(staking_reward_paid_in_era);
                 info.total_points = info.total_points.saturating_add(total_points);
-                info.collator_scores =
-                    Self::update_collator_scores(&info.collator_scores, current_collator_scores);
+                info.collator_scores = Self::update_collator_scores(
+                    payout_era,
+                    &info.collator_scores,
+                    current_collator_scores,
+                );
             });
         }
 
         fn update_collator_scores(
+            payout_era: EraIndex,
             existing_collator_scores: &BoundedVec<CollatorScore<T::AccountId>, CollatorMaxScores>,
             current_collator_scores: BoundedVec<CollatorScore<T::AccountId>, CollatorMaxScores>,
         ) -> BoundedVec<CollatorScore<T::AccountId>, CollatorMaxScores> {
@@ -2195,7 +3595,8 @@ pub mod pallet {
                     .or_insert(new_score.points);
             });
 
-            return BoundedVec::truncate_from(
+            return Self::bound_collator_scores(
+                payout_era,
                 current_scores
                     .into_iter()
                     .map(|(acc, pts)| CollatorScore::new(acc, pts))
@@ -2203,6 +3604,125 @@ pub mod pallet {
             )
         }
 
+        /// Bounds the collator scores of an era to `CollatorMaxScores`. The scores that don't fit
+        /// are counted and reported, so operators can tell when the bound is reached.
+        pub(crate) fn bound_collator_scores(
+            era: EraIndex,
+            collator_scores: Vec<CollatorScore<T::AccountId>>,
+        ) -> BoundedVec<CollatorScore<T::AccountId>, CollatorMaxScores> {
+            let dropped = collator_scores.len().saturating_sub(CollatorMaxScores::get() as usize);
+            if dropped > 0 {
+                log::warn!(
+                    "Dropping {:?} collator scores of era {:?}: limit of {:?} reached",
+                    dropped,
+                    era,
+                    CollatorMaxScores::get()
+                );
+                <DroppedCollatorScores<T>>::mutate(|total| {
+                    *total = total.saturating_add(dropped as u64)
+                });
+                Self::deposit_event(Event::CollatorScoresDropped { era, dropped: dropped as u32 });
+            }
+
+            BoundedVec::truncate_from(collator_scores)
+        }
+
+        /// Compares `growth` with the totals recomputed from the staking data of `eras`, and sets
+        /// the values that don't match to the recomputed ones. A total is only recomputed if the
+        /// data of every era is still in storage.
+        fn audit_growth_record(
+            eras: sp_std::ops::Range<EraIndex>,
+            growth: &mut GrowthInfo<T::AccountId, BalanceOf<T>>,
+        ) -> Vec<GrowthDiscrepancy<BalanceOf<T>>> {
+            let mut discrepancies = Vec::new();
+
+            // The stake of an era is removed when the era is accumulated into its growth, so
+            // `total_stake_accumulated` can't be recomputed and is not audited.
+            let expected_reward = eras.clone().try_fold(BalanceOf::<T>::zero(), |total, era| {
+                <DelayedPayouts<T>>::get(era)
+                    .map(|payout| total.saturating_add(payout.total_staking_reward))
+            });
+            if let Some(expected) = expected_reward {
+                if expected != growth.total_staker_reward {
+                    discrepancies.push(GrowthDiscrepancy::TotalStakerReward {
+                        stored: growth.total_staker_reward,
+                        expected,
+                    });
+                    growth.total_staker_reward = expected;
+                }
+            }
+
+            // Awarded points are removed as collators are paid, so an era is only complete while
+            // its points add up to the total points of the era.
+            let expected_scores = eras.clone().try_fold(BTreeMap::new(), |mut scores, era| {
+                let era_points = <Points<T>>::get(era);
+                let mut awarded_points: RewardPoint = 0;
+                for (collator, points) in <AwardedPts<T>>::iter_prefix(era) {
+                    awarded_points = awarded_points.saturating_add(points);
+                    let score = scores.entry(collator).or_insert(0 as RewardPoint);
+                    *score = score.saturating_add(points);
+                }
+                (!era_points.is_zero() && awarded_points == era_points).then(|| scores)
+            });
+            if let Some(expected) = expected_scores {
+                let expected_points =
+                    expected.values().fold(0 as RewardPoint, |total, p| total.saturating_add(*p));
+                if expected_points != growth.total_points {
+                    discrepancies.push(GrowthDiscrepancy::TotalPoints {
+                        stored: growth.total_points,
+                        expected: expected_points,
+                    });
+                    growth.total_points = expected_points;
+                }
+
+                let stored = growth
+                    .collator_scores
+                    .iter()
+                    .map(|score| (score.collator.clone(), score.points))
+                    .collect::<BTreeMap<_, _>>();
+                if stored != expected {
+                    discrepancies.push(GrowthDiscrepancy::CollatorScores {
+                        stored_collators: stored.len() as u32,
+                        expected_collators: expected.len() as u32,
+                    });
+                    growth.collator_scores = BoundedVec::truncate_from(
+                        expected
+                            .into_iter()
+                            .map(|(collator, points)| CollatorScore::new(collator, points))
+                            .collect(),
+                    );
+                }
+            }
+
+            discrepancies
+        }
+
+        /// Leaves the collators that are no longer candidates out of `growth_info`, so their share
+        /// goes to the others. Nothing is left out if none of the collators is still a candidate.
+        fn redistribute_exited_collators_growth(
+            growth_period: GrowthPeriodIndex,
+            growth_info: &mut GrowthInfo<T::AccountId, BalanceOf<T>>,
+        ) {
+            let (remaining, exited): (Vec<_>, Vec<_>) = growth_info
+                .collator_scores
+                .iter()
+                .cloned()
+                .partition(|score| <CandidateInfo<T>>::contains_key(&score.collator));
+            if remaining.is_empty() || exited.is_empty() {
+                return
+            }
+
+            for score in exited {
+                growth_info.total_points = growth_info.total_points.saturating_sub(score.points);
+                Self::deposit_event(Event::ExitedCollatorGrowthRedistributed {
+                    account: score.collator,
+                    points: score.points,
+                    period: growth_period,
+                });
+            }
+            growth_info.collator_scores = BoundedVec::truncate_from(remaining);
+        }
+
         pub fn payout_collators(amount: BalanceOf<T>, growth_period: u32) -> DispatchResult {
             // The only validation we do is checking for replays, for everything else we trust T1.
             ensure!(
@@ -2211,42 +3731,75 @@ pub mod pallet {
             );
 
             let mut imbalance: PositiveImbalanceOf<T> = PositiveImbalanceOf::<T>::zero();
-            let mut pay =
-                |collator_address: T::AccountId, amount: BalanceOf<T>| -> DispatchResult {
-                    match T::Currency::deposit_into_existing(&collator_address, amount) {
-                        Ok(amount_paid) => {
-                            Self::deposit_event(Event::CollatorPaid {
-                                account: collator_address,
-                                amount: amount_paid.peek(),
-                                period: growth_period,
-                            });
-
-                            imbalance.subsume(amount_paid);
-                            return Ok(())
-                        },
-                        Err(e) => {
-                            log::error!(
-                                "💔💔 Error paying {:?} AVT to collator {:?}: {:?}",
-                                amount,
-                                collator_address,
-                                e
-                            );
-                            return Err(Error::<T>::ErrorPayingCollator.into())
-                        },
-                    }
-                };
+            let mut missed_amount: BalanceOf<T> = Zero::zero();
+            let mut collators_paid: u32 = 0;
+            let mut missed_payments: u32 = 0;
+            // A collator that can't be paid must not block the others, so its payment is kept
+            // for it to claim later.
+            let mut pay = |collator_address: T::AccountId, amount: BalanceOf<T>| {
+                match T::Currency::deposit_into_existing(&collator_address, amount) {
+                    Ok(amount_paid) => {
+                        Self::deposit_event(Event::CollatorPaid {
+                            account: collator_address,
+                            amount: amount_paid.peek(),
+                            period: growth_period,
+                        });
+
+                        imbalance.subsume(amount_paid);
+                        collators_paid = collators_paid.saturating_add(1);
+                    },
+                    Err(e) => {
+                        log::error!(
+                            "💔💔 Error paying {:?} AVT to collator {:?}: {:?}",
+                            amount,
+                            collator_address,
+                            e
+                        );
+                        <MissedGrowthPayments<T>>::mutate(
+                            growth_period,
+                            &collator_address,
+                            |missed| {
+                                *missed = Some(missed.unwrap_or_default().saturating_add(amount))
+                            },
+                        );
+                        missed_amount = missed_amount.saturating_add(amount);
+                        missed_payments = missed_payments.saturating_add(1);
+                        Self::deposit_event(Event::GrowthPaymentMissed {
+                            account: collator_address,
+                            amount,
+                            period: growth_period,
+                        });
+                    },
+                }
+            };
 
             if <Growth<T>>::contains_key(growth_period) {
                 // get the list of candidates that earned points from `growth_period`
-                let growth_info = <Growth<T>>::get(growth_period);
+                let mut growth_info = <Growth<T>>::get(growth_period);
+                let distribution = Self::growth_distribution();
+                if Self::exited_collator_growth() == ExitedCollatorGrowthPolicy::Redistribute {
+                    Self::redistribute_exited_collators_growth(growth_period, &mut growth_info);
+                }
+                let number_of_collators = growth_info.collator_scores.len() as u32;
                 for collator_data in growth_info.collator_scores {
-                    let percent =
-                        Perbill::from_rational(collator_data.points, growth_info.total_points);
-                    pay(collator_data.collator, percent * amount)?;
+                    let collator_amount = distribution.collator_share(
+                        amount,
+                        collator_data.points,
+                        growth_info.total_points,
+                        number_of_collators,
+                    );
+                    pay(collator_data.collator, collator_amount);
                 }
 
+                Self::deposit_event(Event::GrowthDistributed {
+                    period: growth_period,
+                    amount,
+                    distribution,
+                });
+
                 // Tidy up state
                 <Growth<T>>::remove(growth_period);
+                <GrowthStartEra<T>>::remove(growth_period);
                 <ProcessedGrowthPeriods<T>>::insert(growth_period, ());
             } else {
                 // use current candidates because there is no way of knowing who they were
@@ -2254,26 +3807,71 @@ pub mod pallet {
                 let number_of_collators = collators.len() as u32;
                 for collator in collators.into_iter() {
                     let percent = Perbill::from_rational(1u32, number_of_collators);
-                    pay(collator, percent * amount)?;
+                    pay(collator, percent * amount);
                 }
 
                 <ProcessedGrowthPeriods<T>>::insert(growth_period, ());
             }
 
             // Let the runtime know that we finished paying collators and we may have some amount
-            // left.
-            let dust_amount: BalanceOf<T> = amount.saturating_sub(imbalance.peek());
+            // left. Missed payments are not dust, they are still owed to their collators.
+            let dust_amount: BalanceOf<T> =
+                amount.saturating_sub(imbalance.peek()).saturating_sub(missed_amount);
+
+            Self::record_payout_statement(
+                growth_period,
+                CollatorPayoutStatement {
+                    amount,
+                    collators_paid,
+                    amount_paid: imbalance.peek(),
+                    missed_payments,
+                    amount_missed: missed_amount,
+                    dust: dust_amount,
+                },
+            );
 
             // drop the imbalance to increase total issuance
             drop(imbalance);
 
             if dust_amount > BalanceOf::<T>::zero() {
-                T::CollatorPayoutDustHandler::handle_dust(dust_amount);
+                T::CollatorPayoutDustHandler::handle_dust(dust_amount, growth_period);
             }
 
             Ok(())
         }
 
+        fn record_payout_statement(
+            growth_period: GrowthPeriodIndex,
+            statement: CollatorPayoutStatement<BalanceOf<T>>,
+        ) {
+            <CollatorPayoutStatements<T>>::insert(growth_period, statement.clone());
+            if let Some(expired_period) = growth_period.checked_sub(PAYOUT_STATEMENTS_DEPTH) {
+                <CollatorPayoutStatements<T>>::remove(expired_period);
+            }
+
+            Self::deposit_event(Event::CollatorPayoutCompleted {
+                period: growth_period,
+                statement,
+            });
+        }
+
+        /// Splits `amount` equally between `number_of_targets`. Returns the amount for each target
+        /// and the dust left over by the division, so that the parts always add up to `amount`.
+        pub fn split_amount(
+            amount: BalanceOf<T>,
+            number_of_targets: u32,
+        ) -> Result<(BalanceOf<T>, BalanceOf<T>), Error<T>> {
+            let number_of_targets: BalanceOf<T> = number_of_targets.into();
+            let amount_per_target =
+                amount.checked_div(&number_of_targets).ok_or(Error::<T>::NoNominationTargets)?;
+            let dust = amount_per_target
+                .checked_mul(&number_of_targets)
+                .and_then(|split_amount| amount.checked_sub(&split_amount))
+                .ok_or(Error::<T>::Overflow)?;
+
+            Ok((amount_per_target, dust))
+        }
+
         pub fn collator_should_get_dust(
             dust: BalanceOf<T>,
             number_of_collators: u64,
@@ -2360,8 +3958,7 @@ pub mod pallet {
                 nomination_count = nominator_state.nominations.0.len() as u32;
             }
 
-            let amount_per_collator = Perbill::from_rational(1, num_collators) * amount;
-            let dust = amount.saturating_sub(amount_per_collator * num_collators.into());
+            let (amount_per_collator, dust) = Self::split_amount(amount, num_collators)?;
             let mut remaining_amount_to_nominate = amount;
 
             // This is only possible because we won't have more than 20 collators. If that changes,
@@ -2373,7 +3970,8 @@ pub mod pallet {
 
                 let mut actual_amount = amount_per_collator;
                 if Self::collator_should_get_dust(dust, num_collators.into(), index as u64) {
-                    actual_amount = amount_per_collator + dust;
+                    actual_amount =
+                        amount_per_collator.checked_add(&dust).ok_or(Error::<T>::Overflow)?;
                 }
 
                 // make sure we don't nominate more than what the user asked
@@ -2387,7 +3985,9 @@ pub mod pallet {
                     nomination_count,
                 )?;
 
-                remaining_amount_to_nominate -= actual_amount;
+                remaining_amount_to_nominate = remaining_amount_to_nominate
+                    .checked_sub(&actual_amount)
+                    .ok_or(Error::<T>::Overflow)?;
                 nomination_count += 1;
             }
 
@@ -2505,6 +4105,41 @@ pub mod pallet {
             return Self::payout_collators(amount, growth_period)
         }
     }
+
+    /// Transaction fees sent to the staking pot are added to the reward pot and counted for the
+    /// current era. The total is reported once, when the era ends, rather than in every block.
+    impl<T: Config> OnUnbalanced<NegativeImbalanceOf<T>> for Pallet<T> {
+        fn on_nonzero_unbalanced(amount: NegativeImbalanceOf<T>) {
+            let era = <Era<T>>::get().current;
+            <EraFeesToRewardPot<T>>::mutate(era, |fees| *fees = fees.saturating_add(amount.peek()));
+            T::Currency::resolve_creating(&Self::compute_reward_pot_account_id(), amount);
+        }
+    }
+}
+
+/// The proxy nonce of a reaped account is removed. Its epoch is kept and bumped, so the payloads
+/// signed before the account was reaped don't become valid again.
+impl<T: Config> OnKilledAccount<T::AccountId> for Pallet<T> {
+    fn on_killed_account(who: &T::AccountId) {
+        if <ProxyNonces<T>>::contains_key(who) {
+            <ProxyNonces<T>>::remove(who);
+            <ProxyNonceEpochs<T>>::mutate(who, |epoch| *epoch = epoch.saturating_add(1));
+        }
+    }
+}
+
+impl<T: Config> OffenderChiller<T::AccountId> for Pallet<T> {
+    fn chill(offender: &T::AccountId) -> bool {
+        Self::take_candidate_offline(offender).is_ok()
+    }
+
+    fn unchill(offender: &T::AccountId) -> DispatchResult {
+        match Self::bring_candidate_online(offender) {
+            // The offender may have been brought back online by other means
+            Err(e) if e == Error::<T>::AlreadyActive.into() => Ok(()),
+            result => result,
+        }
+    }
 }
 
 impl<T: Config> BridgeInterfaceNotification for Pallet<T> {
@@ -2518,3 +4153,20 @@ impl<T: Config> BridgeInterfaceNotification for Pallet<T> {
         Ok(())
     }
 }
+
+impl<T: Config> pallet_avn::VotingParticipationHandler<T::AccountId> for Pallet<T> {
+    fn on_vote_cast(voter: &T::AccountId) {
+        <VotingParticipation<T>>::mutate(<Era<T>>::get().current, voter, |votes| {
+            *votes = votes.saturating_add(1)
+        });
+    }
+}
+
+/// The backing stake of a validator is the total of its `AtStake` snapshot for the current era
+impl<T: Config> pallet_avn::ValidatorStakeProvider<T::AccountId> for Pallet<T> {
+    fn backing_stake(validator: &T::AccountId) -> u128 {
+        sp_runtime::SaturatedConversion::saturated_into::<u128>(
+            <AtStake<T>>::get(<Era<T>>::get().current, validator).total,
+        )
+    }
+}
@@ -0,0 +1,110 @@
+use crate::{CandidateInfo, Config, Era, NominationScheduledRequests, Pallet};
+use frame_support::{
+    pallet_prelude::PhantomData,
+    traits::{Get, GetStorageVersion, OnRuntimeUpgrade, StorageVersion},
+    weights::Weight,
+};
+use pallet_avn::migration::SteppedMigration;
+use sp_std::vec::Vec;
+
+#[cfg(feature = "try-runtime")]
+use codec::{Decode, Encode};
+#[cfg(feature = "try-runtime")]
+use sp_runtime::TryRuntimeError;
+
+/// Migrations run over several blocks by the pallet hooks
+pub type SteppedMigrations<T> = (RemoveExitedCandidatesRequests<T>,);
+
+/// Removes the empty nomination requests left behind by candidates that have exited. The cursor is
+/// the raw storage key of the last candidate checked.
+pub struct RemoveExitedCandidatesRequests<T>(PhantomData<T>);
+impl<T: Config> SteppedMigration for RemoveExitedCandidatesRequests<T> {
+    type Pallet = Pallet<T>;
+    type Cursor = Vec<u8>;
+
+    const FROM: u16 = 4;
+    const TO: u16 = 5;
+
+    fn step(cursor: Option<Vec<u8>>, limit: Weight) -> (Weight, Option<Vec<u8>>) {
+        let weight_per_candidate = T::DbWeight::get().reads_writes(2, 1);
+        let mut used = Weight::zero();
+
+        let mut candidates = match cursor {
+            Some(last_key) => NominationScheduledRequests::<T>::iter_keys_from(last_key),
+            None => NominationScheduledRequests::<T>::iter_keys(),
+        };
+
+        loop {
+            if used.saturating_add(weight_per_candidate).any_gt(limit) {
+                return (used, Some(candidates.last_raw_key().to_vec()))
+            }
+
+            let candidate = match candidates.next() {
+                Some(candidate) => candidate,
+                None => return (used, None),
+            };
+            used = used.saturating_add(weight_per_candidate);
+
+            if !CandidateInfo::<T>::contains_key(&candidate) &&
+                NominationScheduledRequests::<T>::get(&candidate).is_empty()
+            {
+                NominationScheduledRequests::<T>::remove(&candidate);
+            }
+        }
+    }
+}
+
+/// Scales the era length by `Factor`, to keep its duration when the block time is divided by
+/// `Factor`. The era in progress is extended as well.
+pub struct ScaleEraLength<T, Factor>(PhantomData<(T, Factor)>);
+impl<T: Config, Factor: Get<u32>> OnRuntimeUpgrade for ScaleEraLength<T, Factor> {
+    fn on_runtime_upgrade() -> Weight {
+        let current = Pallet::<T>::current_storage_version();
+        let onchain = Pallet::<T>::on_chain_storage_version();
+
+        log::info!(
+            "ℹ️  Staking `ScaleEraLength` invoked with current storage version {:?} / onchain {:?}",
+            current,
+            onchain
+        );
+
+        if onchain == 3 {
+            return scale_era_length::<T>(Factor::get())
+        }
+
+        T::DbWeight::get().reads(1)
+    }
+
+    #[cfg(feature = "try-runtime")]
+    fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+        Ok((Pallet::<T>::on_chain_storage_version(), Era::<T>::get().length).encode())
+    }
+
+    #[cfg(feature = "try-runtime")]
+    fn post_upgrade(state: Vec<u8>) -> Result<(), TryRuntimeError> {
+        let (onchain, era_length): (StorageVersion, u32) =
+            Decode::decode(&mut &state[..]).map_err(|_| "Invalid pre upgrade state")?;
+
+        if onchain == 3 {
+            frame_support::ensure!(
+                Era::<T>::get().length == era_length.saturating_mul(Factor::get()),
+                "Era length not scaled"
+            );
+        }
+
+        Ok(())
+    }
+}
+
+pub fn scale_era_length<T: Config>(scaling_factor: u32) -> Weight {
+    Era::<T>::mutate(|era| era.length = era.length.saturating_mul(scaling_factor));
+    StorageVersion::new(4).put::<Pallet<T>>();
+
+    log::info!(
+        "✅ Era length scaled by {:?}, it is now {:?} blocks",
+        scaling_factor,
+        Era::<T>::get().length
+    );
+
+    T::DbWeight::get().reads_writes(2, 2)
+}
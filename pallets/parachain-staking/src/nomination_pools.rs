@@ -0,0 +1,453 @@
+// Copyright 2024 Aventus Network Services (UK) Ltd.
+
+//! Nomination pools, for holders with less than `MinTotalNominatorStake`.
+//!
+//! The members of a pool transfer their stake to the pool account, which nominates the collator of
+//! the pool with the stake of all its members. A member has one point per unit of stake it has
+//! bonded. The staking rewards paid to the pool account are shared between the members pro rata
+//! to their points and can be claimed at any time.
+//!
+//! The pool account can only have one scheduled request towards its collator, so the stake members
+//! unbond while a decrease of the pool is pending is queued and scheduled once that decrease is
+//! executed. A pending decrease is never rescheduled, so one member unbonding doesn't delay the
+//! others. Members withdraw their unbonded stake once the decrease that includes it has been
+//! executed. The minimum total nominator stake doesn't apply to pool accounts.
+
+use crate::{
+    BalanceOf, CandidateInfo, Config, Delay, Era, EraIndex, Error, Event, MinTotalNominatorStake,
+    NextPoolId, NominationPools, NominationScheduledRequests, NominatorState, Pallet, PoolMembers,
+};
+use codec::{Decode, Encode, MaxEncodedLen};
+use frame_support::{
+    dispatch::{DispatchErrorWithPostInfo, DispatchResultWithPostInfo},
+    ensure,
+    traits::{Currency, ExistenceRequirement, Get},
+    PalletId,
+};
+use scale_info::TypeInfo;
+use sp_runtime::{
+    traits::{AccountIdConversion, Saturating, Zero},
+    FixedPointNumber, FixedU128, RuntimeDebug, SaturatedConversion,
+};
+
+pub type PoolId = u32;
+
+/// Prefix of the sub accounts of the reward pot used as pool accounts
+const POOL_ACCOUNT_PREFIX: &[u8; 4] = b"pool";
+
+/// A pool of accounts nominating a collator together
+#[derive(Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct NominationPool<AccountId, Balance> {
+    /// The account that created the pool
+    pub depositor: AccountId,
+    /// The collator nominated by the pool account
+    pub collator: AccountId,
+    /// Total points of the members, equal to the stake they have bonded
+    pub points: Balance,
+    /// Stake of the members that are unbonding or have not withdrawn their unbonded stake yet
+    pub unbonding: Balance,
+    /// Stake unbonded while a decrease of the pool was pending, to decrease once it is executed
+    pub queued_decrease: Balance,
+    /// Rewards accounted for in `reward_per_point` that the members have not claimed yet
+    pub unclaimed_rewards: Balance,
+    /// Rewards earned by a point since the pool was created
+    pub reward_per_point: FixedU128,
+}
+
+/// Stake a member is unbonding from a pool
+#[derive(Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct PoolUnbonding<Balance> {
+    pub amount: Balance,
+    /// The era from which the member can withdraw the stake
+    pub withdrawable_era: EraIndex,
+}
+
+/// The stake of an account in a pool
+#[derive(Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct PoolMember<Balance> {
+    pub points: Balance,
+    /// `reward_per_point` of the pool the last time the rewards of the member were paid
+    pub reward_per_point_paid: FixedU128,
+    pub unbonding: Option<PoolUnbonding<Balance>>,
+}
+
+impl<Balance: Zero> PoolMember<Balance> {
+    fn new(reward_per_point: FixedU128) -> Self {
+        PoolMember {
+            points: Zero::zero(),
+            reward_per_point_paid: reward_per_point,
+            unbonding: None,
+        }
+    }
+}
+
+impl<T: Config> Pallet<T> {
+    /// The account that holds the stake of a pool and nominates its collator
+    pub fn pool_account_id(pool_id: PoolId) -> T::AccountId {
+        T::RewardPotId::get().into_sub_account_truncating((POOL_ACCOUNT_PREFIX, pool_id))
+    }
+
+    pub(crate) fn is_pool_account(account: &T::AccountId) -> bool {
+        PalletId::try_from_sub_account::<([u8; 4], PoolId)>(account).map_or(
+            false,
+            |(pallet_id, (prefix, _))| {
+                pallet_id == T::RewardPotId::get() && &prefix == POOL_ACCOUNT_PREFIX
+            },
+        )
+    }
+
+    pub(crate) fn create_pool(
+        depositor: T::AccountId,
+        collator: T::AccountId,
+        amount: BalanceOf<T>,
+    ) -> DispatchResultWithPostInfo {
+        ensure!(amount >= <MinTotalNominatorStake<T>>::get(), Error::<T>::NominatorBondBelowMin);
+        let candidate_nomination_count = <CandidateInfo<T>>::get(&collator)
+            .ok_or(Error::<T>::CandidateDNE)?
+            .nomination_count;
+
+        let pool_id = <NextPoolId<T>>::get();
+        let pool_account = Self::pool_account_id(pool_id);
+        // The pool account keeps the existential deposit so it is never reaped
+        T::Currency::transfer(
+            &depositor,
+            &pool_account,
+            amount.saturating_add(T::Currency::minimum_balance()),
+            ExistenceRequirement::KeepAlive,
+        )?;
+        Self::call_nominate(
+            &pool_account,
+            collator.clone(),
+            amount,
+            candidate_nomination_count,
+            0,
+        )?;
+
+        let pool = NominationPool {
+            depositor: depositor.clone(),
+            collator: collator.clone(),
+            points: amount,
+            unbonding: Zero::zero(),
+            queued_decrease: Zero::zero(),
+            unclaimed_rewards: Zero::zero(),
+            reward_per_point: FixedU128::zero(),
+        };
+        let mut member = PoolMember::new(pool.reward_per_point);
+        member.points = amount;
+
+        <NominationPools<T>>::insert(pool_id, pool);
+        <PoolMembers<T>>::insert(pool_id, &depositor, member);
+        <NextPoolId<T>>::put(pool_id.saturating_add(1));
+
+        Self::deposit_event(Event::NominationPoolCreated {
+            pool_id,
+            depositor,
+            collator,
+            pool_account,
+            amount,
+        });
+        Ok(().into())
+    }
+
+    pub(crate) fn join_pool(
+        account: T::AccountId,
+        pool_id: PoolId,
+        amount: BalanceOf<T>,
+    ) -> DispatchResultWithPostInfo {
+        ensure!(!<PoolMembers<T>>::contains_key(pool_id, &account), Error::<T>::AlreadyPoolMember);
+        let pool = <NominationPools<T>>::get(pool_id).ok_or(Error::<T>::NominationPoolDNE)?;
+
+        Self::bond_to_pool(
+            account,
+            pool_id,
+            pool.clone(),
+            PoolMember::new(pool.reward_per_point),
+            amount,
+        )
+    }
+
+    pub(crate) fn bond_extra_to_pool(
+        account: T::AccountId,
+        pool_id: PoolId,
+        amount: BalanceOf<T>,
+    ) -> DispatchResultWithPostInfo {
+        let pool = <NominationPools<T>>::get(pool_id).ok_or(Error::<T>::NominationPoolDNE)?;
+        let member = <PoolMembers<T>>::get(pool_id, &account).ok_or(Error::<T>::PoolMemberDNE)?;
+
+        Self::bond_to_pool(account, pool_id, pool, member, amount)
+    }
+
+    fn bond_to_pool(
+        account: T::AccountId,
+        pool_id: PoolId,
+        mut pool: NominationPool<T::AccountId, BalanceOf<T>>,
+        mut member: PoolMember<BalanceOf<T>>,
+        amount: BalanceOf<T>,
+    ) -> DispatchResultWithPostInfo {
+        ensure!(amount >= T::MinNominationPerCollator::get(), Error::<T>::NominationBelowMin);
+        let pool_account = Self::pool_account_id(pool_id);
+        ensure!(
+            Self::pool_nomination(&pool_account, &pool).is_some() &&
+                !Self::nomination_request_revoke_exists(&pool.collator, &pool_account),
+            Error::<T>::NominationPoolClosed
+        );
+
+        // Points change, so the member is paid what it earned with its current points first
+        Self::update_pool_rewards(&pool_account, &mut pool);
+        Self::pay_pool_member_rewards(pool_id, &pool_account, &mut pool, &account, &mut member)?;
+
+        T::Currency::transfer(&account, &pool_account, amount, ExistenceRequirement::KeepAlive)?;
+        Self::call_bond_extra(&pool_account, pool.collator.clone(), amount)?;
+
+        member.points = member.points.saturating_add(amount);
+        pool.points = pool.points.saturating_add(amount);
+        <PoolMembers<T>>::insert(pool_id, &account, member);
+        <NominationPools<T>>::insert(pool_id, pool);
+
+        Self::deposit_event(Event::PoolMemberBonded { pool_id, member: account, amount });
+        Ok(().into())
+    }
+
+    pub(crate) fn unbond_from_pool(
+        account: T::AccountId,
+        pool_id: PoolId,
+        amount: BalanceOf<T>,
+    ) -> DispatchResultWithPostInfo {
+        let mut pool = <NominationPools<T>>::get(pool_id).ok_or(Error::<T>::NominationPoolDNE)?;
+        let mut member =
+            <PoolMembers<T>>::get(pool_id, &account).ok_or(Error::<T>::PoolMemberDNE)?;
+        ensure!(!amount.is_zero() && amount <= member.points, Error::<T>::PoolUnbondExceedsBond);
+
+        let pool_account = Self::pool_account_id(pool_id);
+        Self::update_pool_rewards(&pool_account, &mut pool);
+        Self::pay_pool_member_rewards(pool_id, &pool_account, &mut pool, &account, &mut member)?;
+
+        let withdrawable_era = Self::schedule_pool_decrease(&pool_account, &mut pool, amount)?;
+
+        let unbonding_amount = member
+            .unbonding
+            .as_ref()
+            .map_or(amount, |unbonding| unbonding.amount.saturating_add(amount));
+        member.unbonding = Some(PoolUnbonding { amount: unbonding_amount, withdrawable_era });
+        member.points = member.points.saturating_sub(amount);
+        pool.points = pool.points.saturating_sub(amount);
+        pool.unbonding = pool.unbonding.saturating_add(amount);
+        <PoolMembers<T>>::insert(pool_id, &account, member);
+        <NominationPools<T>>::insert(pool_id, pool);
+
+        Self::deposit_event(Event::PoolMemberUnbonded {
+            pool_id,
+            member: account,
+            amount,
+            withdrawable_era,
+        });
+        Ok(().into())
+    }
+
+    /// Decreases the nomination of the pool account by `amount` and returns the era from which
+    /// the stake can be withdrawn. While a decrease of the pool is pending, the amount is queued
+    /// until that decrease is executed, so the stake can be withdrawn one delay after it.
+    fn schedule_pool_decrease(
+        pool_account: &T::AccountId,
+        pool: &mut NominationPool<T::AccountId, BalanceOf<T>>,
+        amount: BalanceOf<T>,
+    ) -> Result<EraIndex, DispatchErrorWithPostInfo> {
+        let delay = <Delay<T>>::get();
+        pool.queued_decrease = pool.queued_decrease.saturating_add(amount);
+
+        let pending_request = <NominationScheduledRequests<T>>::get(&pool.collator)
+            .into_iter()
+            .find(|request| &request.nominator == pool_account);
+        match pending_request {
+            Some(request) => Ok(request.when_executable.saturating_add(delay)),
+            None => {
+                Self::schedule_queued_pool_decrease(pool_account, pool)?;
+                Ok(<Era<T>>::get().current.saturating_add(delay))
+            },
+        }
+    }
+
+    /// Schedules the decrease of the stake queued in the pool. The nomination is revoked when no
+    /// stake is left in the pool. There is nothing to schedule when the nomination has already
+    /// been removed, for instance because the collator left.
+    fn schedule_queued_pool_decrease(
+        pool_account: &T::AccountId,
+        pool: &mut NominationPool<T::AccountId, BalanceOf<T>>,
+    ) -> DispatchResultWithPostInfo {
+        if pool.queued_decrease.is_zero() {
+            return Ok(().into())
+        }
+
+        if let Some(bond) = Self::pool_nomination(pool_account, pool) {
+            if pool.queued_decrease >= bond {
+                Self::nomination_schedule_revoke(pool.collator.clone(), pool_account.clone())?;
+            } else {
+                Self::nomination_schedule_bond_decrease(
+                    pool.collator.clone(),
+                    pool_account.clone(),
+                    pool.queued_decrease,
+                )?;
+            }
+        }
+
+        pool.queued_decrease = Zero::zero();
+        Ok(().into())
+    }
+
+    pub(crate) fn withdraw_unbonded_from_pool(
+        account: T::AccountId,
+        pool_id: PoolId,
+    ) -> DispatchResultWithPostInfo {
+        let mut pool = <NominationPools<T>>::get(pool_id).ok_or(Error::<T>::NominationPoolDNE)?;
+        let mut member =
+            <PoolMembers<T>>::get(pool_id, &account).ok_or(Error::<T>::PoolMemberDNE)?;
+        let unbonding = member.unbonding.take().ok_or(Error::<T>::NoPoolUnbonding)?;
+        ensure!(
+            unbonding.withdrawable_era <= <Era<T>>::get().current,
+            Error::<T>::PendingNominationRequestNotDueYet
+        );
+
+        let pool_account = Self::pool_account_id(pool_id);
+        let pending_request = <NominationScheduledRequests<T>>::get(&pool.collator)
+            .into_iter()
+            .find(|request| request.nominator == pool_account);
+        match pending_request {
+            // The stake of the member may have been released by an earlier decrease
+            Some(request) if request.when_executable > <Era<T>>::get().current => (),
+            Some(_) => {
+                Self::nomination_execute_scheduled_request(
+                    pool.collator.clone(),
+                    pool_account.clone(),
+                )?;
+                // The stake unbonded while the executed decrease was pending can now be decreased
+                Self::schedule_queued_pool_decrease(&pool_account, &mut pool)?;
+            },
+            None => {
+                Self::schedule_queued_pool_decrease(&pool_account, &mut pool)?;
+            },
+        }
+
+        T::Currency::transfer(
+            &pool_account,
+            &account,
+            unbonding.amount,
+            ExistenceRequirement::KeepAlive,
+        )?;
+
+        pool.unbonding = pool.unbonding.saturating_sub(unbonding.amount);
+        <NominationPools<T>>::insert(pool_id, pool);
+        if member.points.is_zero() {
+            <PoolMembers<T>>::remove(pool_id, &account);
+        } else {
+            <PoolMembers<T>>::insert(pool_id, &account, member);
+        }
+
+        Self::deposit_event(Event::PoolMemberWithdrawn {
+            pool_id,
+            member: account,
+            amount: unbonding.amount,
+        });
+        Ok(().into())
+    }
+
+    pub(crate) fn claim_rewards_from_pool(
+        account: T::AccountId,
+        pool_id: PoolId,
+    ) -> DispatchResultWithPostInfo {
+        let mut pool = <NominationPools<T>>::get(pool_id).ok_or(Error::<T>::NominationPoolDNE)?;
+        let mut member =
+            <PoolMembers<T>>::get(pool_id, &account).ok_or(Error::<T>::PoolMemberDNE)?;
+
+        let pool_account = Self::pool_account_id(pool_id);
+        Self::update_pool_rewards(&pool_account, &mut pool);
+        Self::pay_pool_member_rewards(pool_id, &pool_account, &mut pool, &account, &mut member)?;
+
+        <PoolMembers<T>>::insert(pool_id, &account, member);
+        <NominationPools<T>>::insert(pool_id, pool);
+        Ok(().into())
+    }
+
+    /// The rewards a member can claim from a pool
+    pub fn pending_pool_rewards(pool_id: PoolId, account: &T::AccountId) -> BalanceOf<T> {
+        match (<NominationPools<T>>::get(pool_id), <PoolMembers<T>>::get(pool_id, account)) {
+            (Some(mut pool), Some(member)) => {
+                Self::update_pool_rewards(&Self::pool_account_id(pool_id), &mut pool);
+                Self::member_rewards(&pool, &member)
+            },
+            _ => Zero::zero(),
+        }
+    }
+
+    fn pool_nomination(
+        pool_account: &T::AccountId,
+        pool: &NominationPool<T::AccountId, BalanceOf<T>>,
+    ) -> Option<BalanceOf<T>> {
+        <NominatorState<T>>::get(pool_account)
+            .and_then(|state| state.get_bond_amount(&pool.collator))
+    }
+
+    /// Shares the rewards paid to the pool account since the last update between the points of
+    /// the pool. Everything the pool account holds on top of its existential deposit, the stake of
+    /// the members and the unclaimed rewards is a new reward.
+    fn update_pool_rewards(
+        pool_account: &T::AccountId,
+        pool: &mut NominationPool<T::AccountId, BalanceOf<T>>,
+    ) {
+        if pool.points.is_zero() {
+            return
+        }
+
+        let rewards = T::Currency::free_balance(pool_account)
+            .saturating_sub(T::Currency::minimum_balance())
+            .saturating_sub(pool.points)
+            .saturating_sub(pool.unbonding);
+        let new_rewards = rewards.saturating_sub(pool.unclaimed_rewards);
+        if new_rewards.is_zero() {
+            return
+        }
+
+        pool.reward_per_point =
+            pool.reward_per_point.saturating_add(FixedU128::saturating_from_rational(
+                new_rewards.saturated_into::<u128>(),
+                pool.points.saturated_into::<u128>(),
+            ));
+        pool.unclaimed_rewards = rewards;
+    }
+
+    fn member_rewards(
+        pool: &NominationPool<T::AccountId, BalanceOf<T>>,
+        member: &PoolMember<BalanceOf<T>>,
+    ) -> BalanceOf<T> {
+        let rewards: BalanceOf<T> = pool
+            .reward_per_point
+            .saturating_sub(member.reward_per_point_paid)
+            .saturating_mul_int(member.points.saturated_into::<u128>())
+            .saturated_into();
+        // Rounding must never let members claim more than the pool has received
+        rewards.min(pool.unclaimed_rewards)
+    }
+
+    fn pay_pool_member_rewards(
+        pool_id: PoolId,
+        pool_account: &T::AccountId,
+        pool: &mut NominationPool<T::AccountId, BalanceOf<T>>,
+        account: &T::AccountId,
+        member: &mut PoolMember<BalanceOf<T>>,
+    ) -> DispatchResultWithPostInfo {
+        let rewards = Self::member_rewards(pool, member);
+        member.reward_per_point_paid = pool.reward_per_point;
+        if rewards.is_zero() {
+            return Ok(().into())
+        }
+
+        T::Currency::transfer(pool_account, account, rewards, ExistenceRequirement::KeepAlive)?;
+        pool.unclaimed_rewards = pool.unclaimed_rewards.saturating_sub(rewards);
+
+        Self::deposit_event(Event::PoolRewardsClaimed {
+            pool_id,
+            member: account.clone(),
+            amount: rewards,
+        });
+        Ok(().into())
+    }
+}
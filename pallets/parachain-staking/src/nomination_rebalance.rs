@@ -0,0 +1,86 @@
+// Copyright 2024 Aventus Network Services (UK) Ltd.
+
+//! Rebalancing of the top and bottom nominations of the candidates.
+//!
+//! A bottom nomination is only promoted when a top nomination leaves, so after
+//! `MaxTopNominationsPerCandidate` is raised the extra room in the top nominations stays unused
+//! until nominators churn. Anyone can promote the bottom nominations of a candidate with
+//! `rebalance_nominations_for`, and every candidate is swept in `on_idle` when the maximum differs
+//! from the one the candidates were last rebalanced for.
+
+use crate::{
+    weights::WeightInfo, CandidateInfo, Config, Error, Event, NominationsRebalancedFor, Pallet,
+    PendingNominationRebalances,
+};
+use frame_support::{traits::Get, weights::Weight, BoundedVec};
+use sp_runtime::{traits::Saturating, DispatchError};
+use sp_std::prelude::*;
+
+impl<T: Config> Pallet<T> {
+    /// Promotes the bottom nominations of `candidate` while there is room in its top nominations.
+    /// Returns the number of promoted nominations.
+    pub(crate) fn rebalance_candidate_nominations(
+        candidate: &T::AccountId,
+    ) -> Result<u32, DispatchError> {
+        let mut state = <CandidateInfo<T>>::get(candidate).ok_or(Error::<T>::CandidateDNE)?;
+
+        let mut promoted: u32 = 0;
+        while let Some(nomination) = state.promote_highest_bottom_nomination::<T>(candidate) {
+            promoted.saturating_inc();
+            Self::deposit_event(Event::NominationPromoted {
+                nominator: nomination.owner,
+                candidate: candidate.clone(),
+                amount: nomination.amount,
+            });
+        }
+
+        if promoted > 0 {
+            <CandidateInfo<T>>::insert(candidate, state);
+        }
+
+        Ok(promoted)
+    }
+
+    /// Rebalances the candidates left in the current sweep while `remaining_weight` allows it.
+    /// A new sweep of all the candidates starts when `MaxTopNominationsPerCandidate` has changed
+    /// since the last one.
+    pub(crate) fn rebalance_nominations_on_idle(remaining_weight: Weight) -> Weight {
+        let db_weight = T::DbWeight::get();
+        let mut consumed = db_weight.reads_writes(2, 1);
+        if remaining_weight.any_lt(consumed) {
+            return Weight::zero()
+        }
+
+        let mut pending = <PendingNominationRebalances<T>>::get().into_inner();
+        if pending.is_empty() {
+            let max_top_nominations = T::MaxTopNominationsPerCandidate::get();
+            if <NominationsRebalancedFor<T>>::get() == max_top_nominations {
+                return db_weight.reads(2)
+            }
+
+            let start_sweep_weight = db_weight.reads_writes(T::MaxCandidates::get().into(), 1);
+            if remaining_weight.any_lt(consumed.saturating_add(start_sweep_weight)) {
+                return db_weight.reads(2)
+            }
+
+            pending =
+                <CandidateInfo<T>>::iter_keys().take(T::MaxCandidates::get() as usize).collect();
+            <NominationsRebalancedFor<T>>::put(max_top_nominations);
+            consumed = consumed.saturating_add(start_sweep_weight);
+        }
+
+        let max_rebalance_weight =
+            T::WeightInfo::rebalance_nominations_for(T::MaxBottomNominationsPerCandidate::get());
+        while !pending.is_empty() &&
+            remaining_weight.all_gte(consumed.saturating_add(max_rebalance_weight))
+        {
+            let candidate = pending.remove(0);
+            // The candidate may have left since the sweep started
+            let promoted = Self::rebalance_candidate_nominations(&candidate).unwrap_or_default();
+            consumed = consumed.saturating_add(T::WeightInfo::rebalance_nominations_for(promoted));
+        }
+
+        <PendingNominationRebalances<T>>::put(BoundedVec::truncate_from(pending));
+        consumed
+    }
+}
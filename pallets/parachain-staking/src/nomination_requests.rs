@@ -17,7 +17,7 @@
 
 use crate::{
     BalanceOf, CandidateInfo, Config, Delay, Era, EraIndex, Error, Event, MinTotalNominatorStake,
-    NominationScheduledRequests, Nominator, NominatorState, Pallet, Total,
+    NominationScheduledRequests, Nominator, NominatorState, Pallet, Total, WeightInfo,
 };
 use codec::{Decode, Encode, MaxEncodedLen};
 use frame_support::{dispatch::DispatchResultWithPostInfo, ensure, traits::Get};
@@ -95,7 +95,7 @@ impl<T: Config> Pallet<T> {
         }) {
             Ok(()) => {
                 state.less_total = state.less_total.saturating_add(bonded_amount);
-                <NominationScheduledRequests<T>>::insert(collator.clone(), scheduled_requests);
+                Self::put_scheduled_requests(&collator, scheduled_requests);
                 <NominatorState<T>>::insert(nominator.clone(), state);
 
                 Self::deposit_event(Event::NominationRevocationScheduled {
@@ -105,9 +105,7 @@ impl<T: Config> Pallet<T> {
                     scheduled_exit: when,
                 });
             },
-            Err(_) => {
-                ();
-            },
+            Err(_) => return Err(<Error<T>>::TooManyScheduledRequests.into()),
         }
 
         Ok(().into())
@@ -132,12 +130,15 @@ impl<T: Config> Pallet<T> {
         let new_amount: BalanceOf<T> = (bonded_amount - decrease_amount).into();
         ensure!(new_amount >= T::MinNominationPerCollator::get(), <Error<T>>::NominationBelowMin);
 
-        // Net Total is total after pending orders are executed
-        let net_total = state.total().saturating_sub(state.less_total);
-        // Net Total is always >= MinTotalNominatorStake
-        let max_subtracted_amount =
-            net_total.saturating_sub(<MinTotalNominatorStake<T>>::get().into());
-        ensure!(decrease_amount <= max_subtracted_amount, <Error<T>>::NominatorBondBelowMin);
+        // Pool accounts hold the stake of holders below the minimum, so it doesn't apply to them
+        if !Self::is_pool_account(&nominator) {
+            // Net Total is total after pending orders are executed
+            let net_total = state.total().saturating_sub(state.less_total);
+            // Net Total is always >= MinTotalNominatorStake
+            let max_subtracted_amount =
+                net_total.saturating_sub(<MinTotalNominatorStake<T>>::get().into());
+            ensure!(decrease_amount <= max_subtracted_amount, <Error<T>>::NominatorBondBelowMin);
+        }
 
         let now = <Era<T>>::get().current;
         let when = now.saturating_add(<Delay<T>>::get());
@@ -148,7 +149,7 @@ impl<T: Config> Pallet<T> {
         }) {
             Ok(()) => {
                 state.less_total = state.less_total.saturating_add(decrease_amount);
-                <NominationScheduledRequests<T>>::insert(collator.clone(), scheduled_requests);
+                Self::put_scheduled_requests(&collator, scheduled_requests);
                 <NominatorState<T>>::insert(nominator.clone(), state);
 
                 Self::deposit_event(Event::NominationDecreaseScheduled {
@@ -158,7 +159,7 @@ impl<T: Config> Pallet<T> {
                     execute_era: when,
                 });
             },
-            Err(_) => (),
+            Err(_) => return Err(<Error<T>>::TooManyScheduledRequests.into()),
         }
         Ok(().into())
     }
@@ -175,7 +176,7 @@ impl<T: Config> Pallet<T> {
             Self::cancel_request_with_state(&nominator, &mut state, &mut scheduled_requests)
                 .ok_or(<Error<T>>::PendingNominationRequestDNE)?;
 
-        <NominationScheduledRequests<T>>::insert(collator.clone(), scheduled_requests);
+        Self::put_scheduled_requests(&collator, scheduled_requests);
         <NominatorState<T>>::insert(nominator.clone(), state);
 
         Self::deposit_event(Event::CancelledNominationRequest {
@@ -186,6 +187,23 @@ impl<T: Config> Pallet<T> {
         Ok(().into())
     }
 
+    /// Replaces the nominator's existing [ScheduledRequest] towards a given collator with a
+    /// request for `action`, which becomes executable after the usual delay. A revoke always
+    /// removes the whole nomination, so the amount of a [NominationAction::Revoke] is ignored.
+    pub(crate) fn nomination_replace_request(
+        collator: T::AccountId,
+        nominator: T::AccountId,
+        action: NominationAction<BalanceOf<T>>,
+    ) -> DispatchResultWithPostInfo {
+        Self::nomination_cancel_request(collator.clone(), nominator.clone())?;
+
+        match action {
+            NominationAction::Revoke(_) => Self::nomination_schedule_revoke(collator, nominator),
+            NominationAction::Decrease(amount) =>
+                Self::nomination_schedule_bond_decrease(collator, nominator, amount),
+        }
+    }
+
     fn cancel_request_with_state(
         nominator: &T::AccountId,
         state: &mut Nominator<T::AccountId, BalanceOf<T>>,
@@ -247,7 +265,7 @@ impl<T: Config> Pallet<T> {
                     unstaked_amount: amount,
                 });
 
-                <NominationScheduledRequests<T>>::insert(collator, scheduled_requests);
+                Self::put_scheduled_requests(&collator, scheduled_requests);
                 if leaving {
                     <NominatorState<T>>::remove(&nominator);
                     Self::deposit_event(Event::NominatorLeft {
@@ -280,7 +298,8 @@ impl<T: Config> Pallet<T> {
                                     <Error<T>>::NominationBelowMin
                                 );
                                 ensure!(
-                                    new_total >= <MinTotalNominatorStake<T>>::get(),
+                                    new_total >= <MinTotalNominatorStake<T>>::get() ||
+                                        Self::is_pool_account(&nominator),
                                     <Error<T>>::NominatorBondBelowMin
                                 );
 
@@ -298,10 +317,7 @@ impl<T: Config> Pallet<T> {
                             let new_total_staked = <Total<T>>::get().saturating_sub(amount);
                             <Total<T>>::put(new_total_staked);
 
-                            <NominationScheduledRequests<T>>::insert(
-                                collator.clone(),
-                                scheduled_requests,
-                            );
+                            Self::put_scheduled_requests(&collator, scheduled_requests);
                             <NominatorState<T>>::insert(nominator.clone(), state);
                             Self::deposit_event(Event::NominationDecreased {
                                 nominator,
@@ -370,7 +386,7 @@ impl<T: Config> Pallet<T> {
         updated_scheduled_requests
             .into_iter()
             .for_each(|(collator, scheduled_requests)| {
-                <NominationScheduledRequests<T>>::insert(collator, scheduled_requests);
+                Self::put_scheduled_requests(&collator, scheduled_requests);
             });
 
         <NominatorState<T>>::insert(nominator.clone(), state);
@@ -414,7 +430,7 @@ impl<T: Config> Pallet<T> {
         updated_scheduled_requests
             .into_iter()
             .for_each(|(collator, scheduled_requests)| {
-                <NominationScheduledRequests<T>>::insert(collator, scheduled_requests);
+                Self::put_scheduled_requests(&collator, scheduled_requests);
             });
 
         <NominatorState<T>>::insert(nominator.clone(), state);
@@ -431,8 +447,9 @@ impl<T: Config> Pallet<T> {
         nomination_count: u32,
     ) -> DispatchResultWithPostInfo {
         let mut state = <NominatorState<T>>::get(&nominator).ok_or(<Error<T>>::NominatorDNE)?;
+        let actual_nomination_count = state.nominations.0.len() as u32;
         ensure!(
-            nomination_count >= (state.nominations.0.len() as u32),
+            nomination_count >= actual_nomination_count,
             Error::<T>::TooLowNominationCountToLeaveNominators
         );
         let now = <Era<T>>::get().current;
@@ -480,13 +497,14 @@ impl<T: Config> Pallet<T> {
         updated_scheduled_requests
             .into_iter()
             .for_each(|(collator, scheduled_requests)| {
-                <NominationScheduledRequests<T>>::insert(collator, scheduled_requests);
+                Self::put_scheduled_requests(&collator, scheduled_requests);
             });
 
         Self::deposit_event(Event::NominatorLeft { nominator: nominator.clone(), unstaked_amount });
         <NominatorState<T>>::remove(&nominator);
 
-        Ok(().into())
+        Ok(Some(<T as Config>::WeightInfo::execute_leave_nominators(actual_nomination_count))
+            .into())
     }
 
     /// Removes the nominator's existing [ScheduledRequest] towards a given collator, if exists.
@@ -505,7 +523,7 @@ impl<T: Config> Pallet<T> {
             let request = scheduled_requests.remove(request_idx);
             let amount = request.action.amount();
             state.less_total = state.less_total.saturating_sub(amount);
-            <NominationScheduledRequests<T>>::insert(collator, scheduled_requests);
+            Self::put_scheduled_requests(collator, scheduled_requests);
         }
     }
 
@@ -0,0 +1,131 @@
+// Copyright 2024 Aventus Network Services (UK) Ltd.
+
+//! Cleanup of the entries of `NominationScheduledRequests`.
+//!
+//! The entry of a candidate is removed when the candidate exits or its last request is removed.
+//! Requests that were left behind anyway, for candidates that have exited or by nominators that no
+//! longer nominate the candidate, are removed by a sweep of the map in `on_idle`. A sweep runs once
+//! per era and checks at most `MaxNominationRequestCleanupsPerBlock` candidates per block.
+
+use crate::{
+    weights::WeightInfo, BalanceOf, CandidateInfo, Config, Era, Event,
+    NominationRequestsSweepCursor, NominationRequestsSweptEra, NominationScheduledRequests,
+    NominatorState, Pallet, ScheduledRequest,
+};
+use frame_support::{traits::Get, weights::Weight, BoundedVec};
+use sp_runtime::traits::Saturating;
+use sp_std::prelude::*;
+
+impl<T: Config> Pallet<T> {
+    /// Stores the requests towards `collator`, removing its entry when there are none left
+    pub(crate) fn put_scheduled_requests(
+        collator: &T::AccountId,
+        scheduled_requests: BoundedVec<
+            ScheduledRequest<T::AccountId, BalanceOf<T>>,
+            T::MaxNominationsPerNominator,
+        >,
+    ) {
+        if scheduled_requests.is_empty() {
+            <NominationScheduledRequests<T>>::remove(collator);
+        } else {
+            <NominationScheduledRequests<T>>::insert(collator, scheduled_requests);
+        }
+    }
+
+    /// Removes the requests towards `candidate` that can no longer be executed, because the
+    /// candidate has exited or the nominator no longer nominates it. Returns the number of requests
+    /// checked.
+    pub(crate) fn remove_orphaned_nomination_requests(candidate: &T::AccountId) -> u32 {
+        let scheduled_requests = <NominationScheduledRequests<T>>::get(candidate);
+        let checked = scheduled_requests.len() as u32;
+        let candidate_exists = <CandidateInfo<T>>::contains_key(candidate);
+
+        let mut kept = Vec::with_capacity(scheduled_requests.len());
+        let mut removed: u32 = 0;
+        for request in scheduled_requests.into_iter() {
+            let nominator_state = <NominatorState<T>>::get(&request.nominator);
+            let nominating = candidate_exists &&
+                nominator_state.as_ref().map_or(false, |state| {
+                    state.nominations.0.iter().any(|bond| &bond.owner == candidate)
+                });
+
+            if nominating {
+                kept.push(request);
+                continue
+            }
+
+            // The amount of the request no longer counts towards the nominator's pending unbonds
+            if let Some(mut state) = nominator_state {
+                state.less_total = state.less_total.saturating_sub(request.action.amount());
+                <NominatorState<T>>::insert(&request.nominator, state);
+            }
+            removed.saturating_inc();
+        }
+
+        if removed > 0 || kept.is_empty() {
+            Self::put_scheduled_requests(candidate, BoundedVec::truncate_from(kept));
+        }
+        if removed > 0 {
+            Self::deposit_event(Event::OrphanedNominationRequestsRemoved {
+                candidate: candidate.clone(),
+                removed,
+            });
+        }
+
+        checked
+    }
+
+    /// Continues the sweep of `NominationScheduledRequests` while `remaining_weight` allows it,
+    /// starting a new one if none has completed in the current era.
+    pub(crate) fn cleanup_nomination_requests_on_idle(remaining_weight: Weight) -> Weight {
+        let db_weight = T::DbWeight::get();
+        // [Read]: Era, NominationRequestsSweptEra, NominationRequestsSweepCursor
+        // [Write]: NominationRequestsSweptEra, NominationRequestsSweepCursor
+        let mut consumed = db_weight.reads_writes(3, 2);
+        let max_cleanup_weight =
+            T::WeightInfo::cleanup_nomination_requests(T::MaxNominationsPerNominator::get());
+        let max_cleanups = T::MaxNominationRequestCleanupsPerBlock::get();
+        if max_cleanups == 0 || remaining_weight.any_lt(consumed) {
+            return Weight::zero()
+        }
+
+        let era = <Era<T>>::get().current;
+        let cursor = <NominationRequestsSweepCursor<T>>::get();
+        if (cursor.is_none() && <NominationRequestsSweptEra<T>>::get() == era) ||
+            remaining_weight.any_lt(consumed.saturating_add(max_cleanup_weight))
+        {
+            return db_weight.reads(3)
+        }
+
+        let mut candidates = match cursor {
+            Some(last_key) =>
+                <NominationScheduledRequests<T>>::iter_keys_from(last_key.into_inner()),
+            None => <NominationScheduledRequests<T>>::iter_keys(),
+        };
+
+        let mut cleaned: u32 = 0;
+        loop {
+            if cleaned >= max_cleanups ||
+                remaining_weight.any_lt(consumed.saturating_add(max_cleanup_weight))
+            {
+                // The raw keys of the map are shorter than the bound of the cursor
+                <NominationRequestsSweepCursor<T>>::put(BoundedVec::truncate_from(
+                    candidates.last_raw_key().to_vec(),
+                ));
+                return consumed
+            }
+
+            let candidate = match candidates.next() {
+                Some(candidate) => candidate,
+                None => break,
+            };
+            let checked = Self::remove_orphaned_nomination_requests(&candidate);
+            consumed = consumed.saturating_add(T::WeightInfo::cleanup_nomination_requests(checked));
+            cleaned.saturating_inc();
+        }
+
+        <NominationRequestsSweepCursor<T>>::kill();
+        <NominationRequestsSweptEra<T>>::put(era);
+        consumed
+    }
+}
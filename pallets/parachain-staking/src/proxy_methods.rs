@@ -7,13 +7,15 @@ use alloc::string::String;
 
 use codec::Encode;
 use frame_support::traits::IsSubType;
-use sp_avn_common::{verify_signature, InnerCallValidator, Proof};
+use sp_avn_common::{verify_domain_separated_signature, InnerCallValidator, Proof};
 use sp_runtime::traits::StaticLookup;
 use sp_std::prelude::*;
 
 use super::*;
 use crate::Pallet as ParachainStaking;
 
+/// Prefix of the payloads signed for staking operations, ahead of the per call context
+pub const SIGNING_DOMAIN: &'static [u8] = b"parachain_staking";
 pub const SIGNED_NOMINATOR_CONTEXT: &'static [u8] =
     b"parachain authorization for nominate operation";
 pub const SIGNED_NOMINATOR_BOND_EXTRA_CONTEXT: &'static [u8] =
@@ -34,6 +36,14 @@ pub const SIGNED_EXECUTE_NOMINATION_REQUESTS_CONTEXT: &'static [u8] =
     b"parachain authorization for executing nomination requests operation";
 pub const SIGNED_EXECUTE_CANDIDATE_UNBOND_CONTEXT: &'static [u8] =
     b"parachain authorization for executing candidate unbond operation";
+pub const SIGNED_REPLACE_NOMINATION_REQUEST_CONTEXT: &'static [u8] =
+    b"parachain authorization for replacing nomination request operation";
+pub const SIGNED_SCHEDULE_LEAVE_CANDIDATES_CONTEXT: &'static [u8] =
+    b"parachain authorization for scheduling leaving candidates operation";
+pub const SIGNED_EXECUTE_LEAVE_CANDIDATES_CONTEXT: &'static [u8] =
+    b"parachain authorization for executing leave candidates operation";
+pub const SIGNED_CANCEL_LEAVE_CANDIDATES_CONTEXT: &'static [u8] =
+    b"parachain authorization for cancelling leave candidates operation";
 
 pub fn get_encoded_call_param<T: Config>(
     call: &<T as Config>::RuntimeCall,
@@ -51,6 +61,7 @@ pub fn get_encoded_call_param<T: Config>(
                 targets,
                 amount,
                 sender_nonce,
+                ParachainStaking::<T>::proxy_nonce_epoch(&proof.signer),
             );
 
             return Some((proof, encoded_data))
@@ -61,6 +72,7 @@ pub fn get_encoded_call_param<T: Config>(
                 proof.relayer.clone(),
                 extra_amount,
                 sender_nonce,
+                ParachainStaking::<T>::proxy_nonce_epoch(&proof.signer),
             );
 
             return Some((proof, encoded_data))
@@ -71,6 +83,7 @@ pub fn get_encoded_call_param<T: Config>(
                 proof.relayer.clone(),
                 extra_amount,
                 sender_nonce,
+                ParachainStaking::<T>::proxy_nonce_epoch(&proof.signer),
             );
 
             return Some((proof, encoded_data))
@@ -81,6 +94,7 @@ pub fn get_encoded_call_param<T: Config>(
                 proof.relayer.clone(),
                 less,
                 sender_nonce,
+                ParachainStaking::<T>::proxy_nonce_epoch(&proof.signer),
             );
 
             return Some((proof, encoded_data))
@@ -91,6 +105,7 @@ pub fn get_encoded_call_param<T: Config>(
                 proof.relayer.clone(),
                 less,
                 sender_nonce,
+                ParachainStaking::<T>::proxy_nonce_epoch(&proof.signer),
             );
 
             return Some((proof, encoded_data))
@@ -101,6 +116,7 @@ pub fn get_encoded_call_param<T: Config>(
                 proof.relayer.clone(),
                 collator,
                 sender_nonce,
+                ParachainStaking::<T>::proxy_nonce_epoch(&proof.signer),
             );
 
             return Some((proof, encoded_data))
@@ -110,6 +126,7 @@ pub fn get_encoded_call_param<T: Config>(
             let encoded_data = encode_signed_schedule_leave_nominators_params::<T>(
                 proof.relayer.clone(),
                 sender_nonce,
+                ParachainStaking::<T>::proxy_nonce_epoch(&proof.signer),
             );
 
             return Some((proof, encoded_data))
@@ -120,6 +137,7 @@ pub fn get_encoded_call_param<T: Config>(
                 proof.relayer.clone(),
                 nominator,
                 sender_nonce,
+                ParachainStaking::<T>::proxy_nonce_epoch(&proof.signer),
             );
 
             return Some((proof, encoded_data))
@@ -130,6 +148,7 @@ pub fn get_encoded_call_param<T: Config>(
                 proof.relayer.clone(),
                 nominator,
                 sender_nonce,
+                ParachainStaking::<T>::proxy_nonce_epoch(&proof.signer),
             );
 
             return Some((proof, encoded_data))
@@ -140,6 +159,50 @@ pub fn get_encoded_call_param<T: Config>(
                 proof.relayer.clone(),
                 candidate,
                 sender_nonce,
+                ParachainStaking::<T>::proxy_nonce_epoch(&proof.signer),
+            );
+
+            return Some((proof, encoded_data))
+        },
+        Call::signed_replace_nomination_request { proof, candidate, action } => {
+            let sender_nonce = ParachainStaking::<T>::proxy_nonce(&proof.signer);
+            let encoded_data = encode_signed_replace_nomination_request_params::<T>(
+                proof.relayer.clone(),
+                candidate,
+                action,
+                sender_nonce,
+                ParachainStaking::<T>::proxy_nonce_epoch(&proof.signer),
+            );
+
+            return Some((proof, encoded_data))
+        },
+        Call::signed_schedule_leave_candidates { proof } => {
+            let sender_nonce = ParachainStaking::<T>::proxy_nonce(&proof.signer);
+            let encoded_data = encode_signed_schedule_leave_candidates_params::<T>(
+                proof.relayer.clone(),
+                sender_nonce,
+                ParachainStaking::<T>::proxy_nonce_epoch(&proof.signer),
+            );
+
+            return Some((proof, encoded_data))
+        },
+        Call::signed_execute_leave_candidates { proof, candidate } => {
+            let sender_nonce = ParachainStaking::<T>::proxy_nonce(&proof.signer);
+            let encoded_data = encode_signed_execute_leave_candidates_params::<T>(
+                proof.relayer.clone(),
+                candidate,
+                sender_nonce,
+                ParachainStaking::<T>::proxy_nonce_epoch(&proof.signer),
+            );
+
+            return Some((proof, encoded_data))
+        },
+        Call::signed_cancel_leave_candidates { proof } => {
+            let sender_nonce = ParachainStaking::<T>::proxy_nonce(&proof.signer);
+            let encoded_data = encode_signed_cancel_leave_candidates_params::<T>(
+                proof.relayer.clone(),
+                sender_nonce,
+                ParachainStaking::<T>::proxy_nonce_epoch(&proof.signer),
             );
 
             return Some((proof, encoded_data))
@@ -148,84 +211,253 @@ pub fn get_encoded_call_param<T: Config>(
     }
 }
 
+/// Verifies `proof` over a payload built by one of the `encode_signed_*` functions below
+pub fn verify_signed_payload<T: Config>(
+    proof: &Proof<T::Signature, T::AccountId>,
+    signed_payload: &[u8],
+) -> Result<(), ()> {
+    verify_domain_separated_signature::<T::Signature, T::AccountId>(
+        proof,
+        SIGNING_DOMAIN,
+        signed_payload,
+        AVN::<T>::legacy_signed_payloads_accepted(),
+    )
+}
+
 pub fn encode_signed_nominate_params<T: Config>(
     relayer: T::AccountId,
     targets: &Vec<<T::Lookup as StaticLookup>::Source>,
     amount: &BalanceOf<T>,
     sender_nonce: u64,
+    nonce_epoch: u32,
 ) -> Vec<u8> {
-    return (SIGNED_NOMINATOR_CONTEXT, relayer, targets, amount, sender_nonce).encode()
+    return (
+        SIGNING_DOMAIN,
+        SIGNED_NOMINATOR_CONTEXT,
+        relayer,
+        targets,
+        amount,
+        sender_nonce,
+        nonce_epoch,
+    )
+        .encode()
 }
 
 pub fn encode_signed_bond_extra_params<T: Config>(
     relayer: T::AccountId,
     extra_amount: &BalanceOf<T>,
     sender_nonce: u64,
+    nonce_epoch: u32,
 ) -> Vec<u8> {
-    return (SIGNED_NOMINATOR_BOND_EXTRA_CONTEXT, relayer, extra_amount, sender_nonce).encode()
+    return (
+        SIGNING_DOMAIN,
+        SIGNED_NOMINATOR_BOND_EXTRA_CONTEXT,
+        relayer,
+        extra_amount,
+        sender_nonce,
+        nonce_epoch,
+    )
+        .encode()
 }
 
 pub fn encode_signed_candidate_bond_extra_params<T: Config>(
     relayer: T::AccountId,
     extra_amount: &BalanceOf<T>,
     sender_nonce: u64,
+    nonce_epoch: u32,
 ) -> Vec<u8> {
-    return (SIGNED_CANDIDATE_BOND_EXTRA_CONTEXT, relayer, extra_amount, sender_nonce).encode()
+    return (
+        SIGNING_DOMAIN,
+        SIGNED_CANDIDATE_BOND_EXTRA_CONTEXT,
+        relayer,
+        extra_amount,
+        sender_nonce,
+        nonce_epoch,
+    )
+        .encode()
 }
 
 pub fn encode_signed_schedule_nominator_unbond_params<T: Config>(
     relayer: T::AccountId,
     value: &BalanceOf<T>,
     sender_nonce: u64,
+    nonce_epoch: u32,
 ) -> Vec<u8> {
-    return (SIGNED_SCHEDULE_NOMINATOR_UNBOND_CONTEXT, relayer, value, sender_nonce).encode()
+    return (
+        SIGNING_DOMAIN,
+        SIGNED_SCHEDULE_NOMINATOR_UNBOND_CONTEXT,
+        relayer,
+        value,
+        sender_nonce,
+        nonce_epoch,
+    )
+        .encode()
 }
 
 pub fn encode_signed_schedule_candidate_unbond_params<T: Config>(
     relayer: T::AccountId,
     value: &BalanceOf<T>,
     sender_nonce: u64,
+    nonce_epoch: u32,
 ) -> Vec<u8> {
-    return (SIGNED_SCHEDULE_CANDIDATE_UNBOND_CONTEXT, relayer, value, sender_nonce).encode()
+    return (
+        SIGNING_DOMAIN,
+        SIGNED_SCHEDULE_CANDIDATE_UNBOND_CONTEXT,
+        relayer,
+        value,
+        sender_nonce,
+        nonce_epoch,
+    )
+        .encode()
 }
 
 pub fn encode_signed_schedule_revoke_nomination_params<T: Config>(
     relayer: T::AccountId,
     collator: &T::AccountId,
     sender_nonce: u64,
+    nonce_epoch: u32,
 ) -> Vec<u8> {
-    return (SIGNED_NOMINATOR_REMOVE_BOND_CONTEXT, relayer, collator, sender_nonce).encode()
+    return (
+        SIGNING_DOMAIN,
+        SIGNED_NOMINATOR_REMOVE_BOND_CONTEXT,
+        relayer,
+        collator,
+        sender_nonce,
+        nonce_epoch,
+    )
+        .encode()
 }
 
 pub fn encode_signed_schedule_leave_nominators_params<T: Config>(
     relayer: T::AccountId,
     sender_nonce: u64,
+    nonce_epoch: u32,
 ) -> Vec<u8> {
-    return (SIGNED_SCHEDULE_LEAVE_NOMINATORS_CONTEXT, relayer, sender_nonce).encode()
+    return (
+        SIGNING_DOMAIN,
+        SIGNED_SCHEDULE_LEAVE_NOMINATORS_CONTEXT,
+        relayer,
+        sender_nonce,
+        nonce_epoch,
+    )
+        .encode()
 }
 
 pub fn encode_signed_execute_leave_nominators_params<T: Config>(
     relayer: T::AccountId,
     nominator: &T::AccountId,
     sender_nonce: u64,
+    nonce_epoch: u32,
 ) -> Vec<u8> {
-    return (SIGNED_EXECUTE_LEAVE_NOMINATORS_CONTEXT, relayer, nominator, sender_nonce).encode()
+    return (
+        SIGNING_DOMAIN,
+        SIGNED_EXECUTE_LEAVE_NOMINATORS_CONTEXT,
+        relayer,
+        nominator,
+        sender_nonce,
+        nonce_epoch,
+    )
+        .encode()
 }
 
 pub fn encode_signed_execute_nomination_request_params<T: Config>(
     relayer: T::AccountId,
     nominator: &T::AccountId,
     sender_nonce: u64,
+    nonce_epoch: u32,
 ) -> Vec<u8> {
-    return (SIGNED_EXECUTE_NOMINATION_REQUESTS_CONTEXT, relayer, nominator, sender_nonce).encode()
+    return (
+        SIGNING_DOMAIN,
+        SIGNED_EXECUTE_NOMINATION_REQUESTS_CONTEXT,
+        relayer,
+        nominator,
+        sender_nonce,
+        nonce_epoch,
+    )
+        .encode()
 }
 
 pub fn encode_signed_execute_candidate_unbond_params<T: Config>(
     relayer: T::AccountId,
     candidate: &T::AccountId,
     sender_nonce: u64,
+    nonce_epoch: u32,
+) -> Vec<u8> {
+    return (
+        SIGNING_DOMAIN,
+        SIGNED_EXECUTE_CANDIDATE_UNBOND_CONTEXT,
+        relayer,
+        candidate,
+        sender_nonce,
+        nonce_epoch,
+    )
+        .encode()
+}
+
+pub fn encode_signed_replace_nomination_request_params<T: Config>(
+    relayer: T::AccountId,
+    candidate: &T::AccountId,
+    action: &NominationAction<BalanceOf<T>>,
+    sender_nonce: u64,
+    nonce_epoch: u32,
+) -> Vec<u8> {
+    return (
+        SIGNING_DOMAIN,
+        SIGNED_REPLACE_NOMINATION_REQUEST_CONTEXT,
+        relayer,
+        candidate,
+        action,
+        sender_nonce,
+        nonce_epoch,
+    )
+        .encode()
+}
+
+pub fn encode_signed_schedule_leave_candidates_params<T: Config>(
+    relayer: T::AccountId,
+    sender_nonce: u64,
+    nonce_epoch: u32,
+) -> Vec<u8> {
+    return (
+        SIGNING_DOMAIN,
+        SIGNED_SCHEDULE_LEAVE_CANDIDATES_CONTEXT,
+        relayer,
+        sender_nonce,
+        nonce_epoch,
+    )
+        .encode()
+}
+
+pub fn encode_signed_execute_leave_candidates_params<T: Config>(
+    relayer: T::AccountId,
+    candidate: &T::AccountId,
+    sender_nonce: u64,
+    nonce_epoch: u32,
+) -> Vec<u8> {
+    return (
+        SIGNING_DOMAIN,
+        SIGNED_EXECUTE_LEAVE_CANDIDATES_CONTEXT,
+        relayer,
+        candidate,
+        sender_nonce,
+        nonce_epoch,
+    )
+        .encode()
+}
+
+pub fn encode_signed_cancel_leave_candidates_params<T: Config>(
+    relayer: T::AccountId,
+    sender_nonce: u64,
+    nonce_epoch: u32,
 ) -> Vec<u8> {
-    return (SIGNED_EXECUTE_CANDIDATE_UNBOND_CONTEXT, relayer, candidate, sender_nonce).encode()
+    return (
+        SIGNING_DOMAIN,
+        SIGNED_CANCEL_LEAVE_CANDIDATES_CONTEXT,
+        relayer,
+        sender_nonce,
+        nonce_epoch,
+    )
+        .encode()
 }
 
 impl<T: Config> InnerCallValidator for ParachainStaking<T> {
@@ -233,11 +465,7 @@ impl<T: Config> InnerCallValidator for ParachainStaking<T> {
 
     fn signature_is_valid(call: &Box<Self::Call>) -> bool {
         if let Some((proof, signed_payload)) = get_encoded_call_param::<T>(call) {
-            return verify_signature::<T::Signature, T::AccountId>(
-                &proof,
-                &signed_payload.as_slice(),
-            )
-            .is_ok()
+            return verify_signed_payload::<T>(&proof, &signed_payload).is_ok()
         }
 
         return false
@@ -55,6 +55,7 @@ mod proxy_signed_bond_extra {
             staker.relayer.clone(),
             extra_amount,
             sender_nonce,
+            ParachainStaking::proxy_nonce_epoch(staker.account_id),
         );
 
         let signature = sign(&staker.key_pair, &data_to_sign);
@@ -509,6 +510,7 @@ mod proxy_signed_candidate_bond_extra {
             staker.relayer.clone(),
             extra_amount,
             sender_nonce,
+            ParachainStaking::proxy_nonce_epoch(staker.account_id),
         );
 
         let signature = sign(&staker.key_pair, &data_to_sign);
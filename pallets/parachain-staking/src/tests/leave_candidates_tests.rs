@@ -0,0 +1,382 @@
+//Copyright 2022 Aventus Network Services.
+
+#![cfg(test)]
+
+use crate::{
+    assert_event_emitted, encode_signed_cancel_leave_candidates_params,
+    encode_signed_execute_leave_candidates_params, encode_signed_schedule_leave_candidates_params,
+    mock::{
+        build_proof, inner_call_failed_event_emitted, roll_to, sign, AccountId, AvnProxy,
+        ExtBuilder, ParachainStaking, RuntimeCall as MockCall, RuntimeOrigin, Signature, Staker,
+        Test, TestAccount,
+    },
+    Call, Error, Event, Proof,
+};
+use frame_support::{assert_noop, assert_ok, error::BadOrigin};
+use frame_system::RawOrigin;
+use pallet_avn_proxy::Error as avn_proxy_error;
+
+fn to_acc_id(id: u64) -> AccountId {
+    return TestAccount::new(id).account_id()
+}
+
+fn build_ext(collator: &Staker) -> sp_io::TestExternalities {
+    let collator_2 = to_acc_id(2u64);
+    ExtBuilder::default()
+        .with_balances(vec![
+            (collator.account_id, 10000),
+            (collator_2, 10000),
+            (collator.relayer, 10000),
+        ])
+        .with_candidates(vec![(collator.account_id, 100), (collator_2, 100)])
+        .build()
+}
+
+fn create_proof_for_signed_schedule_leave_candidates(
+    sender_nonce: u64,
+    staker: &Staker,
+) -> Proof<Signature, AccountId> {
+    let data_to_sign = encode_signed_schedule_leave_candidates_params::<Test>(
+        staker.relayer.clone(),
+        sender_nonce,
+        ParachainStaking::proxy_nonce_epoch(staker.account_id),
+    );
+
+    let signature = sign(&staker.key_pair, &data_to_sign);
+    return build_proof(&staker.account_id, &staker.relayer, signature)
+}
+
+fn create_proof_for_signed_execute_leave_candidates(
+    sender_nonce: u64,
+    staker: &Staker,
+    candidate: &AccountId,
+) -> Proof<Signature, AccountId> {
+    let data_to_sign = encode_signed_execute_leave_candidates_params::<Test>(
+        staker.relayer.clone(),
+        candidate,
+        sender_nonce,
+        ParachainStaking::proxy_nonce_epoch(staker.account_id),
+    );
+
+    let signature = sign(&staker.key_pair, &data_to_sign);
+    return build_proof(&staker.account_id, &staker.relayer, signature)
+}
+
+fn create_proof_for_signed_cancel_leave_candidates(
+    sender_nonce: u64,
+    staker: &Staker,
+) -> Proof<Signature, AccountId> {
+    let data_to_sign = encode_signed_cancel_leave_candidates_params::<Test>(
+        staker.relayer.clone(),
+        sender_nonce,
+        ParachainStaking::proxy_nonce_epoch(staker.account_id),
+    );
+
+    let signature = sign(&staker.key_pair, &data_to_sign);
+    return build_proof(&staker.account_id, &staker.relayer, signature)
+}
+
+fn schedule_leave(collator: &Staker) {
+    let nonce = ParachainStaking::proxy_nonce(collator.account_id);
+    let proof = create_proof_for_signed_schedule_leave_candidates(nonce, collator);
+    assert_ok!(ParachainStaking::signed_schedule_leave_candidates(
+        RuntimeOrigin::signed(collator.account_id),
+        proof
+    ));
+}
+
+mod proxy_signed_schedule_leave_candidates {
+    use super::*;
+
+    #[test]
+    fn succeeds_with_good_values() {
+        let collator: Staker = Default::default();
+        build_ext(&collator).execute_with(|| {
+            let nonce = ParachainStaking::proxy_nonce(collator.account_id);
+            let proof = create_proof_for_signed_schedule_leave_candidates(nonce, &collator);
+            let call = Box::new(MockCall::ParachainStaking(
+                Call::<Test>::signed_schedule_leave_candidates { proof },
+            ));
+
+            assert_ok!(AvnProxy::proxy(RuntimeOrigin::signed(collator.relayer), call, None));
+
+            assert!(ParachainStaking::candidate_info(collator.account_id).unwrap().is_leaving());
+            assert!(!ParachainStaking::candidate_pool()
+                .0
+                .iter()
+                .any(|bond| bond.owner == collator.account_id));
+            assert_event_emitted!(Event::CandidateScheduledExit {
+                exit_allowed_era: 1,
+                candidate: collator.account_id,
+                scheduled_exit: 1 + ParachainStaking::delay(),
+            });
+            assert_eq!(ParachainStaking::proxy_nonce(collator.account_id), nonce + 1);
+        });
+    }
+
+    mod fails_when {
+        use super::*;
+
+        #[test]
+        fn extrinsic_is_unsigned() {
+            let collator: Staker = Default::default();
+            build_ext(&collator).execute_with(|| {
+                let nonce = ParachainStaking::proxy_nonce(collator.account_id);
+                let proof = create_proof_for_signed_schedule_leave_candidates(nonce, &collator);
+
+                assert_noop!(
+                    ParachainStaking::signed_schedule_leave_candidates(
+                        RawOrigin::None.into(),
+                        proof
+                    ),
+                    BadOrigin
+                );
+            });
+        }
+
+        #[test]
+        fn sender_is_not_the_signer() {
+            let collator: Staker = Default::default();
+            build_ext(&collator).execute_with(|| {
+                let nonce = ParachainStaking::proxy_nonce(collator.account_id);
+                let proof = create_proof_for_signed_schedule_leave_candidates(nonce, &collator);
+
+                assert_noop!(
+                    ParachainStaking::signed_schedule_leave_candidates(
+                        RuntimeOrigin::signed(to_acc_id(2u64)),
+                        proof
+                    ),
+                    Error::<Test>::SenderIsNotSigner
+                );
+            });
+        }
+
+        #[test]
+        fn proxy_proof_nonce_is_not_valid() {
+            let collator: Staker = Default::default();
+            build_ext(&collator).execute_with(|| {
+                let bad_nonce = ParachainStaking::proxy_nonce(collator.account_id) + 1;
+                let proof = create_proof_for_signed_schedule_leave_candidates(bad_nonce, &collator);
+
+                assert_noop!(
+                    ParachainStaking::signed_schedule_leave_candidates(
+                        RuntimeOrigin::signed(collator.account_id),
+                        proof
+                    ),
+                    Error::<Test>::UnauthorizedSignedScheduleLeaveCandidatesTransaction
+                );
+            });
+        }
+
+        #[test]
+        fn proxy_proof_is_for_another_call() {
+            let collator: Staker = Default::default();
+            build_ext(&collator).execute_with(|| {
+                let nonce = ParachainStaking::proxy_nonce(collator.account_id);
+                let proof = create_proof_for_signed_cancel_leave_candidates(nonce, &collator);
+                let call = Box::new(MockCall::ParachainStaking(
+                    Call::<Test>::signed_schedule_leave_candidates { proof },
+                ));
+
+                assert_ok!(AvnProxy::proxy(RuntimeOrigin::signed(collator.relayer), call, None));
+                assert_eq!(
+                    true,
+                    inner_call_failed_event_emitted(
+                        avn_proxy_error::<Test>::UnauthorizedProxyTransaction.into()
+                    )
+                );
+                assert!(ParachainStaking::candidate_info(collator.account_id).unwrap().is_active());
+            });
+        }
+
+        #[test]
+        fn candidate_is_already_leaving() {
+            let collator: Staker = Default::default();
+            build_ext(&collator).execute_with(|| {
+                schedule_leave(&collator);
+
+                let nonce = ParachainStaking::proxy_nonce(collator.account_id);
+                let proof = create_proof_for_signed_schedule_leave_candidates(nonce, &collator);
+                assert_noop!(
+                    ParachainStaking::signed_schedule_leave_candidates(
+                        RuntimeOrigin::signed(collator.account_id),
+                        proof
+                    ),
+                    Error::<Test>::CandidateAlreadyLeaving
+                );
+            });
+        }
+    }
+}
+
+mod proxy_signed_execute_leave_candidates {
+    use super::*;
+
+    #[test]
+    fn succeeds_when_the_exit_is_due() {
+        let collator: Staker = Default::default();
+        let executor = Staker::new(0, 20000);
+        build_ext(&collator).execute_with(|| {
+            schedule_leave(&collator);
+            roll_to(10);
+
+            let nonce = ParachainStaking::proxy_nonce(executor.account_id);
+            let proof = create_proof_for_signed_execute_leave_candidates(
+                nonce,
+                &executor,
+                &collator.account_id,
+            );
+            let call = Box::new(MockCall::ParachainStaking(
+                Call::<Test>::signed_execute_leave_candidates {
+                    proof,
+                    candidate: collator.account_id,
+                },
+            ));
+
+            assert_ok!(AvnProxy::proxy(RuntimeOrigin::signed(executor.relayer), call, None));
+
+            assert!(ParachainStaking::candidate_info(collator.account_id).is_none());
+            assert_event_emitted!(Event::CandidateLeft {
+                ex_candidate: collator.account_id,
+                unlocked_amount: 100,
+                new_total_amt_locked: 100,
+            });
+            assert_eq!(ParachainStaking::proxy_nonce(executor.account_id), nonce + 1);
+        });
+    }
+
+    mod fails_when {
+        use super::*;
+
+        #[test]
+        fn the_exit_is_not_due() {
+            let collator: Staker = Default::default();
+            build_ext(&collator).execute_with(|| {
+                schedule_leave(&collator);
+
+                let nonce = ParachainStaking::proxy_nonce(collator.account_id);
+                let proof = create_proof_for_signed_execute_leave_candidates(
+                    nonce,
+                    &collator,
+                    &collator.account_id,
+                );
+                assert_noop!(
+                    ParachainStaking::signed_execute_leave_candidates(
+                        RuntimeOrigin::signed(collator.account_id),
+                        proof,
+                        collator.account_id
+                    ),
+                    Error::<Test>::CandidateCannotLeaveYet
+                );
+            });
+        }
+
+        #[test]
+        fn proxy_proof_is_for_another_candidate() {
+            let collator: Staker = Default::default();
+            build_ext(&collator).execute_with(|| {
+                schedule_leave(&collator);
+                roll_to(10);
+
+                let nonce = ParachainStaking::proxy_nonce(collator.account_id);
+                let proof = create_proof_for_signed_execute_leave_candidates(
+                    nonce,
+                    &collator,
+                    &to_acc_id(2),
+                );
+                assert_noop!(
+                    ParachainStaking::signed_execute_leave_candidates(
+                        RuntimeOrigin::signed(collator.account_id),
+                        proof,
+                        collator.account_id
+                    ),
+                    Error::<Test>::UnauthorizedSignedExecuteLeaveCandidatesTransaction
+                );
+            });
+        }
+
+        #[test]
+        fn candidate_does_not_exist() {
+            let collator: Staker = Default::default();
+            let not_a_candidate = to_acc_id(5u64);
+            build_ext(&collator).execute_with(|| {
+                let nonce = ParachainStaking::proxy_nonce(collator.account_id);
+                let proof = create_proof_for_signed_execute_leave_candidates(
+                    nonce,
+                    &collator,
+                    &not_a_candidate,
+                );
+                assert_noop!(
+                    ParachainStaking::signed_execute_leave_candidates(
+                        RuntimeOrigin::signed(collator.account_id),
+                        proof,
+                        not_a_candidate
+                    ),
+                    Error::<Test>::CandidateDNE
+                );
+            });
+        }
+    }
+}
+
+mod proxy_signed_cancel_leave_candidates {
+    use super::*;
+
+    #[test]
+    fn succeeds_with_good_values() {
+        let collator: Staker = Default::default();
+        build_ext(&collator).execute_with(|| {
+            schedule_leave(&collator);
+
+            let nonce = ParachainStaking::proxy_nonce(collator.account_id);
+            let proof = create_proof_for_signed_cancel_leave_candidates(nonce, &collator);
+            let call = Box::new(MockCall::ParachainStaking(
+                Call::<Test>::signed_cancel_leave_candidates { proof },
+            ));
+
+            assert_ok!(AvnProxy::proxy(RuntimeOrigin::signed(collator.relayer), call, None));
+
+            assert!(ParachainStaking::candidate_info(collator.account_id).unwrap().is_active());
+            assert_event_emitted!(Event::CancelledCandidateExit { candidate: collator.account_id });
+            assert_eq!(ParachainStaking::proxy_nonce(collator.account_id), nonce + 1);
+        });
+    }
+
+    mod fails_when {
+        use super::*;
+
+        #[test]
+        fn candidate_is_not_leaving() {
+            let collator: Staker = Default::default();
+            build_ext(&collator).execute_with(|| {
+                let nonce = ParachainStaking::proxy_nonce(collator.account_id);
+                let proof = create_proof_for_signed_cancel_leave_candidates(nonce, &collator);
+                assert_noop!(
+                    ParachainStaking::signed_cancel_leave_candidates(
+                        RuntimeOrigin::signed(collator.account_id),
+                        proof
+                    ),
+                    Error::<Test>::CandidateNotLeaving
+                );
+            });
+        }
+
+        #[test]
+        fn proxy_proof_nonce_is_not_valid() {
+            let collator: Staker = Default::default();
+            build_ext(&collator).execute_with(|| {
+                schedule_leave(&collator);
+
+                let bad_nonce = ParachainStaking::proxy_nonce(collator.account_id) + 1;
+                let proof = create_proof_for_signed_cancel_leave_candidates(bad_nonce, &collator);
+                assert_noop!(
+                    ParachainStaking::signed_cancel_leave_candidates(
+                        RuntimeOrigin::signed(collator.account_id),
+                        proof
+                    ),
+                    Error::<Test>::UnauthorizedSignedCancelLeaveCandidatesTransaction
+                );
+            });
+        }
+    }
+}
@@ -26,14 +26,15 @@ use frame_support::{
     dispatch::{DispatchClass, DispatchInfo, PostDispatchInfo},
     parameter_types,
     traits::{
-        ConstU8, Currency, Everything, FindAuthor, Imbalance, LockIdentifier, OnFinalize,
-        OnInitialize, OnUnbalanced, ValidatorRegistration,
+        ConstU128, ConstU8, Currency, EitherOfDiverse, Everything, FindAuthor, Imbalance,
+        LockIdentifier, OnFinalize, OnInitialize, OnUnbalanced, SortedMembers,
+        ValidatorRegistration,
     },
     weights::{Weight, WeightToFee as WeightToFeeT},
     PalletId,
 };
 use frame_system::{self as system, limits};
-use pallet_avn::CollatorPayoutDustHandler;
+use pallet_avn::{CollatorPayoutDustHandler, DisabledOffenderCheck};
 use pallet_avn_proxy::{self as avn_proxy, ProvableProxy};
 use pallet_eth_bridge;
 use pallet_session as session;
@@ -64,15 +65,44 @@ construct_runtime!(
         ParachainStaking: pallet_parachain_staking::{Pallet, Call, Storage, Config<T>, Event<T>},
         Authorship: pallet_authorship::{Pallet, Storage},
         TransactionPayment: pallet_transaction_payment::{Pallet, Storage, Event<T>, Config<T>},
-        AVN: pallet_avn::{Pallet, Storage, Event},
+        AVN: pallet_avn::{Pallet, Storage, Event<T>},
         Session: pallet_session::{Pallet, Call, Storage, Event, Config<T>},
         AvnProxy: avn_proxy::{Pallet, Call, Storage, Event<T>},
         Historical: pallet_session::historical::{Pallet, Storage},
         EthBridge: pallet_eth_bridge::{Pallet, Call, Storage, Event<T>},
         Timestamp: pallet_timestamp,
+        Multisig: pallet_multisig,
+        Preimage: pallet_preimage,
+        Scheduler: pallet_scheduler::{Pallet, Call, Storage, Event<T>},
     }
 );
 
+parameter_types! {
+    pub MaximumSchedulerWeight: Weight = Weight::MAX;
+}
+
+impl pallet_preimage::Config for Test {
+    type RuntimeEvent = RuntimeEvent;
+    type WeightInfo = ();
+    type Currency = Balances;
+    type ManagerOrigin = frame_system::EnsureRoot<AccountId>;
+    type BaseDeposit = ConstU128<0>;
+    type ByteDeposit = ConstU128<0>;
+}
+
+impl pallet_scheduler::Config for Test {
+    type RuntimeEvent = RuntimeEvent;
+    type RuntimeOrigin = RuntimeOrigin;
+    type PalletsOrigin = OriginCaller;
+    type RuntimeCall = RuntimeCall;
+    type MaximumWeight = MaximumSchedulerWeight;
+    type ScheduleOrigin = frame_system::EnsureRoot<AccountId>;
+    type MaxScheduledPerBlock = ConstU32<100>;
+    type WeightInfo = ();
+    type OriginPrivilegeCmp = frame_support::traits::EqualPrivilegeOnly;
+    type Preimages = Preimage;
+}
+
 const NORMAL_DISPATCH_RATIO: Perbill = Perbill::from_percent(75);
 const MAX_BLOCK_WEIGHT: Weight = Weight::from_parts(1024, 0).set_proof_size(u64::MAX);
 pub static TX_LEN: usize = 1;
@@ -151,7 +181,7 @@ impl frame_system::Config for Test {
     type PalletInfo = PalletInfo;
     type AccountData = pallet_balances::AccountData<Balance>;
     type OnNewAccount = ();
-    type OnKilledAccount = ();
+    type OnKilledAccount = ParachainStaking;
     type SystemWeightInfo = ();
     type BlockWeights = RuntimeBlockWeights;
     type BlockLength = BlockLength;
@@ -195,21 +225,35 @@ impl pallet_authorship::Config for Test {
 
 parameter_types! {
     pub const MinBlocksPerEra: u32 = 3;
-    pub const RewardPaymentDelay: u32 = 2;
+    pub static RewardPaymentDelay: u32 = 2;
+    pub const HistoryDepth: u32 = 3;
     pub const MinSelectedCandidates: u32 = 5;
-    pub const MaxTopNominationsPerCandidate: u32 = 4;
+    pub static MaxTopNominationsPerCandidate: u32 = 4;
     pub const MaxBottomNominationsPerCandidate: u32 = 4;
     pub const MaxNominationsPerNominator: u32 = 10;
     pub const MinNominationPerCollator: u128 = 1;
     pub const ErasPerGrowthPeriod: u32 = 2;
     pub const RewardPotId: PalletId = PalletId(*b"av/vamgr");
     pub const MaxCandidates:u32 = 100;
+    pub const MaxNominationRequestCleanupsPerBlock: u32 = 2;
+}
+
+parameter_types! {
+    pub static CollatorsWithoutKeys: Vec<AccountId> = vec![];
+    pub static DisabledOffenders: Vec<AccountId> = vec![];
+}
+
+pub struct TestDisabledOffenderCheck;
+impl DisabledOffenderCheck<AccountId> for TestDisabledOffenderCheck {
+    fn is_disabled(offender: &AccountId) -> bool {
+        DisabledOffenders::get().contains(offender)
+    }
 }
 
 pub struct IsRegistered;
 impl ValidatorRegistration<AccountId> for IsRegistered {
-    fn is_registered(_id: &AccountId) -> bool {
-        true
+    fn is_registered(id: &AccountId) -> bool {
+        !CollatorsWithoutKeys::get().contains(id)
     }
 }
 
@@ -226,6 +270,7 @@ impl Config for Test {
     type RuntimeEvent = RuntimeEvent;
     type Currency = Balances;
     type RewardPaymentDelay = RewardPaymentDelay;
+    type HistoryDepth = HistoryDepth;
     type MinBlocksPerEra = MinBlocksPerEra;
     type MinSelectedCandidates = MinSelectedCandidates;
     type MaxTopNominationsPerCandidate = MaxTopNominationsPerCandidate;
@@ -239,23 +284,76 @@ impl Config for Test {
     type Signature = Signature;
     type CollatorSessionRegistration = IsRegistered;
     type CollatorPayoutDustHandler = TestCollatorPayoutDustHandler;
+    type DisabledOffenderCheck = TestDisabledOffenderCheck;
     type WeightInfo = ();
     type MaxCandidates = MaxCandidates;
     type AccountToBytesConvert = AVN;
     type BridgeInterface = EthBridge;
     type GrowthEnabled = TestGrowthEnabled;
+    type AdminOrigin = EitherOfDiverse<
+        frame_system::EnsureRoot<Self::AccountId>,
+        frame_system::EnsureSignedBy<AdminMultisig, Self::AccountId>,
+    >;
+    type Scheduler = Scheduler;
+    type Preimages = Preimage;
+    type PalletsOrigin = OriginCaller;
+    type AdminChangeDelay = ConstU64<10>;
+    type IdentityProvider = TestIdentityProvider;
+    type RewardPotTopUp = TestRewardPotTopUp;
+    type MaxNominationRequestCleanupsPerBlock = MaxNominationRequestCleanupsPerBlock;
+}
+
+pub const ADMIN_MULTISIG_THRESHOLD: u16 = 2;
+
+pub fn admin_signatories() -> Vec<AccountId> {
+    let mut signatories: Vec<AccountId> =
+        (1..=3).map(|id| TestAccount::new(100 + id).account_id()).collect();
+    signatories.sort();
+    signatories
+}
+
+pub fn admin_multisig_account() -> AccountId {
+    Multisig::multi_account_id(&admin_signatories(), ADMIN_MULTISIG_THRESHOLD)
+}
+
+// The multisig account of the admin signatories can dispatch admin calls
+pub struct AdminMultisig;
+impl SortedMembers<AccountId> for AdminMultisig {
+    fn sorted_members() -> Vec<AccountId> {
+        vec![admin_multisig_account()]
+    }
+}
+
+impl pallet_multisig::Config for Test {
+    type RuntimeEvent = RuntimeEvent;
+    type RuntimeCall = RuntimeCall;
+    type Currency = Balances;
+    type DepositBase = ConstU128<1>;
+    type DepositFactor = ConstU128<1>;
+    type MaxSignatories = ConstU32<3>;
+    type WeightInfo = ();
 }
 
 // Deal with any positive imbalance by sending it to the fake treasury
 pub struct TestCollatorPayoutDustHandler;
 impl CollatorPayoutDustHandler<Balance> for TestCollatorPayoutDustHandler {
-    fn handle_dust(dust_amount: Balance) {
+    fn handle_dust(dust_amount: Balance, _growth_period: u32) {
         // Transfer the amount and drop the imbalance to increase the total issuance
         let imbalance = Balances::deposit_creating(&fake_treasury(), dust_amount);
         drop(imbalance);
     }
 }
 
+// Mint the requested amount into the reward pot
+pub struct TestRewardPotTopUp;
+impl RewardPotTopUp<AccountId, Balance> for TestRewardPotTopUp {
+    fn top_up(reward_pot: &AccountId, amount: Balance) -> Balance {
+        let imbalance = Balances::deposit_creating(reward_pot, amount);
+        drop(imbalance);
+        amount
+    }
+}
+
 impl pallet_session::historical::Config for Test {
     type FullIdentification = AccountId;
     type FullIdentificationOf = ConvertInto;
@@ -270,6 +368,27 @@ thread_local! {
     pub static GROWTH_ENABLED: RefCell<bool> = RefCell::new(true);
 }
 
+thread_local! {
+    pub static OPERATOR_IDENTITIES: RefCell<Vec<(AccountId, OperatorIdentity)>> = RefCell::new(vec![]);
+}
+
+pub fn set_operator_identity(who: AccountId, identity: OperatorIdentity) {
+    OPERATOR_IDENTITIES.with(|identities| identities.borrow_mut().push((who, identity)));
+}
+
+pub struct TestIdentityProvider;
+impl OperatorIdentityProvider<AccountId> for TestIdentityProvider {
+    fn identity(who: &AccountId) -> Option<OperatorIdentity> {
+        OPERATOR_IDENTITIES.with(|identities| {
+            identities
+                .borrow()
+                .iter()
+                .find(|(account, _)| account == who)
+                .map(|(_, i)| i.clone())
+        })
+    }
+}
+
 pub struct TestGrowthEnabled;
 impl Get<bool> for TestGrowthEnabled {
     fn get() -> bool {
@@ -290,8 +409,7 @@ impl OnUnbalanced<pallet_balances::NegativeImbalance<Test>> for DealWithFees {
             if let Some(tips) = fees_then_tips.next() {
                 tips.merge_into(&mut fees);
             }
-            let staking_pot = ParachainStaking::compute_reward_pot_account_id();
-            Balances::resolve_creating(&staking_pot, fees);
+            <ParachainStaking as OnUnbalanced<_>>::on_unbalanced(fees);
         }
     }
 }
@@ -354,6 +472,10 @@ impl avn_proxy::Config for Test {
     type WeightInfo = ();
     type FeeHandler = Self;
     type Token = sp_core::H160;
+    type EthereumAddressMapping = ();
+    type EthereumCallFilter = frame_support::traits::Nothing;
+    type AdminOrigin = frame_system::EnsureRoot<AccountId>;
+    type MaxSessionKeyDuration = frame_support::traits::ConstU64<100>;
 }
 
 impl pallet_eth_bridge::Config for Test {
@@ -368,6 +490,7 @@ impl pallet_eth_bridge::Config for Test {
     type ReportCorroborationOffence = ();
     type ProcessedEventsChecker = ();
     type EthereumEventsFilter = ();
+    type AdminOrigin = frame_system::EnsureRoot<Self::AccountId>;
 }
 
 impl pallet_timestamp::Config for Test {
@@ -451,12 +574,31 @@ impl ProvableProxy<RuntimeCall, Signature, AccountId> for TestAvnProxyConfig {
                     nominator: _,
                 },
             ) => return Some(proof.clone()),
+            RuntimeCall::ParachainStaking(
+                pallet_parachain_staking::Call::signed_replace_nomination_request {
+                    proof,
+                    candidate: _,
+                    action: _,
+                },
+            ) => return Some(proof.clone()),
             RuntimeCall::ParachainStaking(
                 pallet_parachain_staking::Call::signed_execute_candidate_unbond {
                     proof,
                     candidate: _,
                 },
             ) => return Some(proof.clone()),
+            RuntimeCall::ParachainStaking(
+                pallet_parachain_staking::Call::signed_schedule_leave_candidates { proof },
+            ) => return Some(proof.clone()),
+            RuntimeCall::ParachainStaking(
+                pallet_parachain_staking::Call::signed_execute_leave_candidates {
+                    proof,
+                    candidate: _,
+                },
+            ) => return Some(proof.clone()),
+            RuntimeCall::ParachainStaking(
+                pallet_parachain_staking::Call::signed_cancel_leave_candidates { proof },
+            ) => return Some(proof.clone()),
 
             _ => None,
         }
@@ -613,6 +755,9 @@ pub(crate) fn roll_one_block() -> u64 {
         System::block_number(),
     );
     <pallet::Pallet<mock::Test> as OnInitialize<BlockNumber>>::on_initialize(System::block_number());
+    <pallet_scheduler::Pallet<mock::Test> as OnInitialize<BlockNumber>>::on_initialize(
+        System::block_number(),
+    );
     System::block_number()
 }
 
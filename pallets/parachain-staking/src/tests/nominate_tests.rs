@@ -61,6 +61,7 @@ mod proxy_signed_nominate {
             targets,
             amount,
             sender_nonce,
+            ParachainStaking::proxy_nonce_epoch(staker.account_id),
         );
 
         let signature = sign(&staker.key_pair, &data_to_sign);
@@ -0,0 +1,230 @@
+// Copyright 2024 Aventus Network Services (UK) Ltd.
+
+#![cfg(test)]
+
+use crate::{
+    assert_event_emitted, encode_signed_replace_nomination_request_params,
+    mock::{
+        build_proof, roll_to_era_begin, sign, AccountId, AvnProxy, ExtBuilder, ParachainStaking,
+        RuntimeCall as MockCall, RuntimeOrigin, Signature, Staker, Test, TestAccount,
+    },
+    CancelledScheduledRequest, Config, Error, Event, NominationAction, Proof, ScheduledRequest,
+};
+use frame_support::{assert_noop, assert_ok};
+
+const NOMINATION: u128 = 10;
+
+fn to_acc_id(id: u64) -> AccountId {
+    return TestAccount::new(id).account_id()
+}
+
+fn collator_1() -> AccountId {
+    to_acc_id(1u64)
+}
+
+fn collator_2() -> AccountId {
+    to_acc_id(2u64)
+}
+
+fn build_ext(staker: &Staker) -> sp_io::TestExternalities {
+    ExtBuilder::default()
+        .with_balances(vec![
+            (collator_1(), 10000),
+            (collator_2(), 10000),
+            (staker.account_id, 10000),
+            (staker.relayer, 10000),
+        ])
+        .with_candidates(vec![(collator_1(), 100), (collator_2(), 100)])
+        .with_nominations(vec![
+            (staker.account_id, collator_1(), NOMINATION),
+            (staker.account_id, collator_2(), NOMINATION),
+        ])
+        .build()
+}
+
+fn schedule_decrease(staker: &Staker, amount: u128) {
+    assert_ok!(ParachainStaking::schedule_nominator_unbond(
+        RuntimeOrigin::signed(staker.account_id),
+        collator_1(),
+        amount
+    ));
+}
+
+fn less_total(staker: &Staker) -> u128 {
+    ParachainStaking::nominator_state(staker.account_id)
+        .expect("Nominator exists")
+        .less_total
+}
+
+mod replace_nomination_request {
+    use super::*;
+
+    #[test]
+    fn replaces_a_decrease_with_a_revoke() {
+        let staker: Staker = Default::default();
+        build_ext(&staker).execute_with(|| {
+            schedule_decrease(&staker, 5);
+            roll_to_era_begin(2);
+
+            assert_ok!(ParachainStaking::replace_nomination_request(
+                RuntimeOrigin::signed(staker.account_id),
+                collator_1(),
+                NominationAction::Revoke(0)
+            ));
+
+            let when_executable = 2 + ParachainStaking::delay();
+            assert_eq!(
+                ParachainStaking::nomination_scheduled_requests(collator_1()).to_vec(),
+                vec![ScheduledRequest {
+                    nominator: staker.account_id,
+                    when_executable,
+                    action: NominationAction::Revoke(NOMINATION),
+                }]
+            );
+            assert_eq!(less_total(&staker), NOMINATION);
+            assert_event_emitted!(Event::CancelledNominationRequest {
+                nominator: staker.account_id,
+                collator: collator_1(),
+                cancelled_request: CancelledScheduledRequest {
+                    when_executable: 1 + ParachainStaking::delay(),
+                    action: NominationAction::Decrease(5),
+                },
+            });
+            assert_event_emitted!(Event::NominationRevocationScheduled {
+                era: 2,
+                nominator: staker.account_id,
+                candidate: collator_1(),
+                scheduled_exit: when_executable,
+            });
+        });
+    }
+
+    #[test]
+    fn replaces_a_decrease_with_another_amount() {
+        let staker: Staker = Default::default();
+        build_ext(&staker).execute_with(|| {
+            schedule_decrease(&staker, 5);
+
+            assert_ok!(ParachainStaking::replace_nomination_request(
+                RuntimeOrigin::signed(staker.account_id),
+                collator_1(),
+                NominationAction::Decrease(3)
+            ));
+
+            let requests = ParachainStaking::nomination_scheduled_requests(collator_1());
+            assert_eq!(requests.len(), 1);
+            assert_eq!(requests[0].action, NominationAction::Decrease(3));
+            assert_eq!(less_total(&staker), 3);
+        });
+    }
+
+    #[test]
+    fn keeps_the_pending_request_when_the_new_one_is_invalid() {
+        let staker: Staker = Default::default();
+        build_ext(&staker).execute_with(|| {
+            schedule_decrease(&staker, 5);
+
+            assert_noop!(
+                ParachainStaking::replace_nomination_request(
+                    RuntimeOrigin::signed(staker.account_id),
+                    collator_1(),
+                    NominationAction::Decrease(NOMINATION)
+                ),
+                Error::<Test>::NominatorBondBelowMin
+            );
+        });
+    }
+
+    #[test]
+    fn fails_without_a_pending_request() {
+        let staker: Staker = Default::default();
+        build_ext(&staker).execute_with(|| {
+            assert_noop!(
+                ParachainStaking::replace_nomination_request(
+                    RuntimeOrigin::signed(staker.account_id),
+                    collator_1(),
+                    NominationAction::Revoke(0)
+                ),
+                Error::<Test>::PendingNominationRequestDNE
+            );
+        });
+    }
+}
+
+mod proxy_signed_replace_nomination_request {
+    use super::*;
+
+    fn create_proof_for_signed_replace_nomination_request(
+        sender_nonce: u64,
+        staker: &Staker,
+        candidate: &AccountId,
+        action: &NominationAction<u128>,
+    ) -> Proof<Signature, AccountId> {
+        let data_to_sign = encode_signed_replace_nomination_request_params::<Test>(
+            staker.relayer.clone(),
+            candidate,
+            action,
+            sender_nonce,
+            ParachainStaking::proxy_nonce_epoch(staker.account_id),
+        );
+
+        let signature = sign(&staker.key_pair, &data_to_sign);
+        return build_proof(&staker.account_id, &staker.relayer, signature)
+    }
+
+    #[test]
+    fn succeeds_with_good_values() {
+        let staker: Staker = Default::default();
+        build_ext(&staker).execute_with(|| {
+            schedule_decrease(&staker, 5);
+            let nonce = ParachainStaking::proxy_nonce(staker.account_id);
+            let action = NominationAction::Revoke(0);
+            let proof = create_proof_for_signed_replace_nomination_request(
+                nonce,
+                &staker,
+                &collator_1(),
+                &action,
+            );
+            let call: Box<<Test as Config>::RuntimeCall> = Box::new(MockCall::ParachainStaking(
+                super::super::Call::<Test>::signed_replace_nomination_request {
+                    proof,
+                    candidate: collator_1(),
+                    action,
+                },
+            ));
+
+            assert_ok!(AvnProxy::proxy(RuntimeOrigin::signed(staker.relayer), call, None));
+
+            assert_eq!(
+                ParachainStaking::nomination_scheduled_requests(collator_1())[0].action,
+                NominationAction::Revoke(NOMINATION)
+            );
+            assert_eq!(ParachainStaking::proxy_nonce(staker.account_id), nonce + 1);
+        });
+    }
+
+    #[test]
+    fn fails_when_the_action_is_not_the_signed_one() {
+        let staker: Staker = Default::default();
+        build_ext(&staker).execute_with(|| {
+            schedule_decrease(&staker, 5);
+            let nonce = ParachainStaking::proxy_nonce(staker.account_id);
+            let proof = create_proof_for_signed_replace_nomination_request(
+                nonce,
+                &staker,
+                &collator_1(),
+                &NominationAction::Decrease(3),
+            );
+
+            assert_noop!(
+                ParachainStaking::signed_replace_nomination_request(
+                    RuntimeOrigin::signed(staker.account_id),
+                    proof,
+                    collator_1(),
+                    NominationAction::Revoke(0)
+                ),
+                Error::<Test>::UnauthorizedSignedReplaceNominationRequestTransaction
+            );
+        });
+    }
+}
@@ -55,6 +55,7 @@ mod proxy_signed_schedule_revoke_nomination {
             staker.relayer.clone(),
             collator,
             sender_nonce,
+            ParachainStaking::proxy_nonce_epoch(staker.account_id),
         );
 
         let signature = sign(&staker.key_pair, &data_to_sign);
@@ -284,6 +285,7 @@ mod proxy_signed_schedule_leave_nominators {
         let data_to_sign = encode_signed_schedule_leave_nominators_params::<Test>(
             staker.relayer.clone(),
             sender_nonce,
+            ParachainStaking::proxy_nonce_epoch(staker.account_id),
         );
 
         let signature = sign(&staker.key_pair, &data_to_sign);
@@ -467,6 +469,7 @@ mod proxy_signed_execute_revoke_all_nomination {
             staker.relayer.clone(),
             nominator,
             sender_nonce,
+            ParachainStaking::proxy_nonce_epoch(staker.account_id),
         );
 
         let signature = sign(&staker.key_pair, &data_to_sign);
@@ -110,6 +110,7 @@ mod proxy_signed_schedule_nominator_unbond {
             staker.relayer.clone(),
             reduction_amount,
             sender_nonce,
+            ParachainStaking::proxy_nonce_epoch(staker.account_id),
         );
 
         let signature = sign(&staker.key_pair, &data_to_sign);
@@ -476,6 +477,7 @@ mod proxy_signed_schedule_collator_unbond {
             staker.relayer.clone(),
             reduction_amount,
             sender_nonce,
+            ParachainStaking::proxy_nonce_epoch(staker.account_id),
         );
 
         let signature = sign(&staker.key_pair, &data_to_sign);
@@ -721,6 +723,7 @@ mod signed_execute_nomination_request {
             staker.relayer.clone(),
             nominator,
             sender_nonce,
+            ParachainStaking::proxy_nonce_epoch(staker.account_id),
         );
 
         let signature = sign(&staker.key_pair, &data_to_sign);
@@ -983,6 +986,7 @@ mod signed_execute_candidate_unbond {
             staker.relayer.clone(),
             candidate,
             sender_nonce,
+            ParachainStaking::proxy_nonce_epoch(staker.account_id),
         );
 
         let signature = sign(&staker.key_pair, &data_to_sign);
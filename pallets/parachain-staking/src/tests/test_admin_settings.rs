@@ -154,3 +154,27 @@ mod min_collator_stake_admin_setting {
         });
     }
 }
+
+mod growth_equal_share_admin_setting {
+    use super::*;
+    use crate::{GrowthDistribution, GrowthDistributionPolicy};
+    use sp_runtime::Perbill;
+
+    #[test]
+    fn updates_the_growth_distribution_and_its_version() {
+        ExtBuilder::default().build().execute_with(|| {
+            let new_setting =
+                AdminSettings::<BalanceOf<Test>>::GrowthEqualShare(Perbill::from_percent(40));
+
+            assert_ok!(ParachainStaking::set_admin_setting(Origin::root(), new_setting.clone()));
+
+            assert_eq!(
+                <GrowthDistributionPolicy<Test>>::get(),
+                GrowthDistribution { version: 1, equal_share: Perbill::from_percent(40) }
+            );
+            assert_last_event!(MetaEvent::ParachainStaking(Event::AdminSettingsUpdated {
+                value: new_setting
+            }));
+        });
+    }
+}
@@ -0,0 +1,178 @@
+#[cfg(test)]
+use crate::mock::{
+    admin_multisig_account, roll_to, ExtBuilder, ParachainStaking, RuntimeOrigin as Origin, System,
+    Test, TestAccount,
+};
+use crate::{
+    assert_event_emitted, Call as StakingCall, Config, Error, Event, PendingAdminChanges,
+    TotalSelected,
+};
+use frame_support::{assert_noop, assert_ok, traits::Get};
+
+const NEW_TOTAL_SELECTED: u32 = 6;
+
+fn announce_total_selected_change() -> [u8; 32] {
+    assert_ok!(ParachainStaking::set_blocks_per_era(Origin::root(), 10u32));
+    assert_ok!(ParachainStaking::set_total_selected(
+        Origin::signed(admin_multisig_account()),
+        NEW_TOTAL_SELECTED
+    ));
+
+    ParachainStaking::admin_change_name(&StakingCall::<Test>::set_total_selected {
+        new: NEW_TOTAL_SELECTED,
+    })
+}
+
+fn execution_block() -> u64 {
+    System::block_number() + <Test as Config>::AdminChangeDelay::get()
+}
+
+mod total_selected_changed_by_the_admin {
+    use super::*;
+
+    #[test]
+    fn is_announced_instead_of_applied() {
+        ExtBuilder::default().build().execute_with(|| {
+            let execute_at = execution_block();
+            let schedule_name = announce_total_selected_change();
+
+            assert_eq!(<TotalSelected<Test>>::get(), 5);
+            assert_eq!(<PendingAdminChanges<Test>>::get(schedule_name), Some(execute_at));
+            assert_event_emitted!(Event::TotalSelectedChangeAnnounced {
+                new: NEW_TOTAL_SELECTED,
+                execute_at,
+                schedule_name,
+            });
+        });
+    }
+
+    #[test]
+    fn is_applied_once_the_delay_has_passed() {
+        ExtBuilder::default().build().execute_with(|| {
+            let execute_at = execution_block();
+            let schedule_name = announce_total_selected_change();
+
+            roll_to(execute_at - 1);
+            assert_eq!(<TotalSelected<Test>>::get(), 5);
+
+            roll_to(execute_at);
+            assert_eq!(<TotalSelected<Test>>::get(), NEW_TOTAL_SELECTED);
+            assert_eq!(<PendingAdminChanges<Test>>::get(schedule_name), None);
+            assert_event_emitted!(Event::TotalSelectedSet { old: 5, new: NEW_TOTAL_SELECTED });
+        });
+    }
+
+    #[test]
+    fn is_not_applied_when_cancelled() {
+        ExtBuilder::default().build().execute_with(|| {
+            let execute_at = execution_block();
+            let schedule_name = announce_total_selected_change();
+
+            assert_ok!(ParachainStaking::cancel_admin_change(
+                Origin::signed(admin_multisig_account()),
+                schedule_name
+            ));
+            assert_eq!(<PendingAdminChanges<Test>>::get(schedule_name), None);
+            assert_event_emitted!(Event::AdminChangeCancelled { schedule_name });
+
+            roll_to(execute_at);
+            assert_eq!(<TotalSelected<Test>>::get(), 5);
+        });
+    }
+
+    #[test]
+    fn is_applied_immediately_by_root() {
+        ExtBuilder::default().build().execute_with(|| {
+            assert_ok!(ParachainStaking::set_blocks_per_era(Origin::root(), 10u32));
+            assert_ok!(ParachainStaking::set_total_selected(Origin::root(), NEW_TOTAL_SELECTED));
+
+            assert_eq!(<TotalSelected<Test>>::get(), NEW_TOTAL_SELECTED);
+        });
+    }
+
+    #[test]
+    fn is_superseded_by_a_newer_announcement() {
+        ExtBuilder::default().build().execute_with(|| {
+            let schedule_name = announce_total_selected_change();
+            roll_to(System::block_number() + 1);
+            let execute_at = execution_block();
+
+            assert_ok!(ParachainStaking::set_total_selected(
+                Origin::signed(admin_multisig_account()),
+                NEW_TOTAL_SELECTED + 1
+            ));
+            assert_eq!(<PendingAdminChanges<Test>>::get(schedule_name), None);
+            assert_event_emitted!(Event::AdminChangeCancelled { schedule_name });
+
+            roll_to(execute_at - 1);
+            assert_eq!(<TotalSelected<Test>>::get(), 5);
+
+            roll_to(execute_at);
+            assert_eq!(<TotalSelected<Test>>::get(), NEW_TOTAL_SELECTED + 1);
+            assert_eq!(<PendingAdminChanges<Test>>::iter().count(), 0);
+        });
+    }
+
+    #[test]
+    fn is_superseded_by_a_change_applied_by_root() {
+        ExtBuilder::default().build().execute_with(|| {
+            let execute_at = execution_block();
+            let schedule_name = announce_total_selected_change();
+
+            assert_ok!(ParachainStaking::set_total_selected(
+                Origin::root(),
+                NEW_TOTAL_SELECTED + 1
+            ));
+            assert_eq!(<PendingAdminChanges<Test>>::get(schedule_name), None);
+
+            roll_to(execute_at);
+            assert_eq!(<TotalSelected<Test>>::get(), NEW_TOTAL_SELECTED + 1);
+        });
+    }
+
+    #[test]
+    fn is_forgotten_once_its_execution_has_failed() {
+        ExtBuilder::default().build().execute_with(|| {
+            let execute_at = execution_block();
+            let schedule_name = announce_total_selected_change();
+            // The era becomes too short for the announced number of collators
+            assert_ok!(ParachainStaking::set_blocks_per_era(Origin::root(), 5u32));
+
+            roll_to(execute_at);
+            assert_eq!(<TotalSelected<Test>>::get(), 5);
+            assert_eq!(<PendingAdminChanges<Test>>::get(schedule_name), Some(execute_at));
+
+            roll_to(execute_at + 1);
+            assert_eq!(<PendingAdminChanges<Test>>::get(schedule_name), None);
+        });
+    }
+}
+
+mod cancelling_an_admin_change {
+    use super::*;
+
+    #[test]
+    fn fails_when_the_change_was_not_announced() {
+        ExtBuilder::default().build().execute_with(|| {
+            assert_noop!(
+                ParachainStaking::cancel_admin_change(Origin::root(), [0u8; 32]),
+                Error::<Test>::AdminChangeNotFound
+            );
+        });
+    }
+
+    #[test]
+    fn fails_when_the_origin_is_not_the_admin() {
+        ExtBuilder::default().build().execute_with(|| {
+            let schedule_name = announce_total_selected_change();
+
+            assert_noop!(
+                ParachainStaking::cancel_admin_change(
+                    Origin::signed(TestAccount::new(45).account_id()),
+                    schedule_name
+                ),
+                sp_runtime::DispatchError::BadOrigin
+            );
+        });
+    }
+}
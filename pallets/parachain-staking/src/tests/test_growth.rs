@@ -6,7 +6,7 @@ use crate::{
         RuntimeOrigin, System, Test, TestAccount,
     },
     BalanceOf, CollatorScore, EraIndex, Error, Event, Growth, GrowthInfo, GrowthPeriod,
-    GrowthPeriodInfo, ProcessedGrowthPeriods,
+    GrowthPeriodInfo, MissedGrowthPayments, ProcessedGrowthPeriods,
 };
 use codec::{Decode, Encode};
 use frame_support::{assert_noop, assert_ok};
@@ -689,6 +689,58 @@ mod growth_amount {
         }
     }
 
+    mod with_an_equal_share {
+        use super::*;
+        use crate::{AdminSettings, GrowthDistribution};
+
+        #[test]
+        fn splits_part_of_the_growth_equally() {
+            let collator_1 = to_acc_id(1u64);
+            let collator_2 = to_acc_id(2u64);
+            let total_points = COLLATOR1_POINTS + COLLATOR2_POINTS;
+            ExtBuilder::default()
+                .with_balances(vec![(collator_1, COLLATOR_BALANCE), (collator_2, COLLATOR_BALANCE)])
+                .with_candidates(vec![(collator_1, 10), (collator_2, 10)])
+                .build()
+                .execute_with(|| {
+                    set_growth_data(
+                        TOTAL_STAKE,
+                        TOTAL_REWARD,
+                        total_points,
+                        BoundedVec::truncate_from(vec![
+                            CollatorScore::new(collator_1, COLLATOR1_POINTS),
+                            CollatorScore::new(collator_2, COLLATOR2_POINTS),
+                        ]),
+                    );
+                    assert_ok!(ParachainStaking::set_admin_setting(
+                        RuntimeOrigin::root(),
+                        AdminSettings::GrowthEqualShare(Perbill::from_percent(40))
+                    ));
+
+                    let amount = 3000;
+                    assert_ok!(ParachainStaking::payout_collators(amount, PERIOD_INDEX));
+
+                    // 1200 is split equally and the remaining 1800 by points (2/3 and 1/3)
+                    assert_eq!(
+                        Balances::free_balance(&collator_1),
+                        COLLATOR_BALANCE + 600 + Perbill::from_rational::<u32>(2, 3) * 1800
+                    );
+                    assert_eq!(
+                        Balances::free_balance(&collator_2),
+                        COLLATOR_BALANCE + 600 + Perbill::from_rational::<u32>(1, 3) * 1800
+                    );
+                    assert_event_emitted!(Event::GrowthDistributed {
+                        period: PERIOD_INDEX,
+                        amount,
+                        distribution: GrowthDistribution {
+                            version: 1,
+                            equal_share: Perbill::from_percent(40),
+                        },
+                    });
+                });
+        }
+    }
+
     mod fails_to_be_paid {
         use super::*;
 
@@ -751,12 +803,572 @@ mod growth_amount {
                     assert_eq!(false, <ProcessedGrowthPeriods<Test>>::contains_key(PERIOD_INDEX));
 
                     let amount = u128::max_value();
-                    // Payout fails due to overflow
+                    let collator_1_amount =
+                        Perbill::from_rational(COLLATOR1_POINTS, total_points) * amount;
+                    let collator_2_amount =
+                        Perbill::from_rational(COLLATOR2_POINTS, total_points) * amount;
+
+                    // Paying collator 1 overflows, but the period is still processed
+                    assert_ok!(ParachainStaking::payout_collators(amount, PERIOD_INDEX));
+
+                    assert_eq!(true, <ProcessedGrowthPeriods<Test>>::contains_key(PERIOD_INDEX));
+                    assert_eq!(
+                        ParachainStaking::missed_growth_payment(PERIOD_INDEX, collator_1),
+                        Some(collator_1_amount)
+                    );
+                    assert_eq!(
+                        ParachainStaking::missed_growth_payment(PERIOD_INDEX, collator_2),
+                        None
+                    );
+                    assert_eq!(
+                        Balances::free_balance(&collator_2),
+                        collator_balance + collator_2_amount
+                    );
+                    assert_event_emitted!(Event::GrowthPaymentMissed {
+                        account: collator_1,
+                        amount: collator_1_amount,
+                        period: PERIOD_INDEX,
+                    });
+                });
+        }
+    }
+
+    mod missed_payments {
+        use super::*;
+        use frame_support::traits::Currency;
+
+        const AMOUNT: u128 = 300;
+
+        // `previous_collator` has no account, so its growth payment is missed
+        fn pay_growth(collator: AccountId, previous_collator: AccountId) {
+            set_growth_data(
+                TOTAL_STAKE,
+                TOTAL_REWARD,
+                COLLATOR1_POINTS + COLLATOR2_POINTS,
+                BoundedVec::truncate_from(vec![
+                    CollatorScore::new(collator, COLLATOR1_POINTS),
+                    CollatorScore::new(previous_collator, COLLATOR2_POINTS),
+                ]),
+            );
+            assert_ok!(ParachainStaking::payout_collators(AMOUNT, PERIOD_INDEX));
+        }
+
+        fn missed_amount() -> u128 {
+            Perbill::from_rational(COLLATOR2_POINTS, COLLATOR1_POINTS + COLLATOR2_POINTS) * AMOUNT
+        }
+
+        #[test]
+        fn can_be_claimed_once_the_collator_can_receive_them() {
+            let collator = to_acc_id(1u64);
+            let previous_collator = to_acc_id(3u64);
+            ExtBuilder::default()
+                .with_balances(vec![(collator, COLLATOR_BALANCE)])
+                .with_candidates(vec![(collator, 10)])
+                .build()
+                .execute_with(|| {
+                    pay_growth(collator, previous_collator);
+                    assert_eq!(
+                        ParachainStaking::missed_growth_payment(PERIOD_INDEX, previous_collator),
+                        Some(missed_amount())
+                    );
+
+                    Balances::make_free_balance_be(&previous_collator, COLLATOR_BALANCE);
+                    assert_ok!(ParachainStaking::claim_missed_growth_payment(
+                        RuntimeOrigin::signed(previous_collator),
+                        PERIOD_INDEX
+                    ));
+
+                    assert_eq!(
+                        Balances::free_balance(&previous_collator),
+                        COLLATOR_BALANCE + missed_amount()
+                    );
+                    assert_eq!(
+                        <MissedGrowthPayments<Test>>::contains_key(PERIOD_INDEX, previous_collator),
+                        false
+                    );
+                    assert_event_emitted!(Event::MissedGrowthPaymentClaimed {
+                        account: previous_collator,
+                        amount: missed_amount(),
+                        period: PERIOD_INDEX,
+                    });
+                });
+        }
+
+        #[test]
+        fn are_kept_when_the_claim_fails() {
+            let collator = to_acc_id(1u64);
+            let previous_collator = to_acc_id(3u64);
+            ExtBuilder::default()
+                .with_balances(vec![(collator, COLLATOR_BALANCE)])
+                .with_candidates(vec![(collator, 10)])
+                .build()
+                .execute_with(|| {
+                    pay_growth(collator, previous_collator);
+
                     assert_noop!(
-                        ParachainStaking::payout_collators(amount, PERIOD_INDEX),
+                        ParachainStaking::claim_missed_growth_payment(
+                            RuntimeOrigin::signed(previous_collator),
+                            PERIOD_INDEX
+                        ),
                         Error::<Test>::ErrorPayingCollator
                     );
+                    assert_eq!(
+                        ParachainStaking::missed_growth_payment(PERIOD_INDEX, previous_collator),
+                        Some(missed_amount())
+                    );
                 });
         }
+
+        #[test]
+        fn cannot_be_claimed_by_another_account() {
+            let collator = to_acc_id(1u64);
+            let previous_collator = to_acc_id(3u64);
+            ExtBuilder::default()
+                .with_balances(vec![(collator, COLLATOR_BALANCE)])
+                .with_candidates(vec![(collator, 10)])
+                .build()
+                .execute_with(|| {
+                    pay_growth(collator, previous_collator);
+
+                    assert_noop!(
+                        ParachainStaking::claim_missed_growth_payment(
+                            RuntimeOrigin::signed(collator),
+                            PERIOD_INDEX
+                        ),
+                        Error::<Test>::NoMissedGrowthPayment
+                    );
+                });
+        }
+    }
+
+    mod payout_statement {
+        use super::*;
+        use crate::PAYOUT_STATEMENTS_DEPTH;
+
+        const AMOUNT: u128 = 301;
+
+        fn pay_growth(period: u32, collator: AccountId, previous_collator: AccountId) {
+            <Growth<Test>>::insert(
+                period,
+                GrowthInfo {
+                    number_of_accumulations: 1,
+                    total_stake_accumulated: TOTAL_STAKE,
+                    total_staker_reward: TOTAL_REWARD,
+                    total_points: COLLATOR1_POINTS + COLLATOR2_POINTS,
+                    collator_scores: BoundedVec::truncate_from(vec![
+                        CollatorScore::new(collator, COLLATOR1_POINTS),
+                        CollatorScore::new(previous_collator, COLLATOR2_POINTS),
+                    ]),
+                    tx_id: None,
+                    triggered: None,
+                },
+            );
+            assert_ok!(ParachainStaking::payout_collators(AMOUNT, period));
+        }
+
+        #[test]
+        fn summarises_the_payout_of_the_period() {
+            let collator = to_acc_id(1u64);
+            let previous_collator = to_acc_id(3u64);
+            ExtBuilder::default()
+                .with_balances(vec![(collator, COLLATOR_BALANCE)])
+                .with_candidates(vec![(collator, 10)])
+                .build()
+                .execute_with(|| {
+                    pay_growth(PERIOD_INDEX, collator, previous_collator);
+
+                    let statement = ParachainStaking::payout_statement(PERIOD_INDEX).unwrap();
+                    assert_eq!(statement.amount, AMOUNT);
+                    assert_eq!(statement.collators_paid, 1);
+                    assert_eq!(
+                        statement.amount_paid,
+                        Balances::free_balance(&collator) - COLLATOR_BALANCE
+                    );
+                    assert_eq!(statement.missed_payments, 1);
+                    assert_eq!(
+                        Some(statement.amount_missed),
+                        ParachainStaking::missed_growth_payment(PERIOD_INDEX, previous_collator)
+                    );
+                    assert_eq!(
+                        statement.amount_paid + statement.amount_missed + statement.dust,
+                        AMOUNT
+                    );
+                    assert_event_emitted!(Event::CollatorPayoutCompleted {
+                        period: PERIOD_INDEX,
+                        statement,
+                    });
+                });
+        }
+
+        #[test]
+        fn is_only_kept_for_the_last_periods() {
+            let collator = to_acc_id(1u64);
+            let previous_collator = to_acc_id(3u64);
+            ExtBuilder::default()
+                .with_balances(vec![(collator, COLLATOR_BALANCE)])
+                .with_candidates(vec![(collator, 10)])
+                .build()
+                .execute_with(|| {
+                    let last_period = PERIOD_INDEX + PAYOUT_STATEMENTS_DEPTH;
+                    pay_growth(PERIOD_INDEX, collator, previous_collator);
+                    pay_growth(last_period - 1, collator, previous_collator);
+                    assert!(ParachainStaking::payout_statement(PERIOD_INDEX).is_some());
+
+                    pay_growth(last_period, collator, previous_collator);
+
+                    assert!(ParachainStaking::payout_statement(PERIOD_INDEX).is_none());
+                    assert!(ParachainStaking::payout_statement(last_period - 1).is_some());
+                    assert!(ParachainStaking::payout_statement(last_period).is_some());
+                });
+        }
+    }
+
+    mod of_exited_collators {
+        use super::*;
+        use crate::{AdminSettings, ExitedCollatorGrowthPolicy};
+
+        const AMOUNT: u128 = 300;
+
+        // `exited_collator` has an account but is no longer a candidate
+        fn build_ext(collator: AccountId, exited_collator: AccountId) -> sp_io::TestExternalities {
+            ExtBuilder::default()
+                .with_balances(vec![
+                    (collator, COLLATOR_BALANCE),
+                    (exited_collator, COLLATOR_BALANCE),
+                ])
+                .with_candidates(vec![(collator, 10)])
+                .build()
+        }
+
+        fn pay_growth(collator: AccountId, exited_collator: AccountId) {
+            set_growth_data(
+                TOTAL_STAKE,
+                TOTAL_REWARD,
+                COLLATOR1_POINTS + COLLATOR2_POINTS,
+                BoundedVec::truncate_from(vec![
+                    CollatorScore::new(collator, COLLATOR1_POINTS),
+                    CollatorScore::new(exited_collator, COLLATOR2_POINTS),
+                ]),
+            );
+            assert_ok!(ParachainStaking::payout_collators(AMOUNT, PERIOD_INDEX));
+        }
+
+        fn set_policy(policy: ExitedCollatorGrowthPolicy) {
+            assert_ok!(ParachainStaking::set_admin_setting(
+                RuntimeOrigin::root(),
+                AdminSettings::ExitedCollatorGrowth(policy)
+            ));
+        }
+
+        #[test]
+        fn are_paid_by_default() {
+            let collator = to_acc_id(1u64);
+            let exited_collator = to_acc_id(3u64);
+            build_ext(collator, exited_collator).execute_with(|| {
+                pay_growth(collator, exited_collator);
+
+                let total_points = COLLATOR1_POINTS + COLLATOR2_POINTS;
+                assert_eq!(
+                    Balances::free_balance(&exited_collator),
+                    COLLATOR_BALANCE +
+                        Perbill::from_rational(COLLATOR2_POINTS, total_points) * AMOUNT
+                );
+                assert_eq!(
+                    Balances::free_balance(&collator),
+                    COLLATOR_BALANCE +
+                        Perbill::from_rational(COLLATOR1_POINTS, total_points) * AMOUNT
+                );
+            });
+        }
+
+        #[test]
+        fn have_their_share_redistributed_when_the_policy_says_so() {
+            let collator = to_acc_id(1u64);
+            let exited_collator = to_acc_id(3u64);
+            build_ext(collator, exited_collator).execute_with(|| {
+                set_policy(ExitedCollatorGrowthPolicy::Redistribute);
+
+                pay_growth(collator, exited_collator);
+
+                assert_eq!(Balances::free_balance(&exited_collator), COLLATOR_BALANCE);
+                assert_eq!(Balances::free_balance(&collator), COLLATOR_BALANCE + AMOUNT);
+                assert_eq!(
+                    ParachainStaking::missed_growth_payment(PERIOD_INDEX, exited_collator),
+                    None
+                );
+                assert_event_emitted!(Event::ExitedCollatorGrowthRedistributed {
+                    account: exited_collator,
+                    points: COLLATOR2_POINTS,
+                    period: PERIOD_INDEX,
+                });
+            });
+        }
+
+        #[test]
+        fn are_paid_when_no_collator_is_left_to_redistribute_to() {
+            let exited_collator_1 = to_acc_id(3u64);
+            let exited_collator_2 = to_acc_id(4u64);
+            ExtBuilder::default()
+                .with_balances(vec![
+                    (exited_collator_1, COLLATOR_BALANCE),
+                    (exited_collator_2, COLLATOR_BALANCE),
+                ])
+                .build()
+                .execute_with(|| {
+                    set_policy(ExitedCollatorGrowthPolicy::Redistribute);
+
+                    pay_growth(exited_collator_1, exited_collator_2);
+
+                    let total_points = COLLATOR1_POINTS + COLLATOR2_POINTS;
+                    assert_eq!(
+                        Balances::free_balance(&exited_collator_1),
+                        COLLATOR_BALANCE +
+                            Perbill::from_rational(COLLATOR1_POINTS, total_points) * AMOUNT
+                    );
+                    assert_eq!(
+                        Balances::free_balance(&exited_collator_2),
+                        COLLATOR_BALANCE +
+                            Perbill::from_rational(COLLATOR2_POINTS, total_points) * AMOUNT
+                    );
+                });
+        }
+    }
+}
+
+mod collator_scores_over_the_limit {
+    use super::*;
+    use crate::{CollatorMaxScores, DroppedCollatorScores};
+    use frame_support::traits::Get;
+
+    fn scores(number_of_scores: u64) -> Vec<CollatorScore<AccountId>> {
+        (0..number_of_scores)
+            .map(|id| CollatorScore::new(TestAccount::new(id).account_id(), DEFAULT_POINTS))
+            .collect()
+    }
+
+    #[test]
+    fn are_kept_when_within_the_limit() {
+        ExtBuilder::default().build().execute_with(|| {
+            let max_scores = CollatorMaxScores::get() as u64;
+
+            let bounded = ParachainStaking::bound_collator_scores(1, scores(max_scores));
+
+            assert_eq!(bounded.len() as u64, max_scores);
+            assert_eq!(<DroppedCollatorScores<Test>>::get(), 0);
+        });
+    }
+
+    #[test]
+    fn are_counted_and_reported() {
+        ExtBuilder::default().build().execute_with(|| {
+            let max_scores = CollatorMaxScores::get() as u64;
+
+            let bounded = ParachainStaking::bound_collator_scores(1, scores(max_scores + 3));
+            ParachainStaking::bound_collator_scores(2, scores(max_scores + 2));
+
+            assert_eq!(bounded.len() as u64, max_scores);
+            assert_eq!(<DroppedCollatorScores<Test>>::get(), 5);
+            assert_event_emitted!(Event::CollatorScoresDropped { era: 1, dropped: 3 });
+            assert_event_emitted!(Event::CollatorScoresDropped { era: 2, dropped: 2 });
+        });
+    }
+}
+
+mod audit_growth {
+    use super::*;
+    use crate::{
+        AwardedPts, DelayedPayout, DelayedPayouts, GrowthDiscrepancy, GrowthStartEra, Points,
+    };
+    use frame_support::BoundedVec;
+
+    const GROWTH_PERIOD: u32 = 1;
+    const START_ERA: EraIndex = 2;
+    const ERA_REWARD: u128 = 100;
+
+    fn collator_1() -> AccountId {
+        to_acc_id(1)
+    }
+
+    fn collator_2() -> AccountId {
+        to_acc_id(2)
+    }
+
+    // Records the staking data of two eras and a growth that matches it
+    fn setup_growth() -> GrowthInfo<AccountId, BalanceOf<Test>> {
+        for era in START_ERA..START_ERA + 2 {
+            <DelayedPayouts<Test>>::insert(era, DelayedPayout { total_staking_reward: ERA_REWARD });
+            set_equal_points_for_collators(era, collator_1(), collator_2());
+        }
+
+        let mut growth = GrowthInfo::new(2);
+        growth.total_staker_reward = ERA_REWARD * 2;
+        growth.total_points = DEFAULT_POINTS * 4;
+        growth.collator_scores = BoundedVec::truncate_from(vec![
+            CollatorScore::new(collator_1(), DEFAULT_POINTS * 2),
+            CollatorScore::new(collator_2(), DEFAULT_POINTS * 2),
+        ]);
+        <Growth<Test>>::insert(GROWTH_PERIOD, growth.clone());
+        <GrowthStartEra<Test>>::insert(GROWTH_PERIOD, START_ERA);
+        growth
+    }
+
+    #[test]
+    fn reports_no_discrepancy_for_a_consistent_record() {
+        ExtBuilder::default().build().execute_with(|| {
+            let growth = setup_growth();
+
+            assert_ok!(ParachainStaking::audit_growth(RuntimeOrigin::root(), GROWTH_PERIOD, true));
+
+            assert_eq!(ParachainStaking::growth(GROWTH_PERIOD).encode(), growth.encode());
+            assert_event_emitted!(Event::GrowthAudited {
+                growth_period: GROWTH_PERIOD,
+                discrepancies: 0,
+                corrected: false
+            });
+        });
+    }
+
+    #[test]
+    fn reports_discrepancies_without_correcting_them() {
+        ExtBuilder::default().build().execute_with(|| {
+            let mut growth = setup_growth();
+            growth.total_staker_reward = ERA_REWARD;
+            growth.total_points = DEFAULT_POINTS;
+            <Growth<Test>>::insert(GROWTH_PERIOD, growth.clone());
+
+            assert_ok!(ParachainStaking::audit_growth(RuntimeOrigin::root(), GROWTH_PERIOD, false));
+
+            assert_eq!(ParachainStaking::growth(GROWTH_PERIOD).encode(), growth.encode());
+            assert_event_emitted!(Event::GrowthDiscrepancyFound {
+                growth_period: GROWTH_PERIOD,
+                discrepancy: GrowthDiscrepancy::TotalStakerReward {
+                    stored: ERA_REWARD,
+                    expected: ERA_REWARD * 2
+                },
+            });
+            assert_event_emitted!(Event::GrowthDiscrepancyFound {
+                growth_period: GROWTH_PERIOD,
+                discrepancy: GrowthDiscrepancy::TotalPoints {
+                    stored: DEFAULT_POINTS,
+                    expected: DEFAULT_POINTS * 4
+                },
+            });
+            assert_event_emitted!(Event::GrowthAudited {
+                growth_period: GROWTH_PERIOD,
+                discrepancies: 2,
+                corrected: false
+            });
+        });
+    }
+
+    #[test]
+    fn corrects_discrepancies() {
+        ExtBuilder::default().build().execute_with(|| {
+            let expected = setup_growth();
+            let mut growth = expected.clone();
+            growth.total_staker_reward = 0;
+            growth.collator_scores =
+                BoundedVec::truncate_from(vec![CollatorScore::new(collator_1(), DEFAULT_POINTS)]);
+            <Growth<Test>>::insert(GROWTH_PERIOD, growth);
+
+            assert_ok!(ParachainStaking::audit_growth(RuntimeOrigin::root(), GROWTH_PERIOD, true));
+
+            let corrected = ParachainStaking::growth(GROWTH_PERIOD);
+            assert_eq!(corrected.total_staker_reward, expected.total_staker_reward);
+            let mut scores: Vec<(AccountId, u32)> = corrected
+                .collator_scores
+                .iter()
+                .map(|s| (s.collator.clone(), s.points))
+                .collect();
+            scores.sort();
+            let mut expected_scores: Vec<(AccountId, u32)> = expected
+                .collator_scores
+                .iter()
+                .map(|s| (s.collator.clone(), s.points))
+                .collect();
+            expected_scores.sort();
+            assert_eq!(scores, expected_scores);
+            assert_event_emitted!(Event::GrowthDiscrepancyFound {
+                growth_period: GROWTH_PERIOD,
+                discrepancy: GrowthDiscrepancy::CollatorScores {
+                    stored_collators: 1,
+                    expected_collators: 2
+                },
+            });
+            assert_event_emitted!(Event::GrowthAudited {
+                growth_period: GROWTH_PERIOD,
+                discrepancies: 2,
+                corrected: true
+            });
+        });
+    }
+
+    #[test]
+    fn skips_totals_of_eras_that_have_been_paid() {
+        ExtBuilder::default().build().execute_with(|| {
+            let mut growth = setup_growth();
+            <AwardedPts<Test>>::remove(START_ERA, collator_1());
+            <DelayedPayouts<Test>>::remove(START_ERA + 1);
+            growth.total_staker_reward = 0;
+            growth.total_points = 0;
+            <Growth<Test>>::insert(GROWTH_PERIOD, growth.clone());
+
+            assert_ok!(ParachainStaking::audit_growth(RuntimeOrigin::root(), GROWTH_PERIOD, true));
+
+            assert_eq!(ParachainStaking::growth(GROWTH_PERIOD).encode(), growth.encode());
+            assert_event_emitted!(Event::GrowthAudited {
+                growth_period: GROWTH_PERIOD,
+                discrepancies: 0,
+                corrected: false
+            });
+        });
+    }
+
+    #[test]
+    fn cannot_correct_a_published_growth() {
+        ExtBuilder::default().build().execute_with(|| {
+            let mut growth = setup_growth();
+            growth.tx_id = Some(1);
+            <Growth<Test>>::insert(GROWTH_PERIOD, growth);
+
+            assert_noop!(
+                ParachainStaking::audit_growth(RuntimeOrigin::root(), GROWTH_PERIOD, true),
+                Error::<Test>::GrowthAlreadyPublished
+            );
+            assert_ok!(ParachainStaking::audit_growth(RuntimeOrigin::root(), GROWTH_PERIOD, false));
+        });
+    }
+
+    #[test]
+    fn fails_without_the_eras_of_the_period() {
+        ExtBuilder::default().build().execute_with(|| {
+            setup_growth();
+            <GrowthStartEra<Test>>::remove(GROWTH_PERIOD);
+
+            assert_noop!(
+                ParachainStaking::audit_growth(RuntimeOrigin::root(), GROWTH_PERIOD, false),
+                Error::<Test>::GrowthErasUnknown
+            );
+            assert_noop!(
+                ParachainStaking::audit_growth(RuntimeOrigin::root(), GROWTH_PERIOD + 1, false),
+                Error::<Test>::GrowthDataNotFound
+            );
+        });
+    }
+
+    #[test]
+    fn requires_root() {
+        ExtBuilder::default().build().execute_with(|| {
+            setup_growth();
+
+            assert_noop!(
+                ParachainStaking::audit_growth(
+                    RuntimeOrigin::signed(collator_1()),
+                    GROWTH_PERIOD,
+                    false
+                ),
+                sp_runtime::DispatchError::BadOrigin
+            );
+        });
     }
 }
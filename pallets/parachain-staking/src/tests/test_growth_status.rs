@@ -0,0 +1,129 @@
+#[cfg(test)]
+use crate::mock::{ErasPerGrowthPeriod, ExtBuilder, ParachainStaking, Test, TestAccount};
+use crate::{
+    Growth, GrowthAccumulation, GrowthInfo, GrowthPayout, GrowthPeriod, GrowthPeriodIndex,
+    GrowthStartEra, GrowthStatus, GrowthTrigger, MissedGrowthPayments, ProcessedGrowthPeriods,
+    PublishedGrowth, T1Confirmation, PALLET_ID,
+};
+use pallet_avn::BridgeInterfaceNotification;
+
+const PERIOD: GrowthPeriodIndex = 1;
+const TX_ID: u32 = 7;
+
+fn start_period(index: GrowthPeriodIndex) {
+    <GrowthPeriod<Test>>::mutate(|info| info.index = index);
+}
+
+fn accumulate_period() {
+    let mut growth = GrowthInfo::new(1u32);
+    growth.total_stake_accumulated = 300;
+    growth.total_staker_reward = 30;
+    growth.total_points = 20;
+    <Growth<Test>>::insert(PERIOD, growth);
+    <GrowthStartEra<Test>>::insert(PERIOD, 4);
+}
+
+fn publish_period() {
+    <Growth<Test>>::mutate(PERIOD, |growth| growth.tx_id = Some(TX_ID));
+    <PublishedGrowth<Test>>::insert(TX_ID, PERIOD);
+}
+
+fn pay_period() {
+    <Growth<Test>>::remove(PERIOD);
+    <GrowthStartEra<Test>>::remove(PERIOD);
+    <ProcessedGrowthPeriods<Test>>::insert(PERIOD, ());
+}
+
+fn trigger_of_period() -> GrowthTrigger {
+    ParachainStaking::growth_status(PERIOD).unwrap().trigger
+}
+
+#[test]
+fn periods_that_have_not_started_have_no_status() {
+    ExtBuilder::default().build().execute_with(|| {
+        start_period(PERIOD);
+
+        assert!(ParachainStaking::growth_status(PERIOD + 1).is_none());
+    });
+}
+
+#[test]
+fn an_accumulating_period_shows_its_progress() {
+    ExtBuilder::default().build().execute_with(|| {
+        start_period(PERIOD);
+        accumulate_period();
+
+        assert_eq!(
+            ParachainStaking::growth_status(PERIOD),
+            Some(GrowthStatus {
+                period: PERIOD,
+                accumulation: Some(GrowthAccumulation {
+                    start_era: Some(4),
+                    eras_accumulated: 1,
+                    eras_per_period: ErasPerGrowthPeriod::get(),
+                    total_stake_accumulated: 300,
+                    total_staker_reward: 30,
+                    total_points: 20,
+                }),
+                trigger: GrowthTrigger::NotTriggered,
+                payout: GrowthPayout::NotPaid,
+            })
+        );
+    });
+}
+
+#[test]
+fn a_published_period_shows_its_bridge_transaction_until_t1_confirms_it() {
+    ExtBuilder::default().build().execute_with(|| {
+        start_period(PERIOD + 1);
+        accumulate_period();
+        publish_period();
+
+        assert_eq!(
+            trigger_of_period(),
+            GrowthTrigger::Published { tx_id: TX_ID, confirmation: T1Confirmation::Pending }
+        );
+
+        assert!(ParachainStaking::process_result(TX_ID, PALLET_ID.to_vec(), false).is_ok());
+
+        assert_eq!(
+            trigger_of_period(),
+            GrowthTrigger::Published { tx_id: TX_ID, confirmation: T1Confirmation::Failed }
+        );
+    });
+}
+
+#[test]
+fn a_period_with_nothing_to_grow_is_skipped() {
+    ExtBuilder::default().build().execute_with(|| {
+        start_period(PERIOD + 1);
+        accumulate_period();
+        <Growth<Test>>::mutate(PERIOD, |growth| growth.tx_id = Some(0));
+
+        assert_eq!(trigger_of_period(), GrowthTrigger::Skipped);
+    });
+}
+
+#[test]
+fn a_paid_period_shows_its_missed_payments() {
+    ExtBuilder::default().build().execute_with(|| {
+        start_period(PERIOD + 1);
+        accumulate_period();
+        publish_period();
+        pay_period();
+        <MissedGrowthPayments<Test>>::insert(PERIOD, TestAccount::new(1u64).account_id(), 10);
+
+        assert_eq!(
+            ParachainStaking::growth_status(PERIOD),
+            Some(GrowthStatus {
+                period: PERIOD,
+                accumulation: None,
+                trigger: GrowthTrigger::Published {
+                    tx_id: TX_ID,
+                    confirmation: T1Confirmation::Succeeded
+                },
+                payout: GrowthPayout::Paid { missed_payments: 1 },
+            })
+        );
+    });
+}
@@ -0,0 +1,83 @@
+#[cfg(test)]
+use crate::mock::{
+    admin_multisig_account, admin_signatories, AccountId, BlockNumber, ExtBuilder, Multisig,
+    ParachainStaking, RuntimeCall, RuntimeOrigin as Origin, Test, ADMIN_MULTISIG_THRESHOLD,
+};
+use crate::{AdminSettings, BalanceOf, Call as StakingCall, Delay};
+use frame_support::{assert_noop, assert_ok, dispatch::GetDispatchInfo};
+use pallet_multisig::Timepoint;
+use sp_runtime::traits::BadOrigin;
+
+fn other_signatories(signatory: &AccountId) -> Vec<AccountId> {
+    admin_signatories().into_iter().filter(|s| s != signatory).collect()
+}
+
+fn set_delay_call(delay: u32) -> RuntimeCall {
+    RuntimeCall::ParachainStaking(StakingCall::set_admin_setting {
+        value: AdminSettings::<BalanceOf<Test>>::Delay(delay),
+    })
+}
+
+fn approve(signatory: &AccountId, call: RuntimeCall, timepoint: Option<Timepoint<BlockNumber>>) {
+    let max_weight = call.get_dispatch_info().weight;
+    assert_ok!(Multisig::as_multi(
+        Origin::signed(signatory.clone()),
+        ADMIN_MULTISIG_THRESHOLD,
+        other_signatories(signatory),
+        timepoint,
+        Box::new(call),
+        max_weight,
+    ));
+}
+
+fn funded_signatories() -> ExtBuilder {
+    ExtBuilder::default().with_balances(admin_signatories().into_iter().map(|s| (s, 100)).collect())
+}
+
+mod admin_settings_through_multisig {
+    use super::*;
+
+    #[test]
+    fn are_updated_once_the_threshold_is_reached() {
+        funded_signatories().build().execute_with(|| {
+            let signatories = admin_signatories();
+            let new_delay_value = <Delay<Test>>::get() - 1;
+
+            approve(&signatories[0], set_delay_call(new_delay_value), None);
+            assert_ne!(<Delay<Test>>::get(), new_delay_value);
+
+            approve(&signatories[1], set_delay_call(new_delay_value), Some(Multisig::timepoint()));
+            assert_eq!(<Delay<Test>>::get(), new_delay_value);
+        });
+    }
+
+    #[test]
+    fn can_be_updated_by_the_multisig_account() {
+        funded_signatories().build().execute_with(|| {
+            let new_delay_value = <Delay<Test>>::get() - 1;
+
+            assert_ok!(ParachainStaking::set_admin_setting(
+                Origin::signed(admin_multisig_account()),
+                AdminSettings::<BalanceOf<Test>>::Delay(new_delay_value),
+            ));
+
+            assert_eq!(<Delay<Test>>::get(), new_delay_value);
+        });
+    }
+
+    #[test]
+    fn cannot_be_updated_by_a_single_signatory() {
+        funded_signatories().build().execute_with(|| {
+            let signatory = admin_signatories()[0].clone();
+            let new_delay_value = <Delay<Test>>::get() - 1;
+
+            assert_noop!(
+                ParachainStaking::set_admin_setting(
+                    Origin::signed(signatory),
+                    AdminSettings::<BalanceOf<Test>>::Delay(new_delay_value),
+                ),
+                BadOrigin
+            );
+        });
+    }
+}
@@ -0,0 +1,390 @@
+// Copyright 2024 Aventus Network Services (UK) Ltd.
+
+#![cfg(test)]
+
+use crate::{
+    assert_event_emitted,
+    mock::{
+        roll_to_era_begin, AccountId, Balances, ExtBuilder, ParachainStaking, RuntimeOrigin, Test,
+        TestAccount,
+    },
+    Error, Event, NominationAction, PoolId,
+};
+use frame_support::{assert_noop, assert_ok, traits::Currency};
+
+const POOL_ID: PoolId = 0;
+const DEPOSIT: u128 = 10;
+// Less than the minimum nominator stake of the mock
+const SMALL_STAKE: u128 = 2;
+
+fn to_acc_id(id: u64) -> AccountId {
+    return TestAccount::new(id).account_id()
+}
+
+fn collator() -> AccountId {
+    to_acc_id(1u64)
+}
+
+fn depositor() -> AccountId {
+    to_acc_id(2u64)
+}
+
+fn small_holder() -> AccountId {
+    to_acc_id(3u64)
+}
+
+fn pool_account() -> AccountId {
+    ParachainStaking::pool_account_id(POOL_ID)
+}
+
+fn build_ext() -> sp_io::TestExternalities {
+    ExtBuilder::default()
+        .with_balances(vec![(collator(), 100), (depositor(), 100), (small_holder(), 100)])
+        .with_candidates(vec![(collator(), 20)])
+        .build()
+}
+
+fn create_pool() {
+    assert_ok!(ParachainStaking::create_nomination_pool(
+        RuntimeOrigin::signed(depositor()),
+        collator(),
+        DEPOSIT
+    ));
+}
+
+fn join_pool(amount: u128) {
+    assert_ok!(ParachainStaking::join_nomination_pool(
+        RuntimeOrigin::signed(small_holder()),
+        POOL_ID,
+        amount
+    ));
+}
+
+fn pay_pool_rewards(amount: u128) {
+    let _ = Balances::deposit_creating(&pool_account(), amount);
+}
+
+fn pool_nomination() -> Option<u128> {
+    ParachainStaking::nominator_state(pool_account())
+        .and_then(|state| state.get_bond_amount(&collator()))
+}
+
+fn points(account: AccountId) -> u128 {
+    ParachainStaking::pool_member(POOL_ID, account).map_or(0, |member| member.points)
+}
+
+mod create_nomination_pool {
+    use super::*;
+
+    #[test]
+    fn nominates_the_collator_with_the_pool_account() {
+        build_ext().execute_with(|| {
+            create_pool();
+
+            assert_eq!(pool_nomination(), Some(DEPOSIT));
+            assert_eq!(points(depositor()), DEPOSIT);
+            assert_eq!(ParachainStaking::next_pool_id(), POOL_ID + 1);
+            assert_event_emitted!(Event::NominationPoolCreated {
+                pool_id: POOL_ID,
+                depositor: depositor(),
+                collator: collator(),
+                pool_account: pool_account(),
+                amount: DEPOSIT,
+            });
+        });
+    }
+
+    #[test]
+    fn fails_below_the_minimum_nominator_stake() {
+        build_ext().execute_with(|| {
+            assert_noop!(
+                ParachainStaking::create_nomination_pool(
+                    RuntimeOrigin::signed(depositor()),
+                    collator(),
+                    SMALL_STAKE
+                ),
+                Error::<Test>::NominatorBondBelowMin
+            );
+        });
+    }
+}
+
+mod join_nomination_pool {
+    use super::*;
+
+    #[test]
+    fn adds_the_stake_of_a_small_holder_to_the_pool_nomination() {
+        build_ext().execute_with(|| {
+            create_pool();
+
+            join_pool(SMALL_STAKE);
+
+            assert_eq!(pool_nomination(), Some(DEPOSIT + SMALL_STAKE));
+            assert_eq!(points(small_holder()), SMALL_STAKE);
+            assert_eq!(Balances::free_balance(small_holder()), 100 - SMALL_STAKE);
+            assert_event_emitted!(Event::PoolMemberBonded {
+                pool_id: POOL_ID,
+                member: small_holder(),
+                amount: SMALL_STAKE,
+            });
+        });
+    }
+
+    #[test]
+    fn fails_for_a_member() {
+        build_ext().execute_with(|| {
+            create_pool();
+            join_pool(SMALL_STAKE);
+
+            assert_noop!(
+                ParachainStaking::join_nomination_pool(
+                    RuntimeOrigin::signed(small_holder()),
+                    POOL_ID,
+                    SMALL_STAKE
+                ),
+                Error::<Test>::AlreadyPoolMember
+            );
+        });
+    }
+
+    #[test]
+    fn fails_when_the_pool_does_not_exist() {
+        build_ext().execute_with(|| {
+            assert_noop!(
+                ParachainStaking::join_nomination_pool(
+                    RuntimeOrigin::signed(small_holder()),
+                    POOL_ID,
+                    SMALL_STAKE
+                ),
+                Error::<Test>::NominationPoolDNE
+            );
+        });
+    }
+}
+
+mod pool_rewards {
+    use super::*;
+
+    #[test]
+    fn are_shared_pro_rata_to_the_points_of_the_members() {
+        build_ext().execute_with(|| {
+            create_pool();
+            join_pool(SMALL_STAKE);
+            pay_pool_rewards(DEPOSIT + SMALL_STAKE);
+
+            assert_eq!(ParachainStaking::pending_pool_rewards(POOL_ID, &depositor()), DEPOSIT);
+            assert_eq!(
+                ParachainStaking::pending_pool_rewards(POOL_ID, &small_holder()),
+                SMALL_STAKE
+            );
+
+            assert_ok!(ParachainStaking::claim_pool_rewards(
+                RuntimeOrigin::signed(small_holder()),
+                POOL_ID
+            ));
+
+            assert_eq!(Balances::free_balance(small_holder()), 100);
+            assert_eq!(ParachainStaking::pending_pool_rewards(POOL_ID, &small_holder()), 0);
+            assert_eq!(ParachainStaking::pending_pool_rewards(POOL_ID, &depositor()), DEPOSIT);
+            assert_event_emitted!(Event::PoolRewardsClaimed {
+                pool_id: POOL_ID,
+                member: small_holder(),
+                amount: SMALL_STAKE,
+            });
+        });
+    }
+
+    #[test]
+    fn earned_before_joining_are_not_shared_with_the_new_member() {
+        build_ext().execute_with(|| {
+            create_pool();
+            pay_pool_rewards(DEPOSIT);
+
+            join_pool(SMALL_STAKE);
+
+            assert_eq!(ParachainStaking::pending_pool_rewards(POOL_ID, &depositor()), DEPOSIT);
+            assert_eq!(ParachainStaking::pending_pool_rewards(POOL_ID, &small_holder()), 0);
+        });
+    }
+
+    #[test]
+    fn are_paid_before_bonding_extra() {
+        build_ext().execute_with(|| {
+            create_pool();
+            pay_pool_rewards(DEPOSIT);
+
+            assert_ok!(ParachainStaking::pool_bond_extra(
+                RuntimeOrigin::signed(depositor()),
+                POOL_ID,
+                SMALL_STAKE
+            ));
+
+            assert_eq!(points(depositor()), DEPOSIT + SMALL_STAKE);
+            assert_eq!(ParachainStaking::pending_pool_rewards(POOL_ID, &depositor()), 0);
+            assert_eq!(Balances::free_balance(depositor()), 100 - SMALL_STAKE);
+            assert_eq!(pool_nomination(), Some(DEPOSIT + SMALL_STAKE));
+        });
+    }
+}
+
+mod pool_unbond {
+    use super::*;
+
+    #[test]
+    fn decreases_the_pool_nomination_and_releases_the_stake_after_the_delay() {
+        build_ext().execute_with(|| {
+            create_pool();
+            join_pool(SMALL_STAKE);
+
+            assert_ok!(ParachainStaking::pool_unbond(
+                RuntimeOrigin::signed(small_holder()),
+                POOL_ID,
+                SMALL_STAKE
+            ));
+
+            let withdrawable_era = 1 + ParachainStaking::delay();
+            assert_eq!(points(small_holder()), 0);
+            assert_eq!(
+                ParachainStaking::nomination_scheduled_requests(collator())[0].action,
+                NominationAction::Decrease(SMALL_STAKE)
+            );
+            assert_event_emitted!(Event::PoolMemberUnbonded {
+                pool_id: POOL_ID,
+                member: small_holder(),
+                amount: SMALL_STAKE,
+                withdrawable_era,
+            });
+            assert_noop!(
+                ParachainStaking::pool_withdraw_unbonded(
+                    RuntimeOrigin::signed(small_holder()),
+                    POOL_ID
+                ),
+                Error::<Test>::PendingNominationRequestNotDueYet
+            );
+
+            roll_to_era_begin(withdrawable_era.into());
+            assert_ok!(ParachainStaking::pool_withdraw_unbonded(
+                RuntimeOrigin::signed(small_holder()),
+                POOL_ID
+            ));
+
+            assert_eq!(pool_nomination(), Some(DEPOSIT));
+            assert_eq!(Balances::free_balance(small_holder()), 100);
+            assert_eq!(ParachainStaking::pool_member(POOL_ID, small_holder()), None);
+        });
+    }
+
+    #[test]
+    fn queued_behind_a_pending_decrease_does_not_delay_it() {
+        build_ext().execute_with(|| {
+            create_pool();
+            join_pool(SMALL_STAKE);
+            assert_ok!(ParachainStaking::pool_unbond(
+                RuntimeOrigin::signed(small_holder()),
+                POOL_ID,
+                SMALL_STAKE
+            ));
+            let first_withdrawable_era = 1 + ParachainStaking::delay();
+
+            assert_ok!(ParachainStaking::pool_unbond(
+                RuntimeOrigin::signed(depositor()),
+                POOL_ID,
+                SMALL_STAKE
+            ));
+
+            let requests = ParachainStaking::nomination_scheduled_requests(collator());
+            assert_eq!(requests.len(), 1);
+            assert_eq!(requests[0].action, NominationAction::Decrease(SMALL_STAKE));
+            assert_eq!(requests[0].when_executable, first_withdrawable_era);
+            let second_withdrawable_era = first_withdrawable_era + ParachainStaking::delay();
+            assert_event_emitted!(Event::PoolMemberUnbonded {
+                pool_id: POOL_ID,
+                member: depositor(),
+                amount: SMALL_STAKE,
+                withdrawable_era: second_withdrawable_era,
+            });
+
+            roll_to_era_begin(first_withdrawable_era.into());
+            assert_ok!(ParachainStaking::pool_withdraw_unbonded(
+                RuntimeOrigin::signed(small_holder()),
+                POOL_ID
+            ));
+
+            // The queued stake is decreased once the pending decrease is executed
+            let requests = ParachainStaking::nomination_scheduled_requests(collator());
+            assert_eq!(requests[0].action, NominationAction::Decrease(SMALL_STAKE));
+            assert_eq!(requests[0].when_executable, second_withdrawable_era);
+
+            roll_to_era_begin(second_withdrawable_era.into());
+            assert_ok!(ParachainStaking::pool_withdraw_unbonded(
+                RuntimeOrigin::signed(depositor()),
+                POOL_ID
+            ));
+            assert_eq!(pool_nomination(), Some(DEPOSIT - SMALL_STAKE));
+        });
+    }
+
+    #[test]
+    fn can_leave_the_pool_below_the_minimum_nominator_stake() {
+        build_ext().execute_with(|| {
+            create_pool();
+
+            // The mock minimum nominator stake is 5
+            assert_ok!(ParachainStaking::pool_unbond(
+                RuntimeOrigin::signed(depositor()),
+                POOL_ID,
+                DEPOSIT - SMALL_STAKE
+            ));
+
+            roll_to_era_begin((1 + ParachainStaking::delay()).into());
+            assert_ok!(ParachainStaking::pool_withdraw_unbonded(
+                RuntimeOrigin::signed(depositor()),
+                POOL_ID
+            ));
+            assert_eq!(pool_nomination(), Some(SMALL_STAKE));
+        });
+    }
+
+    #[test]
+    fn of_all_the_stake_revokes_the_pool_nomination() {
+        build_ext().execute_with(|| {
+            create_pool();
+
+            assert_ok!(ParachainStaking::pool_unbond(
+                RuntimeOrigin::signed(depositor()),
+                POOL_ID,
+                DEPOSIT
+            ));
+
+            assert_eq!(
+                ParachainStaking::nomination_scheduled_requests(collator())[0].action,
+                NominationAction::Revoke(DEPOSIT)
+            );
+            assert_noop!(
+                ParachainStaking::join_nomination_pool(
+                    RuntimeOrigin::signed(small_holder()),
+                    POOL_ID,
+                    SMALL_STAKE
+                ),
+                Error::<Test>::NominationPoolClosed
+            );
+        });
+    }
+
+    #[test]
+    fn fails_for_more_than_the_member_bonded() {
+        build_ext().execute_with(|| {
+            create_pool();
+            join_pool(SMALL_STAKE);
+
+            assert_noop!(
+                ParachainStaking::pool_unbond(
+                    RuntimeOrigin::signed(small_holder()),
+                    POOL_ID,
+                    SMALL_STAKE + 1
+                ),
+                Error::<Test>::PoolUnbondExceedsBond
+            );
+        });
+    }
+}
@@ -0,0 +1,154 @@
+// Copyright 2024 Aventus Network Services (UK) Ltd.
+
+#![cfg(test)]
+
+use crate::{
+    assert_event_emitted,
+    mock::{
+        AccountId, ExtBuilder, MaxTopNominationsPerCandidate, ParachainStaking, RuntimeOrigin,
+        Test, TestAccount,
+    },
+    weights::WeightInfo,
+    Error, Event,
+};
+use frame_support::{assert_noop, assert_ok, traits::Hooks, weights::Weight};
+
+fn to_acc_id(id: u64) -> AccountId {
+    return TestAccount::new(id).account_id()
+}
+
+fn candidate() -> AccountId {
+    to_acc_id(1)
+}
+
+// Six nominators: four in the top nominations and two in the bottom ones
+fn build_ext() -> sp_io::TestExternalities {
+    let nominators: Vec<u64> = (2..=7).collect();
+    ExtBuilder::default()
+        .with_balances(
+            [(candidate(), 20)]
+                .into_iter()
+                .chain(nominators.iter().map(|id| (to_acc_id(*id), 20)))
+                .collect(),
+        )
+        .with_candidates(vec![(candidate(), 20)])
+        .with_nominations(nominators.iter().map(|id| (to_acc_id(*id), candidate(), 10)).collect())
+        .build()
+}
+
+fn top_and_bottom_counts() -> (usize, usize) {
+    (
+        ParachainStaking::top_nominations(candidate()).unwrap().nominations.len(),
+        ParachainStaking::bottom_nominations(candidate()).unwrap().nominations.len(),
+    )
+}
+
+mod rebalance_nominations_for {
+    use super::*;
+
+    #[test]
+    fn promotes_bottom_nominations_when_the_top_has_room() {
+        build_ext().execute_with(|| {
+            MaxTopNominationsPerCandidate::set(6);
+
+            assert_ok!(ParachainStaking::rebalance_nominations_for(
+                RuntimeOrigin::signed(to_acc_id(2)),
+                candidate()
+            ));
+
+            assert_eq!(top_and_bottom_counts(), (6, 0));
+            let state = ParachainStaking::candidate_info(candidate()).unwrap();
+            assert_eq!(state.total_counted, 20 + 6 * 10);
+            assert_eq!(state.nomination_count, 6);
+            assert_event_emitted!(Event::NominationPromoted {
+                nominator: to_acc_id(6),
+                candidate: candidate(),
+                amount: 10,
+            });
+            assert_event_emitted!(Event::NominationPromoted {
+                nominator: to_acc_id(7),
+                candidate: candidate(),
+                amount: 10,
+            });
+        });
+    }
+
+    #[test]
+    fn only_fills_the_room_in_the_top_nominations() {
+        build_ext().execute_with(|| {
+            MaxTopNominationsPerCandidate::set(5);
+
+            assert_ok!(ParachainStaking::rebalance_nominations_for(
+                RuntimeOrigin::signed(to_acc_id(2)),
+                candidate()
+            ));
+
+            assert_eq!(top_and_bottom_counts(), (5, 1));
+        });
+    }
+
+    #[test]
+    fn does_nothing_when_the_top_is_full() {
+        build_ext().execute_with(|| {
+            assert_ok!(ParachainStaking::rebalance_nominations_for(
+                RuntimeOrigin::signed(to_acc_id(2)),
+                candidate()
+            ));
+
+            assert_eq!(top_and_bottom_counts(), (4, 2));
+        });
+    }
+
+    #[test]
+    fn fails_for_an_unknown_candidate() {
+        build_ext().execute_with(|| {
+            assert_noop!(
+                ParachainStaking::rebalance_nominations_for(
+                    RuntimeOrigin::signed(to_acc_id(2)),
+                    to_acc_id(2)
+                ),
+                Error::<Test>::CandidateDNE
+            );
+        });
+    }
+}
+
+mod on_idle {
+    use super::*;
+
+    #[test]
+    fn sweeps_the_candidates_when_the_maximum_top_nominations_changes() {
+        build_ext().execute_with(|| {
+            ParachainStaking::on_idle(1, Weight::MAX);
+            assert_eq!(ParachainStaking::nominations_rebalanced_for(), 4);
+
+            MaxTopNominationsPerCandidate::set(6);
+            ParachainStaking::on_idle(2, Weight::MAX);
+
+            assert_eq!(top_and_bottom_counts(), (6, 0));
+            assert_eq!(ParachainStaking::nominations_rebalanced_for(), 6);
+            assert!(ParachainStaking::pending_nomination_rebalances().is_empty());
+        });
+    }
+
+    #[test]
+    fn resumes_the_sweep_when_there_is_not_enough_weight() {
+        build_ext().execute_with(|| {
+            MaxTopNominationsPerCandidate::set(6);
+            // Enough to start the sweep but not to rebalance a candidate
+            let remaining_weight = <() as WeightInfo>::rebalance_nominations_for(1);
+            ParachainStaking::on_idle(1, remaining_weight);
+
+            assert_eq!(top_and_bottom_counts(), (4, 2));
+            assert_eq!(
+                ParachainStaking::pending_nomination_rebalances().to_vec(),
+                vec![candidate()]
+            );
+
+            ParachainStaking::on_idle(2, Weight::MAX);
+
+            assert_eq!(top_and_bottom_counts(), (6, 0));
+            assert!(ParachainStaking::pending_nomination_rebalances().is_empty());
+        });
+    }
+}
@@ -0,0 +1,167 @@
+// Copyright 2024 Aventus Network Services (UK) Ltd.
+
+#![cfg(test)]
+
+use crate::{
+    assert_event_emitted, assert_event_not_emitted,
+    mock::{
+        roll_to_era_begin, AccountId, ExtBuilder, ParachainStaking, RuntimeOrigin, Test,
+        TestAccount,
+    },
+    Event, NominationAction, NominationRequestsSweepCursor, NominationScheduledRequests,
+    ScheduledRequest,
+};
+use frame_support::{assert_ok, weights::Weight, BoundedVec};
+
+fn to_acc_id(id: u64) -> AccountId {
+    return TestAccount::new(id).account_id()
+}
+
+fn candidate() -> AccountId {
+    to_acc_id(1)
+}
+
+fn nominator() -> AccountId {
+    to_acc_id(2)
+}
+
+fn build_ext() -> sp_io::TestExternalities {
+    ExtBuilder::default()
+        .with_balances(vec![(candidate(), 20), (nominator(), 20)])
+        .with_candidates(vec![(candidate(), 20)])
+        .with_nominations(vec![(nominator(), candidate(), 10)])
+        .build()
+}
+
+fn insert_revoke_requests(candidate: AccountId, nominators: Vec<AccountId>) {
+    let requests = nominators
+        .into_iter()
+        .map(|nominator| ScheduledRequest {
+            nominator,
+            when_executable: 3,
+            action: NominationAction::Revoke(10),
+        })
+        .collect::<Vec<_>>();
+    <NominationScheduledRequests<Test>>::insert(candidate, BoundedVec::truncate_from(requests));
+}
+
+#[test]
+fn removing_the_last_request_of_a_candidate_removes_its_entry() {
+    build_ext().execute_with(|| {
+        assert_ok!(ParachainStaking::schedule_revoke_nomination(
+            RuntimeOrigin::signed(nominator()),
+            candidate()
+        ));
+        assert!(<NominationScheduledRequests<Test>>::contains_key(candidate()));
+
+        assert_ok!(ParachainStaking::cancel_nomination_request(
+            RuntimeOrigin::signed(nominator()),
+            candidate()
+        ));
+
+        assert!(!<NominationScheduledRequests<Test>>::contains_key(candidate()));
+    });
+}
+
+#[test]
+fn requests_towards_an_exited_candidate_are_removed() {
+    build_ext().execute_with(|| {
+        let exited_candidate = to_acc_id(5);
+        insert_revoke_requests(exited_candidate, vec![nominator()]);
+
+        ParachainStaking::cleanup_nomination_requests_on_idle(Weight::MAX);
+
+        assert!(!<NominationScheduledRequests<Test>>::contains_key(exited_candidate));
+        assert_event_emitted!(Event::OrphanedNominationRequestsRemoved {
+            candidate: exited_candidate,
+            removed: 1,
+        });
+    });
+}
+
+#[test]
+fn requests_of_nominators_that_no_longer_nominate_the_candidate_are_removed() {
+    build_ext().execute_with(|| {
+        let former_nominator = to_acc_id(3);
+        insert_revoke_requests(candidate(), vec![nominator(), former_nominator]);
+
+        ParachainStaking::cleanup_nomination_requests_on_idle(Weight::MAX);
+
+        let requests = ParachainStaking::nomination_scheduled_requests(candidate());
+        assert_eq!(requests.len(), 1);
+        assert_eq!(requests[0].nominator, nominator());
+        assert_event_emitted!(Event::OrphanedNominationRequestsRemoved {
+            candidate: candidate(),
+            removed: 1,
+        });
+    });
+}
+
+#[test]
+fn valid_requests_are_kept() {
+    build_ext().execute_with(|| {
+        assert_ok!(ParachainStaking::schedule_revoke_nomination(
+            RuntimeOrigin::signed(nominator()),
+            candidate()
+        ));
+
+        ParachainStaking::cleanup_nomination_requests_on_idle(Weight::MAX);
+
+        assert_eq!(ParachainStaking::nomination_scheduled_requests(candidate()).len(), 1);
+        assert_event_not_emitted!(Event::OrphanedNominationRequestsRemoved {
+            candidate: candidate(),
+            removed: 1,
+        });
+    });
+}
+
+#[test]
+fn the_sweep_is_bounded_per_block() {
+    build_ext().execute_with(|| {
+        for id in 5..8 {
+            insert_revoke_requests(to_acc_id(id), vec![nominator()]);
+        }
+
+        // MaxNominationRequestCleanupsPerBlock is 2 in the mock
+        ParachainStaking::cleanup_nomination_requests_on_idle(Weight::MAX);
+        assert_eq!(<NominationScheduledRequests<Test>>::iter_keys().count(), 1);
+        assert!(<NominationRequestsSweepCursor<Test>>::get().is_some());
+
+        ParachainStaking::cleanup_nomination_requests_on_idle(Weight::MAX);
+        assert_eq!(<NominationScheduledRequests<Test>>::iter_keys().count(), 0);
+        assert!(<NominationRequestsSweepCursor<Test>>::get().is_none());
+        assert_eq!(
+            ParachainStaking::nomination_requests_swept_era(),
+            ParachainStaking::era().current
+        );
+    });
+}
+
+#[test]
+fn the_sweep_runs_once_per_era() {
+    build_ext().execute_with(|| {
+        ParachainStaking::cleanup_nomination_requests_on_idle(Weight::MAX);
+
+        let exited_candidate = to_acc_id(5);
+        insert_revoke_requests(exited_candidate, vec![nominator()]);
+        ParachainStaking::cleanup_nomination_requests_on_idle(Weight::MAX);
+        assert!(<NominationScheduledRequests<Test>>::contains_key(exited_candidate));
+
+        roll_to_era_begin(2);
+        ParachainStaking::cleanup_nomination_requests_on_idle(Weight::MAX);
+        assert!(!<NominationScheduledRequests<Test>>::contains_key(exited_candidate));
+    });
+}
+
+#[test]
+fn the_sweep_does_not_run_without_enough_weight() {
+    build_ext().execute_with(|| {
+        let exited_candidate = to_acc_id(5);
+        insert_revoke_requests(exited_candidate, vec![nominator()]);
+
+        ParachainStaking::cleanup_nomination_requests_on_idle(Weight::zero());
+
+        assert!(<NominationScheduledRequests<Test>>::contains_key(exited_candidate));
+        assert!(<NominationRequestsSweepCursor<Test>>::get().is_none());
+    });
+}
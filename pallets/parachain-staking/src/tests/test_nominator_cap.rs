@@ -0,0 +1,134 @@
+#[cfg(test)]
+use crate::mock::{AccountId, ExtBuilder, ParachainStaking, RuntimeOrigin, Test, TestAccount};
+use crate::{assert_event_emitted, CandidateNominatorCounts, Error, Event};
+use frame_support::{assert_noop, assert_ok};
+
+fn to_acc_id(id: u64) -> AccountId {
+    return TestAccount::new(id).account_id()
+}
+
+fn candidate() -> AccountId {
+    to_acc_id(1)
+}
+
+// Six nominators: four in the top nominations and two in the bottom ones
+fn build_ext() -> sp_io::TestExternalities {
+    let nominators: Vec<u64> = (2..=8).collect();
+    ExtBuilder::default()
+        .with_balances(
+            [(candidate(), 20)]
+                .into_iter()
+                .chain(nominators.iter().map(|id| (to_acc_id(*id), 20)))
+                .collect(),
+        )
+        .with_candidates(vec![(candidate(), 20)])
+        .with_nominations(
+            nominators[..6].iter().map(|id| (to_acc_id(*id), candidate(), 10)).collect(),
+        )
+        .build()
+}
+
+#[test]
+fn nominator_counts_are_reported() {
+    build_ext().execute_with(|| {
+        assert_eq!(
+            ParachainStaking::candidate_nominator_counts(&candidate()),
+            Some(CandidateNominatorCounts { top: 4, bottom: 2, cap: None })
+        );
+        assert_eq!(ParachainStaking::candidate_nominator_counts(&to_acc_id(2)), None);
+    });
+}
+
+#[test]
+fn candidate_can_set_and_remove_a_cap() {
+    build_ext().execute_with(|| {
+        assert_ok!(ParachainStaking::set_candidate_nominator_cap(
+            RuntimeOrigin::signed(candidate()),
+            Some(6)
+        ));
+        assert_eq!(
+            ParachainStaking::candidate_nominator_counts(&candidate()).unwrap().cap,
+            Some(6)
+        );
+        assert_event_emitted!(Event::CandidateNominatorCapSet {
+            candidate: candidate(),
+            cap: Some(6)
+        });
+
+        assert_ok!(ParachainStaking::set_candidate_nominator_cap(
+            RuntimeOrigin::signed(candidate()),
+            None
+        ));
+        assert_eq!(ParachainStaking::candidate_nominator_cap(candidate()), None);
+    });
+}
+
+#[test]
+fn nominations_beyond_the_cap_are_rejected() {
+    build_ext().execute_with(|| {
+        assert_ok!(ParachainStaking::set_candidate_nominator_cap(
+            RuntimeOrigin::signed(candidate()),
+            Some(6)
+        ));
+
+        assert_noop!(
+            ParachainStaking::nominate(RuntimeOrigin::signed(to_acc_id(8)), candidate(), 15, 6, 0),
+            Error::<Test>::CandidateNominatorCapReached
+        );
+    });
+}
+
+#[test]
+fn nominations_below_the_cap_are_accepted() {
+    build_ext().execute_with(|| {
+        assert_ok!(ParachainStaking::set_candidate_nominator_cap(
+            RuntimeOrigin::signed(candidate()),
+            Some(7)
+        ));
+
+        assert_ok!(ParachainStaking::nominate(
+            RuntimeOrigin::signed(to_acc_id(8)),
+            candidate(),
+            10,
+            6,
+            0
+        ));
+        assert_eq!(
+            ParachainStaking::candidate_nominator_counts(&candidate()),
+            Some(CandidateNominatorCounts { top: 4, bottom: 3, cap: Some(7) })
+        );
+    });
+}
+
+#[test]
+fn cap_must_be_within_the_nomination_limits() {
+    build_ext().execute_with(|| {
+        assert_noop!(
+            ParachainStaking::set_candidate_nominator_cap(
+                RuntimeOrigin::signed(candidate()),
+                Some(0)
+            ),
+            Error::<Test>::InvalidNominatorCap
+        );
+        assert_noop!(
+            ParachainStaking::set_candidate_nominator_cap(
+                RuntimeOrigin::signed(candidate()),
+                Some(9)
+            ),
+            Error::<Test>::InvalidNominatorCap
+        );
+    });
+}
+
+#[test]
+fn only_candidates_can_set_a_cap() {
+    build_ext().execute_with(|| {
+        assert_noop!(
+            ParachainStaking::set_candidate_nominator_cap(
+                RuntimeOrigin::signed(to_acc_id(2)),
+                Some(5)
+            ),
+            Error::<Test>::CandidateDNE
+        );
+    });
+}
@@ -0,0 +1,82 @@
+#[cfg(test)]
+use crate::mock::{
+    events, roll_to_era_begin, set_operator_identity, AccountId, Balance, ExtBuilder,
+    ParachainStaking, TestAccount,
+};
+use crate::{assert_event_emitted, Event, OperatorIdentity};
+
+fn collator(id: u64) -> AccountId {
+    TestAccount::new(id).account_id()
+}
+
+fn verified_identity() -> OperatorIdentity {
+    OperatorIdentity { display_name: b"Collator One".to_vec(), verified: true }
+}
+
+// 6 candidates for 5 seats: the candidate with the lowest stake, collator 6, is not selected
+fn build_ext() -> sp_io::TestExternalities {
+    let candidates: Vec<(AccountId, Balance)> =
+        (1..=6).map(|id| (collator(id), 100 - id as Balance)).collect();
+    ExtBuilder::default()
+        .with_balances(candidates.iter().map(|(account, _)| (account.clone(), 100)).collect())
+        .with_candidates(candidates)
+        .build()
+}
+
+mod candidates_overview {
+    use super::*;
+
+    #[test]
+    fn includes_the_identity_of_the_operators() {
+        build_ext().execute_with(|| {
+            set_operator_identity(collator(1), verified_identity());
+
+            let candidates = ParachainStaking::candidates_overview();
+            let candidate_1 = candidates.iter().find(|c| c.account == collator(1)).unwrap();
+            let candidate_2 = candidates.iter().find(|c| c.account == collator(2)).unwrap();
+
+            assert_eq!(candidates.len(), 6);
+            assert_eq!(candidate_1.identity, Some(verified_identity()));
+            assert_eq!(candidate_1.total_counted, 99);
+            assert_eq!(candidate_2.identity, None);
+        });
+    }
+
+    #[test]
+    fn flags_the_selected_candidates() {
+        build_ext().execute_with(|| {
+            let candidates = ParachainStaking::candidates_overview();
+
+            assert!(candidates.iter().find(|c| c.account == collator(1)).unwrap().is_selected);
+            assert!(!candidates.iter().find(|c| c.account == collator(6)).unwrap().is_selected);
+        });
+    }
+}
+
+mod chosen_collator_identity {
+    use super::*;
+
+    #[test]
+    fn is_emitted_for_chosen_collators_with_an_identity() {
+        build_ext().execute_with(|| {
+            set_operator_identity(collator(1), verified_identity());
+
+            roll_to_era_begin(2);
+
+            assert_event_emitted!(Event::ChosenCollatorIdentity {
+                era: 2,
+                collator_account: collator(1),
+                identity: verified_identity(),
+            });
+        });
+    }
+
+    #[test]
+    fn is_not_emitted_for_collators_without_an_identity() {
+        build_ext().execute_with(|| {
+            roll_to_era_begin(2);
+
+            assert!(!events().iter().any(|e| matches!(e, Event::ChosenCollatorIdentity { .. })));
+        });
+    }
+}
@@ -0,0 +1,81 @@
+#[cfg(test)]
+use crate::mock::{
+    build_proof, sign, AccountId, ExtBuilder, ParachainStaking, RuntimeOrigin as Origin, Signature,
+    Staker, Test, TestAccount,
+};
+use crate::{encode_signed_bond_extra_params, Error, OnKilledAccount, Proof, ProxyNonces};
+use frame_support::{assert_noop, assert_ok};
+
+const EXTRA_AMOUNT: u128 = 10;
+
+fn signed_bond_extra_proof(staker: &Staker, nonce: u64, epoch: u32) -> Proof<Signature, AccountId> {
+    let signed_payload =
+        encode_signed_bond_extra_params::<Test>(staker.relayer, &EXTRA_AMOUNT, nonce, epoch);
+    build_proof(&staker.account_id, &staker.relayer, sign(&staker.key_pair, &signed_payload))
+}
+
+fn build_with_nomination(staker: &Staker) -> ExtBuilder {
+    let collator = TestAccount::new(1).account_id();
+    ExtBuilder::default()
+        .with_balances(vec![(collator, 10000), (staker.account_id, 10000)])
+        .with_candidates(vec![(collator, 1000)])
+        .with_nominations(vec![(staker.account_id, collator, 100)])
+}
+
+mod reaping_an_account {
+    use super::*;
+
+    #[test]
+    fn resets_its_proxy_nonce_and_bumps_its_epoch() {
+        let staker: Staker = Default::default();
+        build_with_nomination(&staker).build().execute_with(|| {
+            <ProxyNonces<Test>>::insert(staker.account_id, 5);
+
+            ParachainStaking::on_killed_account(&staker.account_id);
+
+            assert!(!<ProxyNonces<Test>>::contains_key(staker.account_id));
+            assert_eq!(ParachainStaking::proxy_nonce_epoch(staker.account_id), 1);
+        });
+    }
+
+    #[test]
+    fn keeps_the_epoch_of_accounts_that_never_used_a_proxy() {
+        let staker: Staker = Default::default();
+        build_with_nomination(&staker).build().execute_with(|| {
+            ParachainStaking::on_killed_account(&staker.account_id);
+
+            assert_eq!(ParachainStaking::proxy_nonce_epoch(staker.account_id), 0);
+        });
+    }
+
+    #[test]
+    fn prevents_proofs_signed_before_the_reset_from_being_replayed() {
+        let staker: Staker = Default::default();
+        build_with_nomination(&staker).build().execute_with(|| {
+            let proof = signed_bond_extra_proof(&staker, 0, 0);
+            assert_ok!(ParachainStaking::signed_bond_extra(
+                Origin::signed(staker.account_id),
+                proof.clone(),
+                EXTRA_AMOUNT
+            ));
+
+            ParachainStaking::on_killed_account(&staker.account_id);
+            assert_eq!(ParachainStaking::proxy_nonce(staker.account_id), 0);
+
+            assert_noop!(
+                ParachainStaking::signed_bond_extra(
+                    Origin::signed(staker.account_id),
+                    proof,
+                    EXTRA_AMOUNT
+                ),
+                Error::<Test>::UnauthorizedSignedBondExtraTransaction
+            );
+
+            assert_ok!(ParachainStaking::signed_bond_extra(
+                Origin::signed(staker.account_id),
+                signed_bond_extra_proof(&staker, 0, 1),
+                EXTRA_AMOUNT
+            ));
+        });
+    }
+}
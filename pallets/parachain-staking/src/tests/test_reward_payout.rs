@@ -1,10 +1,13 @@
 #[cfg(test)]
 use crate::mock::{
-    pay_gas_for_transaction, roll_one_block, roll_to_era_begin, set_author, AccountId, Balances,
-    ExtBuilder, ParachainStaking, TestAccount, BASE_FEE, TX_LEN,
+    pay_gas_for_transaction, roll_one_block, roll_to_era_begin, roll_to_era_end, set_author,
+    AccountId, Balances, ExtBuilder, ParachainStaking, RewardPaymentDelay, RuntimeOrigin, Test,
+    TestAccount, BASE_FEE, TX_LEN,
 };
-use crate::{assert_eq_events, assert_event_emitted, Event};
-use frame_support::traits::Currency;
+use crate::{
+    assert_eq_events, assert_event_emitted, Error, Event, FailedPayout, MAX_PAYOUT_RETRIES,
+};
+use frame_support::{assert_noop, assert_ok, traits::Currency};
 use sp_runtime::{traits::Zero, Perbill};
 
 fn collator_1() -> AccountId {
@@ -349,6 +352,262 @@ mod reward_payout_fails_when {
                 assert_eq!(Balances::free_balance(&reward_pot_account_id), bad_reward_pot_balance);
                 // locked era balance did not change
                 assert_eq!(ParachainStaking::locked_era_payout(), collator2_reward);
+                // the payment is queued to be retried
+                assert_eq!(
+                    ParachainStaking::failed_payout(collator_2),
+                    Some(FailedPayout { amount: collator2_reward, retries: 0 })
+                );
+            });
+    }
+}
+
+mod retry_failed_payout {
+    use super::*;
+
+    fn build() -> sp_io::TestExternalities {
+        ExtBuilder::default()
+            .with_balances(vec![(collator_1(), 10000), (collator_2(), 10000), (tx_sender(), 10000)])
+            .with_candidates(vec![
+                (collator_1(), COLLATOR1_OWN_STAKE),
+                (collator_2(), COLLATOR2_OWN_STAKE),
+            ])
+            .build()
+    }
+
+    // Makes the payment of collator 2 fail and returns its reward
+    fn fail_collator_2_payout() -> u128 {
+        roll_to_era_begin(2);
+        let (_, collator2_reward) =
+            set_reward_pot_and_trigger_payout(ERA_BLOCKS_HAVE_BEEN_AUTHORED, 3);
+
+        let reward_pot_account_id = ParachainStaking::compute_reward_pot_account_id();
+        Balances::make_free_balance_be(&reward_pot_account_id, collator2_reward - 1);
+        roll_one_block();
+
+        collator2_reward
+    }
+
+    fn retry(payee: AccountId) -> frame_support::dispatch::DispatchResult {
+        ParachainStaking::retry_failed_payout(RuntimeOrigin::signed(payee), payee)
+    }
+
+    #[test]
+    fn pays_the_reward_once_the_transfer_succeeds() {
+        build().execute_with(|| {
+            let collator2_reward = fail_collator_2_payout();
+            let collator2_balance = Balances::free_balance(&collator_2());
+            let reward_pot_account_id = ParachainStaking::compute_reward_pot_account_id();
+            Balances::make_free_balance_be(&reward_pot_account_id, collator2_reward * 10);
+
+            assert_ok!(retry(collator_2()));
+
+            assert_eq!(Balances::free_balance(&collator_2()), collator2_balance + collator2_reward);
+            assert_eq!(ParachainStaking::failed_payout(collator_2()), None);
+            assert_eq!(ParachainStaking::locked_era_payout(), 0);
+            assert_event_emitted!(Event::Rewarded {
+                account: collator_2(),
+                rewards: collator2_reward
+            });
+        });
+    }
+
+    #[test]
+    fn keeps_the_reward_locked_while_the_transfer_fails() {
+        build().execute_with(|| {
+            let collator2_reward = fail_collator_2_payout();
+
+            assert_ok!(retry(collator_2()));
+
+            assert_eq!(
+                ParachainStaking::failed_payout(collator_2()),
+                Some(FailedPayout { amount: collator2_reward, retries: 1 })
+            );
+            assert_eq!(ParachainStaking::locked_era_payout(), collator2_reward);
+        });
+    }
+
+    #[test]
+    fn does_not_count_the_failed_retries_of_other_accounts() {
+        build().execute_with(|| {
+            let collator2_reward = fail_collator_2_payout();
+
+            for _ in 0..MAX_PAYOUT_RETRIES {
+                assert_ok!(ParachainStaking::retry_failed_payout(
+                    RuntimeOrigin::signed(tx_sender()),
+                    collator_2()
+                ));
+            }
+
+            assert_eq!(
+                ParachainStaking::failed_payout(collator_2()),
+                Some(FailedPayout { amount: collator2_reward, retries: 0 })
+            );
+            assert_eq!(ParachainStaking::locked_era_payout(), collator2_reward);
+        });
+    }
+
+    #[test]
+    fn returns_the_reward_to_the_pot_after_the_last_retry() {
+        build().execute_with(|| {
+            let collator2_reward = fail_collator_2_payout();
+
+            for _ in 0..MAX_PAYOUT_RETRIES {
+                assert_ok!(retry(collator_2()));
+            }
+
+            assert_eq!(ParachainStaking::failed_payout(collator_2()), None);
+            assert_eq!(ParachainStaking::locked_era_payout(), 0);
+            assert_event_emitted!(Event::FailedPayoutAbandoned {
+                payee: collator_2(),
+                rewards: collator2_reward
+            });
+        });
+    }
+
+    #[test]
+    fn fails_when_there_is_no_failed_payout() {
+        build().execute_with(|| {
+            assert_noop!(retry(collator_1()), Error::<Test>::NoFailedPayout);
+        });
+    }
+}
+
+mod era_paid_out {
+    use super::*;
+
+    fn build() -> sp_io::TestExternalities {
+        ExtBuilder::default()
+            .with_balances(vec![(collator_1(), 10000), (collator_2(), 10000), (tx_sender(), 10000)])
+            .with_candidates(vec![
+                (collator_1(), COLLATOR1_OWN_STAKE),
+                (collator_2(), COLLATOR2_OWN_STAKE),
+            ])
+            .build()
+    }
+
+    fn era_paid_out_events() -> usize {
+        crate::mock::events()
+            .into_iter()
+            .filter(|e| matches!(e, Event::EraPaidOut { .. }))
+            .count()
+    }
+
+    #[test]
+    fn is_emitted_once_every_collator_is_paid() {
+        build().execute_with(|| {
+            roll_to_era_begin(2);
+            let (collator1_reward, collator2_reward) =
+                set_reward_pot_and_trigger_payout(ERA_BLOCKS_HAVE_BEEN_AUTHORED, 3);
+            roll_one_block();
+            assert_eq!(era_paid_out_events(), 0);
+
+            roll_one_block();
+
+            let total_staker_reward = expected_tx_fee() + TIP;
+            assert_event_emitted!(Event::EraPaidOut {
+                era: ERA_BLOCKS_HAVE_BEEN_AUTHORED,
+                total_staker_reward,
+                collators_paid: 2,
+                failed_payments: 0,
+                locked_era_payout: total_staker_reward - collator1_reward - collator2_reward,
             });
+            assert_eq!(era_paid_out_events(), 1);
+        });
+    }
+
+    #[test]
+    fn counts_the_failed_payments() {
+        build().execute_with(|| {
+            roll_to_era_begin(2);
+            let (collator1_reward, collator2_reward) =
+                set_reward_pot_and_trigger_payout(ERA_BLOCKS_HAVE_BEEN_AUTHORED, 3);
+            let reward_pot_account_id = ParachainStaking::compute_reward_pot_account_id();
+            Balances::make_free_balance_be(&reward_pot_account_id, collator2_reward - 1);
+            roll_one_block();
+
+            roll_one_block();
+
+            let total_staker_reward = expected_tx_fee() + TIP;
+            assert_event_emitted!(Event::EraPaidOut {
+                era: ERA_BLOCKS_HAVE_BEEN_AUTHORED,
+                total_staker_reward,
+                collators_paid: 2,
+                failed_payments: 1,
+                locked_era_payout: total_staker_reward - collator1_reward,
+            });
+        });
+    }
+}
+
+mod reward_payment_delay_change {
+    use super::*;
+
+    fn build() -> sp_io::TestExternalities {
+        ExtBuilder::default()
+            .with_balances(vec![(collator_1(), 10000), (collator_2(), 10000)])
+            .with_candidates(vec![
+                (collator_1(), COLLATOR1_OWN_STAKE),
+                (collator_2(), COLLATOR2_OWN_STAKE),
+            ])
+            .build()
+    }
+
+    fn fund_reward_pot() {
+        let reward_pot_account_id = ParachainStaking::compute_reward_pot_account_id();
+        Balances::make_free_balance_be(&reward_pot_account_id, 10000);
+    }
+
+    fn rewarded_eras(from_era: u32, to_era: u32) -> Vec<u32> {
+        let mut eras: Vec<u32> = ParachainStaking::rewards_history(&collator_1(), from_era, to_era)
+            .into_iter()
+            .map(|entry| entry.era)
+            .collect();
+        eras.dedup();
+        eras
+    }
+
+    #[test]
+    fn a_reduced_delay_pays_the_eras_it_skips() {
+        build().execute_with(|| {
+            roll_to_era_begin(3);
+            assert_eq!(ParachainStaking::last_prepared_payout_era(), Some(1));
+            set_author(2, collator_1(), COLLATOR1_POINTS);
+            set_author(3, collator_1(), COLLATOR1_POINTS);
+            fund_reward_pot();
+
+            RewardPaymentDelay::set(1);
+            roll_to_era_begin(4);
+
+            // Era 2 is paid with era 3, which is due with the new delay
+            assert_eq!(ParachainStaking::last_prepared_payout_era(), Some(3));
+            assert!(ParachainStaking::delayed_payouts(2).is_some());
+            assert!(ParachainStaking::delayed_payouts(3).is_some());
+
+            roll_to_era_end(5);
+            assert_eq!(rewarded_eras(2, 3), vec![2, 3]);
+        });
+    }
+
+    #[test]
+    fn an_increased_delay_does_not_prepare_an_era_twice() {
+        build().execute_with(|| {
+            roll_to_era_begin(3);
+            assert_eq!(ParachainStaking::last_prepared_payout_era(), Some(1));
+            set_author(2, collator_1(), COLLATOR1_POINTS);
+            fund_reward_pot();
+
+            RewardPaymentDelay::set(3);
+            roll_to_era_begin(4);
+
+            // Era 1 has already been prepared and era 2 is not due yet
+            assert_eq!(ParachainStaking::last_prepared_payout_era(), Some(1));
+            assert!(ParachainStaking::delayed_payouts(2).is_none());
+
+            roll_to_era_begin(5);
+            assert_eq!(ParachainStaking::last_prepared_payout_era(), Some(2));
+
+            roll_to_era_end(5);
+            assert_eq!(rewarded_eras(2, 2), vec![2]);
+        });
     }
 }
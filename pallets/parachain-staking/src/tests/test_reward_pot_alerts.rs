@@ -0,0 +1,118 @@
+#[cfg(test)]
+use crate::mock::{Balances, ExtBuilder, ParachainStaking, RuntimeOrigin, System, Test};
+use crate::{
+    assert_event_emitted, assert_event_not_emitted, AdminSettings, Event, ProjectedEraPayout,
+    RewardPotAlertThreshold, RewardPotTopUpEnabled,
+};
+use frame_support::{assert_ok, traits::Currency};
+
+const ERA_PAYOUT: u128 = 100;
+const ALERT_THRESHOLD: u32 = 3;
+
+fn build_ext(reward_pot_balance: u128) -> sp_io::TestExternalities {
+    let mut ext = ExtBuilder::default().build();
+    ext.execute_with(|| {
+        System::set_block_number(1);
+        let reward_pot = ParachainStaking::compute_reward_pot_account_id();
+        Balances::make_free_balance_be(&reward_pot, reward_pot_balance);
+        <RewardPotAlertThreshold<Test>>::put(ALERT_THRESHOLD);
+    });
+    ext
+}
+
+#[test]
+fn reports_a_reward_pot_below_the_threshold() {
+    build_ext(250).execute_with(|| {
+        ParachainStaking::check_reward_pot_balance(ERA_PAYOUT);
+
+        assert_event_emitted!(Event::RewardPotBalanceLow {
+            reward_pot_balance: 250,
+            projected_era_payout: ERA_PAYOUT,
+            eras_covered: 2,
+        });
+        assert_eq!(ParachainStaking::reward_pot(), 250);
+    });
+}
+
+#[test]
+fn does_not_report_a_reward_pot_covering_the_threshold() {
+    build_ext(300).execute_with(|| {
+        ParachainStaking::check_reward_pot_balance(ERA_PAYOUT);
+
+        assert_event_not_emitted!(Event::RewardPotBalanceLow {
+            reward_pot_balance: 300,
+            projected_era_payout: ERA_PAYOUT,
+            eras_covered: 3,
+        });
+    });
+}
+
+#[test]
+fn does_not_report_when_the_alert_is_disabled() {
+    build_ext(0).execute_with(|| {
+        <RewardPotAlertThreshold<Test>>::put(0);
+
+        ParachainStaking::check_reward_pot_balance(ERA_PAYOUT);
+
+        assert_event_not_emitted!(Event::RewardPotBalanceLow {
+            reward_pot_balance: 0,
+            projected_era_payout: ERA_PAYOUT,
+            eras_covered: 0,
+        });
+    });
+}
+
+#[test]
+fn an_era_without_payout_is_checked_against_the_latest_payout() {
+    build_ext(50).execute_with(|| {
+        <ProjectedEraPayout<Test>>::put(ERA_PAYOUT);
+
+        ParachainStaking::check_reward_pot_balance(0);
+
+        assert_eq!(ParachainStaking::projected_era_payout(), ERA_PAYOUT);
+        assert_event_emitted!(Event::RewardPotBalanceLow {
+            reward_pot_balance: 50,
+            projected_era_payout: ERA_PAYOUT,
+            eras_covered: 0,
+        });
+    });
+}
+
+#[test]
+fn tops_up_the_reward_pot_to_the_threshold_when_enabled() {
+    build_ext(250).execute_with(|| {
+        <RewardPotTopUpEnabled<Test>>::put(true);
+
+        ParachainStaking::check_reward_pot_balance(ERA_PAYOUT);
+
+        assert_eq!(ParachainStaking::reward_pot(), ERA_PAYOUT * ALERT_THRESHOLD as u128);
+        assert_event_emitted!(Event::RewardPotToppedUp { amount: 50 });
+    });
+}
+
+#[test]
+fn does_not_top_up_the_reward_pot_unless_enabled() {
+    build_ext(250).execute_with(|| {
+        ParachainStaking::check_reward_pot_balance(ERA_PAYOUT);
+
+        assert_eq!(ParachainStaking::reward_pot(), 250);
+        assert_event_not_emitted!(Event::RewardPotToppedUp { amount: 50 });
+    });
+}
+
+#[test]
+fn admin_can_configure_the_alert_and_the_top_up() {
+    build_ext(0).execute_with(|| {
+        assert_ok!(ParachainStaking::set_admin_setting(
+            RuntimeOrigin::root(),
+            AdminSettings::RewardPotAlertThreshold(7)
+        ));
+        assert_ok!(ParachainStaking::set_admin_setting(
+            RuntimeOrigin::root(),
+            AdminSettings::RewardPotTopUpEnabled(true)
+        ));
+
+        assert_eq!(ParachainStaking::reward_pot_alert_threshold(), 7);
+        assert!(ParachainStaking::reward_pot_top_up_enabled());
+    });
+}
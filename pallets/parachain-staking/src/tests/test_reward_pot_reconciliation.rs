@@ -0,0 +1,139 @@
+#[cfg(test)]
+use crate::mock::{
+    AccountId, Balances, ExtBuilder, ParachainStaking, RuntimeOrigin, System, Test, TestAccount,
+};
+use crate::{
+    assert_event_emitted, Error, Event, FailedPayout, FailedPayouts, LockedEraPayout,
+    RewardPotReconciliation,
+};
+use frame_support::{assert_noop, assert_ok, traits::Currency};
+use sp_runtime::DispatchError;
+
+const REWARD_POT_BALANCE: u128 = 100;
+
+fn nominator() -> AccountId {
+    return TestAccount::new(1u64).account_id()
+}
+
+fn build_ext() -> sp_io::TestExternalities {
+    let mut ext = ExtBuilder::default().build();
+    ext.execute_with(|| {
+        System::set_block_number(1);
+        let reward_pot = ParachainStaking::compute_reward_pot_account_id();
+        Balances::make_free_balance_be(&reward_pot, REWARD_POT_BALANCE);
+    });
+    ext
+}
+
+mod reconciliation {
+    use super::*;
+
+    #[test]
+    fn reports_the_unlocked_part_of_the_reward_pot() {
+        build_ext().execute_with(|| {
+            <LockedEraPayout<Test>>::put(60);
+            <FailedPayouts<Test>>::insert(nominator(), FailedPayout { amount: 15, retries: 1 });
+
+            assert_eq!(
+                ParachainStaking::reward_pot_reconciliation(),
+                RewardPotReconciliation {
+                    reward_pot_balance: REWARD_POT_BALANCE,
+                    locked_era_payout: 60,
+                    failed_payouts: 15,
+                    unlocked: 40,
+                    deficit: 0,
+                }
+            );
+        });
+    }
+
+    #[test]
+    fn reports_a_deficit_when_more_is_locked_than_the_reward_pot_holds() {
+        build_ext().execute_with(|| {
+            <LockedEraPayout<Test>>::put(REWARD_POT_BALANCE + 25);
+
+            let reconciliation = ParachainStaking::reward_pot_reconciliation();
+
+            assert_eq!(reconciliation.unlocked, 0);
+            assert_eq!(reconciliation.deficit, 25);
+        });
+    }
+}
+
+mod rebase_locked_era_payout {
+    use super::*;
+
+    #[test]
+    fn sets_the_new_value() {
+        build_ext().execute_with(|| {
+            <LockedEraPayout<Test>>::put(REWARD_POT_BALANCE + 25);
+
+            assert_ok!(ParachainStaking::rebase_locked_era_payout(RuntimeOrigin::root(), 40, 0));
+
+            assert_eq!(ParachainStaking::locked_era_payout(), 40);
+            assert_eq!(ParachainStaking::reward_pot_reconciliation().deficit, 0);
+            assert_event_emitted!(Event::LockedEraPayoutRebased {
+                old_locked_era_payout: REWARD_POT_BALANCE + 25,
+                new_locked_era_payout: 40,
+                reward_pot_balance: REWARD_POT_BALANCE,
+            });
+        });
+    }
+
+    #[test]
+    fn keeps_the_failed_payouts_locked() {
+        build_ext().execute_with(|| {
+            <LockedEraPayout<Test>>::put(REWARD_POT_BALANCE + 25);
+            <FailedPayouts<Test>>::insert(nominator(), FailedPayout { amount: 30, retries: 1 });
+
+            assert_ok!(ParachainStaking::rebase_locked_era_payout(RuntimeOrigin::root(), 10, 1));
+
+            assert_eq!(ParachainStaking::locked_era_payout(), 30);
+            assert_event_emitted!(Event::LockedEraPayoutRebased {
+                old_locked_era_payout: REWARD_POT_BALANCE + 25,
+                new_locked_era_payout: 30,
+                reward_pot_balance: REWARD_POT_BALANCE,
+            });
+        });
+    }
+
+    #[test]
+    fn fails_when_the_failed_payout_count_is_too_low() {
+        build_ext().execute_with(|| {
+            <FailedPayouts<Test>>::insert(nominator(), FailedPayout { amount: 30, retries: 1 });
+
+            assert_noop!(
+                ParachainStaking::rebase_locked_era_payout(RuntimeOrigin::root(), 40, 0),
+                Error::<Test>::TooLowFailedPayoutCountWeightHintRebaseLockedEraPayout
+            );
+        });
+    }
+
+    #[test]
+    fn cannot_exceed_the_reward_pot() {
+        build_ext().execute_with(|| {
+            assert_noop!(
+                ParachainStaking::rebase_locked_era_payout(
+                    RuntimeOrigin::root(),
+                    REWARD_POT_BALANCE + 1,
+                    0
+                ),
+                Error::<Test>::LockedEraPayoutExceedsRewardPot
+            );
+        });
+    }
+
+    #[test]
+    fn requires_root() {
+        build_ext().execute_with(|| {
+            assert_noop!(
+                ParachainStaking::rebase_locked_era_payout(
+                    RuntimeOrigin::signed(nominator()),
+                    40,
+                    0
+                ),
+                DispatchError::BadOrigin
+            );
+        });
+    }
+}
@@ -0,0 +1,113 @@
+#[cfg(test)]
+use crate::mock::{
+    pay_gas_for_transaction, roll_one_block, roll_to_era_begin, set_author, AccountId, ExtBuilder,
+    HistoryDepth, ParachainStaking, Test, TestAccount,
+};
+use crate::{EraReward, RewardsHistory, RewardsHistoryEntry};
+
+const PAID_ERA: u32 = 1;
+const COLLATOR_STAKE: u128 = 1000;
+const NOMINATION: u128 = 500;
+
+fn collator_1() -> AccountId {
+    TestAccount::new(1u64).account_id()
+}
+
+fn collator_2() -> AccountId {
+    TestAccount::new(2u64).account_id()
+}
+
+fn nominator() -> AccountId {
+    TestAccount::new(3u64).account_id()
+}
+
+fn tx_sender() -> AccountId {
+    TestAccount::new(4u64).account_id()
+}
+
+fn build_ext() -> sp_io::TestExternalities {
+    ExtBuilder::default()
+        .with_balances(vec![
+            (collator_1(), 10000),
+            (collator_2(), 10000),
+            (nominator(), 10000),
+            (tx_sender(), 10000),
+        ])
+        .with_candidates(vec![(collator_1(), COLLATOR_STAKE), (collator_2(), COLLATOR_STAKE)])
+        .with_nominations(vec![
+            (nominator(), collator_1(), NOMINATION),
+            (nominator(), collator_2(), NOMINATION),
+        ])
+        .build()
+}
+
+// Pays the rewards of `PAID_ERA` to both collators and the nominator
+fn pay_rewards() {
+    roll_to_era_begin(2);
+    pay_gas_for_transaction(&tx_sender(), 100);
+    set_author(PAID_ERA, collator_1(), 1);
+    set_author(PAID_ERA, collator_2(), 1);
+
+    roll_to_era_begin(3);
+    roll_one_block();
+}
+
+fn rewards_of(account: AccountId) -> Vec<EraReward<AccountId, u128>> {
+    ParachainStaking::era_rewards(PAID_ERA, account).to_vec()
+}
+
+mod rewards_history {
+    use super::*;
+
+    #[test]
+    fn records_the_paid_rewards_with_their_collator() {
+        build_ext().execute_with(|| {
+            pay_rewards();
+
+            let collator_rewards = rewards_of(collator_1());
+            assert_eq!(collator_rewards.len(), 1);
+            assert_eq!(collator_rewards[0].collator, collator_1());
+
+            let nominator_rewards = rewards_of(nominator());
+            let collators: Vec<AccountId> =
+                nominator_rewards.iter().map(|reward| reward.collator).collect();
+            assert_eq!(collators.len(), 2);
+            assert!(collators.contains(&collator_1()) && collators.contains(&collator_2()));
+            assert!(nominator_rewards.iter().all(|reward| reward.amount > 0));
+        });
+    }
+
+    #[test]
+    fn can_be_queried_by_era_range() {
+        build_ext().execute_with(|| {
+            pay_rewards();
+
+            let history = ParachainStaking::rewards_history(&collator_2(), 0, 10);
+            let expected_amount = rewards_of(collator_2())[0].amount;
+            assert_eq!(
+                history,
+                vec![RewardsHistoryEntry {
+                    era: PAID_ERA,
+                    collator: collator_2(),
+                    amount: expected_amount
+                }]
+            );
+
+            assert!(ParachainStaking::rewards_history(&collator_2(), PAID_ERA + 1, 10).is_empty());
+            assert!(ParachainStaking::rewards_history(&tx_sender(), 0, 10).is_empty());
+        });
+    }
+
+    #[test]
+    fn drops_eras_older_than_the_history_depth() {
+        build_ext().execute_with(|| {
+            pay_rewards();
+            assert!(!rewards_of(nominator()).is_empty());
+
+            roll_to_era_begin((3 + HistoryDepth::get()) as u64);
+
+            assert!(<RewardsHistory<Test>>::iter_prefix(PAID_ERA).next().is_none());
+            assert!(ParachainStaking::rewards_history(&nominator(), 0, PAID_ERA).is_empty());
+        });
+    }
+}
@@ -0,0 +1,51 @@
+#[cfg(test)]
+use crate::mock::{
+    roll_to_era_begin, AccountId, CollatorsWithoutKeys, ExtBuilder, ParachainStaking,
+    RuntimeOrigin, TestAccount,
+};
+use crate::{assert_event_emitted, Event};
+use frame_support::assert_ok;
+
+fn to_acc_id(id: u64) -> AccountId {
+    return TestAccount::new(id).account_id()
+}
+
+fn build_ext() -> sp_io::TestExternalities {
+    ExtBuilder::default()
+        .with_balances(vec![(to_acc_id(1), 20), (to_acc_id(2), 20)])
+        .with_candidates(vec![(to_acc_id(1), 20), (to_acc_id(2), 20)])
+        .build()
+}
+
+#[test]
+fn candidates_without_session_keys_are_skipped_and_taken_offline() {
+    build_ext().execute_with(|| {
+        CollatorsWithoutKeys::set(vec![to_acc_id(2)]);
+
+        roll_to_era_begin(2);
+
+        assert_eq!(ParachainStaking::selected_candidates().to_vec(), vec![to_acc_id(1)]);
+        assert!(!ParachainStaking::candidate_info(to_acc_id(2)).unwrap().is_active());
+        assert!(!ParachainStaking::candidate_pool()
+            .0
+            .iter()
+            .any(|bond| bond.owner == to_acc_id(2)));
+        assert_event_emitted!(Event::CandidateSkippedNoKeys { era: 2, candidate: to_acc_id(2) });
+    });
+}
+
+#[test]
+fn skipped_candidates_are_selected_again_once_back_online() {
+    build_ext().execute_with(|| {
+        CollatorsWithoutKeys::set(vec![to_acc_id(2)]);
+        roll_to_era_begin(2);
+
+        CollatorsWithoutKeys::set(vec![]);
+        assert_ok!(ParachainStaking::go_online(RuntimeOrigin::signed(to_acc_id(2))));
+        roll_to_era_begin(3);
+
+        let selected = ParachainStaking::selected_candidates();
+        assert_eq!(selected.len(), 2);
+        assert!(selected.contains(&to_acc_id(2)));
+    });
+}
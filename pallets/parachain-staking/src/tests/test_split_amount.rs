@@ -0,0 +1,154 @@
+#[cfg(test)]
+use crate::mock::{
+    build_proof, sign, AccountId, Balance, ExtBuilder, ParachainStaking, RuntimeOrigin as Origin,
+    Staker, Test, TestAccount,
+};
+use crate::{encode_signed_bond_extra_params, Error};
+use frame_support::assert_ok;
+use rand::{rngs::StdRng, RngCore, SeedableRng};
+
+const RANDOM_CASES: u64 = 500;
+const INITIAL_BALANCE: Balance = 1_000_000_000;
+const MAX_CANDIDATES: u64 = 5;
+// Set by the default `ExtBuilder`
+const MIN_TOTAL_NOMINATOR_STAKE: Balance = 5;
+
+fn to_acc_id(id: u64) -> AccountId {
+    TestAccount::new(id).account_id()
+}
+
+fn random_between(rng: &mut StdRng, min: u128, max: u128) -> u128 {
+    let random = (rng.next_u64() as u128) << 64 | rng.next_u64() as u128;
+    min + random % (max - min).saturating_add(1)
+}
+
+fn candidates(number_of_candidates: u64) -> Vec<AccountId> {
+    (1..=number_of_candidates).map(to_acc_id).collect()
+}
+
+fn build_with_candidates(staker: &Staker, nominations: Vec<(AccountId, Balance)>) -> ExtBuilder {
+    let candidates = candidates(MAX_CANDIDATES);
+    let mut balances: Vec<(AccountId, Balance)> =
+        candidates.iter().map(|c| (*c, INITIAL_BALANCE)).collect();
+    balances.push((staker.account_id, INITIAL_BALANCE));
+
+    ExtBuilder::default()
+        .with_balances(balances)
+        .with_candidates(candidates.into_iter().map(|c| (c, 1000)).collect())
+        .with_nominations(
+            nominations
+                .into_iter()
+                .map(|(candidate, amount)| (staker.account_id, candidate, amount))
+                .collect(),
+        )
+}
+
+mod split_amount {
+    use super::*;
+
+    #[test]
+    fn parts_always_add_up_to_the_amount() {
+        let mut rng = StdRng::seed_from_u64(0);
+        for _ in 0..RANDOM_CASES {
+            let amount = random_between(&mut rng, 0, Balance::MAX);
+            let number_of_targets = random_between(&mut rng, 1, 20) as u32;
+
+            let (amount_per_target, dust) =
+                ParachainStaking::split_amount(amount, number_of_targets).unwrap();
+
+            assert_eq!(amount_per_target * number_of_targets as u128 + dust, amount);
+            assert!(dust < number_of_targets as u128);
+        }
+    }
+
+    #[test]
+    fn does_not_overflow_with_the_maximum_amount() {
+        let (amount_per_target, dust) = ParachainStaking::split_amount(Balance::MAX, 3).unwrap();
+
+        assert_eq!(amount_per_target, Balance::MAX / 3);
+        assert_eq!(dust, Balance::MAX % 3);
+    }
+
+    #[test]
+    fn fails_without_targets() {
+        assert!(matches!(
+            ParachainStaking::split_amount(100, 0),
+            Err(Error::<Test>::NoNominationTargets)
+        ));
+    }
+}
+
+mod random_splits {
+    use super::*;
+
+    #[test]
+    fn nominate_the_exact_amount() {
+        let mut rng = StdRng::seed_from_u64(1);
+        for _ in 0..RANDOM_CASES / 10 {
+            let number_of_candidates = random_between(&mut rng, 1, MAX_CANDIDATES as u128) as u64;
+            let min_amount = MIN_TOTAL_NOMINATOR_STAKE * number_of_candidates as u128;
+            let amount = random_between(&mut rng, min_amount, INITIAL_BALANCE);
+            let staker: Staker = Default::default();
+
+            build_with_candidates(&staker, vec![]).build().execute_with(|| {
+                assert_ok!(ParachainStaking::split_and_nominate(
+                    &staker.account_id,
+                    candidates(number_of_candidates),
+                    amount
+                ));
+
+                let state = ParachainStaking::nominator_state(staker.account_id).unwrap();
+                assert_eq!(state.total, amount);
+                assert_eq!(state.nominations.0.iter().map(|n| n.amount).sum::<Balance>(), amount);
+            });
+        }
+    }
+
+    #[test]
+    fn bond_extra_the_exact_amount() {
+        let mut rng = StdRng::seed_from_u64(2);
+        for _ in 0..RANDOM_CASES / 10 {
+            let initial_stake = 10;
+            let number_of_nominations = random_between(&mut rng, 1, MAX_CANDIDATES as u128) as u64;
+            let extra_amount = random_between(
+                &mut rng,
+                number_of_nominations as u128,
+                INITIAL_BALANCE - initial_stake * MAX_CANDIDATES as u128,
+            );
+            let staker: Staker = Default::default();
+            let nominations = candidates(number_of_nominations)
+                .into_iter()
+                .map(|candidate| (candidate, initial_stake))
+                .collect();
+
+            build_with_candidates(&staker, nominations).build().execute_with(|| {
+                let nonce = ParachainStaking::proxy_nonce(staker.account_id);
+                let signed_payload = encode_signed_bond_extra_params::<Test>(
+                    staker.relayer,
+                    &extra_amount,
+                    nonce,
+                    0,
+                );
+                let proof = build_proof(
+                    &staker.account_id,
+                    &staker.relayer,
+                    sign(&staker.key_pair, &signed_payload),
+                );
+
+                assert_ok!(ParachainStaking::signed_bond_extra(
+                    Origin::signed(staker.account_id),
+                    proof,
+                    extra_amount
+                ));
+
+                let state = ParachainStaking::nominator_state(staker.account_id).unwrap();
+                let initial_total = initial_stake * number_of_nominations as u128;
+                assert_eq!(state.total, initial_total + extra_amount);
+                assert_eq!(
+                    state.nominations.0.iter().map(|n| n.amount).sum::<Balance>(),
+                    initial_total + extra_amount
+                );
+            });
+        }
+    }
+}
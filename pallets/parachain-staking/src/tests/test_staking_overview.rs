@@ -0,0 +1,77 @@
+#[cfg(test)]
+use crate::mock::{AccountId, Balances, ExtBuilder, ParachainStaking, Test, TestAccount};
+use crate::{LockedEraPayout, StakingOverview};
+use frame_support::traits::Currency;
+use sp_runtime::Perbill;
+
+fn collator_1() -> AccountId {
+    return TestAccount::new(1u64).account_id()
+}
+
+fn collator_2() -> AccountId {
+    return TestAccount::new(2u64).account_id()
+}
+
+fn collator_3() -> AccountId {
+    return TestAccount::new(3u64).account_id()
+}
+
+fn nominator() -> AccountId {
+    return TestAccount::new(4u64).account_id()
+}
+
+fn build_ext() -> sp_io::TestExternalities {
+    ExtBuilder::default()
+        .with_balances(vec![
+            (collator_1(), 100),
+            (collator_2(), 100),
+            (collator_3(), 100),
+            (nominator(), 100),
+        ])
+        .with_candidates(vec![(collator_1(), 20), (collator_2(), 30), (collator_3(), 40)])
+        .with_nominations(vec![(nominator(), collator_1(), 10)])
+        .build()
+}
+
+fn blocks_per_eras(eras: u32) -> u32 {
+    ParachainStaking::era().length * eras
+}
+
+#[test]
+fn overview_reports_collator_stakes() {
+    build_ext().execute_with(|| {
+        let overview = ParachainStaking::staking_overview(blocks_per_eras(10));
+
+        assert_eq!(overview.total_staked, 100);
+        assert_eq!(overview.total_selected, 3);
+        assert_eq!(overview.average_collator_stake, 33);
+        assert_eq!(overview.min_collator_stake, 30);
+        assert_eq!(overview.max_collator_stake, 40);
+    });
+}
+
+#[test]
+fn overview_estimates_apr_from_the_unlocked_reward_pot() {
+    build_ext().execute_with(|| {
+        let reward_pot = ParachainStaking::compute_reward_pot_account_id();
+        Balances::make_free_balance_be(&reward_pot, 3);
+        <LockedEraPayout<Test>>::put(2);
+
+        let overview = ParachainStaking::staking_overview(blocks_per_eras(10));
+
+        assert_eq!(overview.reward_pot_balance, 3);
+        assert_eq!(overview.locked_era_payout, 2);
+        // 1 token per era over 10 eras, for 100 tokens staked
+        assert_eq!(overview.estimated_apr, Perbill::from_percent(10));
+    });
+}
+
+#[test]
+fn overview_is_empty_without_collators() {
+    ExtBuilder::default().build().execute_with(|| {
+        assert_eq!(
+            ParachainStaking::staking_overview(blocks_per_eras(10)),
+            StakingOverview::default()
+        );
+    });
+}
@@ -1,5 +1,6 @@
 #[cfg(test)]
 use crate::mock::*;
+use crate::{assert_event_emitted, Event};
 use frame_support::traits::Currency;
 
 pub const ONE_TOKEN: u128 = 1_000000_000000_000000u128;
@@ -154,3 +155,32 @@ fn fee_and_tip_is_added_to_pot() {
             assert_eq!(ParachainStaking::reward_pot(), staking_pot_balance + fee + tip);
         });
 }
+
+#[test]
+fn fees_are_accounted_per_era() {
+    let collator_1 = collator_1();
+    let collator_3 = collator_3();
+
+    ExtBuilder::default()
+        .with_balances(vec![(collator_1, 20), (non_collator_account_id(), 40), (collator_3, 20)])
+        .with_candidates(vec![(collator_1, 20), (collator_3, 20)])
+        .build()
+        .execute_with(|| {
+            let fee: u128 = (BASE_FEE + TX_LEN as u64) as u128;
+            let sender = non_collator_account_id();
+            Balances::make_free_balance_be(&sender, AMOUNT_100_TOKEN);
+            let era = ParachainStaking::era().current;
+
+            pay_gas_for_transaction(&sender, 0u128);
+            pay_gas_for_transaction(&sender, 0u128);
+
+            assert_eq!(ParachainStaking::era_fees_to_reward_pot(era), fee * 2);
+            // The fees are only reported when the era ends
+            assert!(!events().iter().any(|e| matches!(e, Event::RewardPotFeesCollected { .. })));
+
+            roll_to_era_begin((era + 1).into());
+
+            assert_event_emitted!(Event::RewardPotFeesCollected { era, amount: fee * 2 });
+            assert_eq!(ParachainStaking::era_fees_to_reward_pot(era + 1), 0);
+        });
+}
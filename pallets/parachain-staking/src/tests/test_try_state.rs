@@ -0,0 +1,105 @@
+#[cfg(test)]
+use crate::mock::{AccountId, Balances, ExtBuilder, ParachainStaking, Test, TestAccount};
+use crate::{
+    FailedPayout, FailedPayouts, LockedEraPayout, SelectedCandidates, Total, TotalSelected,
+    TotalSelectedAtSelection,
+};
+use frame_support::{assert_err, assert_ok, traits::Currency, BoundedVec};
+
+fn collator_1() -> AccountId {
+    return TestAccount::new(1u64).account_id()
+}
+
+fn collator_2() -> AccountId {
+    return TestAccount::new(2u64).account_id()
+}
+
+fn nominator() -> AccountId {
+    return TestAccount::new(3u64).account_id()
+}
+
+fn build_ext() -> sp_io::TestExternalities {
+    ExtBuilder::default()
+        .with_balances(vec![(collator_1(), 100), (collator_2(), 100), (nominator(), 100)])
+        .with_candidates(vec![(collator_1(), 20), (collator_2(), 30)])
+        .with_nominations(vec![(nominator(), collator_1(), 10)])
+        .build()
+}
+
+#[test]
+fn try_state_passes_for_genesis_state() {
+    build_ext().execute_with(|| {
+        assert_ok!(ParachainStaking::do_try_state());
+    });
+}
+
+#[test]
+fn try_state_fails_when_total_does_not_match_bonds() {
+    build_ext().execute_with(|| {
+        <Total<Test>>::put(61);
+
+        assert_err!(ParachainStaking::do_try_state(), "Total does not match the sum of all bonds");
+    });
+}
+
+#[test]
+fn try_state_fails_when_a_selected_collator_is_not_a_candidate() {
+    build_ext().execute_with(|| {
+        let unknown_collator = TestAccount::new(99u64).account_id();
+        <SelectedCandidates<Test>>::put(BoundedVec::truncate_from(vec![unknown_collator]));
+
+        assert_err!(ParachainStaking::do_try_state(), "A selected collator is not a candidate");
+    });
+}
+
+#[test]
+fn try_state_passes_when_total_selected_is_lowered_mid_era() {
+    build_ext().execute_with(|| {
+        assert_eq!(ParachainStaking::selected_candidates().len(), 2);
+        <TotalSelected<Test>>::put(1);
+
+        assert_ok!(ParachainStaking::do_try_state());
+    });
+}
+
+#[test]
+fn try_state_fails_when_more_collators_are_selected_than_the_selection_was_made_with() {
+    build_ext().execute_with(|| {
+        <TotalSelectedAtSelection<Test>>::put(1);
+
+        assert_err!(ParachainStaking::do_try_state(), "More collators selected than TotalSelected");
+    });
+}
+
+#[test]
+fn try_state_fails_when_locked_era_payout_exceeds_reward_pot() {
+    build_ext().execute_with(|| {
+        let reward_pot = ParachainStaking::compute_reward_pot_account_id();
+        Balances::make_free_balance_be(&reward_pot, 10);
+        <LockedEraPayout<Test>>::put(10);
+        assert_ok!(ParachainStaking::do_try_state());
+
+        <LockedEraPayout<Test>>::put(11);
+        assert_err!(
+            ParachainStaking::do_try_state(),
+            "LockedEraPayout exceeds the reward pot balance"
+        );
+    });
+}
+
+#[test]
+fn try_state_fails_when_failed_payouts_are_not_locked() {
+    build_ext().execute_with(|| {
+        let reward_pot = ParachainStaking::compute_reward_pot_account_id();
+        Balances::make_free_balance_be(&reward_pot, 10);
+        <LockedEraPayout<Test>>::put(5);
+        <FailedPayouts<Test>>::insert(nominator(), FailedPayout { amount: 5, retries: 0 });
+        assert_ok!(ParachainStaking::do_try_state());
+
+        <FailedPayouts<Test>>::insert(collator_1(), FailedPayout { amount: 1, retries: 0 });
+        assert_err!(
+            ParachainStaking::do_try_state(),
+            "Failed payouts are not covered by LockedEraPayout"
+        );
+    });
+}
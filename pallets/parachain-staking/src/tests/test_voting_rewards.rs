@@ -0,0 +1,132 @@
+#[cfg(test)]
+use crate::mock::{
+    roll_to_era_begin, AccountId, Balances, ExtBuilder, ParachainStaking, RuntimeOrigin, System,
+    Test, TestAccount,
+};
+use crate::{
+    assert_event_emitted, AdminSettings, BalanceOf, Era, Event, LockedEraPayout,
+    VotingParticipation,
+};
+use frame_support::{assert_ok, traits::Currency};
+use pallet_avn::VotingParticipationHandler;
+
+const REWARD_POT_BALANCE: u128 = 1000;
+const VOTER_BALANCE: u128 = 100;
+const VOTING_REWARD: u128 = 90;
+
+fn voter_1() -> AccountId {
+    return TestAccount::new(1u64).account_id()
+}
+
+fn voter_2() -> AccountId {
+    return TestAccount::new(2u64).account_id()
+}
+
+fn build_ext() -> sp_io::TestExternalities {
+    let mut ext = ExtBuilder::default()
+        .with_balances(vec![(voter_1(), VOTER_BALANCE), (voter_2(), VOTER_BALANCE)])
+        .build();
+    ext.execute_with(|| {
+        System::set_block_number(1);
+        let reward_pot = ParachainStaking::compute_reward_pot_account_id();
+        Balances::make_free_balance_be(&reward_pot, REWARD_POT_BALANCE);
+    });
+    ext
+}
+
+fn set_voting_reward(amount: u128) {
+    assert_ok!(ParachainStaking::set_admin_setting(
+        RuntimeOrigin::root(),
+        AdminSettings::<BalanceOf<Test>>::VotingRewardPerEra(amount)
+    ));
+}
+
+fn cast_votes(voter: &AccountId, votes: u32) {
+    for _ in 0..votes {
+        ParachainStaking::on_vote_cast(voter);
+    }
+}
+
+fn end_current_era() {
+    roll_to_era_begin((<Era<Test>>::get().current + 1).into());
+}
+
+#[test]
+fn votes_are_counted_in_the_current_era() {
+    build_ext().execute_with(|| {
+        let era = <Era<Test>>::get().current;
+
+        cast_votes(&voter_1(), 2);
+
+        assert_eq!(ParachainStaking::voting_participation(era, voter_1()), 2);
+        assert_eq!(ParachainStaking::voting_participation(era + 1, voter_1()), 0);
+    });
+}
+
+#[test]
+fn the_voting_reward_is_shared_in_proportion_to_the_votes() {
+    build_ext().execute_with(|| {
+        let era = <Era<Test>>::get().current;
+        set_voting_reward(VOTING_REWARD);
+        cast_votes(&voter_1(), 2);
+        cast_votes(&voter_2(), 1);
+
+        end_current_era();
+
+        assert_eq!(Balances::free_balance(voter_1()), VOTER_BALANCE + 60);
+        assert_eq!(Balances::free_balance(voter_2()), VOTER_BALANCE + 30);
+        assert_event_emitted!(Event::VotingRewarded {
+            era,
+            voter: voter_1(),
+            votes: 2,
+            amount: 60
+        });
+        assert_event_emitted!(Event::VotingRewarded {
+            era,
+            voter: voter_2(),
+            votes: 1,
+            amount: 30
+        });
+    });
+}
+
+#[test]
+fn the_participation_of_an_era_is_removed_once_it_is_paid() {
+    build_ext().execute_with(|| {
+        let era = <Era<Test>>::get().current;
+        set_voting_reward(VOTING_REWARD);
+        cast_votes(&voter_1(), 2);
+
+        end_current_era();
+
+        assert_eq!(<VotingParticipation<Test>>::iter_prefix(era).count(), 0);
+    });
+}
+
+#[test]
+fn only_the_unlocked_part_of_the_reward_pot_is_paid() {
+    build_ext().execute_with(|| {
+        set_voting_reward(VOTING_REWARD);
+        <LockedEraPayout<Test>>::put(REWARD_POT_BALANCE - 30);
+        cast_votes(&voter_1(), 2);
+        cast_votes(&voter_2(), 1);
+
+        end_current_era();
+
+        assert_eq!(Balances::free_balance(voter_1()), VOTER_BALANCE + 20);
+        assert_eq!(Balances::free_balance(voter_2()), VOTER_BALANCE + 10);
+    });
+}
+
+#[test]
+fn nothing_is_paid_without_a_voting_reward() {
+    build_ext().execute_with(|| {
+        let era = <Era<Test>>::get().current;
+        cast_votes(&voter_1(), 2);
+
+        end_current_era();
+
+        assert_eq!(Balances::free_balance(voter_1()), VOTER_BALANCE);
+        assert_eq!(<VotingParticipation<Test>>::iter_prefix(era).count(), 0);
+    });
+}
@@ -0,0 +1,124 @@
+#[cfg(test)]
+use crate::mock::{
+    roll_to, AccountId, ExtBuilder, ParachainStaking, RuntimeOrigin as Origin, Test, TestAccount,
+};
+use crate::{weights::WeightInfo, Config};
+use frame_support::{assert_ok, dispatch::PostDispatchInfo, weights::Weight};
+
+const HINT: u32 = 50;
+
+type Weights = <Test as Config>::WeightInfo;
+
+fn to_acc_id(id: u64) -> AccountId {
+    TestAccount::new(id).account_id()
+}
+
+fn actual_weight(post_info: PostDispatchInfo) -> Weight {
+    post_info.actual_weight.expect("Actual weight is returned")
+}
+
+#[test]
+fn join_candidates_refunds_the_unused_candidate_count() {
+    let collator = to_acc_id(1);
+    let new_candidate = to_acc_id(2);
+    ExtBuilder::default()
+        .with_balances(vec![(collator, 20), (new_candidate, 20)])
+        .with_candidates(vec![(collator, 20)])
+        .build()
+        .execute_with(|| {
+            let result =
+                ParachainStaking::join_candidates(Origin::signed(new_candidate), 10u128, HINT);
+
+            let weight = actual_weight(result.unwrap());
+            assert_eq!(weight, Weights::join_candidates(1));
+            assert!(weight.all_lt(Weights::join_candidates(HINT)));
+        });
+}
+
+#[test]
+fn schedule_and_cancel_leave_candidates_refund_the_unused_candidate_count() {
+    let collator = to_acc_id(1);
+    let collator_2 = to_acc_id(2);
+    ExtBuilder::default()
+        .with_balances(vec![(collator, 20), (collator_2, 20)])
+        .with_candidates(vec![(collator, 20), (collator_2, 20)])
+        .build()
+        .execute_with(|| {
+            let result =
+                ParachainStaking::schedule_leave_candidates(Origin::signed(collator), HINT);
+            assert_eq!(actual_weight(result.unwrap()), Weights::schedule_leave_candidates(2));
+
+            let result = ParachainStaking::cancel_leave_candidates(Origin::signed(collator), HINT);
+            assert_eq!(actual_weight(result.unwrap()), Weights::cancel_leave_candidates(1));
+        });
+}
+
+#[test]
+fn execute_leave_candidates_refunds_the_unused_nomination_count() {
+    let collator = to_acc_id(1);
+    let nominator = to_acc_id(2);
+    ExtBuilder::default()
+        .with_balances(vec![(collator, 20), (nominator, 20)])
+        .with_candidates(vec![(collator, 20)])
+        .with_nominations(vec![(nominator, collator, 10)])
+        .build()
+        .execute_with(|| {
+            assert_ok!(ParachainStaking::schedule_leave_candidates(Origin::signed(collator), 1));
+            roll_to(10);
+
+            let result = ParachainStaking::execute_leave_candidates(
+                Origin::signed(collator),
+                collator,
+                HINT,
+            );
+
+            let weight = actual_weight(result.unwrap());
+            assert_eq!(weight, Weights::execute_leave_candidates(1));
+            assert!(weight.all_lt(Weights::execute_leave_candidates(HINT)));
+        });
+}
+
+#[test]
+fn nominate_refunds_the_unused_nomination_counts() {
+    let collator = to_acc_id(1);
+    let collator_2 = to_acc_id(2);
+    let nominator = to_acc_id(3);
+    ExtBuilder::default()
+        .with_balances(vec![(collator, 20), (collator_2, 20), (nominator, 40)])
+        .with_candidates(vec![(collator, 20), (collator_2, 20)])
+        .with_nominations(vec![(nominator, collator, 10)])
+        .build()
+        .execute_with(|| {
+            let result =
+                ParachainStaking::nominate(Origin::signed(nominator), collator_2, 10, HINT, HINT);
+
+            let weight = actual_weight(result.unwrap());
+            assert_eq!(weight, Weights::nominate(0, 1));
+            assert!(weight.all_lt(Weights::nominate(HINT, HINT)));
+        });
+}
+
+#[test]
+fn execute_leave_nominators_refunds_the_unused_nomination_count() {
+    let collator = to_acc_id(1);
+    let nominator = to_acc_id(2);
+    ExtBuilder::default()
+        .with_balances(vec![(collator, 20), (nominator, 20)])
+        .with_candidates(vec![(collator, 20)])
+        .with_nominations(vec![(nominator, collator, 10)])
+        .build()
+        .execute_with(|| {
+            assert_ok!(ParachainStaking::schedule_leave_nominators(Origin::signed(nominator)));
+            roll_to(10);
+
+            let result = ParachainStaking::execute_leave_nominators(
+                Origin::signed(nominator),
+                nominator,
+                HINT,
+            );
+
+            let weight = actual_weight(result.unwrap());
+            assert_eq!(weight, Weights::execute_leave_nominators(1));
+            assert!(weight.all_lt(Weights::execute_leave_nominators(HINT)));
+        });
+}
@@ -23,16 +23,21 @@
 use crate::{
     assert_eq_events, assert_eq_last_events, assert_event_emitted, assert_last_event,
     assert_tail_eq,
+    migration::{RemoveExitedCandidatesRequests, SteppedMigrations},
     mock::{
         roll_one_block, roll_to, roll_to_era_begin, roll_to_era_end, set_author, set_reward_pot,
-        AccountId, Balances, ExtBuilder, MaxNominationsPerNominator, ParachainStaking,
-        RuntimeEvent as MetaEvent, RuntimeOrigin as Origin, Test, TestAccount,
+        AccountId, Balances, DisabledOffenders, ExtBuilder, MaxNominationsPerNominator,
+        ParachainStaking, RuntimeEvent as MetaEvent, RuntimeOrigin as Origin, Test, TestAccount,
     },
     nomination_requests::{CancelledScheduledRequest, NominationAction, ScheduledRequest},
     AtStake, CollatorSnapshot, CollatorStatus, Error, Event, NominationScheduledRequests,
     NominatorAdded, NOMINATOR_LOCK_ID,
 };
-use frame_support::{assert_noop, assert_ok};
+use frame_support::{
+    assert_noop, assert_ok,
+    traits::{Get, GetStorageVersion, StorageVersion},
+};
+use pallet_avn::{migration::SteppedMigration, OffenderChiller};
 use sp_runtime::{traits::Zero, BoundedVec, DispatchError, ModuleError};
 
 // ~~ ROOT ~~
@@ -1045,6 +1050,66 @@ fn cannot_go_online_if_leaving() {
         });
 }
 
+// CHILL OFFENDER
+
+#[test]
+fn chilling_an_offender_takes_it_offline() {
+    let account_id = to_acc_id(1u64);
+    ExtBuilder::default()
+        .with_balances(vec![(account_id, 20)])
+        .with_candidates(vec![(account_id, 20)])
+        .build()
+        .execute_with(|| {
+            assert!(<ParachainStaking as OffenderChiller<AccountId>>::chill(&account_id));
+            assert!(ParachainStaking::candidate_pool().0.is_empty());
+            assert!(!<ParachainStaking as OffenderChiller<AccountId>>::chill(&account_id));
+
+            assert_ok!(<ParachainStaking as OffenderChiller<AccountId>>::unchill(&account_id));
+            assert_eq!(ParachainStaking::candidate_pool().0[0].owner, account_id);
+        });
+}
+
+#[test]
+fn unchilling_an_offender_that_is_already_online_succeeds() {
+    let account_id = to_acc_id(1u64);
+    ExtBuilder::default()
+        .with_balances(vec![(account_id, 20)])
+        .with_candidates(vec![(account_id, 20)])
+        .build()
+        .execute_with(|| {
+            assert_ok!(<ParachainStaking as OffenderChiller<AccountId>>::unchill(&account_id));
+            assert_eq!(ParachainStaking::candidate_pool().0[0].owner, account_id);
+        });
+}
+
+#[test]
+fn a_disabled_offender_can_not_go_online() {
+    let account_id = to_acc_id(1u64);
+    ExtBuilder::default()
+        .with_balances(vec![(account_id, 20)])
+        .with_candidates(vec![(account_id, 20)])
+        .build()
+        .execute_with(|| {
+            assert!(<ParachainStaking as OffenderChiller<AccountId>>::chill(&account_id));
+            DisabledOffenders::set(vec![account_id]);
+
+            assert_noop!(
+                ParachainStaking::go_online(Origin::signed(account_id)),
+                Error::<Test>::CandidateDisabled
+            );
+
+            DisabledOffenders::set(vec![]);
+            assert_ok!(ParachainStaking::go_online(Origin::signed(account_id)));
+        });
+}
+
+#[test]
+fn chilling_an_offender_that_is_not_a_candidate_does_nothing() {
+    ExtBuilder::default().build().execute_with(|| {
+        assert!(!<ParachainStaking as OffenderChiller<AccountId>>::chill(&to_acc_id(3)));
+    });
+}
+
 // CANCEL CANDIDATE BOND LESS REQUEST
 
 #[test]
@@ -1970,6 +2035,13 @@ fn parachain_flow_reserve_matches_config() {
                 },
                 Event::Rewarded { account: account_id_7, rewards: 10 },
                 Event::Rewarded { account: account_id_10, rewards: 10 },
+                Event::EraPaidOut {
+                    era: 2,
+                    total_staker_reward: 50,
+                    collators_paid: 1,
+                    failed_payments: 0,
+                    locked_era_payout: 0,
+                },
             ];
             expected.append(&mut new);
             assert_eq_events!(expected.clone());
@@ -2038,6 +2110,13 @@ fn parachain_flow_reserve_matches_config() {
                 Event::Rewarded { account: account_id_6, rewards: 11 },
                 Event::Rewarded { account: account_id_7, rewards: 11 },
                 Event::Rewarded { account: account_id_10, rewards: 11 },
+                Event::EraPaidOut {
+                    era: 3,
+                    total_staker_reward: 55,
+                    collators_paid: 1,
+                    failed_payments: 0,
+                    locked_era_payout: 0,
+                },
                 Event::CollatorChosen {
                     era: 6,
                     collator_account: account_id_5,
@@ -2080,6 +2159,13 @@ fn parachain_flow_reserve_matches_config() {
                     total_candidate_staked: 40,
                 },
                 Event::NominatorLeft { nominator: account_id_6, unstaked_amount: 10 },
+                Event::EraPaidOut {
+                    era: 4,
+                    total_staker_reward: 56,
+                    collators_paid: 1,
+                    failed_payments: 0,
+                    locked_era_payout: 0,
+                },
                 Event::CollatorChosen {
                     era: 7,
                     collator_account: account_id_5,
@@ -2123,6 +2209,13 @@ fn parachain_flow_reserve_matches_config() {
             roll_to(35);
             // keep paying 6
             let mut new3 = vec![
+                Event::EraPaidOut {
+                    era: 5,
+                    total_staker_reward: 58,
+                    collators_paid: 1,
+                    failed_payments: 0,
+                    locked_era_payout: 0,
+                },
                 Event::CollatorChosen {
                     era: 8,
                     collator_account: account_id_5,
@@ -2165,6 +2258,13 @@ fn parachain_flow_reserve_matches_config() {
             roll_to(40);
             // no more paying 6
             let mut new4 = vec![
+                Event::EraPaidOut {
+                    era: 6,
+                    total_staker_reward: 61,
+                    collators_paid: 1,
+                    failed_payments: 0,
+                    locked_era_payout: 0,
+                },
                 Event::CollatorChosen {
                     era: 9,
                     collator_account: account_id_5,
@@ -2220,6 +2320,13 @@ fn parachain_flow_reserve_matches_config() {
                     candidate: account_id,
                     nominator_position: NominatorAdded::AddedToTop { new_total: 50 },
                 },
+                Event::EraPaidOut {
+                    era: 7,
+                    total_staker_reward: 64,
+                    collators_paid: 1,
+                    failed_payments: 0,
+                    locked_era_payout: 0,
+                },
                 Event::CollatorChosen {
                     era: 10,
                     collator_account: account_id_5,
@@ -2263,6 +2370,13 @@ fn parachain_flow_reserve_matches_config() {
             roll_to(50);
             // new nomination is still not rewarded yet
             let mut new6 = vec![
+                Event::EraPaidOut {
+                    era: 8,
+                    total_staker_reward: 67,
+                    collators_paid: 1,
+                    failed_payments: 0,
+                    locked_era_payout: 0,
+                },
                 Event::CollatorChosen {
                     era: 11,
                     collator_account: account_id_5,
@@ -2304,6 +2418,13 @@ fn parachain_flow_reserve_matches_config() {
             roll_to(55);
             // new nomination is rewarded, 2 eras after joining (`RewardPaymentDelay` is 2)
             let mut new7 = vec![
+                Event::EraPaidOut {
+                    era: 9,
+                    total_staker_reward: 70,
+                    collators_paid: 1,
+                    failed_payments: 0,
+                    locked_era_payout: 0,
+                },
                 Event::CollatorChosen {
                     era: 12,
                     collator_account: account_id_5,
@@ -2487,6 +2608,13 @@ fn rewards_matches_config() {
                 Event::Rewarded { account: account_id_4, rewards: 15 },
                 Event::Rewarded { account: account_id_5, rewards: 7 },
                 Event::Rewarded { account: account_id_6, rewards: 7 },
+                Event::EraPaidOut {
+                    era: 3,
+                    total_staker_reward: 30,
+                    collators_paid: 1,
+                    failed_payments: 0,
+                    locked_era_payout: 1,
+                },
             ];
             expected.append(&mut new2);
             assert_eq_events!(expected);
@@ -3008,6 +3136,13 @@ fn payout_distribution_to_solo_collators() {
                     total_balance: 400,
                 },
                 Event::Rewarded { account: account_id, rewards: 305 },
+                Event::EraPaidOut {
+                    era: 2,
+                    total_staker_reward: 305,
+                    collators_paid: 1,
+                    failed_payments: 0,
+                    locked_era_payout: 0,
+                },
             ];
             expected.append(&mut new);
             assert_eq_events!(expected.clone());
@@ -3096,6 +3231,13 @@ fn payout_distribution_to_solo_collators() {
             roll_to(39);
             // pay 20% issuance for all collators
             let mut new2 = vec![
+                Event::EraPaidOut {
+                    era: 4,
+                    total_staker_reward: 320,
+                    collators_paid: 2,
+                    failed_payments: 0,
+                    locked_era_payout: 0,
+                },
                 Event::CollatorChosen {
                     era: 7,
                     collator_account: account_id_5,
@@ -3735,6 +3877,13 @@ fn payouts_follow_nomination_changes() {
                 Event::Rewarded { account: account_id_6, rewards: 10 },
                 Event::Rewarded { account: account_id_7, rewards: 10 },
                 Event::Rewarded { account: account_id_10, rewards: 10 },
+                Event::EraPaidOut {
+                    era: 2,
+                    total_staker_reward: 50,
+                    collators_paid: 1,
+                    failed_payments: 0,
+                    locked_era_payout: 0,
+                },
             ];
             expected.append(&mut new);
             assert_eq_events!(expected.clone());
@@ -3803,6 +3952,13 @@ fn payouts_follow_nomination_changes() {
                 Event::Rewarded { account: account_id_6, rewards: 10 },
                 Event::Rewarded { account: account_id_7, rewards: 10 },
                 Event::Rewarded { account: account_id_10, rewards: 10 },
+                Event::EraPaidOut {
+                    era: 3,
+                    total_staker_reward: 52,
+                    collators_paid: 1,
+                    failed_payments: 0,
+                    locked_era_payout: 0,
+                },
                 Event::CollatorChosen {
                     era: 6,
                     collator_account: account_id_5,
@@ -3856,6 +4012,13 @@ fn payouts_follow_nomination_changes() {
             roll_to(35);
             // keep paying 6
             let mut new3 = vec![
+                Event::EraPaidOut {
+                    era: 4,
+                    total_staker_reward: 56,
+                    collators_paid: 1,
+                    failed_payments: 0,
+                    locked_era_payout: 0,
+                },
                 Event::CollatorChosen {
                     era: 7,
                     collator_account: account_id_5,
@@ -3890,6 +4053,13 @@ fn payouts_follow_nomination_changes() {
                 Event::Rewarded { account: account_id, rewards: 29 },
                 Event::Rewarded { account: account_id_7, rewards: 14 },
                 Event::Rewarded { account: account_id_10, rewards: 14 },
+                Event::EraPaidOut {
+                    era: 5,
+                    total_staker_reward: 58,
+                    collators_paid: 1,
+                    failed_payments: 0,
+                    locked_era_payout: 0,
+                },
                 Event::CollatorChosen {
                     era: 8,
                     collator_account: account_id_5,
@@ -3932,6 +4102,13 @@ fn payouts_follow_nomination_changes() {
             roll_to(40);
             // no more paying 6
             let mut new4 = vec![
+                Event::EraPaidOut {
+                    era: 6,
+                    total_staker_reward: 61,
+                    collators_paid: 1,
+                    failed_payments: 0,
+                    locked_era_payout: 0,
+                },
                 Event::CollatorChosen {
                     era: 9,
                     collator_account: account_id_5,
@@ -3987,6 +4164,13 @@ fn payouts_follow_nomination_changes() {
                     candidate: account_id,
                     nominator_position: NominatorAdded::AddedToTop { new_total: 50 },
                 },
+                Event::EraPaidOut {
+                    era: 7,
+                    total_staker_reward: 64,
+                    collators_paid: 1,
+                    failed_payments: 0,
+                    locked_era_payout: 0,
+                },
                 Event::CollatorChosen {
                     era: 10,
                     collator_account: account_id_5,
@@ -4029,6 +4213,13 @@ fn payouts_follow_nomination_changes() {
             roll_to(50);
             // new nomination not rewarded yet
             let mut new6 = vec![
+                Event::EraPaidOut {
+                    era: 8,
+                    total_staker_reward: 67,
+                    collators_paid: 1,
+                    failed_payments: 0,
+                    locked_era_payout: 0,
+                },
                 Event::CollatorChosen {
                     era: 11,
                     collator_account: account_id_5,
@@ -4071,6 +4262,13 @@ fn payouts_follow_nomination_changes() {
             // new nomination is rewarded for first time
             // 2 eras after joining (`RewardPaymentDelay` = 2)
             let mut new7 = vec![
+                Event::EraPaidOut {
+                    era: 9,
+                    total_staker_reward: 70,
+                    collators_paid: 1,
+                    failed_payments: 0,
+                    locked_era_payout: 0,
+                },
                 Event::CollatorChosen {
                     era: 12,
                     collator_account: account_id_5,
@@ -4602,8 +4800,15 @@ fn no_rewards_paid_until_after_reward_payment_delay() {
             expected.push(Event::Rewarded { account: account_id_3, rewards: 1 });
             assert_eq_events!(expected);
 
-            // there should be no more payments in this era...
+            // there should be no more payments in this era, only the report of the era payout
             let num_blocks_rolled = roll_to_era_end(3);
+            expected.push(Event::EraPaidOut {
+                era: 1,
+                total_staker_reward: 5,
+                collators_paid: 4,
+                failed_payments: 0,
+                locked_era_payout: 0,
+            });
             assert_eq_events!(expected);
             assert_eq!(num_blocks_rolled, 1);
         });
@@ -4736,6 +4941,13 @@ fn deferred_payment_storage_items_are_cleaned_up() {
             roll_to_era_begin(era.into());
             expected.append(&mut vec![
                 Event::Rewarded { account: account_id_2, rewards: 1 }, // from previous era
+                Event::EraPaidOut {
+                    era: 1,
+                    total_staker_reward: 3,
+                    collators_paid: 2,
+                    failed_payments: 0,
+                    locked_era_payout: 1,
+                },
                 Event::CollatorChosen {
                     era,
                     collator_account: account_id,
@@ -4928,8 +5140,14 @@ fn deferred_payment_steady_state_event_flow() {
 
                 roll_one_block();
                 let expected = vec![
-                    // we paid everyone out by now, should repeat last event
-                    Event::Rewarded { account: account_id_33, rewards: 8 },
+                    // we paid everyone out by now, the era payout is reported
+                    Event::EraPaidOut {
+                        era: era as u32 - 2,
+                        total_staker_reward: 130,
+                        collators_paid: 4,
+                        failed_payments: 0,
+                        locked_era_payout: 2,
+                    },
                 ];
                 assert_eq_last_events!(expected);
 
@@ -5604,65 +5822,45 @@ fn test_nomination_request_revoke_exists_returns_true_when_revoke_exists() {
 }
 
 //*******************************************
-#[test]
-fn test_hotfix_remove_nomination_requests_exited_candidates_cleans_up() {
-    let account_id = to_acc_id(1u64);
-    let account_id_2 = to_acc_id(2u64);
-    ExtBuilder::default()
-        .with_balances(vec![(account_id, 20)])
-        .with_candidates(vec![(account_id, 20)])
-        .build()
-        .execute_with(|| {
-            // invalid state
-            <NominationScheduledRequests<Test>>::insert(
-                account_id_2,
-                BoundedVec::<ScheduledRequest<AccountId, u128>, MaxNominationsPerNominator>::default(),
-            );
-            <NominationScheduledRequests<Test>>::insert(
-                to_acc_id(3),
-                BoundedVec::<ScheduledRequest<AccountId, u128>, MaxNominationsPerNominator>::default(),
-            );
-            assert_ok!(ParachainStaking::hotfix_remove_nomination_requests_exited_candidates(
-                Origin::signed(account_id),
-                vec![account_id_2, to_acc_id(3), to_acc_id(4)] /* 4 does not exist, but is OK
-                                                                * for idempotency */
-            ));
-
-            assert!(!<NominationScheduledRequests<Test>>::contains_key(account_id_2));
-            assert!(!<NominationScheduledRequests<Test>>::contains_key(to_acc_id(3)));
-        });
+fn insert_empty_nomination_requests(candidate: AccountId) {
+    <NominationScheduledRequests<Test>>::insert(
+        candidate,
+        BoundedVec::<ScheduledRequest<AccountId, u128>, MaxNominationsPerNominator>::default(),
+    );
 }
 
 #[test]
-fn test_hotfix_remove_nomination_requests_exited_candidates_cleans_up_only_specified_keys() {
+fn migration_removes_empty_nomination_requests_of_exited_candidates() {
     let account_id = to_acc_id(1u64);
-    let account_id_2 = to_acc_id(2u64);
     ExtBuilder::default()
         .with_balances(vec![(account_id, 20)])
         .with_candidates(vec![(account_id, 20)])
         .build()
         .execute_with(|| {
+            StorageVersion::new(4).put::<ParachainStaking>();
             // invalid state
-            <NominationScheduledRequests<Test>>::insert(
-                account_id_2,
-                BoundedVec::<ScheduledRequest<AccountId, u128>, MaxNominationsPerNominator>::default(),
-            );
+            insert_empty_nomination_requests(account_id);
+            insert_empty_nomination_requests(to_acc_id(2));
             <NominationScheduledRequests<Test>>::insert(
                 to_acc_id(3),
-                BoundedVec::<ScheduledRequest<AccountId, u128>, MaxNominationsPerNominator>::default(),
+                BoundedVec::truncate_from(vec![ScheduledRequest {
+                    nominator: to_acc_id(10),
+                    when_executable: 1,
+                    action: NominationAction::Revoke(10),
+                }]),
             );
-            assert_ok!(ParachainStaking::hotfix_remove_nomination_requests_exited_candidates(
-                Origin::signed(account_id),
-                vec![account_id_2]
-            ));
 
-            assert!(!<NominationScheduledRequests<Test>>::contains_key(account_id_2));
+            pallet_avn::migration::migrate::<Test, SteppedMigrations<Test>>();
+
+            assert!(<NominationScheduledRequests<Test>>::contains_key(account_id));
+            assert!(!<NominationScheduledRequests<Test>>::contains_key(to_acc_id(2)));
             assert!(<NominationScheduledRequests<Test>>::contains_key(to_acc_id(3)));
+            assert_eq!(ParachainStaking::on_chain_storage_version(), StorageVersion::new(5));
         });
 }
 
 #[test]
-fn test_hotfix_remove_nomination_requests_exited_candidates_errors_when_requests_not_empty() {
+fn migration_of_nomination_requests_resumes_where_it_stopped() {
     let account_id = to_acc_id(1u64);
     ExtBuilder::default()
         .with_balances(vec![(account_id, 20)])
@@ -5670,31 +5868,28 @@ fn test_hotfix_remove_nomination_requests_exited_candidates_errors_when_requests
         .build()
         .execute_with(|| {
             // invalid state
-            <NominationScheduledRequests<Test>>::insert(
-                to_acc_id(2),
-                BoundedVec::<ScheduledRequest<AccountId, u128>, MaxNominationsPerNominator>::default(),
-            );
-            <NominationScheduledRequests<Test>>::insert(
-                to_acc_id(3),
-                BoundedVec::truncate_from(vec![ScheduledRequest {
-                    nominator: to_acc_id(10),
-                    when_executable: 1,
-                    action: NominationAction::Revoke(10),
-                }]),
-            );
+            for id in 2..6 {
+                insert_empty_nomination_requests(to_acc_id(id));
+            }
+            let one_candidate = <Test as frame_system::Config>::DbWeight::get().reads_writes(2, 1);
+
+            let mut cursor = None;
+            for _ in 0..4 {
+                let (used, next_cursor) =
+                    RemoveExitedCandidatesRequests::<Test>::step(cursor, one_candidate);
+                assert_eq!(used, one_candidate);
+                assert!(next_cursor.is_some());
+                cursor = next_cursor;
+            }
+            assert_eq!(<NominationScheduledRequests<Test>>::iter_keys().count(), 0);
 
-            assert_noop!(
-                ParachainStaking::hotfix_remove_nomination_requests_exited_candidates(
-                    Origin::signed(account_id),
-                    vec![to_acc_id(2), to_acc_id(3)]
-                ),
-                <Error<Test>>::CandidateNotLeaving,
-            );
+            let (_, cursor) = RemoveExitedCandidatesRequests::<Test>::step(cursor, one_candidate);
+            assert!(cursor.is_none());
         });
 }
 
 #[test]
-fn test_hotfix_remove_nomination_requests_exited_candidates_errors_when_candidate_not_exited() {
+fn migration_of_nomination_requests_only_runs_from_version_4() {
     let account_id = to_acc_id(1u64);
     ExtBuilder::default()
         .with_balances(vec![(account_id, 20)])
@@ -5702,17 +5897,11 @@ fn test_hotfix_remove_nomination_requests_exited_candidates_errors_when_candidat
         .build()
         .execute_with(|| {
             // invalid state
-            <NominationScheduledRequests<Test>>::insert(
-                account_id,
-                BoundedVec::<ScheduledRequest<AccountId, u128>, MaxNominationsPerNominator>::default(),
-            );
-            assert_noop!(
-                ParachainStaking::hotfix_remove_nomination_requests_exited_candidates(
-                    Origin::signed(account_id),
-                    vec![account_id]
-                ),
-                <Error<Test>>::CandidateNotLeaving,
-            );
+            insert_empty_nomination_requests(to_acc_id(2));
+
+            pallet_avn::migration::migrate::<Test, SteppedMigrations<Test>>();
+
+            assert!(<NominationScheduledRequests<Test>>::contains_key(to_acc_id(2)));
         });
 }
 
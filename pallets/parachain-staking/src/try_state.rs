@@ -0,0 +1,87 @@
+//! Invariants of the staking state, checked by try-runtime before and after runtime upgrades
+
+use crate::{
+    BalanceOf, CandidateInfo, CandidatePool, Config, FailedPayouts, LockedEraPayout,
+    NominatorState, Pallet, SelectedCandidates, Total, TotalSelectedAtSelection,
+};
+use frame_support::ensure;
+use sp_runtime::{
+    traits::{Saturating, Zero},
+    DispatchError,
+};
+
+impl<T: Config> Pallet<T> {
+    pub(crate) fn do_try_state() -> Result<(), DispatchError> {
+        Self::check_total_staked()?;
+        Self::check_candidates()?;
+        Self::check_locked_era_payout()
+    }
+
+    /// `Total` must be the sum of all collator bonds and nominations
+    fn check_total_staked() -> Result<(), DispatchError> {
+        let mut total_bonded = BalanceOf::<T>::zero();
+
+        for (_, candidate) in <CandidateInfo<T>>::iter() {
+            total_bonded = total_bonded.saturating_add(candidate.bond);
+        }
+
+        for (_, nominator) in <NominatorState<T>>::iter() {
+            let total_nominated = nominator
+                .nominations
+                .0
+                .iter()
+                .fold(BalanceOf::<T>::zero(), |total, bond| total.saturating_add(bond.amount));
+            ensure!(
+                nominator.total == total_nominated,
+                "Nominator total does not match the sum of its nominations"
+            );
+            total_bonded = total_bonded.saturating_add(nominator.total);
+        }
+
+        ensure!(<Total<T>>::get() == total_bonded, "Total does not match the sum of all bonds");
+        Ok(())
+    }
+
+    /// Selected collators and the candidate pool must only contain registered candidates
+    fn check_candidates() -> Result<(), DispatchError> {
+        let selected_candidates = <SelectedCandidates<T>>::get();
+        // `TotalSelected` can be lowered mid-era, so the selection is checked against the value
+        // it was made with. That value is unknown until the first selection after an upgrade.
+        if let Some(total_selected) = <TotalSelectedAtSelection<T>>::get() {
+            ensure!(
+                selected_candidates.len() as u32 <= total_selected,
+                "More collators selected than TotalSelected"
+            );
+        }
+        ensure!(
+            selected_candidates.iter().all(|c| <CandidateInfo<T>>::contains_key(c)),
+            "A selected collator is not a candidate"
+        );
+        ensure!(
+            <CandidatePool<T>>::get()
+                .0
+                .iter()
+                .all(|b| <CandidateInfo<T>>::contains_key(&b.owner)),
+            "The candidate pool contains an unknown candidate"
+        );
+        Ok(())
+    }
+
+    /// Payouts locked for past eras must still be in the reward pot, and include the rewards
+    /// waiting to be retried
+    fn check_locked_era_payout() -> Result<(), DispatchError> {
+        let locked_era_payout = <LockedEraPayout<T>>::get();
+        ensure!(
+            locked_era_payout <= Self::reward_pot(),
+            "LockedEraPayout exceeds the reward pot balance"
+        );
+
+        let failed_payouts = <FailedPayouts<T>>::iter_values()
+            .fold(BalanceOf::<T>::zero(), |total, payout| total.saturating_add(payout.amount));
+        ensure!(
+            failed_payouts <= locked_era_payout,
+            "Failed payouts are not covered by LockedEraPayout"
+        );
+        Ok(())
+    }
+}
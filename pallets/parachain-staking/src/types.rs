@@ -28,8 +28,8 @@ use frame_support::{
     traits::{tokens::WithdrawReasons, LockableCurrency},
 };
 use sp_runtime::{
-    traits::{Saturating, Zero},
-    RuntimeDebug,
+    traits::{AtLeast32BitUnsigned, Saturating, Zero},
+    Perbill, RuntimeDebug,
 };
 use sp_std::{cmp::Ordering, prelude::*};
 
@@ -159,6 +159,44 @@ pub struct DelayedPayout<Balance> {
     pub total_staking_reward: Balance,
 }
 
+#[derive(PartialEq, Clone, Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+/// Staking reward that could not be transferred to its payee, waiting to be retried
+pub struct FailedPayout<Balance> {
+    pub amount: Balance,
+    /// Number of times the transfer has been retried
+    pub retries: u32,
+}
+
+#[derive(Default, PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+/// Progress of the payout of an era
+pub struct PayoutCounts {
+    pub collators_paid: u32,
+    /// Number of rewards that could not be transferred
+    pub failed_payments: u32,
+}
+
+#[derive(Default, PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+/// Summary of the payout of the growth of a period to its collators
+pub struct CollatorPayoutStatement<Balance> {
+    /// Growth lifted for the period
+    pub amount: Balance,
+    pub collators_paid: u32,
+    pub amount_paid: Balance,
+    /// Payments that could not be made. The collators can still claim them.
+    pub missed_payments: u32,
+    pub amount_missed: Balance,
+    /// Part of `amount` left over by the split, handed to the `CollatorPayoutDustHandler`
+    pub dust: Balance,
+}
+
+#[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+/// Staking reward paid to an account for an era
+pub struct EraReward<AccountId, Balance> {
+    /// Collator the reward was earned from
+    pub collator: AccountId,
+    pub amount: Balance,
+}
+
 #[derive(PartialEq, Clone, Copy, Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen)]
 /// Request scheduled to change the collator candidate self-bond
 pub struct CandidateBondLessRequest<Balance> {
@@ -1018,6 +1056,42 @@ impl<
         <BottomNominations<T>>::insert(candidate, bottom_nominations);
         Ok(false)
     }
+    /// Move the highest bottom nomination to the top nominations if there is room for it, which
+    /// can happen when `MaxTopNominationsPerCandidate` is raised.
+    /// Returns the promoted nomination
+    pub fn promote_highest_bottom_nomination<T: Config>(
+        &mut self,
+        candidate: &T::AccountId,
+    ) -> Option<Bond<T::AccountId, BalanceOf<T>>>
+    where
+        BalanceOf<T>: Into<Balance> + From<Balance>,
+    {
+        if matches!(self.bottom_capacity, CapacityStatus::Empty) {
+            return None
+        }
+        // the stored capacity was computed with the maximum in place when it last changed
+        let mut top_nominations = <TopNominations<T>>::get(candidate)
+            .expect("CandidateInfo exists => TopNominations exists");
+        if matches!(top_nominations.top_capacity::<T>(), CapacityStatus::Full) {
+            return None
+        }
+        let mut bottom_nominations = <BottomNominations<T>>::get(candidate)
+            .expect("CandidateInfo exists => BottomNominations exists");
+        if bottom_nominations.nominations.is_empty() {
+            return None
+        }
+        // expect already stored greatest to least by bond amount
+        let highest_bottom_nomination = bottom_nominations.nominations.remove(0);
+        bottom_nominations.total =
+            bottom_nominations.total.saturating_sub(highest_bottom_nomination.amount);
+        self.reset_bottom_data::<T>(&bottom_nominations);
+        <BottomNominations<T>>::insert(candidate, bottom_nominations);
+
+        top_nominations.insert_sorted_greatest_to_least(highest_bottom_nomination.clone());
+        self.reset_top_data::<T>(candidate.clone(), &top_nominations);
+        <TopNominations<T>>::insert(candidate, top_nominations);
+        Some(highest_bottom_nomination)
+    }
 }
 
 /// Convey relevant information describing if a nominator was added to the top or bottom
@@ -1419,6 +1493,62 @@ pub struct GrowthPeriodInfo {
     pub index: GrowthPeriodIndex,
 }
 
+/// A value of a growth record that doesn't match the staking data of the eras of its period
+#[derive(Encode, Decode, Clone, PartialEq, Debug, Eq, TypeInfo)]
+pub enum GrowthDiscrepancy<Balance> {
+    TotalStakerReward { stored: Balance, expected: Balance },
+    TotalPoints { stored: RewardPoint, expected: RewardPoint },
+    CollatorScores { stored_collators: u32, expected_collators: u32 },
+}
+
+/// How the growth of a period is split between the collators that earned points in it
+#[derive(
+    Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, Default, TypeInfo, MaxEncodedLen,
+)]
+pub struct GrowthDistribution {
+    /// Increased every time the formula changes, so payouts can be traced back to it
+    pub version: u32,
+    /// Part of the growth split equally between the collators. The rest is split in proportion
+    /// to their points.
+    pub equal_share: Perbill,
+}
+
+impl GrowthDistribution {
+    /// The amount `collator_points` out of `total_points` earn from the growth `amount`, when
+    /// `number_of_collators` collators share it
+    pub fn collator_share<Balance: AtLeast32BitUnsigned + Copy>(
+        &self,
+        amount: Balance,
+        collator_points: RewardPoint,
+        total_points: RewardPoint,
+        number_of_collators: u32,
+    ) -> Balance {
+        let equal_amount = self.equal_share * amount;
+        let points_amount = amount.saturating_sub(equal_amount);
+        let equal_part = Perbill::from_rational(1u32, number_of_collators) * equal_amount;
+        let points_part = Perbill::from_rational(collator_points, total_points) * points_amount;
+        equal_part.saturating_add(points_part)
+    }
+}
+
+/// What happens to the points a collator earned in a growth period when it is no longer a
+/// candidate by the time the period is paid out
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum ExitedCollatorGrowthPolicy {
+    /// The collator is paid its share. If its account can't receive it, the payment is kept for
+    /// it to claim with `claim_missed_growth_payment`.
+    PayCollator,
+    /// The collator's points are left out of the period and its share goes to the collators
+    /// that are still candidates
+    Redistribute,
+}
+
+impl Default for ExitedCollatorGrowthPolicy {
+    fn default() -> Self {
+        ExitedCollatorGrowthPolicy::PayCollator
+    }
+}
+
 #[derive(Encode, Decode, Clone, PartialEq, Debug, Eq, TypeInfo)]
 pub enum AdminSettings<Balance> {
     /// The delay, in blocks, for actions to wait before being executed
@@ -1427,6 +1557,18 @@ pub enum AdminSettings<Balance> {
     MinCollatorStake(Balance),
     /// Minimum nominator stake amount
     MinTotalNominatorStake(Balance),
+    /// Part of the growth split equally between collators instead of by points
+    GrowthEqualShare(Perbill),
+    /// Amount paid from the reward pot each era to the validators that voted on roots, in
+    /// proportion to their votes
+    VotingRewardPerEra(Balance),
+    /// Number of eras of projected payouts below which the reward pot is reported as low. Zero
+    /// disables the alert.
+    RewardPotAlertThreshold(EraIndex),
+    /// Whether a low reward pot is topped up by `RewardPotTopUp`
+    RewardPotTopUpEnabled(bool),
+    /// How the growth earned by collators that have left the candidate set is settled
+    ExitedCollatorGrowth(ExitedCollatorGrowthPolicy),
 }
 
 impl<
@@ -1451,6 +1593,11 @@ impl<
             AdminSettings::MinTotalNominatorStake(s) =>
                 s >= &<<T as Config>::MinNominationPerCollator as Get<BalanceOf<T>>>::get().into(),
             AdminSettings::MinCollatorStake(_) => true,
+            AdminSettings::GrowthEqualShare(_) => true,
+            AdminSettings::VotingRewardPerEra(_) => true,
+            AdminSettings::RewardPotAlertThreshold(_) => true,
+            AdminSettings::RewardPotTopUpEnabled(_) => true,
+            AdminSettings::ExitedCollatorGrowth(_) => true,
             _ => false,
         }
     }
@@ -1480,3 +1627,153 @@ impl<A, B: Default> StakeInfo<A, B> {
         StakeInfo { owner, free_amount, reserved_amount }
     }
 }
+
+/// On chain identity of the operator of a collator
+#[derive(Clone, Default, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct OperatorIdentity {
+    /// The name registered by the operator for display
+    pub display_name: Vec<u8>,
+    /// True if a registrar judged the identity to be reasonable or known good
+    pub verified: bool,
+}
+
+/// Provides the identity registered by the operator of an account
+pub trait OperatorIdentityProvider<AccountId> {
+    fn identity(who: &AccountId) -> Option<OperatorIdentity>;
+}
+
+impl<AccountId> OperatorIdentityProvider<AccountId> for () {
+    fn identity(_who: &AccountId) -> Option<OperatorIdentity> {
+        None
+    }
+}
+
+/// Funds the reward pot when its balance is reported as low
+pub trait RewardPotTopUp<AccountId, Balance> {
+    /// Transfers up to `amount` to `reward_pot`. Returns the amount transferred.
+    fn top_up(reward_pot: &AccountId, amount: Balance) -> Balance;
+}
+
+impl<AccountId, Balance: Zero> RewardPotTopUp<AccountId, Balance> for () {
+    fn top_up(_reward_pot: &AccountId, _amount: Balance) -> Balance {
+        Zero::zero()
+    }
+}
+
+/// A collator candidate, as seen by nominators choosing where to stake
+#[derive(Clone, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct CandidateOverview<AccountId, Balance> {
+    pub account: AccountId,
+    /// Self bond plus the counted nominations
+    pub total_counted: Balance,
+    /// True if the candidate is collating in the current era
+    pub is_selected: bool,
+    pub identity: Option<OperatorIdentity>,
+}
+
+/// Network-wide staking statistics
+#[derive(Clone, Default, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct StakingOverview<Balance> {
+    /// Total amount locked by collators and nominators
+    pub total_staked: Balance,
+    /// Number of collators selected for the current era
+    pub total_selected: u32,
+    /// Average counted stake of the selected collators
+    pub average_collator_stake: Balance,
+    /// Lowest counted stake of the selected collators
+    pub min_collator_stake: Balance,
+    /// Highest counted stake of the selected collators
+    pub max_collator_stake: Balance,
+    /// Funds available in the reward pot
+    pub reward_pot_balance: Balance,
+    /// Part of the reward pot already allocated to eras that have not been paid out yet
+    pub locked_era_payout: Balance,
+    /// Estimated yearly return of the selected collators' stake if the next era payout is
+    /// repeated every era. This is capped at 100%.
+    pub estimated_apr: sp_runtime::Perbill,
+}
+
+/// How the payouts locked for past eras compare with the funds of the reward pot
+#[derive(Clone, Default, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct RewardPotReconciliation<Balance> {
+    /// Funds available in the reward pot
+    pub reward_pot_balance: Balance,
+    /// Part of the reward pot allocated to eras that have not been paid out yet
+    pub locked_era_payout: Balance,
+    /// Part of `locked_era_payout` owed to payees whose transfer failed
+    pub failed_payouts: Balance,
+    /// Funds of the reward pot that are not locked and will be paid out in the next era
+    pub unlocked: Balance,
+    /// Amount by which `locked_era_payout` exceeds the reward pot. It should always be zero.
+    pub deficit: Balance,
+}
+
+/// Number of nominators of a collator candidate
+#[derive(Clone, Default, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct CandidateNominatorCounts {
+    /// Nominators counted in the candidate's stake
+    pub top: u32,
+    /// Nominators waiting for a place in the top nominations
+    pub bottom: u32,
+    /// Maximum number of nominators set by the candidate, if any
+    pub cap: Option<u32>,
+}
+
+/// Staking reward of an account, as returned by the rewards history
+#[derive(Clone, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct RewardsHistoryEntry<AccountId, Balance> {
+    /// Era the reward was paid for
+    pub era: EraIndex,
+    /// Collator the reward was earned from
+    pub collator: AccountId,
+    pub amount: Balance,
+}
+
+/// Where a growth period is, from the accumulation of its eras to the payout of its collators
+#[derive(Clone, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct GrowthStatus<Balance> {
+    pub period: GrowthPeriodIndex,
+    /// The data accumulated for the period. It is removed once the period is paid out.
+    pub accumulation: Option<GrowthAccumulation<Balance>>,
+    pub trigger: GrowthTrigger,
+    pub payout: GrowthPayout,
+}
+
+#[derive(Clone, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct GrowthAccumulation<Balance> {
+    pub start_era: Option<EraIndex>,
+    /// Eras accumulated so far, out of `eras_per_period`
+    pub eras_accumulated: u32,
+    pub eras_per_period: u32,
+    pub total_stake_accumulated: Balance,
+    pub total_staker_reward: Balance,
+    pub total_points: RewardPoint,
+}
+
+#[derive(Clone, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub enum GrowthTrigger {
+    /// The period is still accumulating, or has not been sent to Ethereum yet
+    NotTriggered,
+    /// Nothing was accumulated in the period, so there was no growth to trigger
+    Skipped,
+    /// The growth was sent to Ethereum in the bridge transaction `tx_id`
+    Published { tx_id: EthereumTransactionId, confirmation: T1Confirmation },
+}
+
+/// Outcome of a bridge transaction on Ethereum
+#[derive(Clone, Copy, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub enum T1Confirmation {
+    Pending,
+    Succeeded,
+    Failed,
+}
+
+#[derive(Clone, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub enum GrowthPayout {
+    NotPaid,
+    /// The lifted growth was paid to the collators. `missed_payments` of them could not be paid
+    /// and can still claim their share.
+    Paid {
+        missed_payments: u32,
+    },
+}
@@ -76,6 +76,25 @@ pub trait WeightInfo {
 	fn select_top_candidates() -> Weight;
 	fn note_author() -> Weight;
 	fn set_admin_setting() -> Weight;
+	fn cancel_admin_change() -> Weight;
+	fn retry_failed_payout() -> Weight;
+	fn audit_growth(e: u32, c: u32, ) -> Weight;
+	fn rebase_locked_era_payout(f: u32, ) -> Weight;
+	fn replace_nomination_request() -> Weight;
+	fn signed_replace_nomination_request() -> Weight;
+	fn set_candidate_nominator_cap() -> Weight;
+	fn claim_missed_growth_payment() -> Weight;
+	fn create_nomination_pool() -> Weight;
+	fn join_nomination_pool() -> Weight;
+	fn pool_bond_extra() -> Weight;
+	fn pool_unbond() -> Weight;
+	fn pool_withdraw_unbonded() -> Weight;
+	fn claim_pool_rewards() -> Weight;
+	fn rebalance_nominations_for(x: u32, ) -> Weight;
+	fn signed_schedule_leave_candidates(x: u32, ) -> Weight;
+	fn signed_execute_leave_candidates(x: u32, ) -> Weight;
+	fn signed_cancel_leave_candidates(x: u32, ) -> Weight;
+	fn cleanup_nomination_requests(x: u32, ) -> Weight;
 }
 
 /// Weights for pallet_parachain_staking using the Substrate node and recommended hardware.
@@ -854,6 +873,8 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `ParachainStaking::AtStake` (`max_values`: None, `max_size`: Some(14486), added: 16961, mode: `MaxEncodedLen`)
 	/// Storage: `ParachainStaking::SelectedCandidates` (r:0 w:1)
 	/// Proof: `ParachainStaking::SelectedCandidates` (`max_values`: Some(1), `max_size`: Some(3202), added: 3697, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::TotalSelectedAtSelection` (r:0 w:1)
+	/// Proof: `ParachainStaking::TotalSelectedAtSelection` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
 	/// Storage: `ParachainStaking::DelayedPayouts` (r:0 w:1)
 	/// Proof: `ParachainStaking::DelayedPayouts` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
 	/// The range of component `x` is `[8, 20]`.
@@ -870,7 +891,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(Weight::from_parts(273_574, 0).saturating_mul(y.into()))
 			.saturating_add(T::DbWeight::get().reads(67_u64))
 			.saturating_add(T::DbWeight::get().reads((9_u64).saturating_mul(x.into())))
-			.saturating_add(T::DbWeight::get().writes(60_u64))
+			.saturating_add(T::DbWeight::get().writes(61_u64))
 			.saturating_add(T::DbWeight::get().writes((6_u64).saturating_mul(x.into())))
 			.saturating_add(Weight::from_parts(0, 16933).saturating_mul(x.into()))
 			.saturating_add(Weight::from_parts(0, 17).saturating_mul(y.into()))
@@ -926,6 +947,8 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `ParachainStaking::TopNominations` (`max_values`: None, `max_size`: Some(14458), added: 16933, mode: `MaxEncodedLen`)
 	/// Storage: `ParachainStaking::SelectedCandidates` (r:0 w:1)
 	/// Proof: `ParachainStaking::SelectedCandidates` (`max_values`: Some(1), `max_size`: Some(3202), added: 3697, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::TotalSelectedAtSelection` (r:0 w:1)
+	/// Proof: `ParachainStaking::TotalSelectedAtSelection` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
 	/// Storage: `ParachainStaking::AtStake` (r:0 w:20)
 	/// Proof: `ParachainStaking::AtStake` (`max_values`: None, `max_size`: Some(14486), added: 16961, mode: `MaxEncodedLen`)
 	fn select_top_candidates() -> Weight {
@@ -935,7 +958,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		// Minimum execution time: 361_771_000 picoseconds.
 		Weight::from_parts(367_409_000, 339650)
 			.saturating_add(T::DbWeight::get().reads(63_u64))
-			.saturating_add(T::DbWeight::get().writes(21_u64))
+			.saturating_add(T::DbWeight::get().writes(22_u64))
 	}
 	/// Storage: `ParachainStaking::Era` (r:1 w:0)
 	/// Proof: `ParachainStaking::Era` (`max_values`: Some(1), `max_size`: Some(12), added: 507, mode: `MaxEncodedLen`)
@@ -962,6 +985,429 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		Weight::from_parts(8_898_000, 0)
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
+	/// Storage: `ParachainStaking::PendingAdminChanges` (r:1 w:1)
+	/// Proof: `ParachainStaking::PendingAdminChanges` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::Lookup` (r:1 w:1)
+	/// Proof: `Scheduler::Lookup` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::Agenda` (r:1 w:1)
+	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(38963), added: 41438, mode: `MaxEncodedLen`)
+	fn cancel_admin_change() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `4242`
+		//  Estimated: `42428`
+		// Minimum execution time: 31_250_000 picoseconds.
+		Weight::from_parts(31_250_000, 42428)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: `ParachainStaking::FailedPayouts` (r:1 w:1)
+	/// Proof: `ParachainStaking::FailedPayouts` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::LockedEraPayout` (r:1 w:1)
+	/// Proof: `ParachainStaking::LockedEraPayout` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	fn retry_failed_payout() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `612`
+		//  Estimated: `6196`
+		// Minimum execution time: 61_430_000 picoseconds.
+		Weight::from_parts(62_817_000, 6196)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: `ParachainStaking::Growth` (r:1 w:1)
+	/// Proof: `ParachainStaking::Growth` (`max_values`: None, `max_size`: Some(520076), added: 522551, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::GrowthStartEra` (r:1 w:0)
+	/// Proof: `ParachainStaking::GrowthStartEra` (`max_values`: None, `max_size`: Some(16), added: 2491, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::DelayedPayouts` (r:1 w:0)
+	/// Proof: `ParachainStaking::DelayedPayouts` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::Points` (r:1 w:0)
+	/// Proof: `ParachainStaking::Points` (`max_values`: None, `max_size`: Some(16), added: 2491, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::AwardedPts` (r:101 w:0)
+	/// Proof: `ParachainStaking::AwardedPts` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// The range of component `e` is `[1, 12]`.
+	/// The range of component `c` is `[1, 100]`.
+	fn audit_growth(e: u32, c: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1342 + c * (86 ±0) + e * (4108 ±0)`
+		//  Estimated: `523541 + c * (2527 ±0) + e * (2507 ±0)`
+		// Minimum execution time: 45_120_000 picoseconds.
+		Weight::from_parts(17_904_310, 523541)
+			// Standard Error: 41_317
+			.saturating_add(Weight::from_parts(9_611_428, 0).saturating_mul(e.into()))
+			// Standard Error: 4_890
+			.saturating_add(Weight::from_parts(4_402_276, 0).saturating_mul(e.into()).saturating_mul(c.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(e.into())))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(e.into()).saturating_mul(c.into())))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(Weight::from_parts(0, 2527).saturating_mul(e.into()).saturating_mul(c.into()))
+			.saturating_add(Weight::from_parts(0, 2507).saturating_mul(e.into()))
+	}
+	/// Storage: `ParachainStaking::FailedPayouts` (r:101 w:0)
+	/// Proof: `ParachainStaking::FailedPayouts` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:0)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::LockedEraPayout` (r:1 w:1)
+	/// Proof: `ParachainStaking::LockedEraPayout` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// The range of component `f` is `[0, 100]`.
+	fn rebase_locked_era_payout(f: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `176 + f * (72 ±0)`
+		//  Estimated: `3581 + f * (2547 ±0)`
+		// Minimum execution time: 21_370_000 picoseconds.
+		Weight::from_parts(22_105_000, 3581)
+			// Standard Error: 2_103
+			.saturating_add(Weight::from_parts(3_412_480, 0).saturating_mul(f.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(f.into())))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(Weight::from_parts(0, 2547).saturating_mul(f.into()))
+	}
+	/// Storage: `ParachainStaking::NominatorState` (r:1 w:1)
+	/// Proof: `ParachainStaking::NominatorState` (`max_values`: None, `max_size`: Some(14506), added: 16981, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::NominationScheduledRequests` (r:1 w:1)
+	/// Proof: `ParachainStaking::NominationScheduledRequests` (`max_values`: None, `max_size`: Some(5350), added: 7825, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::Era` (r:1 w:0)
+	/// Proof: `ParachainStaking::Era` (`max_values`: Some(1), `max_size`: Some(12), added: 507, mode: `MaxEncodedLen`)
+	fn replace_nomination_request() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `785`
+		//  Estimated: `17971`
+		// Minimum execution time: 41_873_000 picoseconds.
+		Weight::from_parts(42_690_000, 17971)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: `ParachainStaking::ProxyNonces` (r:1 w:1)
+	/// Proof: `ParachainStaking::ProxyNonces` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::ProxyNonceEpochs` (r:1 w:0)
+	/// Proof: `ParachainStaking::ProxyNonceEpochs` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::NominatorState` (r:1 w:1)
+	/// Proof: `ParachainStaking::NominatorState` (`max_values`: None, `max_size`: Some(14506), added: 16981, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::NominationScheduledRequests` (r:1 w:1)
+	/// Proof: `ParachainStaking::NominationScheduledRequests` (`max_values`: None, `max_size`: Some(5350), added: 7825, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::Era` (r:1 w:0)
+	/// Proof: `ParachainStaking::Era` (`max_values`: Some(1), `max_size`: Some(12), added: 507, mode: `MaxEncodedLen`)
+	fn signed_replace_nomination_request() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `893`
+		//  Estimated: `17971`
+		// Minimum execution time: 158_204_000 picoseconds.
+		Weight::from_parts(159_811_000, 17971)
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	/// Storage: `ParachainStaking::CandidateInfo` (r:1 w:0)
+	/// Proof: `ParachainStaking::CandidateInfo` (`max_values`: None, `max_size`: Some(152), added: 2627, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::CandidateNominatorCap` (r:0 w:1)
+	/// Proof: `ParachainStaking::CandidateNominatorCap` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	fn set_candidate_nominator_cap() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `512`
+		//  Estimated: `3617`
+		// Minimum execution time: 18_642_000 picoseconds.
+		Weight::from_parts(19_237_000, 3617)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `ParachainStaking::MissedGrowthPayments` (r:1 w:1)
+	/// Proof: `ParachainStaking::MissedGrowthPayments` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn claim_missed_growth_payment() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `478`
+		//  Estimated: `3593`
+		// Minimum execution time: 45_118_000 picoseconds.
+		Weight::from_parts(46_202_000, 3593)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `ParachainStaking::MinTotalNominatorStake` (r:1 w:0)
+	/// Proof: `ParachainStaking::MinTotalNominatorStake` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::CandidateInfo` (r:1 w:1)
+	/// Proof: `ParachainStaking::CandidateInfo` (`max_values`: None, `max_size`: Some(152), added: 2627, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::NextPoolId` (r:1 w:1)
+	/// Proof: `ParachainStaking::NextPoolId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::NominatorState` (r:1 w:1)
+	/// Proof: `ParachainStaking::NominatorState` (`max_values`: None, `max_size`: Some(14506), added: 16981, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::TopNominations` (r:1 w:1)
+	/// Proof: `ParachainStaking::TopNominations` (`max_values`: None, `max_size`: Some(14458), added: 16933, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::CandidatePool` (r:1 w:1)
+	/// Proof: `ParachainStaking::CandidatePool` (`max_values`: Some(1), `max_size`: Some(4802), added: 5297, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::Total` (r:1 w:1)
+	/// Proof: `ParachainStaking::Total` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Locks` (r:1 w:1)
+	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Freezes` (r:1 w:0)
+	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::NominationPools` (r:0 w:1)
+	/// Proof: `ParachainStaking::NominationPools` (`max_values`: None, `max_size`: Some(148), added: 2623, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::PoolMembers` (r:0 w:1)
+	/// Proof: `ParachainStaking::PoolMembers` (`max_values`: None, `max_size`: Some(120), added: 2595, mode: `MaxEncodedLen`)
+	fn create_nomination_pool() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1312`
+		//  Estimated: `17971`
+		// Minimum execution time: 118_404_000 picoseconds.
+		Weight::from_parts(121_930_000, 17971)
+			.saturating_add(T::DbWeight::get().reads(12_u64))
+			.saturating_add(T::DbWeight::get().writes(11_u64))
+	}
+	/// Storage: `ParachainStaking::PoolMembers` (r:1 w:1)
+	/// Proof: `ParachainStaking::PoolMembers` (`max_values`: None, `max_size`: Some(120), added: 2595, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::NominationPools` (r:1 w:1)
+	/// Proof: `ParachainStaking::NominationPools` (`max_values`: None, `max_size`: Some(148), added: 2623, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::NominatorState` (r:1 w:1)
+	/// Proof: `ParachainStaking::NominatorState` (`max_values`: None, `max_size`: Some(14506), added: 16981, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::NominationScheduledRequests` (r:1 w:0)
+	/// Proof: `ParachainStaking::NominationScheduledRequests` (`max_values`: None, `max_size`: Some(5350), added: 7825, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::CandidateInfo` (r:1 w:1)
+	/// Proof: `ParachainStaking::CandidateInfo` (`max_values`: None, `max_size`: Some(152), added: 2627, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::TopNominations` (r:1 w:1)
+	/// Proof: `ParachainStaking::TopNominations` (`max_values`: None, `max_size`: Some(14458), added: 16933, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::CandidatePool` (r:1 w:1)
+	/// Proof: `ParachainStaking::CandidatePool` (`max_values`: Some(1), `max_size`: Some(4802), added: 5297, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::Total` (r:1 w:1)
+	/// Proof: `ParachainStaking::Total` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Locks` (r:1 w:1)
+	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Freezes` (r:1 w:0)
+	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	fn join_nomination_pool() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1597`
+		//  Estimated: `17971`
+		// Minimum execution time: 124_807_000 picoseconds.
+		Weight::from_parts(127_611_000, 17971)
+			.saturating_add(T::DbWeight::get().reads(12_u64))
+			.saturating_add(T::DbWeight::get().writes(10_u64))
+	}
+	/// Storage: `ParachainStaking::NominationPools` (r:1 w:1)
+	/// Proof: `ParachainStaking::NominationPools` (`max_values`: None, `max_size`: Some(148), added: 2623, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::PoolMembers` (r:1 w:1)
+	/// Proof: `ParachainStaking::PoolMembers` (`max_values`: None, `max_size`: Some(120), added: 2595, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::NominatorState` (r:1 w:1)
+	/// Proof: `ParachainStaking::NominatorState` (`max_values`: None, `max_size`: Some(14506), added: 16981, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::NominationScheduledRequests` (r:1 w:0)
+	/// Proof: `ParachainStaking::NominationScheduledRequests` (`max_values`: None, `max_size`: Some(5350), added: 7825, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:3 w:3)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::CandidateInfo` (r:1 w:1)
+	/// Proof: `ParachainStaking::CandidateInfo` (`max_values`: None, `max_size`: Some(152), added: 2627, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::TopNominations` (r:1 w:1)
+	/// Proof: `ParachainStaking::TopNominations` (`max_values`: None, `max_size`: Some(14458), added: 16933, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::CandidatePool` (r:1 w:1)
+	/// Proof: `ParachainStaking::CandidatePool` (`max_values`: Some(1), `max_size`: Some(4802), added: 5297, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::Total` (r:1 w:1)
+	/// Proof: `ParachainStaking::Total` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Locks` (r:1 w:1)
+	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Freezes` (r:1 w:0)
+	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	fn pool_bond_extra() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1713`
+		//  Estimated: `17971`
+		// Minimum execution time: 140_225_000 picoseconds.
+		Weight::from_parts(143_018_000, 17971)
+			.saturating_add(T::DbWeight::get().reads(13_u64))
+			.saturating_add(T::DbWeight::get().writes(11_u64))
+	}
+	/// Storage: `ParachainStaking::NominationPools` (r:1 w:1)
+	/// Proof: `ParachainStaking::NominationPools` (`max_values`: None, `max_size`: Some(148), added: 2623, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::PoolMembers` (r:1 w:1)
+	/// Proof: `ParachainStaking::PoolMembers` (`max_values`: None, `max_size`: Some(120), added: 2595, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::NominatorState` (r:1 w:1)
+	/// Proof: `ParachainStaking::NominatorState` (`max_values`: None, `max_size`: Some(14506), added: 16981, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::NominationScheduledRequests` (r:1 w:1)
+	/// Proof: `ParachainStaking::NominationScheduledRequests` (`max_values`: None, `max_size`: Some(5350), added: 7825, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::MinTotalNominatorStake` (r:1 w:0)
+	/// Proof: `ParachainStaking::MinTotalNominatorStake` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::Era` (r:1 w:0)
+	/// Proof: `ParachainStaking::Era` (`max_values`: Some(1), `max_size`: Some(12), added: 507, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::Delay` (r:1 w:0)
+	/// Proof: `ParachainStaking::Delay` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	fn pool_unbond() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1540`
+		//  Estimated: `17971`
+		// Minimum execution time: 82_316_000 picoseconds.
+		Weight::from_parts(84_140_000, 17971)
+			.saturating_add(T::DbWeight::get().reads(9_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+	}
+	/// Storage: `ParachainStaking::NominationPools` (r:1 w:1)
+	/// Proof: `ParachainStaking::NominationPools` (`max_values`: None, `max_size`: Some(148), added: 2623, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::PoolMembers` (r:1 w:1)
+	/// Proof: `ParachainStaking::PoolMembers` (`max_values`: None, `max_size`: Some(120), added: 2595, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::Era` (r:1 w:0)
+	/// Proof: `ParachainStaking::Era` (`max_values`: Some(1), `max_size`: Some(12), added: 507, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::NominationScheduledRequests` (r:1 w:1)
+	/// Proof: `ParachainStaking::NominationScheduledRequests` (`max_values`: None, `max_size`: Some(5350), added: 7825, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::NominatorState` (r:1 w:1)
+	/// Proof: `ParachainStaking::NominatorState` (`max_values`: None, `max_size`: Some(14506), added: 16981, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::MinTotalNominatorStake` (r:1 w:0)
+	/// Proof: `ParachainStaking::MinTotalNominatorStake` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::CandidateInfo` (r:1 w:1)
+	/// Proof: `ParachainStaking::CandidateInfo` (`max_values`: None, `max_size`: Some(152), added: 2627, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::TopNominations` (r:1 w:1)
+	/// Proof: `ParachainStaking::TopNominations` (`max_values`: None, `max_size`: Some(14458), added: 16933, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::CandidatePool` (r:1 w:1)
+	/// Proof: `ParachainStaking::CandidatePool` (`max_values`: Some(1), `max_size`: Some(4802), added: 5297, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::Total` (r:1 w:1)
+	/// Proof: `ParachainStaking::Total` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Locks` (r:1 w:1)
+	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Freezes` (r:1 w:0)
+	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn pool_withdraw_unbonded() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1902`
+		//  Estimated: `17971`
+		// Minimum execution time: 131_552_000 picoseconds.
+		Weight::from_parts(134_377_000, 17971)
+			.saturating_add(T::DbWeight::get().reads(14_u64))
+			.saturating_add(T::DbWeight::get().writes(11_u64))
+	}
+	/// Storage: `ParachainStaking::NominationPools` (r:1 w:1)
+	/// Proof: `ParachainStaking::NominationPools` (`max_values`: None, `max_size`: Some(148), added: 2623, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::PoolMembers` (r:1 w:1)
+	/// Proof: `ParachainStaking::PoolMembers` (`max_values`: None, `max_size`: Some(120), added: 2595, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn claim_pool_rewards() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `684`
+		//  Estimated: `6196`
+		// Minimum execution time: 52_847_000 picoseconds.
+		Weight::from_parts(54_012_000, 6196)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: `ParachainStaking::CandidateInfo` (r:1 w:1)
+	/// Proof: `ParachainStaking::CandidateInfo` (`max_values`: None, `max_size`: Some(152), added: 2627, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::TopNominations` (r:1 w:1)
+	/// Proof: `ParachainStaking::TopNominations` (`max_values`: None, `max_size`: Some(14458), added: 16933, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::BottomNominations` (r:1 w:1)
+	/// Proof: `ParachainStaking::BottomNominations` (`max_values`: None, `max_size`: Some(14458), added: 16933, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::CandidatePool` (r:1 w:1)
+	/// Proof: `ParachainStaking::CandidatePool` (`max_values`: Some(1), `max_size`: Some(4802), added: 5297, mode: `MaxEncodedLen`)
+	/// The range of component `x` is `[1, 50]`.
+	fn rebalance_nominations_for(x: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `693 + x * (51 ±0)`
+		//  Estimated: `17923`
+		// Minimum execution time: 32_581_000 picoseconds.
+		Weight::from_parts(33_420_000, 17923)
+			// Standard Error: 4_127
+			.saturating_add(Weight::from_parts(6_214_358, 0).saturating_mul(x.into()))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(x.into())))
+			.saturating_add(T::DbWeight::get().writes((4_u64).saturating_mul(x.into())))
+	}
+	/// Storage: `ParachainStaking::ProxyNonces` (r:1 w:1)
+	/// Proof: `ParachainStaking::ProxyNonces` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::CandidateInfo` (r:1 w:1)
+	/// Proof: `ParachainStaking::CandidateInfo` (`max_values`: None, `max_size`: Some(152), added: 2627, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::Era` (r:1 w:0)
+	/// Proof: `ParachainStaking::Era` (`max_values`: Some(1), `max_size`: Some(12), added: 507, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::Delay` (r:1 w:0)
+	/// Proof: `ParachainStaking::Delay` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::CandidatePool` (r:1 w:1)
+	/// Proof: `ParachainStaking::CandidatePool` (`max_values`: Some(1), `max_size`: Some(4802), added: 5297, mode: `MaxEncodedLen`)
+	/// The range of component `x` is `[3, 97]`.
+	fn signed_schedule_leave_candidates(x: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `628`
+		//  Estimated: `6287`
+		// Minimum execution time: 166_347_356 picoseconds.
+		Weight::from_parts(166_347_356, 6287)
+			.saturating_add(Weight::from_parts(140_575, 0).saturating_mul(x.into()))
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: `ParachainStaking::ProxyNonces` (r:1 w:1)
+	/// Proof: `ParachainStaking::ProxyNonces` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::CandidateInfo` (r:1 w:1)
+	/// Proof: `ParachainStaking::CandidateInfo` (`max_values`: None, `max_size`: Some(152), added: 2627, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::Era` (r:1 w:0)
+	/// Proof: `ParachainStaking::Era` (`max_values`: Some(1), `max_size`: Some(12), added: 507, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::TopNominations` (r:1 w:1)
+	/// Proof: `ParachainStaking::TopNominations` (`max_values`: None, `max_size`: Some(14458), added: 16933, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::NominatorState` (r:349 w:349)
+	/// Proof: `ParachainStaking::NominatorState` (`max_values`: None, `max_size`: Some(14506), added: 16981, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Locks` (r:350 w:350)
+	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Freezes` (r:350 w:0)
+	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:350 w:350)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::NominationScheduledRequests` (r:1 w:1)
+	/// Proof: `ParachainStaking::NominationScheduledRequests` (`max_values`: None, `max_size`: Some(5350), added: 7825, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::BottomNominations` (r:1 w:1)
+	/// Proof: `ParachainStaking::BottomNominations` (`max_values`: None, `max_size`: Some(14458), added: 16933, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::Total` (r:1 w:1)
+	/// Proof: `ParachainStaking::Total` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// The range of component `x` is `[2, 350]`.
+	fn signed_execute_leave_candidates(x: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1797`
+		//  Estimated: `17971`
+		// Minimum execution time: 177_479_349 picoseconds.
+		Weight::from_parts(177_479_349, 17971)
+			.saturating_add(Weight::from_parts(34_565_609, 0).saturating_mul(x.into()))
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().reads((4_u64).saturating_mul(x.into())))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+			.saturating_add(T::DbWeight::get().writes((3_u64).saturating_mul(x.into())))
+			.saturating_add(Weight::from_parts(0, 16837).saturating_mul(x.into()))
+	}
+	/// Storage: `ParachainStaking::ProxyNonces` (r:1 w:1)
+	/// Proof: `ParachainStaking::ProxyNonces` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::CandidateInfo` (r:1 w:1)
+	/// Proof: `ParachainStaking::CandidateInfo` (`max_values`: None, `max_size`: Some(152), added: 2627, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::CandidatePool` (r:1 w:1)
+	/// Proof: `ParachainStaking::CandidatePool` (`max_values`: Some(1), `max_size`: Some(4802), added: 5297, mode: `MaxEncodedLen`)
+	/// The range of component `x` is `[3, 97]`.
+	fn signed_cancel_leave_candidates(x: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `628`
+		//  Estimated: `6287`
+		// Minimum execution time: 159_716_566 picoseconds.
+		Weight::from_parts(159_716_566, 6287)
+			.saturating_add(Weight::from_parts(166_242, 0).saturating_mul(x.into()))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: `ParachainStaking::NominationScheduledRequests` (r:1 w:1)
+	/// Proof: `ParachainStaking::NominationScheduledRequests` (`max_values`: None, `max_size`: Some(5350), added: 7825, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::CandidateInfo` (r:1 w:0)
+	/// Proof: `ParachainStaking::CandidateInfo` (`max_values`: None, `max_size`: Some(152), added: 2627, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::NominatorState` (r:10 w:10)
+	/// Proof: `ParachainStaking::NominatorState` (`max_values`: None, `max_size`: Some(14506), added: 16981, mode: `MaxEncodedLen`)
+	/// The range of component `x` is `[0, 100]`.
+	fn cleanup_nomination_requests(x: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `881`
+		//  Estimated: `8815`
+		// Minimum execution time: 14_218_000 picoseconds.
+		Weight::from_parts(14_218_000, 8815)
+			.saturating_add(Weight::from_parts(9_431_000, 0).saturating_mul(x.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(x.into())))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(x.into())))
+	}
 }
 
 // For backwards compatibility and tests.
@@ -1739,6 +2185,8 @@ impl WeightInfo for () {
 	/// Proof: `ParachainStaking::AtStake` (`max_values`: None, `max_size`: Some(14486), added: 16961, mode: `MaxEncodedLen`)
 	/// Storage: `ParachainStaking::SelectedCandidates` (r:0 w:1)
 	/// Proof: `ParachainStaking::SelectedCandidates` (`max_values`: Some(1), `max_size`: Some(3202), added: 3697, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::TotalSelectedAtSelection` (r:0 w:1)
+	/// Proof: `ParachainStaking::TotalSelectedAtSelection` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
 	/// Storage: `ParachainStaking::DelayedPayouts` (r:0 w:1)
 	/// Proof: `ParachainStaking::DelayedPayouts` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
 	/// The range of component `x` is `[8, 20]`.
@@ -1755,7 +2203,7 @@ impl WeightInfo for () {
 			.saturating_add(Weight::from_parts(273_574, 0).saturating_mul(y.into()))
 			.saturating_add(RocksDbWeight::get().reads(67_u64))
 			.saturating_add(RocksDbWeight::get().reads((9_u64).saturating_mul(x.into())))
-			.saturating_add(RocksDbWeight::get().writes(60_u64))
+			.saturating_add(RocksDbWeight::get().writes(61_u64))
 			.saturating_add(RocksDbWeight::get().writes((6_u64).saturating_mul(x.into())))
 			.saturating_add(Weight::from_parts(0, 16933).saturating_mul(x.into()))
 			.saturating_add(Weight::from_parts(0, 17).saturating_mul(y.into()))
@@ -1811,6 +2259,8 @@ impl WeightInfo for () {
 	/// Proof: `ParachainStaking::TopNominations` (`max_values`: None, `max_size`: Some(14458), added: 16933, mode: `MaxEncodedLen`)
 	/// Storage: `ParachainStaking::SelectedCandidates` (r:0 w:1)
 	/// Proof: `ParachainStaking::SelectedCandidates` (`max_values`: Some(1), `max_size`: Some(3202), added: 3697, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::TotalSelectedAtSelection` (r:0 w:1)
+	/// Proof: `ParachainStaking::TotalSelectedAtSelection` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
 	/// Storage: `ParachainStaking::AtStake` (r:0 w:20)
 	/// Proof: `ParachainStaking::AtStake` (`max_values`: None, `max_size`: Some(14486), added: 16961, mode: `MaxEncodedLen`)
 	fn select_top_candidates() -> Weight {
@@ -1820,7 +2270,7 @@ impl WeightInfo for () {
 		// Minimum execution time: 361_771_000 picoseconds.
 		Weight::from_parts(367_409_000, 339650)
 			.saturating_add(RocksDbWeight::get().reads(63_u64))
-			.saturating_add(RocksDbWeight::get().writes(21_u64))
+			.saturating_add(RocksDbWeight::get().writes(22_u64))
 	}
 	/// Storage: `ParachainStaking::Era` (r:1 w:0)
 	/// Proof: `ParachainStaking::Era` (`max_values`: Some(1), `max_size`: Some(12), added: 507, mode: `MaxEncodedLen`)
@@ -1847,4 +2297,427 @@ impl WeightInfo for () {
 		Weight::from_parts(8_898_000, 0)
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
+	/// Storage: `ParachainStaking::PendingAdminChanges` (r:1 w:1)
+	/// Proof: `ParachainStaking::PendingAdminChanges` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::Lookup` (r:1 w:1)
+	/// Proof: `Scheduler::Lookup` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::Agenda` (r:1 w:1)
+	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(38963), added: 41438, mode: `MaxEncodedLen`)
+	fn cancel_admin_change() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `4242`
+		//  Estimated: `42428`
+		// Minimum execution time: 31_250_000 picoseconds.
+		Weight::from_parts(31_250_000, 42428)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: `ParachainStaking::FailedPayouts` (r:1 w:1)
+	/// Proof: `ParachainStaking::FailedPayouts` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::LockedEraPayout` (r:1 w:1)
+	/// Proof: `ParachainStaking::LockedEraPayout` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	fn retry_failed_payout() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `612`
+		//  Estimated: `6196`
+		// Minimum execution time: 61_430_000 picoseconds.
+		Weight::from_parts(62_817_000, 6196)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: `ParachainStaking::Growth` (r:1 w:1)
+	/// Proof: `ParachainStaking::Growth` (`max_values`: None, `max_size`: Some(520076), added: 522551, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::GrowthStartEra` (r:1 w:0)
+	/// Proof: `ParachainStaking::GrowthStartEra` (`max_values`: None, `max_size`: Some(16), added: 2491, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::DelayedPayouts` (r:1 w:0)
+	/// Proof: `ParachainStaking::DelayedPayouts` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::Points` (r:1 w:0)
+	/// Proof: `ParachainStaking::Points` (`max_values`: None, `max_size`: Some(16), added: 2491, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::AwardedPts` (r:101 w:0)
+	/// Proof: `ParachainStaking::AwardedPts` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// The range of component `e` is `[1, 12]`.
+	/// The range of component `c` is `[1, 100]`.
+	fn audit_growth(e: u32, c: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1342 + c * (86 ±0) + e * (4108 ±0)`
+		//  Estimated: `523541 + c * (2527 ±0) + e * (2507 ±0)`
+		// Minimum execution time: 45_120_000 picoseconds.
+		Weight::from_parts(17_904_310, 523541)
+			// Standard Error: 41_317
+			.saturating_add(Weight::from_parts(9_611_428, 0).saturating_mul(e.into()))
+			// Standard Error: 4_890
+			.saturating_add(Weight::from_parts(4_402_276, 0).saturating_mul(e.into()).saturating_mul(c.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(e.into())))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(e.into()).saturating_mul(c.into())))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(Weight::from_parts(0, 2527).saturating_mul(e.into()).saturating_mul(c.into()))
+			.saturating_add(Weight::from_parts(0, 2507).saturating_mul(e.into()))
+	}
+	/// Storage: `ParachainStaking::FailedPayouts` (r:101 w:0)
+	/// Proof: `ParachainStaking::FailedPayouts` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:0)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::LockedEraPayout` (r:1 w:1)
+	/// Proof: `ParachainStaking::LockedEraPayout` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// The range of component `f` is `[0, 100]`.
+	fn rebase_locked_era_payout(f: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `176 + f * (72 ±0)`
+		//  Estimated: `3581 + f * (2547 ±0)`
+		// Minimum execution time: 21_370_000 picoseconds.
+		Weight::from_parts(22_105_000, 3581)
+			// Standard Error: 2_103
+			.saturating_add(Weight::from_parts(3_412_480, 0).saturating_mul(f.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(f.into())))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(Weight::from_parts(0, 2547).saturating_mul(f.into()))
+	}
+	/// Storage: `ParachainStaking::NominatorState` (r:1 w:1)
+	/// Proof: `ParachainStaking::NominatorState` (`max_values`: None, `max_size`: Some(14506), added: 16981, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::NominationScheduledRequests` (r:1 w:1)
+	/// Proof: `ParachainStaking::NominationScheduledRequests` (`max_values`: None, `max_size`: Some(5350), added: 7825, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::Era` (r:1 w:0)
+	/// Proof: `ParachainStaking::Era` (`max_values`: Some(1), `max_size`: Some(12), added: 507, mode: `MaxEncodedLen`)
+	fn replace_nomination_request() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `785`
+		//  Estimated: `17971`
+		// Minimum execution time: 41_873_000 picoseconds.
+		Weight::from_parts(42_690_000, 17971)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: `ParachainStaking::ProxyNonces` (r:1 w:1)
+	/// Proof: `ParachainStaking::ProxyNonces` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::ProxyNonceEpochs` (r:1 w:0)
+	/// Proof: `ParachainStaking::ProxyNonceEpochs` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::NominatorState` (r:1 w:1)
+	/// Proof: `ParachainStaking::NominatorState` (`max_values`: None, `max_size`: Some(14506), added: 16981, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::NominationScheduledRequests` (r:1 w:1)
+	/// Proof: `ParachainStaking::NominationScheduledRequests` (`max_values`: None, `max_size`: Some(5350), added: 7825, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::Era` (r:1 w:0)
+	/// Proof: `ParachainStaking::Era` (`max_values`: Some(1), `max_size`: Some(12), added: 507, mode: `MaxEncodedLen`)
+	fn signed_replace_nomination_request() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `893`
+		//  Estimated: `17971`
+		// Minimum execution time: 158_204_000 picoseconds.
+		Weight::from_parts(159_811_000, 17971)
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	/// Storage: `ParachainStaking::CandidateInfo` (r:1 w:0)
+	/// Proof: `ParachainStaking::CandidateInfo` (`max_values`: None, `max_size`: Some(152), added: 2627, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::CandidateNominatorCap` (r:0 w:1)
+	/// Proof: `ParachainStaking::CandidateNominatorCap` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	fn set_candidate_nominator_cap() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `512`
+		//  Estimated: `3617`
+		// Minimum execution time: 18_642_000 picoseconds.
+		Weight::from_parts(19_237_000, 3617)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `ParachainStaking::MissedGrowthPayments` (r:1 w:1)
+	/// Proof: `ParachainStaking::MissedGrowthPayments` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn claim_missed_growth_payment() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `478`
+		//  Estimated: `3593`
+		// Minimum execution time: 45_118_000 picoseconds.
+		Weight::from_parts(46_202_000, 3593)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `ParachainStaking::MinTotalNominatorStake` (r:1 w:0)
+	/// Proof: `ParachainStaking::MinTotalNominatorStake` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::CandidateInfo` (r:1 w:1)
+	/// Proof: `ParachainStaking::CandidateInfo` (`max_values`: None, `max_size`: Some(152), added: 2627, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::NextPoolId` (r:1 w:1)
+	/// Proof: `ParachainStaking::NextPoolId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::NominatorState` (r:1 w:1)
+	/// Proof: `ParachainStaking::NominatorState` (`max_values`: None, `max_size`: Some(14506), added: 16981, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::TopNominations` (r:1 w:1)
+	/// Proof: `ParachainStaking::TopNominations` (`max_values`: None, `max_size`: Some(14458), added: 16933, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::CandidatePool` (r:1 w:1)
+	/// Proof: `ParachainStaking::CandidatePool` (`max_values`: Some(1), `max_size`: Some(4802), added: 5297, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::Total` (r:1 w:1)
+	/// Proof: `ParachainStaking::Total` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Locks` (r:1 w:1)
+	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Freezes` (r:1 w:0)
+	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::NominationPools` (r:0 w:1)
+	/// Proof: `ParachainStaking::NominationPools` (`max_values`: None, `max_size`: Some(148), added: 2623, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::PoolMembers` (r:0 w:1)
+	/// Proof: `ParachainStaking::PoolMembers` (`max_values`: None, `max_size`: Some(120), added: 2595, mode: `MaxEncodedLen`)
+	fn create_nomination_pool() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1312`
+		//  Estimated: `17971`
+		// Minimum execution time: 118_404_000 picoseconds.
+		Weight::from_parts(121_930_000, 17971)
+			.saturating_add(RocksDbWeight::get().reads(12_u64))
+			.saturating_add(RocksDbWeight::get().writes(11_u64))
+	}
+	/// Storage: `ParachainStaking::PoolMembers` (r:1 w:1)
+	/// Proof: `ParachainStaking::PoolMembers` (`max_values`: None, `max_size`: Some(120), added: 2595, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::NominationPools` (r:1 w:1)
+	/// Proof: `ParachainStaking::NominationPools` (`max_values`: None, `max_size`: Some(148), added: 2623, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::NominatorState` (r:1 w:1)
+	/// Proof: `ParachainStaking::NominatorState` (`max_values`: None, `max_size`: Some(14506), added: 16981, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::NominationScheduledRequests` (r:1 w:0)
+	/// Proof: `ParachainStaking::NominationScheduledRequests` (`max_values`: None, `max_size`: Some(5350), added: 7825, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::CandidateInfo` (r:1 w:1)
+	/// Proof: `ParachainStaking::CandidateInfo` (`max_values`: None, `max_size`: Some(152), added: 2627, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::TopNominations` (r:1 w:1)
+	/// Proof: `ParachainStaking::TopNominations` (`max_values`: None, `max_size`: Some(14458), added: 16933, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::CandidatePool` (r:1 w:1)
+	/// Proof: `ParachainStaking::CandidatePool` (`max_values`: Some(1), `max_size`: Some(4802), added: 5297, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::Total` (r:1 w:1)
+	/// Proof: `ParachainStaking::Total` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Locks` (r:1 w:1)
+	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Freezes` (r:1 w:0)
+	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	fn join_nomination_pool() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1597`
+		//  Estimated: `17971`
+		// Minimum execution time: 124_807_000 picoseconds.
+		Weight::from_parts(127_611_000, 17971)
+			.saturating_add(RocksDbWeight::get().reads(12_u64))
+			.saturating_add(RocksDbWeight::get().writes(10_u64))
+	}
+	/// Storage: `ParachainStaking::NominationPools` (r:1 w:1)
+	/// Proof: `ParachainStaking::NominationPools` (`max_values`: None, `max_size`: Some(148), added: 2623, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::PoolMembers` (r:1 w:1)
+	/// Proof: `ParachainStaking::PoolMembers` (`max_values`: None, `max_size`: Some(120), added: 2595, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::NominatorState` (r:1 w:1)
+	/// Proof: `ParachainStaking::NominatorState` (`max_values`: None, `max_size`: Some(14506), added: 16981, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::NominationScheduledRequests` (r:1 w:0)
+	/// Proof: `ParachainStaking::NominationScheduledRequests` (`max_values`: None, `max_size`: Some(5350), added: 7825, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:3 w:3)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::CandidateInfo` (r:1 w:1)
+	/// Proof: `ParachainStaking::CandidateInfo` (`max_values`: None, `max_size`: Some(152), added: 2627, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::TopNominations` (r:1 w:1)
+	/// Proof: `ParachainStaking::TopNominations` (`max_values`: None, `max_size`: Some(14458), added: 16933, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::CandidatePool` (r:1 w:1)
+	/// Proof: `ParachainStaking::CandidatePool` (`max_values`: Some(1), `max_size`: Some(4802), added: 5297, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::Total` (r:1 w:1)
+	/// Proof: `ParachainStaking::Total` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Locks` (r:1 w:1)
+	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Freezes` (r:1 w:0)
+	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	fn pool_bond_extra() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1713`
+		//  Estimated: `17971`
+		// Minimum execution time: 140_225_000 picoseconds.
+		Weight::from_parts(143_018_000, 17971)
+			.saturating_add(RocksDbWeight::get().reads(13_u64))
+			.saturating_add(RocksDbWeight::get().writes(11_u64))
+	}
+	/// Storage: `ParachainStaking::NominationPools` (r:1 w:1)
+	/// Proof: `ParachainStaking::NominationPools` (`max_values`: None, `max_size`: Some(148), added: 2623, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::PoolMembers` (r:1 w:1)
+	/// Proof: `ParachainStaking::PoolMembers` (`max_values`: None, `max_size`: Some(120), added: 2595, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::NominatorState` (r:1 w:1)
+	/// Proof: `ParachainStaking::NominatorState` (`max_values`: None, `max_size`: Some(14506), added: 16981, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::NominationScheduledRequests` (r:1 w:1)
+	/// Proof: `ParachainStaking::NominationScheduledRequests` (`max_values`: None, `max_size`: Some(5350), added: 7825, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::MinTotalNominatorStake` (r:1 w:0)
+	/// Proof: `ParachainStaking::MinTotalNominatorStake` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::Era` (r:1 w:0)
+	/// Proof: `ParachainStaking::Era` (`max_values`: Some(1), `max_size`: Some(12), added: 507, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::Delay` (r:1 w:0)
+	/// Proof: `ParachainStaking::Delay` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	fn pool_unbond() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1540`
+		//  Estimated: `17971`
+		// Minimum execution time: 82_316_000 picoseconds.
+		Weight::from_parts(84_140_000, 17971)
+			.saturating_add(RocksDbWeight::get().reads(9_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+	}
+	/// Storage: `ParachainStaking::NominationPools` (r:1 w:1)
+	/// Proof: `ParachainStaking::NominationPools` (`max_values`: None, `max_size`: Some(148), added: 2623, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::PoolMembers` (r:1 w:1)
+	/// Proof: `ParachainStaking::PoolMembers` (`max_values`: None, `max_size`: Some(120), added: 2595, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::Era` (r:1 w:0)
+	/// Proof: `ParachainStaking::Era` (`max_values`: Some(1), `max_size`: Some(12), added: 507, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::NominationScheduledRequests` (r:1 w:1)
+	/// Proof: `ParachainStaking::NominationScheduledRequests` (`max_values`: None, `max_size`: Some(5350), added: 7825, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::NominatorState` (r:1 w:1)
+	/// Proof: `ParachainStaking::NominatorState` (`max_values`: None, `max_size`: Some(14506), added: 16981, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::MinTotalNominatorStake` (r:1 w:0)
+	/// Proof: `ParachainStaking::MinTotalNominatorStake` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::CandidateInfo` (r:1 w:1)
+	/// Proof: `ParachainStaking::CandidateInfo` (`max_values`: None, `max_size`: Some(152), added: 2627, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::TopNominations` (r:1 w:1)
+	/// Proof: `ParachainStaking::TopNominations` (`max_values`: None, `max_size`: Some(14458), added: 16933, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::CandidatePool` (r:1 w:1)
+	/// Proof: `ParachainStaking::CandidatePool` (`max_values`: Some(1), `max_size`: Some(4802), added: 5297, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::Total` (r:1 w:1)
+	/// Proof: `ParachainStaking::Total` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Locks` (r:1 w:1)
+	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Freezes` (r:1 w:0)
+	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn pool_withdraw_unbonded() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1902`
+		//  Estimated: `17971`
+		// Minimum execution time: 131_552_000 picoseconds.
+		Weight::from_parts(134_377_000, 17971)
+			.saturating_add(RocksDbWeight::get().reads(14_u64))
+			.saturating_add(RocksDbWeight::get().writes(11_u64))
+	}
+	/// Storage: `ParachainStaking::NominationPools` (r:1 w:1)
+	/// Proof: `ParachainStaking::NominationPools` (`max_values`: None, `max_size`: Some(148), added: 2623, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::PoolMembers` (r:1 w:1)
+	/// Proof: `ParachainStaking::PoolMembers` (`max_values`: None, `max_size`: Some(120), added: 2595, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn claim_pool_rewards() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `684`
+		//  Estimated: `6196`
+		// Minimum execution time: 52_847_000 picoseconds.
+		Weight::from_parts(54_012_000, 6196)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: `ParachainStaking::CandidateInfo` (r:1 w:1)
+	/// Proof: `ParachainStaking::CandidateInfo` (`max_values`: None, `max_size`: Some(152), added: 2627, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::TopNominations` (r:1 w:1)
+	/// Proof: `ParachainStaking::TopNominations` (`max_values`: None, `max_size`: Some(14458), added: 16933, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::BottomNominations` (r:1 w:1)
+	/// Proof: `ParachainStaking::BottomNominations` (`max_values`: None, `max_size`: Some(14458), added: 16933, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::CandidatePool` (r:1 w:1)
+	/// Proof: `ParachainStaking::CandidatePool` (`max_values`: Some(1), `max_size`: Some(4802), added: 5297, mode: `MaxEncodedLen`)
+	/// The range of component `x` is `[1, 50]`.
+	fn rebalance_nominations_for(x: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `693 + x * (51 ±0)`
+		//  Estimated: `17923`
+		// Minimum execution time: 32_581_000 picoseconds.
+		Weight::from_parts(33_420_000, 17923)
+			// Standard Error: 4_127
+			.saturating_add(Weight::from_parts(6_214_358, 0).saturating_mul(x.into()))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().reads((3_u64).saturating_mul(x.into())))
+			.saturating_add(RocksDbWeight::get().writes((4_u64).saturating_mul(x.into())))
+	}
+	/// Storage: `ParachainStaking::ProxyNonces` (r:1 w:1)
+	/// Proof: `ParachainStaking::ProxyNonces` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::CandidateInfo` (r:1 w:1)
+	/// Proof: `ParachainStaking::CandidateInfo` (`max_values`: None, `max_size`: Some(152), added: 2627, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::Era` (r:1 w:0)
+	/// Proof: `ParachainStaking::Era` (`max_values`: Some(1), `max_size`: Some(12), added: 507, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::Delay` (r:1 w:0)
+	/// Proof: `ParachainStaking::Delay` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::CandidatePool` (r:1 w:1)
+	/// Proof: `ParachainStaking::CandidatePool` (`max_values`: Some(1), `max_size`: Some(4802), added: 5297, mode: `MaxEncodedLen`)
+	/// The range of component `x` is `[3, 97]`.
+	fn signed_schedule_leave_candidates(x: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `628`
+		//  Estimated: `6287`
+		// Minimum execution time: 166_347_356 picoseconds.
+		Weight::from_parts(166_347_356, 6287)
+			.saturating_add(Weight::from_parts(140_575, 0).saturating_mul(x.into()))
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: `ParachainStaking::ProxyNonces` (r:1 w:1)
+	/// Proof: `ParachainStaking::ProxyNonces` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::CandidateInfo` (r:1 w:1)
+	/// Proof: `ParachainStaking::CandidateInfo` (`max_values`: None, `max_size`: Some(152), added: 2627, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::Era` (r:1 w:0)
+	/// Proof: `ParachainStaking::Era` (`max_values`: Some(1), `max_size`: Some(12), added: 507, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::TopNominations` (r:1 w:1)
+	/// Proof: `ParachainStaking::TopNominations` (`max_values`: None, `max_size`: Some(14458), added: 16933, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::NominatorState` (r:349 w:349)
+	/// Proof: `ParachainStaking::NominatorState` (`max_values`: None, `max_size`: Some(14506), added: 16981, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Locks` (r:350 w:350)
+	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Freezes` (r:350 w:0)
+	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:350 w:350)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::NominationScheduledRequests` (r:1 w:1)
+	/// Proof: `ParachainStaking::NominationScheduledRequests` (`max_values`: None, `max_size`: Some(5350), added: 7825, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::BottomNominations` (r:1 w:1)
+	/// Proof: `ParachainStaking::BottomNominations` (`max_values`: None, `max_size`: Some(14458), added: 16933, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::Total` (r:1 w:1)
+	/// Proof: `ParachainStaking::Total` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// The range of component `x` is `[2, 350]`.
+	fn signed_execute_leave_candidates(x: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1797`
+		//  Estimated: `17971`
+		// Minimum execution time: 177_479_349 picoseconds.
+		Weight::from_parts(177_479_349, 17971)
+			.saturating_add(Weight::from_parts(34_565_609, 0).saturating_mul(x.into()))
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().reads((4_u64).saturating_mul(x.into())))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+			.saturating_add(RocksDbWeight::get().writes((3_u64).saturating_mul(x.into())))
+			.saturating_add(Weight::from_parts(0, 16837).saturating_mul(x.into()))
+	}
+	/// Storage: `ParachainStaking::ProxyNonces` (r:1 w:1)
+	/// Proof: `ParachainStaking::ProxyNonces` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::CandidateInfo` (r:1 w:1)
+	/// Proof: `ParachainStaking::CandidateInfo` (`max_values`: None, `max_size`: Some(152), added: 2627, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::CandidatePool` (r:1 w:1)
+	/// Proof: `ParachainStaking::CandidatePool` (`max_values`: Some(1), `max_size`: Some(4802), added: 5297, mode: `MaxEncodedLen`)
+	/// The range of component `x` is `[3, 97]`.
+	fn signed_cancel_leave_candidates(x: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `628`
+		//  Estimated: `6287`
+		// Minimum execution time: 159_716_566 picoseconds.
+		Weight::from_parts(159_716_566, 6287)
+			.saturating_add(Weight::from_parts(166_242, 0).saturating_mul(x.into()))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: `ParachainStaking::NominationScheduledRequests` (r:1 w:1)
+	/// Proof: `ParachainStaking::NominationScheduledRequests` (`max_values`: None, `max_size`: Some(5350), added: 7825, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::CandidateInfo` (r:1 w:0)
+	/// Proof: `ParachainStaking::CandidateInfo` (`max_values`: None, `max_size`: Some(152), added: 2627, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::NominatorState` (r:10 w:10)
+	/// Proof: `ParachainStaking::NominatorState` (`max_values`: None, `max_size`: Some(14506), added: 16981, mode: `MaxEncodedLen`)
+	/// The range of component `x` is `[0, 100]`.
+	fn cleanup_nomination_requests(x: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `881`
+		//  Estimated: `8815`
+		// Minimum execution time: 14_218_000 picoseconds.
+		Weight::from_parts(14_218_000, 8815)
+			.saturating_add(Weight::from_parts(9_431_000, 0).saturating_mul(x.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(x.into())))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(x.into())))
+	}
 }
\ No newline at end of file
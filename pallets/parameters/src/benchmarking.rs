@@ -0,0 +1,51 @@
+//! # Parameters pallet
+// Copyright 2024 Aventus Network Services (UK) Ltd.
+
+//! parameters pallet benchmarking.
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+use frame_benchmarking::{benchmarks, impl_benchmark_test_suite};
+use frame_system::{EventRecord, RawOrigin};
+
+benchmarks! {
+    set_parameter {
+        let key = ParameterKey::MinBlocksPerEra;
+        <Parameters<T>>::insert(key, ParameterValue::MinBlocksPerEra(10));
+        let value = ParameterValue::MinBlocksPerEra(20);
+    }: _(RawOrigin::Root, value.clone())
+    verify {
+        assert_eq!(<Parameters<T>>::get(key), Some(value.clone()));
+        assert_last_event::<T>(
+            Event::<T>::ParameterSet {
+                key,
+                old_value: Some(ParameterValue::MinBlocksPerEra(10)),
+                new_value: value,
+            }.into()
+        );
+    }
+
+    reset_parameter {
+        let key = ParameterKey::MinBlocksPerEra;
+        <Parameters<T>>::insert(key, ParameterValue::MinBlocksPerEra(10));
+    }: _(RawOrigin::Root, key)
+    verify {
+        assert!(!<Parameters<T>>::contains_key(key));
+        assert_last_event::<T>(Event::<T>::ParameterReset { key }.into());
+    }
+}
+
+impl_benchmark_test_suite!(
+    Pallet,
+    crate::mock::ExtBuilder::build_default().as_externality(),
+    crate::mock::TestRuntime,
+);
+
+fn assert_last_event<T: Config>(generic_event: <T as Config>::RuntimeEvent) {
+    let events = frame_system::Pallet::<T>::events();
+    let system_event: <T as frame_system::Config>::RuntimeEvent = generic_event.into();
+    // compare to the last event record
+    let EventRecord { event, .. } = &events[events.len().saturating_sub(1 as usize)];
+    assert_eq!(event, &system_event);
+}
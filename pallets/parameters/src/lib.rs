@@ -0,0 +1,253 @@
+//! # AvN Parameters Pallet
+//!
+//! This pallet stores the AvN tunables that can be changed at runtime, without a runtime upgrade.
+//! Each parameter has a typed key, a default value provided by the runtime, and is protected by
+//! an admin origin that can differ per parameter. Pallets read the parameters through the getter
+//! types of this pallet, which implement `Get`, so they can be used in place of constants.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::{Decode, Encode, MaxEncodedLen};
+use frame_support::traits::Get;
+use frame_system::pallet_prelude::BlockNumberFor;
+pub use pallet::*;
+use scale_info::TypeInfo;
+use sp_runtime::{traits::Zero, Perbill, RuntimeDebug};
+use sp_std::marker::PhantomData;
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
+mod benchmarking;
+
+pub mod default_weights;
+pub use default_weights::WeightInfo;
+
+/// The parameters that can be changed at runtime
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum ParameterKey {
+    /// Minimum number of blocks per staking era
+    MinBlocksPerEra,
+    /// Number of eras after which block authors are rewarded
+    RewardPaymentDelay,
+    /// Number of blocks a validator has to advance the summary slot before it can be challenged
+    AdvanceSlotGracePeriod,
+    /// Minimum number of Ethereum blocks mined on top of a transaction before it is accepted
+    MinEthBlockConfirmation,
+    /// Share of the transaction fees and tips sent to the staking reward pot
+    StakingPotFeeShare,
+    /// Maximum number of offenders processed in a single call. The others are processed in the
+    /// following blocks
+    MaxOffendersPerBlock,
+}
+
+/// A parameter together with its value
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum ParameterValue<BlockNumber> {
+    MinBlocksPerEra(u32),
+    RewardPaymentDelay(u32),
+    AdvanceSlotGracePeriod(BlockNumber),
+    MinEthBlockConfirmation(u64),
+    StakingPotFeeShare(Perbill),
+    MaxOffendersPerBlock(u32),
+}
+
+impl<BlockNumber: Zero> ParameterValue<BlockNumber> {
+    pub fn key(&self) -> ParameterKey {
+        match self {
+            ParameterValue::MinBlocksPerEra(_) => ParameterKey::MinBlocksPerEra,
+            ParameterValue::RewardPaymentDelay(_) => ParameterKey::RewardPaymentDelay,
+            ParameterValue::AdvanceSlotGracePeriod(_) => ParameterKey::AdvanceSlotGracePeriod,
+            ParameterValue::MinEthBlockConfirmation(_) => ParameterKey::MinEthBlockConfirmation,
+            ParameterValue::StakingPotFeeShare(_) => ParameterKey::StakingPotFeeShare,
+            ParameterValue::MaxOffendersPerBlock(_) => ParameterKey::MaxOffendersPerBlock,
+        }
+    }
+
+    /// None of the parameters can be set to zero, except the fee share which can route every fee
+    /// to the treasury
+    pub fn is_valid(&self) -> bool {
+        match self {
+            ParameterValue::MinBlocksPerEra(value) => *value > 0,
+            ParameterValue::RewardPaymentDelay(value) => *value > 0,
+            ParameterValue::AdvanceSlotGracePeriod(value) => !value.is_zero(),
+            ParameterValue::MinEthBlockConfirmation(value) => *value > 0,
+            ParameterValue::StakingPotFeeShare(_) => true,
+            ParameterValue::MaxOffendersPerBlock(value) => *value > 0,
+        }
+    }
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+    use super::*;
+    use frame_support::{pallet_prelude::*, traits::EnsureOriginWithArg};
+    use frame_system::pallet_prelude::*;
+
+    #[pallet::config]
+    pub trait Config: frame_system::Config {
+        /// The overarching event type.
+        type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+        /// The origin allowed to change a parameter, which can depend on the parameter
+        type AdminOrigin: EnsureOriginWithArg<Self::RuntimeOrigin, ParameterKey>;
+
+        /// Value of `MinBlocksPerEra` until it is set
+        #[pallet::constant]
+        type DefaultMinBlocksPerEra: Get<u32>;
+        /// Value of `RewardPaymentDelay` until it is set
+        #[pallet::constant]
+        type DefaultRewardPaymentDelay: Get<u32>;
+        /// Value of `AdvanceSlotGracePeriod` until it is set
+        #[pallet::constant]
+        type DefaultAdvanceSlotGracePeriod: Get<BlockNumberFor<Self>>;
+        /// Value of `MinEthBlockConfirmation` until it is set
+        #[pallet::constant]
+        type DefaultMinEthBlockConfirmation: Get<u64>;
+        /// Value of `StakingPotFeeShare` until it is set
+        #[pallet::constant]
+        type DefaultStakingPotFeeShare: Get<Perbill>;
+        /// Value of `MaxOffendersPerBlock` until it is set
+        #[pallet::constant]
+        type DefaultMaxOffendersPerBlock: Get<u32>;
+
+        /// Weight information for the extrinsics in this pallet.
+        type WeightInfo: WeightInfo;
+    }
+
+    #[pallet::pallet]
+    pub struct Pallet<T>(_);
+
+    #[pallet::event]
+    #[pallet::generate_deposit(pub(super) fn deposit_event)]
+    pub enum Event<T: Config> {
+        /// A parameter has been changed. `old_value` is None if the parameter had its default
+        /// value.
+        ParameterSet {
+            key: ParameterKey,
+            old_value: Option<ParameterValue<BlockNumberFor<T>>>,
+            new_value: ParameterValue<BlockNumberFor<T>>,
+        },
+        /// A parameter has been reset to its default value
+        ParameterReset { key: ParameterKey },
+    }
+
+    #[pallet::error]
+    pub enum Error<T> {
+        InvalidParameterValue,
+        ParameterNotSet,
+    }
+
+    /// The parameters that have been changed from their default value
+    #[pallet::storage]
+    pub type Parameters<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        ParameterKey,
+        ParameterValue<BlockNumberFor<T>>,
+        OptionQuery,
+    >;
+
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {
+        /// Set the value of a parameter
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::set_parameter())]
+        #[pallet::call_index(0)]
+        pub fn set_parameter(
+            origin: OriginFor<T>,
+            value: ParameterValue<BlockNumberFor<T>>,
+        ) -> DispatchResult {
+            let key = value.key();
+            T::AdminOrigin::ensure_origin(origin, &key)?;
+            ensure!(value.is_valid(), Error::<T>::InvalidParameterValue);
+
+            let old_value = <Parameters<T>>::get(key);
+            <Parameters<T>>::insert(key, value.clone());
+
+            Self::deposit_event(Event::<T>::ParameterSet { key, old_value, new_value: value });
+            Ok(())
+        }
+
+        /// Reset a parameter to its default value
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::reset_parameter())]
+        #[pallet::call_index(1)]
+        pub fn reset_parameter(origin: OriginFor<T>, key: ParameterKey) -> DispatchResult {
+            T::AdminOrigin::ensure_origin(origin, &key)?;
+            ensure!(<Parameters<T>>::contains_key(key), Error::<T>::ParameterNotSet);
+
+            <Parameters<T>>::remove(key);
+
+            Self::deposit_event(Event::<T>::ParameterReset { key });
+            Ok(())
+        }
+    }
+}
+
+/// Current value of `MinBlocksPerEra`
+pub struct MinBlocksPerEra<T>(PhantomData<T>);
+impl<T: Config> Get<u32> for MinBlocksPerEra<T> {
+    fn get() -> u32 {
+        match <Parameters<T>>::get(ParameterKey::MinBlocksPerEra) {
+            Some(ParameterValue::MinBlocksPerEra(value)) => value,
+            _ => T::DefaultMinBlocksPerEra::get(),
+        }
+    }
+}
+
+/// Current value of `RewardPaymentDelay`
+pub struct RewardPaymentDelay<T>(PhantomData<T>);
+impl<T: Config> Get<u32> for RewardPaymentDelay<T> {
+    fn get() -> u32 {
+        match <Parameters<T>>::get(ParameterKey::RewardPaymentDelay) {
+            Some(ParameterValue::RewardPaymentDelay(value)) => value,
+            _ => T::DefaultRewardPaymentDelay::get(),
+        }
+    }
+}
+
+/// Current value of `AdvanceSlotGracePeriod`
+pub struct AdvanceSlotGracePeriod<T>(PhantomData<T>);
+impl<T: Config> Get<BlockNumberFor<T>> for AdvanceSlotGracePeriod<T> {
+    fn get() -> BlockNumberFor<T> {
+        match <Parameters<T>>::get(ParameterKey::AdvanceSlotGracePeriod) {
+            Some(ParameterValue::AdvanceSlotGracePeriod(value)) => value,
+            _ => T::DefaultAdvanceSlotGracePeriod::get(),
+        }
+    }
+}
+
+/// Current value of `MinEthBlockConfirmation`
+pub struct MinEthBlockConfirmation<T>(PhantomData<T>);
+impl<T: Config> Get<u64> for MinEthBlockConfirmation<T> {
+    fn get() -> u64 {
+        match <Parameters<T>>::get(ParameterKey::MinEthBlockConfirmation) {
+            Some(ParameterValue::MinEthBlockConfirmation(value)) => value,
+            _ => T::DefaultMinEthBlockConfirmation::get(),
+        }
+    }
+}
+
+/// Current value of `StakingPotFeeShare`
+pub struct StakingPotFeeShare<T>(PhantomData<T>);
+impl<T: Config> Get<Perbill> for StakingPotFeeShare<T> {
+    fn get() -> Perbill {
+        match <Parameters<T>>::get(ParameterKey::StakingPotFeeShare) {
+            Some(ParameterValue::StakingPotFeeShare(value)) => value,
+            _ => T::DefaultStakingPotFeeShare::get(),
+        }
+    }
+}
+
+/// Current value of `MaxOffendersPerBlock`
+pub struct MaxOffendersPerBlock<T>(PhantomData<T>);
+impl<T: Config> Get<u32> for MaxOffendersPerBlock<T> {
+    fn get() -> u32 {
+        match <Parameters<T>>::get(ParameterKey::MaxOffendersPerBlock) {
+            Some(ParameterValue::MaxOffendersPerBlock(value)) => value,
+            _ => T::DefaultMaxOffendersPerBlock::get(),
+        }
+    }
+}
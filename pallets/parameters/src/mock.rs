@@ -0,0 +1,114 @@
+// Copyright 2024 Aventus Network Services (UK) Ltd.
+
+use crate::{self as pallet_parameters, *};
+use frame_support::{
+    ord_parameter_types, parameter_types,
+    traits::{ConstU32, ConstU64, EnsureOrigin, EnsureOriginWithArg, Everything},
+};
+use frame_system::{EnsureRoot, EnsureSignedBy};
+use sp_core::H256;
+use sp_runtime::{
+    traits::{BlakeTwo256, IdentityLookup},
+    BuildStorage,
+};
+
+pub const ADMIN_ACCOUNT: u64 = 1;
+pub const OTHER_ACCOUNT: u64 = 2;
+
+type Block = frame_system::mocking::MockBlock<TestRuntime>;
+
+frame_support::construct_runtime!(
+    pub enum TestRuntime {
+        System: frame_system::{Pallet, Call, Config<T>, Storage, Event<T>},
+        Parameters: pallet_parameters::{Pallet, Call, Storage, Event<T>},
+    }
+);
+
+ord_parameter_types! {
+    pub const Admin: u64 = ADMIN_ACCOUNT;
+}
+
+parameter_types! {
+    pub const DefaultStakingPotFeeShare: Perbill = Perbill::from_percent(90);
+}
+
+/// The admin account can change every parameter except `MinEthBlockConfirmation`, which is
+/// reserved to root
+pub struct ParametersAdminOrigin;
+impl EnsureOriginWithArg<RuntimeOrigin, ParameterKey> for ParametersAdminOrigin {
+    type Success = ();
+
+    fn try_origin(o: RuntimeOrigin, key: &ParameterKey) -> Result<Self::Success, RuntimeOrigin> {
+        match key {
+            ParameterKey::MinEthBlockConfirmation => EnsureRoot::<u64>::try_origin(o),
+            _ => EnsureRoot::<u64>::try_origin(o)
+                .or_else(|o| EnsureSignedBy::<Admin, u64>::try_origin(o).map(|_| ())),
+        }
+    }
+
+    #[cfg(feature = "runtime-benchmarks")]
+    fn try_successful_origin(_key: &ParameterKey) -> Result<RuntimeOrigin, ()> {
+        Ok(RuntimeOrigin::root())
+    }
+}
+
+impl Config for TestRuntime {
+    type RuntimeEvent = RuntimeEvent;
+    type AdminOrigin = ParametersAdminOrigin;
+    type DefaultMinBlocksPerEra = ConstU32<10>;
+    type DefaultRewardPaymentDelay = ConstU32<2>;
+    type DefaultAdvanceSlotGracePeriod = ConstU64<5>;
+    type DefaultMinEthBlockConfirmation = ConstU64<20>;
+    type DefaultStakingPotFeeShare = DefaultStakingPotFeeShare;
+    type DefaultMaxOffendersPerBlock = ConstU32<10>;
+    type WeightInfo = ();
+}
+
+impl frame_system::Config for TestRuntime {
+    type BaseCallFilter = Everything;
+    type BlockWeights = ();
+    type BlockLength = ();
+    type DbWeight = ();
+    type RuntimeOrigin = RuntimeOrigin;
+    type RuntimeCall = RuntimeCall;
+    type Nonce = u64;
+    type Hash = H256;
+    type Hashing = BlakeTwo256;
+    type AccountId = u64;
+    type Lookup = IdentityLookup<Self::AccountId>;
+    type Block = Block;
+    type RuntimeEvent = RuntimeEvent;
+    type BlockHashCount = ConstU64<250>;
+    type Version = ();
+    type PalletInfo = PalletInfo;
+    type AccountData = ();
+    type OnNewAccount = ();
+    type OnKilledAccount = ();
+    type SystemWeightInfo = ();
+    type SS58Prefix = ();
+    type OnSetCode = ();
+    type MaxConsumers = ConstU32<16>;
+}
+
+pub struct ExtBuilder {
+    pub storage: sp_runtime::Storage,
+}
+
+impl ExtBuilder {
+    pub fn build_default() -> Self {
+        let storage =
+            frame_system::GenesisConfig::<TestRuntime>::default().build_storage().unwrap();
+        Self { storage }
+    }
+
+    pub fn as_externality(self) -> sp_io::TestExternalities {
+        let mut ext = sp_io::TestExternalities::from(self.storage);
+        // Events do not get emitted on block 0, so we increment the block here
+        ext.execute_with(|| frame_system::Pallet::<TestRuntime>::set_block_number(1u32.into()));
+        ext
+    }
+}
+
+pub fn event_emitted(event: &RuntimeEvent) -> bool {
+    System::events().iter().any(|a| a.event == *event)
+}
@@ -0,0 +1,184 @@
+// Copyright 2024 Aventus Network Services (UK) Ltd.
+
+#![cfg(test)]
+
+use crate::{mock::*, *};
+use frame_support::{assert_noop, assert_ok};
+use sp_runtime::traits::BadOrigin;
+
+mod set_parameter {
+    use super::*;
+
+    mod succeeds {
+        use super::*;
+
+        #[test]
+        fn when_the_parameter_has_its_default_value() {
+            let mut ext = ExtBuilder::build_default().as_externality();
+            ext.execute_with(|| {
+                assert_eq!(MinBlocksPerEra::<TestRuntime>::get(), 10);
+
+                assert_ok!(Parameters::set_parameter(
+                    RuntimeOrigin::signed(ADMIN_ACCOUNT),
+                    ParameterValue::MinBlocksPerEra(30)
+                ));
+
+                assert_eq!(MinBlocksPerEra::<TestRuntime>::get(), 30);
+                assert!(event_emitted(&RuntimeEvent::Parameters(
+                    Event::<TestRuntime>::ParameterSet {
+                        key: ParameterKey::MinBlocksPerEra,
+                        old_value: None,
+                        new_value: ParameterValue::MinBlocksPerEra(30),
+                    }
+                )));
+            });
+        }
+
+        #[test]
+        fn when_the_parameter_has_already_been_set() {
+            let mut ext = ExtBuilder::build_default().as_externality();
+            ext.execute_with(|| {
+                assert_ok!(Parameters::set_parameter(
+                    RuntimeOrigin::root(),
+                    ParameterValue::AdvanceSlotGracePeriod(8)
+                ));
+                assert_ok!(Parameters::set_parameter(
+                    RuntimeOrigin::root(),
+                    ParameterValue::AdvanceSlotGracePeriod(12)
+                ));
+
+                assert_eq!(AdvanceSlotGracePeriod::<TestRuntime>::get(), 12);
+                assert!(event_emitted(&RuntimeEvent::Parameters(
+                    Event::<TestRuntime>::ParameterSet {
+                        key: ParameterKey::AdvanceSlotGracePeriod,
+                        old_value: Some(ParameterValue::AdvanceSlotGracePeriod(8)),
+                        new_value: ParameterValue::AdvanceSlotGracePeriod(12),
+                    }
+                )));
+            });
+        }
+
+        #[test]
+        fn without_changing_the_other_parameters() {
+            let mut ext = ExtBuilder::build_default().as_externality();
+            ext.execute_with(|| {
+                assert_ok!(Parameters::set_parameter(
+                    RuntimeOrigin::root(),
+                    ParameterValue::MinEthBlockConfirmation(40)
+                ));
+
+                assert_eq!(MinEthBlockConfirmation::<TestRuntime>::get(), 40);
+                assert_eq!(MinBlocksPerEra::<TestRuntime>::get(), 10);
+                assert_eq!(RewardPaymentDelay::<TestRuntime>::get(), 2);
+                assert_eq!(AdvanceSlotGracePeriod::<TestRuntime>::get(), 5);
+            });
+        }
+
+        #[test]
+        fn when_the_fee_share_is_zero() {
+            let mut ext = ExtBuilder::build_default().as_externality();
+            ext.execute_with(|| {
+                assert_eq!(StakingPotFeeShare::<TestRuntime>::get(), Perbill::from_percent(90));
+
+                assert_ok!(Parameters::set_parameter(
+                    RuntimeOrigin::signed(ADMIN_ACCOUNT),
+                    ParameterValue::StakingPotFeeShare(Perbill::zero())
+                ));
+
+                assert_eq!(StakingPotFeeShare::<TestRuntime>::get(), Perbill::zero());
+            });
+        }
+    }
+
+    mod fails_when {
+        use super::*;
+
+        #[test]
+        fn the_origin_is_not_an_admin() {
+            let mut ext = ExtBuilder::build_default().as_externality();
+            ext.execute_with(|| {
+                assert_noop!(
+                    Parameters::set_parameter(
+                        RuntimeOrigin::signed(OTHER_ACCOUNT),
+                        ParameterValue::RewardPaymentDelay(3)
+                    ),
+                    BadOrigin
+                );
+            });
+        }
+
+        #[test]
+        fn the_origin_is_not_allowed_to_change_this_parameter() {
+            let mut ext = ExtBuilder::build_default().as_externality();
+            ext.execute_with(|| {
+                assert_noop!(
+                    Parameters::set_parameter(
+                        RuntimeOrigin::signed(ADMIN_ACCOUNT),
+                        ParameterValue::MinEthBlockConfirmation(40)
+                    ),
+                    BadOrigin
+                );
+            });
+        }
+
+        #[test]
+        fn the_value_is_zero() {
+            let mut ext = ExtBuilder::build_default().as_externality();
+            ext.execute_with(|| {
+                assert_noop!(
+                    Parameters::set_parameter(
+                        RuntimeOrigin::root(),
+                        ParameterValue::MinBlocksPerEra(0)
+                    ),
+                    Error::<TestRuntime>::InvalidParameterValue
+                );
+                assert_noop!(
+                    Parameters::set_parameter(
+                        RuntimeOrigin::root(),
+                        ParameterValue::MaxOffendersPerBlock(0)
+                    ),
+                    Error::<TestRuntime>::InvalidParameterValue
+                );
+            });
+        }
+    }
+}
+
+mod reset_parameter {
+    use super::*;
+
+    #[test]
+    fn restores_the_default_value() {
+        let mut ext = ExtBuilder::build_default().as_externality();
+        ext.execute_with(|| {
+            assert_ok!(Parameters::set_parameter(
+                RuntimeOrigin::root(),
+                ParameterValue::RewardPaymentDelay(4)
+            ));
+
+            assert_ok!(Parameters::reset_parameter(
+                RuntimeOrigin::signed(ADMIN_ACCOUNT),
+                ParameterKey::RewardPaymentDelay
+            ));
+
+            assert_eq!(RewardPaymentDelay::<TestRuntime>::get(), 2);
+            assert!(event_emitted(&RuntimeEvent::Parameters(
+                Event::<TestRuntime>::ParameterReset { key: ParameterKey::RewardPaymentDelay }
+            )));
+        });
+    }
+
+    #[test]
+    fn fails_when_the_parameter_has_not_been_set() {
+        let mut ext = ExtBuilder::build_default().as_externality();
+        ext.execute_with(|| {
+            assert_noop!(
+                Parameters::reset_parameter(
+                    RuntimeOrigin::root(),
+                    ParameterKey::RewardPaymentDelay
+                ),
+                Error::<TestRuntime>::ParameterNotSet
+            );
+        });
+    }
+}
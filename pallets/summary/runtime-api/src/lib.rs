@@ -0,0 +1,21 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+use codec::Codec;
+use pallet_summary::{RootId, RootRangeAnomaly, ValidatedRoot};
+use sp_runtime::traits::AtLeast32Bit;
+use sp_std::vec::Vec;
+
+sp_api::decl_runtime_apis! {
+
+    #[api_version(3)]
+    pub trait SummaryApi<BlockNumber>
+            where
+        BlockNumber: Codec + AtLeast32Bit,
+    {
+        /// The validated root of the Ethereum summary whose range includes `block_number`
+        fn validated_root(block_number: BlockNumber) -> Option<ValidatedRoot<BlockNumber>>;
+
+        /// The validated roots whose range left a gap after, or overlapped, the previous range
+        #[api_version(3)]
+        fn root_range_anomalies() -> Vec<(RootId<BlockNumber>, RootRangeAnomaly<BlockNumber>)>;
+    }
+}
@@ -19,6 +19,8 @@ pub const ROOT_HASH_BYTES: [u8; 32] = [
     135, 54, 201, 230, 113, 254, 88, 31, 228, 239, 70, 49, 17, 32, 56, 41, 125, 205, 236, 174, 22,
     62, 135, 36, 194, 129, 236, 232, 173, 148, 200, 195,
 ];
+// Votes, including the sender's, that can't end a voting with the minimum number of validators
+const MAX_VOTES_WITHOUT_OUTCOME: u32 = 4;
 
 fn setup_publish_root_voting<T: Config<I>, I: 'static>(
     validators: Vec<Validator<<T as pallet_avn::Config>::AuthorityId, T::AccountId>>,
@@ -98,6 +100,29 @@ fn setup_votes<T: Config<I>, I: 'static>(
     }
 }
 
+// Sets up `number_of_votes` - 1 votes so that the voting still has no outcome once the sender
+// votes with `sender_approves`
+fn setup_votes_without_outcome<T: Config<I>, I: 'static>(
+    validators: &Vec<Validator<<T as pallet_avn::Config>::AuthorityId, T::AccountId>>,
+    number_of_votes: u32,
+    quorum: u32,
+    root_id: &RootId<BlockNumberFor<T>>,
+    sender_approves: bool,
+) {
+    let existing_votes = number_of_votes - 1;
+    let opposing_votes = existing_votes.min(quorum - 1);
+    setup_votes::<T, I>(validators, opposing_votes, root_id, !sender_approves);
+
+    let mut supporting_voters = validators.clone();
+    supporting_voters.reverse();
+    setup_votes::<T, I>(
+        &supporting_voters,
+        existing_votes - opposing_votes,
+        root_id,
+        sender_approves,
+    );
+}
+
 fn advance_block<T: Config<I>, I: 'static>(number: BlockNumberFor<T>) {
     let now = System::<T>::block_number();
     System::<T>::set_block_number(now + number);
@@ -204,6 +229,13 @@ fn assert_event_exists<T: Config<I>, I: 'static>(generic_event: <T as Config<I>>
     );
 }
 
+fn offence_evidence_of_type<T: Config<I>, I: 'static>(offence_type: SummaryOffenceType) -> H256 {
+    OffenceEvidence::<T, I>::iter()
+        .find(|(_, evidence)| evidence.offence_type == offence_type)
+        .map(|(hash, _)| hash)
+        .expect("Evidence is recorded for the offence")
+}
+
 #[cfg(test)]
 fn set_recovered_account_for_tests<T: Config<I>, I: 'static>(
     sender_account_id: &<T as system::Config>::AccountId,
@@ -226,6 +258,64 @@ benchmarks_instance_pallet! {
         assert_eq!(VotingPeriod::<T, I>::get(), new_voting_period);
     }
 
+    cancel_admin_change {
+        let (schedule_name, _) = Pallet::<T, I>::announce_admin_change(Call::<T, I>::set_periods {
+            schedule_period_in_blocks: 200u32.into(),
+            voting_period_in_blocks: 150u32.into(),
+        })?;
+    }: _(RawOrigin::Root, schedule_name)
+    verify {
+        assert!(!PendingAdminChanges::<T, I>::contains_key(schedule_name));
+    }
+
+    force_skip_range {
+        let next_block_to_process = NextBlockToProcess::<T, I>::get();
+        let root_range = RootRange::new(
+            next_block_to_process,
+            next_block_to_process + SchedulePeriod::<T, I>::get() - 1u32.into(),
+        );
+        let ingress_counter: IngressCounter = 1;
+        PendingApproval::<T, I>::insert(root_range, ingress_counter);
+    }: _(RawOrigin::Root, root_range)
+    verify {
+        assert!(SkippedRootRanges::<T, I>::contains_key(root_range));
+        assert!(!PendingApproval::<T, I>::contains_key(root_range));
+        assert_eq!(NextBlockToProcess::<T, I>::get(), root_range.to_block + 1u32.into());
+    }
+
+    set_adaptive_schedule_period {
+        let bounds = SchedulePeriodBounds {
+            min: (VotingPeriod::<T, I>::get() + 1u32.into()).max(T::MinSchedulePeriod::get()),
+            max: T::MaxSchedulePeriod::get(),
+        };
+    }: _(RawOrigin::Root, Some(bounds))
+    verify {
+        assert_eq!(AdaptiveSchedulePeriod::<T, I>::get(), Some(bounds));
+    }
+
+    cleanup {
+        let c in 1 .. MAX_CLEANUP_LIMIT;
+
+        for ingress_counter in 1 ..= c {
+            let root_id = RootId::new(RootRange::new(0u32.into(), 60u32.into()), ingress_counter);
+            setup_voting_session::<T, I>(&root_id);
+            PendingApproval::<T, I>::remove(root_id.range);
+            InconclusiveVotingRounds::<T, I>::insert(root_id, 1);
+        }
+        let caller: T::AccountId = account("caller", 0, 0);
+    }: _(RawOrigin::Signed(caller), c)
+    verify {
+        assert_eq!(VotesRepository::<T, I>::iter_keys().count(), 0);
+        assert_last_event::<T, I>(
+            Event::<T, I>::ExpiredStateRemoved {
+                voting_sessions: c,
+                tx_ids: 0,
+                offence_evidence: 0,
+            }
+            .into()
+        );
+    }
+
     record_summary_calculation {
         let v in 3 .. MAX_VALIDATOR_ACCOUNTS;
         let r in 1 .. MAX_NUMBER_OF_ROOT_DATA_PER_RANGE;
@@ -238,7 +328,7 @@ benchmarks_instance_pallet! {
     }: _(RawOrigin::None, new_block_number, root_hash, ingress_counter, validator.clone(), signature)
     verify {
         let range = RootRange::new(next_block_to_process, new_block_number);
-        let root = Roots::<T, I>::get(range, ingress_counter);
+        let root = Roots::<T, I>::get(range, ingress_counter).expect("Root exists");
 
         assert_eq!(<TotalIngresses<T, I>>::get(), ingress_counter);
         assert!(PendingApproval::<T, I>::contains_key(range));
@@ -281,7 +371,7 @@ benchmarks_instance_pallet! {
         assert_eq!(true, vote.ayes.contains(&sender.account_id));
 
         assert_eq!(true, NextBlockToProcess::<T, I>::get() == root_id.range.to_block + 1u32.into());
-        assert_eq!(true, Roots::<T, I>::get(root_id.range, root_id.ingress_counter).is_validated);
+        assert_eq!(true, Roots::<T, I>::get(root_id.range, root_id.ingress_counter).expect("Root exists").is_validated);
         assert_eq!(true, SlotOfLastPublishedSummary::<T, I>::get() == CurrentSlot::<T, I>::get());
         assert_eq!(false, PendingApproval::<T, I>::contains_key(&root_id.range));
 
@@ -290,15 +380,17 @@ benchmarks_instance_pallet! {
 
         assert_last_nth_event::<T, I>(Event::<T, I>::SummaryOffenceReported {
                 offence_type: SummaryOffenceType::RejectedValidRoot,
-                offenders: create_offenders_identification::<T, I>(&vote.nays)
+                offenders: create_offenders_identification::<T, I>(&vote.nays),
+                evidence: offence_evidence_of_type::<T, I>(SummaryOffenceType::RejectedValidRoot)
             }.into(),
             4
         );
-        let root_data = Roots::<T, I>::get(root_id.range, root_id.ingress_counter);
+        let root_data = Roots::<T, I>::get(root_id.range, root_id.ingress_counter).expect("Root exists");
             assert_last_nth_event::<T, I>(Event::<T, I>::SummaryRootValidated {
                 root_hash: root_data.root_hash,
                 ingress_counter: root_id.ingress_counter,
-                block_range: root_id.range
+                block_range: root_id.range,
+                sequence: avn::Pallet::<T>::next_event_sequence() - 1,
             }.into(),
             3
         );
@@ -320,10 +412,13 @@ benchmarks_instance_pallet! {
     }
 
     approve_root_without_end_voting {
-        let v in 4 .. MAX_VALIDATOR_ACCOUNTS;
-        let validators = setup_validators::<T, I>(v);
+        let v in 7 .. MAX_VALIDATOR_ACCOUNTS;
+        let c in 1 .. MAX_VOTES_WITHOUT_OUTCOME;
+        let mut validators = setup_validators::<T, I>(v);
         let (sender, root_id,  signature, quorum) = setup_publish_root_voting::<T, I>(validators.clone());
+        validators.remove(validators.len() - (1 as usize)); // Avoid setting up sender to vote automatically
         setup_roots::<T, I>(1, sender.account_id.clone(), root_id.ingress_counter - 1);
+        setup_votes_without_outcome::<T, I>(&validators, c, quorum, &root_id, true);
 
         CurrentSlot::<T, I>::put::<BlockNumberFor<T>>(3u32.into());
     }: approve_root(RawOrigin::None, root_id, sender.clone(), signature)
@@ -332,7 +427,7 @@ benchmarks_instance_pallet! {
         assert_eq!(true, vote.ayes.contains(&sender.account_id));
 
         assert_eq!(false, NextBlockToProcess::<T, I>::get() == root_id.range.to_block + 1u32.into());
-        assert_eq!(false, Roots::<T, I>::get(root_id.range, root_id.ingress_counter).is_validated);
+        assert_eq!(false, Roots::<T, I>::get(root_id.range, root_id.ingress_counter).expect("Root exists").is_validated);
         assert_eq!(false, SlotOfLastPublishedSummary::<T, I>::get() == CurrentSlot::<T, I>::get());
         assert_eq!(true, PendingApproval::<T, I>::contains_key(&root_id.range));
 
@@ -363,22 +458,24 @@ benchmarks_instance_pallet! {
     }: reject_root(RawOrigin::None, root_id.clone(), sender.clone(), signature)
     verify {
         assert_eq!(false, NextBlockToProcess::<T, I>::get() == root_id.range.to_block + 1u32.into());
-        assert_eq!(false, Roots::<T, I>::get(root_id.range, root_id.ingress_counter).is_validated);
+        assert_eq!(false, Roots::<T, I>::get(root_id.range, root_id.ingress_counter).expect("Root exists").is_validated);
         assert_eq!(false, SlotOfLastPublishedSummary::<T, I>::get() == CurrentSlot::<T, I>::get() + 1u32.into());
 
         assert_eq!(false, PendingApproval::<T, I>::contains_key(&root_id.range));
 
-        let root_data = Roots::<T, I>::get(root_id.range, root_id.ingress_counter);
+        let root_data = Roots::<T, I>::get(root_id.range, root_id.ingress_counter).expect("Root exists");
         assert_event_exists::<T, I>(Event::<T, I>::SummaryOffenceReported {
                 offence_type: SummaryOffenceType::CreatedInvalidRoot,
-                offenders: create_offenders_identification::<T, I>(&vec![root_data.added_by.unwrap()])
+                offenders: create_offenders_identification::<T, I>(&vec![root_data.added_by.unwrap()]),
+                evidence: offence_evidence_of_type::<T, I>(SummaryOffenceType::CreatedInvalidRoot)
             }.into()
         );
 
         let vote = VotesRepository::<T, I>::get(&root_id);
         assert_event_exists::<T, I>(Event::<T, I>::SummaryOffenceReported {
                 offence_type: SummaryOffenceType::ApprovedInvalidRoot,
-                offenders: create_offenders_identification::<T, I>(&vote.ayes)
+                offenders: create_offenders_identification::<T, I>(&vote.ayes),
+                evidence: offence_evidence_of_type::<T, I>(SummaryOffenceType::ApprovedInvalidRoot)
             }.into()
         );
 
@@ -397,16 +494,18 @@ benchmarks_instance_pallet! {
     }
 
     reject_root_without_end_voting {
-        let v in 4 .. MAX_VALIDATOR_ACCOUNTS;
+        let v in 7 .. MAX_VALIDATOR_ACCOUNTS;
+        let c in 1 .. MAX_VOTES_WITHOUT_OUTCOME;
         let mut validators = setup_validators::<T, I>(v);
         let (sender, root_id,  signature, quorum) = setup_publish_root_voting::<T, I>(validators.clone());
         validators.remove(validators.len() - (1 as usize)); // Avoid setting up sender to reject vote automatically
 
         setup_roots::<T, I>(1, sender.account_id.clone(), root_id.ingress_counter);
+        setup_votes_without_outcome::<T, I>(&validators, c, quorum, &root_id, false);
     }: reject_root(RawOrigin::None, root_id.clone(), sender.clone(), signature)
     verify {
         assert_eq!(false, NextBlockToProcess::<T, I>::get() == root_id.range.to_block + 1u32.into());
-        assert_eq!(false, Roots::<T, I>::get(root_id.range, root_id.ingress_counter).is_validated);
+        assert_eq!(false, Roots::<T, I>::get(root_id.range, root_id.ingress_counter).expect("Root exists").is_validated);
         assert_eq!(false, SlotOfLastPublishedSummary::<T, I>::get() == CurrentSlot::<T, I>::get() + 1u32.into());
 
         assert_eq!(true, PendingApproval::<T, I>::contains_key(&root_id.range));
@@ -438,14 +537,15 @@ benchmarks_instance_pallet! {
     }: end_voting_period(RawOrigin::None, root_id.clone(), sender.clone(), signature)
     verify {
         assert_eq!(true, NextBlockToProcess::<T, I>::get() == root_id.range.to_block + 1u32.into());
-        assert_eq!(true, Roots::<T, I>::get(root_id.range, root_id.ingress_counter).is_validated);
+        assert_eq!(true, Roots::<T, I>::get(root_id.range, root_id.ingress_counter).expect("Root exists").is_validated);
         assert_eq!(true, SlotOfLastPublishedSummary::<T, I>::get() == CurrentSlot::<T, I>::get());
         assert_eq!(false, PendingApproval::<T, I>::contains_key(&root_id.range));
 
         let vote = VotesRepository::<T, I>::get(&root_id);
         assert_event_exists::<T, I>(Event::<T, I>::SummaryOffenceReported {
                 offence_type: SummaryOffenceType::RejectedValidRoot,
-                offenders: create_offenders_identification::<T, I>(&vote.nays)
+                offenders: create_offenders_identification::<T, I>(&vote.nays),
+                evidence: offence_evidence_of_type::<T, I>(SummaryOffenceType::RejectedValidRoot)
             }.into()
         );
 
@@ -476,14 +576,15 @@ benchmarks_instance_pallet! {
     }: end_voting_period(RawOrigin::None, root_id.clone(), sender.clone(), signature)
     verify {
         assert_eq!(false, NextBlockToProcess::<T, I>::get() == root_id.range.to_block + 1u32.into());
-        assert_eq!(false, Roots::<T, I>::get(root_id.range, root_id.ingress_counter).is_validated);
+        assert_eq!(false, Roots::<T, I>::get(root_id.range, root_id.ingress_counter).expect("Root exists").is_validated);
         assert_eq!(false, SlotOfLastPublishedSummary::<T, I>::get() == CurrentSlot::<T, I>::get());
         assert_eq!(false, PendingApproval::<T, I>::contains_key(&root_id.range));
 
         let vote = VotesRepository::<T, I>::get(&root_id);
         assert_event_exists::<T, I>(Event::<T, I>::SummaryOffenceReported {
                 offence_type: SummaryOffenceType::ApprovedInvalidRoot,
-                offenders: create_offenders_identification::<T, I>(&vote.ayes)
+                offenders: create_offenders_identification::<T, I>(&vote.ayes),
+                evidence: offence_evidence_of_type::<T, I>(SummaryOffenceType::ApprovedInvalidRoot)
             }.into()
         );
 
@@ -597,7 +698,8 @@ benchmarks_instance_pallet! {
 
         assert_event_exists::<T, I>(Event::<T, I>::SummaryOffenceReported {
                 offence_type: SummaryOffenceType::SlotNotAdvanced,
-                offenders: create_offenders_identification::<T, I>(&vec![validators[1].account_id.clone()])
+                offenders: create_offenders_identification::<T, I>(&vec![validators[1].account_id.clone()]),
+                evidence: offence_evidence_of_type::<T, I>(SummaryOffenceType::SlotNotAdvanced)
             }.into()
         );
 
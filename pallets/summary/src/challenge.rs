@@ -182,6 +182,7 @@ pub fn challenge_lock_name<T: Config<I>, I: 'static>(
     challenge: &SummaryChallenge<T::AccountId>,
 ) -> Vec<u8> {
     let mut name = b"challenge_summary::slot::".to_vec();
+    name.push(T::InstanceId::get());
     name.extend_from_slice(&mut challenge.encode());
     name
 }
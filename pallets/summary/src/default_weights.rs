@@ -40,14 +40,18 @@ pub trait WeightInfo {
 	fn set_periods() -> Weight;
 	fn record_summary_calculation(v: u32, r: u32, ) -> Weight;
 	fn approve_root_with_end_voting(v: u32, o: u32, ) -> Weight;
-	fn approve_root_without_end_voting(v: u32, ) -> Weight;
+	fn approve_root_without_end_voting(v: u32, c: u32, ) -> Weight;
 	fn reject_root_with_end_voting(v: u32, o: u32, ) -> Weight;
-	fn reject_root_without_end_voting(v: u32, ) -> Weight;
+	fn reject_root_without_end_voting(v: u32, c: u32, ) -> Weight;
 	fn end_voting_period_with_rejected_valid_votes(v: u32, o: u32, ) -> Weight;
 	fn end_voting_period_with_approved_invalid_votes(v: u32, o: u32, ) -> Weight;
 	fn advance_slot_with_offence(v: u32, ) -> Weight;
 	fn advance_slot_without_offence(v: u32, ) -> Weight;
 	fn add_challenge(v: u32, ) -> Weight;
+	fn cancel_admin_change() -> Weight;
+	fn force_skip_range() -> Weight;
+	fn set_adaptive_schedule_period() -> Weight;
+	fn cleanup(c: u32, ) -> Weight;
 }
 
 /// Weights for pallet_summary using the Substrate node and recommended hardware.
@@ -61,13 +65,15 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `Summary::NextSlotAtBlock` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
 	/// Storage: `Summary::SchedulePeriod` (r:0 w:1)
 	/// Proof: `Summary::SchedulePeriod` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Summary::AdaptiveSchedulePeriod` (r:1 w:0)
+	/// Proof: `Summary::AdaptiveSchedulePeriod` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
 	fn set_periods() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `149`
 		//  Estimated: `1489`
 		// Minimum execution time: 15_520_000 picoseconds.
 		Weight::from_parts(15_945_000, 1489)
-			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
 			.saturating_add(T::DbWeight::get().writes(3_u64))
 	}
 	/// Storage: `Summary::TotalIngresses` (r:1 w:1)
@@ -86,6 +92,10 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `Summary::VotesRepository` (`max_values`: None, `max_size`: Some(16498), added: 18973, mode: `MaxEncodedLen`)
 	/// Storage: `Summary::VotingPeriod` (r:1 w:0)
 	/// Proof: `Summary::VotingPeriod` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Summary::UnsignedSubmissions` (r:1 w:1)
+	/// Proof: `Summary::UnsignedSubmissions` (`max_values`: None, `max_size`: Some(77), added: 2552, mode: `MaxEncodedLen`)
+	/// Storage: `Avn::OcwTaskHints` (r:1 w:1)
+	/// Proof: `Avn::OcwTaskHints` (`max_values`: Some(1), `max_size`: Some(6338), added: 6833, mode: `MaxEncodedLen`)
 	/// The range of component `v` is `[3, 10]`.
 	/// The range of component `r` is `[1, 2]`.
 	fn record_summary_calculation(v: u32, r: u32, ) -> Weight {
@@ -98,8 +108,8 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(Weight::from_parts(389_410, 0).saturating_mul(v.into()))
 			// Standard Error: 79_199
 			.saturating_add(Weight::from_parts(726_378, 0).saturating_mul(r.into()))
-			.saturating_add(T::DbWeight::get().reads(8_u64))
-			.saturating_add(T::DbWeight::get().writes(4_u64))
+			.saturating_add(T::DbWeight::get().reads(10_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
 	}
 	/// Storage: `Summary::Roots` (r:1 w:1)
 	/// Proof: `Summary::Roots` (`max_values`: None, `max_size`: Some(120), added: 2595, mode: `MaxEncodedLen`)
@@ -125,6 +135,18 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `Summary::SlotOfLastPublishedSummary` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
 	/// Storage: `Summary::NextBlockToProcess` (r:0 w:1)
 	/// Proof: `Summary::NextBlockToProcess` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Summary::UnsignedSubmissions` (r:1 w:1)
+	/// Proof: `Summary::UnsignedSubmissions` (`max_values`: None, `max_size`: Some(77), added: 2552, mode: `MaxEncodedLen`)
+	/// Storage: `Summary::InconclusiveVotingRounds` (r:0 w:1)
+	/// Proof: `Summary::InconclusiveVotingRounds` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::Era` (r:1 w:0)
+	/// Proof: `ParachainStaking::Era` (`max_values`: Some(1), `max_size`: Some(12), added: 507, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::VotingParticipation` (r:1 w:1)
+	/// Proof: `ParachainStaking::VotingParticipation` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `Summary::OffenceEvidence` (r:0 w:2)
+	/// Proof: `Summary::OffenceEvidence` (`max_values`: None, `max_size`: Some(157), added: 2632, mode: `MaxEncodedLen`)
+	/// Storage: `Summary::RootRangeAnomalies` (r:0 w:1)
+	/// Proof: `Summary::RootRangeAnomalies` (`max_values`: None, `max_size`: Some(69), added: 2544, mode: `MaxEncodedLen`)
 	/// The range of component `v` is `[3, 10]`.
 	/// The range of component `o` is `[1, 2]`.
 	fn approve_root_with_end_voting(v: u32, o: u32, ) -> Weight {
@@ -137,9 +159,9 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(Weight::from_parts(578_208, 0).saturating_mul(v.into()))
 			// Standard Error: 169_625
 			.saturating_add(Weight::from_parts(14_813_125, 0).saturating_mul(o.into()))
-			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().reads(11_u64))
 			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(o.into())))
-			.saturating_add(T::DbWeight::get().writes(6_u64))
+			.saturating_add(T::DbWeight::get().writes(12_u64))
 			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(o.into())))
 			.saturating_add(Weight::from_parts(0, 2524).saturating_mul(o.into()))
 			.saturating_add(Weight::from_parts(0, 75).saturating_mul(v.into()))
@@ -152,17 +174,26 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `Summary::VotesRepository` (`max_values`: None, `max_size`: Some(16498), added: 18973, mode: `MaxEncodedLen`)
 	/// Storage: `Summary::PendingApproval` (r:1 w:0)
 	/// Proof: `Summary::PendingApproval` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
-	/// The range of component `v` is `[4, 10]`.
-	fn approve_root_without_end_voting(v: u32, ) -> Weight {
+	/// Storage: `Summary::UnsignedSubmissions` (r:1 w:1)
+	/// Proof: `Summary::UnsignedSubmissions` (`max_values`: None, `max_size`: Some(77), added: 2552, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::Era` (r:1 w:0)
+	/// Proof: `ParachainStaking::Era` (`max_values`: Some(1), `max_size`: Some(12), added: 507, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::VotingParticipation` (r:1 w:1)
+	/// Proof: `ParachainStaking::VotingParticipation` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// The range of component `v` is `[7, 10]`.
+	/// The range of component `c` is `[1, 4]`.
+	fn approve_root_without_end_voting(v: u32, c: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `597 + v * (64 ±0)`
+		//  Measured:  `565 + v * (64 ±0) + c * (32 ±0)`
 		//  Estimated: `19963`
-		// Minimum execution time: 55_697_000 picoseconds.
+		// Minimum execution time: 56_304_000 picoseconds.
 		Weight::from_parts(56_429_017, 19963)
 			// Standard Error: 6_680
 			.saturating_add(Weight::from_parts(109_007, 0).saturating_mul(v.into()))
-			.saturating_add(T::DbWeight::get().reads(4_u64))
-			.saturating_add(T::DbWeight::get().writes(1_u64))
+			// Standard Error: 17_512
+			.saturating_add(Weight::from_parts(214_839, 0).saturating_mul(c.into()))
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
 	}
 	/// Storage: `Avn::Validators` (r:1 w:0)
 	/// Proof: `Avn::Validators` (`max_values`: Some(1), `max_size`: Some(16386), added: 16881, mode: `MaxEncodedLen`)
@@ -182,6 +213,16 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `AvnOffenceHandler::ReportedOffenders` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
 	/// Storage: `AvnOffenceHandler::SlashingEnabled` (r:1 w:0)
 	/// Proof: `AvnOffenceHandler::SlashingEnabled` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `Summary::UnsignedSubmissions` (r:1 w:1)
+	/// Proof: `Summary::UnsignedSubmissions` (`max_values`: None, `max_size`: Some(77), added: 2552, mode: `MaxEncodedLen`)
+	/// Storage: `Summary::InconclusiveVotingRounds` (r:0 w:1)
+	/// Proof: `Summary::InconclusiveVotingRounds` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::Era` (r:1 w:0)
+	/// Proof: `ParachainStaking::Era` (`max_values`: Some(1), `max_size`: Some(12), added: 507, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::VotingParticipation` (r:1 w:1)
+	/// Proof: `ParachainStaking::VotingParticipation` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `Summary::OffenceEvidence` (r:0 w:2)
+	/// Proof: `Summary::OffenceEvidence` (`max_values`: None, `max_size`: Some(157), added: 2632, mode: `MaxEncodedLen`)
 	/// The range of component `v` is `[7, 10]`.
 	/// The range of component `o` is `[1, 2]`.
 	fn reject_root_with_end_voting(v: u32, o: u32, ) -> Weight {
@@ -194,9 +235,9 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(Weight::from_parts(469_118, 0).saturating_mul(v.into()))
 			// Standard Error: 109_547
 			.saturating_add(Weight::from_parts(13_772_009, 0).saturating_mul(o.into()))
-			.saturating_add(T::DbWeight::get().reads(9_u64))
+			.saturating_add(T::DbWeight::get().reads(12_u64))
 			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(o.into())))
-			.saturating_add(T::DbWeight::get().writes(5_u64))
+			.saturating_add(T::DbWeight::get().writes(10_u64))
 			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(o.into())))
 			.saturating_add(Weight::from_parts(0, 2524).saturating_mul(o.into()))
 			.saturating_add(Weight::from_parts(0, 67).saturating_mul(v.into()))
@@ -209,17 +250,26 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `Summary::Roots` (`max_values`: None, `max_size`: Some(120), added: 2595, mode: `MaxEncodedLen`)
 	/// Storage: `Summary::PendingApproval` (r:1 w:0)
 	/// Proof: `Summary::PendingApproval` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
-	/// The range of component `v` is `[4, 10]`.
-	fn reject_root_without_end_voting(v: u32, ) -> Weight {
+	/// Storage: `Summary::UnsignedSubmissions` (r:1 w:1)
+	/// Proof: `Summary::UnsignedSubmissions` (`max_values`: None, `max_size`: Some(77), added: 2552, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::Era` (r:1 w:0)
+	/// Proof: `ParachainStaking::Era` (`max_values`: Some(1), `max_size`: Some(12), added: 507, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::VotingParticipation` (r:1 w:1)
+	/// Proof: `ParachainStaking::VotingParticipation` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// The range of component `v` is `[7, 10]`.
+	/// The range of component `c` is `[1, 4]`.
+	fn reject_root_without_end_voting(v: u32, c: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `574 + v * (64 ±0)`
+		//  Measured:  `542 + v * (64 ±0) + c * (32 ±0)`
 		//  Estimated: `19963`
-		// Minimum execution time: 51_751_000 picoseconds.
+		// Minimum execution time: 52_418_000 picoseconds.
 		Weight::from_parts(52_098_347, 19963)
 			// Standard Error: 7_010
 			.saturating_add(Weight::from_parts(160_016, 0).saturating_mul(v.into()))
-			.saturating_add(T::DbWeight::get().reads(4_u64))
-			.saturating_add(T::DbWeight::get().writes(1_u64))
+			// Standard Error: 18_374
+			.saturating_add(Weight::from_parts(231_562, 0).saturating_mul(c.into()))
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
 	}
 	/// Storage: `Summary::VotesRepository` (r:1 w:0)
 	/// Proof: `Summary::VotesRepository` (`max_values`: None, `max_size`: Some(16498), added: 18973, mode: `MaxEncodedLen`)
@@ -245,6 +295,14 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `Summary::SlotOfLastPublishedSummary` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
 	/// Storage: `Summary::NextBlockToProcess` (r:0 w:1)
 	/// Proof: `Summary::NextBlockToProcess` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Summary::UnsignedSubmissions` (r:1 w:1)
+	/// Proof: `Summary::UnsignedSubmissions` (`max_values`: None, `max_size`: Some(77), added: 2552, mode: `MaxEncodedLen`)
+	/// Storage: `Summary::InconclusiveVotingRounds` (r:1 w:1)
+	/// Proof: `Summary::InconclusiveVotingRounds` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	/// Storage: `Summary::OffenceEvidence` (r:0 w:1)
+	/// Proof: `Summary::OffenceEvidence` (`max_values`: None, `max_size`: Some(157), added: 2632, mode: `MaxEncodedLen`)
+	/// Storage: `Summary::RootRangeAnomalies` (r:0 w:1)
+	/// Proof: `Summary::RootRangeAnomalies` (`max_values`: None, `max_size`: Some(69), added: 2544, mode: `MaxEncodedLen`)
 	/// The range of component `v` is `[7, 10]`.
 	/// The range of component `o` is `[1, 2]`.
 	fn end_voting_period_with_rejected_valid_votes(v: u32, o: u32, ) -> Weight {
@@ -257,9 +315,9 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(Weight::from_parts(48_878, 0).saturating_mul(v.into()))
 			// Standard Error: 91_053
 			.saturating_add(Weight::from_parts(13_549_651, 0).saturating_mul(o.into()))
-			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().reads(10_u64))
 			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(o.into())))
-			.saturating_add(T::DbWeight::get().writes(5_u64))
+			.saturating_add(T::DbWeight::get().writes(9_u64))
 			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(o.into())))
 			.saturating_add(Weight::from_parts(0, 2524).saturating_mul(o.into()))
 			.saturating_add(Weight::from_parts(0, 67).saturating_mul(v.into()))
@@ -282,6 +340,12 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `AvnOffenceHandler::ReportedOffenders` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
 	/// Storage: `AvnOffenceHandler::SlashingEnabled` (r:1 w:0)
 	/// Proof: `AvnOffenceHandler::SlashingEnabled` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `Summary::UnsignedSubmissions` (r:1 w:1)
+	/// Proof: `Summary::UnsignedSubmissions` (`max_values`: None, `max_size`: Some(77), added: 2552, mode: `MaxEncodedLen`)
+	/// Storage: `Summary::InconclusiveVotingRounds` (r:1 w:1)
+	/// Proof: `Summary::InconclusiveVotingRounds` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	/// Storage: `Summary::OffenceEvidence` (r:0 w:2)
+	/// Proof: `Summary::OffenceEvidence` (`max_values`: None, `max_size`: Some(157), added: 2632, mode: `MaxEncodedLen`)
 	/// The range of component `v` is `[7, 10]`.
 	/// The range of component `o` is `[1, 2]`.
 	fn end_voting_period_with_approved_invalid_votes(v: u32, o: u32, ) -> Weight {
@@ -294,9 +358,9 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(Weight::from_parts(79_624, 0).saturating_mul(v.into()))
 			// Standard Error: 98_473
 			.saturating_add(Weight::from_parts(12_182_153, 0).saturating_mul(o.into()))
-			.saturating_add(T::DbWeight::get().reads(9_u64))
+			.saturating_add(T::DbWeight::get().reads(11_u64))
 			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(o.into())))
-			.saturating_add(T::DbWeight::get().writes(4_u64))
+			.saturating_add(T::DbWeight::get().writes(8_u64))
 			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(o.into())))
 			.saturating_add(Weight::from_parts(0, 2524).saturating_mul(o.into()))
 			.saturating_add(Weight::from_parts(0, 67).saturating_mul(v.into()))
@@ -323,6 +387,14 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `AvnOffenceHandler::SlashingEnabled` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
 	/// Storage: `Summary::SchedulePeriod` (r:1 w:0)
 	/// Proof: `Summary::SchedulePeriod` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Summary::UnsignedSubmissions` (r:1 w:1)
+	/// Proof: `Summary::UnsignedSubmissions` (`max_values`: None, `max_size`: Some(77), added: 2552, mode: `MaxEncodedLen`)
+	/// Storage: `Summary::OffenceEvidence` (r:0 w:1)
+	/// Proof: `Summary::OffenceEvidence` (`max_values`: None, `max_size`: Some(157), added: 2632, mode: `MaxEncodedLen`)
+	/// Storage: `Summary::AdaptiveSchedulePeriod` (r:1 w:0)
+	/// Proof: `Summary::AdaptiveSchedulePeriod` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `Summary::CurrentSlotActivity` (r:1 w:1)
+	/// Proof: `Summary::CurrentSlotActivity` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
 	/// The range of component `v` is `[5, 10]`.
 	fn advance_slot_with_offence(v: u32, ) -> Weight {
 		// Proof Size summary in bytes:
@@ -332,8 +404,8 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		Weight::from_parts(83_678_131, 17871)
 			// Standard Error: 14_356
 			.saturating_add(Weight::from_parts(138_193, 0).saturating_mul(v.into()))
-			.saturating_add(T::DbWeight::get().reads(11_u64))
-			.saturating_add(T::DbWeight::get().writes(6_u64))
+			.saturating_add(T::DbWeight::get().reads(14_u64))
+			.saturating_add(T::DbWeight::get().writes(10_u64))
 			.saturating_add(Weight::from_parts(0, 64).saturating_mul(v.into()))
 	}
 	/// Storage: `Summary::NextSlotAtBlock` (r:1 w:1)
@@ -348,6 +420,12 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `Avn::Validators` (`max_values`: Some(1), `max_size`: Some(16386), added: 16881, mode: `MaxEncodedLen`)
 	/// Storage: `Summary::SchedulePeriod` (r:1 w:0)
 	/// Proof: `Summary::SchedulePeriod` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Summary::UnsignedSubmissions` (r:1 w:1)
+	/// Proof: `Summary::UnsignedSubmissions` (`max_values`: None, `max_size`: Some(77), added: 2552, mode: `MaxEncodedLen`)
+	/// Storage: `Summary::AdaptiveSchedulePeriod` (r:1 w:0)
+	/// Proof: `Summary::AdaptiveSchedulePeriod` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `Summary::CurrentSlotActivity` (r:1 w:1)
+	/// Proof: `Summary::CurrentSlotActivity` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
 	/// The range of component `v` is `[3, 10]`.
 	fn advance_slot_without_offence(v: u32, ) -> Weight {
 		// Proof Size summary in bytes:
@@ -357,8 +435,8 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		Weight::from_parts(34_410_416, 17871)
 			// Standard Error: 4_961
 			.saturating_add(Weight::from_parts(52_237, 0).saturating_mul(v.into()))
-			.saturating_add(T::DbWeight::get().reads(6_u64))
-			.saturating_add(T::DbWeight::get().writes(3_u64))
+			.saturating_add(T::DbWeight::get().reads(9_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
 	}
 	/// Storage: `Summary::CurrentSlot` (r:1 w:1)
 	/// Proof: `Summary::CurrentSlot` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
@@ -382,6 +460,14 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `Summary::SlotOfLastPublishedSummary` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
 	/// Storage: `Summary::SchedulePeriod` (r:1 w:0)
 	/// Proof: `Summary::SchedulePeriod` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Summary::UnsignedSubmissions` (r:1 w:1)
+	/// Proof: `Summary::UnsignedSubmissions` (`max_values`: None, `max_size`: Some(77), added: 2552, mode: `MaxEncodedLen`)
+	/// Storage: `Summary::OffenceEvidence` (r:0 w:1)
+	/// Proof: `Summary::OffenceEvidence` (`max_values`: None, `max_size`: Some(157), added: 2632, mode: `MaxEncodedLen`)
+	/// Storage: `Summary::AdaptiveSchedulePeriod` (r:1 w:0)
+	/// Proof: `Summary::AdaptiveSchedulePeriod` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `Summary::CurrentSlotActivity` (r:1 w:1)
+	/// Proof: `Summary::CurrentSlotActivity` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
 	/// The range of component `v` is `[3, 10]`.
 	fn add_challenge(v: u32, ) -> Weight {
 		// Proof Size summary in bytes:
@@ -391,10 +477,91 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		Weight::from_parts(95_227_821, 17871)
 			// Standard Error: 9_388
 			.saturating_add(Weight::from_parts(186_520, 0).saturating_mul(v.into()))
-			.saturating_add(T::DbWeight::get().reads(11_u64))
-			.saturating_add(T::DbWeight::get().writes(6_u64))
+			.saturating_add(T::DbWeight::get().reads(14_u64))
+			.saturating_add(T::DbWeight::get().writes(10_u64))
 			.saturating_add(Weight::from_parts(0, 64).saturating_mul(v.into()))
 	}
+	/// Storage: `Summary::PendingAdminChanges` (r:1 w:1)
+	/// Proof: `Summary::PendingAdminChanges` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::Lookup` (r:1 w:1)
+	/// Proof: `Scheduler::Lookup` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::Agenda` (r:1 w:1)
+	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(38963), added: 41438, mode: `MaxEncodedLen`)
+	fn cancel_admin_change() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `4242`
+		//  Estimated: `42428`
+		// Minimum execution time: 31_250_000 picoseconds.
+		Weight::from_parts(31_250_000, 42428)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: `Summary::NextBlockToProcess` (r:1 w:1)
+	/// Proof: `Summary::NextBlockToProcess` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Summary::PendingApproval` (r:1 w:1)
+	/// Proof: `Summary::PendingApproval` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	/// Storage: `Summary::VotesRepository` (r:0 w:1)
+	/// Proof: `Summary::VotesRepository` (`max_values`: None, `max_size`: Some(16120), added: 18595, mode: `MaxEncodedLen`)
+	/// Storage: `Summary::SkippedRootRanges` (r:0 w:1)
+	/// Proof: `Summary::SkippedRootRanges` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+	/// Storage: `Avn::NextEventSequence` (r:1 w:1)
+	/// Proof: `Avn::NextEventSequence` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `Summary::InconclusiveVotingRounds` (r:0 w:1)
+	/// Proof: `Summary::InconclusiveVotingRounds` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	fn force_skip_range() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `311`
+		//  Estimated: `3497`
+		// Minimum execution time: 24_106_000 picoseconds.
+		Weight::from_parts(24_870_000, 3497)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+	}
+	/// Storage: `Summary::VotingPeriod` (r:1 w:0)
+	/// Proof: `Summary::VotingPeriod` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Summary::CurrentSlotActivity` (r:0 w:1)
+	/// Proof: `Summary::CurrentSlotActivity` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `Summary::AdaptiveSchedulePeriod` (r:0 w:1)
+	/// Proof: `Summary::AdaptiveSchedulePeriod` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	fn set_adaptive_schedule_period() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `148`
+		//  Estimated: `1489`
+		// Minimum execution time: 9_000_000 picoseconds.
+		Weight::from_parts(9_000_000, 1489)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Summary::VotesRepository` (r:101 w:100)
+	/// Proof: `Summary::VotesRepository` (`max_values`: None, `max_size`: Some(16498), added: 18973, mode: `MaxEncodedLen`)
+	/// Storage: `Summary::PendingApproval` (r:100 w:0)
+	/// Proof: `Summary::PendingApproval` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	/// Storage: `Summary::InconclusiveVotingRounds` (r:0 w:100)
+	/// Proof: `Summary::InconclusiveVotingRounds` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	/// Storage: `Summary::TxIdToRoot` (r:1 w:0)
+	/// Proof: `Summary::TxIdToRoot` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `Summary::VotingSessionsCleanupCursor` (r:1 w:1)
+	/// Proof: `Summary::VotingSessionsCleanupCursor` (`max_values`: Some(1), `max_size`: Some(130), added: 625, mode: `MaxEncodedLen`)
+	/// Storage: `Summary::TxIdsCleanupCursor` (r:1 w:1)
+	/// Proof: `Summary::TxIdsCleanupCursor` (`max_values`: Some(1), `max_size`: Some(130), added: 625, mode: `MaxEncodedLen`)
+	/// Storage: `Summary::OffenceEvidence` (r:1 w:0)
+	/// Proof: `Summary::OffenceEvidence` (`max_values`: None, `max_size`: Some(157), added: 2632, mode: `MaxEncodedLen`)
+	/// Storage: `Summary::OffenceEvidenceCleanupCursor` (r:1 w:1)
+	/// Proof: `Summary::OffenceEvidenceCleanupCursor` (`max_values`: Some(1), `max_size`: Some(130), added: 625, mode: `MaxEncodedLen`)
+	/// The range of component `c` is `[1, 100]`.
+	fn cleanup(c: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `396`
+		//  Estimated: `3963`
+		// Minimum execution time: 11_000_000 picoseconds.
+		Weight::from_parts(11_000_000, 3963)
+			.saturating_add(Weight::from_parts(9_400_000, 0).saturating_mul(c.into()))
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(c.into())))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(c.into())))
+			.saturating_add(Weight::from_parts(0, 18973).saturating_mul(c.into()))
+	}
 }
 
 // For backwards compatibility and tests.
@@ -407,13 +574,15 @@ impl WeightInfo for () {
 	/// Proof: `Summary::NextSlotAtBlock` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
 	/// Storage: `Summary::SchedulePeriod` (r:0 w:1)
 	/// Proof: `Summary::SchedulePeriod` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Summary::AdaptiveSchedulePeriod` (r:1 w:0)
+	/// Proof: `Summary::AdaptiveSchedulePeriod` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
 	fn set_periods() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `149`
 		//  Estimated: `1489`
 		// Minimum execution time: 15_520_000 picoseconds.
 		Weight::from_parts(15_945_000, 1489)
-			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
 			.saturating_add(RocksDbWeight::get().writes(3_u64))
 	}
 	/// Storage: `Summary::TotalIngresses` (r:1 w:1)
@@ -432,6 +601,10 @@ impl WeightInfo for () {
 	/// Proof: `Summary::VotesRepository` (`max_values`: None, `max_size`: Some(16498), added: 18973, mode: `MaxEncodedLen`)
 	/// Storage: `Summary::VotingPeriod` (r:1 w:0)
 	/// Proof: `Summary::VotingPeriod` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Summary::UnsignedSubmissions` (r:1 w:1)
+	/// Proof: `Summary::UnsignedSubmissions` (`max_values`: None, `max_size`: Some(77), added: 2552, mode: `MaxEncodedLen`)
+	/// Storage: `Avn::OcwTaskHints` (r:1 w:1)
+	/// Proof: `Avn::OcwTaskHints` (`max_values`: Some(1), `max_size`: Some(6338), added: 6833, mode: `MaxEncodedLen`)
 	/// The range of component `v` is `[3, 10]`.
 	/// The range of component `r` is `[1, 2]`.
 	fn record_summary_calculation(v: u32, r: u32, ) -> Weight {
@@ -444,8 +617,8 @@ impl WeightInfo for () {
 			.saturating_add(Weight::from_parts(389_410, 0).saturating_mul(v.into()))
 			// Standard Error: 79_199
 			.saturating_add(Weight::from_parts(726_378, 0).saturating_mul(r.into()))
-			.saturating_add(RocksDbWeight::get().reads(8_u64))
-			.saturating_add(RocksDbWeight::get().writes(4_u64))
+			.saturating_add(RocksDbWeight::get().reads(10_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
 	}
 	/// Storage: `Summary::Roots` (r:1 w:1)
 	/// Proof: `Summary::Roots` (`max_values`: None, `max_size`: Some(120), added: 2595, mode: `MaxEncodedLen`)
@@ -471,6 +644,18 @@ impl WeightInfo for () {
 	/// Proof: `Summary::SlotOfLastPublishedSummary` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
 	/// Storage: `Summary::NextBlockToProcess` (r:0 w:1)
 	/// Proof: `Summary::NextBlockToProcess` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Summary::UnsignedSubmissions` (r:1 w:1)
+	/// Proof: `Summary::UnsignedSubmissions` (`max_values`: None, `max_size`: Some(77), added: 2552, mode: `MaxEncodedLen`)
+	/// Storage: `Summary::InconclusiveVotingRounds` (r:0 w:1)
+	/// Proof: `Summary::InconclusiveVotingRounds` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::Era` (r:1 w:0)
+	/// Proof: `ParachainStaking::Era` (`max_values`: Some(1), `max_size`: Some(12), added: 507, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::VotingParticipation` (r:1 w:1)
+	/// Proof: `ParachainStaking::VotingParticipation` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `Summary::OffenceEvidence` (r:0 w:2)
+	/// Proof: `Summary::OffenceEvidence` (`max_values`: None, `max_size`: Some(157), added: 2632, mode: `MaxEncodedLen`)
+	/// Storage: `Summary::RootRangeAnomalies` (r:0 w:1)
+	/// Proof: `Summary::RootRangeAnomalies` (`max_values`: None, `max_size`: Some(69), added: 2544, mode: `MaxEncodedLen`)
 	/// The range of component `v` is `[3, 10]`.
 	/// The range of component `o` is `[1, 2]`.
 	fn approve_root_with_end_voting(v: u32, o: u32, ) -> Weight {
@@ -483,9 +668,9 @@ impl WeightInfo for () {
 			.saturating_add(Weight::from_parts(578_208, 0).saturating_mul(v.into()))
 			// Standard Error: 169_625
 			.saturating_add(Weight::from_parts(14_813_125, 0).saturating_mul(o.into()))
-			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().reads(11_u64))
 			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(o.into())))
-			.saturating_add(RocksDbWeight::get().writes(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(12_u64))
 			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(o.into())))
 			.saturating_add(Weight::from_parts(0, 2524).saturating_mul(o.into()))
 			.saturating_add(Weight::from_parts(0, 75).saturating_mul(v.into()))
@@ -498,17 +683,26 @@ impl WeightInfo for () {
 	/// Proof: `Summary::VotesRepository` (`max_values`: None, `max_size`: Some(16498), added: 18973, mode: `MaxEncodedLen`)
 	/// Storage: `Summary::PendingApproval` (r:1 w:0)
 	/// Proof: `Summary::PendingApproval` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
-	/// The range of component `v` is `[4, 10]`.
-	fn approve_root_without_end_voting(v: u32, ) -> Weight {
+	/// Storage: `Summary::UnsignedSubmissions` (r:1 w:1)
+	/// Proof: `Summary::UnsignedSubmissions` (`max_values`: None, `max_size`: Some(77), added: 2552, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::Era` (r:1 w:0)
+	/// Proof: `ParachainStaking::Era` (`max_values`: Some(1), `max_size`: Some(12), added: 507, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::VotingParticipation` (r:1 w:1)
+	/// Proof: `ParachainStaking::VotingParticipation` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// The range of component `v` is `[7, 10]`.
+	/// The range of component `c` is `[1, 4]`.
+	fn approve_root_without_end_voting(v: u32, c: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `597 + v * (64 ±0)`
+		//  Measured:  `565 + v * (64 ±0) + c * (32 ±0)`
 		//  Estimated: `19963`
-		// Minimum execution time: 55_697_000 picoseconds.
+		// Minimum execution time: 56_304_000 picoseconds.
 		Weight::from_parts(56_429_017, 19963)
 			// Standard Error: 6_680
 			.saturating_add(Weight::from_parts(109_007, 0).saturating_mul(v.into()))
-			.saturating_add(RocksDbWeight::get().reads(4_u64))
-			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			// Standard Error: 17_512
+			.saturating_add(Weight::from_parts(214_839, 0).saturating_mul(c.into()))
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
 	}
 	/// Storage: `Avn::Validators` (r:1 w:0)
 	/// Proof: `Avn::Validators` (`max_values`: Some(1), `max_size`: Some(16386), added: 16881, mode: `MaxEncodedLen`)
@@ -528,6 +722,16 @@ impl WeightInfo for () {
 	/// Proof: `AvnOffenceHandler::ReportedOffenders` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
 	/// Storage: `AvnOffenceHandler::SlashingEnabled` (r:1 w:0)
 	/// Proof: `AvnOffenceHandler::SlashingEnabled` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `Summary::UnsignedSubmissions` (r:1 w:1)
+	/// Proof: `Summary::UnsignedSubmissions` (`max_values`: None, `max_size`: Some(77), added: 2552, mode: `MaxEncodedLen`)
+	/// Storage: `Summary::InconclusiveVotingRounds` (r:0 w:1)
+	/// Proof: `Summary::InconclusiveVotingRounds` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::Era` (r:1 w:0)
+	/// Proof: `ParachainStaking::Era` (`max_values`: Some(1), `max_size`: Some(12), added: 507, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::VotingParticipation` (r:1 w:1)
+	/// Proof: `ParachainStaking::VotingParticipation` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `Summary::OffenceEvidence` (r:0 w:2)
+	/// Proof: `Summary::OffenceEvidence` (`max_values`: None, `max_size`: Some(157), added: 2632, mode: `MaxEncodedLen`)
 	/// The range of component `v` is `[7, 10]`.
 	/// The range of component `o` is `[1, 2]`.
 	fn reject_root_with_end_voting(v: u32, o: u32, ) -> Weight {
@@ -540,9 +744,9 @@ impl WeightInfo for () {
 			.saturating_add(Weight::from_parts(469_118, 0).saturating_mul(v.into()))
 			// Standard Error: 109_547
 			.saturating_add(Weight::from_parts(13_772_009, 0).saturating_mul(o.into()))
-			.saturating_add(RocksDbWeight::get().reads(9_u64))
+			.saturating_add(RocksDbWeight::get().reads(12_u64))
 			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(o.into())))
-			.saturating_add(RocksDbWeight::get().writes(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(10_u64))
 			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(o.into())))
 			.saturating_add(Weight::from_parts(0, 2524).saturating_mul(o.into()))
 			.saturating_add(Weight::from_parts(0, 67).saturating_mul(v.into()))
@@ -555,17 +759,26 @@ impl WeightInfo for () {
 	/// Proof: `Summary::Roots` (`max_values`: None, `max_size`: Some(120), added: 2595, mode: `MaxEncodedLen`)
 	/// Storage: `Summary::PendingApproval` (r:1 w:0)
 	/// Proof: `Summary::PendingApproval` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
-	/// The range of component `v` is `[4, 10]`.
-	fn reject_root_without_end_voting(v: u32, ) -> Weight {
+	/// Storage: `Summary::UnsignedSubmissions` (r:1 w:1)
+	/// Proof: `Summary::UnsignedSubmissions` (`max_values`: None, `max_size`: Some(77), added: 2552, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::Era` (r:1 w:0)
+	/// Proof: `ParachainStaking::Era` (`max_values`: Some(1), `max_size`: Some(12), added: 507, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::VotingParticipation` (r:1 w:1)
+	/// Proof: `ParachainStaking::VotingParticipation` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// The range of component `v` is `[7, 10]`.
+	/// The range of component `c` is `[1, 4]`.
+	fn reject_root_without_end_voting(v: u32, c: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `574 + v * (64 ±0)`
+		//  Measured:  `542 + v * (64 ±0) + c * (32 ±0)`
 		//  Estimated: `19963`
-		// Minimum execution time: 51_751_000 picoseconds.
+		// Minimum execution time: 52_418_000 picoseconds.
 		Weight::from_parts(52_098_347, 19963)
 			// Standard Error: 7_010
 			.saturating_add(Weight::from_parts(160_016, 0).saturating_mul(v.into()))
-			.saturating_add(RocksDbWeight::get().reads(4_u64))
-			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			// Standard Error: 18_374
+			.saturating_add(Weight::from_parts(231_562, 0).saturating_mul(c.into()))
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
 	}
 	/// Storage: `Summary::VotesRepository` (r:1 w:0)
 	/// Proof: `Summary::VotesRepository` (`max_values`: None, `max_size`: Some(16498), added: 18973, mode: `MaxEncodedLen`)
@@ -591,6 +804,14 @@ impl WeightInfo for () {
 	/// Proof: `Summary::SlotOfLastPublishedSummary` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
 	/// Storage: `Summary::NextBlockToProcess` (r:0 w:1)
 	/// Proof: `Summary::NextBlockToProcess` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Summary::UnsignedSubmissions` (r:1 w:1)
+	/// Proof: `Summary::UnsignedSubmissions` (`max_values`: None, `max_size`: Some(77), added: 2552, mode: `MaxEncodedLen`)
+	/// Storage: `Summary::InconclusiveVotingRounds` (r:1 w:1)
+	/// Proof: `Summary::InconclusiveVotingRounds` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	/// Storage: `Summary::OffenceEvidence` (r:0 w:1)
+	/// Proof: `Summary::OffenceEvidence` (`max_values`: None, `max_size`: Some(157), added: 2632, mode: `MaxEncodedLen`)
+	/// Storage: `Summary::RootRangeAnomalies` (r:0 w:1)
+	/// Proof: `Summary::RootRangeAnomalies` (`max_values`: None, `max_size`: Some(69), added: 2544, mode: `MaxEncodedLen`)
 	/// The range of component `v` is `[7, 10]`.
 	/// The range of component `o` is `[1, 2]`.
 	fn end_voting_period_with_rejected_valid_votes(v: u32, o: u32, ) -> Weight {
@@ -603,9 +824,9 @@ impl WeightInfo for () {
 			.saturating_add(Weight::from_parts(48_878, 0).saturating_mul(v.into()))
 			// Standard Error: 91_053
 			.saturating_add(Weight::from_parts(13_549_651, 0).saturating_mul(o.into()))
-			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().reads(10_u64))
 			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(o.into())))
-			.saturating_add(RocksDbWeight::get().writes(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(9_u64))
 			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(o.into())))
 			.saturating_add(Weight::from_parts(0, 2524).saturating_mul(o.into()))
 			.saturating_add(Weight::from_parts(0, 67).saturating_mul(v.into()))
@@ -628,6 +849,12 @@ impl WeightInfo for () {
 	/// Proof: `AvnOffenceHandler::ReportedOffenders` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
 	/// Storage: `AvnOffenceHandler::SlashingEnabled` (r:1 w:0)
 	/// Proof: `AvnOffenceHandler::SlashingEnabled` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `Summary::UnsignedSubmissions` (r:1 w:1)
+	/// Proof: `Summary::UnsignedSubmissions` (`max_values`: None, `max_size`: Some(77), added: 2552, mode: `MaxEncodedLen`)
+	/// Storage: `Summary::InconclusiveVotingRounds` (r:1 w:1)
+	/// Proof: `Summary::InconclusiveVotingRounds` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	/// Storage: `Summary::OffenceEvidence` (r:0 w:2)
+	/// Proof: `Summary::OffenceEvidence` (`max_values`: None, `max_size`: Some(157), added: 2632, mode: `MaxEncodedLen`)
 	/// The range of component `v` is `[7, 10]`.
 	/// The range of component `o` is `[1, 2]`.
 	fn end_voting_period_with_approved_invalid_votes(v: u32, o: u32, ) -> Weight {
@@ -640,9 +867,9 @@ impl WeightInfo for () {
 			.saturating_add(Weight::from_parts(79_624, 0).saturating_mul(v.into()))
 			// Standard Error: 98_473
 			.saturating_add(Weight::from_parts(12_182_153, 0).saturating_mul(o.into()))
-			.saturating_add(RocksDbWeight::get().reads(9_u64))
+			.saturating_add(RocksDbWeight::get().reads(11_u64))
 			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(o.into())))
-			.saturating_add(RocksDbWeight::get().writes(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(8_u64))
 			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(o.into())))
 			.saturating_add(Weight::from_parts(0, 2524).saturating_mul(o.into()))
 			.saturating_add(Weight::from_parts(0, 67).saturating_mul(v.into()))
@@ -669,6 +896,14 @@ impl WeightInfo for () {
 	/// Proof: `AvnOffenceHandler::SlashingEnabled` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
 	/// Storage: `Summary::SchedulePeriod` (r:1 w:0)
 	/// Proof: `Summary::SchedulePeriod` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Summary::UnsignedSubmissions` (r:1 w:1)
+	/// Proof: `Summary::UnsignedSubmissions` (`max_values`: None, `max_size`: Some(77), added: 2552, mode: `MaxEncodedLen`)
+	/// Storage: `Summary::OffenceEvidence` (r:0 w:1)
+	/// Proof: `Summary::OffenceEvidence` (`max_values`: None, `max_size`: Some(157), added: 2632, mode: `MaxEncodedLen`)
+	/// Storage: `Summary::AdaptiveSchedulePeriod` (r:1 w:0)
+	/// Proof: `Summary::AdaptiveSchedulePeriod` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `Summary::CurrentSlotActivity` (r:1 w:1)
+	/// Proof: `Summary::CurrentSlotActivity` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
 	/// The range of component `v` is `[5, 10]`.
 	fn advance_slot_with_offence(v: u32, ) -> Weight {
 		// Proof Size summary in bytes:
@@ -678,8 +913,8 @@ impl WeightInfo for () {
 		Weight::from_parts(83_678_131, 17871)
 			// Standard Error: 14_356
 			.saturating_add(Weight::from_parts(138_193, 0).saturating_mul(v.into()))
-			.saturating_add(RocksDbWeight::get().reads(11_u64))
-			.saturating_add(RocksDbWeight::get().writes(6_u64))
+			.saturating_add(RocksDbWeight::get().reads(14_u64))
+			.saturating_add(RocksDbWeight::get().writes(10_u64))
 			.saturating_add(Weight::from_parts(0, 64).saturating_mul(v.into()))
 	}
 	/// Storage: `Summary::NextSlotAtBlock` (r:1 w:1)
@@ -694,6 +929,12 @@ impl WeightInfo for () {
 	/// Proof: `Avn::Validators` (`max_values`: Some(1), `max_size`: Some(16386), added: 16881, mode: `MaxEncodedLen`)
 	/// Storage: `Summary::SchedulePeriod` (r:1 w:0)
 	/// Proof: `Summary::SchedulePeriod` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Summary::UnsignedSubmissions` (r:1 w:1)
+	/// Proof: `Summary::UnsignedSubmissions` (`max_values`: None, `max_size`: Some(77), added: 2552, mode: `MaxEncodedLen`)
+	/// Storage: `Summary::AdaptiveSchedulePeriod` (r:1 w:0)
+	/// Proof: `Summary::AdaptiveSchedulePeriod` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `Summary::CurrentSlotActivity` (r:1 w:1)
+	/// Proof: `Summary::CurrentSlotActivity` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
 	/// The range of component `v` is `[3, 10]`.
 	fn advance_slot_without_offence(v: u32, ) -> Weight {
 		// Proof Size summary in bytes:
@@ -703,8 +944,8 @@ impl WeightInfo for () {
 		Weight::from_parts(34_410_416, 17871)
 			// Standard Error: 4_961
 			.saturating_add(Weight::from_parts(52_237, 0).saturating_mul(v.into()))
-			.saturating_add(RocksDbWeight::get().reads(6_u64))
-			.saturating_add(RocksDbWeight::get().writes(3_u64))
+			.saturating_add(RocksDbWeight::get().reads(9_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
 	}
 	/// Storage: `Summary::CurrentSlot` (r:1 w:1)
 	/// Proof: `Summary::CurrentSlot` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
@@ -728,6 +969,14 @@ impl WeightInfo for () {
 	/// Proof: `Summary::SlotOfLastPublishedSummary` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
 	/// Storage: `Summary::SchedulePeriod` (r:1 w:0)
 	/// Proof: `Summary::SchedulePeriod` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Summary::UnsignedSubmissions` (r:1 w:1)
+	/// Proof: `Summary::UnsignedSubmissions` (`max_values`: None, `max_size`: Some(77), added: 2552, mode: `MaxEncodedLen`)
+	/// Storage: `Summary::OffenceEvidence` (r:0 w:1)
+	/// Proof: `Summary::OffenceEvidence` (`max_values`: None, `max_size`: Some(157), added: 2632, mode: `MaxEncodedLen`)
+	/// Storage: `Summary::AdaptiveSchedulePeriod` (r:1 w:0)
+	/// Proof: `Summary::AdaptiveSchedulePeriod` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `Summary::CurrentSlotActivity` (r:1 w:1)
+	/// Proof: `Summary::CurrentSlotActivity` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
 	/// The range of component `v` is `[3, 10]`.
 	fn add_challenge(v: u32, ) -> Weight {
 		// Proof Size summary in bytes:
@@ -737,8 +986,89 @@ impl WeightInfo for () {
 		Weight::from_parts(95_227_821, 17871)
 			// Standard Error: 9_388
 			.saturating_add(Weight::from_parts(186_520, 0).saturating_mul(v.into()))
-			.saturating_add(RocksDbWeight::get().reads(11_u64))
-			.saturating_add(RocksDbWeight::get().writes(6_u64))
+			.saturating_add(RocksDbWeight::get().reads(14_u64))
+			.saturating_add(RocksDbWeight::get().writes(10_u64))
 			.saturating_add(Weight::from_parts(0, 64).saturating_mul(v.into()))
 	}
+	/// Storage: `Summary::PendingAdminChanges` (r:1 w:1)
+	/// Proof: `Summary::PendingAdminChanges` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::Lookup` (r:1 w:1)
+	/// Proof: `Scheduler::Lookup` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::Agenda` (r:1 w:1)
+	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(38963), added: 41438, mode: `MaxEncodedLen`)
+	fn cancel_admin_change() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `4242`
+		//  Estimated: `42428`
+		// Minimum execution time: 31_250_000 picoseconds.
+		Weight::from_parts(31_250_000, 42428)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: `Summary::NextBlockToProcess` (r:1 w:1)
+	/// Proof: `Summary::NextBlockToProcess` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Summary::PendingApproval` (r:1 w:1)
+	/// Proof: `Summary::PendingApproval` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	/// Storage: `Summary::VotesRepository` (r:0 w:1)
+	/// Proof: `Summary::VotesRepository` (`max_values`: None, `max_size`: Some(16120), added: 18595, mode: `MaxEncodedLen`)
+	/// Storage: `Summary::SkippedRootRanges` (r:0 w:1)
+	/// Proof: `Summary::SkippedRootRanges` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+	/// Storage: `Avn::NextEventSequence` (r:1 w:1)
+	/// Proof: `Avn::NextEventSequence` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `Summary::InconclusiveVotingRounds` (r:0 w:1)
+	/// Proof: `Summary::InconclusiveVotingRounds` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	fn force_skip_range() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `311`
+		//  Estimated: `3497`
+		// Minimum execution time: 24_106_000 picoseconds.
+		Weight::from_parts(24_870_000, 3497)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+	}
+	/// Storage: `Summary::VotingPeriod` (r:1 w:0)
+	/// Proof: `Summary::VotingPeriod` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Summary::CurrentSlotActivity` (r:0 w:1)
+	/// Proof: `Summary::CurrentSlotActivity` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `Summary::AdaptiveSchedulePeriod` (r:0 w:1)
+	/// Proof: `Summary::AdaptiveSchedulePeriod` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	fn set_adaptive_schedule_period() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `148`
+		//  Estimated: `1489`
+		// Minimum execution time: 9_000_000 picoseconds.
+		Weight::from_parts(9_000_000, 1489)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Summary::VotesRepository` (r:101 w:100)
+	/// Proof: `Summary::VotesRepository` (`max_values`: None, `max_size`: Some(16498), added: 18973, mode: `MaxEncodedLen`)
+	/// Storage: `Summary::PendingApproval` (r:100 w:0)
+	/// Proof: `Summary::PendingApproval` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	/// Storage: `Summary::InconclusiveVotingRounds` (r:0 w:100)
+	/// Proof: `Summary::InconclusiveVotingRounds` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	/// Storage: `Summary::TxIdToRoot` (r:1 w:0)
+	/// Proof: `Summary::TxIdToRoot` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `Summary::VotingSessionsCleanupCursor` (r:1 w:1)
+	/// Proof: `Summary::VotingSessionsCleanupCursor` (`max_values`: Some(1), `max_size`: Some(130), added: 625, mode: `MaxEncodedLen`)
+	/// Storage: `Summary::TxIdsCleanupCursor` (r:1 w:1)
+	/// Proof: `Summary::TxIdsCleanupCursor` (`max_values`: Some(1), `max_size`: Some(130), added: 625, mode: `MaxEncodedLen`)
+	/// Storage: `Summary::OffenceEvidence` (r:1 w:0)
+	/// Proof: `Summary::OffenceEvidence` (`max_values`: None, `max_size`: Some(157), added: 2632, mode: `MaxEncodedLen`)
+	/// Storage: `Summary::OffenceEvidenceCleanupCursor` (r:1 w:1)
+	/// Proof: `Summary::OffenceEvidenceCleanupCursor` (`max_values`: Some(1), `max_size`: Some(130), added: 625, mode: `MaxEncodedLen`)
+	/// The range of component `c` is `[1, 100]`.
+	fn cleanup(c: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `396`
+		//  Estimated: `3963`
+		// Minimum execution time: 11_000_000 picoseconds.
+		Weight::from_parts(11_000_000, 3963)
+			.saturating_add(Weight::from_parts(9_400_000, 0).saturating_mul(c.into()))
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(c.into())))
+			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(c.into())))
+			.saturating_add(Weight::from_parts(0, 18973).saturating_mul(c.into()))
+	}
 }
\ No newline at end of file
@@ -10,37 +10,48 @@ use sp_avn_common::{
     bounds::VotingSessionIdBound,
     event_types::Validator,
     ocw_lock::{self as OcwLock},
+    ocw_task_queue::OcwTaskPriority,
     safe_add_block_numbers, safe_sub_block_numbers, BridgeContractMethod, IngressCounter,
 };
 use sp_runtime::{
     scale_info::TypeInfo,
-    traits::AtLeast32Bit,
+    traits::{AtLeast32Bit, One, Saturating, UniqueSaturatedInto},
     transaction_validity::{
         InvalidTransaction, TransactionPriority, TransactionSource, TransactionValidity,
         ValidTransaction,
     },
-    BoundedVec, DispatchError,
+    BoundedVec, DispatchError, Perbill,
 };
 use sp_std::prelude::*;
 
 use avn::BridgeInterfaceNotification;
 use core::convert::TryInto;
 use frame_support::{
-    dispatch::DispatchResult, ensure, pallet_prelude::StorageVersion, traits::Get,
+    dispatch::{DispatchResult, DispatchResultWithPostInfo, Pays, PostDispatchInfo},
+    ensure,
+    pallet_prelude::StorageVersion,
+    traits::{
+        schedule::{
+            v3::{Named as ScheduleNamed, TaskName},
+            DispatchTime, HARD_DEADLINE,
+        },
+        Get, QueryPreimage, StorePreimage,
+    },
 };
 use frame_system::{
-    self as system, ensure_none, ensure_root,
+    self as system, ensure_none,
     offchain::{SendTransactionTypes, SubmitTransaction},
 };
 pub use pallet::*;
 use pallet_avn::{
     self as avn,
+    unsigned_rate_limit::{prune_expired_windows, SubmissionWindow, UnsignedRateLimit},
     vote::{
         approve_vote_validate_unsigned, end_voting_period_validate_unsigned, process_approve_vote,
-        process_reject_vote, reject_vote_validate_unsigned, VotingSessionData,
-        VotingSessionManager,
+        process_reject_vote, process_replace_vote, reject_vote_validate_unsigned,
+        replace_vote_validate_unsigned, VotingSessionData, VotingSessionManager,
     },
-    Error as avn_error, MAX_VALIDATOR_ACCOUNTS,
+    Error as avn_error, VotingParticipationHandler, MAX_VALIDATOR_ACCOUNTS,
 };
 use pallet_session::historical::IdentificationTuple;
 use sp_application_crypto::RuntimeAppPublic;
@@ -48,7 +59,10 @@ use sp_core::H256;
 use sp_staking::offence::ReportOffence;
 
 pub mod offence;
-use crate::offence::{create_and_report_summary_offence, SummaryOffence, SummaryOffenceType};
+use crate::offence::{
+    create_and_report_summary_offence, SummaryOffence, SummaryOffenceEvidence,
+    SummaryOffenceSubject, SummaryOffenceType,
+};
 
 pub type EthereumTransactionId = u32;
 
@@ -59,18 +73,29 @@ const ADVANCE_SLOT_CONTEXT: &'static [u8] = b"advance_slot";
 // Error codes returned by validate unsigned methods
 const ERROR_CODE_VALIDATOR_IS_NOT_PRIMARY: u8 = 10;
 const ERROR_CODE_INVALID_ROOT_RANGE: u8 = 30;
+const ERROR_CODE_UNSIGNED_RATE_LIMIT_REACHED: u8 = 40;
 
-const MIN_SCHEDULE_PERIOD: u32 = 120; // 6 MINUTES
 const DEFAULT_SCHEDULE_PERIOD: u32 = 28800; // 1 DAY
-const MIN_VOTING_PERIOD: u32 = 100; // 5 MINUTES
-const MAX_VOTING_PERIOD: u32 = 28800; // 1 DAY
 const DEFAULT_VOTING_PERIOD: u32 = 600; // 30 MINUTES
 
-const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+const STORAGE_VERSION: StorageVersion = StorageVersion::new(5);
 
 // used in benchmarks and weights calculation only
 const MAX_OFFENDERS: u32 = 2; // maximum of offenders need to be less one third of minimum validators so the benchmark won't panic
 const MAX_NUMBER_OF_ROOT_DATA_PER_RANGE: u32 = 2;
+// Voting sessions that can be reopened for a root when they end without reaching a quorum, before
+// the root is judged on the votes it received
+const MAX_INCONCLUSIVE_VOTING_ROUNDS: u32 = 2;
+// Storage entries that a single call to `cleanup` can check
+const MAX_CLEANUP_LIMIT: u32 = 100;
+// A block is busy when its weight or its events reach these values
+const BUSY_BLOCK_FULLNESS: Perbill = Perbill::from_percent(50);
+const BUSY_BLOCK_EVENTS: u32 = 100;
+// Share of busy blocks in a slot above which the adaptive schedule period shortens, and below
+// which it lengthens, by SCHEDULE_PERIOD_ADJUSTMENT of its value
+const HIGH_SLOT_ACTIVITY: Perbill = Perbill::from_percent(50);
+const LOW_SLOT_ACTIVITY: Perbill = Perbill::from_percent(10);
+const SCHEDULE_PERIOD_ADJUSTMENT: Perbill = Perbill::from_percent(25);
 
 pub mod vote;
 use crate::vote::*;
@@ -78,10 +103,14 @@ use crate::vote::*;
 pub mod challenge;
 use crate::challenge::*;
 
+#[cfg(any(test, feature = "try-runtime"))]
+mod try_state;
+
 use pallet_avn::BridgeInterface;
 
 mod benchmarking;
 pub mod default_weights;
+pub mod migration;
 pub use default_weights::WeightInfo;
 
 pub type AVN<T> = avn::Pallet<T>;
@@ -133,6 +162,71 @@ pub mod pallet {
         type AutoSubmitSummaries: Get<bool>;
         /// A unique instance id to differentiate different instances
         type InstanceId: Get<u8>;
+        /// The origin allowed to change the summary periods
+        #[pallet::no_default]
+        type AdminOrigin: EnsureOrigin<<Self as frame_system::Config>::RuntimeOrigin>;
+        /// The overarching call type, used to schedule the changes announced by the admin origin
+        #[pallet::no_default]
+        type RuntimeCall: Parameter + From<Call<Self, I>>;
+        /// The scheduler executing the changes announced by the admin origin
+        #[pallet::no_default]
+        type Scheduler: ScheduleNamed<
+            BlockNumberFor<Self>,
+            <Self as Config<I>>::RuntimeCall,
+            Self::PalletsOrigin,
+        >;
+        /// The preimage provider used to store the announced changes
+        #[pallet::no_default]
+        type Preimages: QueryPreimage + StorePreimage;
+        /// Overarching type of all pallets origins
+        #[pallet::no_default]
+        type PalletsOrigin: From<frame_system::RawOrigin<Self::AccountId>>;
+        /// Number of blocks between the announcement of a change of the periods and its execution
+        #[pallet::constant]
+        #[pallet::no_default]
+        type AdminChangeDelay: Get<BlockNumberFor<Self>>;
+        /// Shortest schedule period (in blocks) the instance accepts
+        #[pallet::constant]
+        #[pallet::no_default]
+        type MinSchedulePeriod: Get<BlockNumberFor<Self>>;
+        /// Longest schedule period (in blocks) the instance accepts
+        #[pallet::constant]
+        #[pallet::no_default]
+        type MaxSchedulePeriod: Get<BlockNumberFor<Self>>;
+        /// Shortest voting period (in blocks) the instance accepts. It must be shorter than
+        /// `MaxSchedulePeriod`.
+        #[pallet::constant]
+        #[pallet::no_default]
+        type MinVotingPeriod: Get<BlockNumberFor<Self>>;
+        /// Longest voting period (in blocks) the instance accepts
+        #[pallet::constant]
+        #[pallet::no_default]
+        type MaxVotingPeriod: Get<BlockNumberFor<Self>>;
+        /// Number of blocks the evidence of a reported offence is kept before `cleanup` removes
+        /// it
+        #[pallet::constant]
+        #[pallet::no_default]
+        type OffenceEvidenceRetention: Get<BlockNumberFor<Self>>;
+        /// Notified of the votes validators cast on roots
+        #[pallet::no_default]
+        type VotingParticipationHandler: VotingParticipationHandler<Self::AccountId>;
+        /// Number of blocks in a window of unsigned submissions
+        #[pallet::constant]
+        #[pallet::no_default]
+        type UnsignedSubmissionWindow: Get<BlockNumberFor<Self>>;
+        /// Summaries a validator can record, and slots or challenges it can submit, in a window
+        #[pallet::constant]
+        #[pallet::no_default]
+        type MaxUnsignedSubmissions: Get<u32>;
+        /// Votes, and ends of voting periods, a validator can submit in a window
+        #[pallet::constant]
+        #[pallet::no_default]
+        type MaxUnsignedVotes: Get<u32>;
+        /// Consecutive windows in which a validator reaches its limit before an offence is
+        /// reported
+        #[pallet::constant]
+        #[pallet::no_default]
+        type UnsignedAbuseThreshold: Get<u32>;
     }
 
     #[pallet::pallet]
@@ -161,10 +255,12 @@ pub mod pallet {
         VoteAdded { voter: T::AccountId, root_id: RootId<BlockNumberFor<T>>, agree_vote: bool },
         /// Voting for the root id is finished, true means the root is approved
         VotingEnded { root_id: RootId<BlockNumberFor<T>>, vote_approved: bool },
-        /// A summary offence by a list of offenders is reported
+        /// A summary offence by a list of offenders is reported. `evidence` is the key of the
+        /// facts recorded for it in `OffenceEvidence`.
         SummaryOffenceReported {
             offence_type: SummaryOffenceType,
             offenders: Vec<IdentificationTuple<T>>,
+            evidence: H256,
         },
         /// A new slot between a range of blocks for a validator is advanced by an account
         SlotAdvanced {
@@ -194,7 +290,45 @@ pub mod pallet {
             root_hash: H256,
             ingress_counter: IngressCounter,
             block_range: RootRange<BlockNumberFor<T>>,
+            sequence: u64,
         },
+        /// A change of the schedule period and voting period has been announced. It executes at
+        /// `execute_at` unless it is cancelled before.
+        SchedulePeriodAndVotingPeriodChangeAnnounced {
+            schedule_period: BlockNumberFor<T>,
+            voting_period: BlockNumberFor<T>,
+            execute_at: BlockNumberFor<T>,
+            schedule_name: TaskName,
+        },
+        /// An announced change of the periods has been cancelled
+        AdminChangeCancelled { schedule_name: TaskName },
+        /// A root range that could not be validated has been skipped by the admin origin.
+        /// Summaries resume from `next_block_to_process`.
+        RootRangeSkipped {
+            block_range: RootRange<BlockNumberFor<T>>,
+            next_block_to_process: BlockNumberFor<T>,
+            sequence: u64,
+        },
+        /// Voting for the root id ended without a quorum and a new voting session is opened
+        VotingReopened { root_id: RootId<BlockNumberFor<T>>, round: u32 },
+        /// Voting for the root id ended without a quorum after `rounds` reopened sessions. The
+        /// root is judged on the votes it received and should be reviewed by the admin.
+        InconclusiveVotingEscalated { root_id: RootId<BlockNumberFor<T>>, rounds: u32 },
+        /// A voter replaced its vote for a root id with `agree_vote`
+        VoteReplaced { voter: T::AccountId, root_id: RootId<BlockNumberFor<T>>, agree_vote: bool },
+        /// A root was validated although its range did not start at the next block to process
+        RootRangeAnomalyDetected {
+            root_id: RootId<BlockNumberFor<T>>,
+            anomaly: RootRangeAnomaly<BlockNumberFor<T>>,
+        },
+        /// The schedule period follows the activity of the chain within `bounds`, or is fixed if
+        /// `bounds` is None
+        AdaptiveSchedulePeriodSet { bounds: Option<SchedulePeriodBounds<BlockNumberFor<T>>> },
+        /// The schedule period was adapted to the activity of the slot that ended
+        SchedulePeriodAdapted { schedule_period: BlockNumberFor<T>, activity: SlotActivity },
+        /// Voting sessions that have ended, transaction ids of finalised roots and expired offence
+        /// evidence were removed
+        ExpiredStateRemoved { voting_sessions: u32, tx_ids: u32, offence_evidence: u32 },
     }
 
     #[pallet::error]
@@ -231,6 +365,11 @@ pub mod pallet {
         VotingPeriodIsEqualOrLongerThanSchedulePeriod,
         CurrentSlotValidatorNotFound,
         ErrorPublishingSummary,
+        AdminChangeNotFound,
+        SchedulePeriodIsTooLong,
+        InvalidRootRangeToSkip,
+        InvalidSchedulePeriodBounds,
+        HeaderHashExpired,
     }
 
     // Note for SYS-152 (see notes in fn end_voting)):
@@ -254,9 +393,15 @@ pub mod pallet {
         Blake2_128Concat,
         EthereumTransactionId,
         RootId<BlockNumberFor<T>>,
-        ValueQuery,
+        OptionQuery,
     >;
 
+    #[pallet::storage]
+    /// The raw storage key of the last transaction id checked by `cleanup`, so the next call
+    /// carries on from there
+    pub type TxIdsCleanupCursor<T: Config<I>, I: 'static = ()> =
+        StorageValue<_, BoundedVec<u8, ConstU32<128>>, OptionQuery>;
+
     #[pallet::storage]
     #[pallet::getter(fn block_number_for_next_slot)]
     pub type NextSlotAtBlock<T: Config<I>, I: 'static = ()> =
@@ -285,9 +430,28 @@ pub mod pallet {
         Blake2_128Concat,
         IngressCounter,
         RootData<T::AccountId>,
-        ValueQuery,
+        OptionQuery,
+    >;
+
+    /// The hash of the parachain header at the last block of each root, published alongside it
+    #[pallet::storage]
+    #[pallet::getter(fn root_header_hash)]
+    pub type RootHeaderHashes<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        RootRange<BlockNumberFor<T>>,
+        Blake2_128Concat,
+        IngressCounter,
+        H256,
+        OptionQuery,
     >;
 
+    /// The validated roots keyed by the big endian bytes of their last block. Keys are stored as
+    /// is, so the root covering a block is the first one from that block onwards.
+    #[pallet::storage]
+    pub type ValidatedRootsByEndBlock<T: Config<I>, I: 'static = ()> =
+        StorageMap<_, Identity, [u8; 4], RootId<BlockNumberFor<T>>, OptionQuery>;
+
     #[pallet::storage]
     #[pallet::getter(fn get_vote )]
     pub type VotesRepository<T: Config<I>, I: 'static = ()> = StorageMap<
@@ -298,10 +462,16 @@ pub mod pallet {
         ValueQuery,
     >;
 
+    #[pallet::storage]
+    /// The raw storage key of the last voting session checked by `cleanup`, so the next call
+    /// carries on from there
+    pub type VotingSessionsCleanupCursor<T: Config<I>, I: 'static = ()> =
+        StorageValue<_, BoundedVec<u8, ConstU32<128>>, OptionQuery>;
+
     #[pallet::storage]
     #[pallet::getter(fn get_pending_roots)]
     pub type PendingApproval<T: Config<I>, I: 'static = ()> =
-        StorageMap<_, Blake2_128Concat, RootRange<BlockNumberFor<T>>, IngressCounter, ValueQuery>;
+        StorageMap<_, Blake2_128Concat, RootRange<BlockNumberFor<T>>, IngressCounter, OptionQuery>;
 
     /// The total ingresses of roots
     #[pallet::storage]
@@ -315,6 +485,19 @@ pub mod pallet {
     pub type SchedulePeriod<T: Config<I>, I: 'static = ()> =
         StorageValue<_, BlockNumberFor<T>, ValueQuery>;
 
+    /// Bounds within which the schedule period follows the activity of the chain. The schedule
+    /// period is fixed when they are not set.
+    #[pallet::storage]
+    #[pallet::getter(fn schedule_period_bounds)]
+    pub type AdaptiveSchedulePeriod<T: Config<I>, I: 'static = ()> =
+        StorageValue<_, SchedulePeriodBounds<BlockNumberFor<T>>, OptionQuery>;
+
+    /// The activity of the chain in the current slot, while the schedule period is adaptive
+    #[pallet::storage]
+    #[pallet::getter(fn slot_activity)]
+    pub type CurrentSlotActivity<T: Config<I>, I: 'static = ()> =
+        StorageValue<_, SlotActivity, ValueQuery>;
+
     /// A period (in block number) where validators are allowed to vote on the validity of a root
     /// hash
     #[pallet::storage]
@@ -322,6 +505,22 @@ pub mod pallet {
     pub type VotingPeriod<T: Config<I>, I: 'static = ()> =
         StorageValue<_, BlockNumberFor<T>, ValueQuery>;
 
+    /// The period changes waiting to be executed by the scheduler, with the block they execute at
+    #[pallet::storage]
+    pub type PendingAdminChanges<T: Config<I>, I: 'static = ()> =
+        StorageMap<_, Blake2_128Concat, TaskName, BlockNumberFor<T>, OptionQuery>;
+
+    /// Root ranges skipped with `force_skip_range`, with the block they were skipped at
+    #[pallet::storage]
+    #[pallet::getter(fn skipped_root_range)]
+    pub type SkippedRootRanges<T: Config<I>, I: 'static = ()> = StorageMap<
+        _,
+        Blake2_128Concat,
+        RootRange<BlockNumberFor<T>>,
+        BlockNumberFor<T>,
+        OptionQuery,
+    >;
+
     #[pallet::storage]
     #[pallet::getter(fn anchor_roots_counter)]
     pub type AnchorRootsCounter<T: Config<I>, I: 'static = ()> = StorageValue<_, u32, ValueQuery>;
@@ -332,6 +531,53 @@ pub mod pallet {
     pub type AnchorRoots<T: Config<I>, I: 'static = ()> =
         StorageMap<_, Blake2_128Concat, u32, H256, ValueQuery>;
 
+    /// Voting sessions of a pending root that ended without a quorum and were reopened
+    #[pallet::storage]
+    #[pallet::getter(fn inconclusive_voting_rounds)]
+    pub type InconclusiveVotingRounds<T: Config<I>, I: 'static = ()> =
+        StorageMap<_, Blake2_128Concat, RootId<BlockNumberFor<T>>, u32, ValueQuery>;
+
+    /// Validated roots whose range did not follow on from the previous root or skipped range
+    #[pallet::storage]
+    #[pallet::getter(fn root_range_anomaly)]
+    pub type RootRangeAnomalies<T: Config<I>, I: 'static = ()> = StorageMap<
+        _,
+        Blake2_128Concat,
+        RootId<BlockNumberFor<T>>,
+        RootRangeAnomaly<BlockNumberFor<T>>,
+        OptionQuery,
+    >;
+
+    /// The evidence of each reported offence, keyed by its hash
+    #[pallet::storage]
+    #[pallet::getter(fn offence_evidence)]
+    pub type OffenceEvidence<T: Config<I>, I: 'static = ()> = StorageMap<
+        _,
+        Identity,
+        H256,
+        SummaryOffenceEvidence<T::AccountId, BlockNumberFor<T>>,
+        OptionQuery,
+    >;
+
+    #[pallet::storage]
+    /// The raw storage key of the last offence evidence checked by `cleanup`, so the next call
+    /// carries on from there
+    pub type OffenceEvidenceCleanupCursor<T: Config<I>, I: 'static = ()> =
+        StorageValue<_, BoundedVec<u8, ConstU32<128>>, OptionQuery>;
+
+    /// Unsigned transactions of each type executed for a validator in its current window
+    #[pallet::storage]
+    #[pallet::getter(fn unsigned_submissions)]
+    pub type UnsignedSubmissions<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        Blake2_128Concat,
+        SummaryUnsignedCall,
+        SubmissionWindow<BlockNumberFor<T>>,
+        ValueQuery,
+    >;
+
     #[pallet::genesis_config]
     pub struct GenesisConfig<T: Config<I>, I: 'static = ()> {
         /// Dummy marker.
@@ -356,7 +602,8 @@ pub mod pallet {
         fn build(&self) {
             let mut schedule_period_in_blocks = self.schedule_period;
             if schedule_period_in_blocks == 0u32.into() {
-                schedule_period_in_blocks = DEFAULT_SCHEDULE_PERIOD.into();
+                schedule_period_in_blocks = BlockNumberFor::<T>::from(DEFAULT_SCHEDULE_PERIOD)
+                    .clamp(T::MinSchedulePeriod::get(), T::MaxSchedulePeriod::get());
             }
             assert!(
                 Pallet::<T, I>::validate_schedule_period(schedule_period_in_blocks).is_ok(),
@@ -367,7 +614,7 @@ pub mod pallet {
 
             let mut voting_period_in_blocks = self.voting_period;
             if voting_period_in_blocks == 0u32.into() {
-                voting_period_in_blocks = MIN_VOTING_PERIOD.into();
+                voting_period_in_blocks = T::MinVotingPeriod::get();
             }
             assert!(
                 Pallet::<T, I>::validate_voting_period(
@@ -400,9 +647,27 @@ pub mod pallet {
             schedule_period_in_blocks: BlockNumberFor<T>,
             voting_period_in_blocks: BlockNumberFor<T>,
         ) -> DispatchResult {
-            ensure_root(origin)?;
+            let execute_now = Self::ensure_admin_change_origin(origin)?;
             Self::validate_schedule_period(schedule_period_in_blocks)?;
             Self::validate_voting_period(voting_period_in_blocks, schedule_period_in_blocks)?;
+            if let Some(bounds) = <AdaptiveSchedulePeriod<T, I>>::get() {
+                // An adaptive schedule period can shorten down to its minimum
+                Self::validate_voting_period(voting_period_in_blocks, bounds.min)?;
+            }
+
+            let call =
+                Call::<T, I>::set_periods { schedule_period_in_blocks, voting_period_in_blocks };
+            if !execute_now {
+                let (schedule_name, execute_at) = Self::announce_admin_change(call)?;
+                Self::deposit_event(Event::<T, I>::SchedulePeriodAndVotingPeriodChangeAnnounced {
+                    schedule_period: schedule_period_in_blocks,
+                    voting_period: voting_period_in_blocks,
+                    execute_at,
+                    schedule_name,
+                });
+                return Ok(())
+            }
+            Self::clear_pending_admin_changes(&call);
 
             let next_block_to_process = <NextBlockToProcess<T, I>>::get();
             let new_slot_at_block =
@@ -439,6 +704,14 @@ pub mod pallet {
                 Error::<T, I>::InvalidIngressCounter
             );
             ensure!(AVN::<T>::is_validator(&validator.account_id), Error::<T, I>::InvalidKey);
+            ensure!(
+                !Self::block_header_hash_expired(new_block_number),
+                Error::<T, I>::HeaderHashExpired
+            );
+            Self::record_unsigned_submission(
+                &validator.account_id,
+                SummaryUnsignedCall::RecordSummaryCalculation,
+            );
 
             let root_range = RootRange::new(Self::get_next_block_to_process(), new_block_number);
             let root_id = RootId::new(root_range, ingress_counter);
@@ -466,6 +739,9 @@ pub mod pallet {
                 ingress_counter,
                 RootData::new(root_hash, validator.account_id.clone(), None),
             );
+            if let Some(header_hash) = Self::block_header_hash(new_block_number) {
+                <RootHeaderHashes<T, I>>::insert(&root_id.range, ingress_counter, header_hash);
+            }
             <PendingApproval<T, I>>::insert(root_id.range, ingress_counter);
             <VotesRepository<T, I>>::insert(
                 root_id,
@@ -476,6 +752,7 @@ pub mod pallet {
                     current_block_number,
                 ),
             );
+            AVN::<T>::hint_ocw_task(&Self::pallet_id(), &root_id.encode(), OcwTaskPriority::High);
 
             Self::deposit_event(Event::<T, I>::SummaryCalculated {
                 from: root_id.range.from_block,
@@ -487,7 +764,7 @@ pub mod pallet {
         }
 
         #[pallet::weight(<T as pallet::Config<I>>::WeightInfo::approve_root_with_end_voting(MAX_VALIDATOR_ACCOUNTS, MAX_OFFENDERS).max(
-            <T as Config<I>>::WeightInfo::approve_root_without_end_voting(MAX_VALIDATOR_ACCOUNTS)
+            <T as Config<I>>::WeightInfo::approve_root_without_end_voting(MAX_VALIDATOR_ACCOUNTS, MAX_VALIDATOR_ACCOUNTS)
         ))]
         #[pallet::call_index(2)]
         pub fn approve_root(
@@ -495,25 +772,33 @@ pub mod pallet {
             root_id: RootId<BlockNumberFor<T>>,
             validator: Validator<<T as avn::Config>::AuthorityId, T::AccountId>,
             _signature: <T::AuthorityId as RuntimeAppPublic>::Signature,
-        ) -> DispatchResult {
+        ) -> DispatchResultWithPostInfo {
             ensure_none(origin)?;
             let _ = Self::try_get_root_data(&root_id)?;
+            Self::record_unsigned_submission(&validator.account_id, SummaryUnsignedCall::Vote);
 
             let voting_session = Self::get_root_voting_session(&root_id);
 
             process_approve_vote::<T>(&voting_session, validator.account_id.clone())?;
+            T::VotingParticipationHandler::on_vote_cast(&validator.account_id);
 
             Self::deposit_event(Event::<T, I>::VoteAdded {
                 voter: validator.account_id,
                 root_id,
                 agree_vote: true,
             });
-            // TODO [TYPE: weightInfo][PRI: medium]: Return accurate weight
-            Ok(())
+
+            let (validators, voters, offenders) = Self::voting_weight_params(&root_id);
+            let weight = if voting_session.is_active() {
+                <T as Config<I>>::WeightInfo::approve_root_without_end_voting(validators, voters)
+            } else {
+                <T as Config<I>>::WeightInfo::approve_root_with_end_voting(validators, offenders)
+            };
+            Ok(Some(weight).into())
         }
 
         #[pallet::weight(<T as pallet::Config<I>>::WeightInfo::reject_root_with_end_voting(MAX_VALIDATOR_ACCOUNTS, MAX_OFFENDERS).max(
-            <T as Config<I>>::WeightInfo::reject_root_without_end_voting(MAX_VALIDATOR_ACCOUNTS)
+            <T as Config<I>>::WeightInfo::reject_root_without_end_voting(MAX_VALIDATOR_ACCOUNTS, MAX_VALIDATOR_ACCOUNTS)
         ))]
         #[pallet::call_index(3)]
         pub fn reject_root(
@@ -521,18 +806,26 @@ pub mod pallet {
             root_id: RootId<BlockNumberFor<T>>,
             validator: Validator<<T as avn::Config>::AuthorityId, T::AccountId>,
             _signature: <T::AuthorityId as RuntimeAppPublic>::Signature,
-        ) -> DispatchResult {
+        ) -> DispatchResultWithPostInfo {
             ensure_none(origin)?;
+            Self::record_unsigned_submission(&validator.account_id, SummaryUnsignedCall::Vote);
             let voting_session = Self::get_root_voting_session(&root_id);
             process_reject_vote::<T>(&voting_session, validator.account_id.clone())?;
+            T::VotingParticipationHandler::on_vote_cast(&validator.account_id);
 
             Self::deposit_event(Event::<T, I>::VoteAdded {
                 voter: validator.account_id,
                 root_id,
                 agree_vote: false,
             });
-            // TODO [TYPE: weightInfo][PRI: medium]: Return accurate weight
-            Ok(())
+
+            let (validators, voters, offenders) = Self::voting_weight_params(&root_id);
+            let weight = if voting_session.is_active() {
+                <T as Config<I>>::WeightInfo::reject_root_without_end_voting(validators, voters)
+            } else {
+                <T as Config<I>>::WeightInfo::reject_root_with_end_voting(validators, offenders)
+            };
+            Ok(Some(weight).into())
         }
 
         #[pallet::weight(<T as pallet::Config<I>>::WeightInfo::end_voting_period_with_rejected_valid_votes(MAX_VALIDATOR_ACCOUNTS, MAX_OFFENDERS).max(
@@ -544,14 +837,27 @@ pub mod pallet {
             root_id: RootId<BlockNumberFor<T>>,
             validator: Validator<<T as avn::Config>::AuthorityId, T::AccountId>,
             _signature: <T::AuthorityId as RuntimeAppPublic>::Signature,
-        ) -> DispatchResult {
+        ) -> DispatchResultWithPostInfo {
             ensure_none(origin)?;
+            Self::record_unsigned_submission(
+                &validator.account_id,
+                SummaryUnsignedCall::EndVotingPeriod,
+            );
             //Event is deposited in end_voting because this function can get called from
             // `approve_root` or `reject_root`
             Self::end_voting(validator.account_id, &root_id)?;
 
-            // TODO [TYPE: weightInfo][PRI: medium]: Return accurate weight
-            Ok(())
+            let (validators, _, offenders) = Self::voting_weight_params(&root_id);
+            let weight = if Self::get_vote(&root_id).is_approved() {
+                <T as Config<I>>::WeightInfo::end_voting_period_with_rejected_valid_votes(
+                    validators, offenders,
+                )
+            } else {
+                <T as Config<I>>::WeightInfo::end_voting_period_with_approved_invalid_votes(
+                    validators, offenders,
+                )
+            };
+            Ok(Some(weight).into())
         }
 
         #[pallet::weight(<T as pallet::Config<I>>::WeightInfo::advance_slot_with_offence(MAX_VALIDATOR_ACCOUNTS).max(
@@ -562,13 +868,23 @@ pub mod pallet {
             origin: OriginFor<T>,
             validator: Validator<<T as avn::Config>::AuthorityId, T::AccountId>,
             _signature: <T::AuthorityId as RuntimeAppPublic>::Signature,
-        ) -> DispatchResult {
+        ) -> DispatchResultWithPostInfo {
             ensure_none(origin)?;
+            Self::record_unsigned_submission(
+                &validator.account_id,
+                SummaryUnsignedCall::AdvanceSlot,
+            );
 
+            let offence_reported = Self::last_summary_slot() < Self::current_slot();
             Self::update_slot_number(validator)?;
 
-            // TODO [TYPE: weightInfo][PRI: medium]: Return accurate weight
-            Ok(())
+            let validators = AVN::<T>::validators().len() as u32;
+            let weight = if offence_reported {
+                <T as Config<I>>::WeightInfo::advance_slot_with_offence(validators)
+            } else {
+                <T as Config<I>>::WeightInfo::advance_slot_without_offence(validators)
+            };
+            Ok(Some(weight).into())
         }
 
         #[pallet::weight(<T as pallet::Config<I>>::WeightInfo::add_challenge(MAX_VALIDATOR_ACCOUNTS))]
@@ -580,6 +896,7 @@ pub mod pallet {
             _signature: <T::AuthorityId as RuntimeAppPublic>::Signature,
         ) -> DispatchResult {
             ensure_none(origin)?;
+            Self::record_unsigned_submission(&validator.account_id, SummaryUnsignedCall::Challenge);
             ensure!(
                 challenge.is_valid::<T, I>(
                     Self::current_slot(),
@@ -607,6 +924,7 @@ pub mod pallet {
                 &validator.account_id,
                 &vec![offender],
                 challenge_type.expect("Already checked"),
+                SummaryOffenceSubject::Slot(Self::current_slot()),
             );
 
             Self::update_slot_number(validator)?;
@@ -619,10 +937,195 @@ pub mod pallet {
 
             Ok(())
         }
+
+        /// Cancel a change of the periods announced by the admin origin, before it is executed
+        #[pallet::weight(<T as pallet::Config<I>>::WeightInfo::cancel_admin_change())]
+        #[pallet::call_index(7)]
+        pub fn cancel_admin_change(
+            origin: OriginFor<T>,
+            schedule_name: TaskName,
+        ) -> DispatchResult {
+            T::AdminOrigin::ensure_origin(origin)?;
+            ensure!(
+                <PendingAdminChanges<T, I>>::contains_key(schedule_name),
+                Error::<T, I>::AdminChangeNotFound
+            );
+
+            T::Scheduler::cancel_named(schedule_name)?;
+            <PendingAdminChanges<T, I>>::remove(schedule_name);
+
+            Self::deposit_event(Event::<T, I>::AdminChangeCancelled { schedule_name });
+
+            Ok(())
+        }
+
+        /// Skip `root_range` when its root can never be validated, for instance because the
+        /// history of the range can't be reproduced. The range must start at the next block to
+        /// process and can't go past the target block of the next root. Any vote on it is
+        /// discarded and summaries resume from the block after it.
+        #[pallet::weight(<T as pallet::Config<I>>::WeightInfo::force_skip_range())]
+        #[pallet::call_index(8)]
+        pub fn force_skip_range(
+            origin: OriginFor<T>,
+            root_range: RootRange<BlockNumberFor<T>>,
+        ) -> DispatchResult {
+            T::AdminOrigin::ensure_origin(origin)?;
+            ensure!(
+                root_range.from_block == <NextBlockToProcess<T, I>>::get() &&
+                    root_range.to_block >= root_range.from_block &&
+                    root_range.to_block <= Self::get_target_block()?,
+                Error::<T, I>::InvalidRootRangeToSkip
+            );
+
+            let next_block_to_process =
+                safe_add_block_numbers::<BlockNumberFor<T>>(root_range.to_block, 1u32.into())
+                    .map_err(|_| Error::<T, I>::Overflow)?;
+
+            if let Some(ingress_counter) = <PendingApproval<T, I>>::take(root_range) {
+                let root_id = RootId::new(root_range, ingress_counter);
+                <VotesRepository<T, I>>::remove(root_id);
+                <InconclusiveVotingRounds<T, I>>::remove(root_id);
+            }
+            <NextBlockToProcess<T, I>>::put(next_block_to_process);
+            <SkippedRootRanges<T, I>>::insert(
+                root_range,
+                <frame_system::Pallet<T>>::block_number(),
+            );
+
+            Self::deposit_sequenced_event(|sequence| Event::<T, I>::RootRangeSkipped {
+                block_range: root_range,
+                next_block_to_process,
+                sequence,
+            });
+
+            Ok(())
+        }
+
+        /// Replaces the vote of a validator on a root with the opposite vote, for instance when
+        /// the validator voted while the avn service returned an error. A vote can be replaced
+        /// once per voting session, before the voting period ends.
+        #[pallet::weight(<T as pallet::Config<I>>::WeightInfo::approve_root_with_end_voting(MAX_VALIDATOR_ACCOUNTS, MAX_OFFENDERS)
+            .max(<T as Config<I>>::WeightInfo::reject_root_with_end_voting(MAX_VALIDATOR_ACCOUNTS, MAX_OFFENDERS))
+            .max(<T as Config<I>>::WeightInfo::approve_root_without_end_voting(MAX_VALIDATOR_ACCOUNTS, MAX_VALIDATOR_ACCOUNTS))
+            .max(<T as Config<I>>::WeightInfo::reject_root_without_end_voting(MAX_VALIDATOR_ACCOUNTS, MAX_VALIDATOR_ACCOUNTS))
+        )]
+        #[pallet::call_index(9)]
+        pub fn replace_vote(
+            origin: OriginFor<T>,
+            root_id: RootId<BlockNumberFor<T>>,
+            validator: Validator<<T as avn::Config>::AuthorityId, T::AccountId>,
+            approve: bool,
+            _signature: <T::AuthorityId as RuntimeAppPublic>::Signature,
+        ) -> DispatchResultWithPostInfo {
+            ensure_none(origin)?;
+            Self::record_unsigned_submission(&validator.account_id, SummaryUnsignedCall::Vote);
+            let voting_session = Self::get_root_voting_session(&root_id);
+            process_replace_vote::<T>(&voting_session, validator.account_id.clone(), approve)?;
+
+            Self::deposit_event(Event::<T, I>::VoteReplaced {
+                voter: validator.account_id,
+                root_id,
+                agree_vote: approve,
+            });
+
+            let (validators, voters, offenders) = Self::voting_weight_params(&root_id);
+            let weight = match (voting_session.is_active(), approve) {
+                (true, true) => <T as Config<I>>::WeightInfo::approve_root_without_end_voting(
+                    validators, voters,
+                ),
+                (true, false) =>
+                    <T as Config<I>>::WeightInfo::reject_root_without_end_voting(validators, voters),
+                (false, true) => <T as Config<I>>::WeightInfo::approve_root_with_end_voting(
+                    validators, offenders,
+                ),
+                (false, false) =>
+                    <T as Config<I>>::WeightInfo::reject_root_with_end_voting(validators, offenders),
+            };
+            Ok(Some(weight).into())
+        }
+
+        /// Lets the schedule period follow the activity of the chain within `bounds`, shortening
+        /// it when blocks are busy and lengthening it when they are idle. None fixes the schedule
+        /// period at its current value.
+        #[pallet::weight(<T as pallet::Config<I>>::WeightInfo::set_adaptive_schedule_period())]
+        #[pallet::call_index(10)]
+        pub fn set_adaptive_schedule_period(
+            origin: OriginFor<T>,
+            bounds: Option<SchedulePeriodBounds<BlockNumberFor<T>>>,
+        ) -> DispatchResult {
+            T::AdminOrigin::ensure_origin(origin)?;
+            if let Some(bounds) = &bounds {
+                ensure!(bounds.min <= bounds.max, Error::<T, I>::InvalidSchedulePeriodBounds);
+                Self::validate_schedule_period(bounds.min)?;
+                Self::validate_schedule_period(bounds.max)?;
+                Self::validate_voting_period(<VotingPeriod<T, I>>::get(), bounds.min)?;
+            }
+
+            <AdaptiveSchedulePeriod<T, I>>::set(bounds.clone());
+            <CurrentSlotActivity<T, I>>::kill();
+
+            Self::deposit_event(Event::<T, I>::AdaptiveSchedulePeriodSet { bounds });
+
+            Ok(())
+        }
+
+        /// Removes up to `limit` entries of state that is no longer needed: the voting sessions
+        /// of roots that are not pending approval, the transaction ids of roots that have been
+        /// finalised or no longer exist and the evidence of offences reported more than
+        /// `OffenceEvidenceRetention` blocks ago. Each call carries on from where the previous one
+        /// stopped.
+        /// Anyone can call it, and it is free when it removes something.
+        #[pallet::weight(<T as pallet::Config<I>>::WeightInfo::cleanup((*limit).min(MAX_CLEANUP_LIMIT)))]
+        #[pallet::call_index(11)]
+        pub fn cleanup(origin: OriginFor<T>, limit: u32) -> DispatchResultWithPostInfo {
+            ensure_signed(origin)?;
+            let limit = limit.min(MAX_CLEANUP_LIMIT);
+
+            let (voting_sessions, sessions_checked) = Self::remove_ended_voting_sessions(limit);
+            let (tx_ids, tx_ids_checked) =
+                Self::remove_finalised_tx_ids(limit.saturating_sub(sessions_checked));
+            let (offence_evidence, evidence_checked) = Self::remove_expired_offence_evidence(
+                limit.saturating_sub(sessions_checked).saturating_sub(tx_ids_checked),
+            );
+
+            let removed = voting_sessions.saturating_add(tx_ids).saturating_add(offence_evidence);
+            if removed > 0 {
+                Self::deposit_event(Event::<T, I>::ExpiredStateRemoved {
+                    voting_sessions,
+                    tx_ids,
+                    offence_evidence,
+                });
+            }
+
+            Ok(PostDispatchInfo {
+                actual_weight: Some(<T as pallet::Config<I>>::WeightInfo::cleanup(
+                    sessions_checked
+                        .saturating_add(tx_ids_checked)
+                        .saturating_add(evidence_checked),
+                )),
+                pays_fee: if removed > 0 { Pays::No } else { Pays::Yes },
+            })
+        }
     }
 
     #[pallet::hooks]
     impl<T: Config<I>, I: 'static> Hooks<BlockNumberFor<T>> for Pallet<T, I> {
+        fn integrity_test() {
+            assert!(
+                T::MinSchedulePeriod::get() <= T::MaxSchedulePeriod::get(),
+                "MinSchedulePeriod must not be greater than MaxSchedulePeriod"
+            );
+            assert!(
+                T::MinVotingPeriod::get() > 0u32.into() &&
+                    T::MinVotingPeriod::get() <= T::MaxVotingPeriod::get(),
+                "MinVotingPeriod must be positive and not greater than MaxVotingPeriod"
+            );
+            assert!(
+                T::MinVotingPeriod::get() < T::MaxSchedulePeriod::get(),
+                "MinVotingPeriod must be shorter than MaxSchedulePeriod"
+            );
+        }
+
         fn offchain_worker(block_number: BlockNumberFor<T>) {
             log::info!(
                 "🚧 🚧 Instance({}) - Running offchain worker for block: {:?}",
@@ -647,6 +1150,7 @@ pub mod pallet {
                 return
             }
             let (this_validator, _) = setup_result.expect("We have a validator");
+            AVN::<T>::enqueue_hinted_ocw_tasks(&Self::pallet_id(), block_number);
 
             Self::advance_slot_if_required(block_number, &this_validator);
             Self::process_summary_if_required(block_number, &this_validator);
@@ -655,36 +1159,67 @@ pub mod pallet {
             challenge_slot_if_required::<T, I>(block_number, &this_validator);
         }
 
-        fn on_runtime_upgrade() -> Weight {
-            let onchain = Pallet::<T, I>::on_chain_storage_version();
+        fn on_initialize(n: BlockNumberFor<T>) -> Weight {
+            let migration_weight =
+                pallet_avn::migration::migrate::<T, migration::SteppedMigrations<T, I>>();
 
-            if onchain < 1 {
-                log::info!(
-                    "💽 Running Summary pallet migration with current storage version {:?} / onchain {:?}",
-                    Pallet::<T, I>::current_storage_version(),
-                    onchain
-                );
+            // [Read]: AdaptiveSchedulePeriod, [Read/Write in on_finalize]: CurrentSlotActivity
+            let activity_weight = if <AdaptiveSchedulePeriod<T, I>>::exists() {
+                T::DbWeight::get().reads_writes(2, 1)
+            } else {
+                T::DbWeight::get().reads(1)
+            };
 
-                let schedule_period_in_blocks: BlockNumberFor<T> = DEFAULT_SCHEDULE_PERIOD.into();
-                <NextSlotAtBlock<T, I>>::put(schedule_period_in_blocks);
-                <SchedulePeriod<T, I>>::put(schedule_period_in_blocks);
+            migration_weight
+                .saturating_add(activity_weight)
+                .saturating_add(Self::clear_expired_admin_changes(n))
+        }
 
-                let voting_period_in_blocks: BlockNumberFor<T> = MIN_VOTING_PERIOD.into();
-                <VotingPeriod<T, I>>::put(voting_period_in_blocks);
+        fn on_idle(n: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+            // All the call types share the same window, so any of their limits tells which
+            // windows have expired
+            prune_expired_windows::<UnsignedSubmissions<T, I>, _, _, _>(
+                n,
+                &Self::unsigned_rate_limit(SummaryUnsignedCall::Vote),
+                T::DbWeight::get(),
+                remaining_weight,
+            )
+        }
 
-                let maybe_first_validator =
-                    AVN::<T>::validators().into_iter().map(|v| v.account_id).nth(0);
+        fn on_finalize(_n: BlockNumberFor<T>) {
+            if <AdaptiveSchedulePeriod<T, I>>::exists() {
+                Self::record_block_activity();
+            }
+        }
 
-                <CurrentSlotsValidator<T, I>>::put(
-                    maybe_first_validator.expect("Validator is checked for none"),
-                );
+        fn on_runtime_upgrade() -> Weight {
+            log::info!(
+                "💽 Running Summary pallet migrations with current storage version {:?} / onchain {:?}",
+                Pallet::<T, I>::current_storage_version(),
+                Pallet::<T, I>::on_chain_storage_version()
+            );
 
-                STORAGE_VERSION.put::<Pallet<T, I>>();
+            pallet_avn::migration::migrate::<T, migration::SteppedMigrations<T, I>>()
+        }
 
-                return T::DbWeight::get().reads_writes(0, 5)
-            }
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::TryRuntimeError> {
+            Self::do_try_state()?;
+            Ok(Vec::new())
+        }
 
-            Weight::zero()
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(_state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+            ensure!(
+                Pallet::<T, I>::on_chain_storage_version() == STORAGE_VERSION,
+                "Summary storage version does not match the pallet after the upgrade"
+            );
+            Self::do_try_state()
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn try_state(_n: BlockNumberFor<T>) -> Result<(), sp_runtime::TryRuntimeError> {
+            Self::do_try_state()
         }
     }
 
@@ -693,6 +1228,12 @@ pub mod pallet {
         type Call = Call<T, I>;
 
         fn validate_unsigned(source: TransactionSource, call: &Self::Call) -> TransactionValidity {
+            if let Some((submitter, call_type)) = Self::unsigned_call_submitter(call) {
+                if !Self::unsigned_submission_allowed(submitter, call_type) {
+                    return InvalidTransaction::Custom(ERROR_CODE_UNSIGNED_RATE_LIMIT_REACHED).into()
+                }
+            }
+
             if let Call::record_summary_calculation { .. } = call {
                 return Self::record_summary_validate_unsigned(source, call)
             } else if let Call::end_voting_period { root_id, validator, signature } = call {
@@ -721,6 +1262,14 @@ pub mod pallet {
                     validator,
                     signature,
                 )
+            } else if let Call::replace_vote { root_id, validator, approve, signature } = call {
+                let root_voting_session = Self::get_root_voting_session(root_id);
+                return replace_vote_validate_unsigned::<T>(
+                    &root_voting_session,
+                    validator,
+                    *approve,
+                    signature,
+                )
             } else if let Call::add_challenge { challenge, validator, signature } = call {
                 return add_challenge_validate_unsigned::<T, I>(challenge, validator, signature)
             } else if let Call::advance_slot { .. } = call {
@@ -731,6 +1280,259 @@ pub mod pallet {
         }
     }
     impl<T: Config<I>, I: 'static> Pallet<T, I> {
+        fn unsigned_call_submitter(
+            call: &Call<T, I>,
+        ) -> Option<(&T::AccountId, SummaryUnsignedCall)> {
+            match call {
+                Call::record_summary_calculation { validator, .. } =>
+                    Some((&validator.account_id, SummaryUnsignedCall::RecordSummaryCalculation)),
+                Call::approve_root { validator, .. } |
+                Call::reject_root { validator, .. } |
+                Call::replace_vote { validator, .. } =>
+                    Some((&validator.account_id, SummaryUnsignedCall::Vote)),
+                Call::end_voting_period { validator, .. } =>
+                    Some((&validator.account_id, SummaryUnsignedCall::EndVotingPeriod)),
+                Call::advance_slot { validator, .. } =>
+                    Some((&validator.account_id, SummaryUnsignedCall::AdvanceSlot)),
+                Call::add_challenge { validator, .. } =>
+                    Some((&validator.account_id, SummaryUnsignedCall::Challenge)),
+                _ => None,
+            }
+        }
+
+        pub fn unsigned_rate_limit(
+            call_type: SummaryUnsignedCall,
+        ) -> UnsignedRateLimit<BlockNumberFor<T>> {
+            let max_submissions = match call_type {
+                SummaryUnsignedCall::RecordSummaryCalculation |
+                SummaryUnsignedCall::AdvanceSlot |
+                SummaryUnsignedCall::Challenge => T::MaxUnsignedSubmissions::get(),
+                SummaryUnsignedCall::Vote | SummaryUnsignedCall::EndVotingPeriod =>
+                    T::MaxUnsignedVotes::get(),
+            };
+            UnsignedRateLimit {
+                max_submissions,
+                window: T::UnsignedSubmissionWindow::get(),
+                abuse_threshold: T::UnsignedAbuseThreshold::get(),
+            }
+        }
+
+        pub fn unsigned_submission_allowed(
+            validator: &T::AccountId,
+            call_type: SummaryUnsignedCall,
+        ) -> bool {
+            Self::unsigned_submissions(validator, call_type).allows_submission(
+                <system::Pallet<T>>::block_number(),
+                &Self::unsigned_rate_limit(call_type),
+            )
+        }
+
+        /// Counts an executed unsigned transaction of `validator`, reporting an offence if it keeps
+        /// submitting as many transactions as it is allowed to
+        fn record_unsigned_submission(validator: &T::AccountId, call_type: SummaryUnsignedCall) {
+            let now = <system::Pallet<T>>::block_number();
+            let limit_reached_repeatedly =
+                <UnsignedSubmissions<T, I>>::mutate(validator, call_type, |window| {
+                    window.record_submission(now, &Self::unsigned_rate_limit(call_type))
+                });
+
+            if limit_reached_repeatedly {
+                create_and_report_summary_offence::<T, I>(
+                    validator,
+                    &vec![validator.clone()],
+                    SummaryOffenceType::UnsignedRateLimitReached,
+                    SummaryOffenceSubject::UnsignedSubmissions,
+                );
+            }
+        }
+
+        /// Removes the voting sessions, among the next `limit` ones after the cleanup cursor, of
+        /// roots that are no longer pending approval. Returns the number of sessions removed and
+        /// checked.
+        fn remove_ended_voting_sessions(limit: u32) -> (u32, u32) {
+            if limit == 0 {
+                return (0, 0)
+            }
+
+            let mut keys = match <VotingSessionsCleanupCursor<T, I>>::get() {
+                Some(last_key) => <VotesRepository<T, I>>::iter_keys_from(last_key.into_inner()),
+                None => <VotesRepository<T, I>>::iter_keys(),
+            };
+            let root_ids =
+                keys.by_ref().take(limit as usize).collect::<Vec<RootId<BlockNumberFor<T>>>>();
+            if root_ids.len() < limit as usize {
+                // The sweep is complete, the next one starts over
+                <VotingSessionsCleanupCursor<T, I>>::kill();
+            } else {
+                <VotingSessionsCleanupCursor<T, I>>::put(BoundedVec::truncate_from(
+                    keys.last_raw_key().to_vec(),
+                ));
+            }
+
+            let mut removed: u32 = 0;
+            for root_id in root_ids.iter() {
+                if <PendingApproval<T, I>>::get(root_id.range) == Some(root_id.ingress_counter) {
+                    continue
+                }
+                <VotesRepository<T, I>>::remove(root_id);
+                <InconclusiveVotingRounds<T, I>>::remove(root_id);
+                removed.saturating_inc();
+            }
+
+            (removed, root_ids.len() as u32)
+        }
+
+        /// Removes the transaction ids, among the next `limit` ones after the cleanup cursor,
+        /// whose root has been finalised or no longer exists. Returns the number of ids removed
+        /// and checked.
+        fn remove_finalised_tx_ids(limit: u32) -> (u32, u32) {
+            if limit == 0 {
+                return (0, 0)
+            }
+
+            let mut entries = match <TxIdsCleanupCursor<T, I>>::get() {
+                Some(last_key) => <TxIdToRoot<T, I>>::iter_from(last_key.into_inner()),
+                None => <TxIdToRoot<T, I>>::iter(),
+            };
+            let tx_ids = entries
+                .by_ref()
+                .take(limit as usize)
+                .collect::<Vec<(EthereumTransactionId, RootId<BlockNumberFor<T>>)>>();
+            if tx_ids.len() < limit as usize {
+                <TxIdsCleanupCursor<T, I>>::kill();
+            } else {
+                <TxIdsCleanupCursor<T, I>>::put(BoundedVec::truncate_from(
+                    entries.last_raw_key().to_vec(),
+                ));
+            }
+
+            let mut removed: u32 = 0;
+            for (tx_id, root_id) in tx_ids.iter() {
+                let finalised = <Roots<T, I>>::get(root_id.range, root_id.ingress_counter)
+                    .map_or(true, |root| root.is_finalised);
+                if finalised {
+                    <TxIdToRoot<T, I>>::remove(tx_id);
+                    removed.saturating_inc();
+                }
+            }
+
+            (removed, tx_ids.len() as u32)
+        }
+
+        /// Removes the offence evidence, among the next `limit` entries after the cleanup cursor,
+        /// reported more than `OffenceEvidenceRetention` blocks ago. Returns the number of entries
+        /// removed and checked.
+        fn remove_expired_offence_evidence(limit: u32) -> (u32, u32) {
+            if limit == 0 {
+                return (0, 0)
+            }
+
+            let mut entries = match <OffenceEvidenceCleanupCursor<T, I>>::get() {
+                Some(last_key) => <OffenceEvidence<T, I>>::iter_from(last_key.into_inner()),
+                None => <OffenceEvidence<T, I>>::iter(),
+            };
+            let evidence = entries
+                .by_ref()
+                .take(limit as usize)
+                .map(|(hash, evidence)| (hash, evidence.reported_at))
+                .collect::<Vec<(H256, BlockNumberFor<T>)>>();
+            if evidence.len() < limit as usize {
+                <OffenceEvidenceCleanupCursor<T, I>>::kill();
+            } else {
+                <OffenceEvidenceCleanupCursor<T, I>>::put(BoundedVec::truncate_from(
+                    entries.last_raw_key().to_vec(),
+                ));
+            }
+
+            let expired_before = <system::Pallet<T>>::block_number()
+                .saturating_sub(T::OffenceEvidenceRetention::get());
+            let mut removed: u32 = 0;
+            for (hash, reported_at) in evidence.iter() {
+                if *reported_at < expired_before {
+                    <OffenceEvidence<T, I>>::remove(hash);
+                    removed.saturating_inc();
+                }
+            }
+
+            (removed, evidence.len() as u32)
+        }
+
+        /// Validated roots carry the global event sequence number so indexers can reference them
+        fn deposit_sequenced_event(event: impl FnOnce(u64) -> Event<T, I>) {
+            avn::Pallet::<T>::deposit_sequenced_event(|sequence| {
+                <T as Config<I>>::RuntimeEvent::from(event(sequence)).into()
+            });
+        }
+
+        /// Root, which is also the origin of the announced changes once their delay has passed,
+        /// changes the periods straight away. The admin origin can only announce changes.
+        fn ensure_admin_change_origin(origin: OriginFor<T>) -> Result<bool, DispatchError> {
+            if ensure_root(origin.clone()).is_ok() {
+                return Ok(true)
+            }
+
+            T::AdminOrigin::ensure_origin(origin)?;
+            Ok(false)
+        }
+
+        /// The name of the scheduled task is derived from the overarching call, so that the same
+        /// change announced on different instances gets a different name.
+        pub(crate) fn admin_change_name(call: &Call<T, I>) -> TaskName {
+            let call = <T as Config<I>>::RuntimeCall::from(call.clone());
+            ("AdminChange", call).using_encoded(sp_io::hashing::blake2_256)
+        }
+
+        pub(crate) fn announce_admin_change(
+            call: Call<T, I>,
+        ) -> Result<(TaskName, BlockNumberFor<T>), DispatchError> {
+            Self::clear_pending_admin_changes(&call);
+
+            let schedule_name = Self::admin_change_name(&call);
+            let execute_at = <frame_system::Pallet<T>>::block_number()
+                .saturating_add(T::AdminChangeDelay::get());
+
+            T::Scheduler::schedule_named(
+                schedule_name,
+                DispatchTime::At(execute_at),
+                None,
+                HARD_DEADLINE,
+                frame_system::RawOrigin::Root.into(),
+                T::Preimages::bound(<T as Config<I>>::RuntimeCall::from(call))?,
+            )?;
+            <PendingAdminChanges<T, I>>::insert(schedule_name, execute_at);
+
+            Ok((schedule_name, execute_at))
+        }
+
+        /// Drops the announced changes that have not been executed, as `call` supersedes them.
+        /// Those other than `call` itself are cancelled.
+        fn clear_pending_admin_changes(call: &Call<T, I>) {
+            let superseded_by = Self::admin_change_name(call);
+            for (schedule_name, _) in <PendingAdminChanges<T, I>>::drain() {
+                // The change being executed by the scheduler is no longer in its agenda
+                let _ = T::Scheduler::cancel_named(schedule_name);
+                if schedule_name != superseded_by {
+                    Self::deposit_event(Event::<T, I>::AdminChangeCancelled { schedule_name });
+                }
+            }
+        }
+
+        /// Removes the announced changes whose execution block has passed. They are only left
+        /// when their execution failed.
+        fn clear_expired_admin_changes(now: BlockNumberFor<T>) -> Weight {
+            let pending: Vec<(TaskName, BlockNumberFor<T>)> =
+                <PendingAdminChanges<T, I>>::iter().collect();
+            let mut expired = 0u64;
+            for (schedule_name, execute_at) in pending.iter() {
+                if *execute_at < now {
+                    <PendingAdminChanges<T, I>>::remove(schedule_name);
+                    expired += 1;
+                }
+            }
+
+            T::DbWeight::get().reads_writes(pending.len() as u64 + 1, expired)
+        }
+
         pub fn update_block_number_context() -> Vec<u8> {
             let mut context = Vec::with_capacity(1 + UPDATE_BLOCK_NUMBER_CONTEXT.len());
             context.push(T::InstanceId::get());
@@ -749,19 +1551,64 @@ pub mod pallet {
             schedule_period_in_blocks: BlockNumberFor<T>,
         ) -> DispatchResult {
             ensure!(
-                schedule_period_in_blocks >= MIN_SCHEDULE_PERIOD.into(),
+                schedule_period_in_blocks >= T::MinSchedulePeriod::get(),
                 Error::<T, I>::SchedulePeriodIsTooShort
             );
+            ensure!(
+                schedule_period_in_blocks <= T::MaxSchedulePeriod::get(),
+                Error::<T, I>::SchedulePeriodIsTooLong
+            );
 
             Ok(())
         }
 
+        fn record_block_activity() {
+            let block_weight = <frame_system::Pallet<T>>::block_weight().total();
+            let max_block_weight =
+                <T as frame_system::Config>::BlockWeights::get().max_block.ref_time();
+            let fullness = Perbill::from_rational(block_weight.ref_time(), max_block_weight);
+            let is_busy = fullness >= BUSY_BLOCK_FULLNESS ||
+                <frame_system::Pallet<T>>::event_count() >= BUSY_BLOCK_EVENTS;
+
+            <CurrentSlotActivity<T, I>>::mutate(|activity| activity.record_block(is_busy));
+        }
+
+        /// Shortens the schedule period after a busy slot and lengthens it after an idle one,
+        /// when it is adaptive. The activity of the next slot is recorded from scratch.
+        fn adapt_schedule_period() {
+            let bounds = match <AdaptiveSchedulePeriod<T, I>>::get() {
+                Some(bounds) => bounds,
+                None => return,
+            };
+            let activity = <CurrentSlotActivity<T, I>>::take();
+
+            let schedule_period = Self::schedule_period();
+            let adjustment = SCHEDULE_PERIOD_ADJUSTMENT * schedule_period;
+            let busy_share = activity.busy_share();
+            let adapted_period = if busy_share >= HIGH_SLOT_ACTIVITY {
+                schedule_period.saturating_sub(adjustment)
+            } else if busy_share <= LOW_SLOT_ACTIVITY {
+                schedule_period.saturating_add(adjustment)
+            } else {
+                schedule_period
+            }
+            .clamp(bounds.min, bounds.max);
+
+            if adapted_period != schedule_period {
+                <SchedulePeriod<T, I>>::put(adapted_period);
+                Self::deposit_event(Event::<T, I>::SchedulePeriodAdapted {
+                    schedule_period: adapted_period,
+                    activity,
+                });
+            }
+        }
+
         fn validate_voting_period(
             voting_period_in_blocks: BlockNumberFor<T>,
             schedule_period_in_blocks: BlockNumberFor<T>,
         ) -> DispatchResult {
             ensure!(
-                voting_period_in_blocks >= MIN_VOTING_PERIOD.into(),
+                voting_period_in_blocks >= T::MinVotingPeriod::get(),
                 Error::<T, I>::VotingPeriodIsTooShort
             );
             ensure!(
@@ -769,7 +1616,7 @@ pub mod pallet {
                 Error::<T, I>::VotingPeriodIsEqualOrLongerThanSchedulePeriod
             );
             ensure!(
-                voting_period_in_blocks <= MAX_VOTING_PERIOD.into(),
+                voting_period_in_blocks <= T::MaxVotingPeriod::get(),
                 Error::<T, I>::VotingPeriodIsTooLong
             );
             Ok(())
@@ -831,6 +1678,7 @@ pub mod pallet {
             let new_validator_account_id =
                 AVN::<T>::calculate_primary_validator_for_block(new_slot_number)?;
 
+            Self::adapt_schedule_period();
             let next_slot_start_block = safe_add_block_numbers::<BlockNumberFor<T>>(
                 Self::block_number_for_next_slot(),
                 Self::schedule_period(),
@@ -892,12 +1740,14 @@ pub mod pallet {
 
         pub fn create_root_lock_name(block_number: BlockNumberFor<T>) -> Vec<u8> {
             let mut name = b"create_summary::".to_vec();
+            name.push(T::InstanceId::get());
             name.extend_from_slice(&mut block_number.encode());
             name
         }
 
         pub fn get_advance_slot_lock_name(block_number: BlockNumberFor<T>) -> Vec<u8> {
             let mut name = b"advance_slot::".to_vec();
+            name.push(T::InstanceId::get());
             name.extend_from_slice(&mut block_number.encode());
             name
         }
@@ -1001,6 +1851,7 @@ pub mod pallet {
                     &reporter.account_id,
                     &vec![current_slot_validator.clone()],
                     SummaryOffenceType::NoSummaryCreated,
+                    SummaryOffenceSubject::Slot(Self::current_slot()),
                 );
 
                 Self::deposit_event(Event::<T, I>::SummaryNotPublishedOffence {
@@ -1177,13 +2028,19 @@ pub mod pallet {
             // the quorum, making ethereum-transactions reject it
             // In either case, we should not slash anyone.
             let function_name: &[u8] = BridgeContractMethod::PublishRoot.as_bytes();
-            let params = vec![(b"bytes32".to_vec(), root_data.root_hash.as_fixed_bytes().to_vec())];
+            let header_hash =
+                Self::root_header_hash(root_id.range, root_id.ingress_counter).unwrap_or_default();
+            let params = vec![
+                (b"bytes32".to_vec(), root_data.root_hash.as_fixed_bytes().to_vec()),
+                (b"bytes32".to_vec(), header_hash.as_fixed_bytes().to_vec()),
+            ];
             let tx_id = T::BridgeInterface::publish(function_name, &params, Self::pallet_id())
                 .map_err(|e| DispatchError::Other(e.into()))?;
 
-            <Roots<T, I>>::mutate(root_id.range, root_id.ingress_counter, |root| {
-                root.tx_id = Some(tx_id)
-            });
+            <Roots<T, I>>::try_mutate(root_id.range, root_id.ingress_counter, |root| {
+                root.as_mut().ok_or(Error::<T, I>::RootDataNotFound)?.tx_id = Some(tx_id);
+                Ok::<(), Error<T, I>>(())
+            })?;
 
             <TxIdToRoot<T, I>>::insert(tx_id, root_id);
 
@@ -1209,7 +2066,26 @@ pub mod pallet {
             let vote = Self::get_vote(root_id);
             ensure!(Self::can_end_vote(&vote), Error::<T, I>::ErrorEndingVotingPeriod);
 
+            if !vote.has_outcome() {
+                // Too few validators voted, which is more likely to be caused by network issues
+                // than by an invalid root.
+                let rounds = Self::inconclusive_voting_rounds(root_id);
+                if rounds < MAX_INCONCLUSIVE_VOTING_ROUNDS {
+                    return Self::reopen_voting(root_id, rounds.saturating_add(1))
+                }
+
+                Self::deposit_event(Event::<T, I>::InconclusiveVotingEscalated {
+                    root_id: *root_id,
+                    rounds,
+                });
+            }
+
             let root_is_approved = vote.is_approved();
+            let offence_subject = SummaryOffenceSubject::Root {
+                root_id: *root_id,
+                ayes: vote.ayes.len() as u32,
+                nays: vote.nays.len() as u32,
+            };
 
             let root_data = Self::try_get_root_data(&root_id)?;
             if root_is_approved {
@@ -1228,6 +2104,7 @@ pub mod pallet {
                     &reporter,
                     &vote.nays,
                     SummaryOffenceType::RejectedValidRoot,
+                    offence_subject,
                 );
 
                 let next_block_to_process = safe_add_block_numbers::<BlockNumberFor<T>>(
@@ -1236,16 +2113,20 @@ pub mod pallet {
                 )
                 .map_err(|_| Error::<T, I>::Overflow)?;
 
+                Self::check_root_range_continuity(root_id);
                 <NextBlockToProcess<T, I>>::put(next_block_to_process);
-                <Roots<T, I>>::mutate(root_id.range, root_id.ingress_counter, |root| {
-                    root.is_validated = true
-                });
+                <Roots<T, I>>::try_mutate(root_id.range, root_id.ingress_counter, |root| {
+                    root.as_mut().ok_or(Error::<T, I>::RootDataNotFound)?.is_validated = true;
+                    Ok::<(), Error<T, I>>(())
+                })?;
+                Self::index_validated_root(root_id);
                 <SlotOfLastPublishedSummary<T, I>>::put(Self::current_slot());
 
-                Self::deposit_event(Event::<T, I>::SummaryRootValidated {
+                Self::deposit_sequenced_event(|sequence| Event::<T, I>::SummaryRootValidated {
                     root_hash: root_data.root_hash,
                     ingress_counter: root_id.ingress_counter,
                     block_range: root_id.range,
+                    sequence,
                 });
             } else {
                 // We didn't get enough votes to approve this root
@@ -1256,16 +2137,19 @@ pub mod pallet {
                     &reporter,
                     &vec![root_creator],
                     SummaryOffenceType::CreatedInvalidRoot,
+                    offence_subject.clone(),
                 );
 
                 create_and_report_summary_offence::<T, I>(
                     &reporter,
                     &vote.ayes,
                     SummaryOffenceType::ApprovedInvalidRoot,
+                    offence_subject,
                 );
             }
 
             <PendingApproval<T, I>>::remove(root_id.range);
+            <InconclusiveVotingRounds<T, I>>::remove(root_id);
 
             // When we get here, the root's voting session has ended and it has been removed from
             // PendingApproval If the root was approved, it is now marked as validated.
@@ -1290,6 +2174,45 @@ pub mod pallet {
             Ok(())
         }
 
+        fn reopen_voting(root_id: &RootId<BlockNumberFor<T>>, round: u32) -> DispatchResult {
+            let current_block_number = <system::Pallet<T>>::block_number();
+            let voting_period_end =
+                safe_add_block_numbers(current_block_number, Self::voting_period())
+                    .map_err(|_| Error::<T, I>::Overflow)?;
+
+            <VotesRepository<T, I>>::insert(
+                root_id,
+                VotingSessionData::new(
+                    root_id.session_id(),
+                    AVN::<T>::quorum(),
+                    voting_period_end,
+                    current_block_number,
+                ),
+            );
+            <InconclusiveVotingRounds<T, I>>::insert(root_id, round);
+
+            Self::deposit_event(Event::<T, I>::VotingReopened { root_id: *root_id, round });
+
+            Ok(())
+        }
+
+        /// Returns the number of validators, the number of votes cast on `root_id` and the number
+        /// of validators reported for an offence if the voting on `root_id` ends with its current
+        /// votes
+        fn voting_weight_params(root_id: &RootId<BlockNumberFor<T>>) -> (u32, u32, u32) {
+            let vote = Self::get_vote(root_id);
+            let offenders = if vote.is_approved() {
+                vote.nays.len()
+            } else {
+                // The creator of the root is reported along with the validators who approved it
+                vote.ayes.len().saturating_add(1)
+            };
+
+            let voters = vote.ayes.len().saturating_add(vote.nays.len());
+
+            (AVN::<T>::validators().len() as u32, voters as u32, offenders as u32)
+        }
+
         fn can_end_vote(vote: &VotingSessionData<T::AccountId, BlockNumberFor<T>>) -> bool {
             return vote.has_outcome() ||
                 <system::Pallet<T>>::block_number() >= vote.end_of_voting_period
@@ -1314,6 +2237,10 @@ pub mod pallet {
                     return InvalidTransaction::Custom(ERROR_CODE_VALIDATOR_IS_NOT_PRIMARY).into()
                 }
 
+                if Self::block_header_hash_expired(*new_block_number) {
+                    return InvalidTransaction::Custom(ERROR_CODE_INVALID_ROOT_RANGE).into()
+                }
+
                 let signed_data = &(
                     Self::update_block_number_context(),
                     root_hash,
@@ -1373,6 +2300,23 @@ pub mod pallet {
             return InvalidTransaction::Call.into()
         }
 
+        /// Returns the hash of the header of `block_number`, if it is still known to the chain
+        fn block_header_hash(block_number: BlockNumberFor<T>) -> Option<H256> {
+            let hash = <system::Pallet<T>>::block_hash(block_number);
+            if hash == Default::default() {
+                return None
+            }
+
+            <[u8; 32]>::try_from(hash.as_ref()).ok().map(H256::from)
+        }
+
+        /// Returns true if the hash of `block_number` has been pruned by the system pallet, which
+        /// only keeps the hashes of the last `BlockHashCount` blocks
+        fn block_header_hash_expired(block_number: BlockNumberFor<T>) -> bool {
+            <system::Pallet<T>>::block_number().saturating_sub(block_number) >
+                T::BlockHashCount::get()
+        }
+
         fn empty_root() -> H256 {
             return H256::from_slice(&[0; 32])
         }
@@ -1390,11 +2334,77 @@ pub mod pallet {
         pub fn try_get_root_data(
             root_id: &RootId<BlockNumberFor<T>>,
         ) -> Result<RootData<T::AccountId>, Error<T, I>> {
-            if <Roots<T, I>>::contains_key(root_id.range, root_id.ingress_counter) {
-                return Ok(<Roots<T, I>>::get(root_id.range, root_id.ingress_counter))
+            <Roots<T, I>>::get(root_id.range, root_id.ingress_counter)
+                .ok_or(Error::<T, I>::RootDataNotFound)
+        }
+
+        fn end_block_key(block_number: BlockNumberFor<T>) -> [u8; 4] {
+            UniqueSaturatedInto::<u32>::unique_saturated_into(block_number).to_be_bytes()
+        }
+
+        pub(crate) fn index_validated_root(root_id: &RootId<BlockNumberFor<T>>) {
+            <ValidatedRootsByEndBlock<T, I>>::insert(
+                Self::end_block_key(root_id.range.to_block),
+                root_id,
+            );
+        }
+
+        /// Returns the validated root whose range includes `block_number`, if there is one
+        pub fn validated_root_for_block(
+            block_number: BlockNumberFor<T>,
+        ) -> Option<ValidatedRoot<BlockNumberFor<T>>> {
+            let key = Self::end_block_key(block_number);
+            // The first root ending at or after the block
+            let root_id = <ValidatedRootsByEndBlock<T, I>>::get(key).or_else(|| {
+                <ValidatedRootsByEndBlock<T, I>>::iter_from(
+                    <ValidatedRootsByEndBlock<T, I>>::hashed_key_for(key),
+                )
+                .next()
+                .map(|(_, root_id)| root_id)
+            })?;
+            if root_id.range.from_block > block_number {
+                return None
             }
 
-            Err(Error::<T, I>::RootDataNotFound)?
+            <Roots<T, I>>::get(root_id.range, root_id.ingress_counter)
+                .filter(|root| root.is_validated)
+                .map(|root| ValidatedRoot {
+                    from_block: root_id.range.from_block,
+                    to_block: root_id.range.to_block,
+                    root_hash: root.root_hash,
+                    header_hash: Self::root_header_hash(root_id.range, root_id.ingress_counter),
+                    tx_id: root.tx_id,
+                })
+        }
+
+        /// The validated roots whose range did not follow on from the previous one
+        pub fn root_range_anomalies(
+        ) -> Vec<(RootId<BlockNumberFor<T>>, RootRangeAnomaly<BlockNumberFor<T>>)> {
+            <RootRangeAnomalies<T, I>>::iter().collect()
+        }
+
+        /// Whether `account` is the validator of the current slot
+        pub fn is_slot_validator(account: &T::AccountId) -> bool {
+            Self::slot_validator().as_ref() == Some(account)
+        }
+
+        /// Records and alerts on a root about to be validated whose range doesn't start at the
+        /// next block to process. The root is still validated, as the votes approved it.
+        fn check_root_range_continuity(root_id: &RootId<BlockNumberFor<T>>) {
+            let next_block_to_process = <NextBlockToProcess<T, I>>::get();
+            if let Some(anomaly) = RootRangeAnomaly::of(&root_id.range, next_block_to_process) {
+                log::error!(
+                    "💔 Instance({}) Root {:?} validated with a range anomaly: {:?}",
+                    T::InstanceId::get(),
+                    root_id,
+                    anomaly
+                );
+                <RootRangeAnomalies<T, I>>::insert(root_id, anomaly);
+                Self::deposit_event(Event::<T, I>::RootRangeAnomalyDetected {
+                    root_id: *root_id,
+                    anomaly,
+                });
+            }
         }
 
         pub(crate) fn pallet_id() -> Vec<u8> {
@@ -1431,6 +2441,57 @@ impl<BlockNumber: AtLeast32Bit> RootRange<BlockNumber> {
     }
 }
 
+/// Shortest and longest schedule periods of an adaptive schedule period
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Debug, Eq, TypeInfo, MaxEncodedLen)]
+pub struct SchedulePeriodBounds<BlockNumber> {
+    pub min: BlockNumber,
+    pub max: BlockNumber,
+}
+
+/// The blocks of a slot, and how many of them were busy
+#[derive(Encode, Decode, Default, Clone, Copy, PartialEq, Debug, Eq, TypeInfo, MaxEncodedLen)]
+pub struct SlotActivity {
+    pub blocks: u32,
+    pub busy_blocks: u32,
+}
+
+impl SlotActivity {
+    fn record_block(&mut self, is_busy: bool) {
+        self.blocks = self.blocks.saturating_add(1);
+        if is_busy {
+            self.busy_blocks = self.busy_blocks.saturating_add(1);
+        }
+    }
+
+    /// The share of busy blocks. A slot without recorded blocks counts as idle.
+    pub fn busy_share(&self) -> Perbill {
+        Perbill::from_rational(self.busy_blocks, self.blocks.max(1))
+    }
+}
+
+/// A validated root whose range did not start at the next block to process
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Debug, Eq, TypeInfo, MaxEncodedLen)]
+pub enum RootRangeAnomaly<BlockNumber: AtLeast32Bit> {
+    /// The blocks before the root range that no root or skipped range covers
+    Gap(RootRange<BlockNumber>),
+    /// The blocks of the root range already covered by a previous root or skipped range
+    Overlap(RootRange<BlockNumber>),
+}
+
+impl<BlockNumber: AtLeast32Bit + Copy> RootRangeAnomaly<BlockNumber> {
+    /// The anomaly of validating `range` when the next block to process is `next_block`, if any
+    fn of(range: &RootRange<BlockNumber>, next_block: BlockNumber) -> Option<Self> {
+        if range.from_block > next_block {
+            Some(RootRangeAnomaly::Gap(RootRange::new(next_block, range.from_block - One::one())))
+        } else if range.from_block < next_block {
+            let overlap_end = range.to_block.min(next_block - One::one());
+            Some(RootRangeAnomaly::Overlap(RootRange::new(range.from_block, overlap_end)))
+        } else {
+            None
+        }
+    }
+}
+
 #[derive(Encode, Decode, Clone, PartialEq, Debug, Eq, TypeInfo, MaxEncodedLen)]
 pub struct RootData<AccountId> {
     pub root_hash: H256,
@@ -1471,6 +2532,27 @@ impl<AccountId> Default for RootData<AccountId> {
     }
 }
 
+/// The types of unsigned transactions whose submissions are limited separately
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Debug, Eq, TypeInfo, MaxEncodedLen)]
+pub enum SummaryUnsignedCall {
+    RecordSummaryCalculation,
+    Vote,
+    EndVotingPeriod,
+    AdvanceSlot,
+    Challenge,
+}
+
+/// A validated root and the range of blocks it summarises
+#[derive(Encode, Decode, Clone, PartialEq, Debug, Eq, TypeInfo)]
+pub struct ValidatedRoot<BlockNumber> {
+    pub from_block: BlockNumber,
+    pub to_block: BlockNumber,
+    pub root_hash: H256,
+    /// The hash of the parachain header at `to_block`, committed to alongside the root
+    pub header_hash: Option<H256>,
+    pub tx_id: Option<EthereumTransactionId>,
+}
+
 impl<T: Config<I>, I: 'static> BridgeInterfaceNotification for Pallet<T, I> {
     fn process_result(tx_id: u32, caller_id: Vec<u8>, succeeded: bool) -> DispatchResult {
         let matches_caller = if T::AutoSubmitSummaries::get() {
@@ -1482,12 +2564,13 @@ impl<T: Config<I>, I: 'static> BridgeInterfaceNotification for Pallet<T, I> {
         } else {
             caller_id == Self::pallet_id()
         };
-        if matches_caller && <TxIdToRoot<T, I>>::contains_key(tx_id) {
+        let maybe_root_id = if matches_caller { <TxIdToRoot<T, I>>::get(tx_id) } else { None };
+        if let Some(root_id) = maybe_root_id {
             if succeeded {
-                let root_id = <TxIdToRoot<T, I>>::get(tx_id);
-                <Roots<T, I>>::mutate(root_id.range, root_id.ingress_counter, |root| {
-                    root.is_finalised = true;
-                });
+                <Roots<T, I>>::try_mutate(root_id.range, root_id.ingress_counter, |root| {
+                    root.as_mut().ok_or(Error::<T, I>::RootDataNotFound)?.is_finalised = true;
+                    Ok::<(), Error<T, I>>(())
+                })?;
                 log::info!(
                     "✅  Transaction with ID {} was successfully published to Ethereum.",
                     tx_id
@@ -1539,4 +2622,52 @@ mod test_ocw_locks;
 #[path = "tests/anchor_tests.rs"]
 mod anchor_tests;
 
+#[cfg(test)]
+#[path = "tests/test_try_state.rs"]
+mod test_try_state;
+
+#[cfg(test)]
+#[path = "tests/test_validated_root.rs"]
+mod test_validated_root;
+
+#[cfg(test)]
+#[path = "tests/test_adaptive_schedule_period.rs"]
+mod test_adaptive_schedule_period;
+
+#[cfg(test)]
+#[path = "tests/test_force_skip_range.rs"]
+mod test_force_skip_range;
+
+#[cfg(test)]
+#[path = "tests/test_offence.rs"]
+mod test_offence;
+
+#[cfg(test)]
+#[path = "tests/test_migration.rs"]
+mod test_migration;
+
+#[cfg(test)]
+#[path = "tests/test_root_header_hash.rs"]
+mod test_root_header_hash;
+
+#[cfg(test)]
+#[path = "tests/test_root_range_anomalies.rs"]
+mod test_root_range_anomalies;
+
+#[cfg(test)]
+#[path = "tests/test_unsigned_rate_limit.rs"]
+mod test_unsigned_rate_limit;
+
+#[cfg(test)]
+#[path = "tests/test_inconclusive_voting.rs"]
+mod test_inconclusive_voting;
+
+#[cfg(test)]
+#[path = "tests/test_replace_vote.rs"]
+mod test_replace_vote;
+
+#[cfg(test)]
+#[path = "tests/test_cleanup.rs"]
+mod test_cleanup;
+
 // TODO: Add unit tests for setting schedule period and voting period
@@ -0,0 +1,318 @@
+use codec::DecodeAll;
+use frame_support::{
+    pallet_prelude::*,
+    storage::unhashed,
+    traits::{Get, GetStorageVersion, OnRuntimeUpgrade},
+    weights::Weight,
+};
+use frame_system::pallet_prelude::BlockNumberFor;
+use pallet_avn::migration::SteppedMigration;
+use sp_avn_common::bounds::MaximumValidatorsBound;
+use sp_runtime::traits::Saturating;
+
+use crate::*;
+
+#[cfg(feature = "try-runtime")]
+use sp_runtime::TryRuntimeError;
+
+/// Migrations run over several blocks by the pallet hooks
+pub type SteppedMigrations<T, I> = (
+    InitialiseSlots<T, I>,
+    RemoveDefaultRoots<T, I>,
+    AddReplacedVotes<T, I>,
+    IndexValidatedRoots<T, I>,
+);
+
+/// Initialises the slots of an instance deployed before they were introduced. The default periods
+/// are already expressed in the current block time, so the instance skips the scaling migration.
+pub struct InitialiseSlots<T, I>(PhantomData<(T, I)>);
+impl<T: Config<I>, I: 'static> SteppedMigration for InitialiseSlots<T, I> {
+    type Pallet = Pallet<T, I>;
+    type Cursor = ();
+
+    const FROM: u16 = 0;
+    const TO: u16 = 2;
+
+    fn step(_cursor: Option<()>, _limit: Weight) -> (Weight, Option<()>) {
+        let schedule_period_in_blocks = BlockNumberFor::<T>::from(DEFAULT_SCHEDULE_PERIOD)
+            .clamp(T::MinSchedulePeriod::get(), T::MaxSchedulePeriod::get());
+        <NextSlotAtBlock<T, I>>::put(schedule_period_in_blocks);
+        <SchedulePeriod<T, I>>::put(schedule_period_in_blocks);
+
+        let voting_period_in_blocks = T::MinVotingPeriod::get();
+        <VotingPeriod<T, I>>::put(voting_period_in_blocks);
+
+        if let Some(first_validator) =
+            AVN::<T>::validators().into_iter().map(|v| v.account_id).nth(0)
+        {
+            <CurrentSlotsValidator<T, I>>::put(first_validator);
+        }
+
+        (T::DbWeight::get().reads_writes(1, 4), None)
+    }
+}
+
+/// Removes the roots, transaction ids and pending approvals that were written with default values
+/// when the maps returned a default for missing keys. Roots are always recorded with their
+/// creator, so a root without one was created by updating a missing root. The cursor is the raw
+/// storage key of the last root checked.
+pub struct RemoveDefaultRoots<T, I>(PhantomData<(T, I)>);
+impl<T: Config<I>, I: 'static> SteppedMigration for RemoveDefaultRoots<T, I> {
+    type Pallet = Pallet<T, I>;
+    type Cursor = Vec<u8>;
+
+    const FROM: u16 = 2;
+    const TO: u16 = 3;
+
+    fn step(cursor: Option<Vec<u8>>, limit: Weight) -> (Weight, Option<Vec<u8>>) {
+        let weight_per_root = T::DbWeight::get().reads_writes(1, 1);
+        let mut used = Weight::zero();
+
+        let mut roots = match cursor {
+            Some(last_key) => Roots::<T, I>::iter_from(last_key),
+            None => Roots::<T, I>::iter(),
+        };
+
+        loop {
+            if used.saturating_add(weight_per_root).any_gt(limit) {
+                return (used, Some(roots.last_raw_key().to_vec()))
+            }
+
+            let (range, ingress_counter, root) = match roots.next() {
+                Some(entry) => entry,
+                None => break,
+            };
+            used = used.saturating_add(weight_per_root);
+
+            if root.added_by.is_none() {
+                Roots::<T, I>::remove(range, ingress_counter);
+            }
+        }
+
+        // These maps only hold the roots in flight, so they are cleaned up in a single step
+        for (tx_id, root_id) in TxIdToRoot::<T, I>::iter() {
+            used = used.saturating_add(T::DbWeight::get().reads_writes(2, 1));
+            if !Roots::<T, I>::contains_key(root_id.range, root_id.ingress_counter) {
+                TxIdToRoot::<T, I>::remove(tx_id);
+            }
+        }
+        for (range, ingress_counter) in PendingApproval::<T, I>::iter() {
+            used = used.saturating_add(T::DbWeight::get().reads_writes(2, 1));
+            if !Roots::<T, I>::contains_key(range, ingress_counter) {
+                PendingApproval::<T, I>::remove(range);
+            }
+        }
+
+        (used, None)
+    }
+}
+
+/// A voting session recorded before votes could be replaced
+#[derive(Decode)]
+struct OldVotingSessionData<AccountId, BlockNumber> {
+    voting_session_id: BoundedVec<u8, VotingSessionIdBound>,
+    threshold: u32,
+    ayes: BoundedVec<AccountId, MaximumValidatorsBound>,
+    nays: BoundedVec<AccountId, MaximumValidatorsBound>,
+    end_of_voting_period: BlockNumber,
+    created_at_block: BlockNumber,
+}
+
+impl<AccountId, BlockNumber> From<OldVotingSessionData<AccountId, BlockNumber>>
+    for VotingSessionData<AccountId, BlockNumber>
+{
+    fn from(old: OldVotingSessionData<AccountId, BlockNumber>) -> Self {
+        VotingSessionData {
+            voting_session_id: old.voting_session_id,
+            threshold: old.threshold,
+            ayes: old.ayes,
+            nays: old.nays,
+            end_of_voting_period: old.end_of_voting_period,
+            created_at_block: old.created_at_block,
+            replaced_votes: BoundedVec::default(),
+        }
+    }
+}
+
+/// Adds an empty list of replaced votes to the recorded voting sessions. The sessions of the roots
+/// pending approval are migrated in the first step, so they can be voted on while the rest of the
+/// history is migrated. The cursor is the raw storage key of the last session checked.
+pub struct AddReplacedVotes<T, I>(PhantomData<(T, I)>);
+impl<T: Config<I>, I: 'static> AddReplacedVotes<T, I> {
+    fn migrate_voting_session(root_id: &RootId<BlockNumberFor<T>>) {
+        let key = VotesRepository::<T, I>::hashed_key_for(root_id);
+        let Some(raw) = unhashed::get_raw(&key) else { return };
+
+        let already_migrated =
+            VotingSessionData::<T::AccountId, BlockNumberFor<T>>::decode_all(&mut &raw[..]).is_ok();
+        if already_migrated {
+            return
+        }
+
+        match OldVotingSessionData::<T::AccountId, BlockNumberFor<T>>::decode_all(&mut &raw[..]) {
+            Ok(old) => VotesRepository::<T, I>::insert(root_id, VotingSessionData::from(old)),
+            Err(_) => log::error!("💔 Unable to migrate the voting session of root {:?}", root_id),
+        }
+    }
+}
+
+impl<T: Config<I>, I: 'static> SteppedMigration for AddReplacedVotes<T, I> {
+    type Pallet = Pallet<T, I>;
+    type Cursor = Vec<u8>;
+
+    const FROM: u16 = 3;
+    const TO: u16 = 4;
+
+    fn step(cursor: Option<Vec<u8>>, limit: Weight) -> (Weight, Option<Vec<u8>>) {
+        let weight_per_session = T::DbWeight::get().reads_writes(1, 1);
+        let mut used = Weight::zero();
+
+        if cursor.is_none() {
+            for (range, ingress_counter) in PendingApproval::<T, I>::iter() {
+                used = used.saturating_add(weight_per_session);
+                Self::migrate_voting_session(&RootId::new(range, ingress_counter));
+            }
+        }
+
+        let mut root_ids = match cursor {
+            Some(last_key) => VotesRepository::<T, I>::iter_keys_from(last_key),
+            None => VotesRepository::<T, I>::iter_keys(),
+        };
+
+        loop {
+            if used.saturating_add(weight_per_session).any_gt(limit) {
+                return (used, Some(root_ids.last_raw_key().to_vec()))
+            }
+
+            let root_id = match root_ids.next() {
+                Some(root_id) => root_id,
+                None => break,
+            };
+            used = used.saturating_add(weight_per_session);
+            Self::migrate_voting_session(&root_id);
+        }
+
+        (used, None)
+    }
+}
+
+/// Indexes the validated roots by their last block, for `validated_root_for_block`. The cursor is
+/// the raw storage key of the last root checked.
+pub struct IndexValidatedRoots<T, I>(PhantomData<(T, I)>);
+impl<T: Config<I>, I: 'static> SteppedMigration for IndexValidatedRoots<T, I> {
+    type Pallet = Pallet<T, I>;
+    type Cursor = Vec<u8>;
+
+    const FROM: u16 = 4;
+    const TO: u16 = 5;
+
+    fn step(cursor: Option<Vec<u8>>, limit: Weight) -> (Weight, Option<Vec<u8>>) {
+        let weight_per_root = T::DbWeight::get().reads_writes(1, 1);
+        let mut used = Weight::zero();
+
+        let mut roots = match cursor {
+            Some(last_key) => Roots::<T, I>::iter_from(last_key),
+            None => Roots::<T, I>::iter(),
+        };
+
+        loop {
+            if used.saturating_add(weight_per_root).any_gt(limit) {
+                return (used, Some(roots.last_raw_key().to_vec()))
+            }
+
+            let (range, ingress_counter, root) = match roots.next() {
+                Some(entry) => entry,
+                None => break,
+            };
+            used = used.saturating_add(weight_per_root);
+
+            if root.is_validated {
+                Pallet::<T, I>::index_validated_root(&RootId::new(range, ingress_counter));
+            }
+        }
+
+        (used, None)
+    }
+}
+
+/// Scales the periods stored in blocks by `Factor`, to keep their duration when the block time is
+/// divided by `Factor`. The slot in progress keeps its end time.
+pub struct ScaleBlockPeriods<T, I, Factor>(PhantomData<(T, I, Factor)>);
+impl<T: Config<I>, I: 'static, Factor: Get<u32>> OnRuntimeUpgrade
+    for ScaleBlockPeriods<T, I, Factor>
+{
+    fn on_runtime_upgrade() -> Weight {
+        let current = Pallet::<T, I>::current_storage_version();
+        let onchain = Pallet::<T, I>::on_chain_storage_version();
+
+        log::info!(
+            "ℹ️  Summary `ScaleBlockPeriods` invoked with current storage version {:?} / onchain {:?}",
+            current,
+            onchain
+        );
+
+        if onchain == 1 {
+            return scale_block_periods::<T, I>(Factor::get())
+        }
+
+        T::DbWeight::get().reads(1)
+    }
+
+    #[cfg(feature = "try-runtime")]
+    fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+        Ok((
+            Pallet::<T, I>::on_chain_storage_version(),
+            SchedulePeriod::<T, I>::get(),
+            VotingPeriod::<T, I>::get(),
+        )
+            .encode())
+    }
+
+    #[cfg(feature = "try-runtime")]
+    fn post_upgrade(state: Vec<u8>) -> Result<(), TryRuntimeError> {
+        let (onchain, schedule_period, voting_period): (
+            StorageVersion,
+            BlockNumberFor<T>,
+            BlockNumberFor<T>,
+        ) = Decode::decode(&mut &state[..]).map_err(|_| "Invalid pre upgrade state")?;
+
+        if onchain == 1 {
+            let factor: BlockNumberFor<T> = Factor::get().into();
+            ensure!(
+                SchedulePeriod::<T, I>::get() == schedule_period.saturating_mul(factor),
+                "Schedule period not scaled"
+            );
+            ensure!(
+                VotingPeriod::<T, I>::get() == voting_period.saturating_mul(factor),
+                "Voting period not scaled"
+            );
+        }
+
+        Ok(())
+    }
+}
+
+pub fn scale_block_periods<T: Config<I>, I: 'static>(scaling_factor: u32) -> Weight {
+    let factor: BlockNumberFor<T> = scaling_factor.into();
+
+    SchedulePeriod::<T, I>::mutate(|period| *period = period.saturating_mul(factor));
+    VotingPeriod::<T, I>::mutate(|period| *period = period.saturating_mul(factor));
+
+    let now = <frame_system::Pallet<T>>::block_number();
+    NextSlotAtBlock::<T, I>::mutate(|next_slot_at| {
+        if *next_slot_at > now {
+            *next_slot_at =
+                now.saturating_add(next_slot_at.saturating_sub(now).saturating_mul(factor));
+        }
+    });
+
+    StorageVersion::new(2).put::<Pallet<T, I>>();
+
+    log::info!(
+        "✅ Summary periods scaled by {:?}, schedule period is now {:?} blocks",
+        scaling_factor,
+        SchedulePeriod::<T, I>::get()
+    );
+
+    T::DbWeight::get().reads_writes(4, 4)
+}
@@ -7,12 +7,17 @@ use sp_staking::{
 };
 
 use codec::{Decode, Encode, MaxEncodedLen};
+use frame_system::pallet_prelude::BlockNumberFor;
+use pallet_avn::{ReportedSlotOffence, ReportedSlotOffences};
 use pallet_session::{historical::IdentificationTuple, Config as SessionConfig};
-use sp_core::Get;
-use sp_runtime::{scale_info::TypeInfo, traits::Convert};
+use sp_core::{Get, H256};
+use sp_runtime::{
+    scale_info::TypeInfo,
+    traits::{AtLeast32Bit, Convert, Saturating},
+};
 use sp_staking::offence::ReportOffence;
 use sp_std::prelude::*;
-#[derive(PartialEq, Eq, Clone, Debug, Encode, Decode, MaxEncodedLen, TypeInfo)]
+#[derive(PartialEq, Eq, PartialOrd, Ord, Clone, Debug, Encode, Decode, MaxEncodedLen, TypeInfo)]
 pub enum SummaryOffenceType {
     InvalidSignatureSubmitted,
     InvalidVoteSubmitted,
@@ -21,14 +26,58 @@ pub enum SummaryOffenceType {
     RejectedValidRoot,
     CreatedInvalidRoot,
     ApprovedInvalidRoot,
+    /// Submitted as many unsigned transactions of a type as allowed in several consecutive windows
+    UnsignedRateLimitReached,
+}
+use crate::{Event, OffenceEvidence, RootId};
+
+/// What the offenders of a summary offence acted on
+#[derive(PartialEq, Eq, Clone, Debug, Encode, Decode, MaxEncodedLen, TypeInfo)]
+pub enum SummaryOffenceSubject<BlockNumber: AtLeast32Bit> {
+    /// A root, with the votes it had received when its voting session ended
+    Root { root_id: RootId<BlockNumber>, ayes: u32, nays: u32 },
+    /// The slot in which the offender was the slot validator
+    Slot(BlockNumber),
+    /// The unsigned transactions submitted by the offender
+    UnsignedSubmissions,
+}
+
+/// The facts of a reported offence, kept so that disputes about it can be reviewed by governance
+#[derive(PartialEq, Eq, Clone, Debug, Encode, Decode, MaxEncodedLen, TypeInfo)]
+pub struct SummaryOffenceEvidence<AccountId, BlockNumber: AtLeast32Bit> {
+    pub offence_type: SummaryOffenceType,
+    pub subject: SummaryOffenceSubject<BlockNumber>,
+    pub reporter: AccountId,
+    pub reported_at: BlockNumber,
+}
+
+impl<AccountId: Encode, BlockNumber: AtLeast32Bit + Encode>
+    SummaryOffenceEvidence<AccountId, BlockNumber>
+{
+    /// The key of the evidence in storage, referenced by the offence event
+    pub fn hash(&self) -> H256 {
+        H256::from(sp_io::hashing::blake2_256(&self.encode()))
+    }
+}
+
+/// When a summary offence happened. pallet-offences accepts a single report of an offender per
+/// time slot, so offences of different types or from different summary instances in the same
+/// session are reported separately, unless they are about slots in the same blocks (see
+/// `pallet_avn::ReportedSlotOffences`).
+#[derive(PartialEq, Eq, PartialOrd, Ord, Clone, Debug, Encode, Decode, MaxEncodedLen, TypeInfo)]
+pub struct SummaryOffenceTimeSlot {
+    pub session_index: SessionIndex,
+    pub instance_id: u8,
+    pub offence_type: SummaryOffenceType,
 }
-use crate::Event;
 
 #[derive(PartialEq, Clone, Debug, Encode, Decode, MaxEncodedLen, TypeInfo)]
 pub struct SummaryOffence<Offender> {
     /// The current session index in which we report the validators that submitted an invalid
     /// ethereum log.
     pub session_index: SessionIndex,
+    /// The summary instance the offence was committed in
+    pub instance_id: u8,
     /// The size of the validator set in current session/era.
     pub validator_set_count: u32,
     /// Authorities that validated the invalid log.
@@ -39,7 +88,7 @@ pub struct SummaryOffence<Offender> {
 
 impl<Offender: Clone> Offence<Offender> for SummaryOffence<Offender> {
     const ID: Kind = *b"summary::offence";
-    type TimeSlot = SessionIndex;
+    type TimeSlot = SummaryOffenceTimeSlot;
 
     fn offenders(&self) -> Vec<Offender> {
         self.offenders.clone()
@@ -54,7 +103,11 @@ impl<Offender: Clone> Offence<Offender> for SummaryOffence<Offender> {
     }
 
     fn time_slot(&self) -> Self::TimeSlot {
-        self.session_index
+        SummaryOffenceTimeSlot {
+            session_index: self.session_index,
+            instance_id: self.instance_id,
+            offence_type: self.offence_type.clone(),
+        }
     }
 
     fn slash_fraction(&self, _offenders: u32) -> Perbill {
@@ -74,39 +127,95 @@ pub fn create_offenders_identification<T: crate::Config<I>, I: 'static>(
     return offenders
 }
 
+/// The blocks of the current slot of the instance, up to the current block
+fn current_slot_blocks<T: crate::Config<I>, I: 'static>() -> ReportedSlotOffence<BlockNumberFor<T>>
+{
+    ReportedSlotOffence {
+        instance_id: T::InstanceId::get(),
+        from_block: crate::Pallet::<T, I>::block_number_for_next_slot()
+            .saturating_sub(crate::Pallet::<T, I>::schedule_period()),
+        to_block: <frame_system::Pallet<T>>::block_number(),
+    }
+}
+
+/// Whether `offender` has already been reported by another summary instance for an offence of
+/// `offence_type` about a slot overlapping `slot_blocks`
+fn reported_by_another_instance<T: crate::Config<I>, I: 'static>(
+    offender: &T::AccountId,
+    offence_type: &SummaryOffenceType,
+    slot_blocks: &ReportedSlotOffence<BlockNumberFor<T>>,
+) -> bool {
+    <ReportedSlotOffences<T>>::get(offender, offence_type.clone() as u8).map_or(false, |reported| {
+        reported.instance_id != slot_blocks.instance_id &&
+            reported.from_block <= slot_blocks.to_block &&
+            slot_blocks.from_block <= reported.to_block
+    })
+}
+
 pub fn create_and_report_summary_offence<T: crate::Config<I>, I: 'static>(
     reporter: &T::AccountId,
     offenders_accounts: &Vec<T::AccountId>,
     offence_type: SummaryOffenceType,
+    subject: SummaryOffenceSubject<BlockNumberFor<T>>,
 ) {
-    let offenders = create_offenders_identification::<T, I>(offenders_accounts);
+    let slot_blocks = match &subject {
+        SummaryOffenceSubject::Slot(_) => Some(current_slot_blocks::<T, I>()),
+        _ => None,
+    };
+    let offenders_accounts = match &slot_blocks {
+        Some(slot_blocks) => offenders_accounts
+            .iter()
+            .filter(|offender| {
+                !reported_by_another_instance::<T, I>(offender, &offence_type, slot_blocks)
+            })
+            .cloned()
+            .collect::<Vec<T::AccountId>>(),
+        None => offenders_accounts.clone(),
+    };
+    let offenders = create_offenders_identification::<T, I>(&offenders_accounts);
 
     if !offenders.is_empty() {
-        let invalid_event_offence = SummaryOffence {
+        let offence = SummaryOffence {
             session_index: <pallet_session::Pallet<T>>::current_index(),
+            instance_id: T::InstanceId::get(),
             validator_set_count: crate::AVN::<T>::validators().len() as u32,
             offenders: offenders.clone(),
             offence_type: offence_type.clone(),
         };
 
-        if !T::ReportSummaryOffence::is_known_offence(
-            &invalid_event_offence.offenders(),
-            &invalid_event_offence.time_slot(),
-        ) {
-            let reporters = vec![reporter.clone()];
-            if let Err(e) =
-                T::ReportSummaryOffence::report_offence(reporters, invalid_event_offence)
-            {
-                log::info!(
-                    target: "pallet-summary",
-                    "ℹ️ Instance({}) Error while reporting offence: {:?}. Stored in deferred",
-                    T::InstanceId::get(), e
-                );
-            }
-            <crate::Pallet<T, I>>::deposit_event(Event::<T, I>::SummaryOffenceReported {
-                offence_type,
-                offenders,
-            });
+        // The offences pallet rejects offences already reported in the same time slot
+        match T::ReportSummaryOffence::report_offence(vec![reporter.clone()], offence) {
+            Ok(()) => {
+                if let Some(slot_blocks) = slot_blocks {
+                    for offender in offenders_accounts.iter() {
+                        <ReportedSlotOffences<T>>::insert(
+                            offender,
+                            offence_type.clone() as u8,
+                            slot_blocks.clone(),
+                        );
+                    }
+                }
+
+                let evidence = SummaryOffenceEvidence {
+                    offence_type: offence_type.clone(),
+                    subject,
+                    reporter: reporter.clone(),
+                    reported_at: <frame_system::Pallet<T>>::block_number(),
+                };
+                let evidence_hash = evidence.hash();
+                <OffenceEvidence<T, I>>::insert(evidence_hash, evidence);
+
+                <crate::Pallet<T, I>>::deposit_event(Event::<T, I>::SummaryOffenceReported {
+                    offence_type,
+                    offenders,
+                    evidence: evidence_hash,
+                })
+            },
+            Err(e) => log::info!(
+                target: "pallet-summary",
+                "ℹ️ Instance({}) Offence {:?} not reported: {:?}",
+                T::InstanceId::get(), offence_type, e
+            ),
         }
     }
 }
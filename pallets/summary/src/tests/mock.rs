@@ -1,7 +1,10 @@
 // Copyright 2022 Aventus Network Services (UK) Ltd.
 
 pub use crate::{self as summary, *};
-use frame_support::parameter_types;
+use frame_support::{
+    parameter_types,
+    traits::{ConstU128, ConstU32, EitherOfDiverse, SortedMembers},
+};
 use sp_state_machine::BasicExternalities;
 
 use frame_system as system;
@@ -33,6 +36,7 @@ use sp_staking::{
     SessionIndex,
 };
 use std::{cell::RefCell, convert::From, sync::Arc};
+use summary::offence::SummaryOffenceTimeSlot;
 use system::pallet_prelude::BlockNumberFor;
 
 pub const APPROVE_ROOT: bool = true;
@@ -46,6 +50,7 @@ pub type BlockNumber = BlockNumberFor<TestRuntime>;
 impl Summary {
     pub fn get_root_data(root_id: &RootId<BlockNumber>) -> RootData<AccountId> {
         return Roots::<TestRuntime>::get(root_id.range, root_id.ingress_counter)
+            .expect("Root exists")
     }
 
     pub fn insert_root_hash(
@@ -71,8 +76,11 @@ impl Summary {
 
     pub fn set_root_as_validated(root_id: &RootId<BlockNumber>) {
         Roots::<TestRuntime>::mutate(root_id.range, root_id.ingress_counter, |root| {
-            root.is_validated = true
+            if let Some(root) = root {
+                root.is_validated = true
+            }
         });
+        Self::index_validated_root(root_id);
     }
 
     pub fn set_next_block_to_process(next_block_number_to_process: BlockNumber) {
@@ -163,6 +171,12 @@ impl Summary {
         )
     }
 
+    pub fn offence_evidence_of_type(offence_type: SummaryOffenceType) -> Option<H256> {
+        return OffenceEvidence::<TestRuntime>::iter()
+            .find(|(_, evidence)| evidence.offence_type == offence_type)
+            .map(|(hash, _)| hash)
+    }
+
     pub fn total_events_emitted() -> usize {
         return System::events().len()
     }
@@ -213,6 +227,7 @@ impl Summary {
                 reporters,
                 SummaryOffence {
                     session_index: _,
+                    instance_id: _,
                     validator_set_count,
                     offenders,
                     offence_type}
@@ -234,6 +249,7 @@ impl Summary {
                 _,
                 SummaryOffence {
                     session_index: _,
+                    instance_id: _,
                     validator_set_count: _,
                     offenders: _,
                     offence_type}
@@ -246,6 +262,7 @@ impl Summary {
 impl AnchorSummary {
     pub fn get_root_data(root_id: &RootId<BlockNumber>) -> RootData<AccountId> {
         return Roots::<TestRuntime, Instance1>::get(root_id.range, root_id.ingress_counter)
+            .expect("Root exists")
     }
 
     pub fn insert_root_hash(
@@ -271,8 +288,11 @@ impl AnchorSummary {
 
     pub fn set_root_as_validated(root_id: &RootId<BlockNumber>) {
         Roots::<TestRuntime, Instance1>::mutate(root_id.range, root_id.ingress_counter, |root| {
-            root.is_validated = true
+            if let Some(root) = root {
+                root.is_validated = true
+            }
         });
+        Self::index_validated_root(root_id);
     }
 
     pub fn set_next_block_to_process(next_block_number_to_process: BlockNumber) {
@@ -336,16 +356,45 @@ frame_support::construct_runtime!(
     pub enum TestRuntime
     {
         System: frame_system::{Pallet, Call, Config<T>, Storage, Event<T>},
+        Balances: pallet_balances::{Pallet, Call, Storage, Config<T>, Event<T>},
         Session: pallet_session::{Pallet, Call, Storage, Event, Config<T>},
-        AVN: pallet_avn::{Pallet, Storage, Event},
+        AVN: pallet_avn::{Pallet, Storage, Event<T>},
         Summary: summary::{Pallet, Call, Storage, Event<T>, Config<T>},
         Historical: pallet_session::historical::{Pallet, Storage},
         EthBridge: pallet_eth_bridge::{Pallet, Call, Storage, Event<T>},
         Timestamp: pallet_timestamp::{Pallet, Call, Storage, Inherent},
         AnchorSummary: summary::<Instance1>::{Pallet, Call, Storage, Event<T>, Config<T>},
+        Preimage: pallet_preimage,
+        Scheduler: pallet_scheduler::{Pallet, Call, Storage, Event<T>},
     }
 );
 
+parameter_types! {
+    pub MaximumSchedulerWeight: frame_support::weights::Weight = frame_support::weights::Weight::MAX;
+}
+
+impl pallet_preimage::Config for TestRuntime {
+    type RuntimeEvent = RuntimeEvent;
+    type WeightInfo = ();
+    type Currency = Balances;
+    type ManagerOrigin = frame_system::EnsureRoot<AccountId>;
+    type BaseDeposit = ConstU128<0>;
+    type ByteDeposit = ConstU128<0>;
+}
+
+impl pallet_scheduler::Config for TestRuntime {
+    type RuntimeEvent = RuntimeEvent;
+    type RuntimeOrigin = RuntimeOrigin;
+    type PalletsOrigin = OriginCaller;
+    type RuntimeCall = RuntimeCall;
+    type MaximumWeight = MaximumSchedulerWeight;
+    type ScheduleOrigin = frame_system::EnsureRoot<AccountId>;
+    type MaxScheduledPerBlock = ConstU32<100>;
+    type WeightInfo = ();
+    type OriginPrivilegeCmp = frame_support::traits::EqualPrivilegeOnly;
+    type Preimages = Preimage;
+}
+
 parameter_types! {
     pub const AdvanceSlotGracePeriod: u64 = 5;
     pub const MinBlockAge: u64 = 5;
@@ -373,6 +422,8 @@ thread_local! {
     static ETH_PUBLIC_KEY_VALID: RefCell<bool> = RefCell::new(true);
 
     static MOCK_RECOVERED_ACCOUNT_ID: RefCell<AccountId> = RefCell::new(FIRST_VALIDATOR_INDEX);
+
+    pub static PUBLISHED_ROOT_PARAMS: RefCell<Vec<(Vec<u8>, Vec<u8>)>> = RefCell::new(vec![]);
 }
 
 impl Config for TestRuntime {
@@ -385,6 +436,35 @@ impl Config for TestRuntime {
     type BridgeInterface = EthBridge;
     type AutoSubmitSummaries = AutoSubmitSummaries;
     type InstanceId = InstanceId;
+    type AdminOrigin = EitherOfDiverse<
+        frame_system::EnsureRoot<Self::AccountId>,
+        frame_system::EnsureSignedBy<Admin, Self::AccountId>,
+    >;
+    type RuntimeCall = RuntimeCall;
+    type Scheduler = Scheduler;
+    type Preimages = Preimage;
+    type PalletsOrigin = OriginCaller;
+    type AdminChangeDelay = ConstU64<10>;
+    type MinSchedulePeriod = ConstU64<120>;
+    type MaxSchedulePeriod = ConstU64<57600>;
+    type MinVotingPeriod = ConstU64<100>;
+    type MaxVotingPeriod = ConstU64<28800>;
+    type OffenceEvidenceRetention = ConstU64<OFFENCE_EVIDENCE_RETENTION>;
+    type VotingParticipationHandler = ();
+    type UnsignedSubmissionWindow = ConstU64<10>;
+    type MaxUnsignedSubmissions = ConstU32<5>;
+    type MaxUnsignedVotes = ConstU32<20>;
+    type UnsignedAbuseThreshold = ConstU32<3>;
+}
+
+pub const ADMIN_ACCOUNT: AccountId = 100;
+pub const OFFENCE_EVIDENCE_RETENTION: u64 = 1000;
+
+pub struct Admin;
+impl SortedMembers<AccountId> for Admin {
+    fn sorted_members() -> Vec<AccountId> {
+        vec![ADMIN_ACCOUNT]
+    }
 }
 
 type AvnAnchorSummary = summary::Instance1;
@@ -398,6 +478,25 @@ impl Config<AvnAnchorSummary> for TestRuntime {
     type BridgeInterface = EthBridge;
     type AutoSubmitSummaries = DoNotSubmit;
     type InstanceId = AnchorInstanceId;
+    type AdminOrigin = EitherOfDiverse<
+        frame_system::EnsureRoot<Self::AccountId>,
+        frame_system::EnsureSignedBy<Admin, Self::AccountId>,
+    >;
+    type RuntimeCall = RuntimeCall;
+    type Scheduler = Scheduler;
+    type Preimages = Preimage;
+    type PalletsOrigin = OriginCaller;
+    type AdminChangeDelay = ConstU64<10>;
+    type MinSchedulePeriod = ConstU64<20>;
+    type MaxSchedulePeriod = ConstU64<57600>;
+    type MinVotingPeriod = ConstU64<10>;
+    type MaxVotingPeriod = ConstU64<28800>;
+    type OffenceEvidenceRetention = ConstU64<OFFENCE_EVIDENCE_RETENTION>;
+    type VotingParticipationHandler = ();
+    type UnsignedSubmissionWindow = ConstU64<10>;
+    type MaxUnsignedSubmissions = ConstU32<5>;
+    type MaxUnsignedVotes = ConstU32<20>;
+    type UnsignedAbuseThreshold = ConstU32<3>;
 }
 
 impl<LocalCall> system::offchain::SendTransactionTypes<LocalCall> for TestRuntime
@@ -433,7 +532,7 @@ impl system::Config for TestRuntime {
     type BlockHashCount = BlockHashCount;
     type Version = ();
     type PalletInfo = PalletInfo;
-    type AccountData = ();
+    type AccountData = pallet_balances::AccountData<u128>;
     type OnNewAccount = ();
     type OnKilledAccount = ();
     type SystemWeightInfo = ();
@@ -463,6 +562,23 @@ impl pallet_eth_bridge::Config for TestRuntime {
     type ReportCorroborationOffence = OffenceHandler;
     type ProcessedEventsChecker = ();
     type EthereumEventsFilter = ();
+    type AdminOrigin = frame_system::EnsureRoot<Self::AccountId>;
+}
+
+impl pallet_balances::Config for TestRuntime {
+    type MaxLocks = ConstU32<1024>;
+    type MaxReserves = ();
+    type ReserveIdentifier = [u8; 8];
+    type Balance = u128;
+    type RuntimeEvent = RuntimeEvent;
+    type DustRemoval = ();
+    type ExistentialDeposit = ConstU128<1>;
+    type AccountStore = System;
+    type WeightInfo = ();
+    type RuntimeHoldReason = ();
+    type FreezeIdentifier = ();
+    type MaxHolds = ();
+    type MaxFreezes = ();
 }
 
 impl pallet_timestamp::Config for TestRuntime {
@@ -489,10 +605,11 @@ parameter_types! {
 impl BridgeInterface for TestRuntime {
     fn publish(
         function_name: &[u8],
-        _params: &[(Vec<u8>, Vec<u8>)],
+        params: &[(Vec<u8>, Vec<u8>)],
         _caller_id: Vec<u8>,
     ) -> Result<u32, DispatchError> {
         if function_name == BridgeContractMethod::PublishRoot.as_bytes() {
+            PUBLISHED_ROOT_PARAMS.with(|p| *p.borrow_mut() = params.to_vec());
             return Ok(INITIAL_TRANSACTION_ID)
         }
         Err(Error::<TestRuntime>::ErrorPublishingSummary.into())
@@ -562,7 +679,10 @@ impl ReportOffence<AccountId, IdentificationTuple, Offence> for OffenceHandler {
         Ok(())
     }
 
-    fn is_known_offence(_offenders: &[IdentificationTuple], _time_slot: &SessionIndex) -> bool {
+    fn is_known_offence(
+        _offenders: &[IdentificationTuple],
+        _time_slot: &SummaryOffenceTimeSlot,
+    ) -> bool {
         false
     }
 }
@@ -0,0 +1,260 @@
+// Copyright 2024 Aventus Network Services (UK) Ltd.
+#![cfg(test)]
+
+use crate::{mock::*, *};
+use frame_support::{
+    assert_noop, assert_ok,
+    dispatch::DispatchClass,
+    traits::{Get, Hooks},
+};
+use sp_runtime::traits::BadOrigin;
+use system::RawOrigin;
+
+const SCHEDULE_PERIOD: BlockNumber = 400;
+const VOTING_PERIOD: BlockNumber = 100;
+const SLOT_END: BlockNumber = 1000;
+
+fn bounds(min: BlockNumber, max: BlockNumber) -> SchedulePeriodBounds<BlockNumber> {
+    SchedulePeriodBounds { min, max }
+}
+
+fn set_bounds(bounds: Option<SchedulePeriodBounds<BlockNumber>>) -> DispatchResult {
+    Summary::set_adaptive_schedule_period(RawOrigin::Root.into(), bounds)
+}
+
+fn setup() {
+    Summary::set_schedule_and_voting_periods(SCHEDULE_PERIOD, VOTING_PERIOD);
+    assert_ok!(set_bounds(Some(bounds(200, 500))));
+}
+
+fn advance_slot_with_activity(activity: SlotActivity) {
+    let validator = get_validator(FIRST_VALIDATOR_INDEX);
+    System::set_block_number(SLOT_END);
+    Summary::set_next_slot_block_number(SLOT_END);
+    Summary::set_current_slot_validator(validator.account_id);
+    Summary::set_current_slot(1);
+    Summary::set_previous_summary_slot(1);
+    CurrentSlotActivity::<TestRuntime>::put(activity);
+
+    assert_ok!(Summary::update_slot_number(validator));
+}
+
+fn activity(blocks: u32, busy_blocks: u32) -> SlotActivity {
+    SlotActivity { blocks, busy_blocks }
+}
+
+mod set_adaptive_schedule_period {
+    use super::*;
+
+    #[test]
+    fn sets_the_bounds() {
+        let mut ext = ExtBuilder::build_default().with_validators().as_externality();
+        ext.execute_with(|| {
+            setup();
+
+            assert_eq!(Summary::schedule_period_bounds(), Some(bounds(200, 500)));
+            assert!(Summary::emitted_event(&RuntimeEvent::Summary(
+                crate::Event::<TestRuntime>::AdaptiveSchedulePeriodSet {
+                    bounds: Some(bounds(200, 500))
+                }
+            )));
+        });
+    }
+
+    #[test]
+    fn can_fix_the_schedule_period_again() {
+        let mut ext = ExtBuilder::build_default().with_validators().as_externality();
+        ext.execute_with(|| {
+            setup();
+            CurrentSlotActivity::<TestRuntime>::put(activity(10, 1));
+
+            assert_ok!(set_bounds(None));
+
+            assert_eq!(Summary::schedule_period_bounds(), None);
+            assert_eq!(Summary::slot_activity(), SlotActivity::default());
+        });
+    }
+
+    #[test]
+    fn rejects_inverted_bounds() {
+        let mut ext = ExtBuilder::build_default().with_validators().as_externality();
+        ext.execute_with(|| {
+            Summary::set_schedule_and_voting_periods(SCHEDULE_PERIOD, VOTING_PERIOD);
+
+            assert_noop!(
+                set_bounds(Some(bounds(500, 200))),
+                Error::<TestRuntime>::InvalidSchedulePeriodBounds
+            );
+        });
+    }
+
+    #[test]
+    fn rejects_bounds_outside_the_accepted_schedule_periods() {
+        let mut ext = ExtBuilder::build_default().with_validators().as_externality();
+        ext.execute_with(|| {
+            Summary::set_schedule_and_voting_periods(SCHEDULE_PERIOD, VOTING_PERIOD);
+            let min_schedule_period: BlockNumber =
+                <TestRuntime as crate::Config>::MinSchedulePeriod::get();
+
+            assert_noop!(
+                set_bounds(Some(bounds(min_schedule_period - 1, 500))),
+                Error::<TestRuntime>::SchedulePeriodIsTooShort
+            );
+        });
+    }
+
+    #[test]
+    fn rejects_a_minimum_not_longer_than_the_voting_period() {
+        let mut ext = ExtBuilder::build_default().with_validators().as_externality();
+        ext.execute_with(|| {
+            Summary::set_schedule_and_voting_periods(SCHEDULE_PERIOD, 200);
+
+            assert_noop!(
+                set_bounds(Some(bounds(200, 500))),
+                Error::<TestRuntime>::VotingPeriodIsEqualOrLongerThanSchedulePeriod
+            );
+        });
+    }
+
+    #[test]
+    fn can_only_be_called_by_the_admin_origin() {
+        let mut ext = ExtBuilder::build_default().with_validators().as_externality();
+        ext.execute_with(|| {
+            Summary::set_schedule_and_voting_periods(SCHEDULE_PERIOD, VOTING_PERIOD);
+
+            assert_noop!(
+                Summary::set_adaptive_schedule_period(
+                    RuntimeOrigin::signed(get_validator(FIRST_VALIDATOR_INDEX).account_id),
+                    Some(bounds(200, 500))
+                ),
+                BadOrigin
+            );
+        });
+    }
+}
+
+mod adapting_the_schedule_period {
+    use super::*;
+
+    #[test]
+    fn shortens_it_after_a_busy_slot() {
+        let mut ext = ExtBuilder::build_default().with_validators().as_externality();
+        ext.execute_with(|| {
+            setup();
+
+            advance_slot_with_activity(activity(10, 8));
+
+            assert_eq!(Summary::schedule_period(), 300);
+            assert_eq!(Summary::block_number_for_next_slot(), SLOT_END + 300);
+            assert!(Summary::emitted_event(&RuntimeEvent::Summary(
+                crate::Event::<TestRuntime>::SchedulePeriodAdapted {
+                    schedule_period: 300,
+                    activity: activity(10, 8),
+                }
+            )));
+        });
+    }
+
+    #[test]
+    fn lengthens_it_after_an_idle_slot() {
+        let mut ext = ExtBuilder::build_default().with_validators().as_externality();
+        ext.execute_with(|| {
+            setup();
+
+            advance_slot_with_activity(activity(10, 0));
+
+            assert_eq!(Summary::schedule_period(), 500);
+        });
+    }
+
+    #[test]
+    fn keeps_it_after_a_moderately_busy_slot() {
+        let mut ext = ExtBuilder::build_default().with_validators().as_externality();
+        ext.execute_with(|| {
+            setup();
+
+            advance_slot_with_activity(activity(10, 3));
+
+            assert_eq!(Summary::schedule_period(), SCHEDULE_PERIOD);
+        });
+    }
+
+    #[test]
+    fn keeps_it_within_the_bounds() {
+        let mut ext = ExtBuilder::build_default().with_validators().as_externality();
+        ext.execute_with(|| {
+            Summary::set_schedule_and_voting_periods(SCHEDULE_PERIOD, VOTING_PERIOD);
+            assert_ok!(set_bounds(Some(bounds(350, 450))));
+
+            advance_slot_with_activity(activity(10, 10));
+
+            assert_eq!(Summary::schedule_period(), 350);
+        });
+    }
+
+    #[test]
+    fn starts_recording_the_activity_of_the_new_slot() {
+        let mut ext = ExtBuilder::build_default().with_validators().as_externality();
+        ext.execute_with(|| {
+            setup();
+
+            advance_slot_with_activity(activity(10, 8));
+
+            assert_eq!(Summary::slot_activity(), SlotActivity::default());
+        });
+    }
+
+    #[test]
+    fn does_not_change_a_fixed_schedule_period() {
+        let mut ext = ExtBuilder::build_default().with_validators().as_externality();
+        ext.execute_with(|| {
+            Summary::set_schedule_and_voting_periods(SCHEDULE_PERIOD, VOTING_PERIOD);
+
+            advance_slot_with_activity(activity(10, 10));
+
+            assert_eq!(Summary::schedule_period(), SCHEDULE_PERIOD);
+        });
+    }
+}
+
+mod recording_the_activity {
+    use super::*;
+
+    #[test]
+    fn counts_full_blocks_as_busy() {
+        let mut ext = ExtBuilder::build_default().with_validators().as_externality();
+        ext.execute_with(|| {
+            setup();
+            let max_block_weight =
+                <TestRuntime as frame_system::Config>::BlockWeights::get().max_block;
+            System::register_extra_weight_unchecked(max_block_weight, DispatchClass::Mandatory);
+
+            Summary::on_finalize(System::block_number());
+
+            assert_eq!(Summary::slot_activity(), activity(1, 1));
+        });
+    }
+
+    #[test]
+    fn counts_empty_blocks_as_idle() {
+        let mut ext = ExtBuilder::build_default().with_validators().as_externality();
+        ext.execute_with(|| {
+            setup();
+            System::reset_events();
+
+            Summary::on_finalize(System::block_number());
+
+            assert_eq!(Summary::slot_activity(), activity(1, 0));
+        });
+    }
+
+    #[test]
+    fn is_not_recorded_for_a_fixed_schedule_period() {
+        let mut ext = ExtBuilder::build_default().with_validators().as_externality();
+        ext.execute_with(|| {
+            Summary::on_finalize(System::block_number());
+
+            assert_eq!(Summary::slot_activity(), SlotActivity::default());
+        });
+    }
+}
@@ -0,0 +1,219 @@
+// Copyright 2024 Aventus Network Services (UK) Ltd.
+#![cfg(test)]
+
+use crate::{mock::*, *};
+use frame_support::{assert_noop, assert_ok, dispatch::Pays};
+use sp_runtime::traits::BadOrigin;
+use system::RawOrigin;
+
+const CALLER: AccountId = 100;
+
+fn cleanup(limit: u32) -> DispatchResultWithPostInfo {
+    Summary::cleanup(RuntimeOrigin::signed(CALLER), limit)
+}
+
+fn setup_ended_voting(context: &Context) {
+    setup_voting_for_root_id(context);
+    Summary::remove_pending_approval(&context.root_id.range);
+}
+
+fn insert_tx_id(context: &Context, finalised: bool) {
+    TxIdToRoot::<TestRuntime>::insert(context.tx_id, context.root_id);
+    Roots::<TestRuntime>::mutate(context.root_id.range, context.root_id.ingress_counter, |root| {
+        if let Some(root) = root {
+            root.is_finalised = finalised
+        }
+    });
+}
+
+fn insert_offence_evidence(reported_at: BlockNumber) -> H256 {
+    let evidence = SummaryOffenceEvidence {
+        offence_type: SummaryOffenceType::NoSummaryCreated,
+        subject: SummaryOffenceSubject::Slot(reported_at),
+        reporter: CALLER,
+        reported_at,
+    };
+    let hash = evidence.hash();
+    OffenceEvidence::<TestRuntime>::insert(hash, evidence);
+    hash
+}
+
+mod cleanup {
+    use super::*;
+
+    #[test]
+    fn removes_voting_sessions_that_have_ended() {
+        let mut ext = ExtBuilder::build_default().with_validators().as_externality();
+        ext.execute_with(|| {
+            let context = setup_context();
+            setup_ended_voting(&context);
+            InconclusiveVotingRounds::<TestRuntime>::insert(context.root_id, 1);
+
+            assert_ok!(cleanup(10));
+
+            assert!(!VotesRepository::<TestRuntime>::contains_key(context.root_id));
+            assert!(!InconclusiveVotingRounds::<TestRuntime>::contains_key(context.root_id));
+            assert!(Summary::emitted_event(&RuntimeEvent::Summary(
+                crate::Event::<TestRuntime>::ExpiredStateRemoved {
+                    voting_sessions: 1,
+                    tx_ids: 0,
+                    offence_evidence: 0
+                }
+            )));
+        });
+    }
+
+    #[test]
+    fn keeps_voting_sessions_of_pending_roots() {
+        let mut ext = ExtBuilder::build_default().with_validators().as_externality();
+        ext.execute_with(|| {
+            let context = setup_context();
+            setup_voting_for_root_id(&context);
+
+            assert_ok!(cleanup(10));
+
+            assert!(VotesRepository::<TestRuntime>::contains_key(context.root_id));
+        });
+    }
+
+    #[test]
+    fn removes_transaction_ids_of_finalised_roots() {
+        let mut ext = ExtBuilder::build_default().with_validators().as_externality();
+        ext.execute_with(|| {
+            let context = setup_context();
+            setup_voting_for_root_id(&context);
+            insert_tx_id(&context, true);
+
+            assert_ok!(cleanup(10));
+
+            assert!(!TxIdToRoot::<TestRuntime>::contains_key(context.tx_id));
+            assert!(Summary::emitted_event(&RuntimeEvent::Summary(
+                crate::Event::<TestRuntime>::ExpiredStateRemoved {
+                    voting_sessions: 0,
+                    tx_ids: 1,
+                    offence_evidence: 0
+                }
+            )));
+        });
+    }
+
+    #[test]
+    fn keeps_transaction_ids_of_roots_that_are_not_finalised() {
+        let mut ext = ExtBuilder::build_default().with_validators().as_externality();
+        ext.execute_with(|| {
+            let context = setup_context();
+            setup_voting_for_root_id(&context);
+            insert_tx_id(&context, false);
+
+            assert_ok!(cleanup(10));
+
+            assert!(TxIdToRoot::<TestRuntime>::contains_key(context.tx_id));
+        });
+    }
+
+    #[test]
+    fn removes_offence_evidence_once_it_has_expired() {
+        let mut ext = ExtBuilder::build_default().with_validators().as_externality();
+        ext.execute_with(|| {
+            let evidence = insert_offence_evidence(1);
+            System::set_block_number(OFFENCE_EVIDENCE_RETENTION + 2);
+
+            assert_ok!(cleanup(10));
+
+            assert!(!OffenceEvidence::<TestRuntime>::contains_key(evidence));
+            assert!(Summary::emitted_event(&RuntimeEvent::Summary(
+                crate::Event::<TestRuntime>::ExpiredStateRemoved {
+                    voting_sessions: 0,
+                    tx_ids: 0,
+                    offence_evidence: 1
+                }
+            )));
+        });
+    }
+
+    #[test]
+    fn keeps_offence_evidence_that_has_not_expired() {
+        let mut ext = ExtBuilder::build_default().with_validators().as_externality();
+        ext.execute_with(|| {
+            let evidence = insert_offence_evidence(1);
+            System::set_block_number(OFFENCE_EVIDENCE_RETENTION + 1);
+
+            assert_ok!(cleanup(10));
+
+            assert!(OffenceEvidence::<TestRuntime>::contains_key(evidence));
+        });
+    }
+
+    #[test]
+    fn checks_at_most_limit_entries() {
+        let mut ext = ExtBuilder::build_default().with_validators().as_externality();
+        ext.execute_with(|| {
+            let context = setup_context();
+            setup_ended_voting(&context);
+            insert_tx_id(&context, true);
+
+            assert_ok!(cleanup(1));
+
+            assert!(!VotesRepository::<TestRuntime>::contains_key(context.root_id));
+            assert!(TxIdToRoot::<TestRuntime>::contains_key(context.tx_id));
+        });
+    }
+
+    #[test]
+    fn carries_on_from_the_last_entries_checked() {
+        let mut ext = ExtBuilder::build_default().with_validators().as_externality();
+        ext.execute_with(|| {
+            let context = setup_context();
+            setup_voting_for_root_id(&context);
+            insert_tx_id(&context, false);
+
+            assert_ok!(cleanup(1));
+            assert!(VotingSessionsCleanupCursor::<TestRuntime>::get().is_some());
+            assert!(TxIdsCleanupCursor::<TestRuntime>::get().is_none());
+
+            // The voting sessions have all been checked, so the transaction ids are checked next
+            assert_ok!(cleanup(1));
+            assert!(VotingSessionsCleanupCursor::<TestRuntime>::get().is_none());
+            assert!(TxIdsCleanupCursor::<TestRuntime>::get().is_some());
+            assert!(VotesRepository::<TestRuntime>::contains_key(context.root_id));
+            assert!(TxIdToRoot::<TestRuntime>::contains_key(context.tx_id));
+        });
+    }
+
+    #[test]
+    fn is_free_when_it_removes_state() {
+        let mut ext = ExtBuilder::build_default().with_validators().as_externality();
+        ext.execute_with(|| {
+            let context = setup_context();
+            setup_ended_voting(&context);
+
+            let post_info = cleanup(10).expect("Cleanup succeeds");
+
+            assert_eq!(post_info.pays_fee, Pays::No);
+            assert_eq!(
+                post_info.actual_weight,
+                Some(<TestRuntime as crate::Config>::WeightInfo::cleanup(1))
+            );
+        });
+    }
+
+    #[test]
+    fn is_paid_for_when_there_is_nothing_to_remove() {
+        let mut ext = ExtBuilder::build_default().with_validators().as_externality();
+        ext.execute_with(|| {
+            setup_context();
+
+            let post_info = cleanup(10).expect("Cleanup succeeds");
+
+            assert_eq!(post_info.pays_fee, Pays::Yes);
+        });
+    }
+
+    #[test]
+    fn fails_for_an_unsigned_origin() {
+        let mut ext = ExtBuilder::build_default().with_validators().as_externality();
+        ext.execute_with(|| {
+            assert_noop!(Summary::cleanup(RawOrigin::None.into(), 10), BadOrigin);
+        });
+    }
+}
@@ -0,0 +1,129 @@
+// Copyright 2024 Aventus Network Services (UK) Ltd.
+#![cfg(test)]
+
+use crate::{mock::*, *};
+use frame_support::{assert_noop, assert_ok};
+use sp_runtime::traits::BadOrigin;
+use system::RawOrigin;
+
+fn skip(root_range: RootRange<BlockNumber>) -> DispatchResult {
+    Summary::force_skip_range(RawOrigin::Root.into(), root_range)
+}
+
+mod force_skip_range {
+    use super::*;
+
+    #[test]
+    fn resumes_summaries_after_the_range() {
+        let mut ext = ExtBuilder::build_default().with_validators().as_externality();
+        ext.execute_with(|| {
+            let context = setup_context();
+            setup_blocks(&context);
+            let root_range = context.root_id.range;
+
+            assert_ok!(skip(root_range));
+
+            assert_eq!(Summary::get_next_block_to_process(), context.last_block_in_range + 1);
+            assert_eq!(Summary::skipped_root_range(root_range), Some(context.current_block_number));
+            assert!(Summary::emitted_event(&RuntimeEvent::Summary(
+                crate::Event::<TestRuntime>::RootRangeSkipped {
+                    block_range: root_range,
+                    next_block_to_process: context.last_block_in_range + 1,
+                    sequence: 0,
+                }
+            )));
+        });
+    }
+
+    #[test]
+    fn discards_the_vote_on_the_range() {
+        let mut ext = ExtBuilder::build_default().with_validators().as_externality();
+        ext.execute_with(|| {
+            let context = setup_context();
+            setup_voting_for_root_id(&context);
+
+            assert_ok!(skip(context.root_id.range));
+
+            assert!(!PendingApproval::<TestRuntime>::contains_key(context.root_id.range));
+            assert!(!VotesRepository::<TestRuntime>::contains_key(context.root_id));
+        });
+    }
+
+    #[test]
+    fn can_be_called_by_the_admin_origin() {
+        let mut ext = ExtBuilder::build_default().with_validators().as_externality();
+        ext.execute_with(|| {
+            let context = setup_context();
+            setup_blocks(&context);
+
+            assert_ok!(Summary::force_skip_range(
+                RuntimeOrigin::signed(ADMIN_ACCOUNT),
+                context.root_id.range
+            ));
+        });
+    }
+
+    mod fails_when {
+        use super::*;
+
+        #[test]
+        fn the_range_does_not_start_at_the_next_block_to_process() {
+            let mut ext = ExtBuilder::build_default().with_validators().as_externality();
+            ext.execute_with(|| {
+                let context = setup_context();
+                setup_blocks(&context);
+                let root_range =
+                    RootRange::new(context.next_block_to_process + 1, context.last_block_in_range);
+
+                assert_noop!(skip(root_range), Error::<TestRuntime>::InvalidRootRangeToSkip);
+            });
+        }
+
+        #[test]
+        fn the_range_ends_after_the_target_block() {
+            let mut ext = ExtBuilder::build_default().with_validators().as_externality();
+            ext.execute_with(|| {
+                let context = setup_context();
+                setup_blocks(&context);
+                let root_range = RootRange::new(
+                    context.next_block_to_process,
+                    Summary::get_target_block().expect("target block is valid") + 1,
+                );
+
+                assert_noop!(skip(root_range), Error::<TestRuntime>::InvalidRootRangeToSkip);
+            });
+        }
+
+        #[test]
+        fn the_range_is_empty() {
+            let mut ext = ExtBuilder::build_default().with_validators().as_externality();
+            ext.execute_with(|| {
+                let context = setup_context();
+                setup_blocks(&context);
+                let root_range = RootRange::new(
+                    context.next_block_to_process,
+                    context.next_block_to_process - 1,
+                );
+
+                assert_noop!(skip(root_range), Error::<TestRuntime>::InvalidRootRangeToSkip);
+            });
+        }
+
+        #[test]
+        fn origin_is_not_the_admin_origin() {
+            let mut ext = ExtBuilder::build_default().with_validators().as_externality();
+            ext.execute_with(|| {
+                let context = setup_context();
+                setup_blocks(&context);
+
+                assert_noop!(
+                    Summary::force_skip_range(
+                        RuntimeOrigin::signed(context.validator.account_id),
+                        context.root_id.range
+                    ),
+                    BadOrigin
+                );
+            });
+        }
+    }
+}
@@ -0,0 +1,208 @@
+// Copyright 2024 Aventus Network Services (UK) Ltd.
+
+#![cfg(test)]
+
+use crate::{mock::*, system, MAX_INCONCLUSIVE_VOTING_ROUNDS};
+use frame_support::{assert_noop, assert_ok};
+use system::RawOrigin;
+
+const EXPIRED_VOTING_PERIOD_BLOCK: u64 = 50;
+
+fn setup() -> Context {
+    let context = setup_context();
+    setup_voting_for_root_id(&context);
+    Summary::set_current_slot(10);
+    Summary::set_previous_summary_slot(5);
+    context
+}
+
+fn vote_without_quorum(context: &Context) {
+    Summary::record_approve_vote(&context.root_id, context.validator.account_id);
+}
+
+fn end_voting_period(context: &Context) {
+    assert_ok!(Summary::end_voting_period(
+        RawOrigin::None.into(),
+        context.root_id,
+        context.validator.clone(),
+        context.record_summary_calculation_signature.clone()
+    ));
+}
+
+fn end_expired_voting_period(context: &Context) {
+    let end_of_voting_period = Summary::get_vote(context.root_id).end_of_voting_period;
+    System::set_block_number(end_of_voting_period.max(EXPIRED_VOTING_PERIOD_BLOCK));
+    end_voting_period(context);
+}
+
+fn voting_ended(context: &Context) -> bool {
+    System::events().iter().any(|e| {
+        matches!(&e.event,
+            mock::RuntimeEvent::Summary(crate::Event::<TestRuntime>::VotingEnded { root_id, .. })
+                if *root_id == context.root_id)
+    })
+}
+
+#[test]
+fn voting_without_a_quorum_is_reopened() {
+    let (mut ext, _pool_state, _offchain_state) = ExtBuilder::build_default()
+        .with_validators()
+        .for_offchain_worker()
+        .as_externality_with_state();
+    ext.execute_with(|| {
+        let context = setup();
+        vote_without_quorum(&context);
+
+        end_expired_voting_period(&context);
+
+        let vote = Summary::get_vote(context.root_id);
+        assert!(vote.ayes.is_empty());
+        assert_eq!(
+            vote.end_of_voting_period,
+            EXPIRED_VOTING_PERIOD_BLOCK + Summary::voting_period()
+        );
+        assert!(PendingApproval::<TestRuntime>::contains_key(&context.root_id.range));
+        assert_eq!(Summary::inconclusive_voting_rounds(context.root_id), 1);
+        assert!(Summary::emitted_event(&mock::RuntimeEvent::Summary(
+            crate::Event::<TestRuntime>::VotingReopened { root_id: context.root_id, round: 1 }
+        )));
+        assert!(!voting_ended(&context));
+    });
+}
+
+#[test]
+fn reopened_voting_does_not_report_offences() {
+    let (mut ext, _pool_state, _offchain_state) = ExtBuilder::build_default()
+        .with_validators()
+        .for_offchain_worker()
+        .as_externality_with_state();
+    ext.execute_with(|| {
+        let context = setup();
+        vote_without_quorum(&context);
+
+        end_expired_voting_period(&context);
+
+        assert!(!Summary::reported_offence_of_type(SummaryOffenceType::CreatedInvalidRoot));
+        assert!(!Summary::reported_offence_of_type(SummaryOffenceType::ApprovedInvalidRoot));
+    });
+}
+
+#[test]
+fn reopened_voting_cannot_be_ended_before_its_new_voting_period_expires() {
+    let (mut ext, _pool_state, _offchain_state) = ExtBuilder::build_default()
+        .with_validators()
+        .for_offchain_worker()
+        .as_externality_with_state();
+    ext.execute_with(|| {
+        let context = setup();
+        vote_without_quorum(&context);
+        end_expired_voting_period(&context);
+
+        assert_noop!(
+            Summary::end_voting_period(
+                RawOrigin::None.into(),
+                context.root_id,
+                context.validator.clone(),
+                context.record_summary_calculation_signature.clone()
+            ),
+            Error::<TestRuntime>::ErrorEndingVotingPeriod
+        );
+    });
+}
+
+#[test]
+fn reopened_voting_that_reaches_a_quorum_validates_the_root() {
+    let (mut ext, _pool_state, _offchain_state) = ExtBuilder::build_default()
+        .with_validators()
+        .for_offchain_worker()
+        .as_externality_with_state();
+    ext.execute_with(|| {
+        let context = setup();
+        vote_without_quorum(&context);
+        end_expired_voting_period(&context);
+
+        Summary::record_approve_vote(
+            &context.root_id,
+            get_validator(FIRST_VALIDATOR_INDEX).account_id,
+        );
+        Summary::record_approve_vote(
+            &context.root_id,
+            get_validator(SECOND_VALIDATOR_INDEX).account_id,
+        );
+        Summary::record_approve_vote(
+            &context.root_id,
+            get_validator(THIRD_VALIDATOR_INDEX).account_id,
+        );
+        end_voting_period(&context);
+
+        assert!(Summary::get_root_data(&context.root_id).is_validated);
+        assert!(!PendingApproval::<TestRuntime>::contains_key(&context.root_id.range));
+        assert_eq!(Summary::inconclusive_voting_rounds(context.root_id), 0);
+        assert!(voting_ended(&context));
+    });
+}
+
+mod when_voting_stays_inconclusive {
+    use super::*;
+
+    fn end_all_voting_rounds(context: &Context) {
+        for _ in 0..=MAX_INCONCLUSIVE_VOTING_ROUNDS {
+            vote_without_quorum(context);
+            end_expired_voting_period(context);
+        }
+    }
+
+    #[test]
+    fn voting_ends_after_the_last_round() {
+        let (mut ext, _pool_state, _offchain_state) = ExtBuilder::build_default()
+            .with_validators()
+            .for_offchain_worker()
+            .as_externality_with_state();
+        ext.execute_with(|| {
+            let context = setup();
+
+            end_all_voting_rounds(&context);
+
+            assert!(!Summary::get_root_data(&context.root_id).is_validated);
+            assert!(!PendingApproval::<TestRuntime>::contains_key(&context.root_id.range));
+            assert_eq!(Summary::inconclusive_voting_rounds(context.root_id), 0);
+            assert!(voting_ended(&context));
+        });
+    }
+
+    #[test]
+    fn voting_is_escalated() {
+        let (mut ext, _pool_state, _offchain_state) = ExtBuilder::build_default()
+            .with_validators()
+            .for_offchain_worker()
+            .as_externality_with_state();
+        ext.execute_with(|| {
+            let context = setup();
+
+            end_all_voting_rounds(&context);
+
+            assert!(Summary::emitted_event(&mock::RuntimeEvent::Summary(
+                crate::Event::<TestRuntime>::InconclusiveVotingEscalated {
+                    root_id: context.root_id,
+                    rounds: MAX_INCONCLUSIVE_VOTING_ROUNDS
+                }
+            )));
+        });
+    }
+
+    #[test]
+    fn offences_are_reported() {
+        let (mut ext, _pool_state, _offchain_state) = ExtBuilder::build_default()
+            .with_validators()
+            .for_offchain_worker()
+            .as_externality_with_state();
+        ext.execute_with(|| {
+            let context = setup();
+
+            end_all_voting_rounds(&context);
+
+            assert!(Summary::reported_offence_of_type(SummaryOffenceType::CreatedInvalidRoot));
+            assert!(Summary::reported_offence_of_type(SummaryOffenceType::ApprovedInvalidRoot));
+        });
+    }
+}
@@ -0,0 +1,216 @@
+// Copyright 2024 Aventus Network Services (UK) Ltd.
+#![cfg(test)]
+
+use crate::{
+    migration::{AddReplacedVotes, IndexValidatedRoots, RemoveDefaultRoots, SteppedMigrations},
+    mock::*,
+    *,
+};
+use codec::Encode;
+use frame_support::{
+    storage::unhashed,
+    traits::{Get, GetStorageVersion, StorageVersion},
+};
+use pallet_avn::migration::{migrate, SteppedMigration};
+
+fn root_id(ingress_counter: IngressCounter) -> RootId<BlockNumber> {
+    RootId::new(RootRange::new(1, 160), ingress_counter)
+}
+
+fn insert_default_root(root_id: &RootId<BlockNumber>) {
+    Roots::<TestRuntime>::insert(root_id.range, root_id.ingress_counter, RootData::default());
+}
+
+mod remove_default_roots {
+    use super::*;
+
+    #[test]
+    fn removes_roots_without_a_creator_and_the_entries_referencing_them() {
+        let mut ext = ExtBuilder::build_default().with_validators().as_externality();
+        ext.execute_with(|| {
+            StorageVersion::new(2).put::<Summary>();
+            let recorded_root = root_id(1);
+            let default_root = RootId::new(RootRange::new(161, 320), 2);
+            Summary::insert_root_hash(
+                &recorded_root,
+                H256::repeat_byte(1),
+                get_validator(1).account_id,
+                1,
+            );
+            insert_default_root(&default_root);
+            TxIdToRoot::<TestRuntime>::insert(2, default_root);
+            Summary::insert_pending_approval(&default_root);
+
+            migrate::<TestRuntime, SteppedMigrations<TestRuntime, ()>>();
+
+            assert!(Summary::try_get_root_data(&recorded_root).is_ok());
+            assert!(Summary::try_get_root_data(&default_root).is_err());
+            assert!(!TxIdToRoot::<TestRuntime>::contains_key(2));
+            assert!(!PendingApproval::<TestRuntime>::contains_key(default_root.range));
+            assert_eq!(Summary::on_chain_storage_version(), StorageVersion::new(5));
+        });
+    }
+
+    #[test]
+    fn resumes_where_it_stopped() {
+        let mut ext = ExtBuilder::build_default().with_validators().as_externality();
+        ext.execute_with(|| {
+            for ingress_counter in 1..4 {
+                insert_default_root(&root_id(ingress_counter));
+            }
+            let one_root =
+                <TestRuntime as frame_system::Config>::DbWeight::get().reads_writes(1, 1);
+
+            let mut cursor = None;
+            for _ in 1..4 {
+                let (_, next_cursor) =
+                    RemoveDefaultRoots::<TestRuntime, ()>::step(cursor, one_root);
+                assert!(next_cursor.is_some());
+                cursor = next_cursor;
+            }
+            assert_eq!(Roots::<TestRuntime>::iter().count(), 0);
+
+            let (_, cursor) = RemoveDefaultRoots::<TestRuntime, ()>::step(cursor, one_root);
+            assert!(cursor.is_none());
+        });
+    }
+
+    #[test]
+    fn does_not_run_on_a_migrated_instance() {
+        let mut ext = ExtBuilder::build_default().with_validators().as_externality();
+        ext.execute_with(|| {
+            StorageVersion::new(3).put::<Summary>();
+            insert_default_root(&root_id(1));
+
+            migrate::<TestRuntime, SteppedMigrations<TestRuntime, ()>>();
+
+            assert_eq!(Roots::<TestRuntime>::iter().count(), 1);
+        });
+    }
+}
+
+mod add_replaced_votes {
+    use super::*;
+
+    fn insert_old_voting_session(root_id: &RootId<BlockNumber>) {
+        let vote = VotingSessionData::<AccountId, BlockNumber>::new(
+            root_id.session_id(),
+            QUORUM,
+            VOTING_PERIOD_END,
+            0,
+        );
+        let encoded = vote.encode();
+        // Drop the empty list of replaced votes, encoded as a single byte
+        unhashed::put_raw(
+            &VotesRepository::<TestRuntime>::hashed_key_for(root_id),
+            &encoded[..encoded.len() - 1],
+        );
+    }
+
+    #[test]
+    fn adds_replaced_votes_to_the_recorded_voting_sessions() {
+        let mut ext = ExtBuilder::build_default().with_validators().as_externality();
+        ext.execute_with(|| {
+            StorageVersion::new(3).put::<Summary>();
+            let old_root = root_id(1);
+            insert_old_voting_session(&old_root);
+            assert!(VotesRepository::<TestRuntime>::try_get(old_root).is_err());
+
+            migrate::<TestRuntime, SteppedMigrations<TestRuntime, ()>>();
+
+            let vote = VotesRepository::<TestRuntime>::try_get(old_root).expect("vote is migrated");
+            assert_eq!(vote.threshold, QUORUM);
+            assert!(vote.replaced_votes.is_empty());
+            assert_eq!(Summary::on_chain_storage_version(), StorageVersion::new(5));
+        });
+    }
+
+    #[test]
+    fn migrates_the_pending_voting_sessions_first() {
+        let mut ext = ExtBuilder::build_default().with_validators().as_externality();
+        ext.execute_with(|| {
+            let pending_root = root_id(3);
+            for ingress_counter in 1..4 {
+                insert_old_voting_session(&root_id(ingress_counter));
+            }
+            Summary::insert_pending_approval(&pending_root);
+            let one_session =
+                <TestRuntime as frame_system::Config>::DbWeight::get().reads_writes(1, 1);
+
+            let (_, cursor) = AddReplacedVotes::<TestRuntime, ()>::step(None, one_session);
+
+            assert!(cursor.is_some());
+            assert!(VotesRepository::<TestRuntime>::try_get(pending_root).is_ok());
+            assert!(VotesRepository::<TestRuntime>::try_get(root_id(1)).is_err());
+        });
+    }
+}
+
+mod index_validated_roots {
+    use super::*;
+
+    #[test]
+    fn indexes_the_validated_roots_by_their_last_block() {
+        let mut ext = ExtBuilder::build_default().with_validators().as_externality();
+        ext.execute_with(|| {
+            StorageVersion::new(4).put::<Summary>();
+            let validated_root = root_id(1);
+            let pending_root = RootId::new(RootRange::new(161, 320), 2);
+            for root_id in [validated_root, pending_root] {
+                Summary::insert_root_hash(
+                    &root_id,
+                    H256::repeat_byte(1),
+                    get_validator(1).account_id,
+                    1,
+                );
+            }
+            Roots::<TestRuntime>::mutate(validated_root.range, 1, |root| {
+                root.as_mut().expect("root is recorded").is_validated = true
+            });
+            assert_eq!(Summary::validated_root_for_block(100), None);
+
+            migrate::<TestRuntime, SteppedMigrations<TestRuntime, ()>>();
+
+            assert_eq!(Summary::validated_root_for_block(100).map(|root| root.to_block), Some(160));
+            assert_eq!(Summary::validated_root_for_block(200), None);
+            assert_eq!(ValidatedRootsByEndBlock::<TestRuntime>::iter().count(), 1);
+            assert_eq!(Summary::on_chain_storage_version(), StorageVersion::new(5));
+        });
+    }
+
+    #[test]
+    fn resumes_where_it_stopped() {
+        let mut ext = ExtBuilder::build_default().with_validators().as_externality();
+        ext.execute_with(|| {
+            StorageVersion::new(4).put::<Summary>();
+            for ingress_counter in 1..4 {
+                let root_id = RootId::new(
+                    RootRange::new(ingress_counter * 10 + 1, ingress_counter * 10 + 10),
+                    ingress_counter,
+                );
+                Summary::insert_root_hash(
+                    &root_id,
+                    H256::repeat_byte(1),
+                    get_validator(1).account_id,
+                    1,
+                );
+                Roots::<TestRuntime>::mutate(root_id.range, ingress_counter, |root| {
+                    root.as_mut().expect("root is recorded").is_validated = true
+                });
+            }
+            let one_root =
+                <TestRuntime as frame_system::Config>::DbWeight::get().reads_writes(1, 1);
+
+            let (_, cursor) = IndexValidatedRoots::<TestRuntime, ()>::step(None, one_root);
+            assert!(cursor.is_some());
+            assert_eq!(ValidatedRootsByEndBlock::<TestRuntime>::iter().count(), 1);
+
+            let (_, cursor) = IndexValidatedRoots::<TestRuntime, ()>::step(cursor, one_root * 2);
+            assert!(cursor.is_some());
+            assert_eq!(ValidatedRootsByEndBlock::<TestRuntime>::iter().count(), 3);
+
+            let (_, cursor) = IndexValidatedRoots::<TestRuntime, ()>::step(cursor, one_root);
+            assert!(cursor.is_none());
+        });
+    }
+}
@@ -301,3 +301,21 @@ mod record_summary_locks {
         });
     }
 }
+
+mod instance_locks {
+    use super::*;
+
+    #[test]
+    fn lock_names_are_unique_per_instance() {
+        let block_number = 10;
+
+        assert_ne!(
+            Summary::create_root_lock_name(block_number),
+            AnchorSummary::create_root_lock_name(block_number)
+        );
+        assert_ne!(
+            Summary::get_advance_slot_lock_name(block_number),
+            AnchorSummary::get_advance_slot_lock_name(block_number)
+        );
+    }
+}
@@ -0,0 +1,246 @@
+// Copyright 2024 Aventus Network Services (UK) Ltd.
+#![cfg(test)]
+
+use crate::{
+    mock::*,
+    offence::{create_and_report_summary_offence, SummaryOffenceTimeSlot},
+    *,
+};
+use frame_support::assert_ok;
+use sp_staking::offence::Offence;
+use system::RawOrigin;
+
+fn offence(instance_id: u8, offence_type: SummaryOffenceType) -> SummaryOffence<u64> {
+    SummaryOffence {
+        session_index: 1,
+        instance_id,
+        validator_set_count: 3,
+        offenders: vec![get_validator(1).account_id],
+        offence_type,
+    }
+}
+
+mod summary_offence {
+    use super::*;
+
+    #[test]
+    fn time_slot_is_the_session_instance_and_type() {
+        assert_eq!(
+            offence(1, SummaryOffenceType::RejectedValidRoot).time_slot(),
+            SummaryOffenceTimeSlot {
+                session_index: 1,
+                instance_id: 1,
+                offence_type: SummaryOffenceType::RejectedValidRoot,
+            }
+        );
+    }
+
+    #[test]
+    fn offences_of_different_types_in_a_session_are_distinct() {
+        assert_ne!(
+            offence(1, SummaryOffenceType::RejectedValidRoot).time_slot(),
+            offence(1, SummaryOffenceType::CreatedInvalidRoot).time_slot()
+        );
+    }
+
+    #[test]
+    fn offences_of_different_instances_in_a_session_are_distinct() {
+        assert_ne!(
+            offence(1, SummaryOffenceType::RejectedValidRoot).time_slot(),
+            offence(2, SummaryOffenceType::RejectedValidRoot).time_slot()
+        );
+    }
+
+    #[test]
+    fn reports_record_the_summary_instance() {
+        let mut ext = ExtBuilder::build_default().with_validators().as_externality();
+        ext.execute_with(|| {
+            let reporter = get_validator(1).account_id;
+            let offender = get_validator(2).account_id;
+
+            create_and_report_summary_offence::<TestRuntime, Instance1>(
+                &reporter,
+                &vec![offender],
+                SummaryOffenceType::NoSummaryCreated,
+                SummaryOffenceSubject::Slot(1),
+            );
+
+            let offences = Summary::get_offence_record();
+            assert_eq!(offences.len(), 1);
+            assert_eq!(offences[0].1.instance_id, AnchorInstanceId::get());
+        });
+    }
+}
+
+mod offence_evidence {
+    use super::*;
+
+    const VALIDATOR_COUNT: u64 = 7;
+
+    fn report_no_summary_created(reporter: AccountId, offender: AccountId, slot: u64) {
+        create_and_report_summary_offence::<TestRuntime, ()>(
+            &reporter,
+            &vec![offender],
+            SummaryOffenceType::NoSummaryCreated,
+            SummaryOffenceSubject::Slot(slot),
+        );
+    }
+
+    #[test]
+    fn reports_record_the_evidence_referenced_by_the_event() {
+        let mut ext = ExtBuilder::build_default().with_validators().as_externality();
+        ext.execute_with(|| {
+            let reporter = get_validator(1).account_id;
+            let offender = get_validator(2).account_id;
+
+            report_no_summary_created(reporter, offender, 7);
+
+            let evidence = SummaryOffenceEvidence {
+                offence_type: SummaryOffenceType::NoSummaryCreated,
+                subject: SummaryOffenceSubject::Slot(7),
+                reporter,
+                reported_at: System::block_number(),
+            };
+            assert_eq!(Summary::offence_evidence(evidence.hash()), Some(evidence.clone()));
+            assert!(Summary::emitted_event(&mock::RuntimeEvent::Summary(crate::Event::<
+                TestRuntime,
+            >::SummaryOffenceReported {
+                offence_type: SummaryOffenceType::NoSummaryCreated,
+                offenders: vec![Summary::create_mock_identification_tuple(offender)],
+                evidence: evidence.hash(),
+            })));
+        });
+    }
+
+    #[test]
+    fn offences_of_different_subjects_have_distinct_evidence() {
+        let mut ext = ExtBuilder::build_default().with_validators().as_externality();
+        ext.execute_with(|| {
+            let reporter = get_validator(1).account_id;
+            let offender = get_validator(2).account_id;
+
+            report_no_summary_created(reporter, offender, 7);
+            report_no_summary_created(reporter, offender, 8);
+
+            assert_eq!(OffenceEvidence::<TestRuntime>::iter().count(), 2);
+        });
+    }
+
+    #[test]
+    fn the_evidence_of_a_root_offence_holds_the_votes_of_the_root() {
+        let (mut ext, _pool_state, _offchain_state) = ExtBuilder::build_default()
+            .with_validator_count(VALIDATOR_COUNT)
+            .for_offchain_worker()
+            .as_externality_with_state();
+        ext.execute_with(|| {
+            let context = setup_context();
+            setup_voting_for_root_id(&context);
+            Summary::record_approve_vote(&context.root_id, get_validator(1).account_id);
+            Summary::record_approve_vote(&context.root_id, get_validator(2).account_id);
+            Summary::record_approve_vote(&context.root_id, get_validator(3).account_id);
+            Summary::record_reject_vote(&context.root_id, get_validator(4).account_id);
+            Summary::set_current_slot(10);
+            Summary::set_previous_summary_slot(5);
+
+            assert_ok!(Summary::end_voting_period(
+                RawOrigin::None.into(),
+                context.root_id,
+                context.validator.clone(),
+                context.record_summary_calculation_signature.clone()
+            ));
+
+            let evidence_hash =
+                Summary::offence_evidence_of_type(SummaryOffenceType::RejectedValidRoot).unwrap();
+            let evidence = Summary::offence_evidence(evidence_hash).unwrap();
+            assert_eq!(
+                evidence.subject,
+                SummaryOffenceSubject::Root { root_id: context.root_id, ayes: 3, nays: 1 }
+            );
+            assert_eq!(evidence.reporter, context.validator.account_id);
+        });
+    }
+}
+
+mod cross_instance_deduplication {
+    use super::*;
+
+    fn report_slot_offence<I: 'static>(offender: AccountId)
+    where
+        TestRuntime: crate::Config<I>,
+    {
+        create_and_report_summary_offence::<TestRuntime, I>(
+            &get_validator(1).account_id,
+            &vec![offender],
+            SummaryOffenceType::SlotNotAdvanced,
+            SummaryOffenceSubject::Slot(1),
+        );
+    }
+
+    #[test]
+    fn a_slot_offence_in_the_same_blocks_is_reported_by_one_instance_only() {
+        let mut ext = ExtBuilder::build_default().with_validators().as_externality();
+        ext.execute_with(|| {
+            let offender = get_validator(2).account_id;
+
+            report_slot_offence::<()>(offender);
+            report_slot_offence::<Instance1>(offender);
+
+            let offences = Summary::get_offence_record();
+            assert_eq!(offences.len(), 1);
+            assert_eq!(offences[0].1.instance_id, InstanceId::get());
+        });
+    }
+
+    #[test]
+    fn a_slot_offence_in_later_blocks_is_reported_by_another_instance() {
+        let mut ext = ExtBuilder::build_default().with_validators().as_externality();
+        ext.execute_with(|| {
+            let offender = get_validator(2).account_id;
+            report_slot_offence::<()>(offender);
+
+            System::set_block_number(100);
+            SchedulePeriod::<TestRuntime, Instance1>::put(10);
+            NextSlotAtBlock::<TestRuntime, Instance1>::put(100);
+            report_slot_offence::<Instance1>(offender);
+
+            assert_eq!(Summary::get_offence_record().len(), 2);
+        });
+    }
+
+    #[test]
+    fn slot_offences_of_other_validators_are_still_reported() {
+        let mut ext = ExtBuilder::build_default().with_validators().as_externality();
+        ext.execute_with(|| {
+            report_slot_offence::<()>(get_validator(2).account_id);
+            report_slot_offence::<Instance1>(get_validator(3).account_id);
+
+            assert_eq!(Summary::get_offence_record().len(), 2);
+        });
+    }
+
+    #[test]
+    fn root_offences_are_not_deduplicated() {
+        let mut ext = ExtBuilder::build_default().with_validators().as_externality();
+        ext.execute_with(|| {
+            let context = setup_context();
+            let offender = get_validator(2).account_id;
+            let subject =
+                SummaryOffenceSubject::Root { root_id: context.root_id, ayes: 0, nays: 0 };
+
+            create_and_report_summary_offence::<TestRuntime, ()>(
+                &get_validator(1).account_id,
+                &vec![offender],
+                SummaryOffenceType::RejectedValidRoot,
+                subject.clone(),
+            );
+            create_and_report_summary_offence::<TestRuntime, Instance1>(
+                &get_validator(1).account_id,
+                &vec![offender],
+                SummaryOffenceType::RejectedValidRoot,
+                subject,
+            );
+
+            assert_eq!(Summary::get_offence_record().len(), 2);
+        });
+    }
+}
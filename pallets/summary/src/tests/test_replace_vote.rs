@@ -0,0 +1,214 @@
+// Copyright 2024 Aventus Network Services (UK) Ltd.
+
+#![cfg(test)]
+
+use crate::{
+    mock::*,
+    system,
+    vote::{CAST_VOTE_CONTEXT, REPLACE_VOTE_CONTEXT},
+};
+use codec::Encode;
+use frame_support::{
+    assert_noop, assert_ok, dispatch::DispatchResultWithPostInfo, unsigned::ValidateUnsigned,
+};
+use pallet_avn::{
+    vote::{APPROVE_VOTE, REJECT_VOTE, REPLACE_VOTE_IS_NOT_VALID},
+    Error as AvNError,
+};
+use sp_avn_common::event_types::Validator;
+use sp_runtime::{
+    testing::{TestSignature, UintAuthorityId},
+    transaction_validity::{InvalidTransaction, TransactionSource},
+};
+use system::RawOrigin;
+
+fn voter() -> Validator<UintAuthorityId, AccountId> {
+    get_validator(SECOND_VALIDATOR_INDEX)
+}
+
+fn replacement_signature(context: &Context, approve: bool) -> TestSignature {
+    voter()
+        .key
+        .sign(&(REPLACE_VOTE_CONTEXT, context.root_id.encode(), approve).encode())
+        .expect("Signature is signed")
+}
+
+fn replace_vote(context: &Context, approve: bool) -> DispatchResultWithPostInfo {
+    Summary::replace_vote(
+        RawOrigin::None.into(),
+        context.root_id,
+        voter(),
+        approve,
+        replacement_signature(context, approve),
+    )
+}
+
+fn setup_rejected_vote() -> Context {
+    let context = setup_context();
+    setup_voting_for_root_id(&context);
+    Summary::record_reject_vote(&context.root_id, voter().account_id);
+    context
+}
+
+#[test]
+fn a_rejection_can_be_replaced_by_an_approval() {
+    let mut ext = ExtBuilder::build_default().with_validators().as_externality();
+    ext.execute_with(|| {
+        let context = setup_rejected_vote();
+
+        assert_ok!(replace_vote(&context, APPROVE_VOTE));
+
+        let vote = Summary::get_vote(context.root_id);
+        assert_eq!(vote.ayes.to_vec(), vec![voter().account_id]);
+        assert!(vote.nays.is_empty());
+        assert_eq!(vote.replaced_votes.to_vec(), vec![voter().account_id]);
+        assert!(Summary::emitted_event(&mock::RuntimeEvent::Summary(
+            crate::Event::<TestRuntime>::VoteReplaced {
+                voter: voter().account_id,
+                root_id: context.root_id,
+                agree_vote: APPROVE_VOTE,
+            }
+        )));
+    });
+}
+
+#[test]
+fn a_vote_can_only_be_replaced_once_per_session() {
+    let mut ext = ExtBuilder::build_default().with_validators().as_externality();
+    ext.execute_with(|| {
+        let context = setup_rejected_vote();
+        assert_ok!(replace_vote(&context, APPROVE_VOTE));
+
+        assert_noop!(
+            replace_vote(&context, REJECT_VOTE),
+            AvNError::<TestRuntime>::VoteCannotBeReplaced
+        );
+    });
+}
+
+#[test]
+fn a_vote_cannot_be_replaced_by_the_same_vote() {
+    let mut ext = ExtBuilder::build_default().with_validators().as_externality();
+    ext.execute_with(|| {
+        let context = setup_rejected_vote();
+
+        assert_noop!(
+            replace_vote(&context, REJECT_VOTE),
+            AvNError::<TestRuntime>::VoteCannotBeReplaced
+        );
+    });
+}
+
+#[test]
+fn a_validator_that_did_not_vote_cannot_replace_a_vote() {
+    let mut ext = ExtBuilder::build_default().with_validators().as_externality();
+    ext.execute_with(|| {
+        let context = setup_context();
+        setup_voting_for_root_id(&context);
+
+        assert_noop!(
+            replace_vote(&context, APPROVE_VOTE),
+            AvNError::<TestRuntime>::VoteCannotBeReplaced
+        );
+    });
+}
+
+#[test]
+fn a_vote_cannot_be_replaced_once_the_voting_period_ended() {
+    let mut ext = ExtBuilder::build_default().with_validators().as_externality();
+    ext.execute_with(|| {
+        let context = setup_rejected_vote();
+        System::set_block_number(VOTING_PERIOD_END);
+
+        assert_noop!(replace_vote(&context, APPROVE_VOTE), AvNError::<TestRuntime>::InvalidVote);
+    });
+}
+
+#[test]
+fn a_replaced_vote_that_reaches_the_quorum_ends_the_voting() {
+    let (mut ext, _pool_state, _offchain_state) = ExtBuilder::build_default()
+        .with_validators()
+        .for_offchain_worker()
+        .as_externality_with_state();
+    ext.execute_with(|| {
+        let context = setup_rejected_vote();
+        Summary::set_current_slot(10);
+        Summary::set_previous_summary_slot(5);
+        Summary::record_approve_vote(
+            &context.root_id,
+            get_validator(FIRST_VALIDATOR_INDEX).account_id,
+        );
+        Summary::record_approve_vote(
+            &context.root_id,
+            get_validator(THIRD_VALIDATOR_INDEX).account_id,
+        );
+
+        assert_ok!(replace_vote(&context, APPROVE_VOTE));
+
+        assert!(Summary::get_root_data(&context.root_id).is_validated);
+        assert!(!Summary::reported_offence_of_type(SummaryOffenceType::RejectedValidRoot));
+    });
+}
+
+mod validate_unsigned {
+    use super::*;
+
+    fn replace_vote_call(context: &Context, signature: TestSignature) -> crate::Call<TestRuntime> {
+        crate::Call::replace_vote {
+            root_id: context.root_id,
+            validator: voter(),
+            approve: APPROVE_VOTE,
+            signature,
+        }
+    }
+
+    #[test]
+    fn accepts_a_replacement_signed_by_the_voter() {
+        let mut ext = ExtBuilder::build_default().with_validators().as_externality();
+        ext.execute_with(|| {
+            let context = setup_rejected_vote();
+            let call = replace_vote_call(&context, replacement_signature(&context, APPROVE_VOTE));
+
+            assert!(<Summary as ValidateUnsigned>::validate_unsigned(
+                TransactionSource::Local,
+                &call
+            )
+            .is_ok());
+        });
+    }
+
+    #[test]
+    fn rejects_a_replacement_signed_as_a_vote() {
+        let mut ext = ExtBuilder::build_default().with_validators().as_externality();
+        ext.execute_with(|| {
+            let context = setup_rejected_vote();
+            let vote_signature = voter()
+                .key
+                .sign(&(CAST_VOTE_CONTEXT, context.root_id.encode(), APPROVE_VOTE).encode())
+                .expect("Signature is signed");
+
+            assert_noop!(
+                <Summary as ValidateUnsigned>::validate_unsigned(
+                    TransactionSource::Local,
+                    &replace_vote_call(&context, vote_signature)
+                ),
+                InvalidTransaction::BadProof
+            );
+        });
+    }
+
+    #[test]
+    fn rejects_a_replacement_that_is_not_allowed() {
+        let mut ext = ExtBuilder::build_default().with_validators().as_externality();
+        ext.execute_with(|| {
+            let context = setup_rejected_vote();
+            assert_ok!(replace_vote(&context, APPROVE_VOTE));
+            let call = replace_vote_call(&context, replacement_signature(&context, APPROVE_VOTE));
+
+            assert_noop!(
+                <Summary as ValidateUnsigned>::validate_unsigned(TransactionSource::Local, &call),
+                InvalidTransaction::Custom(REPLACE_VOTE_IS_NOT_VALID)
+            );
+        });
+    }
+}
@@ -0,0 +1,131 @@
+// Copyright 2024 Aventus Network Services (UK) Ltd.
+#![cfg(test)]
+
+use crate::{mock::*, system, tests_vote::setup_approved_root, *};
+use frame_support::{assert_noop, assert_ok, traits::Get};
+use system::RawOrigin;
+
+const HEADER_HASH: H256 = H256::repeat_byte(7);
+
+fn record_summary_calculation(context: &Context) {
+    assert_ok!(Summary::record_summary_calculation(
+        RawOrigin::None.into(),
+        context.last_block_in_range,
+        context.root_hash_h256,
+        context.root_id.ingress_counter,
+        context.validator.clone(),
+        context.record_summary_calculation_signature.clone(),
+    ));
+}
+
+fn end_voting_period(context: &Context) {
+    let primary_validator_id =
+        AVN::<TestRuntime>::calculate_primary_validator_for_block(context.current_block_number)
+            .expect("Should be able to calculate primary validator.");
+
+    assert_ok!(Summary::end_voting_period(
+        RawOrigin::None.into(),
+        context.root_id,
+        get_validator(primary_validator_id),
+        context.record_summary_calculation_signature.clone(),
+    ));
+}
+
+fn published_root_params() -> Vec<(Vec<u8>, Vec<u8>)> {
+    PUBLISHED_ROOT_PARAMS.with(|p| p.borrow().clone())
+}
+
+mod record_summary_calculation {
+    use super::*;
+
+    #[test]
+    fn stores_the_header_hash_of_the_last_block_in_range() {
+        let mut ext = ExtBuilder::build_default().with_validators().as_externality();
+        ext.execute_with(|| {
+            let context = setup_context();
+            setup_blocks(&context);
+            setup_total_ingresses(&context);
+            frame_system::BlockHash::<TestRuntime>::insert(
+                context.last_block_in_range,
+                HEADER_HASH,
+            );
+
+            record_summary_calculation(&context);
+
+            assert_eq!(
+                Summary::root_header_hash(context.root_id.range, context.root_id.ingress_counter),
+                Some(HEADER_HASH)
+            );
+        });
+    }
+
+    #[test]
+    fn stores_nothing_when_the_header_is_unknown() {
+        let mut ext = ExtBuilder::build_default().with_validators().as_externality();
+        ext.execute_with(|| {
+            let context = setup_context();
+            setup_blocks(&context);
+            setup_total_ingresses(&context);
+
+            record_summary_calculation(&context);
+
+            assert_eq!(
+                Summary::root_header_hash(context.root_id.range, context.root_id.ingress_counter),
+                None
+            );
+        });
+    }
+
+    #[test]
+    fn fails_when_the_header_hash_has_been_pruned() {
+        let mut ext = ExtBuilder::build_default().with_validators().as_externality();
+        ext.execute_with(|| {
+            let context = setup_context();
+            setup_blocks(&context);
+            setup_total_ingresses(&context);
+            let block_hash_count: u64 =
+                <TestRuntime as frame_system::Config>::BlockHashCount::get();
+            System::set_block_number(context.last_block_in_range + block_hash_count + 1);
+
+            assert_noop!(
+                Summary::record_summary_calculation(
+                    RawOrigin::None.into(),
+                    context.last_block_in_range,
+                    context.root_hash_h256,
+                    context.root_id.ingress_counter,
+                    context.validator.clone(),
+                    context.record_summary_calculation_signature.clone(),
+                ),
+                Error::<TestRuntime>::HeaderHashExpired
+            );
+        });
+    }
+}
+
+mod publish_root {
+    use super::*;
+
+    #[test]
+    fn sends_the_header_hash_with_the_root() {
+        let mut ext = ExtBuilder::build_default().with_validators().as_externality();
+        ext.execute_with(|| {
+            let context = setup_context();
+            setup_approved_root(context.clone());
+            RootHeaderHashes::<TestRuntime>::insert(
+                context.root_id.range,
+                context.root_id.ingress_counter,
+                HEADER_HASH,
+            );
+
+            end_voting_period(&context);
+
+            assert_eq!(
+                published_root_params(),
+                vec![
+                    (b"bytes32".to_vec(), context.root_hash_h256.as_fixed_bytes().to_vec()),
+                    (b"bytes32".to_vec(), HEADER_HASH.as_fixed_bytes().to_vec()),
+                ]
+            );
+        });
+    }
+}
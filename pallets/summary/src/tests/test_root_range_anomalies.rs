@@ -0,0 +1,97 @@
+// Copyright 2024 Aventus Network Services (UK) Ltd.
+#![cfg(test)]
+
+use crate::{mock::*, *};
+use frame_support::assert_ok;
+use system::RawOrigin;
+
+fn approve_root(context: &Context) {
+    Summary::record_approve_vote(&context.root_id, get_validator(FIRST_VALIDATOR_INDEX).account_id);
+    Summary::record_approve_vote(
+        &context.root_id,
+        get_validator(SECOND_VALIDATOR_INDEX).account_id,
+    );
+    Summary::record_approve_vote(&context.root_id, get_validator(THIRD_VALIDATOR_INDEX).account_id);
+    Summary::set_current_slot(10);
+    Summary::set_previous_summary_slot(5);
+
+    assert_ok!(Summary::end_voting_period(
+        RawOrigin::None.into(),
+        context.root_id,
+        context.validator.clone(),
+        context.record_summary_calculation_signature.clone()
+    ));
+    assert!(Summary::get_root_data(&context.root_id).is_validated);
+}
+
+fn anomaly_detected_event(
+    context: &Context,
+    anomaly: RootRangeAnomaly<BlockNumber>,
+) -> RuntimeEvent {
+    RuntimeEvent::Summary(crate::Event::<TestRuntime>::RootRangeAnomalyDetected {
+        root_id: context.root_id,
+        anomaly,
+    })
+}
+
+#[test]
+fn a_root_that_follows_the_previous_range_is_not_an_anomaly() {
+    let (mut ext, _pool_state, _offchain_state) = ExtBuilder::build_default()
+        .with_validators()
+        .for_offchain_worker()
+        .as_externality_with_state();
+    ext.execute_with(|| {
+        let context = setup_context();
+        setup_voting_for_root_id(&context);
+
+        approve_root(&context);
+
+        assert_eq!(Summary::root_range_anomaly(context.root_id), None);
+        assert!(Summary::root_range_anomalies().is_empty());
+    });
+}
+
+#[test]
+fn a_root_validated_after_its_range_was_partly_skipped_is_an_overlap() {
+    let (mut ext, _pool_state, _offchain_state) = ExtBuilder::build_default()
+        .with_validators()
+        .for_offchain_worker()
+        .as_externality_with_state();
+    ext.execute_with(|| {
+        let context = setup_context();
+        setup_voting_for_root_id(&context);
+        let skipped_range =
+            RootRange::new(context.next_block_to_process, context.next_block_to_process + 9);
+        assert_ok!(Summary::force_skip_range(RawOrigin::Root.into(), skipped_range));
+
+        approve_root(&context);
+
+        let anomaly = RootRangeAnomaly::Overlap(skipped_range);
+        assert_eq!(Summary::root_range_anomaly(context.root_id), Some(anomaly));
+        assert!(Summary::emitted_event(&anomaly_detected_event(&context, anomaly)));
+        assert_eq!(Summary::get_next_block_to_process(), context.last_block_in_range + 1);
+    });
+}
+
+#[test]
+fn a_root_that_starts_after_the_next_block_to_process_is_a_gap() {
+    let (mut ext, _pool_state, _offchain_state) = ExtBuilder::build_default()
+        .with_validators()
+        .for_offchain_worker()
+        .as_externality_with_state();
+    ext.execute_with(|| {
+        let context = setup_context();
+        setup_voting_for_root_id(&context);
+        let next_block_to_process = context.next_block_to_process - 2;
+        Summary::set_next_block_to_process(next_block_to_process);
+
+        approve_root(&context);
+
+        let anomaly = RootRangeAnomaly::Gap(RootRange::new(
+            next_block_to_process,
+            context.next_block_to_process - 1,
+        ));
+        assert_eq!(Summary::root_range_anomalies(), vec![(context.root_id, anomaly)]);
+        assert!(Summary::emitted_event(&anomaly_detected_event(&context, anomaly)));
+    });
+}
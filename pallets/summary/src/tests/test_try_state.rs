@@ -0,0 +1,165 @@
+// Copyright 2024 Aventus Network Services (UK) Ltd.
+#![cfg(test)]
+
+use crate::{mock::*, *};
+use frame_support::{assert_err, assert_ok};
+
+fn pending_root_id() -> RootId<BlockNumber> {
+    RootId::new(RootRange::new(1, 160), 1)
+}
+
+fn record_pending_root(root_id: &RootId<BlockNumber>) {
+    Summary::insert_root_hash(root_id, H256::repeat_byte(1), get_validator(1).account_id, 1);
+    Summary::insert_pending_approval(root_id);
+    Summary::register_root_for_voting(root_id, 2, 10);
+    Summary::set_total_ingresses(root_id.ingress_counter);
+}
+
+fn record_validated_root(root_id: &RootId<BlockNumber>) {
+    Summary::insert_root_hash(root_id, H256::repeat_byte(1), get_validator(1).account_id, 1);
+    Summary::set_root_as_validated(root_id);
+    Summary::set_next_block_to_process(root_id.range.to_block + 1);
+}
+
+mod try_state {
+    use super::*;
+
+    #[test]
+    fn passes_with_a_pending_root() {
+        let mut ext = ExtBuilder::build_default()
+            .with_validators()
+            .with_genesis_config()
+            .as_externality();
+        ext.execute_with(|| {
+            record_pending_root(&pending_root_id());
+
+            assert_ok!(Summary::do_try_state());
+        });
+    }
+
+    #[test]
+    fn fails_when_a_pending_root_has_no_voting_session() {
+        let mut ext = ExtBuilder::build_default()
+            .with_validators()
+            .with_genesis_config()
+            .as_externality();
+        ext.execute_with(|| {
+            let root_id = pending_root_id();
+            record_pending_root(&root_id);
+            Summary::deregister_root_for_voting(&root_id);
+
+            assert_err!(Summary::do_try_state(), "Pending root has no voting session");
+        });
+    }
+
+    #[test]
+    fn fails_when_a_pending_root_is_validated() {
+        let mut ext = ExtBuilder::build_default()
+            .with_validators()
+            .with_genesis_config()
+            .as_externality();
+        ext.execute_with(|| {
+            let root_id = pending_root_id();
+            record_pending_root(&root_id);
+            Summary::set_root_as_validated(&root_id);
+
+            assert_err!(Summary::do_try_state(), "Pending root is already validated");
+        });
+    }
+
+    #[test]
+    fn fails_when_a_transaction_references_an_unknown_root() {
+        let mut ext = ExtBuilder::build_default()
+            .with_validators()
+            .with_genesis_config()
+            .as_externality();
+        ext.execute_with(|| {
+            TxIdToRoot::<TestRuntime>::insert(1, pending_root_id());
+
+            assert_err!(Summary::do_try_state(), "TxIdToRoot references a root missing from Roots");
+        });
+    }
+
+    #[test]
+    fn fails_when_voting_period_is_not_shorter_than_schedule_period() {
+        let mut ext = ExtBuilder::build_default()
+            .with_validators()
+            .with_genesis_config()
+            .as_externality();
+        ext.execute_with(|| {
+            Summary::set_schedule_and_voting_periods(160, 160);
+
+            assert_err!(
+                Summary::do_try_state(),
+                "Voting period is not shorter than the schedule period"
+            );
+        });
+    }
+
+    #[test]
+    fn passes_with_contiguous_validated_roots() {
+        let mut ext = ExtBuilder::build_default()
+            .with_validators()
+            .with_genesis_config()
+            .as_externality();
+        ext.execute_with(|| {
+            record_validated_root(&RootId::new(RootRange::new(1, 160), 1));
+            record_validated_root(&RootId::new(RootRange::new(161, 320), 2));
+
+            assert_ok!(Summary::do_try_state());
+        });
+    }
+
+    #[test]
+    fn fails_when_validated_roots_overlap_without_a_recorded_anomaly() {
+        let mut ext = ExtBuilder::build_default()
+            .with_validators()
+            .with_genesis_config()
+            .as_externality();
+        ext.execute_with(|| {
+            record_validated_root(&RootId::new(RootRange::new(1, 160), 1));
+            record_validated_root(&RootId::new(RootRange::new(100, 320), 2));
+
+            assert_err!(
+                Summary::do_try_state(),
+                "Validated root ranges overlap without a recorded anomaly"
+            );
+        });
+    }
+
+    #[test]
+    fn passes_when_the_overlap_of_validated_roots_was_recorded() {
+        let mut ext = ExtBuilder::build_default()
+            .with_validators()
+            .with_genesis_config()
+            .as_externality();
+        ext.execute_with(|| {
+            let overlapping_root_id = RootId::new(RootRange::new(100, 320), 2);
+            record_validated_root(&RootId::new(RootRange::new(1, 160), 1));
+            record_validated_root(&overlapping_root_id);
+            RootRangeAnomalies::<TestRuntime>::insert(
+                overlapping_root_id,
+                RootRangeAnomaly::Overlap(RootRange::new(100, 160)),
+            );
+
+            assert_ok!(Summary::do_try_state());
+        });
+    }
+
+    #[test]
+    fn fails_when_the_next_block_to_process_is_not_after_the_last_validated_root() {
+        let mut ext = ExtBuilder::build_default()
+            .with_validators()
+            .with_genesis_config()
+            .as_externality();
+        ext.execute_with(|| {
+            record_validated_root(&RootId::new(RootRange::new(1, 160), 1));
+            Summary::set_next_block_to_process(100);
+
+            assert_err!(
+                Summary::do_try_state(),
+                "NextBlockToProcess is not after the last validated root"
+            );
+        });
+    }
+}
@@ -0,0 +1,141 @@
+// Copyright 2024 Aventus Network Services (UK) Ltd.
+#![cfg(test)]
+
+use crate::{mock::*, tests::assert_record_summary_calculation_is_ok, *};
+use frame_support::{assert_noop, traits::Hooks, unsigned::ValidateUnsigned, weights::Weight};
+
+const RECORD_SUMMARY: SummaryUnsignedCall = SummaryUnsignedCall::RecordSummaryCalculation;
+
+fn record_summary_calculation_call(context: &Context) -> crate::Call<TestRuntime> {
+    crate::Call::record_summary_calculation {
+        new_block_number: context.last_block_in_range,
+        root_hash: context.root_hash_h256,
+        ingress_counter: context.root_id.ingress_counter,
+        validator: context.validator.clone(),
+        signature: context.record_summary_calculation_signature.clone(),
+    }
+}
+
+fn set_submissions(context: &Context, submissions: u32, saturated_windows: u32) {
+    UnsignedSubmissions::<TestRuntime>::insert(
+        context.validator.account_id,
+        RECORD_SUMMARY,
+        SubmissionWindow { start: context.current_block_number, submissions, saturated_windows },
+    );
+}
+
+fn max_submissions() -> u32 {
+    Summary::unsigned_rate_limit(RECORD_SUMMARY).max_submissions
+}
+
+fn setup() -> Context {
+    let context = setup_context();
+    setup_blocks(&context);
+    setup_total_ingresses(&context);
+    context
+}
+
+#[test]
+fn executed_unsigned_transactions_are_counted() {
+    let mut ext = ExtBuilder::build_default().with_validators().as_externality();
+    ext.execute_with(|| {
+        let context = setup();
+
+        assert_record_summary_calculation_is_ok(&context);
+
+        let window = Summary::unsigned_submissions(context.validator.account_id, RECORD_SUMMARY);
+        assert_eq!(window.start, context.current_block_number);
+        assert_eq!(window.submissions, 1);
+        assert_eq!(
+            Summary::unsigned_submissions(context.validator.account_id, SummaryUnsignedCall::Vote),
+            Default::default()
+        );
+    });
+}
+
+#[test]
+fn transactions_are_rejected_once_the_validator_used_its_allowance() {
+    let mut ext = ExtBuilder::build_default().with_validators().as_externality();
+    ext.execute_with(|| {
+        let context = setup();
+        set_submissions(&context, max_submissions(), 0);
+
+        assert_noop!(
+            <Summary as ValidateUnsigned>::validate_unsigned(
+                TransactionSource::Local,
+                &record_summary_calculation_call(&context)
+            ),
+            InvalidTransaction::Custom(ERROR_CODE_UNSIGNED_RATE_LIMIT_REACHED)
+        );
+    });
+}
+
+#[test]
+fn transactions_are_accepted_again_in_the_next_window() {
+    let mut ext = ExtBuilder::build_default().with_validators().as_externality();
+    ext.execute_with(|| {
+        let context = setup();
+        set_submissions(&context, max_submissions(), 0);
+        let window = Summary::unsigned_rate_limit(RECORD_SUMMARY).window;
+
+        System::set_block_number(context.current_block_number + window);
+
+        assert!(Summary::unsigned_submission_allowed(
+            &context.validator.account_id,
+            RECORD_SUMMARY
+        ));
+    });
+}
+
+#[test]
+fn reaching_the_limit_in_consecutive_windows_is_reported_as_an_offence() {
+    let mut ext = ExtBuilder::build_default().with_validators().as_externality();
+    ext.execute_with(|| {
+        let context = setup();
+        let abuse_threshold = Summary::unsigned_rate_limit(RECORD_SUMMARY).abuse_threshold;
+        set_submissions(&context, max_submissions() - 1, abuse_threshold - 1);
+
+        assert_record_summary_calculation_is_ok(&context);
+
+        assert!(Summary::reported_offence_of_type(SummaryOffenceType::UnsignedRateLimitReached));
+        assert!(Summary::emitted_event_for_offence_of_type(
+            SummaryOffenceType::UnsignedRateLimitReached
+        ));
+    });
+}
+
+#[test]
+fn reaching_the_limit_in_a_single_window_is_not_an_offence() {
+    let mut ext = ExtBuilder::build_default().with_validators().as_externality();
+    ext.execute_with(|| {
+        let context = setup();
+        set_submissions(&context, max_submissions() - 1, 0);
+
+        assert_record_summary_calculation_is_ok(&context);
+
+        assert!(!Summary::reported_offence_of_type(SummaryOffenceType::UnsignedRateLimitReached));
+    });
+}
+
+#[test]
+fn expired_windows_are_pruned_when_the_pallet_is_idle() {
+    let mut ext = ExtBuilder::build_default().with_validators().as_externality();
+    ext.execute_with(|| {
+        let context = setup();
+        set_submissions(&context, max_submissions(), 0);
+        let window = Summary::unsigned_rate_limit(RECORD_SUMMARY).window;
+        let first_idle_block = (context.current_block_number / window + 1) * window;
+
+        Summary::on_idle(first_idle_block, Weight::MAX);
+        assert!(UnsignedSubmissions::<TestRuntime>::contains_key(
+            context.validator.account_id,
+            RECORD_SUMMARY
+        ));
+
+        Summary::on_idle(first_idle_block + 2 * window, Weight::MAX);
+        assert!(!UnsignedSubmissions::<TestRuntime>::contains_key(
+            context.validator.account_id,
+            RECORD_SUMMARY
+        ));
+    });
+}
@@ -0,0 +1,100 @@
+// Copyright 2024 Aventus Network Services (UK) Ltd.
+#![cfg(test)]
+
+use crate::{mock::*, *};
+
+const ROOT_HASH: H256 = H256::repeat_byte(1);
+const TX_ID: EthereumTransactionId = 3;
+
+fn root_id() -> RootId<BlockNumber> {
+    RootId::new(RootRange::new(11, 20), 1)
+}
+
+fn record_root(root_id: &RootId<BlockNumber>) {
+    Summary::insert_root_hash(root_id, ROOT_HASH, get_validator(1).account_id, TX_ID);
+}
+
+mod validated_root_for_block {
+    use super::*;
+
+    #[test]
+    fn returns_the_root_covering_the_block() {
+        let mut ext = ExtBuilder::build_default().with_validators().as_externality();
+        ext.execute_with(|| {
+            record_root(&root_id());
+            Summary::set_root_as_validated(&root_id());
+
+            let expected_root = ValidatedRoot {
+                from_block: 11,
+                to_block: 20,
+                root_hash: ROOT_HASH,
+                header_hash: None,
+                tx_id: Some(TX_ID),
+            };
+            for block_number in [11, 15, 20] {
+                assert_eq!(
+                    Summary::validated_root_for_block(block_number),
+                    Some(expected_root.clone())
+                );
+            }
+        });
+    }
+
+    #[test]
+    fn ignores_blocks_outside_the_range() {
+        let mut ext = ExtBuilder::build_default().with_validators().as_externality();
+        ext.execute_with(|| {
+            record_root(&root_id());
+            Summary::set_root_as_validated(&root_id());
+
+            assert_eq!(Summary::validated_root_for_block(10), None);
+            assert_eq!(Summary::validated_root_for_block(21), None);
+        });
+    }
+
+    #[test]
+    fn returns_the_root_of_the_range_including_the_block() {
+        let mut ext = ExtBuilder::build_default().with_validators().as_externality();
+        ext.execute_with(|| {
+            let next_root_id = RootId::new(RootRange::new(21, 30), 2);
+            record_root(&root_id());
+            record_root(&next_root_id);
+            Summary::set_root_as_validated(&root_id());
+            Summary::set_root_as_validated(&next_root_id);
+
+            assert_eq!(Summary::validated_root_for_block(20).map(|root| root.to_block), Some(20));
+            assert_eq!(Summary::validated_root_for_block(21).map(|root| root.to_block), Some(30));
+            assert_eq!(Summary::validated_root_for_block(31), None);
+        });
+    }
+
+    #[test]
+    fn ignores_roots_that_are_not_validated() {
+        let mut ext = ExtBuilder::build_default().with_validators().as_externality();
+        ext.execute_with(|| {
+            record_root(&root_id());
+
+            assert_eq!(Summary::validated_root_for_block(15), None);
+        });
+    }
+
+    #[test]
+    fn includes_the_header_hash_of_the_root() {
+        let mut ext = ExtBuilder::build_default().with_validators().as_externality();
+        ext.execute_with(|| {
+            let header_hash = H256::repeat_byte(2);
+            record_root(&root_id());
+            Summary::set_root_as_validated(&root_id());
+            RootHeaderHashes::<TestRuntime>::insert(
+                root_id().range,
+                root_id().ingress_counter,
+                header_hash,
+            );
+
+            assert_eq!(
+                Summary::validated_root_for_block(15).and_then(|root| root.header_hash),
+                Some(header_hash)
+            );
+        });
+    }
+}
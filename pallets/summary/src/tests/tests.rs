@@ -933,7 +933,8 @@ pub mod record_summary_calculation {
                         ayes: BoundedVec::default(),
                         nays: BoundedVec::default(),
                         end_of_voting_period: VOTING_PERIOD_END,
-                        created_at_block: 10 // Setup creates block number 10
+                        created_at_block: 10, // Setup creates block number 10
+                        replaced_votes: BoundedVec::default(),
                     }
                 );
             });
@@ -965,6 +966,28 @@ pub mod record_summary_calculation {
                     )));
             });
         }
+
+        #[test]
+        fn the_vote_on_the_root_is_hinted_to_the_offchain_workers() {
+            let (mut ext, _pool_state, _offchain_state) = ExtBuilder::build_default()
+                .with_validators()
+                .for_offchain_worker()
+                .as_externality_with_state();
+
+            ext.execute_with(|| {
+                let context = setup_context();
+
+                setup_blocks(&context);
+                setup_total_ingresses(&context);
+
+                assert!(record_summary_calculation_is_ok(&context));
+
+                let hints = AVN::<TestRuntime>::ocw_task_hints();
+                assert_eq!(hints.len(), 1);
+                assert_eq!(hints[0].owner.to_vec(), Summary::pallet_id());
+                assert_eq!(hints[0].id.to_vec(), context.root_id.encode());
+            });
+        }
     }
 
     mod fails {
@@ -2,7 +2,7 @@
 #![cfg(test)]
 
 use crate::{mock::*, *};
-use frame_support::{assert_noop, assert_ok};
+use frame_support::{assert_noop, assert_ok, traits::Get};
 use sp_runtime::traits::BadOrigin;
 use system::RawOrigin;
 
@@ -91,6 +91,34 @@ mod test_set_periods {
         }
     }
 
+    mod with_instance_limits {
+        use super::*;
+
+        #[test]
+        fn an_instance_accepts_periods_below_the_minimum_of_another() {
+            let mut ext = ExtBuilder::build_default()
+                .with_validators()
+                .with_genesis_config()
+                .as_externality();
+            ext.execute_with(|| {
+                let schedule_period = <TestRuntime as Config>::MinSchedulePeriod::get() - 1;
+                let voting_period = <TestRuntime as Config>::MinVotingPeriod::get() - 1;
+
+                assert_noop!(
+                    Summary::set_periods(RawOrigin::Root.into(), schedule_period, voting_period),
+                    Error::<TestRuntime>::SchedulePeriodIsTooShort
+                );
+                assert_ok!(AnchorSummary::set_periods(
+                    RawOrigin::Root.into(),
+                    schedule_period,
+                    voting_period
+                ));
+                assert_eq!(AnchorSummary::schedule_period(), schedule_period);
+                assert_eq!(AnchorSummary::voting_period(), voting_period);
+            });
+        }
+    }
+
     mod fails_when {
         use super::*;
 
@@ -137,7 +165,7 @@ mod test_set_periods {
                     .as_externality();
                 ext.execute_with(|| {
                     let context: Context = Context {
-                        new_schedule_period: (MIN_SCHEDULE_PERIOD - 1).into(),
+                        new_schedule_period: <TestRuntime as Config>::MinSchedulePeriod::get() - 1,
                         ..Default::default()
                     };
 
@@ -148,6 +176,26 @@ mod test_set_periods {
                     assert_ne!(context.new_schedule_period, Summary::schedule_period());
                 });
             }
+
+            #[test]
+            fn more_than_maximum_value_should_fail() {
+                let mut ext = ExtBuilder::build_default()
+                    .with_validators()
+                    .with_genesis_config()
+                    .as_externality();
+                ext.execute_with(|| {
+                    let context: Context = Context {
+                        new_schedule_period: <TestRuntime as Config>::MaxSchedulePeriod::get() + 1,
+                        ..Default::default()
+                    };
+
+                    assert_noop!(
+                        context.dispatch_set_schedule_period(),
+                        Error::<TestRuntime>::SchedulePeriodIsTooLong
+                    );
+                    assert_ne!(context.new_schedule_period, Summary::schedule_period());
+                });
+            }
         }
 
         mod set_voting_period {
@@ -193,7 +241,7 @@ mod test_set_periods {
                     .as_externality();
                 ext.execute_with(|| {
                     let context: Context = Context {
-                        new_voting_period: (MIN_VOTING_PERIOD - 1).into(),
+                        new_voting_period: <TestRuntime as Config>::MinVotingPeriod::get() - 1,
                         ..Default::default()
                     };
 
@@ -246,4 +294,144 @@ mod test_set_periods {
             }
         }
     }
+
+    mod announced_by_the_admin {
+        use super::*;
+        use frame_support::traits::Hooks;
+
+        fn admin_context() -> Context {
+            Context { origin: RuntimeOrigin::signed(ADMIN_ACCOUNT), ..Default::default() }
+        }
+
+        fn schedule_name(context: &Context) -> [u8; 32] {
+            Summary::admin_change_name(&Call::set_periods {
+                schedule_period_in_blocks: context.new_schedule_period,
+                voting_period_in_blocks: context.voting_period,
+            })
+        }
+
+        fn roll_to(block_number: BlockNumber) {
+            System::set_block_number(block_number);
+            Scheduler::on_initialize(block_number);
+        }
+
+        #[test]
+        fn are_executed_after_the_delay() {
+            let mut ext = ExtBuilder::build_default()
+                .with_validators()
+                .with_genesis_config()
+                .as_externality();
+            ext.execute_with(|| {
+                System::set_block_number(1);
+                let context = admin_context();
+                let execute_at = 1 + <TestRuntime as Config>::AdminChangeDelay::get();
+                let schedule_period = Summary::schedule_period();
+
+                assert_ok!(context.dispatch_set_schedule_period());
+                assert_eq!(schedule_period, Summary::schedule_period());
+                assert!(System::events().iter().any(|e| e.event ==
+                    RuntimeEvent::Summary(
+                        Event::<TestRuntime>::SchedulePeriodAndVotingPeriodChangeAnnounced {
+                            schedule_period: context.new_schedule_period,
+                            voting_period: context.voting_period,
+                            execute_at,
+                            schedule_name: schedule_name(&context),
+                        }
+                    )));
+
+                roll_to(execute_at - 1);
+                assert_eq!(schedule_period, Summary::schedule_period());
+
+                roll_to(execute_at);
+                assert_eq!(context.new_schedule_period, Summary::schedule_period());
+                assert!(!PendingAdminChanges::<TestRuntime>::contains_key(schedule_name(&context)));
+            });
+        }
+
+        #[test]
+        fn are_not_executed_when_cancelled() {
+            let mut ext = ExtBuilder::build_default()
+                .with_validators()
+                .with_genesis_config()
+                .as_externality();
+            ext.execute_with(|| {
+                System::set_block_number(1);
+                let context = admin_context();
+                let execute_at = 1 + <TestRuntime as Config>::AdminChangeDelay::get();
+                let schedule_period = Summary::schedule_period();
+
+                assert_ok!(context.dispatch_set_schedule_period());
+                assert_ok!(Summary::cancel_admin_change(
+                    RuntimeOrigin::signed(ADMIN_ACCOUNT),
+                    schedule_name(&context)
+                ));
+
+                roll_to(execute_at);
+                assert_eq!(schedule_period, Summary::schedule_period());
+            });
+        }
+
+        #[test]
+        fn are_superseded_by_a_newer_announcement() {
+            let mut ext = ExtBuilder::build_default()
+                .with_validators()
+                .with_genesis_config()
+                .as_externality();
+            ext.execute_with(|| {
+                System::set_block_number(1);
+                let context = admin_context();
+                let newer_context = Context { new_schedule_period: 250, ..admin_context() };
+                let execute_at = 1 + <TestRuntime as Config>::AdminChangeDelay::get();
+
+                assert_ok!(context.dispatch_set_schedule_period());
+                assert_ok!(newer_context.dispatch_set_schedule_period());
+                assert!(!PendingAdminChanges::<TestRuntime>::contains_key(schedule_name(&context)));
+                assert!(System::events().iter().any(|e| e.event ==
+                    RuntimeEvent::Summary(Event::<TestRuntime>::AdminChangeCancelled {
+                        schedule_name: schedule_name(&context),
+                    })));
+
+                roll_to(execute_at);
+                assert_eq!(newer_context.new_schedule_period, Summary::schedule_period());
+            });
+        }
+
+        #[test]
+        fn are_forgotten_once_their_execution_block_has_passed() {
+            let mut ext = ExtBuilder::build_default()
+                .with_validators()
+                .with_genesis_config()
+                .as_externality();
+            ext.execute_with(|| {
+                let context = admin_context();
+                PendingAdminChanges::<TestRuntime>::insert(schedule_name(&context), 5);
+
+                Summary::on_initialize(5);
+                assert!(PendingAdminChanges::<TestRuntime>::contains_key(schedule_name(&context)));
+
+                Summary::on_initialize(6);
+                assert!(!PendingAdminChanges::<TestRuntime>::contains_key(schedule_name(&context)));
+            });
+        }
+
+        #[test]
+        fn do_not_affect_other_instances() {
+            let mut ext = ExtBuilder::build_default()
+                .with_validators()
+                .with_genesis_config()
+                .as_externality();
+            ext.execute_with(|| {
+                let context = admin_context();
+
+                assert_ok!(context.dispatch_set_schedule_period());
+                assert_noop!(
+                    AnchorSummary::cancel_admin_change(
+                        RuntimeOrigin::signed(ADMIN_ACCOUNT),
+                        schedule_name(&context)
+                    ),
+                    Error::<TestRuntime, Instance1>::AdminChangeNotFound
+                );
+            });
+        }
+    }
 }
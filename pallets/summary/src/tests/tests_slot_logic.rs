@@ -824,6 +824,10 @@ mod cases_for_no_summary_created_offences {
                         crate::Event::<TestRuntime>::SummaryOffenceReported {
                             offence_type: SummaryOffenceType::NoSummaryCreated,
                             offenders,
+                            evidence: Summary::offence_evidence_of_type(
+                                SummaryOffenceType::NoSummaryCreated,
+                            )
+                            .unwrap(),
                         },
                     );
                     assert_eq!(true, Summary::emitted_event(&offence_reported_event));
@@ -5,6 +5,7 @@
 use crate::{mock::*, system};
 use frame_support::{assert_noop, assert_ok};
 use pallet_avn::Error as AvNError;
+use sp_avn_common::ocw_task_queue::{self as OcwTaskQueue, OcwTaskPriority};
 use sp_runtime::{testing::UintAuthorityId, traits::BadOrigin};
 use system::RawOrigin;
 
@@ -24,6 +25,13 @@ fn setup_voting_for_root_id(context: &Context) {
     assert_eq!(Summary::get_vote(context.root_id).nays.is_empty(), true);
 }
 
+fn exhaust_inconclusive_voting_rounds(context: &Context) {
+    InconclusiveVotingRounds::<TestRuntime>::insert(
+        context.root_id,
+        crate::MAX_INCONCLUSIVE_VOTING_ROUNDS,
+    );
+}
+
 pub fn setup_approved_root(context: Context) {
     setup_voting_for_root_id(&context);
 
@@ -115,15 +123,12 @@ mod approve_root {
                 let second_validator = get_validator(SECOND_VALIDATOR_INDEX);
                 set_mock_recovered_account_id(second_validator.account_id);
 
-                assert_eq!(
-                    Result::Ok(()),
-                    Summary::approve_root(
-                        RawOrigin::None.into(),
-                        context.root_id,
-                        second_validator.clone(),
-                        context.record_summary_calculation_signature.clone()
-                    )
-                );
+                assert_ok!(Summary::approve_root(
+                    RawOrigin::None.into(),
+                    context.root_id,
+                    second_validator.clone(),
+                    context.record_summary_calculation_signature.clone()
+                ));
 
                 assert_eq!(
                     Summary::get_vote(&(context.root_id)).ayes,
@@ -158,15 +163,12 @@ mod approve_root {
                 let second_validator = get_validator(SECOND_VALIDATOR_INDEX);
                 set_mock_recovered_account_id(second_validator.account_id);
 
-                assert_eq!(
-                    Result::Ok(()),
-                    Summary::approve_root(
-                        RawOrigin::None.into(),
-                        context.root_id,
-                        second_validator.clone(),
-                        context.record_summary_calculation_signature.clone()
-                    )
-                );
+                assert_ok!(Summary::approve_root(
+                    RawOrigin::None.into(),
+                    context.root_id,
+                    second_validator.clone(),
+                    context.record_summary_calculation_signature.clone()
+                ));
 
                 assert_eq!(
                     Summary::get_vote(context.root_id).ayes,
@@ -200,15 +202,12 @@ mod approve_root {
                 assert!(vote_to_reject_root(&second_validator, &context));
 
                 set_mock_recovered_account_id(third_validator.account_id);
-                assert_eq!(
-                    Result::Ok(()),
-                    Summary::approve_root(
-                        RawOrigin::None.into(),
-                        context.root_id,
-                        third_validator.clone(),
-                        context.record_summary_calculation_signature.clone()
-                    )
-                );
+                assert_ok!(Summary::approve_root(
+                    RawOrigin::None.into(),
+                    context.root_id,
+                    third_validator.clone(),
+                    context.record_summary_calculation_signature.clone()
+                ));
 
                 assert_eq!(
                     Summary::get_vote(context.root_id).ayes,
@@ -789,10 +788,69 @@ mod cast_votes_if_required {
                 cast_votes_if_required::<TestRuntime, ()>(&second_validator);
 
                 assert!(pool_state.read().transactions.is_empty());
+                let tasks = OcwTaskQueue::queued_tasks::<BlockNumber>(Some(&Summary::pallet_id()));
+                assert_eq!(tasks.len(), 1);
+                assert_eq!(tasks[0].leased_until, None);
+                assert_eq!(tasks[0].attempts, 1);
             });
         }
     }
 
+    #[test]
+    fn completes_the_vote_task_of_the_root_once_the_vote_is_sent() {
+        let (mut ext, pool_state, offchain_state) = ExtBuilder::build_default()
+            .with_validators()
+            .for_offchain_worker()
+            .as_externality_with_state();
+
+        ext.execute_with(|| {
+            let context = setup_context();
+            let owner = Summary::pallet_id();
+
+            mock_response_of_get_roothash(
+                &mut offchain_state.write(),
+                context.url_param.clone(),
+                Some(context.root_hash_vec.clone()),
+            );
+
+            setup_voting_for_root_id(&context);
+            assert_ok!(OcwTaskQueue::enqueue(
+                &owner,
+                &context.root_id.encode(),
+                OcwTaskPriority::High,
+                System::block_number()
+            ));
+
+            cast_votes_if_required::<TestRuntime, ()>(&get_validator(SECOND_VALIDATOR_INDEX));
+
+            assert_eq!(pool_state.read().transactions.len(), 1);
+            assert!(OcwTaskQueue::queued_tasks::<BlockNumber>(Some(&owner)).is_empty());
+        });
+    }
+
+    #[test]
+    fn completes_the_vote_tasks_of_roots_no_longer_pending() {
+        let (mut ext, _pool_state, _offchain_state) = ExtBuilder::build_default()
+            .with_validators()
+            .for_offchain_worker()
+            .as_externality_with_state();
+
+        ext.execute_with(|| {
+            let context = setup_context();
+            let owner = Summary::pallet_id();
+            assert_ok!(OcwTaskQueue::enqueue(
+                &owner,
+                &context.root_id.encode(),
+                OcwTaskPriority::High,
+                System::block_number()
+            ));
+
+            cast_votes_if_required::<TestRuntime, ()>(&get_validator(SECOND_VALIDATOR_INDEX));
+
+            assert!(OcwTaskQueue::queued_tasks::<BlockNumber>(Some(&owner)).is_empty());
+        });
+    }
+
     #[test]
     fn sends_approve_vote_transaction_when_root_hash_is_valid() {
         let (mut ext, pool_state, offchain_state) = ExtBuilder::build_default()
@@ -922,7 +980,8 @@ mod end_voting_period {
                         crate::Event::<TestRuntime>::SummaryRootValidated {
                             block_range: context.root_id.range,
                             root_hash: context.root_hash_h256,
-                            ingress_counter: context.root_id.ingress_counter
+                            ingress_counter: context.root_id.ingress_counter,
+                            sequence: 0,
                         }
                     )));
 
@@ -947,6 +1006,7 @@ mod end_voting_period {
                 Summary::set_previous_summary_slot(previous_slot);
 
                 setup_voting_for_root_id(&context);
+                exhaust_inconclusive_voting_rounds(&context);
                 System::set_block_number(50);
 
                 assert!(Summary::end_voting_period(
@@ -1344,6 +1404,7 @@ mod end_voting_period {
                     let context = setup_context();
                     setup_voting_for_root_id(&context);
                     let (_ayes, _nays) = setup_root_without_outcome(&context);
+                    exhaust_inconclusive_voting_rounds(&context);
 
                     end_voting_without_outcome(&context);
 
@@ -1372,6 +1433,7 @@ mod end_voting_period {
                     let context = setup_context();
                     setup_voting_for_root_id(&context);
                     let (ayes, _nays) = setup_root_without_outcome(&context);
+                    exhaust_inconclusive_voting_rounds(&context);
 
                     end_voting_without_outcome(&context);
 
@@ -1389,3 +1451,99 @@ mod end_voting_period {
         }
     }
 }
+
+mod actual_weight {
+    use super::*;
+    use crate::default_weights::WeightInfo;
+
+    type Weights = <TestRuntime as crate::Config>::WeightInfo;
+
+    fn validator_count() -> u32 {
+        AVN::<TestRuntime>::validators().len() as u32
+    }
+
+    #[test]
+    fn is_returned_for_a_vote_that_does_not_end_the_voting() {
+        let (mut ext, _pool_state, _offchain_state) = ExtBuilder::build_default()
+            .with_validators()
+            .for_offchain_worker()
+            .as_externality_with_state();
+
+        ext.execute_with(|| {
+            let context = setup_context();
+            setup_voting_for_root_id(&context);
+
+            let post_info = Summary::approve_root(
+                RawOrigin::None.into(),
+                context.root_id,
+                context.validator.clone(),
+                context.record_summary_calculation_signature.clone(),
+            )
+            .unwrap();
+
+            assert_eq!(
+                post_info.actual_weight,
+                Some(Weights::approve_root_without_end_voting(validator_count(), 1))
+            );
+        });
+    }
+
+    #[test]
+    fn is_returned_when_ending_the_voting_of_an_approved_root() {
+        let (mut ext, _pool_state, _offchain_state) = ExtBuilder::build_default()
+            .with_validators()
+            .for_offchain_worker()
+            .as_externality_with_state();
+
+        ext.execute_with(|| {
+            let context = setup_context();
+            setup_approved_root(context.clone());
+
+            let post_info = Summary::end_voting_period(
+                RawOrigin::None.into(),
+                context.root_id,
+                context.validator.clone(),
+                context.record_summary_calculation_signature.clone(),
+            )
+            .unwrap();
+
+            let no_offenders = 0;
+            assert_eq!(
+                post_info.actual_weight,
+                Some(Weights::end_voting_period_with_rejected_valid_votes(
+                    validator_count(),
+                    no_offenders
+                ))
+            );
+        });
+    }
+
+    #[test]
+    fn is_returned_when_ending_the_voting_of_a_rejected_root() {
+        let (mut ext, _pool_state, _offchain_state) =
+            ExtBuilder::build_default().for_offchain_worker().as_externality_with_state();
+
+        ext.execute_with(|| {
+            let context = setup_context();
+            setup_voting_for_root_id(&context);
+            System::set_block_number(50);
+
+            let post_info = Summary::end_voting_period(
+                RawOrigin::None.into(),
+                context.root_id,
+                context.validator.clone(),
+                context.record_summary_calculation_signature.clone(),
+            )
+            .unwrap();
+
+            let root_creator = 1;
+            assert_eq!(
+                post_info.actual_weight,
+                Some(Weights::end_voting_period_with_approved_invalid_votes(
+                    validator_count(),
+                    root_creator
+                ))
+            );
+        });
+    }
+}
@@ -0,0 +1,103 @@
+//! Invariants of the summary state, checked by try-runtime before and after runtime upgrades
+
+use crate::{
+    AdaptiveSchedulePeriod, Config, NextBlockToProcess, Pallet, PendingApproval, RootId,
+    RootRangeAnomalies, Roots, SchedulePeriod, TotalIngresses, TxIdToRoot, VotesRepository,
+    VotingPeriod,
+};
+use frame_support::ensure;
+use sp_runtime::{traits::Zero, DispatchError};
+use sp_std::prelude::*;
+
+impl<T: Config<I>, I: 'static> Pallet<T, I> {
+    pub(crate) fn do_try_state() -> Result<(), DispatchError> {
+        Self::check_pending_approvals()?;
+        Self::check_submitted_roots()?;
+        Self::check_validated_root_ranges()?;
+        Self::check_periods()
+    }
+
+    /// Every root pending approval must be a recorded, unvalidated root with an open voting session
+    fn check_pending_approvals() -> Result<(), DispatchError> {
+        let total_ingresses = <TotalIngresses<T, I>>::get();
+
+        for (range, ingress_counter) in <PendingApproval<T, I>>::iter() {
+            ensure!(
+                ingress_counter <= total_ingresses,
+                "Pending root has an ingress counter greater than TotalIngresses"
+            );
+            let root = <Roots<T, I>>::get(range, ingress_counter)
+                .ok_or("Pending root is missing from Roots")?;
+            ensure!(!root.is_validated, "Pending root is already validated");
+            ensure!(
+                <VotesRepository<T, I>>::contains_key(RootId::new(range, ingress_counter)),
+                "Pending root has no voting session"
+            );
+        }
+
+        Ok(())
+    }
+
+    /// Every root submitted to Ethereum must reference its transaction
+    fn check_submitted_roots() -> Result<(), DispatchError> {
+        for (tx_id, root_id) in <TxIdToRoot<T, I>>::iter() {
+            let root = <Roots<T, I>>::get(root_id.range, root_id.ingress_counter)
+                .ok_or("TxIdToRoot references a root missing from Roots")?;
+            ensure!(
+                root.tx_id == Some(tx_id),
+                "TxIdToRoot references a root with a different transaction id"
+            );
+        }
+
+        Ok(())
+    }
+
+    /// Validated roots must not overlap, unless the overlap was detected when the root was
+    /// validated, and summaries must resume after the last validated root
+    fn check_validated_root_ranges() -> Result<(), DispatchError> {
+        let mut validated_roots: Vec<RootId<_>> = <Roots<T, I>>::iter()
+            .filter(|(_, _, root)| root.is_validated)
+            .map(|(range, ingress_counter, _)| RootId::new(range, ingress_counter))
+            .collect();
+        validated_roots.sort_by_key(|root_id| root_id.range.from_block);
+
+        for roots in validated_roots.windows(2) {
+            let (previous, next) = (&roots[0], &roots[1]);
+            ensure!(
+                next.range.from_block > previous.range.to_block ||
+                    <RootRangeAnomalies<T, I>>::contains_key(previous) ||
+                    <RootRangeAnomalies<T, I>>::contains_key(next),
+                "Validated root ranges overlap without a recorded anomaly"
+            );
+        }
+
+        if let Some(last_to_block) =
+            validated_roots.iter().map(|root_id| root_id.range.to_block).max()
+        {
+            ensure!(
+                last_to_block < <NextBlockToProcess<T, I>>::get(),
+                "NextBlockToProcess is not after the last validated root"
+            );
+        }
+
+        Ok(())
+    }
+
+    /// Votes must end before the next summary is due
+    fn check_periods() -> Result<(), DispatchError> {
+        let schedule_period = <SchedulePeriod<T, I>>::get();
+        ensure!(
+            schedule_period.is_zero() || <VotingPeriod<T, I>>::get() < schedule_period,
+            "Voting period is not shorter than the schedule period"
+        );
+        if let Some(bounds) = <AdaptiveSchedulePeriod<T, I>>::get() {
+            ensure!(bounds.min <= bounds.max, "Adaptive schedule period bounds are inverted");
+            ensure!(
+                <VotingPeriod<T, I>>::get() < bounds.min,
+                "Voting period is not shorter than the adaptive schedule period"
+            );
+        }
+
+        Ok(())
+    }
+}
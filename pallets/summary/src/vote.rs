@@ -6,7 +6,10 @@ extern crate alloc;
 use alloc::string::String;
 
 use codec::{Decode, Encode, MaxEncodedLen};
-use sp_avn_common::event_types::Validator;
+use sp_avn_common::{
+    event_types::Validator,
+    ocw_task_queue::{self as OcwTaskQueue, OcwTaskPriority},
+};
 use sp_std::prelude::*;
 
 use frame_system::{offchain::SubmitTransaction, pallet_prelude::BlockNumberFor};
@@ -19,6 +22,7 @@ use super::{Call, Config};
 use crate::{OcwLock, Pallet as Summary, PendingApproval, RootId, VotesRepository, AVN};
 
 pub const CAST_VOTE_CONTEXT: &'static [u8] = b"root_casting_vote";
+pub const REPLACE_VOTE_CONTEXT: &'static [u8] = b"root_replacing_vote";
 pub const END_VOTING_PERIOD_CONTEXT: &'static [u8] = b"root_end_voting_period";
 const MAX_VOTING_SESSIONS_RETURNED: usize = 5;
 
@@ -44,6 +48,10 @@ impl<T: Config<I>, I: 'static> VotingSessionManager<T::AccountId, BlockNumberFor
         return CAST_VOTE_CONTEXT
     }
 
+    fn replace_vote_context(&self) -> &'static [u8] {
+        return REPLACE_VOTE_CONTEXT
+    }
+
     fn end_voting_period_context(&self) -> &'static [u8] {
         return END_VOTING_PERIOD_CONTEXT
     }
@@ -74,7 +82,7 @@ impl<T: Config<I>, I: 'static> VotingSessionManager<T::AccountId, BlockNumberFor
         let pending_approval_root_ingress_counter =
             PendingApproval::<T, I>::get(self.root_id.range);
         let vote_is_for_correct_version_of_root_range =
-            pending_approval_root_ingress_counter == self.root_id.ingress_counter;
+            pending_approval_root_ingress_counter == Some(self.root_id.ingress_counter);
 
         return !root_already_accepted && vote_is_for_correct_version_of_root_range
     }
@@ -103,6 +111,15 @@ impl<T: Config<I>, I: 'static> VotingSessionManager<T::AccountId, BlockNumberFor
         Ok(())
     }
 
+    fn record_replaced_vote(&self, voter: T::AccountId, approve: bool) -> DispatchResult {
+        VotesRepository::<T, I>::try_mutate(&self.root_id, |vote| -> DispatchResult {
+            vote.replace_vote(voter, approve)
+                .map_err(|_| avn_error::<T>::VoteCannotBeReplaced)?;
+            Ok(())
+        })?;
+        Ok(())
+    }
+
     fn end_voting_session(&self, sender: T::AccountId) -> DispatchResult {
         return Summary::<T, I>::end_voting(sender, &self.root_id)
     }
@@ -114,6 +131,7 @@ pub fn create_vote_lock_name<T: Config<I>, I: 'static>(
     root_id: &RootId<BlockNumberFor<T>>,
 ) -> Vec<u8> {
     let mut name = b"vote_summary::hash::".to_vec();
+    name.push(T::InstanceId::get());
     name.extend_from_slice(&mut root_id.range.from_block.encode());
     name.extend_from_slice(&mut root_id.range.to_block.encode());
     name.extend_from_slice(&mut root_id.ingress_counter.encode());
@@ -127,67 +145,173 @@ fn is_vote_in_transaction_pool<T: Config<I>, I: 'static>(
     return OcwLock::is_locked::<frame_system::Pallet<T>>(&persistent_data)
 }
 
-pub fn cast_votes_if_required<T: Config<I>, I: 'static>(
+/// Removes the vote tasks of roots that are no longer pending approval
+fn complete_vote_tasks_of_closed_roots<T: Config<I>, I: 'static>() {
+    let owner = Summary::<T, I>::pallet_id();
+    for task in OcwTaskQueue::queued_tasks::<BlockNumberFor<T>>(Some(&owner)) {
+        let is_pending = RootId::<BlockNumberFor<T>>::decode(&mut task.id.as_slice())
+            .map(|root_id| {
+                PendingApproval::<T, I>::get(root_id.range) == Some(root_id.ingress_counter)
+            })
+            .unwrap_or(false);
+        if !is_pending {
+            let _ = OcwTaskQueue::complete::<BlockNumberFor<T>>(&owner, &task.id);
+        }
+    }
+}
+
+/// Queues a vote task for each root this validator can vote on. The hint of a root is missed if
+/// the offchain worker did not run in the block it was registered in, for example because the node
+/// was restarting.
+fn enqueue_vote_tasks<T: Config<I>, I: 'static>(
     this_validator: &Validator<<T as avn::Config>::AuthorityId, T::AccountId>,
-) {
+) -> Vec<RootId<BlockNumberFor<T>>> {
+    let owner = Summary::<T, I>::pallet_id();
+    let now = <frame_system::Pallet<T>>::block_number();
+
     let root_ids: Vec<RootId<BlockNumberFor<T>>> = PendingApproval::<T, I>::iter()
-        .filter(|(root_range, ingress_counter)| {
-            let root_id = RootId::new(*root_range, *ingress_counter);
-            root_can_be_voted_on::<T, I>(&root_id, &this_validator.account_id)
-        })
-        .take(MAX_VOTING_SESSIONS_RETURNED)
         .map(|(root_range, ingress_counter)| RootId::new(root_range, ingress_counter))
+        .filter(|root_id| root_can_be_voted_on::<T, I>(root_id, &this_validator.account_id))
+        .take(MAX_VOTING_SESSIONS_RETURNED)
         .collect();
 
-    // try to send 1 of MAX_VOTING_SESSIONS_RETURNED votes
-    for root_id in root_ids {
-        let vote_lock_name = create_vote_lock_name::<T, I>(&root_id);
-        let mut lock = AVN::<T>::get_ocw_locker(&vote_lock_name);
+    for root_id in root_ids.iter() {
+        if let Err(e) =
+            OcwTaskQueue::enqueue(&owner, &root_id.encode(), OcwTaskPriority::Normal, now)
+        {
+            log::warn!("💔️ Unable to queue the vote task of root {:?}: {:?}", root_id, e);
+        }
+    }
 
-        if let Ok(guard) = lock.try_lock() {
-            let root_hash = Summary::<T, I>::compute_root_hash(
-                root_id.range.from_block,
-                root_id.range.to_block,
-            );
+    root_ids
+}
 
-            if root_hash.is_err() {
-                log::error!(
-                    "💔️ Error getting root hash while signing root id {:?} to vote",
-                    &root_id
-                );
-                continue
-            }
+enum VoteOutcome {
+    Sent,
+    Failed,
+    /// The vote is being handled by another run of the worker, or has to wait for the lock to
+    /// expire
+    Pending,
+}
 
-            let root_data = Summary::<T, I>::try_get_root_data(&root_id);
-            if let Err(e) = root_data {
-                log::error!(
-                    "💔️ Error getting root data while signing root id {:?} to vote. {:?}",
-                    &root_id,
-                    e
-                );
-                continue
-            }
+fn vote_on_root<T: Config<I>, I: 'static>(
+    root_id: &RootId<BlockNumberFor<T>>,
+    this_validator: &Validator<<T as avn::Config>::AuthorityId, T::AccountId>,
+) -> VoteOutcome {
+    let vote_lock_name = create_vote_lock_name::<T, I>(root_id);
+    let mut lock = AVN::<T>::get_ocw_locker(&vote_lock_name);
+
+    let guard = match lock.try_lock() {
+        Ok(guard) => guard,
+        Err(_) => {
+            log::trace!(target: "avn", "🤷 Unable to acquire local lock for root {:?}. Lock probably exists already", root_id);
+            return VoteOutcome::Pending
+        },
+    };
 
-            if root_hash.expect("has valid hash") == root_data.expect("checked for error").root_hash
-            {
-                if send_approve_vote::<T, I>(&root_id, this_validator).is_err() {
-                    // TODO: should we output any error message here?
-                    continue
-                }
-            } else {
-                if send_reject_vote::<T, I>(&root_id, this_validator).is_err() {
-                    // TODO: should we output any error message here?
+    let root_hash =
+        Summary::<T, I>::compute_root_hash(root_id.range.from_block, root_id.range.to_block);
+    if root_hash.is_err() {
+        log::error!("💔️ Error getting root hash while signing root id {:?} to vote", root_id);
+        return VoteOutcome::Failed
+    }
+
+    let root_data = Summary::<T, I>::try_get_root_data(root_id);
+    if let Err(e) = root_data {
+        log::error!(
+            "💔️ Error getting root data while signing root id {:?} to vote. {:?}",
+            root_id,
+            e
+        );
+        return VoteOutcome::Failed
+    }
+
+    let root_hash_matches =
+        root_hash.expect("has valid hash") == root_data.expect("checked for error").root_hash;
+    if Summary::<T, I>::get_vote(root_id).has_voted(&this_validator.account_id) {
+        // This validator rejected the root, possibly because of a transient error of the
+        // avn service. If the root is still invalid, check it again once the lock expires.
+        if !root_hash_matches {
+            guard.forget();
+            return VoteOutcome::Pending
+        }
+
+        if send_replace_vote::<T, I>(root_id, this_validator, APPROVE_VOTE).is_err() {
+            return VoteOutcome::Failed
+        }
+    } else if root_hash_matches {
+        if send_approve_vote::<T, I>(root_id, this_validator).is_err() {
+            // TODO: should we output any error message here?
+            return VoteOutcome::Failed
+        }
+    } else {
+        if send_reject_vote::<T, I>(root_id, this_validator).is_err() {
+            // TODO: should we output any error message here?
+            return VoteOutcome::Failed
+        }
+    }
+
+    // keep the lock until it expires
+    guard.forget();
+    VoteOutcome::Sent
+}
+
+pub fn cast_votes_if_required<T: Config<I>, I: 'static>(
+    this_validator: &Validator<<T as avn::Config>::AuthorityId, T::AccountId>,
+) {
+    complete_vote_tasks_of_closed_roots::<T, I>();
+    let votable_root_ids = enqueue_vote_tasks::<T, I>(this_validator);
+
+    let owner = Summary::<T, I>::pallet_id();
+    let now = <frame_system::Pallet<T>>::block_number();
+    let mut tried_root_ids: Vec<RootId<BlockNumberFor<T>>> = Vec::new();
+    // Released once this run is over, so they are not leased again by it
+    let mut failed_root_ids: Vec<RootId<BlockNumberFor<T>>> = Vec::new();
+
+    // try to send 1 of MAX_VOTING_SESSIONS_RETURNED votes, the roots with a hint first
+    for _ in 0..MAX_VOTING_SESSIONS_RETURNED {
+        let root_id = match OcwTaskQueue::lease_next::<BlockNumberFor<T>>(
+            &owner,
+            now,
+            AVN::<T>::get_default_ocw_lock_expiry(),
+        ) {
+            Ok(Some(task)) => match RootId::<BlockNumberFor<T>>::decode(&mut task.id.as_slice()) {
+                Ok(root_id) => root_id,
+                Err(_) => {
+                    let _ = OcwTaskQueue::complete::<BlockNumberFor<T>>(&owner, &task.id);
                     continue
+                },
+            },
+            Ok(None) => break,
+            Err(e) => {
+                // The queue only tracks the work of the worker, vote without a lease when it is
+                // unavailable
+                log::warn!("💔️ Unable to lease a vote task: {:?}", e);
+                match votable_root_ids.iter().find(|root_id| !tried_root_ids.contains(root_id)) {
+                    Some(root_id) => root_id.clone(),
+                    None => break,
                 }
-            }
+            },
+        };
+        tried_root_ids.push(root_id.clone());
 
-            // keep the lock until it expires
-            guard.forget();
-            return
-        } else {
-            log::trace!(target: "avn", "🤷 Unable to acquire local lock for root {:?}. Lock probably exists already", &root_id);
+        if !root_can_be_voted_on::<T, I>(&root_id, &this_validator.account_id) {
+            let _ = OcwTaskQueue::complete::<BlockNumberFor<T>>(&owner, &root_id.encode());
             continue
-        };
+        }
+
+        match vote_on_root::<T, I>(&root_id, this_validator) {
+            VoteOutcome::Sent => {
+                let _ = OcwTaskQueue::complete::<BlockNumberFor<T>>(&owner, &root_id.encode());
+                break
+            },
+            VoteOutcome::Failed => failed_root_ids.push(root_id),
+            VoteOutcome::Pending => {},
+        }
+    }
+
+    for root_id in failed_root_ids {
+        let _ = OcwTaskQueue::release::<BlockNumberFor<T>>(&owner, &root_id.encode());
     }
 }
 
@@ -252,8 +376,9 @@ fn root_can_be_voted_on<T: Config<I>, I: 'static>(
     // time the vote gets mined. It may be outside the voting window and get rejected.
     let root_voting_session = Summary::<T, I>::get_root_voting_session(root_id);
     let voting_session_data = root_voting_session.state();
-    return voting_session_data.is_ok() &&
-        !voting_session_data.expect("voting session data is ok").has_voted(voter) &&
+    let voter_can_vote = voting_session_data
+        .map_or(false, |vote| !vote.has_voted(voter) || vote.can_replace_vote(voter, APPROVE_VOTE));
+    return voter_can_vote &&
         !is_vote_in_transaction_pool::<T, I>(root_id) &&
         root_voting_session.is_active()
 }
@@ -351,3 +476,50 @@ fn send_reject_vote<T: Config<I>, I: 'static>(
 
     Ok(())
 }
+
+fn send_replace_vote<T: Config<I>, I: 'static>(
+    root_id: &RootId<BlockNumberFor<T>>,
+    this_validator: &Validator<<T as avn::Config>::AuthorityId, T::AccountId>,
+    approve: bool,
+) -> Result<(), ()> {
+    let voting_session_data = Summary::<T, I>::get_root_voting_session(&root_id).state();
+    if voting_session_data.is_err() {
+        log::error!(
+            "💔 Error getting voting session data with root id {:?} to replace vote",
+            &root_id
+        );
+        return Err(())
+    }
+
+    let voting_session_id =
+        voting_session_data.expect("voting session data is ok").voting_session_id;
+    let signature = this_validator
+        .key
+        .sign(&(REPLACE_VOTE_CONTEXT, voting_session_id, approve).encode());
+
+    if signature.is_none() {
+        log::error!("💔️ Error signing root id {:?} to replace vote", &root_id);
+        return Err(())
+    };
+
+    log::trace!(target: "avn", "🖊️  Worker replaces its vote for summary calculation: {:?}]", &root_id);
+
+    if let Err(e) = SubmitTransaction::<T, Call<T, I>>::submit_unsigned_transaction(
+        Call::replace_vote {
+            root_id: root_id.clone(),
+            validator: this_validator.clone(),
+            approve,
+            signature: signature.expect("We have a signature"),
+        }
+        .into(),
+    ) {
+        log::error!(
+            "💔️ Error sending `replace vote transaction` for root id {:?}: {:?}",
+            root_id,
+            e
+        );
+        return Err(())
+    }
+
+    Ok(())
+}
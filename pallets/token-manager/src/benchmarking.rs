@@ -24,7 +24,7 @@ use frame_benchmarking::{account, benchmarks, impl_benchmark_test_suite, whiteli
 use frame_system::{pallet_prelude::BlockNumberFor, EventRecord, RawOrigin};
 use hex_literal::hex;
 use sp_core::sr25519;
-use sp_runtime::RuntimeAppPublic;
+use sp_runtime::{traits::UniqueSaturatedInto, RuntimeAppPublic};
 
 use sp_application_crypto::KeyTypeId;
 pub const BENCH_KEY_TYPE_ID: KeyTypeId = KeyTypeId(*b"test");
@@ -50,6 +50,46 @@ fn assert_last_nth_event<T: Config>(generic_event: <T as Config>::RuntimeEvent,
     assert_eq!(event, &system_event);
 }
 
+struct Stream<T: Config> {
+    sender: T::AccountId,
+    recipient: T::AccountId,
+    token_id: T::TokenId,
+    rate_per_block: T::TokenBalance,
+    duration: BlockNumberFor<T>,
+}
+
+impl<T: Config> Stream<T> {
+    fn new() -> Self {
+        // AVT streams are the worst case because they move balances of system accounts
+        <AVTTokenContract<T>>::put(AVT_TOKEN_CONTRACT);
+        let sender: T::AccountId = account("sender", 0, 0);
+        let unit = <T as pallet::Config>::Currency::minimum_balance().max(1u32.into());
+        <T as pallet::Config>::Currency::make_free_balance_be(&sender, unit * 10_000u32.into());
+
+        let rate_per_block: u128 = unit.unique_saturated_into();
+        return Stream {
+            sender,
+            recipient: account("recipient", 0, 0),
+            token_id: AVT_TOKEN_CONTRACT.into(),
+            rate_per_block: <T::TokenBalance as TryFrom<u128>>::try_from(rate_per_block)
+                .unwrap_or_else(|_| panic!("Rate fits the token balance")),
+            duration: 100u32.into(),
+        }
+    }
+
+    fn create(self) -> Self {
+        Pallet::<T>::do_create_stream(
+            self.sender.clone(),
+            self.recipient.clone(),
+            self.token_id,
+            self.rate_per_block,
+            self.duration,
+        )
+        .unwrap();
+        return self
+    }
+}
+
 struct Transfer<T: Config> {
     relayer: T::AccountId,
     from: T::AccountId,
@@ -262,7 +302,9 @@ benchmarks! {
             recipient: lower.lower_account_id,
             amount: lower.amount.into(),
             t1_recipient: lower.t1_recipient,
-            lower_id: lower.lower_id
+            lower_id: lower.lower_id,
+            memo: None,
+            sequence: avn::Pallet::<T>::next_event_sequence() - 1,
         }.into(), 2);
     }
 
@@ -285,7 +327,9 @@ benchmarks! {
             recipient: lower.lower_account_id,
             amount: lower.amount.into(),
             t1_recipient: lower.t1_recipient,
-            lower_id: lower.lower_id
+            lower_id: lower.lower_id,
+            memo: None,
+            sequence: avn::Pallet::<T>::next_event_sequence() - 1,
         }.into(), 2);
     }
 
@@ -312,6 +356,8 @@ benchmarks! {
                 sender_nonce: Some(0),
                 schedule_name: ("Lower", &lower.lower_id).using_encoded(sp_io::hashing::blake2_256),
                 lower_id: lower.lower_id,
+                memo: None,
+                sequence: avn::Pallet::<T>::next_event_sequence() - 1,
             }.into()
         );
     }
@@ -332,7 +378,7 @@ benchmarks! {
     regenerate_lower_proof {
         let lower: Lower<T> = Lower::new().setup();
         let token_id = H160(hex_literal::hex!("97d9b397189e8b771ffac3cb04cf26c780a93431"));
-        let params = crate::Pallet::<T>::concat_lower_data(lower.lower_id, token_id.into(), &lower.amount.into(), &token_id);
+        let params = crate::Pallet::<T>::concat_lower_data(lower.lower_id, token_id.into(), &lower.amount.into(), &token_id, None);
 
         let lower_data = vec![0u8; 32];
 
@@ -362,6 +408,82 @@ benchmarks! {
         assert_eq!(LowersDisabled::<T>::get(), true);
         assert_last_event::<T>(Event::<T>::LoweringDisabled.into());
     }
+
+    create_stream {
+        let stream: Stream<T> = Stream::new();
+    }: _(
+        RawOrigin::<T::AccountId>::Signed(stream.sender.clone()),
+        stream.recipient.clone(),
+        stream.token_id,
+        stream.rate_per_block,
+        stream.duration
+    )
+    verify {
+        assert!(<PaymentStreams<T>>::contains_key(0));
+        assert_last_event::<T>(Event::<T>::StreamCreated {
+            stream_id: 0,
+            sender: stream.sender,
+            recipient: stream.recipient,
+            token_id: stream.token_id,
+            rate_per_block: stream.rate_per_block,
+            end_block: frame_system::Pallet::<T>::block_number() + stream.duration,
+        }.into());
+    }
+
+    withdraw_from_stream {
+        let stream: Stream<T> = Stream::new().create();
+        frame_system::Pallet::<T>::set_block_number(
+            frame_system::Pallet::<T>::block_number() + stream.duration / 2u32.into()
+        );
+        let amount = Pallet::<T>::vested_amount(&<PaymentStreams<T>>::get(0).unwrap()).unwrap();
+    }: _(RawOrigin::<T::AccountId>::Signed(stream.recipient.clone()), 0)
+    verify {
+        assert_eq!(<PaymentStreams<T>>::get(0).unwrap().withdrawn, amount);
+        assert_last_event::<T>(Event::<T>::StreamWithdrawn {
+            stream_id: 0,
+            recipient: stream.recipient,
+            amount,
+        }.into());
+    }
+
+    cancel_stream {
+        // Both parties are paid when the stream is cancelled half way
+        let stream: Stream<T> = Stream::new().create();
+        frame_system::Pallet::<T>::set_block_number(
+            frame_system::Pallet::<T>::block_number() + stream.duration / 2u32.into()
+        );
+    }: _(RawOrigin::<T::AccountId>::Signed(stream.sender.clone()), 0)
+    verify {
+        assert!(!<PaymentStreams<T>>::contains_key(0));
+    }
+
+    release_held_lift {
+        <AVTTokenContract<T>>::put(AVT_TOKEN_CONTRACT);
+        let recipient: T::AccountId = account("recipient", 1, 1);
+        let amount = 10u32;
+        <T as pallet::Config>::Currency::make_free_balance_be(
+            &Pallet::<T>::compute_lift_holding_account_id(),
+            amount.into(),
+        );
+        <HeldLifts<T>>::insert(0, HeldLift {
+            destination: H256::repeat_byte(1),
+            token_id: AVT_TOKEN_CONTRACT.into(),
+            recipient: recipient.clone(),
+            amount: amount.into(),
+        });
+    }: _(RawOrigin::Root, 0)
+    verify {
+        assert!(!<HeldLifts<T>>::contains_key(0));
+        assert_eq!(<T as pallet::Config>::Currency::free_balance(&recipient), amount.into());
+    }
+
+    set_spending_limit {
+        let account: T::AccountId = account("account", 0, 0);
+        let limit = SpendingLimit { amount: 1_000u128, period: 100u32.into() };
+    }: _(RawOrigin::<T::AccountId>::Signed(account.clone()), AVT_TOKEN_CONTRACT.into(), Some(limit.clone()))
+    verify {
+        assert_eq!(<SpendingLimits<T>>::get(&account, T::TokenId::from(AVT_TOKEN_CONTRACT)), Some(limit));
+    }
 }
 
 impl_benchmark_test_suite!(
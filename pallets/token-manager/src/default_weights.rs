@@ -47,6 +47,11 @@ pub trait WeightInfo {
 	fn regenerate_lower_proof() -> Weight;
 	fn set_lower_schedule_period() -> Weight;
 	fn toggle_lowering() -> Weight;
+	fn create_stream() -> Weight;
+	fn withdraw_from_stream() -> Weight;
+	fn cancel_stream() -> Weight;
+	fn release_held_lift() -> Weight;
+	fn set_spending_limit() -> Weight;
 }
 
 /// Weights for pallet_token_manager using the Substrate node and recommended hardware.
@@ -210,6 +215,78 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		Weight::from_parts(9_304_000, 0)
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
+	/// Storage: `TokenManager::AVTTokenContract` (r:1 w:0)
+	/// Proof: `TokenManager::AVTTokenContract` (`max_values`: Some(1), `max_size`: Some(20), added: 515, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:3 w:3)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `TokenManager::NextStreamId` (r:1 w:1)
+	/// Proof: `TokenManager::NextStreamId` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `TokenManager::PaymentStreams` (r:0 w:1)
+	/// Proof: `TokenManager::PaymentStreams` (`max_values`: None, `max_size`: Some(148), added: 2623, mode: `MaxEncodedLen`)
+	fn create_stream() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `239`
+		//  Estimated: `6196`
+		// Minimum execution time: 63_617_000 picoseconds.
+		Weight::from_parts(64_830_000, 6196)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	/// Storage: `TokenManager::PaymentStreams` (r:1 w:1)
+	/// Proof: `TokenManager::PaymentStreams` (`max_values`: None, `max_size`: Some(148), added: 2623, mode: `MaxEncodedLen`)
+	/// Storage: `TokenManager::AVTTokenContract` (r:1 w:0)
+	/// Proof: `TokenManager::AVTTokenContract` (`max_values`: Some(1), `max_size`: Some(20), added: 515, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn withdraw_from_stream() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `515`
+		//  Estimated: `6196`
+		// Minimum execution time: 61_052_000 picoseconds.
+		Weight::from_parts(62_388_000, 6196)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: `TokenManager::PaymentStreams` (r:1 w:1)
+	/// Proof: `TokenManager::PaymentStreams` (`max_values`: None, `max_size`: Some(148), added: 2623, mode: `MaxEncodedLen`)
+	/// Storage: `TokenManager::AVTTokenContract` (r:1 w:0)
+	/// Proof: `TokenManager::AVTTokenContract` (`max_values`: Some(1), `max_size`: Some(20), added: 515, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:3 w:3)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn cancel_stream() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `618`
+		//  Estimated: `8799`
+		// Minimum execution time: 89_471_000 picoseconds.
+		Weight::from_parts(91_205_000, 8799)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: `TokenManager::HeldLifts` (r:1 w:1)
+	/// Proof: `TokenManager::HeldLifts` (`max_values`: None, `max_size`: Some(124), added: 2599, mode: `MaxEncodedLen`)
+	/// Storage: `TokenManager::AVTTokenContract` (r:1 w:0)
+	/// Proof: `TokenManager::AVTTokenContract` (`max_values`: Some(1), `max_size`: Some(20), added: 515, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn release_held_lift() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `619`
+		//  Estimated: `6196`
+		// Minimum execution time: 53_870_000 picoseconds.
+		Weight::from_parts(54_913_000, 6196)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: `TokenManager::SpendingLimits` (r:0 w:1)
+	/// Proof: `TokenManager::SpendingLimits` (`max_values`: None, `max_size`: Some(108), added: 2583, mode: `MaxEncodedLen`)
+	fn set_spending_limit() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 13_318_000 picoseconds.
+		Weight::from_parts(13_318_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
 }
 
 // For backwards compatibility and tests.
@@ -372,4 +449,76 @@ impl WeightInfo for () {
 		Weight::from_parts(9_304_000, 0)
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
+	/// Storage: `TokenManager::AVTTokenContract` (r:1 w:0)
+	/// Proof: `TokenManager::AVTTokenContract` (`max_values`: Some(1), `max_size`: Some(20), added: 515, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:3 w:3)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `TokenManager::NextStreamId` (r:1 w:1)
+	/// Proof: `TokenManager::NextStreamId` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `TokenManager::PaymentStreams` (r:0 w:1)
+	/// Proof: `TokenManager::PaymentStreams` (`max_values`: None, `max_size`: Some(148), added: 2623, mode: `MaxEncodedLen`)
+	fn create_stream() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `239`
+		//  Estimated: `6196`
+		// Minimum execution time: 63_617_000 picoseconds.
+		Weight::from_parts(64_830_000, 6196)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	/// Storage: `TokenManager::PaymentStreams` (r:1 w:1)
+	/// Proof: `TokenManager::PaymentStreams` (`max_values`: None, `max_size`: Some(148), added: 2623, mode: `MaxEncodedLen`)
+	/// Storage: `TokenManager::AVTTokenContract` (r:1 w:0)
+	/// Proof: `TokenManager::AVTTokenContract` (`max_values`: Some(1), `max_size`: Some(20), added: 515, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn withdraw_from_stream() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `515`
+		//  Estimated: `6196`
+		// Minimum execution time: 61_052_000 picoseconds.
+		Weight::from_parts(62_388_000, 6196)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: `TokenManager::PaymentStreams` (r:1 w:1)
+	/// Proof: `TokenManager::PaymentStreams` (`max_values`: None, `max_size`: Some(148), added: 2623, mode: `MaxEncodedLen`)
+	/// Storage: `TokenManager::AVTTokenContract` (r:1 w:0)
+	/// Proof: `TokenManager::AVTTokenContract` (`max_values`: Some(1), `max_size`: Some(20), added: 515, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:3 w:3)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn cancel_stream() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `618`
+		//  Estimated: `8799`
+		// Minimum execution time: 89_471_000 picoseconds.
+		Weight::from_parts(91_205_000, 8799)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: `TokenManager::HeldLifts` (r:1 w:1)
+	/// Proof: `TokenManager::HeldLifts` (`max_values`: None, `max_size`: Some(124), added: 2599, mode: `MaxEncodedLen`)
+	/// Storage: `TokenManager::AVTTokenContract` (r:1 w:0)
+	/// Proof: `TokenManager::AVTTokenContract` (`max_values`: Some(1), `max_size`: Some(20), added: 515, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn release_held_lift() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `619`
+		//  Estimated: `6196`
+		// Minimum execution time: 53_870_000 picoseconds.
+		Weight::from_parts(54_913_000, 6196)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: `TokenManager::SpendingLimits` (r:0 w:1)
+	/// Proof: `TokenManager::SpendingLimits` (`max_values`: None, `max_size`: Some(108), added: 2583, mode: `MaxEncodedLen`)
+	fn set_spending_limit() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 13_318_000 picoseconds.
+		Weight::from_parts(13_318_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
 }
\ No newline at end of file
@@ -33,7 +33,7 @@ use frame_support::{
             DispatchTime, HARD_DEADLINE,
         },
         Currency, ExistenceRequirement, Get, Imbalance, IsSubType, QueryPreimage, StorePreimage,
-        WithdrawReasons,
+        VestingSchedule, WithdrawReasons,
     },
     BoundedVec, PalletId, Parameter,
 };
@@ -41,14 +41,16 @@ use frame_system::ensure_signed;
 pub use pallet::*;
 use pallet_avn::{
     self as avn, BridgeInterface, BridgeInterfaceNotification, CollatorPayoutDustHandler,
-    LowerParams, OnGrowthLiftedHandler, ProcessedEventsChecker, PACKED_LOWER_PARAM_SIZE,
+    LowerParams, OnGrowthLiftedHandler, ProcessedEventsChecker, LOWER_MEMO_SIZE,
+    PACKED_LOWER_PARAM_SIZE,
 };
 use sp_avn_common::{
     event_types::{
-        AvtGrowthLiftedData, AvtLowerClaimedData, EthEvent, EventData, LiftedData,
-        ProcessedEventHandler, TokenInterface,
+        AvtGrowthLiftedData, AvtLowerClaimedData, EthEvent, EventData, LiftDestination, LiftedData,
+        LiftedToDestinationData, LiftedWithVestingData, OnLiftReceived, ProcessedEventHandler,
+        TokenInterface,
     },
-    verify_signature, CallDecoder, FeePaymentHandler, InnerCallValidator, Proof,
+    verify_domain_separated_signature, CallDecoder, FeePaymentHandler, InnerCallValidator, Proof,
 };
 use sp_core::{ConstU32, MaxEncodedLen, H160, H256};
 use sp_runtime::{
@@ -70,10 +72,20 @@ type PositiveImbalanceOf<T> = <<T as Config>::Currency as Currency<
 type CallOf<T> = <T as Config>::RuntimeCall;
 pub type LowerId = u32;
 pub type LowerDataLimit = ConstU32<10000>; // Max lower proof len. 10kB
+/// Reference chosen by the sender of a lower, e.g. an exchange's withdrawal id
+pub type LowerMemo = BoundedVec<u8, ConstU32<{ LOWER_MEMO_SIZE as u32 }>>;
 
 mod benchmarking;
 pub mod default_weights;
+pub mod lift_destination;
+pub use lift_destination::*;
 pub mod migration;
+pub mod payment_stream;
+pub use payment_stream::*;
+pub mod spending_limit;
+pub use spending_limit::*;
+#[cfg(any(test, feature = "try-runtime"))]
+mod try_state;
 pub use default_weights::WeightInfo;
 
 #[cfg(test)]
@@ -87,16 +99,35 @@ mod test_deferred_lower;
 #[cfg(test)]
 mod test_growth;
 #[cfg(test)]
+mod test_lift_to_destination;
+#[cfg(test)]
+mod test_lift_with_vesting;
+#[cfg(test)]
+mod test_lower_memo;
+#[cfg(test)]
 mod test_lower_proof_generation;
 #[cfg(test)]
 mod test_non_avt_tokens;
 #[cfg(test)]
+mod test_payment_streams;
+#[cfg(test)]
 mod test_proxying_signed_lower;
 #[cfg(test)]
 mod test_proxying_signed_transfer;
+#[cfg(test)]
+mod test_signing_domains;
+#[cfg(test)]
+mod test_spending_limits;
+#[cfg(test)]
+mod test_try_state;
 
+/// Prefix of every payload signed for this pallet, so a proof can't be replayed against another
+/// pallet that signs a payload of the same shape
+pub const SIGNING_DOMAIN: &'static [u8] = b"token_manager";
 pub const SIGNED_TRANSFER_CONTEXT: &'static [u8] = b"authorization for transfer operation";
 pub const SIGNED_LOWER_CONTEXT: &'static [u8] = b"authorization for lower operation";
+pub const SIGNED_LOWER_WITH_MEMO_CONTEXT: &'static [u8] =
+    b"authorization for lower with memo operation";
 const PALLET_ID: &'static [u8; 13] = b"token_manager";
 
 pub use pallet::*;
@@ -157,6 +188,14 @@ pub mod pallet {
         /// Overarching type of all pallets origins.
         type PalletsOrigin: From<frame_system::RawOrigin<Self::AccountId>>;
         type BridgeInterface: BridgeInterface;
+        /// Vesting schedules used to lock AVT lifted with a vesting schedule
+        type Vesting: VestingSchedule<
+            Self::AccountId,
+            Moment = BlockNumberFor<Self>,
+            Currency = <Self as Config>::Currency,
+        >;
+        /// The runtime pallets that tokens can be lifted to with `LogLiftedToDestination`
+        type LiftDestinations: OnLiftReceived<Self::TokenId, Self::AccountId>;
         type WeightInfo: WeightInfo;
     }
 
@@ -173,12 +212,14 @@ pub mod pallet {
             recipient: T::AccountId,
             amount: BalanceOf<T>,
             eth_tx_hash: H256,
+            sequence: u64,
         },
         TokenLifted {
             token_id: T::TokenId,
             recipient: T::AccountId,
             token_balance: T::TokenBalance,
             eth_tx_hash: H256,
+            sequence: u64,
         },
         TokenTransferred {
             token_id: T::TokenId,
@@ -197,6 +238,8 @@ pub mod pallet {
             amount: u128,
             t1_recipient: H160,
             lower_id: LowerId,
+            memo: Option<LowerMemo>,
+            sequence: u64,
         },
         AvtLowered {
             sender: T::AccountId,
@@ -204,6 +247,8 @@ pub mod pallet {
             amount: u128,
             t1_recipient: H160,
             lower_id: LowerId,
+            memo: Option<LowerMemo>,
+            sequence: u64,
         },
         AvtTransferredFromTreasury {
             recipient: T::AccountId,
@@ -213,6 +258,7 @@ pub mod pallet {
             treasury_share: BalanceOf<T>,
             collators_share: BalanceOf<T>,
             eth_tx_hash: H256,
+            sequence: u64,
         },
         LowerRequested {
             token_id: T::TokenId,
@@ -222,12 +268,15 @@ pub mod pallet {
             sender_nonce: Option<u64>,
             lower_id: LowerId,
             schedule_name: TaskName,
+            memo: Option<LowerMemo>,
+            sequence: u64,
         },
         LowerReadyToClaim {
             lower_id: LowerId,
         },
         AvtLowerClaimed {
             lower_id: LowerId,
+            sequence: u64,
         },
         FailedToGenerateLowerProof {
             lower_id: LowerId,
@@ -251,6 +300,79 @@ pub mod pallet {
             recipient: T::AccountId,
             token_balance: T::TokenBalance,
         },
+        /// Event emitted when the dust left after paying collators for `growth_period` is sent to
+        /// the treasury
+        CollatorPayoutDustDeposited {
+            amount: BalanceOf<T>,
+            growth_period: u32,
+        },
+        /// AVT was lifted and locked under a vesting schedule. The amount starts unlocking at
+        /// `starting_block`, by `per_block` every block.
+        AVTLiftedWithVesting {
+            recipient: T::AccountId,
+            amount: BalanceOf<T>,
+            per_block: BalanceOf<T>,
+            starting_block: BlockNumberFor<T>,
+            eth_tx_hash: H256,
+            sequence: u64,
+        },
+        StreamCreated {
+            stream_id: StreamId,
+            sender: T::AccountId,
+            recipient: T::AccountId,
+            token_id: T::TokenId,
+            rate_per_block: T::TokenBalance,
+            end_block: BlockNumberFor<T>,
+        },
+        StreamWithdrawn {
+            stream_id: StreamId,
+            recipient: T::AccountId,
+            amount: T::TokenBalance,
+        },
+        /// The recipient was paid what had vested and the sender was refunded the rest
+        StreamCancelled {
+            stream_id: StreamId,
+            recipient_amount: T::TokenBalance,
+            sender_refund: T::TokenBalance,
+        },
+        /// Tokens were lifted to the recipient and the pallet of `destination` was notified
+        LiftSentToDestination {
+            destination: LiftDestination,
+            token_id: T::TokenId,
+            recipient: T::AccountId,
+            amount: u128,
+            eth_tx_hash: H256,
+            sequence: u64,
+        },
+        /// Tokens lifted to a destination that is unknown, or that rejected the lift, are held
+        /// until governance releases them
+        LiftHeld {
+            held_lift_id: HeldLiftId,
+            destination: LiftDestination,
+            token_id: T::TokenId,
+            recipient: T::AccountId,
+            amount: u128,
+            eth_tx_hash: H256,
+            sequence: u64,
+        },
+        HeldLiftReleased {
+            held_lift_id: HeldLiftId,
+            recipient: T::AccountId,
+            sent_to_destination: bool,
+        },
+        SpendingLimitSet {
+            account: T::AccountId,
+            token_id: T::TokenId,
+            limit: Option<SpendingLimitOf<T>>,
+        },
+        /// A proxied transfer or lower was rejected because it would move more than `remaining`,
+        /// what is left of the spending limit of the account in the current window
+        SpendingLimitExceeded {
+            account: T::AccountId,
+            token_id: T::TokenId,
+            amount: u128,
+            remaining: u128,
+        },
     }
 
     #[pallet::error]
@@ -278,6 +400,19 @@ pub mod pallet {
         InvalidLowerId,
         LoweringDisabled,
         InvalidLiftRequest,
+        NoTier1EventForLogLiftedWithVesting,
+        /// Only AVT can be lifted with a vesting schedule
+        VestingNotSupportedForToken,
+        InvalidVestingSchedule,
+        StreamNotFound,
+        StreamToSelf,
+        InvalidStreamDuration,
+        /// Nothing has vested since the last withdrawal
+        NothingToWithdraw,
+        NoTier1EventForLogLiftedToDestination,
+        HeldLiftNotFound,
+        /// The period of a spending limit can't be zero
+        InvalidSpendingLimit,
     }
 
     #[pallet::storage]
@@ -318,6 +453,12 @@ pub mod pallet {
     pub type FailedLowerProofs<T: Config> =
         StorageMap<_, Blake2_128Concat, LowerId, LowerParams, OptionQuery>;
 
+    /// The memos of the scheduled lowers that have one, until the lower is executed
+    #[pallet::storage]
+    #[pallet::getter(fn lower_memo)]
+    pub type LowerMemos<T: Config> =
+        StorageMap<_, Blake2_128Concat, LowerId, LowerMemo, OptionQuery>;
+
     /// A nonce to uniquely identify each lower request
     #[pallet::storage]
     #[pallet::getter(fn lower_id)]
@@ -333,6 +474,52 @@ pub mod pallet {
     #[pallet::getter(fn lowers_disabled)]
     pub type LowersDisabled<T: Config> = StorageValue<_, bool, ValueQuery>;
 
+    /// The id of the next payment stream
+    #[pallet::storage]
+    pub type NextStreamId<T: Config> = StorageValue<_, StreamId, ValueQuery>;
+
+    /// The payment streams that have not been fully withdrawn or cancelled
+    #[pallet::storage]
+    #[pallet::getter(fn payment_stream)]
+    pub type PaymentStreams<T: Config> =
+        StorageMap<_, Blake2_128Concat, StreamId, PaymentStreamOf<T>, OptionQuery>;
+
+    /// The id of the next lift held in the lift holding account
+    #[pallet::storage]
+    pub type NextHeldLiftId<T: Config> = StorageValue<_, HeldLiftId, ValueQuery>;
+
+    /// The lifts that could not be delivered to their destination, until they are released
+    #[pallet::storage]
+    #[pallet::getter(fn held_lift)]
+    pub type HeldLifts<T: Config> =
+        StorageMap<_, Blake2_128Concat, HeldLiftId, HeldLiftOf<T>, OptionQuery>;
+
+    /// The limits accounts set on what their proxied transfers and lowers of a token can move
+    #[pallet::storage]
+    #[pallet::getter(fn spending_limit)]
+    pub type SpendingLimits<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        Blake2_128Concat,
+        T::TokenId,
+        SpendingLimitOf<T>,
+        OptionQuery,
+    >;
+
+    /// The current spending window of the accounts that have a spending limit
+    #[pallet::storage]
+    #[pallet::getter(fn spending_window)]
+    pub type SpendingWindows<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        Blake2_128Concat,
+        T::TokenId,
+        SpendingWindowOf<T>,
+        OptionQuery,
+    >;
+
     #[pallet::genesis_config]
     pub struct GenesisConfig<T: Config> {
         pub _phantom: sp_std::marker::PhantomData<T>,
@@ -370,6 +557,29 @@ pub mod pallet {
         }
     }
 
+    #[pallet::hooks]
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::TryRuntimeError> {
+            Self::do_try_state()?;
+            Ok(Vec::new())
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(_state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+            ensure!(
+                Pallet::<T>::on_chain_storage_version() == crate::migration::STORAGE_VERSION,
+                "Token manager storage version does not match the pallet after the upgrade"
+            );
+            Self::do_try_state()
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn try_state(_n: BlockNumberFor<T>) -> Result<(), sp_runtime::TryRuntimeError> {
+            Self::do_try_state()
+        }
+    }
+
     #[pallet::call]
     impl<T: Config> Pallet<T> {
         /// This extrinsic allows relayer to dispatch a `signed_transfer` or `signed_lower` call for
@@ -397,7 +607,7 @@ pub mod pallet {
 
         /// Transfer an amount of token with token_id from sender to receiver with a proof
         #[pallet::call_index(1)]
-        #[pallet::weight(<T as pallet::Config>::WeightInfo::signed_transfer())]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::signed_transfer().saturating_add(T::DbWeight::get().reads_writes(2, 1)))]
         pub fn signed_transfer(
             origin: OriginFor<T>,
             proof: Proof<T::Signature, T::AccountId>,
@@ -420,12 +630,15 @@ pub mod pallet {
             );
 
             ensure!(
-                verify_signature::<T::Signature, T::AccountId>(&proof, &signed_payload.as_slice())
-                    .is_ok(),
+                Self::verify_signed_payload(&proof, &signed_payload).is_ok(),
                 Error::<T>::UnauthorizedSignedTransferTransaction
             );
 
-            Self::settle_transfer(&token_id, &from, &to, &amount)?;
+            let amount_u128 = TryInto::<u128>::try_into(amount)
+                .map_err(|_| Error::<T>::ErrorConvertingTokenBalance)?;
+            if Self::record_limited_spend(&from, &token_id, amount_u128) {
+                Self::settle_transfer(&token_id, &from, &to, &amount)?;
+            }
 
             <Nonces<T>>::mutate(from, |n| *n += 1);
 
@@ -500,13 +713,13 @@ pub mod pallet {
             let to_account_id = T::AccountId::decode(&mut Self::lower_account_id().as_bytes())
                 .map_err(|_| Error::<T>::ErrorConvertingAccountId)?;
 
-            Self::schedule_lower(&from, to_account_id, token_id, amount, t1_recipient, None)?;
+            Self::schedule_lower(&from, to_account_id, token_id, amount, t1_recipient, None, None)?;
 
             Ok(().into())
         }
 
         /// Schedule a call to lower an amount of token from tier2 to tier1 by a relayer
-        #[pallet::weight(<T as pallet::Config>::WeightInfo::schedule_signed_lower())]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::schedule_signed_lower().saturating_add(T::DbWeight::get().reads_writes(2, 1)))]
         #[pallet::call_index(7)]
         pub fn schedule_signed_lower(
             origin: OriginFor<T>,
@@ -532,22 +745,24 @@ pub mod pallet {
             );
 
             ensure!(
-                verify_signature::<T::Signature, T::AccountId>(&proof, &signed_payload.as_slice())
-                    .is_ok(),
+                Self::verify_signed_payload(&proof, &signed_payload).is_ok(),
                 Error::<T>::UnauthorizedSignedLowerTransaction
             );
 
             let to_account_id = T::AccountId::decode(&mut Self::lower_account_id().as_bytes())
                 .map_err(|_| Error::<T>::ErrorConvertingAccountId)?;
 
-            Self::schedule_lower(
-                &from,
-                to_account_id,
-                token_id,
-                amount,
-                t1_recipient,
-                Some(sender_nonce),
-            )?;
+            if Self::record_limited_spend(&from, &token_id, amount) {
+                Self::schedule_lower(
+                    &from,
+                    to_account_id,
+                    token_id,
+                    amount,
+                    t1_recipient,
+                    Some(sender_nonce),
+                    None,
+                )?;
+            }
 
             <Nonces<T>>::mutate(from, |n| *n += 1);
 
@@ -613,15 +828,174 @@ pub mod pallet {
 
             return Ok(())
         }
+
+        /// Schedule a call to lower an amount of token from tier2 to tier1 with a memo that is
+        /// included in the lower events and in the claim data sent to tier1
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::schedule_direct_lower().saturating_add(T::DbWeight::get().writes(1)))]
+        #[pallet::call_index(11)]
+        pub fn schedule_direct_lower_with_memo(
+            origin: OriginFor<T>,
+            from: T::AccountId,
+            token_id: T::TokenId,
+            amount: u128,
+            t1_recipient: H160, // the receiver address on tier1
+            memo: LowerMemo,
+        ) -> DispatchResultWithPostInfo {
+            let sender = ensure_signed(origin)?;
+
+            ensure!(sender == from, Error::<T>::SenderNotValid);
+            ensure!(<LowersDisabled<T>>::get() == false, Error::<T>::LoweringDisabled);
+            ensure!(amount != 0, Error::<T>::AmountIsZero);
+
+            let to_account_id = T::AccountId::decode(&mut Self::lower_account_id().as_bytes())
+                .map_err(|_| Error::<T>::ErrorConvertingAccountId)?;
+
+            Self::schedule_lower(
+                &from,
+                to_account_id,
+                token_id,
+                amount,
+                t1_recipient,
+                None,
+                Some(memo),
+            )?;
+
+            Ok(().into())
+        }
+
+        /// Schedule a call to lower an amount of token from tier2 to tier1 with a memo by a
+        /// relayer
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::schedule_signed_lower().saturating_add(T::DbWeight::get().reads_writes(2, 2)))]
+        #[pallet::call_index(12)]
+        pub fn schedule_signed_lower_with_memo(
+            origin: OriginFor<T>,
+            proof: Proof<T::Signature, T::AccountId>,
+            from: T::AccountId,
+            token_id: T::TokenId,
+            amount: u128,
+            t1_recipient: H160, // the receiver address on tier1
+            memo: LowerMemo,
+        ) -> DispatchResultWithPostInfo {
+            let sender = ensure_signed(origin)?;
+            ensure!(sender == from, Error::<T>::SenderNotValid);
+            ensure!(<LowersDisabled<T>>::get() == false, Error::<T>::LoweringDisabled);
+            ensure!(amount != 0, Error::<T>::AmountIsZero);
+
+            let sender_nonce = Self::nonce(&sender);
+            let signed_payload = Self::encode_signed_lower_with_memo_params(
+                &proof,
+                &from,
+                &token_id,
+                &amount,
+                &t1_recipient,
+                &memo,
+                sender_nonce,
+            );
+
+            ensure!(
+                Self::verify_signed_payload(&proof, &signed_payload).is_ok(),
+                Error::<T>::UnauthorizedSignedLowerTransaction
+            );
+
+            let to_account_id = T::AccountId::decode(&mut Self::lower_account_id().as_bytes())
+                .map_err(|_| Error::<T>::ErrorConvertingAccountId)?;
+
+            if Self::record_limited_spend(&from, &token_id, amount) {
+                Self::schedule_lower(
+                    &from,
+                    to_account_id,
+                    token_id,
+                    amount,
+                    t1_recipient,
+                    Some(sender_nonce),
+                    Some(memo),
+                )?;
+            }
+
+            <Nonces<T>>::mutate(from, |n| *n += 1);
+
+            Ok(().into())
+        }
+
+        /// Stream `rate_per_block` of a token to `recipient` for `duration` blocks. The whole
+        /// amount is escrowed from the sender when the stream is created.
+        #[pallet::call_index(13)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::create_stream())]
+        pub fn create_stream(
+            origin: OriginFor<T>,
+            recipient: T::AccountId,
+            token_id: T::TokenId,
+            rate_per_block: T::TokenBalance,
+            duration: BlockNumberFor<T>,
+        ) -> DispatchResult {
+            let sender = ensure_signed(origin)?;
+            Self::do_create_stream(sender, recipient, token_id, rate_per_block, duration)
+        }
+
+        /// Withdraw what has vested from a payment stream. The origin must be the recipient.
+        #[pallet::call_index(14)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::withdraw_from_stream())]
+        pub fn withdraw_from_stream(origin: OriginFor<T>, stream_id: StreamId) -> DispatchResult {
+            let recipient = ensure_signed(origin)?;
+            Self::do_withdraw_from_stream(recipient, stream_id)
+        }
+
+        /// Cancel a payment stream, paying the recipient what has vested and refunding the rest
+        /// to the sender. The origin must be the sender or the recipient.
+        #[pallet::call_index(15)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::cancel_stream())]
+        pub fn cancel_stream(origin: OriginFor<T>, stream_id: StreamId) -> DispatchResult {
+            let sender = ensure_signed(origin)?;
+            Self::do_cancel_stream(sender, stream_id)
+        }
+
+        /// Release a lift held in the lift holding account to its recipient. The origin must be
+        /// root.
+        #[pallet::call_index(16)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::release_held_lift())]
+        pub fn release_held_lift(origin: OriginFor<T>, held_lift_id: HeldLiftId) -> DispatchResult {
+            ensure_root(origin)?;
+            Self::do_release_held_lift(held_lift_id)
+        }
+
+        /// Limit what the proxied transfers and lowers of `token_id` authorised by the sender can
+        /// move within a window of blocks. No limit removes the current one.
+        #[pallet::call_index(17)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::set_spending_limit())]
+        pub fn set_spending_limit(
+            origin: OriginFor<T>,
+            token_id: T::TokenId,
+            limit: Option<SpendingLimitOf<T>>,
+        ) -> DispatchResult {
+            let account = ensure_signed(origin)?;
+            Self::do_set_spending_limit(account, token_id, limit)
+        }
     }
 }
 
 impl<T: Config> Pallet<T> {
+    /// Lifts and lowers carry the global event sequence number so indexers can reference them
+    fn deposit_sequenced_event(event: impl FnOnce(u64) -> Event<T>) {
+        avn::Pallet::<T>::deposit_sequenced_event(|sequence| {
+            <T as Config>::RuntimeEvent::from(event(sequence)).into()
+        });
+    }
+
     fn settle_transfer(
         token_id: &T::TokenId,
         from: &T::AccountId,
         to: &T::AccountId,
         amount: &T::TokenBalance,
+    ) -> DispatchResult {
+        Self::do_settle_transfer(token_id, from, to, amount, ExistenceRequirement::KeepAlive)
+    }
+
+    fn do_settle_transfer(
+        token_id: &T::TokenId,
+        from: &T::AccountId,
+        to: &T::AccountId,
+        amount: &T::TokenBalance,
+        existence_requirement: ExistenceRequirement,
     ) -> DispatchResult {
         if *token_id == Self::avt_token_contract().into() {
             // First convert TokenBalance to u128
@@ -635,7 +1009,7 @@ impl<T: Config> Pallet<T> {
                 from,
                 to,
                 transfer_amount,
-                ExistenceRequirement::KeepAlive,
+                existence_requirement,
             )?;
         } else {
             let sender_balance = Self::balance((token_id, from));
@@ -672,6 +1046,8 @@ impl<T: Config> Pallet<T> {
         t1_recipient: H160,
         lower_id: LowerId,
     ) -> DispatchResult {
+        let memo = <LowerMemos<T>>::take(lower_id);
+
         if token_id == Self::avt_token_contract().into() {
             let lower_amount = <BalanceOf<T> as TryFrom<u128>>::try_from(amount)
                 .or_else(|_error| Err(Error::<T>::AmountOverflow))?;
@@ -694,12 +1070,14 @@ impl<T: Config> Pallet<T> {
             // so that total issued AVT becomes equal to total supply once again.
             drop(imbalance);
 
-            Self::deposit_event(Event::<T>::AvtLowered {
+            Self::deposit_sequenced_event(|sequence| Event::<T>::AvtLowered {
                 sender: from.clone(),
                 recipient: to.clone(),
                 amount,
                 t1_recipient,
                 lower_id,
+                memo: memo.clone(),
+                sequence,
             });
         } else {
             let lower_amount = <T::TokenBalance as TryFrom<u128>>::try_from(amount)
@@ -709,17 +1087,20 @@ impl<T: Config> Pallet<T> {
 
             <Balances<T>>::mutate((token_id, from), |balance| *balance -= lower_amount);
 
-            Self::deposit_event(Event::<T>::TokenLowered {
+            Self::deposit_sequenced_event(|sequence| Event::<T>::TokenLowered {
                 token_id,
                 sender: from.clone(),
                 recipient: to.clone(),
                 amount,
                 t1_recipient,
                 lower_id,
+                memo: memo.clone(),
+                sequence,
             });
         }
 
-        let lower_params = Self::concat_lower_data(lower_id, token_id, &amount, &t1_recipient);
+        let lower_params =
+            Self::concat_lower_data(lower_id, token_id, &amount, &t1_recipient, memo.as_ref());
 
         <LowersPendingProof<T>>::insert(lower_id, &lower_params);
         T::BridgeInterface::generate_lower_proof(lower_id, &lower_params, PALLET_ID.to_vec())?;
@@ -735,11 +1116,12 @@ impl<T: Config> Pallet<T> {
     ) -> DispatchResult {
         let amount =
             Self::do_update_token_balance(token_id, recipient_account_id.clone(), raw_amount)?;
-        Self::deposit_event(Event::<T>::TokenLifted {
+        Self::deposit_sequenced_event(|sequence| Event::<T>::TokenLifted {
             token_id,
             recipient: recipient_account_id,
             token_balance: amount,
             eth_tx_hash: transaction_hash,
+            sequence,
         });
 
         Ok(())
@@ -829,6 +1211,7 @@ impl<T: Config> Pallet<T> {
         token_id: T::TokenId,
         amount: &u128,
         t1_recipient: &H160,
+        memo: Option<&LowerMemo>,
     ) -> LowerParams {
         let mut lower_params: [u8; PACKED_LOWER_PARAM_SIZE] = [0u8; PACKED_LOWER_PARAM_SIZE];
 
@@ -839,11 +1222,29 @@ impl<T: Config> Pallet<T> {
         // T1Recipient = 20 bytes
         lower_params[52..72].copy_from_slice(&t1_recipient.as_fixed_bytes()[0..20]);
         // LowerId = 4 bytes
-        lower_params[72..PACKED_LOWER_PARAM_SIZE].copy_from_slice(&lower_id.to_be_bytes()[0..4]);
+        lower_params[72..76].copy_from_slice(&lower_id.to_be_bytes()[0..4]);
+        // Memo = 32 bytes, right padded with zeros. A lower without a memo is claimed with the 76
+        // bytes before it, see `packed_lower_params`
+        if let Some(memo) = memo {
+            lower_params[76..76 + memo.len()].copy_from_slice(memo.as_slice());
+        }
 
         return lower_params
     }
 
+    /// Verifies `proof` over a payload built with `SIGNING_DOMAIN` by one of the encoders below
+    fn verify_signed_payload(
+        proof: &Proof<T::Signature, T::AccountId>,
+        signed_payload: &[u8],
+    ) -> Result<(), ()> {
+        verify_domain_separated_signature::<T::Signature, T::AccountId>(
+            proof,
+            SIGNING_DOMAIN,
+            signed_payload,
+            avn::Pallet::<T>::legacy_signed_payloads_accepted(),
+        )
+    }
+
     fn encode_signed_transfer_params(
         proof: &Proof<T::Signature, T::AccountId>,
         from: &T::AccountId,
@@ -853,6 +1254,7 @@ impl<T: Config> Pallet<T> {
         sender_nonce: u64,
     ) -> Vec<u8> {
         return (
+            SIGNING_DOMAIN,
             SIGNED_TRANSFER_CONTEXT,
             proof.relayer.clone(),
             from,
@@ -873,6 +1275,7 @@ impl<T: Config> Pallet<T> {
         sender_nonce: u64,
     ) -> Vec<u8> {
         return (
+            SIGNING_DOMAIN,
             SIGNED_LOWER_CONTEXT,
             proof.relayer.clone(),
             from,
@@ -884,6 +1287,29 @@ impl<T: Config> Pallet<T> {
             .encode()
     }
 
+    fn encode_signed_lower_with_memo_params(
+        proof: &Proof<T::Signature, T::AccountId>,
+        from: &T::AccountId,
+        token_id: &T::TokenId,
+        amount: &u128,
+        t1_recipient: &H160,
+        memo: &LowerMemo,
+        sender_nonce: u64,
+    ) -> Vec<u8> {
+        return (
+            SIGNING_DOMAIN,
+            SIGNED_LOWER_WITH_MEMO_CONTEXT,
+            proof.relayer.clone(),
+            from,
+            token_id,
+            amount,
+            t1_recipient,
+            memo,
+            sender_nonce,
+        )
+            .encode()
+    }
+
     fn get_encoded_call_param(
         call: &<T as Config>::RuntimeCall,
     ) -> Option<(&Proof<T::Signature, T::AccountId>, Vec<u8>)> {
@@ -919,6 +1345,27 @@ impl<T: Config> Pallet<T> {
 
                 return Some((proof, encoded_data))
             },
+            Call::schedule_signed_lower_with_memo {
+                proof,
+                from,
+                token_id,
+                amount,
+                t1_recipient,
+                memo,
+            } => {
+                let sender_nonce = Self::nonce(&proof.signer);
+                let encoded_data = Self::encode_signed_lower_with_memo_params(
+                    proof,
+                    from,
+                    token_id,
+                    amount,
+                    t1_recipient,
+                    memo,
+                    sender_nonce,
+                );
+
+                return Some((proof, encoded_data))
+            },
             _ => return None,
         }
     }
@@ -935,26 +1382,93 @@ impl<T: Config> Pallet<T> {
             Err(Error::<T>::AmountIsZero)?
         }
 
-        if data.token_contract == Self::avt_token_contract() {
-            let updated_amount = Self::update_avt_balance(&recipient_account_id, data.amount)?;
+        Self::deposit_lifted_amount(
+            event_id.transaction_hash,
+            data.token_contract,
+            recipient_account_id,
+            data.amount,
+        )
+    }
 
-            Self::deposit_event(Event::<T>::AVTLifted {
-                recipient: recipient_account_id.clone(),
+    fn deposit_lifted_amount(
+        eth_tx_hash: H256,
+        token_contract: H160,
+        recipient_account_id: T::AccountId,
+        amount: u128,
+    ) -> DispatchResult {
+        if token_contract == Self::avt_token_contract() {
+            let updated_amount = Self::update_avt_balance(&recipient_account_id, amount)?;
+
+            Self::deposit_sequenced_event(|sequence| Event::<T>::AVTLifted {
+                recipient: recipient_account_id,
                 amount: updated_amount,
-                eth_tx_hash: event_id.transaction_hash,
+                eth_tx_hash,
+                sequence,
             });
         } else {
             Self::update_token_balance(
-                event_id.transaction_hash,
-                data.token_contract.into(),
+                eth_tx_hash,
+                token_contract.into(),
                 recipient_account_id,
-                data.amount,
+                amount,
             )?;
         }
 
         return Ok(())
     }
 
+    fn process_lift_with_vesting(event: &EthEvent, data: &LiftedWithVestingData) -> DispatchResult {
+        let event_id = &event.event_id;
+        let recipient_account_id = T::AccountId::decode(&mut data.receiver_address.as_bytes())
+            .expect("32 bytes will always decode into an AccountId");
+
+        let event_validity = T::ProcessedEventsChecker::processed_event_exists(event_id);
+        ensure!(event_validity, Error::<T>::NoTier1EventForLogLiftedWithVesting);
+
+        if data.amount == 0 {
+            Err(Error::<T>::AmountIsZero)?
+        }
+
+        ensure!(
+            data.token_contract == Self::avt_token_contract(),
+            Error::<T>::VestingNotSupportedForToken
+        );
+        ensure!(data.vesting_period > 0, Error::<T>::InvalidVestingSchedule);
+
+        // The whole amount is locked until the cliff has passed and then unlocks linearly over
+        // the vesting period. Any remainder of the division unlocks with the last block.
+        let per_block = <BalanceOf<T> as TryFrom<u128>>::try_from(
+            (data.amount / u128::from(data.vesting_period)).max(1),
+        )
+        .or_else(|_error| Err(Error::<T>::AmountOverflow))?;
+        let locked = <BalanceOf<T> as TryFrom<u128>>::try_from(data.amount)
+            .or_else(|_error| Err(Error::<T>::AmountOverflow))?;
+        let starting_block =
+            <frame_system::Pallet<T>>::block_number().saturating_add(data.cliff.into());
+
+        T::Vesting::can_add_vesting_schedule(
+            &recipient_account_id,
+            locked,
+            per_block,
+            starting_block,
+        )
+        .map_err(|_| Error::<T>::InvalidVestingSchedule)?;
+
+        let amount = Self::update_avt_balance(&recipient_account_id, data.amount)?;
+        T::Vesting::add_vesting_schedule(&recipient_account_id, amount, per_block, starting_block)?;
+
+        Self::deposit_sequenced_event(|sequence| Event::<T>::AVTLiftedWithVesting {
+            recipient: recipient_account_id,
+            amount,
+            per_block,
+            starting_block,
+            eth_tx_hash: event_id.transaction_hash,
+            sequence,
+        });
+
+        Ok(())
+    }
+
     fn process_avt_growth_lift(event: &EthEvent, data: &AvtGrowthLiftedData) -> DispatchResult {
         let event_id = &event.event_id;
         let event_validity = T::ProcessedEventsChecker::processed_event_exists(event_id);
@@ -975,10 +1489,11 @@ impl<T: Config> Pallet<T> {
             <BalanceOf<T> as TryFrom<u128>>::try_from(data.amount - treasury_share)
                 .or_else(|_error| Err(Error::<T>::AmountOverflow))?;
 
-        Self::deposit_event(Event::<T>::AVTGrowthLifted {
+        Self::deposit_sequenced_event(|sequence| Event::<T>::AVTGrowthLifted {
             treasury_share: treasury_amount,
             collators_share: remaining_amount,
             eth_tx_hash: event_id.transaction_hash,
+            sequence,
         });
 
         T::OnGrowthLiftedHandler::on_growth_lifted(remaining_amount.into(), data.period)?;
@@ -1001,7 +1516,10 @@ impl<T: Config> Pallet<T> {
             Error::<T>::InvalidLowerId
         );
 
-        Self::deposit_event(Event::<T>::AvtLowerClaimed { lower_id: data.lower_id });
+        Self::deposit_sequenced_event(|sequence| Event::<T>::AvtLowerClaimed {
+            lower_id: data.lower_id,
+            sequence,
+        });
 
         Ok(())
     }
@@ -1027,6 +1545,7 @@ impl<T: Config> Pallet<T> {
         amount: u128,
         t1_recipient: H160,
         sender_nonce: Option<u64>,
+        memo: Option<LowerMemo>,
     ) -> DispatchResult {
         let lower_id = Self::lower_id();
         let schedule_name = ("Lower", &lower_id).using_encoded(sp_io::hashing::blake2_256);
@@ -1050,8 +1569,11 @@ impl<T: Config> Pallet<T> {
         )?;
 
         <LowerNonce<T>>::mutate(|nonce| *nonce += 1);
+        if let Some(memo) = &memo {
+            <LowerMemos<T>>::insert(lower_id, memo);
+        }
 
-        Self::deposit_event(Event::<T>::LowerRequested {
+        Self::deposit_sequenced_event(|sequence| Event::<T>::LowerRequested {
             token_id,
             from: from.clone(),
             amount,
@@ -1059,6 +1581,8 @@ impl<T: Config> Pallet<T> {
             sender_nonce,
             lower_id,
             schedule_name,
+            memo,
+            sequence,
         });
 
         Ok(())
@@ -1067,6 +1591,14 @@ impl<T: Config> Pallet<T> {
     fn processed_event_handler(event: &EthEvent) -> DispatchResult {
         return match &event.event_data {
             EventData::LogLifted(d) => return Self::process_lift(event, d),
+            EventData::LogLiftedWithVesting(d) => return Self::process_lift_with_vesting(event, d),
+            EventData::LogLiftedToDestination(d) =>
+                return Self::process_lift_to_destination(event, d),
+            EventData::LogLiftedToPredictionMarket(d) =>
+                return Self::process_lift_to_destination(
+                    event,
+                    &LiftedToDestinationData::from_prediction_market_lift(d),
+                ),
             EventData::LogAvtGrowthLifted(d) => return Self::process_avt_growth_lift(event, d),
             EventData::LogLowerClaimed(d) => return Self::process_lower_claim(event, d),
 
@@ -1110,6 +1642,7 @@ impl<T: Config> CallDecoder for Pallet<T> {
         match call {
             Call::signed_transfer { proof, .. } => return Ok(proof.clone()),
             Call::schedule_signed_lower { proof, .. } => return Ok(proof.clone()),
+            Call::schedule_signed_lower_with_memo { proof, .. } => return Ok(proof.clone()),
             _ => return Err(Error::TransactionNotSupported),
         }
     }
@@ -1120,11 +1653,7 @@ impl<T: Config> InnerCallValidator for Pallet<T> {
 
     fn signature_is_valid(call: &Box<Self::Call>) -> bool {
         if let Some((proof, signed_payload)) = Self::get_encoded_call_param(call) {
-            return verify_signature::<T::Signature, T::AccountId>(
-                &proof,
-                &signed_payload.as_slice(),
-            )
-            .is_ok()
+            return Self::verify_signed_payload(&proof, &signed_payload).is_ok()
         }
 
         return false
@@ -1133,11 +1662,14 @@ impl<T: Config> InnerCallValidator for Pallet<T> {
 
 // Deal with any positive imbalance by sending it to the treasury
 impl<T: Config> CollatorPayoutDustHandler<BalanceOf<T>> for Pallet<T> {
-    fn handle_dust(imbalance: BalanceOf<T>) {
-        if let Err(e) =
-            T::Currency::deposit_into_existing(&Self::compute_treasury_account_id(), imbalance)
-        {
-            log::error!("💔💔 Error transferring {:?} AVT to treasury : {:?}", imbalance, e);
+    fn handle_dust(imbalance: BalanceOf<T>, growth_period: u32) {
+        match T::Currency::deposit_into_existing(&Self::compute_treasury_account_id(), imbalance) {
+            Ok(_) => Self::deposit_event(Event::<T>::CollatorPayoutDustDeposited {
+                amount: imbalance,
+                growth_period,
+            }),
+            Err(e) =>
+                log::error!("💔💔 Error transferring {:?} AVT to treasury : {:?}", imbalance, e),
         }
 
         // If the deposit succeeds, when this function goes out of scope, total issuance will
@@ -1212,14 +1744,11 @@ impl<T: Config> FeePaymentHandler for Pallet<T> {
     }
 }
 
-// TODO: The implementation feels too specific to PM, try to generalise it
+// Lifts to the prediction market are processed by this pallet and sent to the `OnLiftReceived`
+// destination registered for `PREDICTION_MARKET_LIFT_DESTINATION`.
 impl<T: Config> TokenInterface<T::TokenId, T::AccountId> for Pallet<T> {
     fn process_lift(event: &EthEvent) -> DispatchResult {
         return match &event.event_data {
-            EventData::LogLiftedToPredictionMarket(d) => {
-                let lifted_data = LiftedData::new(d.token_contract, d.receiver_address, d.amount);
-                return Self::process_lift(event, &lifted_data)
-            },
             EventData::LogErc20Transfer(d) => {
                 let lifted_data = LiftedData::new(d.token_contract, d.receiver_address, d.amount);
                 return Self::process_lift(event, &lifted_data)
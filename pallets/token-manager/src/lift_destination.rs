@@ -0,0 +1,156 @@
+// Copyright 2024 Aventus Network Services (UK) Ltd.
+
+//! Lifts to named destinations.
+//!
+//! A `LogLiftedToDestination` event names the runtime pallet the lifted tokens are for. The tokens
+//! are deposited to the recipient and the pallet registered for the destination in
+//! `Config::LiftDestinations` is notified. When no pallet is registered for the destination, or
+//! the pallet rejects the lift, the tokens are held in the lift holding account until governance
+//! releases them to the recipient. `LogLiftedToPredictionMarket` events are lifts to
+//! `PREDICTION_MARKET_LIFT_DESTINATION`.
+
+use crate::*;
+use frame_support::storage::with_storage_layer;
+use sp_avn_common::event_types::{LiftDestination, LiftedToDestinationData, OnLiftReceived};
+
+pub type HeldLiftId = u64;
+pub type HeldLiftOf<T> = HeldLift<<T as frame_system::Config>::AccountId, <T as Config>::TokenId>;
+
+const LIFT_HOLDING_SUB_ACCOUNT: &'static [u8; 8] = b"heldlift";
+
+#[derive(Encode, Decode, Clone, Debug, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+pub struct HeldLift<AccountId, TokenId> {
+    pub destination: LiftDestination,
+    pub token_id: TokenId,
+    pub recipient: AccountId,
+    pub amount: u128,
+}
+
+impl<T: Config> Pallet<T> {
+    /// The account holding the lifts that could not be delivered to their destination
+    pub fn compute_lift_holding_account_id() -> T::AccountId {
+        T::AvnTreasuryPotId::get().into_sub_account_truncating(LIFT_HOLDING_SUB_ACCOUNT)
+    }
+
+    pub(crate) fn process_lift_to_destination(
+        event: &EthEvent,
+        data: &LiftedToDestinationData,
+    ) -> DispatchResult {
+        let event_id = &event.event_id;
+        let recipient_account_id = T::AccountId::decode(&mut data.receiver_address.as_bytes())
+            .expect("32 bytes will always decode into an AccountId");
+
+        let event_validity = T::ProcessedEventsChecker::processed_event_exists(event_id);
+        ensure!(event_validity, Error::<T>::NoTier1EventForLogLiftedToDestination);
+
+        if data.amount == 0 {
+            Err(Error::<T>::AmountIsZero)?
+        }
+
+        let token_id: T::TokenId = data.token_contract.into();
+        if T::LiftDestinations::is_destination(&data.destination) {
+            let delivered = with_storage_layer(|| {
+                Self::deposit_lifted_amount(
+                    event_id.transaction_hash,
+                    data.token_contract,
+                    recipient_account_id.clone(),
+                    data.amount,
+                )?;
+                T::LiftDestinations::on_lift_received(
+                    &data.destination,
+                    token_id,
+                    &recipient_account_id,
+                    data.amount,
+                )
+            });
+
+            match delivered {
+                Ok(()) => {
+                    Self::deposit_sequenced_event(|sequence| Event::<T>::LiftSentToDestination {
+                        destination: data.destination,
+                        token_id,
+                        recipient: recipient_account_id,
+                        amount: data.amount,
+                        eth_tx_hash: event_id.transaction_hash,
+                        sequence,
+                    });
+                    return Ok(())
+                },
+                Err(e) => log::warn!(
+                    "Lift destination {:?} rejected lift {:?}: {:?}",
+                    data.destination,
+                    event_id,
+                    e
+                ),
+            }
+        }
+
+        Self::deposit_lifted_amount(
+            event_id.transaction_hash,
+            data.token_contract,
+            Self::compute_lift_holding_account_id(),
+            data.amount,
+        )?;
+
+        let held_lift_id = <NextHeldLiftId<T>>::mutate(|next_id| {
+            let held_lift_id = *next_id;
+            *next_id = next_id.saturating_add(1);
+            held_lift_id
+        });
+        <HeldLifts<T>>::insert(
+            held_lift_id,
+            HeldLift {
+                destination: data.destination,
+                token_id,
+                recipient: recipient_account_id.clone(),
+                amount: data.amount,
+            },
+        );
+
+        Self::deposit_sequenced_event(|sequence| Event::<T>::LiftHeld {
+            held_lift_id,
+            destination: data.destination,
+            token_id,
+            recipient: recipient_account_id,
+            amount: data.amount,
+            eth_tx_hash: event_id.transaction_hash,
+            sequence,
+        });
+
+        Ok(())
+    }
+
+    /// Pays a held lift to its recipient, and notifies its destination if a pallet has been
+    /// registered for it since the lift was held
+    pub(crate) fn do_release_held_lift(held_lift_id: HeldLiftId) -> DispatchResult {
+        let held_lift = <HeldLifts<T>>::take(held_lift_id).ok_or(Error::<T>::HeldLiftNotFound)?;
+
+        let amount = <T::TokenBalance as TryFrom<u128>>::try_from(held_lift.amount)
+            .map_err(|_| Error::<T>::ErrorConvertingTokenBalance)?;
+        Self::do_settle_transfer(
+            &held_lift.token_id,
+            &Self::compute_lift_holding_account_id(),
+            &held_lift.recipient,
+            &amount,
+            ExistenceRequirement::AllowDeath,
+        )?;
+
+        let sent_to_destination = T::LiftDestinations::is_destination(&held_lift.destination);
+        if sent_to_destination {
+            T::LiftDestinations::on_lift_received(
+                &held_lift.destination,
+                held_lift.token_id,
+                &held_lift.recipient,
+                held_lift.amount,
+            )?;
+        }
+
+        Self::deposit_event(Event::<T>::HeldLiftReleased {
+            held_lift_id,
+            recipient: held_lift.recipient,
+            sent_to_destination,
+        });
+
+        Ok(())
+    }
+}
@@ -1,8 +1,13 @@
-use crate::{Config, LowerSchedulePeriod, Pallet};
+use crate::{
+    Config, FailedLowerProofs, LowerDataLimit, LowerParams, LowerProofData, LowerSchedulePeriod,
+    LowersPendingProof, LowersReadyToClaim, Pallet, PACKED_LOWER_PARAM_SIZE,
+};
+use codec::{Decode, Encode};
 use frame_support::{
     pallet_prelude::{PhantomData, StorageVersion},
     traits::{Get, GetStorageVersion, OnRuntimeUpgrade},
     weights::Weight,
+    BoundedVec,
 };
 use frame_system::pallet_prelude::BlockNumberFor;
 
@@ -12,7 +17,7 @@ use crate::Vec;
 #[cfg(feature = "try-runtime")]
 use sp_runtime::TryRuntimeError;
 
-pub const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+pub const STORAGE_VERSION: StorageVersion = StorageVersion::new(2);
 
 pub fn set_lower_schedule_period<T: Config>() -> Weight {
     let default_lower_schedule_period: BlockNumberFor<T> = 3275u32.into(); // ~ 12 hrs
@@ -28,7 +33,7 @@ pub fn set_lower_schedule_period<T: Config>() -> Weight {
 
     //Write: [LowerSchedulePeriod, STORAGE_VERSION]
     add_weight(0, 2, Weight::from_parts(0 as u64, 0));
-    STORAGE_VERSION.put::<Pallet<T>>();
+    StorageVersion::new(1).put::<Pallet<T>>();
 
     log::info!("✅ Lower schedule period successfully");
 
@@ -85,3 +90,99 @@ impl<T: Config> OnRuntimeUpgrade for SetLowerSchedulePeriod<T> {
         Ok(())
     }
 }
+
+mod v1 {
+    use super::*;
+
+    pub const PACKED_LOWER_PARAM_SIZE: usize = 76;
+
+    #[derive(Encode, Decode)]
+    pub struct LowerProofData {
+        pub params: [u8; PACKED_LOWER_PARAM_SIZE],
+        pub encoded_lower_data: BoundedVec<u8, LowerDataLimit>,
+    }
+}
+
+fn pad_lower_params(old_params: [u8; v1::PACKED_LOWER_PARAM_SIZE]) -> LowerParams {
+    let mut params = [0u8; PACKED_LOWER_PARAM_SIZE];
+    params[0..v1::PACKED_LOWER_PARAM_SIZE].copy_from_slice(&old_params);
+    params
+}
+
+pub fn add_memo_to_lower_params<T: Config>() -> Weight {
+    let mut consumed_weight: Weight = Weight::from_parts(0 as u64, 0);
+    let mut add_weight = |reads, writes, weight: Weight| {
+        consumed_weight += T::DbWeight::get().reads_writes(reads, writes);
+        consumed_weight += weight;
+    };
+
+    log::info!("🚧 🚧 Running migration to add an empty memo to the stored lower params");
+
+    let mut migrated: u64 = 0;
+    <LowersPendingProof<T>>::translate::<[u8; v1::PACKED_LOWER_PARAM_SIZE], _>(|_, params| {
+        migrated += 1;
+        Some(pad_lower_params(params))
+    });
+    <FailedLowerProofs<T>>::translate::<[u8; v1::PACKED_LOWER_PARAM_SIZE], _>(|_, params| {
+        migrated += 1;
+        Some(pad_lower_params(params))
+    });
+    // The encoded lower data is left as it is. A lower without a memo keeps the 76 bytes layout, so
+    // it still matches the padded params
+    <LowersReadyToClaim<T>>::translate::<v1::LowerProofData, _>(|_, lower: v1::LowerProofData| {
+        migrated += 1;
+        Some(LowerProofData {
+            params: pad_lower_params(lower.params),
+            encoded_lower_data: lower.encoded_lower_data,
+        })
+    });
+
+    //Read and write every lower, write STORAGE_VERSION
+    add_weight(migrated, migrated + 1, Weight::from_parts(0 as u64, 0));
+    STORAGE_VERSION.put::<Pallet<T>>();
+
+    log::info!("✅ Migrated {} lowers", migrated);
+
+    return consumed_weight + Weight::from_parts(25_000_000 as u64, 0)
+}
+
+/// Migration to make room for the lower memo in the stored lower params
+pub struct AddMemoToLowerParams<T>(PhantomData<T>);
+impl<T: Config> OnRuntimeUpgrade for AddMemoToLowerParams<T> {
+    fn on_runtime_upgrade() -> Weight {
+        let current = Pallet::<T>::current_storage_version();
+        let onchain = Pallet::<T>::on_chain_storage_version();
+
+        if onchain < 2 {
+            log::info!(
+                "💽 Running Token manager migration with current storage version {:?} / onchain {:?}",
+                current,
+                onchain
+            );
+            return add_memo_to_lower_params::<T>()
+        }
+
+        Weight::zero()
+    }
+
+    #[cfg(feature = "try-runtime")]
+    fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+        let lowers = <LowersPendingProof<T>>::iter_keys().count() +
+            <FailedLowerProofs<T>>::iter_keys().count() +
+            <LowersReadyToClaim<T>>::iter_keys().count();
+
+        Ok((lowers as u32).encode())
+    }
+
+    #[cfg(feature = "try-runtime")]
+    fn post_upgrade(input: Vec<u8>) -> Result<(), TryRuntimeError> {
+        let initial_lowers: u32 =
+            Decode::decode(&mut input.as_slice()).expect("Initial lower count is invalid");
+        let lowers = <LowersPendingProof<T>>::iter_values().count() +
+            <FailedLowerProofs<T>>::iter_values().count() +
+            <LowersReadyToClaim<T>>::iter_values().count();
+
+        frame_support::ensure!(initial_lowers == lowers as u32, "Some lowers were not migrated");
+        Ok(())
+    }
+}
@@ -30,14 +30,14 @@ use pallet_avn::BridgeInterfaceNotification;
 use pallet_transaction_payment::CurrencyAdapter;
 use sp_avn_common::{
     avn_tests_helpers::ethereum_converters::*,
-    event_types::{EthEventId, LiftedData, ValidEvents},
+    event_types::{EthEventId, LiftedData, ValidEvents, PREDICTION_MARKET_LIFT_DESTINATION},
 };
 use sp_core::{sr25519, ConstU128, ConstU64, Pair, H256};
 use sp_keystore::{testing::MemoryKeystore, KeystoreExt};
 use sp_runtime::{
     testing::{TestXt, UintAuthorityId},
     traits::{BlakeTwo256, ConvertInto, IdentifyAccount, IdentityLookup, Verify},
-    BuildStorage, Perbill, SaturatedConversion,
+    BuildStorage, DispatchError, Perbill, SaturatedConversion,
 };
 
 use hex_literal::hex;
@@ -68,8 +68,9 @@ frame_support::construct_runtime!(
     {
         System: frame_system::{Pallet, Call, Config<T>, Storage, Event<T>},
         Balances: pallet_balances::{Pallet, Call, Storage, Config<T>, Event<T>},
-        AVN: pallet_avn::{Pallet, Storage, Event},
+        AVN: pallet_avn::{Pallet, Storage, Event<T>},
         TokenManager: token_manager::{Pallet, Call, Storage, Event<T>, Config<T>},
+        Vesting: pallet_vesting::{Pallet, Call, Storage, Event<T>},
         TransactionPayment: pallet_transaction_payment::{Pallet, Storage, Event<T>, Config<T>},
         Session: pallet_session::{Pallet, Call, Storage, Event, Config<T>},
         ParachainStaking: parachain_staking::{Pallet, Call, Storage, Config<T>, Event<T>},
@@ -103,6 +104,23 @@ impl token_manager::Config for TestRuntime {
     type Preimages = Preimage;
     type PalletsOrigin = OriginCaller;
     type BridgeInterface = EthBridge;
+    type Vesting = Vesting;
+    type LiftDestinations = TestLiftDestination;
+}
+
+parameter_types! {
+    pub UnvestedFundsAllowedWithdrawReasons: WithdrawReasons =
+        WithdrawReasons::except(WithdrawReasons::TRANSFER | WithdrawReasons::RESERVE);
+}
+
+impl pallet_vesting::Config for TestRuntime {
+    type RuntimeEvent = RuntimeEvent;
+    type Currency = Balances;
+    type BlockNumberToBalance = ConvertInto;
+    type MinVestedTransfer = ConstU128<1>;
+    type WeightInfo = ();
+    type UnvestedFundsAllowedWithdrawReasons = UnvestedFundsAllowedWithdrawReasons;
+    const MAX_VESTING_SCHEDULES: u32 = 3;
 }
 
 impl avn::Config for TestRuntime {
@@ -207,7 +225,7 @@ impl system::Config for TestRuntime {
 }
 
 parameter_types! {
-    pub const ExistentialDeposit: u64 = EXISTENTIAL_DEPOSIT;
+    pub static ExistentialDeposit: u64 = EXISTENTIAL_DEPOSIT;
 }
 
 impl pallet_balances::Config for TestRuntime {
@@ -261,6 +279,7 @@ parameter_types! {
     pub const MinNominationPerCollator: u128 = 3;
     pub const ErasPerGrowthPeriod: u32 = 2;
     pub const RewardPaymentDelay: u32 = 2;
+    pub const HistoryDepth: u32 = 3;
     pub const RewardPotId: PalletId = PalletId(*b"av/vamgr");
     pub const MaxCandidates: u32 = 100;
     pub const GrowthEnabled: bool = true;
@@ -272,6 +291,7 @@ impl parachain_staking::Config for TestRuntime {
     type Currency = Balances;
     type MinBlocksPerEra = MinBlocksPerEra;
     type RewardPaymentDelay = RewardPaymentDelay;
+    type HistoryDepth = HistoryDepth;
     type MinSelectedCandidates = MinSelectedCandidates;
     type MaxTopNominationsPerCandidate = MaxTopNominationsPerCandidate;
     type MaxBottomNominationsPerCandidate = MaxBottomNominationsPerCandidate;
@@ -283,12 +303,21 @@ impl parachain_staking::Config for TestRuntime {
     type Signature = Signature;
     type CollatorSessionRegistration = Session;
     type CollatorPayoutDustHandler = TokenManager;
+    type DisabledOffenderCheck = ();
     type ProcessedEventsChecker = ();
     type WeightInfo = ();
     type MaxCandidates = MaxCandidates;
     type AccountToBytesConvert = AVN;
     type BridgeInterface = EthBridge;
     type GrowthEnabled = GrowthEnabled;
+    type AdminOrigin = frame_system::EnsureRoot<Self::AccountId>;
+    type Scheduler = Scheduler;
+    type Preimages = Preimage;
+    type PalletsOrigin = OriginCaller;
+    type AdminChangeDelay = ConstU64<10>;
+    type IdentityProvider = ();
+    type RewardPotTopUp = ();
+    type MaxNominationRequestCleanupsPerBlock = ConstU32<10>;
 }
 
 impl pallet_session::historical::Config for TestRuntime {
@@ -308,6 +337,7 @@ impl pallet_eth_bridge::Config for TestRuntime {
     type ReportCorroborationOffence = ();
     type ProcessedEventsChecker = ();
     type EthereumEventsFilter = ();
+    type AdminOrigin = frame_system::EnsureRoot<Self::AccountId>;
 }
 
 impl pallet_timestamp::Config for TestRuntime {
@@ -371,6 +401,35 @@ impl TestAccount {
 
 thread_local! {
     static PROCESSED_EVENTS: RefCell<Vec<EthEventId>> = RefCell::new(vec![]);
+    pub static RECEIVED_LIFTS: RefCell<Vec<(H160, AccountId, u128)>> = RefCell::new(vec![]);
+}
+
+pub const TEST_LIFT_DESTINATION: H256 = H256::repeat_byte(1);
+/// A registered destination that rejects every lift
+pub const REJECTING_LIFT_DESTINATION: H256 = H256::repeat_byte(2);
+
+pub struct TestLiftDestination;
+impl OnLiftReceived<H160, AccountId> for TestLiftDestination {
+    fn is_destination(destination: &LiftDestination) -> bool {
+        *destination == TEST_LIFT_DESTINATION ||
+            *destination == REJECTING_LIFT_DESTINATION ||
+            *destination == PREDICTION_MARKET_LIFT_DESTINATION
+    }
+
+    fn on_lift_received(
+        destination: &LiftDestination,
+        token_id: H160,
+        recipient: &AccountId,
+        amount: u128,
+    ) -> DispatchResult {
+        ensure!(*destination != REJECTING_LIFT_DESTINATION, DispatchError::Other("Lift rejected"));
+        RECEIVED_LIFTS.with(|l| l.borrow_mut().push((token_id, recipient.clone(), amount)));
+        Ok(())
+    }
+}
+
+pub fn received_lifts() -> Vec<(H160, AccountId, u128)> {
+    RECEIVED_LIFTS.with(|l| l.borrow().clone())
 }
 
 pub fn insert_to_mock_processed_events(event_id: &EthEventId) {
@@ -0,0 +1,207 @@
+// Copyright 2024 Aventus Network Services (UK) Ltd.
+
+//! Payment streams of AVT or of lifted tokens.
+//!
+//! The sender of a stream escrows `rate_per_block` for every block of the stream when creating
+//! it. The amount vests to the recipient block by block and the recipient can withdraw what has
+//! vested at any time. Either party can cancel the stream: the recipient is paid what has vested
+//! and the sender is refunded the rest. The escrow account is kept alive with the existential
+//! deposit, which the treasury provides when the first AVT stream is created.
+
+use crate::*;
+use frame_system::pallet_prelude::BlockNumberFor;
+use sp_runtime::{traits::UniqueSaturatedInto, DispatchError};
+
+pub type StreamId = u64;
+pub type PaymentStreamOf<T> = PaymentStream<
+    <T as frame_system::Config>::AccountId,
+    <T as Config>::TokenId,
+    <T as Config>::TokenBalance,
+    BlockNumberFor<T>,
+>;
+
+const STREAM_ESCROW_SUB_ACCOUNT: &'static [u8; 7] = b"streams";
+
+#[derive(Encode, Decode, Clone, Debug, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+pub struct PaymentStream<AccountId, TokenId, TokenBalance, BlockNumber> {
+    pub sender: AccountId,
+    pub recipient: AccountId,
+    pub token_id: TokenId,
+    pub rate_per_block: TokenBalance,
+    /// The first block the stream vests in
+    pub start_block: BlockNumber,
+    /// The stream is fully vested from this block
+    pub end_block: BlockNumber,
+    /// The amount the recipient has withdrawn so far
+    pub withdrawn: TokenBalance,
+}
+
+impl<T: Config> Pallet<T> {
+    /// The account holding the funds of all the payment streams
+    pub fn compute_stream_escrow_account_id() -> T::AccountId {
+        T::AvnTreasuryPotId::get().into_sub_account_truncating(STREAM_ESCROW_SUB_ACCOUNT)
+    }
+
+    pub(crate) fn do_create_stream(
+        sender: T::AccountId,
+        recipient: T::AccountId,
+        token_id: T::TokenId,
+        rate_per_block: T::TokenBalance,
+        duration: BlockNumberFor<T>,
+    ) -> DispatchResult {
+        ensure!(sender != recipient, Error::<T>::StreamToSelf);
+        ensure!(!rate_per_block.is_zero(), Error::<T>::AmountIsZero);
+        ensure!(!duration.is_zero(), Error::<T>::InvalidStreamDuration);
+
+        let deposit = Self::streamed_amount(rate_per_block, duration)?;
+        if token_id == Self::avt_token_contract().into() {
+            Self::endow_stream_escrow()?;
+        }
+        Self::do_settle_transfer(
+            &token_id,
+            &sender,
+            &Self::compute_stream_escrow_account_id(),
+            &deposit,
+            ExistenceRequirement::KeepAlive,
+        )?;
+
+        let stream_id = <NextStreamId<T>>::mutate(|next_id| {
+            let stream_id = *next_id;
+            *next_id = next_id.saturating_add(1);
+            stream_id
+        });
+        let start_block = <frame_system::Pallet<T>>::block_number();
+        let end_block = start_block.saturating_add(duration);
+        <PaymentStreams<T>>::insert(
+            stream_id,
+            PaymentStream {
+                sender: sender.clone(),
+                recipient: recipient.clone(),
+                token_id,
+                rate_per_block,
+                start_block,
+                end_block,
+                withdrawn: Zero::zero(),
+            },
+        );
+
+        Self::deposit_event(Event::<T>::StreamCreated {
+            stream_id,
+            sender,
+            recipient,
+            token_id,
+            rate_per_block,
+            end_block,
+        });
+
+        Ok(())
+    }
+
+    pub(crate) fn do_withdraw_from_stream(
+        recipient: T::AccountId,
+        stream_id: StreamId,
+    ) -> DispatchResult {
+        let mut stream = Self::payment_stream(stream_id).ok_or(Error::<T>::StreamNotFound)?;
+        ensure!(stream.recipient == recipient, Error::<T>::SenderNotValid);
+
+        let vested = Self::vested_amount(&stream)?;
+        let amount = vested.saturating_sub(stream.withdrawn);
+        ensure!(!amount.is_zero(), Error::<T>::NothingToWithdraw);
+
+        Self::pay_from_stream_escrow(&stream.token_id, &recipient, &amount)?;
+
+        stream.withdrawn = vested;
+        if <frame_system::Pallet<T>>::block_number() >= stream.end_block {
+            <PaymentStreams<T>>::remove(stream_id);
+        } else {
+            <PaymentStreams<T>>::insert(stream_id, stream);
+        }
+
+        Self::deposit_event(Event::<T>::StreamWithdrawn { stream_id, recipient, amount });
+
+        Ok(())
+    }
+
+    pub(crate) fn do_cancel_stream(sender: T::AccountId, stream_id: StreamId) -> DispatchResult {
+        let stream = Self::payment_stream(stream_id).ok_or(Error::<T>::StreamNotFound)?;
+        ensure!(stream.sender == sender || stream.recipient == sender, Error::<T>::SenderNotValid);
+
+        let vested = Self::vested_amount(&stream)?;
+        let recipient_amount = vested.saturating_sub(stream.withdrawn);
+        let sender_refund = Self::streamed_amount(
+            stream.rate_per_block,
+            stream.end_block.saturating_sub(stream.start_block),
+        )?
+        .saturating_sub(vested);
+
+        if !recipient_amount.is_zero() {
+            Self::pay_from_stream_escrow(&stream.token_id, &stream.recipient, &recipient_amount)?;
+        }
+        if !sender_refund.is_zero() {
+            Self::pay_from_stream_escrow(&stream.token_id, &stream.sender, &sender_refund)?;
+        }
+
+        <PaymentStreams<T>>::remove(stream_id);
+
+        Self::deposit_event(Event::<T>::StreamCancelled {
+            stream_id,
+            recipient_amount,
+            sender_refund,
+        });
+
+        Ok(())
+    }
+
+    /// The amount of the stream that has vested to the recipient, including what was withdrawn
+    pub fn vested_amount(stream: &PaymentStreamOf<T>) -> Result<T::TokenBalance, DispatchError> {
+        let now = <frame_system::Pallet<T>>::block_number();
+        let vested_blocks = now.min(stream.end_block).saturating_sub(stream.start_block);
+        Self::streamed_amount(stream.rate_per_block, vested_blocks)
+    }
+
+    fn streamed_amount(
+        rate_per_block: T::TokenBalance,
+        blocks: BlockNumberFor<T>,
+    ) -> Result<T::TokenBalance, DispatchError> {
+        let rate_per_block = TryInto::<u128>::try_into(rate_per_block)
+            .map_err(|_| Error::<T>::ErrorConvertingTokenBalance)?;
+        let amount = rate_per_block
+            .checked_mul(blocks.unique_saturated_into())
+            .ok_or(Error::<T>::AmountOverflow)?;
+
+        Ok(<T::TokenBalance as TryFrom<u128>>::try_from(amount)
+            .map_err(|_| Error::<T>::AmountOverflow)?)
+    }
+
+    fn pay_from_stream_escrow(
+        token_id: &T::TokenId,
+        recipient: &T::AccountId,
+        amount: &T::TokenBalance,
+    ) -> DispatchResult {
+        Self::do_settle_transfer(
+            token_id,
+            &Self::compute_stream_escrow_account_id(),
+            recipient,
+            amount,
+            ExistenceRequirement::KeepAlive,
+        )
+    }
+
+    /// Tops up the escrow to the existential deposit from the treasury, so paying out the last
+    /// stream does not reap it
+    fn endow_stream_escrow() -> DispatchResult {
+        let escrow = Self::compute_stream_escrow_account_id();
+        let shortfall = <T as pallet::Config>::Currency::minimum_balance()
+            .saturating_sub(<T as pallet::Config>::Currency::free_balance(&escrow));
+        if shortfall.is_zero() {
+            return Ok(())
+        }
+
+        <T as pallet::Config>::Currency::transfer(
+            &Self::compute_treasury_account_id(),
+            &escrow,
+            shortfall,
+            ExistenceRequirement::KeepAlive,
+        )
+    }
+}
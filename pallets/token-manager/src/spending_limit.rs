@@ -0,0 +1,132 @@
+// Copyright 2024 Aventus Network Services (UK) Ltd.
+
+//! Spending limits of proxied token operations.
+//!
+//! An account can cap how much of a token the transfers and lowers it authorises with a
+//! signature move within any `period` blocks, so a compromised relayer holding its signed
+//! operations can't move more than that. An operation that would take the spending of the last
+//! `period` blocks over the limit is not executed: its authorisation is used up and
+//! `SpendingLimitExceeded` is emitted. Operations the account submits itself are not limited.
+//!
+//! Spending is recorded in consecutive windows of `period` blocks rather than per operation, so
+//! the spending of the last `period` blocks is the spending of the current window plus the share
+//! of the previous window that is still within the period, as if its operations were evenly spread
+//! over it.
+
+use crate::*;
+use frame_system::pallet_prelude::BlockNumberFor;
+use sp_runtime::{
+    traits::{AtLeast32BitUnsigned, One, UniqueSaturatedInto},
+    Perquintill,
+};
+
+pub type SpendingLimitOf<T> = SpendingLimit<BlockNumberFor<T>>;
+pub type SpendingWindowOf<T> = SpendingWindow<BlockNumberFor<T>>;
+
+#[derive(Encode, Decode, Clone, Debug, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+pub struct SpendingLimit<BlockNumber> {
+    /// The most the proxied operations of a window can move
+    pub amount: u128,
+    /// The length of a window, in blocks
+    pub period: BlockNumber,
+}
+
+#[derive(Encode, Decode, Clone, Debug, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+pub struct SpendingWindow<BlockNumber> {
+    /// The first block of the window
+    pub start: BlockNumber,
+    /// What the proxied operations of the window have moved so far
+    pub spent: u128,
+    /// What the proxied operations of the window before it moved
+    pub previous_spent: u128,
+}
+
+impl<BlockNumber: AtLeast32BitUnsigned + Copy> SpendingWindow<BlockNumber> {
+    fn new(start: BlockNumber) -> Self {
+        SpendingWindow { start, spent: 0, previous_spent: 0 }
+    }
+
+    /// Moves on to the window of `period` blocks that includes `now`
+    fn advance(self, now: BlockNumber, period: BlockNumber) -> Self {
+        let windows_passed = now.saturating_sub(self.start) / period;
+        if windows_passed.is_zero() {
+            return self
+        }
+
+        SpendingWindow {
+            start: self.start.saturating_add(windows_passed.saturating_mul(period)),
+            spent: 0,
+            previous_spent: if windows_passed.is_one() { self.spent } else { 0 },
+        }
+    }
+
+    /// What was spent over the last `period` blocks, counting the part of the previous window
+    /// still within them
+    fn spent_over(&self, now: BlockNumber, period: BlockNumber) -> u128 {
+        let blocks_of_previous_window = period.saturating_sub(now.saturating_sub(self.start));
+        let share_of_previous_window = Perquintill::from_rational(
+            UniqueSaturatedInto::<u64>::unique_saturated_into(blocks_of_previous_window),
+            UniqueSaturatedInto::<u64>::unique_saturated_into(period),
+        );
+
+        share_of_previous_window
+            .mul_ceil(self.previous_spent)
+            .saturating_add(self.spent)
+    }
+}
+
+impl<T: Config> Pallet<T> {
+    pub(crate) fn do_set_spending_limit(
+        account: T::AccountId,
+        token_id: T::TokenId,
+        limit: Option<SpendingLimitOf<T>>,
+    ) -> DispatchResult {
+        match &limit {
+            Some(spending_limit) => {
+                ensure!(!spending_limit.period.is_zero(), Error::<T>::InvalidSpendingLimit);
+                <SpendingLimits<T>>::insert(&account, &token_id, spending_limit);
+            },
+            None => {
+                <SpendingLimits<T>>::remove(&account, &token_id);
+                <SpendingWindows<T>>::remove(&account, &token_id);
+            },
+        }
+
+        Self::deposit_event(Event::<T>::SpendingLimitSet { account, token_id, limit });
+
+        Ok(())
+    }
+
+    /// Records a proxied operation of `account` moving `amount` of `token_id`. Returns false,
+    /// without recording it, if the operation would exceed the spending limit of the account.
+    pub(crate) fn record_limited_spend(
+        account: &T::AccountId,
+        token_id: &T::TokenId,
+        amount: u128,
+    ) -> bool {
+        let limit = match Self::spending_limit(account, token_id) {
+            Some(limit) => limit,
+            None => return true,
+        };
+
+        let now = <frame_system::Pallet<T>>::block_number();
+        let mut window = Self::spending_window(account, token_id)
+            .map(|window| window.advance(now, limit.period))
+            .unwrap_or_else(|| SpendingWindow::new(now));
+
+        let spent_over_period = window.spent_over(now, limit.period);
+        if spent_over_period.saturating_add(amount) > limit.amount {
+            Self::deposit_event(Event::<T>::SpendingLimitExceeded {
+                account: account.clone(),
+                token_id: *token_id,
+                amount,
+                remaining: limit.amount.saturating_sub(spent_over_period),
+            });
+            return false
+        }
+
+        window.spent = window.spent.saturating_add(amount);
+        <SpendingWindows<T>>::insert(account, token_id, window);
+        true
+    }
+}
@@ -51,7 +51,9 @@ fn schedule_lower(
             recipient: burn_acc,
             amount,
             t1_recipient,
-            lower_id: expected_lower_id
+            lower_id: expected_lower_id,
+            memo: None,
+            sequence: pallet_avn::Pallet::<TestRuntime>::next_event_sequence() - 1,
         })));
 }
 
@@ -107,7 +109,8 @@ fn avn_test_lift_to_zero_balance_account_should_succeed() {
             RuntimeEvent::TokenManager(crate::Event::<TestRuntime>::AVTLifted {
                 recipient: mock_data.receiver_account_id,
                 amount: AMOUNT_123_TOKEN,
-                eth_tx_hash: mock_event.event_id.transaction_hash
+                eth_tx_hash: mock_event.event_id.transaction_hash,
+                sequence: 0,
             })));
     });
 }
@@ -140,7 +143,8 @@ fn avn_test_lift_to_non_zero_balance_account_should_succeed() {
             RuntimeEvent::TokenManager(crate::Event::<TestRuntime>::AVTLifted {
                 recipient: mock_data.receiver_account_id,
                 amount: AMOUNT_123_TOKEN,
-                eth_tx_hash: mock_event.event_id.transaction_hash
+                eth_tx_hash: mock_event.event_id.transaction_hash,
+                sequence: 0,
             })));
     });
 }
@@ -166,7 +170,8 @@ fn avn_test_lift_max_balance_to_zero_balance_account_should_succeed() {
             RuntimeEvent::TokenManager(crate::Event::<TestRuntime>::AVTLifted {
                 recipient: mock_data.receiver_account_id,
                 amount: u128_max_amount,
-                eth_tx_hash: mock_event.event_id.transaction_hash
+                eth_tx_hash: mock_event.event_id.transaction_hash,
+                sequence: 0,
             })));
     });
 }
@@ -195,7 +200,8 @@ fn avn_test_lift_max_balance_to_non_zero_balance_account_should_return_deposit_f
             RuntimeEvent::TokenManager(crate::Event::<TestRuntime>::AVTLifted {
                 recipient: mock_data.receiver_account_id,
                 amount: u128_max_amount,
-                eth_tx_hash: mock_event.event_id.transaction_hash
+                eth_tx_hash: mock_event.event_id.transaction_hash,
+                sequence: 0,
             })));
     });
 }
@@ -236,7 +242,9 @@ fn avn_test_lower_all_avt_token_succeed() {
                 recipient: to_account_id,
                 amount,
                 t1_recipient,
-                lower_id: 0
+                lower_id: 0,
+                memo: None,
+                sequence: 1,
             })));
     });
 }
@@ -277,7 +285,9 @@ fn avn_test_lower_some_avt_token_succeed() {
                 recipient: to_account_id,
                 amount,
                 t1_recipient,
-                lower_id: 0
+                lower_id: 0,
+                memo: None,
+                sequence: 1,
             })));
     });
 }
@@ -361,7 +371,9 @@ fn avn_test_avt_token_total_lowered_amount_greater_than_balance_max_value_ok() {
                 recipient: to_account_id,
                 amount,
                 t1_recipient,
-                lower_id: 0
+                lower_id: 0,
+                memo: None,
+                sequence: 1,
             })));
 
         // Lift and lower AVT tokens again
@@ -392,7 +404,9 @@ fn avn_test_avt_token_total_lowered_amount_greater_than_balance_max_value_ok() {
                 recipient: to_account_id,
                 amount,
                 t1_recipient,
-                lower_id: 1
+                lower_id: 1,
+                memo: None,
+                sequence: 3,
             })));
     });
 }
@@ -417,7 +431,8 @@ fn avt_lower_claimed_succesfully() {
 
         assert!(System::events().iter().any(|a| a.event ==
             RuntimeEvent::TokenManager(crate::Event::<TestRuntime>::AvtLowerClaimed {
-                lower_id
+                lower_id,
+                sequence: 2,
             })));
     });
 }
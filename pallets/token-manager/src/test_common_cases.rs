@@ -58,14 +58,16 @@ fn avn_test_lift_ignored_when_event_type_does_not_match() {
             RuntimeEvent::TokenManager(crate::Event::<TestRuntime>::AVTLifted {
                 recipient: mock_data.receiver_account_id,
                 amount: AMOUNT_123_TOKEN,
-                eth_tx_hash: mock_event.event_id.transaction_hash
+                eth_tx_hash: mock_event.event_id.transaction_hash,
+                sequence: 0,
             })));
         assert!(!System::events().iter().any(|a| a.event ==
             RuntimeEvent::TokenManager(crate::Event::<TestRuntime>::TokenLifted {
                 token_id: NON_AVT_TOKEN_ID,
                 recipient: mock_data.receiver_account_id,
                 token_balance: mock_data.token_balance_123_tokens,
-                eth_tx_hash: mock_event.event_id.transaction_hash
+                eth_tx_hash: mock_event.event_id.transaction_hash,
+                sequence: 0,
             })));
     });
 }
@@ -104,14 +106,16 @@ fn avn_test_lift_zero_amount_should_fail() {
             RuntimeEvent::TokenManager(crate::Event::<TestRuntime>::AVTLifted {
                 recipient: mock_data.receiver_account_id,
                 amount: zero_amount,
-                eth_tx_hash: mock_event.event_id.transaction_hash
+                eth_tx_hash: mock_event.event_id.transaction_hash,
+                sequence: 0,
             })));
         assert!(!System::events().iter().any(|a| a.event ==
             RuntimeEvent::TokenManager(crate::Event::<TestRuntime>::TokenLifted {
                 token_id: NON_AVT_TOKEN_ID,
                 recipient: mock_data.receiver_account_id,
                 token_balance: token_balance_zero_tokens,
-                eth_tx_hash: mock_event.event_id.transaction_hash
+                eth_tx_hash: mock_event.event_id.transaction_hash,
+                sequence: 0,
             })));
     });
 }
@@ -147,14 +151,16 @@ fn avn_test_lift_should_fail_when_event_is_not_in_processed_events() {
             RuntimeEvent::TokenManager(crate::Event::<TestRuntime>::AVTLifted {
                 recipient: mock_data.receiver_account_id,
                 amount: AMOUNT_123_TOKEN,
-                eth_tx_hash: mock_event.event_id.transaction_hash
+                eth_tx_hash: mock_event.event_id.transaction_hash,
+                sequence: 0,
             })));
         assert!(!System::events().iter().any(|a| a.event ==
             RuntimeEvent::TokenManager(crate::Event::<TestRuntime>::TokenLifted {
                 token_id: NON_AVT_TOKEN_ID,
                 recipient: mock_data.receiver_account_id,
                 token_balance: mock_data.token_balance_123_tokens,
-                eth_tx_hash: mock_event.event_id.transaction_hash
+                eth_tx_hash: mock_event.event_id.transaction_hash,
+                sequence: 0,
             })));
     });
 }
@@ -45,6 +45,8 @@ fn simple_non_avt_token_lower_works() {
                 sender_nonce: None,
                 lower_id: expected_lower_id,
                 schedule_name: expected_schedule_name,
+                memo: None,
+                sequence: 0,
             })));
 
         // No tokens have been burned
@@ -79,7 +81,9 @@ fn simple_non_avt_token_lower_works() {
                 recipient: burn_acc,
                 amount,
                 t1_recipient,
-                lower_id: 0
+                lower_id: 0,
+                memo: None,
+                sequence: 1,
             })));
 
         // There is nothing scheduled
@@ -110,7 +114,9 @@ fn lower_id_is_unique() {
                 recipient,
                 amount,
                 t1_recipient,
-                lower_id: 0
+                lower_id: 0,
+                memo: None,
+                sequence: 3,
             })));
 
         assert!(System::events().iter().any(|a| a.event ==
@@ -120,7 +126,9 @@ fn lower_id_is_unique() {
                 recipient,
                 amount: amount + 1,
                 t1_recipient,
-                lower_id: 1
+                lower_id: 1,
+                memo: None,
+                sequence: 4,
             })));
 
         assert!(System::events().iter().any(|a| a.event ==
@@ -130,7 +138,9 @@ fn lower_id_is_unique() {
                 recipient,
                 amount: amount + 2,
                 t1_recipient,
-                lower_id: 2
+                lower_id: 2,
+                memo: None,
+                sequence: 5,
             })));
     });
 }
@@ -256,6 +266,8 @@ mod cancelling {
                     sender_nonce: None,
                     lower_id: expected_lower_id,
                     schedule_name: expected_schedule_name,
+                    memo: None,
+                    sequence: 0,
                 })));
 
             // Cancel the lower
@@ -348,3 +360,39 @@ fn toggling_lowering_works() {
         ));
     });
 }
+
+#[test]
+fn lower_events_are_sequenced() {
+    let mut ext = ExtBuilder::build_default().with_genesis_config().as_externality();
+
+    ext.execute_with(|| {
+        let (_, from, _, t1_recipient) = MockData::setup_lower_request_data();
+        let sequence_before = pallet_avn::Pallet::<TestRuntime>::next_event_sequence();
+
+        schedule_lower(from, 1, t1_recipient);
+        fast_forward_to_block(get_expected_execution_block());
+
+        let topics_of = |is_event: fn(&RuntimeEvent) -> bool| {
+            System::events()
+                .into_iter()
+                .find(|record| is_event(&record.event))
+                .expect("Event is emitted")
+                .topics
+        };
+        assert_eq!(
+            topics_of(|e| matches!(
+                e,
+                RuntimeEvent::TokenManager(crate::Event::<TestRuntime>::LowerRequested { .. })
+            )),
+            vec![pallet_avn::Pallet::<TestRuntime>::event_sequence_topic(sequence_before)]
+        );
+        assert_eq!(
+            topics_of(|e| matches!(
+                e,
+                RuntimeEvent::TokenManager(crate::Event::<TestRuntime>::TokenLowered { .. })
+            )),
+            vec![pallet_avn::Pallet::<TestRuntime>::event_sequence_topic(sequence_before + 1)]
+        );
+        assert_eq!(pallet_avn::Pallet::<TestRuntime>::next_event_sequence(), sequence_before + 2);
+    });
+}
@@ -69,7 +69,8 @@ mod lifted_growth_processed_correctly {
                 RuntimeEvent::TokenManager(crate::Event::<TestRuntime>::AVTGrowthLifted {
                     treasury_share: expected_treasury_share,
                     collators_share: lifted_amount - expected_treasury_share,
-                    eth_tx_hash: growth_eth_event.event_id.transaction_hash
+                    eth_tx_hash: growth_eth_event.event_id.transaction_hash,
+                    sequence: 0,
                 })));
         });
     }
@@ -101,7 +102,8 @@ mod lifted_growth_processed_correctly {
                 RuntimeEvent::TokenManager(crate::Event::<TestRuntime>::AVTGrowthLifted {
                     treasury_share: expected_treasury_share,
                     collators_share: lifted_amount - expected_treasury_share,
-                    eth_tx_hash: growth_eth_event.event_id.transaction_hash
+                    eth_tx_hash: growth_eth_event.event_id.transaction_hash,
+                    sequence: 0,
                 })));
         });
     }
@@ -167,6 +169,13 @@ mod lifted_growth_processed_correctly {
 
             assert_eq!(Balances::free_balance(treasury_account_id), expected_treasury_share + dust);
             assert_eq!(Balances::total_issuance(), initial_issuance + lifted_amount);
+            assert!(System::events().iter().any(|a| a.event ==
+                RuntimeEvent::TokenManager(
+                    crate::Event::<TestRuntime>::CollatorPayoutDustDeposited {
+                        amount: dust,
+                        growth_period: growth_data.period
+                    }
+                )));
         });
     }
 }
@@ -0,0 +1,272 @@
+// Copyright 2024 Aventus Network Services (UK) Ltd.
+
+#![cfg(test)]
+
+use crate::{
+    mock::{Balances, RuntimeEvent, *},
+    *,
+};
+use frame_support::{assert_noop, assert_ok};
+use sp_avn_common::event_types::{
+    EthEventId, LiftedData, LiftedToDestinationData, ValidEvents,
+    PREDICTION_MARKET_LIFT_DESTINATION,
+};
+use sp_runtime::traits::BadOrigin;
+
+const UNKNOWN_LIFT_DESTINATION: H256 = H256::repeat_byte(3);
+
+fn recipient() -> AccountId {
+    AccountId::decode(&mut H256::repeat_byte(40).as_bytes()).unwrap()
+}
+
+fn holding_account() -> AccountId {
+    TokenManager::compute_lift_holding_account_id()
+}
+
+fn lift_to_destination_event(token_contract: H160, destination: H256, amount: u128) -> EthEvent {
+    EthEvent {
+        event_id: EthEventId {
+            signature: ValidEvents::LiftedToDestination.signature(),
+            transaction_hash: H256::random(),
+        },
+        event_data: EventData::LogLiftedToDestination(LiftedToDestinationData {
+            token_contract,
+            receiver_address: H256::repeat_byte(40),
+            destination,
+            amount,
+        }),
+    }
+}
+
+fn process_lift(token_contract: H160, destination: H256, amount: u128) -> EthEvent {
+    let event = lift_to_destination_event(token_contract, destination, amount);
+    insert_to_mock_processed_events(&event.event_id);
+    assert_ok!(TokenManager::on_event_processed(&event));
+    event
+}
+
+fn event_emitted(event: crate::Event<TestRuntime>) -> bool {
+    System::events()
+        .iter()
+        .any(|a| a.event == RuntimeEvent::TokenManager(event.clone()))
+}
+
+mod lifting_to_a_destination {
+    use super::*;
+
+    #[test]
+    fn deposits_the_avt_and_notifies_the_destination() {
+        let mut ext = ExtBuilder::build_default().with_genesis_config().as_externality();
+        ext.execute_with(|| {
+            let event = process_lift(AVT_TOKEN_CONTRACT, TEST_LIFT_DESTINATION, AMOUNT_100_TOKEN);
+
+            assert_eq!(Balances::free_balance(recipient()), AMOUNT_100_TOKEN);
+            assert_eq!(received_lifts(), vec![(AVT_TOKEN_CONTRACT, recipient(), AMOUNT_100_TOKEN)]);
+            assert!(event_emitted(crate::Event::<TestRuntime>::LiftSentToDestination {
+                destination: TEST_LIFT_DESTINATION,
+                token_id: AVT_TOKEN_CONTRACT,
+                recipient: recipient(),
+                amount: AMOUNT_100_TOKEN,
+                eth_tx_hash: event.event_id.transaction_hash,
+                sequence: 1,
+            }));
+        });
+    }
+
+    #[test]
+    fn deposits_the_tokens_and_notifies_the_destination() {
+        let mut ext = ExtBuilder::build_default().with_genesis_config().as_externality();
+        ext.execute_with(|| {
+            process_lift(NON_AVT_TOKEN_ID, TEST_LIFT_DESTINATION, AMOUNT_100_TOKEN);
+
+            assert_eq!(TokenManager::balance((NON_AVT_TOKEN_ID, recipient())), AMOUNT_100_TOKEN);
+            assert_eq!(received_lifts(), vec![(NON_AVT_TOKEN_ID, recipient(), AMOUNT_100_TOKEN)]);
+        });
+    }
+
+    #[test]
+    fn holds_the_lift_when_the_destination_is_unknown() {
+        let mut ext = ExtBuilder::build_default().with_genesis_config().as_externality();
+        ext.execute_with(|| {
+            let event =
+                process_lift(AVT_TOKEN_CONTRACT, UNKNOWN_LIFT_DESTINATION, AMOUNT_100_TOKEN);
+
+            assert_eq!(Balances::free_balance(recipient()), 0);
+            assert_eq!(Balances::free_balance(holding_account()), AMOUNT_100_TOKEN);
+            assert!(received_lifts().is_empty());
+            assert_eq!(
+                TokenManager::held_lift(0),
+                Some(HeldLift {
+                    destination: UNKNOWN_LIFT_DESTINATION,
+                    token_id: AVT_TOKEN_CONTRACT,
+                    recipient: recipient(),
+                    amount: AMOUNT_100_TOKEN,
+                })
+            );
+            assert!(event_emitted(crate::Event::<TestRuntime>::LiftHeld {
+                held_lift_id: 0,
+                destination: UNKNOWN_LIFT_DESTINATION,
+                token_id: AVT_TOKEN_CONTRACT,
+                recipient: recipient(),
+                amount: AMOUNT_100_TOKEN,
+                eth_tx_hash: event.event_id.transaction_hash,
+                sequence: 0,
+            }));
+        });
+    }
+
+    #[test]
+    fn sends_prediction_market_lifts_to_their_destination() {
+        let mut ext = ExtBuilder::build_default().with_genesis_config().as_externality();
+        ext.execute_with(|| {
+            let event = EthEvent {
+                event_id: EthEventId {
+                    signature: ValidEvents::LiftedToPredictionMarket.signature(),
+                    transaction_hash: H256::random(),
+                },
+                event_data: EventData::LogLiftedToPredictionMarket(LiftedData::new(
+                    NON_AVT_TOKEN_ID,
+                    H256::repeat_byte(40),
+                    AMOUNT_100_TOKEN,
+                )),
+            };
+            insert_to_mock_processed_events(&event.event_id);
+
+            assert_ok!(TokenManager::on_event_processed(&event));
+
+            assert_eq!(TokenManager::balance((NON_AVT_TOKEN_ID, recipient())), AMOUNT_100_TOKEN);
+            assert_eq!(received_lifts(), vec![(NON_AVT_TOKEN_ID, recipient(), AMOUNT_100_TOKEN)]);
+            assert!(event_emitted(crate::Event::<TestRuntime>::LiftSentToDestination {
+                destination: PREDICTION_MARKET_LIFT_DESTINATION,
+                token_id: NON_AVT_TOKEN_ID,
+                recipient: recipient(),
+                amount: AMOUNT_100_TOKEN,
+                eth_tx_hash: event.event_id.transaction_hash,
+                sequence: 1,
+            }));
+        });
+    }
+
+    #[test]
+    fn holds_the_lift_when_the_destination_rejects_it() {
+        let mut ext = ExtBuilder::build_default().with_genesis_config().as_externality();
+        ext.execute_with(|| {
+            process_lift(NON_AVT_TOKEN_ID, REJECTING_LIFT_DESTINATION, AMOUNT_100_TOKEN);
+
+            assert_eq!(TokenManager::balance((NON_AVT_TOKEN_ID, recipient())), 0);
+            assert_eq!(
+                TokenManager::balance((NON_AVT_TOKEN_ID, holding_account())),
+                AMOUNT_100_TOKEN
+            );
+            assert!(TokenManager::held_lift(0).is_some());
+        });
+    }
+
+    mod fails_when {
+        use super::*;
+
+        #[test]
+        fn the_event_has_not_been_processed() {
+            let mut ext = ExtBuilder::build_default().with_genesis_config().as_externality();
+            ext.execute_with(|| {
+                let event = lift_to_destination_event(
+                    AVT_TOKEN_CONTRACT,
+                    TEST_LIFT_DESTINATION,
+                    AMOUNT_100_TOKEN,
+                );
+
+                assert_noop!(
+                    TokenManager::on_event_processed(&event),
+                    Error::<TestRuntime>::NoTier1EventForLogLiftedToDestination
+                );
+            });
+        }
+
+        #[test]
+        fn the_amount_is_zero() {
+            let mut ext = ExtBuilder::build_default().with_genesis_config().as_externality();
+            ext.execute_with(|| {
+                let event = lift_to_destination_event(AVT_TOKEN_CONTRACT, TEST_LIFT_DESTINATION, 0);
+                insert_to_mock_processed_events(&event.event_id);
+
+                assert_noop!(
+                    TokenManager::on_event_processed(&event),
+                    Error::<TestRuntime>::AmountIsZero
+                );
+            });
+        }
+    }
+}
+
+mod release_held_lift {
+    use super::*;
+
+    #[test]
+    fn pays_the_recipient() {
+        let mut ext = ExtBuilder::build_default().with_genesis_config().as_externality();
+        ext.execute_with(|| {
+            process_lift(AVT_TOKEN_CONTRACT, UNKNOWN_LIFT_DESTINATION, AMOUNT_100_TOKEN);
+
+            assert_ok!(TokenManager::release_held_lift(RuntimeOrigin::root(), 0));
+
+            assert_eq!(Balances::free_balance(recipient()), AMOUNT_100_TOKEN);
+            assert_eq!(Balances::free_balance(holding_account()), 0);
+            assert!(received_lifts().is_empty());
+            assert_eq!(TokenManager::held_lift(0), None);
+            assert!(event_emitted(crate::Event::<TestRuntime>::HeldLiftReleased {
+                held_lift_id: 0,
+                recipient: recipient(),
+                sent_to_destination: false,
+            }));
+        });
+    }
+
+    #[test]
+    fn notifies_a_destination_registered_since_the_lift_was_held() {
+        let mut ext = ExtBuilder::build_default().with_genesis_config().as_externality();
+        ext.execute_with(|| {
+            process_lift(NON_AVT_TOKEN_ID, UNKNOWN_LIFT_DESTINATION, AMOUNT_100_TOKEN);
+            HeldLifts::<TestRuntime>::mutate(0, |held_lift| {
+                held_lift.as_mut().unwrap().destination = TEST_LIFT_DESTINATION
+            });
+
+            assert_ok!(TokenManager::release_held_lift(RuntimeOrigin::root(), 0));
+
+            assert_eq!(TokenManager::balance((NON_AVT_TOKEN_ID, recipient())), AMOUNT_100_TOKEN);
+            assert_eq!(received_lifts(), vec![(NON_AVT_TOKEN_ID, recipient(), AMOUNT_100_TOKEN)]);
+            assert!(event_emitted(crate::Event::<TestRuntime>::HeldLiftReleased {
+                held_lift_id: 0,
+                recipient: recipient(),
+                sent_to_destination: true,
+            }));
+        });
+    }
+
+    mod fails_when {
+        use super::*;
+
+        #[test]
+        fn the_origin_is_not_root() {
+            let mut ext = ExtBuilder::build_default().with_genesis_config().as_externality();
+            ext.execute_with(|| {
+                process_lift(AVT_TOKEN_CONTRACT, UNKNOWN_LIFT_DESTINATION, AMOUNT_100_TOKEN);
+
+                assert_noop!(
+                    TokenManager::release_held_lift(RuntimeOrigin::signed(recipient()), 0),
+                    BadOrigin
+                );
+            });
+        }
+
+        #[test]
+        fn the_lift_is_not_held() {
+            let mut ext = ExtBuilder::build_default().with_genesis_config().as_externality();
+            ext.execute_with(|| {
+                assert_noop!(
+                    TokenManager::release_held_lift(RuntimeOrigin::root(), 0),
+                    Error::<TestRuntime>::HeldLiftNotFound
+                );
+            });
+        }
+    }
+}
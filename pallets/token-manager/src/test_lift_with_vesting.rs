@@ -0,0 +1,146 @@
+// This file is part of Aventus.
+// Copyright (C) 2024 Aventus Network Services (UK) Ltd.
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+#![cfg(test)]
+use crate::{
+    mock::{Balances, RuntimeEvent, Vesting, *},
+    *,
+};
+use frame_support::{assert_noop, assert_ok};
+use sp_avn_common::event_types::{EthEventId, ValidEvents};
+
+const CLIFF: u32 = 10;
+const VESTING_PERIOD: u32 = 100;
+
+fn recipient() -> AccountId {
+    AccountId::decode(&mut H256::repeat_byte(40).as_bytes()).unwrap()
+}
+
+fn lift_with_vesting_event(token_contract: H160, amount: u128) -> EthEvent {
+    EthEvent {
+        event_id: EthEventId {
+            signature: ValidEvents::LiftedWithVesting.signature(),
+            transaction_hash: H256::random(),
+        },
+        event_data: EventData::LogLiftedWithVesting(LiftedWithVestingData {
+            token_contract,
+            receiver_address: H256::repeat_byte(40),
+            amount,
+            cliff: CLIFF,
+            vesting_period: VESTING_PERIOD,
+        }),
+    }
+}
+
+mod lifting_with_vesting {
+    use super::*;
+
+    #[test]
+    fn locks_the_lifted_avt_until_the_cliff() {
+        let mut ext = ExtBuilder::build_default().with_genesis_config().as_externality();
+        ext.execute_with(|| {
+            System::set_block_number(1);
+            let event = lift_with_vesting_event(AVT_TOKEN_CONTRACT, AMOUNT_100_TOKEN);
+            insert_to_mock_processed_events(&event.event_id);
+
+            assert_ok!(TokenManager::on_event_processed(&event));
+
+            let starting_block = 1 + CLIFF as u64;
+            let per_block = AMOUNT_100_TOKEN / VESTING_PERIOD as u128;
+            assert_eq!(Balances::free_balance(recipient()), AMOUNT_100_TOKEN);
+            assert_eq!(Vesting::vesting_balance(&recipient()), Some(AMOUNT_100_TOKEN));
+            assert!(System::events().iter().any(|a| a.event ==
+                RuntimeEvent::TokenManager(
+                    crate::Event::<TestRuntime>::AVTLiftedWithVesting {
+                        recipient: recipient(),
+                        amount: AMOUNT_100_TOKEN,
+                        per_block,
+                        starting_block,
+                        eth_tx_hash: event.event_id.transaction_hash,
+                        sequence: 0,
+                    }
+                )));
+
+            System::set_block_number(starting_block);
+            assert_eq!(Vesting::vesting_balance(&recipient()), Some(AMOUNT_100_TOKEN));
+        });
+    }
+
+    #[test]
+    fn unlocks_the_lifted_avt_linearly_after_the_cliff() {
+        let mut ext = ExtBuilder::build_default().with_genesis_config().as_externality();
+        ext.execute_with(|| {
+            System::set_block_number(1);
+            let event = lift_with_vesting_event(AVT_TOKEN_CONTRACT, AMOUNT_100_TOKEN);
+            insert_to_mock_processed_events(&event.event_id);
+
+            assert_ok!(TokenManager::on_event_processed(&event));
+
+            let starting_block = 1 + CLIFF as u64;
+            System::set_block_number(starting_block + VESTING_PERIOD as u64 / 2);
+            assert_eq!(Vesting::vesting_balance(&recipient()), Some(AMOUNT_100_TOKEN / 2));
+
+            System::set_block_number(starting_block + VESTING_PERIOD as u64);
+            assert_eq!(Vesting::vesting_balance(&recipient()), Some(0));
+        });
+    }
+
+    mod fails_when {
+        use super::*;
+
+        #[test]
+        fn the_event_has_not_been_processed() {
+            let mut ext = ExtBuilder::build_default().with_genesis_config().as_externality();
+            ext.execute_with(|| {
+                let event = lift_with_vesting_event(AVT_TOKEN_CONTRACT, AMOUNT_100_TOKEN);
+
+                assert_noop!(
+                    TokenManager::on_event_processed(&event),
+                    Error::<TestRuntime>::NoTier1EventForLogLiftedWithVesting
+                );
+            });
+        }
+
+        #[test]
+        fn the_token_is_not_avt() {
+            let mut ext = ExtBuilder::build_default().with_genesis_config().as_externality();
+            ext.execute_with(|| {
+                let event = lift_with_vesting_event(NON_AVT_TOKEN_ID, AMOUNT_100_TOKEN);
+                insert_to_mock_processed_events(&event.event_id);
+
+                assert_noop!(
+                    TokenManager::on_event_processed(&event),
+                    Error::<TestRuntime>::VestingNotSupportedForToken
+                );
+                assert_eq!(TokenManager::balance((NON_AVT_TOKEN_ID, recipient())), 0);
+            });
+        }
+
+        #[test]
+        fn the_amount_is_zero() {
+            let mut ext = ExtBuilder::build_default().with_genesis_config().as_externality();
+            ext.execute_with(|| {
+                let event = lift_with_vesting_event(AVT_TOKEN_CONTRACT, 0);
+                insert_to_mock_processed_events(&event.event_id);
+
+                assert_noop!(
+                    TokenManager::on_event_processed(&event),
+                    Error::<TestRuntime>::AmountIsZero
+                );
+            });
+        }
+    }
+}
@@ -0,0 +1,189 @@
+#![cfg(test)]
+use crate::{
+    mock::{RuntimeEvent, *},
+    *,
+};
+use codec::Encode;
+use frame_support::{assert_noop, assert_ok};
+use sp_core::{sr25519, Pair};
+
+fn memo() -> LowerMemo {
+    LowerMemo::try_from(b"withdrawal-42".to_vec()).expect("Memo fits")
+}
+
+fn key_pair() -> sr25519::Pair {
+    sr25519::Pair::from_seed(&[70u8; 32])
+}
+
+fn signer() -> AccountId {
+    get_account_id(&key_pair())
+}
+
+fn relayer() -> AccountId {
+    AccountId::from_raw([10; 32])
+}
+
+fn proof_for_lower_with_memo(
+    amount: u128,
+    t1_recipient: H160,
+    memo: &LowerMemo,
+) -> Proof<Signature, AccountId> {
+    let data_to_sign = (
+        SIGNED_LOWER_WITH_MEMO_CONTEXT,
+        relayer(),
+        signer(),
+        NON_AVT_TOKEN_ID,
+        amount,
+        t1_recipient,
+        memo,
+        TokenManager::nonce(signer()),
+    );
+    let signature = sign(&key_pair(), &data_to_sign.encode());
+
+    Proof { signer: signer(), relayer: relayer(), signature }
+}
+
+#[test]
+fn memo_is_carried_to_the_lower_events() {
+    let mut ext = ExtBuilder::build_default().with_genesis_config().as_externality();
+
+    ext.execute_with(|| {
+        let (_, from, burn_acc, t1_recipient) = MockData::setup_lower_request_data();
+        let amount = TokenManager::balance((NON_AVT_TOKEN_ID, from));
+
+        assert_ok!(TokenManager::schedule_direct_lower_with_memo(
+            RuntimeOrigin::signed(from),
+            from,
+            NON_AVT_TOKEN_ID,
+            amount,
+            t1_recipient,
+            memo()
+        ));
+        assert_eq!(TokenManager::lower_memo(0), Some(memo()));
+        assert!(System::events().iter().any(|a| matches!(
+            &a.event,
+            RuntimeEvent::TokenManager(crate::Event::<TestRuntime>::LowerRequested {
+                lower_id: 0,
+                memo: Some(m),
+                ..
+            }) if *m == memo()
+        )));
+
+        fast_forward_to_block(get_expected_execution_block());
+
+        assert!(System::events().iter().any(|a| a.event ==
+            RuntimeEvent::TokenManager(crate::Event::<TestRuntime>::TokenLowered {
+                token_id: NON_AVT_TOKEN_ID,
+                sender: from,
+                recipient: burn_acc,
+                amount,
+                t1_recipient,
+                lower_id: 0,
+                memo: Some(memo()),
+                sequence: 1,
+            })));
+        assert_eq!(TokenManager::lower_memo(0), None);
+    });
+}
+
+#[test]
+fn memo_is_included_in_the_lower_params() {
+    let mut ext = ExtBuilder::build_default().with_genesis_config().as_externality();
+
+    ext.execute_with(|| {
+        let (_, from, _, t1_recipient) = MockData::setup_lower_request_data();
+
+        assert_ok!(TokenManager::schedule_direct_lower_with_memo(
+            RuntimeOrigin::signed(from),
+            from,
+            NON_AVT_TOKEN_ID,
+            1,
+            t1_recipient,
+            memo()
+        ));
+        fast_forward_to_block(get_expected_execution_block());
+
+        let params = TokenManager::get_lower_pending_proof(0).expect("Proof is pending");
+        let memo = memo();
+        assert_eq!(&params[76..76 + memo.len()], memo.as_slice());
+        assert!(params[76 + memo.len()..].iter().all(|b| *b == 0));
+        assert_eq!(
+            params,
+            TokenManager::concat_lower_data(0, NON_AVT_TOKEN_ID, &1, &t1_recipient, Some(&memo))
+        );
+    });
+}
+
+#[test]
+fn lower_params_without_memo_are_zero_padded() {
+    let mut ext = ExtBuilder::build_default().with_genesis_config().as_externality();
+
+    ext.execute_with(|| {
+        let (_, from, _, t1_recipient) = MockData::setup_lower_request_data();
+
+        assert_ok!(TokenManager::schedule_direct_lower(
+            RuntimeOrigin::signed(from),
+            from,
+            NON_AVT_TOKEN_ID,
+            1,
+            t1_recipient
+        ));
+        fast_forward_to_block(get_expected_execution_block());
+
+        let params = TokenManager::get_lower_pending_proof(0).expect("Proof is pending");
+        assert!(params[76..].iter().all(|b| *b == 0));
+    });
+}
+
+mod signed_lower_with_memo {
+    use super::*;
+
+    #[test]
+    fn succeeds_with_a_signed_memo() {
+        let mut ext = ExtBuilder::build_default().with_genesis_config().as_externality();
+
+        ext.execute_with(|| {
+            let (_, _, _, t1_recipient) = MockData::setup_lower_request_data();
+            TokenManager::initialise_non_avt_tokens_to_account(signer(), 10);
+            let proof = proof_for_lower_with_memo(10, t1_recipient, &memo());
+
+            assert_ok!(TokenManager::schedule_signed_lower_with_memo(
+                RuntimeOrigin::signed(signer()),
+                proof,
+                signer(),
+                NON_AVT_TOKEN_ID,
+                10,
+                t1_recipient,
+                memo()
+            ));
+
+            assert_eq!(TokenManager::lower_memo(0), Some(memo()));
+            assert_eq!(TokenManager::nonce(signer()), 1);
+        });
+    }
+
+    #[test]
+    fn fails_when_the_memo_is_not_the_signed_one() {
+        let mut ext = ExtBuilder::build_default().with_genesis_config().as_externality();
+
+        ext.execute_with(|| {
+            let (_, _, _, t1_recipient) = MockData::setup_lower_request_data();
+            TokenManager::initialise_non_avt_tokens_to_account(signer(), 10);
+            let proof = proof_for_lower_with_memo(10, t1_recipient, &memo());
+            let other_memo = LowerMemo::try_from(b"withdrawal-43".to_vec()).expect("Memo fits");
+
+            assert_noop!(
+                TokenManager::schedule_signed_lower_with_memo(
+                    RuntimeOrigin::signed(signer()),
+                    proof,
+                    signer(),
+                    NON_AVT_TOKEN_ID,
+                    10,
+                    t1_recipient,
+                    other_memo
+                ),
+                Error::<TestRuntime>::UnauthorizedSignedLowerTransaction
+            );
+        });
+    }
+}
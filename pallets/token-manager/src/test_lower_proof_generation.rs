@@ -31,7 +31,9 @@ fn schedule_lower(
             recipient: burn_acc,
             amount,
             t1_recipient,
-            lower_id: expected_lower_id
+            lower_id: expected_lower_id,
+            memo: None,
+            sequence: pallet_avn::Pallet::<TestRuntime>::next_event_sequence() - 1,
         })));
 }
 
@@ -54,7 +54,8 @@ fn avn_test_lift_to_zero_balance_account_should_succeed() {
                 token_id: NON_AVT_TOKEN_ID,
                 recipient: mock_data.receiver_account_id,
                 token_balance: mock_data.token_balance_123_tokens,
-                eth_tx_hash: mock_event.event_id.transaction_hash
+                eth_tx_hash: mock_event.event_id.transaction_hash,
+                sequence: 0,
             })));
     });
 }
@@ -86,7 +87,8 @@ fn avn_test_lift_to_non_zero_balance_account_should_succeed() {
                 token_id: NON_AVT_TOKEN_ID,
                 recipient: mock_data.receiver_account_id,
                 token_balance: mock_data.token_balance_123_tokens,
-                eth_tx_hash: mock_event.event_id.transaction_hash
+                eth_tx_hash: mock_event.event_id.transaction_hash,
+                sequence: 0,
             })));
     });
 }
@@ -123,7 +125,8 @@ fn avn_test_lift_max_balance_to_zero_balance_account_should_succeed() {
                 token_id: NON_AVT_TOKEN_ID,
                 recipient: mock_data.receiver_account_id,
                 token_balance: token_balance_u128_max_amount,
-                eth_tx_hash: mock_event.event_id.transaction_hash
+                eth_tx_hash: mock_event.event_id.transaction_hash,
+                sequence: 0,
             })));
     });
 }
@@ -160,7 +163,8 @@ fn avn_test_lift_max_balance_to_non_zero_balance_account_should_fail_with_overfl
                 token_id: NON_AVT_TOKEN_ID,
                 recipient: mock_data.receiver_account_id,
                 token_balance: token_balance_u128_max_amount,
-                eth_tx_hash: mock_event.event_id.transaction_hash
+                eth_tx_hash: mock_event.event_id.transaction_hash,
+                sequence: 0,
             })));
     });
 }
@@ -774,7 +778,9 @@ fn avn_test_lower_all_non_avt_token_succeed() {
                 recipient: to_account_id,
                 amount,
                 t1_recipient,
-                lower_id: 0
+                lower_id: 0,
+                memo: None,
+                sequence: 1,
             })));
     });
 }
@@ -812,7 +818,9 @@ fn avn_test_lower_some_non_avt_token_succeed() {
                 recipient: to_account_id,
                 amount,
                 t1_recipient,
-                lower_id: 0
+                lower_id: 0,
+                memo: None,
+                sequence: 1,
             })));
     });
 }
@@ -900,7 +908,9 @@ fn avn_test_non_avt_token_total_lowered_amount_greater_than_balance_max_value_ok
                 recipient: to_account_id,
                 amount,
                 t1_recipient,
-                lower_id: 0
+                lower_id: 0,
+                memo: None,
+                sequence: 1,
             })));
 
         // Lift and lower non-AVT tokens again
@@ -931,7 +941,9 @@ fn avn_test_non_avt_token_total_lowered_amount_greater_than_balance_max_value_ok
                 recipient: to_account_id,
                 amount,
                 t1_recipient,
-                lower_id: 1
+                lower_id: 1,
+                memo: None,
+                sequence: 3,
             })));
     });
 }
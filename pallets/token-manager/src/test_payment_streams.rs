@@ -0,0 +1,446 @@
+// Copyright 2024 Aventus Network Services (UK) Ltd.
+
+#![cfg(test)]
+
+use crate::{
+    mock::{Balances, RuntimeEvent, *},
+    *,
+};
+use frame_support::{assert_noop, assert_ok};
+
+const RATE_PER_BLOCK: u128 = ONE_TOKEN;
+const DURATION: u64 = 10;
+const STREAM_DEPOSIT: u128 = RATE_PER_BLOCK * DURATION as u128;
+const START_BLOCK: u64 = 1;
+
+fn sender() -> AccountId {
+    account_id_with_100_avt()
+}
+
+fn recipient() -> AccountId {
+    account_id2_with_100_avt()
+}
+
+fn escrow() -> AccountId {
+    TokenManager::compute_stream_escrow_account_id()
+}
+
+fn create_stream(token_id: H160) -> StreamId {
+    let stream_id = NextStreamId::<TestRuntime>::get();
+    assert_ok!(TokenManager::create_stream(
+        RuntimeOrigin::signed(sender()),
+        recipient(),
+        token_id,
+        RATE_PER_BLOCK,
+        DURATION,
+    ));
+    stream_id
+}
+
+fn event_emitted(event: crate::Event<TestRuntime>) -> bool {
+    System::events()
+        .iter()
+        .any(|a| a.event == RuntimeEvent::TokenManager(event.clone()))
+}
+
+mod create_stream {
+    use super::*;
+
+    #[test]
+    fn escrows_the_avt_of_the_whole_stream() {
+        let mut ext = ExtBuilder::build_default()
+            .with_genesis_config()
+            .with_balances()
+            .as_externality();
+        ext.execute_with(|| {
+            let stream_id = create_stream(AVT_TOKEN_CONTRACT);
+
+            assert_eq!(Balances::free_balance(sender()), AMOUNT_100_TOKEN - STREAM_DEPOSIT);
+            assert_eq!(Balances::free_balance(escrow()), STREAM_DEPOSIT);
+            assert_eq!(
+                TokenManager::payment_stream(stream_id),
+                Some(PaymentStream {
+                    sender: sender(),
+                    recipient: recipient(),
+                    token_id: AVT_TOKEN_CONTRACT,
+                    rate_per_block: RATE_PER_BLOCK,
+                    start_block: START_BLOCK,
+                    end_block: START_BLOCK + DURATION,
+                    withdrawn: 0,
+                })
+            );
+            assert!(event_emitted(crate::Event::<TestRuntime>::StreamCreated {
+                stream_id,
+                sender: sender(),
+                recipient: recipient(),
+                token_id: AVT_TOKEN_CONTRACT,
+                rate_per_block: RATE_PER_BLOCK,
+                end_block: START_BLOCK + DURATION,
+            }));
+        });
+    }
+
+    #[test]
+    fn escrows_the_tokens_of_the_whole_stream() {
+        let mut ext = ExtBuilder::build_default().with_genesis_config().as_externality();
+        ext.execute_with(|| {
+            TokenManager::initialise_non_avt_tokens_to_account(sender(), AMOUNT_100_TOKEN);
+
+            create_stream(NON_AVT_TOKEN_ID);
+
+            assert_eq!(
+                TokenManager::balance((NON_AVT_TOKEN_ID, sender())),
+                AMOUNT_100_TOKEN - STREAM_DEPOSIT
+            );
+            assert_eq!(TokenManager::balance((NON_AVT_TOKEN_ID, escrow())), STREAM_DEPOSIT);
+        });
+    }
+
+    #[test]
+    fn gives_each_stream_a_new_id() {
+        let mut ext = ExtBuilder::build_default()
+            .with_genesis_config()
+            .with_balances()
+            .as_externality();
+        ext.execute_with(|| {
+            let first_stream_id = create_stream(AVT_TOKEN_CONTRACT);
+            let second_stream_id = create_stream(AVT_TOKEN_CONTRACT);
+
+            assert_eq!(second_stream_id, first_stream_id + 1);
+            assert_eq!(Balances::free_balance(escrow()), 2 * STREAM_DEPOSIT);
+        });
+    }
+
+    mod fails_when {
+        use super::*;
+
+        #[test]
+        fn the_recipient_is_the_sender() {
+            let mut ext = ExtBuilder::build_default()
+                .with_genesis_config()
+                .with_balances()
+                .as_externality();
+            ext.execute_with(|| {
+                assert_noop!(
+                    TokenManager::create_stream(
+                        RuntimeOrigin::signed(sender()),
+                        sender(),
+                        AVT_TOKEN_CONTRACT,
+                        RATE_PER_BLOCK,
+                        DURATION,
+                    ),
+                    Error::<TestRuntime>::StreamToSelf
+                );
+            });
+        }
+
+        #[test]
+        fn the_rate_is_zero() {
+            let mut ext = ExtBuilder::build_default()
+                .with_genesis_config()
+                .with_balances()
+                .as_externality();
+            ext.execute_with(|| {
+                assert_noop!(
+                    TokenManager::create_stream(
+                        RuntimeOrigin::signed(sender()),
+                        recipient(),
+                        AVT_TOKEN_CONTRACT,
+                        0,
+                        DURATION,
+                    ),
+                    Error::<TestRuntime>::AmountIsZero
+                );
+            });
+        }
+
+        #[test]
+        fn the_duration_is_zero() {
+            let mut ext = ExtBuilder::build_default()
+                .with_genesis_config()
+                .with_balances()
+                .as_externality();
+            ext.execute_with(|| {
+                assert_noop!(
+                    TokenManager::create_stream(
+                        RuntimeOrigin::signed(sender()),
+                        recipient(),
+                        AVT_TOKEN_CONTRACT,
+                        RATE_PER_BLOCK,
+                        0,
+                    ),
+                    Error::<TestRuntime>::InvalidStreamDuration
+                );
+            });
+        }
+
+        #[test]
+        fn the_sender_cannot_cover_the_whole_stream() {
+            let mut ext = ExtBuilder::build_default().with_genesis_config().as_externality();
+            ext.execute_with(|| {
+                TokenManager::initialise_non_avt_tokens_to_account(sender(), STREAM_DEPOSIT - 1);
+
+                assert_noop!(
+                    TokenManager::create_stream(
+                        RuntimeOrigin::signed(sender()),
+                        recipient(),
+                        NON_AVT_TOKEN_ID,
+                        RATE_PER_BLOCK,
+                        DURATION,
+                    ),
+                    Error::<TestRuntime>::InsufficientSenderBalance
+                );
+            });
+        }
+    }
+}
+
+mod withdraw_from_stream {
+    use super::*;
+
+    #[test]
+    fn pays_the_recipient_what_has_vested() {
+        let mut ext = ExtBuilder::build_default()
+            .with_genesis_config()
+            .with_balances()
+            .as_externality();
+        ext.execute_with(|| {
+            let stream_id = create_stream(AVT_TOKEN_CONTRACT);
+            System::set_block_number(START_BLOCK + 4);
+
+            assert_ok!(TokenManager::withdraw_from_stream(
+                RuntimeOrigin::signed(recipient()),
+                stream_id
+            ));
+
+            assert_eq!(Balances::free_balance(recipient()), AMOUNT_100_TOKEN + 4 * RATE_PER_BLOCK);
+            assert_eq!(Balances::free_balance(escrow()), STREAM_DEPOSIT - 4 * RATE_PER_BLOCK);
+            assert_eq!(
+                TokenManager::payment_stream(stream_id).unwrap().withdrawn,
+                4 * RATE_PER_BLOCK
+            );
+            assert!(event_emitted(crate::Event::<TestRuntime>::StreamWithdrawn {
+                stream_id,
+                recipient: recipient(),
+                amount: 4 * RATE_PER_BLOCK,
+            }));
+        });
+    }
+
+    #[test]
+    fn only_pays_what_has_vested_since_the_last_withdrawal() {
+        let mut ext = ExtBuilder::build_default().with_genesis_config().as_externality();
+        ext.execute_with(|| {
+            TokenManager::initialise_non_avt_tokens_to_account(sender(), AMOUNT_100_TOKEN);
+            let stream_id = create_stream(NON_AVT_TOKEN_ID);
+
+            System::set_block_number(START_BLOCK + 3);
+            assert_ok!(TokenManager::withdraw_from_stream(
+                RuntimeOrigin::signed(recipient()),
+                stream_id
+            ));
+            System::set_block_number(START_BLOCK + 5);
+            assert_ok!(TokenManager::withdraw_from_stream(
+                RuntimeOrigin::signed(recipient()),
+                stream_id
+            ));
+
+            assert_eq!(TokenManager::balance((NON_AVT_TOKEN_ID, recipient())), 5 * RATE_PER_BLOCK);
+            assert!(event_emitted(crate::Event::<TestRuntime>::StreamWithdrawn {
+                stream_id,
+                recipient: recipient(),
+                amount: 2 * RATE_PER_BLOCK,
+            }));
+        });
+    }
+
+    #[test]
+    fn removes_the_stream_once_it_is_fully_withdrawn() {
+        let mut ext = ExtBuilder::build_default()
+            .with_genesis_config()
+            .with_balances()
+            .as_externality();
+        ext.execute_with(|| {
+            let stream_id = create_stream(AVT_TOKEN_CONTRACT);
+            System::set_block_number(START_BLOCK + DURATION + 5);
+
+            assert_ok!(TokenManager::withdraw_from_stream(
+                RuntimeOrigin::signed(recipient()),
+                stream_id
+            ));
+
+            assert_eq!(Balances::free_balance(recipient()), AMOUNT_100_TOKEN + STREAM_DEPOSIT);
+            assert_eq!(Balances::free_balance(escrow()), 0);
+            assert_eq!(TokenManager::payment_stream(stream_id), None);
+        });
+    }
+
+    #[test]
+    fn keeps_the_escrow_alive_with_the_existential_deposit_of_the_treasury() {
+        let mut ext = ExtBuilder::build_default()
+            .with_genesis_config()
+            .with_balances()
+            .as_externality();
+        ext.execute_with(|| {
+            ExistentialDeposit::set(1);
+            let treasury = TokenManager::compute_treasury_account_id();
+            Balances::make_free_balance_be(&treasury, 10);
+
+            let stream_id = create_stream(AVT_TOKEN_CONTRACT);
+            assert_eq!(Balances::free_balance(escrow()), STREAM_DEPOSIT + 1);
+            assert_eq!(Balances::free_balance(treasury), 9);
+
+            System::set_block_number(START_BLOCK + DURATION);
+            assert_ok!(TokenManager::withdraw_from_stream(
+                RuntimeOrigin::signed(recipient()),
+                stream_id
+            ));
+
+            assert_eq!(Balances::free_balance(recipient()), AMOUNT_100_TOKEN + STREAM_DEPOSIT);
+            assert_eq!(Balances::free_balance(escrow()), 1);
+        });
+    }
+
+    mod fails_when {
+        use super::*;
+
+        #[test]
+        fn the_stream_does_not_exist() {
+            let mut ext = ExtBuilder::build_default()
+                .with_genesis_config()
+                .with_balances()
+                .as_externality();
+            ext.execute_with(|| {
+                assert_noop!(
+                    TokenManager::withdraw_from_stream(RuntimeOrigin::signed(recipient()), 0),
+                    Error::<TestRuntime>::StreamNotFound
+                );
+            });
+        }
+
+        #[test]
+        fn the_sender_is_not_the_recipient() {
+            let mut ext = ExtBuilder::build_default()
+                .with_genesis_config()
+                .with_balances()
+                .as_externality();
+            ext.execute_with(|| {
+                let stream_id = create_stream(AVT_TOKEN_CONTRACT);
+                System::set_block_number(START_BLOCK + 4);
+
+                assert_noop!(
+                    TokenManager::withdraw_from_stream(RuntimeOrigin::signed(sender()), stream_id),
+                    Error::<TestRuntime>::SenderNotValid
+                );
+            });
+        }
+
+        #[test]
+        fn nothing_has_vested() {
+            let mut ext = ExtBuilder::build_default()
+                .with_genesis_config()
+                .with_balances()
+                .as_externality();
+            ext.execute_with(|| {
+                let stream_id = create_stream(AVT_TOKEN_CONTRACT);
+
+                assert_noop!(
+                    TokenManager::withdraw_from_stream(
+                        RuntimeOrigin::signed(recipient()),
+                        stream_id
+                    ),
+                    Error::<TestRuntime>::NothingToWithdraw
+                );
+            });
+        }
+    }
+}
+
+mod cancel_stream {
+    use super::*;
+
+    #[test]
+    fn pays_the_recipient_what_has_vested_and_refunds_the_sender_the_rest() {
+        let mut ext = ExtBuilder::build_default()
+            .with_genesis_config()
+            .with_balances()
+            .as_externality();
+        ext.execute_with(|| {
+            let stream_id = create_stream(AVT_TOKEN_CONTRACT);
+            System::set_block_number(START_BLOCK + 2);
+            assert_ok!(TokenManager::withdraw_from_stream(
+                RuntimeOrigin::signed(recipient()),
+                stream_id
+            ));
+            System::set_block_number(START_BLOCK + 6);
+
+            assert_ok!(TokenManager::cancel_stream(RuntimeOrigin::signed(sender()), stream_id));
+
+            assert_eq!(Balances::free_balance(recipient()), AMOUNT_100_TOKEN + 6 * RATE_PER_BLOCK);
+            assert_eq!(Balances::free_balance(sender()), AMOUNT_100_TOKEN - 6 * RATE_PER_BLOCK);
+            assert_eq!(Balances::free_balance(escrow()), 0);
+            assert_eq!(TokenManager::payment_stream(stream_id), None);
+            assert!(event_emitted(crate::Event::<TestRuntime>::StreamCancelled {
+                stream_id,
+                recipient_amount: 4 * RATE_PER_BLOCK,
+                sender_refund: 4 * RATE_PER_BLOCK,
+            }));
+        });
+    }
+
+    #[test]
+    fn can_be_done_by_the_recipient() {
+        let mut ext = ExtBuilder::build_default().with_genesis_config().as_externality();
+        ext.execute_with(|| {
+            TokenManager::initialise_non_avt_tokens_to_account(sender(), AMOUNT_100_TOKEN);
+            let stream_id = create_stream(NON_AVT_TOKEN_ID);
+            System::set_block_number(START_BLOCK + 3);
+
+            assert_ok!(TokenManager::cancel_stream(RuntimeOrigin::signed(recipient()), stream_id));
+
+            assert_eq!(TokenManager::balance((NON_AVT_TOKEN_ID, recipient())), 3 * RATE_PER_BLOCK);
+            assert_eq!(
+                TokenManager::balance((NON_AVT_TOKEN_ID, sender())),
+                AMOUNT_100_TOKEN - 3 * RATE_PER_BLOCK
+            );
+            assert_eq!(TokenManager::balance((NON_AVT_TOKEN_ID, escrow())), 0);
+        });
+    }
+
+    mod fails_when {
+        use super::*;
+
+        #[test]
+        fn the_sender_is_not_a_party_of_the_stream() {
+            let mut ext = ExtBuilder::build_default()
+                .with_genesis_config()
+                .with_balances()
+                .as_externality();
+            ext.execute_with(|| {
+                let stream_id = create_stream(AVT_TOKEN_CONTRACT);
+
+                assert_noop!(
+                    TokenManager::cancel_stream(
+                        RuntimeOrigin::signed(account_id3_with_100_avt()),
+                        stream_id
+                    ),
+                    Error::<TestRuntime>::SenderNotValid
+                );
+            });
+        }
+
+        #[test]
+        fn the_stream_does_not_exist() {
+            let mut ext = ExtBuilder::build_default()
+                .with_genesis_config()
+                .with_balances()
+                .as_externality();
+            ext.execute_with(|| {
+                assert_noop!(
+                    TokenManager::cancel_stream(RuntimeOrigin::signed(sender()), 0),
+                    Error::<TestRuntime>::StreamNotFound
+                );
+            });
+        }
+    }
+}
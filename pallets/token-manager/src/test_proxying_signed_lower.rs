@@ -197,7 +197,9 @@ fn check_proxy_lower_default_call_succeed(call: Box<<TestRuntime as Config>::Run
             recipient: default_receiver_account_id(),
             amount: DEFAULT_AMOUNT,
             t1_recipient: default_t1_recipient(),
-            lower_id: 0
+            lower_id: 0,
+            memo: None,
+            sequence: 1,
         })));
 }
 
@@ -299,7 +301,9 @@ mod proxy_signed_lower {
                         recipient: recipient_account_id,
                         amount: DEFAULT_AMOUNT,
                         t1_recipient,
-                        lower_id: 0
+                        lower_id: 0,
+                        memo: None,
+                        sequence: 1,
                     })));
             });
         }
@@ -348,7 +352,9 @@ mod proxy_signed_lower {
                     recipient: recipient_account_id,
                     amount: DEFAULT_AMOUNT,
                     t1_recipient,
-                    lower_id: 0
+                    lower_id: 0,
+                    memo: None,
+                    sequence: 1,
                 })));
         });
     }
@@ -736,7 +742,9 @@ mod signed_lower {
                         recipient: recipient_account_id,
                         amount: DEFAULT_AMOUNT,
                         t1_recipient,
-                        lower_id: 0
+                        lower_id: 0,
+                        memo: None,
+                        sequence: 1,
                     })));
             });
         }
@@ -844,7 +852,9 @@ mod fees {
                         recipient: recipient_account_id,
                         amount: DEFAULT_AMOUNT,
                         t1_recipient,
-                        lower_id: 0
+                        lower_id: 0,
+                        memo: None,
+                        sequence: 1,
                     })));
 
                 let fee: u128 = (BASE_FEE + TX_LEN as u64) as u128;
@@ -920,7 +930,9 @@ mod fees {
                         recipient: recipient_account_id,
                         amount: DEFAULT_AMOUNT,
                         t1_recipient,
-                        lower_id: 0
+                        lower_id: 0,
+                        memo: None,
+                        sequence: 1,
                     })));
             });
         }
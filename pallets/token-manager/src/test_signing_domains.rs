@@ -0,0 +1,157 @@
+// Copyright 2024 Aventus Network Services (UK) Ltd.
+
+#![cfg(test)]
+
+use crate::{mock::*, Balances as TokenManagerBalances, *};
+use codec::Encode;
+use frame_support::{assert_noop, assert_ok};
+use sp_core::{sr25519, Pair};
+
+const AMOUNT: u128 = 1_000;
+
+fn sender_key_pair() -> sr25519::Pair {
+    sr25519::Pair::from_seed(&[70u8; 32])
+}
+
+fn sender() -> AccountId {
+    get_account_id(&sender_key_pair())
+}
+
+fn recipient() -> AccountId {
+    AccountId::from_raw([1; 32])
+}
+
+fn relayer() -> AccountId {
+    AccountId::from_raw([10; 32])
+}
+
+fn t1_recipient() -> H160 {
+    H160::from([2; 20])
+}
+
+fn transfer_payload(domain: Option<&[u8]>) -> Vec<u8> {
+    let params = (
+        SIGNED_TRANSFER_CONTEXT,
+        relayer(),
+        sender(),
+        recipient(),
+        NON_AVT_TOKEN_ID,
+        AMOUNT,
+        TokenManager::nonce(sender()),
+    );
+    match domain {
+        Some(domain) => (domain, params).encode(),
+        None => params.encode(),
+    }
+}
+
+fn signed_transfer(signed_payload: &[u8]) -> DispatchResult {
+    TokenManager::signed_transfer(
+        RuntimeOrigin::signed(sender()),
+        Proof {
+            signer: sender(),
+            relayer: relayer(),
+            signature: sign(&sender_key_pair(), signed_payload),
+        },
+        sender(),
+        recipient(),
+        NON_AVT_TOKEN_ID,
+        AMOUNT,
+    )
+}
+
+fn reject_legacy_signed_payloads() {
+    assert_ok!(pallet_avn::Pallet::<TestRuntime>::set_legacy_signed_payloads_rejected(
+        RuntimeOrigin::root(),
+        true,
+    ));
+}
+
+fn setup() {
+    TokenManagerBalances::<TestRuntime>::insert((NON_AVT_TOKEN_ID, sender()), 10 * AMOUNT);
+}
+
+#[test]
+fn payloads_signed_with_the_pallet_domain_are_accepted() {
+    let mut ext = ExtBuilder::build_default().as_externality();
+    ext.execute_with(|| {
+        setup();
+        reject_legacy_signed_payloads();
+
+        assert_ok!(signed_transfer(&transfer_payload(Some(SIGNING_DOMAIN))));
+        assert_eq!(
+            TokenManagerBalances::<TestRuntime>::get((NON_AVT_TOKEN_ID, recipient())),
+            AMOUNT
+        );
+    });
+}
+
+#[test]
+fn legacy_payloads_are_accepted_until_they_are_rejected() {
+    let mut ext = ExtBuilder::build_default().as_externality();
+    ext.execute_with(|| {
+        setup();
+        assert_ok!(signed_transfer(&transfer_payload(None)));
+
+        reject_legacy_signed_payloads();
+
+        assert_noop!(
+            signed_transfer(&transfer_payload(None)),
+            Error::<TestRuntime>::UnauthorizedSignedTransferTransaction
+        );
+    });
+}
+
+#[test]
+fn payloads_signed_for_another_pallet_are_rejected() {
+    let mut ext = ExtBuilder::build_default().as_externality();
+    ext.execute_with(|| {
+        setup();
+        let nft_manager_domain: &[u8] = b"nft_manager";
+
+        assert_noop!(
+            signed_transfer(&transfer_payload(Some(nft_manager_domain))),
+            Error::<TestRuntime>::UnauthorizedSignedTransferTransaction
+        );
+    });
+}
+
+#[test]
+fn a_transfer_payload_does_not_validate_a_lower() {
+    let mut ext = ExtBuilder::build_default().as_externality();
+    ext.execute_with(|| {
+        setup();
+        let proof = Proof {
+            signer: sender(),
+            relayer: relayer(),
+            signature: sign(&sender_key_pair(), &transfer_payload(Some(SIGNING_DOMAIN))),
+        };
+
+        assert_noop!(
+            TokenManager::schedule_signed_lower(
+                RuntimeOrigin::signed(sender()),
+                proof,
+                sender(),
+                NON_AVT_TOKEN_ID,
+                AMOUNT,
+                t1_recipient(),
+            ),
+            Error::<TestRuntime>::UnauthorizedSignedLowerTransaction
+        );
+    });
+}
+
+#[test]
+fn only_root_can_reject_legacy_payloads() {
+    let mut ext = ExtBuilder::build_default().as_externality();
+    ext.execute_with(|| {
+        assert_noop!(
+            pallet_avn::Pallet::<TestRuntime>::set_legacy_signed_payloads_rejected(
+                RuntimeOrigin::signed(sender()),
+                true,
+            ),
+            sp_runtime::DispatchError::BadOrigin
+        );
+        assert!(pallet_avn::Pallet::<TestRuntime>::legacy_signed_payloads_accepted());
+    });
+}
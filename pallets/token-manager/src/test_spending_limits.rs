@@ -0,0 +1,218 @@
+// Copyright 2024 Aventus Network Services (UK) Ltd.
+
+#![cfg(test)]
+
+use crate::{
+    mock::{RuntimeEvent, *},
+    Balances as TokenManagerBalances, *,
+};
+use frame_support::{assert_noop, assert_ok};
+use sp_core::{sr25519, Pair};
+
+const LIMIT: u128 = 1_000;
+const PERIOD: u64 = 10;
+
+fn sender_key_pair() -> sr25519::Pair {
+    sr25519::Pair::from_seed(&[70u8; 32])
+}
+
+fn sender() -> AccountId {
+    get_account_id(&sender_key_pair())
+}
+
+fn recipient() -> AccountId {
+    AccountId::from_raw([1; 32])
+}
+
+fn relayer() -> AccountId {
+    AccountId::from_raw([10; 32])
+}
+
+fn set_limit() {
+    assert_ok!(TokenManager::set_spending_limit(
+        RuntimeOrigin::signed(sender()),
+        NON_AVT_TOKEN_ID,
+        Some(SpendingLimit { amount: LIMIT, period: PERIOD }),
+    ));
+}
+
+fn signed_transfer(amount: u128) -> DispatchResult {
+    let nonce = TokenManager::nonce(sender());
+    let signature = create_valid_signature_for_signed_transfer(
+        &relayer(),
+        &sender(),
+        &recipient(),
+        NON_AVT_TOKEN_ID,
+        amount,
+        nonce,
+        &sender_key_pair(),
+    );
+    TokenManager::signed_transfer(
+        RuntimeOrigin::signed(sender()),
+        Proof { signer: sender(), relayer: relayer(), signature },
+        sender(),
+        recipient(),
+        NON_AVT_TOKEN_ID,
+        amount,
+    )
+}
+
+fn recipient_balance() -> u128 {
+    TokenManagerBalances::<TestRuntime>::get((NON_AVT_TOKEN_ID, recipient()))
+}
+
+fn event_emitted(event: crate::Event<TestRuntime>) -> bool {
+    System::events()
+        .iter()
+        .any(|a| a.event == RuntimeEvent::TokenManager(event.clone()))
+}
+
+fn setup() {
+    TokenManagerBalances::<TestRuntime>::insert((NON_AVT_TOKEN_ID, sender()), 10 * LIMIT);
+}
+
+mod set_spending_limit {
+    use super::*;
+
+    #[test]
+    fn sets_and_removes_the_limit_of_a_token() {
+        let mut ext = ExtBuilder::build_default().as_externality();
+        ext.execute_with(|| {
+            setup();
+            set_limit();
+            assert_ok!(signed_transfer(LIMIT / 2));
+
+            assert_ok!(TokenManager::set_spending_limit(
+                RuntimeOrigin::signed(sender()),
+                NON_AVT_TOKEN_ID,
+                None,
+            ));
+
+            assert_eq!(TokenManager::spending_limit(sender(), NON_AVT_TOKEN_ID), None);
+            assert_eq!(TokenManager::spending_window(sender(), NON_AVT_TOKEN_ID), None);
+            assert!(event_emitted(crate::Event::<TestRuntime>::SpendingLimitSet {
+                account: sender(),
+                token_id: NON_AVT_TOKEN_ID,
+                limit: None,
+            }));
+        });
+    }
+
+    #[test]
+    fn fails_when_the_period_is_zero() {
+        let mut ext = ExtBuilder::build_default().as_externality();
+        ext.execute_with(|| {
+            assert_noop!(
+                TokenManager::set_spending_limit(
+                    RuntimeOrigin::signed(sender()),
+                    NON_AVT_TOKEN_ID,
+                    Some(SpendingLimit { amount: LIMIT, period: 0 }),
+                ),
+                Error::<TestRuntime>::InvalidSpendingLimit
+            );
+        });
+    }
+}
+
+mod signed_transfer {
+    use super::*;
+
+    #[test]
+    fn is_executed_within_the_limit() {
+        let mut ext = ExtBuilder::build_default().as_externality();
+        ext.execute_with(|| {
+            setup();
+            set_limit();
+
+            assert_ok!(signed_transfer(LIMIT / 2));
+            assert_ok!(signed_transfer(LIMIT / 2));
+
+            assert_eq!(recipient_balance(), LIMIT);
+            assert_eq!(
+                TokenManager::spending_window(sender(), NON_AVT_TOKEN_ID),
+                Some(SpendingWindow {
+                    start: System::block_number(),
+                    spent: LIMIT,
+                    previous_spent: 0
+                })
+            );
+        });
+    }
+
+    #[test]
+    fn is_rejected_over_the_limit() {
+        let mut ext = ExtBuilder::build_default().as_externality();
+        ext.execute_with(|| {
+            setup();
+            set_limit();
+            assert_ok!(signed_transfer(LIMIT / 2));
+
+            assert_ok!(signed_transfer(LIMIT));
+
+            assert_eq!(recipient_balance(), LIMIT / 2);
+            // The rejected authorisation can't be replayed
+            assert_eq!(TokenManager::nonce(sender()), 2);
+            assert!(event_emitted(crate::Event::<TestRuntime>::SpendingLimitExceeded {
+                account: sender(),
+                token_id: NON_AVT_TOKEN_ID,
+                amount: LIMIT,
+                remaining: LIMIT / 2,
+            }));
+        });
+    }
+
+    #[test]
+    fn frees_the_limit_as_the_window_slides() {
+        let mut ext = ExtBuilder::build_default().as_externality();
+        ext.execute_with(|| {
+            setup();
+            set_limit();
+            assert_ok!(signed_transfer(LIMIT));
+
+            // Half of the previous window is still within the period
+            System::set_block_number(System::block_number() + PERIOD + PERIOD / 2);
+            assert_ok!(signed_transfer(LIMIT / 2));
+            assert_ok!(signed_transfer(1));
+
+            assert_eq!(recipient_balance(), LIMIT + LIMIT / 2);
+            assert!(event_emitted(crate::Event::<TestRuntime>::SpendingLimitExceeded {
+                account: sender(),
+                token_id: NON_AVT_TOKEN_ID,
+                amount: 1,
+                remaining: 0,
+            }));
+        });
+    }
+
+    #[test]
+    fn is_limited_again_once_a_whole_period_has_passed() {
+        let mut ext = ExtBuilder::build_default().as_externality();
+        ext.execute_with(|| {
+            setup();
+            set_limit();
+            assert_ok!(signed_transfer(LIMIT));
+
+            System::set_block_number(System::block_number() + 2 * PERIOD);
+            assert_ok!(signed_transfer(LIMIT));
+
+            assert_eq!(recipient_balance(), 2 * LIMIT);
+        });
+    }
+
+    #[test]
+    fn is_not_limited_for_other_tokens() {
+        let mut ext = ExtBuilder::build_default().as_externality();
+        ext.execute_with(|| {
+            setup();
+            assert_ok!(TokenManager::set_spending_limit(
+                RuntimeOrigin::signed(sender()),
+                NON_AVT_TOKEN_ID_2,
+                Some(SpendingLimit { amount: 0, period: PERIOD }),
+            ));
+
+            assert_ok!(signed_transfer(LIMIT));
+
+            assert_eq!(recipient_balance(), LIMIT);
+        });
+    }
+}
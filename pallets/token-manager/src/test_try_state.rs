@@ -0,0 +1,40 @@
+#![cfg(test)]
+use crate::{mock::*, *};
+use frame_support::{assert_err, assert_ok};
+
+const LOWER_PARAMS: LowerParams = [1u8; PACKED_LOWER_PARAM_SIZE];
+
+#[test]
+fn try_state_passes_for_issued_lowers() {
+    let mut ext = ExtBuilder::build_default().with_genesis_config().as_externality();
+    ext.execute_with(|| {
+        <LowerNonce<TestRuntime>>::put(2);
+        <LowersPendingProof<TestRuntime>>::insert(0, LOWER_PARAMS);
+        <FailedLowerProofs<TestRuntime>>::insert(1, LOWER_PARAMS);
+
+        assert_ok!(TokenManager::do_try_state());
+    });
+}
+
+#[test]
+fn try_state_fails_when_a_lower_id_was_not_issued() {
+    let mut ext = ExtBuilder::build_default().with_genesis_config().as_externality();
+    ext.execute_with(|| {
+        <LowerNonce<TestRuntime>>::put(1);
+        <LowersPendingProof<TestRuntime>>::insert(1, LOWER_PARAMS);
+
+        assert_err!(TokenManager::do_try_state(), "Pending lower has an id that was not issued");
+    });
+}
+
+#[test]
+fn try_state_fails_when_a_lower_is_pending_and_failed() {
+    let mut ext = ExtBuilder::build_default().with_genesis_config().as_externality();
+    ext.execute_with(|| {
+        <LowerNonce<TestRuntime>>::put(1);
+        <LowersPendingProof<TestRuntime>>::insert(0, LOWER_PARAMS);
+        <FailedLowerProofs<TestRuntime>>::insert(0, LOWER_PARAMS);
+
+        assert_err!(TokenManager::do_try_state(), "Pending lower is also ready to claim or failed");
+    });
+}
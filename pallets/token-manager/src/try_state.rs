@@ -0,0 +1,41 @@
+//! Invariants of the token manager state, checked by try-runtime before and after runtime upgrades
+
+use crate::{
+    Config, FailedLowerProofs, LowerNonce, LowersPendingProof, LowersReadyToClaim, Pallet,
+};
+use frame_support::ensure;
+use sp_runtime::DispatchError;
+
+impl<T: Config> Pallet<T> {
+    pub(crate) fn do_try_state() -> Result<(), DispatchError> {
+        Self::check_lower_queues()
+    }
+
+    /// Every lower must have been issued an id and be in at most one stage of proof generation
+    fn check_lower_queues() -> Result<(), DispatchError> {
+        let next_lower_id = <LowerNonce<T>>::get();
+
+        for lower_id in <LowersPendingProof<T>>::iter_keys() {
+            ensure!(lower_id < next_lower_id, "Pending lower has an id that was not issued");
+            ensure!(
+                !<LowersReadyToClaim<T>>::contains_key(lower_id) &&
+                    !<FailedLowerProofs<T>>::contains_key(lower_id),
+                "Pending lower is also ready to claim or failed"
+            );
+        }
+
+        for lower_id in <LowersReadyToClaim<T>>::iter_keys() {
+            ensure!(lower_id < next_lower_id, "Claimable lower has an id that was not issued");
+            ensure!(
+                !<FailedLowerProofs<T>>::contains_key(lower_id),
+                "Claimable lower is also failed"
+            );
+        }
+
+        for lower_id in <FailedLowerProofs<T>>::iter_keys() {
+            ensure!(lower_id < next_lower_id, "Failed lower has an id that was not issued");
+        }
+
+        Ok(())
+    }
+}
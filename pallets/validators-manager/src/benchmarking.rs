@@ -235,6 +235,16 @@ benchmarks! {
         assert_last_event::<T>(Event::<T>::ValidatorDeregistered{ validator_id: caller_account.clone() }.into());
         assert_eq!(true, ValidatorActions::<T>::contains_key(caller_account, <TotalIngresses<T>>::get()));
     }
+
+    cancel_unresponsive_validator_removal {
+        let (validator_account, _, _) = generate_sender_collator_account_details::<T>();
+        ScheduledUnresponsiveRemovals::<T>::insert(&validator_account, 10u32);
+        MissedHeartbeats::<T>::insert(&validator_account, (5u32, 1u32));
+    }: _(RawOrigin::Root, validator_account.clone())
+    verify {
+        assert_eq!(false, ScheduledUnresponsiveRemovals::<T>::contains_key(&validator_account));
+        assert_last_event::<T>(Event::<T>::ValidatorRemovalCancelled{ validator_id: validator_account }.into());
+    }
 }
 
 impl_benchmark_test_suite!(
@@ -39,6 +39,7 @@ use core::marker::PhantomData;
 pub trait WeightInfo {
 	fn add_collator() -> Weight;
 	fn remove_validator(v: u32, ) -> Weight;
+	fn cancel_unresponsive_validator_removal() -> Weight;
 }
 
 /// Weights for pallet_validators_manager using the Substrate node and recommended hardware.
@@ -131,6 +132,19 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().writes(7_u64))
 			.saturating_add(Weight::from_parts(0, 1839).saturating_mul(v.into()))
 	}
+	/// Storage: `ValidatorsManager::ScheduledUnresponsiveRemovals` (r:1 w:1)
+	/// Proof: `ValidatorsManager::ScheduledUnresponsiveRemovals` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `ValidatorsManager::MissedHeartbeats` (r:0 w:1)
+	/// Proof: `ValidatorsManager::MissedHeartbeats` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	fn cancel_unresponsive_validator_removal() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `351`
+		//  Estimated: `3517`
+		// Minimum execution time: 14_215_000 picoseconds.
+		Weight::from_parts(14_215_000, 3517)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
 }
 
 // For backwards compatibility and tests.
@@ -222,4 +236,17 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().writes(7_u64))
 			.saturating_add(Weight::from_parts(0, 1839).saturating_mul(v.into()))
 	}
+	/// Storage: `ValidatorsManager::ScheduledUnresponsiveRemovals` (r:1 w:1)
+	/// Proof: `ValidatorsManager::ScheduledUnresponsiveRemovals` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `ValidatorsManager::MissedHeartbeats` (r:0 w:1)
+	/// Proof: `ValidatorsManager::MissedHeartbeats` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	fn cancel_unresponsive_validator_removal() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `351`
+		//  Estimated: `3517`
+		// Minimum execution time: 14_215_000 picoseconds.
+		Weight::from_parts(14_215_000, 3517)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
 }
\ No newline at end of file
@@ -15,10 +15,11 @@ use alloc::string::String;
 pub type EthereumTransactionId = u32;
 
 use frame_support::{
-    dispatch::DispatchResult, ensure, pallet_prelude::StorageVersion, traits::Get, transactional,
+    dispatch::DispatchResult, ensure, pallet_prelude::StorageVersion, storage::with_storage_layer,
+    traits::Get, transactional,
 };
 use frame_system::{offchain::SendTransactionTypes, RawOrigin};
-use pallet_session::{self as session, Config as SessionConfig};
+use pallet_session::{self as session, historical::IdentificationTuple, Config as SessionConfig};
 use sp_runtime::{
     scale_info::TypeInfo,
     traits::{Convert, Member},
@@ -30,7 +31,8 @@ use codec::{Decode, Encode, MaxEncodedLen};
 use pallet_avn::{
     self as avn, AccountToBytesConverter, BridgeInterfaceNotification, DisabledValidatorChecker,
     Enforcer, EthereumPublicKeyChecker, NewSessionHandler, ProcessedEventsChecker,
-    ValidatorRegistrationNotifier, MAX_VALIDATOR_ACCOUNTS,
+    ValidatorRegistrationNotifier, ValidatorSetCheckpointId, ValidatorSetCheckpointProvider,
+    MAX_VALIDATOR_ACCOUNTS,
 };
 
 use sp_avn_common::{
@@ -38,6 +40,10 @@ use sp_avn_common::{
     event_types::Validator, BridgeContractMethod, IngressCounter,
 };
 use sp_core::{bounded::BoundedVec, ecdsa, H512};
+use sp_staking::{
+    offence::{Offence, OffenceError, ReportOffence},
+    SessionIndex,
+};
 
 pub use pallet_parachain_staking::{self as parachain_staking, BalanceOf, PositiveImbalanceOf};
 
@@ -45,6 +51,9 @@ use pallet_avn::BridgeInterface;
 
 pub use pallet::*;
 
+mod validator_set_checkpoint;
+pub use validator_set_checkpoint::{CheckpointStatus, ValidatorSetCheckpoint};
+
 const PALLET_ID: &'static [u8; 14] = b"author_manager";
 
 #[frame_support::pallet]
@@ -86,6 +95,22 @@ pub mod pallet {
         type WeightInfo: WeightInfo;
 
         type BridgeInterface: avn::BridgeInterface;
+
+        /// Number of consecutive sessions a validator can miss heartbeats for before its removal
+        /// is scheduled
+        type MaxConsecutiveMissedHeartbeats: Get<u32>;
+
+        /// Number of sessions between scheduling the removal of an unresponsive validator and
+        /// executing it. This gives governance time to cancel the removal.
+        type UnresponsiveValidatorRemovalDelay: Get<SessionIndex>;
+
+        /// Number of sessions after which the validator set checkpoint published on Ethereum is
+        /// refreshed, even if the validators have not changed
+        type CheckpointPeriod: Get<SessionIndex>;
+
+        /// Number of attempts made to publish a validator set checkpoint before waiting for the
+        /// next one
+        type MaxCheckpointPublishAttempts: Get<u32>;
     }
 
     #[pallet::error]
@@ -111,18 +136,63 @@ pub mod pallet {
         ValidatorEthKeyAlreadyExists,
         ErrorRemovingAccountFromCollators,
         MaximumValidatorsReached,
+        /// There is no scheduled removal for this validator
+        NoScheduledRemovalFound,
     }
 
     #[pallet::event]
     #[pallet::generate_deposit(pub(super) fn deposit_event)]
     pub enum Event<T: Config> {
-        ValidatorRegistered { validator_id: T::AccountId, eth_key: ecdsa::Public },
-        ValidatorDeregistered { validator_id: T::AccountId },
-        ValidatorActivationStarted { validator_id: T::AccountId },
-        ValidatorActionConfirmed { action_id: ActionId<T::AccountId> },
-        ValidatorSlashed { action_id: ActionId<T::AccountId> },
-        PublishingValidatorActionOnEthereumFailed { tx_id: u32 },
-        PublishingValidatorActionOnEthereumSucceeded { tx_id: u32 },
+        ValidatorRegistered {
+            validator_id: T::AccountId,
+            eth_key: ecdsa::Public,
+        },
+        ValidatorDeregistered {
+            validator_id: T::AccountId,
+        },
+        ValidatorActivationStarted {
+            validator_id: T::AccountId,
+        },
+        ValidatorActionConfirmed {
+            action_id: ActionId<T::AccountId>,
+        },
+        ValidatorSlashed {
+            action_id: ActionId<T::AccountId>,
+        },
+        PublishingValidatorActionOnEthereumFailed {
+            tx_id: u32,
+        },
+        PublishingValidatorActionOnEthereumSucceeded {
+            tx_id: u32,
+        },
+        ValidatorMissedHeartbeats {
+            validator_id: T::AccountId,
+            sessions: u32,
+            threshold: u32,
+        },
+        ValidatorRemovalScheduled {
+            validator_id: T::AccountId,
+            session: SessionIndex,
+        },
+        ValidatorRemovalCancelled {
+            validator_id: T::AccountId,
+        },
+        ValidatorRemovalFailed {
+            validator_id: T::AccountId,
+        },
+        ValidatorSetCheckpointRequested {
+            checkpoint_id: ValidatorSetCheckpointId,
+            session_index: SessionIndex,
+            tx_id: u32,
+        },
+        ValidatorSetCheckpointPublished {
+            checkpoint_id: ValidatorSetCheckpointId,
+            tx_id: u32,
+        },
+        ValidatorSetCheckpointFailed {
+            checkpoint_id: ValidatorSetCheckpointId,
+            attempts: u32,
+        },
     }
 
     #[pallet::storage]
@@ -151,6 +221,34 @@ pub mod pallet {
     #[pallet::getter(fn get_ingress_counter)]
     pub type TotalIngresses<T: Config> = StorageValue<_, IngressCounter, ValueQuery>;
 
+    /// The last session a validator was reported unresponsive for and the number of consecutive
+    /// sessions it has missed heartbeats for.
+    #[pallet::storage]
+    #[pallet::getter(fn missed_heartbeats)]
+    pub type MissedHeartbeats<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, (SessionIndex, u32), OptionQuery>;
+
+    /// Unresponsive validators that will be removed at the start of the given session
+    #[pallet::storage]
+    #[pallet::getter(fn scheduled_removal)]
+    pub type ScheduledUnresponsiveRemovals<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, SessionIndex, OptionQuery>;
+
+    #[pallet::storage]
+    pub type NextCheckpointId<T: Config> = StorageValue<_, ValidatorSetCheckpointId, ValueQuery>;
+
+    /// The most recent validator set checkpoint, whether or not Ethereum has accepted it
+    #[pallet::storage]
+    #[pallet::getter(fn latest_checkpoint)]
+    pub type LatestCheckpoint<T: Config> =
+        StorageValue<_, ValidatorSetCheckpoint<BalanceOf<T>>, OptionQuery>;
+
+    /// The most recent validator set checkpoint accepted by Ethereum, referenced by slash calls
+    #[pallet::storage]
+    #[pallet::getter(fn published_checkpoint)]
+    pub type PublishedCheckpoint<T: Config> =
+        StorageValue<_, ValidatorSetCheckpoint<BalanceOf<T>>, OptionQuery>;
+
     #[pallet::genesis_config]
     pub struct GenesisConfig<T: Config> {
         pub validators: Vec<(T::AccountId, ecdsa::Public)>,
@@ -290,6 +388,30 @@ pub mod pallet {
             )
             .into())
         }
+
+        /// Cancels the scheduled removal of a validator that has been flagged as unresponsive
+        /// and resets its missed heartbeats counter.
+        #[pallet::call_index(2)]
+        #[pallet::weight(<T as Config>::WeightInfo::cancel_unresponsive_validator_removal())]
+        pub fn cancel_unresponsive_validator_removal(
+            origin: OriginFor<T>,
+            validator_account_id: T::AccountId,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+            ensure!(
+                <ScheduledUnresponsiveRemovals<T>>::contains_key(&validator_account_id),
+                Error::<T>::NoScheduledRemovalFound
+            );
+
+            <ScheduledUnresponsiveRemovals<T>>::remove(&validator_account_id);
+            <MissedHeartbeats<T>>::remove(&validator_account_id);
+
+            Self::deposit_event(Event::<T>::ValidatorRemovalCancelled {
+                validator_id: validator_account_id,
+            });
+
+            Ok(())
+        }
     }
 }
 
@@ -361,6 +483,14 @@ mod tests;
 #[path = "tests/mock.rs"]
 mod mock;
 
+#[cfg(test)]
+#[path = "tests/unresponsive_validators_tests.rs"]
+mod unresponsive_validators_tests;
+
+#[cfg(test)]
+#[path = "tests/validator_set_checkpoint_tests.rs"]
+mod validator_set_checkpoint_tests;
+
 mod benchmarking;
 
 pub mod default_weights;
@@ -594,6 +724,105 @@ impl<T: Config> Pallet<T> {
         Ok(())
     }
 
+    fn note_unresponsive_validators(offenders: Vec<T::AccountId>, session_index: SessionIndex) {
+        let registered_validators = Self::validator_account_ids().unwrap_or_default();
+        let max_missed_sessions = T::MaxConsecutiveMissedHeartbeats::get();
+
+        for validator_id in offenders.into_iter().filter(|v| registered_validators.contains(v)) {
+            if <ScheduledUnresponsiveRemovals<T>>::contains_key(&validator_id) {
+                continue
+            }
+
+            let consecutive_sessions = match Self::missed_heartbeats(&validator_id) {
+                // This session has already been accounted for
+                Some((last_session, _)) if last_session == session_index => continue,
+                Some((last_session, missed_sessions))
+                    if last_session.saturating_add(1) == session_index =>
+                    missed_sessions.saturating_add(1),
+                _ => 1,
+            };
+
+            if consecutive_sessions >= max_missed_sessions {
+                Self::schedule_unresponsive_validator_removal(validator_id, session_index);
+            } else {
+                <MissedHeartbeats<T>>::insert(&validator_id, (session_index, consecutive_sessions));
+                Self::deposit_event(Event::<T>::ValidatorMissedHeartbeats {
+                    validator_id,
+                    sessions: consecutive_sessions,
+                    threshold: max_missed_sessions,
+                });
+            }
+        }
+    }
+
+    fn schedule_unresponsive_validator_removal(
+        validator_id: T::AccountId,
+        session_index: SessionIndex,
+    ) {
+        let removal_session =
+            session_index.saturating_add(T::UnresponsiveValidatorRemovalDelay::get());
+
+        <MissedHeartbeats<T>>::remove(&validator_id);
+        <ScheduledUnresponsiveRemovals<T>>::insert(&validator_id, removal_session);
+
+        if let Some(session_validator_id) =
+            <T as SessionConfig>::ValidatorIdOf::convert(validator_id.clone())
+        {
+            T::ValidatorRegistrationNotifier::on_unresponsive_validator_removal_scheduled(
+                &session_validator_id,
+            );
+        }
+
+        Self::deposit_event(Event::<T>::ValidatorRemovalScheduled {
+            validator_id,
+            session: removal_session,
+        });
+    }
+
+    fn process_unresponsive_validators(current_session: SessionIndex) {
+        // Missed heartbeats only count if they are consecutive, so drop the records of validators
+        // that were responsive during the previous session.
+        let responsive_validators = <MissedHeartbeats<T>>::iter()
+            .filter(|(_, (last_session, _))| last_session.saturating_add(1) < current_session)
+            .map(|(validator_id, _)| validator_id)
+            .collect::<Vec<_>>();
+        responsive_validators.iter().for_each(|v| <MissedHeartbeats<T>>::remove(v));
+
+        let validators_to_remove = <ScheduledUnresponsiveRemovals<T>>::iter()
+            .filter(|(_, removal_session)| *removal_session <= current_session)
+            .map(|(validator_id, _)| validator_id)
+            .collect::<Vec<_>>();
+
+        for validator_id in validators_to_remove {
+            <ScheduledUnresponsiveRemovals<T>>::remove(&validator_id);
+
+            if let Err(e) =
+                with_storage_layer(|| Self::remove_unresponsive_validator(&validator_id))
+            {
+                log::error!("💔 Error removing unresponsive validator {:?}: {:?}", validator_id, e);
+                Self::deposit_event(Event::<T>::ValidatorRemovalFailed { validator_id });
+            }
+        }
+    }
+
+    fn remove_unresponsive_validator(validator_id: &T::AccountId) -> DispatchResult {
+        let candidate_count = parachain_staking::Pallet::<T>::candidate_pool().0.len() as u32;
+        parachain_staking::Pallet::<T>::schedule_leave_candidates(
+            <T as frame_system::Config>::RuntimeOrigin::from(RawOrigin::Signed(
+                validator_id.clone(),
+            )),
+            candidate_count,
+        )
+        .map_err(|e| e.error)?;
+
+        Self::remove_deregistered_validator(validator_id)?;
+
+        Self::deposit_event(Event::<T>::ValidatorDeregistered {
+            validator_id: validator_id.clone(),
+        });
+        Ok(())
+    }
+
     fn clean_up_collator_data(action_account_id: T::AccountId, ingress_counter: IngressCounter) {
         if let Ok(()) = Self::clean_up_staking_data(action_account_id.clone()) {
             <ValidatorActions<T>>::mutate(
@@ -618,6 +847,10 @@ impl<T: Config> BridgeInterfaceNotification for Pallet<T> {
     fn process_result(tx_id: u32, caller_id: Vec<u8>, succeeded: bool) -> DispatchResult {
         // TODO: Update data structure to use tx_id as key
         if caller_id == PALLET_ID.to_vec() {
+            if Self::process_checkpoint_result(tx_id, succeeded) {
+                return Ok(())
+            }
+
             if succeeded {
                 log::info!(
                     "✅  Transaction with ID {} was successfully published to Ethereum.",
@@ -694,6 +927,37 @@ impl<T: Config> NewSessionHandler<T::AuthorityId, T::AccountId> for Pallet<T> {
                 }
             }
         }
+
+        let current_session = <session::Pallet<T>>::current_index();
+        Self::process_unresponsive_validators(current_session);
+        Self::refresh_validator_set_checkpoint(current_session, active_validators);
+    }
+}
+
+/// Wraps the offence reporter used by the heartbeat (im-online) pallet so validators reported as
+/// unresponsive are tracked by this pallet before the offence is forwarded to `R`.
+pub struct UnresponsivenessTracker<T, R>(sp_std::marker::PhantomData<(T, R)>);
+
+impl<T, R, O> ReportOffence<T::AccountId, IdentificationTuple<T>, O>
+    for UnresponsivenessTracker<T, R>
+where
+    T: Config + session::Config<ValidatorId = <T as frame_system::Config>::AccountId>,
+    R: ReportOffence<T::AccountId, IdentificationTuple<T>, O>,
+    O: Offence<IdentificationTuple<T>>,
+{
+    fn report_offence(reporters: Vec<T::AccountId>, offence: O) -> Result<(), OffenceError> {
+        let offenders = offence
+            .offenders()
+            .into_iter()
+            .map(|(validator_id, _)| validator_id)
+            .collect::<Vec<_>>();
+        Pallet::<T>::note_unresponsive_validators(offenders, offence.session_index());
+
+        R::report_offence(reporters, offence)
+    }
+
+    fn is_known_offence(offenders: &[IdentificationTuple<T>], time_slot: &O::TimeSlot) -> bool {
+        R::is_known_offence(offenders, time_slot)
     }
 }
 
@@ -733,8 +997,9 @@ impl<T: Config> DisabledValidatorChecker<T::AccountId> for Pallet<T> {
 impl<T: Config> Enforcer<<T as session::Config>::ValidatorId> for Pallet<T> {
     fn slash_validator(
         slashed_validator_id: &<T as session::Config>::ValidatorId,
+        checkpoint: Option<ValidatorSetCheckpointId>,
     ) -> DispatchResult {
-        log::error!("❌ Error: Incomplete Slashing Implementation. An attempt was made to slash validator {:?}, but the slashing implementation is currently incomplete. This code path should not have been reached.", slashed_validator_id);
+        log::error!("❌ Error: Incomplete Slashing Implementation. An attempt was made to slash validator {:?} (validator set checkpoint {:?}), but the slashing implementation is currently incomplete. This code path should not have been reached.", slashed_validator_id, checkpoint);
         Ok(())
     }
 }
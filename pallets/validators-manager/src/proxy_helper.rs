@@ -11,6 +11,8 @@ use sp_std::prelude::*;
 use super::*;
 use crate::Pallet as ValidatorsManager;
 
+/// Prefix of the payloads signed for the proxied staking operations
+pub const SIGNING_DOMAIN: &'static [u8] = b"validators_manager";
 pub const SIGNED_BOND_CONTEXT: &'static [u8] = b"authorization for bond operation";
 pub const SIGNED_NOMINATOR_CONTEXT: &'static [u8] = b"authorization for nominate operation";
 pub const SIGNED_REBOND_CONTEXT: &'static [u8] = b"authorization for rebond operation";
@@ -105,7 +107,15 @@ pub fn encode_signed_bond_params<T: Config>(
     payee: &RewardDestination<T::AccountId>,
     sender_nonce: u64,
 ) -> Vec<u8> {
-    return (SIGNED_BOND_CONTEXT, proof.relayer.clone(), controller, value, payee, sender_nonce)
+    return (
+        SIGNING_DOMAIN,
+        SIGNED_BOND_CONTEXT,
+        proof.relayer.clone(),
+        controller,
+        value,
+        payee,
+        sender_nonce,
+    )
         .encode();
 }
 
@@ -114,7 +124,8 @@ pub fn encode_signed_nominate_params<T: Config>(
     targets: &Vec<<T::Lookup as StaticLookup>::Source>,
     sender_nonce: u64,
 ) -> Vec<u8> {
-    return (SIGNED_NOMINATOR_CONTEXT, proof.relayer.clone(), targets, sender_nonce).encode();
+    return (SIGNING_DOMAIN, SIGNED_NOMINATOR_CONTEXT, proof.relayer.clone(), targets, sender_nonce)
+        .encode();
 }
 
 pub fn encode_signed_rebond_params<T: Config>(
@@ -122,7 +133,8 @@ pub fn encode_signed_rebond_params<T: Config>(
     value: &BalanceOf<T>,
     sender_nonce: u64,
 ) -> Vec<u8> {
-    return (SIGNED_REBOND_CONTEXT, proof.relayer.clone(), value, sender_nonce).encode();
+    return (SIGNING_DOMAIN, SIGNED_REBOND_CONTEXT, proof.relayer.clone(), value, sender_nonce)
+        .encode();
 }
 
 pub fn encode_signed_payout_stakers_params<T: Config>(
@@ -130,7 +142,14 @@ pub fn encode_signed_payout_stakers_params<T: Config>(
     era: &EraIndex,
     sender_nonce: u64,
 ) -> Vec<u8> {
-    return (SIGNED_PAYOUT_STAKERS_CONTEXT, proof.relayer.clone(), era, sender_nonce).encode();
+    return (
+        SIGNING_DOMAIN,
+        SIGNED_PAYOUT_STAKERS_CONTEXT,
+        proof.relayer.clone(),
+        era,
+        sender_nonce,
+    )
+        .encode();
 }
 
 pub fn encode_signed_set_controller_params<T: Config>(
@@ -138,7 +157,13 @@ pub fn encode_signed_set_controller_params<T: Config>(
     controller: &<T::Lookup as StaticLookup>::Source,
     sender_nonce: u64,
 ) -> Vec<u8> {
-    return (SIGNED_SET_CONTROLLER_CONTEXT, proof.relayer.clone(), controller, sender_nonce)
+    return (
+        SIGNING_DOMAIN,
+        SIGNED_SET_CONTROLLER_CONTEXT,
+        proof.relayer.clone(),
+        controller,
+        sender_nonce,
+    )
         .encode();
 }
 
@@ -147,7 +172,8 @@ pub fn encode_signed_set_payee_params<T: Config>(
     payee: &RewardDestination<T::AccountId>,
     sender_nonce: u64,
 ) -> Vec<u8> {
-    return (SIGNED_SET_PAYEE_CONTEXT, proof.relayer.clone(), payee, sender_nonce).encode();
+    return (SIGNING_DOMAIN, SIGNED_SET_PAYEE_CONTEXT, proof.relayer.clone(), payee, sender_nonce)
+        .encode();
 }
 
 pub fn encode_signed_withdraw_unbonded_params<T: Config>(
@@ -156,6 +182,7 @@ pub fn encode_signed_withdraw_unbonded_params<T: Config>(
     sender_nonce: u64,
 ) -> Vec<u8> {
     return (
+        SIGNING_DOMAIN,
         SIGNED_WITHDRAW_UNBONDED_CONTEXT,
         proof.relayer.clone(),
         num_slashing_spans,
@@ -169,7 +196,8 @@ pub fn encode_signed_unbond_params<T: Config>(
     value: &BalanceOf<T>,
     sender_nonce: u64,
 ) -> Vec<u8> {
-    return (SIGNED_UNBOND_CONTEXT, proof.relayer.clone(), value, sender_nonce).encode();
+    return (SIGNING_DOMAIN, SIGNED_UNBOND_CONTEXT, proof.relayer.clone(), value, sender_nonce)
+        .encode();
 }
 
 pub fn encode_signed_bond_extra_params<T: Config>(
@@ -177,6 +205,12 @@ pub fn encode_signed_bond_extra_params<T: Config>(
     max_additional: &BalanceOf<T>,
     sender_nonce: u64,
 ) -> Vec<u8> {
-    return (SIGNED_BOND_EXTRA_CONTEXT, proof.relayer.clone(), max_additional, sender_nonce)
+    return (
+        SIGNING_DOMAIN,
+        SIGNED_BOND_EXTRA_CONTEXT,
+        proof.relayer.clone(),
+        max_additional,
+        sender_nonce,
+    )
         .encode();
 }
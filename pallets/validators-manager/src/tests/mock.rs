@@ -28,7 +28,7 @@ use sp_core::{
         },
         OffchainDbExt, OffchainWorkerExt, TransactionPoolExt,
     },
-    sr25519, ByteArray, ConstU64, Pair, H256,
+    sr25519, ByteArray, ConstU128, ConstU32, ConstU64, Pair, H256,
 };
 use sp_runtime::{
     testing::{Header, TestXt, UintAuthorityId},
@@ -118,13 +118,41 @@ frame_support::construct_runtime!(
         ValidatorManager: validators_manager::{Pallet, Call, Storage, Event<T>, Config<T>},
         Session: pallet_session::{Pallet, Call, Storage, Event, Config<T>},
         Balances: pallet_balances::{Pallet, Call, Storage, Config<T>, Event<T>},
-        AVN: pallet_avn::{Pallet, Storage, Event},
+        AVN: pallet_avn::{Pallet, Storage, Event<T>},
         ParachainStaking: parachain_staking::{Pallet, Call, Storage, Config<T>, Event<T>},
         EthBridge: pallet_eth_bridge::{Pallet, Call, Storage, Event<T>},
         Timestamp: pallet_timestamp::{Pallet, Call, Storage, Inherent},
+        Preimage: pallet_preimage,
+        Scheduler: pallet_scheduler::{Pallet, Call, Storage, Event<T>},
     }
 );
 
+parameter_types! {
+    pub MaximumSchedulerWeight: frame_support::weights::Weight = frame_support::weights::Weight::MAX;
+}
+
+impl pallet_preimage::Config for TestRuntime {
+    type RuntimeEvent = RuntimeEvent;
+    type WeightInfo = ();
+    type Currency = Balances;
+    type ManagerOrigin = frame_system::EnsureRoot<AccountId>;
+    type BaseDeposit = ConstU128<0>;
+    type ByteDeposit = ConstU128<0>;
+}
+
+impl pallet_scheduler::Config for TestRuntime {
+    type RuntimeEvent = RuntimeEvent;
+    type RuntimeOrigin = RuntimeOrigin;
+    type PalletsOrigin = OriginCaller;
+    type RuntimeCall = RuntimeCall;
+    type MaximumWeight = MaximumSchedulerWeight;
+    type ScheduleOrigin = frame_system::EnsureRoot<AccountId>;
+    type MaxScheduledPerBlock = ConstU32<100>;
+    type WeightInfo = ();
+    type OriginPrivilegeCmp = frame_support::traits::EqualPrivilegeOnly;
+    type Preimages = Preimage;
+}
+
 use frame_system as system;
 use pallet_session as session;
 
@@ -152,6 +180,10 @@ impl ValidatorManager {
 
 parameter_types! {
     pub const VotingPeriod: u64 = 2;
+    pub const MaxConsecutiveMissedHeartbeats: u32 = 3;
+    pub const UnresponsiveValidatorRemovalDelay: u32 = 2;
+    pub const CheckpointPeriod: u32 = 5;
+    pub const MaxCheckpointPublishAttempts: u32 = 2;
 }
 
 impl Config for TestRuntime {
@@ -162,6 +194,10 @@ impl Config for TestRuntime {
     type ValidatorRegistrationNotifier = Self;
     type WeightInfo = ();
     type BridgeInterface = EthBridge;
+    type MaxConsecutiveMissedHeartbeats = MaxConsecutiveMissedHeartbeats;
+    type UnresponsiveValidatorRemovalDelay = UnresponsiveValidatorRemovalDelay;
+    type CheckpointPeriod = CheckpointPeriod;
+    type MaxCheckpointPublishAttempts = MaxCheckpointPublishAttempts;
 }
 
 impl<LocalCall> system::offchain::SendTransactionTypes<LocalCall> for TestRuntime
@@ -254,6 +290,7 @@ impl pallet_eth_bridge::Config for TestRuntime {
     type ReportCorroborationOffence = ();
     type ProcessedEventsChecker = ();
     type EthereumEventsFilter = ();
+    type AdminOrigin = frame_system::EnsureRoot<Self::AccountId>;
 }
 
 impl BridgeInterfaceNotification for TestRuntime {
@@ -298,6 +335,7 @@ parameter_types! {
     pub const MinNominationPerCollator: u128 = 3;
     pub const ErasPerGrowthPeriod: u32 = 2;
     pub const RewardPaymentDelay: u32 = 2;
+    pub const HistoryDepth: u32 = 3;
     pub const RewardPotId: PalletId = PalletId(*b"av/vamgr");
     pub const MaxCandidates: u32 = 256;
     pub const GrowthEnabled: bool = true;
@@ -309,6 +347,7 @@ impl parachain_staking::Config for TestRuntime {
     type Currency = Balances;
     type MinBlocksPerEra = MinBlocksPerEra;
     type RewardPaymentDelay = RewardPaymentDelay;
+    type HistoryDepth = HistoryDepth;
     type MinSelectedCandidates = MinSelectedCandidates;
     type MaxTopNominationsPerCandidate = MaxTopNominationsPerCandidate;
     type MaxBottomNominationsPerCandidate = MaxBottomNominationsPerCandidate;
@@ -320,12 +359,21 @@ impl parachain_staking::Config for TestRuntime {
     type Signature = Signature;
     type CollatorSessionRegistration = Session;
     type CollatorPayoutDustHandler = ();
+    type DisabledOffenderCheck = ();
     type ProcessedEventsChecker = ();
     type WeightInfo = ();
     type MaxCandidates = MaxCandidates;
     type AccountToBytesConvert = AVN;
     type BridgeInterface = EthBridge;
     type GrowthEnabled = GrowthEnabled;
+    type AdminOrigin = frame_system::EnsureRoot<Self::AccountId>;
+    type Scheduler = Scheduler;
+    type Preimages = Preimage;
+    type PalletsOrigin = OriginCaller;
+    type AdminChangeDelay = ConstU64<10>;
+    type IdentityProvider = ();
+    type RewardPotTopUp = ();
+    type MaxNominationRequestCleanupsPerBlock = ConstU32<10>;
 }
 
 /// An extrinsic type used for tests.
@@ -0,0 +1,258 @@
+//Copyright 2024 Aventus Network Services (UK) Ltd.
+
+#![cfg(test)]
+
+use crate::{mock::*, *};
+use frame_support::{assert_noop, assert_ok};
+use sp_runtime::{traits::BadOrigin, Perbill};
+use sp_staking::offence::Kind;
+
+type Offender = IdentificationTuple<TestRuntime>;
+
+struct TestUnresponsivenessOffence {
+    session_index: SessionIndex,
+    offenders: Vec<Offender>,
+}
+
+impl Offence<Offender> for TestUnresponsivenessOffence {
+    const ID: Kind = *b"test:unresponsiv";
+    type TimeSlot = SessionIndex;
+
+    fn offenders(&self) -> Vec<Offender> {
+        self.offenders.clone()
+    }
+
+    fn session_index(&self) -> SessionIndex {
+        self.session_index
+    }
+
+    fn validator_set_count(&self) -> u32 {
+        5
+    }
+
+    fn time_slot(&self) -> Self::TimeSlot {
+        self.session_index
+    }
+
+    fn slash_fraction(&self, _offenders_count: u32) -> Perbill {
+        Perbill::zero()
+    }
+}
+
+fn report_unresponsive(validators: Vec<AccountId>, session_index: SessionIndex) {
+    let offence = TestUnresponsivenessOffence {
+        session_index,
+        offenders: validators.into_iter().map(|v| (v, v)).collect(),
+    };
+    assert_ok!(UnresponsivenessTracker::<TestRuntime, ()>::report_offence(vec![], offence));
+}
+
+fn missed_heartbeats_event(validator_id: AccountId, consecutive_sessions: u32) -> RuntimeEvent {
+    RuntimeEvent::ValidatorManager(crate::Event::<TestRuntime>::ValidatorMissedHeartbeats {
+        validator_id,
+        sessions: consecutive_sessions,
+        threshold: MaxConsecutiveMissedHeartbeats::get(),
+    })
+}
+
+mod reporting_unresponsive_validators {
+    use super::*;
+
+    #[test]
+    fn records_missed_heartbeats_and_warns() {
+        let mut ext = ExtBuilder::build_default().with_validators().as_externality();
+        ext.execute_with(|| {
+            report_unresponsive(vec![validator_id_1()], 1);
+
+            assert_eq!(ValidatorManager::missed_heartbeats(validator_id_1()), Some((1, 1)));
+            assert!(ValidatorManager::event_emitted(&missed_heartbeats_event(validator_id_1(), 1)));
+            assert_eq!(ValidatorManager::scheduled_removal(validator_id_1()), None);
+        });
+    }
+
+    #[test]
+    fn escalates_warnings_for_consecutive_sessions() {
+        let mut ext = ExtBuilder::build_default().with_validators().as_externality();
+        ext.execute_with(|| {
+            report_unresponsive(vec![validator_id_1()], 1);
+            report_unresponsive(vec![validator_id_1()], 2);
+
+            assert_eq!(ValidatorManager::missed_heartbeats(validator_id_1()), Some((2, 2)));
+            assert!(ValidatorManager::event_emitted(&missed_heartbeats_event(validator_id_1(), 2)));
+        });
+    }
+
+    #[test]
+    fn ignores_duplicate_reports_for_the_same_session() {
+        let mut ext = ExtBuilder::build_default().with_validators().as_externality();
+        ext.execute_with(|| {
+            report_unresponsive(vec![validator_id_1()], 1);
+            report_unresponsive(vec![validator_id_1()], 1);
+
+            assert_eq!(ValidatorManager::missed_heartbeats(validator_id_1()), Some((1, 1)));
+        });
+    }
+
+    #[test]
+    fn resets_counter_when_misses_are_not_consecutive() {
+        let mut ext = ExtBuilder::build_default().with_validators().as_externality();
+        ext.execute_with(|| {
+            report_unresponsive(vec![validator_id_1()], 1);
+            report_unresponsive(vec![validator_id_1()], 2);
+            report_unresponsive(vec![validator_id_1()], 4);
+
+            assert_eq!(ValidatorManager::missed_heartbeats(validator_id_1()), Some((4, 1)));
+            assert_eq!(ValidatorManager::scheduled_removal(validator_id_1()), None);
+        });
+    }
+
+    #[test]
+    fn ignores_accounts_that_are_not_validators() {
+        let mut ext = ExtBuilder::build_default().with_validators().as_externality();
+        ext.execute_with(|| {
+            let non_validator = TestAccount::new([99u8; 32]).account_id();
+            report_unresponsive(vec![non_validator], 1);
+
+            assert_eq!(ValidatorManager::missed_heartbeats(non_validator), None);
+        });
+    }
+
+    #[test]
+    fn schedules_removal_when_threshold_is_reached() {
+        let mut ext = ExtBuilder::build_default().with_validators().as_externality();
+        ext.execute_with(|| {
+            for session in 1..=MaxConsecutiveMissedHeartbeats::get() {
+                report_unresponsive(vec![validator_id_1()], session);
+            }
+
+            let expected_removal_session =
+                MaxConsecutiveMissedHeartbeats::get() + UnresponsiveValidatorRemovalDelay::get();
+
+            assert_eq!(
+                ValidatorManager::scheduled_removal(validator_id_1()),
+                Some(expected_removal_session)
+            );
+            assert_eq!(ValidatorManager::missed_heartbeats(validator_id_1()), None);
+            assert!(ValidatorManager::event_emitted(&RuntimeEvent::ValidatorManager(
+                crate::Event::<TestRuntime>::ValidatorRemovalScheduled {
+                    validator_id: validator_id_1(),
+                    session: expected_removal_session,
+                }
+            )));
+        });
+    }
+}
+
+mod executing_scheduled_removals {
+    use super::*;
+
+    #[test]
+    fn removes_the_validator_when_the_removal_session_starts() {
+        let mut ext = ExtBuilder::build_default().with_validators().as_externality();
+        ext.execute_with(|| {
+            let removal_session = Session::current_index() + 1;
+            ScheduledUnresponsiveRemovals::<TestRuntime>::insert(validator_id_5(), removal_session);
+
+            advance_session();
+
+            assert_eq!(ValidatorManager::scheduled_removal(validator_id_5()), None);
+            assert!(!ValidatorManager::validator_account_ids()
+                .unwrap()
+                .contains(&validator_id_5()));
+            assert!(ValidatorManager::event_emitted(&RuntimeEvent::ValidatorManager(
+                crate::Event::<TestRuntime>::ValidatorDeregistered {
+                    validator_id: validator_id_5()
+                }
+            )));
+        });
+    }
+
+    #[test]
+    fn does_not_remove_the_validator_before_the_removal_session() {
+        let mut ext = ExtBuilder::build_default().with_validators().as_externality();
+        ext.execute_with(|| {
+            let removal_session = Session::current_index() + 2;
+            ScheduledUnresponsiveRemovals::<TestRuntime>::insert(validator_id_5(), removal_session);
+
+            advance_session();
+
+            assert_eq!(
+                ValidatorManager::scheduled_removal(validator_id_5()),
+                Some(removal_session)
+            );
+            assert!(ValidatorManager::validator_account_ids().unwrap().contains(&validator_id_5()));
+        });
+    }
+
+    #[test]
+    fn clears_missed_heartbeats_of_responsive_validators() {
+        let mut ext = ExtBuilder::build_default().with_validators().as_externality();
+        ext.execute_with(|| {
+            let current_session = Session::current_index();
+            MissedHeartbeats::<TestRuntime>::insert(validator_id_1(), (current_session, 1));
+
+            advance_session();
+            assert_eq!(
+                ValidatorManager::missed_heartbeats(validator_id_1()),
+                Some((current_session, 1))
+            );
+
+            advance_session();
+            assert_eq!(ValidatorManager::missed_heartbeats(validator_id_1()), None);
+        });
+    }
+}
+
+mod cancel_unresponsive_validator_removal {
+    use super::*;
+
+    #[test]
+    fn succeeds_when_called_by_root() {
+        let mut ext = ExtBuilder::build_default().with_validators().as_externality();
+        ext.execute_with(|| {
+            ScheduledUnresponsiveRemovals::<TestRuntime>::insert(validator_id_1(), 10);
+
+            assert_ok!(ValidatorManager::cancel_unresponsive_validator_removal(
+                RawOrigin::Root.into(),
+                validator_id_1()
+            ));
+
+            assert_eq!(ValidatorManager::scheduled_removal(validator_id_1()), None);
+            assert!(ValidatorManager::event_emitted(&RuntimeEvent::ValidatorManager(
+                crate::Event::<TestRuntime>::ValidatorRemovalCancelled {
+                    validator_id: validator_id_1()
+                }
+            )));
+        });
+    }
+
+    #[test]
+    fn fails_when_origin_is_not_root() {
+        let mut ext = ExtBuilder::build_default().with_validators().as_externality();
+        ext.execute_with(|| {
+            ScheduledUnresponsiveRemovals::<TestRuntime>::insert(validator_id_1(), 10);
+
+            assert_noop!(
+                ValidatorManager::cancel_unresponsive_validator_removal(
+                    RawOrigin::Signed(validator_id_2()).into(),
+                    validator_id_1()
+                ),
+                BadOrigin
+            );
+        });
+    }
+
+    #[test]
+    fn fails_when_no_removal_is_scheduled() {
+        let mut ext = ExtBuilder::build_default().with_validators().as_externality();
+        ext.execute_with(|| {
+            assert_noop!(
+                ValidatorManager::cancel_unresponsive_validator_removal(
+                    RawOrigin::Root.into(),
+                    validator_id_1()
+                ),
+                Error::<TestRuntime>::NoScheduledRemovalFound
+            );
+        });
+    }
+}
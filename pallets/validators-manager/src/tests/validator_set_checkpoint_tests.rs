@@ -0,0 +1,190 @@
+//Copyright 2024 Aventus Network Services (UK) Ltd.
+
+#![cfg(test)]
+
+use crate::{mock::*, *};
+use frame_support::assert_ok;
+use sp_runtime::testing::UintAuthorityId;
+
+type ActiveValidators = Vec<Validator<UintAuthorityId, AccountId>>;
+
+fn active_validators() -> ActiveValidators {
+    AVN::<TestRuntime>::validators().to_vec()
+}
+
+fn refresh(session_index: SessionIndex, validators: &ActiveValidators) {
+    ValidatorManager::refresh_validator_set_checkpoint(session_index, validators);
+}
+
+fn latest_checkpoint() -> ValidatorSetCheckpoint<u128> {
+    ValidatorManager::latest_checkpoint().expect("checkpoint requested")
+}
+
+fn notify_result(succeeded: bool) {
+    assert_ok!(ValidatorManager::process_result(
+        latest_checkpoint().tx_id,
+        PALLET_ID.to_vec(),
+        succeeded
+    ));
+}
+
+fn checkpoint_event(event: crate::Event<TestRuntime>) -> bool {
+    ValidatorManager::event_emitted(&RuntimeEvent::ValidatorManager(event))
+}
+
+mod refresh_validator_set_checkpoint {
+    use super::*;
+
+    #[test]
+    fn publishes_a_checkpoint_of_the_active_validators() {
+        let mut ext = ExtBuilder::build_default().with_validators().as_externality();
+        ext.execute_with(|| {
+            refresh(1, &active_validators());
+
+            let checkpoint = latest_checkpoint();
+            assert_eq!(checkpoint.checkpoint_id, 0);
+            assert_eq!(checkpoint.session_index, 1);
+            assert_eq!(checkpoint.status, CheckpointStatus::Pending);
+            assert_eq!(checkpoint.attempts, 1);
+            assert!(checkpoint_event(crate::Event::ValidatorSetCheckpointRequested {
+                checkpoint_id: 0,
+                session_index: 1,
+                tx_id: checkpoint.tx_id,
+            }));
+        });
+    }
+
+    #[test]
+    fn waits_for_the_result_of_the_pending_checkpoint() {
+        let mut ext = ExtBuilder::build_default().with_validators().as_externality();
+        ext.execute_with(|| {
+            let validators = active_validators();
+            refresh(1, &validators);
+
+            refresh(1 + CheckpointPeriod::get(), &validators[1..].to_vec());
+
+            assert_eq!(latest_checkpoint().checkpoint_id, 0);
+            assert_eq!(NextCheckpointId::<TestRuntime>::get(), 1);
+        });
+    }
+
+    #[test]
+    fn is_referenced_once_ethereum_accepts_it() {
+        let mut ext = ExtBuilder::build_default().with_validators().as_externality();
+        ext.execute_with(|| {
+            refresh(1, &active_validators());
+            let tx_id = latest_checkpoint().tx_id;
+
+            notify_result(true);
+
+            assert_eq!(latest_checkpoint().status, CheckpointStatus::Published);
+            assert_eq!(ValidatorManager::published_checkpoint(), Some(latest_checkpoint()));
+            assert_eq!(ValidatorManager::latest_published_checkpoint(), Some(0));
+            assert!(checkpoint_event(crate::Event::ValidatorSetCheckpointPublished {
+                checkpoint_id: 0,
+                tx_id,
+            }));
+        });
+    }
+
+    #[test]
+    fn is_refreshed_when_the_validators_change() {
+        let mut ext = ExtBuilder::build_default().with_validators().as_externality();
+        ext.execute_with(|| {
+            let validators = active_validators();
+            refresh(1, &validators);
+            notify_result(true);
+
+            refresh(2, &validators);
+            assert_eq!(latest_checkpoint().checkpoint_id, 0);
+
+            refresh(2, &validators[1..].to_vec());
+            let checkpoint = latest_checkpoint();
+            assert_eq!(checkpoint.checkpoint_id, 1);
+            assert_eq!(checkpoint.status, CheckpointStatus::Pending);
+            assert_eq!(ValidatorManager::latest_published_checkpoint(), Some(0));
+        });
+    }
+
+    #[test]
+    fn is_refreshed_after_the_checkpoint_period() {
+        let mut ext = ExtBuilder::build_default().with_validators().as_externality();
+        ext.execute_with(|| {
+            let validators = active_validators();
+            refresh(1, &validators);
+            notify_result(true);
+
+            refresh(CheckpointPeriod::get(), &validators);
+            assert_eq!(latest_checkpoint().checkpoint_id, 0);
+
+            refresh(1 + CheckpointPeriod::get(), &validators);
+            assert_eq!(latest_checkpoint().checkpoint_id, 1);
+        });
+    }
+
+    #[test]
+    fn retries_a_checkpoint_that_failed_to_publish() {
+        let mut ext = ExtBuilder::build_default().with_validators().as_externality();
+        ext.execute_with(|| {
+            let validators = active_validators();
+            refresh(1, &validators);
+            let failed_tx_id = latest_checkpoint().tx_id;
+
+            notify_result(false);
+            assert_eq!(latest_checkpoint().status, CheckpointStatus::Failed);
+            assert!(checkpoint_event(crate::Event::ValidatorSetCheckpointFailed {
+                checkpoint_id: 0,
+                attempts: 1,
+            }));
+
+            refresh(2, &validators);
+
+            let checkpoint = latest_checkpoint();
+            assert_eq!(checkpoint.checkpoint_id, 0);
+            assert_eq!(checkpoint.status, CheckpointStatus::Pending);
+            assert_eq!(checkpoint.attempts, 2);
+            assert_ne!(checkpoint.tx_id, failed_tx_id);
+            assert_eq!(ValidatorManager::latest_published_checkpoint(), None);
+        });
+    }
+
+    #[test]
+    fn stops_retrying_after_the_maximum_attempts() {
+        let mut ext = ExtBuilder::build_default().with_validators().as_externality();
+        ext.execute_with(|| {
+            let validators = active_validators();
+            refresh(1, &validators);
+            for session in 2..=MaxCheckpointPublishAttempts::get() {
+                notify_result(false);
+                refresh(session, &validators);
+            }
+            notify_result(false);
+
+            refresh(1 + MaxCheckpointPublishAttempts::get(), &validators);
+
+            let checkpoint = latest_checkpoint();
+            assert_eq!(checkpoint.status, CheckpointStatus::Failed);
+            assert_eq!(checkpoint.attempts, MaxCheckpointPublishAttempts::get());
+        });
+    }
+}
+
+mod process_result {
+    use super::*;
+
+    #[test]
+    fn ignores_transactions_of_other_validator_actions() {
+        let mut ext = ExtBuilder::build_default().with_validators().as_externality();
+        ext.execute_with(|| {
+            refresh(1, &active_validators());
+            let other_tx_id = latest_checkpoint().tx_id + 1;
+
+            assert_ok!(ValidatorManager::process_result(other_tx_id, PALLET_ID.to_vec(), true));
+
+            assert_eq!(latest_checkpoint().status, CheckpointStatus::Pending);
+            assert!(checkpoint_event(crate::Event::PublishingValidatorActionOnEthereumSucceeded {
+                tx_id: other_tx_id
+            }));
+        });
+    }
+}
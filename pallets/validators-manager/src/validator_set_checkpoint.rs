@@ -0,0 +1,210 @@
+//! Checkpoints of the validator set and its stake, published on Ethereum.
+//!
+//! Ethereum verifies slashing proofs against the validator set it knows of, so a new checkpoint
+//! is published whenever the Ethereum public keys of the active validators change, and every
+//! `CheckpointPeriod` sessions to refresh their stake. A checkpoint that fails to publish is
+//! retried at the following sessions, up to `MaxCheckpointPublishAttempts` attempts.
+
+#[cfg(not(feature = "std"))]
+use alloc::format;
+
+use crate::*;
+use pallet_avn::ValidatorSetCheckpointId;
+use sp_core::H256;
+use sp_io::hashing::keccak_256;
+use sp_runtime::{
+    traits::{Saturating, Zero},
+    SaturatedConversion,
+};
+
+#[derive(Copy, Clone, Eq, PartialEq, Encode, Decode, Debug, TypeInfo, MaxEncodedLen)]
+pub enum CheckpointStatus {
+    /// The checkpoint has been sent to Ethereum and is waiting for the result
+    Pending,
+    /// Ethereum has accepted the checkpoint
+    Published,
+    /// The last attempt to publish the checkpoint failed
+    Failed,
+}
+
+#[derive(Clone, Eq, PartialEq, Encode, Decode, Debug, TypeInfo, MaxEncodedLen)]
+pub struct ValidatorSetCheckpoint<Balance> {
+    pub checkpoint_id: ValidatorSetCheckpointId,
+    /// The session the validator set was captured in
+    pub session_index: SessionIndex,
+    /// Keccak hash of the Ethereum public keys of the validators, in ascending order
+    pub authorities_hash: H256,
+    /// Keccak hash of the Ethereum public key and stake of each validator, in the same order
+    pub validator_set_hash: H256,
+    /// The stake backing all the validators
+    pub total_stake: Balance,
+    /// The Ethereum transaction of the last attempt to publish the checkpoint
+    pub tx_id: EthereumTransactionId,
+    pub status: CheckpointStatus,
+    pub attempts: u32,
+}
+
+impl<T: Config> Pallet<T> {
+    /// Publishes a checkpoint of `active_validators` if the last one is outdated, or retries the
+    /// last one if it failed to publish.
+    pub(crate) fn refresh_validator_set_checkpoint(
+        session_index: SessionIndex,
+        active_validators: &Vec<Validator<T::AuthorityId, T::AccountId>>,
+    ) {
+        let latest_checkpoint = Self::latest_checkpoint();
+        if latest_checkpoint.as_ref().map(|c| c.status) == Some(CheckpointStatus::Pending) {
+            // Wait for the result of the checkpoint in flight before sending another one
+            return
+        }
+
+        let (authorities_hash, validator_set_hash, total_stake) =
+            Self::validator_set_snapshot(active_validators);
+
+        let checkpoint = match latest_checkpoint {
+            Some(checkpoint)
+                if checkpoint.status == CheckpointStatus::Failed &&
+                    checkpoint.authorities_hash == authorities_hash &&
+                    checkpoint.attempts < T::MaxCheckpointPublishAttempts::get() =>
+                checkpoint,
+            Some(checkpoint)
+                if checkpoint.authorities_hash == authorities_hash &&
+                    session_index <
+                        checkpoint
+                            .session_index
+                            .saturating_add(T::CheckpointPeriod::get()) =>
+                return,
+            _ => ValidatorSetCheckpoint {
+                checkpoint_id: <NextCheckpointId<T>>::mutate(|next_id| {
+                    let checkpoint_id = *next_id;
+                    *next_id = next_id.saturating_add(1);
+                    checkpoint_id
+                }),
+                session_index,
+                authorities_hash,
+                validator_set_hash,
+                total_stake,
+                tx_id: Default::default(),
+                status: CheckpointStatus::Pending,
+                attempts: 0,
+            },
+        };
+
+        Self::publish_checkpoint(checkpoint);
+    }
+
+    fn publish_checkpoint(mut checkpoint: ValidatorSetCheckpoint<BalanceOf<T>>) {
+        checkpoint.attempts = checkpoint.attempts.saturating_add(1);
+
+        let function_name = BridgeContractMethod::PublishValidatorSetCheckpoint.as_bytes();
+        let params = vec![
+            (b"uint32".to_vec(), format!("{}", checkpoint.checkpoint_id).as_bytes().to_vec()),
+            (b"bytes32".to_vec(), checkpoint.validator_set_hash.as_fixed_bytes().to_vec()),
+            (
+                b"uint128".to_vec(),
+                format!("{}", checkpoint.total_stake.saturated_into::<u128>())
+                    .as_bytes()
+                    .to_vec(),
+            ),
+        ];
+
+        match <T as pallet::Config>::BridgeInterface::publish(
+            function_name,
+            &params,
+            PALLET_ID.to_vec(),
+        ) {
+            Ok(tx_id) => {
+                checkpoint.tx_id = tx_id;
+                checkpoint.status = CheckpointStatus::Pending;
+                Self::deposit_event(Event::<T>::ValidatorSetCheckpointRequested {
+                    checkpoint_id: checkpoint.checkpoint_id,
+                    session_index: checkpoint.session_index,
+                    tx_id,
+                });
+            },
+            Err(e) => {
+                log::error!(
+                    "💔 Error publishing validator set checkpoint {}: {:?}",
+                    checkpoint.checkpoint_id,
+                    e
+                );
+                checkpoint.status = CheckpointStatus::Failed;
+                Self::deposit_event(Event::<T>::ValidatorSetCheckpointFailed {
+                    checkpoint_id: checkpoint.checkpoint_id,
+                    attempts: checkpoint.attempts,
+                });
+            },
+        }
+
+        <LatestCheckpoint<T>>::put(checkpoint);
+    }
+
+    /// Records the result of publishing the latest checkpoint. Returns false if `tx_id` is not
+    /// the transaction of the latest checkpoint.
+    pub(crate) fn process_checkpoint_result(tx_id: EthereumTransactionId, succeeded: bool) -> bool {
+        let mut checkpoint = match Self::latest_checkpoint() {
+            Some(checkpoint)
+                if checkpoint.tx_id == tx_id && checkpoint.status == CheckpointStatus::Pending =>
+                checkpoint,
+            _ => return false,
+        };
+
+        if succeeded {
+            checkpoint.status = CheckpointStatus::Published;
+            <PublishedCheckpoint<T>>::put(&checkpoint);
+            Self::deposit_event(Event::<T>::ValidatorSetCheckpointPublished {
+                checkpoint_id: checkpoint.checkpoint_id,
+                tx_id,
+            });
+        } else {
+            checkpoint.status = CheckpointStatus::Failed;
+            Self::deposit_event(Event::<T>::ValidatorSetCheckpointFailed {
+                checkpoint_id: checkpoint.checkpoint_id,
+                attempts: checkpoint.attempts,
+            });
+        }
+
+        <LatestCheckpoint<T>>::put(checkpoint);
+        true
+    }
+
+    /// Returns the authorities hash, validator set hash and total stake of `active_validators`
+    fn validator_set_snapshot(
+        active_validators: &Vec<Validator<T::AuthorityId, T::AccountId>>,
+    ) -> (H256, H256, BalanceOf<T>) {
+        let mut authorities = <EthereumPublicKeys<T>>::iter()
+            .filter(|(_, account_id)| active_validators.iter().any(|v| &v.account_id == account_id))
+            .filter_map(|(eth_public_key, account_id)| {
+                decompress_eth_public_key(eth_public_key).ok().map(|key| (key, account_id))
+            })
+            .collect::<Vec<_>>();
+        authorities.sort_by(|(a, _), (b, _)| a.cmp(b));
+
+        let mut authorities_data = Vec::new();
+        let mut validator_set_data = Vec::new();
+        let mut total_stake = BalanceOf::<T>::zero();
+        for (eth_public_key, account_id) in authorities {
+            let stake = parachain_staking::Pallet::<T>::candidate_info(&account_id)
+                .map_or_else(Zero::zero, |candidate| candidate.total_counted);
+            total_stake = total_stake.saturating_add(stake);
+
+            let mut stake_bytes = [0u8; 32];
+            stake_bytes[16..].copy_from_slice(&stake.saturated_into::<u128>().to_be_bytes());
+
+            authorities_data.extend_from_slice(eth_public_key.as_bytes());
+            validator_set_data.extend_from_slice(eth_public_key.as_bytes());
+            validator_set_data.extend_from_slice(&stake_bytes);
+        }
+
+        (
+            H256::from(keccak_256(&authorities_data)),
+            H256::from(keccak_256(&validator_set_data)),
+            total_stake,
+        )
+    }
+}
+
+impl<T: Config> ValidatorSetCheckpointProvider for Pallet<T> {
+    fn latest_published_checkpoint() -> Option<ValidatorSetCheckpointId> {
+        Self::published_checkpoint().map(|checkpoint| checkpoint.checkpoint_id)
+    }
+}
@@ -158,9 +158,12 @@ pub mod events_helpers {
     pub struct EthereumEventsPartitionFactory {}
 
     impl EthereumEventsPartitionFactory {
+        /// Splits `events` into partitions of at most `max_events_per_partition` events, capped by
+        /// `IncomingEventsBatchLimit`
         pub fn create_partitions(
             range: EthBlockRange,
             events: Vec<DiscoveredEvent>,
+            max_events_per_partition: u32,
         ) -> Vec<EthereumEventsPartition> {
             let sorted_events = {
                 let mut mut_events = events.clone();
@@ -168,7 +171,9 @@ pub mod events_helpers {
                 mut_events
             };
 
-            let chunk_size: usize = <IncomingEventsBatchLimit as sp_core::Get<u32>>::get() as usize;
+            let chunk_size = max_events_per_partition
+                .min(<IncomingEventsBatchLimit as sp_core::Get<u32>>::get())
+                .max(1) as usize;
             let mut partitions = Vec::<EthereumEventsPartition>::new();
 
             let event_chunks: Vec<_> = sorted_events.chunks(chunk_size).collect();
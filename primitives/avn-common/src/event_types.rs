@@ -66,6 +66,42 @@ pub enum Error {
     LiftedToPredictionMarketEventBadDataLength,
     LiftedToPredictionMarketEventWrongTopicCount,
     LiftedToPredictionMarketEventBadTopicLength,
+
+    LiftedWithVestingEventMissingData,
+    LiftedWithVestingEventDataOverflow,
+    LiftedWithVestingEventBadDataLength,
+    LiftedWithVestingEventWrongTopicCount,
+    LiftedWithVestingEventBadTopicLength,
+
+    LiftedToDestinationEventMissingData,
+    LiftedToDestinationEventDataOverflow,
+    LiftedToDestinationEventBadDataLength,
+    LiftedToDestinationEventWrongTopicCount,
+    LiftedToDestinationEventBadTopicLength,
+
+    SftMintedEventMissingData,
+    SftMintedEventDataOverflow,
+    SftMintedEventBadDataLength,
+    SftMintedEventWrongTopicCount,
+    SftMintedEventBadTopicLength,
+
+    SftTransferToEventMissingData,
+    SftTransferToEventDataOverflow,
+    SftTransferToEventBadDataLength,
+    SftTransferToEventWrongTopicCount,
+    SftTransferToEventBadTopicLength,
+
+    NftBatchListingScheduledEventMissingData,
+    NftBatchListingScheduledEventDataOverflow,
+    NftBatchListingScheduledEventBadDataLength,
+    NftBatchListingScheduledEventWrongTopicCount,
+    NftBatchListingScheduledEventBadTopicLength,
+
+    NftOfferAcceptedEventMissingData,
+    NftOfferAcceptedEventDataOverflow,
+    NftOfferAcceptedEventBadDataLength,
+    NftOfferAcceptedEventWrongTopicCount,
+    NftOfferAcceptedEventBadTopicLength,
 }
 
 #[derive(
@@ -103,6 +139,18 @@ pub enum ValidEvents {
     LiftedToPredictionMarket,
     /// Secondary event emitted by the ERC-20 token contract.
     Erc20DirectTransfer,
+    /// A lift operation where the lifted amount vests over time.
+    LiftedWithVesting,
+    /// A lift operation to a named destination on AvN.
+    LiftedToDestination,
+    /// Semi-fungible tokens were minted.
+    SftMint,
+    /// Semi-fungible tokens of a listing were transferred.
+    SftTransferTo,
+    /// A batch of NFTs was listed with a price schedule by a v2 marketplace contract.
+    NftBatchListingScheduled,
+    /// An offer for an NFT was accepted on a v2 marketplace contract.
+    NftOfferAccepted,
 }
 
 impl ValidEvents {
@@ -152,6 +200,33 @@ impl ValidEvents {
             // hex string of Keccak-256 for Transfer(address,address,uint256)
             ValidEvents::Erc20DirectTransfer =>
                 H256(hex!("ddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef")),
+
+            // hex string of Keccak-256 for
+            // LogLiftedWithVesting(address,bytes32,uint256,uint32,uint32)
+            ValidEvents::LiftedWithVesting =>
+                H256(hex!("4ed69e51d180db4055ed5eb45663086679a6b12f9cdb667779e39f3dc223a7b8")),
+
+            // hex string of Keccak-256 for
+            // LogLiftedToDestination(address,bytes32,bytes32,uint256)
+            ValidEvents::LiftedToDestination =>
+                H256(hex!("0646cc74d929af92c6c537b22810e501b4856e9947e5c0dac8fd23476cd7ed40")),
+
+            // hex string of Keccak-256 for AvnMintSemiFungibleTo(uint256,bytes32,uint256)
+            ValidEvents::SftMint =>
+                H256(hex!("bae47428631a9b9c03efa626a61522e13aa598e9a35716ed79e064360ad5fe8b")),
+
+            // hex string of Keccak-256 for AvnSemiFungibleTransferTo(uint64,bytes32,uint256)
+            ValidEvents::SftTransferTo =>
+                H256(hex!("b3192ff63ebbec459384cae74c3412c806b62b735488a00970ee1ec3c2c01aa2")),
+
+            // hex string of Keccak-256 for
+            // AvnBatchListedWithSchedule(uint256,uint128,uint128,uint64,uint64)
+            ValidEvents::NftBatchListingScheduled =>
+                H256(hex!("fcecc4c4a49b9e4b3174002c50d5834a44b720ee201cb194aaa95a790d30896f")),
+
+            // hex string of Keccak-256 for AvnOfferAccepted(uint256,bytes32,uint64,uint128)
+            ValidEvents::NftOfferAccepted =>
+                H256(hex!("43fa1b92ad1d743f44bc71060921f4cf12a57b86336b47470cfa91d76e1a9922")),
         }
     }
 
@@ -176,6 +251,18 @@ impl ValidEvents {
             return Some(ValidEvents::LiftedToPredictionMarket)
         } else if signature == &ValidEvents::Erc20DirectTransfer.signature() {
             return Some(ValidEvents::Erc20DirectTransfer)
+        } else if signature == &ValidEvents::LiftedWithVesting.signature() {
+            return Some(ValidEvents::LiftedWithVesting)
+        } else if signature == &ValidEvents::LiftedToDestination.signature() {
+            return Some(ValidEvents::LiftedToDestination)
+        } else if signature == &ValidEvents::SftMint.signature() {
+            return Some(ValidEvents::SftMint)
+        } else if signature == &ValidEvents::SftTransferTo.signature() {
+            return Some(ValidEvents::SftTransferTo)
+        } else if signature == &ValidEvents::NftBatchListingScheduled.signature() {
+            return Some(ValidEvents::NftBatchListingScheduled)
+        } else if signature == &ValidEvents::NftOfferAccepted.signature() {
+            return Some(ValidEvents::NftOfferAccepted)
         } else {
             return None
         }
@@ -186,7 +273,11 @@ impl ValidEvents {
             ValidEvents::NftMint |
             ValidEvents::NftTransferTo |
             ValidEvents::NftCancelListing |
-            ValidEvents::NftEndBatchListing => true,
+            ValidEvents::NftEndBatchListing |
+            ValidEvents::SftMint |
+            ValidEvents::SftTransferTo |
+            ValidEvents::NftBatchListingScheduled |
+            ValidEvents::NftOfferAccepted => true,
             _ => false,
         }
     }
@@ -413,6 +504,190 @@ impl LiftedData {
     }
 }
 
+// T1 Event definition:
+// event LogLiftedWithVesting(address indexed tokenContract, bytes32 indexed liftee,
+// uint256 amount, uint32 cliff, uint32 vestingPeriod);
+// The cliff and the vesting period are expressed in AvN blocks.
+#[derive(Encode, Decode, Default, Clone, PartialEq, Debug, Eq, TypeInfo, MaxEncodedLen)]
+pub struct LiftedWithVestingData {
+    pub token_contract: H160,
+    pub receiver_address: H256,
+    pub amount: u128,
+    /// Number of blocks, from the processing of the lift, before the tokens start vesting
+    pub cliff: u32,
+    /// Number of blocks over which the tokens vest linearly, once the cliff has passed
+    pub vesting_period: u32,
+}
+
+impl LiftedWithVestingData {
+    const TOPIC_CURRENCY_CONTRACT: usize = 1;
+    const TOPIC_INDEX_T2_ADDRESS: usize = 2;
+
+    pub fn is_valid(&self) -> bool {
+        return !self.token_contract.is_zero() &&
+            !self.receiver_address.is_zero() &&
+            self.vesting_period > 0
+    }
+
+    pub fn parse_bytes(data: Option<Vec<u8>>, topics: Vec<Vec<u8>>) -> Result<Self, Error> {
+        // Structure of input bytes:
+        // data --> amount (32 bytes) (big endian)
+        //      --> cliff (32 bytes) (first 28 bytes are 0 and should be ignored)
+        //      --> vesting period (32 bytes) (first 28 bytes are 0 and should be ignored)
+        // all topics are 32 bytes long
+        // topics[0] --> event signature (can be ignored)
+        // topics[1] --> currency contract address (first 12 bytes are 0 and should be ignored)
+        // topics[2] --> receiver t2 public key (32 bytes)
+
+        if data.is_none() {
+            return Err(Error::LiftedWithVestingEventMissingData)
+        }
+        let data = data.expect("Already checked for errors");
+
+        if data.len() != 3 * WORD_LENGTH {
+            return Err(Error::LiftedWithVestingEventBadDataLength)
+        }
+
+        if topics.len() != 3 {
+            return Err(Error::LiftedWithVestingEventWrongTopicCount)
+        }
+
+        if topics[Self::TOPIC_CURRENCY_CONTRACT].len() != WORD_LENGTH ||
+            topics[Self::TOPIC_INDEX_T2_ADDRESS].len() != WORD_LENGTH
+        {
+            return Err(Error::LiftedWithVestingEventBadTopicLength)
+        }
+
+        let token_contract = H160::from_slice(
+            &topics[Self::TOPIC_CURRENCY_CONTRACT][DISCARDED_ZERO_BYTES..WORD_LENGTH],
+        );
+
+        let receiver_address = H256::from_slice(&topics[Self::TOPIC_INDEX_T2_ADDRESS]);
+
+        let (amount_word, rest) = data.split_at(WORD_LENGTH);
+        let (cliff_word, vesting_period_word) = rest.split_at(WORD_LENGTH);
+
+        if amount_word[0..HALF_WORD_LENGTH].iter().any(|byte| byte > &0) ||
+            cliff_word[0..TWENTY_EIGHT_BYTES].iter().any(|byte| byte > &0) ||
+            vesting_period_word[0..TWENTY_EIGHT_BYTES].iter().any(|byte| byte > &0)
+        {
+            return Err(Error::LiftedWithVestingEventDataOverflow)
+        }
+
+        let amount = u128::from_be_bytes(
+            amount_word[HALF_WORD_LENGTH..WORD_LENGTH]
+                .try_into()
+                .expect("Slice is the correct size"),
+        );
+        let cliff = u32::from_be_bytes(
+            cliff_word[TWENTY_EIGHT_BYTES..WORD_LENGTH]
+                .try_into()
+                .expect("Slice is the correct size"),
+        );
+        let vesting_period = u32::from_be_bytes(
+            vesting_period_word[TWENTY_EIGHT_BYTES..WORD_LENGTH]
+                .try_into()
+                .expect("Slice is the correct size"),
+        );
+
+        return Ok(LiftedWithVestingData {
+            token_contract,
+            receiver_address,
+            amount,
+            cliff,
+            vesting_period,
+        })
+    }
+}
+
+/// The name of a runtime pallet that tokens can be lifted to, as a left aligned `bytes32`
+pub type LiftDestination = H256;
+
+/// The destination of the lifts of `LogLiftedToPredictionMarket` events, which name no destination
+pub const PREDICTION_MARKET_LIFT_DESTINATION: LiftDestination =
+    H256(*b"prediction-market\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0");
+
+// T1 Event definition:
+// event LogLiftedToDestination(address indexed tokenContract, bytes32 indexed liftee,
+// bytes32 indexed destination, uint256 amount);
+#[derive(Encode, Decode, Default, Clone, PartialEq, Debug, Eq, TypeInfo, MaxEncodedLen)]
+pub struct LiftedToDestinationData {
+    pub token_contract: H160,
+    pub receiver_address: H256,
+    pub destination: LiftDestination,
+    pub amount: u128,
+}
+
+impl LiftedToDestinationData {
+    const TOPIC_CURRENCY_CONTRACT: usize = 1;
+    const TOPIC_INDEX_T2_ADDRESS: usize = 2;
+    const TOPIC_DESTINATION: usize = 3;
+
+    pub fn is_valid(&self) -> bool {
+        return !self.token_contract.is_zero() &&
+            !self.receiver_address.is_zero() &&
+            !self.destination.is_zero()
+    }
+
+    pub fn parse_bytes(data: Option<Vec<u8>>, topics: Vec<Vec<u8>>) -> Result<Self, Error> {
+        // Structure of input bytes:
+        // data --> amount (32 bytes) (big endian)
+        // all topics are 32 bytes long
+        // topics[0] --> event signature (can be ignored)
+        // topics[1] --> currency contract address (first 12 bytes are 0 and should be ignored)
+        // topics[2] --> receiver t2 public key (32 bytes)
+        // topics[3] --> destination (32 bytes)
+
+        if data.is_none() {
+            return Err(Error::LiftedToDestinationEventMissingData)
+        }
+        let data = data.expect("Already checked for errors");
+
+        if data.len() != WORD_LENGTH {
+            return Err(Error::LiftedToDestinationEventBadDataLength)
+        }
+
+        if topics.len() != 4 {
+            return Err(Error::LiftedToDestinationEventWrongTopicCount)
+        }
+
+        if topics[Self::TOPIC_CURRENCY_CONTRACT].len() != WORD_LENGTH ||
+            topics[Self::TOPIC_INDEX_T2_ADDRESS].len() != WORD_LENGTH ||
+            topics[Self::TOPIC_DESTINATION].len() != WORD_LENGTH
+        {
+            return Err(Error::LiftedToDestinationEventBadTopicLength)
+        }
+
+        let token_contract = H160::from_slice(
+            &topics[Self::TOPIC_CURRENCY_CONTRACT][DISCARDED_ZERO_BYTES..WORD_LENGTH],
+        );
+        let receiver_address = H256::from_slice(&topics[Self::TOPIC_INDEX_T2_ADDRESS]);
+        let destination = H256::from_slice(&topics[Self::TOPIC_DESTINATION]);
+
+        if data[0..HALF_WORD_LENGTH].iter().any(|byte| byte > &0) {
+            return Err(Error::LiftedToDestinationEventDataOverflow)
+        }
+
+        let amount = u128::from_be_bytes(
+            data[HALF_WORD_LENGTH..WORD_LENGTH]
+                .try_into()
+                .expect("Slice is the correct size"),
+        );
+
+        return Ok(LiftedToDestinationData { token_contract, receiver_address, destination, amount })
+    }
+
+    /// The lift of a `LogLiftedToPredictionMarket` event, to `PREDICTION_MARKET_LIFT_DESTINATION`
+    pub fn from_prediction_market_lift(data: &LiftedData) -> Self {
+        LiftedToDestinationData {
+            token_contract: data.token_contract,
+            receiver_address: data.receiver_address,
+            destination: PREDICTION_MARKET_LIFT_DESTINATION,
+            amount: data.amount,
+        }
+    }
+}
+
 #[derive(Encode, Decode, Default, Clone, PartialEq, Debug, Eq, TypeInfo, MaxEncodedLen)]
 pub struct NftMintData {
     pub batch_id: U256,
@@ -548,6 +823,340 @@ impl NftTransferToData {
     }
 }
 
+// T1 Event definition:
+// event AvnMintSemiFungibleTo(uint256 indexed sftId, bytes32 indexed t2PublicKey,
+// uint256 amount);
+#[derive(Encode, Decode, Default, Clone, PartialEq, Debug, Eq, TypeInfo, MaxEncodedLen)]
+pub struct SftMintData {
+    pub sft_id: U256,
+    pub t2_owner_public_key: H256,
+    pub amount: u128,
+}
+
+impl SftMintData {
+    const TOPIC_INDEX_SFT_ID: usize = 1;
+    const TOPIC_INDEX_T2_OWNER_PUBLIC_KEY: usize = 2;
+
+    pub fn is_valid(&self) -> bool {
+        return !self.t2_owner_public_key.is_zero() && self.amount > 0
+    }
+
+    pub fn parse_bytes(data: Option<Vec<u8>>, topics: Vec<Vec<u8>>) -> Result<Self, Error> {
+        // Structure of input bytes:
+        // data --> amount (32 bytes) (big endian)
+        // all topics are 32 bytes long
+        // topics[0] --> event signature (can be ignored)
+        // topics[1] --> the sft id (32 bytes)
+        // topics[2] --> AvN public key of the owner (32 bytes)
+
+        if data.is_none() {
+            return Err(Error::SftMintedEventMissingData)
+        }
+        let data = data.expect("Already checked for errors");
+
+        if data.len() != WORD_LENGTH {
+            return Err(Error::SftMintedEventBadDataLength)
+        }
+
+        if topics.len() != 3 {
+            return Err(Error::SftMintedEventWrongTopicCount)
+        }
+
+        if topics[Self::TOPIC_INDEX_SFT_ID].len() != WORD_LENGTH ||
+            topics[Self::TOPIC_INDEX_T2_OWNER_PUBLIC_KEY].len() != WORD_LENGTH
+        {
+            return Err(Error::SftMintedEventBadTopicLength)
+        }
+
+        if data[0..HALF_WORD_LENGTH].iter().any(|byte| byte > &0) {
+            return Err(Error::SftMintedEventDataOverflow)
+        }
+
+        let sft_id = <U256 as From<&[u8]>>::from(&topics[Self::TOPIC_INDEX_SFT_ID]);
+        let t2_owner_public_key = H256::from_slice(&topics[Self::TOPIC_INDEX_T2_OWNER_PUBLIC_KEY]);
+        let amount = u128::from_be_bytes(
+            data[HALF_WORD_LENGTH..WORD_LENGTH]
+                .try_into()
+                .expect("Slice is the correct size"),
+        );
+
+        return Ok(SftMintData { sft_id, t2_owner_public_key, amount })
+    }
+}
+
+// T1 Event definition:
+// event AvnSemiFungibleTransferTo(uint64 indexed listingId, bytes32 indexed t2PublicKey,
+// uint256 amount);
+#[derive(Encode, Decode, Default, Clone, PartialEq, Debug, Eq, TypeInfo, MaxEncodedLen)]
+pub struct SftTransferToData {
+    pub listing_id: u64,
+    pub t2_transfer_to_public_key: H256,
+    pub amount: u128,
+}
+
+impl SftTransferToData {
+    const TOPIC_INDEX_LISTING_ID: usize = 1;
+    const TOPIC_INDEX_T2_TRANSFER_TO_PUBLIC_KEY: usize = 2;
+
+    pub fn is_valid(&self) -> bool {
+        return !self.t2_transfer_to_public_key.is_zero() && self.amount > 0
+    }
+
+    pub fn parse_bytes(data: Option<Vec<u8>>, topics: Vec<Vec<u8>>) -> Result<Self, Error> {
+        // Structure of input bytes:
+        // data --> amount (32 bytes) (big endian)
+        // all topics are 32 bytes long
+        // topics[0] --> event signature (can be ignored)
+        // topics[1] --> listing id (first 24 bytes are 0 and should be ignored)
+        // topics[2] --> AvN public key to transfer the tokens to (32 bytes)
+
+        if data.is_none() {
+            return Err(Error::SftTransferToEventMissingData)
+        }
+        let data = data.expect("Already checked for errors");
+
+        if data.len() != WORD_LENGTH {
+            return Err(Error::SftTransferToEventBadDataLength)
+        }
+
+        if topics.len() != 3 {
+            return Err(Error::SftTransferToEventWrongTopicCount)
+        }
+
+        if topics[Self::TOPIC_INDEX_LISTING_ID].len() != WORD_LENGTH ||
+            topics[Self::TOPIC_INDEX_T2_TRANSFER_TO_PUBLIC_KEY].len() != WORD_LENGTH
+        {
+            return Err(Error::SftTransferToEventBadTopicLength)
+        }
+
+        if topics[Self::TOPIC_INDEX_LISTING_ID][0..TWENTY_FOUR_BYTES]
+            .iter()
+            .any(|byte| byte > &0) ||
+            data[0..HALF_WORD_LENGTH].iter().any(|byte| byte > &0)
+        {
+            return Err(Error::SftTransferToEventDataOverflow)
+        }
+
+        let listing_id = u64::from_be_bytes(
+            topics[Self::TOPIC_INDEX_LISTING_ID][TWENTY_FOUR_BYTES..WORD_LENGTH]
+                .try_into()
+                .expect("Slice is the correct size"),
+        );
+        let t2_transfer_to_public_key =
+            H256::from_slice(&topics[Self::TOPIC_INDEX_T2_TRANSFER_TO_PUBLIC_KEY]);
+        let amount = u128::from_be_bytes(
+            data[HALF_WORD_LENGTH..WORD_LENGTH]
+                .try_into()
+                .expect("Slice is the correct size"),
+        );
+
+        return Ok(SftTransferToData { listing_id, t2_transfer_to_public_key, amount })
+    }
+}
+
+/// The price of a listing moving linearly from `start_price` at `start_time` to `end_price` at
+/// `end_time`. Times are Ethereum timestamps in seconds.
+#[derive(Encode, Decode, Default, Clone, PartialEq, Debug, Eq, TypeInfo, MaxEncodedLen)]
+pub struct PriceSchedule {
+    pub start_price: u128,
+    pub end_price: u128,
+    pub start_time: u64,
+    pub end_time: u64,
+}
+
+impl PriceSchedule {
+    pub fn is_valid(&self) -> bool {
+        return self.start_time < self.end_time && (self.start_price > 0 || self.end_price > 0)
+    }
+
+    /// The price of the listing at `timestamp`
+    pub fn price_at(&self, timestamp: u64) -> u128 {
+        if timestamp <= self.start_time || self.start_time >= self.end_time {
+            return self.start_price
+        }
+        if timestamp >= self.end_time {
+            return self.end_price
+        }
+
+        let elapsed = (timestamp - self.start_time) as u128;
+        let duration = (self.end_time - self.start_time) as u128;
+        if self.end_price >= self.start_price {
+            let increase = (self.end_price - self.start_price).saturating_mul(elapsed) / duration;
+            return self.start_price + increase
+        }
+        let decrease = (self.start_price - self.end_price).saturating_mul(elapsed) / duration;
+        return self.start_price - decrease
+    }
+}
+
+// T1 Event definition (v2 marketplace):
+// event AvnBatchListedWithSchedule(uint256 indexed batchId, uint128 startPrice, uint128 endPrice,
+// uint64 startTime, uint64 endTime);
+#[derive(Encode, Decode, Default, Clone, PartialEq, Debug, Eq, TypeInfo, MaxEncodedLen)]
+pub struct NftBatchListingScheduleData {
+    pub batch_id: U256,
+    pub schedule: PriceSchedule,
+}
+
+impl NftBatchListingScheduleData {
+    const TOPIC_INDEX_BATCH_ID: usize = 1;
+    const DATA_INDEX_START_PRICE: usize = 0;
+    const DATA_INDEX_END_PRICE: usize = 1;
+    const DATA_INDEX_START_TIME: usize = 2;
+    const DATA_INDEX_END_TIME: usize = 3;
+
+    pub fn is_valid(&self) -> bool {
+        return !self.batch_id.is_zero() && self.schedule.is_valid()
+    }
+
+    pub fn parse_bytes(data: Option<Vec<u8>>, topics: Vec<Vec<u8>>) -> Result<Self, Error> {
+        // Structure of input bytes:
+        // data --> 4 words (32 bytes each) (big endian):
+        //      start price (first 16 bytes are 0 and should be ignored)
+        //      end price (first 16 bytes are 0 and should be ignored)
+        //      start time (first 24 bytes are 0 and should be ignored)
+        //      end time (first 24 bytes are 0 and should be ignored)
+        // all topics are 32 bytes long
+        // topics[0] --> event signature (can be ignored)
+        // topics[1] --> the batch id (32 bytes)
+
+        if data.is_none() {
+            return Err(Error::NftBatchListingScheduledEventMissingData)
+        }
+        let data = data.expect("Already checked for errors");
+
+        if data.len() != 4 * WORD_LENGTH {
+            return Err(Error::NftBatchListingScheduledEventBadDataLength)
+        }
+
+        if topics.len() != 2 {
+            return Err(Error::NftBatchListingScheduledEventWrongTopicCount)
+        }
+
+        if topics[Self::TOPIC_INDEX_BATCH_ID].len() != WORD_LENGTH {
+            return Err(Error::NftBatchListingScheduledEventBadTopicLength)
+        }
+
+        let word = |index: usize| &data[index * WORD_LENGTH..(index + 1) * WORD_LENGTH];
+        if word(Self::DATA_INDEX_START_PRICE)[0..HALF_WORD_LENGTH]
+            .iter()
+            .any(|byte| byte > &0) ||
+            word(Self::DATA_INDEX_END_PRICE)[0..HALF_WORD_LENGTH]
+                .iter()
+                .any(|byte| byte > &0) ||
+            word(Self::DATA_INDEX_START_TIME)[0..TWENTY_FOUR_BYTES]
+                .iter()
+                .any(|byte| byte > &0) ||
+            word(Self::DATA_INDEX_END_TIME)[0..TWENTY_FOUR_BYTES]
+                .iter()
+                .any(|byte| byte > &0)
+        {
+            return Err(Error::NftBatchListingScheduledEventDataOverflow)
+        }
+
+        let batch_id = <U256 as From<&[u8]>>::from(&topics[Self::TOPIC_INDEX_BATCH_ID]);
+        let price = |index: usize| {
+            u128::from_be_bytes(
+                word(index)[HALF_WORD_LENGTH..WORD_LENGTH]
+                    .try_into()
+                    .expect("Slice is the correct size"),
+            )
+        };
+        let time = |index: usize| {
+            u64::from_be_bytes(
+                word(index)[TWENTY_FOUR_BYTES..WORD_LENGTH]
+                    .try_into()
+                    .expect("Slice is the correct size"),
+            )
+        };
+
+        return Ok(NftBatchListingScheduleData {
+            batch_id,
+            schedule: PriceSchedule {
+                start_price: price(Self::DATA_INDEX_START_PRICE),
+                end_price: price(Self::DATA_INDEX_END_PRICE),
+                start_time: time(Self::DATA_INDEX_START_TIME),
+                end_time: time(Self::DATA_INDEX_END_TIME),
+            },
+        })
+    }
+}
+
+// T1 Event definition (v2 marketplace):
+// event AvnOfferAccepted(uint256 indexed nftId, bytes32 indexed t2PublicKey, uint64 indexed opId,
+// uint128 price);
+#[derive(Encode, Decode, Default, Clone, PartialEq, Debug, Eq, TypeInfo, MaxEncodedLen)]
+pub struct NftOfferAcceptedData {
+    pub nft_id: U256,
+    pub t2_transfer_to_public_key: H256,
+    pub op_id: u64,
+    pub price: u128,
+}
+
+impl NftOfferAcceptedData {
+    const TOPIC_INDEX_NFT_ID: usize = 1;
+    const TOPIC_INDEX_T2_TRANSFER_TO_PUBLIC_KEY: usize = 2;
+    const TOPIC_INDEX_OP_ID: usize = 3;
+
+    pub fn is_valid(&self) -> bool {
+        return !self.t2_transfer_to_public_key.is_zero() && self.price > 0
+    }
+
+    pub fn parse_bytes(data: Option<Vec<u8>>, topics: Vec<Vec<u8>>) -> Result<Self, Error> {
+        // Structure of input bytes:
+        // data --> price (32 bytes) (big endian)
+        // all topics are 32 bytes long
+        // topics[0] --> event signature (can be ignored)
+        // topics[1] --> the nft id (32 bytes)
+        // topics[2] --> AvN public key of the buyer (32 bytes)
+        // topics[3] --> transfer nonce (first 24 bytes are 0 and should be ignored)
+
+        if data.is_none() {
+            return Err(Error::NftOfferAcceptedEventMissingData)
+        }
+        let data = data.expect("Already checked for errors");
+
+        if data.len() != WORD_LENGTH {
+            return Err(Error::NftOfferAcceptedEventBadDataLength)
+        }
+
+        if topics.len() != 4 {
+            return Err(Error::NftOfferAcceptedEventWrongTopicCount)
+        }
+
+        if topics[Self::TOPIC_INDEX_NFT_ID].len() != WORD_LENGTH ||
+            topics[Self::TOPIC_INDEX_T2_TRANSFER_TO_PUBLIC_KEY].len() != WORD_LENGTH ||
+            topics[Self::TOPIC_INDEX_OP_ID].len() != WORD_LENGTH
+        {
+            return Err(Error::NftOfferAcceptedEventBadTopicLength)
+        }
+
+        if topics[Self::TOPIC_INDEX_OP_ID][0..TWENTY_FOUR_BYTES]
+            .iter()
+            .any(|byte| byte > &0) ||
+            data[0..HALF_WORD_LENGTH].iter().any(|byte| byte > &0)
+        {
+            return Err(Error::NftOfferAcceptedEventDataOverflow)
+        }
+
+        let nft_id = <U256 as From<&[u8]>>::from(&topics[Self::TOPIC_INDEX_NFT_ID]);
+        let t2_transfer_to_public_key =
+            H256::from_slice(&topics[Self::TOPIC_INDEX_T2_TRANSFER_TO_PUBLIC_KEY]);
+        let op_id = u64::from_be_bytes(
+            topics[Self::TOPIC_INDEX_OP_ID][TWENTY_FOUR_BYTES..WORD_LENGTH]
+                .try_into()
+                .expect("Slice is the correct size"),
+        );
+        let price = u128::from_be_bytes(
+            data[HALF_WORD_LENGTH..WORD_LENGTH]
+                .try_into()
+                .expect("Slice is the correct size"),
+        );
+
+        return Ok(NftOfferAcceptedData { nft_id, t2_transfer_to_public_key, op_id, price })
+    }
+}
+
 #[derive(Encode, Decode, Default, Clone, PartialEq, Debug, Eq, TypeInfo, MaxEncodedLen)]
 pub struct NftCancelListingData {
     pub nft_id: U256,
@@ -741,6 +1350,12 @@ pub enum EventData {
     LogLowerClaimed(AvtLowerClaimedData),
     LogLiftedToPredictionMarket(LiftedData),
     LogErc20Transfer(LiftedData),
+    LogLiftedWithVesting(LiftedWithVestingData),
+    LogLiftedToDestination(LiftedToDestinationData),
+    LogSftMinted(SftMintData),
+    LogSftTransferTo(SftTransferToData),
+    LogNftBatchListingScheduled(NftBatchListingScheduleData),
+    LogNftOfferAccepted(NftOfferAcceptedData),
 }
 
 impl EventData {
@@ -757,10 +1372,27 @@ impl EventData {
             EventData::LogAvtGrowthLifted(d) => d.is_valid(),
             EventData::LogLiftedToPredictionMarket(d) => d.is_valid(),
             EventData::LogErc20Transfer(d) => d.is_valid(),
+            EventData::LogLiftedWithVesting(d) => d.is_valid(),
+            EventData::LogLiftedToDestination(d) => d.is_valid(),
+            EventData::LogSftMinted(d) => d.is_valid(),
+            EventData::LogSftTransferTo(d) => d.is_valid(),
+            EventData::LogNftBatchListingScheduled(d) => d.is_valid(),
+            EventData::LogNftOfferAccepted(d) => d.is_valid(),
             EventData::EmptyEvent => true,
             _ => false,
         }
     }
+
+    /// The ERC-20 token of a lift event
+    pub fn lifted_token(&self) -> Option<H160> {
+        match self {
+            EventData::LogLifted(d) | EventData::LogLiftedToPredictionMarket(d) =>
+                Some(d.token_contract),
+            EventData::LogLiftedWithVesting(d) => Some(d.token_contract),
+            EventData::LogLiftedToDestination(d) => Some(d.token_contract),
+            _ => None,
+        }
+    }
 }
 
 impl Default for EventData {
@@ -812,6 +1444,69 @@ impl EthEventId {
     }
 }
 
+/// The progress of an Ethereum event through the AvN
+#[derive(Encode, Decode, Clone, PartialEq, Debug, Eq, TypeInfo)]
+pub enum EthEventStatus<BlockNumber> {
+    /// Found by some authors, who have not yet agreed on it
+    Discovered { votes: u32 },
+    /// Waiting to be checked by a validator
+    Unchecked,
+    /// Checked, and can be challenged until it is ready for processing
+    PendingChallenge { check_result: CheckResult, ready_for_processing_after_block: BlockNumber },
+    /// Processed by the AvN
+    Processed { accepted: bool },
+}
+
+/// Prefix of the offchain index keys holding the record of each processed event
+pub const PROCESSED_EVENT_INDEX_PREFIX: &'static [u8] = b"avn::processed_event::";
+
+/// Record of a processed event, written to the offchain index when the event is processed so
+/// nodes can answer lookups without scanning the chain.
+#[derive(Encode, Decode, Clone, PartialEq, Debug, Eq, TypeInfo)]
+pub struct ProcessedEventRecord<BlockNumber> {
+    pub event_id: EthEventId,
+    pub block_number: BlockNumber,
+    /// True if the event was accepted and handled by the AvN
+    pub accepted: bool,
+}
+
+/// Events are indexed by transaction hash first, so all the events of a transaction can be found
+/// from its hash and the known event signatures.
+pub fn processed_event_index_key(event_id: &EthEventId) -> Vec<u8> {
+    [
+        PROCESSED_EVENT_INDEX_PREFIX,
+        event_id.transaction_hash.as_bytes(),
+        event_id.signature.as_bytes(),
+    ]
+    .concat()
+}
+
+/// Prefix of the offchain index keys holding the processed events of each archived period
+pub const ARCHIVED_EVENTS_INDEX_PREFIX: &'static [u8] = b"avn::archived_events::";
+
+/// The processed events of an archived period are indexed in the order of the leaves of its
+/// Merkle tree, so proofs can be built for them.
+pub fn archived_events_index_key(period: u32) -> Vec<u8> {
+    [ARCHIVED_EVENTS_INDEX_PREFIX, &period.to_le_bytes()].concat()
+}
+
+/// Leaf committing to a processed event in the Merkle tree of an archived period
+pub fn processed_event_leaf(event_id: &EthEventId, accepted: bool) -> H256 {
+    H256::from(sp_io::hashing::keccak_256(&(event_id, accepted).encode()))
+}
+
+/// Proof that an event was processed in a period that has been archived
+#[derive(Encode, Decode, Clone, PartialEq, Debug, Eq, TypeInfo)]
+pub struct ArchivedEventProof {
+    pub period: u32,
+    /// The value recorded for the event when it was processed
+    pub accepted: bool,
+    /// Position of the event in the leaves of the period
+    pub leaf_index: u32,
+    /// Siblings of the leaf, from the leaf up to the root
+    pub siblings: Vec<H256>,
+}
+
 #[derive(Encode, Decode, Default, Clone, PartialEq, Debug, Eq, TypeInfo, MaxEncodedLen)]
 pub struct EthEvent {
     pub event_id: EthEventId,
@@ -827,6 +1522,8 @@ pub struct EthEventCheckResult<BlockNumber: Member, AccountId: Member> {
     pub ready_for_processing_after_block: BlockNumber,
     // Minimum number of votes to successfully challenge this result
     pub min_challenge_votes: u32,
+    /// The Ethereum block of the transaction receipt the result was derived from, if one was found
+    pub eth_block: Option<u64>,
 }
 
 impl<BlockNumber: Member, AccountId: Member> EthEventCheckResult<BlockNumber, AccountId> {
@@ -846,8 +1543,14 @@ impl<BlockNumber: Member, AccountId: Member> EthEventCheckResult<BlockNumber, Ac
             ready_for_processing_after_block: ready_after_block,
             checked_at_block,
             min_challenge_votes,
+            eth_block: None,
         }
     }
+
+    pub fn observed_at(mut self, eth_block: Option<u64>) -> Self {
+        self.eth_block = eth_block;
+        self
+    }
 }
 
 // ================================= Challenges
@@ -940,6 +1643,42 @@ impl<TokenId, AccountId> TokenInterface<TokenId, AccountId> for () {
     }
 }
 
+/// Implemented by the runtime pallets that tokens can be lifted to with `LogLiftedToDestination`
+pub trait OnLiftReceived<TokenId, AccountId> {
+    /// Whether lifts to `destination` are handled by this pallet
+    fn is_destination(destination: &LiftDestination) -> bool;
+
+    /// Called once the lifted tokens have been deposited to `recipient`
+    fn on_lift_received(
+        destination: &LiftDestination,
+        token_id: TokenId,
+        recipient: &AccountId,
+        amount: u128,
+    ) -> DispatchResult;
+}
+
+#[impl_trait_for_tuples::impl_for_tuples(30)]
+impl<TokenId, AccountId> OnLiftReceived<TokenId, AccountId> for Tuple {
+    fn is_destination(destination: &LiftDestination) -> bool {
+        for_tuples!( #( if Tuple::is_destination(destination) { return true } )* );
+        false
+    }
+
+    fn on_lift_received(
+        destination: &LiftDestination,
+        token_id: TokenId,
+        recipient: &AccountId,
+        amount: u128,
+    ) -> DispatchResult {
+        for_tuples!( #(
+            if Tuple::is_destination(destination) {
+                return Tuple::on_lift_received(destination, token_id, recipient, amount)
+            }
+        )* );
+        Err(DispatchError::Other("Unknown lift destination"))
+    }
+}
+
 // ======================================== Tests
 // =====================================================
 
@@ -958,3 +1697,19 @@ mod test_avt_growth_event_parsing;
 #[cfg(test)]
 #[path = "tests/test_lower_claim.rs"]
 mod test_lower_claim;
+
+#[cfg(test)]
+#[path = "tests/test_lifted_with_vesting.rs"]
+mod test_lifted_with_vesting;
+
+#[cfg(test)]
+#[path = "tests/test_lifted_to_destination.rs"]
+mod test_lifted_to_destination;
+
+#[cfg(test)]
+#[path = "tests/test_semi_fungible_events.rs"]
+mod test_semi_fungible_events;
+
+#[cfg(test)]
+#[path = "tests/test_marketplace_v2_events.rs"]
+mod test_marketplace_v2_events;
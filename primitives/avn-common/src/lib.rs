@@ -30,7 +30,10 @@ pub mod avn_tests_helpers;
 pub mod eth_key_actions;
 pub mod event_discovery;
 pub mod event_types;
+pub mod merkle;
+pub mod metadata_hash;
 pub mod ocw_lock;
+pub mod ocw_task_queue;
 
 /// Ingress counter type for a counter that can sign the same message with a different signature
 /// each time
@@ -87,6 +90,10 @@ pub enum BridgeContractMethod {
     TriggerGrowth,
     AddAuthor,
     RemoveAuthor,
+    PublishValidatorSetCheckpoint,
+    ExportNft,
+    BurnNft,
+    SlashValidators,
 }
 
 impl BridgeContractMethod {
@@ -99,6 +106,10 @@ impl BridgeContractMethod {
             BridgeContractMethod::TriggerGrowth => b"triggerGrowth",
             BridgeContractMethod::AddAuthor => b"addAuthor",
             BridgeContractMethod::RemoveAuthor => b"removeAuthor",
+            BridgeContractMethod::PublishValidatorSetCheckpoint => b"publishValidatorSetCheckpoint",
+            BridgeContractMethod::ExportNft => b"exportNft",
+            BridgeContractMethod::BurnNft => b"burnNft",
+            BridgeContractMethod::SlashValidators => b"slashValidators",
         }
     }
 }
@@ -159,6 +170,18 @@ pub trait InnerCallValidator {
     }
 }
 
+/// Gives the account an Ethereum address is registered to, so calls authorised by that address can
+/// be dispatched on behalf of the account
+pub trait EthereumAddressMapping<AccountId> {
+    fn account_of(ethereum_address: &H160) -> Option<AccountId>;
+}
+
+impl<AccountId> EthereumAddressMapping<AccountId> for () {
+    fn account_of(_ethereum_address: &H160) -> Option<AccountId> {
+        None
+    }
+}
+
 pub trait FeePaymentHandler {
     // The type that represents an account id defined in the trait (T::AccountId)
     type AccountId;
@@ -333,11 +356,34 @@ where
     Err(())
 }
 
+/// Verifies a signature over the payload of a proxied call. Like `CheckMetadataHash` does for
+/// extrinsics, an offline signer can commit to the metadata it decoded the payload with by signing
+/// the payload followed by the metadata hash. The signature is then only valid for that metadata.
 pub fn verify_multi_signature<Signature, AccountId>(
     signer: &<<Signature as Verify>::Signer as IdentifyAccount>::AccountId,
     signature: &Signature,
     signed_payload: &[u8],
 ) -> Result<(), ()>
+where
+    Signature: Member + Verify + TypeInfo + codec::Encode + codec::Decode,
+    AccountId: Member + codec::Encode + PartialEq,
+    <<Signature as Verify>::Signer as IdentifyAccount>::AccountId:
+        Into<AccountId> + Clone + codec::Encode,
+{
+    if verify_payload_signature::<Signature, AccountId>(signer, signature, signed_payload).is_ok() {
+        return Ok(())
+    }
+
+    let metadata_hash = metadata_hash::RUNTIME_METADATA_HASH.ok_or(())?;
+    let committed_payload = [signed_payload, &metadata_hash[..]].concat();
+    verify_payload_signature::<Signature, AccountId>(signer, signature, &committed_payload)
+}
+
+fn verify_payload_signature<Signature, AccountId>(
+    signer: &<<Signature as Verify>::Signer as IdentifyAccount>::AccountId,
+    signature: &Signature,
+    signed_payload: &[u8],
+) -> Result<(), ()>
 where
     Signature: Member + Verify + TypeInfo + codec::Encode + codec::Decode,
     AccountId: Member + codec::Encode + PartialEq,
@@ -397,6 +443,33 @@ where
     verify_multi_signature::<Signature, AccountId>(&proof.signer, &proof.signature, signed_payload)
 }
 
+/// Verifies a proof over a payload whose first encoded element is the signing `domain` of the
+/// pallet that built it. If `accept_legacy` is set, a signature over the payload without the domain
+/// prefix, as built before domains were introduced, is also accepted.
+pub fn verify_domain_separated_signature<Signature, AccountId>(
+    proof: &Proof<Signature, <<Signature as Verify>::Signer as IdentifyAccount>::AccountId>,
+    domain: &[u8],
+    signed_payload: &[u8],
+    accept_legacy: bool,
+) -> Result<(), ()>
+where
+    Signature: Member + Verify + TypeInfo + codec::Encode + codec::Decode,
+    AccountId: Member + codec::Encode + PartialEq,
+    <<Signature as Verify>::Signer as IdentifyAccount>::AccountId:
+        Into<AccountId> + Clone + codec::Encode,
+{
+    if verify_signature::<Signature, AccountId>(proof, signed_payload).is_ok() {
+        return Ok(())
+    }
+
+    if !accept_legacy {
+        return Err(())
+    }
+
+    let legacy_payload = signed_payload.strip_prefix(domain.encode().as_slice()).ok_or(())?;
+    verify_signature::<Signature, AccountId>(proof, legacy_payload)
+}
+
 #[derive(Encode, Decode, Clone, PartialEq, Debug, Eq)]
 pub struct EthQueryRequest {
     pub tx_hash: H256,
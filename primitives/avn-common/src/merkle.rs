@@ -0,0 +1,93 @@
+//! Binary Merkle trees used to commit to lists of items that are removed from storage.
+//!
+//! Leaves are paired in order and hashed with keccak. When a level has an odd number of nodes,
+//! the last one is promoted to the next level unchanged. The root of an empty list is zero.
+
+use sp_core::H256;
+use sp_io::hashing::keccak_256;
+use sp_std::vec::Vec;
+
+fn hash_pair(left: &H256, right: &H256) -> H256 {
+    H256::from(keccak_256(&[left.as_bytes(), right.as_bytes()].concat()))
+}
+
+fn next_level(nodes: &[H256]) -> Vec<H256> {
+    nodes
+        .chunks(2)
+        .map(|pair| match pair {
+            [left, right] => hash_pair(left, right),
+            [node] => *node,
+            _ => unreachable!("chunks have one or two nodes"),
+        })
+        .collect()
+}
+
+/// Root of the tree built from `leaves`
+pub fn merkle_root(leaves: &[H256]) -> H256 {
+    if leaves.is_empty() {
+        return H256::zero()
+    }
+
+    let mut nodes = leaves.to_vec();
+    while nodes.len() > 1 {
+        nodes = next_level(&nodes);
+    }
+    nodes[0]
+}
+
+/// Hashes of the siblings of the leaf at `leaf_index`, from the leaf up to the root. Returns None
+/// if there is no such leaf.
+pub fn merkle_proof(leaves: &[H256], leaf_index: u32) -> Option<Vec<H256>> {
+    let mut index = leaf_index as usize;
+    if index >= leaves.len() {
+        return None
+    }
+
+    let mut siblings = Vec::new();
+    let mut nodes = leaves.to_vec();
+    while nodes.len() > 1 {
+        if let Some(sibling) = nodes.get(index ^ 1) {
+            siblings.push(*sibling);
+        }
+        nodes = next_level(&nodes);
+        index /= 2;
+    }
+    Some(siblings)
+}
+
+/// Checks that `leaf` is at `leaf_index` of a tree of `number_of_leaves` leaves with `root`
+pub fn verify_merkle_proof(
+    root: &H256,
+    leaf: H256,
+    leaf_index: u32,
+    number_of_leaves: u32,
+    siblings: &[H256],
+) -> bool {
+    if leaf_index >= number_of_leaves {
+        return false
+    }
+
+    let mut index = leaf_index;
+    let mut width = number_of_leaves;
+    let mut node = leaf;
+    let mut siblings = siblings.iter();
+    while width > 1 {
+        let has_sibling = index % 2 == 1 || index + 1 < width;
+        if has_sibling {
+            let sibling = match siblings.next() {
+                Some(sibling) => sibling,
+                None => return false,
+            };
+            node =
+                if index % 2 == 0 { hash_pair(&node, sibling) } else { hash_pair(sibling, &node) };
+        }
+        index /= 2;
+        width = (width + 1) / 2;
+    }
+
+    siblings.next().is_none() && &node == root
+}
+
+#[cfg(test)]
+#[path = "tests/test_merkle.rs"]
+mod test_merkle;
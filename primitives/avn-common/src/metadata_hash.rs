@@ -0,0 +1,45 @@
+// Copyright 2024 Aventus Network Services (UK) Ltd.
+
+//! The RFC-0078 hash of the runtime metadata, which offline signers commit to.
+//!
+//! The runtime build script computes the hash from the metadata of a first build of the runtime
+//! and builds it again with the hash in the `RUNTIME_METADATA_HASH` environment variable. It is
+//! checked by the `CheckMetadataHash` signed extension for extrinsics, and by
+//! `verify_multi_signature` for the payloads signed for proxied calls.
+
+/// The metadata hash of this runtime, if it was provided when building it
+pub const RUNTIME_METADATA_HASH: Option<[u8; 32]> = match option_env!("RUNTIME_METADATA_HASH") {
+    Some(hash) => Some(decode_metadata_hash(hash)),
+    None => None,
+};
+
+/// Decodes a 32 bytes hex string, with or without the `0x` prefix, at compile time
+const fn decode_metadata_hash(hash: &str) -> [u8; 32] {
+    let mut hex = hash.as_bytes();
+    if let [b'0', b'x', rest @ ..] = hex {
+        hex = rest;
+    }
+    assert!(hex.len() == 64, "RUNTIME_METADATA_HASH must be 32 bytes long");
+
+    let mut decoded = [0u8; 32];
+    let mut i = 0;
+    while i < 32 {
+        decoded[i] = decode_hex_digit(hex[2 * i]) << 4 | decode_hex_digit(hex[2 * i + 1]);
+        i += 1;
+    }
+
+    decoded
+}
+
+const fn decode_hex_digit(digit: u8) -> u8 {
+    match digit {
+        b'0'..=b'9' => digit - b'0',
+        b'a'..=b'f' => digit - b'a' + 10,
+        b'A'..=b'F' => digit - b'A' + 10,
+        _ => panic!("RUNTIME_METADATA_HASH must be a hex string"),
+    }
+}
+
+#[cfg(test)]
+#[path = "tests/test_metadata_hash.rs"]
+mod test_metadata_hash;
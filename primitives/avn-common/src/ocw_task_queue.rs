@@ -0,0 +1,206 @@
+//! A queue of offchain worker tasks, kept in the persistent offchain local storage of the node and
+//! shared by all the AvN pallets.
+//!
+//! Pallets enqueue the work their offchain worker has to do (voting on a root, checking an event,
+//! sending a bridge request...) and lease a task before working on it. A lease expires after a
+//! number of blocks, so a task that was interrupted, for example because the node restarted, is
+//! picked up again by a later run instead of being lost. Completed tasks are removed from the
+//! queue, and the tasks still queued can be inspected to see what the node is working on.
+
+use codec::{Codec, Decode, Encode, MaxEncodedLen};
+use sp_runtime::{
+    offchain::storage::{MutateStorageError, StorageRetrievalError, StorageValueRef},
+    scale_info::TypeInfo,
+    traits::AtLeast32Bit,
+};
+use sp_std::vec::Vec;
+
+pub const OCW_TASK_QUEUE_KEY: &'static [u8] = b"avn::ocw_task_queue";
+/// Tasks the queue can hold, for all the pallets together
+pub const MAX_QUEUED_OCW_TASKS: usize = 256;
+
+#[derive(
+    Encode, Decode, MaxEncodedLen, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug, TypeInfo,
+)]
+pub enum OcwTaskPriority {
+    Low,
+    Normal,
+    High,
+}
+
+impl Default for OcwTaskPriority {
+    fn default() -> Self {
+        OcwTaskPriority::Normal
+    }
+}
+
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo)]
+pub struct OcwTask<BlockNumber> {
+    /// Id of the pallet that enqueued the task
+    pub owner: Vec<u8>,
+    /// Identifies the task among the tasks of its owner
+    pub id: Vec<u8>,
+    pub priority: OcwTaskPriority,
+    pub enqueued_at: BlockNumber,
+    /// Block until which a worker is working on the task
+    pub leased_until: Option<BlockNumber>,
+    /// Times the task has been leased
+    pub attempts: u32,
+}
+
+impl<BlockNumber: AtLeast32Bit + Copy> OcwTask<BlockNumber> {
+    pub fn is_leased(&self, now: BlockNumber) -> bool {
+        self.leased_until.map_or(false, |leased_until| now < leased_until)
+    }
+
+    fn is(&self, owner: &[u8], id: &[u8]) -> bool {
+        self.owner == owner && self.id == id
+    }
+
+    fn lease(&mut self, now: BlockNumber, lease_blocks: u32) {
+        self.leased_until = Some(now.saturating_add(lease_blocks.into()));
+        self.attempts = self.attempts.saturating_add(1);
+    }
+}
+
+#[derive(PartialEq, Eq, Debug)]
+pub enum OcwTaskQueueError {
+    QueueFull,
+    TaskNotFound,
+    TaskLeased,
+    /// Another worker updated the queue at the same time
+    ConcurrentModification,
+}
+
+fn mutate_queue<BlockNumber: Codec, R>(
+    f: impl FnOnce(&mut Vec<OcwTask<BlockNumber>>) -> Result<R, OcwTaskQueueError>,
+) -> Result<R, OcwTaskQueueError> {
+    let mut outcome = None;
+    let storage = StorageValueRef::persistent(OCW_TASK_QUEUE_KEY);
+    let result = storage.mutate(
+        |queue: Result<Option<Vec<OcwTask<BlockNumber>>>, StorageRetrievalError>| {
+            // A queue that cannot be decoded is dropped, the pallets enqueue their tasks again
+            let mut queue = queue.ok().flatten().unwrap_or_default();
+            outcome = Some(f(&mut queue)?);
+            Ok(queue)
+        },
+    );
+
+    match result {
+        Ok(_) => outcome.ok_or(OcwTaskQueueError::ConcurrentModification),
+        Err(MutateStorageError::ValueFunctionFailed(e)) => Err(e),
+        Err(MutateStorageError::ConcurrentModification(_)) =>
+            Err(OcwTaskQueueError::ConcurrentModification),
+    }
+}
+
+/// Adds a task to the queue. Returns false if the task was already queued, in which case its
+/// priority is raised to `priority` if it was lower.
+pub fn enqueue<BlockNumber: Codec + AtLeast32Bit + Copy>(
+    owner: &[u8],
+    id: &[u8],
+    priority: OcwTaskPriority,
+    now: BlockNumber,
+) -> Result<bool, OcwTaskQueueError> {
+    mutate_queue::<BlockNumber, _>(|queue| {
+        if let Some(task) = queue.iter_mut().find(|task| task.is(owner, id)) {
+            task.priority = task.priority.max(priority);
+            return Ok(false)
+        }
+
+        if queue.len() >= MAX_QUEUED_OCW_TASKS {
+            return Err(OcwTaskQueueError::QueueFull)
+        }
+
+        queue.push(OcwTask {
+            owner: owner.to_vec(),
+            id: id.to_vec(),
+            priority,
+            enqueued_at: now,
+            leased_until: None,
+            attempts: 0,
+        });
+        Ok(true)
+    })
+}
+
+/// Leases the task of `owner` with the highest priority that is not leased, the oldest first
+pub fn lease_next<BlockNumber: Codec + AtLeast32Bit + Copy>(
+    owner: &[u8],
+    now: BlockNumber,
+    lease_blocks: u32,
+) -> Result<Option<OcwTask<BlockNumber>>, OcwTaskQueueError> {
+    mutate_queue::<BlockNumber, _>(|queue| {
+        let next = queue
+            .iter_mut()
+            .filter(|task| task.owner == owner && !task.is_leased(now))
+            .min_by(|a, b| b.priority.cmp(&a.priority).then(a.enqueued_at.cmp(&b.enqueued_at)));
+
+        Ok(next.map(|task| {
+            task.lease(now, lease_blocks);
+            task.clone()
+        }))
+    })
+}
+
+/// Leases a given task of `owner`
+pub fn lease<BlockNumber: Codec + AtLeast32Bit + Copy>(
+    owner: &[u8],
+    id: &[u8],
+    now: BlockNumber,
+    lease_blocks: u32,
+) -> Result<OcwTask<BlockNumber>, OcwTaskQueueError> {
+    mutate_queue::<BlockNumber, _>(|queue| {
+        let task = queue
+            .iter_mut()
+            .find(|task| task.is(owner, id))
+            .ok_or(OcwTaskQueueError::TaskNotFound)?;
+        if task.is_leased(now) {
+            return Err(OcwTaskQueueError::TaskLeased)
+        }
+
+        task.lease(now, lease_blocks);
+        Ok(task.clone())
+    })
+}
+
+/// Removes a task that has been done, or that no longer needs to be done
+pub fn complete<BlockNumber: Codec>(owner: &[u8], id: &[u8]) -> Result<(), OcwTaskQueueError> {
+    mutate_queue::<BlockNumber, _>(|queue| {
+        let index = queue
+            .iter()
+            .position(|task| task.owner == owner && task.id == id)
+            .ok_or(OcwTaskQueueError::TaskNotFound)?;
+        queue.remove(index);
+        Ok(())
+    })
+}
+
+/// Ends the lease of a task that failed, so it can be retried without waiting for the lease to
+/// expire
+pub fn release<BlockNumber: Codec>(owner: &[u8], id: &[u8]) -> Result<(), OcwTaskQueueError> {
+    mutate_queue::<BlockNumber, _>(|queue| {
+        let task = queue
+            .iter_mut()
+            .find(|task| task.owner == owner && task.id == id)
+            .ok_or(OcwTaskQueueError::TaskNotFound)?;
+        task.leased_until = None;
+        Ok(())
+    })
+}
+
+/// The queued tasks, of all the pallets or only of `owner`
+pub fn queued_tasks<BlockNumber: Codec>(owner: Option<&[u8]>) -> Vec<OcwTask<BlockNumber>> {
+    StorageValueRef::persistent(OCW_TASK_QUEUE_KEY)
+        .get::<Vec<OcwTask<BlockNumber>>>()
+        .ok()
+        .flatten()
+        .unwrap_or_default()
+        .into_iter()
+        .filter(|task| owner.map_or(true, |owner| task.owner == owner))
+        .collect()
+}
+
+#[cfg(test)]
+#[path = "tests/test_ocw_task_queue.rs"]
+mod test_ocw_task_queue;
@@ -0,0 +1,144 @@
+// Copyright 2024 Aventus Systems (UK) Ltd.
+#[cfg(test)]
+use super::*;
+use sha3::{Digest, Keccak256};
+use sp_std::vec::Vec;
+
+mod lifted_to_destination {
+    use super::*;
+
+    const AMOUNT: u128 = 1_000_000;
+
+    struct LiftedToDestinationConfig {
+        topic1: Vec<u8>,
+        topic2_token_contract: Vec<u8>,
+        topic3_receiver: Vec<u8>,
+        topic4_destination: Vec<u8>,
+        bad_topic_short: Vec<u8>,
+    }
+
+    impl LiftedToDestinationConfig {
+        fn setup() -> Self {
+            let mut topic2_token_contract = vec![0u8; 12];
+            topic2_token_contract.extend_from_slice(&[2u8; 20]);
+
+            LiftedToDestinationConfig {
+                topic1: vec![1; 32],
+                topic2_token_contract,
+                topic3_receiver: vec![3; 32],
+                topic4_destination: vec![4; 32],
+                bad_topic_short: vec![10; 16],
+            }
+        }
+
+        fn topics(&self) -> Vec<Vec<u8>> {
+            vec![
+                self.topic1.clone(),
+                self.topic2_token_contract.clone(),
+                self.topic3_receiver.clone(),
+                self.topic4_destination.clone(),
+            ]
+        }
+    }
+
+    fn data(amount: u128) -> Option<Vec<u8>> {
+        let mut data = vec![0u8; 16];
+        data.extend_from_slice(&amount.to_be_bytes());
+        Some(data)
+    }
+
+    #[test]
+    fn event_signature_should_match() {
+        let mut hasher = Keccak256::new();
+
+        hasher.input(b"LogLiftedToDestination(address,bytes32,bytes32,uint256)");
+        let result = hasher.result();
+
+        assert_eq!(result[..], *ValidEvents::LiftedToDestination.signature().as_bytes());
+        assert_eq!(
+            ValidEvents::try_from(&ValidEvents::LiftedToDestination.signature()),
+            Some(ValidEvents::LiftedToDestination)
+        );
+    }
+
+    #[test]
+    fn can_successfully_be_parsed() {
+        let config = LiftedToDestinationConfig::setup();
+
+        let result = LiftedToDestinationData::parse_bytes(data(AMOUNT), config.topics()).unwrap();
+
+        assert_eq!(result.token_contract, H160::from([2u8; 20]));
+        assert_eq!(result.receiver_address, H256::from([3u8; 32]));
+        assert_eq!(result.destination, H256::from([4u8; 32]));
+        assert_eq!(result.amount, AMOUNT);
+        assert!(result.is_valid());
+    }
+
+    #[test]
+    fn is_not_valid_without_a_destination() {
+        let mut config = LiftedToDestinationConfig::setup();
+        config.topic4_destination = vec![0; 32];
+
+        let result = LiftedToDestinationData::parse_bytes(data(AMOUNT), config.topics()).unwrap();
+
+        assert!(!result.is_valid());
+    }
+
+    mod fails_parsing_when {
+        use super::*;
+
+        #[test]
+        fn data_is_missing() {
+            let config = LiftedToDestinationConfig::setup();
+
+            let result = LiftedToDestinationData::parse_bytes(None, config.topics());
+
+            assert_eq!(result, Err(Error::LiftedToDestinationEventMissingData));
+        }
+
+        #[test]
+        fn data_has_the_wrong_length() {
+            let config = LiftedToDestinationConfig::setup();
+
+            let result = LiftedToDestinationData::parse_bytes(Some(vec![1u8; 16]), config.topics());
+
+            assert_eq!(result, Err(Error::LiftedToDestinationEventBadDataLength));
+        }
+
+        #[test]
+        fn event_contains_few_topics() {
+            let config = LiftedToDestinationConfig::setup();
+
+            let topics = vec![config.topic1, config.topic2_token_contract, config.topic3_receiver];
+            let result = LiftedToDestinationData::parse_bytes(data(AMOUNT), topics);
+
+            assert_eq!(result, Err(Error::LiftedToDestinationEventWrongTopicCount));
+        }
+
+        #[test]
+        fn event_contains_short_topic() {
+            let config = LiftedToDestinationConfig::setup();
+
+            let topics = vec![
+                config.topic1,
+                config.topic2_token_contract,
+                config.topic3_receiver,
+                config.bad_topic_short,
+            ];
+            let result = LiftedToDestinationData::parse_bytes(data(AMOUNT), topics);
+
+            assert_eq!(result, Err(Error::LiftedToDestinationEventBadTopicLength));
+        }
+
+        #[test]
+        fn amount_overflows() {
+            let config = LiftedToDestinationConfig::setup();
+
+            let mut data = data(AMOUNT).unwrap();
+            data[0] = 1;
+            let result = LiftedToDestinationData::parse_bytes(Some(data), config.topics());
+
+            assert_eq!(result, Err(Error::LiftedToDestinationEventDataOverflow));
+        }
+    }
+}
@@ -0,0 +1,159 @@
+// Copyright 2024 Aventus Systems (UK) Ltd.
+#[cfg(test)]
+use super::*;
+use sha3::{Digest, Keccak256};
+use sp_std::vec::Vec;
+
+mod lifted_with_vesting {
+    use super::*;
+
+    const AMOUNT: u128 = 1_000_000;
+    const CLIFF: u32 = 100;
+    const VESTING_PERIOD: u32 = 1_000;
+
+    struct LiftedWithVestingConfig {
+        topic1: Vec<u8>,
+        topic2_token_contract: Vec<u8>,
+        topic3_receiver: Vec<u8>,
+        bad_topic_short: Vec<u8>,
+    }
+
+    impl LiftedWithVestingConfig {
+        fn setup() -> Self {
+            let mut topic2_token_contract = vec![0u8; 12];
+            topic2_token_contract.extend_from_slice(&[2u8; 20]);
+
+            LiftedWithVestingConfig {
+                topic1: vec![1; 32],
+                topic2_token_contract,
+                topic3_receiver: vec![3; 32],
+                bad_topic_short: vec![10; 16],
+            }
+        }
+
+        fn topics(&self) -> Vec<Vec<u8>> {
+            vec![
+                self.topic1.clone(),
+                self.topic2_token_contract.clone(),
+                self.topic3_receiver.clone(),
+            ]
+        }
+    }
+
+    fn word(value: &[u8]) -> Vec<u8> {
+        let mut word = vec![0u8; 32 - value.len()];
+        word.extend_from_slice(value);
+        word
+    }
+
+    fn data(amount: u128, cliff: u32, vesting_period: u32) -> Option<Vec<u8>> {
+        Some(
+            [
+                word(&amount.to_be_bytes()),
+                word(&cliff.to_be_bytes()),
+                word(&vesting_period.to_be_bytes()),
+            ]
+            .concat(),
+        )
+    }
+
+    #[test]
+    fn event_signature_should_match() {
+        let mut hasher = Keccak256::new();
+
+        hasher.input(b"LogLiftedWithVesting(address,bytes32,uint256,uint32,uint32)");
+        let result = hasher.result();
+
+        assert_eq!(result[..], *ValidEvents::LiftedWithVesting.signature().as_bytes());
+        assert_eq!(
+            ValidEvents::try_from(&ValidEvents::LiftedWithVesting.signature()),
+            Some(ValidEvents::LiftedWithVesting)
+        );
+    }
+
+    #[test]
+    fn can_successfully_be_parsed() {
+        let config = LiftedWithVestingConfig::setup();
+
+        let result = LiftedWithVestingData::parse_bytes(
+            data(AMOUNT, CLIFF, VESTING_PERIOD),
+            config.topics(),
+        )
+        .unwrap();
+
+        assert_eq!(result.token_contract, H160::from([2u8; 20]));
+        assert_eq!(result.receiver_address, H256::from([3u8; 32]));
+        assert_eq!(result.amount, AMOUNT);
+        assert_eq!(result.cliff, CLIFF);
+        assert_eq!(result.vesting_period, VESTING_PERIOD);
+        assert!(result.is_valid());
+    }
+
+    #[test]
+    fn is_not_valid_without_a_vesting_period() {
+        let config = LiftedWithVestingConfig::setup();
+
+        let result =
+            LiftedWithVestingData::parse_bytes(data(AMOUNT, CLIFF, 0), config.topics()).unwrap();
+
+        assert!(!result.is_valid());
+    }
+
+    mod fails_parsing_when {
+        use super::*;
+
+        #[test]
+        fn data_is_missing() {
+            let config = LiftedWithVestingConfig::setup();
+
+            let result = LiftedWithVestingData::parse_bytes(None, config.topics());
+
+            assert_eq!(result, Err(Error::LiftedWithVestingEventMissingData));
+        }
+
+        #[test]
+        fn data_has_the_wrong_length() {
+            let config = LiftedWithVestingConfig::setup();
+
+            let result = LiftedWithVestingData::parse_bytes(
+                Some(word(&AMOUNT.to_be_bytes())),
+                config.topics(),
+            );
+
+            assert_eq!(result, Err(Error::LiftedWithVestingEventBadDataLength));
+        }
+
+        #[test]
+        fn event_contains_few_topics() {
+            let config = LiftedWithVestingConfig::setup();
+
+            let topics = vec![config.topic1, config.topic2_token_contract];
+            let result =
+                LiftedWithVestingData::parse_bytes(data(AMOUNT, CLIFF, VESTING_PERIOD), topics);
+
+            assert_eq!(result, Err(Error::LiftedWithVestingEventWrongTopicCount));
+        }
+
+        #[test]
+        fn event_contains_short_topic() {
+            let config = LiftedWithVestingConfig::setup();
+
+            let topics = vec![config.topic1, config.topic2_token_contract, config.bad_topic_short];
+            let result =
+                LiftedWithVestingData::parse_bytes(data(AMOUNT, CLIFF, VESTING_PERIOD), topics);
+
+            assert_eq!(result, Err(Error::LiftedWithVestingEventBadTopicLength));
+        }
+
+        #[test]
+        fn vesting_period_overflows() {
+            let config = LiftedWithVestingConfig::setup();
+
+            let mut data = data(AMOUNT, CLIFF, VESTING_PERIOD).unwrap();
+            data[2 * 32] = 1;
+            let result = LiftedWithVestingData::parse_bytes(Some(data), config.topics());
+
+            assert_eq!(result, Err(Error::LiftedWithVestingEventDataOverflow));
+        }
+    }
+}
@@ -0,0 +1,216 @@
+// Copyright 2024 Aventus Systems (UK) Ltd.
+#[cfg(test)]
+use super::*;
+use sha3::{Digest, Keccak256};
+use sp_std::vec::Vec;
+
+fn word(value: u128) -> Vec<u8> {
+    let mut word = vec![0u8; 16];
+    word.extend_from_slice(&value.to_be_bytes());
+    word
+}
+
+mod price_schedule {
+    use super::*;
+
+    fn schedule(start_price: u128, end_price: u128) -> PriceSchedule {
+        PriceSchedule { start_price, end_price, start_time: 100, end_time: 200 }
+    }
+
+    #[test]
+    fn moves_linearly_between_the_start_and_end_prices() {
+        assert_eq!(schedule(1000, 500).price_at(150), 750);
+        assert_eq!(schedule(500, 1000).price_at(150), 750);
+    }
+
+    #[test]
+    fn holds_the_start_and_end_prices_outside_the_schedule() {
+        assert_eq!(schedule(1000, 500).price_at(50), 1000);
+        assert_eq!(schedule(1000, 500).price_at(250), 500);
+    }
+
+    #[test]
+    fn is_not_valid_when_it_ends_before_it_starts() {
+        let mut schedule = schedule(1000, 500);
+        schedule.end_time = schedule.start_time;
+
+        assert!(!schedule.is_valid());
+    }
+}
+
+mod nft_batch_listing_scheduled {
+    use super::*;
+
+    const START_PRICE: u128 = 1000;
+    const END_PRICE: u128 = 500;
+    const START_TIME: u64 = 100;
+    const END_TIME: u64 = 200;
+
+    fn data() -> Option<Vec<u8>> {
+        Some(
+            [word(START_PRICE), word(END_PRICE), word(START_TIME as u128), word(END_TIME as u128)]
+                .concat(),
+        )
+    }
+
+    fn topics() -> Vec<Vec<u8>> {
+        vec![vec![1; 32], vec![2; 32]]
+    }
+
+    #[test]
+    fn event_signature_should_match() {
+        let mut hasher = Keccak256::new();
+
+        hasher.input(b"AvnBatchListedWithSchedule(uint256,uint128,uint128,uint64,uint64)");
+        let result = hasher.result();
+
+        assert_eq!(result[..], *ValidEvents::NftBatchListingScheduled.signature().as_bytes());
+        assert_eq!(
+            ValidEvents::try_from(&ValidEvents::NftBatchListingScheduled.signature()),
+            Some(ValidEvents::NftBatchListingScheduled)
+        );
+        assert!(ValidEvents::NftBatchListingScheduled.is_nft_event());
+    }
+
+    #[test]
+    fn can_successfully_be_parsed() {
+        let result = NftBatchListingScheduleData::parse_bytes(data(), topics()).unwrap();
+
+        assert_eq!(result.batch_id, U256::from([2u8; 32]));
+        assert_eq!(
+            result.schedule,
+            PriceSchedule {
+                start_price: START_PRICE,
+                end_price: END_PRICE,
+                start_time: START_TIME,
+                end_time: END_TIME
+            }
+        );
+        assert!(result.is_valid());
+    }
+
+    mod fails_parsing_when {
+        use super::*;
+
+        #[test]
+        fn data_is_missing() {
+            assert_eq!(
+                NftBatchListingScheduleData::parse_bytes(None, topics()),
+                Err(Error::NftBatchListingScheduledEventMissingData)
+            );
+        }
+
+        #[test]
+        fn data_has_the_wrong_length() {
+            assert_eq!(
+                NftBatchListingScheduleData::parse_bytes(Some(vec![1u8; 32]), topics()),
+                Err(Error::NftBatchListingScheduledEventBadDataLength)
+            );
+        }
+
+        #[test]
+        fn event_contains_few_topics() {
+            assert_eq!(
+                NftBatchListingScheduleData::parse_bytes(data(), vec![vec![1; 32]]),
+                Err(Error::NftBatchListingScheduledEventWrongTopicCount)
+            );
+        }
+
+        #[test]
+        fn event_contains_short_topic() {
+            assert_eq!(
+                NftBatchListingScheduleData::parse_bytes(data(), vec![vec![1; 32], vec![2; 16]]),
+                Err(Error::NftBatchListingScheduledEventBadTopicLength)
+            );
+        }
+
+        #[test]
+        fn a_time_overflows() {
+            let mut data = data().unwrap();
+            data[2 * 32 + 16] = 1;
+
+            assert_eq!(
+                NftBatchListingScheduleData::parse_bytes(Some(data), topics()),
+                Err(Error::NftBatchListingScheduledEventDataOverflow)
+            );
+        }
+    }
+}
+
+mod nft_offer_accepted {
+    use super::*;
+
+    const OP_ID: u64 = 3;
+    const PRICE: u128 = 1_000_000;
+
+    fn topics() -> Vec<Vec<u8>> {
+        vec![vec![1; 32], vec![2; 32], vec![3; 32], word(OP_ID as u128)]
+    }
+
+    #[test]
+    fn event_signature_should_match() {
+        let mut hasher = Keccak256::new();
+
+        hasher.input(b"AvnOfferAccepted(uint256,bytes32,uint64,uint128)");
+        let result = hasher.result();
+
+        assert_eq!(result[..], *ValidEvents::NftOfferAccepted.signature().as_bytes());
+        assert_eq!(
+            ValidEvents::try_from(&ValidEvents::NftOfferAccepted.signature()),
+            Some(ValidEvents::NftOfferAccepted)
+        );
+        assert!(ValidEvents::NftOfferAccepted.is_nft_event());
+    }
+
+    #[test]
+    fn can_successfully_be_parsed() {
+        let result = NftOfferAcceptedData::parse_bytes(Some(word(PRICE)), topics()).unwrap();
+
+        assert_eq!(result.nft_id, U256::from([2u8; 32]));
+        assert_eq!(result.t2_transfer_to_public_key, H256::from([3u8; 32]));
+        assert_eq!(result.op_id, OP_ID);
+        assert_eq!(result.price, PRICE);
+        assert!(result.is_valid());
+    }
+
+    #[test]
+    fn is_not_valid_without_a_price() {
+        let result = NftOfferAcceptedData::parse_bytes(Some(word(0)), topics()).unwrap();
+
+        assert!(!result.is_valid());
+    }
+
+    mod fails_parsing_when {
+        use super::*;
+
+        #[test]
+        fn data_is_missing() {
+            assert_eq!(
+                NftOfferAcceptedData::parse_bytes(None, topics()),
+                Err(Error::NftOfferAcceptedEventMissingData)
+            );
+        }
+
+        #[test]
+        fn event_contains_few_topics() {
+            assert_eq!(
+                NftOfferAcceptedData::parse_bytes(
+                    Some(word(PRICE)),
+                    vec![vec![1; 32], vec![2; 32], vec![3; 32]]
+                ),
+                Err(Error::NftOfferAcceptedEventWrongTopicCount)
+            );
+        }
+
+        #[test]
+        fn op_id_overflows() {
+            let mut topics = topics();
+            topics[3][0] = 1;
+
+            assert_eq!(
+                NftOfferAcceptedData::parse_bytes(Some(word(PRICE)), topics),
+                Err(Error::NftOfferAcceptedEventDataOverflow)
+            );
+        }
+    }
+}
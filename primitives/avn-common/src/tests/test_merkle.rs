@@ -0,0 +1,58 @@
+#[cfg(test)]
+use super::*;
+
+fn leaves(number_of_leaves: u8) -> Vec<H256> {
+    (0..number_of_leaves).map(|i| H256::repeat_byte(i + 1)).collect()
+}
+
+#[test]
+fn root_of_no_leaves_is_zero() {
+    assert_eq!(merkle_root(&[]), H256::zero());
+}
+
+#[test]
+fn root_of_a_single_leaf_is_the_leaf() {
+    let leaves = leaves(1);
+    assert_eq!(merkle_root(&leaves), leaves[0]);
+}
+
+#[test]
+fn last_leaf_of_an_odd_level_is_promoted() {
+    let leaves = leaves(3);
+    let expected = hash_pair(&hash_pair(&leaves[0], &leaves[1]), &leaves[2]);
+    assert_eq!(merkle_root(&leaves), expected);
+}
+
+#[test]
+fn proofs_of_every_leaf_are_valid() {
+    for number_of_leaves in 1..=9 {
+        let leaves = leaves(number_of_leaves);
+        let root = merkle_root(&leaves);
+        for (index, leaf) in leaves.iter().enumerate() {
+            let siblings = merkle_proof(&leaves, index as u32).expect("Leaf exists");
+            assert!(verify_merkle_proof(
+                &root,
+                *leaf,
+                index as u32,
+                number_of_leaves as u32,
+                &siblings
+            ));
+        }
+    }
+}
+
+#[test]
+fn proofs_do_not_verify_other_leaves() {
+    let leaves = leaves(5);
+    let root = merkle_root(&leaves);
+    let siblings = merkle_proof(&leaves, 2).expect("Leaf exists");
+
+    assert!(!verify_merkle_proof(&root, leaves[3], 2, 5, &siblings));
+    assert!(!verify_merkle_proof(&root, leaves[2], 3, 5, &siblings));
+    assert!(!verify_merkle_proof(&root, leaves[2], 5, 5, &siblings));
+}
+
+#[test]
+fn there_is_no_proof_for_missing_leaves() {
+    assert_eq!(merkle_proof(&leaves(4), 4), None);
+}
@@ -0,0 +1,20 @@
+#[cfg(test)]
+use super::*;
+
+#[test]
+fn decodes_prefixed_and_unprefixed_hashes() {
+    let hash = "0x0102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1fFF";
+    let expected: [u8; 32] = [
+        1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25,
+        26, 27, 28, 29, 30, 31, 255,
+    ];
+
+    assert_eq!(decode_metadata_hash(hash), expected);
+    assert_eq!(decode_metadata_hash(&hash[2..]), expected);
+}
+
+#[test]
+#[should_panic(expected = "RUNTIME_METADATA_HASH must be 32 bytes long")]
+fn rejects_hashes_of_the_wrong_length() {
+    decode_metadata_hash("0x0102");
+}
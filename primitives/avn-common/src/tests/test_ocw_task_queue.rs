@@ -0,0 +1,154 @@
+#[cfg(test)]
+use super::*;
+use sp_core::offchain::{testing, OffchainDbExt as OffchainExt};
+use sp_io::TestExternalities;
+
+const SUMMARY: &[u8] = b"summary";
+const BRIDGE: &[u8] = b"eth_bridge";
+const LEASE_BLOCKS: u32 = 10;
+
+fn offchain_externality() -> TestExternalities {
+    let (offchain, _state) = testing::TestOffchainExt::new();
+    let mut ext = TestExternalities::default();
+    ext.register_extension(OffchainExt::new(offchain));
+    ext
+}
+
+fn enqueue_task(owner: &[u8], id: &[u8], priority: OcwTaskPriority, now: u32) {
+    assert_eq!(enqueue(owner, id, priority, now), Ok(true));
+}
+
+#[test]
+fn enqueued_tasks_are_persisted() {
+    offchain_externality().execute_with(|| {
+        enqueue_task(SUMMARY, b"root", OcwTaskPriority::High, 5);
+
+        assert_eq!(
+            queued_tasks::<u32>(None),
+            vec![OcwTask {
+                owner: SUMMARY.to_vec(),
+                id: b"root".to_vec(),
+                priority: OcwTaskPriority::High,
+                enqueued_at: 5,
+                leased_until: None,
+                attempts: 0,
+            }]
+        );
+    });
+}
+
+#[test]
+fn enqueuing_a_task_again_only_raises_its_priority() {
+    offchain_externality().execute_with(|| {
+        enqueue_task(SUMMARY, b"root", OcwTaskPriority::Normal, 5);
+
+        assert_eq!(enqueue(SUMMARY, b"root", OcwTaskPriority::Low, 6), Ok(false));
+        assert_eq!(queued_tasks::<u32>(None)[0].priority, OcwTaskPriority::Normal);
+
+        assert_eq!(enqueue(SUMMARY, b"root", OcwTaskPriority::High, 7), Ok(false));
+        let tasks = queued_tasks::<u32>(None);
+        assert_eq!(tasks.len(), 1);
+        assert_eq!(tasks[0].priority, OcwTaskPriority::High);
+        assert_eq!(tasks[0].enqueued_at, 5);
+    });
+}
+
+#[test]
+fn tasks_are_not_enqueued_when_the_queue_is_full() {
+    offchain_externality().execute_with(|| {
+        for i in 0..MAX_QUEUED_OCW_TASKS {
+            enqueue_task(SUMMARY, &(i as u32).encode(), OcwTaskPriority::Normal, 1);
+        }
+
+        assert_eq!(
+            enqueue(SUMMARY, b"one too many", OcwTaskPriority::High, 1),
+            Err(OcwTaskQueueError::QueueFull)
+        );
+    });
+}
+
+#[test]
+fn the_task_with_the_highest_priority_is_leased_first() {
+    offchain_externality().execute_with(|| {
+        enqueue_task(SUMMARY, b"low", OcwTaskPriority::Low, 1);
+        enqueue_task(SUMMARY, b"normal", OcwTaskPriority::Normal, 2);
+        enqueue_task(SUMMARY, b"high", OcwTaskPriority::High, 3);
+        enqueue_task(SUMMARY, b"later high", OcwTaskPriority::High, 4);
+
+        let leased_ids: Vec<Vec<u8>> = (0..4)
+            .map(|_| lease_next::<u32>(SUMMARY, 5, LEASE_BLOCKS).unwrap().unwrap().id)
+            .collect();
+
+        assert_eq!(
+            leased_ids,
+            vec![b"high".to_vec(), b"later high".to_vec(), b"normal".to_vec(), b"low".to_vec()]
+        );
+        assert_eq!(lease_next::<u32>(SUMMARY, 5, LEASE_BLOCKS), Ok(None));
+    });
+}
+
+#[test]
+fn only_the_tasks_of_the_owner_are_leased() {
+    offchain_externality().execute_with(|| {
+        enqueue_task(BRIDGE, b"request", OcwTaskPriority::High, 1);
+
+        assert_eq!(lease_next::<u32>(SUMMARY, 5, LEASE_BLOCKS), Ok(None));
+        assert_eq!(queued_tasks::<u32>(Some(SUMMARY)), vec![]);
+        assert_eq!(queued_tasks::<u32>(Some(BRIDGE)).len(), 1);
+    });
+}
+
+#[test]
+fn a_leased_task_can_be_leased_again_once_the_lease_expires() {
+    offchain_externality().execute_with(|| {
+        enqueue_task(SUMMARY, b"root", OcwTaskPriority::Normal, 1);
+
+        let task = lease::<u32>(SUMMARY, b"root", 5, LEASE_BLOCKS).unwrap();
+        assert_eq!(task.leased_until, Some(5 + LEASE_BLOCKS));
+        assert_eq!(task.attempts, 1);
+
+        assert_eq!(
+            lease::<u32>(SUMMARY, b"root", 5 + LEASE_BLOCKS - 1, LEASE_BLOCKS),
+            Err(OcwTaskQueueError::TaskLeased)
+        );
+
+        let task = lease::<u32>(SUMMARY, b"root", 5 + LEASE_BLOCKS, LEASE_BLOCKS).unwrap();
+        assert_eq!(task.attempts, 2);
+    });
+}
+
+#[test]
+fn a_released_task_can_be_leased_again_straight_away() {
+    offchain_externality().execute_with(|| {
+        enqueue_task(SUMMARY, b"root", OcwTaskPriority::Normal, 1);
+        lease::<u32>(SUMMARY, b"root", 5, LEASE_BLOCKS).unwrap();
+
+        assert_eq!(release::<u32>(SUMMARY, b"root"), Ok(()));
+
+        assert!(lease::<u32>(SUMMARY, b"root", 5, LEASE_BLOCKS).is_ok());
+    });
+}
+
+#[test]
+fn completed_tasks_are_removed() {
+    offchain_externality().execute_with(|| {
+        enqueue_task(SUMMARY, b"root", OcwTaskPriority::Normal, 1);
+        enqueue_task(BRIDGE, b"root", OcwTaskPriority::Normal, 1);
+
+        assert_eq!(complete::<u32>(SUMMARY, b"root"), Ok(()));
+
+        assert_eq!(queued_tasks::<u32>(Some(SUMMARY)), vec![]);
+        assert_eq!(queued_tasks::<u32>(Some(BRIDGE)).len(), 1);
+        assert_eq!(complete::<u32>(SUMMARY, b"root"), Err(OcwTaskQueueError::TaskNotFound));
+    });
+}
+
+#[test]
+fn unknown_tasks_cannot_be_leased() {
+    offchain_externality().execute_with(|| {
+        assert_eq!(
+            lease::<u32>(SUMMARY, b"root", 5, LEASE_BLOCKS),
+            Err(OcwTaskQueueError::TaskNotFound)
+        );
+    });
+}
@@ -0,0 +1,202 @@
+// Copyright 2024 Aventus Systems (UK) Ltd.
+#[cfg(test)]
+use super::*;
+use sha3::{Digest, Keccak256};
+use sp_std::vec::Vec;
+
+const AMOUNT: u128 = 1_000_000;
+
+fn data(amount: u128) -> Option<Vec<u8>> {
+    let mut data = vec![0u8; 16];
+    data.extend_from_slice(&amount.to_be_bytes());
+    Some(data)
+}
+
+mod sft_minted {
+    use super::*;
+
+    fn topics() -> Vec<Vec<u8>> {
+        vec![vec![1; 32], vec![2; 32], vec![3; 32]]
+    }
+
+    #[test]
+    fn event_signature_should_match() {
+        let mut hasher = Keccak256::new();
+
+        hasher.input(b"AvnMintSemiFungibleTo(uint256,bytes32,uint256)");
+        let result = hasher.result();
+
+        assert_eq!(result[..], *ValidEvents::SftMint.signature().as_bytes());
+        assert_eq!(
+            ValidEvents::try_from(&ValidEvents::SftMint.signature()),
+            Some(ValidEvents::SftMint)
+        );
+        assert!(ValidEvents::SftMint.is_nft_event());
+    }
+
+    #[test]
+    fn can_successfully_be_parsed() {
+        let result = SftMintData::parse_bytes(data(AMOUNT), topics()).unwrap();
+
+        assert_eq!(result.sft_id, U256::from([2u8; 32]));
+        assert_eq!(result.t2_owner_public_key, H256::from([3u8; 32]));
+        assert_eq!(result.amount, AMOUNT);
+        assert!(result.is_valid());
+    }
+
+    #[test]
+    fn is_not_valid_without_an_amount() {
+        let result = SftMintData::parse_bytes(data(0), topics()).unwrap();
+
+        assert!(!result.is_valid());
+    }
+
+    mod fails_parsing_when {
+        use super::*;
+
+        #[test]
+        fn data_is_missing() {
+            assert_eq!(
+                SftMintData::parse_bytes(None, topics()),
+                Err(Error::SftMintedEventMissingData)
+            );
+        }
+
+        #[test]
+        fn data_has_the_wrong_length() {
+            assert_eq!(
+                SftMintData::parse_bytes(Some(vec![1u8; 16]), topics()),
+                Err(Error::SftMintedEventBadDataLength)
+            );
+        }
+
+        #[test]
+        fn event_contains_few_topics() {
+            assert_eq!(
+                SftMintData::parse_bytes(data(AMOUNT), vec![vec![1; 32], vec![2; 32]]),
+                Err(Error::SftMintedEventWrongTopicCount)
+            );
+        }
+
+        #[test]
+        fn event_contains_short_topic() {
+            assert_eq!(
+                SftMintData::parse_bytes(data(AMOUNT), vec![vec![1; 32], vec![2; 32], vec![3; 16]]),
+                Err(Error::SftMintedEventBadTopicLength)
+            );
+        }
+
+        #[test]
+        fn amount_overflows() {
+            let mut data = data(AMOUNT).unwrap();
+            data[0] = 1;
+
+            assert_eq!(
+                SftMintData::parse_bytes(Some(data), topics()),
+                Err(Error::SftMintedEventDataOverflow)
+            );
+        }
+    }
+}
+
+mod sft_transfer_to {
+    use super::*;
+
+    const LISTING_ID: u64 = 7;
+
+    fn listing_id_topic(listing_id: u64) -> Vec<u8> {
+        let mut topic = vec![0u8; 24];
+        topic.extend_from_slice(&listing_id.to_be_bytes());
+        topic
+    }
+
+    fn topics() -> Vec<Vec<u8>> {
+        vec![vec![1; 32], listing_id_topic(LISTING_ID), vec![3; 32]]
+    }
+
+    #[test]
+    fn event_signature_should_match() {
+        let mut hasher = Keccak256::new();
+
+        hasher.input(b"AvnSemiFungibleTransferTo(uint64,bytes32,uint256)");
+        let result = hasher.result();
+
+        assert_eq!(result[..], *ValidEvents::SftTransferTo.signature().as_bytes());
+        assert_eq!(
+            ValidEvents::try_from(&ValidEvents::SftTransferTo.signature()),
+            Some(ValidEvents::SftTransferTo)
+        );
+        assert!(ValidEvents::SftTransferTo.is_nft_event());
+    }
+
+    #[test]
+    fn can_successfully_be_parsed() {
+        let result = SftTransferToData::parse_bytes(data(AMOUNT), topics()).unwrap();
+
+        assert_eq!(result.listing_id, LISTING_ID);
+        assert_eq!(result.t2_transfer_to_public_key, H256::from([3u8; 32]));
+        assert_eq!(result.amount, AMOUNT);
+        assert!(result.is_valid());
+    }
+
+    mod fails_parsing_when {
+        use super::*;
+
+        #[test]
+        fn data_is_missing() {
+            assert_eq!(
+                SftTransferToData::parse_bytes(None, topics()),
+                Err(Error::SftTransferToEventMissingData)
+            );
+        }
+
+        #[test]
+        fn data_has_the_wrong_length() {
+            assert_eq!(
+                SftTransferToData::parse_bytes(Some(vec![1u8; 16]), topics()),
+                Err(Error::SftTransferToEventBadDataLength)
+            );
+        }
+
+        #[test]
+        fn event_contains_few_topics() {
+            assert_eq!(
+                SftTransferToData::parse_bytes(data(AMOUNT), vec![vec![1; 32], vec![3; 32]]),
+                Err(Error::SftTransferToEventWrongTopicCount)
+            );
+        }
+
+        #[test]
+        fn event_contains_short_topic() {
+            assert_eq!(
+                SftTransferToData::parse_bytes(
+                    data(AMOUNT),
+                    vec![vec![1; 32], listing_id_topic(LISTING_ID), vec![3; 16]]
+                ),
+                Err(Error::SftTransferToEventBadTopicLength)
+            );
+        }
+
+        #[test]
+        fn listing_id_overflows() {
+            let mut topics = topics();
+            topics[1][0] = 1;
+
+            assert_eq!(
+                SftTransferToData::parse_bytes(data(AMOUNT), topics),
+                Err(Error::SftTransferToEventDataOverflow)
+            );
+        }
+
+        #[test]
+        fn amount_overflows() {
+            let mut data = data(AMOUNT).unwrap();
+            data[0] = 1;
+
+            assert_eq!(
+                SftTransferToData::parse_bytes(Some(data), topics()),
+                Err(Error::SftTransferToEventDataOverflow)
+            );
+        }
+    }
+}
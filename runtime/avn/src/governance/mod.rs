@@ -1,15 +1,67 @@
 pub use super::*;
 
 pub mod origins;
-use frame_support::traits::EitherOf;
+use frame_support::traits::{
+    EitherOf, EitherOfDiverse, EnsureOrigin, EnsureOriginWithArg, SortedMembers,
+};
+use frame_system::EnsureSignedBy;
 pub use origins::{
-    pallet_custom_origins, ReferendumCanceller, ReferendumKiller, WhitelistedCaller,
+    pallet_custom_origins, GeneralAdmin, ReferendumCanceller, ReferendumKiller, WhitelistedCaller,
 };
+use pallet_parameters::ParameterKey;
 
 pub mod tracks;
 use pallet_token_manager;
 pub use tracks::TracksInfo;
 
+/// Admin calls of the AvN pallets can be dispatched by root, by a general admin referendum or by
+/// the admin multisig account, if one is configured
+pub type AdminOrigin = EitherOfDiverse<
+    EitherOf<EnsureRoot<AccountId>, GeneralAdmin>,
+    EnsureSignedBy<AdminMultisig, AccountId>,
+>;
+
+parameter_types! {
+    /// The multisig account holding admin custody. It is derived from the signatories and the
+    /// threshold with `Multisig::multi_account_id` and set by root via `system::set_storage`.
+    pub storage AdminMultisigAccount: Option<AccountId> = None;
+}
+
+pub struct AdminMultisig;
+impl SortedMembers<AccountId> for AdminMultisig {
+    fn sorted_members() -> Vec<AccountId> {
+        AdminMultisigAccount::get().into_iter().collect()
+    }
+}
+
+/// The bridge security parameters can only be changed by root. The admin origin can change the
+/// other parameters.
+pub struct ParametersAdminOrigin;
+impl EnsureOriginWithArg<RuntimeOrigin, ParameterKey> for ParametersAdminOrigin {
+    type Success = ();
+
+    fn try_origin(o: RuntimeOrigin, key: &ParameterKey) -> Result<Self::Success, RuntimeOrigin> {
+        match key {
+            ParameterKey::MinEthBlockConfirmation => EnsureRoot::<AccountId>::try_origin(o),
+            ParameterKey::MinBlocksPerEra |
+            ParameterKey::RewardPaymentDelay |
+            ParameterKey::AdvanceSlotGracePeriod |
+            ParameterKey::StakingPotFeeShare |
+            ParameterKey::MaxOffendersPerBlock => AdminOrigin::try_origin(o).map(|_| ()),
+        }
+    }
+
+    #[cfg(feature = "runtime-benchmarks")]
+    fn try_successful_origin(_key: &ParameterKey) -> Result<RuntimeOrigin, ()> {
+        Ok(RuntimeOrigin::root())
+    }
+}
+
+parameter_types! {
+    /// Number of blocks between the announcement of a sensitive admin change and its execution
+    pub const AdminChangeDelay: BlockNumber = 1 * DAYS;
+}
+
 parameter_types! {
     pub const VoteLockingPeriod: BlockNumber = 28 * DAYS;
 }
@@ -47,6 +99,36 @@ where
     }
 }
 
+parameter_types! {
+    pub const ProposalBond: Permill = Permill::from_percent(5);
+    pub const ProposalBondMinimum: Balance = 100 * AVT;
+    pub ProposalBondMaximum: Option<Balance> = Some(1_000 * AVT);
+    pub const SpendPeriod: BlockNumber = 7 * DAYS;
+    pub const MaxApprovals: u32 = 100;
+    pub const MaxTreasurySpend: Balance = Balance::MAX;
+}
+
+/// The treasury shares its pot with the token manager, which deposits the treasury share of
+/// growth and the collator payout dust into it. Spends are approved by governance.
+impl pallet_treasury::Config for Runtime {
+    type PalletId = AvnTreasuryPotId;
+    type Currency = Balances;
+    type ApproveOrigin = AdminOrigin;
+    type RejectOrigin = AdminOrigin;
+    type RuntimeEvent = RuntimeEvent;
+    type OnSlash = Treasury;
+    type ProposalBond = ProposalBond;
+    type ProposalBondMinimum = ProposalBondMinimum;
+    type ProposalBondMaximum = ProposalBondMaximum;
+    type SpendPeriod = SpendPeriod;
+    type Burn = ();
+    type BurnDestination = ();
+    type SpendFunds = ();
+    type WeightInfo = pallet_treasury::weights::SubstrateWeight<Runtime>;
+    type MaxApprovals = MaxApprovals;
+    type SpendOrigin = frame_system::EnsureRootWithSuccess<AccountId, MaxTreasurySpend>;
+}
+
 impl pallet_whitelist::Config for Runtime {
     type WeightInfo = pallet_whitelist::weights::SubstrateWeight<Runtime>;
     type RuntimeCall = RuntimeCall;
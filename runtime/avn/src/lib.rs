@@ -17,9 +17,9 @@ use scale_info::TypeInfo;
 
 use sp_runtime::RuntimeAppPublic;
 
-use cumulus_pallet_parachain_system::RelayNumberStrictlyIncreases;
+use cumulus_pallet_parachain_system::RelayNumberMonotonicallyIncreases;
 use sp_api::impl_runtime_apis;
-use sp_core::{crypto::KeyTypeId, ConstU128, OpaqueMetadata, H160};
+use sp_core::{crypto::KeyTypeId, ConstU128, OpaqueMetadata, H160, H256, U256};
 use sp_runtime::{
     create_runtime_str, generic, impl_opaque_keys,
     traits::{AccountIdLookup, BlakeTwo256, Block as BlockT, ConvertInto},
@@ -37,8 +37,8 @@ use frame_support::{
     dispatch::DispatchClass,
     parameter_types,
     traits::{
-        AsEnsureOriginWithArg, ConstBool, ConstU32, ConstU64, Contains, Currency, Imbalance,
-        OnUnbalanced, PrivilegeCmp,
+        AsEnsureOriginWithArg, ConstBool, ConstU32, ConstU64, Contains, Currency, Get, Imbalance,
+        OnUnbalanced, PrivilegeCmp, WithdrawReasons,
     },
     weights::{constants::WEIGHT_REF_TIME_PER_SECOND, ConstantMultiplier, Weight},
     PalletId,
@@ -48,7 +48,7 @@ pub use frame_system::{
     EnsureRoot, EnsureSigned, Event as SystemEvent, EventRecord, Phase,
 };
 use governance::pallet_custom_origins;
-use proxy_config::AvnProxyConfig;
+use proxy_config::{AvnProxyConfig, EthereumCallFilter};
 pub use sp_consensus_aura::sr25519::AuthorityId as AuraId;
 pub use sp_runtime::{MultiAddress, Perbill, Permill, RuntimeDebug};
 use xcm_config::{XcmConfig, XcmOriginToTransactDispatchOrigin};
@@ -68,10 +68,11 @@ use sp_authority_discovery::AuthorityId as AuthorityDiscoveryId;
 
 use pallet_avn::sr25519::AuthorityId as AvnId;
 
-pub use pallet_avn_proxy::{Event as AvnProxyEvent, ProvableProxy};
+pub use pallet_avn_proxy::{Event as AvnProxyEvent, FeeCategory, ProvableProxy, SpendableAsset};
 use pallet_avn_transaction_payment::AvnCurrencyAdapter;
 use sp_avn_common::{
     event_discovery::{EthBlockRange, EthereumEventsPartition},
+    event_types::{EthEventId, EthEventStatus},
     InnerCallValidator, Proof,
 };
 
@@ -80,26 +81,19 @@ pub type NegativeImbalance<T> = <pallet_balances::Pallet<T> as Currency<
     <T as frame_system::Config>::AccountId,
 >>::NegativeImbalance;
 
-/// Logic for the staking pot to get all the fees, including tips.
-pub struct ToStakingPot<R>(sp_std::marker::PhantomData<R>);
-impl<R> OnUnbalanced<NegativeImbalance<R>> for ToStakingPot<R>
-where
-    R: pallet_balances::Config + pallet_parachain_staking::Config,
-    <R as frame_system::Config>::AccountId: From<AccountId>,
-    <R as frame_system::Config>::AccountId: Into<AccountId>,
-    <R as frame_system::Config>::RuntimeEvent: From<pallet_balances::Event<R>>,
-{
-    fn on_nonzero_unbalanced(amount: NegativeImbalance<R>) {
-        let staking_pot_address =
-            <pallet_parachain_staking::Pallet<R>>::compute_reward_pot_account_id();
-        <pallet_balances::Pallet<R>>::resolve_creating(&staking_pot_address, amount);
-    }
+parameter_types! {
+    /// The share of transaction fees and tips sent to the staking pot until it is changed with
+    /// the parameters pallet. The rest goes to the treasury.
+    pub const DefaultStakingPotFeeShare: Perbill = Perbill::from_percent(90);
 }
 
 pub struct DealWithFees<R>(sp_std::marker::PhantomData<R>);
 impl<R> OnUnbalanced<NegativeImbalance<R>> for DealWithFees<R>
 where
-    R: pallet_balances::Config + pallet_parachain_staking::Config,
+    R: pallet_balances::Config
+        + pallet_parameters::Config
+        + pallet_parachain_staking::Config<Currency = pallet_balances::Pallet<R>>
+        + pallet_treasury::Config<Currency = pallet_balances::Pallet<R>>,
     <R as frame_system::Config>::AccountId: From<AccountId>,
     <R as frame_system::Config>::AccountId: Into<AccountId>,
     <R as frame_system::Config>::RuntimeEvent: From<pallet_balances::Event<R>>,
@@ -109,7 +103,11 @@ where
             if let Some(tips) = fees_then_tips.next() {
                 tips.merge_into(&mut fees);
             }
-            <ToStakingPot<R> as OnUnbalanced<_>>::on_unbalanced(fees);
+            let staking_pot_amount =
+                pallet_parameters::StakingPotFeeShare::<R>::get() * fees.peek();
+            let (to_staking_pot, to_treasury) = fees.split(staking_pot_amount);
+            <pallet_parachain_staking::Pallet<R> as OnUnbalanced<_>>::on_unbalanced(to_staking_pot);
+            <pallet_treasury::Pallet<R> as OnUnbalanced<_>>::on_unbalanced(to_treasury);
         }
     }
 }
@@ -142,6 +140,7 @@ pub type SignedExtra = (
     frame_system::CheckNonce<Runtime>,
     frame_system::CheckWeight<Runtime>,
     pallet_transaction_payment::ChargeTransactionPayment<Runtime>,
+    runtime_common::CheckMetadataHash<Runtime>,
 );
 
 /// Unchecked extrinsic type as expected by this runtime.
@@ -155,9 +154,27 @@ pub type Executive = frame_executive::Executive<
     frame_system::ChainContext<Runtime>,
     Runtime,
     AllPalletsWithSystem,
-    pallet_eth_bridge::migration::SetBlockRangeSize<Runtime>,
+    Migrations,
 >;
 
+parameter_types! {
+    /// How many blocks are now produced in the time one block took before async backing
+    pub const BlockTimeScalingFactor: u32 =
+        (LEGACY_MILLISECS_PER_BLOCK / MILLISECS_PER_BLOCK) as u32;
+}
+
+/// Runtime migrations applied before the pallets' own upgrade hooks
+pub type Migrations = (
+    pallet_eth_bridge::migration::SetBlockRangeSize<Runtime>,
+    pallet_summary::migration::ScaleBlockPeriods<Runtime, EthSummary, BlockTimeScalingFactor>,
+    pallet_summary::migration::ScaleBlockPeriods<Runtime, AvnAnchorSummary, BlockTimeScalingFactor>,
+    pallet_ethereum_events::migration::ScaleEventChallengePeriod<Runtime, BlockTimeScalingFactor>,
+    pallet_ethereum_events::migration::AddEthBlockToCheckResults<Runtime>,
+    pallet_parachain_staking::migration::ScaleEraLength<Runtime, BlockTimeScalingFactor>,
+    pallet_eth_bridge::migration::PadLowerProofRequests<Runtime>,
+    pallet_token_manager::migration::AddMemoToLowerParams<Runtime>,
+);
+
 impl_opaque_keys! {
     pub struct SessionKeys {
         pub aura: Aura,
@@ -172,10 +189,10 @@ pub const VERSION: RuntimeVersion = RuntimeVersion {
     spec_name: create_runtime_str!("avn-parachain"),
     impl_name: create_runtime_str!("avn-parachain"),
     authoring_version: 1,
-    spec_version: 80,
+    spec_version: 81,
     impl_version: 0,
     apis: RUNTIME_API_VERSIONS,
-    transaction_version: 1,
+    transaction_version: 2,
     state_version: 1,
 };
 
@@ -184,8 +201,9 @@ pub const VERSION: RuntimeVersion = RuntimeVersion {
 /// `SLOT_DURATION` is picked up by `pallet_timestamp` which is in turn picked
 /// up by `pallet_aura` to implement `fn slot_duration()`.
 
-// NOTE: Currently it is not possible to change the slot duration after the chain has started.
-//       Attempting to do so will brick block production.
+// NOTE: The slot duration can only be decreased after the chain has started, increasing it will
+//       brick block production. Periods stored in blocks must be scaled when it changes, see
+//       `Migrations`.
 pub const SLOT_DURATION: u64 = MILLISECS_PER_BLOCK;
 
 /// The existential deposit. Set to 1/10 of the Connected Relay Chain.
@@ -199,9 +217,10 @@ const AVERAGE_ON_INITIALIZE_RATIO: Perbill = Perbill::from_percent(5);
 /// `Operational` extrinsics.
 const NORMAL_DISPATCH_RATIO: Perbill = Perbill::from_percent(75);
 
-/// We allow for 0.5 of a second of compute with a 12 second average block time.
+/// We allow for 2 seconds of compute with a 6 second average block time, as blocks can be built
+/// ahead of their inclusion in the relay chain with async backing.
 const MAXIMUM_BLOCK_WEIGHT: Weight = Weight::from_parts(
-    WEIGHT_REF_TIME_PER_SECOND.saturating_div(2),
+    WEIGHT_REF_TIME_PER_SECOND.saturating_mul(2),
     cumulus_primitives_core::relay_chain::MAX_POV_SIZE as u64,
 );
 
@@ -294,7 +313,7 @@ impl frame_system::Config for Runtime {
     /// What to do if a new account is created.
     type OnNewAccount = ();
     /// What to do if an account is fully reaped from the system.
-    type OnKilledAccount = ();
+    type OnKilledAccount = ParachainStaking;
     /// The weight of database operations that the runtime can invoke.
     type DbWeight = RocksDbWeight;
     /// The basic call filter to use in dispatchable.
@@ -370,9 +389,19 @@ impl cumulus_pallet_parachain_system::Config for Runtime {
     type ReservedDmpWeight = ReservedDmpWeight;
     type XcmpMessageHandler = XcmpQueue;
     type ReservedXcmpWeight = ReservedXcmpWeight;
-    type CheckAssociatedRelayNumber = RelayNumberStrictlyIncreases;
+    type CheckAssociatedRelayNumber = RelayNumberMonotonicallyIncreases;
+    type ConsensusHook = ConsensusHook;
 }
 
+/// Allows up to `UNINCLUDED_SEGMENT_CAPACITY` blocks to be built on top of the last block included
+/// in the relay chain, at most `BLOCK_PROCESSING_VELOCITY` of them per relay chain block.
+type ConsensusHook = cumulus_pallet_aura_ext::FixedVelocityConsensusHook<
+    Runtime,
+    RELAY_CHAIN_SLOT_DURATION_MILLIS,
+    BLOCK_PROCESSING_VELOCITY,
+    UNINCLUDED_SEGMENT_CAPACITY,
+>;
+
 impl parachain_info::Config for Runtime {}
 
 impl cumulus_pallet_aura_ext::Config for Runtime {}
@@ -418,7 +447,7 @@ impl pallet_aura::Config for Runtime {
     type AuthorityId = AuraId;
     type DisabledValidators = ();
     type MaxAuthorities = ConstU32<100_000>;
-    type AllowMultipleBlocksPerSlot = ConstBool<false>;
+    type AllowMultipleBlocksPerSlot = ConstBool<true>;
     #[cfg(feature = "experimental")]
     type SlotDuration = pallet_aura::MinimumPeriodTimesTwo<Self>;
 }
@@ -431,10 +460,9 @@ impl pallet_parachain_staking::Config for Runtime {
     type RuntimeCall = RuntimeCall;
     type RuntimeEvent = RuntimeEvent;
     type Currency = Balances;
-    /// Minimum era length is 4 minutes (20 * 12 second block times)
-    type MinBlocksPerEra = ConstU32<20>;
-    /// Eras before the reward is paid
-    type RewardPaymentDelay = ConstU32<2>;
+    type MinBlocksPerEra = pallet_parameters::MinBlocksPerEra<Runtime>;
+    type RewardPaymentDelay = pallet_parameters::RewardPaymentDelay<Runtime>;
+    type HistoryDepth = ConstU32<365>; // 365 eras (~ 1 year if era = 1 day)
     /// Minimum collators selected per era, default at genesis and minimum forever after
     type MinSelectedCandidates = ConstU32<20>;
     /// Maximum top nominations per candidate
@@ -452,11 +480,60 @@ impl pallet_parachain_staking::Config for Runtime {
     type Signature = Signature;
     type CollatorSessionRegistration = Session;
     type CollatorPayoutDustHandler = TokenManager;
+    type DisabledOffenderCheck = AvnOffenceHandler;
     type WeightInfo = pallet_parachain_staking::weights::SubstrateWeight<Runtime>;
     type MaxCandidates = ConstU32<100>;
     type AccountToBytesConvert = Avn;
     type BridgeInterface = EthBridge;
     type GrowthEnabled = ConstBool<true>;
+    type AdminOrigin = governance::AdminOrigin;
+    type Scheduler = Scheduler;
+    type Preimages = Preimage;
+    type PalletsOrigin = OriginCaller;
+    type AdminChangeDelay = governance::AdminChangeDelay;
+    type IdentityProvider = OperatorIdentityOf;
+    type RewardPotTopUp = TreasuryRewardPotTopUp;
+    type MaxNominationRequestCleanupsPerBlock = ConstU32<20>;
+}
+
+/// Exposes the on-chain identity of collator operators to the staking pallet
+pub struct OperatorIdentityOf;
+impl pallet_parachain_staking::OperatorIdentityProvider<AccountId> for OperatorIdentityOf {
+    fn identity(who: &AccountId) -> Option<pallet_parachain_staking::OperatorIdentity> {
+        let registration = Identity::identity(who)?;
+        let display_name = match registration.info.display {
+            pallet_identity::Data::Raw(name) => name.into_inner(),
+            _ => Vec::new(),
+        };
+        let verified = registration.judgements.iter().any(|(_, judgement)| {
+            matches!(
+                judgement,
+                pallet_identity::Judgement::Reasonable | pallet_identity::Judgement::KnownGood
+            )
+        });
+
+        Some(pallet_parachain_staking::OperatorIdentity { display_name, verified })
+    }
+}
+
+/// Tops up the staking reward pot from the treasury, keeping the treasury account alive
+pub struct TreasuryRewardPotTopUp;
+impl pallet_parachain_staking::RewardPotTopUp<AccountId, Balance> for TreasuryRewardPotTopUp {
+    fn top_up(reward_pot: &AccountId, amount: Balance) -> Balance {
+        let treasury = Treasury::account_id();
+        let available = Balances::free_balance(&treasury).saturating_sub(ExistentialDeposit::get());
+        let amount = amount.min(available);
+
+        match <Balances as Currency<AccountId>>::transfer(
+            &treasury,
+            reward_pot,
+            amount,
+            frame_support::traits::ExistenceRequirement::KeepAlive,
+        ) {
+            Ok(_) => amount,
+            Err(_) => 0,
+        }
+    }
 }
 
 // Substrate pallets that AvN has dependency
@@ -496,7 +573,8 @@ impl pallet_im_online::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
     type NextSessionRotation = ParachainStaking;
     type ValidatorSet = Historical;
-    type ReportUnresponsiveness = Offences;
+    type ReportUnresponsiveness =
+        pallet_validators_manager::UnresponsivenessTracker<Runtime, Offences>;
     type UnsignedPriority = ImOnlineUnsignedPriority;
     type WeightInfo = pallet_im_online::weights::SubstrateWeight<Runtime>;
     type MaxKeys = MaxKeys;
@@ -516,10 +594,67 @@ impl pallet_utility::Config for Runtime {
     type WeightInfo = pallet_utility::weights::SubstrateWeight<Runtime>;
 }
 
+parameter_types! {
+    // One storage item; key size is 32 bytes, value size is 8 + 16 bytes.
+    pub const MultisigDepositBase: Balance = AVT + 88 * 10 * MILLI_AVT;
+    // Additional storage item size of 32 bytes.
+    pub const MultisigDepositFactor: Balance = 32 * 10 * MILLI_AVT;
+    pub const MaxSignatories: u32 = 100;
+}
+
+impl pallet_multisig::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type RuntimeCall = RuntimeCall;
+    type Currency = Balances;
+    type DepositBase = MultisigDepositBase;
+    type DepositFactor = MultisigDepositFactor;
+    type MaxSignatories = MaxSignatories;
+    type WeightInfo = pallet_multisig::weights::SubstrateWeight<Runtime>;
+}
+
+parameter_types! {
+    pub const BasicDeposit: Balance = 10 * AVT;
+    pub const FieldDeposit: Balance = 250 * MILLI_AVT;
+    pub const SubAccountDeposit: Balance = 2 * AVT;
+    pub const MaxSubAccounts: u32 = 100;
+    pub const MaxAdditionalFields: u32 = 100;
+    pub const MaxRegistrars: u32 = 20;
+}
+
+impl pallet_identity::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type Currency = Balances;
+    type BasicDeposit = BasicDeposit;
+    type FieldDeposit = FieldDeposit;
+    type SubAccountDeposit = SubAccountDeposit;
+    type MaxSubAccounts = MaxSubAccounts;
+    type MaxAdditionalFields = MaxAdditionalFields;
+    type MaxRegistrars = MaxRegistrars;
+    type Slashed = Treasury;
+    type ForceOrigin = governance::AdminOrigin;
+    type RegistrarOrigin = governance::AdminOrigin;
+    type WeightInfo = pallet_identity::weights::SubstrateWeight<Runtime>;
+}
+
 // AvN pallets
+parameter_types! {
+    pub const MaxSlashPublishAttempts: u32 = 3;
+}
+
 impl pallet_avn_offence_handler::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
     type Enforcer = ValidatorsManager;
+    type CheckpointProvider = ValidatorsManager;
+    type AdminOrigin = governance::AdminOrigin;
+    type RuntimeCall = RuntimeCall;
+    type Scheduler = Scheduler;
+    type Preimages = Preimage;
+    type PalletsOrigin = OriginCaller;
+    type AdminChangeDelay = governance::AdminChangeDelay;
+    type MaxOffendersPerBlock = pallet_parameters::MaxOffendersPerBlock<Runtime>;
+    type BridgeInterface = EthBridge;
+    type MaxSlashPublishAttempts = MaxSlashPublishAttempts;
+    type Chiller = ParachainStaking;
     type WeightInfo = pallet_avn_offence_handler::default_weights::SubstrateWeight<Runtime>;
 }
 
@@ -533,24 +668,59 @@ impl pallet_avn::Config for Runtime {
 }
 
 parameter_types! {
-    // TODO [TYPE: review][PRI: medium][JIRA: SYS-358]: Configurable in eth-events pallet?
     pub const MinEthBlockConfirmation: u64 = 20;
+    pub const ProcessedEventsArchivePeriod: BlockNumber = 7 * DAYS;
+    pub const ProcessedEventsRetention: u32 = 52;
+    pub const ArchivedEventIdsRetention: u32 = 52;
+    pub const OffenceEvidenceRetention: BlockNumber = 28 * DAYS;
+    pub const UnsignedSubmissionWindow: BlockNumber = 10;
+    // The offchain worker of a validator sends at most one transaction of each type per block, so
+    // the allowance is twice that
+    pub const MaxUnsignedEthereumEventsSubmissions: u32 = 20;
+    pub const UnsignedAbuseThreshold: u32 = 3;
 }
 
 impl pallet_ethereum_events::Config for Runtime {
     type RuntimeCall = RuntimeCall;
     type RuntimeEvent = RuntimeEvent;
     type ProcessedEventHandler = (TokenManager, NftManager);
-    type MinEthBlockConfirmation = MinEthBlockConfirmation;
+    type MinEthBlockConfirmation = pallet_parameters::MinEthBlockConfirmation<Runtime>;
     type Public = <Signature as sp_runtime::traits::Verify>::Signer;
     type Signature = Signature;
     type ReportInvalidEthereumLog = Offences;
     type WeightInfo = pallet_ethereum_events::default_weights::SubstrateWeight<Runtime>;
     type EthereumEventsFilter = EthBridgeAvnRuntimeEventsFilter;
+    type AdminOrigin = governance::AdminOrigin;
+    type ArchivePeriod = ProcessedEventsArchivePeriod;
+    type ProcessedEventsRetention = ProcessedEventsRetention;
+    type ArchivedEventIdsRetention = ArchivedEventIdsRetention;
+    type OffenceEvidenceRetention = OffenceEvidenceRetention;
+    type ValidatorStake = ParachainStaking;
+    type UnsignedSubmissionWindow = UnsignedSubmissionWindow;
+    type MaxUnsignedSubmissions = MaxUnsignedEthereumEventsSubmissions;
+    type UnsignedAbuseThreshold = UnsignedAbuseThreshold;
+}
+
+impl pallet_parameters::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type AdminOrigin = governance::ParametersAdminOrigin;
+    /// Minimum era length is 4 minutes
+    type DefaultMinBlocksPerEra = ConstU32<{ 4 * MINUTES }>;
+    /// Eras before the reward is paid
+    type DefaultRewardPaymentDelay = ConstU32<2>;
+    type DefaultAdvanceSlotGracePeriod = AdvanceSlotGracePeriod;
+    type DefaultMinEthBlockConfirmation = MinEthBlockConfirmation;
+    type DefaultStakingPotFeeShare = DefaultStakingPotFeeShare;
+    type DefaultMaxOffendersPerBlock = ConstU32<10>;
+    type WeightInfo = pallet_parameters::default_weights::SubstrateWeight<Runtime>;
 }
 
 parameter_types! {
     pub const ValidatorManagerVotingPeriod: BlockNumber = 30 * MINUTES;
+    pub const MaxConsecutiveMissedHeartbeats: u32 = 4;
+    pub const UnresponsiveValidatorRemovalDelay: u32 = 4;
+    pub const ValidatorSetCheckpointPeriod: u32 = 24;
+    pub const MaxCheckpointPublishAttempts: u32 = 3;
 }
 
 impl pallet_validators_manager::Config for Runtime {
@@ -561,20 +731,39 @@ impl pallet_validators_manager::Config for Runtime {
     type ValidatorRegistrationNotifier = AvnOffenceHandler;
     type WeightInfo = pallet_validators_manager::default_weights::SubstrateWeight<Runtime>;
     type BridgeInterface = EthBridge;
+    type MaxConsecutiveMissedHeartbeats = MaxConsecutiveMissedHeartbeats;
+    type UnresponsiveValidatorRemovalDelay = UnresponsiveValidatorRemovalDelay;
+    type CheckpointPeriod = ValidatorSetCheckpointPeriod;
+    type MaxCheckpointPublishAttempts = MaxCheckpointPublishAttempts;
 }
 
 parameter_types! {
-    pub const AdvanceSlotGracePeriod: BlockNumber = 5;
-    pub const MinBlockAge: BlockNumber = 5;
+    // Both were 5 blocks with 12 second blocks, and keep their duration with async backing
+    pub const AdvanceSlotGracePeriod: BlockNumber = 1 * MINUTES;
+    pub const MinBlockAge: BlockNumber = 1 * MINUTES;
     pub const AvnTreasuryPotId: PalletId = PalletId(*b"Treasury");
     pub const TreasuryGrowthPercentage: Perbill = Perbill::from_percent(75);
     pub const EthAutoSubmitSummaries: bool = true;
     pub const EthereumInstanceId: u8 = 1u8;
+    pub const AvnAutoSubmitSummaries: bool = false;
+    pub const AvnInstanceId: u8 = 2u8;
+    pub const EthMinSchedulePeriod: BlockNumber = 12 * MINUTES;
+    pub const EthMinVotingPeriod: BlockNumber = 10 * MINUTES;
+    // Roots anchored on the AvN are cheap to publish, so its ranges can be much shorter
+    pub const AvnMinSchedulePeriod: BlockNumber = 2 * MINUTES;
+    pub const AvnMinVotingPeriod: BlockNumber = 1 * MINUTES;
+    pub const MaxSummarySchedulePeriod: BlockNumber = 7 * DAYS;
+    pub const MaxSummaryVotingPeriod: BlockNumber = 2 * DAYS;
+    // A validator records at most one summary and advances at most one slot per slot, and votes
+    // once on each root, so these limits are only reached by a faulty validator
+    pub const MaxUnsignedSummarySubmissions: u32 = 5;
+    pub const MaxUnsignedSummaryVotes: u32 = 20;
 }
 
-impl pallet_summary::Config for Runtime {
+pub type EthSummary = pallet_summary::Instance1;
+impl pallet_summary::Config<EthSummary> for Runtime {
     type RuntimeEvent = RuntimeEvent;
-    type AdvanceSlotGracePeriod = AdvanceSlotGracePeriod;
+    type AdvanceSlotGracePeriod = pallet_parameters::AdvanceSlotGracePeriod<Runtime>;
     type MinBlockAge = MinBlockAge;
     type AccountToBytesConvert = Avn;
     type ReportSummaryOffence = Offences;
@@ -582,6 +771,51 @@ impl pallet_summary::Config for Runtime {
     type BridgeInterface = EthBridge;
     type AutoSubmitSummaries = EthAutoSubmitSummaries;
     type InstanceId = EthereumInstanceId;
+    type AdminOrigin = governance::AdminOrigin;
+    type RuntimeCall = RuntimeCall;
+    type Scheduler = Scheduler;
+    type Preimages = Preimage;
+    type PalletsOrigin = OriginCaller;
+    type AdminChangeDelay = governance::AdminChangeDelay;
+    type MinSchedulePeriod = EthMinSchedulePeriod;
+    type MaxSchedulePeriod = MaxSummarySchedulePeriod;
+    type MinVotingPeriod = EthMinVotingPeriod;
+    type MaxVotingPeriod = MaxSummaryVotingPeriod;
+    type OffenceEvidenceRetention = OffenceEvidenceRetention;
+    type VotingParticipationHandler = ParachainStaking;
+    type UnsignedSubmissionWindow = UnsignedSubmissionWindow;
+    type MaxUnsignedSubmissions = MaxUnsignedSummarySubmissions;
+    type MaxUnsignedVotes = MaxUnsignedSummaryVotes;
+    type UnsignedAbuseThreshold = UnsignedAbuseThreshold;
+}
+
+pub type AvnAnchorSummary = pallet_summary::Instance2;
+impl pallet_summary::Config<AvnAnchorSummary> for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type AdvanceSlotGracePeriod = pallet_parameters::AdvanceSlotGracePeriod<Runtime>;
+    type MinBlockAge = MinBlockAge;
+    type AccountToBytesConvert = Avn;
+    type ReportSummaryOffence = Offences;
+    type WeightInfo = pallet_summary::default_weights::SubstrateWeight<Runtime>;
+    type BridgeInterface = EthBridge;
+    type AutoSubmitSummaries = AvnAutoSubmitSummaries;
+    type InstanceId = AvnInstanceId;
+    type AdminOrigin = governance::AdminOrigin;
+    type RuntimeCall = RuntimeCall;
+    type Scheduler = Scheduler;
+    type Preimages = Preimage;
+    type PalletsOrigin = OriginCaller;
+    type AdminChangeDelay = governance::AdminChangeDelay;
+    type MinSchedulePeriod = AvnMinSchedulePeriod;
+    type MaxSchedulePeriod = MaxSummarySchedulePeriod;
+    type MinVotingPeriod = AvnMinVotingPeriod;
+    type MaxVotingPeriod = MaxSummaryVotingPeriod;
+    type OffenceEvidenceRetention = OffenceEvidenceRetention;
+    type VotingParticipationHandler = ParachainStaking;
+    type UnsignedSubmissionWindow = UnsignedSubmissionWindow;
+    type MaxUnsignedSubmissions = MaxUnsignedSummarySubmissions;
+    type MaxUnsignedVotes = MaxUnsignedSummaryVotes;
+    type UnsignedAbuseThreshold = UnsignedAbuseThreshold;
 }
 
 pub type EthAddress = H160;
@@ -603,6 +837,30 @@ impl pallet_token_manager::pallet::Config for Runtime {
     type Preimages = Preimage;
     type PalletsOrigin = OriginCaller;
     type BridgeInterface = EthBridge;
+    type Vesting = Vesting;
+    type LiftDestinations = ();
+}
+
+parameter_types! {
+    pub const MinVestedTransfer: Balance = AVT;
+    pub UnvestedFundsAllowedWithdrawReasons: WithdrawReasons =
+        WithdrawReasons::except(WithdrawReasons::TRANSFER | WithdrawReasons::RESERVE);
+}
+
+impl pallet_vesting::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type Currency = Balances;
+    type BlockNumberToBalance = ConvertInto;
+    type MinVestedTransfer = MinVestedTransfer;
+    type WeightInfo = pallet_vesting::weights::SubstrateWeight<Runtime>;
+    type UnvestedFundsAllowedWithdrawReasons = UnvestedFundsAllowedWithdrawReasons;
+    const MAX_VESTING_SCHEDULES: u32 = 28;
+}
+
+parameter_types! {
+    pub const MinAuctionDuration: BlockNumber = 1 * HOURS;
+    pub const MaxAuctionDuration: BlockNumber = 30 * DAYS;
+    pub const MaxAuctionsEndingPerBlock: u32 = 50;
 }
 
 impl pallet_nft_manager::Config for Runtime {
@@ -613,6 +871,18 @@ impl pallet_nft_manager::Config for Runtime {
     type Signature = Signature;
     type BatchBound = pallet_nft_manager::BatchNftBound;
     type WeightInfo = pallet_nft_manager::default_weights::SubstrateWeight<Runtime>;
+    type Currency = Balances;
+    type EthereumAddressMapping = Avn;
+    type MinAuctionDuration = MinAuctionDuration;
+    type MaxAuctionDuration = MaxAuctionDuration;
+    type MaxAuctionsEndingPerBlock = MaxAuctionsEndingPerBlock;
+    type BridgeInterface = EthBridge;
+    type NftContractChecker = EthereumEvents;
+    type AdminOrigin = governance::AdminOrigin;
+}
+
+parameter_types! {
+    pub const MaxSessionKeyDuration: BlockNumber = 7 * DAYS;
 }
 
 impl pallet_avn_proxy::Config for Runtime {
@@ -625,12 +895,23 @@ impl pallet_avn_proxy::Config for Runtime {
     type WeightInfo = pallet_avn_proxy::default_weights::SubstrateWeight<Runtime>;
     type FeeHandler = TokenManager;
     type Token = EthAddress;
+    type EthereumAddressMapping = Avn;
+    type EthereumCallFilter = EthereumCallFilter;
+    type AdminOrigin = governance::AdminOrigin;
+    type MaxSessionKeyDuration = MaxSessionKeyDuration;
+}
+
+parameter_types! {
+    pub AvnTreasuryAccount: AccountId = TokenManager::compute_treasury_account_id();
 }
 
 impl pallet_avn_transaction_payment::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
     type RuntimeCall = RuntimeCall;
     type Currency = Balances;
+    type AssetId = u32;
+    type Assets = Assets;
+    type FeeAssetCollector = AvnTreasuryAccount;
     type WeightInfo = pallet_avn_transaction_payment::default_weights::SubstrateWeight<Runtime>;
 }
 
@@ -655,8 +936,14 @@ use sp_std::collections::btree_set::BTreeSet;
 pub struct EthBridgeAvnRuntimeEventsFilter;
 impl EthereumEventsFilterTrait for EthBridgeAvnRuntimeEventsFilter {
     fn get() -> EthBridgeEventsFilter {
-        let allowed_events: BTreeSet<ValidEvents> =
-            vec![ValidEvents::AvtLowerClaimed, ValidEvents::Lifted].into_iter().collect();
+        let allowed_events: BTreeSet<ValidEvents> = vec![
+            ValidEvents::AvtLowerClaimed,
+            ValidEvents::Lifted,
+            ValidEvents::LiftedWithVesting,
+            ValidEvents::LiftedToDestination,
+        ]
+        .into_iter()
+        .collect();
 
         EthBridgeEventsFilter::try_from(allowed_events).unwrap_or_default()
     }
@@ -666,14 +953,16 @@ impl pallet_eth_bridge::Config for Runtime {
     type MaxQueuedTxRequests = ConstU32<100>;
     type RuntimeEvent = RuntimeEvent;
     type RuntimeCall = RuntimeCall;
-    type MinEthBlockConfirmation = MinEthBlockConfirmation;
+    type MinEthBlockConfirmation = pallet_parameters::MinEthBlockConfirmation<Runtime>;
     type ProcessedEventsChecker = EthereumEvents;
     type AccountToBytesConvert = Avn;
     type TimeProvider = pallet_timestamp::Pallet<Runtime>;
     type ReportCorroborationOffence = Offences;
     type WeightInfo = pallet_eth_bridge::default_weights::SubstrateWeight<Runtime>;
-    type BridgeInterfaceNotification = (Summary, TokenManager, ParachainStaking);
+    type BridgeInterfaceNotification =
+        (Summary, TokenManager, NftManager, ParachainStaking, ValidatorsManager, AvnOffenceHandler);
     type EthereumEventsFilter = EthBridgeAvnRuntimeEventsFilter;
+    type AdminOrigin = governance::AdminOrigin;
 }
 
 // Other pallets
@@ -785,6 +1074,7 @@ construct_runtime!(
         PolkadotXcm: pallet_xcm = 31,
         CumulusXcm: cumulus_pallet_xcm = 32,
         DmpQueue: cumulus_pallet_dmp_queue = 33,
+        AssetsBridge: pallet_assets_bridge = 34,
 
         // Substrate pallets
         Assets: pallet_assets = 60,
@@ -794,6 +1084,9 @@ construct_runtime!(
         Offences: pallet_offences = 72,
         ImOnline: pallet_im_online = 73,
         Utility: pallet_utility = 74,
+        Multisig: pallet_multisig = 75,
+        Vesting: pallet_vesting = 76,
+        Identity: pallet_identity = 77,
 
         // Rest of AvN pallets
         Avn: pallet_avn = 81,
@@ -801,11 +1094,13 @@ construct_runtime!(
         EthereumEvents: pallet_ethereum_events = 84,
         NftManager: pallet_nft_manager = 86,
         TokenManager: pallet_token_manager = 87,
-        Summary: pallet_summary = 88,
+        Summary: pallet_summary::<Instance1> = 88,
         AvnProxy: pallet_avn_proxy = 89,
         AvnTransactionPayment: pallet_avn_transaction_payment = 90,
         EthBridge: pallet_eth_bridge = 91,
         AvnAnchor: pallet_avn_anchor = 92,
+        Parameters: pallet_parameters = 93,
+        AnchorSummary: pallet_summary::<Instance2> = 110,
 
         // OpenGov pallets
         Preimage: pallet_preimage::{Pallet, Call, Storage, Event<T>} = 97,
@@ -813,7 +1108,8 @@ construct_runtime!(
         Origins: pallet_custom_origins::{Origin} = 99,
         ConvictionVoting: pallet_conviction_voting::{Pallet, Call, Storage, Event<T>} = 100,
         Referenda: pallet_referenda::{Pallet, Call, Storage, Event<T>} = 101,
-        Whitelist: pallet_whitelist::{Pallet, Call, Storage, Event<T>} = 102
+        Whitelist: pallet_whitelist::{Pallet, Call, Storage, Event<T>} = 102,
+        Treasury: pallet_treasury::{Pallet, Call, Storage, Config<T>, Event<T>} = 103
     }
 );
 
@@ -836,8 +1132,13 @@ mod benches {
         [pallet_session, SessionBench::<Runtime>]
         [pallet_timestamp, Timestamp]
         [pallet_utility, Utility]
+        [pallet_multisig, Multisig]
+        [pallet_vesting, Vesting]
+        [pallet_identity, Identity]
         [pallet_parachain_staking, ParachainStaking]
         [pallet_avn_anchor, AvnAnchor]
+        [pallet_parameters, Parameters]
+        [pallet_treasury, Treasury]
         [cumulus_pallet_xcmp_queue, XcmpQueue]
     );
 }
@@ -1033,6 +1334,206 @@ impl_runtime_apis! {
             EthBridge::submit_latest_ethereum_block_vote(author, latest_seen_block, signature.into()).ok()
         }
 
+        fn query_max_events_per_partition() -> u32 {
+            EthBridge::max_events_per_partition()
+        }
+
+        fn query_bridge_contracts() -> Vec<H160> {
+            Avn::bridge_contracts()
+        }
+
+    }
+
+    impl pallet_ethereum_events_runtime_api::EthEventStatusApi<Block, BlockNumber> for Runtime {
+        fn event_status(tx_hash: H256) -> Vec<(EthEventId, EthEventStatus<BlockNumber>)> {
+            let mut statuses = EthereumEvents::event_statuses(tx_hash);
+            for (event_id, votes) in EthBridge::discovered_events(tx_hash) {
+                if !statuses.iter().any(|(id, _)| id == &event_id) {
+                    statuses.push((event_id, EthEventStatus::Discovered { votes }));
+                }
+            }
+            statuses
+        }
+    }
+
+    impl pallet_avn_runtime_api::AvnConfigApi<Block> for Runtime {
+        fn bridge_config() -> pallet_avn_runtime_api::BridgeConfig {
+            pallet_avn_runtime_api::BridgeConfig {
+                bridge_contract: Avn::get_bridge_contract_address(),
+                nft_contracts: pallet_ethereum_events::NftT1Contracts::<Runtime>::iter_keys().collect(),
+                token_contracts: {
+                    let mut tokens = vec![TokenManager::avt_token_contract()];
+                    for token in Avn::bridge_contract_tokens() {
+                        if !tokens.contains(&token) {
+                            tokens.push(token);
+                        }
+                    }
+                    tokens
+                },
+                min_eth_block_confirmation:
+                    <Runtime as pallet_ethereum_events::Config>::MinEthBlockConfirmation::get(),
+                lowers_paused: TokenManager::lowers_disabled(),
+            }
+        }
+    }
+
+    impl pallet_avn_runtime_api::AvnEventsApi<Block> for Runtime {
+        fn next_event_sequence() -> u64 {
+            Avn::next_event_sequence()
+        }
+    }
+
+    impl pallet_nft_manager_runtime_api::NftManagerApi<Block, AccountId> for Runtime {
+        fn nfts_by_owner(
+            account: AccountId,
+            start_key: Option<U256>,
+            limit: u32,
+        ) -> Vec<pallet_nft_manager_runtime_api::NftSummary> {
+            NftManager::nfts_by_owner(&account, start_key, limit)
+                .into_iter()
+                .map(|nft| pallet_nft_manager_runtime_api::NftSummary {
+                    nft_id: nft.nft_id,
+                    info_id: nft.info_id,
+                    t1_authority: NftManager::nft_infos(nft.info_id)
+                        .map(|info| info.t1_authority)
+                        .unwrap_or_default(),
+                    unique_external_ref: nft.unique_external_ref.into_inner(),
+                    is_locked: nft.is_locked,
+                })
+                .collect()
+        }
+    }
+
+    impl pallet_avn_runtime_api::AvnEthereumAddressApi<Block, AccountId> for Runtime {
+        fn account_of(ethereum_address: H160) -> Option<AccountId> {
+            Avn::account_of_ethereum_address(ethereum_address)
+        }
+
+        fn ethereum_address_of(account: AccountId) -> Option<H160> {
+            Avn::ethereum_address_of_account(account)
+        }
+    }
+
+    impl pallet_avn_runtime_api::AvnProxyApi<Block, RuntimeCall, Proof<Signature, AccountId>, Balance> for Runtime {
+        fn validate_proof(
+            call: RuntimeCall,
+            proof: Proof<Signature, AccountId>,
+        ) -> Result<(), sp_runtime::DispatchError> {
+            AvnProxy::validate_proof(Box::new(call), proof)
+        }
+
+        fn relayer_fee(call: RuntimeCall, token: H160) -> Option<Balance> {
+            AvnProxy::scheduled_relayer_fee(&call, &token)
+        }
+    }
+
+    impl pallet_avn_runtime_api::AvnFeeApi<Block, Balance, u32> for Runtime {
+        fn estimate_fee(
+            encoded_extrinsic: Vec<u8>,
+        ) -> Option<pallet_avn_runtime_api::FeeBreakdown<Balance, u32>> {
+            use sp_runtime::traits::StaticLookup;
+            let uxt = <Block as BlockT>::Extrinsic::decode(&mut &encoded_extrinsic[..]).ok()?;
+            let len = encoded_extrinsic.len() as u32;
+            let weight = TransactionPayment::query_info(uxt.clone(), len).weight;
+            let fee_details = TransactionPayment::query_fee_details(uxt.clone(), len);
+            let fee = fee_details.final_fee();
+            let payer = uxt.signature.as_ref().and_then(|(address, _, _)| {
+                <Runtime as frame_system::Config>::Lookup::lookup(address.clone()).ok()
+            });
+            let adjusted_fee = payer
+                .as_ref()
+                .map(|payer| AvnTransactionPayment::adjusted_fee(payer, fee, fee_details.tip))
+                .unwrap_or(fee);
+            let fee_asset = payer.as_ref().and_then(|payer| {
+                AvnTransactionPayment::fee_in_payment_asset(payer, adjusted_fee)
+            });
+            let (base_fee, length_fee, weight_fee) = fee_details
+                .inclusion_fee
+                .map(|inclusion_fee| {
+                    (inclusion_fee.base_fee, inclusion_fee.len_fee, inclusion_fee.adjusted_weight_fee)
+                })
+                .unwrap_or_default();
+
+            Some(pallet_avn_runtime_api::FeeBreakdown {
+                weight_ref_time: weight.ref_time(),
+                weight_proof_size: weight.proof_size(),
+                base_fee,
+                length_fee,
+                weight_fee,
+                tip: fee_details.tip,
+                adjusted_fee,
+                fee_asset,
+                relayer_fee: AvnProxy::relayer_fee(&uxt.function)
+                    .map(|(token, amount)| (token.into(), amount)),
+            })
+        }
+    }
+
+    impl pallet_avn_runtime_api::AvnOcwApi<Block, AccountId, BlockNumber> for Runtime {
+        fn duties(
+            account: AccountId,
+            block: BlockNumber,
+        ) -> pallet_avn_runtime_api::OcwDuties {
+            pallet_avn_runtime_api::OcwDuties {
+                summary_slot_validator: Summary::is_slot_validator(&account) ||
+                    AnchorSummary::is_slot_validator(&account),
+                event_checker: EthereumEvents::has_events_to_check(&account, block),
+                pending_corroboration:
+                    pallet_eth_bridge::author_has_pending_corroboration::<Runtime>(&account),
+            }
+        }
+    }
+
+    impl pallet_summary_runtime_api::SummaryApi<Block, BlockNumber> for Runtime {
+        fn validated_root(block_number: BlockNumber) -> Option<pallet_summary::ValidatedRoot<BlockNumber>> {
+            Summary::validated_root_for_block(block_number)
+        }
+
+        fn root_range_anomalies() -> Vec<(pallet_summary::RootId<BlockNumber>, pallet_summary::RootRangeAnomaly<BlockNumber>)> {
+            Summary::root_range_anomalies()
+        }
+    }
+
+    impl pallet_parachain_staking_runtime_api::StakingInfoApi<Block, AccountId, Balance> for Runtime {
+        fn overview() -> pallet_parachain_staking::StakingOverview<Balance> {
+            ParachainStaking::staking_overview(365 * DAYS)
+        }
+
+        fn candidates() -> Vec<pallet_parachain_staking::CandidateOverview<AccountId, Balance>> {
+            ParachainStaking::candidates_overview()
+        }
+
+        fn reward_pot_reconciliation() -> pallet_parachain_staking::RewardPotReconciliation<Balance> {
+            ParachainStaking::reward_pot_reconciliation()
+        }
+
+        fn candidate_nominator_counts(
+            candidate: AccountId,
+        ) -> Option<pallet_parachain_staking::CandidateNominatorCounts> {
+            ParachainStaking::candidate_nominator_counts(&candidate)
+        }
+
+        fn rewards_history(
+            account: AccountId,
+            from_era: pallet_parachain_staking::EraIndex,
+            to_era: pallet_parachain_staking::EraIndex,
+        ) -> Vec<pallet_parachain_staking::RewardsHistoryEntry<AccountId, Balance>> {
+            ParachainStaking::rewards_history(&account, from_era, to_era)
+        }
+    }
+
+    impl pallet_parachain_staking_runtime_api::StakingGrowthApi<Block, Balance> for Runtime {
+        fn growth_status(
+            period: pallet_parachain_staking::GrowthPeriodIndex,
+        ) -> Option<pallet_parachain_staking::GrowthStatus<Balance>> {
+            ParachainStaking::growth_status(period)
+        }
+
+        fn payout_statement(
+            period: pallet_parachain_staking::GrowthPeriodIndex,
+        ) -> Option<pallet_parachain_staking::CollatorPayoutStatement<Balance>> {
+            ParachainStaking::payout_statement(period)
+        }
     }
 
     impl cumulus_primitives_core::CollectCollationInfo<Block> for Runtime {
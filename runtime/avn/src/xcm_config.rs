@@ -1,31 +1,43 @@
 use super::{
-    AccountId, AllPalletsWithSystem, Balances, ParachainInfo, ParachainSystem, PolkadotXcm,
-    Runtime, RuntimeCall, RuntimeEvent, RuntimeOrigin, WeightToFee, XcmpQueue,
+    AccountId, AllPalletsWithSystem, Assets, AvnTransactionPayment, AvnTreasuryAccount, Balance,
+    Balances, ParachainInfo, ParachainSystem, PolkadotXcm, Runtime, RuntimeCall, RuntimeEvent,
+    RuntimeOrigin, WeightToFee, XcmpQueue,
 };
 use frame_support::{
+    dispatch::DispatchResult,
     match_types, parameter_types,
-    traits::{ConstU32, Everything, Nothing},
-    weights::Weight,
+    traits::{fungibles, ConstU32, Contains, Everything, Nothing},
+    weights::{Weight, WeightToFee as WeightToFeeT},
 };
 use frame_system::EnsureRoot;
-use pallet_xcm::XcmPassthrough;
+use pallet_assets_bridge::{ForeignAssetIdConverter, RegisteredForeignReserve};
+use pallet_xcm::{WeightInfo as XcmWeightInfo, XcmPassthrough};
 use polkadot_parachain_primitives::primitives::Sibling;
 use polkadot_runtime_common::impls::ToAuthor;
+use runtime_common::constants::currency::MILLI_AVT;
+use sp_runtime::traits::MaybeEquivalence;
+use sp_std::{boxed::Box, vec::Vec};
 use xcm::latest::prelude::*;
 use xcm_builder::{
-    AccountId32Aliases, AllowExplicitUnpaidExecutionFrom, AllowTopLevelPaidExecutionFrom,
-    CurrencyAdapter, DenyReserveTransferToRelayChain, DenyThenTry, EnsureXcmOrigin,
-    FixedWeightBounds, IsConcrete, NativeAsset, ParentIsPreset, RelayChainAsNative,
+    AccountId32Aliases, AllowExplicitUnpaidExecutionFrom, AllowKnownQueryResponses,
+    AllowSubscriptionsFrom, AllowTopLevelPaidExecutionFrom, ConvertedConcreteId, CurrencyAdapter,
+    DenyReserveTransferToRelayChain, DenyThenTry, EnsureXcmOrigin, FixedWeightBounds,
+    FungiblesAdapter, IsConcrete, NativeAsset, NoChecking, ParentIsPreset, RelayChainAsNative,
     SiblingParachainAsNative, SiblingParachainConvertsVia, SignedAccountId32AsNative,
     SignedToAccountId32, SovereignSignedViaLocation, TakeWeightCredit, TrailingSetTopicAsId,
     UsingComponents, WithComputedOrigin, WithUniqueTopic,
 };
-use xcm_executor::XcmExecutor;
+use xcm_executor::{
+    traits::{JustTry, WeightTrader},
+    XcmExecutor,
+};
 
 parameter_types! {
     pub const RelayLocation: MultiLocation = MultiLocation::parent();
     pub const RelayNetwork: Option<NetworkId> = None;
     pub RelayChainOrigin: RuntimeOrigin = cumulus_pallet_xcm::Origin::Relay.into();
+    // AVT is the native token of this chain, so we are its reserve.
+    pub const SelfReserve: MultiLocation = MultiLocation::here();
     pub UniversalLocation: InteriorMultiLocation = Parachain(ParachainInfo::parachain_id().into()).into();
 }
 
@@ -46,7 +58,7 @@ pub type LocalAssetTransactor = CurrencyAdapter<
     // Use this currency:
     Balances,
     // Use this currency when it is a fungible asset matching the given location or name:
-    IsConcrete<RelayLocation>,
+    IsConcrete<SelfReserve>,
     // Do a simple punn to convert an AccountId32 MultiLocation into a native chain account ID:
     LocationToAccountId,
     // Our chain's account ID type (we can't get away without mentioning it explicitly):
@@ -55,6 +67,29 @@ pub type LocalAssetTransactor = CurrencyAdapter<
     (),
 >;
 
+parameter_types! {
+    pub CheckingAccount: AccountId = PolkadotXcm::check_account();
+}
+
+/// Means for transacting the foreign assets registered in the assets bridge.
+pub type ForeignAssetsTransactor = FungiblesAdapter<
+    // Use this fungibles implementation:
+    Assets,
+    // Use this matcher to convert a registered foreign asset location into a local asset id:
+    ConvertedConcreteId<u32, Balance, ForeignAssetIdConverter<Runtime>, JustTry>,
+    // Convert an XCM MultiLocation into a local account id:
+    LocationToAccountId,
+    // Our chain's account ID type (we can't get away without mentioning it explicitly):
+    AccountId,
+    // We don't track any teleports of foreign assets.
+    NoChecking,
+    // The account to use for tracking teleports.
+    CheckingAccount,
+>;
+
+/// Means for transacting assets on this chain: AVT and registered foreign assets.
+pub type AssetTransactors = (LocalAssetTransactor, ForeignAssetsTransactor);
+
 /// This is the type we use to convert an (incoming) XCM origin into a local `Origin` instance,
 /// ready for dispatching a transaction with Xcm's `Transact`. There is an `OriginKind` which can
 /// biases the kind of local `Origin` it will become.
@@ -100,6 +135,10 @@ pub type Barrier = TrailingSetTopicAsId<
                     AllowTopLevelPaidExecutionFrom<Everything>,
                     AllowExplicitUnpaidExecutionFrom<ParentOrParentsExecutivePlurality>,
                     // ^^^ Parent and its exec plurality get free execution
+                    // Expected responses are OK.
+                    AllowKnownQueryResponses<PolkadotXcm>,
+                    // Subscriptions for version tracking are OK.
+                    AllowSubscriptionsFrom<Everything>,
                 ),
                 UniversalLocation,
                 ConstU32<8>,
@@ -108,20 +147,101 @@ pub type Barrier = TrailingSetTopicAsId<
     >,
 >;
 
+/// Buys execution with a registered foreign asset that can be used to pay transaction fees, at the
+/// rate set in the transaction payment pallet. The fees collected are given to the AvN treasury.
+pub struct ForeignAssetFeeTrader {
+    weight: Weight,
+    // The location and local id of the asset used to pay, and the amount paid
+    paid: Option<(MultiLocation, u32, u128)>,
+}
+
+impl WeightTrader for ForeignAssetFeeTrader {
+    fn new() -> Self {
+        Self { weight: Weight::zero(), paid: None }
+    }
+
+    fn buy_weight(
+        &mut self,
+        weight: Weight,
+        payment: xcm_executor::Assets,
+        _context: &XcmContext,
+    ) -> Result<xcm_executor::Assets, XcmError> {
+        let (location, asset_id) = payment
+            .fungible_assets_iter()
+            .find_map(|asset| match asset.id {
+                Concrete(location) => ForeignAssetIdConverter::<Runtime>::convert(&location)
+                    .map(|asset_id| (location, asset_id)),
+                _ => None,
+            })
+            .ok_or(XcmError::AssetNotFound)?;
+
+        let already_paid = match self.paid {
+            Some((paid_location, _, amount)) if paid_location == location => amount,
+            Some(_) => return Err(XcmError::NotWithdrawable),
+            None => 0,
+        };
+
+        let fee =
+            AvnTransactionPayment::fee_in_asset(asset_id, WeightToFee::weight_to_fee(&weight))
+                .ok_or(XcmError::TooExpensive)?;
+        let unused = payment
+            .checked_sub((location, fee).into())
+            .map_err(|_| XcmError::TooExpensive)?;
+
+        self.weight = self.weight.saturating_add(weight);
+        self.paid = Some((location, asset_id, already_paid.saturating_add(fee)));
+
+        Ok(unused)
+    }
+
+    fn refund_weight(&mut self, weight: Weight, _context: &XcmContext) -> Option<MultiAsset> {
+        let (location, asset_id, paid) = self.paid?;
+        let weight = weight.min(self.weight);
+        let refund =
+            AvnTransactionPayment::fee_in_asset(asset_id, WeightToFee::weight_to_fee(&weight))?
+                .min(paid);
+
+        self.weight = self.weight.saturating_sub(weight);
+        self.paid = Some((location, asset_id, paid.saturating_sub(refund)));
+
+        if refund > 0 {
+            Some((location, refund).into())
+        } else {
+            None
+        }
+    }
+}
+
+impl Drop for ForeignAssetFeeTrader {
+    fn drop(&mut self) {
+        if let Some((_, asset_id, amount)) = self.paid {
+            if amount > 0 {
+                let _ = <Assets as fungibles::Mutate<AccountId>>::mint_into(
+                    asset_id,
+                    &AvnTreasuryAccount::get(),
+                    amount,
+                );
+            }
+        }
+    }
+}
+
 pub struct XcmConfig;
 impl xcm_executor::Config for XcmConfig {
     type RuntimeCall = RuntimeCall;
     type XcmSender = XcmRouter;
     // How to withdraw and deposit an asset.
-    type AssetTransactor = LocalAssetTransactor;
+    type AssetTransactor = AssetTransactors;
     type OriginConverter = XcmOriginToTransactDispatchOrigin;
-    type IsReserve = NativeAsset;
+    type IsReserve = (NativeAsset, RegisteredForeignReserve<Runtime>);
     type IsTeleporter = (); // Teleporting is disabled.
     type UniversalLocation = UniversalLocation;
     type Barrier = Barrier;
     type Weigher = FixedWeightBounds<UnitWeightCost, RuntimeCall, MaxInstructions>;
-    type Trader =
-        UsingComponents<WeightToFee, RelayLocation, AccountId, Balances, ToAuthor<Runtime>>;
+    type Trader = (
+        UsingComponents<WeightToFee, SelfReserve, AccountId, Balances, ToAuthor<Runtime>>,
+        ForeignAssetFeeTrader,
+    );
     type ResponseHandler = PolkadotXcm;
     type AssetTrap = PolkadotXcm;
     type AssetClaims = PolkadotXcm;
@@ -155,6 +275,14 @@ parameter_types! {
     pub ReachableDest: Option<MultiLocation> = Some(Parent.into());
 }
 
+/// Only AVT can be reserve-transferred out of this chain.
+pub struct OnlyAvtReserveTransfers;
+impl Contains<(MultiLocation, Vec<MultiAsset>)> for OnlyAvtReserveTransfers {
+    fn contains((_dest, assets): &(MultiLocation, Vec<MultiAsset>)) -> bool {
+        assets.iter().all(|asset| asset.id == Concrete(SelfReserve::get()))
+    }
+}
+
 impl pallet_xcm::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
     type SendXcmOrigin = EnsureXcmOrigin<RuntimeOrigin, LocalOriginToLocation>;
@@ -165,7 +293,7 @@ impl pallet_xcm::Config for Runtime {
     // Needs to be `Everything` for local testing.
     type XcmExecutor = XcmExecutor<XcmConfig>;
     type XcmTeleportFilter = Everything;
-    type XcmReserveTransferFilter = Nothing;
+    type XcmReserveTransferFilter = OnlyAvtReserveTransfers;
     type Weigher = FixedWeightBounds<UnitWeightCost, RuntimeCall, MaxInstructions>;
     type UniversalLocation = UniversalLocation;
     type RuntimeOrigin = RuntimeOrigin;
@@ -191,3 +319,42 @@ impl cumulus_pallet_xcm::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
     type XcmExecutor = XcmExecutor<XcmConfig>;
 }
+
+/// Sends AVT reserve transfers via the XCM pallet
+pub struct PolkadotXcmReserveTransfer;
+impl pallet_assets_bridge::ReserveTransfer<AccountId> for PolkadotXcmReserveTransfer {
+    fn limited_reserve_transfer(
+        sender: AccountId,
+        dest: MultiLocation,
+        beneficiary: MultiLocation,
+        asset: MultiAsset,
+        weight_limit: WeightLimit,
+    ) -> DispatchResult {
+        PolkadotXcm::limited_reserve_transfer_assets(
+            RuntimeOrigin::signed(sender),
+            Box::new(dest.into()),
+            Box::new(beneficiary.into()),
+            Box::new(MultiAssets::from(asset).into()),
+            0,
+            weight_limit,
+        )
+    }
+
+    fn weight() -> Weight {
+        <Runtime as pallet_xcm::Config>::WeightInfo::reserve_transfer_assets()
+    }
+}
+
+parameter_types! {
+    pub const MinimumXcmTransferAmount: Balance = 100 * MILLI_AVT;
+}
+
+impl pallet_assets_bridge::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type Currency = Balances;
+    type XcmTransfer = PolkadotXcmReserveTransfer;
+    type MinimumTransferAmount = MinimumXcmTransferAmount;
+    type AssetId = u32;
+    type Assets = Assets;
+    type WeightInfo = pallet_assets_bridge::default_weights::SubstrateWeight<Runtime>;
+}
@@ -0,0 +1,88 @@
+// Copyright 2024 Aventus Network Services (UK) Ltd.
+
+//! Computes, in the build script of a runtime, the RFC-0078 hash of its metadata.
+//!
+//! The runtime is built a first time, its metadata is read by executing the wasm blob, and the
+//! runtime is built again with the hash in `RUNTIME_METADATA_HASH`. Embedding the hash doesn't
+//! change the metadata, so the hash is the one of the final runtime.
+
+use codec::{Decode, Encode};
+use frame_metadata::{RuntimeMetadata, RuntimeMetadataPrefixed};
+use merkleized_metadata::{generate_metadata_digest, ExtraInfo};
+use sc_executor::WasmExecutor;
+use sc_executor_common::runtime_blob::RuntimeBlob;
+use sp_version::RuntimeVersion;
+use std::{env, fs, path::PathBuf};
+
+/// The metadata version the hash is computed from
+const METADATA_VERSION: u32 = 15;
+
+/// Reads the compressed wasm blob written by the last `WasmBuilder::build`
+pub fn built_runtime() -> Vec<u8> {
+    let out_dir = PathBuf::from(env::var("OUT_DIR").expect("OUT_DIR is set by cargo"));
+    let wasm_binary = fs::read_to_string(out_dir.join("wasm_binary.rs"))
+        .expect("The wasm builder writes wasm_binary.rs");
+
+    let path = wasm_binary
+        .split("include_bytes!(\"")
+        .nth(1)
+        .and_then(|rest| rest.split("\")").next())
+        .expect("wasm_binary.rs includes the wasm blob");
+
+    fs::read(path).expect("The wasm blob was just built")
+}
+
+/// Computes the metadata hash of `wasm`, a runtime whose native token is `token_symbol`
+pub fn metadata_hash(wasm: &[u8], token_symbol: &str, decimals: u8) -> [u8; 32] {
+    let executor = WasmExecutor::<sp_io::SubstrateHostFunctions>::builder().build();
+    let call = |method: &str, data: &[u8]| -> Vec<u8> {
+        let runtime = RuntimeBlob::uncompress_if_needed(wasm).expect("The runtime is a wasm blob");
+        let mut externalities = sp_io::TestExternalities::default();
+        let mut ext = externalities.ext();
+        executor
+            .uncached_call(runtime, &mut ext, true, method, data)
+            .unwrap_or_else(|e| panic!("Calling {method} on the runtime failed: {e}"))
+    };
+
+    let version = RuntimeVersion::decode(&mut &call("Core_version", &[])[..])
+        .expect("Core_version returns the runtime version");
+    let metadata = Option::<Vec<u8>>::decode(
+        &mut &call("Metadata_metadata_at_version", &METADATA_VERSION.encode())[..],
+    )
+    .expect("Metadata_metadata_at_version returns the encoded metadata")
+    .expect("The runtime provides metadata V15");
+    let metadata = RuntimeMetadataPrefixed::decode(&mut &metadata[..])
+        .expect("The metadata can be decoded")
+        .1;
+
+    let extra_info = ExtraInfo {
+        spec_version: version.spec_version,
+        spec_name: version.spec_name.to_string(),
+        base58_prefix: ss58_prefix(&metadata),
+        decimals,
+        token_symbol: token_symbol.into(),
+    };
+
+    generate_metadata_digest(&metadata, extra_info)
+        .expect("The digest of the metadata can be generated")
+        .hash()
+}
+
+/// Hex encodes `hash`, as `RUNTIME_METADATA_HASH` expects it
+pub fn to_hex(hash: &[u8; 32]) -> String {
+    hash.iter().map(|byte| format!("{byte:02x}")).collect()
+}
+
+fn ss58_prefix(metadata: &RuntimeMetadata) -> u16 {
+    let RuntimeMetadata::V15(metadata) = metadata else {
+        panic!("The runtime provides metadata V15")
+    };
+
+    metadata
+        .pallets
+        .iter()
+        .find(|pallet| pallet.name == "System")
+        .and_then(|system| system.constants.iter().find(|constant| constant.name == "SS58Prefix"))
+        .and_then(|prefix| u16::decode(&mut &prefix.value[..]).ok())
+        .expect("The System pallet exposes its SS58Prefix")
+}
@@ -0,0 +1,70 @@
+// Copyright 2024 Aventus Network Services (UK) Ltd.
+
+//! Signed extension that lets signers commit to the metadata of the runtime, as described in
+//! RFC-0078. Offline signers, like hardware wallets, use the metadata to decode what they are
+//! asked to sign. When the signer includes the metadata hash in the signed payload, the
+//! transaction is only valid if the metadata matches the one of the runtime.
+//!
+//! The hash is embedded when the runtime is built with the `metadata-hash` feature, which is
+//! enabled by default, see `sp_avn_common::metadata_hash`. A runtime built without it rejects any
+//! transaction that enables the check.
+
+use codec::{Decode, Encode};
+use scale_info::TypeInfo;
+use sp_avn_common::metadata_hash::RUNTIME_METADATA_HASH;
+use sp_runtime::{
+    traits::{DispatchInfoOf, SignedExtension},
+    transaction_validity::TransactionValidityError,
+};
+use sp_std::marker::PhantomData;
+
+/// Whether the signer included the metadata hash in the signed payload
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, Debug, TypeInfo)]
+pub enum Mode {
+    Disabled,
+    Enabled,
+}
+
+#[derive(Encode, Decode, Clone, PartialEq, Eq, TypeInfo)]
+#[scale_info(skip_type_params(T))]
+pub struct CheckMetadataHash<T> {
+    mode: Mode,
+    _phantom: PhantomData<T>,
+}
+
+impl<T> CheckMetadataHash<T> {
+    pub fn new(enable: bool) -> Self {
+        Self { mode: if enable { Mode::Enabled } else { Mode::Disabled }, _phantom: PhantomData }
+    }
+}
+
+impl<T> sp_std::fmt::Debug for CheckMetadataHash<T> {
+    fn fmt(&self, f: &mut sp_std::fmt::Formatter) -> sp_std::fmt::Result {
+        write!(f, "CheckMetadataHash({:?})", self.mode)
+    }
+}
+
+impl<T: frame_system::Config + Send + Sync> SignedExtension for CheckMetadataHash<T> {
+    const IDENTIFIER: &'static str = "CheckMetadataHash";
+    type AccountId = T::AccountId;
+    type Call = <T as frame_system::Config>::RuntimeCall;
+    type AdditionalSigned = Option<[u8; 32]>;
+    type Pre = ();
+
+    fn additional_signed(&self) -> Result<Self::AdditionalSigned, TransactionValidityError> {
+        Ok(match self.mode {
+            Mode::Disabled => None,
+            Mode::Enabled => RUNTIME_METADATA_HASH,
+        })
+    }
+
+    fn pre_dispatch(
+        self,
+        who: &Self::AccountId,
+        call: &Self::Call,
+        info: &DispatchInfoOf<Self::Call>,
+        len: usize,
+    ) -> Result<Self::Pre, TransactionValidityError> {
+        self.validate(who, call, info, len).map(|_| ())
+    }
+}
@@ -49,11 +49,22 @@ pub mod time {
     use node_primitives::{BlockNumber, Moment};
 
     /// Change this to adjust the block time.
-    pub const MILLISECS_PER_BLOCK: u64 = 12000;
+    pub const MILLISECS_PER_BLOCK: u64 = 6000;
+    /// The block time before async backing was enabled. Periods stored on chain in blocks were set
+    /// with this block time in mind.
+    pub const LEGACY_MILLISECS_PER_BLOCK: u64 = 12000;
     pub const SECS_PER_BLOCK: Moment = MILLISECS_PER_BLOCK / 1000;
 
     // These time units are defined in number of blocks.
     pub const MINUTES: BlockNumber = 60 / (SECS_PER_BLOCK as BlockNumber);
     pub const HOURS: BlockNumber = MINUTES * 60;
     pub const DAYS: BlockNumber = HOURS * 24;
+
+    /// The relay chain slot duration, in milliseconds.
+    pub const RELAY_CHAIN_SLOT_DURATION_MILLIS: u32 = 6000;
+    /// Maximum number of blocks authored per relay chain parent block.
+    pub const BLOCK_PROCESSING_VELOCITY: u32 = 1;
+    /// Maximum number of blocks accepted by the runtime that are not yet included in the relay
+    /// chain.
+    pub const UNINCLUDED_SEGMENT_CAPACITY: u32 = 3;
 }
@@ -1,6 +1,7 @@
 // Copyright 2022 Aventus Network Services (UK) Ltd.
 #![cfg_attr(not(feature = "std"), no_std)]
 
+pub mod check_metadata_hash;
 pub mod weights;
 
 use smallvec::smallvec;
@@ -17,6 +18,7 @@ pub use node_primitives::{AccountId, Signature};
 use node_primitives::{Balance, BlockNumber};
 
 pub mod constants;
+pub use check_metadata_hash::CheckMetadataHash;
 use constants::currency::*;
 use weights::ExtrinsicBaseWeight;
 
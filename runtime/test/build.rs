@@ -1,10 +1,27 @@
+#[cfg(all(feature = "std", feature = "metadata-hash"))]
+#[path = "../common/build/metadata_hash.rs"]
+mod metadata_hash;
+
 fn main() {
     #[cfg(feature = "std")]
     {
-        substrate_wasm_builder::WasmBuilder::new()
-            .with_current_project()
-            .export_heap_base()
-            .import_memory()
-            .build();
+        let build = || {
+            substrate_wasm_builder::WasmBuilder::new()
+                .with_current_project()
+                .export_heap_base()
+                .import_memory()
+                .build()
+        };
+
+        build();
+
+        // The metadata of the first build is hashed, and the runtime is built again with the
+        // hash in RUNTIME_METADATA_HASH for `CheckMetadataHash`
+        #[cfg(feature = "metadata-hash")]
+        {
+            let hash = metadata_hash::metadata_hash(&metadata_hash::built_runtime(), "AVT", 18);
+            std::env::set_var("RUNTIME_METADATA_HASH", metadata_hash::to_hex(&hash));
+            build();
+        }
     }
 }
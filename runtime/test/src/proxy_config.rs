@@ -1,7 +1,8 @@
 use super::{
-    AccountId, Box, Decode, Encode, InnerCallValidator, Proof, ProvableProxy, Runtime, RuntimeCall,
-    RuntimeDebug, Signature, TypeInfo,
+    AccountId, Box, Contains, Decode, Encode, FeeCategory, InnerCallValidator, Proof,
+    ProvableProxy, Runtime, RuntimeCall, RuntimeDebug, Signature, SpendableAsset, TypeInfo, H160,
 };
+use sp_std::{vec, vec::Vec};
 
 // Avn proxy configuration logic
 #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Encode, Decode, RuntimeDebug, TypeInfo)]
@@ -38,6 +39,11 @@ impl ProvableProxy<RuntimeCall, Signature, AccountId> for AvnProxyConfig {
                     t1_recipient: _,
                 },
             ) => return Some(proof.clone()),
+            RuntimeCall::TokenManager(
+                pallet_token_manager::pallet::Call::schedule_signed_lower_with_memo {
+                    proof, ..
+                },
+            ) => return Some(proof.clone()),
             RuntimeCall::NftManager(pallet_nft_manager::Call::signed_mint_single_nft {
                 proof,
                 unique_external_ref: _,
@@ -128,9 +134,89 @@ impl ProvableProxy<RuntimeCall, Signature, AccountId> for AvnProxyConfig {
                     nominator: _,
                 },
             ) => return Some(proof.clone()),
+            RuntimeCall::ParachainStaking(
+                pallet_parachain_staking::Call::signed_replace_nomination_request {
+                    proof,
+                    candidate: _,
+                    action: _,
+                },
+            ) => return Some(proof.clone()),
+            RuntimeCall::ParachainStaking(
+                pallet_parachain_staking::Call::signed_schedule_leave_candidates { proof },
+            ) => return Some(proof.clone()),
+            RuntimeCall::ParachainStaking(
+                pallet_parachain_staking::Call::signed_execute_leave_candidates {
+                    proof,
+                    candidate: _,
+                },
+            ) => return Some(proof.clone()),
+            RuntimeCall::ParachainStaking(
+                pallet_parachain_staking::Call::signed_cancel_leave_candidates { proof },
+            ) => return Some(proof.clone()),
             _ => None,
         }
     }
+
+    fn fee_category(call: &RuntimeCall) -> FeeCategory {
+        match call {
+            RuntimeCall::ParachainStaking(..) => FeeCategory::Staking,
+            RuntimeCall::TokenManager(..) | RuntimeCall::Balances(..) => FeeCategory::TokenTransfer,
+            RuntimeCall::NftManager(..) => FeeCategory::Nft,
+            _ => FeeCategory::Other,
+        }
+    }
+
+    fn spending(call: &RuntimeCall) -> Vec<(SpendableAsset, u128)> {
+        let token_spending = |token_id: &H160, amount: u128| {
+            if *token_id == pallet_token_manager::AVTTokenContract::<Runtime>::get() {
+                vec![(SpendableAsset::Native, amount)]
+            } else {
+                vec![(SpendableAsset::Token(*token_id), amount)]
+            }
+        };
+
+        match call {
+            RuntimeCall::TokenManager(pallet_token_manager::Call::signed_transfer {
+                token_id,
+                amount,
+                ..
+            }) => token_spending(token_id, *amount),
+            RuntimeCall::TokenManager(
+                pallet_token_manager::Call::schedule_direct_lower { token_id, amount, .. } |
+                pallet_token_manager::Call::schedule_signed_lower { token_id, amount, .. } |
+                pallet_token_manager::Call::schedule_direct_lower_with_memo {
+                    token_id,
+                    amount,
+                    ..
+                } |
+                pallet_token_manager::Call::schedule_signed_lower_with_memo {
+                    token_id,
+                    amount,
+                    ..
+                },
+            ) => token_spending(token_id, *amount),
+            RuntimeCall::TokenManager(pallet_token_manager::Call::create_stream {
+                token_id,
+                rate_per_block,
+                duration,
+                ..
+            }) => token_spending(token_id, rate_per_block.saturating_mul((*duration).into())),
+            // Bonding locks the native currency without taking it out of the free balance
+            RuntimeCall::ParachainStaking(
+                pallet_parachain_staking::Call::join_candidates { bond: amount, .. } |
+                pallet_parachain_staking::Call::candidate_bond_extra { more: amount } |
+                pallet_parachain_staking::Call::signed_candidate_bond_extra {
+                    extra_amount: amount,
+                    ..
+                } |
+                pallet_parachain_staking::Call::nominate { amount, .. } |
+                pallet_parachain_staking::Call::signed_nominate { amount, .. } |
+                pallet_parachain_staking::Call::bond_extra { more: amount, .. } |
+                pallet_parachain_staking::Call::signed_bond_extra { extra_amount: amount, .. },
+            ) => vec![(SpendableAsset::Native, *amount)],
+            _ => Vec::new(),
+        }
+    }
 }
 
 impl InnerCallValidator for AvnProxyConfig {
@@ -150,3 +236,20 @@ impl InnerCallValidator for AvnProxyConfig {
         }
     }
 }
+
+// The calls an Ethereum address can authorise for the account it is registered to
+pub struct EthereumCallFilter;
+impl Contains<RuntimeCall> for EthereumCallFilter {
+    fn contains(call: &RuntimeCall) -> bool {
+        matches!(
+            call,
+            RuntimeCall::Balances(
+                pallet_balances::Call::transfer_allow_death { .. } |
+                    pallet_balances::Call::transfer_keep_alive { .. }
+            ) | RuntimeCall::ParachainStaking(
+                pallet_parachain_staking::Call::nominate { .. } |
+                    pallet_parachain_staking::Call::bond_extra { .. }
+            )
+        )
+    }
+}